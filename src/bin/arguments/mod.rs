@@ -163,3 +163,251 @@ pub fn aws_region() -> Arg {
         .help("AWS region for S3/CloudFront")
         .num_args(1)
 }
+
+pub fn imprint_ids() -> Arg {
+    Arg::new("imprint")
+        .short('i')
+        .long("imprint")
+        .value_name("IMPRINT_ID")
+        .help("Thoth ID of an imprint to process. Repeat to select several; defaults to all imprints with file hosting configured")
+        .value_parser(|value: &str| uuid::Uuid::parse_str(value))
+        .action(ArgAction::Append)
+}
+
+pub fn failures_only() -> Arg {
+    Arg::new("failures-only")
+        .long("failures-only")
+        .help("Only re-check files whose most recent audit found a problem")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn verify_checksums() -> Arg {
+    Arg::new("verify-checksums")
+        .long("verify-checksums")
+        .help("Read each object in full to compute its SHA-256 instead of relying on the checksum stored by S3")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn snapshot_file() -> Arg {
+    Arg::new("file")
+        .value_name("FILE")
+        .help("Path to a Crossref Funder Registry dump in RDF/JSON format")
+        .value_parser(value_parser!(std::path::PathBuf))
+        .required(true)
+        .num_args(1)
+}
+
+pub fn work_identifier() -> Arg {
+    Arg::new("work")
+        .value_name("ID_OR_DOI")
+        .help("Thoth ID or DOI of the work")
+        .required(true)
+        .num_args(1)
+}
+
+pub fn specification() -> Arg {
+    Arg::new("specification")
+        .value_name("SPECIFICATION")
+        .help("ID of the metadata specification to generate, e.g. onix_3.0::thoth")
+        .required(true)
+        .num_args(1)
+}
+
+pub fn work_id() -> Arg {
+    Arg::new("work")
+        .short('w')
+        .long("work")
+        .value_name("WORK_ID")
+        .help("Thoth ID of the work to generate a record for")
+        .value_parser(|value: &str| uuid::Uuid::parse_str(value))
+        .num_args(1)
+}
+
+pub fn publisher_id() -> Arg {
+    Arg::new("publisher")
+        .short('p')
+        .long("publisher")
+        .value_name("PUBLISHER_ID")
+        .help("Thoth ID of the publisher to generate a record of all works for")
+        .value_parser(|value: &str| uuid::Uuid::parse_str(value))
+        .num_args(1)
+}
+
+pub fn output_file() -> Arg {
+    Arg::new("output")
+        .short('o')
+        .long("output")
+        .value_name("FILE")
+        .help("Path to write the record to. Defaults to the file name served by the export API, in the current directory")
+        .value_parser(value_parser!(std::path::PathBuf))
+        .num_args(1)
+}
+
+pub fn import_file() -> Arg {
+    Arg::new("file")
+        .value_name("FILE")
+        .help("Path to the record to import")
+        .value_parser(value_parser!(std::path::PathBuf))
+        .required(true)
+        .num_args(1)
+}
+
+pub fn imprint_id() -> Arg {
+    Arg::new("imprint")
+        .short('i')
+        .long("imprint")
+        .value_name("IMPRINT_ID")
+        .help("Thoth ID of the imprint to create the works in")
+        .value_parser(|value: &str| uuid::Uuid::parse_str(value))
+        .required(true)
+        .num_args(1)
+}
+
+pub fn specifications() -> Arg {
+    Arg::new("specification")
+        .short('s')
+        .long("specification")
+        .value_name("SPECIFICATION")
+        .help("ID of a metadata specification to process, e.g. onix_3.0::thoth. Repeat to select several; defaults to all specifications")
+        .action(ArgAction::Append)
+}
+
+pub fn work_ids() -> Arg {
+    Arg::new("work")
+        .short('w')
+        .long("work")
+        .value_name("WORK_ID")
+        .help("Thoth ID of a work whose cached records to process. Repeat to select several")
+        .value_parser(|value: &str| uuid::Uuid::parse_str(value))
+        .action(ArgAction::Append)
+}
+
+pub fn publisher_ids() -> Arg {
+    Arg::new("publisher")
+        .short('p')
+        .long("publisher")
+        .value_name("PUBLISHER_ID")
+        .help("Thoth ID of a publisher whose cached records, and those of its works, to process. Repeat to select several")
+        .value_parser(|value: &str| uuid::Uuid::parse_str(value))
+        .action(ArgAction::Append)
+}
+
+pub fn older_than() -> Arg {
+    Arg::new("older-than")
+        .long("older-than")
+        .value_name("TIMESTAMP")
+        .help("Only process records generated from data last updated before this RFC 3339 timestamp, e.g. 2025-01-31T00:00:00Z")
+        .value_parser(|value: &str| thoth::api::model::Timestamp::parse_from_rfc3339(value))
+        .num_args(1)
+}
+
+pub fn all_records() -> Arg {
+    Arg::new("all")
+        .long("all")
+        .help("Process every cached record")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn dry_run() -> Arg {
+    Arg::new("dry-run")
+        .long("dry-run")
+        .help("Report what would be done without changing anything")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn json_output() -> Arg {
+    Arg::new("json")
+        .long("json")
+        .help("Print results as JSON")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn publisher_count() -> Arg {
+    Arg::new("publishers")
+        .long("publishers")
+        .value_name("COUNT")
+        .help("Number of publishers to generate")
+        .value_parser(value_parser!(usize))
+        .default_value("2")
+        .num_args(1)
+}
+
+pub fn work_count() -> Arg {
+    Arg::new("works")
+        .long("works")
+        .value_name("COUNT")
+        .help("Number of books to generate per publisher")
+        .value_parser(value_parser!(usize))
+        .default_value("10")
+        .num_args(1)
+}
+
+pub fn chapter_count() -> Arg {
+    Arg::new("chapters")
+        .long("chapters")
+        .value_name("COUNT")
+        .help("Number of chapters to generate per book")
+        .value_parser(value_parser!(usize))
+        .default_value("3")
+        .num_args(1)
+}
+
+pub fn random_seed() -> Arg {
+    Arg::new("seed")
+        .long("seed")
+        .value_name("SEED")
+        .help("Seed of the random generator. The same seed produces the same dataset, so use a different one to seed a database again")
+        .value_parser(value_parser!(u64))
+        .default_value("0")
+        .num_args(1)
+}
+
+pub fn log_format() -> Arg {
+    Arg::new("log-format")
+        .long("log-format")
+        .value_name("LOG_FORMAT")
+        .env("LOG_FORMAT")
+        .default_value("text")
+        .help("Format of log output: text, or json for one structured record per line")
+        .value_parser(|value: &str| value.parse::<thoth::api::telemetry::LogFormat>())
+        .num_args(1)
+}
+
+pub fn otlp_endpoint() -> Arg {
+    Arg::new("otlp-endpoint")
+        .long("otlp-endpoint")
+        .value_name("OTEL_EXPORTER_OTLP_ENDPOINT")
+        .env("OTEL_EXPORTER_OTLP_ENDPOINT")
+        .help("Base URL of an OpenTelemetry collector to export traces to over OTLP/HTTP, e.g. http://localhost:4318")
+        .num_args(1)
+}
+
+pub fn config_file() -> Arg {
+    Arg::new("config")
+        .short('c')
+        .long("config")
+        .value_name("THOTH_CONFIG")
+        .env("THOTH_CONFIG")
+        .help("Path to a TOML configuration file. Its settings are overridden by environment variables and command line arguments")
+        .value_parser(value_parser!(std::path::PathBuf))
+        .num_args(1)
+}
+
+pub fn cors_origins(env_value: &'static str) -> Arg {
+    Arg::new("cors-origin")
+        .long("cors-origin")
+        .value_name(env_value)
+        .env(env_value)
+        .help("Origin allowed to make cross-origin requests, e.g. https://thoth.pub. Repeat, or separate with commas, to allow several")
+        .value_delimiter(',')
+        .action(ArgAction::Append)
+}
+
+pub fn server() -> Arg {
+    Arg::new("server")
+        .long("server")
+        .value_name("SERVER")
+        .help("Only check the settings of this server")
+        .value_parser(["graphql-api", "export-api"])
+        .num_args(1)
+}
@@ -1,42 +1,206 @@
 use crate::arguments;
 use crate::commands::get_redis_pool;
-use clap::{ArgMatches, Command};
-use dialoguer::{console::Term, theme::ColorfulTheme, MultiSelect};
+use clap::{ArgGroup, ArgMatches, Command};
 use lazy_static::lazy_static;
+use std::sync::Arc;
 use thoth::{
-    api::redis::{del, scan_match},
+    api::{model::Timestamp, redis::RedisPool},
+    cache_stats, delete_cached_records,
     errors::{ThothError, ThothResult},
-    ALL_SPECIFICATIONS,
+    list_cached_records, publisher_record_ids, warm_cache, CacheFilter, CachedRecord,
 };
+use uuid::Uuid;
 
 lazy_static! {
     pub(crate) static ref COMMAND: Command = Command::new("cache")
         .about("Manage cached records")
         .arg(arguments::redis())
+        .arg(arguments::gql_endpoint())
+        .arg(arguments::thoth_pat())
         .subcommand_required(true)
         .arg_required_else_help(true)
-        .subcommand(Command::new("delete").about("Delete cached records"));
+        .subcommand(
+            Command::new("list")
+                .about("List cached records")
+                .args(filter_arguments())
+                .arg(arguments::dry_run())
+                .arg(arguments::json_output()),
+        )
+        .subcommand(
+            Command::new("stats")
+                .about("Count cached records and the memory they use, per specification")
+                .args(filter_arguments())
+                .arg(arguments::dry_run())
+                .arg(arguments::json_output()),
+        )
+        .subcommand(
+            Command::new("delete")
+                .about("Delete cached records")
+                .args(filter_arguments())
+                .arg(arguments::all_records())
+                .group(
+                    ArgGroup::new("selection")
+                        .args(["specification", "work", "publisher", "older-than", "all"])
+                        .multiple(true)
+                        .required(true),
+                )
+                .arg(arguments::dry_run())
+                .arg(arguments::json_output()),
+        )
+        .subcommand(
+            Command::new("warm")
+                .about("Generate and cache the records of all active works of a publisher")
+                .arg(arguments::specifications())
+                .arg(arguments::publisher_ids().required(true))
+                .arg(arguments::dry_run())
+                .arg(arguments::json_output()),
+        );
 }
 
-pub fn delete(arguments: &ArgMatches) -> ThothResult<()> {
-    let pool = get_redis_pool(arguments);
-    let chosen: Vec<usize> = MultiSelect::with_theme(&ColorfulTheme::default())
-        .items(&ALL_SPECIFICATIONS)
-        .with_prompt("Select cached specifications to delete")
-        .interact_on(&Term::stdout())?;
+fn filter_arguments() -> [clap::Arg; 4] {
+    [
+        arguments::specifications(),
+        arguments::work_ids(),
+        arguments::publisher_ids(),
+        arguments::older_than(),
+    ]
+}
+
+fn specifications(arguments: &ArgMatches) -> Vec<String> {
+    arguments
+        .get_many::<String>("specification")
+        .map(|specifications| specifications.cloned().collect())
+        .unwrap_or_default()
+}
+
+fn ids(arguments: &ArgMatches, id: &str) -> Vec<Uuid> {
+    arguments
+        .get_many::<Uuid>(id)
+        .map(|ids| ids.copied().collect())
+        .unwrap_or_default()
+}
+
+/// Find the cached records selected by the filter arguments of a subcommand
+async fn cached_records(
+    pool: &RedisPool,
+    arguments: &ArgMatches,
+    filter_arguments: &ArgMatches,
+) -> ThothResult<Vec<CachedRecord>> {
+    let mut filter = CacheFilter {
+        specifications: specifications(filter_arguments),
+        ids: ids(filter_arguments, "work"),
+        older_than: filter_arguments.get_one::<Timestamp>("older-than").copied(),
+    };
+    let publisher_ids = ids(filter_arguments, "publisher");
+    if !publisher_ids.is_empty() {
+        let thoth_client = super::thoth_client(arguments);
+        for publisher_id in publisher_ids {
+            filter
+                .ids
+                .extend(publisher_record_ids(&thoth_client, publisher_id).await?);
+        }
+    }
+    list_cached_records(pool, &filter).await
+}
+
+fn run<T>(future: impl std::future::Future<Output = ThothResult<T>>) -> ThothResult<T> {
     // run a separate tokio runtime to avoid interfering with actix's threads
     let runtime = tokio::runtime::Builder::new_multi_thread()
         .worker_threads(1)
         .enable_all()
         .build()?;
-    runtime.block_on(async {
-        for index in chosen {
-            let specification = ALL_SPECIFICATIONS.get(index).unwrap();
-            let keys = scan_match(&pool, &format!("{specification}*")).await?;
-            for key in keys {
-                del(&pool, &key).await?;
-            }
+    runtime.block_on(future)
+}
+
+pub fn list(arguments: &ArgMatches, list_arguments: &ArgMatches) -> ThothResult<()> {
+    let pool = get_redis_pool(arguments);
+    let records = run(cached_records(&pool, arguments, list_arguments))?;
+    if list_arguments.get_flag("json") {
+        return super::print_json(serde_json::to_string_pretty(&records));
+    }
+    for record in records {
+        println!(
+            "{}:{}\t{}\t{}\t{} bytes",
+            record.specification,
+            record.id,
+            record
+                .last_updated
+                .map(|last_updated| last_updated.to_rfc3339())
+                .unwrap_or_default(),
+            if record.has_error { "error" } else { "record" },
+            record.memory_bytes,
+        );
+    }
+    Ok(())
+}
+
+pub fn stats(arguments: &ArgMatches, stats_arguments: &ArgMatches) -> ThothResult<()> {
+    let pool = get_redis_pool(arguments);
+    let records = run(cached_records(&pool, arguments, stats_arguments))?;
+    let stats = cache_stats(&records);
+    if stats_arguments.get_flag("json") {
+        return super::print_json(serde_json::to_string_pretty(&stats));
+    }
+    for stat in stats {
+        println!(
+            "{}: {} records, {} errors, {} keys, {} bytes",
+            stat.specification, stat.records, stat.errors, stat.keys, stat.memory_bytes,
+        );
+    }
+    Ok(())
+}
+
+pub fn delete(arguments: &ArgMatches, delete_arguments: &ArgMatches) -> ThothResult<()> {
+    let pool = get_redis_pool(arguments);
+    let dry_run = delete_arguments.get_flag("dry-run");
+    let records = run(async {
+        let records = cached_records(&pool, arguments, delete_arguments).await?;
+        if !dry_run {
+            delete_cached_records(&pool, &records).await?;
+        }
+        Ok::<_, ThothError>(records)
+    })?;
+    if delete_arguments.get_flag("json") {
+        return super::print_json(serde_json::to_string_pretty(&records));
+    }
+    let action = if dry_run { "Would delete" } else { "Deleted" };
+    for record in &records {
+        println!("{action} {}:{}", record.specification, record.id);
+    }
+    println!("{action} {} cached records", records.len());
+    Ok(())
+}
+
+pub fn warm(arguments: &ArgMatches, warm_arguments: &ArgMatches) -> ThothResult<()> {
+    let pool = Arc::new(get_redis_pool(arguments));
+    let thoth_client = Arc::new(super::thoth_client(arguments));
+    let specifications = specifications(warm_arguments);
+    let dry_run = warm_arguments.get_flag("dry-run");
+    let warmed = run(async {
+        let mut warmed = vec![];
+        for publisher_id in ids(warm_arguments, "publisher") {
+            warmed.extend(
+                warm_cache(
+                    thoth_client.clone(),
+                    pool.clone(),
+                    &specifications,
+                    publisher_id,
+                    dry_run,
+                )
+                .await?,
+            );
+        }
+        Ok::<_, ThothError>(warmed)
+    })?;
+    if warm_arguments.get_flag("json") {
+        return super::print_json(serde_json::to_string_pretty(&warmed));
+    }
+    for record in &warmed {
+        match (&record.error, record.generated) {
+            (Some(error), _) => println!("✗ {}:{}: {error}", record.specification, record.work_id),
+            (None, true) => println!("✓ {}:{}", record.specification, record.work_id),
+            (None, false) => println!("Would generate {}:{}", record.specification, record.work_id),
         }
-        Ok::<(), ThothError>(())
-    })
+    }
+    Ok(())
 }
@@ -0,0 +1,71 @@
+use crate::arguments;
+use crate::commands::start;
+use clap::{ArgMatches, Command};
+use lazy_static::lazy_static;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use thoth::errors::{ThothError, ThothResult};
+
+const SERVERS: [&str; 2] = ["graphql-api", "export-api"];
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("config")
+        .about("Inspect server configuration")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("check")
+                .about("Validate the settings the servers would start with, from the configuration file and the environment")
+                .arg(arguments::config_file())
+                .arg(arguments::server()),
+        );
+}
+
+/// Resolve the settings of a server the way `thoth start` would, with no command line arguments
+/// other than the configuration file
+fn check_server(server: &str, config: Option<&PathBuf>) -> ThothResult<()> {
+    let mut argv = vec![OsString::from(server)];
+    if let Some(path) = config {
+        argv.push("--config".into());
+        argv.push(path.into());
+    }
+    let arguments = start::COMMAND
+        .find_subcommand(server)
+        .expect("Every server has a start subcommand")
+        .clone()
+        .try_get_matches_from(argv)
+        .map_err(|e| ThothError::InvalidConfiguration(e.to_string()))?;
+    match server {
+        "graphql-api" => start::graphql_api_settings(&arguments).map(|_| ()),
+        _ => start::export_api_settings(&arguments).map(|_| ()),
+    }
+}
+
+pub fn check(arguments: &ArgMatches) -> ThothResult<()> {
+    let config = arguments.get_one::<PathBuf>("config");
+    let servers = match arguments.get_one::<String>("server") {
+        Some(server) => vec![server.as_str()],
+        None => SERVERS.to_vec(),
+    };
+    let mut failed = vec![];
+    for server in servers {
+        match check_server(server, config) {
+            Ok(()) => println!("{server}: OK"),
+            Err(ThothError::InvalidConfiguration(problems)) => {
+                println!("{server}: invalid");
+                for problem in problems.split("; ") {
+                    println!("  - {problem}");
+                }
+                failed.push(server);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    match failed.is_empty() {
+        true => Ok(()),
+        false => Err(ThothError::InvalidConfiguration(format!(
+            "invalid settings for {}",
+            failed.join(", ")
+        ))),
+    }
+}
@@ -0,0 +1,108 @@
+use crate::arguments;
+use clap::{ArgMatches, Command};
+use lazy_static::lazy_static;
+use std::path::PathBuf;
+use thoth::{
+    api::db::{anonymise_database, export_fixture, init_pool, seed_database, SeedOptions},
+    errors::{ThothError, ThothResult},
+};
+use uuid::Uuid;
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("db")
+        .about("Generate, scrub and extract development data")
+        .arg(arguments::database())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("seed")
+                .about("Fill the database with a synthetic dataset of publishers, imprints and works")
+                .arg(arguments::publisher_count())
+                .arg(arguments::work_count())
+                .arg(arguments::chapter_count())
+                .arg(arguments::random_seed())
+                .arg(arguments::json_output()),
+        )
+        .subcommand(
+            Command::new("anonymise")
+                .about("Scrub contact emails, Zitadel IDs, storage settings and user IDs from the database")
+                .arg(arguments::json_output()),
+        )
+        .subcommand(
+            Command::new("fixture")
+                .about("Manage test fixtures")
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .subcommand(
+                    Command::new("export")
+                        .about("Dump a work, its related works and everything attached to them as a JSON fixture")
+                        .arg(
+                            arguments::work_id()
+                                .help("Thoth ID of the work to export")
+                                .required(true),
+                        )
+                        .arg(
+                            arguments::output_file()
+                                .help("Path to write the fixture to. Defaults to the standard output"),
+                        ),
+                ),
+        );
+}
+
+pub fn seed(arguments: &ArgMatches, seed_arguments: &ArgMatches) -> ThothResult<()> {
+    let database_url = arguments.get_one::<String>("db").unwrap();
+    let options = SeedOptions {
+        publishers: *seed_arguments.get_one::<usize>("publishers").unwrap(),
+        works_per_publisher: *seed_arguments.get_one::<usize>("works").unwrap(),
+        chapters_per_work: *seed_arguments.get_one::<usize>("chapters").unwrap(),
+        seed: *seed_arguments.get_one::<u64>("seed").unwrap(),
+    };
+    let pool = init_pool(database_url);
+    let summary = seed_database(&pool, &options)?;
+    if seed_arguments.get_flag("json") {
+        return super::print_json(serde_json::to_string_pretty(&summary));
+    }
+    for (table, count) in summary.0 {
+        println!("{table}: {count}");
+    }
+    Ok(())
+}
+
+pub fn anonymise(arguments: &ArgMatches, anonymise_arguments: &ArgMatches) -> ThothResult<()> {
+    let database_url = arguments.get_one::<String>("db").unwrap();
+    let pool = init_pool(database_url);
+    let summary = anonymise_database(&pool)?;
+    if anonymise_arguments.get_flag("json") {
+        return super::print_json(serde_json::to_string_pretty(&summary));
+    }
+    println!(
+        "Anonymised {} contacts, {} publishers, {} imprints, {} file versions, {} history entries and {} history snapshots",
+        summary.contacts,
+        summary.publishers,
+        summary.imprints,
+        summary.file_versions,
+        summary.history_entries,
+        summary.history_snapshots,
+    );
+    Ok(())
+}
+
+pub fn export_fixture_file(
+    arguments: &ArgMatches,
+    export_arguments: &ArgMatches,
+) -> ThothResult<()> {
+    let database_url = arguments.get_one::<String>("db").unwrap();
+    let work_id = export_arguments.get_one::<Uuid>("work").unwrap();
+    let pool = init_pool(database_url);
+    let fixture = serde_json::to_string_pretty(&export_fixture(&pool, *work_id)?)?;
+    match export_arguments.get_one::<PathBuf>("output") {
+        Some(output) => {
+            std::fs::write(output, fixture).map_err(|e| {
+                ThothError::InternalError(format!("Could not write {}: {}", output.display(), e))
+            })?;
+            println!("{}", output.display());
+        }
+        None => println!("{fixture}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::arguments;
+use clap::{ArgGroup, ArgMatches, Command};
+use lazy_static::lazy_static;
+use std::path::PathBuf;
+use std::sync::Arc;
+use thoth::{
+    errors::{ThothError, ThothResult},
+    generate_record, RecordTarget,
+};
+use uuid::Uuid;
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("export")
+        .about("Generate a metadata record locally, without going through the export API")
+        .arg(arguments::gql_endpoint())
+        .arg(arguments::thoth_pat())
+        .arg(arguments::specification())
+        .arg(arguments::work_id())
+        .arg(arguments::publisher_id())
+        .arg(arguments::output_file())
+        .group(
+            ArgGroup::new("target")
+                .args(["work", "publisher"])
+                .required(true),
+        );
+}
+
+pub fn export(arguments: &ArgMatches) -> ThothResult<()> {
+    let thoth_client = Arc::new(super::thoth_client(arguments));
+    let specification_id = arguments.get_one::<String>("specification").unwrap();
+    let target = match (
+        arguments.get_one::<Uuid>("work"),
+        arguments.get_one::<Uuid>("publisher"),
+    ) {
+        (Some(work_id), _) => RecordTarget::Work(*work_id),
+        (_, Some(publisher_id)) => RecordTarget::Publisher(*publisher_id),
+        // the "target" group requires one of them
+        (None, None) => unreachable!(),
+    };
+
+    // run a separate tokio runtime to avoid interfering with actix's threads
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()?;
+    let record = runtime.block_on(generate_record(thoth_client, specification_id, target))?;
+
+    let output = arguments
+        .get_one::<PathBuf>("output")
+        .cloned()
+        .unwrap_or_else(|| PathBuf::from(&record.file_name));
+    std::fs::write(&output, record.content).map_err(|e| {
+        ThothError::InternalError(format!("Could not write {}: {}", output.display(), e))
+    })?;
+    println!("{}", output.display());
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::arguments;
+use clap::{ArgMatches, Command};
+use lazy_static::lazy_static;
+use std::path::PathBuf;
+use thoth::{
+    api::{
+        db::init_pool,
+        model::institution::funder_registry::{parse_rdf_json, RegisteredFunder},
+    },
+    errors::{ThothError, ThothResult},
+};
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("funder-registry")
+        .about("Manage the local snapshot of the Crossref Funder Registry")
+        .arg(arguments::database())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("load")
+                .about(
+                    "Replace the local snapshot with a Funder Registry dump serialised as RDF/JSON"
+                )
+                .arg(arguments::snapshot_file()),
+        );
+}
+
+pub fn load(arguments: &ArgMatches, load_arguments: &ArgMatches) -> ThothResult<()> {
+    let database_url = arguments.get_one::<String>("db").unwrap();
+    let path = load_arguments.get_one::<PathBuf>("file").unwrap();
+
+    let input = std::fs::read_to_string(path).map_err(|e| {
+        ThothError::FunderRegistrySnapshotError(format!("{}: {}", path.display(), e))
+    })?;
+    let funders = parse_rdf_json(&input)?;
+    let replaced = funders
+        .iter()
+        .filter(|funder| funder.replaced_by.is_some())
+        .count();
+
+    let pool = init_pool(database_url);
+    let loaded = RegisteredFunder::load_snapshot(&pool, &funders)?;
+    println!(
+        "Loaded {} funders ({} replaced) from {}",
+        loaded,
+        replaced,
+        path.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::arguments;
+use clap::{ArgMatches, Command};
+use lazy_static::lazy_static;
+use std::path::PathBuf;
+use thoth::{
+    errors::{ThothError, ThothResult},
+    read_works,
+};
+use uuid::Uuid;
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("import")
+        .about("Create works from records in the csv::thoth or json::thoth layout")
+        .arg(arguments::gql_endpoint())
+        .arg(arguments::thoth_pat())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("csv")
+                .about("Create a work from each row of a csv::thoth record")
+                .arg(arguments::import_file())
+                .arg(arguments::imprint_id()),
+        )
+        .subcommand(
+            Command::new("json")
+                .about("Create the work of a json::thoth record")
+                .arg(arguments::import_file())
+                .arg(arguments::imprint_id()),
+        );
+}
+
+pub fn csv(arguments: &ArgMatches, csv_arguments: &ArgMatches) -> ThothResult<()> {
+    import(arguments, csv_arguments, "csv::thoth")
+}
+
+pub fn json(arguments: &ArgMatches, json_arguments: &ArgMatches) -> ThothResult<()> {
+    import(arguments, json_arguments, "json::thoth")
+}
+
+fn import(
+    arguments: &ArgMatches,
+    import_arguments: &ArgMatches,
+    specification_id: &str,
+) -> ThothResult<()> {
+    if arguments.get_one::<String>("thoth-pat").is_none() {
+        return Err(ThothError::InternalError(
+            "A Personal Access Token (--thoth-pat) is required to create works".to_string(),
+        ));
+    }
+    let thoth_client = super::thoth_client(arguments);
+    let file = import_arguments.get_one::<PathBuf>("file").unwrap();
+    let imprint_id = *import_arguments.get_one::<Uuid>("imprint").unwrap();
+
+    let input = std::fs::read_to_string(file).map_err(|e| {
+        ThothError::InternalError(format!("Could not read {}: {}", file.display(), e))
+    })?;
+    // parse the whole file before creating anything, so that a malformed record is not
+    // partially imported
+    let works = read_works(specification_id, &input)?;
+
+    // run a separate tokio runtime to avoid interfering with actix's threads
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()?;
+    runtime.block_on(async {
+        for work in works {
+            let work = work.create(&thoth_client, imprint_id).await?;
+            println!("{}", work.work_id);
+        }
+        Ok::<(), ThothError>(())
+    })
+}
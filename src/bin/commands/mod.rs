@@ -6,16 +6,25 @@ use thoth::{
         db::{revert_migrations as revert_db_migrations, run_migrations as run_db_migrations},
         redis::{init_pool as init_redis_pool, RedisPool},
     },
+    client::ThothClient,
     errors::ThothResult,
 };
 
 pub(super) mod cache;
+pub(super) mod config;
+pub(super) mod db;
+pub(super) mod export;
+pub(super) mod funder_registry;
+pub(super) mod import;
 pub(super) mod start;
+pub(super) mod storage;
+pub(super) mod work;
 pub(super) mod zitadel;
 
 lazy_static! {
     pub(super) static ref INIT: Command = Command::new("init")
         .about("Run the database migrations and start the thoth API server")
+        .arg(arguments::config_file())
         .arg(arguments::database())
         .arg(arguments::host("GRAPHQL_API_HOST"))
         .arg(arguments::port("8000", "GRAPHQL_API_PORT"))
@@ -26,7 +35,10 @@ lazy_static! {
         .arg(arguments::zitadel_url())
         .arg(arguments::aws_access_key_id())
         .arg(arguments::aws_secret_access_key())
-        .arg(arguments::aws_region());
+        .arg(arguments::aws_region())
+        .arg(arguments::cors_origins("GRAPHQL_API_CORS_ORIGINS"))
+        .arg(arguments::log_format())
+        .arg(arguments::otlp_endpoint());
 }
 
 lazy_static! {
@@ -41,6 +53,22 @@ fn get_redis_pool(arguments: &clap::ArgMatches) -> RedisPool {
     init_redis_pool(redis_url)
 }
 
+/// Client for the GraphQL API, authenticated with the PAT if one was given
+fn thoth_client(arguments: &clap::ArgMatches) -> ThothClient {
+    let gql_endpoint = arguments.get_one::<String>("gql-endpoint").unwrap();
+    let thoth_client = ThothClient::new(gql_endpoint.clone());
+    match arguments.get_one::<String>("thoth-pat") {
+        Some(pat) => thoth_client.with_token(pat.clone()),
+        None => thoth_client,
+    }
+}
+
+/// Print a serialised JSON report to standard output
+fn print_json(json: serde_json::Result<String>) -> ThothResult<()> {
+    println!("{}", json?);
+    Ok(())
+}
+
 pub(super) fn migrate(arguments: &clap::ArgMatches) -> ThothResult<()> {
     match arguments.get_flag("revert") {
         true => revert_migrations(arguments),
@@ -48,6 +76,13 @@ pub(super) fn migrate(arguments: &clap::ArgMatches) -> ThothResult<()> {
     }
 }
 
+/// Run the database migrations and start the GraphQL API server, once its settings are valid
+pub(super) fn init(arguments: &clap::ArgMatches) -> ThothResult<()> {
+    let settings = start::graphql_api_settings(arguments)?;
+    run_db_migrations(&settings.database_url)?;
+    start::start_graphql_api(settings)
+}
+
 pub(super) fn run_migrations(arguments: &clap::ArgMatches) -> ThothResult<()> {
     let database_url = arguments.get_one::<String>("db").unwrap();
     run_db_migrations(database_url)
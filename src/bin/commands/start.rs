@@ -1,7 +1,14 @@
 use crate::arguments;
-use clap::{ArgMatches, Command};
+use clap::{parser::ValueSource, ArgMatches, Command};
 use lazy_static::lazy_static;
-use thoth::{api_server, errors::ThothResult, export_server};
+use std::path::PathBuf;
+use thoth::{
+    api::telemetry::{LogFormat, TelemetryConfig},
+    api_server,
+    config::{ConfigFile, ExportApiSettings, GraphqlApiSettings},
+    errors::ThothResult,
+    export_server,
+};
 
 lazy_static! {
     pub(crate) static ref COMMAND: Command = Command::new("start")
@@ -11,6 +18,7 @@ lazy_static! {
         .subcommand(
             Command::new("graphql-api")
                 .about("Start the thoth GraphQL API server")
+                .arg(arguments::config_file())
                 .arg(arguments::database())
                 .arg(arguments::host("GRAPHQL_API_HOST"))
                 .arg(arguments::port("8000", "GRAPHQL_API_PORT"))
@@ -21,80 +29,164 @@ lazy_static! {
                 .arg(arguments::zitadel_url())
                 .arg(arguments::aws_access_key_id())
                 .arg(arguments::aws_secret_access_key())
-                .arg(arguments::aws_region()),
+                .arg(arguments::aws_region())
+                .arg(arguments::cors_origins("GRAPHQL_API_CORS_ORIGINS"))
+                .arg(arguments::log_format())
+                .arg(arguments::otlp_endpoint()),
         )
         .subcommand(
             Command::new("export-api")
                 .about("Start the thoth metadata export API")
+                .arg(arguments::config_file())
                 .arg(arguments::redis())
                 .arg(arguments::host("EXPORT_API_HOST"))
                 .arg(arguments::port("8181", "EXPORT_API_PORT"))
                 .arg(arguments::threads("EXPORT_API_THREADS"))
                 .arg(arguments::keep_alive("EXPORT_API_KEEP_ALIVE"))
                 .arg(arguments::export_url())
-                .arg(arguments::gql_endpoint()),
+                .arg(arguments::gql_endpoint())
+                .arg(arguments::cors_origins("EXPORT_API_CORS_ORIGINS"))
+                .arg(arguments::log_format())
+                .arg(arguments::otlp_endpoint()),
         );
 }
 
+fn config_file(arguments: &ArgMatches) -> ThothResult<ConfigFile> {
+    match arguments.get_one::<PathBuf>("config") {
+        Some(path) => ConfigFile::load(path),
+        None => Ok(ConfigFile::default()),
+    }
+}
+
+/// Resolve a setting from, in increasing order of precedence, the default value of its argument,
+/// the configuration file, the environment, and the command line
+fn layered<T: Clone + Send + Sync + 'static>(
+    arguments: &ArgMatches,
+    id: &str,
+    from_file: Option<T>,
+) -> Option<T> {
+    match arguments.value_source(id) {
+        Some(ValueSource::EnvVariable | ValueSource::CommandLine) => {
+            arguments.get_one::<T>(id).cloned()
+        }
+        _ => from_file.or_else(|| arguments.get_one::<T>(id).cloned()),
+    }
+}
+
+fn layered_string(arguments: &ArgMatches, id: &str, from_file: Option<String>) -> String {
+    layered(arguments, id, from_file).unwrap_or_default()
+}
+
+fn layered_list(arguments: &ArgMatches, id: &str, from_file: Option<Vec<String>>) -> Vec<String> {
+    let from_arguments = || {
+        arguments
+            .get_many::<String>(id)
+            .map(|values| values.filter(|value| !value.is_empty()).cloned().collect())
+    };
+    match arguments.value_source(id) {
+        Some(ValueSource::EnvVariable | ValueSource::CommandLine) => from_arguments(),
+        _ => from_file.or_else(from_arguments),
+    }
+    .unwrap_or_default()
+}
+
+fn telemetry_config(arguments: &ArgMatches, config: &ConfigFile) -> ThothResult<TelemetryConfig> {
+    Ok(TelemetryConfig {
+        log_format: layered::<LogFormat>(arguments, "log-format", config.log_format()?)
+            .unwrap_or_default(),
+        otlp_endpoint: layered(
+            arguments,
+            "otlp-endpoint",
+            config.logging.otlp_endpoint.clone(),
+        ),
+    })
+}
+
+/// Resolve and validate the settings of the GraphQL API server
+pub(crate) fn graphql_api_settings(arguments: &ArgMatches) -> ThothResult<GraphqlApiSettings> {
+    let config = config_file(arguments)?;
+    let server = &config.graphql_api;
+    let settings = GraphqlApiSettings {
+        database_url: layered_string(arguments, "db", config.database.url.clone()),
+        host: layered_string(arguments, "host", server.host.clone()),
+        port: layered_string(arguments, "port", server.port.map(|p| p.to_string())),
+        threads: layered(arguments, "threads", server.threads).unwrap_or_default(),
+        keep_alive: layered(arguments, "keep-alive", server.keep_alive).unwrap_or_default(),
+        public_url: layered_string(arguments, "gql-url", server.public_url.clone()),
+        private_key: layered_string(arguments, "key", config.zitadel.private_key.clone()),
+        zitadel_url: layered_string(arguments, "zitadel-url", config.zitadel.url.clone()),
+        aws_access_key_id: layered_string(
+            arguments,
+            "aws-access-key-id",
+            config.aws.access_key_id.clone(),
+        ),
+        aws_secret_access_key: layered_string(
+            arguments,
+            "aws-secret-access-key",
+            config.aws.secret_access_key.clone(),
+        ),
+        aws_region: layered_string(arguments, "aws-region", config.aws.region.clone()),
+        cors_origins: layered_list(arguments, "cors-origin", server.cors_origins.clone()),
+        telemetry: telemetry_config(arguments, &config)?,
+    };
+    settings.validate()?;
+    Ok(settings)
+}
+
+/// Resolve and validate the settings of the export API server
+pub(crate) fn export_api_settings(arguments: &ArgMatches) -> ThothResult<ExportApiSettings> {
+    let config = config_file(arguments)?;
+    let server = &config.export_api;
+    let settings = ExportApiSettings {
+        redis_url: layered_string(arguments, "redis", config.redis.url.clone()),
+        host: layered_string(arguments, "host", server.host.clone()),
+        port: layered_string(arguments, "port", server.port.map(|p| p.to_string())),
+        threads: layered(arguments, "threads", server.threads).unwrap_or_default(),
+        keep_alive: layered(arguments, "keep-alive", server.keep_alive).unwrap_or_default(),
+        public_url: layered_string(arguments, "export-url", server.public_url.clone()),
+        gql_endpoint: layered_string(arguments, "gql-endpoint", server.graphql_endpoint.clone()),
+        cors_origins: layered_list(arguments, "cors-origin", server.cors_origins.clone()),
+        telemetry: telemetry_config(arguments, &config)?,
+    };
+    settings.validate()?;
+    Ok(settings)
+}
+
 pub fn graphql_api(arguments: &ArgMatches) -> ThothResult<()> {
-    let database_url = arguments.get_one::<String>("db").unwrap().to_owned();
-    let host = arguments.get_one::<String>("host").unwrap().to_owned();
-    let port = arguments.get_one::<String>("port").unwrap().to_owned();
-    let threads = *arguments.get_one::<usize>("threads").unwrap();
-    let keep_alive = *arguments.get_one::<u64>("keep-alive").unwrap();
-    let url = arguments.get_one::<String>("gql-url").unwrap().to_owned();
-    let private_key = arguments.get_one::<String>("key").unwrap().to_owned();
-    let zitadel_url = arguments
-        .get_one::<String>("zitadel-url")
-        .unwrap()
-        .to_owned();
+    start_graphql_api(graphql_api_settings(arguments)?)
+}
 
+pub(crate) fn start_graphql_api(settings: GraphqlApiSettings) -> ThothResult<()> {
     api_server(
-        database_url,
-        host,
-        port,
-        threads,
-        keep_alive,
-        url,
-        private_key,
-        zitadel_url,
-        arguments
-            .get_one::<String>("aws-access-key-id")
-            .unwrap()
-            .to_owned(),
-        arguments
-            .get_one::<String>("aws-secret-access-key")
-            .unwrap()
-            .to_owned(),
-        arguments
-            .get_one::<String>("aws-region")
-            .unwrap()
-            .to_owned(),
+        settings.database_url,
+        settings.host,
+        settings.port,
+        settings.threads,
+        settings.keep_alive,
+        settings.public_url,
+        settings.private_key,
+        settings.zitadel_url,
+        settings.aws_access_key_id,
+        settings.aws_secret_access_key,
+        settings.aws_region,
+        settings.cors_origins,
+        settings.telemetry,
     )
     .map_err(|e| e.into())
 }
+
 pub fn export_api(arguments: &ArgMatches) -> ThothResult<()> {
-    let redis_url = arguments.get_one::<String>("redis").unwrap().to_owned();
-    let host = arguments.get_one::<String>("host").unwrap().to_owned();
-    let port = arguments.get_one::<String>("port").unwrap().to_owned();
-    let threads = *arguments.get_one::<usize>("threads").unwrap();
-    let keep_alive = *arguments.get_one::<u64>("keep-alive").unwrap();
-    let url = arguments
-        .get_one::<String>("export-url")
-        .unwrap()
-        .to_owned();
-    let gql_endpoint = arguments
-        .get_one::<String>("gql-endpoint")
-        .unwrap()
-        .to_owned();
+    let settings = export_api_settings(arguments)?;
     export_server(
-        redis_url,
-        host,
-        port,
-        threads,
-        keep_alive,
-        url,
-        gql_endpoint,
+        settings.redis_url,
+        settings.host,
+        settings.port,
+        settings.threads,
+        settings.keep_alive,
+        settings.public_url,
+        settings.gql_endpoint,
+        settings.cors_origins,
+        settings.telemetry,
     )
     .map_err(|e| e.into())
 }
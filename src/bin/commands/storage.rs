@@ -0,0 +1,107 @@
+use crate::arguments;
+use clap::{ArgMatches, Command};
+use lazy_static::lazy_static;
+use thoth::{
+    api::{
+        db::init_pool,
+        storage::{audit_imprint_files, create_s3_client, imprints_to_audit},
+    },
+    errors::{ThothError, ThothResult},
+};
+use uuid::Uuid;
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("storage")
+        .about("Manage hosted files")
+        .arg(arguments::database())
+        .arg(arguments::aws_access_key_id())
+        .arg(arguments::aws_secret_access_key())
+        .arg(arguments::aws_region())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("audit")
+                .about("Compare stored files against their S3 objects and record the results")
+                .arg(arguments::imprint_ids())
+                .arg(arguments::failures_only())
+                .arg(arguments::verify_checksums()),
+        );
+}
+
+pub fn audit(arguments: &ArgMatches, audit_arguments: &ArgMatches) -> ThothResult<()> {
+    let database_url = arguments.get_one::<String>("db").unwrap();
+    let access_key_id = arguments.get_one::<String>("aws-access-key-id").unwrap();
+    let secret_access_key = arguments
+        .get_one::<String>("aws-secret-access-key")
+        .unwrap();
+    let region = arguments.get_one::<String>("aws-region").unwrap();
+    let imprint_ids: Vec<Uuid> = audit_arguments
+        .get_many::<Uuid>("imprint")
+        .map(|ids| ids.copied().collect())
+        .unwrap_or_default();
+    let failures_only = audit_arguments.get_flag("failures-only");
+    let verify_checksums = audit_arguments.get_flag("verify-checksums");
+
+    let pool = init_pool(database_url);
+    let imprints = imprints_to_audit(&pool, &imprint_ids)?;
+
+    // run a separate tokio runtime to avoid interfering with actix's threads
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()?;
+    let files_failed = runtime.block_on(async {
+        let s3_client = create_s3_client(access_key_id, secret_access_key, region).await;
+        let mut files_failed = 0;
+        for imprint in imprints {
+            let summary =
+                audit_imprint_files(&pool, &s3_client, &imprint, failures_only, verify_checksums)
+                    .await?;
+            println!(
+                "{} ({}): {} checked, {} failed [run {}]",
+                imprint.imprint_name,
+                imprint.imprint_id,
+                summary.files_checked,
+                summary.files_failed,
+                summary.audit_run_id,
+            );
+            for audit in summary.audits.iter().filter(|audit| !audit.passed()) {
+                let mut problems = vec![];
+                if audit.object_missing {
+                    problems.push("missing".to_string());
+                }
+                if audit.size_mismatch {
+                    problems.push(format!(
+                        "size {} != {}",
+                        audit.actual_bytes.unwrap_or_default(),
+                        audit.expected_bytes
+                    ));
+                }
+                if audit.checksum_mismatch {
+                    problems.push("checksum".to_string());
+                }
+                if audit.mime_type_mismatch {
+                    problems.push(format!(
+                        "mime {} != {}",
+                        audit.actual_mime_type.as_deref().unwrap_or_default(),
+                        audit.expected_mime_type
+                    ));
+                }
+                if let Some(error) = &audit.error_message {
+                    problems.push(format!("error: {error}"));
+                }
+                println!("  ✗ {}: {}", audit.object_key, problems.join(", "));
+            }
+            files_failed += summary.files_failed;
+        }
+        Ok::<usize, ThothError>(files_failed)
+    })?;
+
+    if files_failed > 0 {
+        Err(ThothError::InternalError(format!(
+            "Storage audit found {files_failed} file(s) with problems"
+        )))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use crate::arguments;
+use clap::{ArgMatches, Command};
+use lazy_static::lazy_static;
+use std::str::FromStr;
+use thoth::{
+    api::model::{work::Work, Doi},
+    client::QueryParameters,
+    errors::{ThothError, ThothResult},
+};
+use uuid::Uuid;
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("work")
+        .about("Query works")
+        .arg(arguments::gql_endpoint())
+        .arg(arguments::thoth_pat())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("get")
+                .about("Print a work, with all its related records, as JSON")
+                .arg(arguments::work_identifier()),
+        );
+}
+
+pub fn get(arguments: &ArgMatches, get_arguments: &ArgMatches) -> ThothResult<()> {
+    let thoth_client = super::thoth_client(arguments);
+    let identifier = get_arguments.get_one::<String>("work").unwrap();
+
+    // run a separate tokio runtime to avoid interfering with actix's threads
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()?;
+    let work = runtime.block_on(async {
+        let work_id = match Uuid::parse_str(identifier) {
+            Ok(work_id) => work_id,
+            Err(_) => {
+                let doi = Doi::from_str(identifier)?;
+                thoth_client.get_work_by_doi::<Work>(&doi).await?.work_id
+            }
+        };
+        thoth_client
+            .get_work(work_id, QueryParameters::new().with_all())
+            .await
+    })?;
+
+    let json = serde_json::to_string_pretty(&work)
+        .map_err(|e| ThothError::InternalError(e.to_string()))?;
+    println!("{json}");
+    Ok(())
+}
@@ -12,7 +12,14 @@ lazy_static::lazy_static! {
         .subcommand(commands::start::COMMAND.clone())
         .subcommand(commands::INIT.clone())
         .subcommand(commands::cache::COMMAND.clone())
-        .subcommand(commands::zitadel::COMMAND.clone());
+        .subcommand(commands::funder_registry::COMMAND.clone())
+        .subcommand(commands::storage::COMMAND.clone())
+        .subcommand(commands::zitadel::COMMAND.clone())
+        .subcommand(commands::work::COMMAND.clone())
+        .subcommand(commands::export::COMMAND.clone())
+        .subcommand(commands::import::COMMAND.clone())
+        .subcommand(commands::db::COMMAND.clone())
+        .subcommand(commands::config::COMMAND.clone());
 }
 
 fn main() -> thoth::errors::ThothResult<()> {
@@ -26,18 +33,59 @@ fn main() -> thoth::errors::ThothResult<()> {
             _ => unreachable!(),
         },
         Some(("migrate", arguments)) => commands::migrate(arguments),
-        Some(("init", arguments)) => {
-            commands::run_migrations(arguments)?;
-            commands::start::graphql_api(arguments)
-        }
+        Some(("init", arguments)) => commands::init(arguments),
         Some(("cache", arguments)) => match arguments.subcommand() {
-            Some(("delete", _)) => commands::cache::delete(arguments),
+            Some(("list", list_arguments)) => commands::cache::list(arguments, list_arguments),
+            Some(("stats", stats_arguments)) => commands::cache::stats(arguments, stats_arguments),
+            Some(("delete", delete_arguments)) => {
+                commands::cache::delete(arguments, delete_arguments)
+            }
+            Some(("warm", warm_arguments)) => commands::cache::warm(arguments, warm_arguments),
+            _ => unreachable!(),
+        },
+        Some(("funder-registry", arguments)) => match arguments.subcommand() {
+            Some(("load", load_arguments)) => {
+                commands::funder_registry::load(arguments, load_arguments)
+            }
+            _ => unreachable!(),
+        },
+        Some(("storage", arguments)) => match arguments.subcommand() {
+            Some(("audit", audit_arguments)) => {
+                commands::storage::audit(arguments, audit_arguments)
+            }
             _ => unreachable!(),
         },
         Some(("zitadel", arguments)) => match arguments.subcommand() {
             Some(("setup", _)) => commands::zitadel::setup(arguments),
             _ => unreachable!(),
         },
+        Some(("work", arguments)) => match arguments.subcommand() {
+            Some(("get", get_arguments)) => commands::work::get(arguments, get_arguments),
+            _ => unreachable!(),
+        },
+        Some(("export", arguments)) => commands::export::export(arguments),
+        Some(("import", arguments)) => match arguments.subcommand() {
+            Some(("csv", csv_arguments)) => commands::import::csv(arguments, csv_arguments),
+            Some(("json", json_arguments)) => commands::import::json(arguments, json_arguments),
+            _ => unreachable!(),
+        },
+        Some(("db", arguments)) => match arguments.subcommand() {
+            Some(("seed", seed_arguments)) => commands::db::seed(arguments, seed_arguments),
+            Some(("anonymise", anonymise_arguments)) => {
+                commands::db::anonymise(arguments, anonymise_arguments)
+            }
+            Some(("fixture", fixture_arguments)) => match fixture_arguments.subcommand() {
+                Some(("export", export_arguments)) => {
+                    commands::db::export_fixture_file(arguments, export_arguments)
+                }
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        },
+        Some(("config", arguments)) => match arguments.subcommand() {
+            Some(("check", check_arguments)) => commands::config::check(check_arguments),
+            _ => unreachable!(),
+        },
         _ => unreachable!(),
     }
 }
@@ -1,4 +1,11 @@
+pub mod config;
+
 pub use thoth_api as api;
 pub use thoth_api_server::start_server as api_server;
+pub use thoth_client as client;
 pub use thoth_errors as errors;
-pub use thoth_export_server::{start_server as export_server, ALL_SPECIFICATIONS};
+pub use thoth_export_server::{
+    cache_stats, delete_cached_records, generate_record, list_cached_records, publisher_record_ids,
+    read_works, start_server as export_server, warm_cache, CacheFilter, CacheStats, CachedRecord,
+    GeneratedRecord, RecordTarget, WarmedRecord, ALL_SPECIFICATIONS,
+};
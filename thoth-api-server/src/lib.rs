@@ -1,5 +1,6 @@
 mod graphiql;
 mod logger;
+mod price_schedule;
 
 use std::{io, sync::Arc, time::Duration};
 
@@ -9,23 +10,28 @@ use actix_web::{
     http::header,
     middleware::Compress,
     post,
-    web::{Data, Json},
-    App, Error, HttpResponse, HttpServer, Result,
+    web::{self, Data, Json},
+    App, Error, HttpRequest, HttpResponse, HttpServer, Result,
 };
 use base64::{engine::general_purpose, Engine as _};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use thoth_api::{
     db::{init_pool, PgPool},
     graphql::{create_schema, Context, GraphQLRequest, Schema},
+    health::{check_database, live, Readiness},
+    metrics::{metrics, record_graphql_errors, record_pool_state, RequestMetrics},
     storage::{create_cloudfront_client, create_s3_client, CloudFrontClient, S3Client},
+    telemetry::{init_telemetry, RequestTracing, TelemetryConfig, REQUEST_ID_HEADER},
 };
+use thoth_errors::{Locale, ThothError};
 use zitadel::{
     actix::introspection::{IntrospectedUser, IntrospectionConfigBuilder},
     credentials::Application,
 };
 
 use crate::graphiql::graphiql_source;
-use crate::logger::{BodyLogger, Logger};
+use crate::logger::BodyLogger;
+use crate::price_schedule::spawn_price_schedule;
 
 #[derive(Serialize)]
 struct ApiConfig {
@@ -84,14 +90,66 @@ async fn graphql_schema(st: Data<Arc<Schema>>) -> HttpResponse {
     HttpResponse::Ok().body(st.as_sdl())
 }
 
+/// A GraphQL request, along with the optional `extensions` object of the request body
+#[derive(Deserialize)]
+struct LocalisedGraphQLRequest {
+    #[serde(flatten)]
+    request: GraphQLRequest,
+    #[serde(default)]
+    extensions: RequestExtensions,
+}
+
+#[derive(Default, Deserialize)]
+struct RequestExtensions {
+    locale: Option<String>,
+}
+
+/// Locale in which to return error messages: the `locale` request extension takes precedence
+/// over the `Accept-Language` header, and English is used when neither names a supported locale
+fn request_locale(req: &HttpRequest, extensions: &RequestExtensions) -> Locale {
+    extensions
+        .locale
+        .as_deref()
+        .and_then(Locale::from_tag)
+        .or_else(|| {
+            req.headers()
+                .get(header::ACCEPT_LANGUAGE)
+                .and_then(|value| value.to_str().ok())
+                .and_then(Locale::from_accept_language)
+        })
+        .unwrap_or_default()
+}
+
+#[get("/health/live")]
+async fn health_live() -> HttpResponse {
+    live().await
+}
+
+#[get("/health/ready")]
+async fn health_ready(pool: Data<PgPool>) -> HttpResponse {
+    let database = web::block(move || check_database(&pool))
+        .await
+        .unwrap_or_else(|e| Err(ThothError::InternalError(e.to_string())));
+    Readiness::default()
+        .check("database", database)
+        .into_response()
+}
+
+#[get("/metrics")]
+async fn prometheus_metrics(pool: Data<PgPool>) -> HttpResponse {
+    record_pool_state(&pool);
+    metrics().await
+}
+
 #[post("/graphql")]
 async fn graphql(
+    req: HttpRequest,
     st: Data<Arc<Schema>>,
     pool: Data<PgPool>,
     s3_client: Data<S3Client>,
     cloudfront_client: Data<CloudFrontClient>,
     user: Option<IntrospectedUser>,
-    data: Json<GraphQLRequest>,
+    data: Json<LocalisedGraphQLRequest>,
 ) -> Result<HttpResponse, Error> {
     let ctx = Context::new(
         pool.into_inner(),
@@ -99,10 +157,37 @@ async fn graphql(
         s3_client.into_inner(),
         cloudfront_client.into_inner(),
     );
-    let result = data.execute(&st, &ctx).await;
-    match result.is_ok() {
-        true => Ok(HttpResponse::Ok().json(result)),
-        false => Ok(HttpResponse::BadRequest().json(result)),
+    let locale = request_locale(&req, &data.extensions);
+    let result = locale.scope(data.request.execute(&st, &ctx)).await;
+    let mut response = match result.is_ok() {
+        true => HttpResponse::Ok(),
+        false => HttpResponse::BadRequest(),
+    };
+    let body = serde_json::to_value(&result)?;
+    record_graphql_errors(&body);
+    Ok(response
+        .insert_header((header::CONTENT_LANGUAGE, locale.as_str()))
+        .json(body))
+}
+
+/// Allow cross-origin requests from the given origins, or from any origin if none are given
+fn cors(origins: &[String]) -> Cors {
+    let cors = Cors::default()
+        .allowed_methods(vec!["GET", "POST", "OPTIONS"])
+        .allowed_headers(vec![
+            header::AUTHORIZATION,
+            header::ACCEPT,
+            header::ACCEPT_LANGUAGE,
+        ])
+        .allowed_header(header::CONTENT_TYPE)
+        .allowed_header(REQUEST_ID_HEADER)
+        .expose_headers(vec![REQUEST_ID_HEADER])
+        .supports_credentials();
+    match origins.is_empty() {
+        true => cors.allow_any_origin(),
+        false => origins
+            .iter()
+            .fold(cors, |cors, origin| cors.allowed_origin(origin)),
     }
 }
 
@@ -120,8 +205,10 @@ pub async fn start_server(
     aws_access_key_id: String,
     aws_secret_access_key: String,
     aws_region: String,
+    cors_origins: Vec<String>,
+    telemetry: TelemetryConfig,
 ) -> io::Result<()> {
-    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
+    let _telemetry = init_telemetry("thoth-graphql-api", &telemetry).map_err(io::Error::other)?;
 
     let decoded_private_key = general_purpose::STANDARD
         .decode(&private_key)
@@ -138,20 +225,15 @@ pub async fn start_server(
     let cloudfront_client =
         create_cloudfront_client(&aws_access_key_id, &aws_secret_access_key, &aws_region).await;
     let pool = Data::new(init_pool(&database_url));
+    spawn_price_schedule(pool.clone());
 
     HttpServer::new(move || {
         App::new()
             .wrap(Compress::default())
-            .wrap(Logger::default())
+            .wrap(RequestMetrics)
             .wrap(BodyLogger)
-            .wrap(
-                Cors::default()
-                    .allowed_methods(vec!["GET", "POST", "OPTIONS"])
-                    .allow_any_origin()
-                    .allowed_headers(vec![header::AUTHORIZATION, header::ACCEPT])
-                    .allowed_header(header::CONTENT_TYPE)
-                    .supports_credentials(),
-            )
+            .wrap(cors(&cors_origins))
+            .wrap(RequestTracing)
             .app_data(auth.clone())
             .app_data(Data::new(ApiConfig::new(public_url.clone())))
             .app_data(pool.clone())
@@ -163,6 +245,9 @@ pub async fn start_server(
             .service(graphql)
             .service(graphiql_interface)
             .service(graphql_schema)
+            .service(health_live)
+            .service(health_ready)
+            .service(prometheus_metrics)
     })
     .workers(threads)
     .keep_alive(Duration::from_secs(keep_alive))
@@ -6,32 +6,19 @@ use std::{
 use actix_http::h1;
 use actix_web::{
     dev::{self, Payload, Service, ServiceRequest, ServiceResponse, Transform},
-    middleware, web, Error, HttpMessage,
+    web, Error,
 };
 use futures_util::future::LocalBoxFuture;
 
-const LOG_FORMAT: &str = r#"%{r}a %a "%r" %s %b "%{Referer}i" "%{User-Agent}i" %T %{QUERY}xi"#;
-pub(crate) struct Logger;
 pub(crate) struct BodyLogger;
 
-impl Logger {
-    pub(crate) fn default() -> middleware::Logger {
-        middleware::Logger::new(LOG_FORMAT).custom_request_replace("QUERY", Self::get_request_body)
-    }
-
+impl BodyLogger {
     fn format_request_body(body: &web::Bytes) -> String {
         // Pretty print request body when logging level is Debug
-        if log::log_enabled!(log::Level::Debug) {
-            return format!("\n{}", String::from_utf8_lossy(body).replace("\\n", "\n"));
-        }
-        format!("\n{}", String::from_utf8_lossy(body))
-    }
-
-    fn get_request_body(req: &ServiceRequest) -> String {
-        if let Some(body) = req.extensions().get::<web::Bytes>() {
-            return Self::format_request_body(body);
+        if tracing::enabled!(tracing::Level::DEBUG) {
+            return String::from_utf8_lossy(body).replace("\\n", "\n");
         }
-        "".to_string()
+        String::from_utf8_lossy(body).to_string()
     }
 }
 
@@ -72,11 +59,14 @@ where
         let svc = self.service.clone();
 
         Box::pin(async move {
-            // store request body in the request extensions container for retrieval by logger later
-            // only store GraphQL queries to avoid logging credentials
+            // log the request body within the span of the request, so that it shares its ID
+            // only log GraphQL queries to avoid logging credentials
             if req.path().eq("/graphql") {
                 let body = req.extract::<web::Bytes>().await.unwrap();
-                req.extensions_mut().insert(body.clone());
+                tracing::info!(
+                    query = %BodyLogger::format_request_body(&body),
+                    "GraphQL request"
+                );
                 req.set_payload(bytes_to_payload(body));
             }
 
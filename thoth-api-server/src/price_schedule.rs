@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use actix_web::{rt, web::Data};
+use chrono::{NaiveDate, Utc};
+use log::{error, info};
+use thoth_api::{db::PgPool, model::price::Price};
+
+/// How often to check whether the date has changed
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Mark works whose prices have started or stopped applying since they were last updated,
+/// so that cached exports are regenerated with the prices now in effect. Runs once per day;
+/// changes dated on days it did not run are caught up on the next run.
+pub(crate) fn spawn_price_schedule(pool: Data<PgPool>) {
+    rt::spawn(async move {
+        let mut interval = rt::time::interval(CHECK_INTERVAL);
+        let mut last_run: Option<NaiveDate> = None;
+        loop {
+            interval.tick().await;
+            let today = Utc::now().date_naive();
+            if last_run == Some(today) {
+                continue;
+            }
+            let pool = pool.clone();
+            match actix_web::web::block(move || Price::touch_scheduled_changes(&pool, today)).await
+            {
+                Ok(Ok(count)) => {
+                    info!("Applied scheduled price changes up to {today}: {count} work(s) updated");
+                    last_run = Some(today);
+                }
+                Ok(Err(e)) => error!("Failed to apply scheduled price changes: {e}"),
+                Err(e) => error!("Failed to apply scheduled price changes: {e}"),
+            }
+        }
+    });
+}
@@ -0,0 +1,23 @@
+CREATE TABLE file_audit (
+    file_audit_id       UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    audit_run_id        UUID NOT NULL,
+    file_id             UUID NOT NULL REFERENCES file(file_id) ON DELETE CASCADE,
+    imprint_id          UUID NOT NULL REFERENCES imprint(imprint_id) ON DELETE CASCADE,
+    object_key          TEXT NOT NULL,
+    expected_bytes      BIGINT NOT NULL,
+    actual_bytes        BIGINT,
+    expected_sha256     TEXT NOT NULL,
+    actual_sha256       TEXT,
+    expected_mime_type  TEXT NOT NULL,
+    actual_mime_type    TEXT,
+    object_missing      BOOLEAN NOT NULL DEFAULT FALSE,
+    size_mismatch       BOOLEAN NOT NULL DEFAULT FALSE,
+    checksum_mismatch   BOOLEAN NOT NULL DEFAULT FALSE,
+    mime_type_mismatch  BOOLEAN NOT NULL DEFAULT FALSE,
+    error_message       TEXT CHECK (octet_length(error_message) >= 1),
+    checked_at          TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+CREATE INDEX file_audit_file_checked_at_idx ON file_audit (file_id, checked_at DESC);
+CREATE INDEX file_audit_imprint_idx ON file_audit (imprint_id);
+CREATE INDEX file_audit_run_idx ON file_audit (audit_run_id);
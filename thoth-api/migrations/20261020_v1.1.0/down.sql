@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS file_version;
+
+ALTER TABLE work
+    DROP COLUMN IF EXISTS expose_latest_file_url;
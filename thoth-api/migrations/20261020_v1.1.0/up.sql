@@ -0,0 +1,20 @@
+ALTER TABLE work
+    ADD COLUMN expose_latest_file_url BOOLEAN NOT NULL DEFAULT TRUE;
+
+CREATE TABLE file_version (
+    file_version_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    file_id UUID NOT NULL REFERENCES file(file_id) ON DELETE CASCADE,
+    publication_id UUID NOT NULL REFERENCES publication(publication_id) ON DELETE CASCADE,
+    version_number INTEGER NOT NULL CHECK (version_number > 0),
+    object_key TEXT NOT NULL,
+    cdn_url TEXT NOT NULL,
+    mime_type TEXT NOT NULL,
+    bytes BIGINT NOT NULL,
+    sha256 TEXT NOT NULL,
+    uploaded_by TEXT CHECK (octet_length(uploaded_by) >= 1),
+    restored_from_file_version_id UUID REFERENCES file_version(file_version_id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    CONSTRAINT file_version_publication_id_version_number_uniq UNIQUE (publication_id, version_number)
+);
+CREATE UNIQUE INDEX file_version_object_key_unique_idx ON file_version (object_key);
+CREATE INDEX file_version_file_idx ON file_version (file_id);
@@ -0,0 +1,9 @@
+ALTER TABLE imprint
+    DROP COLUMN IF EXISTS reject_invalid_files;
+
+ALTER TABLE file
+    DROP COLUMN IF EXISTS validated_at,
+    DROP COLUMN IF EXISTS validation_issues,
+    DROP COLUMN IF EXISTS validation_status;
+
+DROP TYPE IF EXISTS file_validation_status;
@@ -0,0 +1,14 @@
+CREATE TYPE file_validation_status AS ENUM (
+    'not_validated',
+    'passed',
+    'passed_with_warnings',
+    'failed'
+);
+
+ALTER TABLE file
+    ADD COLUMN validation_status file_validation_status NOT NULL DEFAULT 'not_validated',
+    ADD COLUMN validation_issues JSONB NOT NULL DEFAULT '[]'::jsonb,
+    ADD COLUMN validated_at TIMESTAMPTZ;
+
+ALTER TABLE imprint
+    ADD COLUMN reject_invalid_files BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,26 @@
+DROP INDEX IF EXISTS price_publication_id_currency_code_uniq;
+
+-- Keep one price per currency so that the original constraint can be restored
+DELETE FROM price a
+    USING price b
+    WHERE a.publication_id = b.publication_id
+        AND a.currency_code = b.currency_code
+        AND (a.created_at, a.price_id) > (b.created_at, b.price_id);
+
+ALTER TABLE price
+    ADD CONSTRAINT price_publication_id_currency_code_uniq UNIQUE (publication_id, currency_code);
+
+ALTER TABLE price
+    DROP CONSTRAINT IF EXISTS price_effective_dates_check,
+    DROP CONSTRAINT IF EXISTS price_countries_included_check,
+    DROP COLUMN IF EXISTS effective_until,
+    DROP COLUMN IF EXISTS effective_from,
+    DROP COLUMN IF EXISTS countries_included,
+    DROP COLUMN IF EXISTS tax_inclusive,
+    DROP COLUMN IF EXISTS price_type;
+
+DROP TYPE IF EXISTS price_type;
+
+DROP TABLE IF EXISTS sales_rights_history;
+DROP TABLE IF EXISTS sales_rights;
+DROP TYPE IF EXISTS sales_rights_type;
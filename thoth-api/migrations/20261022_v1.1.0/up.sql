@@ -0,0 +1,64 @@
+CREATE TYPE sales_rights_type AS ENUM (
+    'for_sale_exclusive',
+    'for_sale_non_exclusive',
+    'not_for_sale'
+);
+
+CREATE TABLE sales_rights (
+    sales_rights_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    publication_id UUID NOT NULL REFERENCES publication(publication_id) ON DELETE CASCADE,
+    sales_rights_type sales_rights_type NOT NULL,
+    -- NULL means worldwide (ONIX RegionsIncluded WORLD)
+    countries_included country_code[],
+    countries_excluded country_code[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    CONSTRAINT sales_rights_countries_included_check
+        CHECK (countries_included IS NULL OR cardinality(countries_included) > 0),
+    CONSTRAINT sales_rights_countries_excluded_check
+        CHECK (countries_included IS NULL OR cardinality(countries_excluded) = 0)
+);
+CREATE INDEX idx_sales_rights_publication_id ON sales_rights (publication_id);
+SELECT diesel_manage_updated_at('sales_rights');
+
+CREATE TABLE sales_rights_history (
+    sales_rights_history_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    sales_rights_id UUID NOT NULL REFERENCES sales_rights(sales_rights_id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL,
+    data JSONB NOT NULL,
+    timestamp TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+-- The price trigger function only relies on publication_id, so it can be shared
+CREATE TRIGGER set_work_updated_at_with_relations
+    AFTER INSERT OR DELETE OR UPDATE ON sales_rights
+    FOR EACH ROW EXECUTE FUNCTION price_work_updated_at_with_relations();
+
+CREATE TYPE price_type AS ENUM (
+    'rrp',
+    'agency'
+);
+
+ALTER TABLE price
+    ADD COLUMN price_type price_type NOT NULL DEFAULT 'rrp',
+    ADD COLUMN tax_inclusive BOOLEAN NOT NULL DEFAULT TRUE,
+    -- NULL means worldwide (ONIX RegionsIncluded WORLD)
+    ADD COLUMN countries_included country_code[],
+    ADD COLUMN effective_from DATE,
+    ADD COLUMN effective_until DATE,
+    ADD CONSTRAINT price_countries_included_check
+        CHECK (countries_included IS NULL OR cardinality(countries_included) > 0),
+    ADD CONSTRAINT price_effective_dates_check
+        CHECK (effective_from IS NULL OR effective_until IS NULL OR effective_from <= effective_until);
+
+-- A publication may now have several prices in one currency, one per territory, type and period
+ALTER TABLE price
+    DROP CONSTRAINT price_publication_id_currency_code_uniq;
+CREATE UNIQUE INDEX price_publication_id_currency_code_uniq ON price (
+    publication_id,
+    currency_code,
+    price_type,
+    tax_inclusive,
+    COALESCE(countries_included, '{}'),
+    COALESCE(effective_from, '-infinity'::date)
+);
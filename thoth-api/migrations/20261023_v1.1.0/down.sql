@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS price_effective_until_idx;
+DROP INDEX IF EXISTS price_effective_from_idx;
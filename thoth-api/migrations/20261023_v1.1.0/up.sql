@@ -0,0 +1,5 @@
+-- Scheduled price changes are looked up by the day they start or stop applying
+CREATE INDEX price_effective_from_idx ON price (effective_from)
+    WHERE effective_from IS NOT NULL;
+CREATE INDEX price_effective_until_idx ON price (effective_until)
+    WHERE effective_until IS NOT NULL;
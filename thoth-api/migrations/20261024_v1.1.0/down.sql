@@ -0,0 +1,7 @@
+ALTER TABLE publication
+    DROP CONSTRAINT IF EXISTS publication_embargo_end_date_check,
+    DROP COLUMN IF EXISTS embargo_end_date,
+    DROP COLUMN IF EXISTS access_status,
+    DROP COLUMN IF EXISTS license;
+
+DROP TYPE IF EXISTS access_status;
@@ -0,0 +1,16 @@
+CREATE TYPE access_status AS ENUM (
+    'open',
+    'embargoed',
+    'restricted'
+);
+
+-- A publication may override the work's licence, e.g. a hybrid title whose PDF is
+-- openly licensed after an embargo while the print edition remains all rights reserved
+ALTER TABLE publication
+    ADD COLUMN license TEXT
+        CONSTRAINT publication_license_check
+        CHECK (license ~* '^[^:]*:\/\/(?:[^\/:]*:[^\/@]*@)?(?:[^\/:.]*\.)+([^:\/]+)'),
+    ADD COLUMN access_status access_status,
+    ADD COLUMN embargo_end_date DATE,
+    ADD CONSTRAINT publication_embargo_end_date_check
+        CHECK ((access_status = 'embargoed') = (embargo_end_date IS NOT NULL));
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS audience_history;
+DROP TABLE IF EXISTS audience;
+DROP TYPE IF EXISTS audience_range_precision;
+DROP TYPE IF EXISTS audience_range_qualifier;
+DROP TYPE IF EXISTS audience_code;
@@ -0,0 +1,75 @@
+-- ONIX code list 28
+CREATE TYPE audience_code AS ENUM (
+    'general',
+    'children',
+    'young_adult',
+    'primary_and_secondary',
+    'higher_education',
+    'professional_and_scholarly',
+    'elt',
+    'adult_education',
+    'second_language_teaching'
+);
+
+-- ONIX code list 30 (subset)
+CREATE TYPE audience_range_qualifier AS ENUM (
+    'us_school_grade',
+    'uk_school_grade',
+    'interest_age_months',
+    'interest_age_years',
+    'reading_age_years'
+);
+
+-- ONIX code list 31
+CREATE TYPE audience_range_precision AS ENUM (
+    'exact',
+    'from',
+    'to'
+);
+
+CREATE TABLE audience (
+    audience_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    work_id UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    audience_code audience_code NOT NULL,
+    audience_range_qualifier audience_range_qualifier,
+    audience_range_precision audience_range_precision,
+    audience_range_from TEXT
+        CONSTRAINT audience_range_from_check CHECK (octet_length(audience_range_from) >= 1),
+    audience_range_to TEXT
+        CONSTRAINT audience_range_to_check CHECK (octet_length(audience_range_to) >= 1),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    CONSTRAINT audience_range_check CHECK (
+        (audience_range_qualifier IS NULL) = (audience_range_precision IS NULL)
+        AND (
+            audience_range_qualifier IS NOT NULL
+            OR (audience_range_from IS NULL AND audience_range_to IS NULL)
+        )
+    ),
+    -- Exact and From ranges start at audience_range_from; a From range may also end at
+    -- audience_range_to. To ranges only have an upper bound.
+    CONSTRAINT audience_range_precision_check CHECK (
+        audience_range_precision IS NULL
+        OR (audience_range_precision = 'exact'
+            AND audience_range_from IS NOT NULL AND audience_range_to IS NULL)
+        OR (audience_range_precision = 'from' AND audience_range_from IS NOT NULL)
+        OR (audience_range_precision = 'to'
+            AND audience_range_from IS NULL AND audience_range_to IS NOT NULL)
+    )
+);
+CREATE INDEX idx_audience_work_id ON audience (work_id);
+CREATE UNIQUE INDEX audience_uniq_idx
+    ON audience (work_id, audience_code, audience_range_qualifier) NULLS NOT DISTINCT;
+SELECT diesel_manage_updated_at('audience');
+
+CREATE TABLE audience_history (
+    audience_history_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    audience_id UUID NOT NULL REFERENCES audience(audience_id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL,
+    data JSONB NOT NULL,
+    timestamp TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+CREATE TRIGGER set_work_updated_at_with_relations
+    AFTER INSERT OR DELETE OR UPDATE ON audience
+    FOR EACH ROW EXECUTE FUNCTION work_updated_at_with_relations();
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS product_form_detail_history;
+DROP TABLE IF EXISTS product_form_detail;
+DROP TYPE IF EXISTS product_form_detail_type;
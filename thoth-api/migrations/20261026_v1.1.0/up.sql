@@ -0,0 +1,43 @@
+CREATE TYPE product_form_detail_type AS ENUM (
+    'form_detail',
+    'form_feature',
+    'packaging'
+);
+
+-- Detail codes are ONIX code list 175 (form detail), 79 (form feature type) or
+-- 80 (packaging) values, validated by the API
+CREATE TABLE product_form_detail (
+    product_form_detail_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    publication_id UUID NOT NULL REFERENCES publication(publication_id) ON DELETE CASCADE,
+    detail_type product_form_detail_type NOT NULL,
+    detail_code TEXT NOT NULL CHECK (octet_length(detail_code) >= 1),
+    feature_value TEXT CHECK (octet_length(feature_value) >= 1),
+    feature_description TEXT CHECK (octet_length(feature_description) >= 1),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    CONSTRAINT product_form_detail_feature_check CHECK (
+        detail_type = 'form_feature'
+        OR (feature_value IS NULL AND feature_description IS NULL)
+    )
+);
+CREATE INDEX idx_product_form_detail_publication_id ON product_form_detail (publication_id);
+CREATE UNIQUE INDEX product_form_detail_uniq_idx
+    ON product_form_detail (publication_id, detail_type, detail_code);
+-- ONIX only allows one ProductPackaging per product
+CREATE UNIQUE INDEX product_form_detail_packaging_uniq_idx
+    ON product_form_detail (publication_id)
+    WHERE detail_type = 'packaging';
+SELECT diesel_manage_updated_at('product_form_detail');
+
+CREATE TABLE product_form_detail_history (
+    product_form_detail_history_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    product_form_detail_id UUID NOT NULL REFERENCES product_form_detail(product_form_detail_id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL,
+    data JSONB NOT NULL,
+    timestamp TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+-- The price trigger function only relies on publication_id, so it can be shared
+CREATE TRIGGER set_work_updated_at_with_relations
+    AFTER INSERT OR DELETE OR UPDATE ON product_form_detail
+    FOR EACH ROW EXECUTE FUNCTION price_work_updated_at_with_relations();
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS work_date_history;
+DROP TABLE IF EXISTS work_date;
+DROP TYPE IF EXISTS work_date_type;
@@ -0,0 +1,34 @@
+-- ONIX code list 163 (subset). Publication and withdrawn dates remain on work.
+CREATE TYPE work_date_type AS ENUM (
+    'public_announcement',
+    'trade_announcement',
+    'on_sale',
+    'first_publication',
+    'last_reprint',
+    'last_reissue',
+    'out_of_print'
+);
+
+CREATE TABLE work_date (
+    work_date_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    work_id UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    date_type work_date_type NOT NULL,
+    date DATE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+CREATE INDEX idx_work_date_work_id ON work_date (work_id);
+CREATE UNIQUE INDEX work_date_uniq_idx ON work_date (work_id, date_type);
+SELECT diesel_manage_updated_at('work_date');
+
+CREATE TABLE work_date_history (
+    work_date_history_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    work_date_id UUID NOT NULL REFERENCES work_date(work_date_id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL,
+    data JSONB NOT NULL,
+    timestamp TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+CREATE TRIGGER set_work_updated_at_with_relations
+    AFTER INSERT OR DELETE OR UPDATE ON work_date
+    FOR EACH ROW EXECUTE FUNCTION work_updated_at_with_relations();
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS name_variant_history;
+DROP TABLE IF EXISTS name_variant;
+DROP FUNCTION IF EXISTS name_variant_work_updated_at_with_relations();
+DROP TYPE IF EXISTS name_variant_type;
@@ -0,0 +1,63 @@
+-- Alternative forms of a contributor's name, either held against the contributor
+-- or against a single contribution when a work credits the name differently.
+CREATE TYPE name_variant_type AS ENUM (
+    'transliteration',
+    'pseudonym',
+    'former_name'
+);
+
+CREATE TABLE name_variant (
+    name_variant_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    contributor_id UUID REFERENCES contributor(contributor_id) ON DELETE CASCADE,
+    contribution_id UUID REFERENCES contribution(contribution_id) ON DELETE CASCADE,
+    variant_type name_variant_type NOT NULL,
+    -- ISO 15924 four-letter script code, e.g. Cyrl, Arab, Hans
+    script_code TEXT CHECK (script_code ~ '^[A-Z][a-z]{3}$'),
+    locale_code locale_code,
+    first_name TEXT CHECK (octet_length(first_name) >= 1),
+    last_name TEXT NOT NULL CHECK (octet_length(last_name) >= 1),
+    full_name TEXT NOT NULL CHECK (octet_length(full_name) >= 1),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    CONSTRAINT name_variant_parent_check CHECK (num_nonnulls(contributor_id, contribution_id) = 1)
+);
+CREATE INDEX idx_name_variant_contributor_id ON name_variant (contributor_id);
+CREATE INDEX idx_name_variant_contribution_id ON name_variant (contribution_id);
+CREATE UNIQUE INDEX name_variant_contributor_uniq_idx
+    ON name_variant (contributor_id, variant_type, full_name)
+    WHERE contributor_id IS NOT NULL;
+CREATE UNIQUE INDEX name_variant_contribution_uniq_idx
+    ON name_variant (contribution_id, variant_type, full_name)
+    WHERE contribution_id IS NOT NULL;
+SELECT diesel_manage_updated_at('name_variant');
+
+CREATE TABLE name_variant_history (
+    name_variant_history_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    name_variant_id UUID NOT NULL REFERENCES name_variant(name_variant_id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL,
+    data JSONB NOT NULL,
+    timestamp TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+CREATE FUNCTION name_variant_work_updated_at_with_relations() RETURNS trigger
+    LANGUAGE plpgsql
+    AS $$
+BEGIN
+    IF (
+        NEW IS DISTINCT FROM OLD
+    ) THEN
+        UPDATE work
+        SET updated_at_with_relations = current_timestamp
+        FROM contribution
+        WHERE work.work_id = contribution.work_id AND contribution.contribution_id = OLD.contribution_id
+           OR work.work_id = contribution.work_id AND contribution.contribution_id = NEW.contribution_id
+           OR work.work_id = contribution.work_id AND contribution.contributor_id = OLD.contributor_id
+           OR work.work_id = contribution.work_id AND contribution.contributor_id = NEW.contributor_id;
+    END IF;
+    RETURN NULL;
+END;
+$$;
+
+CREATE TRIGGER set_work_updated_at_with_relations
+    AFTER INSERT OR DELETE OR UPDATE ON name_variant
+    FOR EACH ROW EXECUTE FUNCTION name_variant_work_updated_at_with_relations();
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS credit_role_history;
+DROP TABLE IF EXISTS credit_role;
+DROP TYPE IF EXISTS credit_role_degree;
+DROP TYPE IF EXISTS credit_role_type;
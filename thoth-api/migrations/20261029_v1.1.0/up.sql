@@ -0,0 +1,48 @@
+-- CRediT Contributor Roles Taxonomy (ANSI/NISO Z39.104-2022)
+CREATE TYPE credit_role_type AS ENUM (
+    'conceptualization',
+    'data_curation',
+    'formal_analysis',
+    'funding_acquisition',
+    'investigation',
+    'methodology',
+    'project_administration',
+    'resources',
+    'software',
+    'supervision',
+    'validation',
+    'visualization',
+    'writing_original_draft',
+    'writing_review_editing'
+);
+
+CREATE TYPE credit_role_degree AS ENUM (
+    'lead',
+    'equal',
+    'supporting'
+);
+
+CREATE TABLE credit_role (
+    credit_role_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    contribution_id UUID NOT NULL REFERENCES contribution(contribution_id) ON DELETE CASCADE,
+    role_type credit_role_type NOT NULL,
+    degree credit_role_degree,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+CREATE INDEX idx_credit_role_contribution_id ON credit_role (contribution_id);
+CREATE UNIQUE INDEX credit_role_uniq_idx ON credit_role (contribution_id, role_type);
+SELECT diesel_manage_updated_at('credit_role');
+
+CREATE TABLE credit_role_history (
+    credit_role_history_id UUID PRIMARY KEY NOT NULL DEFAULT uuid_generate_v4(),
+    credit_role_id UUID NOT NULL REFERENCES credit_role(credit_role_id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL,
+    data JSONB NOT NULL,
+    timestamp TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT now()
+);
+
+-- Same as affiliation: the work is reached through the parent contribution
+CREATE TRIGGER set_work_updated_at_with_relations
+    AFTER INSERT OR DELETE OR UPDATE ON credit_role
+    FOR EACH ROW EXECUTE FUNCTION affiliation_work_updated_at_with_relations();
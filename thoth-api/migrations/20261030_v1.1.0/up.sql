@@ -0,0 +1,14 @@
+-- Local snapshot of the Crossref Funder Registry, loaded with `thoth funder-registry load`
+-- and used to validate funder DOIs and suggest corrections offline. Rows are replaced
+-- wholesale on every load, so parent and replacement DOIs are not foreign keys.
+CREATE TABLE funder_registry (
+    funder_doi TEXT PRIMARY KEY NOT NULL CHECK (funder_doi ~ '^https:\/\/doi\.org\/10\.13039\/[-._;()\/:a-zA-Z0-9<>+[\]]+$'),
+    funder_name TEXT NOT NULL CHECK (octet_length(funder_name) >= 1),
+    alt_names TEXT[] NOT NULL DEFAULT '{}',
+    country_code country_code,
+    parent_doi TEXT CHECK (parent_doi ~ '^https:\/\/doi\.org\/10\.13039\/[-._;()\/:a-zA-Z0-9<>+[\]]+$'),
+    ror TEXT CHECK (ror ~ '^https:\/\/ror\.org\/0[a-hjkmnp-z0-9]{6}\d{2}$'),
+    replaced_by TEXT CHECK (replaced_by ~ '^https:\/\/doi\.org\/10\.13039\/[-._;()\/:a-zA-Z0-9<>+[\]]+$')
+);
+CREATE INDEX idx_funder_registry_funder_name ON funder_registry (lower(funder_name));
+CREATE INDEX idx_funder_registry_ror ON funder_registry (ror);
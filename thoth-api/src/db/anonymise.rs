@@ -0,0 +1,245 @@
+use diesel::sql_types::Text;
+use diesel::{Connection, QueryableByName, RunQueryDsl};
+use serde::Serialize;
+
+use super::PgPool;
+use thoth_errors::{ThothError, ThothResult};
+
+/// Value written in place of the account IDs recorded in history tables
+pub const ANONYMISED_USER_ID: &str = "anonymised";
+
+/// Number of rows scrubbed by [`anonymise_database`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnonymiseSummary {
+    pub contacts: usize,
+    pub publishers: usize,
+    pub imprints: usize,
+    pub file_versions: usize,
+    pub history_entries: usize,
+    pub history_snapshots: usize,
+}
+
+/// Fields scrubbed above, as they appear in the snapshots stored in each history table, with
+/// the SQL expression replacing their value
+const HISTORY_FIELDS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "contact_history",
+        &[(
+            "email",
+            "to_jsonb('contact-' || contact_id || '@example.org')",
+        )],
+    ),
+    ("publisher_history", &[("zitadelId", "'null'::jsonb")]),
+    (
+        "imprint_history",
+        &[
+            ("s3Bucket", "'null'::jsonb"),
+            ("cdnDomain", "'null'::jsonb"),
+            ("cloudfrontDistId", "'null'::jsonb"),
+        ],
+    ),
+];
+
+/// Statement replacing `fields` in the `data` snapshots of a history table. Snapshots are
+/// normally stored as a JSON string holding the serialised entity, so they are parsed,
+/// updated and serialised again.
+fn scrub_history_snapshots(table: &str, fields: &[(&str, &str)]) -> String {
+    let snapshot =
+        "(CASE jsonb_typeof(data) WHEN 'string' THEN (data #>> '{}')::jsonb ELSE data END)";
+    let scrubbed = fields
+        .iter()
+        .fold(snapshot.to_string(), |json, (field, replacement)| {
+            format!("jsonb_set({json}, '{{{field}}}', {replacement})")
+        });
+    let stale = fields
+        .iter()
+        .map(|(field, replacement)| {
+            format!("({snapshot} ? '{field}' AND {snapshot} -> '{field}' IS DISTINCT FROM {replacement})")
+        })
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    format!(
+        "UPDATE {table} SET data = CASE jsonb_typeof(data)
+            WHEN 'string' THEN to_jsonb({scrubbed}::text)
+            ELSE {scrubbed}
+        END
+        WHERE {stale}"
+    )
+}
+
+#[derive(QueryableByName)]
+struct HistoryTable {
+    #[diesel(sql_type = Text)]
+    table_name: String,
+}
+
+/// Scrub the personal and deployment-specific fields of a database, e.g. a production dump
+/// restored for development or QA:
+///
+/// - contact emails are replaced with `contact-<id>@example.org`
+/// - publishers are unlinked from their Zitadel organisations
+/// - imprints lose their file storage settings (S3 bucket, CDN domain, CloudFront distribution)
+/// - the uploader of file versions is removed
+/// - account IDs recorded in history tables are replaced with [`ANONYMISED_USER_ID`]
+/// - the snapshots stored in history tables have the fields above scrubbed in the same way
+///
+/// Everything runs in a single transaction.
+pub fn anonymise_database(db: &PgPool) -> ThothResult<AnonymiseSummary> {
+    let mut connection = db.get()?;
+    connection.transaction::<_, ThothError, _>(|connection| {
+        let contacts = diesel::sql_query(
+            "UPDATE contact SET email = 'contact-' || contact_id || '@example.org'",
+        )
+        .execute(connection)?;
+        let publishers =
+            diesel::sql_query("UPDATE publisher SET zitadel_id = NULL WHERE zitadel_id IS NOT NULL")
+                .execute(connection)?;
+        let imprints = diesel::sql_query(
+            "UPDATE imprint SET s3_bucket = NULL, cdn_domain = NULL, cloudfront_dist_id = NULL
+            WHERE s3_bucket IS NOT NULL OR cdn_domain IS NOT NULL OR cloudfront_dist_id IS NOT NULL",
+        )
+        .execute(connection)?;
+        let file_versions = diesel::sql_query(
+            "UPDATE file_version SET uploaded_by = NULL WHERE uploaded_by IS NOT NULL",
+        )
+        .execute(connection)?;
+
+        let history_tables: Vec<HistoryTable> = diesel::sql_query(
+            "SELECT table_name::text AS table_name FROM information_schema.columns
+            WHERE table_schema = 'public' AND column_name = 'user_id'
+            AND table_name LIKE '%\\_history'
+            ORDER BY table_name",
+        )
+        .load(connection)?;
+        let mut history_entries = 0;
+        for HistoryTable { table_name } in history_tables {
+            history_entries += diesel::sql_query(format!(
+                "UPDATE \"{table_name}\" SET user_id = $1 WHERE user_id <> $1"
+            ))
+            .bind::<Text, _>(ANONYMISED_USER_ID)
+            .execute(connection)?;
+        }
+
+        let mut history_snapshots = 0;
+        for (table, fields) in HISTORY_FIELDS {
+            history_snapshots +=
+                diesel::sql_query(scrub_history_snapshots(table, fields)).execute(connection)?;
+        }
+
+        Ok(AnonymiseSummary {
+            contacts,
+            publishers,
+            imprints,
+            file_versions,
+            history_entries,
+            history_snapshots,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::contact::{Contact, ContactType, NewContact, PatchContact};
+    use crate::model::publisher::PatchPublisher;
+    use crate::model::tests::db::{create_publisher, setup_test_db, test_context};
+    use crate::model::Crud;
+    use diesel::sql_types::BigInt;
+
+    #[derive(QueryableByName)]
+    struct Matches {
+        #[diesel(sql_type = BigInt)]
+        count: i64,
+    }
+
+    /// Number of rows, in any table, with a column containing `needle`
+    fn rows_containing(pool: &PgPool, needle: &str) -> i64 {
+        let mut connection = pool.get().expect("Failed to get DB connection");
+        let tables: Vec<HistoryTable> = diesel::sql_query(
+            "SELECT table_name::text AS table_name FROM information_schema.tables
+            WHERE table_schema = 'public' AND table_type = 'BASE TABLE'",
+        )
+        .load(&mut connection)
+        .expect("Failed to list tables");
+        tables
+            .into_iter()
+            .map(|HistoryTable { table_name }| {
+                diesel::sql_query(format!(
+                    "SELECT COUNT(*) AS count FROM \"{table_name}\" t WHERE t::text LIKE '%' || $1 || '%'"
+                ))
+                .bind::<Text, _>(needle)
+                .get_result::<Matches>(&mut connection)
+                .expect("Failed to search table")
+                .count
+            })
+            .sum()
+    }
+
+    #[test]
+    fn anonymise_scrubs_history_snapshots() {
+        let (_guard, pool) = setup_test_db();
+        let ctx = test_context(pool.clone(), "editor-account");
+
+        let publisher = create_publisher(pool.as_ref());
+        let zitadel_id = publisher.zitadel_id.clone().unwrap();
+        publisher
+            .update(
+                &ctx,
+                &PatchPublisher {
+                    publisher_id: publisher.publisher_id,
+                    publisher_name: publisher.publisher_name.clone(),
+                    publisher_shortname: Some("Renamed".to_string()),
+                    publisher_url: None,
+                    zitadel_id: publisher.zitadel_id.clone(),
+                    accessibility_statement: None,
+                    accessibility_report_url: None,
+                },
+            )
+            .expect("Failed to update publisher");
+
+        let contact = Contact::create(
+            pool.as_ref(),
+            &NewContact {
+                publisher_id: publisher.publisher_id,
+                contact_type: ContactType::Accessibility,
+                email: "first.editor@press.example.com".to_string(),
+            },
+        )
+        .expect("Failed to create contact");
+        contact
+            .update(
+                &ctx,
+                &PatchContact {
+                    contact_id: contact.contact_id,
+                    publisher_id: publisher.publisher_id,
+                    contact_type: ContactType::Accessibility,
+                    email: "second.editor@press.example.com".to_string(),
+                },
+            )
+            .expect("Failed to update contact");
+        assert!(rows_containing(&pool, "first.editor@press.example.com") > 0);
+
+        let summary = anonymise_database(&pool).expect("Failed to anonymise database");
+        assert_eq!(summary.history_snapshots, 2);
+
+        for needle in [
+            "first.editor@press.example.com",
+            "second.editor@press.example.com",
+            zitadel_id.as_str(),
+            "editor-account",
+        ] {
+            assert_eq!(rows_containing(&pool, needle), 0, "'{needle}' survived");
+        }
+        assert!(
+            rows_containing(
+                &pool,
+                &format!("contact-{}@example.org", contact.contact_id)
+            ) >= 2
+        );
+
+        // Running again finds nothing left to scrub
+        let summary = anonymise_database(&pool).expect("Failed to anonymise database");
+        assert_eq!(summary.history_snapshots, 0);
+    }
+}
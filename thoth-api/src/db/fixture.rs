@@ -0,0 +1,242 @@
+use diesel::sql_types::{Array, Jsonb, Nullable, Text, Uuid as SqlUuid};
+use diesel::{Connection, QueryableByName, RunQueryDsl};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use super::PgPool;
+use thoth_errors::{ThothError, ThothResult};
+
+// Conditions selecting the rows that belong to the subtree of a set of works, bound as `$1`
+const WORKS: &str = "work_id = ANY($1)";
+const CONTRIBUTIONS: &str =
+    "contribution_id IN (SELECT contribution_id FROM contribution WHERE work_id = ANY($1))";
+const PUBLICATIONS: &str =
+    "publication_id IN (SELECT publication_id FROM publication WHERE work_id = ANY($1))";
+// Imprints of the works and of the series they are issues of, which may differ
+const IMPRINTS: &str = "imprint_id IN (
+    SELECT imprint_id FROM work WHERE work_id = ANY($1)
+    UNION SELECT series.imprint_id FROM series
+    JOIN issue ON issue.series_id = series.series_id WHERE issue.work_id = ANY($1))";
+const PUBLISHERS: &str = "publisher_id IN (SELECT publisher_id FROM imprint WHERE imprint_id IN (
+    SELECT imprint_id FROM work WHERE work_id = ANY($1)
+    UNION SELECT series.imprint_id FROM series
+    JOIN issue ON issue.series_id = series.series_id WHERE issue.work_id = ANY($1)))";
+
+/// Tables included in a fixture, in an order that satisfies their foreign keys, along with the
+/// rows of each that are exported. Files and history tables are left out.
+const FIXTURE_TABLES: &[(&str, &str)] = &[
+    ("publisher", PUBLISHERS),
+    ("contact", PUBLISHERS),
+    ("imprint", IMPRINTS),
+    (
+        "series",
+        "series_id IN (SELECT series_id FROM issue WHERE work_id = ANY($1))",
+    ),
+    (
+        "institution",
+        "institution_id IN (
+            SELECT affiliation.institution_id FROM affiliation
+            JOIN contribution ON contribution.contribution_id = affiliation.contribution_id
+            WHERE contribution.work_id = ANY($1)
+            UNION SELECT institution_id FROM funding WHERE work_id = ANY($1)
+            UNION SELECT author_institution_id FROM endorsement WHERE work_id = ANY($1)
+            UNION SELECT reviewer_institution_id FROM book_review WHERE work_id = ANY($1))",
+    ),
+    (
+        "contributor",
+        "contributor_id IN (SELECT contributor_id FROM contribution WHERE work_id = ANY($1))",
+    ),
+    ("work", WORKS),
+    ("title", WORKS),
+    ("abstract", WORKS),
+    ("language", WORKS),
+    ("subject", WORKS),
+    ("funding", WORKS),
+    ("issue", WORKS),
+    ("audience", WORKS),
+    ("work_date", WORKS),
+    ("reference", WORKS),
+    ("award", WORKS),
+    ("endorsement", WORKS),
+    ("book_review", WORKS),
+    ("additional_resource", WORKS),
+    ("work_featured_video", WORKS),
+    (
+        "work_relation",
+        "relator_work_id = ANY($1) AND related_work_id = ANY($1)",
+    ),
+    ("contribution", WORKS),
+    ("biography", CONTRIBUTIONS),
+    ("affiliation", CONTRIBUTIONS),
+    ("credit_role", CONTRIBUTIONS),
+    (
+        "name_variant",
+        "contribution_id IN (SELECT contribution_id FROM contribution WHERE work_id = ANY($1))
+        OR contributor_id IN (SELECT contributor_id FROM contribution WHERE work_id = ANY($1))",
+    ),
+    ("publication", WORKS),
+    ("price", PUBLICATIONS),
+    ("location", PUBLICATIONS),
+    ("sales_rights", PUBLICATIONS),
+    ("product_form_detail", PUBLICATIONS),
+];
+
+/// Rows of a set of tables, as exported by [`export_fixture`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Fixture {
+    pub tables: Vec<FixtureTable>,
+}
+
+/// Rows of a table, each mapping column names to values
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FixtureTable {
+    pub table: String,
+    pub rows: Vec<Map<String, Value>>,
+}
+
+#[derive(QueryableByName)]
+struct JsonRows {
+    #[diesel(sql_type = Nullable<Text>)]
+    rows: Option<String>,
+}
+
+#[derive(QueryableByName)]
+struct RelatedWork {
+    #[diesel(sql_type = SqlUuid)]
+    related_work_id: Uuid,
+}
+
+/// Export a work and everything attached to it: its publisher, imprint and series, its
+/// contributors and their institutions, and the works it is directly related to (e.g. its
+/// chapters, or the book a chapter belongs to) along with their own records.
+///
+/// The result can be loaded into an empty database with [`load_fixture`].
+pub fn export_fixture(db: &PgPool, work_id: Uuid) -> ThothResult<Fixture> {
+    let mut connection = db.get()?;
+    let root: Vec<RelatedWork> =
+        diesel::sql_query("SELECT work_id AS related_work_id FROM work WHERE work_id = $1")
+            .bind::<SqlUuid, _>(work_id)
+            .load(&mut connection)?;
+    if root.is_empty() {
+        return Err(ThothError::EntityNotFound);
+    }
+    let mut work_ids = vec![work_id];
+    work_ids.extend(
+        diesel::sql_query("SELECT related_work_id FROM work_relation WHERE relator_work_id = $1")
+            .bind::<SqlUuid, _>(work_id)
+            .load::<RelatedWork>(&mut connection)?
+            .into_iter()
+            .map(|related| related.related_work_id),
+    );
+
+    let mut tables = vec![];
+    for (table, condition) in FIXTURE_TABLES {
+        let json = diesel::sql_query(format!(
+            "SELECT json_agg(t)::text AS rows FROM (
+                SELECT * FROM \"{table}\" WHERE {condition} ORDER BY 1
+            ) t"
+        ))
+        .bind::<Array<SqlUuid>, _>(work_ids.clone())
+        .get_result::<JsonRows>(&mut connection)?;
+        let rows: Vec<Map<String, Value>> = match json.rows {
+            Some(rows) => serde_json::from_str(&rows)?,
+            None => continue,
+        };
+        tables.push(FixtureTable {
+            table: table.to_string(),
+            rows,
+        });
+    }
+    Ok(Fixture { tables })
+}
+
+/// Insert the rows of a fixture, in a single transaction. Only the tables that can be exported
+/// with [`export_fixture`] are accepted.
+pub fn load_fixture(db: &PgPool, fixture: &Fixture) -> ThothResult<()> {
+    let mut connection = db.get()?;
+    connection.transaction::<_, ThothError, _>(|connection| {
+        for FixtureTable { table, rows } in &fixture.tables {
+            if !FIXTURE_TABLES
+                .iter()
+                .any(|(name, _)| *name == table.as_str())
+            {
+                return Err(ThothError::InternalError(format!(
+                    "Unsupported fixture table: {table}"
+                )));
+            }
+            let mut columns: Vec<&str> = vec![];
+            for column in rows.iter().flat_map(|row| row.keys()) {
+                if !column
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+                {
+                    return Err(ThothError::InternalError(format!(
+                        "Invalid fixture column: {table}.{column}"
+                    )));
+                }
+                if !columns.contains(&column.as_str()) {
+                    columns.push(column);
+                }
+            }
+            if columns.is_empty() {
+                continue;
+            }
+            let columns = columns
+                .iter()
+                .map(|column| format!("\"{column}\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+            diesel::sql_query(format!(
+                "INSERT INTO \"{table}\" ({columns})
+                SELECT {columns} FROM jsonb_populate_recordset(NULL::\"{table}\", $1)"
+            ))
+            .bind::<Jsonb, _>(Value::Array(
+                rows.iter().cloned().map(Value::Object).collect(),
+            ))
+            .execute(connection)?;
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::tests::db::{
+        create_imprint, create_publisher, create_work, reset_db, setup_test_db,
+    };
+
+    #[test]
+    fn test_export_and_load_fixture() {
+        let (_guard, pool) = setup_test_db();
+        let publisher = create_publisher(&pool);
+        let imprint = create_imprint(&pool, &publisher);
+        let work = create_work(&pool, &imprint);
+
+        let fixture = export_fixture(&pool, work.work_id).expect("Failed to export fixture");
+        let tables: Vec<&str> = fixture.tables.iter().map(|t| t.table.as_str()).collect();
+        assert_eq!(tables, vec!["publisher", "imprint", "work"]);
+
+        reset_db(&pool).expect("Failed to reset DB");
+        load_fixture(&pool, &fixture).expect("Failed to load fixture");
+        let reloaded = export_fixture(&pool, work.work_id).expect("Failed to export fixture");
+        assert_eq!(reloaded.tables.len(), fixture.tables.len());
+        assert_eq!(
+            reloaded.tables[2].rows[0]["work_id"],
+            fixture.tables[2].rows[0]["work_id"]
+        );
+    }
+
+    #[test]
+    fn test_load_fixture_rejects_unknown_table() {
+        let (_guard, pool) = setup_test_db();
+        let fixture = Fixture {
+            tables: vec![FixtureTable {
+                table: "file".to_string(),
+                rows: vec![],
+            }],
+        };
+        assert!(load_fixture(&pool, &fixture).is_err());
+    }
+}
@@ -5,6 +5,16 @@ use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 
 use thoth_errors::ThothResult;
 
+use crate::telemetry::QueryTracing;
+
+mod anonymise;
+mod fixture;
+mod seed;
+
+pub use anonymise::{anonymise_database, AnonymiseSummary, ANONYMISED_USER_ID};
+pub use fixture::{export_fixture, load_fixture, Fixture, FixtureTable};
+pub use seed::{seed_database, SeedOptions, SeedSummary};
+
 pub type PgPool = Pool<ConnectionManager<PgConnection>>;
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
@@ -12,6 +22,7 @@ pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
 pub fn init_pool(database_url: &str) -> PgPool {
     let manager = ConnectionManager::<PgConnection>::new(database_url);
     Pool::builder()
+        .connection_customizer(Box::new(QueryTracing))
         .build(manager)
         .expect("Failed to create database pool.")
 }
@@ -0,0 +1,841 @@
+use chrono::NaiveDate;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use super::PgPool;
+use crate::model::additional_resource::{AdditionalResource, NewAdditionalResource, ResourceType};
+use crate::model::affiliation::{Affiliation, NewAffiliation};
+use crate::model::audience::{Audience, AudienceCode, NewAudience};
+use crate::model::award::{Award, AwardRole, NewAward};
+use crate::model::biography::{Biography, NewBiography};
+use crate::model::book_review::{BookReview, NewBookReview};
+use crate::model::contact::{Contact, ContactType, NewContact};
+use crate::model::contribution::{Contribution, ContributionType, NewContribution};
+use crate::model::contributor::{Contributor, NewContributor};
+use crate::model::credit_role::{CreditRole, CreditRoleDegree, CreditRoleType, NewCreditRole};
+use crate::model::endorsement::{Endorsement, NewEndorsement};
+use crate::model::funding::{Funding, NewFunding};
+use crate::model::imprint::{Imprint, NewImprint};
+use crate::model::institution::{Institution, NewInstitution};
+use crate::model::issue::{Issue, NewIssue};
+use crate::model::language::{Language, LanguageCode, LanguageRelation, NewLanguage};
+use crate::model::locale::LocaleCode;
+use crate::model::location::{Location, LocationPlatform, NewLocation};
+use crate::model::name_variant::{NameVariant, NameVariantType, NewNameVariant};
+use crate::model::price::{CurrencyCode, NewPrice, Price, PriceType};
+use crate::model::product_form_detail::{
+    NewProductFormDetail, ProductFormDetail, ProductFormDetailType,
+};
+use crate::model::publication::{NewPublication, Publication, PublicationType};
+use crate::model::publisher::{NewPublisher, Publisher};
+use crate::model::r#abstract::{Abstract, AbstractType, NewAbstract};
+use crate::model::reference::{NewReference, Reference};
+use crate::model::sales_rights::{NewSalesRights, SalesRights, SalesRightsType};
+use crate::model::series::{NewSeries, Series, SeriesType};
+use crate::model::subject::{NewSubject, Subject, SubjectType};
+use crate::model::title::{NewTitle, Title};
+use crate::model::work::{NewWork, Work, WorkStatus, WorkType};
+use crate::model::work_date::{NewWorkDate, WorkDate, WorkDateType};
+use crate::model::work_featured_video::{NewWorkFeaturedVideo, WorkFeaturedVideo};
+use crate::model::work_relation::{NewWorkRelation, RelationType, WorkRelation};
+use crate::model::{CountryCode, Crud, Doi, Isbn, Orcid};
+use thoth_errors::ThothResult;
+
+const FIRST_NAMES: [&str; 12] = [
+    "Ada", "Bruno", "Carmen", "Dmitri", "Esther", "Farid", "Greta", "Hiro", "Ines", "Jonah",
+    "Kwame", "Lucia",
+];
+const LAST_NAMES: [&str; 12] = [
+    "Abara",
+    "Berg",
+    "Castillo",
+    "Dubois",
+    "Eriksen",
+    "Fontaine",
+    "Gallo",
+    "Haddad",
+    "Iverson",
+    "Jansen",
+    "Kowalski",
+    "Lindqvist",
+];
+const TITLE_ADJECTIVES: [&str; 10] = [
+    "Open",
+    "Hidden",
+    "Common",
+    "Uncertain",
+    "Radical",
+    "Quiet",
+    "Shared",
+    "Forgotten",
+    "Digital",
+    "Living",
+];
+const TITLE_NOUNS: [&str; 10] = [
+    "Archives",
+    "Commons",
+    "Borders",
+    "Histories",
+    "Cities",
+    "Machines",
+    "Rivers",
+    "Voices",
+    "Knowledge",
+    "Gardens",
+];
+const THEMA_CODES: [&str; 6] = ["JP", "JB", "NH", "GT", "QD", "RG"];
+const DOI_PREFIX: &str = "10.99999";
+// Registrant element of the ISBNs given to seeded publications, in the (well established)
+// 978-0-00 range so that they can be hyphenated
+const ISBN_PREFIX: &str = "97800";
+
+/// Size of the synthetic dataset generated by [`seed_database`]
+#[derive(Debug, Clone, Copy)]
+pub struct SeedOptions {
+    /// Number of publishers, each with one imprint and one series
+    pub publishers: usize,
+    /// Number of books created in each imprint
+    pub works_per_publisher: usize,
+    /// Number of chapters created for each book
+    pub chapters_per_work: usize,
+    /// Seed of the random number generator, so that a dataset can be reproduced.
+    ///
+    /// Identifiers (DOIs, ISBNs, ORCIDs) and names are derived from it: seeding the same
+    /// database twice requires a different seed.
+    pub seed: u64,
+}
+
+impl Default for SeedOptions {
+    fn default() -> Self {
+        Self {
+            publishers: 2,
+            works_per_publisher: 10,
+            chapters_per_work: 3,
+            seed: 0,
+        }
+    }
+}
+
+/// Number of records created by [`seed_database`], per table
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SeedSummary(pub BTreeMap<&'static str, usize>);
+
+/// Fill the database with a synthetic dataset that satisfies all of its constraints: publishers,
+/// imprints and series, books with every kind of child record, and chapters related to them.
+///
+/// Records are inserted directly, bypassing the permission checks of the GraphQL API, and are
+/// not recorded in the history tables.
+pub fn seed_database(db: &PgPool, options: &SeedOptions) -> ThothResult<SeedSummary> {
+    let mut seeder = Seeder {
+        db,
+        options,
+        rng: StdRng::seed_from_u64(options.seed),
+        summary: SeedSummary::default(),
+        identifiers: 0,
+    };
+    for publisher_number in 1..=options.publishers {
+        seeder.publisher(publisher_number)?;
+    }
+    Ok(seeder.summary)
+}
+
+struct Seeder<'a> {
+    db: &'a PgPool,
+    options: &'a SeedOptions,
+    rng: StdRng,
+    summary: SeedSummary,
+    /// Counter used to generate unique DOIs, ISBNs and ORCIDs
+    identifiers: u64,
+}
+
+impl Seeder<'_> {
+    fn create<T: Crud>(&mut self, table: &'static str, data: &T::NewEntity) -> ThothResult<T> {
+        let entity = T::create(self.db, data)?;
+        *self.summary.0.entry(table).or_default() += 1;
+        Ok(entity)
+    }
+
+    fn pick<'b>(&mut self, items: &[&'b str]) -> &'b str {
+        items[self.rng.random_range(0..items.len())]
+    }
+
+    fn next_identifier(&mut self) -> u64 {
+        self.identifiers += 1;
+        self.identifiers
+    }
+
+    fn doi(&mut self) -> Option<Doi> {
+        let number = self.next_identifier();
+        Doi::from_str(&format!(
+            "{DOI_PREFIX}/thoth-seed.{}.{number}",
+            self.options.seed
+        ))
+        .ok()
+    }
+
+    fn isbn(&mut self) -> Option<Isbn> {
+        let number = (self.options.seed % 100) * 10_000 + self.next_identifier() % 10_000;
+        let digits = format!("{ISBN_PREFIX}{number:07}");
+        let sum: u32 = digits
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .enumerate()
+            .map(|(i, digit)| if i % 2 == 0 { digit } else { digit * 3 })
+            .sum();
+        let check_digit = (10 - sum % 10) % 10;
+        Isbn::from_str(&format!("{digits}{check_digit}")).ok()
+    }
+
+    fn orcid(&mut self) -> Option<Orcid> {
+        let number = self.next_identifier() % 10_000;
+        Orcid::from_str(&format!(
+            "0000-0002-{:04}-{number:04}",
+            self.options.seed % 10_000
+        ))
+        .ok()
+    }
+
+    fn date(&mut self, years: std::ops::RangeInclusive<i32>) -> NaiveDate {
+        let year = self.rng.random_range(years);
+        let month = self.rng.random_range(1..=12);
+        let day = self.rng.random_range(1..=28);
+        NaiveDate::from_ymd_opt(year, month, day).unwrap_or_default()
+    }
+
+    fn full_title(&mut self) -> (String, String) {
+        let title = format!(
+            "{} {}",
+            self.pick(&TITLE_ADJECTIVES),
+            self.pick(&TITLE_NOUNS)
+        );
+        let subtitle = format!(
+            "Essays on {} {}",
+            self.pick(&TITLE_ADJECTIVES).to_lowercase(),
+            self.pick(&TITLE_NOUNS).to_lowercase()
+        );
+        (title, subtitle)
+    }
+
+    fn publisher(&mut self, number: usize) -> ThothResult<()> {
+        let seed = self.options.seed;
+        let publisher: Publisher = self.create(
+            "publisher",
+            &NewPublisher {
+                publisher_name: format!("Synthetic Press {seed}-{number}"),
+                publisher_shortname: Some(format!("SP{number}")),
+                publisher_url: Some(format!("https://press{number}.example.org")),
+                zitadel_id: None,
+                accessibility_statement: Some(
+                    "All our digital publications meet WCAG 2.1 AA.".to_string(),
+                ),
+                accessibility_report_url: None,
+            },
+        )?;
+        self.create::<Contact>(
+            "contact",
+            &NewContact {
+                publisher_id: publisher.publisher_id,
+                contact_type: ContactType::Accessibility,
+                email: format!("accessibility@press{number}.example.org"),
+            },
+        )?;
+        let imprint: Imprint = self.create(
+            "imprint",
+            &NewImprint {
+                publisher_id: publisher.publisher_id,
+                imprint_name: format!("Synthetic Books {seed}-{number}"),
+                imprint_url: Some(format!("https://press{number}.example.org/books")),
+                crossmark_doi: None,
+                s3_bucket: None,
+                cdn_domain: None,
+                cloudfront_dist_id: None,
+                default_currency: Some(CurrencyCode::Gbp),
+                default_place: Some("Cambridge, UK".to_string()),
+                default_locale: Some(LocaleCode::En),
+                reject_invalid_files: Some(false),
+            },
+        )?;
+        let series: Series = self.create(
+            "series",
+            &NewSeries {
+                series_type: SeriesType::BookSeries,
+                series_name: format!("Synthetic Studies {seed}-{number}"),
+                issn_print: None,
+                issn_digital: None,
+                series_url: Some(format!("https://press{number}.example.org/series")),
+                series_description: Some("A series of synthetic books.".to_string()),
+                series_cfp_url: None,
+                imprint_id: imprint.imprint_id,
+            },
+        )?;
+        let institution: Institution = self.create(
+            "institution",
+            &NewInstitution {
+                institution_name: format!("University of Synthesis {seed}-{number}"),
+                institution_doi: None,
+                ror: None,
+                country_code: Some(CountryCode::Gbr),
+            },
+        )?;
+        for work_number in 1..=self.options.works_per_publisher {
+            self.book(&imprint, &series, &institution, work_number)?;
+        }
+        Ok(())
+    }
+
+    fn contributor(&mut self) -> ThothResult<Contributor> {
+        let first_name = self.pick(&FIRST_NAMES).to_string();
+        let last_name = self.pick(&LAST_NAMES).to_string();
+        let orcid = self.orcid();
+        let contributor: Contributor = self.create(
+            "contributor",
+            &NewContributor {
+                full_name: format!("{first_name} {last_name}"),
+                first_name: Some(first_name),
+                last_name,
+                orcid,
+                website: None,
+            },
+        )?;
+        self.create::<NameVariant>(
+            "name_variant",
+            &NewNameVariant {
+                contributor_id: Some(contributor.contributor_id),
+                contribution_id: None,
+                variant_type: NameVariantType::Pseudonym,
+                script_code: None,
+                locale_code: None,
+                first_name: None,
+                last_name: format!("{} Pseud", contributor.last_name),
+                full_name: format!("{} Pseud", contributor.last_name),
+            },
+        )?;
+        Ok(contributor)
+    }
+
+    fn contribution(
+        &mut self,
+        work_id: Uuid,
+        contributor: &Contributor,
+        contribution_type: ContributionType,
+        ordinal: i32,
+    ) -> ThothResult<Contribution> {
+        self.create(
+            "contribution",
+            &NewContribution {
+                work_id,
+                contributor_id: contributor.contributor_id,
+                contribution_type,
+                main_contribution: ordinal == 1,
+                first_name: contributor.first_name.clone(),
+                last_name: contributor.last_name.clone(),
+                full_name: contributor.full_name.clone(),
+                contribution_ordinal: ordinal,
+            },
+        )
+    }
+
+    fn titles(&mut self, work_id: Uuid) -> ThothResult<String> {
+        let (title, subtitle) = self.full_title();
+        self.create::<Title>(
+            "title",
+            &NewTitle {
+                work_id,
+                locale_code: LocaleCode::En,
+                full_title: format!("{title}: {subtitle}"),
+                title: title.clone(),
+                subtitle: Some(subtitle),
+                canonical: true,
+            },
+        )?;
+        for abstract_type in [AbstractType::Short, AbstractType::Long] {
+            self.create::<Abstract>(
+                "abstract",
+                &NewAbstract {
+                    work_id,
+                    content: format!("<p>{title} explores synthetic data ({abstract_type}).</p>"),
+                    locale_code: LocaleCode::En,
+                    abstract_type,
+                    canonical: true,
+                },
+            )?;
+        }
+        Ok(title)
+    }
+
+    fn book(
+        &mut self,
+        imprint: &Imprint,
+        series: &Series,
+        institution: &Institution,
+        number: usize,
+    ) -> ThothResult<()> {
+        let forthcoming = number.is_multiple_of(5);
+        let publication_date = if forthcoming {
+            self.date(2027..=2028)
+        } else {
+            self.date(2015..=2025)
+        };
+        let work_type = if number.is_multiple_of(3) {
+            WorkType::EditedBook
+        } else {
+            WorkType::Monograph
+        };
+        let doi = self.doi();
+        let landing_page = format!(
+            "https://press.example.org/books/{}-{number}",
+            imprint.imprint_id
+        );
+        let page_count = self.rng.random_range(120..=480);
+        let image_count = self.rng.random_range(0..=20);
+        let table_count = self.rng.random_range(0..=10);
+        let work: Work = self.create(
+            "work",
+            &NewWork {
+                work_type,
+                work_status: if forthcoming {
+                    WorkStatus::Forthcoming
+                } else {
+                    WorkStatus::Active
+                },
+                reference: Some(format!("SEED-{number}")),
+                edition: Some(1),
+                imprint_id: imprint.imprint_id,
+                doi,
+                publication_date: Some(publication_date),
+                withdrawn_date: None,
+                place: imprint.default_place.clone(),
+                page_count: Some(page_count),
+                page_breakdown: None,
+                image_count: Some(image_count),
+                table_count: Some(table_count),
+                audio_count: None,
+                video_count: None,
+                license: Some("https://creativecommons.org/licenses/by/4.0/".to_string()),
+                copyright_holder: None,
+                landing_page: Some(landing_page.clone()),
+                lccn: None,
+                oclc: None,
+                general_note: None,
+                bibliography_note: None,
+                toc: None,
+                resources_description: None,
+                cover_url: None,
+                cover_caption: None,
+                first_page: None,
+                last_page: None,
+                page_interval: None,
+                expose_latest_file_url: Some(true),
+            },
+        )?;
+        let work_id = work.work_id;
+        let title = self.titles(work_id)?;
+
+        let contribution_type = if work_type == WorkType::EditedBook {
+            ContributionType::Editor
+        } else {
+            ContributionType::Author
+        };
+        let mut contributors = vec![];
+        for ordinal in 1..=self.rng.random_range(1..=3) {
+            let contributor = self.contributor()?;
+            let contribution =
+                self.contribution(work_id, &contributor, contribution_type, ordinal)?;
+            self.create::<Biography>(
+                "biography",
+                &NewBiography {
+                    contribution_id: contribution.contribution_id,
+                    content: format!(
+                        "<p>{} is a researcher at {}.</p>",
+                        contributor.full_name, institution.institution_name
+                    ),
+                    canonical: true,
+                    locale_code: LocaleCode::En,
+                },
+            )?;
+            self.create::<Affiliation>(
+                "affiliation",
+                &NewAffiliation {
+                    contribution_id: contribution.contribution_id,
+                    institution_id: institution.institution_id,
+                    affiliation_ordinal: 1,
+                    position: Some("Lecturer".to_string()),
+                },
+            )?;
+            self.create::<CreditRole>(
+                "credit_role",
+                &NewCreditRole {
+                    contribution_id: contribution.contribution_id,
+                    role_type: if ordinal == 1 {
+                        CreditRoleType::Conceptualization
+                    } else {
+                        CreditRoleType::WritingOriginalDraft
+                    },
+                    degree: Some(if ordinal == 1 {
+                        CreditRoleDegree::Lead
+                    } else {
+                        CreditRoleDegree::Equal
+                    }),
+                },
+            )?;
+            contributors.push(contributor);
+        }
+
+        for publication_type in [PublicationType::Paperback, PublicationType::Pdf] {
+            self.publication(work_id, publication_type, &landing_page)?;
+        }
+
+        self.create::<Language>(
+            "language",
+            &NewLanguage {
+                work_id,
+                language_code: LanguageCode::Eng,
+                language_relation: LanguageRelation::Original,
+            },
+        )?;
+        let thema_code = self.pick(&THEMA_CODES).to_string();
+        for (subject_type, subject_code) in [
+            (SubjectType::Thema, thema_code),
+            (SubjectType::Keyword, "synthetic data".to_string()),
+        ] {
+            self.create::<Subject>(
+                "subject",
+                &NewSubject {
+                    work_id,
+                    subject_type,
+                    subject_code,
+                    subject_ordinal: 1,
+                },
+            )?;
+        }
+        self.create::<Funding>(
+            "funding",
+            &NewFunding {
+                work_id,
+                institution_id: institution.institution_id,
+                program: Some("Open Access Fund".to_string()),
+                project_name: None,
+                project_shortname: None,
+                grant_number: Some(format!("OA-{number:04}")),
+            },
+        )?;
+        self.create::<Issue>(
+            "issue",
+            &NewIssue {
+                series_id: series.series_id,
+                work_id,
+                issue_ordinal: number as i32,
+                issue_number: Some(number as i32),
+            },
+        )?;
+        self.create::<Audience>(
+            "audience",
+            &NewAudience {
+                work_id,
+                audience_code: AudienceCode::ProfessionalAndScholarly,
+                audience_range_qualifier: None,
+                audience_range_precision: None,
+                audience_range_from: None,
+                audience_range_to: None,
+            },
+        )?;
+        self.create::<WorkDate>(
+            "work_date",
+            &NewWorkDate {
+                work_id,
+                date_type: WorkDateType::OnSale,
+                date: publication_date,
+            },
+        )?;
+        let citation = format!(
+            "{}, {title} (Synthetic Press, 2010).",
+            self.pick(&LAST_NAMES)
+        );
+        self.create::<Reference>(
+            "reference",
+            &NewReference {
+                work_id,
+                reference_ordinal: 1,
+                doi: None,
+                unstructured_citation: Some(citation),
+                issn: None,
+                isbn: None,
+                journal_title: None,
+                article_title: None,
+                series_title: None,
+                volume_title: None,
+                edition: None,
+                author: None,
+                volume: None,
+                issue: None,
+                first_page: None,
+                component_number: None,
+                standard_designator: None,
+                standards_body_name: None,
+                standards_body_acronym: None,
+                url: None,
+                publication_date: None,
+                retrieval_date: None,
+            },
+        )?;
+
+        if !forthcoming {
+            self.reception(work_id, &title, institution, publication_date)?;
+        }
+        self.create::<AdditionalResource>(
+            "additional_resource",
+            &NewAdditionalResource {
+                work_id,
+                title: "Companion website".to_string(),
+                description: Some("<p>Data and supplementary material.</p>".to_string()),
+                attribution: None,
+                resource_type: ResourceType::Website,
+                doi: None,
+                handle: None,
+                url: Some(format!("{landing_page}/resources")),
+                date: None,
+                resource_ordinal: 1,
+            },
+        )?;
+        self.create::<WorkFeaturedVideo>(
+            "work_featured_video",
+            &NewWorkFeaturedVideo {
+                work_id,
+                title: Some(format!("Introducing {title}")),
+                url: Some(format!("{landing_page}/video")),
+                width: 560,
+                height: 315,
+            },
+        )?;
+
+        for ordinal in 1..=self.options.chapters_per_work {
+            self.chapter(&work, &contributors, ordinal as i32)?;
+        }
+        Ok(())
+    }
+
+    fn publication(
+        &mut self,
+        work_id: Uuid,
+        publication_type: PublicationType,
+        landing_page: &str,
+    ) -> ThothResult<Publication> {
+        let isbn = self.isbn();
+        let publication: Publication = self.create(
+            "publication",
+            &NewPublication {
+                publication_type,
+                work_id,
+                isbn,
+                width_mm: None,
+                width_in: None,
+                height_mm: None,
+                height_in: None,
+                depth_mm: None,
+                depth_in: None,
+                weight_g: None,
+                weight_oz: None,
+                accessibility_standard: None,
+                accessibility_additional_standard: None,
+                accessibility_exception: None,
+                accessibility_report_url: None,
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
+            },
+        )?;
+        let publication_id = publication.publication_id;
+        let is_print = publication_type == PublicationType::Paperback;
+        // digital editions are open access, and prices must be positive
+        let prices = if is_print {
+            vec![(CurrencyCode::Gbp, 20.0), (CurrencyCode::Usd, 25.0)]
+        } else {
+            vec![]
+        };
+        for (currency_code, base) in prices {
+            let unit_price = base + f64::from(self.rng.random_range(0..10));
+            self.create::<Price>(
+                "price",
+                &NewPrice {
+                    publication_id,
+                    currency_code,
+                    unit_price,
+                    price_type: Some(PriceType::Rrp),
+                    tax_inclusive: Some(true),
+                    countries_included: None,
+                    effective_from: None,
+                    effective_until: None,
+                },
+            )?;
+        }
+        self.create::<Location>(
+            "location",
+            &NewLocation {
+                publication_id,
+                landing_page: Some(landing_page.to_string()),
+                full_text_url: (!is_print).then(|| format!("{landing_page}/download")),
+                location_platform: LocationPlatform::PublisherWebsite,
+                canonical: true,
+            },
+        )?;
+        self.create::<SalesRights>(
+            "sales_rights",
+            &NewSalesRights {
+                publication_id,
+                sales_rights_type: SalesRightsType::ForSaleExclusive,
+                countries_included: None,
+                countries_excluded: vec![],
+            },
+        )?;
+        if is_print {
+            self.create::<ProductFormDetail>(
+                "product_form_detail",
+                &NewProductFormDetail {
+                    publication_id,
+                    detail_type: ProductFormDetailType::FormDetail,
+                    detail_code: "B106".to_string(),
+                    feature_value: None,
+                    feature_description: None,
+                },
+            )?;
+        }
+        Ok(publication)
+    }
+
+    /// Award, endorsement and review of a published book
+    fn reception(
+        &mut self,
+        work_id: Uuid,
+        title: &str,
+        institution: &Institution,
+        publication_date: NaiveDate,
+    ) -> ThothResult<()> {
+        let author_name = format!("{} {}", self.pick(&FIRST_NAMES), self.pick(&LAST_NAMES));
+        let role = if self.rng.random_bool(0.5) {
+            AwardRole::Winner
+        } else {
+            AwardRole::ShortListed
+        };
+        self.create::<Award>(
+            "award",
+            &NewAward {
+                work_id,
+                title: "Prize for Synthetic Scholarship".to_string(),
+                url: None,
+                category: Some("Monograph".to_string()),
+                year: Some(publication_date.format("%Y").to_string()),
+                jury: None,
+                country: Some(CountryCode::Gbr),
+                prize_statement: None,
+                role: Some(role),
+                award_ordinal: 1,
+            },
+        )?;
+        self.create::<Endorsement>(
+            "endorsement",
+            &NewEndorsement {
+                work_id,
+                author_name: Some(author_name.clone()),
+                author_role: Some("Professor".to_string()),
+                author_orcid: None,
+                author_institution_id: Some(institution.institution_id),
+                url: None,
+                text: Some(format!("<p>{title} is a landmark contribution.</p>")),
+                endorsement_ordinal: 1,
+            },
+        )?;
+        self.create::<BookReview>(
+            "book_review",
+            &NewBookReview {
+                work_id,
+                title: Some(format!("Review of {title}")),
+                author_name: Some(author_name),
+                reviewer_orcid: None,
+                reviewer_institution_id: Some(institution.institution_id),
+                url: None,
+                doi: None,
+                review_date: Some(publication_date + chrono::Days::new(90)),
+                journal_name: Some("Journal of Synthetic Studies".to_string()),
+                journal_volume: Some("12".to_string()),
+                journal_number: Some("3".to_string()),
+                journal_issn: None,
+                page_range: Some("101-103".to_string()),
+                text: Some("<p>A welcome addition to the field.</p>".to_string()),
+                review_ordinal: 1,
+            },
+        )?;
+        Ok(())
+    }
+
+    fn chapter(
+        &mut self,
+        book: &Work,
+        contributors: &[Contributor],
+        ordinal: i32,
+    ) -> ThothResult<()> {
+        let doi = self.doi();
+        let pages = book.page_count.unwrap_or(100) / (self.options.chapters_per_work as i32 + 1);
+        let first_page = (ordinal - 1) * pages + 1;
+        let last_page = ordinal * pages;
+        let chapter: Work = self.create(
+            "work",
+            &NewWork {
+                work_type: WorkType::BookChapter,
+                work_status: book.work_status,
+                reference: None,
+                edition: None,
+                imprint_id: book.imprint_id,
+                doi,
+                publication_date: book.publication_date,
+                withdrawn_date: None,
+                place: book.place.clone(),
+                page_count: Some(last_page - first_page + 1),
+                page_breakdown: None,
+                image_count: None,
+                table_count: None,
+                audio_count: None,
+                video_count: None,
+                license: book.license.clone(),
+                copyright_holder: None,
+                landing_page: book
+                    .landing_page
+                    .as_ref()
+                    .map(|landing_page| format!("{landing_page}/chapters/{ordinal}")),
+                lccn: None,
+                oclc: None,
+                general_note: None,
+                bibliography_note: None,
+                toc: None,
+                resources_description: None,
+                cover_url: None,
+                cover_caption: None,
+                first_page: Some(first_page.to_string()),
+                last_page: Some(last_page.to_string()),
+                page_interval: Some(format!("{first_page}–{last_page}")),
+                expose_latest_file_url: Some(true),
+            },
+        )?;
+        self.titles(chapter.work_id)?;
+        if let Some(contributor) = contributors.get(ordinal as usize % contributors.len().max(1)) {
+            self.contribution(chapter.work_id, contributor, ContributionType::Author, 1)?;
+        }
+        if let Some(landing_page) = chapter.landing_page.clone() {
+            self.publication(chapter.work_id, PublicationType::Pdf, &landing_page)?;
+        }
+        // the inverse relation (chapter is child of book) is inserted along with this one
+        self.create::<WorkRelation>(
+            "work_relation",
+            &NewWorkRelation {
+                relator_work_id: book.work_id,
+                related_work_id: chapter.work_id,
+                relation_type: RelationType::HasChild,
+                relation_ordinal: ordinal,
+            },
+        )?;
+        Ok(())
+    }
+}
@@ -1,7 +1,6 @@
 use std::sync::Arc;
 
 use chrono::naive::NaiveDate;
-use juniper::{FieldError, FieldResult};
 use uuid::Uuid;
 use zitadel::actix::introspection::IntrospectedUser;
 
@@ -14,40 +13,62 @@ use crate::markup::{convert_from_jats, ConversionLimit, MarkupFormat};
 use crate::model::{
     additional_resource::{AdditionalResource, AdditionalResourceOrderBy},
     affiliation::{Affiliation, AffiliationOrderBy},
+    audience::{
+        Audience, AudienceCode, AudienceOrderBy, AudienceRangePrecision, AudienceRangeQualifier,
+    },
     award::{Award, AwardOrderBy, AwardRole},
     biography::{Biography, BiographyOrderBy},
     book_review::{BookReview, BookReviewOrderBy},
     contact::{Contact, ContactOrderBy, ContactType},
     contribution::{Contribution, ContributionType},
     contributor::Contributor,
+    credit_role::{CreditRole, CreditRoleDegree, CreditRoleOrderBy, CreditRoleType},
     endorsement::{Endorsement, EndorsementOrderBy},
-    file::{File, FileType},
+    file::{File, FileType, FileValidationIssue, FileValidationStatus},
+    file_audit::FileAudit,
+    file_version::FileVersion,
     funding::Funding,
     imprint::{Imprint, ImprintField, ImprintOrderBy},
-    institution::Institution,
+    institution::{
+        funder_registry::{
+            FunderRegistryCheck, FunderRegistryStatus, FunderSuggestion, RegisteredFunder,
+        },
+        Institution,
+    },
     issue::Issue,
     language::{Language, LanguageCode, LanguageRelation},
+    license::CreativeCommonsLicense,
     locale::LocaleCode,
     location::{Location, LocationOrderBy, LocationPlatform},
-    price::{CurrencyCode, Price},
+    name_variant::{NameVariant, NameVariantOrderBy, NameVariantType},
+    price::{CurrencyCode, Price, PriceType},
+    product_form_detail::{
+        ProductFormDetail, ProductFormDetailOrderBy, ProductFormDetailProperties,
+        ProductFormDetailType,
+    },
     publication::{
-        AccessibilityException, AccessibilityStandard, Publication, PublicationOrderBy,
-        PublicationType,
+        AccessStatus, AccessibilityException, AccessibilityStandard, Publication,
+        PublicationOrderBy, PublicationType,
     },
     publisher::Publisher,
     r#abstract::{Abstract, AbstractOrderBy, AbstractType},
     reference::{Reference, ReferenceOrderBy},
+    sales_rights::{SalesRights, SalesRightsOrderBy, SalesRightsType},
     series::{Series, SeriesType},
-    subject::{Subject, SubjectType},
+    subject::{
+        vocabulary::{SubjectHeading, SubjectHeadingLabel},
+        Subject, SubjectType,
+    },
     title::{Title, TitleOrderBy},
     work::{Work, WorkOrderBy, WorkStatus, WorkType},
+    work_date::{WorkDate, WorkDateOrderBy, WorkDateType},
     work_featured_video::WorkFeaturedVideo,
     work_relation::{RelationType, WorkRelation, WorkRelationOrderBy},
     CountryCode, Crud, Doi, Isbn, Orcid, Ror, Timestamp,
 };
 use crate::policy::PolicyContext;
 use crate::storage::{CloudFrontClient, S3Client};
-use thoth_errors::ThothError;
+use thoth_errors::{ThothError, ThothResult};
 
 impl juniper::Context for Context {}
 
@@ -112,7 +133,7 @@ impl Work {
     #[graphql(
         deprecated = "Please use Work `titles` field instead to get the correct full title in a multilingual manner"
     )]
-    pub fn full_title(&self, ctx: &Context) -> FieldResult<String> {
+    pub fn full_title(&self, ctx: &Context) -> ThothResult<String> {
         Ok(Title::canonical_from_work_id(&ctx.db, &self.work_id)?.full_title)
     }
 
@@ -120,7 +141,7 @@ impl Work {
     #[graphql(
         deprecated = "Please use Work `titles` field instead to get the correct title in a multilingual manner"
     )]
-    pub fn title(&self, ctx: &Context) -> FieldResult<String> {
+    pub fn title(&self, ctx: &Context) -> ThothResult<String> {
         Ok(Title::canonical_from_work_id(&ctx.db, &self.work_id)?.title)
     }
 
@@ -128,7 +149,7 @@ impl Work {
     #[graphql(
         deprecated = "Please use Work `titles` field instead to get the correct sub_title in a multilingual manner"
     )]
-    pub fn subtitle(&self, ctx: &Context) -> FieldResult<Option<String>> {
+    pub fn subtitle(&self, ctx: &Context) -> ThothResult<Option<String>> {
         Ok(Title::canonical_from_work_id(&ctx.db, &self.work_id)?.subtitle)
     }
 
@@ -138,7 +159,7 @@ impl Work {
     #[graphql(
         deprecated = "Please use Work `abstracts` field instead to get the correct short abstract in a multilingual manner"
     )]
-    pub fn short_abstract(&self, ctx: &Context) -> FieldResult<Option<String>> {
+    pub fn short_abstract(&self, ctx: &Context) -> ThothResult<Option<String>> {
         Ok(
             Abstract::short_canonical_from_work_id(&ctx.db, &self.work_id)
                 .map(|a| a.content)
@@ -152,7 +173,7 @@ impl Work {
     #[graphql(
         deprecated = "Please use Work `abstracts` field instead to get the correct long abstract in a multilingual manner"
     )]
-    pub fn long_abstract(&self, ctx: &Context) -> FieldResult<Option<String>> {
+    pub fn long_abstract(&self, ctx: &Context) -> ThothResult<Option<String>> {
         Ok(
             Abstract::long_canonical_from_work_id(&ctx.db, &self.work_id)
                 .map(|a| a.content)
@@ -187,7 +208,7 @@ impl Work {
             description = "If set, only shows results with this markup format"
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Vec<Title>> {
+    ) -> ThothResult<Vec<Title>> {
         let mut titles = Title::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -201,8 +222,7 @@ impl Work {
             vec![],
             None,
             None,
-        )
-        .map_err(FieldError::from)?;
+        )?;
 
         let markup = markup_format.ok_or(ThothError::MissingMarkupFormat)?;
         for title in titles.iter_mut() {
@@ -246,7 +266,7 @@ impl Work {
             description = "If set, only shows results with this markup format"
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Vec<Abstract>> {
+    ) -> ThothResult<Vec<Abstract>> {
         let mut abstracts = Abstract::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -260,8 +280,7 @@ impl Work {
             vec![],
             None,
             None,
-        )
-        .map_err(FieldError::from)?;
+        )?;
 
         let markup = markup_format.ok_or(ThothError::MissingMarkupFormat)?;
         for r#abstract in &mut abstracts {
@@ -403,7 +422,7 @@ impl Work {
             description = "Markup format used for rendering resources description",
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Option<String>> {
+    ) -> ThothResult<Option<String>> {
         self.resources_description
             .as_ref()
             .map(|value| {
@@ -414,7 +433,6 @@ impl Work {
                 )
             })
             .transpose()
-            .map_err(Into::into)
     }
 
     #[graphql(description = "URL of the work's cover image")]
@@ -461,9 +479,16 @@ impl Work {
         self.updated_at_with_relations
     }
 
+    #[graphql(
+        description = "Whether uploaded publication files are also served from a stable URL that always points to the latest version. When false, publication files are only served from immutable per-version URLs. Takes effect from the next upload or restore."
+    )]
+    pub fn expose_latest_file_url(&self) -> bool {
+        self.expose_latest_file_url
+    }
+
     #[graphql(description = "Get this work's imprint")]
-    pub fn imprint(&self, context: &Context) -> FieldResult<Imprint> {
-        Imprint::from_id(&context.db, &self.imprint_id).map_err(Into::into)
+    pub fn imprint(&self, context: &Context) -> ThothResult<Imprint> {
+        Imprint::from_id(&context.db, &self.imprint_id)
     }
 
     #[graphql(description = "Get contributions linked to this work")]
@@ -482,7 +507,7 @@ impl Work {
             description = "Specific types to filter by",
         )]
         contribution_types: Option<Vec<ContributionType>>,
-    ) -> FieldResult<Vec<Contribution>> {
+    ) -> ThothResult<Vec<Contribution>> {
         Contribution::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -497,7 +522,6 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -526,7 +550,7 @@ impl Work {
             description = "Specific relations to filter by"
         )]
         language_relations: Option<Vec<LanguageRelation>>,
-    ) -> FieldResult<Vec<Language>> {
+    ) -> ThothResult<Vec<Language>> {
         let mut relations = language_relations.unwrap_or_default();
         if let Some(relation) = language_relation {
             relations.push(relation);
@@ -545,7 +569,6 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get publications linked to this work")]
@@ -569,7 +592,7 @@ impl Work {
             description = "Specific types to filter by",
         )]
         publication_types: Option<Vec<PublicationType>>,
-    ) -> FieldResult<Vec<Publication>> {
+    ) -> ThothResult<Vec<Publication>> {
         Publication::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -584,7 +607,6 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get subjects linked to this work")]
@@ -608,7 +630,7 @@ impl Work {
             description = "Specific types to filter by",
         )]
         subject_types: Option<Vec<SubjectType>>,
-    ) -> FieldResult<Vec<Subject>> {
+    ) -> ThothResult<Vec<Subject>> {
         Subject::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -623,7 +645,74 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
+    }
+
+    #[graphql(description = "Get audiences for which this work is intended")]
+    pub fn audiences(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = AudienceOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<AudienceOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific audience codes to filter by"
+        )]
+        audience_codes: Option<Vec<AudienceCode>>,
+    ) -> ThothResult<Vec<Audience>> {
+        Audience::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            audience_codes.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(
+        description = "Get lifecycle dates of this work, such as its announcement, on-sale, reprint and out-of-print dates"
+    )]
+    pub fn work_dates(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = WorkDateOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<WorkDateOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific date types to filter by"
+        )]
+        date_types: Option<Vec<WorkDateType>>,
+    ) -> ThothResult<Vec<WorkDate>> {
+        WorkDate::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            date_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
     }
 
     #[graphql(description = "Get fundings linked to this work")]
@@ -637,7 +726,7 @@ impl Work {
             description = "The order in which to sort the results"
         )]
         order: Option<FundingOrderBy>,
-    ) -> FieldResult<Vec<Funding>> {
+    ) -> ThothResult<Vec<Funding>> {
         Funding::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -652,7 +741,6 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get issues linked to this work")]
@@ -666,7 +754,7 @@ impl Work {
             description = "The order in which to sort the results"
         )]
         order: Option<IssueOrderBy>,
-    ) -> FieldResult<Vec<Issue>> {
+    ) -> ThothResult<Vec<Issue>> {
         Issue::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -681,7 +769,6 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
     #[graphql(description = "Get other works related to this work")]
     pub fn relations(
@@ -699,7 +786,7 @@ impl Work {
             description = "Specific types to filter by",
         )]
         relation_types: Option<Vec<RelationType>>,
-    ) -> FieldResult<Vec<WorkRelation>> {
+    ) -> ThothResult<Vec<WorkRelation>> {
         WorkRelation::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -714,11 +801,10 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
     #[graphql(description = "Get the front cover file for this work")]
-    pub fn frontcover(&self, context: &Context) -> FieldResult<Option<File>> {
-        File::from_work_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn frontcover(&self, context: &Context) -> ThothResult<Option<File>> {
+        File::from_work_id(&context.db, &self.work_id)
     }
     #[graphql(description = "Get references cited by this work")]
     pub fn references(
@@ -736,7 +822,7 @@ impl Work {
             description = "The order in which to sort the results"
         )]
         order: Option<ReferenceOrderBy>,
-    ) -> FieldResult<Vec<Reference>> {
+    ) -> ThothResult<Vec<Reference>> {
         Reference::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -751,7 +837,6 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get additional resources linked to this work")]
@@ -765,7 +850,7 @@ impl Work {
             description = "Markup format used for rendering textual fields"
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Vec<AdditionalResource>> {
+    ) -> ThothResult<Vec<AdditionalResource>> {
         let mut additional_resources = AdditionalResource::all(
             &context.db,
             limit.unwrap_or(50),
@@ -803,7 +888,7 @@ impl Work {
         context: &Context,
         #[graphql(default = 50, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-    ) -> FieldResult<Vec<Award>> {
+    ) -> ThothResult<Vec<Award>> {
         Award::all(
             &context.db,
             limit.unwrap_or(50),
@@ -818,7 +903,6 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get endorsements linked to this work")]
@@ -827,7 +911,7 @@ impl Work {
         context: &Context,
         #[graphql(default = 50, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-    ) -> FieldResult<Vec<Endorsement>> {
+    ) -> ThothResult<Vec<Endorsement>> {
         Endorsement::all(
             &context.db,
             limit.unwrap_or(50),
@@ -842,7 +926,6 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get book reviews linked to this work")]
@@ -851,7 +934,7 @@ impl Work {
         context: &Context,
         #[graphql(default = 50, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-    ) -> FieldResult<Vec<BookReview>> {
+    ) -> ThothResult<Vec<BookReview>> {
         BookReview::all(
             &context.db,
             limit.unwrap_or(50),
@@ -866,12 +949,11 @@ impl Work {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get the featured video linked to this work")]
-    pub fn featured_video(&self, context: &Context) -> FieldResult<Option<WorkFeaturedVideo>> {
-        WorkFeaturedVideo::from_work_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn featured_video(&self, context: &Context) -> ThothResult<Option<WorkFeaturedVideo>> {
+        WorkFeaturedVideo::from_work_id(&context.db, &self.work_id)
     }
 }
 
@@ -1012,6 +1094,27 @@ impl Publication {
         self.accessibility_report_url.as_ref()
     }
 
+    #[graphql(
+        description = "URL of the license which applies to this publication, if different from the work's license"
+    )]
+    pub fn license(&self) -> Option<&String> {
+        self.license.as_ref()
+    }
+
+    #[graphql(
+        description = "Whether this publication is open, embargoed or restricted. If omitted, publications of openly licensed works are considered open"
+    )]
+    pub fn access_status(&self) -> Option<&AccessStatus> {
+        self.access_status.as_ref()
+    }
+
+    #[graphql(
+        description = "Date on which the embargo on this publication ends and it becomes open (embargoed publications only)"
+    )]
+    pub fn embargo_end_date(&self) -> Option<NaiveDate> {
+        self.embargo_end_date
+    }
+
     #[graphql(description = "Get prices linked to this publication")]
     pub fn prices(
         &self,
@@ -1028,7 +1131,11 @@ impl Publication {
             description = "Specific currencies to filter by"
         )]
         currency_codes: Option<Vec<CurrencyCode>>,
-    ) -> FieldResult<Vec<Price>> {
+        #[graphql(
+            description = "If set, only shows prices which apply on this date (prices with no effective dates always apply)"
+        )]
+        on_date: Option<NaiveDate>,
+    ) -> ThothResult<Vec<Price>> {
         Price::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1040,10 +1147,77 @@ impl Publication {
             None,
             currency_codes.unwrap_or_default(),
             vec![],
+            on_date,
+            None,
+        )
+    }
+
+    #[graphql(
+        description = "Get binding, format, feature and packaging details of this publication"
+    )]
+    pub fn product_form_details(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = ProductFormDetailOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<ProductFormDetailOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of product form detail to filter by"
+        )]
+        detail_types: Option<Vec<ProductFormDetailType>>,
+    ) -> ThothResult<Vec<ProductFormDetail>> {
+        ProductFormDetail::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.publication_id),
+            None,
+            detail_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Get sales rights linked to this publication")]
+    pub fn sales_rights(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = SalesRightsOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<SalesRightsOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of sales rights to filter by"
+        )]
+        sales_rights_types: Option<Vec<SalesRightsType>>,
+    ) -> ThothResult<Vec<SalesRights>> {
+        SalesRights::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.publication_id),
+            None,
+            sales_rights_types.unwrap_or_default(),
+            vec![],
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get locations linked to this publication")]
@@ -1062,7 +1236,7 @@ impl Publication {
             description = "Specific platforms to filter by"
         )]
         location_platforms: Option<Vec<LocationPlatform>>,
-    ) -> FieldResult<Vec<Location>> {
+    ) -> ThothResult<Vec<Location>> {
         Location::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1077,17 +1251,41 @@ impl Publication {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get the publication file for this publication")]
-    pub fn file(&self, context: &Context) -> FieldResult<Option<File>> {
-        File::from_publication_id(&context.db, &self.publication_id).map_err(Into::into)
+    pub fn file(&self, context: &Context) -> ThothResult<Option<File>> {
+        File::from_publication_id(&context.db, &self.publication_id)
+    }
+
+    #[graphql(
+        description = "Get every stored version of this publication's file, most recent first"
+    )]
+    pub fn file_versions(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+    ) -> ThothResult<Vec<FileVersion>> {
+        FileVersion::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            (),
+            vec![],
+            Some(self.publication_id),
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
     }
 
     #[graphql(description = "Get the work to which this publication belongs")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 }
 
@@ -1163,103 +1361,301 @@ impl File {
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
+
+    #[graphql(description = "Outcome of the most recent content validation of this file")]
+    pub fn validation_status(&self) -> FileValidationStatus {
+        self.validation_status
+    }
+
+    #[graphql(
+        name = "validationIssues",
+        description = "Errors and warnings found by the most recent content validation"
+    )]
+    pub fn validation_findings(&self) -> Vec<FileValidationIssue> {
+        self.validation_issues()
+    }
+
+    #[graphql(description = "Date and time at which the file contents were last validated")]
+    pub fn validated_at(&self) -> Option<Timestamp> {
+        self.validated_at
+    }
 }
 
-#[juniper::graphql_object(Context = Context, description = "An organisation that produces and distributes written texts.")]
-impl Publisher {
-    #[graphql(description = "Thoth ID of the publisher")]
-    pub fn publisher_id(&self) -> Uuid {
-        self.publisher_id
+#[juniper::graphql_object(
+    Context = Context,
+    description = "A retained version of a publication file, served from an immutable URL."
+)]
+impl FileVersion {
+    #[graphql(description = "Thoth ID of the file version")]
+    pub fn file_version_id(&self) -> &Uuid {
+        &self.file_version_id
     }
 
-    #[graphql(description = "Name of the publisher")]
-    pub fn publisher_name(&self) -> &String {
-        &self.publisher_name
+    #[graphql(description = "Thoth ID of the file this is a version of")]
+    pub fn file_id(&self) -> &Uuid {
+        &self.file_id
     }
 
-    #[graphql(description = "Short name of the publisher, if any (e.g. an abbreviation)")]
-    pub fn publisher_shortname(&self) -> Option<&String> {
-        self.publisher_shortname.as_ref()
+    #[graphql(description = "Thoth ID of the publication")]
+    pub fn publication_id(&self) -> &Uuid {
+        &self.publication_id
     }
 
-    #[graphql(description = "URL of the publisher's website")]
-    pub fn publisher_url(&self) -> Option<&String> {
-        self.publisher_url.as_ref()
+    #[graphql(description = "Sequential number of this version, starting at 1")]
+    pub fn version_number(&self) -> i32 {
+        self.version_number
     }
 
-    #[graphql(description = "Zitadel organisation ID associated with the publisher")]
-    pub fn zitadel_id(&self) -> Option<&String> {
-        self.zitadel_id.as_ref()
+    #[graphql(description = "S3 object key of this version")]
+    pub fn object_key(&self) -> &String {
+        &self.object_key
     }
 
-    #[graphql(
-        description = "Statement from the publisher on the accessibility of its texts for readers with impairments"
-    )]
-    pub fn accessibility_statement(&self) -> Option<&String> {
-        self.accessibility_statement.as_ref()
+    #[graphql(description = "Immutable CDN URL of this version")]
+    pub fn cdn_url(&self) -> &String {
+        &self.cdn_url
+    }
+
+    #[graphql(description = "MIME type used when serving the file")]
+    pub fn mime_type(&self) -> &String {
+        &self.mime_type
+    }
+
+    #[graphql(description = "Size of the file in bytes")]
+    pub fn bytes(&self) -> i32 {
+        // GraphQL does not support i64; files larger than 2GB will overflow.
+        self.bytes as i32
+    }
+
+    #[graphql(description = "SHA-256 checksum of the stored file")]
+    pub fn sha256(&self) -> &String {
+        &self.sha256
     }
 
     #[graphql(
-        description = "URL of the publisher's report on the accessibility of its texts for readers with impairments"
+        description = "ID of the user who uploaded or restored this version, if known. Versions backfilled from files stored before versioning have no uploader."
     )]
-    pub fn accessibility_report_url(&self) -> Option<&String> {
-        self.accessibility_report_url.as_ref()
+    pub fn uploaded_by(&self) -> Option<&String> {
+        self.uploaded_by.as_ref()
     }
 
-    #[graphql(description = "Date and time at which the publisher record was created")]
+    #[graphql(description = "Thoth ID of the version this one was restored from, if any")]
+    pub fn restored_from_file_version_id(&self) -> Option<&Uuid> {
+        self.restored_from_file_version_id.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which this version was stored")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the publisher record was last updated")]
-    pub fn updated_at(&self) -> Timestamp {
-        self.updated_at
+    #[graphql(description = "Get the file this is a version of")]
+    pub fn file(&self, context: &Context) -> ThothResult<File> {
+        File::from_id(&context.db, &self.file_id)
     }
+}
 
-    #[graphql(description = "Get imprints linked to this publisher")]
-    pub fn imprints(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-        #[graphql(
-            default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on imprint_name and imprint_url"
-        )]
-        filter: Option<String>,
-        #[graphql(
-           default = {
-                ImprintOrderBy {
-                    field: ImprintField::ImprintName,
-                    direction: Direction::Asc }
-            },
-            description = "The order in which to sort the results"
-        )]
-        order: Option<ImprintOrderBy>,
-    ) -> FieldResult<Vec<Imprint>> {
-        Imprint::all(
-            &context.db,
-            limit.unwrap_or_default(),
-            offset.unwrap_or_default(),
-            filter,
-            order.unwrap_or_default(),
-            vec![],
-            Some(self.publisher_id),
-            None,
-            vec![],
-            vec![],
-            None,
-            None,
-        )
-        .map_err(Into::into)
+#[juniper::graphql_object(
+    Context = Context,
+    description = "The result of checking a stored file against the object held in S3."
+)]
+impl FileAudit {
+    #[graphql(description = "Thoth ID of the file audit")]
+    pub fn file_audit_id(&self) -> &Uuid {
+        &self.file_audit_id
     }
 
-    #[graphql(description = "Get contacts linked to this publisher")]
-    pub fn contacts(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+    #[graphql(description = "ID shared by all audits recorded in the same run")]
+    pub fn audit_run_id(&self) -> &Uuid {
+        &self.audit_run_id
+    }
+
+    #[graphql(description = "Thoth ID of the audited file")]
+    pub fn file_id(&self) -> &Uuid {
+        &self.file_id
+    }
+
+    #[graphql(description = "Thoth ID of the imprint whose bucket was audited")]
+    pub fn imprint_id(&self) -> &Uuid {
+        &self.imprint_id
+    }
+
+    #[graphql(description = "S3 object key that was checked")]
+    pub fn object_key(&self) -> &String {
+        &self.object_key
+    }
+
+    #[graphql(description = "Size in bytes recorded in the file record")]
+    pub fn expected_bytes(&self) -> i32 {
+        // GraphQL does not support i64; files larger than 2GB will overflow.
+        self.expected_bytes as i32
+    }
+
+    #[graphql(description = "Size in bytes reported by S3")]
+    pub fn actual_bytes(&self) -> Option<i32> {
+        self.actual_bytes.map(|bytes| bytes as i32)
+    }
+
+    #[graphql(description = "SHA-256 checksum recorded in the file record")]
+    pub fn expected_sha256(&self) -> &String {
+        &self.expected_sha256
+    }
+
+    #[graphql(
+        description = "SHA-256 checksum of the stored object, if it was stored by S3 or computed during the audit"
+    )]
+    pub fn actual_sha256(&self) -> Option<&String> {
+        self.actual_sha256.as_ref()
+    }
+
+    #[graphql(description = "MIME type recorded in the file record")]
+    pub fn expected_mime_type(&self) -> &String {
+        &self.expected_mime_type
+    }
+
+    #[graphql(description = "Content type reported by S3")]
+    pub fn actual_mime_type(&self) -> Option<&String> {
+        self.actual_mime_type.as_ref()
+    }
+
+    #[graphql(description = "Whether the object was not found in S3")]
+    pub fn object_missing(&self) -> bool {
+        self.object_missing
+    }
+
+    #[graphql(description = "Whether the stored size differs from the file record")]
+    pub fn size_mismatch(&self) -> bool {
+        self.size_mismatch
+    }
+
+    #[graphql(description = "Whether the stored checksum differs from the file record")]
+    pub fn checksum_mismatch(&self) -> bool {
+        self.checksum_mismatch
+    }
+
+    #[graphql(description = "Whether the stored content type differs from the file record")]
+    pub fn mime_type_mismatch(&self) -> bool {
+        self.mime_type_mismatch
+    }
+
+    #[graphql(description = "Error encountered while checking the object, if any")]
+    pub fn error_message(&self) -> Option<&String> {
+        self.error_message.as_ref()
+    }
+
+    #[graphql(
+        name = "passed",
+        description = "Whether the stored object matched the file record on every check"
+    )]
+    pub fn audit_passed(&self) -> bool {
+        self.passed()
+    }
+
+    #[graphql(description = "Date and time at which the check was performed")]
+    pub fn checked_at(&self) -> Timestamp {
+        self.checked_at
+    }
+
+    #[graphql(description = "Get the audited file")]
+    pub fn file(&self, context: &Context) -> ThothResult<File> {
+        File::from_id(&context.db, &self.file_id)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "An organisation that produces and distributes written texts.")]
+impl Publisher {
+    #[graphql(description = "Thoth ID of the publisher")]
+    pub fn publisher_id(&self) -> Uuid {
+        self.publisher_id
+    }
+
+    #[graphql(description = "Name of the publisher")]
+    pub fn publisher_name(&self) -> &String {
+        &self.publisher_name
+    }
+
+    #[graphql(description = "Short name of the publisher, if any (e.g. an abbreviation)")]
+    pub fn publisher_shortname(&self) -> Option<&String> {
+        self.publisher_shortname.as_ref()
+    }
+
+    #[graphql(description = "URL of the publisher's website")]
+    pub fn publisher_url(&self) -> Option<&String> {
+        self.publisher_url.as_ref()
+    }
+
+    #[graphql(description = "Zitadel organisation ID associated with the publisher")]
+    pub fn zitadel_id(&self) -> Option<&String> {
+        self.zitadel_id.as_ref()
+    }
+
+    #[graphql(
+        description = "Statement from the publisher on the accessibility of its texts for readers with impairments"
+    )]
+    pub fn accessibility_statement(&self) -> Option<&String> {
+        self.accessibility_statement.as_ref()
+    }
+
+    #[graphql(
+        description = "URL of the publisher's report on the accessibility of its texts for readers with impairments"
+    )]
+    pub fn accessibility_report_url(&self) -> Option<&String> {
+        self.accessibility_report_url.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which the publisher record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the publisher record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get imprints linked to this publisher")]
+    pub fn imprints(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on imprint_name and imprint_url"
+        )]
+        filter: Option<String>,
+        #[graphql(
+           default = {
+                ImprintOrderBy {
+                    field: ImprintField::ImprintName,
+                    direction: Direction::Asc }
+            },
+            description = "The order in which to sort the results"
+        )]
+        order: Option<ImprintOrderBy>,
+    ) -> ThothResult<Vec<Imprint>> {
+        Imprint::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.publisher_id),
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Get contacts linked to this publisher")]
+    pub fn contacts(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
             default = ContactOrderBy::default(),
             description = "The order in which to sort the results"
@@ -1270,7 +1666,7 @@ impl Publisher {
             description = "Specific types to filter by",
         )]
         contact_types: Option<Vec<ContactType>>,
-    ) -> FieldResult<Vec<Contact>> {
+    ) -> ThothResult<Vec<Contact>> {
         Contact::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1285,7 +1681,6 @@ impl Publisher {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 }
 
@@ -1320,19 +1715,19 @@ impl Imprint {
     }
 
     #[graphql(description = "S3 bucket used for files belonging to this imprint")]
-    pub fn s3_bucket(&self, context: &Context) -> FieldResult<Option<&str>> {
+    pub fn s3_bucket(&self, context: &Context) -> ThothResult<Option<&str>> {
         context.require_superuser()?;
         Ok(self.s3_bucket.as_deref())
     }
 
     #[graphql(description = "CDN domain used for files belonging to this imprint")]
-    pub fn cdn_domain(&self, context: &Context) -> FieldResult<Option<&str>> {
+    pub fn cdn_domain(&self, context: &Context) -> ThothResult<Option<&str>> {
         context.require_superuser()?;
         Ok(self.cdn_domain.as_deref())
     }
 
     #[graphql(description = "CloudFront distribution ID used for files belonging to this imprint")]
-    pub fn cloudfront_dist_id(&self, context: &Context) -> FieldResult<Option<&str>> {
+    pub fn cloudfront_dist_id(&self, context: &Context) -> ThothResult<Option<&str>> {
         context.require_superuser()?;
         Ok(self.cloudfront_dist_id.as_deref())
     }
@@ -1352,6 +1747,13 @@ impl Imprint {
         self.default_locale.as_ref()
     }
 
+    #[graphql(
+        description = "Whether file uploads that fail content validation (e.g. a structurally broken EPUB) are rejected rather than stored with a failed validation status"
+    )]
+    pub fn reject_invalid_files(&self) -> bool {
+        self.reject_invalid_files
+    }
+
     #[graphql(description = "Date and time at which the imprint record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
@@ -1363,8 +1765,8 @@ impl Imprint {
     }
 
     #[graphql(description = "Get the publisher to which this imprint belongs")]
-    pub fn publisher(&self, context: &Context) -> FieldResult<Publisher> {
-        Publisher::from_id(&context.db, &self.publisher_id).map_err(Into::into)
+    pub fn publisher(&self, context: &Context) -> ThothResult<Publisher> {
+        Publisher::from_id(&context.db, &self.publisher_id)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1405,7 +1807,7 @@ impl Imprint {
             description = "Only show results with a publication date either before (less than) or after (greater than) the specified timestamp"
         )]
         updated_at_with_relations: Option<TimeExpression>,
-    ) -> FieldResult<Vec<Work>> {
+    ) -> ThothResult<Vec<Work>> {
         let mut statuses = work_statuses.unwrap_or_default();
         if let Some(status) = work_status {
             statuses.push(status);
@@ -1424,7 +1826,6 @@ impl Imprint {
             publication_date,
             updated_at_with_relations,
         )
-        .map_err(Into::into)
     }
 }
 
@@ -1490,7 +1891,7 @@ impl Contributor {
             description = "Specific types to filter by",
         )]
         contribution_types: Option<Vec<ContributionType>>,
-    ) -> FieldResult<Vec<Contribution>> {
+    ) -> ThothResult<Vec<Contribution>> {
         Contribution::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1505,7 +1906,41 @@ impl Contributor {
             None,
             None,
         )
-        .map_err(Into::into)
+    }
+
+    #[graphql(
+        description = "Get variants of the contributor's name, such as transliterations into other scripts"
+    )]
+    pub fn name_variants(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = NameVariantOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<NameVariantOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific variant types to filter by",
+        )]
+        variant_types: Option<Vec<NameVariantType>>,
+    ) -> ThothResult<Vec<NameVariant>> {
+        NameVariant::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.contributor_id),
+            None,
+            variant_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
     }
 }
 
@@ -1565,7 +2000,7 @@ impl Contribution {
             description = "If set, only shows results with this markup format"
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Vec<Biography>> {
+    ) -> ThothResult<Vec<Biography>> {
         let mut biographies = Biography::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1579,8 +2014,7 @@ impl Contribution {
             vec![],
             None,
             None,
-        )
-        .map_err(FieldError::from)?;
+        )?;
 
         let markup = markup_format.ok_or(ThothError::MissingMarkupFormat)?;
         for biography in &mut biographies {
@@ -1595,7 +2029,7 @@ impl Contribution {
     #[graphql(
         deprecated = "Please use Contribution `biographies` field instead to get the correct biography in a multilingual manner"
     )]
-    pub fn biography(&self, ctx: &Context) -> FieldResult<Option<String>> {
+    pub fn biography(&self, ctx: &Context) -> ThothResult<Option<String>> {
         Ok(
             Biography::canonical_from_contribution_id(&ctx.db, &self.contribution_id)
                 .map(|a| a.content)
@@ -1642,13 +2076,13 @@ impl Contribution {
     }
 
     #[graphql(description = "Get the work in which the contribution appears")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 
     #[graphql(description = "Get the contributor who created the contribution")]
-    pub fn contributor(&self, context: &Context) -> FieldResult<Contributor> {
-        Contributor::from_id(&context.db, &self.contributor_id).map_err(Into::into)
+    pub fn contributor(&self, context: &Context) -> ThothResult<Contributor> {
+        Contributor::from_id(&context.db, &self.contributor_id)
     }
 
     #[graphql(description = "Get affiliations linked to this contribution")]
@@ -1662,7 +2096,7 @@ impl Contribution {
             description = "The order in which to sort the results"
         )]
         order: Option<AffiliationOrderBy>,
-    ) -> FieldResult<Vec<Affiliation>> {
+    ) -> ThothResult<Vec<Affiliation>> {
         Affiliation::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1677,7 +2111,72 @@ impl Contribution {
             None,
             None,
         )
-        .map_err(Into::into)
+    }
+
+    #[graphql(description = "Get variants of the name as credited in this contribution")]
+    pub fn name_variants(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = NameVariantOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<NameVariantOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific variant types to filter by",
+        )]
+        variant_types: Option<Vec<NameVariantType>>,
+    ) -> ThothResult<Vec<NameVariant>> {
+        NameVariant::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            None,
+            Some(self.contribution_id),
+            variant_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Get the CRediT roles played by the contributor in this contribution")]
+    pub fn credit_roles(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = CreditRoleOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<CreditRoleOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific CRediT roles to filter by",
+        )]
+        role_types: Option<Vec<CreditRoleType>>,
+    ) -> ThothResult<Vec<CreditRole>> {
+        CreditRole::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.contribution_id),
+            None,
+            role_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
     }
 }
 
@@ -1732,271 +2231,619 @@ impl Series {
         self.imprint_id
     }
 
-    #[graphql(description = "Date and time at which the series record was created")]
+    #[graphql(description = "Date and time at which the series record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the series record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the imprint linked to this series")]
+    pub fn imprint(&self, context: &Context) -> ThothResult<Imprint> {
+        Imprint::from_id(&context.db, &self.imprint_id)
+    }
+
+    #[graphql(description = "Get issues linked to this series")]
+    pub fn issues(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = IssueOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<IssueOrderBy>,
+    ) -> ThothResult<Vec<Issue>> {
+        Issue::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            None,
+            Some(self.series_id),
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A work published as a number in a periodical.")]
+impl Issue {
+    #[graphql(description = "Thoth ID of the issue")]
+    pub fn issue_id(&self) -> Uuid {
+        self.issue_id
+    }
+
+    #[graphql(description = "Thoth ID of the work represented by the issue")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    #[graphql(description = "Thoth ID of the series to which the issue belongs")]
+    pub fn series_id(&self) -> Uuid {
+        self.series_id
+    }
+
+    #[graphql(
+        description = "Number representing this issue's position in an ordered list of issues within the series (does not have to correspond to published issue number)"
+    )]
+    pub fn issue_ordinal(&self) -> &i32 {
+        &self.issue_ordinal
+    }
+
+    #[graphql(description = "Published issue number given to this issue within the series, if any")]
+    pub fn issue_number(&self) -> Option<&i32> {
+        self.issue_number.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which the issue record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the issue record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the series to which the issue belongs")]
+    pub fn series(&self, context: &Context) -> ThothResult<Series> {
+        Series::from_id(&context.db, &self.series_id)
+    }
+
+    #[graphql(description = "Get the work represented by the issue")]
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "Description of a work's language.")]
+impl Language {
+    #[graphql(description = "Thoth ID of the language")]
+    pub fn language_id(&self) -> Uuid {
+        self.language_id
+    }
+
+    #[graphql(description = "Thoth ID of the work which has this language")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    #[graphql(description = "Three-letter ISO 639 code representing the language")]
+    pub fn language_code(&self) -> &LanguageCode {
+        &self.language_code
+    }
+
+    #[graphql(description = "Relation between this language and the original language of the text")]
+    pub fn language_relation(&self) -> &LanguageRelation {
+        &self.language_relation
+    }
+
+    #[graphql(description = "Date and time at which the language record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the language record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the work which has this language")]
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A location, such as a web shop or distribution platform, where a publication can be acquired or viewed.")]
+impl Location {
+    #[graphql(description = "Thoth ID of the location")]
+    pub fn location_id(&self) -> Uuid {
+        self.location_id
+    }
+
+    #[graphql(description = "Thoth ID of the publication linked to this location")]
+    pub fn publication_id(&self) -> Uuid {
+        self.publication_id
+    }
+
+    #[graphql(description = "Public-facing URL via which the publication can be accessed")]
+    pub fn landing_page(&self) -> Option<&String> {
+        self.landing_page.as_ref()
+    }
+
+    #[graphql(description = "Direct link to the full text file")]
+    pub fn full_text_url(&self) -> Option<&String> {
+        self.full_text_url.as_ref()
+    }
+
+    #[graphql(description = "Platform where the publication is hosted or can be acquired")]
+    pub fn location_platform(&self) -> &LocationPlatform {
+        &self.location_platform
+    }
+
+    #[graphql(
+        description = "Whether this is the canonical location for this specific publication (e.g. the main platform on which the print version is sold, or the official version of record hosted on the publisher's own web server)"
+    )]
+    pub fn canonical(&self) -> bool {
+        self.canonical
+    }
+
+    #[graphql(description = "Date and time at which the location record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the location record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the publication linked to this location")]
+    pub fn publication(&self, context: &Context) -> ThothResult<Publication> {
+        Publication::from_id(&context.db, &self.publication_id)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "The amount of money, in any currency, that a publication costs.")]
+impl Price {
+    #[graphql(description = "Thoth ID of the price")]
+    pub fn price_id(&self) -> Uuid {
+        self.price_id
+    }
+
+    #[graphql(description = "Thoth ID of the publication linked to this price")]
+    pub fn publication_id(&self) -> Uuid {
+        self.publication_id
+    }
+
+    #[graphql(
+        description = "Three-letter ISO 4217 code representing the currency used in this price"
+    )]
+    pub fn currency_code(&self) -> &CurrencyCode {
+        &self.currency_code
+    }
+
+    #[graphql(description = "Value of the publication in the specified currency")]
+    pub fn unit_price(&self) -> f64 {
+        self.unit_price
+    }
+
+    #[graphql(description = "Type of the price (recommended retail or agency)")]
+    pub fn price_type(&self) -> &PriceType {
+        &self.price_type
+    }
+
+    #[graphql(description = "Whether the unit price includes any applicable sales tax")]
+    pub fn tax_inclusive(&self) -> bool {
+        self.tax_inclusive
+    }
+
+    #[graphql(
+        description = "Countries in which this price applies. If null, the price applies worldwide"
+    )]
+    pub fn countries_included(&self) -> Option<&Vec<CountryCode>> {
+        self.countries_included.as_ref()
+    }
+
+    #[graphql(description = "Date from which this price applies")]
+    pub fn effective_from(&self) -> Option<NaiveDate> {
+        self.effective_from
+    }
+
+    #[graphql(description = "Date until which this price applies")]
+    pub fn effective_until(&self) -> Option<NaiveDate> {
+        self.effective_until
+    }
+
+    #[graphql(description = "Date and time at which the price record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the price record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the publication linked to this price")]
+    pub fn publication(&self, context: &Context) -> ThothResult<Publication> {
+        Publication::from_id(&context.db, &self.publication_id)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "An aspect of a publication's form, such as its binding, a feature like cover colour, or its packaging.")]
+impl ProductFormDetail {
+    #[graphql(description = "Thoth ID of the product form detail")]
+    pub fn product_form_detail_id(&self) -> Uuid {
+        self.product_form_detail_id
+    }
+
+    #[graphql(description = "Thoth ID of the publication linked to this product form detail")]
+    pub fn publication_id(&self) -> Uuid {
+        self.publication_id
+    }
+
+    #[graphql(description = "Aspect of the publication's form being described")]
+    pub fn detail_type(&self) -> &ProductFormDetailType {
+        &self.detail_type
+    }
+
+    #[graphql(
+        description = "ONIX code for the detail: a code list 175 value for form details, a code list 79 feature type for features, or a code list 80 value for packaging"
+    )]
+    pub fn detail_code(&self) -> &String {
+        &self.detail_code
+    }
+
+    #[graphql(
+        description = "Value of a feature, e.g. an ONIX code list 98 colour for cover colour, or the font size in points for text font"
+    )]
+    pub fn feature_value(&self) -> Option<&String> {
+        self.feature_value.as_ref()
+    }
+
+    #[graphql(description = "Free text description of a feature, e.g. the name of a text font")]
+    pub fn feature_description(&self) -> Option<&String> {
+        self.feature_description.as_ref()
+    }
+
+    #[graphql(
+        description = "English name of the detail code, including the colour for colour features"
+    )]
+    pub fn detail_label(&self) -> Option<String> {
+        self.label()
+    }
+
+    #[graphql(description = "Date and time at which the product form detail record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(
+        description = "Date and time at which the product form detail record was last updated"
+    )]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the publication linked to this product form detail")]
+    pub fn publication(&self, context: &Context) -> ThothResult<Publication> {
+        Publication::from_id(&context.db, &self.publication_id)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "The rights held to sell a publication in a given territory.")]
+impl SalesRights {
+    #[graphql(description = "Thoth ID of the sales rights")]
+    pub fn sales_rights_id(&self) -> Uuid {
+        self.sales_rights_id
+    }
+
+    #[graphql(description = "Thoth ID of the publication linked to these sales rights")]
+    pub fn publication_id(&self) -> Uuid {
+        self.publication_id
+    }
+
+    #[graphql(description = "Type of rights held in the territory")]
+    pub fn sales_rights_type(&self) -> &SalesRightsType {
+        &self.sales_rights_type
+    }
+
+    #[graphql(
+        description = "Countries to which these rights apply. If null, the rights apply worldwide"
+    )]
+    pub fn countries_included(&self) -> Option<&Vec<CountryCode>> {
+        self.countries_included.as_ref()
+    }
+
+    #[graphql(
+        description = "Countries excluded from worldwide rights. Only permitted when no countries are included"
+    )]
+    pub fn countries_excluded(&self) -> &Vec<CountryCode> {
+        &self.countries_excluded
+    }
+
+    #[graphql(description = "Date and time at which the sales rights record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the sales rights record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the publication linked to these sales rights")]
+    pub fn publication(&self, context: &Context) -> ThothResult<Publication> {
+        Publication::from_id(&context.db, &self.publication_id)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A significant discipline or term related to a work.")]
+impl Subject {
+    #[graphql(description = "Thoth ID of the subject")]
+    pub fn subject_id(&self) -> &Uuid {
+        &self.subject_id
+    }
+
+    #[graphql(description = "Thoth ID of the work to which the subject is linked")]
+    pub fn work_id(&self) -> &Uuid {
+        &self.work_id
+    }
+
+    #[graphql(description = "Type of the subject (e.g. the subject category scheme being used)")]
+    pub fn subject_type(&self) -> &SubjectType {
+        &self.subject_type
+    }
+
+    #[graphql(description = "Code representing the subject within the specified type")]
+    pub fn subject_code(&self) -> &String {
+        &self.subject_code
+    }
+
+    #[graphql(
+        description = "Number representing this subject's position in an ordered list of subjects of the same type within the work (subjects of equal prominence can have the same number)"
+    )]
+    pub fn subject_ordinal(&self) -> &i32 {
+        &self.subject_ordinal
+    }
+
+    #[graphql(description = "Date and time at which the subject record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the series record was last updated")]
+    #[graphql(description = "Date and time at which the subject record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get the imprint linked to this series")]
-    pub fn imprint(&self, context: &Context) -> FieldResult<Imprint> {
-        Imprint::from_id(&context.db, &self.imprint_id).map_err(Into::into)
-    }
-
-    #[graphql(description = "Get issues linked to this series")]
-    pub fn issues(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-        #[graphql(
-            default = IssueOrderBy::default(),
-            description = "The order in which to sort the results"
-        )]
-        order: Option<IssueOrderBy>,
-    ) -> FieldResult<Vec<Issue>> {
-        Issue::all(
-            &context.db,
-            limit.unwrap_or_default(),
-            offset.unwrap_or_default(),
-            None,
-            order.unwrap_or_default(),
-            vec![],
-            None,
-            Some(self.series_id),
-            vec![],
-            vec![],
-            None,
-            None,
-        )
-        .map_err(Into::into)
+    #[graphql(description = "Get the work to which the subject is linked")]
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "A work published as a number in a periodical.")]
-impl Issue {
-    #[graphql(description = "Thoth ID of the issue")]
-    pub fn issue_id(&self) -> Uuid {
-        self.issue_id
+#[juniper::graphql_object(Context = Context, description = "A group of readers for which a work is intended, optionally narrowed to an age or grade range.")]
+impl Audience {
+    #[graphql(description = "Thoth ID of the audience")]
+    pub fn audience_id(&self) -> Uuid {
+        self.audience_id
     }
 
-    #[graphql(description = "Thoth ID of the work represented by the issue")]
+    #[graphql(description = "Thoth ID of the work to which the audience is linked")]
     pub fn work_id(&self) -> Uuid {
         self.work_id
     }
 
-    #[graphql(description = "Thoth ID of the series to which the issue belongs")]
-    pub fn series_id(&self) -> Uuid {
-        self.series_id
+    #[graphql(description = "Broad audience for which the work is intended")]
+    pub fn audience_code(&self) -> &AudienceCode {
+        &self.audience_code
+    }
+
+    #[graphql(description = "Scale in which the audience range is expressed, if any")]
+    pub fn audience_range_qualifier(&self) -> Option<&AudienceRangeQualifier> {
+        self.audience_range_qualifier.as_ref()
+    }
+
+    #[graphql(description = "How the audience range values should be read")]
+    pub fn audience_range_precision(&self) -> Option<&AudienceRangePrecision> {
+        self.audience_range_precision.as_ref()
     }
 
     #[graphql(
-        description = "Number representing this issue's position in an ordered list of issues within the series (does not have to correspond to published issue number)"
+        description = "Lower bound (or single value, if precision is EXACT) of the audience range"
     )]
-    pub fn issue_ordinal(&self) -> &i32 {
-        &self.issue_ordinal
+    pub fn audience_range_from(&self) -> Option<&String> {
+        self.audience_range_from.as_ref()
     }
 
-    #[graphql(description = "Published issue number given to this issue within the series, if any")]
-    pub fn issue_number(&self) -> Option<&i32> {
-        self.issue_number.as_ref()
+    #[graphql(description = "Upper bound of the audience range")]
+    pub fn audience_range_to(&self) -> Option<&String> {
+        self.audience_range_to.as_ref()
     }
 
-    #[graphql(description = "Date and time at which the issue record was created")]
+    #[graphql(description = "Date and time at which the audience record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the issue record was last updated")]
+    #[graphql(description = "Date and time at which the audience record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get the series to which the issue belongs")]
-    pub fn series(&self, context: &Context) -> FieldResult<Series> {
-        Series::from_id(&context.db, &self.series_id).map_err(Into::into)
-    }
-
-    #[graphql(description = "Get the work represented by the issue")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    #[graphql(description = "Get the work to which the audience is linked")]
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "Description of a work's language.")]
-impl Language {
-    #[graphql(description = "Thoth ID of the language")]
-    pub fn language_id(&self) -> Uuid {
-        self.language_id
+#[juniper::graphql_object(Context = Context, description = "A dated event in the lifecycle of a work, other than its publication or withdrawal.")]
+impl WorkDate {
+    #[graphql(description = "Thoth ID of the work date")]
+    pub fn work_date_id(&self) -> Uuid {
+        self.work_date_id
     }
 
-    #[graphql(description = "Thoth ID of the work which has this language")]
+    #[graphql(description = "Thoth ID of the work to which the date is linked")]
     pub fn work_id(&self) -> Uuid {
         self.work_id
     }
 
-    #[graphql(description = "Three-letter ISO 639 code representing the language")]
-    pub fn language_code(&self) -> &LanguageCode {
-        &self.language_code
+    #[graphql(description = "Event which took or will take place on this date")]
+    pub fn date_type(&self) -> &WorkDateType {
+        &self.date_type
     }
 
-    #[graphql(description = "Relation between this language and the original language of the text")]
-    pub fn language_relation(&self) -> &LanguageRelation {
-        &self.language_relation
+    #[graphql(description = "Date of the event")]
+    pub fn date(&self) -> NaiveDate {
+        self.date
     }
 
-    #[graphql(description = "Date and time at which the language record was created")]
+    #[graphql(description = "Date and time at which the work date record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the language record was last updated")]
+    #[graphql(description = "Date and time at which the work date record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get the work which has this language")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    #[graphql(description = "Get the work to which the date is linked")]
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "A location, such as a web shop or distribution platform, where a publication can be acquired or viewed.")]
-impl Location {
-    #[graphql(description = "Thoth ID of the location")]
-    pub fn location_id(&self) -> Uuid {
-        self.location_id
-    }
-
-    #[graphql(description = "Thoth ID of the publication linked to this location")]
-    pub fn publication_id(&self) -> Uuid {
-        self.publication_id
-    }
-
-    #[graphql(description = "Public-facing URL via which the publication can be accessed")]
-    pub fn landing_page(&self) -> Option<&String> {
-        self.landing_page.as_ref()
-    }
-
-    #[graphql(description = "Direct link to the full text file")]
-    pub fn full_text_url(&self) -> Option<&String> {
-        self.full_text_url.as_ref()
-    }
-
-    #[graphql(description = "Platform where the publication is hosted or can be acquired")]
-    pub fn location_platform(&self) -> &LocationPlatform {
-        &self.location_platform
+#[juniper::graphql_object(Context = Context, description = "An alternative form of a contributor's name, such as a transliteration, pseudonym or former name.")]
+impl NameVariant {
+    #[graphql(description = "Thoth ID of the name variant")]
+    pub fn name_variant_id(&self) -> Uuid {
+        self.name_variant_id
     }
 
     #[graphql(
-        description = "Whether this is the canonical location for this specific publication (e.g. the main platform on which the print version is sold, or the official version of record hosted on the publisher's own web server)"
+        description = "Thoth ID of the contributor to which the name variant is linked, if it applies to all of their contributions"
     )]
-    pub fn canonical(&self) -> bool {
-        self.canonical
+    pub fn contributor_id(&self) -> Option<Uuid> {
+        self.contributor_id
     }
 
-    #[graphql(description = "Date and time at which the location record was created")]
-    pub fn created_at(&self) -> Timestamp {
-        self.created_at
+    #[graphql(
+        description = "Thoth ID of the contribution to which the name variant is linked, if it only applies to that contribution"
+    )]
+    pub fn contribution_id(&self) -> Option<Uuid> {
+        self.contribution_id
     }
 
-    #[graphql(description = "Date and time at which the location record was last updated")]
-    pub fn updated_at(&self) -> Timestamp {
-        self.updated_at
+    #[graphql(description = "Relationship between this name and the contributor's recorded name")]
+    pub fn variant_type(&self) -> &NameVariantType {
+        &self.variant_type
     }
 
-    #[graphql(description = "Get the publication linked to this location")]
-    pub fn publication(&self, context: &Context) -> FieldResult<Publication> {
-        Publication::from_id(&context.db, &self.publication_id).map_err(Into::into)
+    #[graphql(
+        description = "ISO 15924 code of the script in which the name is written (e.g. Cyrl, Arab, Hans)"
+    )]
+    pub fn script_code(&self) -> Option<&String> {
+        self.script_code.as_ref()
     }
-}
 
-#[juniper::graphql_object(Context = Context, description = "The amount of money, in any currency, that a publication costs.")]
-impl Price {
-    #[graphql(description = "Thoth ID of the price")]
-    pub fn price_id(&self) -> Uuid {
-        self.price_id
+    #[graphql(description = "Locale in which this form of the name is used")]
+    pub fn locale_code(&self) -> Option<&LocaleCode> {
+        self.locale_code.as_ref()
     }
 
-    #[graphql(description = "Thoth ID of the publication linked to this price")]
-    pub fn publication_id(&self) -> Uuid {
-        self.publication_id
+    #[graphql(description = "Given or first name(s) in this form of the name")]
+    pub fn first_name(&self) -> Option<&String> {
+        self.first_name.as_ref()
     }
 
-    #[graphql(
-        description = "Three-letter ISO 4217 code representing the currency used in this price"
-    )]
-    pub fn currency_code(&self) -> &CurrencyCode {
-        &self.currency_code
+    #[graphql(description = "Family or surname in this form of the name")]
+    pub fn last_name(&self) -> &String {
+        &self.last_name
     }
 
-    #[graphql(description = "Value of the publication in the specified currency")]
-    pub fn unit_price(&self) -> f64 {
-        self.unit_price
+    #[graphql(description = "Full, serialized form of the name")]
+    pub fn full_name(&self) -> &String {
+        &self.full_name
     }
 
-    #[graphql(description = "Date and time at which the price record was created")]
+    #[graphql(description = "Date and time at which the name variant record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the price record was last updated")]
+    #[graphql(description = "Date and time at which the name variant record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get the publication linked to this price")]
-    pub fn publication(&self, context: &Context) -> FieldResult<Publication> {
-        Publication::from_id(&context.db, &self.publication_id).map_err(Into::into)
+    #[graphql(description = "Get the contributor to which the name variant is linked")]
+    pub fn contributor(&self, context: &Context) -> ThothResult<Option<Contributor>> {
+        self.contributor_id
+            .map(|contributor_id| Contributor::from_id(&context.db, &contributor_id))
+            .transpose()
+    }
+
+    #[graphql(description = "Get the contribution to which the name variant is linked")]
+    pub fn contribution(&self, context: &Context) -> ThothResult<Option<Contribution>> {
+        self.contribution_id
+            .map(|contribution_id| Contribution::from_id(&context.db, &contribution_id))
+            .transpose()
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "A significant discipline or term related to a work.")]
-impl Subject {
-    #[graphql(description = "Thoth ID of the subject")]
-    pub fn subject_id(&self) -> &Uuid {
-        &self.subject_id
+#[juniper::graphql_object(Context = Context, description = "A role played by a contributor in the research and writing of a work, as defined by the CRediT Contributor Roles Taxonomy.")]
+impl CreditRole {
+    #[graphql(description = "Thoth ID of the CRediT role")]
+    pub fn credit_role_id(&self) -> Uuid {
+        self.credit_role_id
     }
 
-    #[graphql(description = "Thoth ID of the work to which the subject is linked")]
-    pub fn work_id(&self) -> &Uuid {
-        &self.work_id
+    #[graphql(description = "Thoth ID of the contribution to which the CRediT role is linked")]
+    pub fn contribution_id(&self) -> Uuid {
+        self.contribution_id
     }
 
-    #[graphql(description = "Type of the subject (e.g. the subject category scheme being used)")]
-    pub fn subject_type(&self) -> &SubjectType {
-        &self.subject_type
+    #[graphql(description = "Role played by the contributor")]
+    pub fn role_type(&self) -> &CreditRoleType {
+        &self.role_type
     }
 
-    #[graphql(description = "Code representing the subject within the specified type")]
-    pub fn subject_code(&self) -> &String {
-        &self.subject_code
+    #[graphql(description = "Degree of the contributor's involvement in the role, if specified")]
+    pub fn degree(&self) -> Option<&CreditRoleDegree> {
+        self.degree.as_ref()
     }
 
-    #[graphql(
-        description = "Number representing this subject's position in an ordered list of subjects of the same type within the work (subjects of equal prominence can have the same number)"
-    )]
-    pub fn subject_ordinal(&self) -> &i32 {
-        &self.subject_ordinal
+    #[graphql(description = "URI identifying the role in the CRediT vocabulary")]
+    pub fn role_uri(&self) -> String {
+        self.role_type.uri()
     }
 
-    #[graphql(description = "Date and time at which the subject record was created")]
+    #[graphql(description = "Date and time at which the CRediT role record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the subject record was last updated")]
+    #[graphql(description = "Date and time at which the CRediT role record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get the work to which the subject is linked")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    #[graphql(description = "Get the contribution to which the CRediT role is linked")]
+    pub fn contribution(&self, context: &Context) -> ThothResult<Contribution> {
+        Contribution::from_id(&context.db, &self.contribution_id)
     }
 }
 
@@ -2043,6 +2890,13 @@ impl Institution {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Compare the institution against the local Crossref Funder Registry snapshot, with suggested corrections"
+    )]
+    pub fn funder_registry_check(&self, context: &Context) -> ThothResult<FunderRegistryCheck> {
+        FunderRegistryCheck::for_institution(&context.db, self)
+    }
+
     #[graphql(description = "Get fundings linked to this institution")]
     pub fn fundings(
         &self,
@@ -2054,7 +2908,7 @@ impl Institution {
             description = "The order in which to sort the results"
         )]
         order: Option<FundingOrderBy>,
-    ) -> FieldResult<Vec<Funding>> {
+    ) -> ThothResult<Vec<Funding>> {
         Funding::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -2069,7 +2923,6 @@ impl Institution {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get affiliations linked to this institution")]
@@ -2083,7 +2936,7 @@ impl Institution {
             description = "The order in which to sort the results"
         )]
         order: Option<AffiliationOrderBy>,
-    ) -> FieldResult<Vec<Affiliation>> {
+    ) -> ThothResult<Vec<Affiliation>> {
         Affiliation::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -2098,7 +2951,6 @@ impl Institution {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 }
 
@@ -2150,13 +3002,87 @@ impl Funding {
     }
 
     #[graphql(description = "Get the funded work")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 
     #[graphql(description = "Get the funding institution")]
-    pub fn institution(&self, context: &Context) -> FieldResult<Institution> {
-        Institution::from_id(&context.db, &self.institution_id).map_err(Into::into)
+    pub fn institution(&self, context: &Context) -> ThothResult<Institution> {
+        Institution::from_id(&context.db, &self.institution_id)
+    }
+
+    #[graphql(
+        description = "Compare the funding institution and grant number against the local Crossref Funder Registry snapshot, with suggested corrections"
+    )]
+    pub fn funder_registry_check(&self, context: &Context) -> ThothResult<FunderRegistryCheck> {
+        FunderRegistryCheck::for_funding(&context.db, self)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A funder listed in the local snapshot of the Crossref Funder Registry.")]
+impl RegisteredFunder {
+    #[graphql(
+        description = "Funder Registry DOI of the funder as full URL (e.g. https://doi.org/10.13039/100014013)"
+    )]
+    pub fn funder_doi(&self) -> &Doi {
+        &self.funder_doi
+    }
+
+    #[graphql(description = "Preferred name of the funder")]
+    pub fn funder_name(&self) -> &String {
+        &self.funder_name
+    }
+
+    #[graphql(description = "Alternative names and acronyms of the funder")]
+    pub fn alt_names(&self) -> &Vec<String> {
+        &self.alt_names
+    }
+
+    #[graphql(
+        description = "Three-letter ISO 3166-1 code representing the country where the funder is based"
+    )]
+    pub fn country_code(&self) -> Option<&CountryCode> {
+        self.country_code.as_ref()
+    }
+
+    #[graphql(description = "Research Organisation Registry identifier cross-linked to the funder")]
+    pub fn ror(&self) -> Option<&Ror> {
+        self.ror.as_ref()
+    }
+
+    #[graphql(description = "Funder Registry DOI of the funder's parent organisation")]
+    pub fn parent_doi(&self) -> Option<&Doi> {
+        self.parent_doi.as_ref()
+    }
+
+    #[graphql(description = "Funder Registry DOI which has replaced this one, if retired")]
+    pub fn replaced_by(&self) -> Option<&Doi> {
+        self.replaced_by.as_ref()
+    }
+
+    #[graphql(description = "Get the funder's parent organisation")]
+    pub fn parent(&self, context: &Context) -> ThothResult<Option<RegisteredFunder>> {
+        self.parent_funder(&context.db)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "Result of comparing a record against the local Crossref Funder Registry snapshot.")]
+impl FunderRegistryCheck {
+    #[graphql(description = "Outcome of the comparison")]
+    pub fn status(&self) -> FunderRegistryStatus {
+        self.status
+    }
+
+    #[graphql(
+        description = "Registry entry the record was matched against, whether by its DOI, ROR ID or name"
+    )]
+    pub fn funder(&self) -> Option<&RegisteredFunder> {
+        self.funder.as_ref()
+    }
+
+    #[graphql(description = "Corrections proposed from the registry entry")]
+    pub fn suggestions(&self) -> &Vec<FunderSuggestion> {
+        &self.suggestions
     }
 }
 
@@ -2182,11 +3108,9 @@ impl AdditionalResource {
         #[graphql(description = "Markup format used for rendering title")] markup_format: Option<
             MarkupFormat,
         >,
-    ) -> FieldResult<String> {
+    ) -> ThothResult<String> {
         match markup_format {
-            Some(markup) => {
-                convert_from_jats(&self.title, markup, ConversionLimit::Title).map_err(Into::into)
-            }
+            Some(markup) => convert_from_jats(&self.title, markup, ConversionLimit::Title),
             None => Ok(self.title.clone()),
         }
     }
@@ -2196,12 +3120,10 @@ impl AdditionalResource {
         &self,
         #[graphql(description = "Markup format used for rendering description")]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Option<String>> {
+    ) -> ThothResult<Option<String>> {
         match (&self.description, markup_format) {
             (Some(description), Some(markup)) => {
-                convert_from_jats(description, markup, ConversionLimit::Abstract)
-                    .map(Some)
-                    .map_err(Into::into)
+                convert_from_jats(description, markup, ConversionLimit::Abstract).map(Some)
             }
             (Some(description), None) => Ok(Some(description.clone())),
             (None, _) => Ok(None),
@@ -2258,14 +3180,13 @@ impl AdditionalResource {
     }
 
     #[graphql(description = "Get the work linked to this resource")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 
     #[graphql(description = "Get the hosted file linked to this resource")]
-    pub fn file(&self, context: &Context) -> FieldResult<Option<File>> {
+    pub fn file(&self, context: &Context) -> ThothResult<Option<File>> {
         File::from_additional_resource_id(&context.db, &self.additional_resource_id)
-            .map_err(Into::into)
     }
 }
 
@@ -2292,13 +3213,12 @@ impl Award {
             description = "Markup format used for rendering title",
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<String> {
+    ) -> ThothResult<String> {
         convert_from_jats(
             &self.title,
             markup_format.ok_or(ThothError::MissingMarkupFormat)?,
             ConversionLimit::Title,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "URL of the award page")]
@@ -2339,7 +3259,7 @@ impl Award {
             description = "Markup format used for rendering prize statement",
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Option<String>> {
+    ) -> ThothResult<Option<String>> {
         self.prize_statement
             .as_ref()
             .map(|prize_statement| {
@@ -2350,7 +3270,6 @@ impl Award {
                 )
             })
             .transpose()
-            .map_err(Into::into)
     }
 
     #[graphql(
@@ -2371,8 +3290,8 @@ impl Award {
     }
 
     #[graphql(description = "Get the work linked to this award")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 }
 
@@ -2404,7 +3323,7 @@ impl Endorsement {
             description = "Markup format used for rendering endorsement author role",
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Option<String>> {
+    ) -> ThothResult<Option<String>> {
         self.author_role
             .as_ref()
             .map(|author_role| {
@@ -2415,7 +3334,6 @@ impl Endorsement {
                 )
             })
             .transpose()
-            .map_err(Into::into)
     }
 
     #[graphql(
@@ -2443,7 +3361,7 @@ impl Endorsement {
             description = "Markup format used for rendering endorsement text",
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Option<String>> {
+    ) -> ThothResult<Option<String>> {
         self.text
             .as_ref()
             .map(|text| {
@@ -2454,7 +3372,6 @@ impl Endorsement {
                 )
             })
             .transpose()
-            .map_err(Into::into)
     }
 
     #[graphql(
@@ -2475,17 +3392,16 @@ impl Endorsement {
     }
 
     #[graphql(description = "Get the work linked to this endorsement")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 
     #[graphql(description = "Get the endorsement author's institution")]
-    pub fn author_institution(&self, context: &Context) -> FieldResult<Option<Institution>> {
+    pub fn author_institution(&self, context: &Context) -> ThothResult<Option<Institution>> {
         self.author_institution_id
             .as_ref()
             .map(|institution_id| Institution::from_id(&context.db, institution_id))
             .transpose()
-            .map_err(Into::into)
     }
 }
 
@@ -2512,7 +3428,7 @@ impl BookReview {
             description = "Markup format used for rendering review title",
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Option<String>> {
+    ) -> ThothResult<Option<String>> {
         self.title
             .as_ref()
             .map(|title| {
@@ -2523,7 +3439,6 @@ impl BookReview {
                 )
             })
             .transpose()
-            .map_err(Into::into)
     }
 
     #[graphql(description = "Name of the review author")]
@@ -2593,7 +3508,7 @@ impl BookReview {
             description = "Markup format used for rendering review text",
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Option<String>> {
+    ) -> ThothResult<Option<String>> {
         self.text
             .as_ref()
             .map(|text| {
@@ -2604,7 +3519,6 @@ impl BookReview {
                 )
             })
             .transpose()
-            .map_err(Into::into)
     }
 
     #[graphql(
@@ -2625,17 +3539,16 @@ impl BookReview {
     }
 
     #[graphql(description = "Get the work linked to this review")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 
     #[graphql(description = "Get the reviewer's institution")]
-    pub fn reviewer_institution(&self, context: &Context) -> FieldResult<Option<Institution>> {
+    pub fn reviewer_institution(&self, context: &Context) -> ThothResult<Option<Institution>> {
         self.reviewer_institution_id
             .as_ref()
             .map(|institution_id| Institution::from_id(&context.db, institution_id))
             .transpose()
-            .map_err(Into::into)
     }
 }
 
@@ -2685,14 +3598,13 @@ impl WorkFeaturedVideo {
     }
 
     #[graphql(description = "Get the work linked to this featured video")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 
     #[graphql(description = "Get the hosted file linked to this featured video")]
-    pub fn file(&self, context: &Context) -> FieldResult<Option<File>> {
+    pub fn file(&self, context: &Context) -> ThothResult<Option<File>> {
         File::from_work_featured_video_id(&context.db, &self.work_featured_video_id)
-            .map_err(Into::into)
     }
 }
 
@@ -2738,13 +3650,13 @@ impl Affiliation {
     }
 
     #[graphql(description = "Get the institution linked to this affiliation")]
-    pub fn institution(&self, context: &Context) -> FieldResult<Institution> {
-        Institution::from_id(&context.db, &self.institution_id).map_err(Into::into)
+    pub fn institution(&self, context: &Context) -> ThothResult<Institution> {
+        Institution::from_id(&context.db, &self.institution_id)
     }
 
     #[graphql(description = "Get the contribution linked to this affiliation")]
-    pub fn contribution(&self, context: &Context) -> FieldResult<Contribution> {
-        Contribution::from_id(&context.db, &self.contribution_id).map_err(Into::into)
+    pub fn contribution(&self, context: &Context) -> ThothResult<Contribution> {
+        Contribution::from_id(&context.db, &self.contribution_id)
     }
 }
 
@@ -2788,8 +3700,8 @@ impl WorkRelation {
     }
 
     #[graphql(description = "Get the other work in the relationship")]
-    pub fn related_work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.related_work_id).map_err(Into::into)
+    pub fn related_work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.related_work_id)
     }
 }
 
@@ -2938,8 +3850,8 @@ impl Reference {
     }
 
     #[graphql(description = "The citing work.")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 }
 
@@ -2981,8 +3893,8 @@ impl Title {
     }
 
     #[graphql(description = "Get the work to which the title is linked")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 }
 
@@ -3013,8 +3925,8 @@ impl Abstract {
         &self.abstract_type
     }
     #[graphql(description = "Get the work to which the abstract is linked")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(Into::into)
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
+        Work::from_id(&context.db, &self.work_id)
     }
 }
 
@@ -3046,14 +3958,14 @@ impl Biography {
     }
 
     #[graphql(description = "Get the work to which the biography is linked via contribution")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+    pub fn work(&self, context: &Context) -> ThothResult<Work> {
         let contribution = Contribution::from_id(&context.db, &self.contribution_id)?;
-        Work::from_id(&context.db, &contribution.work_id).map_err(Into::into)
+        Work::from_id(&context.db, &contribution.work_id)
     }
 
     #[graphql(description = "Get the contribution to which the biography is linked")]
-    pub fn contribution(&self, context: &Context) -> FieldResult<Contribution> {
-        Contribution::from_id(&context.db, &self.contribution_id).map_err(Into::into)
+    pub fn contribution(&self, context: &Context) -> ThothResult<Contribution> {
+        Contribution::from_id(&context.db, &self.contribution_id)
     }
 }
 
@@ -3090,7 +4002,88 @@ impl Contact {
     }
 
     #[graphql(description = "Get the publisher to which this contact belongs")]
-    pub fn publisher(&self, context: &Context) -> FieldResult<Publisher> {
-        Publisher::from_id(&context.db, &self.publisher_id).map_err(Into::into)
+    pub fn publisher(&self, context: &Context) -> ThothResult<Publisher> {
+        Publisher::from_id(&context.db, &self.publisher_id)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A Creative Commons license recognised by Thoth.")]
+impl CreativeCommonsLicense {
+    #[graphql(description = "Short identifier of the license, e.g. CC BY 4.0")]
+    pub fn code(&self) -> &str {
+        self.code
+    }
+
+    #[graphql(description = "Full name of the license")]
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    #[graphql(description = "Canonical URL of the license, to be used in license fields")]
+    pub fn url(&self) -> &str {
+        self.url
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A heading in a controlled subject vocabulary (Thema, BIC, BISAC or LCC).")]
+impl SubjectHeading {
+    #[graphql(description = "Subject scheme to which the heading belongs")]
+    pub fn subject_type(&self) -> &SubjectType {
+        &self.subject_type
+    }
+
+    #[graphql(description = "Code of the heading, as used in subject_code")]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    #[graphql(
+        description = "Name of the heading in the requested language, falling back to English. Null for headings which have no label yet."
+    )]
+    pub fn label(
+        &self,
+        #[graphql(default = LanguageCode::Eng, description = "Language of the label")]
+        language: Option<LanguageCode>,
+    ) -> Option<&str> {
+        self.label_for(language.unwrap_or(LanguageCode::Eng))
+    }
+
+    #[graphql(description = "Names of the heading in every available language")]
+    pub fn labels(&self) -> Vec<SubjectHeadingLabel> {
+        self.translations()
+    }
+
+    #[graphql(description = "Code of the broader heading containing this one, if any")]
+    pub fn parent_code(&self) -> Option<String> {
+        self.parent().map(|parent| parent.code)
+    }
+
+    #[graphql(description = "Narrower headings directly below this one")]
+    pub fn children(&self) -> Vec<SubjectHeading> {
+        self.narrower_headings()
+    }
+
+    #[graphql(
+        description = "Nearest equivalent code in another scheme (Thema, BIC or BISAC), according to the subject crosswalk"
+    )]
+    pub fn equivalent_code(
+        &self,
+        #[graphql(description = "Scheme in which to find the equivalent code")]
+        subject_type: SubjectType,
+    ) -> Option<String> {
+        self.equivalent(subject_type).map(|heading| heading.code)
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "Name of a subject heading in a given language.")]
+impl SubjectHeadingLabel {
+    #[graphql(description = "Language of the label")]
+    pub fn language(&self) -> &LanguageCode {
+        &self.language
+    }
+
+    #[graphql(description = "Text of the label")]
+    pub fn text(&self) -> &str {
+        self.text
     }
 }
@@ -1,4 +1,3 @@
-use juniper::FieldResult;
 use uuid::Uuid;
 
 use crate::graphql::Context;
@@ -9,35 +8,44 @@ use crate::model::{
         PatchAdditionalResource, ResourceType,
     },
     affiliation::{Affiliation, AffiliationPolicy, NewAffiliation, PatchAffiliation},
+    audience::{Audience, AudiencePolicy, NewAudience, PatchAudience},
     award::{Award, AwardPolicy, NewAward, PatchAward},
     biography::{Biography, BiographyPolicy, NewBiography, PatchBiography},
     book_review::{BookReview, BookReviewPolicy, NewBookReview, PatchBookReview},
     contact::{Contact, ContactPolicy, NewContact, PatchContact},
     contribution::{Contribution, ContributionPolicy, NewContribution, PatchContribution},
     contributor::{Contributor, ContributorPolicy, NewContributor, PatchContributor},
+    credit_role::{CreditRole, CreditRolePolicy, NewCreditRole, PatchCreditRole},
     endorsement::{Endorsement, EndorsementPolicy, NewEndorsement, PatchEndorsement},
     file::{
         CompleteFileUpload, File, FilePolicy, FileUpload, FileUploadResponse,
         NewAdditionalResourceFileUpload, NewFileUpload, NewFrontcoverFileUpload,
         NewPublicationFileUpload, NewWorkFeaturedVideoFileUpload,
     },
+    file_version::{FileVersion, FileVersionPolicy},
     funding::{Funding, FundingPolicy, NewFunding, PatchFunding},
     imprint::{Imprint, ImprintPolicy, NewImprint, PatchImprint},
     institution::{Institution, InstitutionPolicy, NewInstitution, PatchInstitution},
     issue::{Issue, IssuePolicy, NewIssue, PatchIssue},
     language::{Language, LanguagePolicy, NewLanguage, PatchLanguage},
     location::{Location, LocationPolicy, NewLocation, PatchLocation},
+    name_variant::{NameVariant, NameVariantPolicy, NewNameVariant, PatchNameVariant},
     price::{NewPrice, PatchPrice, Price, PricePolicy},
+    product_form_detail::{
+        NewProductFormDetail, PatchProductFormDetail, ProductFormDetail, ProductFormDetailPolicy,
+    },
     publication::{
         NewPublication, PatchPublication, Publication, PublicationPolicy, PublicationProperties,
     },
     publisher::{NewPublisher, PatchPublisher, Publisher, PublisherPolicy},
     r#abstract::{Abstract, AbstractPolicy, NewAbstract, PatchAbstract},
     reference::{NewReference, PatchReference, Reference, ReferencePolicy},
+    sales_rights::{NewSalesRights, PatchSalesRights, SalesRights, SalesRightsPolicy},
     series::{NewSeries, PatchSeries, Series, SeriesPolicy},
     subject::{NewSubject, PatchSubject, Subject, SubjectPolicy},
     title::{convert_title_to_jats, NewTitle, PatchTitle, Title, TitlePolicy},
     work::{NewWork, PatchWork, Work, WorkPolicy},
+    work_date::{NewWorkDate, PatchWorkDate, WorkDate, WorkDatePolicy},
     work_featured_video::{
         NewWorkFeaturedVideo, PatchWorkFeaturedVideo, WorkFeaturedVideo, WorkFeaturedVideoPolicy,
     },
@@ -49,11 +57,12 @@ use crate::policy::{
 };
 use crate::storage::{
     additional_resource_cleanup_plan, build_cdn_url, copy_temp_object_to_final, delete_object,
-    head_object, probe_video_dimensions, publication_cleanup_plan, reconcile_replaced_object,
-    run_cleanup_plan_sync, temp_key, work_cleanup_plan, work_featured_video_cleanup_plan,
-    StorageConfig,
+    head_object, probe_video_dimensions, publication_cleanup_plan, publish_publication_file,
+    reconcile_replaced_object, run_cleanup_plan_sync, temp_key, validate_stored_object,
+    work_cleanup_plan, work_featured_video_cleanup_plan, PublicationFileSource, StorageConfig,
+    ValidationPipeline,
 };
-use thoth_errors::ThothError;
+use thoth_errors::{ThothError, ThothResult};
 
 pub struct MutationRoot;
 
@@ -63,82 +72,82 @@ impl MutationRoot {
     fn create_work(
         context: &Context,
         #[graphql(description = "Values for work to be created")] data: NewWork,
-    ) -> FieldResult<Work> {
+    ) -> ThothResult<Work> {
         WorkPolicy::can_create(context, &data, ())?;
-        Work::create(&context.db, &data).map_err(Into::into)
+        Work::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new publisher with the specified values")]
     fn create_publisher(
         context: &Context,
         #[graphql(description = "Values for publisher to be created")] data: NewPublisher,
-    ) -> FieldResult<Publisher> {
+    ) -> ThothResult<Publisher> {
         PublisherPolicy::can_create(context, &data, ())?;
-        Publisher::create(&context.db, &data).map_err(Into::into)
+        Publisher::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new imprint with the specified values")]
     fn create_imprint(
         context: &Context,
         #[graphql(description = "Values for imprint to be created")] data: NewImprint,
-    ) -> FieldResult<Imprint> {
+    ) -> ThothResult<Imprint> {
         ImprintPolicy::can_create(context, &data, ())?;
-        Imprint::create(&context.db, &data).map_err(Into::into)
+        Imprint::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new contributor with the specified values")]
     fn create_contributor(
         context: &Context,
         #[graphql(description = "Values for contributor to be created")] data: NewContributor,
-    ) -> FieldResult<Contributor> {
+    ) -> ThothResult<Contributor> {
         ContributorPolicy::can_create(context, &data, ())?;
-        Contributor::create(&context.db, &data).map_err(Into::into)
+        Contributor::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new contribution with the specified values")]
     fn create_contribution(
         context: &Context,
         #[graphql(description = "Values for contribution to be created")] data: NewContribution,
-    ) -> FieldResult<Contribution> {
+    ) -> ThothResult<Contribution> {
         ContributionPolicy::can_create(context, &data, ())?;
-        Contribution::create(&context.db, &data).map_err(Into::into)
+        Contribution::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new publication with the specified values")]
     fn create_publication(
         context: &Context,
         #[graphql(description = "Values for publication to be created")] data: NewPublication,
-    ) -> FieldResult<Publication> {
+    ) -> ThothResult<Publication> {
         let data = data.into_normalised()?;
         PublicationPolicy::can_create(context, &data, ())?;
-        Publication::create(&context.db, &data).map_err(Into::into)
+        Publication::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new series with the specified values")]
     fn create_series(
         context: &Context,
         #[graphql(description = "Values for series to be created")] data: NewSeries,
-    ) -> FieldResult<Series> {
+    ) -> ThothResult<Series> {
         SeriesPolicy::can_create(context, &data, ())?;
-        Series::create(&context.db, &data).map_err(Into::into)
+        Series::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new issue with the specified values")]
     fn create_issue(
         context: &Context,
         #[graphql(description = "Values for issue to be created")] data: NewIssue,
-    ) -> FieldResult<Issue> {
+    ) -> ThothResult<Issue> {
         IssuePolicy::can_create(context, &data, ())?;
-        Issue::create(&context.db, &data).map_err(Into::into)
+        Issue::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new language with the specified values")]
     fn create_language(
         context: &Context,
         #[graphql(description = "Values for language to be created")] data: NewLanguage,
-    ) -> FieldResult<Language> {
+    ) -> ThothResult<Language> {
         LanguagePolicy::can_create(context, &data, ())?;
-        Language::create(&context.db, &data).map_err(Into::into)
+        Language::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new title with the specified values")]
@@ -148,13 +157,13 @@ impl MutationRoot {
             MarkupFormat,
         >,
         #[graphql(description = "Values for title to be created")] mut data: NewTitle,
-    ) -> FieldResult<Title> {
+    ) -> ThothResult<Title> {
         TitlePolicy::can_create(context, &data, markup_format)?;
 
         let markup = markup_format.expect("Validated by policy");
         convert_title_to_jats(&mut data, markup)?;
 
-        Title::create(&context.db, &data).map_err(Into::into)
+        Title::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new abstract with the specified values")]
@@ -164,13 +173,13 @@ impl MutationRoot {
             MarkupFormat,
         >,
         #[graphql(description = "Values for abstract to be created")] mut data: NewAbstract,
-    ) -> FieldResult<Abstract> {
+    ) -> ThothResult<Abstract> {
         AbstractPolicy::can_create(context, &data, markup_format)?;
 
         let markup = markup_format.expect("Validated by policy");
         data.content = convert_to_jats(data.content, markup, ConversionLimit::Abstract)?;
 
-        Abstract::create(&context.db, &data).map_err(Into::into)
+        Abstract::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new biography with the specified values")]
@@ -180,85 +189,140 @@ impl MutationRoot {
             MarkupFormat,
         >,
         #[graphql(description = "Values for biography to be created")] mut data: NewBiography,
-    ) -> FieldResult<Biography> {
+    ) -> ThothResult<Biography> {
         BiographyPolicy::can_create(context, &data, markup_format)?;
 
         let markup = markup_format.expect("Validated by policy");
         data.content = convert_to_jats(data.content, markup, ConversionLimit::Biography)?;
 
-        Biography::create(&context.db, &data).map_err(Into::into)
+        Biography::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new institution with the specified values")]
     fn create_institution(
         context: &Context,
         #[graphql(description = "Values for institution to be created")] data: NewInstitution,
-    ) -> FieldResult<Institution> {
+    ) -> ThothResult<Institution> {
         InstitutionPolicy::can_create(context, &data, ())?;
-        Institution::create(&context.db, &data).map_err(Into::into)
+        Institution::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new funding with the specified values")]
     fn create_funding(
         context: &Context,
         #[graphql(description = "Values for funding to be created")] data: NewFunding,
-    ) -> FieldResult<Funding> {
+    ) -> ThothResult<Funding> {
         FundingPolicy::can_create(context, &data, ())?;
-        Funding::create(&context.db, &data).map_err(Into::into)
+        Funding::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new location with the specified values")]
     fn create_location(
         context: &Context,
         #[graphql(description = "Values for location to be created")] data: NewLocation,
-    ) -> FieldResult<Location> {
+    ) -> ThothResult<Location> {
         LocationPolicy::can_create(context, &data, ())?;
-        Location::create(&context.db, &data).map_err(Into::into)
+        Location::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new price with the specified values")]
     fn create_price(
         context: &Context,
         #[graphql(description = "Values for price to be created")] data: NewPrice,
-    ) -> FieldResult<Price> {
+    ) -> ThothResult<Price> {
         PricePolicy::can_create(context, &data, ())?;
-        Price::create(&context.db, &data).map_err(Into::into)
+        Price::create(&context.db, &data)
+    }
+
+    #[graphql(description = "Create a new product form detail with the specified values")]
+    fn create_product_form_detail(
+        context: &Context,
+        #[graphql(description = "Values for product form detail to be created")]
+        data: NewProductFormDetail,
+    ) -> ThothResult<ProductFormDetail> {
+        ProductFormDetailPolicy::can_create(context, &data, ())?;
+        ProductFormDetail::create(&context.db, &data)
+    }
+
+    #[graphql(description = "Create new sales rights with the specified values")]
+    fn create_sales_rights(
+        context: &Context,
+        #[graphql(description = "Values for sales rights to be created")] data: NewSalesRights,
+    ) -> ThothResult<SalesRights> {
+        SalesRightsPolicy::can_create(context, &data, ())?;
+        SalesRights::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new subject with the specified values")]
     fn create_subject(
         context: &Context,
         #[graphql(description = "Values for subject to be created")] data: NewSubject,
-    ) -> FieldResult<Subject> {
+    ) -> ThothResult<Subject> {
         SubjectPolicy::can_create(context, &data, ())?;
-        Subject::create(&context.db, &data).map_err(Into::into)
+        Subject::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new affiliation with the specified values")]
     fn create_affiliation(
         context: &Context,
         #[graphql(description = "Values for affiliation to be created")] data: NewAffiliation,
-    ) -> FieldResult<Affiliation> {
+    ) -> ThothResult<Affiliation> {
         AffiliationPolicy::can_create(context, &data, ())?;
-        Affiliation::create(&context.db, &data).map_err(Into::into)
+        Affiliation::create(&context.db, &data)
+    }
+
+    #[graphql(description = "Create a new audience with the specified values")]
+    fn create_audience(
+        context: &Context,
+        #[graphql(description = "Values for audience to be created")] data: NewAudience,
+    ) -> ThothResult<Audience> {
+        AudiencePolicy::can_create(context, &data, ())?;
+        Audience::create(&context.db, &data)
+    }
+
+    #[graphql(description = "Create a new work date with the specified values")]
+    fn create_work_date(
+        context: &Context,
+        #[graphql(description = "Values for work date to be created")] data: NewWorkDate,
+    ) -> ThothResult<WorkDate> {
+        WorkDatePolicy::can_create(context, &data, ())?;
+        WorkDate::create(&context.db, &data)
+    }
+
+    #[graphql(description = "Create a new name variant with the specified values")]
+    fn create_name_variant(
+        context: &Context,
+        #[graphql(description = "Values for name variant to be created")] data: NewNameVariant,
+    ) -> ThothResult<NameVariant> {
+        NameVariantPolicy::can_create(context, &data, ())?;
+        NameVariant::create(&context.db, &data)
+    }
+
+    #[graphql(description = "Create a new CRediT role with the specified values")]
+    fn create_credit_role(
+        context: &Context,
+        #[graphql(description = "Values for CRediT role to be created")] data: NewCreditRole,
+    ) -> ThothResult<CreditRole> {
+        CreditRolePolicy::can_create(context, &data, ())?;
+        CreditRole::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new work relation with the specified values")]
     fn create_work_relation(
         context: &Context,
         #[graphql(description = "Values for work relation to be created")] data: NewWorkRelation,
-    ) -> FieldResult<WorkRelation> {
+    ) -> ThothResult<WorkRelation> {
         WorkRelationPolicy::can_create(context, &data, ())?;
-        WorkRelation::create(&context.db, &data).map_err(Into::into)
+        WorkRelation::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new reference with the specified values")]
     fn create_reference(
         context: &Context,
         #[graphql(description = "Values for reference to be created")] data: NewReference,
-    ) -> FieldResult<Reference> {
+    ) -> ThothResult<Reference> {
         ReferencePolicy::can_create(context, &data, ())?;
-        Reference::create(&context.db, &data).map_err(Into::into)
+        Reference::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new additional resource with the specified values")]
@@ -268,7 +332,7 @@ impl MutationRoot {
         markup_format: Option<MarkupFormat>,
         #[graphql(description = "Values for additional resource to be created")]
         mut data: NewAdditionalResource,
-    ) -> FieldResult<AdditionalResource> {
+    ) -> ThothResult<AdditionalResource> {
         AdditionalResourcePolicy::can_create(context, &data, ())?;
 
         let markup = markup_format.unwrap_or(MarkupFormat::JatsXml);
@@ -278,7 +342,7 @@ impl MutationRoot {
             .map(|description| convert_to_jats(description, markup, ConversionLimit::Abstract))
             .transpose()?;
 
-        AdditionalResource::create(&context.db, &data).map_err(Into::into)
+        AdditionalResource::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new award with the specified values")]
@@ -287,7 +351,7 @@ impl MutationRoot {
         #[graphql(description = "The markup format of the award text fields")]
         markup_format: Option<MarkupFormat>,
         #[graphql(description = "Values for award to be created")] mut data: NewAward,
-    ) -> FieldResult<Award> {
+    ) -> ThothResult<Award> {
         AwardPolicy::can_create(context, &data, ())?;
 
         let markup = markup_format.unwrap_or(MarkupFormat::JatsXml);
@@ -299,7 +363,7 @@ impl MutationRoot {
             })
             .transpose()?;
 
-        Award::create(&context.db, &data).map_err(Into::into)
+        Award::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new endorsement with the specified values")]
@@ -308,7 +372,7 @@ impl MutationRoot {
         #[graphql(description = "The markup format of the endorsement rich-text fields")]
         markup_format: Option<MarkupFormat>,
         #[graphql(description = "Values for endorsement to be created")] mut data: NewEndorsement,
-    ) -> FieldResult<Endorsement> {
+    ) -> ThothResult<Endorsement> {
         EndorsementPolicy::can_create(context, &data, ())?;
 
         let markup = markup_format.unwrap_or(MarkupFormat::JatsXml);
@@ -321,7 +385,7 @@ impl MutationRoot {
             .map(|text| convert_to_jats(text, markup, ConversionLimit::Abstract))
             .transpose()?;
 
-        Endorsement::create(&context.db, &data).map_err(Into::into)
+        Endorsement::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new book review with the specified values")]
@@ -330,7 +394,7 @@ impl MutationRoot {
         #[graphql(description = "The markup format of the book review text field")]
         markup_format: Option<MarkupFormat>,
         #[graphql(description = "Values for book review to be created")] mut data: NewBookReview,
-    ) -> FieldResult<BookReview> {
+    ) -> ThothResult<BookReview> {
         BookReviewPolicy::can_create(context, &data, ())?;
 
         let markup = markup_format.unwrap_or(MarkupFormat::JatsXml);
@@ -343,7 +407,7 @@ impl MutationRoot {
             .map(|text| convert_to_jats(text, markup, ConversionLimit::Abstract))
             .transpose()?;
 
-        BookReview::create(&context.db, &data).map_err(Into::into)
+        BookReview::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new featured video with the specified values")]
@@ -351,25 +415,25 @@ impl MutationRoot {
         context: &Context,
         #[graphql(description = "Values for featured video to be created")]
         data: NewWorkFeaturedVideo,
-    ) -> FieldResult<WorkFeaturedVideo> {
+    ) -> ThothResult<WorkFeaturedVideo> {
         WorkFeaturedVideoPolicy::can_create(context, &data, ())?;
-        WorkFeaturedVideo::create(&context.db, &data).map_err(Into::into)
+        WorkFeaturedVideo::create(&context.db, &data)
     }
 
     #[graphql(description = "Create a new contact with the specified values")]
     fn create_contact(
         context: &Context,
         #[graphql(description = "Values for contact to be created")] data: NewContact,
-    ) -> FieldResult<Contact> {
+    ) -> ThothResult<Contact> {
         ContactPolicy::can_create(context, &data, ())?;
-        Contact::create(&context.db, &data).map_err(Into::into)
+        Contact::create(&context.db, &data)
     }
 
     #[graphql(description = "Update an existing work with the specified values")]
     fn update_work(
         context: &Context,
         #[graphql(description = "Values to apply to existing work")] data: PatchWork,
-    ) -> FieldResult<Work> {
+    ) -> ThothResult<Work> {
         let work = context.load_current(&data.work_id)?;
         WorkPolicy::can_update(context, &work, &data, ())?;
 
@@ -394,18 +458,18 @@ impl MutationRoot {
     fn update_publisher(
         context: &Context,
         #[graphql(description = "Values to apply to existing publisher")] data: PatchPublisher,
-    ) -> FieldResult<Publisher> {
+    ) -> ThothResult<Publisher> {
         let publisher = context.load_current(&data.publisher_id)?;
         PublisherPolicy::can_update(context, &publisher, &data, ())?;
 
-        publisher.update(context, &data).map_err(Into::into)
+        publisher.update(context, &data)
     }
 
     #[graphql(description = "Update an existing imprint with the specified values")]
     fn update_imprint(
         context: &Context,
         #[graphql(description = "Values to apply to existing imprint")] data: PatchImprint,
-    ) -> FieldResult<Imprint> {
+    ) -> ThothResult<Imprint> {
         let imprint: Imprint = context.load_current(&data.imprint_id)?;
         let mut data = data;
 
@@ -425,18 +489,18 @@ impl MutationRoot {
 
         ImprintPolicy::can_update(context, &imprint, &data, ())?;
 
-        imprint.update(context, &data).map_err(Into::into)
+        imprint.update(context, &data)
     }
 
     #[graphql(description = "Update an existing contributor with the specified values")]
     fn update_contributor(
         context: &Context,
         #[graphql(description = "Values to apply to existing contributor")] data: PatchContributor,
-    ) -> FieldResult<Contributor> {
+    ) -> ThothResult<Contributor> {
         let contributor = context.load_current(&data.contributor_id)?;
         ContributorPolicy::can_update(context, &contributor, &data, ())?;
 
-        contributor.update(context, &data).map_err(Into::into)
+        contributor.update(context, &data)
     }
 
     #[graphql(description = "Update an existing contribution with the specified values")]
@@ -444,122 +508,189 @@ impl MutationRoot {
         context: &Context,
         #[graphql(description = "Values to apply to existing contribution")]
         data: PatchContribution,
-    ) -> FieldResult<Contribution> {
+    ) -> ThothResult<Contribution> {
         let contribution = context.load_current(&data.contribution_id)?;
         ContributionPolicy::can_update(context, &contribution, &data, ())?;
 
-        contribution.update(context, &data).map_err(Into::into)
+        contribution.update(context, &data)
     }
 
     #[graphql(description = "Update an existing publication with the specified values")]
     fn update_publication(
         context: &Context,
         #[graphql(description = "Values to apply to existing publication")] data: PatchPublication,
-    ) -> FieldResult<Publication> {
+    ) -> ThothResult<Publication> {
         let data = data.into_normalised()?;
         let publication = context.load_current(&data.publication_id)?;
         PublicationPolicy::can_update(context, &publication, &data, ())?;
 
-        publication.update(context, &data).map_err(Into::into)
+        publication.update(context, &data)
     }
 
     #[graphql(description = "Update an existing series with the specified values")]
     fn update_series(
         context: &Context,
         #[graphql(description = "Values to apply to existing series")] data: PatchSeries,
-    ) -> FieldResult<Series> {
+    ) -> ThothResult<Series> {
         let series = context.load_current(&data.series_id)?;
         SeriesPolicy::can_update(context, &series, &data, ())?;
 
-        series.update(context, &data).map_err(Into::into)
+        series.update(context, &data)
     }
 
     #[graphql(description = "Update an existing issue with the specified values")]
     fn update_issue(
         context: &Context,
         #[graphql(description = "Values to apply to existing issue")] data: PatchIssue,
-    ) -> FieldResult<Issue> {
+    ) -> ThothResult<Issue> {
         let issue = context.load_current(&data.issue_id)?;
         IssuePolicy::can_update(context, &issue, &data, ())?;
 
-        issue.update(context, &data).map_err(Into::into)
+        issue.update(context, &data)
     }
 
     #[graphql(description = "Update an existing language with the specified values")]
     fn update_language(
         context: &Context,
         #[graphql(description = "Values to apply to existing language")] data: PatchLanguage,
-    ) -> FieldResult<Language> {
+    ) -> ThothResult<Language> {
         let language = context.load_current(&data.language_id)?;
         LanguagePolicy::can_update(context, &language, &data, ())?;
 
-        language.update(context, &data).map_err(Into::into)
+        language.update(context, &data)
     }
 
     #[graphql(description = "Update an existing institution with the specified values")]
     fn update_institution(
         context: &Context,
         #[graphql(description = "Values to apply to existing institution")] data: PatchInstitution,
-    ) -> FieldResult<Institution> {
+    ) -> ThothResult<Institution> {
         let institution = context.load_current(&data.institution_id)?;
         InstitutionPolicy::can_update(context, &institution, &data, ())?;
 
-        institution.update(context, &data).map_err(Into::into)
+        institution.update(context, &data)
     }
 
     #[graphql(description = "Update an existing funding with the specified values")]
     fn update_funding(
         context: &Context,
         #[graphql(description = "Values to apply to existing funding")] data: PatchFunding,
-    ) -> FieldResult<Funding> {
+    ) -> ThothResult<Funding> {
         let funding = context.load_current(&data.funding_id)?;
         FundingPolicy::can_update(context, &funding, &data, ())?;
 
-        funding.update(context, &data).map_err(Into::into)
+        funding.update(context, &data)
     }
 
     #[graphql(description = "Update an existing location with the specified values")]
     fn update_location(
         context: &Context,
         #[graphql(description = "Values to apply to existing location")] data: PatchLocation,
-    ) -> FieldResult<Location> {
+    ) -> ThothResult<Location> {
         let current_location = context.load_current(&data.location_id)?;
         LocationPolicy::can_update(context, &current_location, &data, ())?;
 
-        current_location.update(context, &data).map_err(Into::into)
+        current_location.update(context, &data)
     }
 
     #[graphql(description = "Update an existing price with the specified values")]
     fn update_price(
         context: &Context,
         #[graphql(description = "Values to apply to existing price")] data: PatchPrice,
-    ) -> FieldResult<Price> {
+    ) -> ThothResult<Price> {
         let price = context.load_current(&data.price_id)?;
         PricePolicy::can_update(context, &price, &data, ())?;
 
-        price.update(context, &data).map_err(Into::into)
+        price.update(context, &data)
+    }
+
+    #[graphql(description = "Update an existing product form detail with the specified values")]
+    fn update_product_form_detail(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing product form detail")]
+        data: PatchProductFormDetail,
+    ) -> ThothResult<ProductFormDetail> {
+        let product_form_detail = context.load_current(&data.product_form_detail_id)?;
+        ProductFormDetailPolicy::can_update(context, &product_form_detail, &data, ())?;
+
+        product_form_detail.update(context, &data)
+    }
+
+    #[graphql(description = "Update existing sales rights with the specified values")]
+    fn update_sales_rights(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing sales rights")] data: PatchSalesRights,
+    ) -> ThothResult<SalesRights> {
+        let sales_rights = context.load_current(&data.sales_rights_id)?;
+        SalesRightsPolicy::can_update(context, &sales_rights, &data, ())?;
+
+        sales_rights.update(context, &data)
     }
 
     #[graphql(description = "Update an existing subject with the specified values")]
     fn update_subject(
         context: &Context,
         #[graphql(description = "Values to apply to existing subject")] data: PatchSubject,
-    ) -> FieldResult<Subject> {
+    ) -> ThothResult<Subject> {
         let subject = context.load_current(&data.subject_id)?;
         SubjectPolicy::can_update(context, &subject, &data, ())?;
 
-        subject.update(context, &data).map_err(Into::into)
+        subject.update(context, &data)
     }
 
     #[graphql(description = "Update an existing affiliation with the specified values")]
     fn update_affiliation(
         context: &Context,
         #[graphql(description = "Values to apply to existing affiliation")] data: PatchAffiliation,
-    ) -> FieldResult<Affiliation> {
+    ) -> ThothResult<Affiliation> {
         let affiliation = context.load_current(&data.affiliation_id)?;
         AffiliationPolicy::can_update(context, &affiliation, &data, ())?;
 
-        affiliation.update(context, &data).map_err(Into::into)
+        affiliation.update(context, &data)
+    }
+
+    #[graphql(description = "Update an existing audience with the specified values")]
+    fn update_audience(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing audience")] data: PatchAudience,
+    ) -> ThothResult<Audience> {
+        let audience = context.load_current(&data.audience_id)?;
+        AudiencePolicy::can_update(context, &audience, &data, ())?;
+
+        audience.update(context, &data)
+    }
+
+    #[graphql(description = "Update an existing work date with the specified values")]
+    fn update_work_date(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing work date")] data: PatchWorkDate,
+    ) -> ThothResult<WorkDate> {
+        let work_date = context.load_current(&data.work_date_id)?;
+        WorkDatePolicy::can_update(context, &work_date, &data, ())?;
+
+        work_date.update(context, &data)
+    }
+
+    #[graphql(description = "Update an existing name variant with the specified values")]
+    fn update_name_variant(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing name variant")] data: PatchNameVariant,
+    ) -> ThothResult<NameVariant> {
+        let name_variant = context.load_current(&data.name_variant_id)?;
+        NameVariantPolicy::can_update(context, &name_variant, &data, ())?;
+
+        name_variant.update(context, &data)
+    }
+
+    #[graphql(description = "Update an existing CRediT role with the specified values")]
+    fn update_credit_role(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing CRediT role")] data: PatchCreditRole,
+    ) -> ThothResult<CreditRole> {
+        let credit_role = context.load_current(&data.credit_role_id)?;
+        CreditRolePolicy::can_update(context, &credit_role, &data, ())?;
+
+        credit_role.update(context, &data)
     }
 
     #[graphql(description = "Update an existing work relation with the specified values")]
@@ -567,22 +698,22 @@ impl MutationRoot {
         context: &Context,
         #[graphql(description = "Values to apply to existing work relation")]
         data: PatchWorkRelation,
-    ) -> FieldResult<WorkRelation> {
+    ) -> ThothResult<WorkRelation> {
         let work_relation = context.load_current(&data.work_relation_id)?;
         WorkRelationPolicy::can_update(context, &work_relation, &data, ())?;
 
-        work_relation.update(context, &data).map_err(Into::into)
+        work_relation.update(context, &data)
     }
 
     #[graphql(description = "Update an existing reference with the specified values")]
     fn update_reference(
         context: &Context,
         #[graphql(description = "Values to apply to existing reference")] data: PatchReference,
-    ) -> FieldResult<Reference> {
+    ) -> ThothResult<Reference> {
         let reference = context.load_current(&data.reference_id)?;
         ReferencePolicy::can_update(context, &reference, &data, ())?;
 
-        reference.update(context, &data).map_err(Into::into)
+        reference.update(context, &data)
     }
 
     #[graphql(description = "Update an existing additional resource with the specified values")]
@@ -592,7 +723,7 @@ impl MutationRoot {
         markup_format: Option<MarkupFormat>,
         #[graphql(description = "Values to apply to existing additional resource")]
         mut data: PatchAdditionalResource,
-    ) -> FieldResult<AdditionalResource> {
+    ) -> ThothResult<AdditionalResource> {
         let additional_resource = context.load_current(&data.additional_resource_id)?;
         AdditionalResourcePolicy::can_update(context, &additional_resource, &data, ())?;
 
@@ -603,9 +734,7 @@ impl MutationRoot {
             .map(|description| convert_to_jats(description, markup, ConversionLimit::Abstract))
             .transpose()?;
 
-        additional_resource
-            .update(context, &data)
-            .map_err(Into::into)
+        additional_resource.update(context, &data)
     }
 
     #[graphql(description = "Update an existing award with the specified values")]
@@ -614,7 +743,7 @@ impl MutationRoot {
         #[graphql(description = "The markup format of the award text fields")]
         markup_format: Option<MarkupFormat>,
         #[graphql(description = "Values to apply to existing award")] mut data: PatchAward,
-    ) -> FieldResult<Award> {
+    ) -> ThothResult<Award> {
         let award = context.load_current(&data.award_id)?;
         AwardPolicy::can_update(context, &award, &data, ())?;
 
@@ -627,7 +756,7 @@ impl MutationRoot {
             })
             .transpose()?;
 
-        award.update(context, &data).map_err(Into::into)
+        award.update(context, &data)
     }
 
     #[graphql(description = "Update an existing endorsement with the specified values")]
@@ -637,7 +766,7 @@ impl MutationRoot {
         markup_format: Option<MarkupFormat>,
         #[graphql(description = "Values to apply to existing endorsement")]
         mut data: PatchEndorsement,
-    ) -> FieldResult<Endorsement> {
+    ) -> ThothResult<Endorsement> {
         let endorsement = context.load_current(&data.endorsement_id)?;
         EndorsementPolicy::can_update(context, &endorsement, &data, ())?;
 
@@ -651,7 +780,7 @@ impl MutationRoot {
             .map(|text| convert_to_jats(text, markup, ConversionLimit::Abstract))
             .transpose()?;
 
-        endorsement.update(context, &data).map_err(Into::into)
+        endorsement.update(context, &data)
     }
 
     #[graphql(description = "Update an existing book review with the specified values")]
@@ -661,7 +790,7 @@ impl MutationRoot {
         markup_format: Option<MarkupFormat>,
         #[graphql(description = "Values to apply to existing book review")]
         mut data: PatchBookReview,
-    ) -> FieldResult<BookReview> {
+    ) -> ThothResult<BookReview> {
         let book_review = context.load_current(&data.book_review_id)?;
         BookReviewPolicy::can_update(context, &book_review, &data, ())?;
 
@@ -675,7 +804,7 @@ impl MutationRoot {
             .map(|text| convert_to_jats(text, markup, ConversionLimit::Abstract))
             .transpose()?;
 
-        book_review.update(context, &data).map_err(Into::into)
+        book_review.update(context, &data)
     }
 
     #[graphql(description = "Update an existing featured video with the specified values")]
@@ -683,24 +812,22 @@ impl MutationRoot {
         context: &Context,
         #[graphql(description = "Values to apply to existing featured video")]
         data: PatchWorkFeaturedVideo,
-    ) -> FieldResult<WorkFeaturedVideo> {
+    ) -> ThothResult<WorkFeaturedVideo> {
         let work_featured_video = context.load_current(&data.work_featured_video_id)?;
         WorkFeaturedVideoPolicy::can_update(context, &work_featured_video, &data, ())?;
 
-        work_featured_video
-            .update(context, &data)
-            .map_err(Into::into)
+        work_featured_video.update(context, &data)
     }
 
     #[graphql(description = "Update an existing contact with the specified values")]
     fn update_contact(
         context: &Context,
         #[graphql(description = "Values to apply to existing contact")] data: PatchContact,
-    ) -> FieldResult<Contact> {
+    ) -> ThothResult<Contact> {
         let contact = context.load_current(&data.contact_id)?;
         ContactPolicy::can_update(context, &contact, &data, ())?;
 
-        contact.update(context, &data).map_err(Into::into)
+        contact.update(context, &data)
     }
 
     #[graphql(description = "Update an existing title with the specified values")]
@@ -710,14 +837,14 @@ impl MutationRoot {
             MarkupFormat,
         >,
         #[graphql(description = "Values to apply to existing title")] mut data: PatchTitle,
-    ) -> FieldResult<Title> {
+    ) -> ThothResult<Title> {
         let title = context.load_current(&data.title_id)?;
         TitlePolicy::can_update(context, &title, &data, markup_format)?;
 
         let markup = markup_format.expect("Validated by policy");
         convert_title_to_jats(&mut data, markup)?;
 
-        title.update(context, &data).map_err(Into::into)
+        title.update(context, &data)
     }
 
     #[graphql(description = "Update an existing abstract with the specified values")]
@@ -727,14 +854,14 @@ impl MutationRoot {
             MarkupFormat,
         >,
         #[graphql(description = "Values to apply to existing abstract")] mut data: PatchAbstract,
-    ) -> FieldResult<Abstract> {
+    ) -> ThothResult<Abstract> {
         let r#abstract = context.load_current(&data.abstract_id)?;
         AbstractPolicy::can_update(context, &r#abstract, &data, markup_format)?;
 
         let markup = markup_format.expect("Validated by policy");
         data.content = convert_to_jats(data.content, markup, ConversionLimit::Abstract)?;
 
-        r#abstract.update(context, &data).map_err(Into::into)
+        r#abstract.update(context, &data)
     }
 
     #[graphql(description = "Update an existing biography with the specified values")]
@@ -744,21 +871,21 @@ impl MutationRoot {
             MarkupFormat,
         >,
         #[graphql(description = "Values to apply to existing biography")] mut data: PatchBiography,
-    ) -> FieldResult<Biography> {
+    ) -> ThothResult<Biography> {
         let biography = context.load_current(&data.biography_id)?;
         BiographyPolicy::can_update(context, &biography, &data, markup_format)?;
 
         let markup = markup_format.expect("Validated by policy");
         data.content = convert_to_jats(data.content, markup, ConversionLimit::Biography)?;
 
-        biography.update(context, &data).map_err(Into::into)
+        biography.update(context, &data)
     }
 
     #[graphql(description = "Delete a single work using its ID")]
     fn delete_work(
         context: &Context,
         #[graphql(description = "Thoth ID of work to be deleted")] work_id: Uuid,
-    ) -> FieldResult<Work> {
+    ) -> ThothResult<Work> {
         let work = context.load_current(&work_id)?;
         WorkPolicy::can_delete(context, &work)?;
         let cleanup_plan = work_cleanup_plan(context.db(), &work)?;
@@ -775,51 +902,51 @@ impl MutationRoot {
     fn delete_publisher(
         context: &Context,
         #[graphql(description = "Thoth ID of publisher to be deleted")] publisher_id: Uuid,
-    ) -> FieldResult<Publisher> {
+    ) -> ThothResult<Publisher> {
         let publisher = context.load_current(&publisher_id)?;
         PublisherPolicy::can_delete(context, &publisher)?;
 
-        publisher.delete(&context.db).map_err(Into::into)
+        publisher.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single imprint using its ID")]
     fn delete_imprint(
         context: &Context,
         #[graphql(description = "Thoth ID of imprint to be deleted")] imprint_id: Uuid,
-    ) -> FieldResult<Imprint> {
+    ) -> ThothResult<Imprint> {
         let imprint = context.load_current(&imprint_id)?;
         ImprintPolicy::can_delete(context, &imprint)?;
 
-        imprint.delete(&context.db).map_err(Into::into)
+        imprint.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single contributor using its ID")]
     fn delete_contributor(
         context: &Context,
         #[graphql(description = "Thoth ID of contributor to be deleted")] contributor_id: Uuid,
-    ) -> FieldResult<Contributor> {
+    ) -> ThothResult<Contributor> {
         let contributor = context.load_current(&contributor_id)?;
         ContributorPolicy::can_delete(context, &contributor)?;
 
-        contributor.delete(&context.db).map_err(Into::into)
+        contributor.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single contribution using its ID")]
     fn delete_contribution(
         context: &Context,
         #[graphql(description = "Thoth ID of contribution to be deleted")] contribution_id: Uuid,
-    ) -> FieldResult<Contribution> {
+    ) -> ThothResult<Contribution> {
         let contribution = context.load_current(&contribution_id)?;
         ContributionPolicy::can_delete(context, &contribution)?;
 
-        contribution.delete(&context.db).map_err(Into::into)
+        contribution.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single publication using its ID")]
     fn delete_publication(
         context: &Context,
         #[graphql(description = "Thoth ID of publication to be deleted")] publication_id: Uuid,
-    ) -> FieldResult<Publication> {
+    ) -> ThothResult<Publication> {
         let publication = context.load_current(&publication_id)?;
         PublicationPolicy::can_delete(context, &publication)?;
         let cleanup_plan = publication_cleanup_plan(context.db(), &publication)?;
@@ -836,132 +963,199 @@ impl MutationRoot {
     fn delete_series(
         context: &Context,
         #[graphql(description = "Thoth ID of series to be deleted")] series_id: Uuid,
-    ) -> FieldResult<Series> {
+    ) -> ThothResult<Series> {
         let series = context.load_current(&series_id)?;
         SeriesPolicy::can_delete(context, &series)?;
 
-        series.delete(&context.db).map_err(Into::into)
+        series.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single issue using its ID")]
     fn delete_issue(
         context: &Context,
         #[graphql(description = "Thoth ID of issue to be deleted")] issue_id: Uuid,
-    ) -> FieldResult<Issue> {
+    ) -> ThothResult<Issue> {
         let issue = context.load_current(&issue_id)?;
         IssuePolicy::can_delete(context, &issue)?;
 
-        issue.delete(&context.db).map_err(Into::into)
+        issue.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single language using its ID")]
     fn delete_language(
         context: &Context,
         #[graphql(description = "Thoth ID of language to be deleted")] language_id: Uuid,
-    ) -> FieldResult<Language> {
+    ) -> ThothResult<Language> {
         let language = context.load_current(&language_id)?;
         LanguagePolicy::can_delete(context, &language)?;
 
-        language.delete(&context.db).map_err(Into::into)
+        language.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single title using its ID")]
     fn delete_title(
         context: &Context,
         #[graphql(description = "Thoth ID of title to be deleted")] title_id: Uuid,
-    ) -> FieldResult<Title> {
+    ) -> ThothResult<Title> {
         let title = context.load_current(&title_id)?;
         TitlePolicy::can_delete(context, &title)?;
 
-        title.delete(&context.db).map_err(Into::into)
+        title.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single institution using its ID")]
     fn delete_institution(
         context: &Context,
         #[graphql(description = "Thoth ID of institution to be deleted")] institution_id: Uuid,
-    ) -> FieldResult<Institution> {
+    ) -> ThothResult<Institution> {
         let institution = context.load_current(&institution_id)?;
         InstitutionPolicy::can_delete(context, &institution)?;
 
-        institution.delete(&context.db).map_err(Into::into)
+        institution.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single funding using its ID")]
     fn delete_funding(
         context: &Context,
         #[graphql(description = "Thoth ID of funding to be deleted")] funding_id: Uuid,
-    ) -> FieldResult<Funding> {
+    ) -> ThothResult<Funding> {
         let funding = context.load_current(&funding_id)?;
         FundingPolicy::can_delete(context, &funding)?;
 
-        funding.delete(&context.db).map_err(Into::into)
+        funding.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single location using its ID")]
     fn delete_location(
         context: &Context,
         #[graphql(description = "Thoth ID of location to be deleted")] location_id: Uuid,
-    ) -> FieldResult<Location> {
+    ) -> ThothResult<Location> {
         let location = context.load_current(&location_id)?;
         LocationPolicy::can_delete(context, &location)?;
 
-        location.delete(&context.db).map_err(Into::into)
+        location.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single price using its ID")]
     fn delete_price(
         context: &Context,
         #[graphql(description = "Thoth ID of price to be deleted")] price_id: Uuid,
-    ) -> FieldResult<Price> {
+    ) -> ThothResult<Price> {
         let price = context.load_current(&price_id)?;
         PricePolicy::can_delete(context, &price)?;
 
-        price.delete(&context.db).map_err(Into::into)
+        price.delete(&context.db)
+    }
+
+    #[graphql(description = "Delete a single product form detail using its ID")]
+    fn delete_product_form_detail(
+        context: &Context,
+        #[graphql(description = "Thoth ID of product form detail to be deleted")]
+        product_form_detail_id: Uuid,
+    ) -> ThothResult<ProductFormDetail> {
+        let product_form_detail = context.load_current(&product_form_detail_id)?;
+        ProductFormDetailPolicy::can_delete(context, &product_form_detail)?;
+
+        product_form_detail.delete(&context.db)
+    }
+
+    #[graphql(description = "Delete a single sales rights record using its ID")]
+    fn delete_sales_rights(
+        context: &Context,
+        #[graphql(description = "Thoth ID of sales rights to be deleted")] sales_rights_id: Uuid,
+    ) -> ThothResult<SalesRights> {
+        let sales_rights = context.load_current(&sales_rights_id)?;
+        SalesRightsPolicy::can_delete(context, &sales_rights)?;
+
+        sales_rights.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single subject using its ID")]
     fn delete_subject(
         context: &Context,
         #[graphql(description = "Thoth ID of subject to be deleted")] subject_id: Uuid,
-    ) -> FieldResult<Subject> {
+    ) -> ThothResult<Subject> {
         let subject = context.load_current(&subject_id)?;
         SubjectPolicy::can_delete(context, &subject)?;
 
-        subject.delete(&context.db).map_err(Into::into)
+        subject.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single affiliation using its ID")]
     fn delete_affiliation(
         context: &Context,
         #[graphql(description = "Thoth ID of affiliation to be deleted")] affiliation_id: Uuid,
-    ) -> FieldResult<Affiliation> {
+    ) -> ThothResult<Affiliation> {
         let affiliation = context.load_current(&affiliation_id)?;
         AffiliationPolicy::can_delete(context, &affiliation)?;
 
-        affiliation.delete(&context.db).map_err(Into::into)
+        affiliation.delete(&context.db)
+    }
+
+    #[graphql(description = "Delete a single audience using its ID")]
+    fn delete_audience(
+        context: &Context,
+        #[graphql(description = "Thoth ID of audience to be deleted")] audience_id: Uuid,
+    ) -> ThothResult<Audience> {
+        let audience = context.load_current(&audience_id)?;
+        AudiencePolicy::can_delete(context, &audience)?;
+
+        audience.delete(&context.db)
+    }
+
+    #[graphql(description = "Delete a single work date using its ID")]
+    fn delete_work_date(
+        context: &Context,
+        #[graphql(description = "Thoth ID of work date to be deleted")] work_date_id: Uuid,
+    ) -> ThothResult<WorkDate> {
+        let work_date = context.load_current(&work_date_id)?;
+        WorkDatePolicy::can_delete(context, &work_date)?;
+
+        work_date.delete(&context.db)
+    }
+
+    #[graphql(description = "Delete a single name variant using its ID")]
+    fn delete_name_variant(
+        context: &Context,
+        #[graphql(description = "Thoth ID of name variant to be deleted")] name_variant_id: Uuid,
+    ) -> ThothResult<NameVariant> {
+        let name_variant = context.load_current(&name_variant_id)?;
+        NameVariantPolicy::can_delete(context, &name_variant)?;
+
+        name_variant.delete(&context.db)
+    }
+
+    #[graphql(description = "Delete a single CRediT role using its ID")]
+    fn delete_credit_role(
+        context: &Context,
+        #[graphql(description = "Thoth ID of CRediT role to be deleted")] credit_role_id: Uuid,
+    ) -> ThothResult<CreditRole> {
+        let credit_role = context.load_current(&credit_role_id)?;
+        CreditRolePolicy::can_delete(context, &credit_role)?;
+
+        credit_role.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single work relation using its ID")]
     fn delete_work_relation(
         context: &Context,
         #[graphql(description = "Thoth ID of work relation to be deleted")] work_relation_id: Uuid,
-    ) -> FieldResult<WorkRelation> {
+    ) -> ThothResult<WorkRelation> {
         let work_relation = context.load_current(&work_relation_id)?;
         WorkRelationPolicy::can_delete(context, &work_relation)?;
 
-        work_relation.delete(&context.db).map_err(Into::into)
+        work_relation.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single reference using its ID")]
     fn delete_reference(
         context: &Context,
         #[graphql(description = "Thoth ID of reference to be deleted")] reference_id: Uuid,
-    ) -> FieldResult<Reference> {
+    ) -> ThothResult<Reference> {
         let reference = context.load_current(&reference_id)?;
         ReferencePolicy::can_delete(context, &reference)?;
 
-        reference.delete(&context.db).map_err(Into::into)
+        reference.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single additional resource using its ID")]
@@ -969,7 +1163,7 @@ impl MutationRoot {
         context: &Context,
         #[graphql(description = "Thoth ID of additional resource to be deleted")]
         additional_resource_id: Uuid,
-    ) -> FieldResult<AdditionalResource> {
+    ) -> ThothResult<AdditionalResource> {
         let additional_resource = context.load_current(&additional_resource_id)?;
         AdditionalResourcePolicy::can_delete(context, &additional_resource)?;
 
@@ -986,40 +1180,40 @@ impl MutationRoot {
     fn delete_award(
         context: &Context,
         #[graphql(description = "Thoth ID of award to be deleted")] award_id: Uuid,
-    ) -> FieldResult<Award> {
+    ) -> ThothResult<Award> {
         let award = context.load_current(&award_id)?;
         AwardPolicy::can_delete(context, &award)?;
 
-        award.delete(&context.db).map_err(Into::into)
+        award.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single endorsement using its ID")]
     fn delete_endorsement(
         context: &Context,
         #[graphql(description = "Thoth ID of endorsement to be deleted")] endorsement_id: Uuid,
-    ) -> FieldResult<Endorsement> {
+    ) -> ThothResult<Endorsement> {
         let endorsement = context.load_current(&endorsement_id)?;
         EndorsementPolicy::can_delete(context, &endorsement)?;
 
-        endorsement.delete(&context.db).map_err(Into::into)
+        endorsement.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single book review using its ID")]
     fn delete_book_review(
         context: &Context,
         #[graphql(description = "Thoth ID of book review to be deleted")] book_review_id: Uuid,
-    ) -> FieldResult<BookReview> {
+    ) -> ThothResult<BookReview> {
         let book_review = context.load_current(&book_review_id)?;
         BookReviewPolicy::can_delete(context, &book_review)?;
 
-        book_review.delete(&context.db).map_err(Into::into)
+        book_review.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single featured video using its ID")]
     fn delete_work_featured_video(
         context: &Context,
         #[graphql(description = "Thoth ID of featured video to be deleted")] work_featured_video_id: Uuid,
-    ) -> FieldResult<WorkFeaturedVideo> {
+    ) -> ThothResult<WorkFeaturedVideo> {
         let work_featured_video = context.load_current(&work_featured_video_id)?;
         WorkFeaturedVideoPolicy::can_delete(context, &work_featured_video)?;
 
@@ -1036,22 +1230,22 @@ impl MutationRoot {
     fn delete_abstract(
         context: &Context,
         #[graphql(description = "Thoth ID of abstract to be deleted")] abstract_id: Uuid,
-    ) -> FieldResult<Abstract> {
+    ) -> ThothResult<Abstract> {
         let r#abstract = context.load_current(&abstract_id)?;
         AbstractPolicy::can_delete(context, &r#abstract)?;
 
-        r#abstract.delete(&context.db).map_err(Into::into)
+        r#abstract.delete(&context.db)
     }
 
     #[graphql(description = "Delete a single biography using its ID")]
     fn delete_biography(
         context: &Context,
         #[graphql(description = "Thoth ID of biography to be deleted")] biography_id: Uuid,
-    ) -> FieldResult<Biography> {
+    ) -> ThothResult<Biography> {
         let biography = context.load_current(&biography_id)?;
         BiographyPolicy::can_delete(context, &biography)?;
 
-        biography.delete(&context.db).map_err(Into::into)
+        biography.delete(&context.db)
     }
 
     #[graphql(description = "Change the ordering of an affiliation within a contribution")]
@@ -1062,7 +1256,7 @@ impl MutationRoot {
             description = "Ordinal representing position to which affiliation should be moved"
         )]
         new_ordinal: i32,
-    ) -> FieldResult<Affiliation> {
+    ) -> ThothResult<Affiliation> {
         let affiliation = context.load_current(&affiliation_id)?;
         AffiliationPolicy::can_move(context, &affiliation)?;
 
@@ -1071,9 +1265,7 @@ impl MutationRoot {
             return Ok(affiliation);
         }
 
-        affiliation
-            .change_ordinal(context, affiliation.affiliation_ordinal, new_ordinal)
-            .map_err(Into::into)
+        affiliation.change_ordinal(context, affiliation.affiliation_ordinal, new_ordinal)
     }
 
     #[graphql(description = "Change the ordering of a contribution within a work")]
@@ -1084,7 +1276,7 @@ impl MutationRoot {
             description = "Ordinal representing position to which contribution should be moved"
         )]
         new_ordinal: i32,
-    ) -> FieldResult<Contribution> {
+    ) -> ThothResult<Contribution> {
         let contribution = context.load_current(&contribution_id)?;
         ContributionPolicy::can_move(context, &contribution)?;
 
@@ -1093,9 +1285,7 @@ impl MutationRoot {
             return Ok(contribution);
         }
 
-        contribution
-            .change_ordinal(context, contribution.contribution_ordinal, new_ordinal)
-            .map_err(Into::into)
+        contribution.change_ordinal(context, contribution.contribution_ordinal, new_ordinal)
     }
 
     #[graphql(description = "Change the ordering of an issue within a series")]
@@ -1104,7 +1294,7 @@ impl MutationRoot {
         #[graphql(description = "Thoth ID of issue to be moved")] issue_id: Uuid,
         #[graphql(description = "Ordinal representing position to which issue should be moved")]
         new_ordinal: i32,
-    ) -> FieldResult<Issue> {
+    ) -> ThothResult<Issue> {
         let issue = context.load_current(&issue_id)?;
         IssuePolicy::can_move(context, &issue)?;
 
@@ -1113,9 +1303,7 @@ impl MutationRoot {
             return Ok(issue);
         }
 
-        issue
-            .change_ordinal(context, issue.issue_ordinal, new_ordinal)
-            .map_err(Into::into)
+        issue.change_ordinal(context, issue.issue_ordinal, new_ordinal)
     }
 
     #[graphql(description = "Change the ordering of a reference within a work")]
@@ -1126,7 +1314,7 @@ impl MutationRoot {
             description = "Ordinal representing position to which reference should be moved"
         )]
         new_ordinal: i32,
-    ) -> FieldResult<Reference> {
+    ) -> ThothResult<Reference> {
         let reference = context.load_current(&reference_id)?;
         ReferencePolicy::can_move(context, &reference)?;
 
@@ -1135,9 +1323,7 @@ impl MutationRoot {
             return Ok(reference);
         }
 
-        reference
-            .change_ordinal(context, reference.reference_ordinal, new_ordinal)
-            .map_err(Into::into)
+        reference.change_ordinal(context, reference.reference_ordinal, new_ordinal)
     }
 
     #[graphql(description = "Change the ordering of an additional resource within a work")]
@@ -1149,7 +1335,7 @@ impl MutationRoot {
             description = "Ordinal representing position to which additional resource should be moved"
         )]
         new_ordinal: i32,
-    ) -> FieldResult<AdditionalResource> {
+    ) -> ThothResult<AdditionalResource> {
         let additional_resource = context.load_current(&additional_resource_id)?;
         AdditionalResourcePolicy::can_move(context, &additional_resource)?;
 
@@ -1157,9 +1343,11 @@ impl MutationRoot {
             return Ok(additional_resource);
         }
 
-        additional_resource
-            .change_ordinal(context, additional_resource.resource_ordinal, new_ordinal)
-            .map_err(Into::into)
+        additional_resource.change_ordinal(
+            context,
+            additional_resource.resource_ordinal,
+            new_ordinal,
+        )
     }
 
     #[graphql(description = "Change the ordering of an award within a work")]
@@ -1168,7 +1356,7 @@ impl MutationRoot {
         #[graphql(description = "Thoth ID of award to be moved")] award_id: Uuid,
         #[graphql(description = "Ordinal representing position to which award should be moved")]
         new_ordinal: i32,
-    ) -> FieldResult<Award> {
+    ) -> ThothResult<Award> {
         let award = context.load_current(&award_id)?;
         AwardPolicy::can_move(context, &award)?;
 
@@ -1176,9 +1364,7 @@ impl MutationRoot {
             return Ok(award);
         }
 
-        award
-            .change_ordinal(context, award.award_ordinal, new_ordinal)
-            .map_err(Into::into)
+        award.change_ordinal(context, award.award_ordinal, new_ordinal)
     }
 
     #[graphql(description = "Change the ordering of an endorsement within a work")]
@@ -1189,7 +1375,7 @@ impl MutationRoot {
             description = "Ordinal representing position to which endorsement should be moved"
         )]
         new_ordinal: i32,
-    ) -> FieldResult<Endorsement> {
+    ) -> ThothResult<Endorsement> {
         let endorsement = context.load_current(&endorsement_id)?;
         EndorsementPolicy::can_move(context, &endorsement)?;
 
@@ -1197,9 +1383,7 @@ impl MutationRoot {
             return Ok(endorsement);
         }
 
-        endorsement
-            .change_ordinal(context, endorsement.endorsement_ordinal, new_ordinal)
-            .map_err(Into::into)
+        endorsement.change_ordinal(context, endorsement.endorsement_ordinal, new_ordinal)
     }
 
     #[graphql(description = "Change the ordering of a book review within a work")]
@@ -1210,7 +1394,7 @@ impl MutationRoot {
             description = "Ordinal representing position to which book review should be moved"
         )]
         new_ordinal: i32,
-    ) -> FieldResult<BookReview> {
+    ) -> ThothResult<BookReview> {
         let book_review = context.load_current(&book_review_id)?;
         BookReviewPolicy::can_move(context, &book_review)?;
 
@@ -1218,9 +1402,7 @@ impl MutationRoot {
             return Ok(book_review);
         }
 
-        book_review
-            .change_ordinal(context, book_review.review_ordinal, new_ordinal)
-            .map_err(Into::into)
+        book_review.change_ordinal(context, book_review.review_ordinal, new_ordinal)
     }
 
     #[graphql(description = "Change the ordering of a subject within a work")]
@@ -1229,7 +1411,7 @@ impl MutationRoot {
         #[graphql(description = "Thoth ID of subject to be moved")] subject_id: Uuid,
         #[graphql(description = "Ordinal representing position to which subject should be moved")]
         new_ordinal: i32,
-    ) -> FieldResult<Subject> {
+    ) -> ThothResult<Subject> {
         let subject = context.load_current(&subject_id)?;
         SubjectPolicy::can_move(context, &subject)?;
 
@@ -1238,9 +1420,7 @@ impl MutationRoot {
             return Ok(subject);
         }
 
-        subject
-            .change_ordinal(context, subject.subject_ordinal, new_ordinal)
-            .map_err(Into::into)
+        subject.change_ordinal(context, subject.subject_ordinal, new_ordinal)
     }
 
     #[graphql(description = "Change the ordering of a work relation within a work")]
@@ -1251,7 +1431,7 @@ impl MutationRoot {
             description = "Ordinal representing position to which work relation should be moved"
         )]
         new_ordinal: i32,
-    ) -> FieldResult<WorkRelation> {
+    ) -> ThothResult<WorkRelation> {
         let work_relation = context.load_current(&work_relation_id)?;
         WorkRelationPolicy::can_move(context, &work_relation)?;
 
@@ -1260,9 +1440,7 @@ impl MutationRoot {
             return Ok(work_relation);
         }
 
-        work_relation
-            .change_ordinal(context, work_relation.relation_ordinal, new_ordinal)
-            .map_err(Into::into)
+        work_relation.change_ordinal(context, work_relation.relation_ordinal, new_ordinal)
     }
 
     #[graphql(
@@ -1272,7 +1450,7 @@ impl MutationRoot {
         context: &Context,
         #[graphql(description = "Input for starting a publication file upload")]
         data: NewPublicationFileUpload,
-    ) -> FieldResult<FileUploadResponse> {
+    ) -> ThothResult<FileUploadResponse> {
         let publication: Publication = context.load_current(&data.publication_id)?;
 
         let new_upload: NewFileUpload = data.into();
@@ -1287,7 +1465,6 @@ impl MutationRoot {
         new_upload
             .create_upload_response(&context.db, context.s3_client(), &storage_config, 30)
             .await
-            .map_err(Into::into)
     }
 
     #[graphql(
@@ -1297,7 +1474,7 @@ impl MutationRoot {
         context: &Context,
         #[graphql(description = "Input for starting a front cover upload")]
         data: NewFrontcoverFileUpload,
-    ) -> FieldResult<FileUploadResponse> {
+    ) -> ThothResult<FileUploadResponse> {
         let work: Work = context.load_current(&data.work_id)?;
 
         let new_upload: NewFileUpload = data.into();
@@ -1311,7 +1488,6 @@ impl MutationRoot {
         new_upload
             .create_upload_response(&context.db, context.s3_client(), &storage_config, 30)
             .await
-            .map_err(Into::into)
     }
 
     #[graphql(
@@ -1321,7 +1497,7 @@ impl MutationRoot {
         context: &Context,
         #[graphql(description = "Input for starting an additional resource upload")]
         data: NewAdditionalResourceFileUpload,
-    ) -> FieldResult<FileUploadResponse> {
+    ) -> ThothResult<FileUploadResponse> {
         let additional_resource: AdditionalResource =
             context.load_current(&data.additional_resource_id)?;
         context.require_cdn_write_for(&additional_resource)?;
@@ -1345,7 +1521,6 @@ impl MutationRoot {
         new_upload
             .create_upload_response(&context.db, context.s3_client(), &storage_config, 30)
             .await
-            .map_err(Into::into)
     }
 
     #[graphql(
@@ -1355,7 +1530,7 @@ impl MutationRoot {
         context: &Context,
         #[graphql(description = "Input for starting a featured video upload")]
         data: NewWorkFeaturedVideoFileUpload,
-    ) -> FieldResult<FileUploadResponse> {
+    ) -> ThothResult<FileUploadResponse> {
         let work_featured_video: WorkFeaturedVideo =
             context.load_current(&data.work_featured_video_id)?;
         context.require_cdn_write_for(&work_featured_video)?;
@@ -1379,7 +1554,6 @@ impl MutationRoot {
         new_upload
             .create_upload_response(&context.db, context.s3_client(), &storage_config, 30)
             .await
-            .map_err(Into::into)
     }
 
     #[graphql(
@@ -1388,7 +1562,7 @@ impl MutationRoot {
     async fn complete_file_upload(
         context: &Context,
         #[graphql(description = "Input for completing a file upload")] data: CompleteFileUpload,
-    ) -> FieldResult<File> {
+    ) -> ThothResult<File> {
         let file_upload: FileUpload = context.load_current(&data.file_upload_id)?;
         FilePolicy::can_delete(context, &file_upload)?;
 
@@ -1433,6 +1607,21 @@ impl MutationRoot {
             &mime_type,
         )?;
 
+        let validation = validate_stored_object(
+            &ValidationPipeline::default(),
+            s3_client,
+            &storage_config.s3_bucket,
+            &temp_key,
+            file_upload.file_type,
+            &mime_type,
+            &file_upload.declared_extension,
+            bytes,
+        )
+        .await?;
+        if imprint.reject_invalid_files && validation.failed() {
+            return Err(validation.rejection_error());
+        }
+
         let featured_video_dimensions = if matches!(
             file_upload.file_type,
             crate::model::file::FileType::WorkFeaturedVideo
@@ -1449,6 +1638,39 @@ impl MutationRoot {
             None
         };
 
+        if matches!(
+            file_upload.file_type,
+            crate::model::file::FileType::Publication
+        ) {
+            let publication_id = file_upload
+                .publication_id
+                .ok_or(ThothError::PublicationFileUploadMissingPublicationId)?;
+            let (file, _) = publish_publication_file(
+                context,
+                s3_client,
+                cloudfront_client,
+                &storage_config,
+                &work,
+                doi,
+                publication_id,
+                PublicationFileSource {
+                    object_key: &temp_key,
+                    extension: &file_upload.declared_extension,
+                    mime_type: &mime_type,
+                    bytes,
+                    sha256: &file_upload.declared_sha256,
+                    restored_from_file_version_id: None,
+                },
+            )
+            .await?;
+            let file = file.record_validation(&context.db, &validation)?;
+
+            file_upload.clone().delete(&context.db)?;
+            delete_object(s3_client, &storage_config.s3_bucket, &temp_key).await?;
+
+            return Ok(file);
+        }
+
         let canonical_key = file_upload.canonical_key(doi)?;
 
         copy_temp_object_to_final(
@@ -1467,6 +1689,7 @@ impl MutationRoot {
             &mime_type,
             bytes,
         )?;
+        let file = file.record_validation(&context.db, &validation)?;
         file_upload.sync_related_metadata(context, &work, &cdn_url, featured_video_dimensions)?;
 
         reconcile_replaced_object(
@@ -1486,14 +1709,74 @@ impl MutationRoot {
         Ok(file)
     }
 
+    #[graphql(
+        description = "Make an earlier version of a publication file the current one. The restored content is recorded as a new version, so no history is lost."
+    )]
+    async fn restore_file_version(
+        context: &Context,
+        #[graphql(description = "Thoth ID of the file version to restore")] file_version_id: Uuid,
+    ) -> ThothResult<FileVersion> {
+        let file_version: FileVersion = context.load_current(&file_version_id)?;
+        FileVersionPolicy::can_restore(context, &file_version)?;
+
+        let publication: Publication = context.load_current(&file_version.publication_id)?;
+        let work: Work = context.load_current(&publication.work_id)?;
+        let doi = work
+            .doi
+            .as_ref()
+            .ok_or(ThothError::WorkMissingDoiForFileUpload)?;
+        let imprint: Imprint = context.load_current(&work.imprint_id)?;
+        let storage_config = StorageConfig::from_imprint(&imprint)?;
+        let extension = file_version
+            .extension()
+            .ok_or(ThothError::NoFileExtensionFound)?;
+
+        let validation = validate_stored_object(
+            &ValidationPipeline::default(),
+            context.s3_client(),
+            &storage_config.s3_bucket,
+            &file_version.object_key,
+            crate::model::file::FileType::Publication,
+            &file_version.mime_type,
+            extension,
+            file_version.bytes,
+        )
+        .await?;
+        if imprint.reject_invalid_files && validation.failed() {
+            return Err(validation.rejection_error());
+        }
+
+        let (file, restored) = publish_publication_file(
+            context,
+            context.s3_client(),
+            context.cloudfront_client(),
+            &storage_config,
+            &work,
+            doi,
+            publication.publication_id,
+            PublicationFileSource {
+                object_key: &file_version.object_key,
+                extension,
+                mime_type: &file_version.mime_type,
+                bytes: file_version.bytes,
+                sha256: &file_version.sha256,
+                restored_from_file_version_id: Some(file_version.file_version_id),
+            },
+        )
+        .await?;
+        file.record_validation(&context.db, &validation)?;
+
+        Ok(restored)
+    }
+
     #[graphql(description = "Delete a single contact using its ID")]
     fn delete_contact(
         context: &Context,
         #[graphql(description = "Thoth ID of contact to be deleted")] contact_id: Uuid,
-    ) -> FieldResult<Contact> {
+    ) -> ThothResult<Contact> {
         let contact = context.load_current(&contact_id)?;
         ContactPolicy::can_delete(context, &contact)?;
 
-        contact.delete(&context.db).map_err(Into::into)
+        contact.delete(&context.db)
     }
 }
@@ -1,4 +1,4 @@
-use juniper::{FieldError, FieldResult};
+use chrono::naive::NaiveDate;
 use uuid::Uuid;
 
 use super::types::inputs::{
@@ -11,35 +11,44 @@ use crate::markup::{convert_from_jats, ConversionLimit, MarkupFormat};
 use crate::model::{
     additional_resource::{AdditionalResource, AdditionalResourceOrderBy},
     affiliation::{Affiliation, AffiliationOrderBy},
+    audience::{Audience, AudienceCode, AudienceOrderBy},
     award::{Award, AwardOrderBy},
     biography::{Biography, BiographyOrderBy},
     book_review::{BookReview, BookReviewOrderBy},
     contact::{Contact, ContactOrderBy, ContactType},
     contribution::{Contribution, ContributionType},
     contributor::{Contributor, ContributorOrderBy},
+    credit_role::{CreditRole, CreditRoleOrderBy, CreditRoleType},
     endorsement::{Endorsement, EndorsementOrderBy},
-    file::File,
+    file::{File, FileValidationStatus},
+    file_audit::{FileAudit, FileAuditOrderBy},
     funding::Funding,
     imprint::{Imprint, ImprintOrderBy},
-    institution::{Institution, InstitutionOrderBy},
+    institution::{funder_registry::RegisteredFunder, Institution, InstitutionOrderBy},
     issue::Issue,
     language::{Language, LanguageCode, LanguageRelation},
+    license::{CreativeCommonsLicense, CREATIVE_COMMONS_LICENSES},
     locale::LocaleCode,
     location::{Location, LocationOrderBy, LocationPlatform},
+    name_variant::{NameVariant, NameVariantOrderBy, NameVariantType},
     price::{CurrencyCode, Price},
+    product_form_detail::{ProductFormDetail, ProductFormDetailOrderBy, ProductFormDetailType},
     publication::{Publication, PublicationOrderBy, PublicationType},
     publisher::{Publisher, PublisherOrderBy},
     r#abstract::{Abstract, AbstractOrderBy},
     reference::{Reference, ReferenceOrderBy},
+    sales_rights::{SalesRights, SalesRightsOrderBy, SalesRightsType},
     series::{Series, SeriesOrderBy, SeriesType},
-    subject::{Subject, SubjectType},
+    subject::{vocabulary::SubjectHeading, Subject, SubjectType},
     title::{Title, TitleOrderBy},
     work::{Work, WorkOrderBy, WorkStatus, WorkType},
+    work_date::{WorkDate, WorkDateOrderBy, WorkDateType},
     work_featured_video::{WorkFeaturedVideo, WorkFeaturedVideoOrderBy},
     Crud, Doi,
 };
 use crate::policy::PolicyContext;
-use thoth_errors::ThothError;
+use crate::storage::audit_imprint_files;
+use thoth_errors::{ThothError, ThothResult};
 
 pub struct QueryRoot;
 
@@ -87,7 +96,7 @@ impl QueryRoot {
             description = "Only show results updated either before (less than) or after (greater than) the specified timestamp"
         )]
         updated_at_with_relations: Option<TimeExpression>,
-    ) -> FieldResult<Vec<Work>> {
+    ) -> ThothResult<Vec<Work>> {
         let mut statuses = work_statuses.unwrap_or_default();
         if let Some(status) = work_status {
             statuses.push(status);
@@ -106,23 +115,22 @@ impl QueryRoot {
             publication_date,
             updated_at_with_relations,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single work using its ID")]
     fn work(
         context: &Context,
         #[graphql(description = "Thoth work ID to search on")] work_id: Uuid,
-    ) -> FieldResult<Work> {
-        Work::from_id(&context.db, &work_id).map_err(Into::into)
+    ) -> ThothResult<Work> {
+        Work::from_id(&context.db, &work_id)
     }
 
     #[graphql(description = "Query a single work using its DOI")]
     fn work_by_doi(
         context: &Context,
         #[graphql(description = "Work DOI to search on")] doi: Doi,
-    ) -> FieldResult<Work> {
-        Work::from_doi(&context.db, doi, vec![]).map_err(Into::into)
+    ) -> ThothResult<Work> {
+        Work::from_doi(&context.db, doi, vec![])
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -160,7 +168,7 @@ impl QueryRoot {
             description = "Only show results updated either before (less than) or after (greater than) the specified timestamp"
         )]
         updated_at_with_relations: Option<TimeExpression>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         let mut statuses = work_statuses.unwrap_or_default();
         if let Some(status) = work_status {
             statuses.push(status);
@@ -174,7 +182,6 @@ impl QueryRoot {
             publication_date,
             updated_at_with_relations,
         )
-        .map_err(Into::into)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -214,7 +221,7 @@ impl QueryRoot {
             description = "Only show results updated either before (less than) or after (greater than) the specified timestamp"
         )]
         updated_at_with_relations: Option<TimeExpression>,
-    ) -> FieldResult<Vec<Work>> {
+    ) -> ThothResult<Vec<Work>> {
         let mut statuses = work_statuses.unwrap_or_default();
         if let Some(status) = work_status {
             statuses.push(status);
@@ -238,14 +245,13 @@ impl QueryRoot {
             publication_date,
             updated_at_with_relations,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single book using its DOI")]
     fn book_by_doi(
         context: &Context,
         #[graphql(description = "Book DOI to search on")] doi: Doi,
-    ) -> FieldResult<Work> {
+    ) -> ThothResult<Work> {
         Work::from_doi(
             &context.db,
             doi,
@@ -256,7 +262,6 @@ impl QueryRoot {
                 WorkType::JournalIssue,
             ],
         )
-        .map_err(Into::into)
     }
 
     #[graphql(
@@ -290,7 +295,7 @@ impl QueryRoot {
             description = "Only show results updated either before (less than) or after (greater than) the specified timestamp"
         )]
         updated_at_with_relations: Option<TimeExpression>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         let mut statuses = work_statuses.unwrap_or_default();
         if let Some(status) = work_status {
             statuses.push(status);
@@ -309,7 +314,6 @@ impl QueryRoot {
             publication_date,
             updated_at_with_relations,
         )
-        .map_err(Into::into)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -349,7 +353,7 @@ impl QueryRoot {
             description = "Only show results updated either before (less than) or after (greater than) the specified timestamp"
         )]
         updated_at_with_relations: Option<TimeExpression>,
-    ) -> FieldResult<Vec<Work>> {
+    ) -> ThothResult<Vec<Work>> {
         let mut statuses = work_statuses.unwrap_or_default();
         if let Some(status) = work_status {
             statuses.push(status);
@@ -368,15 +372,14 @@ impl QueryRoot {
             publication_date,
             updated_at_with_relations,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single chapter using its DOI")]
     fn chapter_by_doi(
         context: &Context,
         #[graphql(description = "Chapter DOI to search on")] doi: Doi,
-    ) -> FieldResult<Work> {
-        Work::from_doi(&context.db, doi, vec![WorkType::BookChapter]).map_err(Into::into)
+    ) -> ThothResult<Work> {
+        Work::from_doi(&context.db, doi, vec![WorkType::BookChapter])
     }
 
     #[graphql(
@@ -410,7 +413,7 @@ impl QueryRoot {
             description = "Only show results updated either before (less than) or after (greater than) the specified timestamp"
         )]
         updated_at_with_relations: Option<TimeExpression>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         let mut statuses = work_statuses.unwrap_or_default();
         if let Some(status) = work_status {
             statuses.push(status);
@@ -424,7 +427,6 @@ impl QueryRoot {
             publication_date,
             updated_at_with_relations,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of publications")]
@@ -452,7 +454,7 @@ impl QueryRoot {
             description = "Specific types to filter by",
         )]
         publication_types: Option<Vec<PublicationType>>,
-    ) -> FieldResult<Vec<Publication>> {
+    ) -> ThothResult<Vec<Publication>> {
         Publication::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -467,23 +469,116 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single publication using its ID")]
     fn publication(
         context: &Context,
         #[graphql(description = "Thoth publication ID to search on")] publication_id: Uuid,
-    ) -> FieldResult<Publication> {
-        Publication::from_id(&context.db, &publication_id).map_err(Into::into)
+    ) -> ThothResult<Publication> {
+        Publication::from_id(&context.db, &publication_id)
     }
 
     #[graphql(description = "Query a single file using its ID")]
     fn file(
         context: &Context,
         #[graphql(description = "Thoth file ID to search on")] file_id: Uuid,
-    ) -> FieldResult<File> {
-        File::from_id(&context.db, &file_id).map_err(Into::into)
+    ) -> ThothResult<File> {
+        File::from_id(&context.db, &file_id)
+    }
+
+    #[graphql(
+        description = "Query the stored files of an imprint, optionally limited to those with the given content validation statuses"
+    )]
+    fn files(
+        context: &Context,
+        #[graphql(description = "Thoth ID of the imprint whose files to return")] imprint_id: Uuid,
+        #[graphql(
+            default = vec![],
+            description = "Specific validation statuses to filter by"
+        )]
+        validation_statuses: Option<Vec<FileValidationStatus>>,
+    ) -> ThothResult<Vec<File>> {
+        let validation_statuses = validation_statuses.unwrap_or_default();
+        let mut files = File::all_for_imprint(&context.db, &imprint_id)?;
+        if !validation_statuses.is_empty() {
+            files.retain(|file| validation_statuses.contains(&file.validation_status));
+        }
+        Ok(files)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query recorded storage integrity audits. Superuser only.")]
+    fn file_audits(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on object_key"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = FileAuditOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<FileAuditOrderBy>,
+        #[graphql(description = "If set, only shows audits of files belonging to this imprint")]
+        imprint_id: Option<Uuid>,
+        #[graphql(description = "If set, only shows audits recorded in this run")]
+        audit_run_id: Option<Uuid>,
+        #[graphql(
+            default = false,
+            description = "Only show audits that found at least one problem"
+        )]
+        failures_only: Option<bool>,
+    ) -> ThothResult<Vec<FileAudit>> {
+        context.require_superuser()?;
+        FileAudit::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            vec![],
+            imprint_id,
+            audit_run_id,
+            vec![],
+            vec![],
+            failures_only,
+            None,
+        )
+    }
+
+    #[graphql(
+        description = "Check every stored file of an imprint against its S3 object, record the results and return them. Superuser only."
+    )]
+    async fn file_integrity_audit(
+        context: &Context,
+        #[graphql(description = "Thoth ID of the imprint whose files should be audited")]
+        imprint_id: Uuid,
+        #[graphql(
+            default = false,
+            description = "Only re-check files whose most recent audit found a problem"
+        )]
+        failures_only: Option<bool>,
+        #[graphql(
+            default = false,
+            description = "Read each object in full to compute its SHA-256 instead of relying on the checksum stored by S3"
+        )]
+        verify_checksums: Option<bool>,
+    ) -> ThothResult<Vec<FileAudit>> {
+        context.require_superuser()?;
+        let imprint = Imprint::from_id(&context.db, &imprint_id)?;
+        audit_imprint_files(
+            &context.db,
+            context.s3_client(),
+            &imprint,
+            failures_only.unwrap_or_default(),
+            verify_checksums.unwrap_or_default(),
+        )
+        .await
+        .map(|summary| summary.audits)
     }
 
     #[graphql(description = "Get the total number of publications")]
@@ -504,7 +599,7 @@ impl QueryRoot {
             description = "Specific types to filter by",
         )]
         publication_types: Option<Vec<PublicationType>>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Publication::count(
             &context.db,
             filter,
@@ -514,7 +609,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of publishers")]
@@ -537,7 +631,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Publisher>> {
+    ) -> ThothResult<Vec<Publisher>> {
         Publisher::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -552,15 +646,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single publisher using its ID")]
     fn publisher(
         context: &Context,
         #[graphql(description = "Thoth publisher ID to search on")] publisher_id: Uuid,
-    ) -> FieldResult<Publisher> {
-        Publisher::from_id(&context.db, &publisher_id).map_err(Into::into)
+    ) -> ThothResult<Publisher> {
+        Publisher::from_id(&context.db, &publisher_id)
     }
 
     #[graphql(description = "Get the total number of publishers")]
@@ -576,7 +669,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Publisher::count(
             &context.db,
             filter,
@@ -586,7 +679,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of imprints")]
@@ -609,7 +701,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Imprint>> {
+    ) -> ThothResult<Vec<Imprint>> {
         Imprint::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -624,15 +716,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single imprint using its ID")]
     fn imprint(
         context: &Context,
         #[graphql(description = "Thoth imprint ID to search on")] imprint_id: Uuid,
-    ) -> FieldResult<Imprint> {
-        Imprint::from_id(&context.db, &imprint_id).map_err(Into::into)
+    ) -> ThothResult<Imprint> {
+        Imprint::from_id(&context.db, &imprint_id)
     }
 
     #[graphql(description = "Get the total number of imprints")]
@@ -648,7 +739,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Imprint::count(
             &context.db,
             filter,
@@ -658,7 +749,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of contributors")]
@@ -676,7 +766,7 @@ impl QueryRoot {
             description = "The order in which to sort the results"
         )]
         order: Option<ContributorOrderBy>,
-    ) -> FieldResult<Vec<Contributor>> {
+    ) -> ThothResult<Vec<Contributor>> {
         Contributor::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -691,15 +781,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single contributor using its ID")]
     fn contributor(
         context: &Context,
         #[graphql(description = "Thoth contributor ID to search on")] contributor_id: Uuid,
-    ) -> FieldResult<Contributor> {
-        Contributor::from_id(&context.db, &contributor_id).map_err(Into::into)
+    ) -> ThothResult<Contributor> {
+        Contributor::from_id(&context.db, &contributor_id)
     }
 
     #[graphql(description = "Get the total number of contributors")]
@@ -710,9 +799,8 @@ impl QueryRoot {
             description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_name, last_name and orcid"
         )]
         filter: Option<String>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Contributor::count(&context.db, filter, vec![], vec![], vec![], None, None)
-            .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of contributions")]
@@ -735,7 +823,7 @@ impl QueryRoot {
             description = "Specific types to filter by",
         )]
         contribution_types: Option<Vec<ContributionType>>,
-    ) -> FieldResult<Vec<Contribution>> {
+    ) -> ThothResult<Vec<Contribution>> {
         Contribution::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -750,15 +838,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single contribution using its ID")]
     fn contribution(
         context: &Context,
         #[graphql(description = "Thoth contribution ID to search on")] contribution_id: Uuid,
-    ) -> FieldResult<Contribution> {
-        Contribution::from_id(&context.db, &contribution_id).map_err(Into::into)
+    ) -> ThothResult<Contribution> {
+        Contribution::from_id(&context.db, &contribution_id)
     }
 
     #[graphql(description = "Get the total number of contributions")]
@@ -769,7 +856,7 @@ impl QueryRoot {
             description = "Specific types to filter by",
         )]
         contribution_types: Option<Vec<ContributionType>>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Contribution::count(
             &context.db,
             None,
@@ -779,7 +866,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of series")]
@@ -807,7 +893,7 @@ impl QueryRoot {
             description = "Specific types to filter by",
         )]
         series_types: Option<Vec<SeriesType>>,
-    ) -> FieldResult<Vec<Series>> {
+    ) -> ThothResult<Vec<Series>> {
         Series::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -822,15 +908,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single series using its ID")]
     fn series(
         context: &Context,
         #[graphql(description = "Thoth series ID to search on")] series_id: Uuid,
-    ) -> FieldResult<Series> {
-        Series::from_id(&context.db, &series_id).map_err(Into::into)
+    ) -> ThothResult<Series> {
+        Series::from_id(&context.db, &series_id)
     }
 
     #[graphql(description = "Get the total number of series")]
@@ -851,7 +936,7 @@ impl QueryRoot {
             description = "Specific types to filter by",
         )]
         series_types: Option<Vec<SeriesType>>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Series::count(
             &context.db,
             filter,
@@ -861,7 +946,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of issues")]
@@ -879,7 +963,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Issue>> {
+    ) -> ThothResult<Vec<Issue>> {
         Issue::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -894,20 +978,19 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single issue using its ID")]
     fn issue(
         context: &Context,
         #[graphql(description = "Thoth issue ID to search on")] issue_id: Uuid,
-    ) -> FieldResult<Issue> {
-        Issue::from_id(&context.db, &issue_id).map_err(Into::into)
+    ) -> ThothResult<Issue> {
+        Issue::from_id(&context.db, &issue_id)
     }
 
     #[graphql(description = "Get the total number of issues")]
-    fn issue_count(context: &Context) -> FieldResult<i32> {
-        Issue::count(&context.db, None, vec![], vec![], vec![], None, None).map_err(Into::into)
+    fn issue_count(context: &Context) -> ThothResult<i32> {
+        Issue::count(&context.db, None, vec![], vec![], vec![], None, None)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -940,7 +1023,7 @@ impl QueryRoot {
             description = "Specific relations to filter by"
         )]
         language_relations: Option<Vec<LanguageRelation>>,
-    ) -> FieldResult<Vec<Language>> {
+    ) -> ThothResult<Vec<Language>> {
         let mut relations = language_relations.unwrap_or_default();
         if let Some(relation) = language_relation {
             relations.push(relation);
@@ -959,15 +1042,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single language using its ID")]
     fn language(
         context: &Context,
         #[graphql(description = "Thoth language ID to search on")] language_id: Uuid,
-    ) -> FieldResult<Language> {
-        Language::from_id(&context.db, &language_id).map_err(Into::into)
+    ) -> ThothResult<Language> {
+        Language::from_id(&context.db, &language_id)
     }
 
     #[graphql(description = "Get the total number of languages associated to works")]
@@ -987,7 +1069,7 @@ impl QueryRoot {
             description = "Specific relations to filter by"
         )]
         language_relations: Option<Vec<LanguageRelation>>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         let mut relations = language_relations.unwrap_or_default();
         if let Some(relation) = language_relation {
             relations.push(relation);
@@ -1001,7 +1083,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of locations")]
@@ -1024,7 +1105,7 @@ impl QueryRoot {
             description = "Specific platforms to filter by"
         )]
         location_platforms: Option<Vec<LocationPlatform>>,
-    ) -> FieldResult<Vec<Location>> {
+    ) -> ThothResult<Vec<Location>> {
         Location::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1039,15 +1120,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single location using its ID")]
     fn location(
         context: &Context,
         #[graphql(description = "Thoth location ID to search on")] location_id: Uuid,
-    ) -> FieldResult<Location> {
-        Location::from_id(&context.db, &location_id).map_err(Into::into)
+    ) -> ThothResult<Location> {
+        Location::from_id(&context.db, &location_id)
     }
 
     #[graphql(description = "Get the total number of locations associated to works")]
@@ -1058,7 +1138,7 @@ impl QueryRoot {
             description = "Specific platforms to filter by"
         )]
         location_platforms: Option<Vec<LocationPlatform>>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Location::count(
             &context.db,
             None,
@@ -1068,7 +1148,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of prices")]
@@ -1091,7 +1170,11 @@ impl QueryRoot {
             description = "Specific currencies to filter by"
         )]
         currency_codes: Option<Vec<CurrencyCode>>,
-    ) -> FieldResult<Vec<Price>> {
+        #[graphql(
+            description = "If set, only shows prices which apply on this date (prices with no effective dates always apply)"
+        )]
+        on_date: Option<NaiveDate>,
+    ) -> ThothResult<Vec<Price>> {
         Price::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1103,18 +1186,17 @@ impl QueryRoot {
             None,
             currency_codes.unwrap_or_default(),
             vec![],
-            None,
+            on_date,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single price using its ID")]
     fn price(
         context: &Context,
         #[graphql(description = "Thoth price ID to search on")] price_id: Uuid,
-    ) -> FieldResult<Price> {
-        Price::from_id(&context.db, &price_id).map_err(Into::into)
+    ) -> ThothResult<Price> {
+        Price::from_id(&context.db, &price_id)
     }
 
     #[graphql(description = "Get the total number of prices associated to works")]
@@ -1125,17 +1207,193 @@ impl QueryRoot {
             description = "Specific currencies to filter by"
         )]
         currency_codes: Option<Vec<CurrencyCode>>,
-    ) -> FieldResult<i32> {
+        #[graphql(
+            description = "If set, only shows prices which apply on this date (prices with no effective dates always apply)"
+        )]
+        on_date: Option<NaiveDate>,
+    ) -> ThothResult<i32> {
         Price::count(
             &context.db,
             None,
             vec![],
             currency_codes.unwrap_or_default(),
             vec![],
+            on_date,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query the full list of product form details")]
+    fn product_form_details(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = ProductFormDetailOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<ProductFormDetailOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of product form detail to filter by"
+        )]
+        detail_types: Option<Vec<ProductFormDetailType>>,
+    ) -> ThothResult<Vec<ProductFormDetail>> {
+        ProductFormDetail::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            detail_types.unwrap_or_default(),
+            vec![],
             None,
             None,
         )
-        .map_err(Into::into)
+    }
+
+    #[graphql(description = "Query a single product form detail using its ID")]
+    fn product_form_detail(
+        context: &Context,
+        #[graphql(description = "Thoth product form detail ID to search on")]
+        product_form_detail_id: Uuid,
+    ) -> ThothResult<ProductFormDetail> {
+        ProductFormDetail::from_id(&context.db, &product_form_detail_id)
+    }
+
+    #[graphql(
+        description = "Get the total number of product form details associated to publications"
+    )]
+    fn product_form_detail_count(
+        context: &Context,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of product form detail to filter by"
+        )]
+        detail_types: Option<Vec<ProductFormDetailType>>,
+    ) -> ThothResult<i32> {
+        ProductFormDetail::count(
+            &context.db,
+            None,
+            vec![],
+            detail_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query the full list of sales rights")]
+    fn sales_rights(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = SalesRightsOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<SalesRightsOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of sales rights to filter by"
+        )]
+        sales_rights_types: Option<Vec<SalesRightsType>>,
+    ) -> ThothResult<Vec<SalesRights>> {
+        SalesRights::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            sales_rights_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query a single sales rights record using its ID")]
+    fn sales_right(
+        context: &Context,
+        #[graphql(description = "Thoth sales rights ID to search on")] sales_rights_id: Uuid,
+    ) -> ThothResult<SalesRights> {
+        SalesRights::from_id(&context.db, &sales_rights_id)
+    }
+
+    #[graphql(description = "Get the total number of sales rights associated to publications")]
+    fn sales_rights_count(
+        context: &Context,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of sales rights to filter by"
+        )]
+        sales_rights_types: Option<Vec<SalesRightsType>>,
+    ) -> ThothResult<i32> {
+        SalesRights::count(
+            &context.db,
+            None,
+            vec![],
+            sales_rights_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(
+        description = "Query the catalogue of Creative Commons licenses which can be used as work or publication licenses"
+    )]
+    fn creative_commons_licenses() -> Vec<CreativeCommonsLicense> {
+        CREATIVE_COMMONS_LICENSES.to_vec()
+    }
+
+    #[graphql(
+        description = "Browse or search the controlled vocabulary of a subject type. Returns the top-level headings, the children of parentCode if given, or the headings whose code starts with, or label contains, filter. BIC and BISAC are listed down to their main headings only, and Thema codes below the top level have no labels"
+    )]
+    fn subject_vocabulary(
+        #[graphql(description = "Subject scheme to browse")] subject_type: SubjectType,
+        #[graphql(
+            default = "".to_string(),
+            description = "A code prefix or label fragment to search for, e.g. for autocompletion"
+        )]
+        filter: Option<String>,
+        #[graphql(description = "Code of the heading whose children should be returned")]
+        parent_code: Option<String>,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+    ) -> ThothResult<Vec<SubjectHeading>> {
+        let filter = filter.unwrap_or_default();
+        let headings = if let Some(code) = parent_code {
+            SubjectHeading::new(subject_type, &code)
+                .ok_or_else(|| ThothError::InvalidSubjectCode {
+                    input: code,
+                    subject_type: subject_type.to_string(),
+                })?
+                .narrower_headings()
+        } else if !filter.is_empty() {
+            SubjectHeading::search(subject_type, &filter)
+        } else {
+            SubjectHeading::top_level(subject_type)
+        };
+        Ok(headings
+            .into_iter()
+            .take(limit.unwrap_or_default().max(0) as usize)
+            .collect())
     }
 
     #[graphql(description = "Query the full list of subjects")]
@@ -1163,7 +1421,7 @@ impl QueryRoot {
             description = "Specific types to filter by",
         )]
         subject_types: Option<Vec<SubjectType>>,
-    ) -> FieldResult<Vec<Subject>> {
+    ) -> ThothResult<Vec<Subject>> {
         Subject::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1178,15 +1436,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single subject using its ID")]
     fn subject(
         context: &Context,
         #[graphql(description = "Thoth subject ID to search on")] subject_id: Uuid,
-    ) -> FieldResult<Subject> {
-        Subject::from_id(&context.db, &subject_id).map_err(Into::into)
+    ) -> ThothResult<Subject> {
+        Subject::from_id(&context.db, &subject_id)
     }
 
     #[graphql(description = "Get the total number of subjects associated to works")]
@@ -1202,7 +1459,7 @@ impl QueryRoot {
             description = "Specific types to filter by",
         )]
         subject_types: Option<Vec<SubjectType>>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Subject::count(
             &context.db,
             filter,
@@ -1212,7 +1469,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of institutions")]
@@ -1230,7 +1486,7 @@ impl QueryRoot {
             description = "The order in which to sort the results"
         )]
         order: Option<InstitutionOrderBy>,
-    ) -> FieldResult<Vec<Institution>> {
+    ) -> ThothResult<Vec<Institution>> {
         Institution::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1245,15 +1501,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single institution using its ID")]
     fn institution(
         context: &Context,
         #[graphql(description = "Thoth institution ID to search on")] institution_id: Uuid,
-    ) -> FieldResult<Institution> {
-        Institution::from_id(&context.db, &institution_id).map_err(Into::into)
+    ) -> ThothResult<Institution> {
+        Institution::from_id(&context.db, &institution_id)
     }
 
     #[graphql(description = "Get the total number of institutions")]
@@ -1264,9 +1519,27 @@ impl QueryRoot {
             description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on institution_name, ror and institution_doi"
         )]
         filter: Option<String>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Institution::count(&context.db, filter, vec![], vec![], vec![], None, None)
-            .map_err(Into::into)
+    }
+
+    #[graphql(
+        description = "Search the local Crossref Funder Registry snapshot for active funders whose name, alternative names or DOI contain filter, e.g. for autocompletion"
+    )]
+    fn registered_funders(
+        context: &Context,
+        #[graphql(description = "A name fragment or DOI to search for")] filter: String,
+        #[graphql(default = 10, description = "The number of items to return")] limit: Option<i32>,
+    ) -> ThothResult<Vec<RegisteredFunder>> {
+        RegisteredFunder::search(&context.db, &filter, limit.unwrap_or_default())
+    }
+
+    #[graphql(description = "Query a single funder in the local Crossref Funder Registry snapshot")]
+    fn registered_funder(
+        context: &Context,
+        #[graphql(description = "Funder Registry DOI to search on")] funder_doi: Doi,
+    ) -> ThothResult<Option<RegisteredFunder>> {
+        RegisteredFunder::from_doi(&context.db, &funder_doi)
     }
 
     #[graphql(description = "Query the full list of fundings")]
@@ -1284,7 +1557,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Funding>> {
+    ) -> ThothResult<Vec<Funding>> {
         Funding::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1299,20 +1572,19 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single funding using its ID")]
     fn funding(
         context: &Context,
         #[graphql(description = "Thoth funding ID to search on")] funding_id: Uuid,
-    ) -> FieldResult<Funding> {
-        Funding::from_id(&context.db, &funding_id).map_err(Into::into)
+    ) -> ThothResult<Funding> {
+        Funding::from_id(&context.db, &funding_id)
     }
 
     #[graphql(description = "Get the total number of funding instances associated to works")]
-    fn funding_count(context: &Context) -> FieldResult<i32> {
-        Funding::count(&context.db, None, vec![], vec![], vec![], None, None).map_err(Into::into)
+    fn funding_count(context: &Context) -> ThothResult<i32> {
+        Funding::count(&context.db, None, vec![], vec![], vec![], None, None)
     }
 
     #[graphql(description = "Query the full list of affiliations")]
@@ -1330,7 +1602,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Affiliation>> {
+    ) -> ThothResult<Vec<Affiliation>> {
         Affiliation::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1345,21 +1617,286 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single affiliation using its ID")]
     fn affiliation(
         context: &Context,
         #[graphql(description = "Thoth affiliation ID to search on")] affiliation_id: Uuid,
-    ) -> FieldResult<Affiliation> {
-        Affiliation::from_id(&context.db, &affiliation_id).map_err(Into::into)
+    ) -> ThothResult<Affiliation> {
+        Affiliation::from_id(&context.db, &affiliation_id)
     }
 
     #[graphql(description = "Get the total number of affiliations")]
-    fn affiliation_count(context: &Context) -> FieldResult<i32> {
+    fn affiliation_count(context: &Context) -> ThothResult<i32> {
         Affiliation::count(&context.db, None, vec![], vec![], vec![], None, None)
-            .map_err(Into::into)
+    }
+
+    #[graphql(description = "Query the full list of audiences")]
+    fn audiences(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = AudienceOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<AudienceOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific audience codes to filter by"
+        )]
+        audience_codes: Option<Vec<AudienceCode>>,
+    ) -> ThothResult<Vec<Audience>> {
+        Audience::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            audience_codes.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query a single audience using its ID")]
+    fn audience(
+        context: &Context,
+        #[graphql(description = "Thoth audience ID to search on")] audience_id: Uuid,
+    ) -> ThothResult<Audience> {
+        Audience::from_id(&context.db, &audience_id)
+    }
+
+    #[graphql(description = "Get the total number of audiences associated to works")]
+    fn audience_count(
+        context: &Context,
+        #[graphql(
+            default = vec![],
+            description = "Specific audience codes to filter by"
+        )]
+        audience_codes: Option<Vec<AudienceCode>>,
+    ) -> ThothResult<i32> {
+        Audience::count(
+            &context.db,
+            None,
+            vec![],
+            audience_codes.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query the full list of work dates")]
+    fn work_dates(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = WorkDateOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<WorkDateOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific date types to filter by"
+        )]
+        date_types: Option<Vec<WorkDateType>>,
+    ) -> ThothResult<Vec<WorkDate>> {
+        WorkDate::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            date_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query a single work date using its ID")]
+    fn work_date(
+        context: &Context,
+        #[graphql(description = "Thoth work date ID to search on")] work_date_id: Uuid,
+    ) -> ThothResult<WorkDate> {
+        WorkDate::from_id(&context.db, &work_date_id)
+    }
+
+    #[graphql(description = "Get the total number of lifecycle dates associated to works")]
+    fn work_date_count(
+        context: &Context,
+        #[graphql(
+            default = vec![],
+            description = "Specific date types to filter by"
+        )]
+        date_types: Option<Vec<WorkDateType>>,
+    ) -> ThothResult<i32> {
+        WorkDate::count(
+            &context.db,
+            None,
+            vec![],
+            date_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query the full list of name variants")]
+    fn name_variants(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = NameVariantOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<NameVariantOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific variant types to filter by"
+        )]
+        variant_types: Option<Vec<NameVariantType>>,
+    ) -> ThothResult<Vec<NameVariant>> {
+        NameVariant::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            variant_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query a single name variant using its ID")]
+    fn name_variant(
+        context: &Context,
+        #[graphql(description = "Thoth name variant ID to search on")] name_variant_id: Uuid,
+    ) -> ThothResult<NameVariant> {
+        NameVariant::from_id(&context.db, &name_variant_id)
+    }
+
+    #[graphql(
+        description = "Get the total number of name variants associated to contributors and contributions"
+    )]
+    fn name_variant_count(
+        context: &Context,
+        #[graphql(
+            default = vec![],
+            description = "Specific variant types to filter by"
+        )]
+        variant_types: Option<Vec<NameVariantType>>,
+    ) -> ThothResult<i32> {
+        NameVariant::count(
+            &context.db,
+            None,
+            vec![],
+            variant_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query the full list of CRediT roles")]
+    fn credit_roles(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = CreditRoleOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<CreditRoleOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific CRediT roles to filter by"
+        )]
+        role_types: Option<Vec<CreditRoleType>>,
+    ) -> ThothResult<Vec<CreditRole>> {
+        CreditRole::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            role_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
+    }
+
+    #[graphql(description = "Query a single CRediT role using its ID")]
+    fn credit_role(
+        context: &Context,
+        #[graphql(description = "Thoth CRediT role ID to search on")] credit_role_id: Uuid,
+    ) -> ThothResult<CreditRole> {
+        CreditRole::from_id(&context.db, &credit_role_id)
+    }
+
+    #[graphql(description = "Get the total number of CRediT roles associated to contributions")]
+    fn credit_role_count(
+        context: &Context,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific CRediT roles to filter by"
+        )]
+        role_types: Option<Vec<CreditRoleType>>,
+    ) -> ThothResult<i32> {
+        CreditRole::count(
+            &context.db,
+            None,
+            publishers.unwrap_or_default(),
+            role_types.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+        )
     }
 
     #[graphql(description = "Query the full list of references")]
@@ -1377,7 +1914,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Reference>> {
+    ) -> ThothResult<Vec<Reference>> {
         Reference::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1392,20 +1929,19 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single reference using its ID")]
     fn reference(
         context: &Context,
         #[graphql(description = "Thoth reference ID to search on")] reference_id: Uuid,
-    ) -> FieldResult<Reference> {
-        Reference::from_id(&context.db, &reference_id).map_err(Into::into)
+    ) -> ThothResult<Reference> {
+        Reference::from_id(&context.db, &reference_id)
     }
 
     #[graphql(description = "Get the total number of references")]
-    fn reference_count(context: &Context) -> FieldResult<i32> {
-        Reference::count(&context.db, None, vec![], vec![], vec![], None, None).map_err(Into::into)
+    fn reference_count(context: &Context) -> ThothResult<i32> {
+        Reference::count(&context.db, None, vec![], vec![], vec![], None, None)
     }
 
     #[graphql(description = "Query the full list of additional resources")]
@@ -1423,7 +1959,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<AdditionalResource>> {
+    ) -> ThothResult<Vec<AdditionalResource>> {
         AdditionalResource::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1438,7 +1974,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single additional resource using its ID")]
@@ -1446,14 +1981,13 @@ impl QueryRoot {
         context: &Context,
         #[graphql(description = "Thoth additional resource ID to search on")]
         additional_resource_id: Uuid,
-    ) -> FieldResult<AdditionalResource> {
-        AdditionalResource::from_id(&context.db, &additional_resource_id).map_err(Into::into)
+    ) -> ThothResult<AdditionalResource> {
+        AdditionalResource::from_id(&context.db, &additional_resource_id)
     }
 
     #[graphql(description = "Get the total number of additional resources")]
-    fn additional_resource_count(context: &Context) -> FieldResult<i32> {
+    fn additional_resource_count(context: &Context) -> ThothResult<i32> {
         AdditionalResource::count(&context.db, None, vec![], vec![], vec![], None, None)
-            .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of awards")]
@@ -1471,7 +2005,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Award>> {
+    ) -> ThothResult<Vec<Award>> {
         Award::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1486,20 +2020,19 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single award using its ID")]
     fn award(
         context: &Context,
         #[graphql(description = "Thoth award ID to search on")] award_id: Uuid,
-    ) -> FieldResult<Award> {
-        Award::from_id(&context.db, &award_id).map_err(Into::into)
+    ) -> ThothResult<Award> {
+        Award::from_id(&context.db, &award_id)
     }
 
     #[graphql(description = "Get the total number of awards")]
-    fn award_count(context: &Context) -> FieldResult<i32> {
-        Award::count(&context.db, None, vec![], vec![], vec![], None, None).map_err(Into::into)
+    fn award_count(context: &Context) -> ThothResult<i32> {
+        Award::count(&context.db, None, vec![], vec![], vec![], None, None)
     }
 
     #[graphql(description = "Query the full list of endorsements")]
@@ -1517,7 +2050,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Endorsement>> {
+    ) -> ThothResult<Vec<Endorsement>> {
         Endorsement::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1532,21 +2065,19 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single endorsement using its ID")]
     fn endorsement(
         context: &Context,
         #[graphql(description = "Thoth endorsement ID to search on")] endorsement_id: Uuid,
-    ) -> FieldResult<Endorsement> {
-        Endorsement::from_id(&context.db, &endorsement_id).map_err(Into::into)
+    ) -> ThothResult<Endorsement> {
+        Endorsement::from_id(&context.db, &endorsement_id)
     }
 
     #[graphql(description = "Get the total number of endorsements")]
-    fn endorsement_count(context: &Context) -> FieldResult<i32> {
+    fn endorsement_count(context: &Context) -> ThothResult<i32> {
         Endorsement::count(&context.db, None, vec![], vec![], vec![], None, None)
-            .map_err(Into::into)
     }
 
     #[graphql(description = "Query the full list of book reviews")]
@@ -1564,7 +2095,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<BookReview>> {
+    ) -> ThothResult<Vec<BookReview>> {
         BookReview::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1579,20 +2110,19 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single book review using its ID")]
     fn book_review(
         context: &Context,
         #[graphql(description = "Thoth book review ID to search on")] book_review_id: Uuid,
-    ) -> FieldResult<BookReview> {
-        BookReview::from_id(&context.db, &book_review_id).map_err(Into::into)
+    ) -> ThothResult<BookReview> {
+        BookReview::from_id(&context.db, &book_review_id)
     }
 
     #[graphql(description = "Get the total number of book reviews")]
-    fn book_review_count(context: &Context) -> FieldResult<i32> {
-        BookReview::count(&context.db, None, vec![], vec![], vec![], None, None).map_err(Into::into)
+    fn book_review_count(context: &Context) -> ThothResult<i32> {
+        BookReview::count(&context.db, None, vec![], vec![], vec![], None, None)
     }
 
     #[graphql(description = "Query the full list of featured videos")]
@@ -1610,7 +2140,7 @@ impl QueryRoot {
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<WorkFeaturedVideo>> {
+    ) -> ThothResult<Vec<WorkFeaturedVideo>> {
         WorkFeaturedVideo::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1625,7 +2155,6 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single featured video using its ID")]
@@ -1633,14 +2162,13 @@ impl QueryRoot {
         context: &Context,
         #[graphql(description = "Thoth featured video ID to search on")]
         work_featured_video_id: Uuid,
-    ) -> FieldResult<WorkFeaturedVideo> {
-        WorkFeaturedVideo::from_id(&context.db, &work_featured_video_id).map_err(Into::into)
+    ) -> ThothResult<WorkFeaturedVideo> {
+        WorkFeaturedVideo::from_id(&context.db, &work_featured_video_id)
     }
 
     #[graphql(description = "Get the total number of featured videos")]
-    fn work_featured_video_count(context: &Context) -> FieldResult<i32> {
+    fn work_featured_video_count(context: &Context) -> ThothResult<i32> {
         WorkFeaturedVideo::count(&context.db, None, vec![], vec![], vec![], None, None)
-            .map_err(Into::into)
     }
 
     #[graphql(description = "Query a title by its ID")]
@@ -1648,8 +2176,8 @@ impl QueryRoot {
         context: &Context,
         title_id: Uuid,
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Title> {
-        let mut title = Title::from_id(&context.db, &title_id).map_err(FieldError::from)?;
+    ) -> ThothResult<Title> {
+        let mut title = Title::from_id(&context.db, &title_id)?;
         let markup = markup_format.ok_or(ThothError::MissingMarkupFormat)?;
         title.title = convert_from_jats(&title.title, markup, ConversionLimit::Title)?;
         if let Some(subtitle) = &title.subtitle {
@@ -1684,7 +2212,7 @@ impl QueryRoot {
             description = "If set shows result with this markup format"
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Vec<Title>> {
+    ) -> ThothResult<Vec<Title>> {
         let mut titles = Title::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1698,8 +2226,7 @@ impl QueryRoot {
             vec![],
             None,
             None,
-        )
-        .map_err(FieldError::from)?;
+        )?;
 
         let markup = markup_format.ok_or(ThothError::MissingMarkupFormat)?;
         for title in &mut titles {
@@ -1722,9 +2249,8 @@ impl QueryRoot {
             description = "If set shows results with this markup format"
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Abstract> {
-        let mut r#abstract =
-            Abstract::from_id(&context.db, &abstract_id).map_err(FieldError::from)?;
+    ) -> ThothResult<Abstract> {
+        let mut r#abstract = Abstract::from_id(&context.db, &abstract_id)?;
         let markup = markup_format.ok_or(ThothError::MissingMarkupFormat)?;
         r#abstract.content =
             convert_from_jats(&r#abstract.content, markup, ConversionLimit::Abstract)?;
@@ -1757,7 +2283,7 @@ impl QueryRoot {
             description = "If set shows result with this markup format"
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Vec<Abstract>> {
+    ) -> ThothResult<Vec<Abstract>> {
         let mut abstracts = Abstract::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1771,8 +2297,7 @@ impl QueryRoot {
             vec![],
             None,
             None,
-        )
-        .map_err(FieldError::from)?;
+        )?;
 
         let markup = markup_format.ok_or(ThothError::MissingMarkupFormat)?;
         for r#abstract in &mut abstracts {
@@ -1792,9 +2317,8 @@ impl QueryRoot {
             description = "If set shows result with this markup format"
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Biography> {
-        let mut biography =
-            Biography::from_id(&context.db, &biography_id).map_err(FieldError::from)?;
+    ) -> ThothResult<Biography> {
+        let mut biography = Biography::from_id(&context.db, &biography_id)?;
         let markup = markup_format.ok_or(ThothError::MissingMarkupFormat)?;
         biography.content =
             convert_from_jats(&biography.content, markup, ConversionLimit::Biography)?;
@@ -1827,7 +2351,7 @@ impl QueryRoot {
             description = "If set shows result with this markup format"
         )]
         markup_format: Option<MarkupFormat>,
-    ) -> FieldResult<Vec<Biography>> {
+    ) -> ThothResult<Vec<Biography>> {
         let mut biographies = Biography::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1841,8 +2365,7 @@ impl QueryRoot {
             vec![],
             None,
             None,
-        )
-        .map_err(FieldError::from)?;
+        )?;
 
         let markup = markup_format.ok_or(ThothError::MissingMarkupFormat)?;
         for biography in &mut biographies {
@@ -1873,7 +2396,7 @@ impl QueryRoot {
             description = "Specific types to filter by",
         )]
         contact_types: Option<Vec<ContactType>>,
-    ) -> FieldResult<Vec<Contact>> {
+    ) -> ThothResult<Vec<Contact>> {
         Contact::all(
             &context.db,
             limit.unwrap_or_default(),
@@ -1888,15 +2411,14 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Query a single contact using its ID")]
     fn contact(
         context: &Context,
         #[graphql(description = "Thoth contact ID to search on")] contact_id: Uuid,
-    ) -> FieldResult<Contact> {
-        Contact::from_id(&context.db, &contact_id).map_err(Into::into)
+    ) -> ThothResult<Contact> {
+        Contact::from_id(&context.db, &contact_id)
     }
 
     #[graphql(description = "Get the total number of contacts")]
@@ -1907,7 +2429,7 @@ impl QueryRoot {
             description = "Specific types to filter by"
         )]
         contact_types: Option<Vec<ContactType>>,
-    ) -> FieldResult<i32> {
+    ) -> ThothResult<i32> {
         Contact::count(
             &context.db,
             None,
@@ -1917,11 +2439,10 @@ impl QueryRoot {
             None,
             None,
         )
-        .map_err(Into::into)
     }
 
     #[graphql(description = "Get the total number of contacts")]
-    fn me(context: &Context) -> FieldResult<Me> {
+    fn me(context: &Context) -> ThothResult<Me> {
         let user = context.require_authentication()?;
         user.to_me(context)
     }
@@ -8,29 +8,42 @@ use crate::model::tests::db as test_db;
 use crate::model::{
     additional_resource::{AdditionalResource, NewAdditionalResource, ResourceType},
     affiliation::{Affiliation, NewAffiliation, PatchAffiliation},
+    audience::{
+        Audience, AudienceCode, AudienceRangePrecision, AudienceRangeQualifier, NewAudience,
+        PatchAudience,
+    },
     award::{Award, AwardRole, NewAward},
     biography::{Biography, NewBiography, PatchBiography},
     book_review::{BookReview, NewBookReview},
     contact::{Contact, ContactType, NewContact, PatchContact},
     contribution::{Contribution, ContributionType, NewContribution, PatchContribution},
     contributor::{Contributor, NewContributor, PatchContributor},
+    credit_role::{CreditRole, CreditRoleDegree, CreditRoleType, NewCreditRole, PatchCreditRole},
     endorsement::{Endorsement, NewEndorsement, PatchEndorsement},
     funding::{Funding, NewFunding, PatchFunding},
     imprint::{Imprint, NewImprint, PatchImprint},
-    institution::{Institution, NewInstitution, PatchInstitution},
+    institution::{
+        funder_registry::RegisteredFunder, Institution, NewInstitution, PatchInstitution,
+    },
     issue::{Issue, NewIssue, PatchIssue},
     language::{Language, LanguageCode, LanguageRelation, NewLanguage, PatchLanguage},
     locale::LocaleCode,
     location::{Location, LocationPlatform, NewLocation, PatchLocation},
-    price::{CurrencyCode, NewPrice, PatchPrice, Price},
+    name_variant::{NameVariant, NameVariantType, NewNameVariant, PatchNameVariant},
+    price::{CurrencyCode, NewPrice, PatchPrice, Price, PriceType},
+    product_form_detail::{
+        NewProductFormDetail, PatchProductFormDetail, ProductFormDetail, ProductFormDetailType,
+    },
     publication::{NewPublication, PatchPublication, Publication, PublicationType},
     publisher::{NewPublisher, PatchPublisher, Publisher},
     r#abstract::{Abstract, AbstractType, NewAbstract, PatchAbstract},
     reference::{NewReference, PatchReference, Reference},
+    sales_rights::{NewSalesRights, PatchSalesRights, SalesRights, SalesRightsType},
     series::{NewSeries, PatchSeries, Series, SeriesType},
     subject::{NewSubject, PatchSubject, Subject, SubjectType},
     title::{NewTitle, PatchTitle, Title},
     work::{NewWork, PatchWork, Work, WorkStatus, WorkType},
+    work_date::{NewWorkDate, PatchWorkDate, WorkDate, WorkDateType},
     work_relation::{NewWorkRelation, PatchWorkRelation, RelationType, WorkRelation},
     CountryCode, Crud, Doi, Isbn, Orcid, Ror,
 };
@@ -228,6 +241,7 @@ fn make_new_imprint(publisher_id: Uuid) -> NewImprint {
         default_currency: None,
         default_place: None,
         default_locale: None,
+        reject_invalid_files: Some(false),
     }
 }
 
@@ -262,6 +276,7 @@ fn make_new_book_work(imprint_id: Uuid, doi: Doi) -> NewWork {
         first_page: None,
         last_page: None,
         page_interval: None,
+        expose_latest_file_url: Some(true),
     }
 }
 
@@ -301,6 +316,7 @@ fn make_new_work(imprint_id: Uuid, work_type: WorkType, doi: Doi) -> NewWork {
         first_page: None,
         last_page: None,
         page_interval: None,
+        expose_latest_file_url: Some(true),
     }
 }
 
@@ -456,6 +472,9 @@ fn make_new_publication(work_id: Uuid) -> NewPublication {
         accessibility_additional_standard: None,
         accessibility_exception: None,
         accessibility_report_url: None,
+        license: None,
+        access_status: None,
+        embargo_end_date: None,
     }
 }
 
@@ -478,13 +497,18 @@ fn make_new_price(publication_id: Uuid) -> NewPrice {
         publication_id,
         currency_code: CurrencyCode::Usd,
         unit_price: 12.34,
+        price_type: Some(PriceType::Rrp),
+        tax_inclusive: Some(true),
+        countries_included: None,
+        effective_from: None,
+        effective_until: None,
     }
 }
 
 fn make_new_subject(work_id: Uuid, subject_ordinal: i32) -> NewSubject {
     NewSubject {
         work_id,
-        subject_type: SubjectType::Bic,
+        subject_type: SubjectType::Keyword,
         subject_code: format!("CODE-{subject_ordinal}"),
         subject_ordinal,
     }
@@ -989,6 +1013,7 @@ fn patch_imprint(imprint: &Imprint) -> PatchImprint {
         default_currency: imprint.default_currency,
         default_place: imprint.default_place.clone(),
         default_locale: imprint.default_locale,
+        reject_invalid_files: Some(imprint.reject_invalid_files),
     }
 }
 
@@ -1035,6 +1060,9 @@ fn patch_publication(publication: &Publication) -> PatchPublication {
         accessibility_additional_standard: publication.accessibility_additional_standard,
         accessibility_exception: publication.accessibility_exception,
         accessibility_report_url: publication.accessibility_report_url.clone(),
+        license: publication.license.clone(),
+        access_status: publication.access_status,
+        embargo_end_date: publication.embargo_end_date,
     }
 }
 
@@ -1113,6 +1141,11 @@ fn patch_price(price: &Price) -> PatchPrice {
         publication_id: price.publication_id,
         currency_code: price.currency_code,
         unit_price: price.unit_price + 1.0,
+        price_type: Some(price.price_type),
+        tax_inclusive: Some(price.tax_inclusive),
+        countries_included: price.countries_included.clone(),
+        effective_from: price.effective_from,
+        effective_until: price.effective_until,
     }
 }
 
@@ -1362,7 +1395,7 @@ fn assert_publication_resolvers(publication: &Publication, context: &Context) {
         publication.accessibility_report_url.as_ref()
     );
     assert!(!publication
-        .prices(context, Some(10), Some(0), None, None)
+        .prices(context, Some(10), Some(0), None, None, None)
         .unwrap()
         .is_empty());
     assert!(!publication
@@ -2468,6 +2501,871 @@ fn graphql_update_endorsement_supports_author_role_markup() {
         .contains("<italic>"));
 }
 
+#[test]
+fn graphql_sales_rights_roundtrip_on_publication() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-sales-rights");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let sales_rights = create_with_data(
+        &schema,
+        &context,
+        "createSalesRights",
+        "NewSalesRights",
+        "salesRightsId salesRightsType countriesIncluded countriesExcluded",
+        NewSalesRights {
+            publication_id: seed.publication_id,
+            sales_rights_type: SalesRightsType::ForSaleNonExclusive,
+            countries_included: None,
+            countries_excluded: vec![CountryCode::Usa],
+        },
+    );
+    assert_eq!(
+        sales_rights["salesRightsType"].as_str(),
+        Some("FOR_SALE_NON_EXCLUSIVE")
+    );
+    assert!(sales_rights["countriesIncluded"].is_null());
+    assert_eq!(sales_rights["countriesExcluded"][0].as_str(), Some("USA"));
+    let sales_rights_id = json_uuid(&sales_rights["salesRightsId"]);
+
+    let updated = update_with_data(
+        &schema,
+        &context,
+        "updateSalesRights",
+        "PatchSalesRights",
+        "salesRightsType countriesIncluded countriesExcluded",
+        PatchSalesRights {
+            sales_rights_id,
+            publication_id: seed.publication_id,
+            sales_rights_type: SalesRightsType::ForSaleExclusive,
+            countries_included: Some(vec![CountryCode::Gbr, CountryCode::Irl]),
+            countries_excluded: vec![],
+        },
+    );
+    assert_eq!(
+        updated["salesRightsType"].as_str(),
+        Some("FOR_SALE_EXCLUSIVE")
+    );
+    assert_eq!(updated["countriesIncluded"][1].as_str(), Some("IRL"));
+    assert_eq!(
+        updated["countriesExcluded"].as_array().map(Vec::len),
+        Some(0)
+    );
+
+    let query = r#"
+query PublicationSalesRights($publicationId: Uuid!) {
+  publication(publicationId: $publicationId) {
+    salesRights { salesRightsId }
+  }
+  salesRightsCount
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(&mut vars, "publicationId", seed.publication_id);
+    let data = execute_graphql(&schema, &context, query, Some(vars));
+    assert_eq!(
+        data["publication"]["salesRights"][0]["salesRightsId"].as_str(),
+        Some(sales_rights_id.to_string().as_str())
+    );
+    assert_eq!(data["salesRightsCount"].as_i64(), Some(1));
+
+    delete_with_id(
+        &schema,
+        &context,
+        "deleteSalesRights",
+        "salesRightsId",
+        sales_rights_id,
+        "salesRightsId",
+    );
+    assert!(SalesRights::from_id(pool.as_ref(), &sales_rights_id).is_err());
+}
+
+#[test]
+fn graphql_audience_roundtrip_on_work() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-audience");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let audience = create_with_data(
+        &schema,
+        &context,
+        "createAudience",
+        "NewAudience",
+        "audienceId audienceCode audienceRangeQualifier audienceRangePrecision audienceRangeFrom audienceRangeTo",
+        NewAudience {
+            work_id: seed.book_work_id,
+            audience_code: AudienceCode::Children,
+            audience_range_qualifier: Some(AudienceRangeQualifier::InterestAgeYears),
+            audience_range_precision: Some(AudienceRangePrecision::From),
+            audience_range_from: Some("7".to_string()),
+            audience_range_to: Some("11".to_string()),
+        },
+    );
+    assert_eq!(audience["audienceCode"].as_str(), Some("CHILDREN"));
+    assert_eq!(
+        audience["audienceRangeQualifier"].as_str(),
+        Some("INTEREST_AGE_YEARS")
+    );
+    assert_eq!(audience["audienceRangeTo"].as_str(), Some("11"));
+    let audience_id = json_uuid(&audience["audienceId"]);
+
+    let updated = update_with_data(
+        &schema,
+        &context,
+        "updateAudience",
+        "PatchAudience",
+        "audienceCode audienceRangeQualifier audienceRangeFrom",
+        PatchAudience {
+            audience_id,
+            work_id: seed.book_work_id,
+            audience_code: AudienceCode::HigherEducation,
+            audience_range_qualifier: None,
+            audience_range_precision: None,
+            audience_range_from: None,
+            audience_range_to: None,
+        },
+    );
+    assert_eq!(updated["audienceCode"].as_str(), Some("HIGHER_EDUCATION"));
+    assert!(updated["audienceRangeQualifier"].is_null());
+    assert!(updated["audienceRangeFrom"].is_null());
+
+    let query = r#"
+query WorkAudiences($workId: Uuid!) {
+  work(workId: $workId) {
+    audiences { audienceId }
+  }
+  audienceCount(audienceCodes: [HIGHER_EDUCATION])
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(&mut vars, "workId", seed.book_work_id);
+    let data = execute_graphql(&schema, &context, query, Some(vars));
+    assert_eq!(
+        data["work"]["audiences"][0]["audienceId"].as_str(),
+        Some(audience_id.to_string().as_str())
+    );
+    assert_eq!(data["audienceCount"].as_i64(), Some(1));
+
+    delete_with_id(
+        &schema,
+        &context,
+        "deleteAudience",
+        "audienceId",
+        audience_id,
+        "audienceId",
+    );
+    assert!(Audience::from_id(pool.as_ref(), &audience_id).is_err());
+}
+
+#[test]
+fn graphql_create_audience_rejects_descending_age_range() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-audience-range");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let query = r#"
+mutation CreateAudience($data: NewAudience!) {
+  createAudience(data: $data) { audienceId }
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(
+        &mut vars,
+        "data",
+        NewAudience {
+            work_id: seed.book_work_id,
+            audience_code: AudienceCode::Children,
+            audience_range_qualifier: Some(AudienceRangeQualifier::ReadingAgeYears),
+            audience_range_precision: Some(AudienceRangePrecision::From),
+            audience_range_from: Some("11".to_string()),
+            audience_range_to: Some("7".to_string()),
+        },
+    );
+    let (_, errors) = juniper::execute_sync(query, None, &schema, &vars, &context)
+        .expect("GraphQL execution failed");
+    assert!(!errors.is_empty(), "Expected invalid audience range error");
+}
+
+#[test]
+fn graphql_duplicate_doi_error_has_code_category_and_field() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-duplicate-doi");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let query = r#"
+mutation CreateWork($data: NewWork!) {
+  createWork(data: $data) { workId }
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(
+        &mut vars,
+        "data",
+        make_new_book_work(seed.imprint_id, Doi::from_str("10.1234/book").unwrap()),
+    );
+    let (_, errors) = juniper::execute_sync(query, None, &schema, &vars, &context)
+        .expect("GraphQL execution failed");
+    assert_eq!(errors.len(), 1);
+    let error = errors[0].error();
+    assert_eq!(error.message(), "A work with this DOI already exists.");
+    let extensions = error
+        .extensions()
+        .as_object_value()
+        .expect("Expected error extensions");
+    assert_eq!(
+        extensions.get_field_value("code"),
+        Some(&juniper::Value::scalar("DATABASE_CONSTRAINT_VIOLATION"))
+    );
+    assert_eq!(
+        extensions.get_field_value("category"),
+        Some(&juniper::Value::scalar("CONFLICT"))
+    );
+    assert_eq!(
+        extensions.get_field_value("status"),
+        Some(&juniper::Value::scalar(409))
+    );
+    assert_eq!(
+        extensions.get_field_value("field"),
+        Some(&juniper::Value::scalar("doi"))
+    );
+}
+
+#[test]
+fn graphql_work_date_roundtrip_on_work() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-work-date");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let work_date = create_with_data(
+        &schema,
+        &context,
+        "createWorkDate",
+        "NewWorkDate",
+        "workDateId dateType date",
+        NewWorkDate {
+            work_id: seed.book_work_id,
+            date_type: WorkDateType::PublicAnnouncement,
+            date: NaiveDate::from_ymd_opt(2023, 9, 1).unwrap(),
+        },
+    );
+    assert_eq!(work_date["dateType"].as_str(), Some("PUBLIC_ANNOUNCEMENT"));
+    assert_eq!(work_date["date"].as_str(), Some("2023-09-01"));
+    let work_date_id = json_uuid(&work_date["workDateId"]);
+
+    let updated = update_with_data(
+        &schema,
+        &context,
+        "updateWorkDate",
+        "PatchWorkDate",
+        "dateType date",
+        PatchWorkDate {
+            work_date_id,
+            work_id: seed.book_work_id,
+            date_type: WorkDateType::TradeAnnouncement,
+            date: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap(),
+        },
+    );
+    assert_eq!(updated["dateType"].as_str(), Some("TRADE_ANNOUNCEMENT"));
+    assert_eq!(updated["date"].as_str(), Some("2023-06-01"));
+
+    let query = r#"
+query WorkDates($workId: Uuid!) {
+  work(workId: $workId) {
+    workDates { workDateId }
+  }
+  workDateCount(dateTypes: [TRADE_ANNOUNCEMENT])
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(&mut vars, "workId", seed.book_work_id);
+    let data = execute_graphql(&schema, &context, query, Some(vars));
+    assert_eq!(
+        data["work"]["workDates"][0]["workDateId"].as_str(),
+        Some(work_date_id.to_string().as_str())
+    );
+    assert_eq!(data["workDateCount"].as_i64(), Some(1));
+
+    delete_with_id(
+        &schema,
+        &context,
+        "deleteWorkDate",
+        "workDateId",
+        work_date_id,
+        "workDateId",
+    );
+    assert!(WorkDate::from_id(pool.as_ref(), &work_date_id).is_err());
+}
+
+#[test]
+fn graphql_withdrawing_work_requires_out_of_print_date() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-work-withdrawn");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let work = Work::from_id(pool.as_ref(), &seed.book_work_id).expect("Failed to load work");
+    let mut patch = PatchWork::from(work);
+    patch.work_status = WorkStatus::Withdrawn;
+    patch.withdrawn_date = NaiveDate::from_ymd_opt(2025, 1, 1);
+
+    let query = r#"
+mutation UpdateWork($data: PatchWork!) {
+  updateWork(data: $data) { workStatus }
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(&mut vars, "data", patch);
+    let (_, errors) = juniper::execute_sync(query, None, &schema, &vars, &context)
+        .expect("GraphQL execution failed");
+    assert!(
+        !errors.is_empty(),
+        "Expected missing out-of-print date error"
+    );
+
+    create_with_data(
+        &schema,
+        &context,
+        "createWorkDate",
+        "NewWorkDate",
+        "workDateId",
+        NewWorkDate {
+            work_id: seed.book_work_id,
+            date_type: WorkDateType::OutOfPrint,
+            date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        },
+    );
+    let data = execute_graphql(&schema, &context, query, Some(vars));
+    assert_eq!(data["updateWork"]["workStatus"].as_str(), Some("WITHDRAWN"));
+}
+
+#[test]
+fn graphql_name_variant_roundtrip_on_contributor_and_contribution() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-name-variant");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let transliteration = create_with_data(
+        &schema,
+        &context,
+        "createNameVariant",
+        "NewNameVariant",
+        "nameVariantId variantType scriptCode localeCode fullName",
+        NewNameVariant {
+            contributor_id: Some(seed.contributor_id),
+            contribution_id: None,
+            variant_type: NameVariantType::Transliteration,
+            script_code: Some("Hans".to_string()),
+            locale_code: Some(LocaleCode::ZhHans),
+            first_name: Some("鲁".to_string()),
+            last_name: "迅".to_string(),
+            full_name: "鲁迅".to_string(),
+        },
+    );
+    assert_eq!(
+        transliteration["variantType"].as_str(),
+        Some("TRANSLITERATION")
+    );
+    assert_eq!(transliteration["scriptCode"].as_str(), Some("Hans"));
+    assert_eq!(transliteration["fullName"].as_str(), Some("鲁迅"));
+    let transliteration_id = json_uuid(&transliteration["nameVariantId"]);
+
+    let pseudonym = create_with_data(
+        &schema,
+        &context,
+        "createNameVariant",
+        "NewNameVariant",
+        "nameVariantId",
+        NewNameVariant {
+            contributor_id: None,
+            contribution_id: Some(seed.contribution_id),
+            variant_type: NameVariantType::Pseudonym,
+            script_code: None,
+            locale_code: None,
+            first_name: None,
+            last_name: "Xun".to_string(),
+            full_name: "Lu Xun".to_string(),
+        },
+    );
+    let pseudonym_id = json_uuid(&pseudonym["nameVariantId"]);
+
+    let updated = update_with_data(
+        &schema,
+        &context,
+        "updateNameVariant",
+        "PatchNameVariant",
+        "scriptCode",
+        PatchNameVariant {
+            name_variant_id: transliteration_id,
+            contributor_id: Some(seed.contributor_id),
+            contribution_id: None,
+            variant_type: NameVariantType::Transliteration,
+            script_code: Some("Hant".to_string()),
+            locale_code: Some(LocaleCode::ZhHant),
+            first_name: Some("魯".to_string()),
+            last_name: "迅".to_string(),
+            full_name: "魯迅".to_string(),
+        },
+    );
+    assert_eq!(updated["scriptCode"].as_str(), Some("Hant"));
+
+    let query = r#"
+query NameVariants($contributorId: Uuid!, $contributionId: Uuid!) {
+  contributor(contributorId: $contributorId) {
+    nameVariants { nameVariantId }
+  }
+  contribution(contributionId: $contributionId) {
+    nameVariants { nameVariantId }
+  }
+  nameVariantCount(variantTypes: [PSEUDONYM])
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(&mut vars, "contributorId", seed.contributor_id);
+    insert_var(&mut vars, "contributionId", seed.contribution_id);
+    let data = execute_graphql(&schema, &context, query, Some(vars));
+    assert_eq!(
+        data["contributor"]["nameVariants"][0]["nameVariantId"].as_str(),
+        Some(transliteration_id.to_string().as_str())
+    );
+    assert_eq!(
+        data["contribution"]["nameVariants"][0]["nameVariantId"].as_str(),
+        Some(pseudonym_id.to_string().as_str())
+    );
+    assert_eq!(data["nameVariantCount"].as_i64(), Some(1));
+
+    delete_with_id(
+        &schema,
+        &context,
+        "deleteNameVariant",
+        "nameVariantId",
+        transliteration_id,
+        "nameVariantId",
+    );
+    assert!(NameVariant::from_id(pool.as_ref(), &transliteration_id).is_err());
+}
+
+#[test]
+fn graphql_transliterated_name_variant_requires_script() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-name-variant-script");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let query = r#"
+mutation CreateNameVariant($data: NewNameVariant!) {
+  createNameVariant(data: $data) { nameVariantId }
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(
+        &mut vars,
+        "data",
+        NewNameVariant {
+            contributor_id: Some(seed.contributor_id),
+            contribution_id: None,
+            variant_type: NameVariantType::Transliteration,
+            script_code: None,
+            locale_code: None,
+            first_name: None,
+            last_name: "Толстой".to_string(),
+            full_name: "Лев Толстой".to_string(),
+        },
+    );
+    let (_, errors) = juniper::execute_sync(query, None, &schema, &vars, &context)
+        .expect("GraphQL execution failed");
+    assert!(!errors.is_empty(), "Expected missing script error");
+}
+
+#[test]
+fn graphql_credit_role_roundtrip_on_contribution() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-credit-role");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let credit_role = create_with_data(
+        &schema,
+        &context,
+        "createCreditRole",
+        "NewCreditRole",
+        "creditRoleId roleType degree roleUri",
+        NewCreditRole {
+            contribution_id: seed.contribution_id,
+            role_type: CreditRoleType::WritingOriginalDraft,
+            degree: Some(CreditRoleDegree::Lead),
+        },
+    );
+    assert_eq!(
+        credit_role["roleType"].as_str(),
+        Some("WRITING_ORIGINAL_DRAFT")
+    );
+    assert_eq!(credit_role["degree"].as_str(), Some("LEAD"));
+    assert_eq!(
+        credit_role["roleUri"].as_str(),
+        Some("https://credit.niso.org/contributor-roles/writing-original-draft/")
+    );
+    let credit_role_id = json_uuid(&credit_role["creditRoleId"]);
+
+    let updated = update_with_data(
+        &schema,
+        &context,
+        "updateCreditRole",
+        "PatchCreditRole",
+        "degree",
+        PatchCreditRole {
+            credit_role_id,
+            contribution_id: seed.contribution_id,
+            role_type: CreditRoleType::WritingOriginalDraft,
+            degree: None,
+        },
+    );
+    assert!(updated["degree"].is_null());
+
+    let query = r#"
+query CreditRoles($contributionId: Uuid!) {
+  contribution(contributionId: $contributionId) {
+    creditRoles { creditRoleId }
+  }
+  creditRoleCount(roleTypes: [WRITING_ORIGINAL_DRAFT])
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(&mut vars, "contributionId", seed.contribution_id);
+    let data = execute_graphql(&schema, &context, query, Some(vars));
+    assert_eq!(
+        data["contribution"]["creditRoles"][0]["creditRoleId"].as_str(),
+        Some(credit_role_id.to_string().as_str())
+    );
+    assert_eq!(data["creditRoleCount"].as_i64(), Some(1));
+
+    delete_with_id(
+        &schema,
+        &context,
+        "deleteCreditRole",
+        "creditRoleId",
+        credit_role_id,
+        "creditRoleId",
+    );
+    assert!(CreditRole::from_id(pool.as_ref(), &credit_role_id).is_err());
+}
+
+#[test]
+fn graphql_create_institution_returns_funder_registry_suggestions() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-funder-registry");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+
+    RegisteredFunder::load_snapshot(
+        pool.as_ref(),
+        &[RegisteredFunder {
+            funder_doi: Doi::from_str("10.13039/501100000780").unwrap(),
+            funder_name: "European Commission".to_string(),
+            alt_names: vec!["EC".to_string()],
+            country_code: Some(CountryCode::Bel),
+            parent_doi: None,
+            ror: Some(Ror::from_str("00k4n6c32").unwrap()),
+            replaced_by: None,
+        }],
+    )
+    .expect("Failed to load snapshot");
+
+    let institution = create_with_data(
+        &schema,
+        &context,
+        "createInstitution",
+        "NewInstitution",
+        "funderRegistryCheck { status funder { funderName } suggestions { field currentValue suggestedValue } }",
+        NewInstitution {
+            institution_name: "EC".to_string(),
+            institution_doi: None,
+            ror: None,
+            country_code: Some(CountryCode::Bel),
+        },
+    );
+    let check = &institution["funderRegistryCheck"];
+    assert_eq!(check["status"].as_str(), Some("NO_FUNDER_DOI"));
+    assert_eq!(
+        check["funder"]["funderName"].as_str(),
+        Some("European Commission")
+    );
+    assert_eq!(
+        check["suggestions"][0]["field"].as_str(),
+        Some("institutionDoi")
+    );
+    assert!(check["suggestions"][0]["currentValue"].is_null());
+    assert_eq!(
+        check["suggestions"][0]["suggestedValue"].as_str(),
+        Some("https://doi.org/10.13039/501100000780")
+    );
+    assert_eq!(check["suggestions"][1]["field"].as_str(), Some("ror"));
+    assert_eq!(check["suggestions"].as_array().map(Vec::len), Some(2));
+
+    let query = r#"
+query RegisteredFunders {
+  registeredFunders(filter: "commission") { funderDoi altNames }
+}
+"#;
+    let data = execute_graphql(&schema, &context, query, None);
+    assert_eq!(
+        data["registeredFunders"][0]["funderDoi"].as_str(),
+        Some("https://doi.org/10.13039/501100000780")
+    );
+
+    let query =
+        "mutation($data: NewInstitution!) { createInstitution(data: $data) { institutionId } }";
+    let mut vars = Variables::new();
+    insert_var(
+        &mut vars,
+        "data",
+        NewInstitution {
+            institution_name: "Unknown Funder".to_string(),
+            institution_doi: Some(Doi::from_str("10.13039/999999999").unwrap()),
+            ror: None,
+            country_code: None,
+        },
+    );
+    let (_, errors) = juniper::execute_sync(query, None, &schema, &vars, &context)
+        .expect("GraphQL execution failed");
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn graphql_product_form_detail_roundtrip_on_publication() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-product-form");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let detail = create_with_data(
+        &schema,
+        &context,
+        "createProductFormDetail",
+        "NewProductFormDetail",
+        "productFormDetailId detailType detailCode featureValue detailLabel",
+        NewProductFormDetail {
+            publication_id: seed.publication_id,
+            detail_type: ProductFormDetailType::FormFeature,
+            detail_code: "01".to_string(),
+            feature_value: Some("NAV".to_string()),
+            feature_description: None,
+        },
+    );
+    assert_eq!(detail["detailType"].as_str(), Some("FORM_FEATURE"));
+    assert_eq!(
+        detail["detailLabel"].as_str(),
+        Some("Colour of cover: Navy/Dark blue")
+    );
+    let product_form_detail_id = json_uuid(&detail["productFormDetailId"]);
+
+    let updated = update_with_data(
+        &schema,
+        &context,
+        "updateProductFormDetail",
+        "PatchProductFormDetail",
+        "detailType detailCode featureValue",
+        PatchProductFormDetail {
+            product_form_detail_id,
+            publication_id: seed.publication_id,
+            detail_type: ProductFormDetailType::FormDetail,
+            detail_code: "B305".to_string(),
+            feature_value: None,
+            feature_description: None,
+        },
+    );
+    assert_eq!(updated["detailType"].as_str(), Some("FORM_DETAIL"));
+    assert_eq!(updated["detailCode"].as_str(), Some("B305"));
+    assert!(updated["featureValue"].is_null());
+
+    let query = r#"
+query PublicationProductFormDetails($publicationId: Uuid!) {
+  publication(publicationId: $publicationId) {
+    productFormDetails { productFormDetailId }
+  }
+  productFormDetailCount(detailTypes: [FORM_DETAIL])
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(&mut vars, "publicationId", seed.publication_id);
+    let data = execute_graphql(&schema, &context, query, Some(vars));
+    assert_eq!(
+        data["publication"]["productFormDetails"][0]["productFormDetailId"].as_str(),
+        Some(product_form_detail_id.to_string().as_str())
+    );
+    assert_eq!(data["productFormDetailCount"].as_i64(), Some(1));
+
+    delete_with_id(
+        &schema,
+        &context,
+        "deleteProductFormDetail",
+        "productFormDetailId",
+        product_form_detail_id,
+        "productFormDetailId",
+    );
+    assert!(ProductFormDetail::from_id(pool.as_ref(), &product_form_detail_id).is_err());
+}
+
+#[test]
+fn graphql_create_product_form_detail_rejects_ebook_code_on_print() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-product-form-code");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let query = r#"
+mutation CreateProductFormDetail($data: NewProductFormDetail!) {
+  createProductFormDetail(data: $data) { productFormDetailId }
+}
+"#;
+    let mut vars = Variables::new();
+    insert_var(
+        &mut vars,
+        "data",
+        NewProductFormDetail {
+            publication_id: seed.publication_id,
+            detail_type: ProductFormDetailType::FormDetail,
+            detail_code: "E201".to_string(),
+            feature_value: None,
+            feature_description: None,
+        },
+    );
+    let (_, errors) = juniper::execute_sync(query, None, &schema, &vars, &context)
+        .expect("GraphQL execution failed");
+    assert!(
+        !errors.is_empty(),
+        "Expected invalid product form detail error"
+    );
+}
+
+#[test]
+fn graphql_creative_commons_license_catalogue_is_listed() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let context = test_db::test_context_anonymous(pool);
+
+    let data = execute_graphql(
+        &schema,
+        &context,
+        "query { creativeCommonsLicenses { code name url } }",
+        None,
+    );
+    let licenses = data["creativeCommonsLicenses"]
+        .as_array()
+        .expect("Expected list of licenses");
+    assert!(licenses.iter().any(|license| {
+        license["code"].as_str() == Some("CC BY 4.0")
+            && license["url"].as_str() == Some("https://creativecommons.org/licenses/by/4.0/")
+    }));
+}
+
+#[test]
+fn graphql_subject_vocabulary_supports_browse_and_search() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let context = test_db::test_context_anonymous(pool);
+
+    let data = execute_graphql(
+        &schema,
+        &context,
+        "query { subjectVocabulary(subjectType: THEMA, parentCode: \"A\") { code parentCode } }",
+        None,
+    );
+    let children = data["subjectVocabulary"]
+        .as_array()
+        .expect("Expected list of headings");
+    assert!(children.iter().any(|heading| heading["code"] == "AB"));
+    assert!(children.iter().all(|heading| heading["parentCode"] == "A"));
+
+    let data = execute_graphql(
+        &schema,
+        &context,
+        "query { subjectVocabulary(subjectType: THEMA, filter: \"droit\") { code label(language: FRE) equivalentCode(subjectType: BISAC) } }",
+        None,
+    );
+    assert_eq!(
+        data["subjectVocabulary"],
+        serde_json::json!([{ "code": "L", "label": "Droit", "equivalentCode": "LAW000000" }])
+    );
+
+    let data = execute_graphql(
+        &schema,
+        &context,
+        "query { subjectVocabulary(subjectType: BISAC, limit: 3) { code } }",
+        None,
+    );
+    assert_eq!(
+        data["subjectVocabulary"]
+            .as_array()
+            .map(|headings| headings.len()),
+        Some(3)
+    );
+
+    let query =
+        "query { subjectVocabulary(subjectType: BISAC, parentCode: \"BLA000000\") { code } }";
+    let vars = Variables::new();
+    let (_, errors) = juniper::execute_sync(query, None, &schema, &vars, &context)
+        .expect("GraphQL execution failed");
+    assert!(!errors.is_empty(), "Expected invalid subject code error");
+}
+
+#[test]
+fn graphql_price_supports_territory_type_and_validity_dates() {
+    let (_guard, pool) = test_db::setup_test_db();
+    let schema = create_schema();
+    let superuser = test_db::test_superuser("user-price-territory");
+    let context = test_db::test_context_with_user(pool.clone(), superuser);
+    let seed = seed_data(&schema, &context);
+
+    let price = create_with_data(
+        &schema,
+        &context,
+        "createPrice",
+        "NewPrice",
+        "priceId priceType taxInclusive countriesIncluded effectiveFrom effectiveUntil",
+        NewPrice {
+            publication_id: seed.publication_id,
+            currency_code: CurrencyCode::Usd,
+            unit_price: 20.0,
+            price_type: Some(PriceType::Agency),
+            tax_inclusive: Some(false),
+            countries_included: Some(vec![CountryCode::Usa, CountryCode::Can]),
+            effective_from: NaiveDate::from_ymd_opt(2026, 1, 1),
+            effective_until: NaiveDate::from_ymd_opt(2026, 12, 31),
+        },
+    );
+
+    assert_eq!(price["priceType"].as_str(), Some("AGENCY"));
+    assert_eq!(price["taxInclusive"].as_bool(), Some(false));
+    assert_eq!(price["countriesIncluded"][0].as_str(), Some("USA"));
+    assert_eq!(price["effectiveFrom"].as_str(), Some("2026-01-01"));
+    assert_eq!(price["effectiveUntil"].as_str(), Some("2026-12-31"));
+
+    let price_id = json_uuid(&price["priceId"]);
+    let stored = Price::from_id(pool.as_ref(), &price_id).expect("Failed to fetch stored price");
+    assert_eq!(stored.price_type, PriceType::Agency);
+    assert_eq!(
+        stored.countries_included,
+        Some(vec![CountryCode::Usa, CountryCode::Can])
+    );
+}
+
 #[test]
 fn graphql_review_and_endorsement_relations_null_after_institution_delete() {
     let (_guard, pool) = test_db::setup_test_db();
@@ -2788,6 +3686,9 @@ fn graphql_create_publication_normalises_hyphenless_isbn() {
             accessibility_additional_standard: None,
             accessibility_exception: None,
             accessibility_report_url: None,
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         },
     );
     let publication_id = json_uuid(&publication["publicationId"]);
@@ -2882,6 +3783,9 @@ fn graphql_create_publication_rejects_invalid_isbn_before_db_constraint() {
             accessibility_additional_standard: None,
             accessibility_exception: None,
             accessibility_report_url: None,
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         },
     );
 
@@ -2,7 +2,7 @@ use crate::graphql::Context;
 use crate::model::publisher::{Publisher, PublisherOrderBy};
 use crate::model::Crud;
 use crate::policy::{PublisherPermissions, UserAccess};
-use juniper::FieldResult;
+use thoth_errors::ThothResult;
 use zitadel::actix::introspection::IntrospectedUser;
 
 pub struct Me {
@@ -21,11 +21,11 @@ pub struct PublisherContext {
 }
 
 pub trait ToMe {
-    fn to_me(&self, context: &Context) -> FieldResult<Me>;
+    fn to_me(&self, context: &Context) -> ThothResult<Me>;
 }
 
 impl ToMe for IntrospectedUser {
-    fn to_me(&self, context: &Context) -> FieldResult<Me> {
+    fn to_me(&self, context: &Context) -> ThothResult<Me> {
         let is_superuser = self.is_superuser();
         let mut publisher_contexts = publisher_contexts_for_user(context, self)?;
         publisher_contexts
@@ -45,7 +45,7 @@ impl ToMe for IntrospectedUser {
 fn publisher_contexts_for_user(
     context: &Context,
     user: &IntrospectedUser,
-) -> FieldResult<Vec<PublisherContext>> {
+) -> ThothResult<Vec<PublisherContext>> {
     if user.is_superuser() {
         let publishers = Publisher::all(
             &context.db,
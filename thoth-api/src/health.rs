@@ -0,0 +1,102 @@
+//! Liveness and readiness probes of the Thoth servers
+
+use std::collections::BTreeMap;
+
+use actix_web::HttpResponse;
+use diesel::RunQueryDsl;
+use serde::Serialize;
+use thoth_errors::ThothResult;
+
+use crate::db::PgPool;
+
+const OK: &str = "ok";
+const UNAVAILABLE: &str = "unavailable";
+
+/// Respond to liveness probes: the server is up as long as it can answer
+pub async fn live() -> HttpResponse {
+    HttpResponse::Ok().json(Readiness::default())
+}
+
+/// Outcome of checking each of the services a server depends on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Readiness {
+    pub status: &'static str,
+    /// `ok`, or the error raised when checking the service
+    pub checks: BTreeMap<&'static str, String>,
+}
+
+impl Default for Readiness {
+    fn default() -> Self {
+        Self {
+            status: OK,
+            checks: BTreeMap::new(),
+        }
+    }
+}
+
+impl Readiness {
+    /// Record the result of checking a service; any failure makes the server unavailable
+    pub fn check(mut self, service: &'static str, result: ThothResult<()>) -> Self {
+        let outcome = match result {
+            Ok(()) => OK.to_string(),
+            Err(e) => {
+                log::warn!("Readiness check of {service} failed: {e}");
+                self.status = UNAVAILABLE;
+                e.to_string()
+            }
+        };
+        self.checks.insert(service, outcome);
+        self
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status == OK
+    }
+
+    /// `200 OK` if every check passed, `503 Service Unavailable` otherwise
+    pub fn into_response(self) -> HttpResponse {
+        match self.is_ready() {
+            true => HttpResponse::Ok().json(self),
+            false => HttpResponse::ServiceUnavailable().json(self),
+        }
+    }
+}
+
+/// Check that a connection can be obtained from the Postgres pool and queried.
+///
+/// This blocks until the pool hands out a connection, so it should be run off the async
+/// executor, e.g. with `actix_web::web::block`.
+pub fn check_database(pool: &PgPool) -> ThothResult<()> {
+    let mut connection = pool.get()?;
+    diesel::sql_query("SELECT 1").execute(&mut connection)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use thoth_errors::ThothError;
+
+    #[test]
+    fn test_readiness() {
+        let readiness = Readiness::default().check("database", Ok(()));
+        assert!(readiness.is_ready());
+        assert_eq!(readiness.checks["database"], "ok");
+
+        let readiness = readiness.check(
+            "redis",
+            Err(ThothError::InternalError("Connection refused".to_string())),
+        );
+        assert!(!readiness.is_ready());
+        assert_eq!(readiness.status, "unavailable");
+        assert_eq!(readiness.checks["database"], "ok");
+        assert_ne!(readiness.checks["redis"], "ok");
+    }
+
+    #[test]
+    fn test_readiness_response_status() {
+        assert_eq!(Readiness::default().into_response().status(), 200);
+        let unavailable = Readiness::default().check("redis", Err(ThothError::EntityNotFound));
+        assert_eq!(unavailable.into_response().status(), 503);
+    }
+}
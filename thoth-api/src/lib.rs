@@ -2,7 +2,11 @@
 pub mod db;
 #[cfg(feature = "backend")]
 pub mod graphql;
+#[cfg(feature = "backend")]
+pub mod health;
 pub mod markup;
+#[cfg(feature = "backend")]
+pub mod metrics;
 #[macro_use]
 pub mod model;
 #[cfg(feature = "backend")]
@@ -13,3 +17,5 @@ pub mod redis;
 mod schema;
 #[cfg(feature = "backend")]
 pub mod storage;
+#[cfg(feature = "backend")]
+pub mod telemetry;
@@ -1458,15 +1458,8 @@ mod tests {
         // Check that we have a SmallCaps node somewhere in the AST
         fn find_small_caps(node: &Node) -> bool {
             match node {
-                Node::SmallCaps(children) => {
-                    if children.len() == 1 {
-                        match &children[0] {
-                            Node::Text(content) => content == "Small caps text",
-                            _ => false,
-                        }
-                    } else {
-                        false
-                    }
+                Node::SmallCaps(children) if children.len() == 1 => {
+                    matches!(&children[0], Node::Text(content) if content == "Small caps text")
                 }
                 Node::Document(children) | Node::Paragraph(children) => {
                     children.iter().any(find_small_caps)
@@ -0,0 +1,181 @@
+//! Prometheus metrics shared by the Thoth servers, collected in the default registry and
+//! served in the text exposition format by [`metrics`].
+
+use std::future::{ready, Ready};
+use std::rc::Rc;
+use std::time::Instant;
+
+use actix_web::dev::{self, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{Error, HttpResponse};
+use futures::future::LocalBoxFuture;
+use lazy_static::lazy_static;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, Encoder,
+    HistogramVec, IntCounterVec, IntGaugeVec, TextEncoder,
+};
+use serde_json::Value;
+
+use crate::db::PgPool;
+
+/// Path parameter identifying the metadata specification of an export API request
+const SPECIFICATION_PARAMETER: &str = "specification_id";
+
+lazy_static! {
+    static ref HTTP_REQUEST_DURATION: HistogramVec = register_histogram_vec!(
+        "thoth_http_request_duration_seconds",
+        "Time taken to respond to HTTP requests, per route and metadata specification",
+        &["method", "route", "specification", "status"]
+    )
+    .unwrap();
+    static ref DB_POOL_CONNECTIONS: IntGaugeVec = register_int_gauge_vec!(
+        "thoth_db_pool_connections",
+        "Connections of the Postgres pool, by state (idle, in_use, max)",
+        &["state"]
+    )
+    .unwrap();
+    static ref GRAPHQL_ERRORS: IntCounterVec = register_int_counter_vec!(
+        "thoth_graphql_errors_total",
+        "Errors returned by GraphQL resolvers, by error code",
+        &["code"]
+    )
+    .unwrap();
+}
+
+/// Respond with every metric registered in the default registry
+pub async fn metrics() -> HttpResponse {
+    let encoder = TextEncoder::new();
+    let mut buffer = vec![];
+    match encoder.encode(&prometheus::gather(), &mut buffer) {
+        Ok(()) => HttpResponse::Ok()
+            .content_type(encoder.format_type())
+            .body(buffer),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
+
+/// Record the current state of a Postgres pool, to be called before gathering metrics
+pub fn record_pool_state(pool: &PgPool) {
+    let state = pool.state();
+    let idle = i64::from(state.idle_connections);
+    let total = i64::from(state.connections);
+    DB_POOL_CONNECTIONS.with_label_values(&["idle"]).set(idle);
+    DB_POOL_CONNECTIONS
+        .with_label_values(&["in_use"])
+        .set(total - idle);
+    DB_POOL_CONNECTIONS
+        .with_label_values(&["max"])
+        .set(i64::from(pool.max_size()));
+}
+
+/// Count the errors of a serialised GraphQL response, using the code added to their
+/// `extensions` (`UNKNOWN` for errors raised by juniper itself, e.g. query validation errors)
+pub fn record_graphql_errors(response: &Value) {
+    let Some(errors) = response.get("errors").and_then(Value::as_array) else {
+        return;
+    };
+    for error in errors {
+        let code = error
+            .pointer("/extensions/code")
+            .and_then(Value::as_str)
+            .unwrap_or("UNKNOWN");
+        GRAPHQL_ERRORS.with_label_values(&[code]).inc();
+    }
+}
+
+/// Middleware timing every request, labelled with the pattern of the route it matched rather
+/// than its path, so that IDs do not end up in label values
+pub struct RequestMetrics;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestMetrics
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestMetricsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestMetricsMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct RequestMetricsMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestMetricsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    dev::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        let start = Instant::now();
+        let method = req.method().to_string();
+
+        Box::pin(async move {
+            let res = svc.call(req).await?;
+            let status = res.status();
+            let request = res.request();
+            let route = request
+                .match_pattern()
+                .unwrap_or_else(|| "unmatched".to_string());
+            // only label successful requests with their specification, as unknown IDs are
+            // rejected and would otherwise each create a new series
+            let specification = match status.is_success() {
+                true => request
+                    .match_info()
+                    .get(SPECIFICATION_PARAMETER)
+                    .unwrap_or_default(),
+                false => "",
+            };
+            HTTP_REQUEST_DURATION
+                .with_label_values(&[&method, &route, specification, status.as_str()])
+                .observe(start.elapsed().as_secs_f64());
+            Ok(res)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_record_graphql_errors() {
+        let before = GRAPHQL_ERRORS
+            .with_label_values(&["ENTITY_NOT_FOUND"])
+            .get();
+        let unknown = GRAPHQL_ERRORS.with_label_values(&["UNKNOWN"]).get();
+        record_graphql_errors(&json!({
+            "data": null,
+            "errors": [
+                { "message": "Not found", "extensions": { "code": "ENTITY_NOT_FOUND" } },
+                { "message": "Unknown field" }
+            ]
+        }));
+        record_graphql_errors(&json!({ "data": { "works": [] } }));
+        assert_eq!(
+            GRAPHQL_ERRORS
+                .with_label_values(&["ENTITY_NOT_FOUND"])
+                .get(),
+            before + 1
+        );
+        assert_eq!(
+            GRAPHQL_ERRORS.with_label_values(&["UNKNOWN"]).get(),
+            unknown + 1
+        );
+    }
+}
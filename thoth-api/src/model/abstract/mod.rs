@@ -81,6 +81,8 @@ pub struct Abstract {
     graphql(description = "Set of values required to define a new work's abstract"),
     diesel(table_name = work_abstract)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewAbstract {
     pub work_id: Uuid,
     pub content: String,
@@ -107,6 +109,8 @@ impl Default for NewAbstract {
     graphql(description = "Set of values required to update an existing work's abstract"),
     diesel(table_name = work_abstract)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchAbstract {
     pub abstract_id: Uuid,
     pub work_id: Uuid,
@@ -97,6 +97,8 @@ pub struct AdditionalResource {
     graphql(description = "Set of values required to define a new additional resource linked to a work"),
     diesel(table_name = additional_resource)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewAdditionalResource {
     pub work_id: Uuid,
     pub title: String,
@@ -116,6 +118,8 @@ pub struct NewAdditionalResource {
     graphql(description = "Set of values required to update an existing additional resource"),
     diesel(table_name = additional_resource, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchAdditionalResource {
     pub additional_resource_id: Uuid,
     pub work_id: Uuid,
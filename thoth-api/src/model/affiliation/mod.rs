@@ -42,6 +42,8 @@ pub struct Affiliation {
     graphql(description = "Set of values required to define a new association between a person and an institution for a specific contribution"),
     diesel(table_name = affiliation)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewAffiliation {
     pub contribution_id: Uuid,
     pub institution_id: Uuid,
@@ -55,6 +57,8 @@ pub struct NewAffiliation {
     graphql(description = "Set of values required to update an existing association between a person and an institution for a specific contribution"),
     diesel(table_name = affiliation, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchAffiliation {
     pub affiliation_id: Uuid,
     pub contribution_id: Uuid,
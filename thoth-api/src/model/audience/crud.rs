@@ -0,0 +1,136 @@
+use super::{
+    Audience, AudienceCode, AudienceField, AudienceHistory, AudienceOrderBy, NewAudience,
+    NewAudienceHistory, PatchAudience,
+};
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{audience, audience_history};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for Audience {
+    type NewEntity = NewAudience;
+    type PatchEntity = PatchAudience;
+    type OrderByEntity = AudienceOrderBy;
+    type FilterParameter1 = AudienceCode;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+    type FilterParameter4 = ();
+
+    fn pk(&self) -> Uuid {
+        self.audience_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        audience_codes: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<Vec<Audience>> {
+        use crate::schema::audience::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::audience
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::audience::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            AudienceField::AudienceId => {
+                apply_directional_order!(query, order.direction, order, dsl::audience_id)
+            }
+            AudienceField::WorkId => {
+                apply_directional_order!(query, order.direction, order, dsl::work_id)
+            }
+            AudienceField::AudienceCode => {
+                apply_directional_order!(query, order.direction, order, dsl::audience_code)
+            }
+            AudienceField::AudienceRangeQualifier => {
+                apply_directional_order!(
+                    query,
+                    order.direction,
+                    order,
+                    dsl::audience_range_qualifier
+                )
+            }
+            AudienceField::CreatedAt => {
+                apply_directional_order!(query, order.direction, order, dsl::created_at)
+            }
+            AudienceField::UpdatedAt => {
+                apply_directional_order!(query, order.direction, order, dsl::updated_at)
+            }
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if !audience_codes.is_empty() {
+            query = query.filter(dsl::audience_code.eq_any(audience_codes));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<Audience>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        publishers: Vec<Uuid>,
+        audience_codes: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<i32> {
+        use crate::schema::audience::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::audience
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .into_boxed();
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if !audience_codes.is_empty() {
+            query = query.filter(dsl::audience_code.eq_any(audience_codes));
+        }
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    crud_methods!(audience::table, audience::dsl::audience);
+}
+
+publisher_id_impls!(Audience, NewAudience, PatchAudience, |s, db| {
+    crate::model::work::Work::from_id(db, &s.work_id)?.publisher_id(db)
+});
+
+impl HistoryEntry for Audience {
+    type NewHistoryEntity = NewAudienceHistory;
+
+    fn new_history_entry(&self, user_id: &str) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            audience_id: self.audience_id,
+            user_id: user_id.to_string(),
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewAudienceHistory {
+    type MainEntity = AudienceHistory;
+
+    db_insert!(audience_history::table);
+}
@@ -0,0 +1,365 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::graphql::types::inputs::Direction;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::audience;
+#[cfg(feature = "backend")]
+use crate::schema::audience_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Broad audience for which a work is intended (ONIX code list 28)"),
+    ExistingTypePath = "crate::schema::sql_types::AudienceCode"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum AudienceCode {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "general",
+        graphql(description = "General/trade: for a non-specialist adult audience")
+    )]
+    General,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "children",
+        graphql(description = "Children/juvenile: for children, up to about 12 years of age")
+    )]
+    Children,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "young_adult",
+        graphql(description = "Young adult: for teenage readers")
+    )]
+    YoungAdult,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "primary_and_secondary",
+        graphql(description = "Primary and secondary/elementary and high school education")
+    )]
+    PrimaryAndSecondary,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "higher_education",
+        graphql(description = "College/higher education")
+    )]
+    HigherEducation,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "professional_and_scholarly",
+        graphql(description = "Professional and scholarly")
+    )]
+    #[default]
+    ProfessionalAndScholarly,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "elt",
+        graphql(description = "English language teaching (ELT/ESL)")
+    )]
+    #[strum(serialize = "ELT")]
+    Elt,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "adult_education",
+        graphql(description = "Adult education")
+    )]
+    AdultEducation,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "second_language_teaching",
+        graphql(description = "Teaching of a second language other than English")
+    )]
+    SecondLanguageTeaching,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Scale in which an audience range is expressed (ONIX code list 30)"),
+    ExistingTypePath = "crate::schema::sql_types::AudienceRangeQualifier"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum AudienceRangeQualifier {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "us_school_grade",
+        graphql(description = "US school grade, from P (preschool) and K (kindergarten) to 17")
+    )]
+    #[strum(serialize = "US School Grade")]
+    UsSchoolGrade,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "uk_school_grade",
+        graphql(description = "UK school grade")
+    )]
+    #[strum(serialize = "UK School Grade")]
+    UkSchoolGrade,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "interest_age_months",
+        graphql(description = "Interest age, in months")
+    )]
+    InterestAgeMonths,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "interest_age_years",
+        graphql(description = "Interest age, in years")
+    )]
+    #[default]
+    InterestAgeYears,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "reading_age_years",
+        graphql(description = "Reading age, in years")
+    )]
+    ReadingAgeYears,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "How the values of an audience range should be read (ONIX code list 31)"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::AudienceRangePrecision"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum AudienceRangePrecision {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "exact",
+        graphql(description = "The range is the single value given as From")
+    )]
+    Exact,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "from",
+        graphql(description = "The range starts at From and, if given, ends at To")
+    )]
+    #[default]
+    From,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "to",
+        graphql(description = "The range has no lower bound and ends at To")
+    )]
+    To,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting audiences list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AudienceField {
+    AudienceId,
+    WorkId,
+    #[default]
+    AudienceCode,
+    AudienceRangeQualifier,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Audience {
+    pub audience_id: Uuid,
+    pub work_id: Uuid,
+    pub audience_code: AudienceCode,
+    pub audience_range_qualifier: Option<AudienceRangeQualifier>,
+    pub audience_range_precision: Option<AudienceRangePrecision>,
+    pub audience_range_from: Option<String>,
+    pub audience_range_to: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::Insertable),
+    graphql(description = "Set of values required to define a new audience for a work"),
+    diesel(table_name = audience)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewAudience {
+    pub work_id: Uuid,
+    pub audience_code: AudienceCode,
+    pub audience_range_qualifier: Option<AudienceRangeQualifier>,
+    pub audience_range_precision: Option<AudienceRangePrecision>,
+    pub audience_range_from: Option<String>,
+    pub audience_range_to: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::AsChangeset),
+    graphql(description = "Set of values required to update an existing audience"),
+    diesel(table_name = audience, treat_none_as_null = true)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchAudience {
+    pub audience_id: Uuid,
+    pub work_id: Uuid,
+    pub audience_code: AudienceCode,
+    pub audience_range_qualifier: Option<AudienceRangeQualifier>,
+    pub audience_range_precision: Option<AudienceRangePrecision>,
+    pub audience_range_from: Option<String>,
+    pub audience_range_to: Option<String>,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+pub struct AudienceHistory {
+    pub audience_history_id: Uuid,
+    pub audience_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel::Insertable),
+    diesel(table_name = audience_history)
+)]
+pub struct NewAudienceHistory {
+    pub audience_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Field and order to use when sorting audiences list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AudienceOrderBy {
+    pub field: AudienceField,
+    pub direction: Direction,
+}
+
+impl AudienceRangeQualifier {
+    /// Whether values in this scale are whole numbers (ages) rather than grade labels
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            AudienceRangeQualifier::InterestAgeMonths
+                | AudienceRangeQualifier::InterestAgeYears
+                | AudienceRangeQualifier::ReadingAgeYears
+        )
+    }
+}
+
+/// Check that an audience range is complete and consistent with its precision, mirroring
+/// the `audience_range_check` and `audience_range_precision_check` database constraints,
+/// and that age ranges are made up of ascending whole numbers.
+pub fn validate_audience_range(
+    qualifier: Option<&AudienceRangeQualifier>,
+    precision: Option<&AudienceRangePrecision>,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> ThothResult<()> {
+    let (qualifier, precision) = match (qualifier, precision) {
+        (Some(qualifier), Some(precision)) => (qualifier, precision),
+        (None, None) if from.is_none() && to.is_none() => return Ok(()),
+        _ => return Err(ThothError::AudienceRangeIncompleteError),
+    };
+    let consistent = match precision {
+        AudienceRangePrecision::Exact => from.is_some() && to.is_none(),
+        AudienceRangePrecision::From => from.is_some(),
+        AudienceRangePrecision::To => from.is_none() && to.is_some(),
+    };
+    if !consistent {
+        return Err(ThothError::AudienceRangePrecisionError);
+    }
+    if qualifier.is_numeric() {
+        let parse = |value: &str| {
+            value.parse::<u32>().map_err(|_| {
+                ThothError::AudienceRangeValueError(format!(
+                    "{qualifier} values must be whole numbers, not \"{value}\""
+                ))
+            })
+        };
+        let from = from.map(parse).transpose()?;
+        let to = to.map(parse).transpose()?;
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err(ThothError::AudienceRangeValueError(format!(
+                    "From value {from} is greater than To value {to}"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub trait AudienceProperties {
+    fn audience_range_qualifier(&self) -> Option<&AudienceRangeQualifier>;
+    fn audience_range_precision(&self) -> Option<&AudienceRangePrecision>;
+    fn audience_range_from(&self) -> Option<&str>;
+    fn audience_range_to(&self) -> Option<&str>;
+
+    fn validate(&self) -> ThothResult<()> {
+        validate_audience_range(
+            self.audience_range_qualifier(),
+            self.audience_range_precision(),
+            self.audience_range_from(),
+            self.audience_range_to(),
+        )
+    }
+}
+
+macro_rules! audience_properties {
+    ($t:ty) => {
+        impl AudienceProperties for $t {
+            fn audience_range_qualifier(&self) -> Option<&AudienceRangeQualifier> {
+                self.audience_range_qualifier.as_ref()
+            }
+            fn audience_range_precision(&self) -> Option<&AudienceRangePrecision> {
+                self.audience_range_precision.as_ref()
+            }
+            fn audience_range_from(&self) -> Option<&str> {
+                self.audience_range_from.as_deref()
+            }
+            fn audience_range_to(&self) -> Option<&str> {
+                self.audience_range_to.as_deref()
+            }
+        }
+    };
+}
+
+audience_properties!(Audience);
+audience_properties!(NewAudience);
+audience_properties!(PatchAudience);
+
+#[cfg(feature = "backend")]
+pub mod crud;
+#[cfg(feature = "backend")]
+mod policy;
+#[cfg(feature = "backend")]
+pub(crate) use policy::AudiencePolicy;
+#[cfg(test)]
+mod tests;
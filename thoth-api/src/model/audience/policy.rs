@@ -0,0 +1,38 @@
+use crate::model::audience::{Audience, AudienceProperties, NewAudience, PatchAudience};
+use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy};
+use thoth_errors::ThothResult;
+
+/// Write policies for `Audience`.
+///
+/// These policies are responsible for:
+/// - requiring authentication
+/// - requiring publisher membership (tenant boundary)
+/// - enforcing ONIX audience range rules (qualifier, precision and values)
+pub struct AudiencePolicy;
+
+impl CreatePolicy<NewAudience> for AudiencePolicy {
+    fn can_create<C: PolicyContext>(ctx: &C, data: &NewAudience, _params: ()) -> ThothResult<()> {
+        ctx.require_publisher_for(data)?;
+        data.validate()
+    }
+}
+
+impl UpdatePolicy<Audience, PatchAudience> for AudiencePolicy {
+    fn can_update<C: PolicyContext>(
+        ctx: &C,
+        current: &Audience,
+        patch: &PatchAudience,
+        _params: (),
+    ) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        ctx.require_publisher_for(patch)?;
+        patch.validate()
+    }
+}
+
+impl DeletePolicy<Audience> for AudiencePolicy {
+    fn can_delete<C: PolicyContext>(ctx: &C, current: &Audience) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        Ok(())
+    }
+}
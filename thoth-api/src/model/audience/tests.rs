@@ -0,0 +1,642 @@
+use super::*;
+use crate::model::Crud;
+use uuid::Uuid;
+
+fn make_audience(
+    pool: &crate::db::PgPool,
+    work_id: Uuid,
+    audience_code: AudienceCode,
+    audience_range_qualifier: Option<AudienceRangeQualifier>,
+) -> Audience {
+    let new_audience = NewAudience {
+        work_id,
+        audience_code,
+        audience_range_qualifier,
+        audience_range_precision: audience_range_qualifier.map(|_| AudienceRangePrecision::From),
+        audience_range_from: audience_range_qualifier.map(|_| "9".to_string()),
+        audience_range_to: audience_range_qualifier.map(|_| "12".to_string()),
+    };
+
+    Audience::create(pool, &new_audience).expect("Failed to create audience")
+}
+
+mod defaults {
+    use super::*;
+
+    #[test]
+    fn audiencecode_default_is_professional_and_scholarly() {
+        let audiencecode: AudienceCode = Default::default();
+        assert_eq!(audiencecode, AudienceCode::ProfessionalAndScholarly);
+    }
+
+    #[test]
+    fn audiencerangequalifier_default_is_interest_age_years() {
+        let qualifier: AudienceRangeQualifier = Default::default();
+        assert_eq!(qualifier, AudienceRangeQualifier::InterestAgeYears);
+    }
+
+    #[test]
+    fn audiencerangeprecision_default_is_from() {
+        let precision: AudienceRangePrecision = Default::default();
+        assert_eq!(precision, AudienceRangePrecision::From);
+    }
+
+    #[test]
+    fn audiencefield_default_is_audience_code() {
+        let audiencefield: AudienceField = Default::default();
+        assert_eq!(audiencefield, AudienceField::AudienceCode);
+    }
+}
+
+mod display_and_parse {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn audiencecode_display_formats_expected_strings() {
+        assert_eq!(format!("{}", AudienceCode::General), "General");
+        assert_eq!(format!("{}", AudienceCode::YoungAdult), "Young Adult");
+        assert_eq!(
+            format!("{}", AudienceCode::ProfessionalAndScholarly),
+            "Professional And Scholarly"
+        );
+        assert_eq!(format!("{}", AudienceCode::Elt), "ELT");
+    }
+
+    #[test]
+    fn audiencecode_fromstr_parses_expected_values() {
+        assert_eq!(
+            AudienceCode::from_str("Higher Education").unwrap(),
+            AudienceCode::HigherEducation
+        );
+        assert_eq!(AudienceCode::from_str("ELT").unwrap(), AudienceCode::Elt);
+        assert!(AudienceCode::from_str("Scholarly").is_err());
+    }
+
+    #[test]
+    fn audiencerangequalifier_display_formats_expected_strings() {
+        assert_eq!(
+            format!("{}", AudienceRangeQualifier::UsSchoolGrade),
+            "US School Grade"
+        );
+        assert_eq!(
+            format!("{}", AudienceRangeQualifier::InterestAgeMonths),
+            "Interest Age Months"
+        );
+    }
+
+    #[test]
+    fn audiencerangeprecision_fromstr_parses_expected_values() {
+        assert_eq!(
+            AudienceRangePrecision::from_str("Exact").unwrap(),
+            AudienceRangePrecision::Exact
+        );
+        assert_eq!(
+            AudienceRangePrecision::from_str("To").unwrap(),
+            AudienceRangePrecision::To
+        );
+        assert!(AudienceRangePrecision::from_str("Between").is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod conversions {
+    use super::*;
+    use crate::model::tests::db::setup_test_db;
+    use crate::model::tests::{assert_db_enum_roundtrip, assert_graphql_enum_roundtrip};
+
+    #[test]
+    fn audiencecode_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(AudienceCode::SecondLanguageTeaching);
+    }
+
+    #[test]
+    fn audiencerangequalifier_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(AudienceRangeQualifier::ReadingAgeYears);
+    }
+
+    #[test]
+    fn audiencerangeprecision_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(AudienceRangePrecision::Exact);
+    }
+
+    #[test]
+    fn audiencecode_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<AudienceCode, crate::schema::sql_types::AudienceCode>(
+            pool.as_ref(),
+            "'young_adult'::audience_code",
+            AudienceCode::YoungAdult,
+        );
+    }
+
+    #[test]
+    fn audiencerangequalifier_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<
+            AudienceRangeQualifier,
+            crate::schema::sql_types::AudienceRangeQualifier,
+        >(
+            pool.as_ref(),
+            "'us_school_grade'::audience_range_qualifier",
+            AudienceRangeQualifier::UsSchoolGrade,
+        );
+    }
+
+    #[test]
+    fn audiencerangeprecision_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<
+            AudienceRangePrecision,
+            crate::schema::sql_types::AudienceRangePrecision,
+        >(
+            pool.as_ref(),
+            "'to'::audience_range_precision",
+            AudienceRangePrecision::To,
+        );
+    }
+}
+
+mod helpers {
+    use super::*;
+    use crate::model::{Crud, HistoryEntry};
+
+    #[test]
+    fn pk_returns_id() {
+        let audience: Audience = Default::default();
+        assert_eq!(audience.pk(), audience.audience_id);
+    }
+
+    #[test]
+    fn history_entry_serializes_model() {
+        let audience: Audience = Default::default();
+        let user_id = "123456".to_string();
+        let new_audience_history = audience.new_history_entry(&user_id);
+        assert_eq!(new_audience_history.audience_id, audience.audience_id);
+        assert_eq!(new_audience_history.user_id, user_id);
+        assert_eq!(
+            new_audience_history.data,
+            serde_json::Value::String(serde_json::to_string(&audience).unwrap())
+        );
+    }
+}
+
+mod validation {
+    use super::*;
+
+    const YEARS: Option<&AudienceRangeQualifier> = Some(&AudienceRangeQualifier::InterestAgeYears);
+    const GRADE: Option<&AudienceRangeQualifier> = Some(&AudienceRangeQualifier::UsSchoolGrade);
+
+    #[test]
+    fn accepts_audience_without_range() {
+        assert!(validate_audience_range(None, None, None, None).is_ok());
+    }
+
+    #[test]
+    fn accepts_ranges_consistent_with_precision() {
+        let exact = Some(&AudienceRangePrecision::Exact);
+        let from = Some(&AudienceRangePrecision::From);
+        let to = Some(&AudienceRangePrecision::To);
+        assert!(validate_audience_range(YEARS, exact, Some("8"), None).is_ok());
+        assert!(validate_audience_range(YEARS, from, Some("8"), None).is_ok());
+        assert!(validate_audience_range(YEARS, from, Some("8"), Some("12")).is_ok());
+        assert!(validate_audience_range(YEARS, to, None, Some("12")).is_ok());
+        assert!(validate_audience_range(GRADE, from, Some("K"), Some("3")).is_ok());
+    }
+
+    #[test]
+    fn rejects_incomplete_range() {
+        let from = Some(&AudienceRangePrecision::From);
+        assert!(matches!(
+            validate_audience_range(YEARS, None, Some("8"), None),
+            Err(ThothError::AudienceRangeIncompleteError)
+        ));
+        assert!(matches!(
+            validate_audience_range(None, from, Some("8"), None),
+            Err(ThothError::AudienceRangeIncompleteError)
+        ));
+        assert!(matches!(
+            validate_audience_range(None, None, Some("8"), None),
+            Err(ThothError::AudienceRangeIncompleteError)
+        ));
+    }
+
+    #[test]
+    fn rejects_values_inconsistent_with_precision() {
+        let exact = Some(&AudienceRangePrecision::Exact);
+        let from = Some(&AudienceRangePrecision::From);
+        let to = Some(&AudienceRangePrecision::To);
+        for (precision, from_value, to_value) in [
+            (exact, Some("8"), Some("12")),
+            (exact, None, Some("12")),
+            (from, None, Some("12")),
+            (to, Some("8"), Some("12")),
+            (to, None, None),
+        ] {
+            assert!(matches!(
+                validate_audience_range(YEARS, precision, from_value, to_value),
+                Err(ThothError::AudienceRangePrecisionError)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_non_numeric_or_descending_ages() {
+        let from = Some(&AudienceRangePrecision::From);
+        assert!(matches!(
+            validate_audience_range(YEARS, from, Some("eight"), None),
+            Err(ThothError::AudienceRangeValueError(_))
+        ));
+        assert!(matches!(
+            validate_audience_range(YEARS, from, Some("12"), Some("8")),
+            Err(ThothError::AudienceRangeValueError(_))
+        ));
+    }
+}
+
+#[cfg(feature = "backend")]
+mod policy {
+    use super::*;
+
+    use crate::model::audience::policy::AudiencePolicy;
+    use crate::model::tests::db::{
+        create_imprint, create_publisher, create_work, setup_test_db, test_context_with_user,
+        test_user_with_role,
+    };
+    use crate::model::Crud;
+    use crate::policy::{CreatePolicy, DeletePolicy, Role, UpdatePolicy};
+
+    #[test]
+    fn crud_policy_allows_publisher_user_for_write() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("audience-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let new_audience = NewAudience {
+            work_id: work.work_id,
+            audience_code: AudienceCode::Children,
+            audience_range_qualifier: Some(AudienceRangeQualifier::InterestAgeYears),
+            audience_range_precision: Some(AudienceRangePrecision::From),
+            audience_range_from: Some("5".to_string()),
+            audience_range_to: Some("8".to_string()),
+        };
+
+        let audience = Audience::create(pool.as_ref(), &new_audience).expect("Failed to create");
+        let patch = PatchAudience {
+            audience_id: audience.audience_id,
+            work_id: audience.work_id,
+            audience_code: AudienceCode::YoungAdult,
+            audience_range_qualifier: None,
+            audience_range_precision: None,
+            audience_range_from: None,
+            audience_range_to: None,
+        };
+
+        assert!(AudiencePolicy::can_create(&ctx, &new_audience, ()).is_ok());
+        assert!(AudiencePolicy::can_update(&ctx, &audience, &patch, ()).is_ok());
+        assert!(AudiencePolicy::can_delete(&ctx, &audience).is_ok());
+    }
+
+    #[test]
+    fn crud_policy_rejects_user_without_publisher_role() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let audience = make_audience(pool.as_ref(), work.work_id, AudienceCode::General, None);
+        let patch = PatchAudience {
+            audience_id: audience.audience_id,
+            work_id: audience.work_id,
+            audience_code: AudienceCode::HigherEducation,
+            audience_range_qualifier: None,
+            audience_range_precision: None,
+            audience_range_from: None,
+            audience_range_to: None,
+        };
+
+        let user = test_user_with_role("audience-user", Role::PublisherUser, "org-other");
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let new_audience = NewAudience {
+            work_id: work.work_id,
+            audience_code: AudienceCode::Children,
+            audience_range_qualifier: None,
+            audience_range_precision: None,
+            audience_range_from: None,
+            audience_range_to: None,
+        };
+
+        assert!(AudiencePolicy::can_create(&ctx, &new_audience, ()).is_err());
+        assert!(AudiencePolicy::can_update(&ctx, &audience, &patch, ()).is_err());
+        assert!(AudiencePolicy::can_delete(&ctx, &audience).is_err());
+    }
+
+    #[test]
+    fn crud_policy_rejects_invalid_range() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("audience-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        let descending = NewAudience {
+            work_id: work.work_id,
+            audience_code: AudienceCode::Children,
+            audience_range_qualifier: Some(AudienceRangeQualifier::InterestAgeYears),
+            audience_range_precision: Some(AudienceRangePrecision::From),
+            audience_range_from: Some("12".to_string()),
+            audience_range_to: Some("8".to_string()),
+        };
+        let missing_precision = NewAudience {
+            work_id: work.work_id,
+            audience_code: AudienceCode::Children,
+            audience_range_qualifier: Some(AudienceRangeQualifier::InterestAgeYears),
+            audience_range_precision: None,
+            audience_range_from: Some("8".to_string()),
+            audience_range_to: None,
+        };
+
+        assert!(AudiencePolicy::can_create(&ctx, &descending, ()).is_err());
+        assert!(AudiencePolicy::can_create(&ctx, &missing_precision, ()).is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod crud {
+    use super::*;
+
+    use crate::graphql::types::inputs::Direction;
+    use crate::model::tests::db::{
+        create_imprint, create_publisher, create_work, setup_test_db, test_context,
+    };
+    use crate::model::Crud;
+
+    #[test]
+    fn crud_roundtrip_create_fetch_update_delete() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        let audience = make_audience(
+            pool.as_ref(),
+            work.work_id,
+            AudienceCode::Children,
+            Some(AudienceRangeQualifier::InterestAgeYears),
+        );
+        let fetched =
+            Audience::from_id(pool.as_ref(), &audience.audience_id).expect("Failed to fetch");
+        assert_eq!(audience.audience_id, fetched.audience_id);
+        assert_eq!(fetched.audience_range_from.as_deref(), Some("9"));
+        assert_eq!(fetched.audience_range_to.as_deref(), Some("12"));
+
+        let patch = PatchAudience {
+            audience_id: audience.audience_id,
+            work_id: audience.work_id,
+            audience_code: AudienceCode::PrimaryAndSecondary,
+            audience_range_qualifier: Some(AudienceRangeQualifier::UsSchoolGrade),
+            audience_range_precision: Some(AudienceRangePrecision::Exact),
+            audience_range_from: Some("K".to_string()),
+            audience_range_to: None,
+        };
+
+        let ctx = test_context(pool.clone(), "test-user");
+        let updated = audience.update(&ctx, &patch).expect("Failed to update");
+        assert_eq!(updated.audience_code, patch.audience_code);
+        assert_eq!(
+            updated.audience_range_qualifier,
+            patch.audience_range_qualifier
+        );
+        assert_eq!(updated.audience_range_to, None);
+
+        let deleted = updated.delete(pool.as_ref()).expect("Failed to delete");
+        assert!(Audience::from_id(pool.as_ref(), &deleted.audience_id).is_err());
+    }
+
+    #[test]
+    fn crud_rejects_duplicate_audience_code_and_qualifier() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        make_audience(pool.as_ref(), work.work_id, AudienceCode::General, None);
+        let duplicate = NewAudience {
+            work_id: work.work_id,
+            audience_code: AudienceCode::General,
+            audience_range_qualifier: None,
+            audience_range_precision: None,
+            audience_range_from: None,
+            audience_range_to: None,
+        };
+
+        assert!(Audience::create(pool.as_ref(), &duplicate).is_err());
+    }
+
+    #[test]
+    fn crud_rejects_range_without_precision() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        let new_audience = NewAudience {
+            work_id: work.work_id,
+            audience_code: AudienceCode::Children,
+            audience_range_qualifier: Some(AudienceRangeQualifier::ReadingAgeYears),
+            audience_range_precision: None,
+            audience_range_from: Some("7".to_string()),
+            audience_range_to: None,
+        };
+
+        assert!(Audience::create(pool.as_ref(), &new_audience).is_err());
+    }
+
+    #[test]
+    fn crud_count_filters_by_audience_code() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        make_audience(pool.as_ref(), work.work_id, AudienceCode::General, None);
+        make_audience(
+            pool.as_ref(),
+            work.work_id,
+            AudienceCode::Children,
+            Some(AudienceRangeQualifier::InterestAgeYears),
+        );
+
+        let total = Audience::count(pool.as_ref(), None, vec![], vec![], vec![], None, None)
+            .expect("Failed to count audiences");
+        let filtered = Audience::count(
+            pool.as_ref(),
+            None,
+            vec![],
+            vec![AudienceCode::Children],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to count audiences by code");
+
+        assert_eq!(total, 2);
+        assert_eq!(filtered, 1);
+    }
+
+    #[test]
+    fn crud_filter_parent_work_id_limits_results() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let other_work = create_work(pool.as_ref(), &imprint);
+
+        let matches = make_audience(pool.as_ref(), work.work_id, AudienceCode::General, None);
+        make_audience(
+            pool.as_ref(),
+            other_work.work_id,
+            AudienceCode::General,
+            None,
+        );
+
+        let filtered = Audience::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            AudienceOrderBy {
+                field: AudienceField::AudienceId,
+                direction: Direction::Asc,
+            },
+            vec![],
+            Some(work.work_id),
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to filter audiences by work");
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].audience_id, matches.audience_id);
+    }
+
+    #[test]
+    fn crud_filter_publishers_limits_results() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let matches = make_audience(pool.as_ref(), work.work_id, AudienceCode::General, None);
+
+        let other_publisher = create_publisher(pool.as_ref());
+        let other_imprint = create_imprint(pool.as_ref(), &other_publisher);
+        let other_work = create_work(pool.as_ref(), &other_imprint);
+        make_audience(
+            pool.as_ref(),
+            other_work.work_id,
+            AudienceCode::General,
+            None,
+        );
+
+        let filtered = Audience::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            AudienceOrderBy {
+                field: AudienceField::AudienceId,
+                direction: Direction::Asc,
+            },
+            vec![publisher.publisher_id],
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to filter audiences by publisher");
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].audience_id, matches.audience_id);
+    }
+
+    #[test]
+    fn crud_ordering_by_fields_is_supported() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        make_audience(pool.as_ref(), work.work_id, AudienceCode::General, None);
+        make_audience(
+            pool.as_ref(),
+            work.work_id,
+            AudienceCode::Children,
+            Some(AudienceRangeQualifier::InterestAgeYears),
+        );
+
+        let fields: Vec<fn() -> AudienceField> = vec![
+            || AudienceField::AudienceId,
+            || AudienceField::WorkId,
+            || AudienceField::AudienceCode,
+            || AudienceField::AudienceRangeQualifier,
+            || AudienceField::CreatedAt,
+            || AudienceField::UpdatedAt,
+        ];
+
+        for field in fields {
+            for direction in [Direction::Asc, Direction::Desc] {
+                let results = Audience::all(
+                    pool.as_ref(),
+                    10,
+                    0,
+                    None,
+                    AudienceOrderBy {
+                        field: field(),
+                        direction,
+                    },
+                    vec![],
+                    None,
+                    None,
+                    vec![],
+                    vec![],
+                    None,
+                    None,
+                )
+                .expect("Failed to order audiences");
+
+                assert_eq!(results.len(), 2);
+            }
+        }
+    }
+}
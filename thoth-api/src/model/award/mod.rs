@@ -83,6 +83,8 @@ pub struct Award {
     graphql(description = "Set of values required to define a new award linked to a work"),
     diesel(table_name = award)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewAward {
     pub work_id: Uuid,
     pub title: String,
@@ -102,6 +104,8 @@ pub struct NewAward {
     graphql(description = "Set of values required to update an existing award"),
     diesel(table_name = award, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchAward {
     pub award_id: Uuid,
     pub work_id: Uuid,
@@ -58,7 +58,8 @@ pub struct Biography {
     graphql(description = "Set of values required to define a new work's biography"),
     diesel(table_name = biography)
 )]
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewBiography {
     pub contribution_id: Uuid,
     pub content: String,
@@ -72,6 +73,8 @@ pub struct NewBiography {
     graphql(description = "Set of values required to update an existing work's biography"),
     diesel(table_name = biography, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchBiography {
     pub biography_id: Uuid,
     pub contribution_id: Uuid,
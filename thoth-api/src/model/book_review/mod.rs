@@ -59,6 +59,8 @@ pub struct BookReview {
     graphql(description = "Set of values required to define a new book review linked to a work"),
     diesel(table_name = book_review)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewBookReview {
     pub work_id: Uuid,
     pub title: Option<String>,
@@ -83,6 +85,8 @@ pub struct NewBookReview {
     graphql(description = "Set of values required to update an existing book review"),
     diesel(table_name = book_review, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchBookReview {
     pub book_review_id: Uuid,
     pub work_id: Uuid,
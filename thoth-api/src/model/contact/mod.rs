@@ -64,6 +64,8 @@ pub struct Contact {
     graphql(description = "Set of values required to define a new way of getting in touch with a publisher"),
     diesel(table_name = contact)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewContact {
     pub publisher_id: Uuid,
     pub contact_type: ContactType,
@@ -76,6 +78,8 @@ pub struct NewContact {
     graphql(description = "Set of values required to update an existing way of getting in touch with a publisher"),
     diesel(table_name = contact, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchContact {
     pub contact_id: Uuid,
     pub publisher_id: Uuid,
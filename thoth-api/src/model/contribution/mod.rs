@@ -140,6 +140,8 @@ pub struct Contribution {
     graphql(description = "Set of values required to define a new individual involvement in the production of a work"),
     diesel(table_name = contribution)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewContribution {
     pub work_id: Uuid,
     pub contributor_id: Uuid,
@@ -157,6 +159,8 @@ pub struct NewContribution {
     graphql(description = "Set of values required to update an individual involvement in the production of a work"),
     diesel(table_name = contribution, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchContribution {
     pub contribution_id: Uuid,
     pub work_id: Uuid,
@@ -207,6 +211,126 @@ impl Default for Contribution {
     }
 }
 
+/// The terms used for a contribution type by the metadata formats Thoth exports to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContributionRoleMapping {
+    pub contribution_type: ContributionType,
+    /// ONIX code list 17, Contributor role code
+    pub onix_role_code: &'static str,
+    /// MARC relator term (RDA relationship designator), as output in subfield $e
+    pub marc_relator_term: &'static str,
+    /// Crossref `contributor_role`, if the role is one the deposit schema accepts
+    pub crossref_role: Option<&'static str>,
+}
+
+pub const CONTRIBUTION_ROLE_MAPPINGS: [ContributionRoleMapping; 14] = [
+    ContributionRoleMapping {
+        contribution_type: ContributionType::Author,
+        onix_role_code: "A01",
+        marc_relator_term: "author",
+        crossref_role: Some("author"),
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::Editor,
+        onix_role_code: "B01",
+        marc_relator_term: "editor",
+        crossref_role: Some("editor"),
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::Translator,
+        onix_role_code: "B06",
+        marc_relator_term: "translator",
+        crossref_role: Some("translator"),
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::Photographer,
+        onix_role_code: "A13",
+        marc_relator_term: "photographer",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::Illustrator,
+        onix_role_code: "A12",
+        marc_relator_term: "illustrator",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::MusicEditor,
+        onix_role_code: "B25",
+        marc_relator_term: "editor",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::ForewordBy,
+        onix_role_code: "A23",
+        marc_relator_term: "writer of foreword",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::IntroductionBy,
+        onix_role_code: "A24",
+        marc_relator_term: "writer of introduction",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::AfterwordBy,
+        onix_role_code: "A19",
+        marc_relator_term: "writer of afterword",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::PrefaceBy,
+        onix_role_code: "A15",
+        marc_relator_term: "writer of preface",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::SoftwareBy,
+        onix_role_code: "A30",
+        marc_relator_term: "programmer",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::ResearchBy,
+        onix_role_code: "A51",
+        marc_relator_term: "researcher",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::ContributionsBy,
+        onix_role_code: "A32",
+        marc_relator_term: "contributor",
+        crossref_role: None,
+    },
+    ContributionRoleMapping {
+        contribution_type: ContributionType::Indexer,
+        onix_role_code: "A34",
+        marc_relator_term: "indexer",
+        crossref_role: None,
+    },
+];
+
+impl ContributionType {
+    pub fn role_mapping(&self) -> &'static ContributionRoleMapping {
+        CONTRIBUTION_ROLE_MAPPINGS
+            .iter()
+            .find(|mapping| mapping.contribution_type == *self)
+            .expect("every contribution type has a role mapping")
+    }
+
+    pub fn onix_role_code(&self) -> &'static str {
+        self.role_mapping().onix_role_code
+    }
+
+    pub fn marc_relator_term(&self) -> &'static str {
+        self.role_mapping().marc_relator_term
+    }
+
+    pub fn crossref_role(&self) -> Option<&'static str> {
+        self.role_mapping().crossref_role
+    }
+}
+
 #[cfg(feature = "backend")]
 pub mod crud;
 #[cfg(feature = "backend")]
@@ -182,6 +182,50 @@ mod display_and_parse {
     }
 }
 
+mod role_mappings {
+    use super::*;
+
+    #[test]
+    fn every_contribution_type_has_one_mapping() {
+        for (i, mapping) in CONTRIBUTION_ROLE_MAPPINGS.iter().enumerate() {
+            assert_eq!(mapping.contribution_type.role_mapping(), mapping);
+            assert!(CONTRIBUTION_ROLE_MAPPINGS[i + 1..]
+                .iter()
+                .all(|other| other.contribution_type != mapping.contribution_type));
+        }
+    }
+
+    #[test]
+    fn onix_role_codes_follow_code_list_17() {
+        assert_eq!(ContributionType::Author.onix_role_code(), "A01");
+        assert_eq!(ContributionType::MusicEditor.onix_role_code(), "B25");
+        assert_eq!(ContributionType::ResearchBy.onix_role_code(), "A51");
+    }
+
+    #[test]
+    fn marc_relator_terms_are_rda_designators() {
+        assert_eq!(ContributionType::Editor.marc_relator_term(), "editor");
+        assert_eq!(
+            ContributionType::IntroductionBy.marc_relator_term(),
+            "writer of introduction"
+        );
+        assert_eq!(
+            ContributionType::SoftwareBy.marc_relator_term(),
+            "programmer"
+        );
+    }
+
+    #[test]
+    fn crossref_roles_are_limited_to_supported_roles() {
+        assert_eq!(
+            ContributionType::Translator.crossref_role(),
+            Some("translator")
+        );
+        assert_eq!(ContributionType::Illustrator.crossref_role(), None);
+        assert_eq!(ContributionType::Indexer.crossref_role(), None);
+    }
+}
+
 #[cfg(feature = "backend")]
 mod conversions {
     use super::*;
@@ -55,6 +55,8 @@ pub struct Contributor {
     graphql(description = "Set of values required to define a new individual involved in the production of works"),
     diesel(table_name = contributor)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewContributor {
     pub first_name: Option<String>,
     pub last_name: String,
@@ -69,6 +71,8 @@ pub struct NewContributor {
     graphql(description = "Set of values required to update an existing individual involved in the production of works"),
     diesel(table_name = contributor, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchContributor {
     pub contributor_id: Uuid,
     pub first_name: Option<String>,
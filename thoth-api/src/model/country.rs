@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
+use thoth_errors::{ThothError, ThothResult};
 
 #[cfg_attr(
     feature = "backend",
@@ -789,6 +790,291 @@ pub enum CountryCode {
     Zwe,
 }
 
+impl CountryCode {
+    /// Two-letter ISO 3166-1 code, as required by ONIX `CountriesIncluded`/`CountriesExcluded`
+    pub fn alpha2(&self) -> &'static str {
+        match self {
+            CountryCode::Afg => "AF",
+            CountryCode::Ala => "AX",
+            CountryCode::Alb => "AL",
+            CountryCode::Dza => "DZ",
+            CountryCode::Asm => "AS",
+            CountryCode::And => "AD",
+            CountryCode::Ago => "AO",
+            CountryCode::Aia => "AI",
+            CountryCode::Ata => "AQ",
+            CountryCode::Atg => "AG",
+            CountryCode::Arg => "AR",
+            CountryCode::Arm => "AM",
+            CountryCode::Abw => "AW",
+            CountryCode::Aus => "AU",
+            CountryCode::Aut => "AT",
+            CountryCode::Aze => "AZ",
+            CountryCode::Bhs => "BS",
+            CountryCode::Bhr => "BH",
+            CountryCode::Bgd => "BD",
+            CountryCode::Brb => "BB",
+            CountryCode::Blr => "BY",
+            CountryCode::Bel => "BE",
+            CountryCode::Blz => "BZ",
+            CountryCode::Ben => "BJ",
+            CountryCode::Bmu => "BM",
+            CountryCode::Btn => "BT",
+            CountryCode::Bol => "BO",
+            CountryCode::Bes => "BQ",
+            CountryCode::Bih => "BA",
+            CountryCode::Bwa => "BW",
+            CountryCode::Bvt => "BV",
+            CountryCode::Bra => "BR",
+            CountryCode::Iot => "IO",
+            CountryCode::Brn => "BN",
+            CountryCode::Bgr => "BG",
+            CountryCode::Bfa => "BF",
+            CountryCode::Bdi => "BI",
+            CountryCode::Cpv => "CV",
+            CountryCode::Khm => "KH",
+            CountryCode::Cmr => "CM",
+            CountryCode::Can => "CA",
+            CountryCode::Cym => "KY",
+            CountryCode::Caf => "CF",
+            CountryCode::Tcd => "TD",
+            CountryCode::Chl => "CL",
+            CountryCode::Chn => "CN",
+            CountryCode::Cxr => "CX",
+            CountryCode::Cck => "CC",
+            CountryCode::Col => "CO",
+            CountryCode::Com => "KM",
+            CountryCode::Cok => "CK",
+            CountryCode::Cri => "CR",
+            CountryCode::Civ => "CI",
+            CountryCode::Hrv => "HR",
+            CountryCode::Cub => "CU",
+            CountryCode::Cuw => "CW",
+            CountryCode::Cyp => "CY",
+            CountryCode::Cze => "CZ",
+            CountryCode::Cod => "CD",
+            CountryCode::Dnk => "DK",
+            CountryCode::Dji => "DJ",
+            CountryCode::Dma => "DM",
+            CountryCode::Dom => "DO",
+            CountryCode::Ecu => "EC",
+            CountryCode::Egy => "EG",
+            CountryCode::Slv => "SV",
+            CountryCode::Gnq => "GQ",
+            CountryCode::Eri => "ER",
+            CountryCode::Est => "EE",
+            CountryCode::Swz => "SZ",
+            CountryCode::Eth => "ET",
+            CountryCode::Flk => "FK",
+            CountryCode::Fro => "FO",
+            CountryCode::Fji => "FJ",
+            CountryCode::Fin => "FI",
+            CountryCode::Fra => "FR",
+            CountryCode::Guf => "GF",
+            CountryCode::Pyf => "PF",
+            CountryCode::Atf => "TF",
+            CountryCode::Gab => "GA",
+            CountryCode::Gmb => "GM",
+            CountryCode::Geo => "GE",
+            CountryCode::Deu => "DE",
+            CountryCode::Gha => "GH",
+            CountryCode::Gib => "GI",
+            CountryCode::Grc => "GR",
+            CountryCode::Grl => "GL",
+            CountryCode::Grd => "GD",
+            CountryCode::Glp => "GP",
+            CountryCode::Gum => "GU",
+            CountryCode::Gtm => "GT",
+            CountryCode::Ggy => "GG",
+            CountryCode::Gin => "GN",
+            CountryCode::Gnb => "GW",
+            CountryCode::Guy => "GY",
+            CountryCode::Hti => "HT",
+            CountryCode::Hmd => "HM",
+            CountryCode::Hnd => "HN",
+            CountryCode::Hkg => "HK",
+            CountryCode::Hun => "HU",
+            CountryCode::Isl => "IS",
+            CountryCode::Ind => "IN",
+            CountryCode::Idn => "ID",
+            CountryCode::Irn => "IR",
+            CountryCode::Irq => "IQ",
+            CountryCode::Irl => "IE",
+            CountryCode::Imn => "IM",
+            CountryCode::Isr => "IL",
+            CountryCode::Ita => "IT",
+            CountryCode::Jam => "JM",
+            CountryCode::Jpn => "JP",
+            CountryCode::Jey => "JE",
+            CountryCode::Jor => "JO",
+            CountryCode::Kaz => "KZ",
+            CountryCode::Ken => "KE",
+            CountryCode::Kir => "KI",
+            CountryCode::Kwt => "KW",
+            CountryCode::Kgz => "KG",
+            CountryCode::Lao => "LA",
+            CountryCode::Lva => "LV",
+            CountryCode::Lbn => "LB",
+            CountryCode::Lso => "LS",
+            CountryCode::Lbr => "LR",
+            CountryCode::Lby => "LY",
+            CountryCode::Lie => "LI",
+            CountryCode::Ltu => "LT",
+            CountryCode::Lux => "LU",
+            CountryCode::Mac => "MO",
+            CountryCode::Mdg => "MG",
+            CountryCode::Mwi => "MW",
+            CountryCode::Mys => "MY",
+            CountryCode::Mdv => "MV",
+            CountryCode::Mli => "ML",
+            CountryCode::Mlt => "MT",
+            CountryCode::Mhl => "MH",
+            CountryCode::Mtq => "MQ",
+            CountryCode::Mrt => "MR",
+            CountryCode::Mus => "MU",
+            CountryCode::Myt => "YT",
+            CountryCode::Mex => "MX",
+            CountryCode::Fsm => "FM",
+            CountryCode::Mda => "MD",
+            CountryCode::Mco => "MC",
+            CountryCode::Mng => "MN",
+            CountryCode::Mne => "ME",
+            CountryCode::Msr => "MS",
+            CountryCode::Mar => "MA",
+            CountryCode::Moz => "MZ",
+            CountryCode::Mmr => "MM",
+            CountryCode::Nam => "NA",
+            CountryCode::Nru => "NR",
+            CountryCode::Npl => "NP",
+            CountryCode::Nld => "NL",
+            CountryCode::Ncl => "NC",
+            CountryCode::Nzl => "NZ",
+            CountryCode::Nic => "NI",
+            CountryCode::Ner => "NE",
+            CountryCode::Nga => "NG",
+            CountryCode::Niu => "NU",
+            CountryCode::Nfk => "NF",
+            CountryCode::Prk => "KP",
+            CountryCode::Mkd => "MK",
+            CountryCode::Mnp => "MP",
+            CountryCode::Nor => "NO",
+            CountryCode::Omn => "OM",
+            CountryCode::Pak => "PK",
+            CountryCode::Plw => "PW",
+            CountryCode::Pse => "PS",
+            CountryCode::Pan => "PA",
+            CountryCode::Png => "PG",
+            CountryCode::Pry => "PY",
+            CountryCode::Per => "PE",
+            CountryCode::Phl => "PH",
+            CountryCode::Pcn => "PN",
+            CountryCode::Pol => "PL",
+            CountryCode::Prt => "PT",
+            CountryCode::Pri => "PR",
+            CountryCode::Qat => "QA",
+            CountryCode::Cog => "CG",
+            CountryCode::Reu => "RE",
+            CountryCode::Rou => "RO",
+            CountryCode::Rus => "RU",
+            CountryCode::Rwa => "RW",
+            CountryCode::Blm => "BL",
+            CountryCode::Shn => "SH",
+            CountryCode::Kna => "KN",
+            CountryCode::Lca => "LC",
+            CountryCode::Maf => "MF",
+            CountryCode::Spm => "PM",
+            CountryCode::Vct => "VC",
+            CountryCode::Wsm => "WS",
+            CountryCode::Smr => "SM",
+            CountryCode::Stp => "ST",
+            CountryCode::Sau => "SA",
+            CountryCode::Sen => "SN",
+            CountryCode::Srb => "RS",
+            CountryCode::Syc => "SC",
+            CountryCode::Sle => "SL",
+            CountryCode::Sgp => "SG",
+            CountryCode::Sxm => "SX",
+            CountryCode::Svk => "SK",
+            CountryCode::Svn => "SI",
+            CountryCode::Slb => "SB",
+            CountryCode::Som => "SO",
+            CountryCode::Zaf => "ZA",
+            CountryCode::Sgs => "GS",
+            CountryCode::Kor => "KR",
+            CountryCode::Ssd => "SS",
+            CountryCode::Esp => "ES",
+            CountryCode::Lka => "LK",
+            CountryCode::Sdn => "SD",
+            CountryCode::Sur => "SR",
+            CountryCode::Sjm => "SJ",
+            CountryCode::Swe => "SE",
+            CountryCode::Che => "CH",
+            CountryCode::Syr => "SY",
+            CountryCode::Twn => "TW",
+            CountryCode::Tjk => "TJ",
+            CountryCode::Tza => "TZ",
+            CountryCode::Tha => "TH",
+            CountryCode::Tls => "TL",
+            CountryCode::Tgo => "TG",
+            CountryCode::Tkl => "TK",
+            CountryCode::Ton => "TO",
+            CountryCode::Tto => "TT",
+            CountryCode::Tun => "TN",
+            CountryCode::Tur => "TR",
+            CountryCode::Tkm => "TM",
+            CountryCode::Tca => "TC",
+            CountryCode::Tuv => "TV",
+            CountryCode::Uga => "UG",
+            CountryCode::Ukr => "UA",
+            CountryCode::Are => "AE",
+            CountryCode::Gbr => "GB",
+            CountryCode::Umi => "UM",
+            CountryCode::Usa => "US",
+            CountryCode::Ury => "UY",
+            CountryCode::Uzb => "UZ",
+            CountryCode::Vut => "VU",
+            CountryCode::Vat => "VA",
+            CountryCode::Ven => "VE",
+            CountryCode::Vnm => "VN",
+            CountryCode::Vgb => "VG",
+            CountryCode::Vir => "VI",
+            CountryCode::Wlf => "WF",
+            CountryCode::Esh => "EH",
+            CountryCode::Yem => "YE",
+            CountryCode::Zmb => "ZM",
+            CountryCode::Zwe => "ZW",
+        }
+    }
+}
+
+/// Check a sales territory, where `None` for the included countries means worldwide.
+///
+/// Mirrors the ONIX `Territory` rules: an explicit country list must not be empty, and
+/// exclusions are only meaningful when the territory is worldwide.
+pub fn validate_territory(
+    countries_included: Option<&[CountryCode]>,
+    countries_excluded: &[CountryCode],
+) -> ThothResult<()> {
+    if let Some(included) = countries_included {
+        if included.is_empty() {
+            return Err(ThothError::TerritoryEmptyCountriesError);
+        }
+        if !countries_excluded.is_empty() {
+            return Err(ThothError::TerritoryExcludedCountriesError);
+        }
+    }
+    for countries in [countries_included.unwrap_or_default(), countries_excluded] {
+        let has_duplicates = countries
+            .iter()
+            .enumerate()
+            .any(|(i, country)| countries[..i].contains(country));
+        if has_duplicates {
+            return Err(ThothError::TerritoryDuplicateCountryError);
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -805,6 +1091,42 @@ mod tests {
         assert!(CountryCode::from_str("Narnia").is_err());
     }
 
+    #[test]
+    fn validate_territory_accepts_worldwide_and_country_lists() {
+        assert!(validate_territory(None, &[]).is_ok());
+        assert!(validate_territory(None, &[CountryCode::Usa, CountryCode::Can]).is_ok());
+        assert!(validate_territory(Some(&[CountryCode::Gbr, CountryCode::Irl]), &[]).is_ok());
+    }
+
+    #[test]
+    fn validate_territory_rejects_invalid_combinations() {
+        assert_eq!(
+            validate_territory(Some(&[]), &[]),
+            Err(ThothError::TerritoryEmptyCountriesError)
+        );
+        assert_eq!(
+            validate_territory(Some(&[CountryCode::Gbr]), &[CountryCode::Usa]),
+            Err(ThothError::TerritoryExcludedCountriesError)
+        );
+        assert_eq!(
+            validate_territory(Some(&[CountryCode::Gbr, CountryCode::Gbr]), &[]),
+            Err(ThothError::TerritoryDuplicateCountryError)
+        );
+        assert_eq!(
+            validate_territory(None, &[CountryCode::Usa, CountryCode::Usa]),
+            Err(ThothError::TerritoryDuplicateCountryError)
+        );
+    }
+
+    #[test]
+    fn countrycode_alpha2_returns_iso_3166_1_alpha_2_codes() {
+        assert_eq!(CountryCode::Gbr.alpha2(), "GB");
+        assert_eq!(CountryCode::Usa.alpha2(), "US");
+        assert_eq!(CountryCode::Deu.alpha2(), "DE");
+        assert_eq!(CountryCode::Ala.alpha2(), "AX");
+        assert_eq!(CountryCode::Srb.alpha2(), "RS");
+    }
+
     #[cfg(feature = "backend")]
     #[test]
     fn countrycode_graphql_roundtrip() {
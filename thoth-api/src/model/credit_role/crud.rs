@@ -0,0 +1,137 @@
+use super::{
+    CreditRole, CreditRoleField, CreditRoleHistory, CreditRoleOrderBy, CreditRoleType,
+    NewCreditRole, NewCreditRoleHistory, PatchCreditRole,
+};
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{credit_role, credit_role_history};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for CreditRole {
+    type NewEntity = NewCreditRole;
+    type PatchEntity = PatchCreditRole;
+    type OrderByEntity = CreditRoleOrderBy;
+    type FilterParameter1 = CreditRoleType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+    type FilterParameter4 = ();
+
+    fn pk(&self) -> Uuid {
+        self.credit_role_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        role_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<Vec<CreditRole>> {
+        use crate::schema::credit_role::dsl;
+        let mut connection = db.get()?;
+        let mut query =
+            dsl::credit_role
+                .inner_join(crate::schema::contribution::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .select(crate::schema::credit_role::all_columns)
+                .into_boxed();
+
+        query = match order.field {
+            CreditRoleField::CreditRoleId => {
+                apply_directional_order!(query, order.direction, order, dsl::credit_role_id)
+            }
+            CreditRoleField::ContributionId => {
+                apply_directional_order!(query, order.direction, order, dsl::contribution_id)
+            }
+            CreditRoleField::RoleType => {
+                apply_directional_order!(query, order.direction, order, dsl::role_type)
+            }
+            CreditRoleField::Degree => {
+                apply_directional_order!(query, order.direction, order, dsl::degree)
+            }
+            CreditRoleField::CreatedAt => {
+                apply_directional_order!(query, order.direction, order, dsl::created_at)
+            }
+            CreditRoleField::UpdatedAt => {
+                apply_directional_order!(query, order.direction, order, dsl::updated_at)
+            }
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::contribution_id.eq(pid));
+        }
+        if !role_types.is_empty() {
+            query = query.filter(dsl::role_type.eq_any(role_types));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<CreditRole>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        publishers: Vec<Uuid>,
+        role_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<i32> {
+        use crate::schema::credit_role::dsl;
+        let mut connection = db.get()?;
+        let mut query =
+            dsl::credit_role
+                .inner_join(crate::schema::contribution::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .into_boxed();
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if !role_types.is_empty() {
+            query = query.filter(dsl::role_type.eq_any(role_types));
+        }
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    crud_methods!(credit_role::table, credit_role::dsl::credit_role);
+}
+
+publisher_id_impls!(CreditRole, NewCreditRole, PatchCreditRole, |s, db| {
+    crate::model::contribution::Contribution::from_id(db, &s.contribution_id)?.publisher_id(db)
+});
+
+impl HistoryEntry for CreditRole {
+    type NewHistoryEntity = NewCreditRoleHistory;
+
+    fn new_history_entry(&self, user_id: &str) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            credit_role_id: self.credit_role_id,
+            user_id: user_id.to_string(),
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewCreditRoleHistory {
+    type MainEntity = CreditRoleHistory;
+
+    db_insert!(credit_role_history::table);
+}
@@ -0,0 +1,292 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::graphql::types::inputs::Direction;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::credit_role;
+#[cfg(feature = "backend")]
+use crate::schema::credit_role_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Role played by a contributor in the research and writing of a work, as defined by the CRediT Contributor Roles Taxonomy (ANSI/NISO Z39.104-2022)"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::CreditRoleType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum CreditRoleType {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "conceptualization",
+        graphql(
+            description = "Ideas; formulation or evolution of overarching research goals and aims"
+        )
+    )]
+    #[default]
+    Conceptualization,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "data_curation",
+        graphql(
+            description = "Management activities to annotate, scrub and maintain research data for initial use and later re-use"
+        )
+    )]
+    DataCuration,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "formal_analysis",
+        graphql(
+            description = "Application of statistical, mathematical, computational, or other formal techniques to analyse or synthesise study data"
+        )
+    )]
+    FormalAnalysis,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "funding_acquisition",
+        graphql(
+            description = "Acquisition of the financial support for the project leading to this publication"
+        )
+    )]
+    FundingAcquisition,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "investigation",
+        graphql(
+            description = "Conducting a research and investigation process, specifically performing the experiments, or data/evidence collection"
+        )
+    )]
+    Investigation,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "methodology",
+        graphql(description = "Development or design of methodology; creation of models")
+    )]
+    Methodology,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "project_administration",
+        graphql(
+            description = "Management and coordination responsibility for the research activity planning and execution"
+        )
+    )]
+    ProjectAdministration,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "resources",
+        graphql(
+            description = "Provision of study materials, reagents, materials, patients, laboratory samples, animals, instrumentation, computing resources, or other analysis tools"
+        )
+    )]
+    Resources,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "software",
+        graphql(
+            description = "Programming, software development; designing computer programs; implementation of the computer code and supporting algorithms"
+        )
+    )]
+    Software,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "supervision",
+        graphql(
+            description = "Oversight and leadership responsibility for the research activity planning and execution"
+        )
+    )]
+    Supervision,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "validation",
+        graphql(
+            description = "Verification of the overall replication/reproducibility of results, experiments and other research outputs"
+        )
+    )]
+    Validation,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "visualization",
+        graphql(
+            description = "Preparation, creation and/or presentation of the published work, specifically visualisation or data presentation"
+        )
+    )]
+    Visualization,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "writing_original_draft",
+        graphql(
+            description = "Preparation, creation and/or presentation of the published work, specifically writing the initial draft"
+        )
+    )]
+    WritingOriginalDraft,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "writing_review_editing",
+        graphql(
+            description = "Preparation, creation and/or presentation of the published work, specifically critical review, commentary or revision"
+        )
+    )]
+    WritingReviewEditing,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Degree of a contributor's involvement in a CRediT role"),
+    ExistingTypePath = "crate::schema::sql_types::CreditRoleDegree"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum CreditRoleDegree {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "lead",
+        graphql(description = "Leading contributor in this role")
+    )]
+    Lead,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "equal",
+        graphql(description = "Contributor sharing this role equally with others")
+    )]
+    #[default]
+    Equal,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "supporting",
+        graphql(description = "Contributor supporting others in this role")
+    )]
+    Supporting,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting CRediT roles list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CreditRoleField {
+    CreditRoleId,
+    ContributionId,
+    #[default]
+    RoleType,
+    Degree,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreditRole {
+    pub credit_role_id: Uuid,
+    pub contribution_id: Uuid,
+    pub role_type: CreditRoleType,
+    pub degree: Option<CreditRoleDegree>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::Insertable),
+    graphql(description = "Set of values required to define a new CRediT role of a contribution"),
+    diesel(table_name = credit_role)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewCreditRole {
+    pub contribution_id: Uuid,
+    pub role_type: CreditRoleType,
+    pub degree: Option<CreditRoleDegree>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::AsChangeset),
+    graphql(description = "Set of values required to update an existing CRediT role of a contribution"),
+    diesel(table_name = credit_role, treat_none_as_null = true)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchCreditRole {
+    pub credit_role_id: Uuid,
+    pub contribution_id: Uuid,
+    pub role_type: CreditRoleType,
+    pub degree: Option<CreditRoleDegree>,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+pub struct CreditRoleHistory {
+    pub credit_role_history_id: Uuid,
+    pub credit_role_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel::Insertable),
+    diesel(table_name = credit_role_history)
+)]
+pub struct NewCreditRoleHistory {
+    pub credit_role_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Field and order to use when sorting CRediT roles list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CreditRoleOrderBy {
+    pub field: CreditRoleField,
+    pub direction: Direction,
+}
+
+impl CreditRoleType {
+    /// Persistent identifier of the role in the CRediT vocabulary
+    pub fn uri(&self) -> String {
+        let slug = match self {
+            CreditRoleType::Conceptualization => "conceptualization",
+            CreditRoleType::DataCuration => "data-curation",
+            CreditRoleType::FormalAnalysis => "formal-analysis",
+            CreditRoleType::FundingAcquisition => "funding-acquisition",
+            CreditRoleType::Investigation => "investigation",
+            CreditRoleType::Methodology => "methodology",
+            CreditRoleType::ProjectAdministration => "project-administration",
+            CreditRoleType::Resources => "resources",
+            CreditRoleType::Software => "software",
+            CreditRoleType::Supervision => "supervision",
+            CreditRoleType::Validation => "validation",
+            CreditRoleType::Visualization => "visualization",
+            CreditRoleType::WritingOriginalDraft => "writing-original-draft",
+            CreditRoleType::WritingReviewEditing => "writing-review-editing",
+        };
+        format!("https://credit.niso.org/contributor-roles/{slug}/")
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
+#[cfg(feature = "backend")]
+mod policy;
+#[cfg(feature = "backend")]
+pub(crate) use policy::CreditRolePolicy;
+#[cfg(test)]
+mod tests;
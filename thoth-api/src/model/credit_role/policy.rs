@@ -0,0 +1,38 @@
+use crate::model::credit_role::{CreditRole, NewCreditRole, PatchCreditRole};
+use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy};
+use thoth_errors::ThothResult;
+
+/// Write policies for `CreditRole`.
+///
+/// These policies are responsible for:
+/// - requiring authentication
+/// - requiring publisher membership (tenant boundary)
+pub struct CreditRolePolicy;
+
+impl CreatePolicy<NewCreditRole> for CreditRolePolicy {
+    fn can_create<C: PolicyContext>(ctx: &C, data: &NewCreditRole, _params: ()) -> ThothResult<()> {
+        ctx.require_publisher_for(data)?;
+        Ok(())
+    }
+}
+
+impl UpdatePolicy<CreditRole, PatchCreditRole> for CreditRolePolicy {
+    fn can_update<C: PolicyContext>(
+        ctx: &C,
+        current: &CreditRole,
+        patch: &PatchCreditRole,
+        _params: (),
+    ) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        ctx.require_publisher_for(patch)?;
+
+        Ok(())
+    }
+}
+
+impl DeletePolicy<CreditRole> for CreditRolePolicy {
+    fn can_delete<C: PolicyContext>(ctx: &C, current: &CreditRole) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,499 @@
+use super::*;
+use crate::model::Crud;
+use uuid::Uuid;
+
+fn make_credit_role(
+    pool: &crate::db::PgPool,
+    contribution_id: Uuid,
+    role_type: CreditRoleType,
+    degree: Option<CreditRoleDegree>,
+) -> CreditRole {
+    let new_credit_role = NewCreditRole {
+        contribution_id,
+        role_type,
+        degree,
+    };
+
+    CreditRole::create(pool, &new_credit_role).expect("Failed to create CRediT role")
+}
+
+mod defaults {
+    use super::*;
+
+    #[test]
+    fn creditroletype_default_is_conceptualization() {
+        let role_type: CreditRoleType = Default::default();
+        assert_eq!(role_type, CreditRoleType::Conceptualization);
+    }
+
+    #[test]
+    fn creditroledegree_default_is_equal() {
+        let degree: CreditRoleDegree = Default::default();
+        assert_eq!(degree, CreditRoleDegree::Equal);
+    }
+
+    #[test]
+    fn creditrolefield_default_is_role_type() {
+        let field: CreditRoleField = Default::default();
+        assert_eq!(field, CreditRoleField::RoleType);
+    }
+}
+
+mod display_and_parse {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn creditroletype_display_formats_expected_strings() {
+        assert_eq!(format!("{}", CreditRoleType::DataCuration), "Data Curation");
+        assert_eq!(
+            format!("{}", CreditRoleType::WritingReviewEditing),
+            "Writing Review Editing"
+        );
+    }
+
+    #[test]
+    fn creditroletype_fromstr_parses_expected_values() {
+        assert_eq!(
+            CreditRoleType::from_str("Formal Analysis").unwrap(),
+            CreditRoleType::FormalAnalysis
+        );
+        assert!(CreditRoleType::from_str("Typesetting").is_err());
+    }
+
+    #[test]
+    fn creditroledegree_display_formats_expected_strings() {
+        assert_eq!(format!("{}", CreditRoleDegree::Lead), "Lead");
+        assert_eq!(format!("{}", CreditRoleDegree::Supporting), "Supporting");
+    }
+}
+
+mod identifiers {
+    use super::*;
+
+    #[test]
+    fn creditroletype_uri_uses_credit_vocabulary() {
+        assert_eq!(
+            CreditRoleType::Conceptualization.uri(),
+            "https://credit.niso.org/contributor-roles/conceptualization/"
+        );
+        assert_eq!(
+            CreditRoleType::WritingOriginalDraft.uri(),
+            "https://credit.niso.org/contributor-roles/writing-original-draft/"
+        );
+    }
+}
+
+#[cfg(feature = "backend")]
+mod conversions {
+    use super::*;
+    use crate::model::tests::db::setup_test_db;
+    use crate::model::tests::{assert_db_enum_roundtrip, assert_graphql_enum_roundtrip};
+
+    #[test]
+    fn creditroletype_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(CreditRoleType::ProjectAdministration);
+    }
+
+    #[test]
+    fn creditroledegree_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(CreditRoleDegree::Supporting);
+    }
+
+    #[test]
+    fn creditroletype_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<CreditRoleType, crate::schema::sql_types::CreditRoleType>(
+            pool.as_ref(),
+            "'writing_review_editing'::credit_role_type",
+            CreditRoleType::WritingReviewEditing,
+        );
+    }
+
+    #[test]
+    fn creditroledegree_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<CreditRoleDegree, crate::schema::sql_types::CreditRoleDegree>(
+            pool.as_ref(),
+            "'lead'::credit_role_degree",
+            CreditRoleDegree::Lead,
+        );
+    }
+}
+
+mod helpers {
+    use super::*;
+    use crate::model::{Crud, HistoryEntry};
+
+    #[test]
+    fn pk_returns_id() {
+        let credit_role: CreditRole = Default::default();
+        assert_eq!(credit_role.pk(), credit_role.credit_role_id);
+    }
+
+    #[test]
+    fn history_entry_serializes_model() {
+        let credit_role: CreditRole = Default::default();
+        let user_id = "123456".to_string();
+        let new_credit_role_history = credit_role.new_history_entry(&user_id);
+        assert_eq!(
+            new_credit_role_history.credit_role_id,
+            credit_role.credit_role_id
+        );
+        assert_eq!(new_credit_role_history.user_id, user_id);
+        assert_eq!(
+            new_credit_role_history.data,
+            serde_json::Value::String(serde_json::to_string(&credit_role).unwrap())
+        );
+    }
+}
+
+#[cfg(feature = "backend")]
+mod policy {
+    use super::*;
+
+    use crate::model::credit_role::policy::CreditRolePolicy;
+    use crate::model::tests::db::{
+        create_contribution, create_contributor, create_imprint, create_publisher, create_work,
+        setup_test_db, test_context_with_user, test_user_with_role,
+    };
+    use crate::policy::{CreatePolicy, DeletePolicy, Role, UpdatePolicy};
+
+    #[test]
+    fn crud_policy_allows_publisher_user_for_write() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("credit-role-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+        let new_credit_role = NewCreditRole {
+            contribution_id: contribution.contribution_id,
+            role_type: CreditRoleType::Methodology,
+            degree: Some(CreditRoleDegree::Lead),
+        };
+
+        let credit_role =
+            CreditRole::create(pool.as_ref(), &new_credit_role).expect("Failed to create");
+        let patch = PatchCreditRole {
+            credit_role_id: credit_role.credit_role_id,
+            contribution_id: credit_role.contribution_id,
+            role_type: CreditRoleType::Methodology,
+            degree: Some(CreditRoleDegree::Supporting),
+        };
+
+        assert!(CreditRolePolicy::can_create(&ctx, &new_credit_role, ()).is_ok());
+        assert!(CreditRolePolicy::can_update(&ctx, &credit_role, &patch, ()).is_ok());
+        assert!(CreditRolePolicy::can_delete(&ctx, &credit_role).is_ok());
+    }
+
+    #[test]
+    fn crud_policy_rejects_user_without_publisher_role() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+        let credit_role = make_credit_role(
+            pool.as_ref(),
+            contribution.contribution_id,
+            CreditRoleType::Software,
+            None,
+        );
+        let patch = PatchCreditRole {
+            credit_role_id: credit_role.credit_role_id,
+            contribution_id: credit_role.contribution_id,
+            role_type: CreditRoleType::Software,
+            degree: Some(CreditRoleDegree::Equal),
+        };
+
+        let user = test_user_with_role("credit-role-user", Role::PublisherUser, "org-other");
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let new_credit_role = NewCreditRole {
+            contribution_id: contribution.contribution_id,
+            role_type: CreditRoleType::Validation,
+            degree: None,
+        };
+
+        assert!(CreditRolePolicy::can_create(&ctx, &new_credit_role, ()).is_err());
+        assert!(CreditRolePolicy::can_update(&ctx, &credit_role, &patch, ()).is_err());
+        assert!(CreditRolePolicy::can_delete(&ctx, &credit_role).is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod crud {
+    use super::*;
+
+    use crate::model::tests::db::{
+        create_contribution, create_contributor, create_imprint, create_publisher, create_work,
+        setup_test_db, test_context,
+    };
+
+    #[test]
+    fn crud_roundtrip_create_fetch_update_delete() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+
+        let credit_role = make_credit_role(
+            pool.as_ref(),
+            contribution.contribution_id,
+            CreditRoleType::Investigation,
+            Some(CreditRoleDegree::Lead),
+        );
+        let fetched = CreditRole::from_id(pool.as_ref(), &credit_role.credit_role_id)
+            .expect("Failed to fetch");
+        assert_eq!(credit_role.credit_role_id, fetched.credit_role_id);
+        assert_eq!(fetched.degree, Some(CreditRoleDegree::Lead));
+
+        let patch = PatchCreditRole {
+            credit_role_id: credit_role.credit_role_id,
+            contribution_id: credit_role.contribution_id,
+            role_type: CreditRoleType::Investigation,
+            degree: None,
+        };
+
+        let ctx = test_context(pool.clone(), "test-user");
+        let updated = credit_role.update(&ctx, &patch).expect("Failed to update");
+        assert_eq!(updated.degree, None);
+
+        let deleted = updated.delete(pool.as_ref()).expect("Failed to delete");
+        assert!(CreditRole::from_id(pool.as_ref(), &deleted.credit_role_id).is_err());
+    }
+
+    #[test]
+    fn crud_rejects_duplicate_role_type() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+
+        make_credit_role(
+            pool.as_ref(),
+            contribution.contribution_id,
+            CreditRoleType::Supervision,
+            Some(CreditRoleDegree::Lead),
+        );
+        let duplicate = NewCreditRole {
+            contribution_id: contribution.contribution_id,
+            role_type: CreditRoleType::Supervision,
+            degree: Some(CreditRoleDegree::Supporting),
+        };
+
+        assert!(CreditRole::create(pool.as_ref(), &duplicate).is_err());
+    }
+
+    #[test]
+    fn crud_count_filters_by_role_type() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+
+        make_credit_role(
+            pool.as_ref(),
+            contribution.contribution_id,
+            CreditRoleType::Resources,
+            None,
+        );
+        make_credit_role(
+            pool.as_ref(),
+            contribution.contribution_id,
+            CreditRoleType::Visualization,
+            None,
+        );
+
+        let total = CreditRole::count(pool.as_ref(), None, vec![], vec![], vec![], None, None)
+            .expect("Failed to count CRediT roles");
+        let filtered = CreditRole::count(
+            pool.as_ref(),
+            None,
+            vec![],
+            vec![CreditRoleType::Visualization],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to count CRediT roles by type");
+
+        assert_eq!(total, 2);
+        assert_eq!(filtered, 1);
+    }
+
+    #[test]
+    fn crud_filter_parent_contribution_id_limits_results() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let other_work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+        let other_contribution = create_contribution(pool.as_ref(), &other_work, &contributor);
+
+        let matches = make_credit_role(
+            pool.as_ref(),
+            contribution.contribution_id,
+            CreditRoleType::Conceptualization,
+            None,
+        );
+        make_credit_role(
+            pool.as_ref(),
+            other_contribution.contribution_id,
+            CreditRoleType::Conceptualization,
+            None,
+        );
+
+        let filtered = CreditRole::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            CreditRoleOrderBy {
+                field: CreditRoleField::CreditRoleId,
+                direction: Direction::Asc,
+            },
+            vec![],
+            Some(contribution.contribution_id),
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to filter CRediT roles by contribution");
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].credit_role_id, matches.credit_role_id);
+    }
+
+    #[test]
+    fn crud_filter_publishers_limits_results() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+        let matches = make_credit_role(
+            pool.as_ref(),
+            contribution.contribution_id,
+            CreditRoleType::FormalAnalysis,
+            None,
+        );
+
+        let other_publisher = create_publisher(pool.as_ref());
+        let other_imprint = create_imprint(pool.as_ref(), &other_publisher);
+        let other_work = create_work(pool.as_ref(), &other_imprint);
+        let other_contribution = create_contribution(pool.as_ref(), &other_work, &contributor);
+        make_credit_role(
+            pool.as_ref(),
+            other_contribution.contribution_id,
+            CreditRoleType::FormalAnalysis,
+            None,
+        );
+
+        let filtered = CreditRole::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            CreditRoleOrderBy {
+                field: CreditRoleField::CreditRoleId,
+                direction: Direction::Asc,
+            },
+            vec![publisher.publisher_id],
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to filter CRediT roles by publisher");
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].credit_role_id, matches.credit_role_id);
+    }
+
+    #[test]
+    fn crud_ordering_by_fields_is_supported() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+
+        make_credit_role(
+            pool.as_ref(),
+            contribution.contribution_id,
+            CreditRoleType::Validation,
+            Some(CreditRoleDegree::Lead),
+        );
+        make_credit_role(
+            pool.as_ref(),
+            contribution.contribution_id,
+            CreditRoleType::Software,
+            None,
+        );
+
+        for field in [
+            CreditRoleField::CreditRoleId,
+            CreditRoleField::ContributionId,
+            CreditRoleField::RoleType,
+            CreditRoleField::Degree,
+            CreditRoleField::CreatedAt,
+            CreditRoleField::UpdatedAt,
+        ] {
+            for direction in [Direction::Asc, Direction::Desc] {
+                let results = CreditRole::all(
+                    pool.as_ref(),
+                    10,
+                    0,
+                    None,
+                    CreditRoleOrderBy {
+                        field: field.clone(),
+                        direction,
+                    },
+                    vec![],
+                    Some(contribution.contribution_id),
+                    None,
+                    vec![],
+                    vec![],
+                    None,
+                    None,
+                )
+                .expect("Failed to order CRediT roles");
+                assert_eq!(results.len(), 2);
+            }
+        }
+    }
+}
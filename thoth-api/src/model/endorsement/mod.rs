@@ -50,6 +50,8 @@ pub struct Endorsement {
     graphql(description = "Set of values required to define a new endorsement linked to a work"),
     diesel(table_name = endorsement)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewEndorsement {
     pub work_id: Uuid,
     pub author_name: Option<String>,
@@ -67,6 +69,8 @@ pub struct NewEndorsement {
     graphql(description = "Set of values required to update an existing endorsement"),
     diesel(table_name = endorsement, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchEndorsement {
     pub endorsement_id: Uuid,
     pub work_id: Uuid,
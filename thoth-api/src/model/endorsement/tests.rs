@@ -202,6 +202,7 @@ mod policy {
                 first_page: Some("1".to_string()),
                 last_page: Some("10".to_string()),
                 page_interval: Some("1-10".to_string()),
+                expose_latest_file_url: Some(true),
             },
         )
         .expect("Failed to create chapter work");
@@ -1,8 +1,8 @@
-use super::FileType;
 use super::{
     upload_request_headers, File, FileCleanupCandidate, FilePolicy, FileUpload, FileUploadResponse,
     NewFile, NewFileUpload,
 };
+use super::{FileType, FileValidationStatus};
 use crate::db::PgPool;
 use crate::model::{
     additional_resource::{AdditionalResource, PatchAdditionalResource},
@@ -16,7 +16,7 @@ use crate::policy::{CreatePolicy, PolicyContext};
 use crate::schema::{file, file_upload};
 use crate::storage::{
     canonical_frontcover_key, canonical_publication_key, canonical_resource_key,
-    presign_put_for_upload, temp_key, S3Client, StorageConfig,
+    presign_put_for_upload, temp_key, FileValidationReport, S3Client, StorageConfig,
 };
 use chrono::{Duration, Utc};
 use diesel::prelude::*;
@@ -290,6 +290,16 @@ impl File {
             .collect()
     }
 
+    fn to_version_cleanup_candidates(object_keys: Vec<String>) -> Vec<FileCleanupCandidate> {
+        object_keys
+            .into_iter()
+            .map(|object_key| FileCleanupCandidate {
+                file_type: FileType::Publication,
+                object_key,
+            })
+            .collect()
+    }
+
     fn to_pending_upload_cleanup_candidates(uploads: Vec<FileUpload>) -> Vec<FileCleanupCandidate> {
         uploads
             .into_iter()
@@ -304,7 +314,10 @@ impl File {
         db: &PgPool,
         publication_id: &Uuid,
     ) -> ThothResult<Vec<FileCleanupCandidate>> {
-        use crate::schema::{file::dsl as file_dsl, file_upload::dsl as file_upload_dsl};
+        use crate::schema::{
+            file::dsl as file_dsl, file_upload::dsl as file_upload_dsl,
+            file_version::dsl as file_version_dsl,
+        };
 
         let mut connection = db.get()?;
         let files = file_dsl::file
@@ -315,8 +328,14 @@ impl File {
             .filter(file_upload_dsl::publication_id.eq(Some(*publication_id)))
             .load::<FileUpload>(&mut connection)
             .map_err(ThothError::from)?;
+        let version_keys = file_version_dsl::file_version
+            .filter(file_version_dsl::publication_id.eq(*publication_id))
+            .select(file_version_dsl::object_key)
+            .load::<String>(&mut connection)
+            .map_err(ThothError::from)?;
 
         let mut candidates = Self::to_cleanup_candidates(files);
+        candidates.extend(Self::to_version_cleanup_candidates(version_keys));
         candidates.extend(Self::to_pending_upload_cleanup_candidates(uploads));
 
         Ok(Self::deduplicate_cleanup_candidates(candidates))
@@ -422,6 +441,14 @@ impl File {
             candidates.extend(Self::to_pending_upload_cleanup_candidates(
                 publication_uploads,
             ));
+            let publication_version_keys = crate::schema::file_version::dsl::file_version
+                .filter(crate::schema::file_version::dsl::publication_id.eq_any(&publication_ids))
+                .select(crate::schema::file_version::dsl::object_key)
+                .load::<String>(&mut connection)
+                .map_err(ThothError::from)?;
+            candidates.extend(Self::to_version_cleanup_candidates(
+                publication_version_keys,
+            ));
         }
 
         if !additional_resource_ids.is_empty() {
@@ -469,6 +496,57 @@ impl File {
         Ok(Self::deduplicate_cleanup_candidates(candidates))
     }
 
+    /// Point this file at a newly stored object, replacing its key, URL and content metadata.
+    pub(crate) fn update_stored_object(
+        &self,
+        db: &PgPool,
+        object_key: &str,
+        cdn_url: &str,
+        mime_type: &str,
+        bytes: i64,
+        sha256: &str,
+    ) -> ThothResult<Self> {
+        use crate::schema::file::dsl;
+
+        let mut connection = db.get()?;
+        diesel::update(dsl::file.find(self.file_id))
+            .set((
+                dsl::object_key.eq(object_key),
+                dsl::cdn_url.eq(cdn_url),
+                dsl::mime_type.eq(mime_type),
+                dsl::bytes.eq(bytes),
+                dsl::sha256.eq(sha256),
+                // the previous validation result no longer describes the stored object
+                dsl::validation_status.eq(FileValidationStatus::NotValidated),
+                dsl::validation_issues.eq(serde_json::json!([])),
+                dsl::validated_at.eq(None::<Timestamp>),
+            ))
+            .get_result::<File>(&mut connection)
+            .map_err(ThothError::from)
+    }
+
+    /// Store the outcome of validating this file's contents.
+    pub(crate) fn record_validation(
+        &self,
+        db: &PgPool,
+        report: &FileValidationReport,
+    ) -> ThothResult<Self> {
+        use crate::schema::file::dsl;
+
+        let issues = serde_json::to_value(&report.issues)?;
+        let validated_at =
+            (report.status != FileValidationStatus::NotValidated).then(Timestamp::now);
+        let mut connection = db.get()?;
+        diesel::update(dsl::file.find(self.file_id))
+            .set((
+                dsl::validation_status.eq(report.status),
+                dsl::validation_issues.eq(issues),
+                dsl::validated_at.eq(validated_at),
+            ))
+            .get_result::<File>(&mut connection)
+            .map_err(ThothError::from)
+    }
+
     pub fn from_object_key(db: &PgPool, object_key: &str) -> ThothResult<Self> {
         use crate::schema::file::dsl;
 
@@ -532,6 +610,56 @@ impl File {
             .optional()
             .map_err(ThothError::from)
     }
+
+    /// Load every stored file belonging to works in the given imprint, ordered by object key.
+    pub fn all_for_imprint(db: &PgPool, imprint_id: &Uuid) -> ThothResult<Vec<Self>> {
+        use crate::schema::additional_resource::dsl as additional_resource_dsl;
+        use crate::schema::file::dsl as file_dsl;
+        use crate::schema::publication::dsl as publication_dsl;
+        use crate::schema::work::dsl as work_dsl;
+        use crate::schema::work_featured_video::dsl as work_featured_video_dsl;
+
+        let mut connection = db.get()?;
+
+        let work_ids = work_dsl::work
+            .filter(work_dsl::imprint_id.eq(*imprint_id))
+            .select(work_dsl::work_id)
+            .load::<Uuid>(&mut connection)
+            .map_err(ThothError::from)?;
+        if work_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let publication_ids = publication_dsl::publication
+            .filter(publication_dsl::work_id.eq_any(&work_ids))
+            .select(publication_dsl::publication_id)
+            .load::<Uuid>(&mut connection)
+            .map_err(ThothError::from)?;
+
+        let additional_resource_ids = additional_resource_dsl::additional_resource
+            .filter(additional_resource_dsl::work_id.eq_any(&work_ids))
+            .select(additional_resource_dsl::additional_resource_id)
+            .load::<Uuid>(&mut connection)
+            .map_err(ThothError::from)?;
+
+        let work_featured_video_ids = work_featured_video_dsl::work_featured_video
+            .filter(work_featured_video_dsl::work_id.eq_any(&work_ids))
+            .select(work_featured_video_dsl::work_featured_video_id)
+            .load::<Uuid>(&mut connection)
+            .map_err(ThothError::from)?;
+
+        file_dsl::file
+            .filter(
+                file_dsl::work_id
+                    .eq_any(&work_ids)
+                    .or(file_dsl::publication_id.eq_any(&publication_ids))
+                    .or(file_dsl::additional_resource_id.eq_any(&additional_resource_ids))
+                    .or(file_dsl::work_featured_video_id.eq_any(&work_featured_video_ids)),
+            )
+            .order(file_dsl::object_key.asc())
+            .load::<File>(&mut connection)
+            .map_err(ThothError::from)
+    }
 }
 
 impl NewFileUpload {
@@ -682,23 +810,18 @@ impl FileUpload {
         mime_type: &str,
         bytes: i64,
     ) -> ThothResult<(File, Option<String>)> {
-        use crate::schema::file::dsl as file_dsl;
-
         let existing_file = self.existing_file(ctx.db())?;
         let old_object_key = existing_file.as_ref().map(|file| file.object_key.clone());
 
         let file = if let Some(existing) = existing_file {
-            let mut connection = ctx.db().get()?;
-            diesel::update(file_dsl::file.find(existing.file_id))
-                .set((
-                    file_dsl::object_key.eq(canonical_key),
-                    file_dsl::cdn_url.eq(cdn_url),
-                    file_dsl::mime_type.eq(mime_type),
-                    file_dsl::bytes.eq(bytes),
-                    file_dsl::sha256.eq(&self.declared_sha256),
-                ))
-                .get_result::<File>(&mut connection)
-                .map_err(ThothError::from)?
+            existing.update_stored_object(
+                ctx.db(),
+                canonical_key,
+                cdn_url,
+                mime_type,
+                bytes,
+                &self.declared_sha256,
+            )?
         } else {
             let new_file = NewFile {
                 file_type: self.file_type,
@@ -787,7 +910,7 @@ impl FileUpload {
         Ok(())
     }
 
-    fn upsert_thoth_location<C: PolicyContext>(
+    pub(crate) fn upsert_thoth_location<C: PolicyContext>(
         ctx: &C,
         publication_id: Uuid,
         landing_page: Option<String>,
@@ -50,6 +50,97 @@ pub enum FileType {
     WorkFeaturedVideo,
 }
 
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Outcome of validating the contents of a stored file"),
+    ExistingTypePath = "crate::schema::sql_types::FileValidationStatus"
+)]
+#[derive(
+    Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "snake_case")]
+pub enum FileValidationStatus {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "not_validated",
+        graphql(
+            description = "No validator applies to this file, or it was stored before validation was introduced"
+        )
+    )]
+    #[default]
+    NotValidated,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "passed",
+        graphql(description = "All applicable checks passed")
+    )]
+    Passed,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "passed_with_warnings",
+        graphql(description = "No errors were found, but some checks raised warnings")
+    )]
+    PassedWithWarnings,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "failed",
+        graphql(description = "At least one check found an error")
+    )]
+    Failed,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Severity of a file validation finding")
+)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "lowercase")]
+pub enum FileValidationSeverity {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            name = "ERROR",
+            description = "The file is structurally broken and is likely to be rejected by platforms"
+        )
+    )]
+    #[serde(rename = "ERROR")]
+    #[strum(serialize = "error")]
+    Severe,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "The file is usable but may not meet stricter conformance requirements"
+        )
+    )]
+    Warning,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "A single finding reported while validating a stored file")
+)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileValidationIssue {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Name of the validator that reported the finding, e.g. 'epub'")
+    )]
+    pub validator: String,
+    #[cfg_attr(feature = "backend", graphql(description = "Severity of the finding"))]
+    pub severity: FileValidationSeverity,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Description of the finding")
+    )]
+    pub message: String,
+}
+
 #[cfg_attr(feature = "backend", derive(diesel::Queryable))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -67,6 +158,17 @@ pub struct File {
     pub sha256: String,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    pub validation_status: FileValidationStatus,
+    /// JSON array of `FileValidationIssue`
+    pub validation_issues: serde_json::Value,
+    pub validated_at: Option<Timestamp>,
+}
+
+impl File {
+    /// Findings recorded by the most recent validation of this file.
+    pub fn validation_issues(&self) -> Vec<FileValidationIssue> {
+        serde_json::from_value(self.validation_issues.clone()).unwrap_or_default()
+    }
 }
 
 #[cfg(feature = "backend")]
@@ -128,109 +230,200 @@ pub struct NewFile {
     pub sha256: String,
 }
 
-#[cfg(feature = "backend")]
-#[derive(juniper::GraphQLInputObject)]
-#[graphql(description = "Input for starting a publication file upload (PDF, EPUB, XML, etc.).")]
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Input for starting a publication file upload (PDF, EPUB, XML, etc.).")
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewPublicationFileUpload {
-    #[graphql(description = "Thoth ID of the publication linked to this file.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Thoth ID of the publication linked to this file.")
+    )]
     pub publication_id: Uuid,
-    #[graphql(
-        description = "MIME type declared by the client (used for validation and in the presigned URL)."
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "MIME type declared by the client (used for validation and in the presigned URL)."
+        )
     )]
     pub declared_mime_type: String,
-    #[graphql(
-        description = "File extension to use in the final canonical key, e.g. 'pdf', 'epub', 'xml'."
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "File extension to use in the final canonical key, e.g. 'pdf', 'epub', 'xml'."
+        )
     )]
     pub declared_extension: String,
-    #[graphql(description = "SHA-256 checksum of the file, hex-encoded.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "SHA-256 checksum of the file, hex-encoded.")
+    )]
     pub declared_sha256: String,
 }
 
-#[cfg(feature = "backend")]
-#[derive(juniper::GraphQLInputObject)]
-#[graphql(description = "Input for starting a front cover upload for a work.")]
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Input for starting a front cover upload for a work.")
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewFrontcoverFileUpload {
-    #[graphql(description = "Thoth ID of the work this front cover belongs to.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Thoth ID of the work this front cover belongs to.")
+    )]
     pub work_id: Uuid,
-    #[graphql(description = "MIME type declared by the client (e.g. 'image/jpeg').")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "MIME type declared by the client (e.g. 'image/jpeg').")
+    )]
     pub declared_mime_type: String,
-    #[graphql(
-        description = "File extension to use in the final canonical key, e.g. 'jpg', 'png', 'webp'."
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "File extension to use in the final canonical key, e.g. 'jpg', 'png', 'webp'."
+        )
     )]
     pub declared_extension: String,
-    #[graphql(description = "SHA-256 checksum of the file, hex-encoded.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "SHA-256 checksum of the file, hex-encoded.")
+    )]
     pub declared_sha256: String,
 }
 
-#[cfg(feature = "backend")]
-#[derive(juniper::GraphQLInputObject)]
-#[graphql(description = "Input for starting an upload for an additional resource asset.")]
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Input for starting an upload for an additional resource asset.")
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewAdditionalResourceFileUpload {
-    #[graphql(description = "Thoth ID of the additional resource linked to this file.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Thoth ID of the additional resource linked to this file.")
+    )]
     pub additional_resource_id: Uuid,
-    #[graphql(
-        description = "MIME type declared by the client (used for validation and in the presigned URL)."
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "MIME type declared by the client (used for validation and in the presigned URL)."
+        )
     )]
     pub declared_mime_type: String,
-    #[graphql(
-        description = "File extension to use in the final canonical key, e.g. 'jpg', 'png', 'mp4', 'xlsx'."
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "File extension to use in the final canonical key, e.g. 'jpg', 'png', 'mp4', 'xlsx'."
+        )
     )]
     pub declared_extension: String,
-    #[graphql(description = "SHA-256 checksum of the file, hex-encoded.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "SHA-256 checksum of the file, hex-encoded.")
+    )]
     pub declared_sha256: String,
 }
 
-#[cfg(feature = "backend")]
-#[derive(juniper::GraphQLInputObject)]
-#[graphql(description = "Input for starting an upload for a work featured video.")]
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Input for starting an upload for a work featured video.")
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewWorkFeaturedVideoFileUpload {
-    #[graphql(description = "Thoth ID of the work featured video linked to this file.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Thoth ID of the work featured video linked to this file.")
+    )]
     pub work_featured_video_id: Uuid,
-    #[graphql(
-        description = "MIME type declared by the client (used for validation and in the presigned URL)."
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "MIME type declared by the client (used for validation and in the presigned URL)."
+        )
     )]
     pub declared_mime_type: String,
-    #[graphql(
-        description = "File extension to use in the final canonical key, e.g. 'mp4', 'webm', 'mov'."
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "File extension to use in the final canonical key, e.g. 'mp4', 'webm', 'mov'."
+        )
     )]
     pub declared_extension: String,
-    #[graphql(description = "SHA-256 checksum of the file, hex-encoded.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "SHA-256 checksum of the file, hex-encoded.")
+    )]
     pub declared_sha256: String,
 }
 
-#[cfg(feature = "backend")]
-#[derive(juniper::GraphQLInputObject)]
-#[graphql(
-    description = "Input for completing a file upload and promoting it to its final DOI-based location."
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(
+        description = "Input for completing a file upload and promoting it to its final DOI-based location."
+    )
 )]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CompleteFileUpload {
-    #[graphql(description = "ID of the upload session to complete.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "ID of the upload session to complete.")
+    )]
     pub file_upload_id: Uuid,
 }
 
-#[cfg(feature = "backend")]
-#[derive(juniper::GraphQLObject)]
-#[graphql(
-    description = "Response from initiating a file upload, containing the upload URL and expiration time."
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(
+        description = "Response from initiating a file upload, containing the upload URL and expiration time."
+    )
 )]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct FileUploadResponse {
-    #[graphql(description = "ID of the upload session.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "ID of the upload session.")
+    )]
     pub file_upload_id: Uuid,
-    #[graphql(description = "Presigned S3 PUT URL for uploading the file.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Presigned S3 PUT URL for uploading the file.")
+    )]
     pub upload_url: String,
-    #[graphql(description = "Headers that must be sent with the HTTP PUT request to uploadUrl.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Headers that must be sent with the HTTP PUT request to uploadUrl.")
+    )]
     pub upload_headers: Vec<UploadRequestHeader>,
-    #[graphql(description = "Time when the upload URL expires.")]
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Time when the upload URL expires.")
+    )]
     pub expires_at: Timestamp,
 }
 
-#[cfg(feature = "backend")]
-#[derive(juniper::GraphQLObject)]
-#[graphql(description = "Single required HTTP header for presigned file upload.")]
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "Single required HTTP header for presigned file upload.")
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct UploadRequestHeader {
-    #[graphql(description = "HTTP header name.")]
+    #[cfg_attr(feature = "backend", graphql(description = "HTTP header name."))]
     pub name: String,
-    #[graphql(description = "HTTP header value.")]
+    #[cfg_attr(feature = "backend", graphql(description = "HTTP header value."))]
     pub value: String,
 }
 
@@ -126,6 +126,9 @@ fn create_pdf_publication(
         accessibility_additional_standard: None,
         accessibility_exception: None,
         accessibility_report_url: None,
+        license: None,
+        access_status: None,
+        embargo_end_date: None,
     };
 
     Publication::create(pool, &new_publication).expect("Failed to create PDF publication")
@@ -320,11 +323,52 @@ mod helpers {
             sha256: TEST_SHA256_HEX.to_string(),
             created_at: Timestamp::default(),
             updated_at: Timestamp::default(),
+            validation_status: FileValidationStatus::NotValidated,
+            validation_issues: serde_json::json!([]),
+            validated_at: None,
         };
 
         assert_eq!(file.pk(), file_id);
     }
 
+    #[test]
+    fn validation_issues_are_decoded_from_json() {
+        let mut file = File {
+            file_id: Uuid::new_v4(),
+            file_type: FileType::Publication,
+            work_id: None,
+            publication_id: Some(Uuid::new_v4()),
+            additional_resource_id: None,
+            work_featured_video_id: None,
+            object_key: "10.00000/test.pdf".to_string(),
+            cdn_url: "https://cdn.example.com/10.00000/test.pdf".to_string(),
+            mime_type: "application/pdf".to_string(),
+            bytes: 1024,
+            sha256: TEST_SHA256_HEX.to_string(),
+            created_at: Timestamp::default(),
+            updated_at: Timestamp::default(),
+            validation_status: FileValidationStatus::PassedWithWarnings,
+            validation_issues: serde_json::json!([{
+                "validator": "pdf",
+                "severity": "WARNING",
+                "message": "No PDF/A identification"
+            }]),
+            validated_at: Some(Timestamp::default()),
+        };
+
+        assert_eq!(
+            file.validation_issues(),
+            vec![FileValidationIssue {
+                validator: "pdf".to_string(),
+                severity: FileValidationSeverity::Warning,
+                message: "No PDF/A identification".to_string(),
+            }]
+        );
+
+        file.validation_issues = serde_json::Value::Null;
+        assert!(file.validation_issues().is_empty());
+    }
+
     #[test]
     fn pk_returns_file_upload_id() {
         let file_upload_id = Uuid::new_v4();
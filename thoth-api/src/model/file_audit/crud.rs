@@ -0,0 +1,180 @@
+use super::{FileAudit, FileAuditField, FileAuditOrderBy, NewFileAudit};
+use crate::db::PgPool;
+use crate::model::Crud;
+use crate::policy::PolicyContext;
+use crate::schema::file_audit;
+use diesel::pg::Pg;
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+impl Crud for FileAudit {
+    type NewEntity = NewFileAudit;
+    type PatchEntity = NewFileAudit;
+    type OrderByEntity = FileAuditOrderBy;
+    /// Only return audits of files belonging to these imprints
+    type FilterParameter1 = Uuid;
+    /// Only return audits recorded in these audit runs
+    type FilterParameter2 = Uuid;
+    /// Only return audits that found at least one problem
+    type FilterParameter3 = bool;
+    type FilterParameter4 = ();
+
+    fn pk(&self) -> Uuid {
+        self.file_audit_id
+    }
+
+    fn all(
+        db: &PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        _: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        parent_id_2: Option<Uuid>,
+        imprint_ids: Vec<Self::FilterParameter1>,
+        audit_run_ids: Vec<Self::FilterParameter2>,
+        failures_only: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<Vec<FileAudit>> {
+        use crate::schema::file_audit::dsl::*;
+        let mut connection = db.get()?;
+        let mut query = filtered_audits(
+            filter,
+            parent_id_1.into_iter().chain(imprint_ids).collect(),
+            parent_id_2.into_iter().chain(audit_run_ids).collect(),
+            failures_only.unwrap_or(false),
+        );
+
+        query = match order.field {
+            FileAuditField::FileAuditId => {
+                apply_directional_order!(query, order.direction, order, file_audit_id)
+            }
+            FileAuditField::FileId => {
+                apply_directional_order!(query, order.direction, order, file_id, checked_at)
+            }
+            FileAuditField::ObjectKey => {
+                apply_directional_order!(query, order.direction, order, object_key, checked_at)
+            }
+            FileAuditField::CheckedAt => {
+                apply_directional_order!(query, order.direction, order, checked_at, object_key)
+            }
+        };
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<FileAudit>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        imprint_ids: Vec<Self::FilterParameter1>,
+        audit_run_ids: Vec<Self::FilterParameter2>,
+        failures_only: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<i32> {
+        let mut connection = db.get()?;
+        filtered_audits(
+            filter,
+            imprint_ids,
+            audit_run_ids,
+            failures_only.unwrap_or(false),
+        )
+        .count()
+        .get_result::<i64>(&mut connection)
+        .map(|t| t.to_string().parse::<i32>().unwrap())
+        .map_err(Into::into)
+    }
+
+    fn from_id(db: &PgPool, entity_id: &Uuid) -> ThothResult<Self> {
+        let mut connection = db.get()?;
+        file_audit::table
+            .find(entity_id)
+            .get_result::<FileAudit>(&mut connection)
+            .map_err(ThothError::from)
+    }
+
+    fn create(db: &PgPool, data: &NewFileAudit) -> ThothResult<Self> {
+        let mut connection = db.get()?;
+        diesel::insert_into(file_audit::table)
+            .values(data)
+            .get_result::<FileAudit>(&mut connection)
+            .map_err(ThothError::from)
+    }
+
+    fn update<C: PolicyContext>(&self, _ctx: &C, _data: &NewFileAudit) -> ThothResult<Self> {
+        unimplemented!()
+    }
+
+    fn delete(self, db: &PgPool) -> ThothResult<Self> {
+        let mut connection = db.get()?;
+        diesel::delete(file_audit::table.find(self.file_audit_id))
+            .execute(&mut connection)
+            .map(|_| self)
+            .map_err(ThothError::from)
+    }
+}
+
+/// Build the audit query shared by `all` and `count`, restricted by the given filters.
+fn filtered_audits(
+    filter: Option<String>,
+    imprint_ids: Vec<Uuid>,
+    audit_run_ids: Vec<Uuid>,
+    failures_only: bool,
+) -> file_audit::BoxedQuery<'static, Pg> {
+    use crate::schema::file_audit::dsl::*;
+    let mut query = file_audit.into_boxed();
+
+    if !imprint_ids.is_empty() {
+        query = query.filter(imprint_id.eq_any(imprint_ids));
+    }
+    if !audit_run_ids.is_empty() {
+        query = query.filter(audit_run_id.eq_any(audit_run_ids));
+    }
+    if failures_only {
+        query = query.filter(
+            object_missing
+                .eq(true)
+                .or(size_mismatch.eq(true))
+                .or(checksum_mismatch.eq(true))
+                .or(mime_type_mismatch.eq(true))
+                .or(error_message.is_not_null()),
+        );
+    }
+    if let Some(filter) = filter {
+        if !filter.is_empty() {
+            query = query.filter(object_key.ilike(format!("%{filter}%")));
+        }
+    }
+    query
+}
+
+impl FileAudit {
+    /// Return the most recent audit recorded for each file in the given imprint.
+    pub fn latest_for_imprint(db: &PgPool, imprint_id: &Uuid) -> ThothResult<Vec<FileAudit>> {
+        use crate::schema::file_audit::dsl;
+
+        let mut connection = db.get()?;
+        dsl::file_audit
+            .filter(dsl::imprint_id.eq(imprint_id))
+            .distinct_on(dsl::file_id)
+            .order_by((dsl::file_id, dsl::checked_at.desc()))
+            .load::<FileAudit>(&mut connection)
+            .map_err(ThothError::from)
+    }
+
+    /// Return the IDs of files whose most recent audit in the given imprint found a problem.
+    pub fn latest_failed_file_ids(db: &PgPool, imprint_id: &Uuid) -> ThothResult<Vec<Uuid>> {
+        Ok(Self::latest_for_imprint(db, imprint_id)?
+            .into_iter()
+            .filter(|audit| !audit.passed())
+            .map(|audit| audit.file_id)
+            .collect())
+    }
+}
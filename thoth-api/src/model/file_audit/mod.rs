@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::graphql::types::inputs::Direction;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::file_audit;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting file audit results")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FileAuditField {
+    FileAuditId,
+    FileId,
+    ObjectKey,
+    #[default]
+    CheckedAt,
+}
+
+/// Result of comparing a single `file` row against the object stored in S3.
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileAudit {
+    pub file_audit_id: Uuid,
+    pub audit_run_id: Uuid,
+    pub file_id: Uuid,
+    pub imprint_id: Uuid,
+    pub object_key: String,
+    pub expected_bytes: i64,
+    pub actual_bytes: Option<i64>,
+    pub expected_sha256: String,
+    pub actual_sha256: Option<String>,
+    pub expected_mime_type: String,
+    pub actual_mime_type: Option<String>,
+    pub object_missing: bool,
+    pub size_mismatch: bool,
+    pub checksum_mismatch: bool,
+    pub mime_type_mismatch: bool,
+    pub error_message: Option<String>,
+    pub checked_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel::Insertable),
+    diesel(table_name = file_audit)
+)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewFileAudit {
+    pub audit_run_id: Uuid,
+    pub file_id: Uuid,
+    pub imprint_id: Uuid,
+    pub object_key: String,
+    pub expected_bytes: i64,
+    pub actual_bytes: Option<i64>,
+    pub expected_sha256: String,
+    pub actual_sha256: Option<String>,
+    pub expected_mime_type: String,
+    pub actual_mime_type: Option<String>,
+    pub object_missing: bool,
+    pub size_mismatch: bool,
+    pub checksum_mismatch: bool,
+    pub mime_type_mismatch: bool,
+    pub error_message: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Field and order to use when sorting file audit results")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileAuditOrderBy {
+    pub field: FileAuditField,
+    pub direction: Direction,
+}
+
+/// Whether an audit found no problem with the stored object.
+fn audit_passed(
+    object_missing: bool,
+    size_mismatch: bool,
+    checksum_mismatch: bool,
+    mime_type_mismatch: bool,
+    error_message: &Option<String>,
+) -> bool {
+    !(object_missing
+        || size_mismatch
+        || checksum_mismatch
+        || mime_type_mismatch
+        || error_message.is_some())
+}
+
+impl FileAudit {
+    /// Whether the stored object matched the `file` row on every checked property.
+    pub fn passed(&self) -> bool {
+        audit_passed(
+            self.object_missing,
+            self.size_mismatch,
+            self.checksum_mismatch,
+            self.mime_type_mismatch,
+            &self.error_message,
+        )
+    }
+}
+
+impl NewFileAudit {
+    /// Whether the stored object matched the `file` row on every checked property.
+    pub fn passed(&self) -> bool {
+        audit_passed(
+            self.object_missing,
+            self.size_mismatch,
+            self.checksum_mismatch,
+            self.mime_type_mismatch,
+            &self.error_message,
+        )
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,268 @@
+use super::*;
+use uuid::Uuid;
+
+mod defaults {
+    use super::*;
+
+    #[test]
+    fn fileauditfield_default_is_checked_at() {
+        let field: FileAuditField = Default::default();
+        assert_eq!(field, FileAuditField::CheckedAt);
+    }
+}
+
+mod helpers {
+    use super::*;
+    use crate::model::Crud;
+
+    #[test]
+    fn pk_returns_id() {
+        let audit: FileAudit = Default::default();
+        assert_eq!(audit.pk(), audit.file_audit_id);
+    }
+
+    #[test]
+    fn passed_is_true_without_findings() {
+        let audit: FileAudit = Default::default();
+        assert!(audit.passed());
+        let new_audit: NewFileAudit = Default::default();
+        assert!(new_audit.passed());
+    }
+
+    #[test]
+    fn passed_is_false_for_any_finding() {
+        let findings = [
+            NewFileAudit {
+                object_missing: true,
+                ..Default::default()
+            },
+            NewFileAudit {
+                size_mismatch: true,
+                ..Default::default()
+            },
+            NewFileAudit {
+                checksum_mismatch: true,
+                ..Default::default()
+            },
+            NewFileAudit {
+                mime_type_mismatch: true,
+                ..Default::default()
+            },
+            NewFileAudit {
+                error_message: Some("Failed to head object".to_string()),
+                ..Default::default()
+            },
+        ];
+        for finding in findings {
+            assert!(!finding.passed(), "{finding:?} should not pass");
+        }
+    }
+}
+
+#[cfg(feature = "backend")]
+mod crud {
+    use super::*;
+
+    use crate::graphql::types::inputs::Direction;
+    use crate::model::file::{File, FileType, NewFile};
+    use crate::model::tests::db::{
+        create_imprint, create_publication, create_publisher, create_work, setup_test_db,
+    };
+    use crate::model::Crud;
+
+    fn make_file(pool: &crate::db::PgPool, publication_id: Uuid) -> File {
+        let object_key = format!("10.1234/{}.pdf", Uuid::new_v4());
+        File::create(
+            pool,
+            &NewFile {
+                file_type: FileType::Publication,
+                work_id: None,
+                publication_id: Some(publication_id),
+                additional_resource_id: None,
+                work_featured_video_id: None,
+                cdn_url: format!("https://cdn.example.org/{object_key}"),
+                object_key,
+                mime_type: "application/pdf".to_string(),
+                bytes: 1024,
+                sha256: "444b138b41e3c48ca505b1740091b0c93ce9a71c7c9d24956e6cf8716f1aad7e"
+                    .to_string(),
+            },
+        )
+        .expect("Failed to create file")
+    }
+
+    fn audit_for(file: &File, imprint_id: Uuid, audit_run_id: Uuid) -> NewFileAudit {
+        NewFileAudit {
+            audit_run_id,
+            file_id: file.file_id,
+            imprint_id,
+            object_key: file.object_key.clone(),
+            expected_bytes: file.bytes,
+            actual_bytes: Some(file.bytes),
+            expected_sha256: file.sha256.clone(),
+            actual_sha256: Some(file.sha256.clone()),
+            expected_mime_type: file.mime_type.clone(),
+            actual_mime_type: Some(file.mime_type.clone()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn crud_roundtrip_create_fetch_delete() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let file = make_file(pool.as_ref(), publication.publication_id);
+
+        let audit = FileAudit::create(
+            pool.as_ref(),
+            &audit_for(&file, imprint.imprint_id, Uuid::new_v4()),
+        )
+        .expect("Failed to create");
+        let fetched =
+            FileAudit::from_id(pool.as_ref(), &audit.file_audit_id).expect("Failed to fetch");
+        assert_eq!(audit, fetched);
+        assert!(fetched.passed());
+
+        let deleted = fetched.delete(pool.as_ref()).expect("Failed to delete");
+        assert!(FileAudit::from_id(pool.as_ref(), &deleted.file_audit_id).is_err());
+    }
+
+    #[test]
+    fn crud_all_filters_failures_and_count_matches() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let file = make_file(pool.as_ref(), publication.publication_id);
+        let run_id = Uuid::new_v4();
+
+        FileAudit::create(pool.as_ref(), &audit_for(&file, imprint.imprint_id, run_id))
+            .expect("Failed to create passing audit");
+        let failing = FileAudit::create(
+            pool.as_ref(),
+            &NewFileAudit {
+                actual_bytes: Some(1),
+                size_mismatch: true,
+                ..audit_for(&file, imprint.imprint_id, run_id)
+            },
+        )
+        .expect("Failed to create failing audit");
+
+        let order = FileAuditOrderBy {
+            field: FileAuditField::CheckedAt,
+            direction: Direction::Desc,
+        };
+        let all = FileAudit::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            order.clone(),
+            vec![],
+            Some(imprint.imprint_id),
+            Some(run_id),
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to load audits");
+        assert_eq!(all.len(), 2);
+
+        let failures = FileAudit::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            order,
+            vec![],
+            Some(imprint.imprint_id),
+            None,
+            vec![],
+            vec![],
+            Some(true),
+            None,
+        )
+        .expect("Failed to load failing audits");
+        assert_eq!(failures, vec![failing]);
+
+        let count = FileAudit::count(
+            pool.as_ref(),
+            None,
+            vec![],
+            vec![],
+            vec![],
+            Some(true),
+            None,
+        )
+        .expect("Failed to count failing audits");
+        assert_eq!(count, 1);
+
+        let run_count = FileAudit::count(
+            pool.as_ref(),
+            None,
+            vec![],
+            vec![imprint.imprint_id],
+            vec![run_id],
+            None,
+            None,
+        )
+        .expect("Failed to count audits in run");
+        assert_eq!(run_count, 2);
+
+        let other_run_count = FileAudit::count(
+            pool.as_ref(),
+            None,
+            vec![],
+            vec![imprint.imprint_id],
+            vec![Uuid::new_v4()],
+            None,
+            None,
+        )
+        .expect("Failed to count audits in another run");
+        assert_eq!(other_run_count, 0);
+    }
+
+    #[test]
+    fn latest_failed_file_ids_uses_most_recent_audit() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let file = make_file(pool.as_ref(), publication.publication_id);
+
+        FileAudit::create(
+            pool.as_ref(),
+            &NewFileAudit {
+                object_missing: true,
+                ..audit_for(&file, imprint.imprint_id, Uuid::new_v4())
+            },
+        )
+        .expect("Failed to create failing audit");
+        assert_eq!(
+            FileAudit::latest_failed_file_ids(pool.as_ref(), &imprint.imprint_id)
+                .expect("Failed to load failed files"),
+            vec![file.file_id]
+        );
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        FileAudit::create(
+            pool.as_ref(),
+            &audit_for(&file, imprint.imprint_id, Uuid::new_v4()),
+        )
+        .expect("Failed to create passing audit");
+        assert!(
+            FileAudit::latest_failed_file_ids(pool.as_ref(), &imprint.imprint_id)
+                .expect("Failed to load failed files")
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use super::{FileVersion, NewFileVersion};
+use crate::db::PgPool;
+use crate::model::{publication::Publication, Crud, PublisherId};
+use crate::policy::PolicyContext;
+use crate::schema::file_version;
+use diesel::dsl::max;
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+impl Crud for FileVersion {
+    type NewEntity = NewFileVersion;
+    type PatchEntity = NewFileVersion;
+    type OrderByEntity = ();
+    type FilterParameter1 = ();
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+    type FilterParameter4 = ();
+
+    fn pk(&self) -> Uuid {
+        self.file_version_id
+    }
+
+    fn all(
+        db: &PgPool,
+        limit: i32,
+        offset: i32,
+        _filter: Option<String>,
+        _order: Self::OrderByEntity,
+        _publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _parent_id_2: Option<Uuid>,
+        _filter_param_1: Vec<Self::FilterParameter1>,
+        _filter_param_2: Vec<Self::FilterParameter2>,
+        _filter_param_3: Option<Self::FilterParameter3>,
+        _filter_param_4: Option<Self::FilterParameter4>,
+    ) -> ThothResult<Vec<FileVersion>> {
+        use crate::schema::file_version::dsl::*;
+        let mut connection = db.get()?;
+        let mut query = file_version.into_boxed();
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(publication_id.eq(pid));
+        }
+        query
+            .order(version_number.desc())
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<FileVersion>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        _db: &PgPool,
+        _filter: Option<String>,
+        _publishers: Vec<Uuid>,
+        _filter_param_1: Vec<Self::FilterParameter1>,
+        _filter_param_2: Vec<Self::FilterParameter2>,
+        _filter_param_3: Option<Self::FilterParameter3>,
+        _filter_param_4: Option<Self::FilterParameter4>,
+    ) -> ThothResult<i32> {
+        unimplemented!()
+    }
+
+    fn from_id(db: &PgPool, entity_id: &Uuid) -> ThothResult<Self> {
+        let mut connection = db.get()?;
+        file_version::table
+            .find(entity_id)
+            .get_result::<FileVersion>(&mut connection)
+            .map_err(ThothError::from)
+    }
+
+    fn create(db: &PgPool, data: &NewFileVersion) -> ThothResult<Self> {
+        let mut connection = db.get()?;
+        diesel::insert_into(file_version::table)
+            .values(data)
+            .get_result::<FileVersion>(&mut connection)
+            .map_err(ThothError::from)
+    }
+
+    fn update<C: PolicyContext>(&self, _ctx: &C, _data: &NewFileVersion) -> ThothResult<Self> {
+        unimplemented!()
+    }
+
+    fn delete(self, db: &PgPool) -> ThothResult<Self> {
+        let mut connection = db.get()?;
+        diesel::delete(file_version::table.find(self.file_version_id))
+            .execute(&mut connection)
+            .map(|_| self)
+            .map_err(ThothError::from)
+    }
+}
+
+impl PublisherId for FileVersion {
+    fn publisher_id(&self, db: &PgPool) -> ThothResult<Uuid> {
+        Publication::from_id(db, &self.publication_id)?.publisher_id(db)
+    }
+}
+
+impl FileVersion {
+    /// Number to assign to the next version of a publication's file.
+    pub fn next_version_number(db: &PgPool, publication_id: &Uuid) -> ThothResult<i32> {
+        use crate::schema::file_version::dsl;
+
+        let mut connection = db.get()?;
+        dsl::file_version
+            .filter(dsl::publication_id.eq(publication_id))
+            .select(max(dsl::version_number))
+            .get_result::<Option<i32>>(&mut connection)
+            .map(|latest| latest.unwrap_or(0) + 1)
+            .map_err(ThothError::from)
+    }
+
+    /// Whether any version has been recorded for the given file.
+    pub fn exists_for_file(db: &PgPool, file_id: &Uuid) -> ThothResult<bool> {
+        use crate::schema::file_version::dsl;
+
+        let mut connection = db.get()?;
+        dsl::file_version
+            .filter(dsl::file_id.eq(file_id))
+            .select(dsl::file_version_id)
+            .first::<Uuid>(&mut connection)
+            .optional()
+            .map(|version| version.is_some())
+            .map_err(ThothError::from)
+    }
+
+    /// Find the version stored under the given object key, if any.
+    pub fn from_object_key(db: &PgPool, object_key: &str) -> ThothResult<Option<Self>> {
+        use crate::schema::file_version::dsl;
+
+        let mut connection = db.get()?;
+        dsl::file_version
+            .filter(dsl::object_key.eq(object_key))
+            .first::<FileVersion>(&mut connection)
+            .optional()
+            .map_err(ThothError::from)
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::file_version;
+
+/// A stored version of a publication file.
+///
+/// Every completed publication file upload (or restore) creates a new version with an immutable
+/// object key, so that replacing a file never destroys what readers previously cited.
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileVersion {
+    pub file_version_id: Uuid,
+    pub file_id: Uuid,
+    pub publication_id: Uuid,
+    pub version_number: i32,
+    pub object_key: String,
+    pub cdn_url: String,
+    pub mime_type: String,
+    pub bytes: i64,
+    pub sha256: String,
+    pub uploaded_by: Option<String>,
+    pub restored_from_file_version_id: Option<Uuid>,
+    pub created_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel::Insertable),
+    diesel(table_name = file_version)
+)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewFileVersion {
+    pub file_id: Uuid,
+    pub publication_id: Uuid,
+    pub version_number: i32,
+    pub object_key: String,
+    pub cdn_url: String,
+    pub mime_type: String,
+    pub bytes: i64,
+    pub sha256: String,
+    pub uploaded_by: Option<String>,
+    pub restored_from_file_version_id: Option<Uuid>,
+    /// Defaults to the time of insertion when not set
+    pub created_at: Option<Timestamp>,
+}
+
+impl FileVersion {
+    /// File extension of the stored object, taken from its key.
+    pub fn extension(&self) -> Option<&str> {
+        object_key_extension(&self.object_key)
+    }
+}
+
+/// Extract the file extension from an object key, e.g. `pdf` from `10.1234/abc/versions/2.pdf`.
+pub fn object_key_extension(object_key: &str) -> Option<&str> {
+    let file_name = object_key.rsplit('/').next()?;
+    file_name
+        .split_once('.')
+        .map(|(_, extension)| extension)
+        .filter(|extension| !extension.is_empty())
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
+#[cfg(feature = "backend")]
+mod policy;
+#[cfg(feature = "backend")]
+pub(crate) use policy::FileVersionPolicy;
+#[cfg(test)]
+mod tests;
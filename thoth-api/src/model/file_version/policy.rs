@@ -0,0 +1,16 @@
+use super::FileVersion;
+use crate::policy::PolicyContext;
+use thoth_errors::ThothResult;
+
+/// Write policies for `FileVersion`.
+///
+/// Restoring a version publishes a new object on the CDN, so it requires the same
+/// CDN write permission as uploading a file for the linked publisher.
+pub struct FileVersionPolicy;
+
+impl FileVersionPolicy {
+    pub(crate) fn can_restore<C: PolicyContext>(ctx: &C, version: &FileVersion) -> ThothResult<()> {
+        ctx.require_cdn_write_for(version)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+use super::*;
+use uuid::Uuid;
+
+mod helpers {
+    use super::*;
+    use crate::model::Crud;
+
+    #[test]
+    fn pk_returns_id() {
+        let version: FileVersion = Default::default();
+        assert_eq!(version.pk(), version.file_version_id);
+    }
+
+    #[test]
+    fn object_key_extension_reads_last_path_segment() {
+        assert_eq!(
+            object_key_extension("10.1234/abc.def/versions/2.pdf"),
+            Some("pdf")
+        );
+        assert_eq!(object_key_extension("10.1234/abc.epub"), Some("epub"));
+        assert_eq!(object_key_extension("10.1234/abc.fb2.zip"), Some("fb2.zip"));
+        assert_eq!(object_key_extension("10.1234/abc/versions/2"), None);
+        assert_eq!(object_key_extension("10.1234/abc/versions/2."), None);
+    }
+
+    #[test]
+    fn extension_uses_object_key() {
+        let version = FileVersion {
+            object_key: "10.1234/abc/versions/3.epub".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(version.extension(), Some("epub"));
+    }
+}
+
+#[cfg(feature = "backend")]
+mod crud {
+    use super::*;
+
+    use crate::model::file::{File, FileType, NewFile};
+    use crate::model::tests::db::{
+        create_imprint, create_publication, create_publisher, create_work, setup_test_db,
+    };
+    use crate::model::Crud;
+
+    const TEST_SHA256: &str = "444b138b41e3c48ca505b1740091b0c93ce9a71c7c9d24956e6cf8716f1aad7e";
+
+    fn make_file(pool: &crate::db::PgPool, publication_id: Uuid) -> File {
+        let object_key = format!("10.1234/{}.pdf", Uuid::new_v4());
+        File::create(
+            pool,
+            &NewFile {
+                file_type: FileType::Publication,
+                work_id: None,
+                publication_id: Some(publication_id),
+                additional_resource_id: None,
+                work_featured_video_id: None,
+                cdn_url: format!("https://cdn.example.org/{object_key}"),
+                object_key,
+                mime_type: "application/pdf".to_string(),
+                bytes: 1024,
+                sha256: TEST_SHA256.to_string(),
+            },
+        )
+        .expect("Failed to create file")
+    }
+
+    fn version_for(file: &File, version_number: i32) -> NewFileVersion {
+        let object_key = format!("10.1234/{}/versions/{version_number}.pdf", file.file_id);
+        NewFileVersion {
+            file_id: file.file_id,
+            publication_id: file.publication_id.expect("Expected publication file"),
+            version_number,
+            cdn_url: format!("https://cdn.example.org/{object_key}"),
+            object_key,
+            mime_type: file.mime_type.clone(),
+            bytes: file.bytes,
+            sha256: file.sha256.clone(),
+            uploaded_by: Some("user-1".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn crud_roundtrip_create_fetch_delete() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let file = make_file(pool.as_ref(), publication.publication_id);
+
+        let version =
+            FileVersion::create(pool.as_ref(), &version_for(&file, 1)).expect("Failed to create");
+        let fetched =
+            FileVersion::from_id(pool.as_ref(), &version.file_version_id).expect("Failed to fetch");
+        assert_eq!(version, fetched);
+        assert_eq!(
+            FileVersion::from_object_key(pool.as_ref(), &version.object_key)
+                .expect("Failed to fetch by key"),
+            Some(version.clone())
+        );
+
+        let deleted = fetched.delete(pool.as_ref()).expect("Failed to delete");
+        assert!(FileVersion::from_id(pool.as_ref(), &deleted.file_version_id).is_err());
+    }
+
+    #[test]
+    fn version_numbers_are_sequential_and_listed_newest_first() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let file = make_file(pool.as_ref(), publication.publication_id);
+
+        assert!(!FileVersion::exists_for_file(pool.as_ref(), &file.file_id)
+            .expect("Failed to check versions"));
+        assert_eq!(
+            FileVersion::next_version_number(pool.as_ref(), &publication.publication_id)
+                .expect("Failed to get next version"),
+            1
+        );
+
+        let first =
+            FileVersion::create(pool.as_ref(), &version_for(&file, 1)).expect("Failed to create");
+        let second = FileVersion::create(
+            pool.as_ref(),
+            &NewFileVersion {
+                restored_from_file_version_id: Some(first.file_version_id),
+                ..version_for(&file, 2)
+            },
+        )
+        .expect("Failed to create");
+
+        assert!(FileVersion::exists_for_file(pool.as_ref(), &file.file_id)
+            .expect("Failed to check versions"));
+        assert_eq!(
+            FileVersion::next_version_number(pool.as_ref(), &publication.publication_id)
+                .expect("Failed to get next version"),
+            3
+        );
+        assert!(FileVersion::create(pool.as_ref(), &version_for(&file, 2)).is_err());
+
+        let all = FileVersion::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            (),
+            vec![],
+            Some(publication.publication_id),
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to load versions");
+        assert_eq!(all, vec![second, first]);
+    }
+
+    #[test]
+    fn publication_cleanup_includes_version_objects() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let file = make_file(pool.as_ref(), publication.publication_id);
+        let version =
+            FileVersion::create(pool.as_ref(), &version_for(&file, 1)).expect("Failed to create");
+
+        for candidates in [
+            File::cleanup_candidates_for_publication(pool.as_ref(), &publication.publication_id),
+            File::cleanup_candidates_for_work(pool.as_ref(), &work.work_id),
+        ] {
+            let keys: Vec<String> = candidates
+                .expect("Failed to load cleanup candidates")
+                .into_iter()
+                .map(|candidate| candidate.object_key)
+                .collect();
+            assert!(keys.contains(&file.object_key));
+            assert!(keys.contains(&version.object_key));
+        }
+    }
+}
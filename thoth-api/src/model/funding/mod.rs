@@ -45,6 +45,8 @@ pub struct Funding {
     graphql(description = "Set of values required to define a new grant awarded for the publication of a work by an institution"),
     diesel(table_name = funding)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewFunding {
     pub work_id: Uuid,
     pub institution_id: Uuid,
@@ -60,6 +62,8 @@ pub struct NewFunding {
     graphql(description = "Set of values required to update an existing grant awarded for the publication of a work by an institution"),
     diesel(table_name = funding, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchFunding {
     pub funding_id: Uuid,
     pub work_id: Uuid,
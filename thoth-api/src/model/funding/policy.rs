@@ -1,18 +1,31 @@
 use crate::model::funding::{Funding, NewFunding, PatchFunding};
+use crate::model::institution::{funder_registry::validate_funder_doi, Institution};
+use crate::model::Crud;
 use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy};
 use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+/// Reject funding from an institution whose funder DOI the Funder Registry snapshot does not
+/// recognise, since it would be deposited as an invalid funder identifier.
+fn ensure_funder_is_registered(db: &crate::db::PgPool, institution_id: &Uuid) -> ThothResult<()> {
+    match Institution::from_id(db, institution_id)?.institution_doi {
+        Some(doi) => validate_funder_doi(db, &doi),
+        None => Ok(()),
+    }
+}
 
 /// Write policies for `Funding`.
 ///
 /// These policies are responsible for:
 /// - requiring authentication
 /// - requiring publisher membership (tenant boundary)
+/// - validating the funder DOI against the local Funder Registry snapshot
 pub struct FundingPolicy;
 
 impl CreatePolicy<NewFunding> for FundingPolicy {
     fn can_create<C: PolicyContext>(ctx: &C, data: &NewFunding, _params: ()) -> ThothResult<()> {
         ctx.require_publisher_for(data)?;
-        Ok(())
+        ensure_funder_is_registered(ctx.db(), &data.institution_id)
     }
 }
 
@@ -25,6 +38,9 @@ impl UpdatePolicy<Funding, PatchFunding> for FundingPolicy {
     ) -> ThothResult<()> {
         ctx.require_publisher_for(current)?;
         ctx.require_publisher_for(patch)?;
+        if patch.institution_id != current.institution_id {
+            ensure_funder_is_registered(ctx.db(), &patch.institution_id)?;
+        }
 
         Ok(())
     }
@@ -53,6 +53,7 @@ pub struct Imprint {
     pub default_locale: Option<LocaleCode>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    pub reject_invalid_files: bool,
 }
 
 #[cfg_attr(
@@ -61,6 +62,8 @@ pub struct Imprint {
     graphql(description = "Set of values required to define a new brand under which a publisher issues works"),
     diesel(table_name = imprint)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewImprint {
     pub publisher_id: Uuid,
     pub imprint_name: String,
@@ -72,6 +75,8 @@ pub struct NewImprint {
     pub default_currency: Option<CurrencyCode>,
     pub default_place: Option<String>,
     pub default_locale: Option<LocaleCode>,
+    /// Defaults to `false` when omitted
+    pub reject_invalid_files: Option<bool>,
 }
 
 #[cfg_attr(
@@ -80,6 +85,8 @@ pub struct NewImprint {
     graphql(description = "Set of values required to update an existing brand under which a publisher issues works"),
     diesel(table_name = imprint, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchImprint {
     pub imprint_id: Uuid,
     pub publisher_id: Uuid,
@@ -92,6 +99,9 @@ pub struct PatchImprint {
     pub default_currency: Option<CurrencyCode>,
     pub default_place: Option<String>,
     pub default_locale: Option<LocaleCode>,
+    /// Left unchanged when omitted
+    #[cfg_attr(feature = "backend", diesel(treat_none_as_null = false))]
+    pub reject_invalid_files: Option<bool>,
 }
 
 #[cfg_attr(feature = "backend", derive(diesel::Queryable))]
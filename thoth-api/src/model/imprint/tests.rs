@@ -134,6 +134,7 @@ mod policy {
             default_currency: None,
             default_place: None,
             default_locale: None,
+            reject_invalid_files: Some(false),
         };
 
         let imprint = Imprint::create(pool.as_ref(), &new_imprint).expect("Failed to create");
@@ -160,6 +161,7 @@ mod policy {
             default_currency: imprint.default_currency,
             default_place: imprint.default_place.clone(),
             default_locale: imprint.default_locale,
+            reject_invalid_files: Some(imprint.reject_invalid_files),
         };
 
         let org_id = publisher
@@ -196,6 +198,7 @@ mod crud {
             default_currency: None,
             default_place: None,
             default_locale: None,
+            reject_invalid_files: Some(false),
         };
 
         Imprint::create(pool, &new_imprint).expect("Failed to create imprint")
@@ -223,6 +226,7 @@ mod crud {
             default_currency: imprint.default_currency,
             default_place: imprint.default_place.clone(),
             default_locale: imprint.default_locale,
+            reject_invalid_files: Some(imprint.reject_invalid_files),
         };
 
         let ctx = test_context(pool.clone(), "test-user");
@@ -522,6 +526,7 @@ mod crud {
                 default_currency: None,
                 default_place: None,
                 default_locale: None,
+                reject_invalid_files: Some(false),
             },
         )
         .expect("Failed to create imprint");
@@ -538,6 +543,7 @@ mod crud {
                 default_currency: None,
                 default_place: None,
                 default_locale: None,
+                reject_invalid_files: Some(false),
             },
         )
         .expect("Failed to create imprint");
@@ -574,6 +580,7 @@ mod crud {
                 default_currency: None,
                 default_place: None,
                 default_locale: None,
+                reject_invalid_files: Some(false),
             },
         )
         .expect("Failed to create imprint");
@@ -590,6 +597,7 @@ mod crud {
                 default_currency: None,
                 default_place: None,
                 default_locale: None,
+                reject_invalid_files: Some(false),
             },
         )
         .expect("Failed to create imprint");
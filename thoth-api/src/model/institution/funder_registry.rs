@@ -0,0 +1,501 @@
+use diesel::{
+    dsl::sql,
+    sql_types::{Bool, Text},
+    BoolExpressionMethods, Connection, ExpressionMethods, OptionalExtension,
+    PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::str::FromStr;
+use thoth_errors::{ThothError, ThothResult};
+
+use super::Institution;
+use crate::db::PgPool;
+use crate::model::funding::Funding;
+use crate::model::{CountryCode, Crud, Doi, IdentifierWithDomain, Ror};
+use crate::schema::funder_registry;
+
+/// DOI prefix under which Crossref registers funder identifiers
+pub const FUNDER_DOI_PREFIX: &str = "10.13039";
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const SKOS_CONCEPT: &str = "http://www.w3.org/2004/02/skos/core#Concept";
+const SKOS_PREF_LABEL: &str = "http://www.w3.org/2004/02/skos/core#prefLabel";
+const SKOS_ALT_LABEL: &str = "http://www.w3.org/2004/02/skos/core#altLabel";
+const SKOS_BROADER: &str = "http://www.w3.org/2004/02/skos/core#broader";
+const SKOS_EXACT_MATCH: &str = "http://www.w3.org/2004/02/skos/core#exactMatch";
+const SKOSXL_PREF_LABEL: &str = "http://www.w3.org/2008/05/skos-xl#prefLabel";
+const SKOSXL_ALT_LABEL: &str = "http://www.w3.org/2008/05/skos-xl#altLabel";
+const SKOSXL_LITERAL_FORM: &str = "http://www.w3.org/2008/05/skos-xl#literalForm";
+const OWL_SAME_AS: &str = "http://www.w3.org/2002/07/owl#sameAs";
+const DCT_IS_REPLACED_BY: &str = "http://purl.org/dc/terms/isReplacedBy";
+const SCHEMA_ADDRESS: &str = "http://schema.org/address";
+const SCHEMA_ADDRESS_COUNTRY: &str = "http://schema.org/addressCountry";
+
+/// Number of rows written per INSERT when loading a snapshot
+const LOAD_BATCH_SIZE: usize = 1000;
+
+/// A funder as listed in the local snapshot of the Crossref Funder Registry
+#[derive(Debug, Clone, PartialEq, Eq, diesel::Queryable, diesel::Insertable)]
+#[diesel(table_name = funder_registry)]
+pub struct RegisteredFunder {
+    pub funder_doi: Doi,
+    pub funder_name: String,
+    pub alt_names: Vec<String>,
+    pub country_code: Option<CountryCode>,
+    pub parent_doi: Option<Doi>,
+    pub ror: Option<Ror>,
+    pub replaced_by: Option<Doi>,
+}
+
+/// A single value in an RDF/JSON graph (https://www.w3.org/TR/rdf-json/)
+#[derive(Debug, Deserialize)]
+struct RdfObject {
+    value: String,
+}
+
+type RdfGraph = HashMap<String, HashMap<String, Vec<RdfObject>>>;
+
+fn objects<'a>(graph: &'a RdfGraph, subject: &str, predicate: &str) -> &'a [RdfObject] {
+    graph
+        .get(subject)
+        .and_then(|properties| properties.get(predicate))
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Collect the labels of a concept, whether given as plain SKOS literals or as SKOS-XL
+/// label resources carrying a literal form.
+fn labels(graph: &RdfGraph, subject: &str, skos: &str, skosxl: &str) -> Vec<String> {
+    let mut labels: Vec<String> = objects(graph, subject, skos)
+        .iter()
+        .map(|label| label.value.trim().to_string())
+        .collect();
+    for label in objects(graph, subject, skosxl) {
+        labels.extend(
+            objects(graph, &label.value, SKOSXL_LITERAL_FORM)
+                .iter()
+                .map(|literal| literal.value.trim().to_string()),
+        );
+    }
+    labels.retain(|label| !label.is_empty());
+    labels
+}
+
+fn first_doi(graph: &RdfGraph, subject: &str, predicate: &str) -> Option<Doi> {
+    objects(graph, subject, predicate)
+        .iter()
+        .find_map(|object| Doi::from_str(&object.value).ok())
+}
+
+/// The registry records countries as lowercase ISO 3166-1 alpha-3 codes, either on the
+/// concept itself or on its postal address.
+fn country(graph: &RdfGraph, subject: &str) -> Option<CountryCode> {
+    objects(graph, subject, SCHEMA_ADDRESS)
+        .iter()
+        .flat_map(|address| objects(graph, &address.value, SCHEMA_ADDRESS_COUNTRY))
+        .chain(objects(graph, subject, SCHEMA_ADDRESS_COUNTRY))
+        .find_map(|code| {
+            serde_json::from_value(serde_json::Value::String(code.value.trim().to_uppercase())).ok()
+        })
+}
+
+fn ror(graph: &RdfGraph, subject: &str) -> Option<Ror> {
+    objects(graph, subject, OWL_SAME_AS)
+        .iter()
+        .chain(objects(graph, subject, SKOS_EXACT_MATCH))
+        .filter(|object| object.value.contains("ror.org/"))
+        .find_map(|object| Ror::from_str(&object.value).ok())
+}
+
+/// Parse a Funder Registry dump serialised as RDF/JSON into registry entries, sorted by DOI.
+///
+/// Every `skos:Concept` whose subject is a funder DOI becomes one entry; other subjects
+/// (the concept scheme, SKOS-XL labels, addresses) are only read through the concepts
+/// that reference them.
+pub fn parse_rdf_json(input: &str) -> ThothResult<Vec<RegisteredFunder>> {
+    let graph: RdfGraph = serde_json::from_str(input)
+        .map_err(|e| ThothError::FunderRegistrySnapshotError(e.to_string()))?;
+
+    let mut funders = vec![];
+    for subject in graph.keys() {
+        let is_concept = objects(&graph, subject, RDF_TYPE)
+            .iter()
+            .any(|rdf_type| rdf_type.value == SKOS_CONCEPT);
+        let funder_doi = match Doi::from_str(subject) {
+            Ok(doi) if is_concept && doi.prefix() == FUNDER_DOI_PREFIX => doi,
+            _ => continue,
+        };
+        let funder_name = labels(&graph, subject, SKOS_PREF_LABEL, SKOSXL_PREF_LABEL)
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                ThothError::FunderRegistrySnapshotError(format!(
+                    "{} has no preferred label",
+                    funder_doi
+                ))
+            })?;
+        let mut alt_names = vec![];
+        for name in labels(&graph, subject, SKOS_ALT_LABEL, SKOSXL_ALT_LABEL) {
+            if name != funder_name && !alt_names.contains(&name) {
+                alt_names.push(name);
+            }
+        }
+        funders.push(RegisteredFunder {
+            parent_doi: first_doi(&graph, subject, SKOS_BROADER),
+            replaced_by: first_doi(&graph, subject, DCT_IS_REPLACED_BY),
+            country_code: country(&graph, subject),
+            ror: ror(&graph, subject),
+            funder_doi,
+            funder_name,
+            alt_names,
+        });
+    }
+
+    if funders.is_empty() {
+        return Err(ThothError::FunderRegistrySnapshotError(
+            "no funder concepts found".to_string(),
+        ));
+    }
+    funders.sort_by_key(|a| a.funder_doi.to_string());
+    Ok(funders)
+}
+
+/// Whether `doi` lies under the Funder Registry prefix
+pub fn is_funder_doi(doi: &Doi) -> bool {
+    doi.prefix() == FUNDER_DOI_PREFIX
+}
+
+impl RegisteredFunder {
+    /// Replace the local snapshot with `funders`, returning the number of entries stored
+    pub fn load_snapshot(db: &PgPool, funders: &[RegisteredFunder]) -> ThothResult<usize> {
+        let mut connection = db.get()?;
+        connection.transaction(|connection| {
+            diesel::delete(funder_registry::table).execute(connection)?;
+            let mut loaded = 0;
+            for batch in funders.chunks(LOAD_BATCH_SIZE) {
+                loaded += diesel::insert_into(funder_registry::table)
+                    .values(batch)
+                    .execute(connection)?;
+            }
+            Ok(loaded)
+        })
+    }
+
+    /// Whether a snapshot has been loaded. Registry validation is skipped until one is.
+    pub fn is_loaded(db: &PgPool) -> ThothResult<bool> {
+        let mut connection = db.get()?;
+        diesel::select(diesel::dsl::exists(
+            funder_registry::table.select(funder_registry::funder_doi),
+        ))
+        .get_result(&mut connection)
+        .map_err(Into::into)
+    }
+
+    pub fn from_doi(db: &PgPool, doi: &Doi) -> ThothResult<Option<RegisteredFunder>> {
+        let mut connection = db.get()?;
+        funder_registry::table
+            .find(doi)
+            .get_result(&mut connection)
+            .optional()
+            .map_err(Into::into)
+    }
+
+    pub fn from_ror(db: &PgPool, ror: &Ror) -> ThothResult<Vec<RegisteredFunder>> {
+        let mut connection = db.get()?;
+        funder_registry::table
+            .filter(funder_registry::ror.eq(ror))
+            .order(funder_registry::funder_doi.asc())
+            .load(&mut connection)
+            .map_err(Into::into)
+    }
+
+    /// Funders whose preferred or alternative name equals `name`, ignoring case
+    pub fn from_name(db: &PgPool, name: &str) -> ThothResult<Vec<RegisteredFunder>> {
+        let mut connection = db.get()?;
+        let name = name.trim().to_lowercase();
+        funder_registry::table
+            .filter(
+                sql::<Text>("lower(funder_name)")
+                    .eq(name.clone())
+                    .or(sql::<Bool>(
+                    "EXISTS (SELECT 1 FROM unnest(alt_names) AS alt_name WHERE lower(alt_name) = ",
+                )
+                .bind::<Text, _>(name)
+                .sql(")")),
+            )
+            .order(funder_registry::funder_doi.asc())
+            .load(&mut connection)
+            .map_err(Into::into)
+    }
+
+    /// Active funders whose name, alternative names or DOI contain `filter`, for autocompletion
+    pub fn search(db: &PgPool, filter: &str, limit: i32) -> ThothResult<Vec<RegisteredFunder>> {
+        let mut connection = db.get()?;
+        let pattern = format!("%{}%", filter.trim());
+        funder_registry::table
+            .filter(funder_registry::replaced_by.is_null())
+            .filter(
+                funder_registry::funder_name
+                    .ilike(pattern.clone())
+                    .or(sql::<Text>("array_to_string(alt_names, ' ')").ilike(pattern.clone()))
+                    .or(funder_registry::funder_doi.ilike(pattern)),
+            )
+            .order(funder_registry::funder_name.asc())
+            .limit(limit.into())
+            .load(&mut connection)
+            .map_err(Into::into)
+    }
+
+    pub fn parent_funder(&self, db: &PgPool) -> ThothResult<Option<RegisteredFunder>> {
+        match &self.parent_doi {
+            Some(parent_doi) => RegisteredFunder::from_doi(db, parent_doi),
+            None => Ok(None),
+        }
+    }
+
+    /// Whether `name` is the preferred or one of the alternative names of the funder
+    pub fn has_name(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.funder_name.to_lowercase() == name
+            || self
+                .alt_names
+                .iter()
+                .any(|alt_name| alt_name.to_lowercase() == name)
+    }
+}
+
+/// Reject funder DOIs that are missing from, or have been retired in, the loaded snapshot.
+///
+/// DOIs outside the Funder Registry prefix, and any DOI while no snapshot is loaded, are
+/// accepted unchecked.
+pub fn validate_funder_doi(db: &PgPool, doi: &Doi) -> ThothResult<()> {
+    if !is_funder_doi(doi) || !RegisteredFunder::is_loaded(db)? {
+        return Ok(());
+    }
+    match RegisteredFunder::from_doi(db, doi)? {
+        None => Err(ThothError::FunderRegistryUnknownDoiError(doi.to_string())),
+        Some(RegisteredFunder {
+            replaced_by: Some(replacement),
+            ..
+        }) => Err(ThothError::FunderRegistryReplacedDoiError(
+            doi.to_string(),
+            replacement.to_string(),
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, juniper::GraphQLEnum)]
+#[graphql(description = "Outcome of comparing a record against the local Funder Registry snapshot")]
+pub enum FunderRegistryStatus {
+    #[graphql(description = "No Funder Registry snapshot has been loaded")]
+    NotLoaded,
+    #[graphql(description = "The funder DOI is listed in the Funder Registry")]
+    Matched,
+    #[graphql(description = "The funder DOI has been replaced by another in the Funder Registry")]
+    Replaced,
+    #[graphql(description = "The funder DOI is not listed in the Funder Registry")]
+    UnknownDoi,
+    #[graphql(description = "The record does not carry a Funder Registry DOI")]
+    NoFunderDoi,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, juniper::GraphQLObject)]
+#[graphql(
+    description = "A correction to a record proposed from the local Funder Registry snapshot"
+)]
+pub struct FunderSuggestion {
+    #[graphql(description = "Name of the field to which the suggestion applies, e.g. 'ror'")]
+    pub field: String,
+    #[graphql(description = "Value currently held in the field, if any")]
+    pub current_value: Option<String>,
+    #[graphql(description = "Value proposed for the field")]
+    pub suggested_value: String,
+    #[graphql(description = "Reason for the suggestion")]
+    pub reason: String,
+}
+
+impl FunderSuggestion {
+    fn new(
+        field: &str,
+        current_value: Option<String>,
+        suggested_value: String,
+        reason: &str,
+    ) -> Self {
+        FunderSuggestion {
+            field: field.to_string(),
+            current_value,
+            suggested_value,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Result of comparing an institution or funding against the local Funder Registry snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunderRegistryCheck {
+    pub status: FunderRegistryStatus,
+    pub funder: Option<RegisteredFunder>,
+    pub suggestions: Vec<FunderSuggestion>,
+}
+
+fn country_value(country_code: &CountryCode) -> String {
+    serde_json::to_value(country_code)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Suggestions for bringing an institution's name, ROR ID and country in line with `funder`
+fn compare_with_funder(
+    funder: &RegisteredFunder,
+    institution: &Institution,
+) -> Vec<FunderSuggestion> {
+    let mut suggestions = vec![];
+    if !funder.has_name(&institution.institution_name) {
+        suggestions.push(FunderSuggestion::new(
+            "institutionName",
+            Some(institution.institution_name.clone()),
+            funder.funder_name.clone(),
+            "Name differs from the names listed in the Funder Registry",
+        ));
+    }
+    if let Some(funder_ror) = &funder.ror {
+        match &institution.ror {
+            None => suggestions.push(FunderSuggestion::new(
+                "ror",
+                None,
+                funder_ror.with_domain(),
+                "ROR ID cross-linked from the Funder Registry",
+            )),
+            Some(ror) if ror != funder_ror => suggestions.push(FunderSuggestion::new(
+                "ror",
+                Some(ror.with_domain()),
+                funder_ror.with_domain(),
+                "ROR ID differs from the one cross-linked in the Funder Registry",
+            )),
+            Some(_) => {}
+        }
+    }
+    if let Some(funder_country) = &funder.country_code {
+        if institution.country_code.as_ref() != Some(funder_country) {
+            suggestions.push(FunderSuggestion::new(
+                "countryCode",
+                institution.country_code.as_ref().map(country_value),
+                country_value(funder_country),
+                "Country listed in the Funder Registry",
+            ));
+        }
+    }
+    suggestions
+}
+
+/// Find the registry entry an institution without a funder DOI most likely corresponds to:
+/// the funder cross-linked to its ROR ID, or else the only active funder with its name.
+fn candidate_for(db: &PgPool, institution: &Institution) -> ThothResult<Option<RegisteredFunder>> {
+    let mut candidates = match &institution.ror {
+        Some(ror) => RegisteredFunder::from_ror(db, ror)?,
+        None => vec![],
+    };
+    if candidates.is_empty() {
+        candidates = RegisteredFunder::from_name(db, &institution.institution_name)?;
+    }
+    candidates.retain(|funder| funder.replaced_by.is_none());
+    Ok(match candidates.len() {
+        1 => candidates.pop(),
+        _ => None,
+    })
+}
+
+impl FunderRegistryCheck {
+    fn new(status: FunderRegistryStatus) -> Self {
+        FunderRegistryCheck {
+            status,
+            funder: None,
+            suggestions: vec![],
+        }
+    }
+
+    pub fn for_institution(db: &PgPool, institution: &Institution) -> ThothResult<Self> {
+        if !RegisteredFunder::is_loaded(db)? {
+            return Ok(Self::new(FunderRegistryStatus::NotLoaded));
+        }
+        let funder_doi = institution
+            .institution_doi
+            .as_ref()
+            .filter(|doi| is_funder_doi(doi));
+        let Some(doi) = funder_doi else {
+            let mut check = Self::new(FunderRegistryStatus::NoFunderDoi);
+            if let Some(funder) = candidate_for(db, institution)? {
+                check.suggestions.push(FunderSuggestion::new(
+                    "institutionDoi",
+                    institution
+                        .institution_doi
+                        .as_ref()
+                        .map(|doi| doi.with_domain()),
+                    funder.funder_doi.with_domain(),
+                    "Funder Registry DOI of the matching funder",
+                ));
+                check
+                    .suggestions
+                    .extend(compare_with_funder(&funder, institution));
+                check.funder = Some(funder);
+            }
+            return Ok(check);
+        };
+
+        match RegisteredFunder::from_doi(db, doi)? {
+            None => {
+                let mut check = Self::new(FunderRegistryStatus::UnknownDoi);
+                if let Some(funder) = candidate_for(db, institution)? {
+                    check.suggestions.push(FunderSuggestion::new(
+                        "institutionDoi",
+                        Some(doi.with_domain()),
+                        funder.funder_doi.with_domain(),
+                        "Funder Registry DOI of the matching funder",
+                    ));
+                    check.funder = Some(funder);
+                }
+                Ok(check)
+            }
+            Some(funder) => match &funder.replaced_by {
+                Some(replacement) => {
+                    let mut check = Self::new(FunderRegistryStatus::Replaced);
+                    check.suggestions.push(FunderSuggestion::new(
+                        "institutionDoi",
+                        Some(doi.with_domain()),
+                        replacement.with_domain(),
+                        "Funder DOI has been replaced in the Funder Registry",
+                    ));
+                    check.funder = Some(funder);
+                    Ok(check)
+                }
+                None => {
+                    let mut check = Self::new(FunderRegistryStatus::Matched);
+                    check.suggestions = compare_with_funder(&funder, institution);
+                    check.funder = Some(funder);
+                    Ok(check)
+                }
+            },
+        }
+    }
+
+    /// Check the funding institution, and flag grant numbers padded with whitespace
+    pub fn for_funding(db: &PgPool, funding: &Funding) -> ThothResult<Self> {
+        let institution = Institution::from_id(db, &funding.institution_id)?;
+        let mut check = Self::for_institution(db, &institution)?;
+        if let Some(grant_number) = &funding.grant_number {
+            let trimmed = grant_number.trim();
+            if trimmed != grant_number && !trimmed.is_empty() {
+                check.suggestions.push(FunderSuggestion::new(
+                    "grantNumber",
+                    Some(grant_number.clone()),
+                    trimmed.to_string(),
+                    "Grant number has leading or trailing whitespace",
+                ));
+            }
+        }
+        Ok(check)
+    }
+}
@@ -52,6 +52,8 @@ pub struct Institution {
     graphql(description = "Set of values required to define a new organisation with which contributors may be affiliated or by which works may be funded"),
     diesel(table_name = institution)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewInstitution {
     pub institution_name: String,
     pub institution_doi: Option<Doi>,
@@ -65,6 +67,8 @@ pub struct NewInstitution {
     graphql(description = "Set of values required to update an existing organisation with which contributors may be affiliated or by which works may be funded"),
     diesel(table_name = institution, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchInstitution {
     pub institution_id: Uuid,
     pub institution_name: String,
@@ -119,6 +123,8 @@ impl fmt::Display for Institution {
 #[cfg(feature = "backend")]
 pub mod crud;
 #[cfg(feature = "backend")]
+pub mod funder_registry;
+#[cfg(feature = "backend")]
 mod policy;
 #[cfg(feature = "backend")]
 pub(crate) use policy::InstitutionPolicy;
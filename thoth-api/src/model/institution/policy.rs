@@ -1,3 +1,4 @@
+use crate::model::institution::funder_registry::validate_funder_doi;
 use crate::model::institution::{Institution, NewInstitution, PatchInstitution};
 use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy};
 use thoth_errors::ThothResult;
@@ -7,15 +8,19 @@ use thoth_errors::ThothResult;
 /// These policies are responsible for:
 /// - requiring authentication
 /// - requiring publisher membership (tenant boundary)
+/// - rejecting funder DOIs unknown to, or replaced in, the local Funder Registry snapshot
 pub struct InstitutionPolicy;
 
 impl CreatePolicy<NewInstitution> for InstitutionPolicy {
     fn can_create<C: PolicyContext>(
         ctx: &C,
-        _data: &NewInstitution,
+        data: &NewInstitution,
         _params: (),
     ) -> ThothResult<()> {
         ctx.require_authentication()?;
+        if let Some(doi) = &data.institution_doi {
+            validate_funder_doi(ctx.db(), doi)?;
+        }
         Ok(())
     }
 }
@@ -23,11 +28,18 @@ impl CreatePolicy<NewInstitution> for InstitutionPolicy {
 impl UpdatePolicy<Institution, PatchInstitution> for InstitutionPolicy {
     fn can_update<C: PolicyContext>(
         ctx: &C,
-        _current: &Institution,
-        _patch: &PatchInstitution,
+        current: &Institution,
+        patch: &PatchInstitution,
         _params: (),
     ) -> ThothResult<()> {
         ctx.require_authentication()?;
+        // Only a changed DOI is checked, so that edits to other fields are not blocked
+        // by a DOI the registry has since retired
+        if let Some(doi) = &patch.institution_doi {
+            if current.institution_doi.as_ref() != Some(doi) {
+                validate_funder_doi(ctx.db(), doi)?;
+            }
+        }
         Ok(())
     }
 }
@@ -1386,3 +1386,339 @@ mod crud {
         }
     }
 }
+
+#[cfg(feature = "backend")]
+mod funder_registry {
+    use super::*;
+
+    use crate::model::funding::{Funding, NewFunding};
+    use crate::model::institution::funder_registry::{
+        is_funder_doi, parse_rdf_json, validate_funder_doi, FunderRegistryCheck,
+        FunderRegistryStatus, RegisteredFunder,
+    };
+    use crate::model::institution::policy::InstitutionPolicy;
+    use crate::model::tests::db::{
+        create_imprint, create_publisher, create_work, setup_test_db, test_context,
+    };
+    use crate::policy::{CreatePolicy, UpdatePolicy};
+    use std::str::FromStr;
+    use thoth_errors::ThothError;
+
+    const SNAPSHOT: &str = r#"{
+        "http://data.crossref.org/fundingdata/vocabulary": {
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#type": [
+                {"type": "uri", "value": "http://www.w3.org/2004/02/skos/core#ConceptScheme"}
+            ]
+        },
+        "http://dx.doi.org/10.13039/501100000780": {
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#type": [
+                {"type": "uri", "value": "http://www.w3.org/2004/02/skos/core#Concept"}
+            ],
+            "http://www.w3.org/2008/05/skos-xl#prefLabel": [
+                {"type": "uri", "value": "http://data.crossref.org/fundingdata/vocabulary/Label-1"}
+            ],
+            "http://www.w3.org/2008/05/skos-xl#altLabel": [
+                {"type": "uri", "value": "http://data.crossref.org/fundingdata/vocabulary/Label-2"}
+            ],
+            "http://schema.org/address": [{"type": "bnode", "value": "_:address1"}],
+            "http://www.w3.org/2002/07/owl#sameAs": [
+                {"type": "uri", "value": "https://ror.org/00k4n6c32"}
+            ]
+        },
+        "http://data.crossref.org/fundingdata/vocabulary/Label-1": {
+            "http://www.w3.org/2008/05/skos-xl#literalForm": [
+                {"type": "literal", "value": "European Commission", "lang": "en"}
+            ]
+        },
+        "http://data.crossref.org/fundingdata/vocabulary/Label-2": {
+            "http://www.w3.org/2008/05/skos-xl#literalForm": [
+                {"type": "literal", "value": "EC", "lang": "en"}
+            ]
+        },
+        "_:address1": {
+            "http://schema.org/addressCountry": [{"type": "literal", "value": "bel"}]
+        },
+        "http://dx.doi.org/10.13039/100010661": {
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#type": [
+                {"type": "uri", "value": "http://www.w3.org/2004/02/skos/core#Concept"}
+            ],
+            "http://www.w3.org/2004/02/skos/core#prefLabel": [
+                {"type": "literal", "value": "Horizon 2020 Framework Programme"}
+            ],
+            "http://www.w3.org/2004/02/skos/core#altLabel": [
+                {"type": "literal", "value": "H2020"},
+                {"type": "literal", "value": "Horizon 2020"},
+                {"type": "literal", "value": "H2020"}
+            ],
+            "http://www.w3.org/2004/02/skos/core#broader": [
+                {"type": "uri", "value": "http://dx.doi.org/10.13039/501100000780"}
+            ]
+        },
+        "http://dx.doi.org/10.13039/100011111": {
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#type": [
+                {"type": "uri", "value": "http://www.w3.org/2004/02/skos/core#Concept"}
+            ],
+            "http://www.w3.org/2004/02/skos/core#prefLabel": [
+                {"type": "literal", "value": "Seventh Framework Programme"}
+            ],
+            "http://purl.org/dc/terms/isReplacedBy": [
+                {"type": "uri", "value": "http://dx.doi.org/10.13039/100010661"}
+            ]
+        }
+    }"#;
+
+    fn doi(value: &str) -> Doi {
+        Doi::from_str(value).unwrap()
+    }
+
+    fn load_snapshot(pool: &crate::db::PgPool) {
+        let funders = parse_rdf_json(SNAPSHOT).expect("Failed to parse snapshot");
+        RegisteredFunder::load_snapshot(pool, &funders).expect("Failed to load snapshot");
+    }
+
+    fn new_institution(name: &str, institution_doi: Option<Doi>) -> NewInstitution {
+        NewInstitution {
+            institution_name: name.to_string(),
+            institution_doi,
+            ror: None,
+            country_code: None,
+        }
+    }
+
+    #[test]
+    fn parse_rdf_json_reads_skos_and_skosxl_concepts() {
+        let funders = parse_rdf_json(SNAPSHOT).unwrap();
+        assert_eq!(funders.len(), 3);
+
+        let horizon = &funders[0];
+        assert_eq!(horizon.funder_doi, doi("10.13039/100010661"));
+        assert_eq!(horizon.funder_name, "Horizon 2020 Framework Programme");
+        assert_eq!(horizon.alt_names, vec!["H2020", "Horizon 2020"]);
+        assert_eq!(horizon.parent_doi, Some(doi("10.13039/501100000780")));
+        assert_eq!(horizon.replaced_by, None);
+
+        let retired = &funders[1];
+        assert_eq!(retired.funder_doi, doi("10.13039/100011111"));
+        assert_eq!(retired.replaced_by, Some(doi("10.13039/100010661")));
+
+        let commission = &funders[2];
+        assert_eq!(commission.funder_name, "European Commission");
+        assert_eq!(commission.alt_names, vec!["EC"]);
+        assert_eq!(commission.country_code, Some(CountryCode::Bel));
+        assert_eq!(commission.ror, Some(Ror::from_str("00k4n6c32").unwrap()));
+    }
+
+    #[test]
+    fn parse_rdf_json_rejects_invalid_snapshots() {
+        assert!(matches!(
+            parse_rdf_json("not json"),
+            Err(ThothError::FunderRegistrySnapshotError(_))
+        ));
+        assert!(matches!(
+            parse_rdf_json("{}"),
+            Err(ThothError::FunderRegistrySnapshotError(_))
+        ));
+        let unlabelled = r#"{"http://dx.doi.org/10.13039/100000001": {
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#type": [
+                {"type": "uri", "value": "http://www.w3.org/2004/02/skos/core#Concept"}
+            ]
+        }}"#;
+        assert!(matches!(
+            parse_rdf_json(unlabelled),
+            Err(ThothError::FunderRegistrySnapshotError(_))
+        ));
+    }
+
+    #[test]
+    fn is_funder_doi_checks_prefix() {
+        assert!(is_funder_doi(&doi("10.13039/100010661")));
+        assert!(!is_funder_doi(&doi("10.00001/100010661")));
+    }
+
+    #[test]
+    fn load_snapshot_replaces_previous_entries() {
+        let (_guard, pool) = setup_test_db();
+
+        assert!(!RegisteredFunder::is_loaded(pool.as_ref()).unwrap());
+        load_snapshot(pool.as_ref());
+        load_snapshot(pool.as_ref());
+        assert!(RegisteredFunder::is_loaded(pool.as_ref()).unwrap());
+
+        let horizon = RegisteredFunder::from_doi(pool.as_ref(), &doi("10.13039/100010661"))
+            .unwrap()
+            .expect("Missing funder");
+        let parent = horizon.parent_funder(pool.as_ref()).unwrap().unwrap();
+        assert_eq!(parent.funder_name, "European Commission");
+        assert_eq!(
+            RegisteredFunder::search(pool.as_ref(), "", 100)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn lookups_match_names_case_insensitively() {
+        let (_guard, pool) = setup_test_db();
+        load_snapshot(pool.as_ref());
+
+        let by_alt_name = RegisteredFunder::from_name(pool.as_ref(), "horizon 2020").unwrap();
+        assert_eq!(by_alt_name.len(), 1);
+        assert_eq!(by_alt_name[0].funder_doi, doi("10.13039/100010661"));
+
+        let by_name = RegisteredFunder::from_name(pool.as_ref(), "EUROPEAN COMMISSION").unwrap();
+        assert_eq!(by_name.len(), 1);
+
+        let searched = RegisteredFunder::search(pool.as_ref(), "h2020", 10).unwrap();
+        assert_eq!(searched.len(), 1);
+        // replaced funders are excluded from autocompletion
+        assert!(RegisteredFunder::search(pool.as_ref(), "Seventh", 10)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn validate_funder_doi_rejects_unknown_and_replaced_dois() {
+        let (_guard, pool) = setup_test_db();
+
+        // nothing is checked until a snapshot is loaded
+        assert!(validate_funder_doi(pool.as_ref(), &doi("10.13039/999999999")).is_ok());
+
+        load_snapshot(pool.as_ref());
+        assert!(validate_funder_doi(pool.as_ref(), &doi("10.13039/100010661")).is_ok());
+        assert!(validate_funder_doi(pool.as_ref(), &doi("10.00001/999999999")).is_ok());
+        assert_eq!(
+            validate_funder_doi(pool.as_ref(), &doi("10.13039/999999999")),
+            Err(ThothError::FunderRegistryUnknownDoiError(
+                "10.13039/999999999".to_string()
+            ))
+        );
+        assert_eq!(
+            validate_funder_doi(pool.as_ref(), &doi("10.13039/100011111")),
+            Err(ThothError::FunderRegistryReplacedDoiError(
+                "10.13039/100011111".to_string(),
+                "10.13039/100010661".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn policy_rejects_unregistered_funder_dois() {
+        let (_guard, pool) = setup_test_db();
+        load_snapshot(pool.as_ref());
+        let ctx = test_context(pool.clone(), "institution-user");
+
+        let unknown = new_institution("Unknown Funder", Some(doi("10.13039/999999999")));
+        assert!(InstitutionPolicy::can_create(&ctx, &unknown, ()).is_err());
+
+        let registered = new_institution("European Commission", Some(doi("10.13039/501100000780")));
+        assert!(InstitutionPolicy::can_create(&ctx, &registered, ()).is_ok());
+
+        let institution = Institution::create(pool.as_ref(), &registered).unwrap();
+        let patch = PatchInstitution {
+            institution_id: institution.institution_id,
+            institution_name: institution.institution_name.clone(),
+            institution_doi: Some(doi("10.13039/100011111")),
+            ror: None,
+            country_code: None,
+        };
+        assert!(InstitutionPolicy::can_update(&ctx, &institution, &patch, ()).is_err());
+    }
+
+    #[test]
+    fn check_reports_not_loaded_without_snapshot() {
+        let (_guard, pool) = setup_test_db();
+        let institution = make_institution(pool.as_ref(), "European Commission".to_string());
+
+        let check = FunderRegistryCheck::for_institution(pool.as_ref(), &institution).unwrap();
+        assert_eq!(check.status, FunderRegistryStatus::NotLoaded);
+        assert!(check.suggestions.is_empty());
+    }
+
+    #[test]
+    fn check_suggests_doi_ror_and_country_for_named_funder() {
+        let (_guard, pool) = setup_test_db();
+        load_snapshot(pool.as_ref());
+        let institution = make_institution(pool.as_ref(), "european commission".to_string());
+
+        let check = FunderRegistryCheck::for_institution(pool.as_ref(), &institution).unwrap();
+        assert_eq!(check.status, FunderRegistryStatus::NoFunderDoi);
+        assert_eq!(
+            check.funder.map(|funder| funder.funder_doi),
+            Some(doi("10.13039/501100000780"))
+        );
+        let suggested: Vec<(&str, &str)> = check
+            .suggestions
+            .iter()
+            .map(|s| (s.field.as_str(), s.suggested_value.as_str()))
+            .collect();
+        assert_eq!(
+            suggested,
+            vec![
+                ("institutionDoi", "https://doi.org/10.13039/501100000780"),
+                ("ror", "https://ror.org/00k4n6c32"),
+                ("countryCode", "BEL"),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_suggests_replacement_for_retired_doi() {
+        let (_guard, pool) = setup_test_db();
+        let institution = Institution::create(
+            pool.as_ref(),
+            &new_institution(
+                "Seventh Framework Programme",
+                Some(doi("10.13039/100011111")),
+            ),
+        )
+        .unwrap();
+        load_snapshot(pool.as_ref());
+
+        let check = FunderRegistryCheck::for_institution(pool.as_ref(), &institution).unwrap();
+        assert_eq!(check.status, FunderRegistryStatus::Replaced);
+        assert_eq!(check.suggestions.len(), 1);
+        assert_eq!(check.suggestions[0].field, "institutionDoi");
+        assert_eq!(
+            check.suggestions[0].suggested_value,
+            "https://doi.org/10.13039/100010661"
+        );
+    }
+
+    #[test]
+    fn check_on_funding_flags_padded_grant_numbers() {
+        let (_guard, pool) = setup_test_db();
+        load_snapshot(pool.as_ref());
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let institution = Institution::create(
+            pool.as_ref(),
+            &NewInstitution {
+                institution_name: "Horizon 2020 Framework Programme".to_string(),
+                institution_doi: Some(doi("10.13039/100010661")),
+                ror: None,
+                country_code: None,
+            },
+        )
+        .unwrap();
+        let funding = Funding::create(
+            pool.as_ref(),
+            &NewFunding {
+                work_id: work.work_id,
+                institution_id: institution.institution_id,
+                program: None,
+                project_name: None,
+                project_shortname: None,
+                grant_number: Some(" 101004617 ".to_string()),
+            },
+        )
+        .unwrap();
+
+        let check = FunderRegistryCheck::for_funding(pool.as_ref(), &funding).unwrap();
+        assert_eq!(check.status, FunderRegistryStatus::Matched);
+        assert_eq!(check.suggestions.len(), 1);
+        assert_eq!(check.suggestions[0].field, "grantNumber");
+        assert_eq!(check.suggestions[0].suggested_value, "101004617");
+    }
+}
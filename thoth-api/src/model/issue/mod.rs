@@ -41,6 +41,8 @@ pub struct Issue {
     graphql(description = "Set of values required to define a new work published as a number in a periodical"),
     diesel(table_name = issue)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewIssue {
     pub series_id: Uuid,
     pub work_id: Uuid,
@@ -54,6 +56,8 @@ pub struct NewIssue {
     graphql(description = "Set of values required to update an existing work published as a number in a periodical"),
     diesel(table_name = issue, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchIssue {
     pub issue_id: Uuid,
     pub series_id: Uuid,
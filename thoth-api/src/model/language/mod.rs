@@ -75,6 +75,8 @@ pub struct Language {
     graphql(description = "Set of values required to define a new description of a work's language"),
     diesel(table_name = language)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewLanguage {
     pub work_id: Uuid,
     pub language_code: LanguageCode,
@@ -87,6 +89,8 @@ pub struct NewLanguage {
     graphql(description = "Set of values required to update an existing description of a work's language"),
     diesel(table_name = language, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchLanguage {
     pub language_id: Uuid,
     pub work_id: Uuid,
@@ -0,0 +1,208 @@
+use cc_license::License;
+use thoth_errors::{ThothError, ThothResult};
+
+/// A Creative Commons licence (or public domain tool) in Thoth's catalogue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CreativeCommonsLicense {
+    /// Short identifier, e.g. `CC BY 4.0`
+    pub code: &'static str,
+    /// Human-readable name of the licence
+    pub name: &'static str,
+    /// Canonical URL of the licence deed
+    pub url: &'static str,
+}
+
+macro_rules! cc_license {
+    ($code:literal, $name:literal, $path:literal) => {
+        CreativeCommonsLicense {
+            code: $code,
+            name: $name,
+            url: concat!("https://creativecommons.org/", $path),
+        }
+    };
+}
+
+/// Creative Commons licences which may be recorded against works and publications.
+/// Other versions (e.g. ported 3.0 licences) are accepted if recognised by `cc_license`.
+pub const CREATIVE_COMMONS_LICENSES: &[CreativeCommonsLicense] = &[
+    cc_license!(
+        "CC BY 4.0",
+        "Creative Commons Attribution 4.0 International",
+        "licenses/by/4.0/"
+    ),
+    cc_license!(
+        "CC BY-SA 4.0",
+        "Creative Commons Attribution-ShareAlike 4.0 International",
+        "licenses/by-sa/4.0/"
+    ),
+    cc_license!(
+        "CC BY-ND 4.0",
+        "Creative Commons Attribution-NoDerivatives 4.0 International",
+        "licenses/by-nd/4.0/"
+    ),
+    cc_license!(
+        "CC BY-NC 4.0",
+        "Creative Commons Attribution-NonCommercial 4.0 International",
+        "licenses/by-nc/4.0/"
+    ),
+    cc_license!(
+        "CC BY-NC-SA 4.0",
+        "Creative Commons Attribution-NonCommercial-ShareAlike 4.0 International",
+        "licenses/by-nc-sa/4.0/"
+    ),
+    cc_license!(
+        "CC BY-NC-ND 4.0",
+        "Creative Commons Attribution-NonCommercial-NoDerivatives 4.0 International",
+        "licenses/by-nc-nd/4.0/"
+    ),
+    cc_license!(
+        "CC BY 3.0",
+        "Creative Commons Attribution 3.0 Unported",
+        "licenses/by/3.0/"
+    ),
+    cc_license!(
+        "CC BY-SA 3.0",
+        "Creative Commons Attribution-ShareAlike 3.0 Unported",
+        "licenses/by-sa/3.0/"
+    ),
+    cc_license!(
+        "CC BY-ND 3.0",
+        "Creative Commons Attribution-NoDerivs 3.0 Unported",
+        "licenses/by-nd/3.0/"
+    ),
+    cc_license!(
+        "CC BY-NC 3.0",
+        "Creative Commons Attribution-NonCommercial 3.0 Unported",
+        "licenses/by-nc/3.0/"
+    ),
+    cc_license!(
+        "CC BY-NC-SA 3.0",
+        "Creative Commons Attribution-NonCommercial-ShareAlike 3.0 Unported",
+        "licenses/by-nc-sa/3.0/"
+    ),
+    cc_license!(
+        "CC BY-NC-ND 3.0",
+        "Creative Commons Attribution-NonCommercial-NoDerivs 3.0 Unported",
+        "licenses/by-nc-nd/3.0/"
+    ),
+    cc_license!(
+        "CC0 1.0",
+        "CC0 1.0 Universal Public Domain Dedication",
+        "publicdomain/zero/1.0/"
+    ),
+    cc_license!(
+        "PDM 1.0",
+        "Public Domain Mark 1.0",
+        "publicdomain/mark/1.0/"
+    ),
+];
+
+/// Reduce a Creative Commons URL to its canonical deed form, e.g.
+/// `http://creativecommons.org/licenses/by/4.0/legalcode.de` becomes
+/// `https://creativecommons.org/licenses/by/4.0/`. Returns `None` for other URLs.
+fn canonical_cc_url(url: &str) -> Option<String> {
+    let trimmed = url.trim();
+    let without_scheme = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))?;
+    let (host, path) = without_scheme.split_once('/')?;
+    let host = host.to_ascii_lowercase();
+    if host != "creativecommons.org" && host != "www.creativecommons.org" {
+        return None;
+    }
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .take_while(|s| !s.starts_with("legalcode") && !s.starts_with("deed"))
+        .collect();
+    Some(format!(
+        "https://creativecommons.org/{}/",
+        segments.join("/").to_ascii_lowercase()
+    ))
+}
+
+/// Whether a URL points at creativecommons.org
+pub fn is_creative_commons_url(url: &str) -> bool {
+    canonical_cc_url(url).is_some()
+}
+
+/// Look up a licence URL in the Creative Commons catalogue, ignoring scheme,
+/// trailing slashes and deed/legal code suffixes.
+pub fn find_creative_commons_license(url: &str) -> Option<&'static CreativeCommonsLicense> {
+    let canonical = canonical_cc_url(url)?;
+    CREATIVE_COMMONS_LICENSES
+        .iter()
+        .find(|license| license.url == canonical)
+}
+
+/// Check a licence URL. Non-Creative Commons URLs (e.g. a publisher's own licence page)
+/// are accepted as they are; Creative Commons URLs must identify a known licence.
+pub fn validate_license_url(url: &str) -> ThothResult<()> {
+    if !is_creative_commons_url(url)
+        || find_creative_commons_license(url).is_some()
+        || License::from_url(url).is_ok()
+    {
+        Ok(())
+    } else {
+        Err(ThothError::UnrecognisedCreativeCommonsLicense(
+            url.to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalogue_urls_are_canonical() {
+        for license in CREATIVE_COMMONS_LICENSES {
+            assert_eq!(
+                canonical_cc_url(license.url).as_deref(),
+                Some(license.url),
+                "{} is not in canonical form",
+                license.code
+            );
+        }
+    }
+
+    #[test]
+    fn find_creative_commons_license_normalises_urls() {
+        for url in [
+            "https://creativecommons.org/licenses/by/4.0/",
+            "http://creativecommons.org/licenses/by/4.0",
+            "https://www.creativecommons.org/licenses/BY/4.0/legalcode",
+            "https://creativecommons.org/licenses/by/4.0/deed.es",
+        ] {
+            assert_eq!(
+                find_creative_commons_license(url).map(|l| l.code),
+                Some("CC BY 4.0"),
+                "{url}"
+            );
+        }
+        assert_eq!(
+            find_creative_commons_license("https://creativecommons.org/publicdomain/zero/1.0/")
+                .map(|l| l.code),
+            Some("CC0 1.0")
+        );
+        assert!(find_creative_commons_license("https://example.com/licenses/by/4.0/").is_none());
+    }
+
+    #[test]
+    fn validate_license_url_accepts_known_and_non_cc_licenses() {
+        assert!(validate_license_url("https://creativecommons.org/licenses/by-nc-nd/4.0/").is_ok());
+        assert!(validate_license_url("https://www.example.com/all-rights-reserved").is_ok());
+    }
+
+    #[test]
+    fn validate_license_url_rejects_unknown_cc_licenses() {
+        let url = "https://creativecommons.org/licenses/by-xx/9.0/";
+        assert_eq!(
+            validate_license_url(url),
+            Err(ThothError::UnrecognisedCreativeCommonsLicense(
+                url.to_string()
+            ))
+        );
+    }
+}
@@ -189,6 +189,8 @@ pub struct Location {
     graphql(description = "Set of values required to define a new location (such as a web shop or distribution platform) where a publication can be acquired or viewed"),
     diesel(table_name = location)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewLocation {
     pub publication_id: Uuid,
     pub landing_page: Option<String>,
@@ -203,6 +205,8 @@ pub struct NewLocation {
     graphql(description = "Set of values required to update an existing location (such as a web shop or distribution platform) where a publication can be acquired or viewed"),
     diesel(table_name = location, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchLocation {
     pub location_id: Uuid,
     pub publication_id: Uuid,
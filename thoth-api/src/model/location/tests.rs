@@ -281,6 +281,9 @@ mod policy {
                 accessibility_additional_standard: None,
                 accessibility_exception: None,
                 accessibility_report_url: None,
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
             },
         )
         .expect("Failed to create publication");
@@ -773,6 +776,9 @@ mod crud {
                 accessibility_additional_standard: None,
                 accessibility_exception: None,
                 accessibility_report_url: None,
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
             },
         )
         .expect("Failed to create publication");
@@ -89,6 +89,10 @@ pub struct Ror(String);
 pub struct Timestamp(DateTime<Utc>);
 
 impl Timestamp {
+    pub fn now() -> Self {
+        Timestamp(Utc::now())
+    }
+
     pub fn to_rfc3339(&self) -> String {
         self.0.to_rfc3339()
     }
@@ -880,6 +884,7 @@ pub(crate) mod tests;
 pub mod r#abstract;
 pub mod additional_resource;
 pub mod affiliation;
+pub mod audience;
 pub mod award;
 pub mod biography;
 pub mod book_review;
@@ -887,22 +892,30 @@ pub mod contact;
 pub mod contribution;
 pub mod contributor;
 pub mod country;
+pub mod credit_role;
 pub mod endorsement;
 pub mod file;
+pub mod file_audit;
+pub mod file_version;
 pub mod funding;
 pub mod imprint;
 pub mod institution;
 pub mod issue;
 pub mod language;
+pub mod license;
 pub mod locale;
 pub mod location;
+pub mod name_variant;
 pub mod price;
+pub mod product_form_detail;
 pub mod publication;
 pub mod publisher;
 pub mod reference;
+pub mod sales_rights;
 pub mod series;
 pub mod subject;
 pub mod title;
 pub mod work;
+pub mod work_date;
 pub mod work_featured_video;
 pub mod work_relation;
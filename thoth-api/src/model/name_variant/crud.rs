@@ -0,0 +1,184 @@
+use super::{
+    NameVariant, NameVariantField, NameVariantHistory, NameVariantOrderBy, NameVariantType,
+    NewNameVariant, NewNameVariantHistory, PatchNameVariant,
+};
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{name_variant, name_variant_history};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+impl Crud for NameVariant {
+    type NewEntity = NewNameVariant;
+    type PatchEntity = PatchNameVariant;
+    type OrderByEntity = NameVariantOrderBy;
+    type FilterParameter1 = NameVariantType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+    type FilterParameter4 = ();
+
+    fn pk(&self) -> Uuid {
+        self.name_variant_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        parent_id_2: Option<Uuid>,
+        variant_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<Vec<NameVariant>> {
+        use crate::schema::name_variant::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::name_variant.into_boxed();
+
+        query = match order.field {
+            NameVariantField::NameVariantId => {
+                apply_directional_order!(query, order.direction, order, dsl::name_variant_id)
+            }
+            NameVariantField::ContributorId => {
+                apply_directional_order!(query, order.direction, order, dsl::contributor_id)
+            }
+            NameVariantField::ContributionId => {
+                apply_directional_order!(query, order.direction, order, dsl::contribution_id)
+            }
+            NameVariantField::VariantType => {
+                apply_directional_order!(query, order.direction, order, dsl::variant_type)
+            }
+            NameVariantField::ScriptCode => {
+                apply_directional_order!(query, order.direction, order, dsl::script_code)
+            }
+            NameVariantField::LocaleCode => {
+                apply_directional_order!(query, order.direction, order, dsl::locale_code)
+            }
+            NameVariantField::FirstName => {
+                apply_directional_order!(query, order.direction, order, dsl::first_name)
+            }
+            NameVariantField::LastName => {
+                apply_directional_order!(query, order.direction, order, dsl::last_name)
+            }
+            NameVariantField::FullName => {
+                apply_directional_order!(query, order.direction, order, dsl::full_name)
+            }
+            NameVariantField::CreatedAt => {
+                apply_directional_order!(query, order.direction, order, dsl::created_at)
+            }
+            NameVariantField::UpdatedAt => {
+                apply_directional_order!(query, order.direction, order, dsl::updated_at)
+            }
+        };
+        if !publishers.is_empty() {
+            // A variant is visible to a publisher either through one of its contributions or
+            // through a contributor who has contributed to one of its works.
+            let publisher_contributions = crate::schema::contribution::table
+                .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+                .filter(crate::schema::imprint::publisher_id.eq_any(publishers.clone()));
+            query = query.filter(
+                dsl::contribution_id
+                    .eq_any(
+                        publisher_contributions
+                            .clone()
+                            .select(crate::schema::contribution::contribution_id.nullable()),
+                    )
+                    .or(dsl::contributor_id.eq_any(
+                        publisher_contributions
+                            .select(crate::schema::contribution::contributor_id.nullable()),
+                    )),
+            );
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::contributor_id.eq(pid));
+        }
+        if let Some(pid) = parent_id_2 {
+            query = query.filter(dsl::contribution_id.eq(pid));
+        }
+        if !variant_types.is_empty() {
+            query = query.filter(dsl::variant_type.eq_any(variant_types));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<NameVariant>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        publishers: Vec<Uuid>,
+        variant_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<i32> {
+        use crate::schema::name_variant::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::name_variant.into_boxed();
+        if !publishers.is_empty() {
+            let publisher_contributions = crate::schema::contribution::table
+                .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+                .filter(crate::schema::imprint::publisher_id.eq_any(publishers.clone()));
+            query = query.filter(
+                dsl::contribution_id
+                    .eq_any(
+                        publisher_contributions
+                            .clone()
+                            .select(crate::schema::contribution::contribution_id.nullable()),
+                    )
+                    .or(dsl::contributor_id.eq_any(
+                        publisher_contributions
+                            .select(crate::schema::contribution::contributor_id.nullable()),
+                    )),
+            );
+        }
+        if !variant_types.is_empty() {
+            query = query.filter(dsl::variant_type.eq_any(variant_types));
+        }
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    crud_methods!(name_variant::table, name_variant::dsl::name_variant);
+}
+
+// Only variants held against a contribution belong to a single publisher. Variants held
+// against a contributor are governed by the contributor's own policy instead.
+publisher_id_impls!(NameVariant, NewNameVariant, PatchNameVariant, |s, db| {
+    match s.contribution_id {
+        Some(contribution_id) => {
+            crate::model::contribution::Contribution::from_id(db, &contribution_id)?
+                .publisher_id(db)
+        }
+        None => Err(ThothError::NameVariantParentError),
+    }
+});
+
+impl HistoryEntry for NameVariant {
+    type NewHistoryEntity = NewNameVariantHistory;
+
+    fn new_history_entry(&self, user_id: &str) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            name_variant_id: self.name_variant_id,
+            user_id: user_id.to_string(),
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewNameVariantHistory {
+    type MainEntity = NameVariantHistory;
+
+    db_insert!(name_variant_history::table);
+}
@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::graphql::types::inputs::Direction;
+use crate::model::locale::LocaleCode;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::name_variant;
+#[cfg(feature = "backend")]
+use crate::schema::name_variant_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Relationship between a name variant and the name under which a contributor is recorded"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::NameVariantType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum NameVariantType {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "transliteration",
+        graphql(
+            description = "The same name written in another script, e.g. the native-script form of a romanised name"
+        )
+    )]
+    #[default]
+    Transliteration,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "pseudonym",
+        graphql(description = "Name under which the contributor also publishes")
+    )]
+    Pseudonym,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "former_name",
+        graphql(description = "Name previously used by the contributor")
+    )]
+    FormerName,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting name variants list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NameVariantField {
+    NameVariantId,
+    ContributorId,
+    ContributionId,
+    VariantType,
+    ScriptCode,
+    LocaleCode,
+    FirstName,
+    LastName,
+    #[default]
+    FullName,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NameVariant {
+    pub name_variant_id: Uuid,
+    pub contributor_id: Option<Uuid>,
+    pub contribution_id: Option<Uuid>,
+    pub variant_type: NameVariantType,
+    pub script_code: Option<String>,
+    pub locale_code: Option<LocaleCode>,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::Insertable),
+    graphql(description = "Set of values required to define a new variant of a contributor's name, linked either to the contributor or to a single contribution"),
+    diesel(table_name = name_variant)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewNameVariant {
+    pub contributor_id: Option<Uuid>,
+    pub contribution_id: Option<Uuid>,
+    pub variant_type: NameVariantType,
+    pub script_code: Option<String>,
+    pub locale_code: Option<LocaleCode>,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::AsChangeset),
+    graphql(description = "Set of values required to update an existing variant of a contributor's name"),
+    diesel(table_name = name_variant, treat_none_as_null = true)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchNameVariant {
+    pub name_variant_id: Uuid,
+    pub contributor_id: Option<Uuid>,
+    pub contribution_id: Option<Uuid>,
+    pub variant_type: NameVariantType,
+    pub script_code: Option<String>,
+    pub locale_code: Option<LocaleCode>,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+pub struct NameVariantHistory {
+    pub name_variant_history_id: Uuid,
+    pub name_variant_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel::Insertable),
+    diesel(table_name = name_variant_history)
+)]
+pub struct NewNameVariantHistory {
+    pub name_variant_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Field and order to use when sorting name variants list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NameVariantOrderBy {
+    pub field: NameVariantField,
+    pub direction: Direction,
+}
+
+/// Check that `script_code` has the shape of an ISO 15924 code: four letters, the first
+/// in upper case and the rest in lower case (e.g. `Cyrl`, `Hans`).
+pub fn validate_script_code(script_code: &str) -> ThothResult<()> {
+    let mut chars = script_code.chars();
+    let well_formed = script_code.chars().count() == 4
+        && chars.next().is_some_and(|c| c.is_ascii_uppercase())
+        && chars.all(|c| c.is_ascii_lowercase());
+    if well_formed {
+        Ok(())
+    } else {
+        Err(ThothError::NameVariantScriptError(script_code.to_string()))
+    }
+}
+
+pub trait NameVariantProperties {
+    fn contributor_id(&self) -> Option<&Uuid>;
+    fn contribution_id(&self) -> Option<&Uuid>;
+    fn variant_type(&self) -> &NameVariantType;
+    fn script_code(&self) -> Option<&String>;
+
+    /// Check that the variant belongs to exactly one parent, and that its script, if any,
+    /// is a well-formed ISO 15924 code. Transliterations must always state their script.
+    fn validate(&self) -> ThothResult<()> {
+        if self.contributor_id().is_some() == self.contribution_id().is_some() {
+            return Err(ThothError::NameVariantParentError);
+        }
+        match self.script_code() {
+            Some(script_code) => validate_script_code(script_code),
+            None if *self.variant_type() == NameVariantType::Transliteration => {
+                Err(ThothError::NameVariantMissingScriptError)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+macro_rules! name_variant_properties {
+    ($t:ty) => {
+        impl NameVariantProperties for $t {
+            fn contributor_id(&self) -> Option<&Uuid> {
+                self.contributor_id.as_ref()
+            }
+            fn contribution_id(&self) -> Option<&Uuid> {
+                self.contribution_id.as_ref()
+            }
+            fn variant_type(&self) -> &NameVariantType {
+                &self.variant_type
+            }
+            fn script_code(&self) -> Option<&String> {
+                self.script_code.as_ref()
+            }
+        }
+    };
+}
+
+name_variant_properties!(NameVariant);
+name_variant_properties!(NewNameVariant);
+name_variant_properties!(PatchNameVariant);
+
+#[cfg(feature = "backend")]
+pub mod crud;
+#[cfg(feature = "backend")]
+mod policy;
+#[cfg(feature = "backend")]
+pub(crate) use policy::NameVariantPolicy;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,68 @@
+use crate::model::contributor::Contributor;
+use crate::model::name_variant::{
+    NameVariant, NameVariantProperties, NewNameVariant, PatchNameVariant,
+};
+use crate::model::Crud;
+use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy};
+use thoth_errors::ThothResult;
+
+/// Write policies for `NameVariant`.
+///
+/// These policies are responsible for:
+/// - requiring authentication
+/// - requiring publisher membership (tenant boundary) for variants linked to a contribution,
+///   and following the contributor policy for variants linked to a contributor
+/// - checking the parent and script of the variant
+pub struct NameVariantPolicy;
+
+/// Authorise a change to a variant, which depends on the entity it is linked to.
+fn require_parent_access<C: PolicyContext, T>(ctx: &C, value: &T) -> ThothResult<()>
+where
+    T: NameVariantProperties + crate::model::PublisherId,
+{
+    if value.contribution_id().is_some() {
+        ctx.require_publisher_for(value)?;
+    } else {
+        ctx.require_authentication()?;
+    }
+    Ok(())
+}
+
+impl CreatePolicy<NewNameVariant> for NameVariantPolicy {
+    fn can_create<C: PolicyContext>(
+        ctx: &C,
+        data: &NewNameVariant,
+        _params: (),
+    ) -> ThothResult<()> {
+        require_parent_access(ctx, data)?;
+        data.validate()
+    }
+}
+
+impl UpdatePolicy<NameVariant, PatchNameVariant> for NameVariantPolicy {
+    fn can_update<C: PolicyContext>(
+        ctx: &C,
+        current: &NameVariant,
+        patch: &PatchNameVariant,
+        _params: (),
+    ) -> ThothResult<()> {
+        require_parent_access(ctx, current)?;
+        require_parent_access(ctx, patch)?;
+        patch.validate()
+    }
+}
+
+impl DeletePolicy<NameVariant> for NameVariantPolicy {
+    fn can_delete<C: PolicyContext>(ctx: &C, current: &NameVariant) -> ThothResult<()> {
+        match current.contributor_id {
+            Some(contributor_id) => {
+                let contributor = Contributor::from_id(ctx.db(), &contributor_id)?;
+                ctx.require_publishers_for(&contributor)?;
+            }
+            None => {
+                ctx.require_publisher_for(current)?;
+            }
+        }
+        Ok(())
+    }
+}
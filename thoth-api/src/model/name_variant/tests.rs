@@ -0,0 +1,566 @@
+use super::*;
+use uuid::Uuid;
+
+fn new_contributor_variant(contributor_id: Uuid) -> NewNameVariant {
+    NewNameVariant {
+        contributor_id: Some(contributor_id),
+        contribution_id: None,
+        variant_type: NameVariantType::Transliteration,
+        script_code: Some("Cyrl".to_string()),
+        locale_code: Some(LocaleCode::Ru),
+        first_name: Some("Лев".to_string()),
+        last_name: "Толстой".to_string(),
+        full_name: "Лев Толстой".to_string(),
+    }
+}
+
+fn new_contribution_variant(contribution_id: Uuid) -> NewNameVariant {
+    NewNameVariant {
+        contributor_id: None,
+        contribution_id: Some(contribution_id),
+        variant_type: NameVariantType::Pseudonym,
+        script_code: None,
+        locale_code: None,
+        first_name: None,
+        last_name: "Anonymous".to_string(),
+        full_name: "Anonymous".to_string(),
+    }
+}
+
+fn patch_from(name_variant: &NameVariant) -> PatchNameVariant {
+    PatchNameVariant {
+        name_variant_id: name_variant.name_variant_id,
+        contributor_id: name_variant.contributor_id,
+        contribution_id: name_variant.contribution_id,
+        variant_type: name_variant.variant_type,
+        script_code: name_variant.script_code.clone(),
+        locale_code: name_variant.locale_code,
+        first_name: name_variant.first_name.clone(),
+        last_name: name_variant.last_name.clone(),
+        full_name: name_variant.full_name.clone(),
+    }
+}
+
+mod defaults {
+    use super::*;
+
+    #[test]
+    fn namevarianttype_default_is_transliteration() {
+        let variant_type: NameVariantType = Default::default();
+        assert_eq!(variant_type, NameVariantType::Transliteration);
+    }
+
+    #[test]
+    fn namevariantfield_default_is_full_name() {
+        let field: NameVariantField = Default::default();
+        assert_eq!(field, NameVariantField::FullName);
+    }
+}
+
+mod display_and_parse {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn namevarianttype_display_formats_expected_strings() {
+        assert_eq!(
+            format!("{}", NameVariantType::Transliteration),
+            "Transliteration"
+        );
+        assert_eq!(format!("{}", NameVariantType::FormerName), "Former Name");
+    }
+
+    #[test]
+    fn namevarianttype_fromstr_parses_expected_values() {
+        assert_eq!(
+            NameVariantType::from_str("Pseudonym").unwrap(),
+            NameVariantType::Pseudonym
+        );
+        assert!(NameVariantType::from_str("Nickname").is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod conversions {
+    use super::*;
+    use crate::model::tests::db::setup_test_db;
+    use crate::model::tests::{assert_db_enum_roundtrip, assert_graphql_enum_roundtrip};
+
+    #[test]
+    fn namevarianttype_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(NameVariantType::FormerName);
+    }
+
+    #[test]
+    fn namevarianttype_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<NameVariantType, crate::schema::sql_types::NameVariantType>(
+            pool.as_ref(),
+            "'former_name'::name_variant_type",
+            NameVariantType::FormerName,
+        );
+    }
+}
+
+mod helpers {
+    use super::*;
+    use crate::model::{Crud, HistoryEntry};
+
+    #[test]
+    fn pk_returns_id() {
+        let name_variant: NameVariant = Default::default();
+        assert_eq!(name_variant.pk(), name_variant.name_variant_id);
+    }
+
+    #[test]
+    fn history_entry_serializes_model() {
+        let name_variant: NameVariant = Default::default();
+        let user_id = "123456".to_string();
+        let new_name_variant_history = name_variant.new_history_entry(&user_id);
+        assert_eq!(
+            new_name_variant_history.name_variant_id,
+            name_variant.name_variant_id
+        );
+        assert_eq!(new_name_variant_history.user_id, user_id);
+        assert_eq!(
+            new_name_variant_history.data,
+            serde_json::Value::String(serde_json::to_string(&name_variant).unwrap())
+        );
+    }
+}
+
+mod validation {
+    use super::*;
+
+    #[test]
+    fn accepts_well_formed_script_codes() {
+        for script_code in ["Latn", "Cyrl", "Arab", "Hans", "Hant"] {
+            assert!(validate_script_code(script_code).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_script_codes() {
+        for script_code in ["latn", "CYRL", "Cyr", "Cyrll", "Cy1l", ""] {
+            assert_eq!(
+                validate_script_code(script_code),
+                Err(ThothError::NameVariantScriptError(script_code.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn requires_exactly_one_parent() {
+        let mut variant = new_contributor_variant(Uuid::new_v4());
+        assert!(variant.validate().is_ok());
+
+        variant.contribution_id = Some(Uuid::new_v4());
+        assert_eq!(variant.validate(), Err(ThothError::NameVariantParentError));
+
+        variant.contributor_id = None;
+        assert!(variant.validate().is_ok());
+
+        variant.contribution_id = None;
+        assert_eq!(variant.validate(), Err(ThothError::NameVariantParentError));
+    }
+
+    #[test]
+    fn transliteration_requires_script() {
+        let mut variant = new_contributor_variant(Uuid::new_v4());
+        variant.script_code = None;
+        assert_eq!(
+            variant.validate(),
+            Err(ThothError::NameVariantMissingScriptError)
+        );
+
+        variant.variant_type = NameVariantType::Pseudonym;
+        assert!(variant.validate().is_ok());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod policy {
+    use super::*;
+
+    use crate::model::name_variant::policy::NameVariantPolicy;
+    use crate::model::tests::db::{
+        create_contribution, create_contributor, create_imprint, create_publisher, create_work,
+        setup_test_db, test_context_anonymous, test_context_with_user, test_user_with_role,
+    };
+    use crate::model::Crud;
+    use crate::policy::{CreatePolicy, DeletePolicy, Role, UpdatePolicy};
+
+    #[test]
+    fn crud_policy_allows_publisher_user_for_contribution_variant() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("name-variant-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+        let new_name_variant = new_contribution_variant(contribution.contribution_id);
+
+        let name_variant =
+            NameVariant::create(pool.as_ref(), &new_name_variant).expect("Failed to create");
+        let mut patch = patch_from(&name_variant);
+        patch.variant_type = NameVariantType::FormerName;
+
+        assert!(NameVariantPolicy::can_create(&ctx, &new_name_variant, ()).is_ok());
+        assert!(NameVariantPolicy::can_update(&ctx, &name_variant, &patch, ()).is_ok());
+        assert!(NameVariantPolicy::can_delete(&ctx, &name_variant).is_ok());
+    }
+
+    #[test]
+    fn crud_policy_rejects_user_without_publisher_role() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+        let new_name_variant = new_contribution_variant(contribution.contribution_id);
+        let name_variant =
+            NameVariant::create(pool.as_ref(), &new_name_variant).expect("Failed to create");
+        let contributor_variant = NameVariant::create(
+            pool.as_ref(),
+            &new_contributor_variant(contributor.contributor_id),
+        )
+        .expect("Failed to create");
+        let patch = patch_from(&name_variant);
+
+        let user = test_user_with_role("name-variant-user", Role::PublisherUser, "org-other");
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        assert!(NameVariantPolicy::can_create(&ctx, &new_name_variant, ()).is_err());
+        assert!(NameVariantPolicy::can_update(&ctx, &name_variant, &patch, ()).is_err());
+        assert!(NameVariantPolicy::can_delete(&ctx, &name_variant).is_err());
+        assert!(NameVariantPolicy::can_delete(&ctx, &contributor_variant).is_err());
+    }
+
+    #[test]
+    fn crud_policy_follows_contributor_policy_for_contributor_variant() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        create_contribution(pool.as_ref(), &work, &contributor);
+
+        let new_name_variant = new_contributor_variant(contributor.contributor_id);
+        let name_variant =
+            NameVariant::create(pool.as_ref(), &new_name_variant).expect("Failed to create");
+        let patch = patch_from(&name_variant);
+
+        let anonymous = test_context_anonymous(pool.clone());
+        assert!(NameVariantPolicy::can_create(&anonymous, &new_name_variant, ()).is_err());
+
+        let outsider = test_context_with_user(
+            pool.clone(),
+            test_user_with_role("name-variant-user", Role::PublisherUser, "org-other"),
+        );
+        assert!(NameVariantPolicy::can_create(&outsider, &new_name_variant, ()).is_ok());
+        assert!(NameVariantPolicy::can_update(&outsider, &name_variant, &patch, ()).is_ok());
+
+        let member = test_context_with_user(
+            pool.clone(),
+            test_user_with_role("name-variant-user", Role::PublisherUser, &org_id),
+        );
+        assert!(NameVariantPolicy::can_delete(&member, &name_variant).is_ok());
+    }
+
+    #[test]
+    fn crud_policy_rejects_invalid_variant() {
+        let (_guard, pool) = setup_test_db();
+
+        let contributor = create_contributor(pool.as_ref());
+        let ctx = test_context_with_user(
+            pool.clone(),
+            test_user_with_role("name-variant-user", Role::PublisherUser, "org-other"),
+        );
+
+        let mut missing_script = new_contributor_variant(contributor.contributor_id);
+        missing_script.script_code = None;
+        let mut malformed_script = new_contributor_variant(contributor.contributor_id);
+        malformed_script.script_code = Some("cyrillic".to_string());
+
+        assert!(matches!(
+            NameVariantPolicy::can_create(&ctx, &missing_script, ()),
+            Err(ThothError::NameVariantMissingScriptError)
+        ));
+        assert!(matches!(
+            NameVariantPolicy::can_create(&ctx, &malformed_script, ()),
+            Err(ThothError::NameVariantScriptError(_))
+        ));
+    }
+}
+
+#[cfg(feature = "backend")]
+mod crud {
+    use super::*;
+
+    use crate::graphql::types::inputs::Direction;
+    use crate::model::tests::db::{
+        create_contribution, create_contributor, create_imprint, create_publisher, create_work,
+        setup_test_db, test_context,
+    };
+    use crate::model::Crud;
+
+    #[test]
+    fn crud_roundtrip_create_fetch_update_delete() {
+        let (_guard, pool) = setup_test_db();
+
+        let contributor = create_contributor(pool.as_ref());
+        let name_variant = NameVariant::create(
+            pool.as_ref(),
+            &new_contributor_variant(contributor.contributor_id),
+        )
+        .expect("Failed to create name variant");
+        let fetched = NameVariant::from_id(pool.as_ref(), &name_variant.name_variant_id)
+            .expect("Failed to fetch");
+        assert_eq!(name_variant.name_variant_id, fetched.name_variant_id);
+        assert_eq!(fetched.script_code.as_deref(), Some("Cyrl"));
+        assert_eq!(fetched.locale_code, Some(LocaleCode::Ru));
+
+        let mut patch = patch_from(&name_variant);
+        patch.variant_type = NameVariantType::FormerName;
+        patch.script_code = None;
+        patch.full_name = "Lev Tolstoy".to_string();
+
+        let ctx = test_context(pool.clone(), "test-user");
+        let updated = name_variant.update(&ctx, &patch).expect("Failed to update");
+        assert_eq!(updated.variant_type, patch.variant_type);
+        assert_eq!(updated.script_code, None);
+        assert_eq!(updated.full_name, patch.full_name);
+
+        let deleted = updated.delete(pool.as_ref()).expect("Failed to delete");
+        assert!(NameVariant::from_id(pool.as_ref(), &deleted.name_variant_id).is_err());
+    }
+
+    #[test]
+    fn crud_rejects_variant_with_two_parents() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+
+        let mut both = new_contributor_variant(contributor.contributor_id);
+        both.contribution_id = Some(contribution.contribution_id);
+        let mut neither = new_contributor_variant(contributor.contributor_id);
+        neither.contributor_id = None;
+
+        assert!(NameVariant::create(pool.as_ref(), &both).is_err());
+        assert!(NameVariant::create(pool.as_ref(), &neither).is_err());
+    }
+
+    #[test]
+    fn crud_rejects_duplicate_variant() {
+        let (_guard, pool) = setup_test_db();
+
+        let contributor = create_contributor(pool.as_ref());
+        let new_name_variant = new_contributor_variant(contributor.contributor_id);
+        NameVariant::create(pool.as_ref(), &new_name_variant).expect("Failed to create");
+
+        assert!(NameVariant::create(pool.as_ref(), &new_name_variant).is_err());
+    }
+
+    #[test]
+    fn crud_all_filters_by_parent_and_type() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+
+        let contributor_variant = NameVariant::create(
+            pool.as_ref(),
+            &new_contributor_variant(contributor.contributor_id),
+        )
+        .expect("Failed to create");
+        let contribution_variant = NameVariant::create(
+            pool.as_ref(),
+            &new_contribution_variant(contribution.contribution_id),
+        )
+        .expect("Failed to create");
+
+        let load = |contributor_id: Option<Uuid>,
+                    contribution_id: Option<Uuid>,
+                    variant_types: Vec<NameVariantType>| {
+            NameVariant::all(
+                pool.as_ref(),
+                10,
+                0,
+                None,
+                NameVariantOrderBy::default(),
+                vec![],
+                contributor_id,
+                contribution_id,
+                variant_types,
+                vec![],
+                None,
+                None,
+            )
+            .expect("Failed to load name variants")
+            .into_iter()
+            .map(|v| v.name_variant_id)
+            .collect::<Vec<Uuid>>()
+        };
+
+        assert_eq!(
+            load(Some(contributor.contributor_id), None, vec![]),
+            vec![contributor_variant.name_variant_id]
+        );
+        assert_eq!(
+            load(None, Some(contribution.contribution_id), vec![]),
+            vec![contribution_variant.name_variant_id]
+        );
+        assert_eq!(
+            load(None, None, vec![NameVariantType::Pseudonym]),
+            vec![contribution_variant.name_variant_id]
+        );
+    }
+
+    #[test]
+    fn crud_filter_publishers_limits_results() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let contributor = create_contributor(pool.as_ref());
+        let contribution = create_contribution(pool.as_ref(), &work, &contributor);
+        let contributor_variant = NameVariant::create(
+            pool.as_ref(),
+            &new_contributor_variant(contributor.contributor_id),
+        )
+        .expect("Failed to create");
+        let contribution_variant = NameVariant::create(
+            pool.as_ref(),
+            &new_contribution_variant(contribution.contribution_id),
+        )
+        .expect("Failed to create");
+
+        let other_publisher = create_publisher(pool.as_ref());
+        let other_imprint = create_imprint(pool.as_ref(), &other_publisher);
+        let other_work = create_work(pool.as_ref(), &other_imprint);
+        let other_contributor = create_contributor(pool.as_ref());
+        let other_contribution =
+            create_contribution(pool.as_ref(), &other_work, &other_contributor);
+        NameVariant::create(
+            pool.as_ref(),
+            &new_contribution_variant(other_contribution.contribution_id),
+        )
+        .expect("Failed to create");
+
+        let filtered = NameVariant::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            NameVariantOrderBy {
+                field: NameVariantField::VariantType,
+                direction: Direction::Asc,
+            },
+            vec![publisher.publisher_id],
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to filter name variants by publisher");
+        let ids: Vec<Uuid> = filtered.iter().map(|v| v.name_variant_id).collect();
+        assert_eq!(
+            ids,
+            vec![
+                contributor_variant.name_variant_id,
+                contribution_variant.name_variant_id
+            ]
+        );
+
+        let count = NameVariant::count(
+            pool.as_ref(),
+            None,
+            vec![publisher.publisher_id],
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to count name variants by publisher");
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn crud_ordering_by_fields_is_supported() {
+        let (_guard, pool) = setup_test_db();
+
+        let contributor = create_contributor(pool.as_ref());
+        NameVariant::create(
+            pool.as_ref(),
+            &new_contributor_variant(contributor.contributor_id),
+        )
+        .expect("Failed to create");
+        let mut former_name = new_contributor_variant(contributor.contributor_id);
+        former_name.variant_type = NameVariantType::FormerName;
+        former_name.script_code = Some("Latn".to_string());
+        former_name.full_name = "Lev Nikolayevich Tolstoy".to_string();
+        NameVariant::create(pool.as_ref(), &former_name).expect("Failed to create");
+
+        for field in [
+            NameVariantField::NameVariantId,
+            NameVariantField::ContributorId,
+            NameVariantField::ContributionId,
+            NameVariantField::VariantType,
+            NameVariantField::ScriptCode,
+            NameVariantField::LocaleCode,
+            NameVariantField::FirstName,
+            NameVariantField::LastName,
+            NameVariantField::FullName,
+            NameVariantField::CreatedAt,
+            NameVariantField::UpdatedAt,
+        ] {
+            for direction in [Direction::Asc, Direction::Desc] {
+                let results = NameVariant::all(
+                    pool.as_ref(),
+                    10,
+                    0,
+                    None,
+                    NameVariantOrderBy {
+                        field: field.clone(),
+                        direction,
+                    },
+                    vec![],
+                    Some(contributor.contributor_id),
+                    None,
+                    vec![],
+                    vec![],
+                    None,
+                    None,
+                )
+                .expect("Failed to order name variants");
+                assert_eq!(results.len(), 2);
+            }
+        }
+    }
+}
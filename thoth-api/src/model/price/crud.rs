@@ -1,8 +1,12 @@
 use super::{CurrencyCode, NewPrice, NewPriceHistory, PatchPrice, Price, PriceField, PriceHistory};
 use crate::graphql::types::inputs::PriceOrderBy;
+use crate::model::Timestamp;
 use crate::model::{Crud, DbInsert, HistoryEntry};
 use crate::schema::{price, price_history};
-use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use chrono::NaiveDate;
+use diesel::dsl::sql;
+use diesel::sql_types::Timestamptz;
+use diesel::{BoolExpressionMethods, ExpressionMethods, QueryDsl, RunQueryDsl};
 use thoth_errors::ThothResult;
 use uuid::Uuid;
 
@@ -12,7 +16,7 @@ impl Crud for Price {
     type OrderByEntity = PriceOrderBy;
     type FilterParameter1 = CurrencyCode;
     type FilterParameter2 = ();
-    type FilterParameter3 = ();
+    type FilterParameter3 = NaiveDate;
     type FilterParameter4 = ();
 
     fn pk(&self) -> Uuid {
@@ -30,7 +34,7 @@ impl Crud for Price {
         _: Option<Uuid>,
         currency_codes: Vec<Self::FilterParameter1>,
         _: Vec<Self::FilterParameter2>,
-        _: Option<Self::FilterParameter3>,
+        on_date: Option<Self::FilterParameter3>,
         _: Option<Self::FilterParameter4>,
     ) -> ThothResult<Vec<Price>> {
         use crate::schema::price::dsl::*;
@@ -56,6 +60,15 @@ impl Crud for Price {
             PriceField::UnitPrice => {
                 apply_directional_order!(query, order.direction, order, unit_price)
             }
+            PriceField::PriceType => {
+                apply_directional_order!(query, order.direction, order, price_type)
+            }
+            PriceField::EffectiveFrom => {
+                apply_directional_order!(query, order.direction, order, effective_from)
+            }
+            PriceField::EffectiveUntil => {
+                apply_directional_order!(query, order.direction, order, effective_until)
+            }
             PriceField::CreatedAt => {
                 apply_directional_order!(query, order.direction, order, created_at)
             }
@@ -72,6 +85,11 @@ impl Crud for Price {
         if !currency_codes.is_empty() {
             query = query.filter(currency_code.eq_any(currency_codes));
         }
+        if let Some(date) = on_date {
+            query = query
+                .filter(effective_from.is_null().or(effective_from.le(date)))
+                .filter(effective_until.is_null().or(effective_until.ge(date)));
+        }
         query
             .limit(limit.into())
             .offset(offset.into())
@@ -85,7 +103,7 @@ impl Crud for Price {
         _: Vec<Uuid>,
         currency_codes: Vec<Self::FilterParameter1>,
         _: Vec<Self::FilterParameter2>,
-        _: Option<Self::FilterParameter3>,
+        on_date: Option<Self::FilterParameter3>,
         _: Option<Self::FilterParameter4>,
     ) -> ThothResult<i32> {
         use crate::schema::price::dsl::*;
@@ -94,6 +112,11 @@ impl Crud for Price {
         if !currency_codes.is_empty() {
             query = query.filter(currency_code.eq_any(currency_codes));
         }
+        if let Some(date) = on_date {
+            query = query
+                .filter(effective_from.is_null().or(effective_from.le(date)))
+                .filter(effective_until.is_null().or(effective_until.ge(date)));
+        }
         // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
         // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
         // is converting i64 to string and then parsing it as i32. This should work until we reach
@@ -108,6 +131,39 @@ impl Crud for Price {
     crud_methods!(price::table, price::dsl::price);
 }
 
+impl Price {
+    /// Bump `updated_at_with_relations` on every work with a price that has started or stopped
+    /// applying on or before `date` since the work was last updated, so that cached exports pick
+    /// up scheduled price changes, including any that fell on a day the schedule did not run.
+    /// Returns the number of works touched.
+    pub fn touch_scheduled_changes(db: &crate::db::PgPool, date: NaiveDate) -> ThothResult<usize> {
+        use crate::schema::{publication, work};
+        let mut connection = db.get()?;
+        // A change is pending if the work has not been updated since midnight (UTC) of the day on
+        // which it took effect. A price with an `effective_until` date still applies on that
+        // date, so it stops applying on the following day. The subquery is correlated with the
+        // work being updated.
+        let started_at = sql::<Timestamptz>("price.effective_from::timestamp AT TIME ZONE 'UTC'");
+        let stopped_at =
+            sql::<Timestamptz>("(price.effective_until + 1)::timestamp AT TIME ZONE 'UTC'");
+        let pending = price::table
+            .inner_join(publication::table)
+            .filter(
+                price::effective_from
+                    .le(date)
+                    .and(work::updated_at_with_relations.lt(started_at))
+                    .or(price::effective_until
+                        .lt(date)
+                        .and(work::updated_at_with_relations.lt(stopped_at))),
+            )
+            .select(publication::work_id);
+        diesel::update(work::table.filter(work::work_id.eq_any(pending)))
+            .set(work::updated_at_with_relations.eq(Timestamp::now()))
+            .execute(&mut connection)
+            .map_err(Into::into)
+    }
+}
+
 publisher_id_impls!(Price, NewPrice, PatchPrice, |s, db| {
     crate::model::publication::Publication::from_id(db, &s.publication_id)?.publisher_id(db)
 });
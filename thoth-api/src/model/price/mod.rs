@@ -1,8 +1,10 @@
+use chrono::naive::NaiveDate;
 use serde::{Deserialize, Serialize};
 use strum::Display;
 use strum::EnumString;
 use uuid::Uuid;
 
+use crate::model::country::CountryCode;
 use crate::model::Timestamp;
 #[cfg(feature = "backend")]
 use crate::schema::price;
@@ -19,6 +21,9 @@ pub enum PriceField {
     PublicationId,
     CurrencyCode,
     UnitPrice,
+    PriceType,
+    EffectiveFrom,
+    EffectiveUntil,
     CreatedAt,
     UpdatedAt,
 }
@@ -33,6 +38,11 @@ pub struct Price {
     pub unit_price: f64,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    pub price_type: PriceType,
+    pub tax_inclusive: bool,
+    pub countries_included: Option<Vec<CountryCode>>,
+    pub effective_from: Option<NaiveDate>,
+    pub effective_until: Option<NaiveDate>,
 }
 
 #[cfg_attr(
@@ -41,10 +51,19 @@ pub struct Price {
     graphql(description = "Set of values required to define a new amount of money that a publication costs"),
     diesel(table_name = price)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewPrice {
     pub publication_id: Uuid,
     pub currency_code: CurrencyCode,
     pub unit_price: f64,
+    /// Defaults to `RRP` when omitted
+    pub price_type: Option<PriceType>,
+    /// Defaults to `true` when omitted
+    pub tax_inclusive: Option<bool>,
+    pub countries_included: Option<Vec<CountryCode>>,
+    pub effective_from: Option<NaiveDate>,
+    pub effective_until: Option<NaiveDate>,
 }
 
 #[cfg_attr(
@@ -53,11 +72,51 @@ pub struct NewPrice {
     graphql(description = "Set of values required to update an existing amount of money that a publication costs"),
     diesel(table_name = price, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchPrice {
     pub price_id: Uuid,
     pub publication_id: Uuid,
     pub currency_code: CurrencyCode,
     pub unit_price: f64,
+    /// Left unchanged when omitted
+    #[cfg_attr(feature = "backend", diesel(treat_none_as_null = false))]
+    pub price_type: Option<PriceType>,
+    /// Left unchanged when omitted
+    #[cfg_attr(feature = "backend", diesel(treat_none_as_null = false))]
+    pub tax_inclusive: Option<bool>,
+    pub countries_included: Option<Vec<CountryCode>>,
+    pub effective_from: Option<NaiveDate>,
+    pub effective_until: Option<NaiveDate>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Basis on which a price is set"),
+    ExistingTypePath = "crate::schema::sql_types::PriceType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum PriceType {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "rrp",
+        graphql(description = "Recommended retail price, which resellers may vary")
+    )]
+    #[default]
+    Rrp,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "agency",
+        graphql(
+            description = "Retail price fixed by the publisher, with resellers acting as agents"
+        )
+    )]
+    Agency,
 }
 
 #[cfg_attr(
@@ -823,6 +882,14 @@ pub enum CurrencyCode {
     Zwr,
 }
 
+impl Price {
+    /// Whether the price applies on the given date. Prices without effective dates always apply.
+    pub fn is_effective_on(&self, date: NaiveDate) -> bool {
+        self.effective_from.is_none_or(|from| from <= date)
+            && self.effective_until.is_none_or(|until| until >= date)
+    }
+}
+
 #[cfg_attr(feature = "backend", derive(diesel::Queryable))]
 pub struct PriceHistory {
     pub price_history_id: Uuid,
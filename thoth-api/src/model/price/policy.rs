@@ -1,5 +1,7 @@
+use crate::model::country::validate_territory;
 use crate::model::price::{NewPrice, PatchPrice, Price};
 use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy};
+use chrono::NaiveDate;
 use thoth_errors::{ThothError, ThothResult};
 
 /// Write policies for `Price`.
@@ -7,7 +9,7 @@ use thoth_errors::{ThothError, ThothResult};
 /// These policies are responsible for:
 /// - requiring authentication
 /// - requiring publisher membership (tenant boundary)
-/// - enforcing business rules (e.g. non-zero unit price)
+/// - enforcing business rules (e.g. non-zero unit price, valid territory and date range)
 pub struct PricePolicy;
 
 fn validate_unit_price(unit_price: f64) -> ThothResult<()> {
@@ -18,10 +20,24 @@ fn validate_unit_price(unit_price: f64) -> ThothResult<()> {
     Ok(())
 }
 
+fn validate_effective_dates(
+    effective_from: Option<NaiveDate>,
+    effective_until: Option<NaiveDate>,
+) -> ThothResult<()> {
+    if let (Some(from), Some(until)) = (effective_from, effective_until) {
+        if until < from {
+            return Err(ThothError::PriceEffectiveDatesError);
+        }
+    }
+    Ok(())
+}
+
 impl CreatePolicy<NewPrice> for PricePolicy {
     fn can_create<C: PolicyContext>(ctx: &C, data: &NewPrice, _params: ()) -> ThothResult<()> {
         ctx.require_publisher_for(data)?;
-        validate_unit_price(data.unit_price)
+        validate_unit_price(data.unit_price)?;
+        validate_territory(data.countries_included.as_deref(), &[])?;
+        validate_effective_dates(data.effective_from, data.effective_until)
     }
 }
 
@@ -36,7 +52,9 @@ impl UpdatePolicy<Price, PatchPrice> for PricePolicy {
         ctx.require_publisher_for(patch)?;
 
         // Enforce non-zero unit price.
-        validate_unit_price(patch.unit_price)
+        validate_unit_price(patch.unit_price)?;
+        validate_territory(patch.countries_included.as_deref(), &[])?;
+        validate_effective_dates(patch.effective_from, patch.effective_until)
     }
 }
 
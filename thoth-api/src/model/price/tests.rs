@@ -12,11 +12,48 @@ fn make_price(
         publication_id,
         currency_code,
         unit_price,
+        price_type: Some(PriceType::Rrp),
+        tax_inclusive: Some(true),
+        countries_included: None,
+        effective_from: None,
+        effective_until: None,
     };
 
     Price::create(pool, &new_price).expect("Failed to create price")
 }
 
+fn make_scheduled_price(
+    pool: &crate::db::PgPool,
+    publication_id: Uuid,
+    unit_price: f64,
+    effective_from: Option<NaiveDate>,
+    effective_until: Option<NaiveDate>,
+) -> Price {
+    let new_price = NewPrice {
+        publication_id,
+        currency_code: CurrencyCode::Gbp,
+        unit_price,
+        price_type: Some(PriceType::Rrp),
+        tax_inclusive: Some(true),
+        countries_included: None,
+        effective_from,
+        effective_until,
+    };
+
+    Price::create(pool, &new_price).expect("Failed to create scheduled price")
+}
+
+fn backdate_work(pool: &crate::db::PgPool, work_id: Uuid, timestamp: &str) {
+    use crate::schema::work;
+    use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+
+    let mut connection = pool.get().expect("Failed to get DB connection");
+    diesel::update(work::table.filter(work::work_id.eq(work_id)))
+        .set(work::updated_at_with_relations.eq(Timestamp::parse_from_rfc3339(timestamp).unwrap()))
+        .execute(&mut connection)
+        .expect("Failed to backdate work");
+}
+
 mod defaults {
     use super::*;
 
@@ -25,6 +62,12 @@ mod defaults {
         let currencycode: CurrencyCode = Default::default();
         assert_eq!(currencycode, CurrencyCode::Gbp);
     }
+
+    #[test]
+    fn pricetype_default_is_rrp() {
+        let pricetype: PriceType = Default::default();
+        assert_eq!(pricetype, PriceType::Rrp);
+    }
 }
 
 mod display_and_parse {
@@ -655,6 +698,22 @@ mod conversions {
         assert_graphql_enum_roundtrip(CurrencyCode::Gbp);
     }
 
+    #[test]
+    fn pricetype_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(PriceType::Agency);
+    }
+
+    #[test]
+    fn pricetype_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<PriceType, crate::schema::sql_types::PriceType>(
+            pool.as_ref(),
+            "'agency'::price_type",
+            PriceType::Agency,
+        );
+    }
+
     #[test]
     fn currencycode_db_enum_roundtrip() {
         let (_guard, pool) = setup_test_db();
@@ -677,6 +736,23 @@ mod helpers {
         assert_eq!(price.pk(), price.price_id);
     }
 
+    #[test]
+    fn is_effective_on_respects_inclusive_date_range() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        let unscheduled: Price = Default::default();
+        let scheduled = Price {
+            effective_from: Some(day(10)),
+            effective_until: Some(day(20)),
+            ..Default::default()
+        };
+
+        assert!(unscheduled.is_effective_on(day(1)));
+        assert!(!scheduled.is_effective_on(day(9)));
+        assert!(scheduled.is_effective_on(day(10)));
+        assert!(scheduled.is_effective_on(day(20)));
+        assert!(!scheduled.is_effective_on(day(21)));
+    }
+
     #[test]
     fn history_entry_serializes_model() {
         let price: Price = Default::default();
@@ -722,6 +798,11 @@ mod policy {
             publication_id: publication.publication_id,
             currency_code: CurrencyCode::Usd,
             unit_price: 9.99,
+            price_type: Some(PriceType::Rrp),
+            tax_inclusive: Some(true),
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
         };
 
         let price = Price::create(pool.as_ref(), &new_price).expect("Failed to create");
@@ -730,6 +811,11 @@ mod policy {
             publication_id: price.publication_id,
             currency_code: price.currency_code,
             unit_price: 14.5,
+            price_type: Some(PriceType::Rrp),
+            tax_inclusive: Some(true),
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
         };
 
         assert!(PricePolicy::can_create(&ctx, &new_price, ()).is_ok());
@@ -756,6 +842,11 @@ mod policy {
             publication_id: price.publication_id,
             currency_code: price.currency_code,
             unit_price: 14.5,
+            price_type: Some(PriceType::Rrp),
+            tax_inclusive: Some(true),
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
         };
 
         let user = test_user_with_role("price-user", Role::PublisherUser, "org-other");
@@ -765,6 +856,11 @@ mod policy {
             publication_id: publication.publication_id,
             currency_code: CurrencyCode::Usd,
             unit_price: 9.99,
+            price_type: Some(PriceType::Rrp),
+            tax_inclusive: Some(true),
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
         };
 
         assert!(PricePolicy::can_create(&ctx, &new_price, ()).is_err());
@@ -791,10 +887,55 @@ mod policy {
             publication_id: publication.publication_id,
             currency_code: CurrencyCode::Usd,
             unit_price: 0.0,
+            price_type: Some(PriceType::Rrp),
+            tax_inclusive: Some(true),
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
         };
 
         assert!(PricePolicy::can_create(&ctx, &new_price, ()).is_err());
     }
+
+    #[test]
+    fn crud_policy_rejects_invalid_territory_and_dates() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("price-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let new_price = |countries_included: Option<Vec<CountryCode>>,
+                         effective_until: Option<NaiveDate>| NewPrice {
+            publication_id: publication.publication_id,
+            currency_code: CurrencyCode::Eur,
+            unit_price: 9.99,
+            price_type: Some(PriceType::Agency),
+            tax_inclusive: Some(true),
+            countries_included,
+            effective_from: NaiveDate::from_ymd_opt(2026, 1, 1),
+            effective_until,
+        };
+        let valid_price = new_price(
+            Some(vec![CountryCode::Deu, CountryCode::Aut]),
+            NaiveDate::from_ymd_opt(2026, 1, 1),
+        );
+        let empty_territory = new_price(Some(vec![]), None);
+        let duplicate_country = new_price(Some(vec![CountryCode::Deu, CountryCode::Deu]), None);
+        let inverted_dates = new_price(None, NaiveDate::from_ymd_opt(2025, 12, 31));
+
+        assert!(PricePolicy::can_create(&ctx, &valid_price, ()).is_ok());
+        assert!(PricePolicy::can_create(&ctx, &empty_territory, ()).is_err());
+        assert!(PricePolicy::can_create(&ctx, &duplicate_country, ()).is_err());
+        assert!(PricePolicy::can_create(&ctx, &inverted_dates, ()).is_err());
+    }
 }
 
 #[cfg(feature = "backend")]
@@ -806,6 +947,7 @@ mod crud {
         create_imprint, create_publication, create_publisher, create_work, setup_test_db,
         test_context,
     };
+    use crate::model::work::Work;
     use crate::model::Crud;
 
     #[test]
@@ -821,6 +963,11 @@ mod crud {
             publication_id: publication.publication_id,
             currency_code: CurrencyCode::Usd,
             unit_price: 9.99,
+            price_type: Some(PriceType::Rrp),
+            tax_inclusive: Some(true),
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
         };
 
         let price = Price::create(pool.as_ref(), &new_price).expect("Failed to create");
@@ -832,6 +979,11 @@ mod crud {
             publication_id: price.publication_id,
             currency_code: price.currency_code,
             unit_price: 14.5,
+            price_type: Some(PriceType::Rrp),
+            tax_inclusive: Some(true),
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
         };
 
         let ctx = test_context(pool.clone(), "test-user");
@@ -842,6 +994,50 @@ mod crud {
         assert!(Price::from_id(pool.as_ref(), &deleted.price_id).is_err());
     }
 
+    #[test]
+    fn crud_omitted_price_type_and_tax_inclusive_use_defaults_then_current_values() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        let new_price = NewPrice {
+            publication_id: publication.publication_id,
+            currency_code: CurrencyCode::Eur,
+            unit_price: 20.0,
+            price_type: None,
+            tax_inclusive: None,
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
+        };
+        let price = Price::create(pool.as_ref(), &new_price).expect("Failed to create");
+        assert_eq!(price.price_type, PriceType::Rrp);
+        assert!(price.tax_inclusive);
+
+        let mut patch = PatchPrice {
+            price_id: price.price_id,
+            publication_id: price.publication_id,
+            currency_code: price.currency_code,
+            unit_price: price.unit_price,
+            price_type: Some(PriceType::Agency),
+            tax_inclusive: Some(false),
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
+        };
+        let ctx = test_context(pool.clone(), "test-user");
+        let price = price.update(&ctx, &patch).expect("Failed to update");
+
+        patch.price_type = None;
+        patch.tax_inclusive = None;
+        let price = price.update(&ctx, &patch).expect("Failed to update");
+        assert_eq!(price.price_type, PriceType::Agency);
+        assert!(!price.tax_inclusive);
+    }
+
     #[test]
     fn crud_all_respects_limit_and_offset() {
         let (_guard, pool) = setup_test_db();
@@ -1015,6 +1211,198 @@ mod crud {
         assert_eq!(filtered[0].price_id, matches.price_id);
     }
 
+    #[test]
+    fn crud_filter_on_date_limits_results_to_effective_prices() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        let current = make_scheduled_price(
+            pool.as_ref(),
+            publication.publication_id,
+            12.99,
+            None,
+            NaiveDate::from_ymd_opt(2026, 6, 30),
+        );
+        let future = make_scheduled_price(
+            pool.as_ref(),
+            publication.publication_id,
+            14.99,
+            NaiveDate::from_ymd_opt(2026, 7, 1),
+            None,
+        );
+
+        let on_date = |date: NaiveDate| {
+            Price::all(
+                pool.as_ref(),
+                10,
+                0,
+                None,
+                PriceOrderBy {
+                    field: PriceField::PriceId,
+                    direction: Direction::Asc,
+                },
+                vec![],
+                Some(publication.publication_id),
+                None,
+                vec![],
+                vec![],
+                Some(date),
+                None,
+            )
+            .expect("Failed to filter prices by date")
+        };
+
+        let before = on_date(NaiveDate::from_ymd_opt(2026, 6, 30).unwrap());
+        let after = on_date(NaiveDate::from_ymd_opt(2026, 7, 1).unwrap());
+        assert_eq!(before.len(), 1);
+        assert_eq!(before[0].price_id, current.price_id);
+        assert_eq!(after.len(), 1);
+        assert_eq!(after[0].price_id, future.price_id);
+
+        let count = Price::count(
+            pool.as_ref(),
+            None,
+            vec![],
+            vec![],
+            vec![],
+            NaiveDate::from_ymd_opt(2026, 7, 1),
+            None,
+        )
+        .expect("Failed to count prices by date");
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn touch_scheduled_changes_bumps_work_updated_at_with_relations() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let other_work = create_work(pool.as_ref(), &imprint);
+        let other_publication = create_publication(pool.as_ref(), &other_work);
+
+        make_scheduled_price(
+            pool.as_ref(),
+            publication.publication_id,
+            12.99,
+            None,
+            NaiveDate::from_ymd_opt(2026, 6, 30),
+        );
+        make_scheduled_price(
+            pool.as_ref(),
+            other_publication.publication_id,
+            14.99,
+            NaiveDate::from_ymd_opt(2026, 9, 1),
+            None,
+        );
+        backdate_work(pool.as_ref(), work.work_id, "2026-06-01T00:00:00Z");
+        backdate_work(pool.as_ref(), other_work.work_id, "2026-06-01T00:00:00Z");
+        let before = Work::from_id(pool.as_ref(), &work.work_id)
+            .expect("Failed to fetch work")
+            .updated_at_with_relations;
+        let other_before = Work::from_id(pool.as_ref(), &other_work.work_id)
+            .expect("Failed to fetch work")
+            .updated_at_with_relations;
+
+        let untouched = Price::touch_scheduled_changes(
+            pool.as_ref(),
+            NaiveDate::from_ymd_opt(2026, 6, 30).unwrap(),
+        )
+        .expect("Failed to touch scheduled price changes");
+        assert_eq!(untouched, 0);
+
+        let touched = Price::touch_scheduled_changes(
+            pool.as_ref(),
+            NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+        )
+        .expect("Failed to touch scheduled price changes");
+        assert_eq!(touched, 1);
+
+        let after = Work::from_id(pool.as_ref(), &work.work_id)
+            .expect("Failed to fetch work")
+            .updated_at_with_relations;
+        let other_after = Work::from_id(pool.as_ref(), &other_work.work_id)
+            .expect("Failed to fetch work")
+            .updated_at_with_relations;
+        assert!(after > before);
+        assert_eq!(other_after, other_before);
+    }
+
+    #[test]
+    fn touch_scheduled_changes_applies_changes_from_missed_days() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let started = create_work(pool.as_ref(), &imprint);
+        let started_publication = create_publication(pool.as_ref(), &started);
+        let ended = create_work(pool.as_ref(), &imprint);
+        let ended_publication = create_publication(pool.as_ref(), &ended);
+        let updated = create_work(pool.as_ref(), &imprint);
+        let updated_publication = create_publication(pool.as_ref(), &updated);
+
+        make_scheduled_price(
+            pool.as_ref(),
+            started_publication.publication_id,
+            12.99,
+            NaiveDate::from_ymd_opt(2026, 7, 1),
+            None,
+        );
+        make_scheduled_price(
+            pool.as_ref(),
+            ended_publication.publication_id,
+            14.99,
+            None,
+            NaiveDate::from_ymd_opt(2026, 7, 1),
+        );
+        make_scheduled_price(
+            pool.as_ref(),
+            updated_publication.publication_id,
+            16.99,
+            NaiveDate::from_ymd_opt(2026, 7, 1),
+            None,
+        );
+        backdate_work(pool.as_ref(), started.work_id, "2026-06-01T00:00:00Z");
+        backdate_work(pool.as_ref(), ended.work_id, "2026-06-01T00:00:00Z");
+        // Already updated since its price took effect, so there is nothing left to apply
+        backdate_work(pool.as_ref(), updated.work_id, "2026-07-02T09:00:00Z");
+
+        // The schedule did not run between 1 and 4 July
+        let touched = Price::touch_scheduled_changes(
+            pool.as_ref(),
+            NaiveDate::from_ymd_opt(2026, 7, 5).unwrap(),
+        )
+        .expect("Failed to touch scheduled price changes");
+        assert_eq!(touched, 2);
+
+        let fetch = |work_id: &Uuid| {
+            Work::from_id(pool.as_ref(), work_id)
+                .expect("Failed to fetch work")
+                .updated_at_with_relations
+        };
+        let backdated = Timestamp::parse_from_rfc3339("2026-06-01T00:00:00Z").unwrap();
+        assert!(fetch(&started.work_id) > backdated);
+        assert!(fetch(&ended.work_id) > backdated);
+        assert_eq!(
+            fetch(&updated.work_id),
+            Timestamp::parse_from_rfc3339("2026-07-02T09:00:00Z").unwrap()
+        );
+
+        // Changes are only applied once
+        let repeated = Price::touch_scheduled_changes(
+            pool.as_ref(),
+            NaiveDate::from_ymd_opt(2026, 7, 5).unwrap(),
+        )
+        .expect("Failed to touch scheduled price changes");
+        assert_eq!(repeated, 0);
+    }
+
     #[test]
     fn crud_filter_parent_publication_id_limits_results() {
         let (_guard, pool) = setup_test_db();
@@ -1204,6 +1592,9 @@ mod crud {
             || PriceField::PublicationId,
             || PriceField::CurrencyCode,
             || PriceField::UnitPrice,
+            || PriceField::PriceType,
+            || PriceField::EffectiveFrom,
+            || PriceField::EffectiveUntil,
             || PriceField::CreatedAt,
             || PriceField::UpdatedAt,
         ];
@@ -0,0 +1,145 @@
+//! Supported subsets of the ONIX code lists used to describe a product's form, each sorted by
+//! code so that it can be binary searched.
+
+/// ONIX code list 175 (Product form detail): binding, trim, paper and finish of print
+/// products, plus e-publication and audio format details
+pub const PRODUCT_FORM_DETAIL_CODES: &[(&str, &str)] = &[
+    ("A103", "MP3 format"),
+    ("A104", "WAV format"),
+    ("A105", "Real Audio format"),
+    ("A106", "WMA"),
+    ("A107", "AAC"),
+    ("A108", "Ogg/Vorbis"),
+    ("A109", "Audible"),
+    ("A110", "FLAC"),
+    ("A111", "AIFF"),
+    ("A112", "ALAC"),
+    ("B101", "Mass market (rack) paperback"),
+    ("B102", "Trade paperback (US)"),
+    ("B103", "Digest format paperback"),
+    ("B104", "A-format paperback"),
+    ("B105", "B-format paperback"),
+    ("B106", "Trade paperback (UK)"),
+    ("B107", "Tall rack paperback"),
+    ("B108", "A5 size"),
+    ("B109", "JIS B5 size"),
+    ("B110", "JIS B6 size"),
+    ("B111", "A6 size"),
+    ("B112", "Pocket format"),
+    ("B201", "Coloring / join-the-dot book"),
+    ("B202", "Lift-the-flap book"),
+    ("B204", "Miniature book"),
+    ("B206", "Pop-up book"),
+    ("B301", "Loose leaf sheets and binder"),
+    ("B302", "Loose leaf binder only"),
+    ("B303", "Loose leaf sheets only"),
+    ("B304", "Sewn"),
+    ("B305", "Unsewn / adhesive bound"),
+    ("B306", "Library binding"),
+    ("B307", "Reinforced binding"),
+    ("B308", "Half bound"),
+    ("B309", "Quarter bound"),
+    ("B310", "Saddle-sewn"),
+    ("B311", "Comb bound"),
+    ("B312", "Wire-O"),
+    ("B313", "Concealed wire"),
+    ("B401", "Cloth over boards"),
+    ("B402", "Paper over boards"),
+    ("B403", "Leather, real"),
+    ("B404", "Leather, imitation"),
+    ("B405", "Leather, bonded"),
+    ("B406", "Vellum"),
+    ("B409", "Cloth"),
+    ("B410", "Imitation cloth"),
+    ("B411", "Velvet"),
+    ("B412", "Flexible plastic/vinyl cover"),
+    ("B413", "Plastic-covered"),
+    ("B414", "Vinyl-covered"),
+    ("B415", "Laminated cover"),
+    ("B501", "With dust jacket"),
+    ("B502", "With printed dust jacket"),
+    ("B503", "With translucent dust cover"),
+    ("B504", "With flaps"),
+    ("B505", "With thumb index"),
+    ("B506", "With ribbon marker(s)"),
+    ("B507", "With zip fastener"),
+    ("B508", "With button snap fastener"),
+    ("B509", "With leather edge lining"),
+    ("B510", "Rough front"),
+    ("B511", "Foldout"),
+    ("B512", "Wide margin"),
+    ("B513", "With fastening strap"),
+    ("B514", "With perforated pages"),
+    ("B515", "Acid-free paper"),
+    ("B516", "Archival paper"),
+    ("E101", "EPUB"),
+    ("E104", "DOCX"),
+    ("E105", "HTML"),
+    ("E107", "PDF"),
+    ("E108", "PDF/A"),
+    ("E112", "TXT"),
+    ("E113", "XHTML"),
+    ("E116", "Amazon Kindle"),
+    ("E127", "Mobipocket"),
+    ("E200", "Reflowable"),
+    ("E201", "Fixed format"),
+    ("E202", "Readable offline"),
+    ("E203", "Requires network connection"),
+];
+
+/// ONIX code list 79 (Product form feature type), excluding 09 (E-publication accessibility
+/// detail), which is derived from a publication's accessibility fields
+pub const PRODUCT_FORM_FEATURE_TYPES: &[(&str, &str)] = &[
+    ("01", "Colour of cover"),
+    ("02", "Colour of page edge"),
+    ("03", "Text font"),
+];
+
+/// ONIX code list 98 (Product form feature value - binding or page edge colour)
+pub const PRODUCT_FORM_COLOURS: &[(&str, &str)] = &[
+    ("BLK", "Black"),
+    ("BLU", "Blue"),
+    ("BRN", "Brown"),
+    ("BUR", "Burgundy/maroon"),
+    ("CRE", "Cream"),
+    ("FUC", "Fuchsia"),
+    ("GLD", "Gold"),
+    ("GRN", "Green"),
+    ("GRY", "Grey"),
+    ("MUL", "Multicolor"),
+    ("NAV", "Navy/Dark blue"),
+    ("ORG", "Orange"),
+    ("PNK", "Pink"),
+    ("PUR", "Purple"),
+    ("RED", "Red"),
+    ("SKY", "Sky/Pale blue"),
+    ("SLV", "Silver"),
+    ("TAN", "Tan/Light brown"),
+    ("TEA", "Teal/Turquoise green"),
+    ("WHI", "White"),
+    ("YEL", "Yellow"),
+    ("ZZZ", "Other"),
+];
+
+/// ONIX code list 80 (Product packaging type)
+pub const PRODUCT_PACKAGING_TYPES: &[(&str, &str)] = &[
+    ("00", "No outer packaging"),
+    ("01", "Slip-sleeve"),
+    ("02", "Clamshell"),
+    ("09", "In box"),
+    ("10", "Slip-cased"),
+    ("11", "Slip-cased set"),
+    ("13", "Binder"),
+    ("14", "In wallet or folder"),
+    ("21", "Shrink-wrapped"),
+];
+
+/// Look up the label of `code` in one of the code lists above
+pub fn code_label(
+    list: &'static [(&'static str, &'static str)],
+    code: &str,
+) -> Option<&'static str> {
+    list.binary_search_by(|(entry_code, _)| (*entry_code).cmp(code))
+        .ok()
+        .map(|i| list[i].1)
+}
@@ -0,0 +1,146 @@
+use super::{
+    NewProductFormDetail, NewProductFormDetailHistory, PatchProductFormDetail, ProductFormDetail,
+    ProductFormDetailField, ProductFormDetailHistory, ProductFormDetailOrderBy,
+    ProductFormDetailType,
+};
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{product_form_detail, product_form_detail_history};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for ProductFormDetail {
+    type NewEntity = NewProductFormDetail;
+    type PatchEntity = PatchProductFormDetail;
+    type OrderByEntity = ProductFormDetailOrderBy;
+    type FilterParameter1 = ProductFormDetailType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+    type FilterParameter4 = ();
+
+    fn pk(&self) -> Uuid {
+        self.product_form_detail_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        detail_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<Vec<ProductFormDetail>> {
+        use crate::schema::product_form_detail::dsl::*;
+        let mut connection = db.get()?;
+        let mut query =
+            product_form_detail
+                .inner_join(crate::schema::publication::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .select(crate::schema::product_form_detail::all_columns)
+                .into_boxed();
+
+        query = match order.field {
+            ProductFormDetailField::ProductFormDetailId => {
+                apply_directional_order!(query, order.direction, order, product_form_detail_id)
+            }
+            ProductFormDetailField::PublicationId => {
+                apply_directional_order!(query, order.direction, order, publication_id)
+            }
+            ProductFormDetailField::DetailType => {
+                apply_directional_order!(query, order.direction, order, detail_type)
+            }
+            ProductFormDetailField::DetailCode => {
+                apply_directional_order!(query, order.direction, order, detail_code)
+            }
+            ProductFormDetailField::CreatedAt => {
+                apply_directional_order!(query, order.direction, order, created_at)
+            }
+            ProductFormDetailField::UpdatedAt => {
+                apply_directional_order!(query, order.direction, order, updated_at)
+            }
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(publication_id.eq(pid));
+        }
+        if !detail_types.is_empty() {
+            query = query.filter(detail_type.eq_any(detail_types));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<ProductFormDetail>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        publishers: Vec<Uuid>,
+        detail_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<i32> {
+        use crate::schema::product_form_detail::dsl::*;
+        let mut connection = db.get()?;
+        let mut query =
+            product_form_detail
+                .inner_join(crate::schema::publication::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .into_boxed();
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if !detail_types.is_empty() {
+            query = query.filter(detail_type.eq_any(detail_types));
+        }
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    crud_methods!(
+        product_form_detail::table,
+        product_form_detail::dsl::product_form_detail
+    );
+}
+
+publisher_id_impls!(
+    ProductFormDetail,
+    NewProductFormDetail,
+    PatchProductFormDetail,
+    |s, db| {
+        crate::model::publication::Publication::from_id(db, &s.publication_id)?.publisher_id(db)
+    }
+);
+
+impl HistoryEntry for ProductFormDetail {
+    type NewHistoryEntity = NewProductFormDetailHistory;
+
+    fn new_history_entry(&self, user_id: &str) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            product_form_detail_id: self.product_form_detail_id,
+            user_id: user_id.to_string(),
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewProductFormDetailHistory {
+    type MainEntity = ProductFormDetailHistory;
+
+    db_insert!(product_form_detail_history::table);
+}
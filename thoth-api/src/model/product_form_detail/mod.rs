@@ -0,0 +1,297 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::graphql::types::inputs::Direction;
+use crate::model::publication::PublicationType;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::product_form_detail;
+#[cfg(feature = "backend")]
+use crate::schema::product_form_detail_history;
+
+pub mod codes;
+
+use codes::{
+    code_label, PRODUCT_FORM_COLOURS, PRODUCT_FORM_DETAIL_CODES, PRODUCT_FORM_FEATURE_TYPES,
+    PRODUCT_PACKAGING_TYPES,
+};
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Aspect of a publication's physical or digital form being described"),
+    ExistingTypePath = "crate::schema::sql_types::ProductFormDetailType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum ProductFormDetailType {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "form_detail",
+        graphql(
+            description = "Binding, trim, paper or format detail (ONIX ProductFormDetail, code list 175)"
+        )
+    )]
+    #[default]
+    FormDetail,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "form_feature",
+        graphql(
+            description = "Feature such as cover or page edge colour (ONIX ProductFormFeature, code list 79)"
+        )
+    )]
+    FormFeature,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "packaging",
+        graphql(
+            description = "Outer packaging of a print publication (ONIX ProductPackaging, code list 80)"
+        )
+    )]
+    Packaging,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting product form details list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProductFormDetailField {
+    ProductFormDetailId,
+    PublicationId,
+    #[default]
+    DetailType,
+    DetailCode,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductFormDetail {
+    pub product_form_detail_id: Uuid,
+    pub publication_id: Uuid,
+    pub detail_type: ProductFormDetailType,
+    pub detail_code: String,
+    pub feature_value: Option<String>,
+    pub feature_description: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::Insertable),
+    graphql(description = "Set of values required to define a new product form detail of a publication"),
+    diesel(table_name = product_form_detail)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewProductFormDetail {
+    pub publication_id: Uuid,
+    pub detail_type: ProductFormDetailType,
+    pub detail_code: String,
+    pub feature_value: Option<String>,
+    pub feature_description: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::AsChangeset),
+    graphql(description = "Set of values required to update an existing product form detail"),
+    diesel(table_name = product_form_detail, treat_none_as_null = true)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchProductFormDetail {
+    pub product_form_detail_id: Uuid,
+    pub publication_id: Uuid,
+    pub detail_type: ProductFormDetailType,
+    pub detail_code: String,
+    pub feature_value: Option<String>,
+    pub feature_description: Option<String>,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+pub struct ProductFormDetailHistory {
+    pub product_form_detail_history_id: Uuid,
+    pub product_form_detail_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel::Insertable),
+    diesel(table_name = product_form_detail_history)
+)]
+pub struct NewProductFormDetailHistory {
+    pub product_form_detail_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Field and order to use when sorting product form details list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProductFormDetailOrderBy {
+    pub field: ProductFormDetailField,
+    pub direction: Direction,
+}
+
+impl ProductFormDetailType {
+    /// The ONIX code list from which detail codes of this type are drawn
+    pub fn code_list(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            ProductFormDetailType::FormDetail => PRODUCT_FORM_DETAIL_CODES,
+            ProductFormDetailType::FormFeature => PRODUCT_FORM_FEATURE_TYPES,
+            ProductFormDetailType::Packaging => PRODUCT_PACKAGING_TYPES,
+        }
+    }
+}
+
+/// First letter of the code list 175 codes which apply to a publication type:
+/// B (book) for print, A (audio) for audio files and E (e-publication) for everything else
+fn form_detail_prefix(publication_type: &PublicationType) -> char {
+    match publication_type {
+        PublicationType::Paperback | PublicationType::Hardback => 'B',
+        PublicationType::Mp3 | PublicationType::Wav => 'A',
+        _ => 'E',
+    }
+}
+
+/// Check that a product form detail uses a supported ONIX code which applies to the
+/// publication type, and that feature values are only given for features, where they must
+/// match the feature type: a code list 98 colour for cover and page edge colours, or a font
+/// size in points for text fonts.
+pub fn validate_product_form_detail(
+    publication_type: &PublicationType,
+    detail_type: &ProductFormDetailType,
+    detail_code: &str,
+    feature_value: Option<&str>,
+    feature_description: Option<&str>,
+) -> ThothResult<()> {
+    if code_label(detail_type.code_list(), detail_code).is_none() {
+        return Err(ThothError::ProductFormDetailCodeError(format!(
+            "\"{detail_code}\" is not a supported {detail_type} code"
+        )));
+    }
+    match detail_type {
+        ProductFormDetailType::FormDetail
+            if !detail_code.starts_with(form_detail_prefix(publication_type)) =>
+        {
+            Err(ThothError::ProductFormDetailCodeError(format!(
+                "{detail_code} does not apply to {publication_type} publications"
+            )))
+        }
+        ProductFormDetailType::Packaging if form_detail_prefix(publication_type) != 'B' => {
+            Err(ThothError::ProductFormDetailCodeError(
+                "Packaging only applies to print publications".to_string(),
+            ))
+        }
+        ProductFormDetailType::FormDetail | ProductFormDetailType::Packaging => {
+            if feature_value.is_some() || feature_description.is_some() {
+                return Err(ThothError::ProductFormFeatureError(
+                    "feature values and descriptions are only applicable to product form features"
+                        .to_string(),
+                ));
+            }
+            Ok(())
+        }
+        ProductFormDetailType::FormFeature => match (detail_code, feature_value) {
+            // 01 Colour of cover, 02 Colour of page edge
+            ("01" | "02", Some(value)) if code_label(PRODUCT_FORM_COLOURS, value).is_some() => {
+                Ok(())
+            }
+            ("01" | "02", _) => Err(ThothError::ProductFormFeatureError(format!(
+                "{} requires an ONIX colour code (e.g. BLK, NAV, WHI)",
+                code_label(PRODUCT_FORM_FEATURE_TYPES, detail_code).unwrap_or_default()
+            ))),
+            // 03 Text font: font name as the description and, optionally, size as the value
+            ("03", _) if feature_description.is_none() => Err(ThothError::ProductFormFeatureError(
+                "Text font requires the name of the font as its description".to_string(),
+            )),
+            ("03", Some(value)) if !value.parse::<f64>().is_ok_and(|size| size > 0.0) => {
+                Err(ThothError::ProductFormFeatureError(format!(
+                    "Text font size must be a number of points, not \"{value}\""
+                )))
+            }
+            _ => Ok(()),
+        },
+    }
+}
+
+pub trait ProductFormDetailProperties {
+    fn detail_type(&self) -> &ProductFormDetailType;
+    fn detail_code(&self) -> &str;
+    fn feature_value(&self) -> Option<&str>;
+    fn feature_description(&self) -> Option<&str>;
+
+    fn validate(&self, publication_type: &PublicationType) -> ThothResult<()> {
+        validate_product_form_detail(
+            publication_type,
+            self.detail_type(),
+            self.detail_code(),
+            self.feature_value(),
+            self.feature_description(),
+        )
+    }
+
+    /// Name of the detail code, and of the colour for colour features
+    fn label(&self) -> Option<String> {
+        let label = code_label(self.detail_type().code_list(), self.detail_code())?;
+        match (self.detail_type(), self.detail_code(), self.feature_value()) {
+            (ProductFormDetailType::FormFeature, "01" | "02", Some(value)) => Some(format!(
+                "{label}: {}",
+                code_label(PRODUCT_FORM_COLOURS, value).unwrap_or(value)
+            )),
+            _ => Some(label.to_string()),
+        }
+    }
+}
+
+macro_rules! product_form_detail_properties {
+    ($t:ty) => {
+        impl ProductFormDetailProperties for $t {
+            fn detail_type(&self) -> &ProductFormDetailType {
+                &self.detail_type
+            }
+            fn detail_code(&self) -> &str {
+                &self.detail_code
+            }
+            fn feature_value(&self) -> Option<&str> {
+                self.feature_value.as_deref()
+            }
+            fn feature_description(&self) -> Option<&str> {
+                self.feature_description.as_deref()
+            }
+        }
+    };
+}
+
+product_form_detail_properties!(ProductFormDetail);
+product_form_detail_properties!(NewProductFormDetail);
+product_form_detail_properties!(PatchProductFormDetail);
+
+#[cfg(feature = "backend")]
+pub mod crud;
+#[cfg(feature = "backend")]
+mod policy;
+#[cfg(feature = "backend")]
+pub(crate) use policy::ProductFormDetailPolicy;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+use crate::model::product_form_detail::{
+    NewProductFormDetail, PatchProductFormDetail, ProductFormDetail, ProductFormDetailProperties,
+};
+use crate::model::publication::Publication;
+use crate::model::Crud;
+use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy};
+use thoth_errors::ThothResult;
+
+/// Write policies for `ProductFormDetail`.
+///
+/// These policies are responsible for:
+/// - requiring authentication
+/// - requiring publisher membership (tenant boundary)
+/// - checking ONIX codes against the supported code lists and the publication type
+pub struct ProductFormDetailPolicy;
+
+impl CreatePolicy<NewProductFormDetail> for ProductFormDetailPolicy {
+    fn can_create<C: PolicyContext>(
+        ctx: &C,
+        data: &NewProductFormDetail,
+        _params: (),
+    ) -> ThothResult<()> {
+        ctx.require_publisher_for(data)?;
+        let publication = Publication::from_id(ctx.db(), &data.publication_id)?;
+        data.validate(&publication.publication_type)
+    }
+}
+
+impl UpdatePolicy<ProductFormDetail, PatchProductFormDetail> for ProductFormDetailPolicy {
+    fn can_update<C: PolicyContext>(
+        ctx: &C,
+        current: &ProductFormDetail,
+        patch: &PatchProductFormDetail,
+        _params: (),
+    ) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        ctx.require_publisher_for(patch)?;
+        let publication = Publication::from_id(ctx.db(), &patch.publication_id)?;
+        patch.validate(&publication.publication_type)
+    }
+}
+
+impl DeletePolicy<ProductFormDetail> for ProductFormDetailPolicy {
+    fn can_delete<C: PolicyContext>(ctx: &C, current: &ProductFormDetail) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,662 @@
+use super::*;
+use crate::model::Crud;
+use uuid::Uuid;
+
+fn make_product_form_detail(
+    pool: &crate::db::PgPool,
+    publication_id: Uuid,
+    detail_type: ProductFormDetailType,
+    detail_code: &str,
+    feature_value: Option<&str>,
+) -> ProductFormDetail {
+    let new_product_form_detail = NewProductFormDetail {
+        publication_id,
+        detail_type,
+        detail_code: detail_code.to_string(),
+        feature_value: feature_value.map(ToString::to_string),
+        feature_description: None,
+    };
+
+    ProductFormDetail::create(pool, &new_product_form_detail)
+        .expect("Failed to create product form detail")
+}
+
+mod defaults {
+    use super::*;
+
+    #[test]
+    fn productformdetailtype_default_is_form_detail() {
+        let detail_type: ProductFormDetailType = Default::default();
+        assert_eq!(detail_type, ProductFormDetailType::FormDetail);
+    }
+
+    #[test]
+    fn productformdetailfield_default_is_detail_type() {
+        let field: ProductFormDetailField = Default::default();
+        assert_eq!(field, ProductFormDetailField::DetailType);
+    }
+}
+
+mod display_and_parse {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn productformdetailtype_display_formats_expected_strings() {
+        assert_eq!(
+            format!("{}", ProductFormDetailType::FormDetail),
+            "Form Detail"
+        );
+        assert_eq!(
+            format!("{}", ProductFormDetailType::FormFeature),
+            "Form Feature"
+        );
+        assert_eq!(format!("{}", ProductFormDetailType::Packaging), "Packaging");
+    }
+
+    #[test]
+    fn productformdetailtype_fromstr_parses_expected_values() {
+        assert_eq!(
+            ProductFormDetailType::from_str("Form Feature").unwrap(),
+            ProductFormDetailType::FormFeature
+        );
+        assert!(ProductFormDetailType::from_str("Binding").is_err());
+    }
+}
+
+mod validation {
+    use super::*;
+
+    fn validate(
+        publication_type: PublicationType,
+        detail_type: ProductFormDetailType,
+        detail_code: &str,
+        feature_value: Option<&str>,
+        feature_description: Option<&str>,
+    ) -> ThothResult<()> {
+        validate_product_form_detail(
+            &publication_type,
+            &detail_type,
+            detail_code,
+            feature_value,
+            feature_description,
+        )
+    }
+
+    #[test]
+    fn code_lists_are_sorted() {
+        for list in [
+            codes::PRODUCT_FORM_DETAIL_CODES,
+            codes::PRODUCT_FORM_FEATURE_TYPES,
+            codes::PRODUCT_FORM_COLOURS,
+            codes::PRODUCT_PACKAGING_TYPES,
+        ] {
+            assert!(list.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+    }
+
+    #[test]
+    fn form_detail_must_match_publication_type() {
+        use ProductFormDetailType::FormDetail;
+        use PublicationType::*;
+
+        assert!(validate(Hardback, FormDetail, "B401", None, None).is_ok());
+        assert!(validate(Paperback, FormDetail, "B515", None, None).is_ok());
+        assert!(validate(Epub, FormDetail, "E200", None, None).is_ok());
+        assert!(validate(Mp3, FormDetail, "A109", None, None).is_ok());
+        assert!(validate(Pdf, FormDetail, "B401", None, None).is_err());
+        assert!(validate(Paperback, FormDetail, "E201", None, None).is_err());
+        assert!(validate(Paperback, FormDetail, "B999", None, None).is_err());
+    }
+
+    #[test]
+    fn packaging_is_print_only() {
+        use ProductFormDetailType::Packaging;
+
+        assert!(validate(PublicationType::Hardback, Packaging, "10", None, None).is_ok());
+        assert!(validate(PublicationType::Epub, Packaging, "10", None, None).is_err());
+        assert!(validate(PublicationType::Hardback, Packaging, "99", None, None).is_err());
+    }
+
+    #[test]
+    fn feature_values_only_apply_to_features() {
+        assert!(validate(
+            PublicationType::Hardback,
+            ProductFormDetailType::FormDetail,
+            "B401",
+            Some("BLK"),
+            None
+        )
+        .is_err());
+        assert!(validate(
+            PublicationType::Hardback,
+            ProductFormDetailType::Packaging,
+            "10",
+            None,
+            Some("Blue slipcase")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn colour_features_require_colour_code() {
+        use ProductFormDetailType::FormFeature;
+
+        assert!(validate(
+            PublicationType::Hardback,
+            FormFeature,
+            "01",
+            Some("NAV"),
+            None
+        )
+        .is_ok());
+        assert!(validate(
+            PublicationType::Hardback,
+            FormFeature,
+            "02",
+            Some("GLD"),
+            None
+        )
+        .is_ok());
+        assert!(validate(PublicationType::Hardback, FormFeature, "01", None, None).is_err());
+        assert!(validate(
+            PublicationType::Hardback,
+            FormFeature,
+            "01",
+            Some("Navy"),
+            None
+        )
+        .is_err());
+        assert!(validate(
+            PublicationType::Hardback,
+            FormFeature,
+            "09",
+            Some("00"),
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn text_font_requires_name_and_numeric_size() {
+        use ProductFormDetailType::FormFeature;
+
+        assert!(validate(
+            PublicationType::Paperback,
+            FormFeature,
+            "03",
+            Some("10.5"),
+            Some("Garamond")
+        )
+        .is_ok());
+        assert!(validate(
+            PublicationType::Paperback,
+            FormFeature,
+            "03",
+            None,
+            Some("Garamond")
+        )
+        .is_ok());
+        assert!(validate(
+            PublicationType::Paperback,
+            FormFeature,
+            "03",
+            Some("10"),
+            None
+        )
+        .is_err());
+        assert!(validate(
+            PublicationType::Paperback,
+            FormFeature,
+            "03",
+            Some("large"),
+            Some("Garamond")
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn label_includes_colour_name() {
+        let feature = ProductFormDetail {
+            detail_type: ProductFormDetailType::FormFeature,
+            detail_code: "01".to_string(),
+            feature_value: Some("NAV".to_string()),
+            ..Default::default()
+        };
+        let detail = ProductFormDetail {
+            detail_type: ProductFormDetailType::FormDetail,
+            detail_code: "B304".to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            feature.label(),
+            Some("Colour of cover: Navy/Dark blue".to_string())
+        );
+        assert_eq!(detail.label(), Some("Sewn".to_string()));
+    }
+}
+
+#[cfg(feature = "backend")]
+mod conversions {
+    use super::*;
+    use crate::model::tests::db::setup_test_db;
+    use crate::model::tests::{assert_db_enum_roundtrip, assert_graphql_enum_roundtrip};
+
+    #[test]
+    fn productformdetailtype_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(ProductFormDetailType::Packaging);
+    }
+
+    #[test]
+    fn productformdetailtype_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<
+            ProductFormDetailType,
+            crate::schema::sql_types::ProductFormDetailType,
+        >(
+            pool.as_ref(),
+            "'form_feature'::product_form_detail_type",
+            ProductFormDetailType::FormFeature,
+        );
+    }
+}
+
+mod helpers {
+    use super::*;
+    use crate::model::{Crud, HistoryEntry};
+
+    #[test]
+    fn pk_returns_id() {
+        let product_form_detail: ProductFormDetail = Default::default();
+        assert_eq!(
+            product_form_detail.pk(),
+            product_form_detail.product_form_detail_id
+        );
+    }
+
+    #[test]
+    fn history_entry_serializes_model() {
+        let product_form_detail: ProductFormDetail = Default::default();
+        let user_id = "123456".to_string();
+        let history = product_form_detail.new_history_entry(&user_id);
+        assert_eq!(
+            history.product_form_detail_id,
+            product_form_detail.product_form_detail_id
+        );
+        assert_eq!(history.user_id, user_id);
+        assert_eq!(
+            history.data,
+            serde_json::Value::String(serde_json::to_string(&product_form_detail).unwrap())
+        );
+    }
+}
+
+#[cfg(feature = "backend")]
+mod policy {
+    use super::*;
+
+    use crate::model::product_form_detail::policy::ProductFormDetailPolicy;
+    use crate::model::tests::db::{
+        create_imprint, create_publication, create_publisher, create_work, setup_test_db,
+        test_context_with_user, test_user_with_role,
+    };
+    use crate::model::Crud;
+    use crate::policy::{CreatePolicy, DeletePolicy, Role, UpdatePolicy};
+
+    #[test]
+    fn crud_policy_allows_publisher_user_for_write() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("product-form-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let new_product_form_detail = NewProductFormDetail {
+            publication_id: publication.publication_id,
+            detail_type: ProductFormDetailType::FormDetail,
+            detail_code: "B305".to_string(),
+            feature_value: None,
+            feature_description: None,
+        };
+
+        let product_form_detail =
+            ProductFormDetail::create(pool.as_ref(), &new_product_form_detail)
+                .expect("Failed to create");
+        let patch = PatchProductFormDetail {
+            product_form_detail_id: product_form_detail.product_form_detail_id,
+            publication_id: product_form_detail.publication_id,
+            detail_type: ProductFormDetailType::FormFeature,
+            detail_code: "01".to_string(),
+            feature_value: Some("RED".to_string()),
+            feature_description: None,
+        };
+
+        assert!(ProductFormDetailPolicy::can_create(&ctx, &new_product_form_detail, ()).is_ok());
+        assert!(
+            ProductFormDetailPolicy::can_update(&ctx, &product_form_detail, &patch, ()).is_ok()
+        );
+        assert!(ProductFormDetailPolicy::can_delete(&ctx, &product_form_detail).is_ok());
+    }
+
+    #[test]
+    fn crud_policy_rejects_user_without_publisher_role() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let product_form_detail = make_product_form_detail(
+            pool.as_ref(),
+            publication.publication_id,
+            ProductFormDetailType::FormDetail,
+            "B305",
+            None,
+        );
+
+        let user = test_user_with_role("product-form-user", Role::PublisherUser, "org-other");
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let new_product_form_detail = NewProductFormDetail {
+            publication_id: publication.publication_id,
+            detail_type: ProductFormDetailType::FormDetail,
+            detail_code: "B515".to_string(),
+            feature_value: None,
+            feature_description: None,
+        };
+
+        assert!(ProductFormDetailPolicy::can_create(&ctx, &new_product_form_detail, ()).is_err());
+        assert!(ProductFormDetailPolicy::can_delete(&ctx, &product_form_detail).is_err());
+    }
+
+    #[test]
+    fn crud_policy_rejects_code_for_other_publication_type() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("product-form-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        // Paperback
+        let publication = create_publication(pool.as_ref(), &work);
+        let ebook_detail = NewProductFormDetail {
+            publication_id: publication.publication_id,
+            detail_type: ProductFormDetailType::FormDetail,
+            detail_code: "E200".to_string(),
+            feature_value: None,
+            feature_description: None,
+        };
+
+        assert!(ProductFormDetailPolicy::can_create(&ctx, &ebook_detail, ()).is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod crud {
+    use super::*;
+
+    use crate::graphql::types::inputs::Direction;
+    use crate::model::tests::db::{
+        create_imprint, create_publication, create_publisher, create_work, setup_test_db,
+        test_context,
+    };
+    use crate::model::Crud;
+
+    #[test]
+    fn crud_roundtrip_create_fetch_update_delete() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        let product_form_detail = make_product_form_detail(
+            pool.as_ref(),
+            publication.publication_id,
+            ProductFormDetailType::FormFeature,
+            "01",
+            Some("BLK"),
+        );
+        let fetched =
+            ProductFormDetail::from_id(pool.as_ref(), &product_form_detail.product_form_detail_id)
+                .expect("Failed to fetch");
+        assert_eq!(fetched.feature_value, Some("BLK".to_string()));
+
+        let patch = PatchProductFormDetail {
+            product_form_detail_id: product_form_detail.product_form_detail_id,
+            publication_id: product_form_detail.publication_id,
+            detail_type: ProductFormDetailType::FormFeature,
+            detail_code: "02".to_string(),
+            feature_value: Some("GLD".to_string()),
+            feature_description: Some("Gilt edges".to_string()),
+        };
+
+        let ctx = test_context(pool.clone(), "test-user");
+        let updated = product_form_detail
+            .update(&ctx, &patch)
+            .expect("Failed to update");
+        assert_eq!(updated.detail_code, patch.detail_code);
+        assert_eq!(updated.feature_description, patch.feature_description);
+
+        let deleted = updated.delete(pool.as_ref()).expect("Failed to delete");
+        assert!(
+            ProductFormDetail::from_id(pool.as_ref(), &deleted.product_form_detail_id).is_err()
+        );
+    }
+
+    #[test]
+    fn crud_rejects_feature_value_on_form_detail() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        let new_product_form_detail = NewProductFormDetail {
+            publication_id: publication.publication_id,
+            detail_type: ProductFormDetailType::FormDetail,
+            detail_code: "B401".to_string(),
+            feature_value: Some("BLK".to_string()),
+            feature_description: None,
+        };
+
+        assert!(ProductFormDetail::create(pool.as_ref(), &new_product_form_detail).is_err());
+    }
+
+    #[test]
+    fn crud_rejects_second_packaging() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        make_product_form_detail(
+            pool.as_ref(),
+            publication.publication_id,
+            ProductFormDetailType::Packaging,
+            "10",
+            None,
+        );
+        let second = NewProductFormDetail {
+            publication_id: publication.publication_id,
+            detail_type: ProductFormDetailType::Packaging,
+            detail_code: "21".to_string(),
+            feature_value: None,
+            feature_description: None,
+        };
+
+        assert!(ProductFormDetail::create(pool.as_ref(), &second).is_err());
+    }
+
+    #[test]
+    fn crud_count_filters_by_detail_type() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        make_product_form_detail(
+            pool.as_ref(),
+            publication.publication_id,
+            ProductFormDetailType::FormDetail,
+            "B305",
+            None,
+        );
+        make_product_form_detail(
+            pool.as_ref(),
+            publication.publication_id,
+            ProductFormDetailType::Packaging,
+            "21",
+            None,
+        );
+
+        let total =
+            ProductFormDetail::count(pool.as_ref(), None, vec![], vec![], vec![], None, None)
+                .expect("Failed to count product form details");
+        let filtered = ProductFormDetail::count(
+            pool.as_ref(),
+            None,
+            vec![],
+            vec![ProductFormDetailType::Packaging],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to count product form details by type");
+
+        assert_eq!(total, 2);
+        assert_eq!(filtered, 1);
+    }
+
+    #[test]
+    fn crud_filter_parent_publication_id_limits_results() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let other_work = create_work(pool.as_ref(), &imprint);
+        let other_publication = create_publication(pool.as_ref(), &other_work);
+
+        let matches = make_product_form_detail(
+            pool.as_ref(),
+            publication.publication_id,
+            ProductFormDetailType::FormDetail,
+            "B305",
+            None,
+        );
+        make_product_form_detail(
+            pool.as_ref(),
+            other_publication.publication_id,
+            ProductFormDetailType::FormDetail,
+            "B305",
+            None,
+        );
+
+        let filtered = ProductFormDetail::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            ProductFormDetailOrderBy {
+                field: ProductFormDetailField::ProductFormDetailId,
+                direction: Direction::Asc,
+            },
+            vec![],
+            Some(publication.publication_id),
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to filter product form details by publication");
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(
+            filtered[0].product_form_detail_id,
+            matches.product_form_detail_id
+        );
+    }
+
+    #[test]
+    fn crud_ordering_by_fields_is_supported() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        make_product_form_detail(
+            pool.as_ref(),
+            publication.publication_id,
+            ProductFormDetailType::FormDetail,
+            "B305",
+            None,
+        );
+        make_product_form_detail(
+            pool.as_ref(),
+            publication.publication_id,
+            ProductFormDetailType::FormFeature,
+            "01",
+            Some("NAV"),
+        );
+
+        let fields: Vec<fn() -> ProductFormDetailField> = vec![
+            || ProductFormDetailField::ProductFormDetailId,
+            || ProductFormDetailField::PublicationId,
+            || ProductFormDetailField::DetailType,
+            || ProductFormDetailField::DetailCode,
+            || ProductFormDetailField::CreatedAt,
+            || ProductFormDetailField::UpdatedAt,
+        ];
+
+        for field in fields {
+            for direction in [Direction::Asc, Direction::Desc] {
+                let results = ProductFormDetail::all(
+                    pool.as_ref(),
+                    10,
+                    0,
+                    None,
+                    ProductFormDetailOrderBy {
+                        field: field(),
+                        direction,
+                    },
+                    vec![],
+                    None,
+                    None,
+                    vec![],
+                    vec![],
+                    None,
+                    None,
+                )
+                .expect("Failed to order product form details");
+
+                assert_eq!(results.len(), 2);
+            }
+        }
+    }
+}
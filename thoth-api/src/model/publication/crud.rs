@@ -100,6 +100,15 @@ impl Crud for Publication {
             PublicationField::AccessibilityReportUrl => {
                 apply_directional_order!(query, order.direction, order, accessibility_report_url)
             }
+            PublicationField::License => {
+                apply_directional_order!(query, order.direction, order, license)
+            }
+            PublicationField::AccessStatus => {
+                apply_directional_order!(query, order.direction, order, access_status)
+            }
+            PublicationField::EmbargoEndDate => {
+                apply_directional_order!(query, order.direction, order, embargo_end_date)
+            }
         };
         if !publishers.is_empty() {
             query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
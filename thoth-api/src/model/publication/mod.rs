@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use strum::Display;
@@ -214,6 +215,55 @@ pub enum AccessibilityException {
     FundamentalAlteration,
 }
 
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Whether a publication can be freely accessed"),
+    ExistingTypePath = "crate::schema::sql_types::AccessStatus"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccessStatus {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "open",
+        graphql(description = "Freely available under its license")
+    )]
+    Open,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "embargoed",
+        graphql(
+            description = "Restricted until the embargo end date, then freely available under its license"
+        )
+    )]
+    Embargoed,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "restricted",
+        graphql(description = "Only available to purchasers or subscribers")
+    )]
+    Restricted,
+}
+
+impl AccessStatus {
+    /// Access status in force on `date`, falling back to the work's licence when the
+    /// publication does not record its own status: openly licensed works are open.
+    pub fn resolve(
+        access_status: Option<AccessStatus>,
+        embargo_end_date: Option<NaiveDate>,
+        has_license: bool,
+        date: NaiveDate,
+    ) -> AccessStatus {
+        match (access_status, embargo_end_date) {
+            (Some(AccessStatus::Embargoed), Some(end)) if end <= date => AccessStatus::Open,
+            (Some(status), _) => status,
+            (None, _) if has_license => AccessStatus::Open,
+            (None, _) => AccessStatus::Restricted,
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "backend",
     derive(juniper::GraphQLEnum),
@@ -245,6 +295,9 @@ pub enum PublicationField {
     AccessibilityAdditionalStandard,
     AccessibilityException,
     AccessibilityReportUrl,
+    License,
+    AccessStatus,
+    EmbargoEndDate,
 }
 
 #[cfg_attr(feature = "backend", derive(diesel::Queryable))]
@@ -269,6 +322,9 @@ pub struct Publication {
     pub accessibility_additional_standard: Option<AccessibilityStandard>,
     pub accessibility_exception: Option<AccessibilityException>,
     pub accessibility_report_url: Option<String>,
+    pub license: Option<String>,
+    pub access_status: Option<AccessStatus>,
+    pub embargo_end_date: Option<NaiveDate>,
 }
 
 #[cfg_attr(
@@ -277,6 +333,8 @@ pub struct Publication {
     graphql(description = "Set of values required to define a new manifestation of a written text"),
     diesel(table_name = publication)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewPublication {
     pub publication_type: PublicationType,
     pub work_id: Uuid,
@@ -293,6 +351,9 @@ pub struct NewPublication {
     pub accessibility_additional_standard: Option<AccessibilityStandard>,
     pub accessibility_exception: Option<AccessibilityException>,
     pub accessibility_report_url: Option<String>,
+    pub license: Option<String>,
+    pub access_status: Option<AccessStatus>,
+    pub embargo_end_date: Option<NaiveDate>,
 }
 
 #[cfg_attr(
@@ -301,6 +362,8 @@ pub struct NewPublication {
     graphql(description = "Set of values required to update an existing manifestation of a written text"),
     diesel(table_name = publication, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchPublication {
     pub publication_id: Uuid,
     pub publication_type: PublicationType,
@@ -318,6 +381,9 @@ pub struct PatchPublication {
     pub accessibility_additional_standard: Option<AccessibilityStandard>,
     pub accessibility_exception: Option<AccessibilityException>,
     pub accessibility_report_url: Option<String>,
+    pub license: Option<String>,
+    pub access_status: Option<AccessStatus>,
+    pub embargo_end_date: Option<NaiveDate>,
 }
 
 #[cfg_attr(feature = "backend", derive(diesel::Queryable))]
@@ -364,6 +430,9 @@ pub trait PublicationProperties {
     fn isbn(&self) -> &Option<Isbn>;
     fn isbn_mut(&mut self) -> &mut Option<Isbn>;
     fn work_id(&self) -> &Uuid;
+    fn license(&self) -> &Option<String>;
+    fn access_status(&self) -> &Option<AccessStatus>;
+    fn embargo_end_date(&self) -> &Option<NaiveDate>;
 
     fn normalise_isbn(&mut self) -> ThothResult<()> {
         let normalised = self
@@ -430,6 +499,16 @@ pub trait PublicationProperties {
         Ok(())
     }
 
+    fn validate_access_constraints(&self) -> ThothResult<()> {
+        let is_embargoed = matches!(self.access_status(), Some(AccessStatus::Embargoed));
+        if is_embargoed != self.embargo_end_date().is_some() {
+            return Err(ThothError::PublicationEmbargoEndDateError);
+        }
+        self.license()
+            .as_deref()
+            .map_or(Ok(()), crate::model::license::validate_license_url)
+    }
+
     #[cfg(feature = "backend")]
     fn is_chapter(&self, db: &crate::db::PgPool) -> ThothResult<bool> {
         use crate::model::work::WorkType;
@@ -456,7 +535,8 @@ pub trait PublicationProperties {
         if self.is_chapter(db)? {
             self.validate_chapter_constraints()?;
         }
-        self.validate_dimensions_constraints()
+        self.validate_dimensions_constraints()?;
+        self.validate_access_constraints()
     }
 }
 
@@ -499,6 +579,15 @@ macro_rules! publication_properties {
             fn work_id(&self) -> &Uuid {
                 &self.work_id
             }
+            fn license(&self) -> &Option<String> {
+                &self.license
+            }
+            fn access_status(&self) -> &Option<AccessStatus> {
+                &self.access_status
+            }
+            fn embargo_end_date(&self) -> &Option<NaiveDate> {
+                &self.embargo_end_date
+            }
         }
     };
 }
@@ -170,6 +170,22 @@ mod conversions {
             AccessibilityException::MicroEnterprises,
         );
     }
+
+    #[test]
+    fn accessstatus_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(AccessStatus::Embargoed);
+    }
+
+    #[test]
+    fn accessstatus_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<AccessStatus, crate::schema::sql_types::AccessStatus>(
+            pool.as_ref(),
+            "'embargoed'::access_status",
+            AccessStatus::Embargoed,
+        );
+    }
 }
 
 mod helpers {
@@ -202,6 +218,33 @@ mod helpers {
         }
     }
 
+    #[test]
+    fn access_status_resolve_lifts_embargo_and_falls_back_to_work_license() {
+        let end = NaiveDate::from_ymd_opt(2027, 1, 1).unwrap();
+        let before = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap();
+
+        assert_eq!(
+            AccessStatus::resolve(Some(AccessStatus::Embargoed), Some(end), true, before),
+            AccessStatus::Embargoed
+        );
+        assert_eq!(
+            AccessStatus::resolve(Some(AccessStatus::Embargoed), Some(end), true, end),
+            AccessStatus::Open
+        );
+        assert_eq!(
+            AccessStatus::resolve(Some(AccessStatus::Restricted), None, true, end),
+            AccessStatus::Restricted
+        );
+        assert_eq!(
+            AccessStatus::resolve(None, None, true, end),
+            AccessStatus::Open
+        );
+        assert_eq!(
+            AccessStatus::resolve(None, None, false, end),
+            AccessStatus::Restricted
+        );
+    }
+
     #[test]
     fn pk_returns_id() {
         let publication: Publication = Default::default();
@@ -370,6 +413,45 @@ mod validation {
         assert!(publication.validate_dimensions_constraints().is_ok());
     }
 
+    #[test]
+    fn validate_access_constraints_requires_embargo_end_date_only_when_embargoed() {
+        let mut publication = Publication {
+            publication_type: PublicationType::Pdf,
+            access_status: Some(AccessStatus::Embargoed),
+            ..Default::default()
+        };
+        assert_eq!(
+            publication.validate_access_constraints(),
+            Err(ThothError::PublicationEmbargoEndDateError)
+        );
+        publication.embargo_end_date = NaiveDate::from_ymd_opt(2027, 1, 1);
+        assert!(publication.validate_access_constraints().is_ok());
+        publication.access_status = Some(AccessStatus::Open);
+        assert_eq!(
+            publication.validate_access_constraints(),
+            Err(ThothError::PublicationEmbargoEndDateError)
+        );
+        publication.embargo_end_date = None;
+        assert!(publication.validate_access_constraints().is_ok());
+    }
+
+    #[test]
+    fn validate_access_constraints_checks_creative_commons_license() {
+        let mut publication = Publication {
+            publication_type: PublicationType::Pdf,
+            license: Some("http://creativecommons.org/licenses/by/4.0/legalcode".to_string()),
+            ..Default::default()
+        };
+        assert!(publication.validate_access_constraints().is_ok());
+        publication.license = Some("https://creativecommons.org/licenses/by-zz/4.0/".to_string());
+        assert_eq!(
+            publication.validate_access_constraints(),
+            Err(ThothError::UnrecognisedCreativeCommonsLicense(
+                "https://creativecommons.org/licenses/by-zz/4.0/".to_string()
+            ))
+        );
+    }
+
     #[test]
     fn normalise_isbn_accepts_valid_hyphenless_isbn13() {
         let mut publication = Publication {
@@ -442,6 +524,9 @@ mod policy {
             accessibility_additional_standard: None,
             accessibility_exception: None,
             accessibility_report_url: None,
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         };
 
         let publication =
@@ -463,6 +548,9 @@ mod policy {
             accessibility_additional_standard: publication.accessibility_additional_standard,
             accessibility_exception: publication.accessibility_exception,
             accessibility_report_url: publication.accessibility_report_url.clone(),
+            license: publication.license.clone(),
+            access_status: publication.access_status,
+            embargo_end_date: publication.embargo_end_date,
         };
 
         assert!(PublicationPolicy::can_create(&ctx, &new_publication, ()).is_ok());
@@ -495,6 +583,9 @@ mod policy {
             accessibility_additional_standard: publication.accessibility_additional_standard,
             accessibility_exception: publication.accessibility_exception,
             accessibility_report_url: publication.accessibility_report_url.clone(),
+            license: publication.license.clone(),
+            access_status: publication.access_status,
+            embargo_end_date: publication.embargo_end_date,
         };
 
         let user = test_user_with_role("publication-user", Role::PublisherUser, "org-other");
@@ -516,6 +607,9 @@ mod policy {
             accessibility_additional_standard: None,
             accessibility_exception: None,
             accessibility_report_url: None,
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         };
 
         assert!(PublicationPolicy::can_create(&ctx, &new_publication, ()).is_err());
@@ -561,6 +655,7 @@ mod policy {
                 first_page: None,
                 last_page: None,
                 page_interval: None,
+                expose_latest_file_url: Some(true),
             },
         )
         .expect("Failed to create published work");
@@ -617,6 +712,7 @@ mod policy {
                 first_page: None,
                 last_page: None,
                 page_interval: None,
+                expose_latest_file_url: Some(true),
             },
         )
         .expect("Failed to create published work");
@@ -661,6 +757,9 @@ mod crud {
             accessibility_additional_standard: None,
             accessibility_exception: None,
             accessibility_report_url: None,
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         };
 
         Publication::create(pool, &new_publication).expect("Failed to create publication")
@@ -705,6 +804,7 @@ mod crud {
             first_page: None,
             last_page: None,
             page_interval: None,
+            expose_latest_file_url: Some(true),
         };
 
         Work::create(pool, &new_work).expect("Failed to create work")
@@ -742,6 +842,9 @@ mod crud {
             accessibility_additional_standard: publication.accessibility_additional_standard,
             accessibility_exception: publication.accessibility_exception,
             accessibility_report_url: publication.accessibility_report_url.clone(),
+            license: publication.license.clone(),
+            access_status: publication.access_status,
+            embargo_end_date: publication.embargo_end_date,
         };
 
         let ctx = test_context(pool.clone(), "test-user");
@@ -756,6 +859,48 @@ mod crud {
         assert!(Publication::from_id(pool.as_ref(), &deleted.publication_id).is_err());
     }
 
+    #[test]
+    fn crud_roundtrip_persists_access_fields() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = make_publication(pool.as_ref(), work.work_id, PublicationType::Pdf, None);
+
+        let patch = PatchPublication {
+            publication_id: publication.publication_id,
+            publication_type: publication.publication_type,
+            work_id: publication.work_id,
+            isbn: None,
+            width_mm: None,
+            width_in: None,
+            height_mm: None,
+            height_in: None,
+            depth_mm: None,
+            depth_in: None,
+            weight_g: None,
+            weight_oz: None,
+            accessibility_standard: None,
+            accessibility_additional_standard: None,
+            accessibility_exception: None,
+            accessibility_report_url: None,
+            license: Some("https://creativecommons.org/licenses/by/4.0/".to_string()),
+            access_status: Some(AccessStatus::Embargoed),
+            embargo_end_date: NaiveDate::from_ymd_opt(2027, 6, 1),
+        };
+
+        let ctx = test_context(pool.clone(), "test-user");
+        let updated = publication
+            .update(&ctx, &patch)
+            .expect("Failed to update publication");
+        let fetched = Publication::from_id(pool.as_ref(), &updated.publication_id)
+            .expect("Failed to fetch publication");
+        assert_eq!(fetched.license, patch.license);
+        assert_eq!(fetched.access_status, Some(AccessStatus::Embargoed));
+        assert_eq!(fetched.embargo_end_date, patch.embargo_end_date);
+    }
+
     #[test]
     fn crud_validate_rejects_chapter_with_isbn() {
         let (_guard, pool) = setup_test_db();
@@ -782,6 +927,9 @@ mod crud {
                 accessibility_additional_standard: None,
                 accessibility_exception: None,
                 accessibility_report_url: None,
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
             },
         )
         .expect("Failed to create publication");
@@ -817,6 +965,9 @@ mod crud {
             accessibility_additional_standard: None,
             accessibility_exception: None,
             accessibility_report_url: None,
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         };
 
         let result = publication.validate(pool.as_ref());
@@ -849,6 +1000,9 @@ mod crud {
                 accessibility_additional_standard: None,
                 accessibility_exception: None,
                 accessibility_report_url: None,
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
             },
         )
         .expect("Failed to create publication");
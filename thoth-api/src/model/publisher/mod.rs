@@ -56,6 +56,8 @@ pub struct Publisher {
     graphql(description = "Set of values required to define a new organisation that produces and distributes works"),
     diesel(table_name = publisher)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewPublisher {
     pub publisher_name: String,
     pub publisher_shortname: Option<String>,
@@ -71,6 +73,8 @@ pub struct NewPublisher {
     graphql(description = "Set of values required to update an existing organisation that produces and distributes works"),
     diesel(table_name = publisher, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchPublisher {
     pub publisher_id: Uuid,
     pub publisher_name: String,
@@ -82,6 +82,8 @@ pub struct Reference {
     graphql(description = "Set of values required to define a new citation to a written text"),
     diesel(table_name = reference)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewReference {
     pub work_id: Uuid,
     pub reference_ordinal: i32,
@@ -113,6 +115,8 @@ pub struct NewReference {
     graphql(description = "Set of values required to update an existing citation to a written text"),
     diesel(table_name = reference, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchReference {
     pub reference_id: Uuid,
     pub work_id: Uuid,
@@ -0,0 +1,134 @@
+use super::{
+    NewSalesRights, NewSalesRightsHistory, PatchSalesRights, SalesRights, SalesRightsField,
+    SalesRightsHistory, SalesRightsOrderBy, SalesRightsType,
+};
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{sales_rights, sales_rights_history};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for SalesRights {
+    type NewEntity = NewSalesRights;
+    type PatchEntity = PatchSalesRights;
+    type OrderByEntity = SalesRightsOrderBy;
+    type FilterParameter1 = SalesRightsType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+    type FilterParameter4 = ();
+
+    fn pk(&self) -> Uuid {
+        self.sales_rights_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        sales_rights_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<Vec<SalesRights>> {
+        use crate::schema::sales_rights::dsl::*;
+        let mut connection = db.get()?;
+        let mut query =
+            sales_rights
+                .inner_join(crate::schema::publication::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .select(crate::schema::sales_rights::all_columns)
+                .into_boxed();
+
+        query = match order.field {
+            SalesRightsField::SalesRightsId => {
+                apply_directional_order!(query, order.direction, order, sales_rights_id)
+            }
+            SalesRightsField::PublicationId => {
+                apply_directional_order!(query, order.direction, order, publication_id)
+            }
+            SalesRightsField::SalesRightsType => {
+                apply_directional_order!(query, order.direction, order, sales_rights_type)
+            }
+            SalesRightsField::CreatedAt => {
+                apply_directional_order!(query, order.direction, order, created_at)
+            }
+            SalesRightsField::UpdatedAt => {
+                apply_directional_order!(query, order.direction, order, updated_at)
+            }
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(publication_id.eq(pid));
+        }
+        if !sales_rights_types.is_empty() {
+            query = query.filter(sales_rights_type.eq_any(sales_rights_types));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<SalesRights>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        publishers: Vec<Uuid>,
+        sales_rights_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<i32> {
+        use crate::schema::sales_rights::dsl::*;
+        let mut connection = db.get()?;
+        let mut query =
+            sales_rights
+                .inner_join(crate::schema::publication::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .into_boxed();
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if !sales_rights_types.is_empty() {
+            query = query.filter(sales_rights_type.eq_any(sales_rights_types));
+        }
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    crud_methods!(sales_rights::table, sales_rights::dsl::sales_rights);
+}
+
+publisher_id_impls!(SalesRights, NewSalesRights, PatchSalesRights, |s, db| {
+    crate::model::publication::Publication::from_id(db, &s.publication_id)?.publisher_id(db)
+});
+
+impl HistoryEntry for SalesRights {
+    type NewHistoryEntity = NewSalesRightsHistory;
+
+    fn new_history_entry(&self, user_id: &str) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            sales_rights_id: self.sales_rights_id,
+            user_id: user_id.to_string(),
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewSalesRightsHistory {
+    type MainEntity = SalesRightsHistory;
+
+    db_insert!(sales_rights_history::table);
+}
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::graphql::types::inputs::Direction;
+use crate::model::country::CountryCode;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::sales_rights;
+#[cfg(feature = "backend")]
+use crate::schema::sales_rights_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Type of rights held to sell a publication in a territory"),
+    ExistingTypePath = "crate::schema::sql_types::SalesRightsType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum SalesRightsType {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "for_sale_exclusive",
+        graphql(description = "For sale with exclusive rights in the specified territory")
+    )]
+    ForSaleExclusive,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "for_sale_non_exclusive",
+        graphql(description = "For sale with non-exclusive rights in the specified territory")
+    )]
+    #[default]
+    ForSaleNonExclusive,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "not_for_sale",
+        graphql(description = "Not for sale in the specified territory")
+    )]
+    NotForSale,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting sales rights list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SalesRightsField {
+    SalesRightsId,
+    PublicationId,
+    #[default]
+    SalesRightsType,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesRights {
+    pub sales_rights_id: Uuid,
+    pub publication_id: Uuid,
+    pub sales_rights_type: SalesRightsType,
+    pub countries_included: Option<Vec<CountryCode>>,
+    pub countries_excluded: Vec<CountryCode>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::Insertable),
+    graphql(description = "Set of values required to define new sales rights for a publication"),
+    diesel(table_name = sales_rights)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSalesRights {
+    pub publication_id: Uuid,
+    pub sales_rights_type: SalesRightsType,
+    pub countries_included: Option<Vec<CountryCode>>,
+    #[cfg_attr(feature = "backend", graphql(default = vec![]))]
+    pub countries_excluded: Vec<CountryCode>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::AsChangeset),
+    graphql(description = "Set of values required to update existing sales rights"),
+    diesel(table_name = sales_rights, treat_none_as_null = true)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchSalesRights {
+    pub sales_rights_id: Uuid,
+    pub publication_id: Uuid,
+    pub sales_rights_type: SalesRightsType,
+    pub countries_included: Option<Vec<CountryCode>>,
+    #[cfg_attr(feature = "backend", graphql(default = vec![]))]
+    pub countries_excluded: Vec<CountryCode>,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+pub struct SalesRightsHistory {
+    pub sales_rights_history_id: Uuid,
+    pub sales_rights_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel::Insertable),
+    diesel(table_name = sales_rights_history)
+)]
+pub struct NewSalesRightsHistory {
+    pub sales_rights_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Field and order to use when sorting sales rights list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SalesRightsOrderBy {
+    pub field: SalesRightsField,
+    pub direction: Direction,
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
+#[cfg(feature = "backend")]
+mod policy;
+#[cfg(feature = "backend")]
+pub(crate) use policy::SalesRightsPolicy;
+#[cfg(test)]
+mod tests;
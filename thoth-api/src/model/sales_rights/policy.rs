@@ -0,0 +1,46 @@
+use crate::model::country::validate_territory;
+use crate::model::sales_rights::{NewSalesRights, PatchSalesRights, SalesRights};
+use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy};
+use thoth_errors::ThothResult;
+
+/// Write policies for `SalesRights`.
+///
+/// These policies are responsible for:
+/// - requiring authentication
+/// - requiring publisher membership (tenant boundary)
+/// - enforcing ONIX territory rules on the included and excluded countries
+pub struct SalesRightsPolicy;
+
+impl CreatePolicy<NewSalesRights> for SalesRightsPolicy {
+    fn can_create<C: PolicyContext>(
+        ctx: &C,
+        data: &NewSalesRights,
+        _params: (),
+    ) -> ThothResult<()> {
+        ctx.require_publisher_for(data)?;
+        validate_territory(data.countries_included.as_deref(), &data.countries_excluded)
+    }
+}
+
+impl UpdatePolicy<SalesRights, PatchSalesRights> for SalesRightsPolicy {
+    fn can_update<C: PolicyContext>(
+        ctx: &C,
+        current: &SalesRights,
+        patch: &PatchSalesRights,
+        _params: (),
+    ) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        ctx.require_publisher_for(patch)?;
+        validate_territory(
+            patch.countries_included.as_deref(),
+            &patch.countries_excluded,
+        )
+    }
+}
+
+impl DeletePolicy<SalesRights> for SalesRightsPolicy {
+    fn can_delete<C: PolicyContext>(ctx: &C, current: &SalesRights) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,500 @@
+use super::*;
+use crate::model::Crud;
+use uuid::Uuid;
+
+fn make_sales_rights(
+    pool: &crate::db::PgPool,
+    publication_id: Uuid,
+    sales_rights_type: SalesRightsType,
+    countries_included: Option<Vec<CountryCode>>,
+) -> SalesRights {
+    let new_sales_rights = NewSalesRights {
+        publication_id,
+        sales_rights_type,
+        countries_included,
+        countries_excluded: vec![],
+    };
+
+    SalesRights::create(pool, &new_sales_rights).expect("Failed to create sales rights")
+}
+
+mod defaults {
+    use super::*;
+
+    #[test]
+    fn salesrightstype_default_is_for_sale_non_exclusive() {
+        let salesrightstype: SalesRightsType = Default::default();
+        assert_eq!(salesrightstype, SalesRightsType::ForSaleNonExclusive);
+    }
+
+    #[test]
+    fn salesrightsfield_default_is_sales_rights_type() {
+        let salesrightsfield: SalesRightsField = Default::default();
+        assert_eq!(salesrightsfield, SalesRightsField::SalesRightsType);
+    }
+}
+
+mod display_and_parse {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn salesrightstype_display_formats_expected_strings() {
+        assert_eq!(
+            format!("{}", SalesRightsType::ForSaleExclusive),
+            "For Sale Exclusive"
+        );
+        assert_eq!(
+            format!("{}", SalesRightsType::ForSaleNonExclusive),
+            "For Sale Non Exclusive"
+        );
+        assert_eq!(format!("{}", SalesRightsType::NotForSale), "Not For Sale");
+    }
+
+    #[test]
+    fn salesrightstype_fromstr_parses_expected_values() {
+        assert_eq!(
+            SalesRightsType::from_str("For Sale Exclusive").unwrap(),
+            SalesRightsType::ForSaleExclusive
+        );
+        assert_eq!(
+            SalesRightsType::from_str("Not For Sale").unwrap(),
+            SalesRightsType::NotForSale
+        );
+        assert!(SalesRightsType::from_str("Exclusive").is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod conversions {
+    use super::*;
+    use crate::model::tests::db::setup_test_db;
+    use crate::model::tests::{assert_db_enum_roundtrip, assert_graphql_enum_roundtrip};
+
+    #[test]
+    fn salesrightstype_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(SalesRightsType::NotForSale);
+    }
+
+    #[test]
+    fn salesrightstype_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<SalesRightsType, crate::schema::sql_types::SalesRightsType>(
+            pool.as_ref(),
+            "'for_sale_non_exclusive'::sales_rights_type",
+            SalesRightsType::ForSaleNonExclusive,
+        );
+    }
+}
+
+mod helpers {
+    use super::*;
+    use crate::model::{Crud, HistoryEntry};
+
+    #[test]
+    fn pk_returns_id() {
+        let sales_rights: SalesRights = Default::default();
+        assert_eq!(sales_rights.pk(), sales_rights.sales_rights_id);
+    }
+
+    #[test]
+    fn history_entry_serializes_model() {
+        let sales_rights: SalesRights = Default::default();
+        let user_id = "123456".to_string();
+        let new_sales_rights_history = sales_rights.new_history_entry(&user_id);
+        assert_eq!(
+            new_sales_rights_history.sales_rights_id,
+            sales_rights.sales_rights_id
+        );
+        assert_eq!(new_sales_rights_history.user_id, user_id);
+        assert_eq!(
+            new_sales_rights_history.data,
+            serde_json::Value::String(serde_json::to_string(&sales_rights).unwrap())
+        );
+    }
+}
+
+#[cfg(feature = "backend")]
+mod policy {
+    use super::*;
+
+    use crate::model::sales_rights::policy::SalesRightsPolicy;
+    use crate::model::tests::db::{
+        create_imprint, create_publication, create_publisher, create_work, setup_test_db,
+        test_context_with_user, test_user_with_role,
+    };
+    use crate::model::Crud;
+    use crate::policy::{CreatePolicy, DeletePolicy, Role, UpdatePolicy};
+
+    #[test]
+    fn crud_policy_allows_publisher_user_for_write() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("sales-rights-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let new_sales_rights = NewSalesRights {
+            publication_id: publication.publication_id,
+            sales_rights_type: SalesRightsType::ForSaleExclusive,
+            countries_included: Some(vec![CountryCode::Gbr, CountryCode::Irl]),
+            countries_excluded: vec![],
+        };
+
+        let sales_rights =
+            SalesRights::create(pool.as_ref(), &new_sales_rights).expect("Failed to create");
+        let patch = PatchSalesRights {
+            sales_rights_id: sales_rights.sales_rights_id,
+            publication_id: sales_rights.publication_id,
+            sales_rights_type: SalesRightsType::ForSaleNonExclusive,
+            countries_included: None,
+            countries_excluded: vec![CountryCode::Usa],
+        };
+
+        assert!(SalesRightsPolicy::can_create(&ctx, &new_sales_rights, ()).is_ok());
+        assert!(SalesRightsPolicy::can_update(&ctx, &sales_rights, &patch, ()).is_ok());
+        assert!(SalesRightsPolicy::can_delete(&ctx, &sales_rights).is_ok());
+    }
+
+    #[test]
+    fn crud_policy_rejects_user_without_publisher_role() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let sales_rights = make_sales_rights(
+            pool.as_ref(),
+            publication.publication_id,
+            SalesRightsType::ForSaleNonExclusive,
+            None,
+        );
+        let patch = PatchSalesRights {
+            sales_rights_id: sales_rights.sales_rights_id,
+            publication_id: sales_rights.publication_id,
+            sales_rights_type: SalesRightsType::NotForSale,
+            countries_included: None,
+            countries_excluded: vec![],
+        };
+
+        let user = test_user_with_role("sales-rights-user", Role::PublisherUser, "org-other");
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let new_sales_rights = NewSalesRights {
+            publication_id: publication.publication_id,
+            sales_rights_type: SalesRightsType::ForSaleNonExclusive,
+            countries_included: None,
+            countries_excluded: vec![],
+        };
+
+        assert!(SalesRightsPolicy::can_create(&ctx, &new_sales_rights, ()).is_err());
+        assert!(SalesRightsPolicy::can_update(&ctx, &sales_rights, &patch, ()).is_err());
+        assert!(SalesRightsPolicy::can_delete(&ctx, &sales_rights).is_err());
+    }
+
+    #[test]
+    fn crud_policy_rejects_invalid_territory() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("sales-rights-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        let empty_included = NewSalesRights {
+            publication_id: publication.publication_id,
+            sales_rights_type: SalesRightsType::ForSaleNonExclusive,
+            countries_included: Some(vec![]),
+            countries_excluded: vec![],
+        };
+        let excluded_with_included = NewSalesRights {
+            publication_id: publication.publication_id,
+            sales_rights_type: SalesRightsType::ForSaleNonExclusive,
+            countries_included: Some(vec![CountryCode::Gbr]),
+            countries_excluded: vec![CountryCode::Usa],
+        };
+
+        assert!(SalesRightsPolicy::can_create(&ctx, &empty_included, ()).is_err());
+        assert!(SalesRightsPolicy::can_create(&ctx, &excluded_with_included, ()).is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod crud {
+    use super::*;
+
+    use crate::graphql::types::inputs::Direction;
+    use crate::model::tests::db::{
+        create_imprint, create_publication, create_publisher, create_work, setup_test_db,
+        test_context,
+    };
+    use crate::model::Crud;
+
+    #[test]
+    fn crud_roundtrip_create_fetch_update_delete() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        let sales_rights = make_sales_rights(
+            pool.as_ref(),
+            publication.publication_id,
+            SalesRightsType::ForSaleExclusive,
+            Some(vec![CountryCode::Gbr, CountryCode::Irl]),
+        );
+        let fetched = SalesRights::from_id(pool.as_ref(), &sales_rights.sales_rights_id)
+            .expect("Failed to fetch");
+        assert_eq!(sales_rights.sales_rights_id, fetched.sales_rights_id);
+        assert_eq!(
+            fetched.countries_included,
+            Some(vec![CountryCode::Gbr, CountryCode::Irl])
+        );
+        assert!(fetched.countries_excluded.is_empty());
+
+        let patch = PatchSalesRights {
+            sales_rights_id: sales_rights.sales_rights_id,
+            publication_id: sales_rights.publication_id,
+            sales_rights_type: SalesRightsType::ForSaleNonExclusive,
+            countries_included: None,
+            countries_excluded: vec![CountryCode::Usa, CountryCode::Can],
+        };
+
+        let ctx = test_context(pool.clone(), "test-user");
+        let updated = sales_rights.update(&ctx, &patch).expect("Failed to update");
+        assert_eq!(updated.sales_rights_type, patch.sales_rights_type);
+        assert_eq!(updated.countries_included, None);
+        assert_eq!(updated.countries_excluded, patch.countries_excluded);
+
+        let deleted = updated.delete(pool.as_ref()).expect("Failed to delete");
+        assert!(SalesRights::from_id(pool.as_ref(), &deleted.sales_rights_id).is_err());
+    }
+
+    #[test]
+    fn crud_rejects_excluded_countries_alongside_included_countries() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        let new_sales_rights = NewSalesRights {
+            publication_id: publication.publication_id,
+            sales_rights_type: SalesRightsType::ForSaleNonExclusive,
+            countries_included: Some(vec![CountryCode::Gbr]),
+            countries_excluded: vec![CountryCode::Usa],
+        };
+
+        assert!(SalesRights::create(pool.as_ref(), &new_sales_rights).is_err());
+    }
+
+    #[test]
+    fn crud_count_filters_by_sales_rights_type() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        make_sales_rights(
+            pool.as_ref(),
+            publication.publication_id,
+            SalesRightsType::ForSaleExclusive,
+            Some(vec![CountryCode::Gbr]),
+        );
+        make_sales_rights(
+            pool.as_ref(),
+            publication.publication_id,
+            SalesRightsType::NotForSale,
+            Some(vec![CountryCode::Usa]),
+        );
+
+        let total = SalesRights::count(pool.as_ref(), None, vec![], vec![], vec![], None, None)
+            .expect("Failed to count sales rights");
+        let filtered = SalesRights::count(
+            pool.as_ref(),
+            None,
+            vec![],
+            vec![SalesRightsType::NotForSale],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to count sales rights by type");
+
+        assert_eq!(total, 2);
+        assert_eq!(filtered, 1);
+    }
+
+    #[test]
+    fn crud_filter_parent_publication_id_limits_results() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let other_work = create_work(pool.as_ref(), &imprint);
+        let other_publication = create_publication(pool.as_ref(), &other_work);
+
+        let matches = make_sales_rights(
+            pool.as_ref(),
+            publication.publication_id,
+            SalesRightsType::ForSaleNonExclusive,
+            None,
+        );
+        make_sales_rights(
+            pool.as_ref(),
+            other_publication.publication_id,
+            SalesRightsType::ForSaleNonExclusive,
+            None,
+        );
+
+        let filtered = SalesRights::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            SalesRightsOrderBy {
+                field: SalesRightsField::SalesRightsId,
+                direction: Direction::Asc,
+            },
+            vec![],
+            Some(publication.publication_id),
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to filter sales rights by publication");
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].sales_rights_id, matches.sales_rights_id);
+    }
+
+    #[test]
+    fn crud_filter_publishers_limits_results() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+        let matches = make_sales_rights(
+            pool.as_ref(),
+            publication.publication_id,
+            SalesRightsType::ForSaleNonExclusive,
+            None,
+        );
+
+        let other_publisher = create_publisher(pool.as_ref());
+        let other_imprint = create_imprint(pool.as_ref(), &other_publisher);
+        let other_work = create_work(pool.as_ref(), &other_imprint);
+        let other_publication = create_publication(pool.as_ref(), &other_work);
+        make_sales_rights(
+            pool.as_ref(),
+            other_publication.publication_id,
+            SalesRightsType::ForSaleNonExclusive,
+            None,
+        );
+
+        let filtered = SalesRights::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            SalesRightsOrderBy {
+                field: SalesRightsField::SalesRightsId,
+                direction: Direction::Asc,
+            },
+            vec![publisher.publisher_id],
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to filter sales rights by publisher");
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].sales_rights_id, matches.sales_rights_id);
+    }
+
+    #[test]
+    fn crud_ordering_by_fields_is_supported() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let publication = create_publication(pool.as_ref(), &work);
+
+        make_sales_rights(
+            pool.as_ref(),
+            publication.publication_id,
+            SalesRightsType::ForSaleExclusive,
+            Some(vec![CountryCode::Gbr]),
+        );
+        make_sales_rights(
+            pool.as_ref(),
+            publication.publication_id,
+            SalesRightsType::NotForSale,
+            Some(vec![CountryCode::Usa]),
+        );
+
+        let fields: Vec<fn() -> SalesRightsField> = vec![
+            || SalesRightsField::SalesRightsId,
+            || SalesRightsField::PublicationId,
+            || SalesRightsField::SalesRightsType,
+            || SalesRightsField::CreatedAt,
+            || SalesRightsField::UpdatedAt,
+        ];
+
+        for field in fields {
+            for direction in [Direction::Asc, Direction::Desc] {
+                let results = SalesRights::all(
+                    pool.as_ref(),
+                    10,
+                    0,
+                    None,
+                    SalesRightsOrderBy {
+                        field: field(),
+                        direction,
+                    },
+                    vec![],
+                    None,
+                    None,
+                    vec![],
+                    vec![],
+                    None,
+                    None,
+                )
+                .expect("Failed to order sales rights");
+
+                assert_eq!(results.len(), 2);
+            }
+        }
+    }
+}
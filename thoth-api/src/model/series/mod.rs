@@ -88,6 +88,8 @@ pub struct Series {
     graphql(description = "Set of values required to define a new periodical of publications"),
     diesel(table_name = series)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewSeries {
     pub series_type: SeriesType,
     pub series_name: String,
@@ -105,6 +107,8 @@ pub struct NewSeries {
     graphql(description = "Set of values required to update an existing periodical of publications"),
     diesel(table_name = series, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchSeries {
     pub series_id: Uuid,
     pub series_type: SeriesType,
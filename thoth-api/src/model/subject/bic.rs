@@ -0,0 +1,85 @@
+use super::vocabulary::Labels;
+use crate::model::language::LanguageCode::Eng;
+
+/// BIC 2.1 sections and qualifier groups, plus the second-level headings used by the
+/// Thema crosswalk. Other BIC codes are validated structurally.
+pub(crate) static BIC_HEADINGS: &[(&str, Labels)] = &[
+    ("1", &[(Eng, "Geographical qualifiers")]),
+    ("2", &[(Eng, "Language qualifiers")]),
+    ("3", &[(Eng, "Time period qualifiers")]),
+    ("4", &[(Eng, "Educational purpose qualifiers")]),
+    ("5", &[(Eng, "Interest age / special interest qualifiers")]),
+    ("6", &[(Eng, "Styles qualifiers")]),
+    ("A", &[(Eng, "The arts")]),
+    ("AJ", &[(Eng, "Photography & photographs")]),
+    ("AM", &[(Eng, "Architecture")]),
+    ("AP", &[(Eng, "Film, TV & radio")]),
+    ("APF", &[(Eng, "Films, cinema")]),
+    ("AV", &[(Eng, "Music")]),
+    ("B", &[(Eng, "Biography & true stories")]),
+    ("BG", &[(Eng, "Biography: general")]),
+    ("C", &[(Eng, "Language")]),
+    ("CF", &[(Eng, "Linguistics")]),
+    ("D", &[(Eng, "Literature & literary studies")]),
+    ("DC", &[(Eng, "Poetry")]),
+    ("DD", &[(Eng, "Plays, playscripts")]),
+    ("DS", &[(Eng, "Literature: history & criticism")]),
+    ("E", &[(Eng, "English language teaching (ELT)")]),
+    ("F", &[(Eng, "Fiction & related items")]),
+    ("FF", &[(Eng, "Crime & mystery")]),
+    ("FL", &[(Eng, "Science fiction")]),
+    ("FM", &[(Eng, "Fantasy")]),
+    ("FR", &[(Eng, "Romance")]),
+    ("FV", &[(Eng, "Historical fiction")]),
+    ("FX", &[(Eng, "Graphic novels")]),
+    (
+        "G",
+        &[(Eng, "Reference, information & interdisciplinary subjects")],
+    ),
+    ("GL", &[(Eng, "Library & information sciences")]),
+    ("H", &[(Eng, "Humanities")]),
+    ("HB", &[(Eng, "History")]),
+    ("HD", &[(Eng, "Archaeology")]),
+    ("HP", &[(Eng, "Philosophy")]),
+    ("HR", &[(Eng, "Religion & beliefs")]),
+    ("J", &[(Eng, "Society & social sciences")]),
+    ("JH", &[(Eng, "Sociology & anthropology")]),
+    ("JHM", &[(Eng, "Anthropology")]),
+    ("JM", &[(Eng, "Psychology")]),
+    ("JN", &[(Eng, "Education")]),
+    ("JP", &[(Eng, "Politics & government")]),
+    ("K", &[(Eng, "Economics, finance, business & management")]),
+    ("KC", &[(Eng, "Economics")]),
+    ("KJ", &[(Eng, "Business & management")]),
+    ("L", &[(Eng, "Law")]),
+    ("M", &[(Eng, "Medicine")]),
+    ("MQ", &[(Eng, "Nursing & ancillary services")]),
+    ("MQC", &[(Eng, "Nursing")]),
+    ("P", &[(Eng, "Mathematics & science")]),
+    ("PB", &[(Eng, "Mathematics")]),
+    ("PH", &[(Eng, "Physics")]),
+    ("PN", &[(Eng, "Chemistry")]),
+    ("PS", &[(Eng, "Biology, life sciences")]),
+    (
+        "R",
+        &[(Eng, "Earth sciences, geography, environment, planning")],
+    ),
+    ("RN", &[(Eng, "The environment")]),
+    ("T", &[(Eng, "Technology, engineering, agriculture")]),
+    ("TV", &[(Eng, "Agriculture & farming")]),
+    ("U", &[(Eng, "Computing & information technology")]),
+    ("V", &[(Eng, "Health & personal development")]),
+    ("VF", &[(Eng, "Family & health")]),
+    ("VS", &[(Eng, "Self-help & personal development")]),
+    ("W", &[(Eng, "Lifestyle, sport & leisure")]),
+    ("WB", &[(Eng, "Cookery, food & drink")]),
+    ("WF", &[(Eng, "Handicrafts, decorative arts & crafts")]),
+    ("WM", &[(Eng, "Gardening")]),
+    ("WS", &[(Eng, "Sports & outdoor recreation")]),
+    ("WT", &[(Eng, "Travel & holiday")]),
+    ("Y", &[(Eng, "Children's, teenage & educational")]),
+    (
+        "YF",
+        &[(Eng, "Children's & teenage fiction & true stories")],
+    ),
+];
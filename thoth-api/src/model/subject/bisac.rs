@@ -0,0 +1,107 @@
+use super::vocabulary::Labels;
+use crate::model::language::LanguageCode::Eng;
+
+/// BISAC main headings, plus the subheadings used by the Thema crosswalk. Any other
+/// subject code under a known heading is accepted on structure alone.
+pub(crate) static BISAC_HEADINGS: &[(&str, Labels)] = &[
+    ("ANT000000", &[(Eng, "Antiques & Collectibles / General")]),
+    ("ARC000000", &[(Eng, "Architecture / General")]),
+    ("ART000000", &[(Eng, "Art / General")]),
+    ("BIB000000", &[(Eng, "Bibles / General")]),
+    ("BIO000000", &[(Eng, "Biography & Autobiography / General")]),
+    ("BOD000000", &[(Eng, "Body, Mind & Spirit / General")]),
+    ("BUS000000", &[(Eng, "Business & Economics / General")]),
+    (
+        "BUS069000",
+        &[(Eng, "Business & Economics / Economics / General")],
+    ),
+    ("CGN000000", &[(Eng, "Comics & Graphic Novels / General")]),
+    ("CKB000000", &[(Eng, "Cooking / General")]),
+    ("COM000000", &[(Eng, "Computers / General")]),
+    ("CRA000000", &[(Eng, "Crafts & Hobbies / General")]),
+    ("DES000000", &[(Eng, "Design / General")]),
+    ("DRA000000", &[(Eng, "Drama / General")]),
+    ("EDU000000", &[(Eng, "Education / General")]),
+    ("FAM000000", &[(Eng, "Family & Relationships / General")]),
+    ("FIC000000", &[(Eng, "Fiction / General")]),
+    ("FIC009000", &[(Eng, "Fiction / Fantasy / General")]),
+    ("FIC014000", &[(Eng, "Fiction / Historical / General")]),
+    (
+        "FIC022000",
+        &[(Eng, "Fiction / Mystery & Detective / General")],
+    ),
+    ("FIC027000", &[(Eng, "Fiction / Romance / General")]),
+    ("FIC028000", &[(Eng, "Fiction / Science Fiction / General")]),
+    ("FOR000000", &[(Eng, "Foreign Language Study / General")]),
+    ("GAM000000", &[(Eng, "Games & Activities / General")]),
+    ("GAR000000", &[(Eng, "Gardening / General")]),
+    ("HEA000000", &[(Eng, "Health & Fitness / General")]),
+    ("HIS000000", &[(Eng, "History / General")]),
+    ("HOM000000", &[(Eng, "House & Home / General")]),
+    ("HUM000000", &[(Eng, "Humor / General")]),
+    ("JNF000000", &[(Eng, "Juvenile Nonfiction / General")]),
+    ("JUV000000", &[(Eng, "Juvenile Fiction / General")]),
+    (
+        "LAN000000",
+        &[(Eng, "Language Arts & Disciplines / General")],
+    ),
+    (
+        "LAN009000",
+        &[(Eng, "Language Arts & Disciplines / Linguistics / General")],
+    ),
+    (
+        "LAN025000",
+        &[(
+            Eng,
+            "Language Arts & Disciplines / Library & Information Science / General",
+        )],
+    ),
+    ("LAW000000", &[(Eng, "Law / General")]),
+    ("LCO000000", &[(Eng, "Literary Collections / General")]),
+    ("LIT000000", &[(Eng, "Literary Criticism / General")]),
+    ("MAT000000", &[(Eng, "Mathematics / General")]),
+    ("MED000000", &[(Eng, "Medical / General")]),
+    ("MED058000", &[(Eng, "Medical / Nursing / General")]),
+    ("MUS000000", &[(Eng, "Music / General")]),
+    ("NAT000000", &[(Eng, "Nature / General")]),
+    ("NAT010000", &[(Eng, "Nature / Ecology")]),
+    ("NON000000", &[(Eng, "Non-Classifiable")]),
+    ("PER000000", &[(Eng, "Performing Arts / General")]),
+    ("PER004000", &[(Eng, "Performing Arts / Film / General")]),
+    ("PET000000", &[(Eng, "Pets / General")]),
+    ("PHI000000", &[(Eng, "Philosophy / General")]),
+    ("PHO000000", &[(Eng, "Photography / General")]),
+    ("POE000000", &[(Eng, "Poetry / General")]),
+    ("POL000000", &[(Eng, "Political Science / General")]),
+    ("PSY000000", &[(Eng, "Psychology / General")]),
+    ("REF000000", &[(Eng, "Reference / General")]),
+    ("REL000000", &[(Eng, "Religion / General")]),
+    ("SCI000000", &[(Eng, "Science / General")]),
+    ("SCI008000", &[(Eng, "Science / Life Sciences / Biology")]),
+    ("SCI013000", &[(Eng, "Science / Chemistry / General")]),
+    ("SCI019000", &[(Eng, "Science / Earth Sciences / General")]),
+    ("SCI055000", &[(Eng, "Science / Physics / General")]),
+    ("SEL000000", &[(Eng, "Self-Help / General")]),
+    ("SOC000000", &[(Eng, "Social Science / General")]),
+    (
+        "SOC002000",
+        &[(Eng, "Social Science / Anthropology / General")],
+    ),
+    ("SOC003000", &[(Eng, "Social Science / Archaeology")]),
+    (
+        "SOC026000",
+        &[(Eng, "Social Science / Sociology / General")],
+    ),
+    ("SPO000000", &[(Eng, "Sports & Recreation / General")]),
+    ("STU000000", &[(Eng, "Study Aids / General")]),
+    ("TEC000000", &[(Eng, "Technology & Engineering / General")]),
+    (
+        "TEC003000",
+        &[(Eng, "Technology & Engineering / Agriculture / General")],
+    ),
+    ("TRA000000", &[(Eng, "Transportation / General")]),
+    ("TRU000000", &[(Eng, "True Crime / General")]),
+    ("TRV000000", &[(Eng, "Travel / General")]),
+    ("YAF000000", &[(Eng, "Young Adult Fiction / General")]),
+    ("YAN000000", &[(Eng, "Young Adult Nonfiction / General")]),
+];
@@ -0,0 +1,81 @@
+/// A row of the subject crosswalk, pairing a Thema code with its nearest BIC and BISAC equivalents
+pub(crate) struct CrosswalkEntry {
+    pub thema: &'static str,
+    pub bic: Option<&'static str>,
+    pub bisac: Option<&'static str>,
+}
+
+macro_rules! crosswalk {
+    ($thema:literal, $bic:expr, $bisac:expr) => {
+        CrosswalkEntry {
+            thema: $thema,
+            bic: $bic,
+            bisac: $bisac,
+        }
+    };
+}
+
+/// Equivalences between Thema, BIC and BISAC headings.
+///
+/// Only headings with a clear counterpart are listed: lookups walk up the source hierarchy
+/// until they reach a listed ancestor, so more specific codes map to their closest match.
+/// Where one code maps from several rows, the first (most general) row wins.
+pub(crate) static SUBJECT_CROSSWALK: &[CrosswalkEntry] = &[
+    crosswalk!("A", Some("A"), Some("ART000000")),
+    crosswalk!("AJ", Some("AJ"), Some("PHO000000")),
+    crosswalk!("AM", Some("AM"), Some("ARC000000")),
+    crosswalk!("AT", None, Some("PER000000")),
+    crosswalk!("ATF", Some("APF"), Some("PER004000")),
+    crosswalk!("AV", Some("AV"), Some("MUS000000")),
+    crosswalk!("C", Some("C"), Some("LAN000000")),
+    crosswalk!("CF", Some("CF"), Some("LAN009000")),
+    crosswalk!("D", Some("D"), Some("LIT000000")),
+    crosswalk!("DC", Some("DC"), Some("POE000000")),
+    crosswalk!("DD", Some("DD"), Some("DRA000000")),
+    crosswalk!("DN", Some("BG"), Some("BIO000000")),
+    crosswalk!("DS", Some("DS"), Some("LIT000000")),
+    crosswalk!("F", Some("F"), Some("FIC000000")),
+    crosswalk!("FF", Some("FF"), Some("FIC022000")),
+    crosswalk!("FL", Some("FL"), Some("FIC028000")),
+    crosswalk!("FM", Some("FM"), Some("FIC009000")),
+    crosswalk!("FR", Some("FR"), Some("FIC027000")),
+    crosswalk!("FV", Some("FV"), Some("FIC014000")),
+    crosswalk!("G", Some("G"), Some("REF000000")),
+    crosswalk!("GL", Some("GL"), Some("LAN025000")),
+    crosswalk!("J", Some("J"), Some("SOC000000")),
+    crosswalk!("JH", Some("JH"), Some("SOC026000")),
+    crosswalk!("JHM", Some("JHM"), Some("SOC002000")),
+    crosswalk!("JM", Some("JM"), Some("PSY000000")),
+    crosswalk!("JN", Some("JN"), Some("EDU000000")),
+    crosswalk!("JP", Some("JP"), Some("POL000000")),
+    crosswalk!("K", Some("K"), Some("BUS000000")),
+    crosswalk!("KC", Some("KC"), Some("BUS069000")),
+    crosswalk!("KJ", Some("KJ"), Some("BUS000000")),
+    crosswalk!("L", Some("L"), Some("LAW000000")),
+    crosswalk!("M", Some("M"), Some("MED000000")),
+    crosswalk!("MQC", Some("MQC"), Some("MED058000")),
+    crosswalk!("N", Some("HB"), Some("HIS000000")),
+    crosswalk!("NK", Some("HD"), Some("SOC003000")),
+    crosswalk!("P", Some("P"), Some("SCI000000")),
+    crosswalk!("PB", Some("PB"), Some("MAT000000")),
+    crosswalk!("PH", Some("PH"), Some("SCI055000")),
+    crosswalk!("PN", Some("PN"), Some("SCI013000")),
+    crosswalk!("PS", Some("PS"), Some("SCI008000")),
+    crosswalk!("QD", Some("HP"), Some("PHI000000")),
+    crosswalk!("QR", Some("HR"), Some("REL000000")),
+    crosswalk!("R", Some("R"), Some("SCI019000")),
+    crosswalk!("RN", Some("RN"), Some("NAT010000")),
+    crosswalk!("S", Some("WS"), Some("SPO000000")),
+    crosswalk!("T", Some("T"), Some("TEC000000")),
+    crosswalk!("TV", Some("TV"), Some("TEC003000")),
+    crosswalk!("U", Some("U"), Some("COM000000")),
+    crosswalk!("VF", Some("VF"), Some("FAM000000")),
+    crosswalk!("VS", Some("VS"), Some("SEL000000")),
+    crosswalk!("WB", Some("WB"), Some("CKB000000")),
+    crosswalk!("WF", Some("WF"), Some("CRA000000")),
+    crosswalk!("WM", Some("WM"), Some("GAR000000")),
+    crosswalk!("WT", Some("WT"), Some("TRV000000")),
+    crosswalk!("X", Some("FX"), Some("CGN000000")),
+    crosswalk!("Y", Some("Y"), Some("JNF000000")),
+    crosswalk!("YF", Some("YF"), Some("JUV000000")),
+];
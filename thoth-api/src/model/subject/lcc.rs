@@ -0,0 +1,416 @@
+use super::vocabulary::Labels;
+use crate::model::language::LanguageCode::Eng;
+
+/// Library of Congress Classification outline: main classes and their subclasses. Ranges of law
+/// subclasses (e.g. `KJ-KKZ`) are listed as in the outline and cover every subclass within them.
+pub(crate) static LCC_CLASSES: &[(&str, Labels)] = &[
+    ("A", &[(Eng, "General Works")]),
+    ("AC", &[(Eng, "Collections. Series. Collected works")]),
+    ("AE", &[(Eng, "Encyclopedias")]),
+    ("AG", &[(Eng, "Dictionaries and other general reference works")]),
+    ("AI", &[(Eng, "Indexes")]),
+    ("AM", &[(Eng, "Museums. Collectors and collecting")]),
+    ("AN", &[(Eng, "Newspapers")]),
+    ("AP", &[(Eng, "Periodicals")]),
+    ("AS", &[(Eng, "Academies and learned societies")]),
+    ("AY", &[(Eng, "Yearbooks. Almanacs. Directories")]),
+    (
+        "AZ",
+        &[(Eng, "History of scholarship and learning. The humanities")],
+    ),
+    ("B", &[(Eng, "Philosophy. Psychology. Religion")]),
+    ("BC", &[(Eng, "Logic")]),
+    ("BD", &[(Eng, "Speculative philosophy")]),
+    ("BF", &[(Eng, "Psychology")]),
+    ("BH", &[(Eng, "Aesthetics")]),
+    ("BJ", &[(Eng, "Ethics")]),
+    ("BL", &[(Eng, "Religions. Mythology. Rationalism")]),
+    ("BM", &[(Eng, "Judaism")]),
+    ("BP", &[(Eng, "Islam. Bahai Faith. Theosophy, etc.")]),
+    ("BQ", &[(Eng, "Buddhism")]),
+    ("BR", &[(Eng, "Christianity")]),
+    ("BS", &[(Eng, "The Bible")]),
+    ("BT", &[(Eng, "Doctrinal theology")]),
+    ("BV", &[(Eng, "Practical theology")]),
+    ("BX", &[(Eng, "Christian denominations")]),
+    ("C", &[(Eng, "Auxiliary Sciences of History")]),
+    ("CB", &[(Eng, "History of civilization")]),
+    ("CC", &[(Eng, "Archaeology")]),
+    ("CD", &[(Eng, "Diplomatics. Archives. Seals")]),
+    ("CE", &[(Eng, "Technical chronology. Calendar")]),
+    ("CJ", &[(Eng, "Numismatics")]),
+    ("CN", &[(Eng, "Inscriptions. Epigraphy")]),
+    ("CR", &[(Eng, "Heraldry")]),
+    ("CS", &[(Eng, "Genealogy")]),
+    ("CT", &[(Eng, "Biography")]),
+    (
+        "D",
+        &[(
+            Eng,
+            "World History and History of Europe, Asia, Africa, Australia, New Zealand, etc.",
+        )],
+    ),
+    ("DA", &[(Eng, "Great Britain")]),
+    ("DAW", &[(Eng, "Central Europe")]),
+    (
+        "DB",
+        &[(Eng, "Austria - Liechtenstein - Hungary - Czechoslovakia")],
+    ),
+    ("DC", &[(Eng, "France - Andorra - Monaco")]),
+    ("DD", &[(Eng, "Germany")]),
+    ("DE", &[(Eng, "Greco-Roman world")]),
+    ("DF", &[(Eng, "Greece")]),
+    ("DG", &[(Eng, "Italy - Malta")]),
+    ("DH", &[(Eng, "Low Countries - Benelux Countries")]),
+    ("DJ", &[(Eng, "Netherlands (Holland)")]),
+    ("DJK", &[(Eng, "Eastern Europe (General)")]),
+    (
+        "DK",
+        &[(
+            Eng,
+            "Russia. Soviet Union. Former Soviet Republics - Poland",
+        )],
+    ),
+    ("DL", &[(Eng, "Northern Europe. Scandinavia")]),
+    ("DP", &[(Eng, "Spain - Portugal")]),
+    ("DQ", &[(Eng, "Switzerland")]),
+    ("DR", &[(Eng, "Balkan Peninsula")]),
+    ("DS", &[(Eng, "Asia")]),
+    ("DT", &[(Eng, "Africa")]),
+    ("DU", &[(Eng, "Oceania (South Seas)")]),
+    ("DX", &[(Eng, "Romanies")]),
+    ("E", &[(Eng, "History of the Americas")]),
+    ("F", &[(Eng, "History of the Americas")]),
+    ("G", &[(Eng, "Geography. Anthropology. Recreation")]),
+    ("GA", &[(Eng, "Mathematical geography. Cartography")]),
+    ("GB", &[(Eng, "Physical geography")]),
+    ("GC", &[(Eng, "Oceanography")]),
+    ("GE", &[(Eng, "Environmental sciences")]),
+    ("GF", &[(Eng, "Human ecology. Anthropogeography")]),
+    ("GN", &[(Eng, "Anthropology")]),
+    ("GR", &[(Eng, "Folklore")]),
+    ("GT", &[(Eng, "Manners and customs (General)")]),
+    ("GV", &[(Eng, "Recreation. Leisure")]),
+    ("H", &[(Eng, "Social Sciences")]),
+    ("HA", &[(Eng, "Statistics")]),
+    ("HB", &[(Eng, "Economic theory. Demography")]),
+    ("HC", &[(Eng, "Economic history and conditions")]),
+    ("HD", &[(Eng, "Industries. Land use. Labor")]),
+    ("HE", &[(Eng, "Transportation and communications")]),
+    ("HF", &[(Eng, "Commerce")]),
+    ("HG", &[(Eng, "Finance")]),
+    ("HJ", &[(Eng, "Public finance")]),
+    ("HM", &[(Eng, "Sociology (General)")]),
+    (
+        "HN",
+        &[(
+            Eng,
+            "Social history and conditions. Social problems. Social reform",
+        )],
+    ),
+    ("HQ", &[(Eng, "The family. Marriage. Women")]),
+    ("HS", &[(Eng, "Societies: secret, benevolent, etc.")]),
+    ("HT", &[(Eng, "Communities. Classes. Races")]),
+    (
+        "HV",
+        &[(
+            Eng,
+            "Social pathology. Social and public welfare. Criminology",
+        )],
+    ),
+    ("HX", &[(Eng, "Socialism. Communism. Anarchism")]),
+    ("J", &[(Eng, "Political Science")]),
+    ("JA", &[(Eng, "Political science (General)")]),
+    ("JC", &[(Eng, "Political theory")]),
+    (
+        "JF",
+        &[(Eng, "Political institutions and public administration")],
+    ),
+    (
+        "JJ",
+        &[(
+            Eng,
+            "Political institutions and public administration (North America)",
+        )],
+    ),
+    (
+        "JK",
+        &[(
+            Eng,
+            "Political institutions and public administration (United States)",
+        )],
+    ),
+    (
+        "JL",
+        &[(
+            Eng,
+            "Political institutions and public administration (Canada, Latin America, etc.)",
+        )],
+    ),
+    (
+        "JN",
+        &[(
+            Eng,
+            "Political institutions and public administration (Europe)",
+        )],
+    ),
+    (
+        "JQ",
+        &[(
+            Eng,
+            "Political institutions and public administration (Asia, Africa, Australia, Pacific Area, etc.)",
+        )],
+    ),
+    ("JS", &[(Eng, "Local government. Municipal government")]),
+    (
+        "JV",
+        &[(
+            Eng,
+            "Colonies and colonization. Emigration and immigration. International migration",
+        )],
+    ),
+    ("JX", &[(Eng, "International law (obsolete)")]),
+    ("JZ", &[(Eng, "International relations")]),
+    ("K", &[(Eng, "Law")]),
+    (
+        "KB",
+        &[(
+            Eng,
+            "Religious law in general. Comparative religious law. Jurisprudence",
+        )],
+    ),
+    ("KBM", &[(Eng, "Jewish law")]),
+    ("KBP", &[(Eng, "Islamic law")]),
+    ("KBR", &[(Eng, "History of canon law")]),
+    ("KBS", &[(Eng, "Canon law of Eastern churches")]),
+    (
+        "KBT",
+        &[(
+            Eng,
+            "Canon law of Eastern Rite Churches in Communion with the Holy See of Rome",
+        )],
+    ),
+    (
+        "KBU",
+        &[(Eng, "Law of the Roman Catholic Church. The Holy See")],
+    ),
+    ("KD-KDK", &[(Eng, "United Kingdom and Ireland")]),
+    ("KDZ", &[(Eng, "America. North America")]),
+    ("KE", &[(Eng, "Canada")]),
+    ("KF", &[(Eng, "United States")]),
+    (
+        "KG",
+        &[(
+            Eng,
+            "Latin America - Mexico and Central America - West Indies. Caribbean area",
+        )],
+    ),
+    ("KH", &[(Eng, "South America")]),
+    ("KJ-KKZ", &[(Eng, "Europe")]),
+    (
+        "KL-KWX",
+        &[(
+            Eng,
+            "Asia and Eurasia, Africa, Pacific Area, and Antarctica",
+        )],
+    ),
+    ("KZ", &[(Eng, "Law of nations")]),
+    ("L", &[(Eng, "Education")]),
+    ("LA", &[(Eng, "History of education")]),
+    ("LB", &[(Eng, "Theory and practice of education")]),
+    ("LC", &[(Eng, "Special aspects of education")]),
+    ("LD", &[(Eng, "Individual institutions - United States")]),
+    (
+        "LE",
+        &[(
+            Eng,
+            "Individual institutions - America (except United States)",
+        )],
+    ),
+    ("LF", &[(Eng, "Individual institutions - Europe")]),
+    (
+        "LG",
+        &[(
+            Eng,
+            "Individual institutions - Asia, Africa, Indian Ocean islands, Australia, New Zealand, Pacific islands",
+        )],
+    ),
+    ("LH", &[(Eng, "College and school magazines and papers")]),
+    (
+        "LJ",
+        &[(Eng, "Student fraternities and societies, United States")],
+    ),
+    ("LT", &[(Eng, "Textbooks")]),
+    ("M", &[(Eng, "Music and Books on Music")]),
+    ("ML", &[(Eng, "Literature on music")]),
+    ("MT", &[(Eng, "Instruction and study")]),
+    ("N", &[(Eng, "Fine Arts")]),
+    ("NA", &[(Eng, "Architecture")]),
+    ("NB", &[(Eng, "Sculpture")]),
+    ("NC", &[(Eng, "Drawing. Design. Illustration")]),
+    ("ND", &[(Eng, "Painting")]),
+    ("NE", &[(Eng, "Print media")]),
+    ("NK", &[(Eng, "Decorative arts")]),
+    ("NX", &[(Eng, "Arts in general")]),
+    ("P", &[(Eng, "Language and Literature")]),
+    (
+        "PA",
+        &[(
+            Eng,
+            "Greek language and literature. Latin language and literature",
+        )],
+    ),
+    ("PB", &[(Eng, "Modern languages. Celtic languages")]),
+    ("PC", &[(Eng, "Romanic languages")]),
+    ("PD", &[(Eng, "Germanic languages. Scandinavian languages")]),
+    ("PE", &[(Eng, "English language")]),
+    ("PF", &[(Eng, "West Germanic languages")]),
+    (
+        "PG",
+        &[(
+            Eng,
+            "Slavic languages. Baltic languages. Albanian language",
+        )],
+    ),
+    ("PH", &[(Eng, "Uralic languages. Basque language")]),
+    ("PJ", &[(Eng, "Oriental languages and literatures")]),
+    ("PK", &[(Eng, "Indo-Iranian languages and literatures")]),
+    (
+        "PL",
+        &[(
+            Eng,
+            "Languages and literatures of Eastern Asia, Africa, Oceania",
+        )],
+    ),
+    (
+        "PM",
+        &[(Eng, "Hyperborean, Indian, and artificial languages")],
+    ),
+    ("PN", &[(Eng, "Literature (General)")]),
+    (
+        "PQ",
+        &[(
+            Eng,
+            "French literature - Italian literature - Spanish literature - Portuguese literature",
+        )],
+    ),
+    ("PR", &[(Eng, "English literature")]),
+    ("PS", &[(Eng, "American literature")]),
+    (
+        "PT",
+        &[(
+            Eng,
+            "German literature - Dutch literature - Flemish literature since 1830 - Afrikaans literature - Scandinavian literature",
+        )],
+    ),
+    ("PZ", &[(Eng, "Fiction and juvenile belles lettres")]),
+    ("Q", &[(Eng, "Science")]),
+    ("QA", &[(Eng, "Mathematics")]),
+    ("QB", &[(Eng, "Astronomy")]),
+    ("QC", &[(Eng, "Physics")]),
+    ("QD", &[(Eng, "Chemistry")]),
+    ("QE", &[(Eng, "Geology")]),
+    ("QH", &[(Eng, "Natural history - Biology")]),
+    ("QK", &[(Eng, "Botany")]),
+    ("QL", &[(Eng, "Zoology")]),
+    ("QM", &[(Eng, "Human anatomy")]),
+    ("QP", &[(Eng, "Physiology")]),
+    ("QR", &[(Eng, "Microbiology")]),
+    ("R", &[(Eng, "Medicine")]),
+    ("RA", &[(Eng, "Public aspects of medicine")]),
+    ("RB", &[(Eng, "Pathology")]),
+    ("RC", &[(Eng, "Internal medicine")]),
+    ("RD", &[(Eng, "Surgery")]),
+    ("RE", &[(Eng, "Ophthalmology")]),
+    ("RF", &[(Eng, "Otorhinolaryngology")]),
+    ("RG", &[(Eng, "Gynecology and obstetrics")]),
+    ("RJ", &[(Eng, "Pediatrics")]),
+    ("RK", &[(Eng, "Dentistry")]),
+    ("RL", &[(Eng, "Dermatology")]),
+    ("RM", &[(Eng, "Therapeutics. Pharmacology")]),
+    ("RS", &[(Eng, "Pharmacy and materia medica")]),
+    ("RT", &[(Eng, "Nursing")]),
+    (
+        "RV",
+        &[(Eng, "Botanic, Thomsonian, and eclectic medicine")],
+    ),
+    ("RX", &[(Eng, "Homeopathy")]),
+    ("RZ", &[(Eng, "Other systems of medicine")]),
+    ("S", &[(Eng, "Agriculture")]),
+    ("SB", &[(Eng, "Plant culture")]),
+    ("SD", &[(Eng, "Forestry")]),
+    ("SF", &[(Eng, "Animal culture")]),
+    ("SH", &[(Eng, "Aquaculture. Fisheries. Angling")]),
+    ("SK", &[(Eng, "Hunting sports")]),
+    ("T", &[(Eng, "Technology")]),
+    ("TA", &[(Eng, "Engineering (General). Civil engineering")]),
+    ("TC", &[(Eng, "Hydraulic engineering. Ocean engineering")]),
+    (
+        "TD",
+        &[(Eng, "Environmental technology. Sanitary engineering")],
+    ),
+    ("TE", &[(Eng, "Highway engineering. Roads and pavements")]),
+    ("TF", &[(Eng, "Railroad engineering and operation")]),
+    ("TG", &[(Eng, "Bridge engineering")]),
+    ("TH", &[(Eng, "Building construction")]),
+    ("TJ", &[(Eng, "Mechanical engineering and machinery")]),
+    (
+        "TK",
+        &[(
+            Eng,
+            "Electrical engineering. Electronics. Nuclear engineering",
+        )],
+    ),
+    (
+        "TL",
+        &[(Eng, "Motor vehicles. Aeronautics. Astronautics")],
+    ),
+    ("TN", &[(Eng, "Mining engineering. Metallurgy")]),
+    ("TP", &[(Eng, "Chemical technology")]),
+    ("TR", &[(Eng, "Photography")]),
+    ("TS", &[(Eng, "Manufactures")]),
+    ("TT", &[(Eng, "Handicrafts. Arts and crafts")]),
+    ("TX", &[(Eng, "Home economics")]),
+    ("U", &[(Eng, "Military Science")]),
+    (
+        "UA",
+        &[(
+            Eng,
+            "Armies: Organization, distribution, military situation",
+        )],
+    ),
+    ("UB", &[(Eng, "Military administration")]),
+    ("UC", &[(Eng, "Maintenance and transportation")]),
+    ("UD", &[(Eng, "Infantry")]),
+    ("UE", &[(Eng, "Cavalry. Armor")]),
+    ("UF", &[(Eng, "Artillery")]),
+    ("UG", &[(Eng, "Military engineering. Air forces")]),
+    ("UH", &[(Eng, "Other services")]),
+    ("V", &[(Eng, "Naval Science")]),
+    (
+        "VA",
+        &[(Eng, "Navies: Organization, distribution, naval situation")],
+    ),
+    ("VB", &[(Eng, "Naval administration")]),
+    ("VC", &[(Eng, "Naval maintenance")]),
+    ("VD", &[(Eng, "Naval seamen")]),
+    ("VE", &[(Eng, "Marines")]),
+    ("VF", &[(Eng, "Naval ordnance")]),
+    ("VG", &[(Eng, "Minor services of navies")]),
+    ("VK", &[(Eng, "Navigation. Merchant marine")]),
+    (
+        "VM",
+        &[(
+            Eng,
+            "Naval architecture. Shipbuilding. Marine engineering",
+        )],
+    ),
+    (
+        "Z",
+        &[(
+            Eng,
+            "Bibliography. Library Science. Information Resources (General)",
+        )],
+    ),
+    ("ZA", &[(Eng, "Information resources (General)")]),
+];
@@ -77,6 +77,8 @@ pub struct Subject {
     graphql(description = "Set of values required to define a new significant discipline or term related to a work"),
     diesel(table_name = subject)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewSubject {
     pub work_id: Uuid,
     pub subject_type: SubjectType,
@@ -90,6 +92,8 @@ pub struct NewSubject {
     graphql(description = "Set of values required to update an existing significant discipline or term related to a work"),
     diesel(table_name = subject, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchSubject {
     pub subject_id: Uuid,
     pub work_id: Uuid,
@@ -132,11 +136,16 @@ impl Default for Subject {
     }
 }
 
+mod bic;
+mod bisac;
+mod crosswalk;
 #[cfg(feature = "backend")]
 pub mod crud;
+mod lcc;
 #[cfg(feature = "backend")]
 mod policy;
 mod thema;
+pub mod vocabulary;
 #[cfg(feature = "backend")]
 pub(crate) use policy::SubjectPolicy;
 #[cfg(test)]
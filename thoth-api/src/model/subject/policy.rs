@@ -1,4 +1,6 @@
-use crate::model::subject::{thema::THEMA_CODES, NewSubject, PatchSubject, Subject, SubjectType};
+use crate::model::subject::{
+    vocabulary::is_valid_subject_code, NewSubject, PatchSubject, Subject, SubjectType,
+};
 use crate::policy::{CreatePolicy, DeletePolicy, MovePolicy, PolicyContext, UpdatePolicy};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -10,7 +12,7 @@ use thoth_errors::{ThothError, ThothResult};
 pub struct SubjectPolicy;
 
 fn check_subject(subject_type: &SubjectType, code: &str) -> ThothResult<()> {
-    if matches!(subject_type, SubjectType::Thema) && THEMA_CODES.binary_search(&code).is_err() {
+    if !is_valid_subject_code(subject_type, code) {
         return Err(ThothError::InvalidSubjectCode {
             input: code.to_string(),
             subject_type: subject_type.to_string(),
@@ -59,13 +61,18 @@ fn test_check_subject() {
     assert!(check_subject(&SubjectType::Bic, "HRQX9").is_ok());
     assert!(check_subject(&SubjectType::Bisac, "BIB004060").is_ok());
     assert!(check_subject(&SubjectType::Thema, "ATXZ1").is_ok());
+    assert!(check_subject(&SubjectType::Lcc, "JA85").is_ok());
+    assert!(check_subject(&SubjectType::Lcc, "QA76.9.D3").is_ok());
 
     // Custom fields: no validity restrictions
     assert!(check_subject(&SubjectType::Custom, "A custom subject").is_ok());
     assert!(check_subject(&SubjectType::Keyword, "keyword").is_ok());
 
-    // Invalid codes for specific schemas: only validate Thema
-    assert!(check_subject(&SubjectType::Bic, "ABCD0").is_ok());
-    assert!(check_subject(&SubjectType::Bisac, "BLA123456").is_ok());
+    // Invalid codes for specific schemas
+    assert!(check_subject(&SubjectType::Bic, "ABCD0").is_err());
+    assert!(check_subject(&SubjectType::Bisac, "BLA123456").is_err());
+    assert!(check_subject(&SubjectType::Bisac, "BIB4060").is_err());
     assert!(check_subject(&SubjectType::Thema, "AHBW").is_err());
+    assert!(check_subject(&SubjectType::Lcc, "IA85").is_err());
+    assert!(check_subject(&SubjectType::Lcc, "ja85").is_err());
 }
@@ -110,6 +110,180 @@ mod helpers {
     }
 }
 
+mod vocabulary {
+    use super::*;
+    use crate::model::language::LanguageCode;
+    use crate::model::subject::vocabulary::{crosswalk, is_valid_subject_code, SubjectHeading};
+
+    fn codes(headings: Vec<SubjectHeading>) -> Vec<String> {
+        headings.into_iter().map(|heading| heading.code).collect()
+    }
+
+    #[test]
+    fn bisac_codes_require_known_heading() {
+        assert!(is_valid_subject_code(&SubjectType::Bisac, "FIC022000"));
+        assert!(is_valid_subject_code(&SubjectType::Bisac, "HIS037080"));
+        assert!(!is_valid_subject_code(&SubjectType::Bisac, "BLA123456"));
+        assert!(!is_valid_subject_code(&SubjectType::Bisac, "fic022000"));
+    }
+
+    #[test]
+    fn bic_codes_follow_section_structure() {
+        assert!(is_valid_subject_code(&SubjectType::Bic, "AAB"));
+        assert!(is_valid_subject_code(&SubjectType::Bic, "1DBKESF"));
+        assert!(!is_valid_subject_code(&SubjectType::Bic, "NAB"));
+        assert!(!is_valid_subject_code(&SubjectType::Bic, "7AB"));
+    }
+
+    #[test]
+    fn lcc_codes_follow_class_structure() {
+        assert!(is_valid_subject_code(&SubjectType::Lcc, "KF"));
+        assert!(is_valid_subject_code(
+            &SubjectType::Lcc,
+            "PR6068.O93 Z5 1990"
+        ));
+        assert!(!is_valid_subject_code(&SubjectType::Lcc, "WA85"));
+        assert!(!is_valid_subject_code(&SubjectType::Lcc, "12AB"));
+        // Subclass letters must exist in the outline
+        assert!(is_valid_subject_code(&SubjectType::Lcc, "DAW1001"));
+        assert!(!is_valid_subject_code(&SubjectType::Lcc, "QZ100"));
+        assert!(!is_valid_subject_code(&SubjectType::Lcc, "IA85"));
+        // Law subclasses are covered by the ranges of the outline
+        assert!(is_valid_subject_code(&SubjectType::Lcc, "KJV4000"));
+        assert!(is_valid_subject_code(&SubjectType::Lcc, "KNQ100"));
+        assert!(!is_valid_subject_code(&SubjectType::Lcc, "KY100"));
+    }
+
+    #[test]
+    fn vocabularies_are_sorted_for_lookup() {
+        use crate::model::subject::{
+            bic::BIC_HEADINGS, bisac::BISAC_HEADINGS, lcc::LCC_CLASSES, thema::THEMA_LABELS,
+        };
+        for entries in [BIC_HEADINGS, BISAC_HEADINGS, LCC_CLASSES, THEMA_LABELS] {
+            assert!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+    }
+
+    #[test]
+    fn heading_rejects_invalid_and_uncontrolled_codes() {
+        assert!(SubjectHeading::new(SubjectType::Thema, "AHBW").is_none());
+        assert!(SubjectHeading::new(SubjectType::Keyword, "keyword").is_none());
+        assert!(SubjectHeading::new(SubjectType::Thema, "ATXZ1").is_some());
+    }
+
+    #[test]
+    fn thema_hierarchy_follows_code_prefixes() {
+        let heading = SubjectHeading::new(SubjectType::Thema, "1DDB-BE-FA").unwrap();
+        assert_eq!(heading.parent().unwrap().code, "1DDB-BE-F");
+        let heading = SubjectHeading::new(SubjectType::Thema, "1DDB-BE-B").unwrap();
+        assert_eq!(heading.parent().unwrap().code, "1DDB");
+
+        let section = SubjectHeading::new(SubjectType::Thema, "A").unwrap();
+        assert!(section.parent().is_none());
+        let children = codes(section.narrower_headings());
+        assert!(children.contains(&"AB".to_string()));
+        assert!(children.iter().all(|code| code.len() == 2));
+    }
+
+    #[test]
+    fn bisac_hierarchy_groups_subjects_under_heading() {
+        let heading = SubjectHeading::new(SubjectType::Bisac, "FIC022000").unwrap();
+        assert_eq!(heading.parent().unwrap().code, "FIC000000");
+        let fiction = heading.parent().unwrap();
+        assert!(fiction.parent().is_none());
+        assert!(codes(fiction.narrower_headings()).contains(&"FIC022000".to_string()));
+    }
+
+    #[test]
+    fn lcc_hierarchy_groups_numbers_under_class() {
+        let heading = SubjectHeading::new(SubjectType::Lcc, "JA85").unwrap();
+        let subclass = heading.parent().unwrap();
+        assert_eq!(subclass.code, "JA");
+        assert_eq!(subclass.parent().unwrap().code, "J");
+
+        let law = SubjectHeading::new(SubjectType::Lcc, "K").unwrap();
+        let ranges = codes(law.narrower_headings());
+        assert!(ranges.contains(&"KJ-KKZ".to_string()));
+        assert!(ranges.contains(&"KF".to_string()));
+    }
+
+    #[test]
+    fn top_level_lists_sections_only() {
+        let sections = codes(SubjectHeading::top_level(SubjectType::Thema));
+        assert!(sections.contains(&"A".to_string()));
+        assert!(sections.contains(&"1".to_string()));
+        assert!(sections.iter().all(|code| code.len() == 1));
+        assert!(SubjectHeading::top_level(SubjectType::Custom).is_empty());
+    }
+
+    #[test]
+    fn labels_fall_back_to_english() {
+        let heading = SubjectHeading::new(SubjectType::Thema, "L").unwrap();
+        assert_eq!(heading.label_for(LanguageCode::Fre), Some("Droit"));
+        assert_eq!(heading.label_for(LanguageCode::Spa), Some("Derecho"));
+        assert_eq!(heading.label_for(LanguageCode::Jpn), Some("Law"));
+        assert_eq!(heading.translations().len(), 4);
+
+        let heading = SubjectHeading::new(SubjectType::Thema, "LNDC").unwrap();
+        assert!(heading.label_for(LanguageCode::Eng).is_none());
+    }
+
+    #[test]
+    fn search_matches_code_prefix_and_label() {
+        let by_code = codes(SubjectHeading::search(SubjectType::Bisac, "fic0"));
+        assert!(by_code.contains(&"FIC000000".to_string()));
+        assert!(by_code.iter().all(|code| code.starts_with("FIC")));
+
+        let by_label = codes(SubjectHeading::search(SubjectType::Thema, "philosophie"));
+        assert_eq!(by_label, vec!["Q".to_string()]);
+    }
+
+    #[test]
+    fn crosswalk_maps_thema_via_nearest_ancestor() {
+        assert_eq!(
+            crosswalk(&SubjectType::Thema, "FF", &SubjectType::Bisac),
+            Some("FIC022000")
+        );
+        assert_eq!(
+            crosswalk(&SubjectType::Thema, "FFD", &SubjectType::Bisac),
+            Some("FIC022000")
+        );
+        assert_eq!(
+            crosswalk(&SubjectType::Thema, "ATFD", &SubjectType::Bic),
+            Some("APF")
+        );
+        // No BIC equivalent for performing arts: fall back to the arts section
+        assert_eq!(
+            crosswalk(&SubjectType::Thema, "ATD", &SubjectType::Bic),
+            Some("A")
+        );
+        assert_eq!(
+            crosswalk(&SubjectType::Thema, "ATD", &SubjectType::Lcc),
+            None
+        );
+        assert_eq!(
+            crosswalk(&SubjectType::Thema, "AHBW", &SubjectType::Bic),
+            None
+        );
+    }
+
+    #[test]
+    fn crosswalk_maps_between_bic_and_bisac() {
+        assert_eq!(
+            crosswalk(&SubjectType::Bisac, "FIC022010", &SubjectType::Thema),
+            Some("F")
+        );
+        assert_eq!(
+            crosswalk(&SubjectType::Bisac, "FIC022000", &SubjectType::Bic),
+            Some("FF")
+        );
+        assert_eq!(
+            crosswalk(&SubjectType::Bic, "HBJD", &SubjectType::Bisac),
+            Some("HIS000000")
+        );
+    }
+}
+
 #[cfg(feature = "backend")]
 mod policy {
     use super::*;
@@ -1,3 +1,6 @@
+use super::vocabulary::Labels;
+use crate::model::language::LanguageCode::{Eng, Fre, Ger, Spa};
+
 pub(crate) static THEMA_CODES: &[&str] = &[
     "1",
     "1A",
@@ -9187,3 +9190,275 @@ pub(crate) static THEMA_CODES: &[&str] = &[
     "YZSN",
     "YZSP",
 ];
+
+/// Labels for the Thema sections and qualifier groups, in English, French, Spanish and German.
+/// Codes below the top level are identified by code alone.
+pub(crate) static THEMA_LABELS: &[(&str, Labels)] = &[
+    (
+        "1",
+        &[
+            (Eng, "Place qualifiers"),
+            (Fre, "Qualificatifs géographiques"),
+            (Spa, "Calificadores de lugar"),
+            (Ger, "Orts-Qualifier"),
+        ],
+    ),
+    (
+        "2",
+        &[
+            (Eng, "Language qualifiers"),
+            (Fre, "Qualificatifs de langue"),
+            (Spa, "Calificadores de lengua"),
+            (Ger, "Sprach-Qualifier"),
+        ],
+    ),
+    (
+        "3",
+        &[
+            (Eng, "Time period qualifiers"),
+            (Fre, "Qualificatifs de période"),
+            (Spa, "Calificadores de período"),
+            (Ger, "Zeitraum-Qualifier"),
+        ],
+    ),
+    (
+        "4",
+        &[
+            (Eng, "Educational purpose qualifiers"),
+            (Fre, "Qualificatifs éducatifs"),
+            (Spa, "Calificadores de finalidad educativa"),
+            (Ger, "Qualifier Bildungszweck"),
+        ],
+    ),
+    (
+        "5",
+        &[
+            (Eng, "Interest qualifiers"),
+            (Fre, "Qualificatifs d'intérêt"),
+            (Spa, "Calificadores de interés"),
+            (Ger, "Interessen-Qualifier"),
+        ],
+    ),
+    (
+        "6",
+        &[
+            (Eng, "Style qualifiers"),
+            (Fre, "Qualificatifs de style"),
+            (Spa, "Calificadores de estilo"),
+            (Ger, "Stil-Qualifier"),
+        ],
+    ),
+    (
+        "A",
+        &[
+            (Eng, "The Arts"),
+            (Fre, "Arts"),
+            (Spa, "Artes"),
+            (Ger, "Künste"),
+        ],
+    ),
+    (
+        "C",
+        &[
+            (Eng, "Language and Linguistics"),
+            (Fre, "Langue et linguistique"),
+            (Spa, "Lengua y lingüística"),
+            (Ger, "Sprache und Linguistik"),
+        ],
+    ),
+    (
+        "D",
+        &[
+            (Eng, "Biography, Literature and Literary studies"),
+            (Fre, "Biographie, littérature et études littéraires"),
+            (Spa, "Biografía, literatura y estudios literarios"),
+            (Ger, "Biografien, Literatur und Literaturwissenschaft"),
+        ],
+    ),
+    (
+        "F",
+        &[
+            (Eng, "Fiction and Related items"),
+            (Fre, "Fiction et sujets apparentés"),
+            (Spa, "Ficción y temas afines"),
+            (Ger, "Belletristik und verwandte Bereiche"),
+        ],
+    ),
+    (
+        "G",
+        &[
+            (Eng, "Reference, Information and Interdisciplinary subjects"),
+            (
+                Fre,
+                "Ouvrages de référence, information et sujets interdisciplinaires",
+            ),
+            (
+                Spa,
+                "Obras de referencia, información y materias interdisciplinarias",
+            ),
+            (
+                Ger,
+                "Nachschlagewerke, Information und interdisziplinäre Themen",
+            ),
+        ],
+    ),
+    (
+        "J",
+        &[
+            (Eng, "Society and Social Sciences"),
+            (Fre, "Société et sciences sociales"),
+            (Spa, "Sociedad y ciencias sociales"),
+            (Ger, "Gesellschaft und Sozialwissenschaften"),
+        ],
+    ),
+    (
+        "K",
+        &[
+            (Eng, "Economics, Finance, Business and Management"),
+            (Fre, "Économie, finance, entreprise et gestion"),
+            (Spa, "Economía, finanzas, empresa y gestión"),
+            (Ger, "Wirtschaft, Finanzen, Business und Management"),
+        ],
+    ),
+    (
+        "L",
+        &[
+            (Eng, "Law"),
+            (Fre, "Droit"),
+            (Spa, "Derecho"),
+            (Ger, "Recht"),
+        ],
+    ),
+    (
+        "M",
+        &[
+            (Eng, "Medicine and Nursing"),
+            (Fre, "Médecine et soins infirmiers"),
+            (Spa, "Medicina y enfermería"),
+            (Ger, "Medizin und Krankenpflege"),
+        ],
+    ),
+    (
+        "N",
+        &[
+            (Eng, "History and Archaeology"),
+            (Fre, "Histoire et archéologie"),
+            (Spa, "Historia y arqueología"),
+            (Ger, "Geschichte und Archäologie"),
+        ],
+    ),
+    (
+        "P",
+        &[
+            (Eng, "Mathematics and Science"),
+            (Fre, "Mathématiques et sciences"),
+            (Spa, "Matemáticas y ciencia"),
+            (Ger, "Mathematik und Naturwissenschaften"),
+        ],
+    ),
+    (
+        "Q",
+        &[
+            (Eng, "Philosophy and Religion"),
+            (Fre, "Philosophie et religion"),
+            (Spa, "Filosofía y religión"),
+            (Ger, "Philosophie und Religion"),
+        ],
+    ),
+    (
+        "R",
+        &[
+            (Eng, "Earth Sciences, Geography, Environment, Planning"),
+            (
+                Fre,
+                "Sciences de la Terre, géographie, environnement, aménagement",
+            ),
+            (
+                Spa,
+                "Ciencias de la Tierra, geografía, medio ambiente, planificación",
+            ),
+            (Ger, "Geowissenschaften, Geographie, Umwelt, Planung"),
+        ],
+    ),
+    (
+        "S",
+        &[
+            (Eng, "Sports and Active outdoor recreation"),
+            (Fre, "Sports et activités de plein air"),
+            (Spa, "Deportes y actividades al aire libre"),
+            (Ger, "Sport und Aktivitäten im Freien"),
+        ],
+    ),
+    (
+        "T",
+        &[
+            (
+                Eng,
+                "Technology, Engineering, Agriculture, Industrial processes",
+            ),
+            (
+                Fre,
+                "Technologie, ingénierie, agriculture, procédés industriels",
+            ),
+            (
+                Spa,
+                "Tecnología, ingeniería, agricultura, procesos industriales",
+            ),
+            (
+                Ger,
+                "Technologie, Ingenieurwesen, Landwirtschaft, industrielle Prozesse",
+            ),
+        ],
+    ),
+    (
+        "U",
+        &[
+            (Eng, "Computing and Information Technology"),
+            (Fre, "Informatique et technologies de l'information"),
+            (Spa, "Informática y tecnologías de la información"),
+            (Ger, "Computing und Informationstechnologie"),
+        ],
+    ),
+    (
+        "V",
+        &[
+            (Eng, "Health, Relationships and Personal development"),
+            (Fre, "Santé, relations et développement personnel"),
+            (Spa, "Salud, relaciones y desarrollo personal"),
+            (
+                Ger,
+                "Gesundheit, Beziehungen und Persönlichkeitsentwicklung",
+            ),
+        ],
+    ),
+    (
+        "W",
+        &[
+            (Eng, "Lifestyle, Hobbies and Leisure"),
+            (Fre, "Mode de vie, loisirs et passe-temps"),
+            (Spa, "Estilo de vida, aficiones y ocio"),
+            (Ger, "Lifestyle, Hobbys und Freizeit"),
+        ],
+    ),
+    (
+        "X",
+        &[
+            (Eng, "Graphic novels, Comic books, Cartoons"),
+            (
+                Fre,
+                "Romans graphiques, bandes dessinées, dessins humoristiques",
+            ),
+            (Spa, "Novelas gráficas, cómics, historietas"),
+            (Ger, "Graphic Novels, Comics, Cartoons"),
+        ],
+    ),
+    (
+        "Y",
+        &[
+            (Eng, "Children's, Teenage and Educational"),
+            (Fre, "Jeunesse et éducation"),
+            (Spa, "Infantil, juvenil y didáctico"),
+            (Ger, "Kinder, Jugendliche und Bildung"),
+        ],
+    ),
+];
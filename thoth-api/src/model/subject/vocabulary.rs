@@ -0,0 +1,272 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::bic::BIC_HEADINGS;
+use super::bisac::BISAC_HEADINGS;
+use super::crosswalk::{CrosswalkEntry, SUBJECT_CROSSWALK};
+use super::lcc::LCC_CLASSES;
+use super::thema::{THEMA_CODES, THEMA_LABELS};
+use super::SubjectType;
+use crate::model::language::LanguageCode;
+
+/// Labels of a vocabulary entry, at most one per language
+pub(crate) type Labels = &'static [(LanguageCode, &'static str)];
+
+/// A code within one of the controlled subject vocabularies (Thema, BIC, BISAC or LCC)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectHeading {
+    pub subject_type: SubjectType,
+    pub code: String,
+}
+
+/// The name of a subject heading in a given language
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubjectHeadingLabel {
+    pub language: LanguageCode,
+    pub text: &'static str,
+}
+
+lazy_static! {
+    // Subject codes under sections A-Y (there are no I, N, O, Q, S or X sections),
+    // optionally ending in 9, or qualifier codes under groups 1-6
+    static ref BIC_RE: Regex =
+        Regex::new(r"^(?:[A-HJ-MPRT-WY][A-Z]{0,5}9?|[1-6][A-Z0-9]{0,7})$").unwrap();
+    // Three-letter heading followed by a six-digit subject number
+    static ref BISAC_RE: Regex = Regex::new(r"^[A-Z]{3}[0-9]{6}$").unwrap();
+    // Class and subclass letters, optional class number (or range of numbers) and optional
+    // cutter numbers, e.g. "QA76.9.D3"
+    static ref LCC_RE: Regex = Regex::new(
+        r"^([A-Z]{1,3})(?:[0-9]+(?:\.[0-9]+)?(?:-[0-9]+(?:\.[0-9]+)?)?)?(?:\s*\.?[A-Z][0-9]+[A-Z0-9.\s]*)?$"
+    )
+    .unwrap();
+}
+
+/// Check that `code` is a valid code in the vocabulary of `subject_type`.
+///
+/// Thema codes must exist in the Thema list, and LCC codes must start with a class or subclass
+/// of the LCC outline. BIC and BISAC codes are checked against the structure of their schemes
+/// (and, for BISAC, a known main heading). Custom subjects and keywords are free text.
+pub fn is_valid_subject_code(subject_type: &SubjectType, code: &str) -> bool {
+    match subject_type {
+        SubjectType::Thema => THEMA_CODES.binary_search(&code).is_ok(),
+        SubjectType::Bic => BIC_RE.is_match(code),
+        SubjectType::Bisac => {
+            BISAC_RE.is_match(code)
+                && find_labels(&SubjectType::Bisac, &format!("{}000000", &code[..3])).is_some()
+        }
+        SubjectType::Lcc => {
+            find_labels(&SubjectType::Lcc, code).is_some()
+                || LCC_RE
+                    .captures(code)
+                    .is_some_and(|captures| is_lcc_subclass(&captures[1]))
+        }
+        SubjectType::Custom | SubjectType::Keyword => true,
+    }
+}
+
+/// Whether `letters` is a class or subclass of the LCC outline, or falls within one of its
+/// ranges of subclasses
+fn is_lcc_subclass(letters: &str) -> bool {
+    LCC_CLASSES
+        .iter()
+        .any(|(code, _)| match code.split_once('-') {
+            Some((first, last)) => first <= letters && letters <= last,
+            None => *code == letters,
+        })
+}
+
+fn labelled_entries(subject_type: &SubjectType) -> &'static [(&'static str, Labels)] {
+    match subject_type {
+        SubjectType::Thema => THEMA_LABELS,
+        SubjectType::Bic => BIC_HEADINGS,
+        SubjectType::Bisac => BISAC_HEADINGS,
+        SubjectType::Lcc => LCC_CLASSES,
+        SubjectType::Custom | SubjectType::Keyword => &[],
+    }
+}
+
+fn find_labels(subject_type: &SubjectType, code: &str) -> Option<Labels> {
+    let entries = labelled_entries(subject_type);
+    entries
+        .binary_search_by(|(entry_code, _)| (*entry_code).cmp(code))
+        .ok()
+        .map(|i| entries[i].1)
+}
+
+/// Codes which may be browsed in a vocabulary: the full Thema list, or the labelled headings
+/// of the other schemes
+fn browsable_codes(subject_type: &SubjectType) -> Vec<&'static str> {
+    match subject_type {
+        SubjectType::Thema => THEMA_CODES.to_vec(),
+        _ => labelled_entries(subject_type)
+            .iter()
+            .map(|(code, _)| *code)
+            .collect(),
+    }
+}
+
+/// Parent of a code already known to be valid. Thema and BIC are hierarchical by prefix,
+/// BISAC subjects sit under their main heading, and LCC numbers sit under their (sub)class.
+fn parent_code(subject_type: &SubjectType, code: &str) -> Option<String> {
+    match subject_type {
+        SubjectType::Thema => (1..code.len())
+            .rev()
+            .map(|len| &code[..len])
+            .find(|prefix| THEMA_CODES.binary_search(prefix).is_ok())
+            .map(ToString::to_string),
+        SubjectType::Bic => (code.len() > 1).then(|| code[..code.len() - 1].to_string()),
+        SubjectType::Bisac => {
+            let heading = format!("{}000000", &code[..3]);
+            (heading != code).then_some(heading)
+        }
+        // Ranges of subclasses in the outline sit directly under their class
+        SubjectType::Lcc if code.contains('-') && find_labels(subject_type, code).is_some() => {
+            Some(code[..1].to_string())
+        }
+        SubjectType::Lcc => {
+            let letters = code
+                .find(|c: char| !c.is_ascii_uppercase())
+                .map_or(code, |end| &code[..end]);
+            if letters.len() < code.len() {
+                Some(letters.to_string())
+            } else if letters.len() > 1 {
+                Some(letters[..1].to_string())
+            } else {
+                None
+            }
+        }
+        SubjectType::Custom | SubjectType::Keyword => None,
+    }
+}
+
+fn crosswalk_code(entry: &CrosswalkEntry, subject_type: &SubjectType) -> Option<&'static str> {
+    match subject_type {
+        SubjectType::Thema => Some(entry.thema),
+        SubjectType::Bic => entry.bic,
+        SubjectType::Bisac => entry.bisac,
+        _ => None,
+    }
+}
+
+/// Find the equivalent of a Thema, BIC or BISAC code in another of those schemes.
+///
+/// Codes without a direct equivalent are mapped via their closest ancestor which has one,
+/// so the result may be broader than the source code.
+pub fn crosswalk(from: &SubjectType, code: &str, to: &SubjectType) -> Option<&'static str> {
+    if from == to || !is_valid_subject_code(from, code) {
+        return None;
+    }
+    let mut current = Some(code.to_string());
+    while let Some(code) = current {
+        let equivalent = SUBJECT_CROSSWALK
+            .iter()
+            .filter(|entry| crosswalk_code(entry, from) == Some(code.as_str()))
+            .find_map(|entry| crosswalk_code(entry, to));
+        if equivalent.is_some() {
+            return equivalent;
+        }
+        current = parent_code(from, &code);
+    }
+    None
+}
+
+impl SubjectHeading {
+    /// Look up a code in the vocabulary of `subject_type`, returning `None` if the code is
+    /// invalid or the subject type has no controlled vocabulary
+    pub fn new(subject_type: SubjectType, code: &str) -> Option<Self> {
+        match subject_type {
+            SubjectType::Custom | SubjectType::Keyword => None,
+            _ => is_valid_subject_code(&subject_type, code).then(|| SubjectHeading {
+                subject_type,
+                code: code.to_string(),
+            }),
+        }
+    }
+
+    /// Headings at the root of the vocabulary of `subject_type`
+    pub fn top_level(subject_type: SubjectType) -> Vec<Self> {
+        browsable_codes(&subject_type)
+            .into_iter()
+            .filter(|code| parent_code(&subject_type, code).is_none())
+            .map(|code| SubjectHeading {
+                subject_type,
+                code: code.to_string(),
+            })
+            .collect()
+    }
+
+    /// Headings whose code starts with `query`, or whose label in any language contains it,
+    /// ignoring case
+    pub fn search(subject_type: SubjectType, query: &str) -> Vec<Self> {
+        let code_query = query.trim().to_uppercase();
+        let label_query = query.trim().to_lowercase();
+        browsable_codes(&subject_type)
+            .into_iter()
+            .filter(|code| {
+                code.starts_with(&code_query)
+                    || find_labels(&subject_type, code).is_some_and(|labels| {
+                        labels
+                            .iter()
+                            .any(|(_, text)| text.to_lowercase().contains(&label_query))
+                    })
+            })
+            .map(|code| SubjectHeading {
+                subject_type,
+                code: code.to_string(),
+            })
+            .collect()
+    }
+
+    pub fn translations(&self) -> Vec<SubjectHeadingLabel> {
+        find_labels(&self.subject_type, &self.code)
+            .unwrap_or_default()
+            .iter()
+            .map(|(language, text)| SubjectHeadingLabel {
+                language: *language,
+                text,
+            })
+            .collect()
+    }
+
+    /// Label in the requested language, falling back to English
+    pub fn label_for(&self, language: LanguageCode) -> Option<&'static str> {
+        let labels = find_labels(&self.subject_type, &self.code)?;
+        labels
+            .iter()
+            .find(|(label_language, _)| *label_language == language)
+            .or_else(|| {
+                labels
+                    .iter()
+                    .find(|(label_language, _)| *label_language == LanguageCode::Eng)
+            })
+            .map(|(_, text)| *text)
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        parent_code(&self.subject_type, &self.code).map(|code| SubjectHeading {
+            subject_type: self.subject_type,
+            code,
+        })
+    }
+
+    pub fn narrower_headings(&self) -> Vec<Self> {
+        browsable_codes(&self.subject_type)
+            .into_iter()
+            .filter(|code| {
+                parent_code(&self.subject_type, code).as_deref() == Some(self.code.as_str())
+            })
+            .map(|code| SubjectHeading {
+                subject_type: self.subject_type,
+                code: code.to_string(),
+            })
+            .collect()
+    }
+
+    /// Nearest equivalent heading in another scheme, according to the subject crosswalk
+    pub fn equivalent(&self, subject_type: SubjectType) -> Option<Self> {
+        crosswalk(&self.subject_type, &self.code, &subject_type).map(|code| SubjectHeading {
+            subject_type,
+            code: code.to_string(),
+        })
+    }
+}
@@ -225,6 +225,7 @@ END $$;
             default_currency: None,
             default_place: None,
             default_locale: None,
+            reject_invalid_files: Some(false),
         };
 
         Imprint::create(pool, &new_imprint).expect("Failed to create imprint in DB")
@@ -300,6 +301,7 @@ END $$;
             first_page: None,
             last_page: None,
             page_interval: None,
+            expose_latest_file_url: Some(true),
         };
 
         Work::create(pool, &new_work).expect("Failed to create work in DB")
@@ -341,6 +343,9 @@ END $$;
             accessibility_additional_standard: None,
             accessibility_exception: None,
             accessibility_report_url: None,
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         };
 
         Publication::create(pool, &new_publication).expect("Failed to create publication in DB")
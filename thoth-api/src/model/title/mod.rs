@@ -64,7 +64,8 @@ pub struct Title {
     graphql(description = "Set of values required to define a new work's title"),
     diesel(table_name = work_title)
 )]
-#[derive(Default)]
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewTitle {
     pub work_id: Uuid,
     pub locale_code: LocaleCode,
@@ -80,6 +81,8 @@ pub struct NewTitle {
     graphql(description = "Set of values required to update an existing work's title"),
     diesel(table_name = work_title, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchTitle {
     pub title_id: Uuid,
     pub work_id: Uuid,
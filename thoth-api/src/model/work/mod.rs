@@ -217,6 +217,7 @@ pub struct Work {
     pub last_page: Option<String>,
     pub page_interval: Option<String>,
     pub updated_at_with_relations: Timestamp,
+    pub expose_latest_file_url: bool,
 }
 #[cfg_attr(
     feature = "backend",
@@ -224,6 +225,8 @@ pub struct Work {
     graphql(description = "Set of values required to define a new written text that can be published"),
     diesel(table_name = work)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewWork {
     pub work_type: WorkType,
     pub work_status: WorkStatus,
@@ -254,6 +257,8 @@ pub struct NewWork {
     pub first_page: Option<String>,
     pub last_page: Option<String>,
     pub page_interval: Option<String>,
+    /// Defaults to `true` when omitted
+    pub expose_latest_file_url: Option<bool>,
 }
 
 #[cfg_attr(
@@ -262,6 +267,8 @@ pub struct NewWork {
     graphql(description = "Set of values required to update an existing written text that can be published"),
     diesel(table_name = work, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchWork {
     pub work_id: Uuid,
     pub work_type: WorkType,
@@ -293,6 +300,9 @@ pub struct PatchWork {
     pub first_page: Option<String>,
     pub last_page: Option<String>,
     pub page_interval: Option<String>,
+    /// Left unchanged when omitted
+    #[cfg_attr(feature = "backend", diesel(treat_none_as_null = false))]
+    pub expose_latest_file_url: Option<bool>,
 }
 
 #[cfg_attr(feature = "backend", derive(diesel::Queryable))]
@@ -429,6 +439,7 @@ impl From<Work> for PatchWork {
             first_page: w.first_page,
             last_page: w.last_page,
             page_interval: w.page_interval,
+            expose_latest_file_url: Some(w.expose_latest_file_url),
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::model::work::{NewWork, PatchWork, Work, WorkProperties, WorkType};
+use crate::model::work_date::{validate_work_status_dates, WorkDate, WorkDateProperties};
 use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy, UserAccess};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -7,6 +8,7 @@ use thoth_errors::{ThothError, ThothResult};
 /// This policy layer enforces:
 /// - authentication
 /// - publisher membership derived from the entity / input via `PublisherId`
+/// - status transitions and publication dates consistent with the work's lifecycle dates
 pub struct WorkPolicy;
 
 impl CreatePolicy<NewWork> for WorkPolicy {
@@ -43,6 +45,19 @@ impl UpdatePolicy<Work, PatchWork> for WorkPolicy {
 
         patch.validate()?;
 
+        if patch.work_status != current.work_status
+            || patch.publication_date != current.publication_date
+        {
+            let work_dates = WorkDate::all_for_work(ctx.db(), &current.work_id)?;
+            if patch.work_status != current.work_status {
+                let date_types: Vec<_> = work_dates.iter().map(|d| d.date_type).collect();
+                validate_work_status_dates(&patch.work_status, &date_types)?;
+            }
+            for work_date in &work_dates {
+                work_date.validate(patch.publication_date.as_ref())?;
+            }
+        }
+
         if current.is_published() && !patch.is_published() && !user.is_superuser() {
             return Err(ThothError::ThothSetWorkStatusError);
         }
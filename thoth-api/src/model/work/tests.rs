@@ -37,6 +37,7 @@ fn make_work() -> Work {
         last_page: None,
         page_interval: None,
         updated_at_with_relations: Default::default(),
+        expose_latest_file_url: true,
     }
 }
 
@@ -569,6 +570,7 @@ mod policy {
         test_superuser, test_user_with_role,
     };
     use crate::model::work::policy::WorkPolicy;
+    use crate::model::work_date::{NewWorkDate, WorkDate, WorkDateType};
     use crate::model::Crud;
     use crate::model::Isbn;
     use crate::policy::{CreatePolicy, DeletePolicy, Role, UpdatePolicy};
@@ -606,6 +608,7 @@ mod policy {
             first_page: work.first_page.clone(),
             last_page: work.last_page.clone(),
             page_interval: work.page_interval.clone(),
+            expose_latest_file_url: Some(work.expose_latest_file_url),
         }
     }
 
@@ -640,6 +643,7 @@ mod policy {
             first_page: None,
             last_page: None,
             page_interval: None,
+            expose_latest_file_url: Some(true),
         }
     }
 
@@ -660,6 +664,9 @@ mod policy {
             accessibility_additional_standard: None,
             accessibility_exception: None,
             accessibility_report_url: None,
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         }
     }
 
@@ -729,6 +736,7 @@ mod policy {
             first_page: None,
             last_page: None,
             page_interval: None,
+            expose_latest_file_url: Some(true),
         };
 
         assert!(WorkPolicy::can_create(&ctx, &new_work, ()).is_ok());
@@ -877,6 +885,77 @@ mod policy {
         assert!(matches!(result, Err(ThothError::ThothSetWorkStatusError)));
     }
 
+    #[test]
+    fn crud_policy_requires_out_of_print_date_when_withdrawing_work() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let mut new_work = make_new_work(imprint.imprint_id);
+        new_work.work_status = WorkStatus::Active;
+        new_work.publication_date = NaiveDate::from_ymd_opt(2020, 1, 1);
+        let work = Work::create(pool.as_ref(), &new_work).expect("Failed to create work");
+
+        let mut patch = make_patch_from_work(&work);
+        patch.work_status = WorkStatus::Withdrawn;
+        patch.withdrawn_date = NaiveDate::from_ymd_opt(2024, 1, 1);
+
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let lifecycle_user = user_with_roles(
+            "work-user",
+            &org_id,
+            &[Role::PublisherUser, Role::WorkLifecycle],
+        );
+        let ctx = test_context_with_user(pool.clone(), lifecycle_user);
+
+        let result = WorkPolicy::can_update(&ctx, &work, &patch, ());
+        assert!(matches!(
+            result,
+            Err(ThothError::WorkStatusMissingDateError(_, _))
+        ));
+
+        WorkDate::create(
+            pool.as_ref(),
+            &NewWorkDate {
+                work_id: work.work_id,
+                date_type: WorkDateType::OutOfPrint,
+                date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            },
+        )
+        .expect("Failed to create out-of-print date");
+        assert!(WorkPolicy::can_update(&ctx, &work, &patch, ()).is_ok());
+    }
+
+    #[test]
+    fn crud_policy_rejects_publication_date_before_announcement() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        WorkDate::create(
+            pool.as_ref(),
+            &NewWorkDate {
+                work_id: work.work_id,
+                date_type: WorkDateType::PublicAnnouncement,
+                date: NaiveDate::from_ymd_opt(2024, 6, 1).unwrap(),
+            },
+        )
+        .expect("Failed to create announcement date");
+
+        let mut patch = make_patch_from_work(&work);
+        patch.publication_date = NaiveDate::from_ymd_opt(2024, 1, 1);
+
+        let superuser = test_superuser("work-superuser");
+        let ctx = test_context_with_user(pool.clone(), superuser);
+
+        let result = WorkPolicy::can_update(&ctx, &work, &patch, ());
+        assert!(matches!(result, Err(ThothError::WorkDateError(_))));
+    }
+
     #[test]
     fn crud_policy_prevents_non_superuser_from_deleting_published_work() {
         let (_guard, pool) = setup_test_db();
@@ -913,6 +992,7 @@ mod policy {
             first_page: None,
             last_page: None,
             page_interval: None,
+            expose_latest_file_url: Some(true),
         };
 
         let work = Work::create(pool.as_ref(), &new_work).expect("Failed to create work");
@@ -984,6 +1064,7 @@ mod crud {
             first_page: None,
             last_page: None,
             page_interval: None,
+            expose_latest_file_url: Some(true),
         }
     }
 
@@ -1046,6 +1127,7 @@ mod crud {
             first_page: work.first_page.clone(),
             last_page: work.last_page.clone(),
             page_interval: work.page_interval.clone(),
+            expose_latest_file_url: Some(work.expose_latest_file_url),
         };
 
         let ctx = test_context(pool.clone(), "test-user");
@@ -1058,6 +1140,35 @@ mod crud {
         assert!(Work::from_id(pool.as_ref(), &deleted.work_id).is_err());
     }
 
+    #[test]
+    fn crud_omitted_expose_latest_file_url_uses_default_then_current_value() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let new_work = NewWork {
+            expose_latest_file_url: None,
+            ..make_new_work(imprint.imprint_id)
+        };
+        let work = Work::create(pool.as_ref(), &new_work).expect("Failed to create work");
+        assert!(work.expose_latest_file_url);
+
+        let ctx = test_context(pool.clone(), "test-user");
+        let patch = PatchWork {
+            expose_latest_file_url: Some(false),
+            ..PatchWork::from(work.clone())
+        };
+        let work = work.update(&ctx, &patch).expect("Failed to update work");
+        assert!(!work.expose_latest_file_url);
+
+        let patch = PatchWork {
+            expose_latest_file_url: None,
+            ..PatchWork::from(work.clone())
+        };
+        let work = work.update(&ctx, &patch).expect("Failed to update work");
+        assert!(!work.expose_latest_file_url);
+    }
+
     #[test]
     fn crud_all_respects_limit_and_offset() {
         let (_guard, pool) = setup_test_db();
@@ -1582,6 +1693,9 @@ mod crud {
                 accessibility_additional_standard: None,
                 accessibility_exception: None,
                 accessibility_report_url: None,
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
             },
         )
         .expect("Failed to create publication");
@@ -0,0 +1,145 @@
+use super::{
+    NewWorkDate, NewWorkDateHistory, PatchWorkDate, WorkDate, WorkDateField, WorkDateHistory,
+    WorkDateOrderBy, WorkDateType,
+};
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{work_date, work_date_history};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl WorkDate {
+    /// All lifecycle dates recorded for a work
+    pub fn all_for_work(db: &crate::db::PgPool, work_id: &Uuid) -> ThothResult<Vec<WorkDate>> {
+        use crate::schema::work_date::dsl;
+
+        let mut connection = db.get()?;
+        dsl::work_date
+            .filter(dsl::work_id.eq(work_id))
+            .order(dsl::date.asc())
+            .load::<WorkDate>(&mut connection)
+            .map_err(Into::into)
+    }
+}
+
+impl Crud for WorkDate {
+    type NewEntity = NewWorkDate;
+    type PatchEntity = PatchWorkDate;
+    type OrderByEntity = WorkDateOrderBy;
+    type FilterParameter1 = WorkDateType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+    type FilterParameter4 = ();
+
+    fn pk(&self) -> Uuid {
+        self.work_date_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        date_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<Vec<WorkDate>> {
+        use crate::schema::work_date::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::work_date
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::work_date::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            WorkDateField::WorkDateId => {
+                apply_directional_order!(query, order.direction, order, dsl::work_date_id)
+            }
+            WorkDateField::WorkId => {
+                apply_directional_order!(query, order.direction, order, dsl::work_id)
+            }
+            WorkDateField::DateType => {
+                apply_directional_order!(query, order.direction, order, dsl::date_type)
+            }
+            WorkDateField::Date => {
+                apply_directional_order!(query, order.direction, order, dsl::date)
+            }
+            WorkDateField::CreatedAt => {
+                apply_directional_order!(query, order.direction, order, dsl::created_at)
+            }
+            WorkDateField::UpdatedAt => {
+                apply_directional_order!(query, order.direction, order, dsl::updated_at)
+            }
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if !date_types.is_empty() {
+            query = query.filter(dsl::date_type.eq_any(date_types));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<WorkDate>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        publishers: Vec<Uuid>,
+        date_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+        _: Option<Self::FilterParameter4>,
+    ) -> ThothResult<i32> {
+        use crate::schema::work_date::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::work_date
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .into_boxed();
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if !date_types.is_empty() {
+            query = query.filter(dsl::date_type.eq_any(date_types));
+        }
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    crud_methods!(work_date::table, work_date::dsl::work_date);
+}
+
+publisher_id_impls!(WorkDate, NewWorkDate, PatchWorkDate, |s, db| {
+    crate::model::work::Work::from_id(db, &s.work_id)?.publisher_id(db)
+});
+
+impl HistoryEntry for WorkDate {
+    type NewHistoryEntity = NewWorkDateHistory;
+
+    fn new_history_entry(&self, user_id: &str) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            work_date_id: self.work_date_id,
+            user_id: user_id.to_string(),
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewWorkDateHistory {
+    type MainEntity = WorkDateHistory;
+
+    db_insert!(work_date_history::table);
+}
@@ -0,0 +1,266 @@
+use chrono::naive::NaiveDate;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::graphql::types::inputs::Direction;
+use crate::model::work::WorkStatus;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::work_date;
+#[cfg(feature = "backend")]
+use crate::schema::work_date_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel_derive_enum::DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Event in the lifecycle of a work, other than its publication or withdrawal (ONIX code list 163)"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::WorkDateType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum WorkDateType {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "public_announcement",
+        graphql(description = "Date when the work is first announced to the public")
+    )]
+    #[default]
+    PublicAnnouncement,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "trade_announcement",
+        graphql(description = "Date when the work is first announced to the book trade")
+    )]
+    TradeAnnouncement,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "on_sale",
+        graphql(
+            description = "Date from which the work may be sold to the public, lifting any sales embargo"
+        )
+    )]
+    OnSale,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "first_publication",
+        graphql(
+            description = "Date when the work was first published, where this work is a new edition or reissue"
+        )
+    )]
+    FirstPublication,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "last_reprint",
+        graphql(description = "Date when the work was most recently reprinted")
+    )]
+    LastReprint,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "last_reissue",
+        graphql(description = "Date when the work was most recently reissued")
+    )]
+    LastReissue,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "out_of_print",
+        graphql(description = "Date when the work was declared out of print")
+    )]
+    OutOfPrint,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting work dates list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WorkDateField {
+    WorkDateId,
+    WorkId,
+    DateType,
+    #[default]
+    Date,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkDate {
+    pub work_date_id: Uuid,
+    pub work_id: Uuid,
+    pub date_type: WorkDateType,
+    pub date: NaiveDate,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::Insertable),
+    graphql(description = "Set of values required to define a new lifecycle date of a work"),
+    diesel(table_name = work_date)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewWorkDate {
+    pub work_id: Uuid,
+    pub date_type: WorkDateType,
+    pub date: NaiveDate,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, diesel::AsChangeset),
+    graphql(description = "Set of values required to update an existing lifecycle date of a work"),
+    diesel(table_name = work_date, treat_none_as_null = true)
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchWorkDate {
+    pub work_date_id: Uuid,
+    pub work_id: Uuid,
+    pub date_type: WorkDateType,
+    pub date: NaiveDate,
+}
+
+#[cfg_attr(feature = "backend", derive(diesel::Queryable))]
+pub struct WorkDateHistory {
+    pub work_date_history_id: Uuid,
+    pub work_date_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(diesel::Insertable),
+    diesel(table_name = work_date_history)
+)]
+pub struct NewWorkDateHistory {
+    pub work_date_id: Uuid,
+    pub user_id: String,
+    pub data: serde_json::Value,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "Field and order to use when sorting work dates list")
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorkDateOrderBy {
+    pub field: WorkDateField,
+    pub direction: Direction,
+}
+
+impl WorkDateType {
+    /// Whether the event must happen on or before the publication date of the work
+    pub fn precedes_publication(&self) -> bool {
+        matches!(
+            self,
+            WorkDateType::PublicAnnouncement
+                | WorkDateType::TradeAnnouncement
+                | WorkDateType::FirstPublication
+        )
+    }
+
+    /// Whether the event must happen on or after the publication date of the work
+    pub fn follows_publication(&self) -> bool {
+        matches!(
+            self,
+            WorkDateType::LastReprint | WorkDateType::LastReissue | WorkDateType::OutOfPrint
+        )
+    }
+
+    /// The date which a work must record before it can be given `work_status`
+    pub fn required_for_status(work_status: &WorkStatus) -> Option<WorkDateType> {
+        match work_status {
+            WorkStatus::Withdrawn => Some(WorkDateType::OutOfPrint),
+            _ => None,
+        }
+    }
+}
+
+/// Check that a lifecycle date is consistent with the publication date of its work:
+/// announcements and first publication cannot come after it, and reprints, reissues and
+/// out-of-print declarations cannot come before it.
+pub fn validate_work_date(
+    date_type: &WorkDateType,
+    date: &NaiveDate,
+    publication_date: Option<&NaiveDate>,
+) -> ThothResult<()> {
+    match publication_date {
+        Some(publication) if date_type.precedes_publication() && date > publication => {
+            Err(ThothError::WorkDateError(format!(
+                "{date_type} date must not be later than the Publication Date ({publication})"
+            )))
+        }
+        Some(publication) if date_type.follows_publication() && date < publication => {
+            Err(ThothError::WorkDateError(format!(
+                "{date_type} date must not be earlier than the Publication Date ({publication})"
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check that a work recording `dates` may be given `work_status`, e.g. that a work being
+/// withdrawn has an out-of-print date.
+pub fn validate_work_status_dates(
+    work_status: &WorkStatus,
+    dates: &[WorkDateType],
+) -> ThothResult<()> {
+    match WorkDateType::required_for_status(work_status) {
+        Some(required) if !dates.contains(&required) => Err(
+            ThothError::WorkStatusMissingDateError(work_status.to_string(), required.to_string()),
+        ),
+        _ => Ok(()),
+    }
+}
+
+pub trait WorkDateProperties {
+    fn date_type(&self) -> &WorkDateType;
+    fn date(&self) -> &NaiveDate;
+
+    fn validate(&self, publication_date: Option<&NaiveDate>) -> ThothResult<()> {
+        validate_work_date(self.date_type(), self.date(), publication_date)
+    }
+}
+
+macro_rules! work_date_properties {
+    ($t:ty) => {
+        impl WorkDateProperties for $t {
+            fn date_type(&self) -> &WorkDateType {
+                &self.date_type
+            }
+            fn date(&self) -> &NaiveDate {
+                &self.date
+            }
+        }
+    };
+}
+
+work_date_properties!(WorkDate);
+work_date_properties!(NewWorkDate);
+work_date_properties!(PatchWorkDate);
+
+#[cfg(feature = "backend")]
+pub mod crud;
+#[cfg(feature = "backend")]
+mod policy;
+#[cfg(feature = "backend")]
+pub(crate) use policy::WorkDatePolicy;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+use crate::model::work::Work;
+use crate::model::work_date::{
+    validate_work_status_dates, NewWorkDate, PatchWorkDate, WorkDate, WorkDateProperties,
+    WorkDateType,
+};
+use crate::model::Crud;
+use crate::policy::{CreatePolicy, DeletePolicy, PolicyContext, UpdatePolicy};
+use thoth_errors::ThothResult;
+
+/// Write policies for `WorkDate`.
+///
+/// These policies are responsible for:
+/// - requiring authentication
+/// - requiring publisher membership (tenant boundary)
+/// - checking the date against the publication date of the work
+/// - preventing the removal of dates which the status of the work depends on
+pub struct WorkDatePolicy;
+
+/// Check that the work of `current` still satisfies its status once `current` is removed
+/// from it, whether by deletion or by changing its type or work.
+fn can_remove<C: PolicyContext>(ctx: &C, current: &WorkDate) -> ThothResult<()> {
+    let work = Work::from_id(ctx.db(), &current.work_id)?;
+    let remaining: Vec<WorkDateType> = WorkDate::all_for_work(ctx.db(), &current.work_id)?
+        .into_iter()
+        .filter(|work_date| work_date.work_date_id != current.work_date_id)
+        .map(|work_date| work_date.date_type)
+        .collect();
+    validate_work_status_dates(&work.work_status, &remaining)
+}
+
+impl CreatePolicy<NewWorkDate> for WorkDatePolicy {
+    fn can_create<C: PolicyContext>(ctx: &C, data: &NewWorkDate, _params: ()) -> ThothResult<()> {
+        ctx.require_publisher_for(data)?;
+        let work = Work::from_id(ctx.db(), &data.work_id)?;
+        data.validate(work.publication_date.as_ref())
+    }
+}
+
+impl UpdatePolicy<WorkDate, PatchWorkDate> for WorkDatePolicy {
+    fn can_update<C: PolicyContext>(
+        ctx: &C,
+        current: &WorkDate,
+        patch: &PatchWorkDate,
+        _params: (),
+    ) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        ctx.require_publisher_for(patch)?;
+        let work = Work::from_id(ctx.db(), &patch.work_id)?;
+        patch.validate(work.publication_date.as_ref())?;
+        if patch.work_id != current.work_id || patch.date_type != current.date_type {
+            can_remove(ctx, current)?;
+        }
+        Ok(())
+    }
+}
+
+impl DeletePolicy<WorkDate> for WorkDatePolicy {
+    fn can_delete<C: PolicyContext>(ctx: &C, current: &WorkDate) -> ThothResult<()> {
+        ctx.require_publisher_for(current)?;
+        can_remove(ctx, current)
+    }
+}
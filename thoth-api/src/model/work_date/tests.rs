@@ -0,0 +1,598 @@
+use super::*;
+use crate::model::Crud;
+use uuid::Uuid;
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn make_work_date(
+    pool: &crate::db::PgPool,
+    work_id: Uuid,
+    date_type: WorkDateType,
+    date: NaiveDate,
+) -> WorkDate {
+    let new_work_date = NewWorkDate {
+        work_id,
+        date_type,
+        date,
+    };
+
+    WorkDate::create(pool, &new_work_date).expect("Failed to create work date")
+}
+
+mod defaults {
+    use super::*;
+
+    #[test]
+    fn workdatetype_default_is_public_announcement() {
+        let date_type: WorkDateType = Default::default();
+        assert_eq!(date_type, WorkDateType::PublicAnnouncement);
+    }
+
+    #[test]
+    fn workdatefield_default_is_date() {
+        let field: WorkDateField = Default::default();
+        assert_eq!(field, WorkDateField::Date);
+    }
+}
+
+mod display_and_parse {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn workdatetype_display_formats_expected_strings() {
+        assert_eq!(format!("{}", WorkDateType::OnSale), "On Sale");
+        assert_eq!(format!("{}", WorkDateType::OutOfPrint), "Out Of Print");
+        assert_eq!(
+            format!("{}", WorkDateType::TradeAnnouncement),
+            "Trade Announcement"
+        );
+    }
+
+    #[test]
+    fn workdatetype_fromstr_parses_expected_values() {
+        assert_eq!(
+            WorkDateType::from_str("Last Reprint").unwrap(),
+            WorkDateType::LastReprint
+        );
+        assert!(WorkDateType::from_str("Withdrawn").is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod conversions {
+    use super::*;
+    use crate::model::tests::db::setup_test_db;
+    use crate::model::tests::{assert_db_enum_roundtrip, assert_graphql_enum_roundtrip};
+
+    #[test]
+    fn workdatetype_graphql_roundtrip() {
+        assert_graphql_enum_roundtrip(WorkDateType::LastReissue);
+    }
+
+    #[test]
+    fn workdatetype_db_enum_roundtrip() {
+        let (_guard, pool) = setup_test_db();
+
+        assert_db_enum_roundtrip::<WorkDateType, crate::schema::sql_types::WorkDateType>(
+            pool.as_ref(),
+            "'out_of_print'::work_date_type",
+            WorkDateType::OutOfPrint,
+        );
+    }
+}
+
+mod helpers {
+    use super::*;
+    use crate::model::{Crud, HistoryEntry};
+
+    #[test]
+    fn pk_returns_id() {
+        let work_date: WorkDate = Default::default();
+        assert_eq!(work_date.pk(), work_date.work_date_id);
+    }
+
+    #[test]
+    fn history_entry_serializes_model() {
+        let work_date: WorkDate = Default::default();
+        let user_id = "123456".to_string();
+        let new_work_date_history = work_date.new_history_entry(&user_id);
+        assert_eq!(new_work_date_history.work_date_id, work_date.work_date_id);
+        assert_eq!(new_work_date_history.user_id, user_id);
+        assert_eq!(
+            new_work_date_history.data,
+            serde_json::Value::String(serde_json::to_string(&work_date).unwrap())
+        );
+    }
+}
+
+mod validation {
+    use super::*;
+
+    #[test]
+    fn accepts_any_date_without_publication_date() {
+        assert!(validate_work_date(&WorkDateType::OutOfPrint, &ymd(2000, 1, 1), None).is_ok());
+        assert!(
+            validate_work_date(&WorkDateType::PublicAnnouncement, &ymd(2100, 1, 1), None).is_ok()
+        );
+    }
+
+    #[test]
+    fn accepts_dates_on_the_publication_date() {
+        let publication = ymd(2024, 1, 1);
+        for date_type in [
+            WorkDateType::PublicAnnouncement,
+            WorkDateType::OnSale,
+            WorkDateType::LastReprint,
+            WorkDateType::OutOfPrint,
+        ] {
+            assert!(validate_work_date(&date_type, &publication, Some(&publication)).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_announcement_after_publication() {
+        let publication = ymd(2024, 1, 1);
+        assert!(matches!(
+            validate_work_date(
+                &WorkDateType::TradeAnnouncement,
+                &ymd(2024, 2, 1),
+                Some(&publication)
+            ),
+            Err(ThothError::WorkDateError(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_out_of_print_before_publication() {
+        let publication = ymd(2024, 1, 1);
+        assert!(matches!(
+            validate_work_date(
+                &WorkDateType::OutOfPrint,
+                &ymd(2023, 12, 31),
+                Some(&publication)
+            ),
+            Err(ThothError::WorkDateError(_))
+        ));
+    }
+
+    #[test]
+    fn accepts_on_sale_date_either_side_of_publication() {
+        let publication = ymd(2024, 1, 1);
+        assert!(
+            validate_work_date(&WorkDateType::OnSale, &ymd(2023, 12, 1), Some(&publication))
+                .is_ok()
+        );
+        assert!(
+            validate_work_date(&WorkDateType::OnSale, &ymd(2024, 2, 1), Some(&publication)).is_ok()
+        );
+    }
+
+    #[test]
+    fn withdrawn_status_requires_out_of_print_date() {
+        assert_eq!(
+            validate_work_status_dates(&WorkStatus::Withdrawn, &[WorkDateType::LastReprint]),
+            Err(ThothError::WorkStatusMissingDateError(
+                "Withdrawn".to_string(),
+                "Out Of Print".to_string()
+            ))
+        );
+        assert!(
+            validate_work_status_dates(&WorkStatus::Withdrawn, &[WorkDateType::OutOfPrint]).is_ok()
+        );
+        assert!(validate_work_status_dates(&WorkStatus::Active, &[]).is_ok());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod policy {
+    use super::*;
+
+    use crate::db::PgPool;
+    use crate::model::tests::db::{
+        create_imprint, create_publisher, create_work, setup_test_db, test_context,
+        test_context_with_user, test_user_with_role,
+    };
+    use crate::model::work::{PatchWork, Work};
+    use crate::model::work_date::policy::WorkDatePolicy;
+    use crate::model::Crud;
+    use crate::policy::{CreatePolicy, DeletePolicy, Role, UpdatePolicy};
+    use std::sync::Arc;
+
+    fn publish_work(pool: &Arc<PgPool>, work: &Work, withdrawn_date: Option<NaiveDate>) -> Work {
+        let mut patch = PatchWork::from(work.clone());
+        patch.work_status = match withdrawn_date {
+            Some(_) => WorkStatus::Withdrawn,
+            None => WorkStatus::Active,
+        };
+        patch.publication_date = Some(ymd(2024, 1, 1));
+        patch.withdrawn_date = withdrawn_date;
+        let ctx = test_context(pool.clone(), "test-user");
+        work.update(&ctx, &patch).expect("Failed to publish work")
+    }
+
+    #[test]
+    fn crud_policy_allows_publisher_user_for_write() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("work-date-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let new_work_date = NewWorkDate {
+            work_id: work.work_id,
+            date_type: WorkDateType::OnSale,
+            date: ymd(2024, 3, 1),
+        };
+
+        let work_date = WorkDate::create(pool.as_ref(), &new_work_date).expect("Failed to create");
+        let patch = PatchWorkDate {
+            work_date_id: work_date.work_date_id,
+            work_id: work_date.work_id,
+            date_type: WorkDateType::LastReprint,
+            date: ymd(2025, 3, 1),
+        };
+
+        assert!(WorkDatePolicy::can_create(&ctx, &new_work_date, ()).is_ok());
+        assert!(WorkDatePolicy::can_update(&ctx, &work_date, &patch, ()).is_ok());
+        assert!(WorkDatePolicy::can_delete(&ctx, &work_date).is_ok());
+    }
+
+    #[test]
+    fn crud_policy_rejects_user_without_publisher_role() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let work_date = make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::OnSale,
+            ymd(2024, 3, 1),
+        );
+        let patch = PatchWorkDate {
+            work_date_id: work_date.work_date_id,
+            work_id: work_date.work_id,
+            date_type: WorkDateType::OnSale,
+            date: ymd(2024, 4, 1),
+        };
+
+        let user = test_user_with_role("work-date-user", Role::PublisherUser, "org-other");
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let new_work_date = NewWorkDate {
+            work_id: work.work_id,
+            date_type: WorkDateType::LastReprint,
+            date: ymd(2025, 1, 1),
+        };
+
+        assert!(WorkDatePolicy::can_create(&ctx, &new_work_date, ()).is_err());
+        assert!(WorkDatePolicy::can_update(&ctx, &work_date, &patch, ()).is_err());
+        assert!(WorkDatePolicy::can_delete(&ctx, &work_date).is_err());
+    }
+
+    #[test]
+    fn crud_policy_rejects_date_inconsistent_with_publication_date() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("work-date-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = publish_work(&pool, &create_work(pool.as_ref(), &imprint), None);
+        let publication_date = work
+            .publication_date
+            .expect("published work missing publication date");
+
+        let late_announcement = NewWorkDate {
+            work_id: work.work_id,
+            date_type: WorkDateType::PublicAnnouncement,
+            date: publication_date.succ_opt().unwrap(),
+        };
+        let early_reprint = NewWorkDate {
+            work_id: work.work_id,
+            date_type: WorkDateType::LastReprint,
+            date: publication_date.pred_opt().unwrap(),
+        };
+
+        assert!(WorkDatePolicy::can_create(&ctx, &late_announcement, ()).is_err());
+        assert!(WorkDatePolicy::can_create(&ctx, &early_reprint, ()).is_err());
+    }
+
+    #[test]
+    fn crud_policy_keeps_out_of_print_date_of_withdrawn_work() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let org_id = publisher
+            .zitadel_id
+            .clone()
+            .expect("publisher missing zitadel id");
+        let user = test_user_with_role("work-date-user", Role::PublisherUser, &org_id);
+        let ctx = test_context_with_user(pool.clone(), user);
+
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let out_of_print_date = ymd(2030, 1, 1);
+        let out_of_print = make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::OutOfPrint,
+            out_of_print_date,
+        );
+        publish_work(&pool, &work, Some(out_of_print_date));
+
+        let retyped = PatchWorkDate {
+            work_date_id: out_of_print.work_date_id,
+            work_id: out_of_print.work_id,
+            date_type: WorkDateType::LastReprint,
+            date: out_of_print_date,
+        };
+        let redated = PatchWorkDate {
+            date_type: WorkDateType::OutOfPrint,
+            ..retyped
+        };
+
+        assert!(matches!(
+            WorkDatePolicy::can_delete(&ctx, &out_of_print),
+            Err(ThothError::WorkStatusMissingDateError(_, _))
+        ));
+        assert!(WorkDatePolicy::can_update(&ctx, &out_of_print, &retyped, ()).is_err());
+        assert!(WorkDatePolicy::can_update(&ctx, &out_of_print, &redated, ()).is_ok());
+    }
+}
+
+#[cfg(feature = "backend")]
+mod crud {
+    use super::*;
+
+    use crate::graphql::types::inputs::Direction;
+    use crate::model::tests::db::{
+        create_imprint, create_publisher, create_work, setup_test_db, test_context,
+    };
+    use crate::model::Crud;
+
+    #[test]
+    fn crud_roundtrip_create_fetch_update_delete() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        let work_date = make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::OnSale,
+            ymd(2024, 3, 1),
+        );
+        let fetched =
+            WorkDate::from_id(pool.as_ref(), &work_date.work_date_id).expect("Failed to fetch");
+        assert_eq!(work_date.work_date_id, fetched.work_date_id);
+        assert_eq!(fetched.date, ymd(2024, 3, 1));
+
+        let patch = PatchWorkDate {
+            work_date_id: work_date.work_date_id,
+            work_id: work_date.work_id,
+            date_type: WorkDateType::LastReissue,
+            date: ymd(2026, 5, 1),
+        };
+
+        let ctx = test_context(pool.clone(), "test-user");
+        let updated = work_date.update(&ctx, &patch).expect("Failed to update");
+        assert_eq!(updated.date_type, patch.date_type);
+        assert_eq!(updated.date, patch.date);
+
+        let deleted = updated.delete(pool.as_ref()).expect("Failed to delete");
+        assert!(WorkDate::from_id(pool.as_ref(), &deleted.work_date_id).is_err());
+    }
+
+    #[test]
+    fn crud_rejects_duplicate_date_type() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::OnSale,
+            ymd(2024, 3, 1),
+        );
+        let duplicate = NewWorkDate {
+            work_id: work.work_id,
+            date_type: WorkDateType::OnSale,
+            date: ymd(2024, 4, 1),
+        };
+
+        assert!(WorkDate::create(pool.as_ref(), &duplicate).is_err());
+    }
+
+    #[test]
+    fn crud_all_for_work_returns_dates_in_order() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let other_work = create_work(pool.as_ref(), &imprint);
+
+        let later = make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::LastReprint,
+            ymd(2026, 1, 1),
+        );
+        let earlier = make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::PublicAnnouncement,
+            ymd(2019, 1, 1),
+        );
+        make_work_date(
+            pool.as_ref(),
+            other_work.work_id,
+            WorkDateType::OnSale,
+            ymd(2024, 1, 1),
+        );
+
+        let dates =
+            WorkDate::all_for_work(pool.as_ref(), &work.work_id).expect("Failed to load dates");
+        let ids: Vec<Uuid> = dates.iter().map(|d| d.work_date_id).collect();
+        assert_eq!(ids, vec![earlier.work_date_id, later.work_date_id]);
+    }
+
+    #[test]
+    fn crud_count_filters_by_date_type() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::OnSale,
+            ymd(2024, 3, 1),
+        );
+        make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::LastReprint,
+            ymd(2025, 3, 1),
+        );
+
+        let total = WorkDate::count(pool.as_ref(), None, vec![], vec![], vec![], None, None)
+            .expect("Failed to count work dates");
+        let filtered = WorkDate::count(
+            pool.as_ref(),
+            None,
+            vec![],
+            vec![WorkDateType::LastReprint],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to count work dates by type");
+
+        assert_eq!(total, 2);
+        assert_eq!(filtered, 1);
+    }
+
+    #[test]
+    fn crud_filter_publishers_limits_results() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+        let matches = make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::OnSale,
+            ymd(2024, 3, 1),
+        );
+
+        let other_publisher = create_publisher(pool.as_ref());
+        let other_imprint = create_imprint(pool.as_ref(), &other_publisher);
+        let other_work = create_work(pool.as_ref(), &other_imprint);
+        make_work_date(
+            pool.as_ref(),
+            other_work.work_id,
+            WorkDateType::OnSale,
+            ymd(2024, 3, 1),
+        );
+
+        let filtered = WorkDate::all(
+            pool.as_ref(),
+            10,
+            0,
+            None,
+            WorkDateOrderBy {
+                field: WorkDateField::WorkDateId,
+                direction: Direction::Asc,
+            },
+            vec![publisher.publisher_id],
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+            None,
+        )
+        .expect("Failed to filter work dates by publisher");
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].work_date_id, matches.work_date_id);
+    }
+
+    #[test]
+    fn crud_ordering_by_fields_is_supported() {
+        let (_guard, pool) = setup_test_db();
+
+        let publisher = create_publisher(pool.as_ref());
+        let imprint = create_imprint(pool.as_ref(), &publisher);
+        let work = create_work(pool.as_ref(), &imprint);
+
+        make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::OnSale,
+            ymd(2024, 3, 1),
+        );
+        make_work_date(
+            pool.as_ref(),
+            work.work_id,
+            WorkDateType::LastReprint,
+            ymd(2025, 3, 1),
+        );
+
+        for field in [
+            WorkDateField::WorkDateId,
+            WorkDateField::WorkId,
+            WorkDateField::DateType,
+            WorkDateField::Date,
+            WorkDateField::CreatedAt,
+            WorkDateField::UpdatedAt,
+        ] {
+            for direction in [Direction::Asc, Direction::Desc] {
+                let results = WorkDate::all(
+                    pool.as_ref(),
+                    10,
+                    0,
+                    None,
+                    WorkDateOrderBy {
+                        field: field.clone(),
+                        direction,
+                    },
+                    vec![],
+                    Some(work.work_id),
+                    None,
+                    vec![],
+                    vec![],
+                    None,
+                    None,
+                )
+                .expect("Failed to order work dates");
+                assert_eq!(results.len(), 2);
+            }
+        }
+    }
+}
@@ -47,6 +47,8 @@ pub struct WorkFeaturedVideo {
     graphql(description = "Set of values required to define a new featured video linked to a work"),
     diesel(table_name = work_featured_video)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewWorkFeaturedVideo {
     pub work_id: Uuid,
     pub title: Option<String>,
@@ -61,6 +63,8 @@ pub struct NewWorkFeaturedVideo {
     graphql(description = "Set of values required to update an existing featured video"),
     diesel(table_name = work_featured_video, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchWorkFeaturedVideo {
     pub work_featured_video_id: Uuid,
     pub work_id: Uuid,
@@ -125,6 +125,8 @@ pub struct WorkRelation {
     graphql(description = "Set of values required to define a new relationship between two works"),
     diesel(table_name = work_relation)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct NewWorkRelation {
     pub relator_work_id: Uuid,
     pub related_work_id: Uuid,
@@ -138,6 +140,8 @@ pub struct NewWorkRelation {
     graphql(description = "Set of values required to update an existing relationship between two works"),
     diesel(table_name = work_relation, treat_none_as_null = true)
 )]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PatchWorkRelation {
     pub work_relation_id: Uuid,
     pub relator_work_id: Uuid,
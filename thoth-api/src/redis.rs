@@ -1,4 +1,7 @@
-use deadpool_redis::{redis::AsyncCommands, Config, Connection, Pool};
+use deadpool_redis::{
+    redis::{cmd, AsyncCommands},
+    Config, Connection, Pool,
+};
 use futures::StreamExt;
 use thoth_errors::ThothResult;
 
@@ -38,6 +41,24 @@ pub async fn scan_match(pool: &RedisPool, pattern: &str) -> ThothResult<Vec<Stri
     Ok(keys)
 }
 
+/// Check that a connection can be obtained from the pool and that the server responds
+pub async fn ping(pool: &RedisPool) -> ThothResult<()> {
+    let mut con = create_connection(pool).await?;
+    cmd("PING").query_async::<()>(&mut con).await?;
+    Ok(())
+}
+
+/// Number of bytes a key and its value take up in memory, or 0 if the key does not exist
+pub async fn memory_usage(pool: &RedisPool, key: &str) -> ThothResult<u64> {
+    let mut con = create_connection(pool).await?;
+    let bytes: Option<u64> = cmd("MEMORY")
+        .arg("USAGE")
+        .arg(key)
+        .query_async(&mut con)
+        .await?;
+    Ok(bytes.unwrap_or_default())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,6 +78,12 @@ mod tests {
         assert!(pool.get().await.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_ping() {
+        let pool = get_pool().await;
+        assert!(ping(&pool).await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_set_and_get() {
         let pool = get_pool().await;
@@ -107,4 +134,15 @@ mod tests {
         assert!(keys.contains(&"onix_3.0::key2".to_string()));
         assert!(keys.contains(&"onix_3.0::key3".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_memory_usage() {
+        let pool = get_pool().await;
+        set(&pool, "test_key_memory", "test_value").await.unwrap();
+
+        let bytes = memory_usage(&pool, "test_key_memory").await.unwrap();
+        assert!(bytes > 0);
+        let missing = memory_usage(&pool, "nonexistent_key").await.unwrap();
+        assert_eq!(missing, 0);
+    }
 }
@@ -1,4 +1,16 @@
 pub mod sql_types {
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "audience_code"))]
+    pub struct AudienceCode;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "audience_range_qualifier"))]
+    pub struct AudienceRangeQualifier;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "audience_range_precision"))]
+    pub struct AudienceRangePrecision;
+
     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
     #[diesel(postgres_type(name = "award_role"))]
     pub struct AwardRole;
@@ -11,6 +23,14 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "country_code"))]
     pub struct CountryCode;
 
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "credit_role_type"))]
+    pub struct CreditRoleType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "credit_role_degree"))]
+    pub struct CreditRoleDegree;
+
     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
     #[diesel(postgres_type(name = "language_relation"))]
     pub struct LanguageRelation;
@@ -23,6 +43,10 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "location_platform"))]
     pub struct LocationPlatform;
 
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "name_variant_type"))]
+    pub struct NameVariantType;
+
     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
     #[diesel(postgres_type(name = "currency_code"))]
     pub struct CurrencyCode;
@@ -47,6 +71,10 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "work_status"))]
     pub struct WorkStatus;
 
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "work_date_type"))]
+    pub struct WorkDateType;
+
     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
     #[diesel(postgres_type(name = "relation_type"))]
     pub struct RelationType;
@@ -71,6 +99,22 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "file_type"))]
     pub struct FileType;
 
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "file_validation_status"))]
+    pub struct FileValidationStatus;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "sales_rights_type"))]
+    pub struct SalesRightsType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "price_type"))]
+    pub struct PriceType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "product_form_detail_type"))]
+    pub struct ProductFormDetailType;
+
     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
     #[diesel(postgres_type(name = "contact_type"))]
     pub struct ContactType;
@@ -82,6 +126,10 @@ pub mod sql_types {
     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
     #[diesel(postgres_type(name = "accessibility_exception"))]
     pub struct AccessibilityException;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "access_status"))]
+    pub struct AccessStatus;
 }
 
 use diesel::{allow_tables_to_appear_in_same_query, joinable, table};
@@ -161,6 +209,35 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::{AudienceCode, AudienceRangePrecision, AudienceRangeQualifier};
+
+    audience (audience_id) {
+        audience_id -> Uuid,
+        work_id -> Uuid,
+        audience_code -> AudienceCode,
+        audience_range_qualifier -> Nullable<AudienceRangeQualifier>,
+        audience_range_precision -> Nullable<AudienceRangePrecision>,
+        audience_range_from -> Nullable<Text>,
+        audience_range_to -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    audience_history (audience_history_id) {
+        audience_history_id -> Uuid,
+        audience_id -> Uuid,
+        user_id -> Text,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
     use super::sql_types::{AwardRole, CountryCode};
@@ -290,6 +367,32 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::{CreditRoleDegree, CreditRoleType};
+
+    credit_role (credit_role_id) {
+        credit_role_id -> Uuid,
+        contribution_id -> Uuid,
+        role_type -> CreditRoleType,
+        degree -> Nullable<CreditRoleDegree>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    credit_role_history (credit_role_history_id) {
+        credit_role_history_id -> Uuid,
+        credit_role_id -> Uuid,
+        user_id -> Text,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
 
@@ -373,6 +476,21 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CountryCode;
+
+    funder_registry (funder_doi) {
+        funder_doi -> Text,
+        funder_name -> Text,
+        alt_names -> Array<Text>,
+        country_code -> Nullable<CountryCode>,
+        parent_doi -> Nullable<Text>,
+        ror -> Nullable<Text>,
+        replaced_by -> Nullable<Text>,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
 
@@ -404,6 +522,7 @@ table! {
         default_locale -> Nullable<LocaleCode>,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        reject_invalid_files -> Bool,
     }
 }
 
@@ -527,9 +646,42 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::{LocaleCode, NameVariantType};
+
+    name_variant (name_variant_id) {
+        name_variant_id -> Uuid,
+        contributor_id -> Nullable<Uuid>,
+        contribution_id -> Nullable<Uuid>,
+        variant_type -> NameVariantType,
+        script_code -> Nullable<Text>,
+        locale_code -> Nullable<LocaleCode>,
+        first_name -> Nullable<Text>,
+        last_name -> Text,
+        full_name -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    name_variant_history (name_variant_history_id) {
+        name_variant_history_id -> Uuid,
+        name_variant_id -> Uuid,
+        user_id -> Text,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
     use super::sql_types::CurrencyCode;
+    use super::sql_types::PriceType;
+    use super::sql_types::CountryCode;
 
     price (price_id) {
         price_id -> Uuid,
@@ -538,6 +690,11 @@ table! {
         unit_price -> Float8,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        price_type -> PriceType,
+        tax_inclusive -> Bool,
+        countries_included -> Nullable<Array<CountryCode>>,
+        effective_from -> Nullable<Date>,
+        effective_until -> Nullable<Date>,
     }
 }
 
@@ -553,11 +710,40 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ProductFormDetailType;
+
+    product_form_detail (product_form_detail_id) {
+        product_form_detail_id -> Uuid,
+        publication_id -> Uuid,
+        detail_type -> ProductFormDetailType,
+        detail_code -> Text,
+        feature_value -> Nullable<Text>,
+        feature_description -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    product_form_detail_history (product_form_detail_history_id) {
+        product_form_detail_history_id -> Uuid,
+        product_form_detail_id -> Uuid,
+        user_id -> Text,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
     use super::sql_types::PublicationType;
     use super::sql_types::AccessibilityStandard;
     use super::sql_types::AccessibilityException;
+    use super::sql_types::AccessStatus;
 
     publication (publication_id) {
         publication_id -> Uuid,
@@ -578,6 +764,9 @@ table! {
         accessibility_additional_standard -> Nullable<AccessibilityStandard>,
         accessibility_exception -> Nullable<AccessibilityException>,
         accessibility_report_url -> Nullable<Text>,
+        license -> Nullable<Text>,
+        access_status -> Nullable<AccessStatus>,
+        embargo_end_date -> Nullable<Date>,
     }
 }
 
@@ -665,6 +854,34 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::SalesRightsType;
+    use super::sql_types::CountryCode;
+
+    sales_rights (sales_rights_id) {
+        sales_rights_id -> Uuid,
+        publication_id -> Uuid,
+        sales_rights_type -> SalesRightsType,
+        countries_included -> Nullable<Array<CountryCode>>,
+        countries_excluded -> Array<CountryCode>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    sales_rights_history (sales_rights_history_id) {
+        sales_rights_history_id -> Uuid,
+        sales_rights_id -> Uuid,
+        user_id -> Text,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
     use super::sql_types::SeriesType;
@@ -762,6 +979,7 @@ table! {
         last_page -> Nullable<Text>,
         page_interval -> Nullable<Text>,
         updated_at_with_relations -> Timestamptz,
+        expose_latest_file_url -> Bool,
     }
 }
 
@@ -777,6 +995,32 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WorkDateType;
+
+    work_date (work_date_id) {
+        work_date_id -> Uuid,
+        work_id -> Uuid,
+        date_type -> WorkDateType,
+        date -> Date,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    work_date_history (work_date_history_id) {
+        work_date_history_id -> Uuid,
+        work_date_id -> Uuid,
+        user_id -> Text,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
 
@@ -863,6 +1107,7 @@ table! {
 table! {
     use diesel::sql_types::*;
     use super::sql_types::FileType;
+    use super::sql_types::FileValidationStatus;
 
     file (file_id) {
         file_id -> Uuid,
@@ -878,6 +1123,9 @@ table! {
         sha256 -> Text,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        validation_status -> FileValidationStatus,
+        validation_issues -> Jsonb,
+        validated_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -900,6 +1148,49 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+
+    file_audit (file_audit_id) {
+        file_audit_id -> Uuid,
+        audit_run_id -> Uuid,
+        file_id -> Uuid,
+        imprint_id -> Uuid,
+        object_key -> Text,
+        expected_bytes -> Int8,
+        actual_bytes -> Nullable<Int8>,
+        expected_sha256 -> Text,
+        actual_sha256 -> Nullable<Text>,
+        expected_mime_type -> Text,
+        actual_mime_type -> Nullable<Text>,
+        object_missing -> Bool,
+        size_mismatch -> Bool,
+        checksum_mismatch -> Bool,
+        mime_type_mismatch -> Bool,
+        error_message -> Nullable<Text>,
+        checked_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    file_version (file_version_id) {
+        file_version_id -> Uuid,
+        file_id -> Uuid,
+        publication_id -> Uuid,
+        version_number -> Int4,
+        object_key -> Text,
+        cdn_url -> Text,
+        mime_type -> Text,
+        bytes -> Int8,
+        sha256 -> Text,
+        uploaded_by -> Nullable<Text>,
+        restored_from_file_version_id -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
 
@@ -930,6 +1221,8 @@ joinable!(additional_resource_history -> additional_resource (additional_resourc
 joinable!(affiliation -> contribution (contribution_id));
 joinable!(affiliation -> institution (institution_id));
 joinable!(affiliation_history -> affiliation (affiliation_id));
+joinable!(audience -> work (work_id));
+joinable!(audience_history -> audience (audience_id));
 joinable!(award -> work (work_id));
 joinable!(award_history -> award (award_id));
 joinable!(biography_history -> biography (biography_id));
@@ -942,6 +1235,8 @@ joinable!(contribution -> contributor (contributor_id));
 joinable!(contribution -> work (work_id));
 joinable!(contribution_history -> contribution (contribution_id));
 joinable!(contributor_history -> contributor (contributor_id));
+joinable!(credit_role -> contribution (contribution_id));
+joinable!(credit_role_history -> credit_role (credit_role_id));
 joinable!(endorsement -> institution (author_institution_id));
 joinable!(endorsement -> work (work_id));
 joinable!(endorsement_history -> endorsement (endorsement_id));
@@ -949,10 +1244,14 @@ joinable!(file -> work (work_id));
 joinable!(file -> publication (publication_id));
 joinable!(file -> additional_resource (additional_resource_id));
 joinable!(file -> work_featured_video (work_featured_video_id));
+joinable!(file_audit -> file (file_id));
+joinable!(file_audit -> imprint (imprint_id));
 joinable!(file_upload -> work (work_id));
 joinable!(file_upload -> publication (publication_id));
 joinable!(file_upload -> additional_resource (additional_resource_id));
 joinable!(file_upload -> work_featured_video (work_featured_video_id));
+joinable!(file_version -> file (file_id));
+joinable!(file_version -> publication (publication_id));
 joinable!(funding -> institution (institution_id));
 joinable!(funding -> work (work_id));
 joinable!(funding_history -> funding (funding_id));
@@ -966,13 +1265,20 @@ joinable!(language -> work (work_id));
 joinable!(language_history -> language (language_id));
 joinable!(location -> publication (publication_id));
 joinable!(location_history -> location (location_id));
+joinable!(name_variant -> contribution (contribution_id));
+joinable!(name_variant -> contributor (contributor_id));
+joinable!(name_variant_history -> name_variant (name_variant_id));
 joinable!(price -> publication (publication_id));
 joinable!(price_history -> price (price_id));
+joinable!(product_form_detail -> publication (publication_id));
+joinable!(product_form_detail_history -> product_form_detail (product_form_detail_id));
 joinable!(publication -> work (work_id));
 joinable!(publication_history -> publication (publication_id));
 joinable!(publisher_history -> publisher (publisher_id));
 joinable!(reference -> work (work_id));
 joinable!(reference_history -> reference (reference_id));
+joinable!(sales_rights -> publication (publication_id));
+joinable!(sales_rights_history -> sales_rights (sales_rights_id));
 joinable!(series -> imprint (imprint_id));
 joinable!(series_history -> series (series_id));
 joinable!(subject -> work (work_id));
@@ -980,6 +1286,8 @@ joinable!(subject_history -> subject (subject_id));
 joinable!(title_history -> work_title (title_id));
 joinable!(work -> imprint (imprint_id));
 joinable!(work_abstract -> work (work_id));
+joinable!(work_date -> work (work_id));
+joinable!(work_date_history -> work_date (work_date_id));
 joinable!(work_history -> work (work_id));
 joinable!(work_featured_video -> work (work_id));
 joinable!(work_featured_video_history -> work_featured_video (work_featured_video_id));
@@ -993,6 +1301,8 @@ allow_tables_to_appear_in_same_query!(
     additional_resource_history,
     affiliation,
     affiliation_history,
+    audience,
+    audience_history,
     award,
     award_history,
     biography,
@@ -1005,10 +1315,15 @@ allow_tables_to_appear_in_same_query!(
     contribution_history,
     contributor,
     contributor_history,
+    credit_role,
+    credit_role_history,
     endorsement,
     endorsement_history,
     file,
+    file_audit,
     file_upload,
+    file_version,
+    funder_registry,
     funding,
     funding_history,
     imprint,
@@ -1021,14 +1336,20 @@ allow_tables_to_appear_in_same_query!(
     language_history,
     location,
     location_history,
+    name_variant,
+    name_variant_history,
     price,
     price_history,
+    product_form_detail,
+    product_form_detail_history,
     publication,
     publication_history,
     publisher,
     publisher_history,
     reference,
     reference_history,
+    sales_rights,
+    sales_rights_history,
     series,
     series_history,
     subject,
@@ -1036,6 +1357,8 @@ allow_tables_to_appear_in_same_query!(
     title_history,
     work,
     work_abstract,
+    work_date,
+    work_date_history,
     work_history,
     work_featured_video,
     work_featured_video_history,
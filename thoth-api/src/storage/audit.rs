@@ -0,0 +1,291 @@
+use aws_sdk_s3::types::ChecksumMode;
+use base64::{engine::general_purpose, Engine as _};
+use log::{info, warn};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::time::Instant;
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::db::PgPool;
+use crate::model::{
+    file::File,
+    file_audit::{FileAudit, NewFileAudit},
+    imprint::Imprint,
+    Crud,
+};
+
+use super::cleanup::elapsed_ms;
+use super::{S3Client, StorageConfig};
+
+/// Properties of an object as reported by S3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredObject {
+    pub bytes: i64,
+    pub mime_type: String,
+    /// Hex-encoded SHA-256 of the full object, if it could be determined
+    pub sha256: Option<String>,
+}
+
+/// Outcome of looking up a single object in S3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectObservation {
+    Present(StoredObject),
+    Missing,
+    Error(String),
+}
+
+/// Summary of a single audit run over one imprint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileAuditSummary {
+    pub audit_run_id: Uuid,
+    pub imprint_id: Uuid,
+    pub files_checked: usize,
+    pub files_failed: usize,
+    pub audits: Vec<FileAudit>,
+}
+
+fn normalise_mime_type(mime_type: &str) -> String {
+    mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// Convert the base64 `x-amz-checksum-sha256` value returned by S3 into hex.
+///
+/// Composite checksums of multipart uploads (suffixed with `-<parts>`) are not a digest of the
+/// full object and are therefore ignored.
+pub fn s3_checksum_to_hex(checksum: &str) -> Option<String> {
+    if checksum.contains('-') {
+        return None;
+    }
+    general_purpose::STANDARD
+        .decode(checksum)
+        .ok()
+        .filter(|digest| digest.len() == 32)
+        .map(hex::encode)
+}
+
+/// Compare a `file` row against what is actually stored under its object key.
+pub fn compare_file_to_object(
+    audit_run_id: Uuid,
+    imprint_id: Uuid,
+    file: &File,
+    observation: &ObjectObservation,
+) -> NewFileAudit {
+    let mut audit = NewFileAudit {
+        audit_run_id,
+        file_id: file.file_id,
+        imprint_id,
+        object_key: file.object_key.clone(),
+        expected_bytes: file.bytes,
+        expected_sha256: file.sha256.to_ascii_lowercase(),
+        expected_mime_type: file.mime_type.clone(),
+        ..Default::default()
+    };
+
+    match observation {
+        ObjectObservation::Present(object) => {
+            audit.actual_bytes = Some(object.bytes);
+            audit.actual_mime_type = Some(object.mime_type.clone());
+            audit.actual_sha256 = object.sha256.as_ref().map(|s| s.to_ascii_lowercase());
+            audit.size_mismatch = object.bytes != file.bytes;
+            audit.mime_type_mismatch =
+                normalise_mime_type(&object.mime_type) != normalise_mime_type(&file.mime_type);
+            audit.checksum_mismatch = audit
+                .actual_sha256
+                .as_ref()
+                .is_some_and(|actual| *actual != audit.expected_sha256);
+        }
+        ObjectObservation::Missing => audit.object_missing = true,
+        ObjectObservation::Error(message) => audit.error_message = Some(message.clone()),
+    }
+
+    audit
+}
+
+#[tracing::instrument(skip(s3_client))]
+async fn stream_object_sha256(
+    s3_client: &S3Client,
+    bucket: &str,
+    key: &str,
+) -> ThothResult<String> {
+    let response = s3_client
+        .get_object()
+        .bucket(bucket)
+        .key(key)
+        .send()
+        .await
+        .map_err(|e| ThothError::InternalError(format!("Failed to get object: {}", e)))?;
+
+    let mut body = response.body;
+    let mut hasher = Sha256::new();
+    while let Some(chunk) = body
+        .try_next()
+        .await
+        .map_err(|e| ThothError::InternalError(format!("Failed to read object body: {}", e)))?
+    {
+        hasher.update(&chunk);
+    }
+
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Look up an object in S3, optionally reading its full body to compute a SHA-256 digest.
+///
+/// When `verify_checksums` is false the digest is only taken from the checksum S3 stored at
+/// upload time, if any.
+pub async fn inspect_object(
+    s3_client: &S3Client,
+    bucket: &str,
+    key: &str,
+    verify_checksums: bool,
+) -> ObjectObservation {
+    let response = match s3_client
+        .head_object()
+        .bucket(bucket)
+        .key(key)
+        .checksum_mode(ChecksumMode::Enabled)
+        .send()
+        .await
+    {
+        Ok(response) => response,
+        Err(error) => {
+            let not_found = error
+                .as_service_error()
+                .is_some_and(|service_error| service_error.is_not_found())
+                || error
+                    .raw_response()
+                    .is_some_and(|response| response.status().as_u16() == 404);
+            return if not_found {
+                ObjectObservation::Missing
+            } else {
+                ObjectObservation::Error(format!("Failed to head object: {}", error))
+            };
+        }
+    };
+
+    let mut object = StoredObject {
+        bytes: response.content_length().unwrap_or(0),
+        mime_type: response
+            .content_type()
+            .unwrap_or("application/octet-stream")
+            .to_string(),
+        sha256: response.checksum_sha256().and_then(s3_checksum_to_hex),
+    };
+
+    if verify_checksums {
+        match stream_object_sha256(s3_client, bucket, key).await {
+            Ok(sha256) => object.sha256 = Some(sha256),
+            Err(error) => return ObjectObservation::Error(error.to_string()),
+        }
+    }
+
+    ObjectObservation::Present(object)
+}
+
+/// Load the imprints to audit: the given IDs, or every imprint with file hosting configured.
+pub fn imprints_to_audit(db: &PgPool, imprint_ids: &[Uuid]) -> ThothResult<Vec<Imprint>> {
+    if !imprint_ids.is_empty() {
+        return imprint_ids
+            .iter()
+            .map(|imprint_id| Imprint::from_id(db, imprint_id))
+            .collect();
+    }
+    let count = Imprint::count(db, None, vec![], vec![], vec![], None, None)?;
+    Ok(Imprint::all(
+        db,
+        count,
+        0,
+        None,
+        Default::default(),
+        vec![],
+        None,
+        None,
+        vec![],
+        vec![],
+        None,
+        None,
+    )?
+    .into_iter()
+    .filter(|imprint| StorageConfig::from_imprint(imprint).is_ok())
+    .collect())
+}
+
+/// Audit every stored file of an imprint against its S3 bucket and record the results.
+///
+/// With `failures_only`, only files whose most recent audit found a problem are re-checked.
+pub async fn audit_imprint_files(
+    db: &PgPool,
+    s3_client: &S3Client,
+    imprint: &Imprint,
+    failures_only: bool,
+    verify_checksums: bool,
+) -> ThothResult<FileAuditSummary> {
+    let storage_config = StorageConfig::from_imprint(imprint)?;
+    let audit_run_id = Uuid::new_v4();
+    let started = Instant::now();
+
+    let mut files = File::all_for_imprint(db, &imprint.imprint_id)?;
+    if failures_only {
+        let failed: HashSet<Uuid> = FileAudit::latest_failed_file_ids(db, &imprint.imprint_id)?
+            .into_iter()
+            .collect();
+        files.retain(|file| failed.contains(&file.file_id));
+    }
+
+    info!(
+        "audit_run_id={audit_run_id} imprint_id={} bucket={} files_total={} failures_only={failures_only} verify_checksums={verify_checksums} audit_started=true",
+        imprint.imprint_id,
+        storage_config.s3_bucket,
+        files.len(),
+    );
+
+    let mut audits = Vec::with_capacity(files.len());
+    let mut files_failed = 0;
+    for file in &files {
+        let observation = inspect_object(
+            s3_client,
+            &storage_config.s3_bucket,
+            &file.object_key,
+            verify_checksums,
+        )
+        .await;
+        let audit = FileAudit::create(
+            db,
+            &compare_file_to_object(audit_run_id, imprint.imprint_id, file, &observation),
+        )?;
+        if !audit.passed() {
+            files_failed += 1;
+            warn!(
+                "audit_run_id={audit_run_id} file_id={} object_key={} object_missing={} size_mismatch={} checksum_mismatch={} mime_type_mismatch={} error=\"{}\"",
+                audit.file_id,
+                audit.object_key,
+                audit.object_missing,
+                audit.size_mismatch,
+                audit.checksum_mismatch,
+                audit.mime_type_mismatch,
+                audit.error_message.as_deref().unwrap_or_default(),
+            );
+        }
+        audits.push(audit);
+    }
+
+    info!(
+        "audit_run_id={audit_run_id} imprint_id={} files_checked={} files_failed={files_failed} total_ms={} audit_finished=true",
+        imprint.imprint_id,
+        audits.len(),
+        elapsed_ms(started),
+    );
+
+    Ok(FileAuditSummary {
+        audit_run_id,
+        imprint_id: imprint.imprint_id,
+        files_checked: audits.len(),
+        files_failed,
+        audits,
+    })
+}
@@ -11,11 +11,24 @@ use uuid::Uuid;
 
 use crate::model::imprint::Imprint;
 
+pub mod audit;
 pub mod cleanup;
+pub mod validation;
+pub mod versioning;
+pub use audit::{
+    audit_imprint_files, compare_file_to_object, imprints_to_audit, inspect_object,
+    FileAuditSummary, ObjectObservation, StoredObject,
+};
 pub use cleanup::{
     additional_resource_cleanup_plan, publication_cleanup_plan, run_cleanup_plan,
     run_cleanup_plan_sync, work_cleanup_plan, work_featured_video_cleanup_plan, FileCleanupPlan,
 };
+pub use validation::{
+    validate_stored_object, FileValidationReport, FileValidator, PartialObject, ValidationFindings,
+    ValidationPipeline,
+};
+pub(crate) use versioning::publish_publication_file;
+pub use versioning::PublicationFileSource;
 
 const S3_EXTENDED_REQUEST_ID_META_KEY: &str = "s3_extended_request_id";
 const S3_EXTENDED_REQUEST_ID_HEADER: &str = "x-amz-id-2";
@@ -187,6 +200,7 @@ fn thoth_internal_error(operation: &str, context: &AwsErrorContext) -> ThothErro
     ThothError::InternalError(format!("{operation}: {}", context.summary()))
 }
 
+#[tracing::instrument(skip(s3_client))]
 async fn delete_object_with_outcome(
     s3_client: &S3Client,
     bucket: &str,
@@ -211,6 +225,7 @@ async fn delete_object_with_outcome(
     }
 }
 
+#[tracing::instrument(skip(cloudfront_client))]
 async fn invalidate_cloudfront_with_context(
     cloudfront_client: &CloudFrontClient,
     distribution_id: &str,
@@ -325,6 +340,7 @@ pub async fn create_cloudfront_client(
 /// - Content-Type: from declared_mime_type
 /// - x-amz-checksum-sha256: base64-encoded SHA-256 checksum
 /// - x-amz-sdk-checksum-algorithm: SHA256
+#[tracing::instrument(skip(s3_client))]
 pub async fn presign_put_for_upload(
     s3_client: &S3Client,
     bucket: &str,
@@ -370,13 +386,24 @@ pub async fn copy_temp_object_to_final(
     temp_key: &str,
     final_key: &str,
 ) -> ThothResult<()> {
-    let copy_source = format!("{}/{}", bucket, temp_key);
+    copy_object(s3_client, bucket, temp_key, final_key).await
+}
+
+/// Copy an object to another key within the same bucket
+#[tracing::instrument(skip(s3_client))]
+pub async fn copy_object(
+    s3_client: &S3Client,
+    bucket: &str,
+    source_key: &str,
+    destination_key: &str,
+) -> ThothResult<()> {
+    let copy_source = format!("{}/{}", bucket, source_key);
 
     s3_client
         .copy_object()
         .bucket(bucket)
         .copy_source(copy_source)
-        .key(final_key)
+        .key(destination_key)
         .send()
         .await
         .map_err(|e| ThothError::InternalError(format!("Failed to copy object: {}", e)))?;
@@ -393,6 +420,7 @@ pub async fn delete_object(s3_client: &S3Client, bucket: &str, key: &str) -> Tho
 }
 
 /// Get object metadata (HeadObject) from S3
+#[tracing::instrument(skip(s3_client))]
 pub async fn head_object(
     s3_client: &S3Client,
     bucket: &str,
@@ -415,7 +443,8 @@ pub async fn head_object(
     Ok((bytes, mime_type))
 }
 
-async fn get_object_range_bytes(
+#[tracing::instrument(skip(s3_client))]
+pub(crate) async fn get_object_range_bytes(
     s3_client: &S3Client,
     bucket: &str,
     key: &str,
@@ -441,6 +470,32 @@ async fn get_object_range_bytes(
     Ok(bytes)
 }
 
+/// Download the full body of an object
+#[tracing::instrument(skip(s3_client))]
+pub(crate) async fn get_object_bytes(
+    s3_client: &S3Client,
+    bucket: &str,
+    key: &str,
+) -> ThothResult<Vec<u8>> {
+    let response = s3_client
+        .get_object()
+        .bucket(bucket)
+        .key(key)
+        .send()
+        .await
+        .map_err(|e| ThothError::InternalError(format!("Failed to get object: {}", e)))?;
+
+    let bytes = response
+        .body
+        .collect()
+        .await
+        .map_err(|e| ThothError::InternalError(format!("Failed to read object body: {}", e)))?
+        .into_bytes()
+        .to_vec();
+
+    Ok(bytes)
+}
+
 fn read_u32_be(data: &[u8], offset: usize) -> Option<u32> {
     let end = offset.checked_add(4)?;
     let bytes: [u8; 4] = data.get(offset..end)?.try_into().ok()?;
@@ -684,6 +739,22 @@ pub fn canonical_publication_key(doi_prefix: &str, doi_suffix: &str, extension:
     )
 }
 
+/// Compute the immutable object key for a numbered version of a publication file
+pub fn versioned_publication_key(
+    doi_prefix: &str,
+    doi_suffix: &str,
+    version_number: i32,
+    extension: &str,
+) -> String {
+    format!(
+        "{}/{}/versions/{}.{}",
+        doi_prefix.to_lowercase(),
+        doi_suffix.to_lowercase(),
+        version_number,
+        extension.to_lowercase()
+    )
+}
+
 /// Compute the canonical object key for a frontcover file
 pub fn canonical_frontcover_key(doi_prefix: &str, doi_suffix: &str, extension: &str) -> String {
     format!(
@@ -55,6 +55,13 @@ fn canonical_publication_key_lowercases_parts() {
     assert_eq!(key, "10.1234/abc/def.pdf");
 }
 
+#[test]
+fn versioned_publication_key_uses_versions_subpath() {
+    let key = versioned_publication_key("10.1234", "AbC/Def", 3, "PDF");
+    assert_eq!(key, "10.1234/abc/def/versions/3.pdf");
+    assert_ne!(key, canonical_publication_key("10.1234", "AbC/Def", "PDF"));
+}
+
 #[test]
 fn canonical_frontcover_key_lowercases_parts() {
     let key = canonical_frontcover_key("10.1234", "AbC/Def", "PNG");
@@ -128,6 +135,7 @@ fn create_hosting_imprint(
             default_currency: None,
             default_place: None,
             default_locale: None,
+            reject_invalid_files: Some(false),
         },
     )
     .expect("Failed to create hosting imprint")
@@ -461,3 +469,174 @@ fn duration_ms_helper_reports_milliseconds() {
     );
     assert_eq!(super::cleanup::duration_ms(Duration::from_secs(2)), 2000);
 }
+
+fn audited_file(mime_type: &str) -> File {
+    File {
+        file_id: Uuid::new_v4(),
+        file_type: FileType::Publication,
+        object_key: "10.1234/abc.pdf".to_string(),
+        mime_type: mime_type.to_string(),
+        bytes: 2048,
+        sha256: TEST_SHA256_HEX.to_string(),
+        work_id: None,
+        publication_id: Some(Uuid::new_v4()),
+        additional_resource_id: None,
+        work_featured_video_id: None,
+        cdn_url: "https://cdn.example.org/10.1234/abc.pdf".to_string(),
+        created_at: Default::default(),
+        updated_at: Default::default(),
+        validation_status: Default::default(),
+        validation_issues: serde_json::json!([]),
+        validated_at: None,
+    }
+}
+
+#[test]
+fn compare_file_to_object_passes_matching_object() {
+    let file = audited_file("application/pdf");
+    let observation = ObjectObservation::Present(StoredObject {
+        bytes: 2048,
+        mime_type: "application/pdf; charset=binary".to_string(),
+        sha256: Some(TEST_SHA256_HEX.to_uppercase()),
+    });
+
+    let audit = compare_file_to_object(Uuid::new_v4(), Uuid::new_v4(), &file, &observation);
+    assert!(audit.passed());
+    assert_eq!(audit.actual_bytes, Some(2048));
+    assert_eq!(audit.actual_sha256.as_deref(), Some(TEST_SHA256_HEX));
+}
+
+#[test]
+fn compare_file_to_object_flags_size_checksum_and_mime_drift() {
+    let file = audited_file("application/pdf");
+    let observation = ObjectObservation::Present(StoredObject {
+        bytes: 10,
+        mime_type: "application/octet-stream".to_string(),
+        sha256: Some("0".repeat(64)),
+    });
+
+    let audit = compare_file_to_object(Uuid::new_v4(), Uuid::new_v4(), &file, &observation);
+    assert!(!audit.passed());
+    assert!(audit.size_mismatch);
+    assert!(audit.checksum_mismatch);
+    assert!(audit.mime_type_mismatch);
+    assert!(!audit.object_missing);
+}
+
+#[test]
+fn compare_file_to_object_skips_checksum_when_unknown() {
+    let file = audited_file("application/pdf");
+    let observation = ObjectObservation::Present(StoredObject {
+        bytes: 2048,
+        mime_type: "application/pdf".to_string(),
+        sha256: None,
+    });
+
+    let audit = compare_file_to_object(Uuid::new_v4(), Uuid::new_v4(), &file, &observation);
+    assert!(audit.passed());
+    assert!(!audit.checksum_mismatch);
+}
+
+#[test]
+fn compare_file_to_object_records_missing_and_errored_objects() {
+    let file = audited_file("application/pdf");
+
+    let missing = compare_file_to_object(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &file,
+        &ObjectObservation::Missing,
+    );
+    assert!(missing.object_missing);
+    assert!(!missing.passed());
+
+    let errored = compare_file_to_object(
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+        &file,
+        &ObjectObservation::Error("Access denied".to_string()),
+    );
+    assert!(!errored.object_missing);
+    assert_eq!(errored.error_message.as_deref(), Some("Access denied"));
+    assert!(!errored.passed());
+}
+
+#[test]
+fn s3_checksum_to_hex_decodes_full_object_checksums_only() {
+    use base64::{engine::general_purpose, Engine as _};
+
+    let encoded = general_purpose::STANDARD.encode(hex::decode(TEST_SHA256_HEX).unwrap());
+    assert_eq!(
+        super::audit::s3_checksum_to_hex(&encoded).as_deref(),
+        Some(TEST_SHA256_HEX)
+    );
+    assert_eq!(
+        super::audit::s3_checksum_to_hex(&format!("{encoded}-3")),
+        None
+    );
+    assert_eq!(super::audit::s3_checksum_to_hex("not base64"), None);
+}
+
+#[test]
+fn file_all_for_imprint_collects_files_across_scopes() {
+    let (_guard, pool) = setup_test_db();
+    let publisher = create_publisher(pool.as_ref());
+    let imprint = create_hosting_imprint(pool.as_ref(), &publisher);
+    let other_imprint = create_hosting_imprint(pool.as_ref(), &publisher);
+    let work = create_work(pool.as_ref(), &imprint);
+    let other_work = create_work(pool.as_ref(), &other_imprint);
+    let publication = create_publication(pool.as_ref(), &work);
+
+    let new_file = |file_type: FileType,
+                    work_id: Option<Uuid>,
+                    publication_id: Option<Uuid>,
+                    object_key: String| NewFile {
+        file_type,
+        work_id,
+        publication_id,
+        additional_resource_id: None,
+        work_featured_video_id: None,
+        cdn_url: format!("https://cdn.example.org/{object_key}"),
+        object_key,
+        mime_type: "application/pdf".to_string(),
+        bytes: 1024,
+        sha256: TEST_SHA256_HEX.to_string(),
+    };
+
+    let cover = File::create(
+        pool.as_ref(),
+        &new_file(
+            FileType::Frontcover,
+            Some(work.work_id),
+            None,
+            format!("10.1234/{}/cover.jpg", Uuid::new_v4()),
+        ),
+    )
+    .expect("Failed to create cover file");
+    let publication_file = File::create(
+        pool.as_ref(),
+        &new_file(
+            FileType::Publication,
+            None,
+            Some(publication.publication_id),
+            format!("10.1234/{}.pdf", Uuid::new_v4()),
+        ),
+    )
+    .expect("Failed to create publication file");
+    File::create(
+        pool.as_ref(),
+        &new_file(
+            FileType::Frontcover,
+            Some(other_work.work_id),
+            None,
+            format!("10.1234/{}/cover.jpg", Uuid::new_v4()),
+        ),
+    )
+    .expect("Failed to create other imprint file");
+
+    let files = File::all_for_imprint(pool.as_ref(), &imprint.imprint_id)
+        .expect("Failed to load imprint files");
+    assert_eq!(files.len(), 2);
+    assert!(files.iter().any(|f| f.file_id == cover.file_id));
+    assert!(files.iter().any(|f| f.file_id == publication_file.file_id));
+}
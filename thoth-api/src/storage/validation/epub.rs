@@ -0,0 +1,402 @@
+use roxmltree::{Document, Node};
+use std::collections::HashSet;
+use std::ops::Range;
+
+use crate::model::file::FileType;
+
+use super::zip::{first_local_header, PartialSource, ZipArchive, ZipSource, METHOD_STORED};
+use super::{FileValidator, PartialObject, ValidationFindings};
+
+const EPUB_MIME_TYPE: &str = "application/epub+zip";
+const OPF_MIME_TYPE: &str = "application/oebps-package+xml";
+const NCX_MIME_TYPE: &str = "application/x-dtbncx+xml";
+const CONTAINER_PATH: &str = "META-INF/container.xml";
+const OPS_NAMESPACE: &str = "http://www.idpf.org/2007/ops";
+
+/// Structural checks of the OCF container, package document and navigation of an EPUB,
+/// modelled on the most common EPUBCheck errors.
+pub struct EpubValidator;
+
+/// Resolve a manifest `href` relative to the directory containing the package document.
+pub(super) fn resolve_href(base_dir: &str, href: &str) -> Option<String> {
+    let href = href.split('#').next().unwrap_or_default();
+    if href.is_empty() || href.contains("://") || href.starts_with("data:") {
+        return None;
+    }
+    let mut parts: Vec<String> = base_dir
+        .split('/')
+        .filter(|part| !part.is_empty())
+        .map(str::to_string)
+        .collect();
+    for segment in percent_decode(href).split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            segment => parts.push(segment.to_string()),
+        }
+    }
+    Some(parts.join("/"))
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            if let Some(byte) = value
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn parse_xml<'a>(
+    text: &'a str,
+    path: &str,
+    findings: &mut ValidationFindings,
+) -> Option<Document<'a>> {
+    match Document::parse(text) {
+        Ok(document) => Some(document),
+        Err(error) => {
+            findings.error(format!("'{path}' is not well-formed XML: {error}"));
+            None
+        }
+    }
+}
+
+fn child_elements<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.has_tag_name(name))
+}
+
+impl EpubValidator {
+    fn check_mimetype<S: ZipSource + ?Sized>(
+        archive: &ZipArchive<S>,
+        findings: &mut ValidationFindings,
+    ) {
+        let Some(first) = archive.first_entry() else {
+            findings.error("EPUB container is empty");
+            return;
+        };
+        if first.name != "mimetype" {
+            findings.error(format!(
+                "The first entry of the container must be 'mimetype', found '{}'",
+                first.name
+            ));
+            return;
+        }
+        if first.method != METHOD_STORED {
+            findings.error("The 'mimetype' entry must be stored uncompressed");
+        }
+        if archive.local_extra_len(first).unwrap_or_default() != 0 {
+            findings.error("The 'mimetype' entry must not have an extra field in its header");
+        }
+        match archive.read(first) {
+            Ok(contents) if contents == EPUB_MIME_TYPE.as_bytes() => {}
+            Ok(_) => findings.error(format!(
+                "The 'mimetype' entry must contain exactly '{EPUB_MIME_TYPE}'"
+            )),
+            Err(error) => findings.error(error),
+        }
+    }
+
+    /// Locate the package document declared in `META-INF/container.xml`.
+    fn package_path<S: ZipSource + ?Sized>(
+        archive: &ZipArchive<S>,
+        findings: &mut ValidationFindings,
+    ) -> Option<String> {
+        let Some(entry) = archive.find(CONTAINER_PATH) else {
+            findings.error(format!("Required file '{CONTAINER_PATH}' is missing"));
+            return None;
+        };
+        let text = archive
+            .read_to_string(entry)
+            .map_err(|error| findings.error(error))
+            .ok()?;
+        let document = parse_xml(&text, CONTAINER_PATH, findings)?;
+        let rootfile = document.descendants().find(|node| {
+            node.has_tag_name("rootfile")
+                && node.attribute("media-type") == Some(OPF_MIME_TYPE)
+                && node.attribute("full-path").is_some()
+        });
+        match rootfile.and_then(|node| node.attribute("full-path")) {
+            Some(path) => Some(path.to_string()),
+            None => {
+                findings.error(format!(
+                    "'{CONTAINER_PATH}' does not declare a rootfile of type '{OPF_MIME_TYPE}'"
+                ));
+                None
+            }
+        }
+    }
+
+    fn check_package<S: ZipSource + ?Sized>(
+        archive: &ZipArchive<S>,
+        package_path: &str,
+        findings: &mut ValidationFindings,
+    ) {
+        let Some(entry) = archive.find(package_path) else {
+            findings.error(format!("Package document '{package_path}' is missing"));
+            return;
+        };
+        let Ok(text) = archive
+            .read_to_string(entry)
+            .map_err(|error| findings.error(error))
+        else {
+            return;
+        };
+        let Some(document) = parse_xml(&text, package_path, findings) else {
+            return;
+        };
+        let package = document.root_element();
+        if !package.has_tag_name("package") {
+            findings.error(format!(
+                "Root element of '{package_path}' must be 'package', found '{}'",
+                package.tag_name().name()
+            ));
+            return;
+        }
+        let version = package.attribute("version").unwrap_or_default();
+        let is_epub3 = version.starts_with('3');
+        if !is_epub3 && !version.starts_with('2') {
+            findings.error(format!(
+                "Unsupported or missing package version '{version}'"
+            ));
+        }
+        let base_dir = package_path
+            .rsplit_once('/')
+            .map(|(dir, _)| dir)
+            .unwrap_or_default();
+
+        Self::check_metadata(package, findings);
+
+        let Some(manifest) = child_elements(package, "manifest").next() else {
+            findings.error("Package document has no manifest");
+            return;
+        };
+        let mut manifest_ids = HashSet::new();
+        let mut nav_path = None;
+        let mut ncx = Vec::new();
+        for item in child_elements(manifest, "item") {
+            let (Some(id), Some(href)) = (item.attribute("id"), item.attribute("href")) else {
+                findings.error("Manifest item is missing its 'id' or 'href' attribute");
+                continue;
+            };
+            if !manifest_ids.insert(id) {
+                findings.error(format!("Duplicate manifest item id '{id}'"));
+            }
+            let Some(path) = resolve_href(base_dir, href) else {
+                continue;
+            };
+            if archive.find(&path).is_none() {
+                findings.error(format!(
+                    "Manifest item '{id}' refers to '{path}', which is not in the container"
+                ));
+            }
+            if item
+                .attribute("properties")
+                .is_some_and(|properties| properties.split_whitespace().any(|p| p == "nav"))
+            {
+                nav_path = Some(path.clone());
+            }
+            if item.attribute("media-type") == Some(NCX_MIME_TYPE) {
+                ncx.push((id, path));
+            }
+        }
+
+        match child_elements(package, "spine").next() {
+            None => findings.error("Package document has no spine"),
+            Some(spine) => {
+                let itemrefs: Vec<_> = child_elements(spine, "itemref").collect();
+                if itemrefs.is_empty() {
+                    findings.error("Spine does not reference any content documents");
+                }
+                for itemref in itemrefs {
+                    match itemref.attribute("idref") {
+                        Some(idref) if manifest_ids.contains(idref) => {}
+                        Some(idref) => findings.error(format!(
+                            "Spine item refers to '{idref}', which is not in the manifest"
+                        )),
+                        None => findings.error("Spine item is missing its 'idref' attribute"),
+                    }
+                }
+                if !is_epub3 {
+                    match spine.attribute("toc") {
+                        Some(toc) if ncx.iter().any(|(id, _)| *id == toc) => {}
+                        _ => findings.error(
+                            "EPUB 2 spine must reference an NCX table of contents via its 'toc' attribute",
+                        ),
+                    }
+                }
+            }
+        }
+
+        if is_epub3 {
+            match nav_path {
+                Some(path) => Self::check_nav(archive, &path, findings),
+                None => findings.error(
+                    "EPUB 3 manifest must declare a navigation document (properties=\"nav\")",
+                ),
+            }
+        }
+    }
+
+    fn check_metadata(package: Node, findings: &mut ValidationFindings) {
+        let Some(metadata) = child_elements(package, "metadata").next() else {
+            findings.error("Package document has no metadata");
+            return;
+        };
+        for required in ["identifier", "title", "language"] {
+            let present = child_elements(metadata, required)
+                .any(|node| node.text().is_some_and(|text| !text.trim().is_empty()));
+            if !present {
+                findings.error(format!(
+                    "Package metadata is missing a non-empty dc:{required}"
+                ));
+            }
+        }
+        match package.attribute("unique-identifier") {
+            Some(unique_id) => {
+                let matches = child_elements(metadata, "identifier")
+                    .any(|node| node.attribute("id") == Some(unique_id));
+                if !matches {
+                    findings.error(format!(
+                        "unique-identifier '{unique_id}' does not match any dc:identifier"
+                    ));
+                }
+            }
+            None => findings.error("Package element is missing its 'unique-identifier' attribute"),
+        }
+    }
+
+    fn check_nav<S: ZipSource + ?Sized>(
+        archive: &ZipArchive<S>,
+        nav_path: &str,
+        findings: &mut ValidationFindings,
+    ) {
+        let Some(entry) = archive.find(nav_path) else {
+            // already reported as a missing manifest item
+            return;
+        };
+        let Ok(text) = archive
+            .read_to_string(entry)
+            .map_err(|error| findings.error(error))
+        else {
+            return;
+        };
+        let Some(document) = parse_xml(&text, nav_path, findings) else {
+            return;
+        };
+        let toc = document.descendants().find(|node| {
+            node.has_tag_name("nav")
+                && node
+                    .attribute((OPS_NAMESPACE, "type"))
+                    .is_some_and(|types| types.split_whitespace().any(|t| t == "toc"))
+        });
+        match toc {
+            None => findings.error(format!(
+                "Navigation document '{nav_path}' has no nav element with epub:type=\"toc\""
+            )),
+            Some(toc) => {
+                if !toc.descendants().any(|node| node.has_tag_name("ol")) {
+                    findings.error("Table of contents nav element must contain an ordered list");
+                }
+            }
+        }
+    }
+
+    fn check_archive<S: ZipSource + ?Sized>(data: &S, findings: &mut ValidationFindings) {
+        let archive = match ZipArchive::parse(data) {
+            Ok(archive) => archive,
+            Err(error) => {
+                findings.error(format!("EPUB is not a valid ZIP container: {error}"));
+                return;
+            }
+        };
+        if archive.entries().is_empty() {
+            findings.error("EPUB container is empty");
+            return;
+        }
+
+        Self::check_mimetype(&archive, findings);
+        if let Some(package_path) = Self::package_path(&archive, findings) {
+            Self::check_package(&archive, &package_path, findings);
+        }
+    }
+}
+
+impl FileValidator for EpubValidator {
+    fn name(&self) -> &'static str {
+        "epub"
+    }
+
+    fn applies_to(&self, file_type: FileType, mime_type: &str, extension: &str) -> bool {
+        file_type == FileType::Publication
+            && (extension.eq_ignore_ascii_case("epub") || mime_type.starts_with(EPUB_MIME_TYPE))
+    }
+
+    fn validate(&self, _mime_type: &str, data: &[u8], findings: &mut ValidationFindings) {
+        Self::check_archive(data, findings);
+    }
+
+    /// Run the same checks as for a complete file, reading the central directory and the
+    /// entries inspected from ranges of the archive.
+    fn check_ranges(
+        &self,
+        _mime_type: &str,
+        object: &PartialObject,
+        findings: &mut ValidationFindings,
+    ) -> Option<Range<u64>> {
+        let source = PartialSource::new(object);
+        let mut archive_findings = ValidationFindings::default();
+        Self::check_archive(&source, &mut archive_findings);
+        let missing = source.missing();
+        if missing.is_none() {
+            findings.extend(archive_findings);
+        }
+        missing
+    }
+
+    /// The container must open with the uncompressed `mimetype` entry, which fixes its first bytes.
+    fn check_signature(&self, _mime_type: &str, head: &[u8], findings: &mut ValidationFindings) {
+        let first = match first_local_header(head) {
+            Ok(first) => first,
+            Err(error) => {
+                findings.error(format!("EPUB is not a valid ZIP container: {error}"));
+                return;
+            }
+        };
+        if first.name != "mimetype" {
+            findings.error(format!(
+                "The first entry of the container must be 'mimetype', found '{}'",
+                first.name
+            ));
+            return;
+        }
+        if first.method != METHOD_STORED {
+            findings.error("The 'mimetype' entry must be stored uncompressed");
+        }
+        if first.extra_len != 0 {
+            findings.error("The 'mimetype' entry must not have an extra field in its header");
+        }
+        if !head[first.data_offset.min(head.len())..].starts_with(EPUB_MIME_TYPE.as_bytes()) {
+            findings.error(format!(
+                "The 'mimetype' entry must contain exactly '{EPUB_MIME_TYPE}'"
+            ));
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use flate2::{read::ZlibDecoder, Crc};
+use std::io::Read;
+
+use crate::model::file::FileType;
+
+use super::{FileValidator, ValidationFindings};
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+/// Refuse to inflate PNG image data larger than this, to guard against decompression bombs
+const MAX_PNG_IMAGE_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Checks that a front cover is a decodable JPEG, PNG or WebP image of the declared type.
+pub struct CoverImageValidator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ImageFormat {
+    Jpeg,
+    Png,
+    Webp,
+}
+
+impl ImageFormat {
+    pub(super) fn detect(data: &[u8]) -> Option<Self> {
+        if data.starts_with(&[0xff, 0xd8, 0xff]) {
+            Some(ImageFormat::Jpeg)
+        } else if data.starts_with(PNG_SIGNATURE) {
+            Some(ImageFormat::Png)
+        } else if data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+            Some(ImageFormat::Webp)
+        } else {
+            None
+        }
+    }
+
+    fn mime_type(self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Png => "image/png",
+            ImageFormat::Webp => "image/webp",
+        }
+    }
+}
+
+/// Detect the image format and check that it matches the declared MIME type.
+fn check_format(
+    mime_type: &str,
+    data: &[u8],
+    findings: &mut ValidationFindings,
+) -> Option<ImageFormat> {
+    let Some(format) = ImageFormat::detect(data) else {
+        findings.error("Cover is not a recognisable JPEG, PNG or WebP image");
+        return None;
+    };
+    let declared = mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let declared = if declared == "image/jpg" {
+        "image/jpeg"
+    } else {
+        declared.as_str()
+    };
+    if declared != format.mime_type() && declared != "application/octet-stream" {
+        findings.error(format!(
+            "Cover is a {} image but is served as '{declared}'",
+            format.mime_type()
+        ));
+    }
+    Some(format)
+}
+
+fn read_u16_be(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes: [u8; 2] = data.get(offset..offset.checked_add(2)?)?.try_into().ok()?;
+    Some(u16::from_be_bytes(bytes))
+}
+
+fn read_u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes: [u8; 4] = data.get(offset..offset.checked_add(4)?)?.try_into().ok()?;
+    Some(u32::from_be_bytes(bytes))
+}
+
+fn read_u32_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes: [u8; 4] = data.get(offset..offset.checked_add(4)?)?.try_into().ok()?;
+    Some(u32::from_le_bytes(bytes))
+}
+
+/// Walk JPEG segments up to the start of scan, returning the frame dimensions.
+fn check_jpeg(data: &[u8], findings: &mut ValidationFindings) -> Option<(u32, u32)> {
+    let mut offset = 2;
+    let mut dimensions = None;
+    loop {
+        if data.get(offset) != Some(&0xff) {
+            findings.error(format!("JPEG marker expected at offset {offset}"));
+            return None;
+        }
+        while data.get(offset) == Some(&0xff) {
+            offset += 1;
+        }
+        let Some(&marker) = data.get(offset) else {
+            findings.error("JPEG ends before the image data");
+            return None;
+        };
+        offset += 1;
+        if marker == 0xd9 {
+            findings.error("JPEG ends before the image data");
+            return None;
+        }
+        if matches!(marker, 0x01 | 0xd0..=0xd7) {
+            continue;
+        }
+        let Some(length) = read_u16_be(data, offset).map(usize::from) else {
+            findings.error("JPEG segment is truncated");
+            return None;
+        };
+        if length < 2 || offset + length > data.len() {
+            findings.error(format!(
+                "JPEG segment at offset {offset} has an invalid length"
+            ));
+            return None;
+        }
+        // SOF0-SOF15, excluding DHT (C4), JPG (C8) and DAC (CC)
+        if matches!(marker, 0xc0..=0xcf) && !matches!(marker, 0xc4 | 0xc8 | 0xcc) {
+            let height = read_u16_be(data, offset + 3).unwrap_or_default();
+            let width = read_u16_be(data, offset + 5).unwrap_or_default();
+            dimensions = Some((u32::from(width), u32::from(height)));
+        }
+        if marker == 0xda {
+            break;
+        }
+        offset += length;
+    }
+
+    let Some((width, height)) = dimensions else {
+        findings.error("JPEG has no frame header (SOF) before its image data");
+        return None;
+    };
+    if width == 0 || height == 0 {
+        findings.error("JPEG frame header declares a zero width or height");
+        return None;
+    }
+    if rfind_eoi(data).is_none() {
+        findings.error("JPEG end-of-image marker not found; the file may be truncated");
+    }
+    Some((width, height))
+}
+
+fn rfind_eoi(data: &[u8]) -> Option<usize> {
+    data.windows(2).rposition(|window| window == [0xff, 0xd9])
+}
+
+fn png_bits_per_pixel(color_type: u8, bit_depth: u8) -> Option<u64> {
+    let channels = match (color_type, bit_depth) {
+        (0, 1 | 2 | 4 | 8 | 16) => 1,
+        (3, 1 | 2 | 4 | 8) => 1,
+        (2, 8 | 16) => 3,
+        (4, 8 | 16) => 2,
+        (6, 8 | 16) => 4,
+        _ => return None,
+    };
+    Some(channels * u64::from(bit_depth))
+}
+
+/// Walk PNG chunks verifying their CRCs, then inflate the image data.
+fn check_png(data: &[u8], findings: &mut ValidationFindings) -> Option<(u32, u32)> {
+    let mut offset = PNG_SIGNATURE.len();
+    let mut header = None;
+    let mut image_data = Vec::new();
+    let mut ended = false;
+    while offset < data.len() {
+        let (Some(length), Some(kind)) = (
+            read_u32_be(data, offset).map(|length| length as usize),
+            data.get(offset + 4..offset + 8),
+        ) else {
+            findings.error("PNG chunk header is truncated");
+            return None;
+        };
+        let Some(body) = offset
+            .checked_add(8 + length)
+            .and_then(|end| data.get(offset + 8..end))
+        else {
+            findings.error(format!(
+                "PNG chunk '{}' is truncated",
+                String::from_utf8_lossy(kind)
+            ));
+            return None;
+        };
+        let mut crc = Crc::new();
+        crc.update(kind);
+        crc.update(body);
+        if read_u32_be(data, offset + 8 + length) != Some(crc.sum()) {
+            findings.error(format!(
+                "PNG chunk '{}' fails its CRC check",
+                String::from_utf8_lossy(kind)
+            ));
+            return None;
+        }
+        match kind {
+            b"IHDR" if header.is_none() && offset == PNG_SIGNATURE.len() && length == 13 => {
+                header = Some((
+                    read_u32_be(body, 0).unwrap_or_default(),
+                    read_u32_be(body, 4).unwrap_or_default(),
+                    body[8],
+                    body[9],
+                    body[12],
+                ));
+            }
+            b"IHDR" => {
+                findings.error("PNG header chunk (IHDR) is misplaced or malformed");
+                return None;
+            }
+            b"IDAT" => image_data.extend_from_slice(body),
+            b"IEND" => {
+                ended = true;
+                break;
+            }
+            _ => {}
+        }
+        offset += 12 + length;
+    }
+
+    let Some((width, height, bit_depth, color_type, interlace)) = header else {
+        findings.error("PNG does not start with a header chunk (IHDR)");
+        return None;
+    };
+    if width == 0 || height == 0 {
+        findings.error("PNG header declares a zero width or height");
+        return None;
+    }
+    let Some(bits_per_pixel) = png_bits_per_pixel(color_type, bit_depth) else {
+        findings.error(format!(
+            "PNG declares an invalid colour type ({color_type}) and bit depth ({bit_depth})"
+        ));
+        return None;
+    };
+    if !ended {
+        findings.error("PNG end chunk (IEND) not found; the file may be truncated");
+    }
+    if image_data.is_empty() {
+        findings.error("PNG contains no image data (IDAT)");
+        return None;
+    }
+
+    let mut decoded = Vec::new();
+    if let Err(error) = ZlibDecoder::new(image_data.as_slice())
+        .take(MAX_PNG_IMAGE_BYTES)
+        .read_to_end(&mut decoded)
+    {
+        findings.error(format!("PNG image data could not be decompressed: {error}"));
+        return None;
+    }
+    // Each scanline is prefixed with a filter byte; interlaced images have a different layout
+    if interlace == 0 {
+        let row_bytes = (u64::from(width) * bits_per_pixel).div_ceil(8) + 1;
+        let expected = row_bytes * u64::from(height);
+        if (decoded.len() as u64) < expected {
+            findings.error(format!(
+                "PNG image data is incomplete: expected {expected} bytes, found {}",
+                decoded.len()
+            ));
+        }
+    }
+    Some((width, height))
+}
+
+/// Check the RIFF container of a WebP image and read its canvas dimensions.
+fn check_webp(data: &[u8], findings: &mut ValidationFindings) -> Option<(u32, u32)> {
+    let riff_size = read_u32_le(data, 4).unwrap_or_default() as usize;
+    if riff_size + 8 > data.len() {
+        findings.error("WebP container is truncated");
+        return None;
+    }
+    let Some(kind) = data.get(12..16) else {
+        findings.error("WebP container has no image chunk");
+        return None;
+    };
+    let chunk = &data[20.min(data.len())..];
+    let dimensions = match kind {
+        b"VP8 " if chunk.get(3..6) == Some(&[0x9d, 0x01, 0x2a]) => {
+            let width = u32::from(read_u16_le(chunk, 6).unwrap_or_default() & 0x3fff);
+            let height = u32::from(read_u16_le(chunk, 8).unwrap_or_default() & 0x3fff);
+            Some((width, height))
+        }
+        b"VP8L" if chunk.first() == Some(&0x2f) => {
+            read_u32_le(chunk, 1).map(|bits| ((bits & 0x3fff) + 1, ((bits >> 14) & 0x3fff) + 1))
+        }
+        b"VP8X" if chunk.len() >= 10 => {
+            let read_u24 = |offset: usize| {
+                u32::from(chunk[offset])
+                    | (u32::from(chunk[offset + 1]) << 8)
+                    | (u32::from(chunk[offset + 2]) << 16)
+            };
+            Some((read_u24(4) + 1, read_u24(7) + 1))
+        }
+        _ => None,
+    };
+    match dimensions {
+        Some((width, height)) if width > 0 && height > 0 => Some((width, height)),
+        _ => {
+            findings.error("WebP image chunk is missing or malformed");
+            None
+        }
+    }
+}
+
+fn read_u16_le(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes: [u8; 2] = data.get(offset..offset.checked_add(2)?)?.try_into().ok()?;
+    Some(u16::from_le_bytes(bytes))
+}
+
+impl FileValidator for CoverImageValidator {
+    fn name(&self) -> &'static str {
+        "image"
+    }
+
+    fn applies_to(&self, file_type: FileType, _mime_type: &str, _extension: &str) -> bool {
+        file_type == FileType::Frontcover
+    }
+
+    fn validate(&self, mime_type: &str, data: &[u8], findings: &mut ValidationFindings) {
+        let Some(format) = check_format(mime_type, data, findings) else {
+            return;
+        };
+        let dimensions = match format {
+            ImageFormat::Jpeg => check_jpeg(data, findings),
+            ImageFormat::Png => check_png(data, findings),
+            ImageFormat::Webp => check_webp(data, findings),
+        };
+        if dimensions.is_none() && findings.is_empty() {
+            findings.error(format!(
+                "Cover could not be decoded as {}",
+                format.mime_type()
+            ));
+        }
+    }
+
+    fn check_signature(&self, mime_type: &str, head: &[u8], findings: &mut ValidationFindings) {
+        check_format(mime_type, head, findings);
+    }
+}
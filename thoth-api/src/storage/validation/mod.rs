@@ -0,0 +1,452 @@
+//! Content validation of uploaded files.
+//!
+//! Validators are run on completion of an upload, before the object is promoted to its final
+//! key. Each validator declares which files it applies to and records its findings as errors or
+//! warnings; the pipeline combines them into a single report that is stored on the `file` row.
+
+use log::info;
+use std::future::Future;
+use std::ops::Range;
+use thoth_errors::{ThothError, ThothResult};
+
+use crate::model::file::{
+    FileType, FileValidationIssue, FileValidationSeverity, FileValidationStatus,
+};
+
+use super::S3Client;
+
+mod epub;
+mod image;
+mod pdf;
+mod zip;
+
+pub use epub::EpubValidator;
+pub use image::CoverImageValidator;
+pub use pdf::PdfValidator;
+
+/// Objects up to this size are downloaded and validated in full. Larger objects only have their
+/// structure checked through ranged reads, so that concurrent uploads cannot exhaust the
+/// server's memory.
+pub const MAX_VALIDATION_BYTES: i64 = 16 * 1024 * 1024;
+
+/// Number of leading bytes fetched to check the signature of objects too large to validate.
+pub const SIGNATURE_BYTES: i64 = 1024;
+
+/// Number of trailing bytes fetched from objects too large to validate: enough to hold a PDF
+/// trailer, or a ZIP end of central directory record with the longest possible comment.
+pub const TAIL_BYTES: u64 = 66 * 1024;
+
+/// Smallest ranged read made on behalf of a validator, to limit the number of round trips.
+pub const MIN_RANGED_READ_BYTES: u64 = 64 * 1024;
+
+/// Most bytes fetched in total to check the structure of an object too large to validate.
+pub const MAX_RANGED_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Findings collected by a single validator.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationFindings {
+    findings: Vec<(FileValidationSeverity, String)>,
+}
+
+impl ValidationFindings {
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.findings
+            .push((FileValidationSeverity::Severe, message.into()));
+    }
+
+    pub fn warning(&mut self, message: impl Into<String>) {
+        self.findings
+            .push((FileValidationSeverity::Warning, message.into()));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    pub fn extend(&mut self, other: ValidationFindings) {
+        self.findings.extend(other.findings);
+    }
+}
+
+/// The parts of an object too large to download that have been fetched with ranged reads.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PartialObject {
+    len: u64,
+    segments: Vec<(u64, Vec<u8>)>,
+}
+
+impl PartialObject {
+    pub fn new(len: u64) -> Self {
+        PartialObject {
+            len,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Size of the whole object.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Record the bytes read from the object starting at `start`.
+    pub fn insert(&mut self, start: u64, bytes: Vec<u8>) {
+        self.segments.push((start, bytes));
+    }
+
+    /// Total number of bytes fetched so far.
+    pub fn fetched_bytes(&self) -> u64 {
+        self.segments
+            .iter()
+            .map(|(_, bytes)| bytes.len() as u64)
+            .sum()
+    }
+
+    /// The leading bytes of the object.
+    pub fn head(&self) -> &[u8] {
+        self.get_from(0).unwrap_or_default()
+    }
+
+    /// The trailing bytes of the object, with the offset at which they start.
+    pub fn tail(&self) -> (u64, &[u8]) {
+        self.segments
+            .iter()
+            .filter(|(start, bytes)| start + bytes.len() as u64 == self.len)
+            .min_by_key(|(start, _)| *start)
+            .map(|(start, bytes)| (*start, bytes.as_slice()))
+            .unwrap_or((self.len, &[]))
+    }
+
+    /// The bytes in `range`, if they were fetched in a single read.
+    pub fn get(&self, range: Range<u64>) -> Option<&[u8]> {
+        self.segments.iter().find_map(|(start, bytes)| {
+            let end = start + bytes.len() as u64;
+            (*start <= range.start && range.start <= range.end && range.end <= end)
+                .then(|| &bytes[(range.start - start) as usize..(range.end - start) as usize])
+        })
+    }
+
+    /// The longest fetched run of bytes starting at `offset`.
+    pub fn get_from(&self, offset: u64) -> Option<&[u8]> {
+        self.segments
+            .iter()
+            .filter(|(start, bytes)| *start <= offset && offset < start + bytes.len() as u64)
+            .map(|(start, bytes)| &bytes[(offset - start) as usize..])
+            .max_by_key(|bytes| bytes.len())
+    }
+}
+
+/// A check run against the contents of an uploaded file.
+pub trait FileValidator: Send + Sync {
+    /// Short name recorded alongside each finding, e.g. `epub`
+    fn name(&self) -> &'static str;
+
+    /// Whether this validator inspects files with the given metadata.
+    fn applies_to(&self, file_type: FileType, mime_type: &str, extension: &str) -> bool;
+
+    /// Inspect the full contents of the file, as stored with the given MIME type.
+    fn validate(&self, mime_type: &str, data: &[u8], findings: &mut ValidationFindings);
+
+    /// Inspect only the first `SIGNATURE_BYTES` of a file too large to validate in full.
+    ///
+    /// Validators that cannot judge a file from its leading bytes find nothing.
+    fn check_signature(&self, _mime_type: &str, _head: &[u8], _findings: &mut ValidationFindings) {}
+
+    /// Check the structure of a file too large to validate in full, from parts of it.
+    ///
+    /// `object` holds the first `SIGNATURE_BYTES` and last `TAIL_BYTES` of the file, and every
+    /// range asked for by earlier calls. Returning a range asks for it to be fetched and the
+    /// check run again; only the findings of the final call are kept. By default only the
+    /// signature is checked.
+    fn check_ranges(
+        &self,
+        mime_type: &str,
+        object: &PartialObject,
+        findings: &mut ValidationFindings,
+    ) -> Option<Range<u64>> {
+        self.check_signature(mime_type, object.head(), findings);
+        None
+    }
+}
+
+/// Combined outcome of every validator that applied to a file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FileValidationReport {
+    pub status: FileValidationStatus,
+    pub issues: Vec<FileValidationIssue>,
+}
+
+impl FileValidationReport {
+    pub fn not_validated() -> Self {
+        Self::default()
+    }
+
+    /// Derive the overall status from the issues found.
+    pub fn from_issues(issues: Vec<FileValidationIssue>) -> Self {
+        let status = if issues
+            .iter()
+            .any(|issue| issue.severity == FileValidationSeverity::Severe)
+        {
+            FileValidationStatus::Failed
+        } else if issues.is_empty() {
+            FileValidationStatus::Passed
+        } else {
+            FileValidationStatus::PassedWithWarnings
+        };
+        FileValidationReport { status, issues }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.status == FileValidationStatus::Failed
+    }
+
+    /// Error raised when an imprint rejects files that fail validation.
+    pub fn rejection_error(&self) -> ThothError {
+        let errors: Vec<String> = self
+            .issues
+            .iter()
+            .filter(|issue| issue.severity == FileValidationSeverity::Severe)
+            .map(|issue| format!("[{}] {}", issue.validator, issue.message))
+            .collect();
+        ThothError::FileValidationFailed(errors.join("; "))
+    }
+}
+
+/// An ordered set of validators.
+pub struct ValidationPipeline {
+    validators: Vec<Box<dyn FileValidator>>,
+}
+
+impl Default for ValidationPipeline {
+    /// EPUB and PDF structure checks for publication files, and decodability checks for covers.
+    fn default() -> Self {
+        Self::empty()
+            .with_validator(EpubValidator)
+            .with_validator(PdfValidator)
+            .with_validator(CoverImageValidator)
+    }
+}
+
+impl ValidationPipeline {
+    pub fn empty() -> Self {
+        ValidationPipeline {
+            validators: Vec::new(),
+        }
+    }
+
+    pub fn with_validator(mut self, validator: impl FileValidator + 'static) -> Self {
+        self.validators.push(Box::new(validator));
+        self
+    }
+
+    pub fn applies_to(&self, file_type: FileType, mime_type: &str, extension: &str) -> bool {
+        self.validators
+            .iter()
+            .any(|validator| validator.applies_to(file_type, mime_type, extension))
+    }
+
+    /// Run every applicable validator over the file contents.
+    pub fn validate(
+        &self,
+        file_type: FileType,
+        mime_type: &str,
+        extension: &str,
+        data: &[u8],
+    ) -> FileValidationReport {
+        self.run(file_type, mime_type, extension, |validator, findings| {
+            validator.validate(mime_type, data, findings)
+        })
+        .map(FileValidationReport::from_issues)
+        .unwrap_or_else(FileValidationReport::not_validated)
+    }
+
+    /// Check the structure of a file too large to validate, from the parts of it fetched so far.
+    ///
+    /// Returns the next range that a validator needs in order to complete its checks. Once
+    /// `exhausted`, no more ranges can be fetched and the file is reported as not validated,
+    /// unless the checks that could be made already failed.
+    pub fn check_ranges(
+        &self,
+        file_type: FileType,
+        mime_type: &str,
+        extension: &str,
+        object: &PartialObject,
+        exhausted: bool,
+    ) -> Result<FileValidationReport, Range<u64>> {
+        let mut wanted = None;
+        let Some(mut issues) = self.run(file_type, mime_type, extension, |validator, findings| {
+            if let Some(range) = validator.check_ranges(mime_type, object, findings) {
+                wanted.get_or_insert(range);
+            }
+        }) else {
+            return Ok(FileValidationReport::not_validated());
+        };
+        match wanted {
+            Some(range) if !exhausted => Err(range),
+            Some(_) => {
+                issues.push(FileValidationIssue {
+                    validator: "pipeline".to_string(),
+                    severity: FileValidationSeverity::Warning,
+                    message: format!(
+                        "File is larger than {MAX_VALIDATION_BYTES} bytes and its structure could not be checked within {MAX_RANGED_BYTES} bytes of ranged reads"
+                    ),
+                });
+                let report = FileValidationReport::from_issues(issues);
+                if report.failed() {
+                    Ok(report)
+                } else {
+                    Ok(FileValidationReport {
+                        status: FileValidationStatus::NotValidated,
+                        ..report
+                    })
+                }
+            }
+            None => {
+                issues.push(FileValidationIssue {
+                    validator: "pipeline".to_string(),
+                    severity: FileValidationSeverity::Warning,
+                    message: format!(
+                        "File is larger than {MAX_VALIDATION_BYTES} bytes; only its structure was checked"
+                    ),
+                });
+                Ok(FileValidationReport::from_issues(issues))
+            }
+        }
+    }
+
+    /// Apply `check` with every applicable validator, collecting their issues, or `None` if no
+    /// validator applies.
+    fn run(
+        &self,
+        file_type: FileType,
+        mime_type: &str,
+        extension: &str,
+        mut check: impl FnMut(&dyn FileValidator, &mut ValidationFindings),
+    ) -> Option<Vec<FileValidationIssue>> {
+        let mut applied = false;
+        let mut issues = Vec::new();
+        for validator in self
+            .validators
+            .iter()
+            .filter(|validator| validator.applies_to(file_type, mime_type, extension))
+        {
+            applied = true;
+            let mut findings = ValidationFindings::default();
+            check(validator.as_ref(), &mut findings);
+            issues.extend(findings.findings.into_iter().map(|(severity, message)| {
+                FileValidationIssue {
+                    validator: validator.name().to_string(),
+                    severity,
+                    message,
+                }
+            }));
+        }
+        applied.then_some(issues)
+    }
+}
+
+/// Check the structure of an object of `len` bytes with the pipeline, reading the parts it needs
+/// with `fetch`.
+///
+/// The first `SIGNATURE_BYTES` and last `TAIL_BYTES` are read up front; validators then ask for
+/// further ranges, such as a PDF cross-reference table or a ZIP central directory, until their
+/// checks are complete or `MAX_RANGED_BYTES` have been read.
+pub async fn check_object_ranges<F, Fut>(
+    pipeline: &ValidationPipeline,
+    file_type: FileType,
+    mime_type: &str,
+    extension: &str,
+    len: u64,
+    mut fetch: F,
+) -> ThothResult<FileValidationReport>
+where
+    F: FnMut(Range<u64>) -> Fut,
+    Fut: Future<Output = ThothResult<Vec<u8>>>,
+{
+    let mut object = PartialObject::new(len);
+    for range in [
+        0..(SIGNATURE_BYTES as u64).min(len),
+        len.saturating_sub(TAIL_BYTES)..len,
+    ] {
+        let start = range.start;
+        object.insert(start, fetch(range).await?);
+    }
+
+    let mut exhausted = false;
+    loop {
+        match pipeline.check_ranges(file_type, mime_type, extension, &object, exhausted) {
+            Ok(report) => return Ok(report),
+            Err(wanted) => {
+                let wanted = wanted.start..wanted.end.min(len);
+                let range = wanted.start
+                    ..wanted
+                        .end
+                        .max(wanted.start + MIN_RANGED_READ_BYTES)
+                        .min(len);
+                // a range that was already fetched, or lies outside the object, cannot help
+                if wanted.is_empty()
+                    || object.get(wanted).is_some()
+                    || object.fetched_bytes() + (range.end - range.start) > MAX_RANGED_BYTES
+                {
+                    exhausted = true;
+                } else {
+                    let start = range.start;
+                    object.insert(start, fetch(range).await?);
+                }
+            }
+        }
+    }
+}
+
+/// Download a stored object and run the pipeline over it.
+///
+/// Objects to which no validator applies are not downloaded. Objects above
+/// `MAX_VALIDATION_BYTES` are not downloaded in full: their structure is checked from ranged
+/// reads of the parts the validators need.
+#[allow(clippy::too_many_arguments)]
+pub async fn validate_stored_object(
+    pipeline: &ValidationPipeline,
+    s3_client: &S3Client,
+    bucket: &str,
+    key: &str,
+    file_type: FileType,
+    mime_type: &str,
+    extension: &str,
+    bytes: i64,
+) -> ThothResult<FileValidationReport> {
+    if !pipeline.applies_to(file_type, mime_type, extension) {
+        return Ok(FileValidationReport::not_validated());
+    }
+
+    let report = if bytes > MAX_VALIDATION_BYTES {
+        check_object_ranges(
+            pipeline,
+            file_type,
+            mime_type,
+            extension,
+            bytes as u64,
+            |range| {
+                let byte_range = format!("bytes={}-{}", range.start, range.end - 1);
+                async move {
+                    super::get_object_range_bytes(s3_client, bucket, key, &byte_range).await
+                }
+            },
+        )
+        .await?
+    } else {
+        let data = super::get_object_bytes(s3_client, bucket, key).await?;
+        pipeline.validate(file_type, mime_type, extension, &data)
+    };
+    info!(
+        "object_key={key} file_type={file_type} validation_status={} validation_issues={}",
+        report.status,
+        report.issues.len(),
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,400 @@
+use std::ops::Range;
+
+use crate::model::file::FileType;
+
+use super::{FileValidator, PartialObject, ValidationFindings};
+
+const HEADER_SEARCH_BYTES: usize = 1024;
+const TRAILER_SEARCH_BYTES: usize = 1024;
+/// Size of the first read of a cross-reference section in a file too large to validate in full
+const XREF_WINDOW_BYTES: u64 = 64 * 1024;
+const XREF_ENTRY_LEN: usize = 20;
+/// Stop reporting individual broken xref entries after this many
+const MAX_REPORTED_XREF_ERRORS: usize = 5;
+
+/// Checks the header, cross-reference table and trailer of a PDF, and reports hints about
+/// PDF/A conformance as warnings.
+pub struct PdfValidator;
+
+pub(super) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+pub(super) fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .rposition(|window| window == needle)
+}
+
+fn skip_whitespace(data: &[u8], mut offset: usize) -> usize {
+    while data
+        .get(offset)
+        .is_some_and(|byte| byte.is_ascii_whitespace())
+    {
+        offset += 1;
+    }
+    offset
+}
+
+/// Parse an unsigned decimal integer, returning it with the offset just past its last digit.
+fn read_integer(data: &[u8], offset: usize) -> Option<(u64, usize)> {
+    let digits = data
+        .get(offset..)?
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if digits == 0 {
+        return None;
+    }
+    let value = std::str::from_utf8(&data[offset..offset + digits])
+        .ok()?
+        .parse()
+        .ok()?;
+    Some((value, offset + digits))
+}
+
+/// Parse an indirect object header (`<num> <gen> obj`) at the given offset.
+fn read_object_header(data: &[u8], offset: usize) -> Option<(u64, u64, usize)> {
+    let (number, offset) = read_integer(data, offset)?;
+    let offset = skip_whitespace(data, offset);
+    let (generation, offset) = read_integer(data, offset)?;
+    let offset = skip_whitespace(data, offset);
+    data.get(offset..)?
+        .starts_with(b"obj")
+        .then_some((number, generation, offset + 3))
+}
+
+/// Offset just past a classic cross-reference table and its trailer, found from the entry counts
+/// in its subsection headers, or `None` if the table runs past the end of `data`.
+fn xref_table_end(data: &[u8], xref_offset: usize) -> Option<usize> {
+    let mut offset = xref_offset + 4;
+    loop {
+        offset = skip_whitespace(data, offset);
+        if data.get(offset..)?.starts_with(b"trailer") {
+            return find(&data[offset..], b"startxref").map(|end| offset + end);
+        }
+        let header = read_integer(data, offset)
+            .and_then(|(_, next)| read_integer(data, skip_whitespace(data, next)));
+        let Some((count, next)) = header else {
+            // a malformed header is reported by the full check, unless the data ran out
+            return (data.len() - offset > XREF_ENTRY_LEN).then_some(offset);
+        };
+        offset = skip_whitespace(data, next)
+            .checked_add((count as usize).checked_mul(XREF_ENTRY_LEN)?)?;
+        if offset > data.len() {
+            return None;
+        }
+    }
+}
+
+fn is_xref_entry(entry: &[u8]) -> bool {
+    entry.len() == XREF_ENTRY_LEN
+        && entry[..10].iter().all(u8::is_ascii_digit)
+        && entry[10] == b' '
+        && entry[11..16].iter().all(u8::is_ascii_digit)
+        && entry[16] == b' '
+        && matches!(entry[17], b'n' | b'f')
+        && entry[18..]
+            .iter()
+            .all(|byte| matches!(byte, b' ' | b'\r' | b'\n'))
+}
+
+impl PdfValidator {
+    fn check_header(data: &[u8], findings: &mut ValidationFindings) {
+        let search = &data[..data.len().min(HEADER_SEARCH_BYTES)];
+        let Some(offset) = find(search, b"%PDF-") else {
+            findings.error("PDF header ('%PDF-') not found at the start of the file");
+            return;
+        };
+        let version = &data[offset + 5..(offset + 8).min(data.len())];
+        if !matches!(
+            version,
+            b"1.0" | b"1.1" | b"1.2" | b"1.3" | b"1.4" | b"1.5" | b"1.6" | b"1.7" | b"2.0"
+        ) {
+            findings.error(format!(
+                "Unrecognised PDF version '{}'",
+                String::from_utf8_lossy(version)
+            ));
+        }
+        if offset != 0 {
+            findings.warning("PDF/A: the header must be at the very start of the file");
+        }
+
+        // PDF/A requires a comment of at least four binary bytes on the line after the header
+        let second_line = data[offset..]
+            .iter()
+            .position(|byte| matches!(byte, b'\r' | b'\n'))
+            .map(|end| skip_whitespace(data, offset + end));
+        let has_binary_comment = second_line.is_some_and(|start| {
+            data.get(start) == Some(&b'%')
+                && data[start + 1..]
+                    .iter()
+                    .take_while(|byte| !matches!(byte, b'\r' | b'\n'))
+                    .filter(|byte| **byte >= 128)
+                    .count()
+                    >= 4
+        });
+        if !has_binary_comment {
+            findings.warning(
+                "PDF/A: the header should be followed by a comment of at least four binary bytes",
+            );
+        }
+    }
+
+    /// Locate the end-of-file marker and the offset recorded by `startxref`, given the end of
+    /// the file starting at offset `base`.
+    fn check_trailer(data: &[u8], base: usize, findings: &mut ValidationFindings) -> Option<usize> {
+        let tail_start = data.len().saturating_sub(TRAILER_SEARCH_BYTES);
+        let Some(eof) = rfind(&data[tail_start..], b"%%EOF").map(|pos| tail_start + pos) else {
+            findings.error("End-of-file marker ('%%EOF') not found; the file may be truncated");
+            return None;
+        };
+        if data[eof + 5..]
+            .iter()
+            .any(|byte| !byte.is_ascii_whitespace())
+        {
+            findings.warning("PDF/A: no data may follow the last end-of-file marker");
+        }
+
+        let Some(startxref) = rfind(&data[..eof], b"startxref") else {
+            findings.error("'startxref' keyword not found before the end-of-file marker");
+            return None;
+        };
+        let offset = skip_whitespace(data, startxref + 9);
+        match read_integer(data, offset) {
+            Some((xref_offset, _)) if (xref_offset as usize) < base + startxref => {
+                Some(xref_offset as usize)
+            }
+            Some((xref_offset, _)) => {
+                findings.error(format!(
+                    "'startxref' offset {xref_offset} lies outside the body of the file"
+                ));
+                None
+            }
+            None => {
+                findings.error("'startxref' is not followed by a byte offset");
+                None
+            }
+        }
+    }
+
+    /// Validate a classic cross-reference table, returning the trailer dictionary bytes.
+    ///
+    /// Entries are checked to point at their objects if `verify_offsets`, which needs the whole
+    /// file.
+    fn check_xref_table<'a>(
+        data: &'a [u8],
+        xref_offset: usize,
+        verify_offsets: bool,
+        findings: &mut ValidationFindings,
+    ) -> Option<&'a [u8]> {
+        let mut offset = xref_offset + 4;
+        let mut broken = 0;
+        loop {
+            offset = skip_whitespace(data, offset);
+            if data[offset..].starts_with(b"trailer") {
+                break;
+            }
+            let Some((first, next)) = read_integer(data, offset) else {
+                findings.error(format!(
+                    "Malformed cross-reference subsection header at offset {offset}"
+                ));
+                return None;
+            };
+            let Some((count, next)) = read_integer(data, skip_whitespace(data, next)) else {
+                findings.error(format!(
+                    "Malformed cross-reference subsection header at offset {offset}"
+                ));
+                return None;
+            };
+            // the header line ends with a single EOL, after which entries follow
+            offset = next;
+            while data
+                .get(offset)
+                .is_some_and(|byte| matches!(byte, b' ' | b'\r' | b'\n'))
+            {
+                offset += 1;
+            }
+            for index in 0..count {
+                let Some(entry) = data.get(offset..offset + XREF_ENTRY_LEN) else {
+                    findings.error("Cross-reference table is truncated");
+                    return None;
+                };
+                if !is_xref_entry(entry) {
+                    findings.error(format!(
+                        "Malformed cross-reference entry for object {}",
+                        first + index
+                    ));
+                    return None;
+                }
+                if verify_offsets && entry[17] == b'n' {
+                    let object_offset = std::str::from_utf8(&entry[..10])
+                        .ok()
+                        .and_then(|value| value.parse::<usize>().ok())
+                        .unwrap_or_default();
+                    let points_at_object = read_object_header(data, object_offset)
+                        .is_some_and(|(number, _, _)| number == first + index);
+                    if !points_at_object {
+                        broken += 1;
+                        if broken <= MAX_REPORTED_XREF_ERRORS {
+                            findings.error(format!(
+                                "Cross-reference entry for object {} points to offset {object_offset}, where that object does not start",
+                                first + index
+                            ));
+                        }
+                    }
+                }
+                offset += XREF_ENTRY_LEN;
+            }
+        }
+        if broken > MAX_REPORTED_XREF_ERRORS {
+            findings.error(format!(
+                "{} further cross-reference entries point to the wrong offset",
+                broken - MAX_REPORTED_XREF_ERRORS
+            ));
+        }
+
+        let trailer_end = find(&data[offset..], b"startxref")
+            .map(|end| offset + end)
+            .unwrap_or(data.len());
+        Some(&data[offset..trailer_end])
+    }
+
+    /// Validate a cross-reference stream, returning its dictionary bytes.
+    fn check_xref_stream<'a>(
+        data: &'a [u8],
+        xref_offset: usize,
+        findings: &mut ValidationFindings,
+    ) -> Option<&'a [u8]> {
+        let (_, _, dictionary_start) = read_object_header(data, xref_offset)?;
+        let dictionary_end = find(&data[dictionary_start..], b"stream")
+            .map(|end| dictionary_start + end)
+            .unwrap_or(data.len());
+        let dictionary = &data[dictionary_start..dictionary_end];
+        let compact: Vec<u8> = dictionary
+            .iter()
+            .copied()
+            .filter(|byte| !byte.is_ascii_whitespace())
+            .collect();
+        if find(&compact, b"/Type/XRef").is_none() {
+            findings.error("'startxref' points to an object that is not a cross-reference stream");
+            return None;
+        }
+        Some(dictionary)
+    }
+
+    /// Check the cross-reference section at the end of a file too large to validate in full,
+    /// returning the range to fetch if the section runs past the bytes fetched so far.
+    fn check_xref_ranges(
+        object: &PartialObject,
+        xref_offset: u64,
+        findings: &mut ValidationFindings,
+    ) -> Result<(), Range<u64>> {
+        let window = |len: u64| xref_offset..(xref_offset + len).min(object.len());
+        let Some(data) = object.get_from(xref_offset) else {
+            return Err(window(XREF_WINDOW_BYTES));
+        };
+        // fetch twice as much while the section is cut off before the end of the file
+        let cut_off = xref_offset + (data.len() as u64) < object.len();
+        let wider = window(2 * data.len() as u64);
+
+        let start = skip_whitespace(data, 0);
+        let trailer = if data[start..].starts_with(b"xref") {
+            if cut_off && xref_table_end(data, start).is_none() {
+                return Err(wider);
+            }
+            Self::check_xref_table(data, start, false, findings)
+        } else if read_object_header(data, start).is_some() {
+            if cut_off && find(&data[start..], b"stream").is_none() {
+                return Err(wider);
+            }
+            Self::check_xref_stream(data, start, findings)
+        } else {
+            findings.error(format!(
+                "'startxref' offset {xref_offset} does not point to a cross-reference table or stream"
+            ));
+            None
+        };
+        if trailer.is_some_and(|trailer| find(trailer, b"/Root").is_none()) {
+            findings.error("Trailer does not reference a document catalog (/Root)");
+        }
+        Ok(())
+    }
+
+    fn check_pdfa_hints(data: &[u8], trailer: &[u8], findings: &mut ValidationFindings) {
+        if find(trailer, b"/Encrypt").is_some() {
+            findings.warning("PDF/A: encrypted files cannot conform to PDF/A");
+        }
+        if find(data, b"pdfaid:part").is_none() {
+            findings.warning(
+                "No PDF/A identification (pdfaid:part) found in XMP metadata; the file does not claim PDF/A conformance",
+            );
+            return;
+        }
+        if find(data, b"/OutputIntents").is_none() {
+            findings.warning("PDF/A: the document catalog must contain an output intent");
+        }
+        if find(data, b"/JavaScript").is_some() {
+            findings.warning("PDF/A: JavaScript actions are not permitted");
+        }
+    }
+}
+
+impl FileValidator for PdfValidator {
+    fn name(&self) -> &'static str {
+        "pdf"
+    }
+
+    fn applies_to(&self, file_type: FileType, mime_type: &str, extension: &str) -> bool {
+        file_type == FileType::Publication
+            && (extension.eq_ignore_ascii_case("pdf") || mime_type.starts_with("application/pdf"))
+    }
+
+    fn validate(&self, _mime_type: &str, data: &[u8], findings: &mut ValidationFindings) {
+        Self::check_header(data, findings);
+        let Some(xref_offset) = Self::check_trailer(data, 0, findings) else {
+            return;
+        };
+
+        let start = skip_whitespace(data, xref_offset);
+        let trailer = if data[start..].starts_with(b"xref") {
+            Self::check_xref_table(data, start, true, findings)
+        } else if read_object_header(data, start).is_some() {
+            Self::check_xref_stream(data, start, findings)
+        } else {
+            findings.error(format!(
+                "'startxref' offset {xref_offset} does not point to a cross-reference table or stream"
+            ));
+            None
+        };
+        let Some(trailer) = trailer else {
+            return;
+        };
+
+        if find(trailer, b"/Root").is_none() {
+            findings.error("Trailer does not reference a document catalog (/Root)");
+        }
+        Self::check_pdfa_hints(data, trailer, findings);
+    }
+
+    fn check_signature(&self, _mime_type: &str, head: &[u8], findings: &mut ValidationFindings) {
+        Self::check_header(head, findings);
+    }
+
+    /// Check the header, the trailer at the end of the file and the last cross-reference
+    /// section. Neither the objects the cross-reference entries point to nor PDF/A hints are
+    /// checked.
+    fn check_ranges(
+        &self,
+        _mime_type: &str,
+        object: &PartialObject,
+        findings: &mut ValidationFindings,
+    ) -> Option<Range<u64>> {
+        Self::check_header(object.head(), findings);
+        let (tail_start, tail) = object.tail();
+        let xref_offset = Self::check_trailer(tail, tail_start as usize, findings)?;
+        Self::check_xref_ranges(object, xref_offset as u64, findings).err()
+    }
+}
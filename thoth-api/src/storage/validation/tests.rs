@@ -0,0 +1,800 @@
+use super::*;
+use flate2::{write::ZlibEncoder, Compression, Crc};
+use std::cell::Cell;
+use std::io::Write;
+
+/// Build a ZIP archive with every entry stored uncompressed.
+fn build_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut data = Vec::new();
+    let mut directory = Vec::new();
+    for (name, contents) in entries {
+        let mut crc = Crc::new();
+        crc.update(contents);
+        let offset = data.len() as u32;
+        let size = contents.len() as u32;
+
+        data.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        data.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        data.extend_from_slice(&crc.sum().to_le_bytes());
+        data.extend_from_slice(&size.to_le_bytes());
+        data.extend_from_slice(&size.to_le_bytes());
+        data.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        data.extend_from_slice(&0u16.to_le_bytes());
+        data.extend_from_slice(name.as_bytes());
+        data.extend_from_slice(contents);
+
+        directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        directory.extend_from_slice(&[20, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        directory.extend_from_slice(&crc.sum().to_le_bytes());
+        directory.extend_from_slice(&size.to_le_bytes());
+        directory.extend_from_slice(&size.to_le_bytes());
+        directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        directory.extend_from_slice(&[0; 12]);
+        directory.extend_from_slice(&offset.to_le_bytes());
+        directory.extend_from_slice(name.as_bytes());
+    }
+    let directory_offset = data.len() as u32;
+    data.extend_from_slice(&directory);
+    data.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    data.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    data.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+    data.extend_from_slice(&directory_offset.to_le_bytes());
+    data.extend_from_slice(&0u16.to_le_bytes());
+    data
+}
+
+const CONTAINER_XML: &str = r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>"#;
+
+const PACKAGE_OPF: &str = r#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="uid">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:identifier id="uid">doi:10.00000/test</dc:identifier>
+    <dc:title>Test Book</dc:title>
+    <dc:language>en</dc:language>
+  </metadata>
+  <manifest>
+    <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+    <item id="chapter1" href="text/chapter%201.xhtml" media-type="application/xhtml+xml"/>
+  </manifest>
+  <spine>
+    <itemref idref="chapter1"/>
+  </spine>
+</package>"#;
+
+const NAV_XHTML: &str = r#"<?xml version="1.0"?>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops">
+  <body>
+    <nav epub:type="toc"><ol><li><a href="text/chapter%201.xhtml">Chapter 1</a></li></ol></nav>
+  </body>
+</html>"#;
+
+const CHAPTER_XHTML: &str = r#"<?xml version="1.0"?>
+<html xmlns="http://www.w3.org/1999/xhtml"><body><p>Hello</p></body></html>"#;
+
+fn build_epub(package: &str) -> Vec<u8> {
+    build_zip(&[
+        ("mimetype", b"application/epub+zip"),
+        ("META-INF/container.xml", CONTAINER_XML.as_bytes()),
+        ("OEBPS/content.opf", package.as_bytes()),
+        ("OEBPS/nav.xhtml", NAV_XHTML.as_bytes()),
+        ("OEBPS/text/chapter 1.xhtml", CHAPTER_XHTML.as_bytes()),
+    ])
+}
+
+/// Build an EPUB whose package documents lie outside both the leading and the trailing bytes
+/// fetched from files too large to validate in full.
+fn build_large_epub(package: &str) -> Vec<u8> {
+    let padding = vec![0; 200 * 1024];
+    build_zip(&[
+        ("mimetype", b"application/epub+zip"),
+        ("OEBPS/fonts/padding.bin", &padding),
+        ("META-INF/container.xml", CONTAINER_XML.as_bytes()),
+        ("OEBPS/content.opf", package.as_bytes()),
+        ("OEBPS/nav.xhtml", NAV_XHTML.as_bytes()),
+        ("OEBPS/text/chapter 1.xhtml", CHAPTER_XHTML.as_bytes()),
+        ("OEBPS/fonts/padding2.bin", &padding),
+    ])
+}
+
+/// Build a minimal PDF with a correct cross-reference table.
+fn build_pdf(header: &[u8], catalog_extra: &str) -> Vec<u8> {
+    build_pdf_with_objects(
+        header,
+        vec![
+            format!("<< /Type /Catalog /Pages 2 0 R {catalog_extra}>>"),
+            "<< /Type /Pages /Kids [] /Count 0 >>".to_string(),
+        ],
+    )
+}
+
+/// Build a PDF with a cross-reference table too long to fit in the tail fetched from large files.
+fn build_large_pdf() -> Vec<u8> {
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [] /Count 0 >>".to_string(),
+    ];
+    objects.extend((3..=5000).map(|number| format!("<< /Type /Annot /N {number} >>")));
+    build_pdf_with_objects(PDF_HEADER, objects)
+}
+
+fn build_pdf_with_objects(header: &[u8], objects: Vec<String>) -> Vec<u8> {
+    let mut data = header.to_vec();
+    let mut offsets = Vec::new();
+    for object in objects {
+        offsets.push(data.len());
+        data.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", offsets.len()).as_bytes());
+    }
+    let xref_offset = data.len();
+    let size = offsets.len() + 1;
+    data.extend_from_slice(format!("xref\n0 {size}\n0000000000 65535 f \n").as_bytes());
+    for offset in offsets {
+        data.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    data.extend_from_slice(
+        format!("trailer\n<< /Size {size} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n")
+            .as_bytes(),
+    );
+    data
+}
+
+const PDF_HEADER: &[u8] = b"%PDF-1.7\n%\xe2\xe3\xcf\xd3\n";
+
+fn png_chunk(kind: &[u8], body: &[u8]) -> Vec<u8> {
+    let mut crc = Crc::new();
+    crc.update(kind);
+    crc.update(body);
+    let mut chunk = (body.len() as u32).to_be_bytes().to_vec();
+    chunk.extend_from_slice(kind);
+    chunk.extend_from_slice(body);
+    chunk.extend_from_slice(&crc.sum().to_be_bytes());
+    chunk
+}
+
+/// Build a 2x2 greyscale PNG.
+fn build_png() -> Vec<u8> {
+    let mut header = Vec::new();
+    header.extend_from_slice(&2u32.to_be_bytes());
+    header.extend_from_slice(&2u32.to_be_bytes());
+    header.extend_from_slice(&[8, 0, 0, 0, 0]);
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&[0, 0, 255, 0, 255, 0]).unwrap();
+    let image_data = encoder.finish().unwrap();
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    png.extend(png_chunk(b"IHDR", &header));
+    png.extend(png_chunk(b"IDAT", &image_data));
+    png.extend(png_chunk(b"IEND", &[]));
+    png
+}
+
+/// Build the markers of a baseline JPEG, with placeholder scan data.
+fn build_jpeg() -> Vec<u8> {
+    let mut jpeg = vec![0xff, 0xd8];
+    jpeg.extend_from_slice(&[0xff, 0xe0, 0x00, 0x10]);
+    jpeg.extend_from_slice(b"JFIF\0\x01\x01\0\0\x01\0\x01\0\0");
+    jpeg.extend_from_slice(&[0xff, 0xc0, 0x00, 0x0b, 0x08, 0x00, 0x20, 0x00, 0x10, 0x01]);
+    jpeg.extend_from_slice(&[0x01, 0x11, 0x00]);
+    jpeg.extend_from_slice(&[0xff, 0xda, 0x00, 0x08, 0x01, 0x01, 0x00, 0x00, 0x3f, 0x00]);
+    jpeg.extend_from_slice(&[0x12, 0x34, 0x56]);
+    jpeg.extend_from_slice(&[0xff, 0xd9]);
+    jpeg
+}
+
+fn run(
+    validator: &dyn FileValidator,
+    mime_type: &str,
+    data: &[u8],
+) -> Vec<(FileValidationSeverity, String)> {
+    let mut findings = ValidationFindings::default();
+    validator.validate(mime_type, data, &mut findings);
+    findings.findings
+}
+
+/// Check only the leading bytes of a file, as done for files too large to validate in full.
+fn run_signature(
+    validator: &dyn FileValidator,
+    mime_type: &str,
+    data: &[u8],
+) -> Vec<(FileValidationSeverity, String)> {
+    let head = &data[..data.len().min(SIGNATURE_BYTES as usize)];
+    let mut findings = ValidationFindings::default();
+    validator.check_signature(mime_type, head, &mut findings);
+    findings.findings
+}
+
+/// Check a file through ranged reads of its contents, as done for files too large to validate in
+/// full, returning the report and the number of reads made.
+async fn check_ranged(
+    file_type: FileType,
+    mime_type: &str,
+    extension: &str,
+    data: &[u8],
+) -> (FileValidationReport, usize) {
+    let reads = Cell::new(0);
+    let report = check_object_ranges(
+        &ValidationPipeline::default(),
+        file_type,
+        mime_type,
+        extension,
+        data.len() as u64,
+        |range| {
+            reads.set(reads.get() + 1);
+            std::future::ready(Ok(data[range.start as usize..range.end as usize].to_vec()))
+        },
+    )
+    .await
+    .expect("Failed to read ranges");
+    (report, reads.get())
+}
+
+fn report_errors(report: &FileValidationReport) -> Vec<&str> {
+    report
+        .issues
+        .iter()
+        .filter(|issue| issue.severity == FileValidationSeverity::Severe)
+        .map(|issue| issue.message.as_str())
+        .collect()
+}
+
+fn errors(findings: &[(FileValidationSeverity, String)]) -> Vec<&str> {
+    findings
+        .iter()
+        .filter(|(severity, _)| *severity == FileValidationSeverity::Severe)
+        .map(|(_, message)| message.as_str())
+        .collect()
+}
+
+mod pipeline {
+    use super::*;
+
+    struct AlwaysWarns;
+
+    impl FileValidator for AlwaysWarns {
+        fn name(&self) -> &'static str {
+            "custom"
+        }
+
+        fn applies_to(&self, file_type: FileType, _mime_type: &str, _extension: &str) -> bool {
+            file_type == FileType::AdditionalResource
+        }
+
+        fn validate(&self, _mime_type: &str, _data: &[u8], findings: &mut ValidationFindings) {
+            findings.warning("Looks odd");
+        }
+    }
+
+    #[test]
+    fn report_status_reflects_most_severe_issue() {
+        let issue = |severity| FileValidationIssue {
+            validator: "test".to_string(),
+            severity,
+            message: "message".to_string(),
+        };
+        assert_eq!(
+            FileValidationReport::from_issues(vec![]).status,
+            FileValidationStatus::Passed
+        );
+        assert_eq!(
+            FileValidationReport::from_issues(vec![issue(FileValidationSeverity::Warning)]).status,
+            FileValidationStatus::PassedWithWarnings
+        );
+        let failed = FileValidationReport::from_issues(vec![
+            issue(FileValidationSeverity::Warning),
+            issue(FileValidationSeverity::Severe),
+        ]);
+        assert!(failed.failed());
+        assert_eq!(
+            failed.rejection_error(),
+            ThothError::FileValidationFailed("[test] message".to_string())
+        );
+    }
+
+    #[test]
+    fn files_without_applicable_validators_are_not_validated() {
+        let pipeline = ValidationPipeline::default();
+        assert!(!pipeline.applies_to(FileType::AdditionalResource, "text/csv", "csv"));
+        assert_eq!(
+            pipeline.validate(FileType::AdditionalResource, "text/csv", "csv", b"a,b"),
+            FileValidationReport::not_validated()
+        );
+    }
+
+    #[test]
+    fn default_pipeline_selects_validators_by_file_type_and_format() {
+        let pipeline = ValidationPipeline::default();
+        assert!(pipeline.applies_to(FileType::Publication, "application/epub+zip", "epub"));
+        assert!(pipeline.applies_to(FileType::Publication, "application/pdf", "pdf"));
+        assert!(!pipeline.applies_to(FileType::Publication, "text/xml", "xml"));
+        assert!(pipeline.applies_to(FileType::Frontcover, "image/png", "png"));
+    }
+
+    #[tokio::test]
+    async fn large_files_have_their_structure_checked_from_ranges() {
+        let (report, _) = check_ranged(
+            FileType::Publication,
+            "application/pdf",
+            "pdf",
+            &build_pdf(PDF_HEADER, ""),
+        )
+        .await;
+        assert_eq!(report.status, FileValidationStatus::PassedWithWarnings);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].validator, "pipeline");
+        assert!(report.issues[0]
+            .message
+            .contains("only its structure was checked"));
+
+        let (report, _) = check_ranged(
+            FileType::Publication,
+            "application/pdf",
+            "pdf",
+            b"<html>not a pdf</html>",
+        )
+        .await;
+        assert!(report.failed());
+        let (report, reads) =
+            check_ranged(FileType::AdditionalResource, "text/csv", "csv", b"a,b").await;
+        assert_eq!(report, FileValidationReport::not_validated());
+        assert_eq!(reads, 2);
+    }
+
+    #[test]
+    fn ranged_checks_that_run_out_of_reads_are_not_validated() {
+        let pdf = build_large_pdf();
+        let len = pdf.len() as u64;
+        let mut object = PartialObject::new(len);
+        object.insert(0, pdf[..SIGNATURE_BYTES as usize].to_vec());
+        let tail_start = len - TAIL_BYTES;
+        object.insert(tail_start, pdf[tail_start as usize..].to_vec());
+
+        let pipeline = ValidationPipeline::default();
+        let wanted = pipeline
+            .check_ranges(
+                FileType::Publication,
+                "application/pdf",
+                "pdf",
+                &object,
+                false,
+            )
+            .expect_err("The cross-reference table should be requested");
+        assert!(wanted.start < tail_start);
+
+        let report = pipeline
+            .check_ranges(
+                FileType::Publication,
+                "application/pdf",
+                "pdf",
+                &object,
+                true,
+            )
+            .expect("No more ranges can be requested");
+        assert_eq!(report.status, FileValidationStatus::NotValidated);
+        assert!(report
+            .issues
+            .iter()
+            .any(|issue| issue.message.contains("could not be checked within")));
+    }
+
+    #[test]
+    fn custom_validators_can_be_registered() {
+        let pipeline = ValidationPipeline::empty().with_validator(AlwaysWarns);
+        let report = pipeline.validate(FileType::AdditionalResource, "text/csv", "csv", b"a,b");
+        assert_eq!(report.status, FileValidationStatus::PassedWithWarnings);
+        assert_eq!(report.issues[0].validator, "custom");
+        assert_eq!(report.issues[0].message, "Looks odd");
+    }
+}
+
+mod epub_validator {
+    use super::*;
+
+    #[test]
+    fn resolve_href_handles_relative_and_encoded_paths() {
+        assert_eq!(
+            epub::resolve_href("OEBPS", "text/chapter%201.xhtml#s1"),
+            Some("OEBPS/text/chapter 1.xhtml".to_string())
+        );
+        assert_eq!(
+            epub::resolve_href("OEBPS/text", "../images/cover.jpg"),
+            Some("OEBPS/images/cover.jpg".to_string())
+        );
+        assert_eq!(
+            epub::resolve_href("", "nav.xhtml"),
+            Some("nav.xhtml".to_string())
+        );
+        assert_eq!(
+            epub::resolve_href("OEBPS", "https://example.org/a.css"),
+            None
+        );
+    }
+
+    #[test]
+    fn well_formed_epub_passes() {
+        let findings = run(
+            &EpubValidator,
+            "application/epub+zip",
+            &build_epub(PACKAGE_OPF),
+        );
+        assert!(findings.is_empty(), "{findings:?}");
+    }
+
+    #[test]
+    fn non_zip_data_fails() {
+        let findings = run(
+            &EpubValidator,
+            "application/epub+zip",
+            b"not a zip file at all",
+        );
+        assert_eq!(errors(&findings).len(), 1);
+        assert!(errors(&findings)[0].contains("not a valid ZIP container"));
+    }
+
+    #[test]
+    fn mimetype_must_be_first() {
+        let data = build_zip(&[
+            ("META-INF/container.xml", CONTAINER_XML.as_bytes()),
+            ("mimetype", b"application/epub+zip"),
+        ]);
+        let findings = run(&EpubValidator, "application/epub+zip", &data);
+        assert!(errors(&findings)
+            .iter()
+            .any(|error| error.contains("first entry of the container must be 'mimetype'")));
+    }
+
+    #[test]
+    fn signature_check_reads_the_leading_mimetype_entry() {
+        assert!(run_signature(
+            &EpubValidator,
+            "application/epub+zip",
+            &build_epub(PACKAGE_OPF)
+        )
+        .is_empty());
+
+        let data = build_zip(&[
+            ("META-INF/container.xml", CONTAINER_XML.as_bytes()),
+            ("mimetype", b"application/epub+zip"),
+        ]);
+        let findings = run_signature(&EpubValidator, "application/epub+zip", &data);
+        assert!(errors(&findings)[0].contains("first entry of the container must be 'mimetype'"));
+
+        let data = build_zip(&[("mimetype", b"application/zip")]);
+        let findings = run_signature(&EpubValidator, "application/epub+zip", &data);
+        assert_eq!(
+            errors(&findings),
+            vec!["The 'mimetype' entry must contain exactly 'application/epub+zip'"]
+        );
+
+        let findings = run_signature(&EpubValidator, "application/epub+zip", b"%PDF-1.7");
+        assert!(errors(&findings)[0].contains("not a valid ZIP container"));
+    }
+
+    #[tokio::test]
+    async fn ranged_checks_read_the_central_directory_and_package() {
+        let (report, reads) = check_ranged(
+            FileType::Publication,
+            "application/epub+zip",
+            "epub",
+            &build_large_epub(PACKAGE_OPF),
+        )
+        .await;
+        assert_eq!(report.status, FileValidationStatus::PassedWithWarnings);
+        assert_eq!(report.issues.len(), 1, "{:?}", report.issues);
+        assert!(reads > 2);
+
+        let (report, _) = check_ranged(
+            FileType::Publication,
+            "application/epub+zip",
+            "epub",
+            &build_large_epub("<package><metadata></package>"),
+        )
+        .await;
+        assert!(report.failed());
+        assert!(report_errors(&report)
+            .iter()
+            .any(|e| e.contains("'OEBPS/content.opf' is not well-formed XML")));
+
+        let padding = vec![0; 200 * 1024];
+        let data = build_zip(&[
+            ("mimetype", b"application/epub+zip"),
+            ("OEBPS/fonts/padding.bin", &padding),
+        ]);
+        let (report, _) =
+            check_ranged(FileType::Publication, "application/epub+zip", "epub", &data).await;
+        assert_eq!(
+            report_errors(&report),
+            vec!["Required file 'META-INF/container.xml' is missing"]
+        );
+
+        let mut data = build_large_epub(PACKAGE_OPF);
+        data.truncate(data.len() - 100);
+        let (report, _) =
+            check_ranged(FileType::Publication, "application/epub+zip", "epub", &data).await;
+        assert!(report_errors(&report)
+            .iter()
+            .any(|e| e.contains("end of central directory record not found")));
+    }
+
+    #[test]
+    fn missing_container_fails() {
+        let data = build_zip(&[("mimetype", b"application/epub+zip")]);
+        let findings = run(&EpubValidator, "application/epub+zip", &data);
+        assert_eq!(
+            errors(&findings),
+            vec!["Required file 'META-INF/container.xml' is missing"]
+        );
+    }
+
+    #[test]
+    fn broken_package_references_are_reported() {
+        let package = PACKAGE_OPF
+            .replace("text/chapter%201.xhtml", "text/missing.xhtml")
+            .replace("idref=\"chapter1\"", "idref=\"chapter2\"")
+            .replace("<dc:title>Test Book</dc:title>", "");
+        let findings = run(
+            &EpubValidator,
+            "application/epub+zip",
+            &build_epub(&package),
+        );
+        let errors = errors(&findings);
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("missing a non-empty dc:title")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("refers to 'OEBPS/text/missing.xhtml'")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("Spine item refers to 'chapter2'")));
+    }
+
+    #[test]
+    fn epub3_requires_navigation_document() {
+        let package = PACKAGE_OPF.replace(" properties=\"nav\"", "");
+        let findings = run(
+            &EpubValidator,
+            "application/epub+zip",
+            &build_epub(&package),
+        );
+        assert!(errors(&findings)
+            .iter()
+            .any(|e| e.contains("must declare a navigation document")));
+    }
+
+    #[test]
+    fn epub2_requires_ncx() {
+        let package = PACKAGE_OPF.replace("version=\"3.0\"", "version=\"2.0\"");
+        let findings = run(
+            &EpubValidator,
+            "application/epub+zip",
+            &build_epub(&package),
+        );
+        assert!(errors(&findings)
+            .iter()
+            .any(|e| e.contains("must reference an NCX table of contents")));
+    }
+
+    #[test]
+    fn malformed_package_xml_fails() {
+        let findings = run(
+            &EpubValidator,
+            "application/epub+zip",
+            &build_epub("<package><metadata></package>"),
+        );
+        assert!(errors(&findings)
+            .iter()
+            .any(|e| e.contains("'OEBPS/content.opf' is not well-formed XML")));
+    }
+}
+
+mod pdf_validator {
+    use super::*;
+
+    #[test]
+    fn well_formed_pdf_passes_with_pdfa_hint() {
+        let findings = run(&PdfValidator, "application/pdf", &build_pdf(PDF_HEADER, ""));
+        assert!(errors(&findings).is_empty(), "{findings:?}");
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].1.contains("pdfaid:part"));
+    }
+
+    #[test]
+    fn pdfa_claim_without_output_intent_is_reported() {
+        let data = build_pdf(
+            PDF_HEADER,
+            "/Metadata << /Subtype /XML >> % <pdfaid:part>2</pdfaid:part>\n",
+        );
+        let findings = run(&PdfValidator, "application/pdf", &data);
+        assert!(errors(&findings).is_empty(), "{findings:?}");
+        assert!(findings
+            .iter()
+            .any(|(_, message)| message.contains("output intent")));
+    }
+
+    #[test]
+    fn missing_header_fails() {
+        let data = build_pdf(b"garbage\n", "");
+        let findings = run(&PdfValidator, "application/pdf", &data);
+        assert!(errors(&findings)
+            .iter()
+            .any(|e| e.contains("PDF header ('%PDF-') not found")));
+    }
+
+    #[test]
+    fn signature_check_reads_the_header() {
+        let findings = run_signature(&PdfValidator, "application/pdf", &build_pdf(PDF_HEADER, ""));
+        assert!(findings.is_empty(), "{findings:?}");
+        let findings = run_signature(
+            &PdfValidator,
+            "application/pdf",
+            &build_pdf(b"garbage\n", ""),
+        );
+        assert!(errors(&findings)
+            .iter()
+            .any(|e| e.contains("PDF header ('%PDF-') not found")));
+    }
+
+    #[tokio::test]
+    async fn ranged_checks_read_the_cross_reference_table() {
+        let pdf = build_large_pdf();
+        let (report, reads) =
+            check_ranged(FileType::Publication, "application/pdf", "pdf", &pdf).await;
+        assert_eq!(report.status, FileValidationStatus::PassedWithWarnings);
+        assert_eq!(report.issues.len(), 1, "{:?}", report.issues);
+        assert!(reads > 2);
+
+        let mut data = pdf.clone();
+        let entries = pdf::rfind(&data, b"xref\n0 5001\n").unwrap() + 12;
+        data[entries + 2500 * 20 + 17] = b'x';
+        let (report, _) =
+            check_ranged(FileType::Publication, "application/pdf", "pdf", &data).await;
+        assert_eq!(
+            report_errors(&report),
+            vec!["Malformed cross-reference entry for object 2500"]
+        );
+
+        let mut data = pdf;
+        data.truncate(data.len() - 10);
+        let (report, _) =
+            check_ranged(FileType::Publication, "application/pdf", "pdf", &data).await;
+        assert!(report_errors(&report)
+            .iter()
+            .any(|e| e.contains("End-of-file marker")));
+    }
+
+    #[test]
+    fn truncated_file_fails() {
+        let data = build_pdf(PDF_HEADER, "");
+        let findings = run(&PdfValidator, "application/pdf", &data[..data.len() - 10]);
+        assert!(errors(&findings)
+            .iter()
+            .any(|e| e.contains("End-of-file marker")));
+    }
+
+    #[test]
+    fn wrong_xref_offsets_fail() {
+        let mut data = build_pdf(PDF_HEADER, "");
+        let object = pdf::find(&data, b"1 0 obj").unwrap();
+        data.insert(object, b' ');
+        let findings = run(&PdfValidator, "application/pdf", &data);
+        assert!(errors(&findings)
+            .iter()
+            .any(|e| e.contains("Cross-reference entry for object 1 points to offset")));
+    }
+
+    #[test]
+    fn startxref_must_point_to_xref() {
+        let mut data = build_pdf(PDF_HEADER, "");
+        data.truncate(pdf::rfind(&data, b"startxref").unwrap());
+        data.extend_from_slice(b"startxref\n9\n%%EOF\n");
+        let findings = run(&PdfValidator, "application/pdf", &data);
+        assert!(errors(&findings)
+            .iter()
+            .any(|e| e.contains("does not point to a cross-reference table or stream")));
+    }
+}
+
+mod cover_image_validator {
+    use super::*;
+
+    #[test]
+    fn detects_image_formats() {
+        assert_eq!(
+            image::ImageFormat::detect(&build_png()),
+            Some(image::ImageFormat::Png)
+        );
+        assert_eq!(
+            image::ImageFormat::detect(&build_jpeg()),
+            Some(image::ImageFormat::Jpeg)
+        );
+        assert_eq!(
+            image::ImageFormat::detect(b"RIFF\x04\x00\x00\x00WEBP"),
+            Some(image::ImageFormat::Webp)
+        );
+        assert_eq!(image::ImageFormat::detect(b"GIF89a"), None);
+    }
+
+    #[test]
+    fn valid_png_and_jpeg_pass() {
+        assert!(run(&CoverImageValidator, "image/png", &build_png()).is_empty());
+        assert!(run(&CoverImageValidator, "image/jpeg", &build_jpeg()).is_empty());
+    }
+
+    #[test]
+    fn signature_check_detects_format_and_declared_type() {
+        assert!(run_signature(&CoverImageValidator, "image/png", &build_png()).is_empty());
+        assert_eq!(
+            errors(&run_signature(
+                &CoverImageValidator,
+                "image/jpeg",
+                &build_png()
+            )),
+            vec!["Cover is a image/png image but is served as 'image/jpeg'"]
+        );
+        assert_eq!(
+            errors(&run_signature(
+                &CoverImageValidator,
+                "image/gif",
+                b"GIF89a...."
+            )),
+            vec!["Cover is not a recognisable JPEG, PNG or WebP image"]
+        );
+    }
+
+    #[test]
+    fn png_with_bad_crc_fails() {
+        let mut png = build_png();
+        let last = png.len() - 1;
+        png[last] ^= 0xff;
+        let findings = run(&CoverImageValidator, "image/png", &png);
+        assert_eq!(
+            errors(&findings),
+            vec!["PNG chunk 'IEND' fails its CRC check"]
+        );
+    }
+
+    #[test]
+    fn truncated_png_fails() {
+        let png = build_png();
+        let findings = run(&CoverImageValidator, "image/png", &png[..png.len() - 12]);
+        assert!(errors(&findings).iter().any(|e| e.contains("IEND")));
+    }
+
+    #[test]
+    fn jpeg_without_frame_header_fails() {
+        let jpeg: Vec<u8> = build_jpeg()
+            .into_iter()
+            .enumerate()
+            .map(|(index, byte)| if index == 21 { 0xe1 } else { byte })
+            .collect();
+        let findings = run(&CoverImageValidator, "image/jpeg", &jpeg);
+        assert_eq!(
+            errors(&findings),
+            vec!["JPEG has no frame header (SOF) before its image data"]
+        );
+    }
+
+    #[test]
+    fn mismatched_mime_type_fails() {
+        let findings = run(&CoverImageValidator, "image/jpeg", &build_png());
+        assert_eq!(
+            errors(&findings),
+            vec!["Cover is a image/png image but is served as 'image/jpeg'"]
+        );
+    }
+
+    #[test]
+    fn unknown_format_fails() {
+        let findings = run(&CoverImageValidator, "image/gif", b"GIF89a....");
+        assert_eq!(
+            errors(&findings),
+            vec!["Cover is not a recognisable JPEG, PNG or WebP image"]
+        );
+    }
+}
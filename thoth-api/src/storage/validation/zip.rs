@@ -0,0 +1,276 @@
+//! Minimal read-only ZIP reader, sufficient to inspect the contents of EPUB containers.
+
+use flate2::{read::DeflateDecoder, Crc};
+use std::cell::Cell;
+use std::io::Read;
+use std::ops::Range;
+
+use super::PartialObject;
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+const LOCAL_HEADER_LEN: usize = 30;
+const END_OF_CENTRAL_DIRECTORY_LEN: usize = 22;
+const MAX_COMMENT_LEN: usize = u16::MAX as usize;
+/// Refuse to inflate entries larger than this, to guard against decompression bombs
+const MAX_ENTRY_BYTES: u64 = 256 * 1024 * 1024;
+
+pub(super) const METHOD_STORED: u16 = 0;
+pub(super) const METHOD_DEFLATED: u16 = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ZipEntry {
+    pub name: String,
+    pub method: u16,
+    pub crc32: u32,
+    pub compressed_size: u64,
+    pub uncompressed_size: u64,
+    pub local_header_offset: u64,
+}
+
+/// The local header of the first entry of an archive, and where that entry's data starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct LocalHeader {
+    pub name: String,
+    pub method: u16,
+    pub extra_len: usize,
+    pub data_offset: usize,
+}
+
+/// The bytes of an archive, of which only some may be available.
+pub(super) trait ZipSource {
+    fn len(&self) -> usize;
+
+    /// The bytes in `range`, if they are available.
+    fn get(&self, range: Range<usize>) -> Option<&[u8]>;
+}
+
+impl ZipSource for [u8] {
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    fn get(&self, range: Range<usize>) -> Option<&[u8]> {
+        <[u8]>::get(self, range)
+    }
+}
+
+/// An archive read from the fetched parts of an object, remembering the first range that was
+/// needed but has not been fetched.
+pub(super) struct PartialSource<'a> {
+    object: &'a PartialObject,
+    missing: Cell<Option<Range<u64>>>,
+}
+
+impl<'a> PartialSource<'a> {
+    pub fn new(object: &'a PartialObject) -> Self {
+        PartialSource {
+            object,
+            missing: Cell::new(None),
+        }
+    }
+
+    /// The first range that was read but has not been fetched, if any.
+    pub fn missing(self) -> Option<Range<u64>> {
+        self.missing.into_inner()
+    }
+}
+
+impl ZipSource for PartialSource<'_> {
+    fn len(&self) -> usize {
+        self.object.len() as usize
+    }
+
+    fn get(&self, range: Range<usize>) -> Option<&[u8]> {
+        let range = range.start as u64..range.end as u64;
+        let bytes = self.object.get(range.clone());
+        if bytes.is_none() {
+            let missing = self.missing.take();
+            self.missing.set(missing.or(Some(range)));
+        }
+        bytes
+    }
+}
+
+pub(super) struct ZipArchive<'a, S: ZipSource + ?Sized = [u8]> {
+    data: &'a S,
+    entries: Vec<ZipEntry>,
+}
+
+fn read_u16_le(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes: [u8; 2] = data.get(offset..offset.checked_add(2)?)?.try_into().ok()?;
+    Some(u16::from_le_bytes(bytes))
+}
+
+fn read_u32_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes: [u8; 4] = data.get(offset..offset.checked_add(4)?)?.try_into().ok()?;
+    Some(u32::from_le_bytes(bytes))
+}
+
+/// Read the local header at the very start of an archive, which is all that is needed to inspect
+/// its first entry given only the leading bytes of the file.
+pub(super) fn first_local_header(data: &[u8]) -> Result<LocalHeader, String> {
+    if read_u32_le(data, 0) != Some(LOCAL_HEADER_SIGNATURE) {
+        return Err("File does not start with a ZIP local header".to_string());
+    }
+    let truncated = || "Truncated ZIP local header".to_string();
+    let method = read_u16_le(data, 8).ok_or_else(truncated)?;
+    let name_len = read_u16_le(data, 26).ok_or_else(truncated)? as usize;
+    let extra_len = read_u16_le(data, 28).ok_or_else(truncated)? as usize;
+    let name = data.get(30..30 + name_len).ok_or_else(truncated)?;
+    Ok(LocalHeader {
+        name: String::from_utf8_lossy(name).into_owned(),
+        method,
+        extra_len,
+        data_offset: 30 + name_len + extra_len,
+    })
+}
+
+impl<'a, S: ZipSource + ?Sized> ZipArchive<'a, S> {
+    /// Read the central directory of a ZIP archive.
+    pub fn parse(data: &'a S) -> Result<Self, String> {
+        if data.len() < END_OF_CENTRAL_DIRECTORY_LEN {
+            return Err("File is too small to be a ZIP archive".to_string());
+        }
+        let search_start = data
+            .len()
+            .saturating_sub(END_OF_CENTRAL_DIRECTORY_LEN + MAX_COMMENT_LEN);
+        let tail = data
+            .get(search_start..data.len())
+            .ok_or("ZIP end of central directory record not available")?;
+        let eocd = (0..=tail.len() - END_OF_CENTRAL_DIRECTORY_LEN)
+            .rev()
+            .find(|&offset| read_u32_le(tail, offset) == Some(END_OF_CENTRAL_DIRECTORY_SIGNATURE))
+            .ok_or("ZIP end of central directory record not found")?;
+
+        let entry_count = read_u16_le(tail, eocd + 10).unwrap_or_default();
+        let directory_offset = read_u32_le(tail, eocd + 16).unwrap_or_default();
+        if entry_count == u16::MAX || directory_offset == u32::MAX {
+            return Err("ZIP64 archives are not supported".to_string());
+        }
+
+        // the central directory runs up to the end of central directory record
+        let directory_offset = directory_offset as usize;
+        let directory = data
+            .get(directory_offset..(search_start + eocd).max(directory_offset))
+            .ok_or_else(|| {
+                format!("Invalid ZIP central directory entry at offset {directory_offset}")
+            })?;
+        let mut entries = Vec::with_capacity(entry_count as usize);
+        let mut position = 0;
+        for _ in 0..entry_count {
+            let offset = directory_offset + position;
+            if read_u32_le(directory, position) != Some(CENTRAL_HEADER_SIGNATURE) {
+                return Err(format!(
+                    "Invalid ZIP central directory entry at offset {offset}"
+                ));
+            }
+            let truncated = || format!("Truncated ZIP central directory entry at offset {offset}");
+            let method = read_u16_le(directory, position + 10).ok_or_else(truncated)?;
+            let crc32 = read_u32_le(directory, position + 16).ok_or_else(truncated)?;
+            let compressed_size = read_u32_le(directory, position + 20).ok_or_else(truncated)?;
+            let uncompressed_size = read_u32_le(directory, position + 24).ok_or_else(truncated)?;
+            let name_len = read_u16_le(directory, position + 28).ok_or_else(truncated)? as usize;
+            let extra_len = read_u16_le(directory, position + 30).ok_or_else(truncated)? as usize;
+            let comment_len = read_u16_le(directory, position + 32).ok_or_else(truncated)? as usize;
+            let local_header_offset =
+                read_u32_le(directory, position + 42).ok_or_else(truncated)?;
+            let name = directory
+                .get(position + 46..position + 46 + name_len)
+                .ok_or_else(truncated)?;
+            entries.push(ZipEntry {
+                name: String::from_utf8_lossy(name).into_owned(),
+                method,
+                crc32,
+                compressed_size: compressed_size.into(),
+                uncompressed_size: uncompressed_size.into(),
+                local_header_offset: local_header_offset.into(),
+            });
+            position += 46 + name_len + extra_len + comment_len;
+        }
+
+        Ok(ZipArchive { data, entries })
+    }
+
+    pub fn entries(&self) -> &[ZipEntry] {
+        &self.entries
+    }
+
+    pub fn find(&self, name: &str) -> Option<&ZipEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    /// The entry stored first in the archive, by position rather than directory order.
+    pub fn first_entry(&self) -> Option<&ZipEntry> {
+        self.entries
+            .iter()
+            .min_by_key(|entry| entry.local_header_offset)
+    }
+
+    /// The fixed-size part of an entry's local header.
+    fn local_header(&self, entry: &ZipEntry) -> Result<&[u8], String> {
+        let offset = entry.local_header_offset as usize;
+        self.data
+            .get(offset..offset + LOCAL_HEADER_LEN)
+            .filter(|header| read_u32_le(header, 0) == Some(LOCAL_HEADER_SIGNATURE))
+            .ok_or_else(|| format!("Invalid ZIP local header for '{}'", entry.name))
+    }
+
+    /// Length of the extra field in an entry's local header.
+    pub fn local_extra_len(&self, entry: &ZipEntry) -> Result<usize, String> {
+        read_u16_le(self.local_header(entry)?, 28)
+            .map(usize::from)
+            .ok_or_else(|| format!("Truncated ZIP local header for '{}'", entry.name))
+    }
+
+    /// Read and decompress an entry, verifying its CRC-32.
+    pub fn read(&self, entry: &ZipEntry) -> Result<Vec<u8>, String> {
+        if entry.uncompressed_size > MAX_ENTRY_BYTES {
+            return Err(format!(
+                "ZIP entry '{}' is too large to inspect",
+                entry.name
+            ));
+        }
+        let offset = entry.local_header_offset as usize;
+        let extra_len = self.local_extra_len(entry)?;
+        let name_len = read_u16_le(self.local_header(entry)?, 26).unwrap_or_default() as usize;
+        let start = offset + LOCAL_HEADER_LEN + name_len + extra_len;
+        let compressed = start
+            .checked_add(entry.compressed_size as usize)
+            .and_then(|end| self.data.get(start..end))
+            .ok_or_else(|| format!("ZIP entry '{}' is truncated", entry.name))?;
+
+        let contents = match entry.method {
+            METHOD_STORED => compressed.to_vec(),
+            METHOD_DEFLATED => {
+                let mut contents = Vec::with_capacity(entry.uncompressed_size as usize);
+                DeflateDecoder::new(compressed)
+                    .take(MAX_ENTRY_BYTES)
+                    .read_to_end(&mut contents)
+                    .map_err(|e| {
+                        format!("ZIP entry '{}' could not be inflated: {e}", entry.name)
+                    })?;
+                contents
+            }
+            method => {
+                return Err(format!(
+                    "ZIP entry '{}' uses unsupported compression method {method}",
+                    entry.name
+                ))
+            }
+        };
+
+        let mut crc = Crc::new();
+        crc.update(&contents);
+        if crc.sum() != entry.crc32 {
+            return Err(format!("ZIP entry '{}' fails its CRC-32 check", entry.name));
+        }
+        Ok(contents)
+    }
+
+    pub fn read_to_string(&self, entry: &ZipEntry) -> Result<String, String> {
+        String::from_utf8(self.read(entry)?)
+            .map_err(|_| format!("ZIP entry '{}' is not valid UTF-8", entry.name))
+    }
+}
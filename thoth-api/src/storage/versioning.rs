@@ -0,0 +1,164 @@
+use log::info;
+use uuid::Uuid;
+
+use thoth_errors::ThothResult;
+
+use crate::model::{
+    file::{File, FilePolicy, FileType, FileUpload, NewFile},
+    file_version::{object_key_extension, FileVersion, NewFileVersion},
+    work::Work,
+    Crud, Doi,
+};
+use crate::policy::{CreatePolicy, PolicyContext};
+
+use super::{
+    build_cdn_url, canonical_publication_key, copy_object, reconcile_replaced_object,
+    versioned_publication_key, CloudFrontClient, S3Client, StorageConfig,
+};
+
+/// An object already in the bucket that is to become the current file of a publication.
+#[derive(Debug, Clone, Copy)]
+pub struct PublicationFileSource<'a> {
+    pub object_key: &'a str,
+    pub extension: &'a str,
+    pub mime_type: &'a str,
+    pub bytes: i64,
+    pub sha256: &'a str,
+    /// Set when an earlier version is being restored
+    pub restored_from_file_version_id: Option<Uuid>,
+}
+
+/// Make `source` the current file of a publication, recording it as a new version.
+///
+/// The object is copied to an immutable per-version key. If the work exposes a stable "latest"
+/// URL the object is also copied to the canonical publication key and the file points there;
+/// otherwise the file points at the versioned key. Files stored before versioning existed are
+/// backfilled as version 1 so that their content is retained rather than overwritten.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn publish_publication_file<C: PolicyContext>(
+    ctx: &C,
+    s3_client: &S3Client,
+    cloudfront_client: &CloudFrontClient,
+    storage_config: &StorageConfig,
+    work: &Work,
+    doi: &Doi,
+    publication_id: Uuid,
+    source: PublicationFileSource<'_>,
+) -> ThothResult<(File, FileVersion)> {
+    let bucket = &storage_config.s3_bucket;
+    let existing_file = File::from_publication_id(ctx.db(), &publication_id)?;
+    let old_object_key = existing_file.as_ref().map(|file| file.object_key.clone());
+
+    if let Some(existing) = &existing_file {
+        if !FileVersion::exists_for_file(ctx.db(), &existing.file_id)? {
+            let extension = object_key_extension(&existing.object_key).unwrap_or(source.extension);
+            let version_key = versioned_publication_key(doi.prefix(), doi.suffix(), 1, extension);
+            copy_object(s3_client, bucket, &existing.object_key, &version_key).await?;
+            FileVersion::create(
+                ctx.db(),
+                &NewFileVersion {
+                    file_id: existing.file_id,
+                    publication_id,
+                    version_number: 1,
+                    cdn_url: build_cdn_url(&storage_config.cdn_domain, &version_key),
+                    object_key: version_key,
+                    mime_type: existing.mime_type.clone(),
+                    bytes: existing.bytes,
+                    sha256: existing.sha256.clone(),
+                    uploaded_by: None,
+                    restored_from_file_version_id: None,
+                    created_at: Some(existing.updated_at),
+                },
+            )?;
+        }
+    }
+
+    let version_number = FileVersion::next_version_number(ctx.db(), &publication_id)?;
+    let version_key =
+        versioned_publication_key(doi.prefix(), doi.suffix(), version_number, source.extension);
+    copy_object(s3_client, bucket, source.object_key, &version_key).await?;
+
+    let file_key = if work.expose_latest_file_url {
+        let canonical_key = canonical_publication_key(doi.prefix(), doi.suffix(), source.extension);
+        copy_object(s3_client, bucket, &version_key, &canonical_key).await?;
+        canonical_key
+    } else {
+        version_key.clone()
+    };
+    let file_url = build_cdn_url(&storage_config.cdn_domain, &file_key);
+
+    let file = if let Some(existing) = existing_file {
+        existing.update_stored_object(
+            ctx.db(),
+            &file_key,
+            &file_url,
+            source.mime_type,
+            source.bytes,
+            source.sha256,
+        )?
+    } else {
+        let new_file = NewFile {
+            file_type: FileType::Publication,
+            work_id: None,
+            publication_id: Some(publication_id),
+            additional_resource_id: None,
+            work_featured_video_id: None,
+            object_key: file_key.clone(),
+            cdn_url: file_url.clone(),
+            mime_type: source.mime_type.to_string(),
+            bytes: source.bytes,
+            sha256: source.sha256.to_string(),
+        };
+        FilePolicy::can_create(ctx, &new_file, ())?;
+        File::create(ctx.db(), &new_file)?
+    };
+
+    let version = FileVersion::create(
+        ctx.db(),
+        &NewFileVersion {
+            file_id: file.file_id,
+            publication_id,
+            version_number,
+            cdn_url: build_cdn_url(&storage_config.cdn_domain, &version_key),
+            object_key: version_key,
+            mime_type: source.mime_type.to_string(),
+            bytes: source.bytes,
+            sha256: source.sha256.to_string(),
+            uploaded_by: ctx.user_id().ok().map(str::to_string),
+            restored_from_file_version_id: source.restored_from_file_version_id,
+            created_at: None,
+        },
+    )?;
+
+    FileUpload::upsert_thoth_location(ctx, publication_id, work.landing_page.clone(), &file_url)?;
+
+    // Objects under versioned keys are immutable and must survive the replacement
+    let replaced_key = match old_object_key {
+        Some(key) if FileVersion::from_object_key(ctx.db(), &key)?.is_some() => {
+            Some(file_key.clone())
+        }
+        key => key,
+    };
+    reconcile_replaced_object(
+        s3_client,
+        cloudfront_client,
+        bucket,
+        &storage_config.cloudfront_dist_id,
+        replaced_key.as_deref(),
+        &file_key,
+    )
+    .await?;
+
+    info!(
+        "publication_id={publication_id} file_id={} file_version_id={} version_number={version_number} object_key={} restored_from={}",
+        file.file_id,
+        version.file_version_id,
+        version.object_key,
+        source
+            .restored_from_file_version_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+    );
+
+    Ok((file, version))
+}
@@ -0,0 +1,300 @@
+//! Structured logging and request tracing shared by the Thoth servers.
+//!
+//! Logs are emitted through [`tracing`], either as human-readable text or as one JSON object
+//! per line, and events recorded with the `log` crate are forwarded to the same output. Every
+//! HTTP request is handled within a span carrying its request ID, so that all the events and
+//! spans it produces (database queries, S3 calls, record generation) can be correlated, and
+//! optionally exported to an OpenTelemetry collector over OTLP.
+
+use std::future::{ready, Ready};
+use std::rc::Rc;
+use std::time::Instant;
+
+use actix_web::dev::{self, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::{Error, HttpMessage};
+use diesel::connection::{Instrumentation, InstrumentationEvent};
+use diesel::pg::PgConnection;
+use diesel::r2d2::CustomizeConnection;
+use futures::future::LocalBoxFuture;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use opentelemetry_sdk::Resource;
+use strum::{Display, EnumString};
+use thoth_errors::{ThothError, ThothResult};
+use tracing::{field, Instrument, Span};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter};
+use uuid::Uuid;
+
+/// Header used to pass request IDs between services. An incoming ID is adopted, otherwise a
+/// new one is generated; either way it is returned in the response.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// Longest request ID adopted from an incoming request
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// How a server reports what it is doing
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TelemetryConfig {
+    pub log_format: LogFormat,
+    /// Base URL of an OpenTelemetry collector accepting OTLP over HTTP, e.g.
+    /// `http://localhost:4318`. Spans are only exported when it is set.
+    pub otlp_endpoint: Option<String>,
+}
+
+/// Keeps the OTLP exporter alive, flushing pending spans when dropped
+pub struct TelemetryGuard {
+    tracer_provider: Option<SdkTracerProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Some(tracer_provider) = self.tracer_provider.take() {
+            if let Err(e) = tracer_provider.shutdown() {
+                eprintln!("Failed to export pending spans: {e}");
+            }
+        }
+    }
+}
+
+/// Install the global subscriber, filtered with `RUST_LOG` (`info` by default).
+///
+/// The returned guard must be held for as long as the server runs.
+pub fn init_telemetry(
+    service_name: &'static str,
+    config: &TelemetryConfig,
+) -> ThothResult<TelemetryGuard> {
+    let tracer_provider = config
+        .otlp_endpoint
+        .as_deref()
+        .map(|endpoint| otlp_tracer_provider(service_name, endpoint))
+        .transpose()?;
+    let otel_layer = tracer_provider.as_ref().map(|tracer_provider| {
+        tracing_opentelemetry::layer().with_tracer(tracer_provider.tracer(service_name))
+    });
+    let json = config.log_format == LogFormat::Json;
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with((!json).then(fmt::layer))
+        .with(json.then(|| {
+            fmt::layer()
+                .json()
+                .with_current_span(true)
+                .with_span_list(false)
+        }))
+        .with(otel_layer)
+        .try_init()
+        .map_err(|e| ThothError::InternalError(format!("Failed to initialise logging: {e}")))?;
+    Ok(TelemetryGuard { tracer_provider })
+}
+
+fn otlp_tracer_provider(
+    service_name: &'static str,
+    endpoint: &str,
+) -> ThothResult<SdkTracerProvider> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()
+        .map_err(|e| ThothError::InternalError(format!("Failed to create OTLP exporter: {e}")))?;
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(Resource::builder().with_service_name(service_name).build())
+        .build())
+}
+
+/// ID of the request being handled, available in the request extensions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    /// Adopt the ID sent by the caller if it looks sensible, or generate a new one
+    fn from_request(req: &ServiceRequest) -> Self {
+        req.headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|id| is_valid_request_id(id))
+            .map(|id| RequestId(id.to_string()))
+            .unwrap_or_else(|| RequestId(Uuid::new_v4().to_string()))
+    }
+}
+
+fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LENGTH
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Middleware handling every request within a span identified by its [`RequestId`], and
+/// logging its outcome once a response is ready
+pub struct RequestTracing;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestTracing
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestTracingMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestTracingMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct RequestTracingMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestTracingMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    dev::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let start = Instant::now();
+        let request_id = RequestId::from_request(&req);
+        let span = tracing::info_span!(
+            "http_request",
+            request_id = %request_id.0,
+            method = %req.method(),
+            path = %req.path(),
+            route = field::Empty,
+            status = field::Empty,
+        );
+        let peer = req
+            .connection_info()
+            .realip_remote_addr()
+            .unwrap_or_default()
+            .to_string();
+        let user_agent = req
+            .headers()
+            .get(actix_web::http::header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        req.extensions_mut().insert(request_id.clone());
+        let fut = span.in_scope(|| self.service.call(req));
+
+        Box::pin(
+            async move {
+                let mut res = match fut.await {
+                    Ok(res) => res,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Request failed");
+                        return Err(e);
+                    }
+                };
+                let status = res.status();
+                let span = Span::current();
+                if let Some(route) = res.request().match_pattern() {
+                    span.record("route", route.as_str());
+                }
+                span.record("status", status.as_u16());
+                if let Ok(value) = HeaderValue::from_str(&request_id.0) {
+                    res.headers_mut()
+                        .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+                }
+                tracing::info!(
+                    peer = %peer,
+                    user_agent = %user_agent,
+                    latency_ms = start.elapsed().as_secs_f64() * 1000.0,
+                    "Responded {status}"
+                );
+                Ok(res)
+            }
+            .instrument(span),
+        )
+    }
+}
+
+/// Connection customiser recording a span for every query run through a pooled connection.
+///
+/// Only the SQL is recorded, as bind parameters may contain personal data.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryTracing;
+
+impl CustomizeConnection<PgConnection, diesel::r2d2::Error> for QueryTracing {
+    fn on_acquire(&self, connection: &mut PgConnection) -> Result<(), diesel::r2d2::Error> {
+        diesel::Connection::set_instrumentation(connection, QueryInstrumentation::default());
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct QueryInstrumentation {
+    query: Option<Span>,
+}
+
+impl Instrumentation for QueryInstrumentation {
+    fn on_connection_event(&mut self, event: InstrumentationEvent<'_>) {
+        match event {
+            InstrumentationEvent::StartQuery { query, .. } => {
+                let statement = query.to_string();
+                let statement = statement
+                    .split_once(" -- binds: ")
+                    .map_or(statement.as_str(), |(sql, _)| sql);
+                self.query = Some(tracing::info_span!(
+                    "db_query",
+                    db.system = "postgresql",
+                    db.statement = statement,
+                    error = field::Empty,
+                ));
+            }
+            InstrumentationEvent::FinishQuery { error, .. } => {
+                // closing the span marks the end of the query
+                if let (Some(span), Some(error)) = (self.query.take(), error) {
+                    span.record("error", field::display(error));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_format_from_str() {
+        assert_eq!("text".parse::<LogFormat>(), Ok(LogFormat::Text));
+        assert_eq!("json".parse::<LogFormat>(), Ok(LogFormat::Json));
+        assert!("xml".parse::<LogFormat>().is_err());
+        assert_eq!(LogFormat::default().to_string(), "text");
+    }
+
+    #[test]
+    fn test_is_valid_request_id() {
+        assert!(is_valid_request_id("5f0c8e1e-6f3a-4a57-9a8e-0a4c1d3e2b7f"));
+        assert!(is_valid_request_id("export_api.1234"));
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id("two words"));
+        assert!(!is_valid_request_id("line\nbreak"));
+        assert!(!is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LENGTH + 1)));
+    }
+}
@@ -800,3 +800,37 @@ query($imprintId: Uuid!) {
         Some("dist-roundtrip")
     );
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_create_publication_on_fixture_work_requires_publisher() {
+    let _guard = support::test_lock();
+    let pool = support::db_pool();
+    support::reset_db(&pool).expect("Failed to reset DB");
+    support::load_fixture(&pool, "forthcoming_monograph");
+
+    let query = r#"
+mutation($data: NewPublication!) {
+  createPublication(data: $data) {
+    publicationId
+    workId
+  }
+}
+"#;
+
+    let variables = json!({
+        "data": {
+            "publicationType": "PDF",
+            "workId": "00000000-0000-0000-cccc-000000000001",
+        }
+    });
+
+    let other_org = format!("org-{}", Uuid::new_v4());
+    let user = support::user_with_roles("user-1", &[("PUBLISHER_USER", other_org.as_str())]);
+    let response =
+        support::execute_graphql(pool.clone(), Some(user), query, Some(variables.clone())).await;
+    support::assert_no_access(&response);
+
+    let user = support::user_with_roles("user-2", &[("PUBLISHER_USER", "org-fixture")]);
+    let response = support::execute_graphql(pool, Some(user), query, Some(variables)).await;
+    support::assert_no_errors(&response);
+}
@@ -2,13 +2,13 @@
 
 use std::collections::HashMap;
 use std::env;
-use std::fs::OpenOptions;
+use std::fs::{self, OpenOptions};
 use std::sync::{Arc, OnceLock};
 
 use diesel::RunQueryDsl;
 use fs2::FileExt;
 use serde_json::Value;
-use thoth_api::db::{init_pool, run_migrations, PgPool};
+use thoth_api::db::{init_pool, run_migrations, Fixture, PgPool};
 use thoth_api::graphql::{create_schema, Context, GraphQLRequest};
 use thoth_api::storage::{create_cloudfront_client, create_s3_client, CloudFrontClient, S3Client};
 use zitadel::actix::introspection::IntrospectedUser;
@@ -90,6 +90,17 @@ END $$;
     diesel::sql_query(sql).execute(&mut connection).map(|_| ())
 }
 
+/// Load `tests/fixtures/{name}.json`, as written by `thoth db fixture export`, into the test DB
+pub fn load_fixture(pool: &PgPool, name: &str) {
+    let path = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+    let content = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("Failed to read fixture {path}: {err}"));
+    let fixture: Fixture = serde_json::from_str(&content)
+        .unwrap_or_else(|err| panic!("Failed to parse fixture {path}: {err}"));
+    thoth_api::db::load_fixture(pool, &fixture)
+        .unwrap_or_else(|err| panic!("Failed to load fixture {path}: {err}"));
+}
+
 pub async fn execute_graphql(
     pool: Arc<PgPool>,
     user: Option<IntrospectedUser>,
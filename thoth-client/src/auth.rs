@@ -0,0 +1,21 @@
+use thoth_errors::ThothResult;
+
+/// Source of the bearer token sent in the `Authorization` header of every request.
+///
+/// Any closure returning a token implements this trait, which allows tokens to be read
+/// lazily (e.g. from a secrets store) or refreshed between requests. For a fixed token,
+/// such as a service account Personal Access Token (PAT), use
+/// [`ThothClient::with_token`](crate::ThothClient::with_token).
+pub trait TokenProvider: Send + Sync {
+    /// Token to authenticate the next request with
+    fn token(&self) -> ThothResult<String>;
+}
+
+impl<F> TokenProvider for F
+where
+    F: Fn() -> ThothResult<String> + Send + Sync,
+{
+    fn token(&self) -> ThothResult<String> {
+        self()
+    }
+}
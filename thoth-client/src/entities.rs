@@ -0,0 +1,1320 @@
+//! Generic queries for every entity exposed by the Thoth GraphQL API.
+//!
+//! Each `thoth_api` model struct is an [`Entity`] with the root queries it can be fetched
+//! from, and a [`Fragment`] selecting all of its fields. Callers that only need part of a
+//! record, or want to follow relations, can define their own [`Fragment`] and still get
+//! typed responses from the same methods.
+
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use thoth_api::model::additional_resource::AdditionalResource;
+use thoth_api::model::affiliation::Affiliation;
+use thoth_api::model::audience::Audience;
+use thoth_api::model::award::Award;
+use thoth_api::model::biography::Biography;
+use thoth_api::model::book_review::BookReview;
+use thoth_api::model::contact::Contact;
+use thoth_api::model::contribution::Contribution;
+use thoth_api::model::contributor::Contributor;
+use thoth_api::model::credit_role::CreditRole;
+use thoth_api::model::endorsement::Endorsement;
+use thoth_api::model::file::{File, FileValidationStatus};
+use thoth_api::model::file_audit::FileAudit;
+use thoth_api::model::funding::Funding;
+use thoth_api::model::imprint::Imprint;
+use thoth_api::model::institution::Institution;
+use thoth_api::model::issue::Issue;
+use thoth_api::model::language::Language;
+use thoth_api::model::location::Location;
+use thoth_api::model::name_variant::NameVariant;
+use thoth_api::model::price::Price;
+use thoth_api::model::product_form_detail::ProductFormDetail;
+use thoth_api::model::publication::Publication;
+use thoth_api::model::publisher::Publisher;
+use thoth_api::model::r#abstract::Abstract;
+use thoth_api::model::reference::Reference;
+use thoth_api::model::sales_rights::SalesRights;
+use thoth_api::model::series::Series;
+use thoth_api::model::subject::{Subject, SubjectType};
+use thoth_api::model::title::Title;
+use thoth_api::model::work::Work;
+use thoth_api::model::work_date::WorkDate;
+use thoth_api::model::work_featured_video::WorkFeaturedVideo;
+use thoth_api::model::Doi;
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::selections;
+use crate::ThothClient;
+
+/// A root query of the Thoth GraphQL API, with the arguments it accepts and their GraphQL
+/// types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RootQuery {
+    pub name: &'static str,
+    pub arguments: &'static [(&'static str, &'static str)],
+}
+
+impl RootQuery {
+    /// Build a query document calling this root query with `arguments`, passed as variables,
+    /// and requesting `selection` from its result (an empty selection requests a scalar).
+    fn document(
+        &self,
+        arguments: &QueryArguments,
+        selection: &str,
+        fragments: &str,
+    ) -> ThothResult<(String, Map<String, Value>)> {
+        let mut declarations = Vec::with_capacity(arguments.values.len());
+        let mut parameters = Vec::with_capacity(arguments.values.len());
+        for name in arguments.values.keys() {
+            let (_, graphql_type) = self
+                .arguments
+                .iter()
+                .find(|(argument, _)| *argument == *name)
+                .ok_or_else(|| {
+                    ThothError::GraphqlError(format!(
+                        "Unknown argument \"{name}\" on field \"{}\"",
+                        self.name
+                    ))
+                })?;
+            declarations.push(format!("${name}: {graphql_type}"));
+            parameters.push(format!("{name}: ${name}"));
+        }
+        let (signature, call) = if declarations.is_empty() {
+            (String::new(), String::new())
+        } else {
+            (
+                format!("({})", declarations.join(", ")),
+                format!("({})", parameters.join(", ")),
+            )
+        };
+        let selection = if selection.is_empty() {
+            String::new()
+        } else {
+            format!(" {{{selection}}}")
+        };
+        let query = format!(
+            "query{signature} {{ {}{call}{selection} }}\n{fragments}",
+            self.name
+        );
+        Ok((query, arguments.values.clone()))
+    }
+}
+
+/// An object type of the Thoth GraphQL API
+pub trait Entity {
+    /// Name of the GraphQL object type, for use in fragment type conditions
+    const TYPE_NAME: &'static str;
+}
+
+/// An entity that can be queried by its Thoth ID
+pub trait SingleQuery: Entity {
+    /// Root query returning a single record, whose first argument is the record's ID
+    const SINGLE: RootQuery;
+}
+
+/// An entity that can be listed with `limit` and `offset`
+pub trait ListQuery: Entity {
+    /// Root query returning a page of records
+    const LIST: RootQuery;
+}
+
+/// An entity whose records can be counted
+pub trait CountQuery: Entity {
+    /// Root query returning the number of records
+    const COUNT: RootQuery;
+}
+
+/// Fields to request from an [`Entity`], and the type they are deserialised into.
+///
+/// The `thoth_api` model structs select every one of their fields. To request fewer fields,
+/// or nested objects, implement this trait for your own type:
+///
+/// ```
+/// # use serde::Deserialize;
+/// # use thoth_api::model::publisher::Publisher;
+/// # use thoth_client::Fragment;
+/// # use uuid::Uuid;
+///
+/// #[derive(Deserialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct PublisherWithImprints {
+///     publisher_id: Uuid,
+///     publisher_name: String,
+///     imprints: Vec<ImprintName>,
+/// }
+///
+/// #[derive(Deserialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct ImprintName {
+///     imprint_name: String,
+/// }
+///
+/// impl Fragment for PublisherWithImprints {
+///     type Entity = Publisher;
+///     const SELECTION: &'static str = "...PublisherWithImprints";
+///     const FRAGMENTS: &'static str = "
+///         fragment PublisherWithImprints on Publisher {
+///             publisherId
+///             publisherName
+///             imprints { imprintName }
+///         }
+///     ";
+/// }
+/// ```
+pub trait Fragment: DeserializeOwned {
+    /// Entity the fields are selected from
+    type Entity: Entity;
+    /// Selection set requested from each record, without the surrounding braces
+    const SELECTION: &'static str;
+    /// Fragment definitions used in [`Fragment::SELECTION`], appended to the query document
+    const FRAGMENTS: &'static str = "";
+}
+
+/// Arguments passed to a root query, e.g. to filter or sort a list.
+///
+/// Arguments are named as in the GraphQL schema and checked against the arguments the root
+/// query accepts when the request is built. `limit` and `offset` are set by the paginating
+/// methods.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryArguments {
+    values: Map<String, Value>,
+}
+
+impl QueryArguments {
+    /// Constructs an empty set of arguments, leaving every argument to its default value
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set an argument given its GraphQL name, e.g.
+    /// `argument("workStatuses", vec![WorkStatus::Active])`
+    pub fn argument(mut self, name: &str, value: impl Serialize) -> Self {
+        self.values.insert(name.to_string(), json!(value));
+        self
+    }
+
+    /// Only return records matching a search string
+    pub fn filter(self, filter: impl Into<String>) -> Self {
+        self.argument("filter", filter.into())
+    }
+
+    /// Sort results, given the entity's `OrderBy` input (e.g. `WorkOrderBy`)
+    pub fn order(self, order: impl Serialize) -> Self {
+        self.argument("order", order)
+    }
+
+    /// Only return records belonging to the given publishers
+    pub fn publishers(self, publishers: Vec<Uuid>) -> Self {
+        self.argument("publishers", publishers)
+    }
+}
+
+/// Implement [`Entity`] and [`Fragment`] for a model struct, selecting all of its fields, and
+/// the root query traits for each query the entity can be fetched with.
+macro_rules! entities {
+    (@single $entity:ty, $query:expr) => {
+        impl SingleQuery for $entity {
+            const SINGLE: RootQuery = $query;
+        }
+    };
+    (@list $entity:ty, $query:expr) => {
+        impl ListQuery for $entity {
+            const LIST: RootQuery = $query;
+        }
+    };
+    (@count $entity:ty, $query:expr) => {
+        impl CountQuery for $entity {
+            const COUNT: RootQuery = $query;
+        }
+    };
+    ($(
+        $entity:ty: $type_name:literal = $selection:path {
+            $($kind:ident $name:literal($($argument:literal: $graphql_type:literal),* $(,)?);)*
+        }
+    )*) => {
+        $(
+            impl Entity for $entity {
+                const TYPE_NAME: &'static str = $type_name;
+            }
+
+            impl Fragment for $entity {
+                type Entity = Self;
+                const SELECTION: &'static str = $selection;
+            }
+
+            $(
+                entities!(@$kind $entity, RootQuery {
+                    name: $name,
+                    arguments: &[$(($argument, $graphql_type)),*],
+                });
+            )*
+        )*
+    };
+}
+
+entities! {
+    Abstract: "Abstract" = selections::ABSTRACT {
+        single "abstract"("abstractId": "Uuid!", "markupFormat": "MarkupFormat");
+        list "abstracts"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "AbstractOrderBy",
+            "localeCodes": "[LocaleCode!]",
+            "markupFormat": "MarkupFormat",
+        );
+    }
+    AdditionalResource: "WorkResource" = selections::ADDITIONAL_RESOURCE {
+        single "additionalResource"("additionalResourceId": "Uuid!");
+        list "additionalResources"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "AdditionalResourceOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "additionalResourceCount"();
+    }
+    Affiliation: "Affiliation" = selections::AFFILIATION {
+        single "affiliation"("affiliationId": "Uuid!");
+        list "affiliations"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "AffiliationOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "affiliationCount"();
+    }
+    Audience: "Audience" = selections::AUDIENCE {
+        single "audience"("audienceId": "Uuid!");
+        list "audiences"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "AudienceOrderBy",
+            "publishers": "[Uuid!]",
+            "audienceCodes": "[AudienceCode!]",
+        );
+        count "audienceCount"("audienceCodes": "[AudienceCode!]");
+    }
+    Award: "Award" = selections::AWARD {
+        single "award"("awardId": "Uuid!");
+        list "awards"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "AwardOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "awardCount"();
+    }
+    Biography: "Biography" = selections::BIOGRAPHY {
+        single "biography"("biographyId": "Uuid!", "markupFormat": "MarkupFormat");
+        list "biographies"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "BiographyOrderBy",
+            "localeCodes": "[LocaleCode!]",
+            "markupFormat": "MarkupFormat",
+        );
+    }
+    BookReview: "BookReview" = selections::BOOK_REVIEW {
+        single "bookReview"("bookReviewId": "Uuid!");
+        list "bookReviews"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "BookReviewOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "bookReviewCount"();
+    }
+    Contact: "Contact" = selections::CONTACT {
+        single "contact"("contactId": "Uuid!");
+        list "contacts"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "ContactOrderBy",
+            "publishers": "[Uuid!]",
+            "contactTypes": "[ContactType!]",
+        );
+        count "contactCount"("contactTypes": "[ContactType!]");
+    }
+    Contribution: "Contribution" = selections::CONTRIBUTION {
+        single "contribution"("contributionId": "Uuid!");
+        list "contributions"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "ContributionOrderBy",
+            "publishers": "[Uuid!]",
+            "contributionTypes": "[ContributionType!]",
+        );
+        count "contributionCount"("contributionTypes": "[ContributionType!]");
+    }
+    Contributor: "Contributor" = selections::CONTRIBUTOR {
+        single "contributor"("contributorId": "Uuid!");
+        list "contributors"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "ContributorOrderBy",
+        );
+        count "contributorCount"("filter": "String");
+    }
+    CreditRole: "CreditRole" = selections::CREDIT_ROLE {
+        single "creditRole"("creditRoleId": "Uuid!");
+        list "creditRoles"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "CreditRoleOrderBy",
+            "publishers": "[Uuid!]",
+            "roleTypes": "[CreditRoleType!]",
+        );
+        count "creditRoleCount"("publishers": "[Uuid!]", "roleTypes": "[CreditRoleType!]");
+    }
+    Endorsement: "Endorsement" = selections::ENDORSEMENT {
+        single "endorsement"("endorsementId": "Uuid!");
+        list "endorsements"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "EndorsementOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "endorsementCount"();
+    }
+    File: "File" = selections::FILE {
+        single "file"("fileId": "Uuid!");
+    }
+    FileAudit: "FileAudit" = selections::FILE_AUDIT {
+        list "fileAudits"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "FileAuditOrderBy",
+            "imprintId": "Uuid",
+            "auditRunId": "Uuid",
+            "failuresOnly": "Boolean",
+        );
+    }
+    Funding: "Funding" = selections::FUNDING {
+        single "funding"("fundingId": "Uuid!");
+        list "fundings"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "FundingOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "fundingCount"();
+    }
+    Imprint: "Imprint" = selections::IMPRINT {
+        single "imprint"("imprintId": "Uuid!");
+        list "imprints"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "ImprintOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "imprintCount"("filter": "String", "publishers": "[Uuid!]");
+    }
+    Institution: "Institution" = selections::INSTITUTION {
+        single "institution"("institutionId": "Uuid!");
+        list "institutions"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "InstitutionOrderBy",
+        );
+        count "institutionCount"("filter": "String");
+    }
+    Issue: "Issue" = selections::ISSUE {
+        single "issue"("issueId": "Uuid!");
+        list "issues"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "IssueOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "issueCount"();
+    }
+    Language: "Language" = selections::LANGUAGE {
+        single "language"("languageId": "Uuid!");
+        list "languages"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "LanguageOrderBy",
+            "publishers": "[Uuid!]",
+            "languageCodes": "[LanguageCode!]",
+            "languageRelation": "LanguageRelation",
+            "languageRelations": "[LanguageRelation!]",
+        );
+        count "languageCount"(
+            "languageCodes": "[LanguageCode!]",
+            "languageRelation": "LanguageRelation",
+            "languageRelations": "[LanguageRelation!]",
+        );
+    }
+    Location: "Location" = selections::LOCATION {
+        single "location"("locationId": "Uuid!");
+        list "locations"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "LocationOrderBy",
+            "publishers": "[Uuid!]",
+            "locationPlatforms": "[LocationPlatform!]",
+        );
+        count "locationCount"("locationPlatforms": "[LocationPlatform!]");
+    }
+    NameVariant: "NameVariant" = selections::NAME_VARIANT {
+        single "nameVariant"("nameVariantId": "Uuid!");
+        list "nameVariants"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "NameVariantOrderBy",
+            "publishers": "[Uuid!]",
+            "variantTypes": "[NameVariantType!]",
+        );
+        count "nameVariantCount"("variantTypes": "[NameVariantType!]");
+    }
+    Price: "Price" = selections::PRICE {
+        single "price"("priceId": "Uuid!");
+        list "prices"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "PriceOrderBy",
+            "publishers": "[Uuid!]",
+            "currencyCodes": "[CurrencyCode!]",
+            "onDate": "Date",
+        );
+        count "priceCount"("currencyCodes": "[CurrencyCode!]", "onDate": "Date");
+    }
+    ProductFormDetail: "ProductFormDetail" = selections::PRODUCT_FORM_DETAIL {
+        single "productFormDetail"("productFormDetailId": "Uuid!");
+        list "productFormDetails"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "ProductFormDetailOrderBy",
+            "publishers": "[Uuid!]",
+            "detailTypes": "[ProductFormDetailType!]",
+        );
+        count "productFormDetailCount"("detailTypes": "[ProductFormDetailType!]");
+    }
+    Publication: "Publication" = selections::PUBLICATION {
+        single "publication"("publicationId": "Uuid!");
+        list "publications"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "PublicationOrderBy",
+            "publishers": "[Uuid!]",
+            "publicationTypes": "[PublicationType!]",
+        );
+        count "publicationCount"(
+            "filter": "String",
+            "publishers": "[Uuid!]",
+            "publicationTypes": "[PublicationType!]",
+        );
+    }
+    Publisher: "Publisher" = selections::PUBLISHER {
+        single "publisher"("publisherId": "Uuid!");
+        list "publishers"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "PublisherOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "publisherCount"("filter": "String", "publishers": "[Uuid!]");
+    }
+    Reference: "Reference" = selections::REFERENCE {
+        single "reference"("referenceId": "Uuid!");
+        list "references"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "ReferenceOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "referenceCount"();
+    }
+    SalesRights: "SalesRights" = selections::SALES_RIGHTS {
+        single "salesRight"("salesRightsId": "Uuid!");
+        list "salesRights"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "SalesRightsOrderBy",
+            "publishers": "[Uuid!]",
+            "salesRightsTypes": "[SalesRightsType!]",
+        );
+        count "salesRightsCount"("salesRightsTypes": "[SalesRightsType!]");
+    }
+    Series: "Series" = selections::SERIES {
+        single "series"("seriesId": "Uuid!");
+        list "serieses"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "SeriesOrderBy",
+            "publishers": "[Uuid!]",
+            "seriesTypes": "[SeriesType!]",
+        );
+        count "seriesCount"(
+            "filter": "String",
+            "publishers": "[Uuid!]",
+            "seriesTypes": "[SeriesType!]",
+        );
+    }
+    Subject: "Subject" = selections::SUBJECT {
+        single "subject"("subjectId": "Uuid!");
+        list "subjects"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "SubjectOrderBy",
+            "publishers": "[Uuid!]",
+            "subjectTypes": "[SubjectType!]",
+        );
+        count "subjectCount"("filter": "String", "subjectTypes": "[SubjectType!]");
+    }
+    Title: "Title" = selections::TITLE {
+        single "title"("titleId": "Uuid!", "markupFormat": "MarkupFormat");
+        list "titles"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "TitleOrderBy",
+            "localeCodes": "[LocaleCode!]",
+            "markupFormat": "MarkupFormat",
+        );
+    }
+    Work: "Work" = selections::WORK {
+        single "work"("workId": "Uuid!");
+        list "works"(
+            "limit": "Int",
+            "offset": "Int",
+            "filter": "String",
+            "order": "WorkOrderBy",
+            "publishers": "[Uuid!]",
+            "workTypes": "[WorkType!]",
+            "workStatus": "WorkStatus",
+            "workStatuses": "[WorkStatus!]",
+            "publicationDate": "TimeExpression",
+            "updatedAtWithRelations": "TimeExpression",
+        );
+        count "workCount"(
+            "filter": "String",
+            "publishers": "[Uuid!]",
+            "workTypes": "[WorkType!]",
+            "workStatus": "WorkStatus",
+            "workStatuses": "[WorkStatus!]",
+            "publicationDate": "TimeExpression",
+            "updatedAtWithRelations": "TimeExpression",
+        );
+    }
+    WorkDate: "WorkDate" = selections::WORK_DATE {
+        single "workDate"("workDateId": "Uuid!");
+        list "workDates"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "WorkDateOrderBy",
+            "publishers": "[Uuid!]",
+            "dateTypes": "[WorkDateType!]",
+        );
+        count "workDateCount"("dateTypes": "[WorkDateType!]");
+    }
+    WorkFeaturedVideo: "WorkFeaturedVideo" = selections::WORK_FEATURED_VIDEO {
+        single "workFeaturedVideo"("workFeaturedVideoId": "Uuid!");
+        list "workFeaturedVideos"(
+            "limit": "Int",
+            "offset": "Int",
+            "order": "WorkFeaturedVideoOrderBy",
+            "publishers": "[Uuid!]",
+        );
+        count "workFeaturedVideoCount"();
+    }
+}
+
+/// Generate an uninhabited [`Entity`] for a GraphQL object without a deserialisable
+/// counterpart in `thoth_api`, which callers query through a [`Fragment`] of their own.
+macro_rules! marker_entities {
+    ($($(#[$meta:meta])* $entity:ident;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub enum $entity {}
+
+            impl Entity for $entity {
+                const TYPE_NAME: &'static str = stringify!($entity);
+            }
+        )*
+    };
+}
+
+marker_entities! {
+    /// A Creative Commons licence (or public domain tool) in Thoth's catalogue
+    CreativeCommonsLicense;
+    /// A code within one of the controlled subject vocabularies
+    SubjectHeading;
+    /// A funder listed in the Crossref Funder Registry
+    RegisteredFunder;
+    /// The authenticated user, and the publishers they have access to
+    Me;
+}
+
+const WORK_ARGUMENTS: &[(&str, &str)] = &[
+    ("limit", "Int"),
+    ("offset", "Int"),
+    ("filter", "String"),
+    ("order", "WorkOrderBy"),
+    ("publishers", "[Uuid!]"),
+    ("workStatus", "WorkStatus"),
+    ("workStatuses", "[WorkStatus!]"),
+    ("publicationDate", "TimeExpression"),
+    ("updatedAtWithRelations", "TimeExpression"),
+];
+const WORK_COUNT_ARGUMENTS: &[(&str, &str)] = &[
+    ("filter", "String"),
+    ("publishers", "[Uuid!]"),
+    ("workStatus", "WorkStatus"),
+    ("workStatuses", "[WorkStatus!]"),
+    ("publicationDate", "TimeExpression"),
+    ("updatedAtWithRelations", "TimeExpression"),
+];
+const DOI_ARGUMENT: &[(&str, &str)] = &[("doi", "Doi!")];
+
+const WORK_BY_DOI: RootQuery = RootQuery {
+    name: "workByDoi",
+    arguments: DOI_ARGUMENT,
+};
+const BOOK_BY_DOI: RootQuery = RootQuery {
+    name: "bookByDoi",
+    arguments: DOI_ARGUMENT,
+};
+const CHAPTER_BY_DOI: RootQuery = RootQuery {
+    name: "chapterByDoi",
+    arguments: DOI_ARGUMENT,
+};
+const BOOKS: RootQuery = RootQuery {
+    name: "books",
+    arguments: WORK_ARGUMENTS,
+};
+const CHAPTERS: RootQuery = RootQuery {
+    name: "chapters",
+    arguments: WORK_ARGUMENTS,
+};
+const BOOK_COUNT: RootQuery = RootQuery {
+    name: "bookCount",
+    arguments: WORK_COUNT_ARGUMENTS,
+};
+const CHAPTER_COUNT: RootQuery = RootQuery {
+    name: "chapterCount",
+    arguments: WORK_COUNT_ARGUMENTS,
+};
+const FILES: RootQuery = RootQuery {
+    name: "files",
+    arguments: &[
+        ("imprintId", "Uuid!"),
+        ("validationStatuses", "[FileValidationStatus!]"),
+    ],
+};
+const FILE_INTEGRITY_AUDIT: RootQuery = RootQuery {
+    name: "fileIntegrityAudit",
+    arguments: &[
+        ("imprintId", "Uuid!"),
+        ("failuresOnly", "Boolean"),
+        ("verifyChecksums", "Boolean"),
+    ],
+};
+const CREATIVE_COMMONS_LICENSES: RootQuery = RootQuery {
+    name: "creativeCommonsLicenses",
+    arguments: &[],
+};
+const SUBJECT_VOCABULARY: RootQuery = RootQuery {
+    name: "subjectVocabulary",
+    arguments: &[
+        ("subjectType", "SubjectType!"),
+        ("filter", "String"),
+        ("parentCode", "String"),
+        ("limit", "Int"),
+    ],
+};
+const REGISTERED_FUNDERS: RootQuery = RootQuery {
+    name: "registeredFunders",
+    arguments: &[("filter", "String!"), ("limit", "Int")],
+};
+const REGISTERED_FUNDER: RootQuery = RootQuery {
+    name: "registeredFunder",
+    arguments: &[("funderDoi", "Doi!")],
+};
+const ME: RootQuery = RootQuery {
+    name: "me",
+    arguments: &[],
+};
+
+impl ThothClient {
+    /// Call a root query and deserialise the records it returns as `F`
+    async fn select<F: Fragment, T: DeserializeOwned>(
+        &self,
+        query: &RootQuery,
+        arguments: &QueryArguments,
+    ) -> ThothResult<T> {
+        let (document, variables) = query.document(arguments, F::SELECTION, F::FRAGMENTS)?;
+        self.execute(query.name, document, variables).await
+    }
+
+    /// Call a root query returning the number of records matching `arguments`
+    async fn count(&self, query: &RootQuery, arguments: &QueryArguments) -> ThothResult<i64> {
+        let (document, variables) = query.document(arguments, "", "")?;
+        self.execute(query.name, document, variables).await
+    }
+
+    /// Stream the pages of a list query, stopping after the first page shorter than
+    /// `page_size`
+    fn paginate<'a, F: Fragment + 'a>(
+        &'a self,
+        query: RootQuery,
+        arguments: QueryArguments,
+        page_size: i64,
+    ) -> impl Stream<Item = ThothResult<Vec<F>>> + 'a {
+        stream::try_unfold(Some(0), move |offset: Option<i64>| {
+            let arguments = arguments.clone().argument("limit", page_size);
+            async move {
+                let Some(offset) = offset else {
+                    return Ok::<_, ThothError>(None);
+                };
+                let arguments = arguments.argument("offset", offset);
+                let page: Vec<F> = self.select::<F, _>(&query, &arguments).await?;
+                if page.is_empty() {
+                    return Ok(None);
+                }
+                let next_offset = (page.len() as i64 >= page_size).then_some(offset + page_size);
+                Ok(Some((page, next_offset)))
+            }
+        })
+    }
+
+    /// Flatten a stream of pages into a stream of records
+    fn flatten_pages<'a, F: 'a>(
+        pages: impl Stream<Item = ThothResult<Vec<F>>> + 'a,
+    ) -> impl Stream<Item = ThothResult<F>> + 'a {
+        pages
+            .map_ok(|page| stream::iter(page.into_iter().map(Ok::<F, ThothError>)))
+            .try_flatten()
+    }
+
+    /// Get a single record from Thoth given its ID
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the ID was not found or if there was an error while sending the
+    /// request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use thoth_api::model::publisher::Publisher;
+    /// # use thoth_errors::ThothResult;
+    /// # use thoth_client::ThothClient;
+    /// # use uuid::Uuid;
+    ///
+    /// # async fn run() -> ThothResult<Publisher> {
+    /// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string());
+    /// let publisher_id = Uuid::parse_str("00000000-0000-0000-AAAA-000000000001")?;
+    /// let publisher: Publisher = thoth_client.get_entity(publisher_id).await?;
+    /// # Ok(publisher)
+    /// # }
+    /// ```
+    pub async fn get_entity<F>(&self, id: Uuid) -> ThothResult<F>
+    where
+        F: Fragment,
+        F::Entity: SingleQuery,
+    {
+        let query = <F::Entity as SingleQuery>::SINGLE;
+        let arguments = QueryArguments::new().argument(query.arguments[0].0, id);
+        self.select::<F, _>(&query, &arguments).await
+    }
+
+    /// Get a page of records from Thoth
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the root query does not accept one of the `arguments` or if there
+    /// was an error while sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use thoth_api::model::contributor::Contributor;
+    /// # use thoth_errors::ThothResult;
+    /// # use thoth_client::{QueryArguments, ThothClient};
+    ///
+    /// # async fn run() -> ThothResult<Vec<Contributor>> {
+    /// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string());
+    /// let arguments = QueryArguments::new().filter("Tolstoy");
+    /// let contributors: Vec<Contributor> = thoth_client.get_entities(&arguments, 100, 0).await?;
+    /// # Ok(contributors)
+    /// # }
+    /// ```
+    pub async fn get_entities<F>(
+        &self,
+        arguments: &QueryArguments,
+        limit: i64,
+        offset: i64,
+    ) -> ThothResult<Vec<F>>
+    where
+        F: Fragment,
+        F::Entity: ListQuery,
+    {
+        let arguments = arguments
+            .clone()
+            .argument("limit", limit)
+            .argument("offset", offset);
+        self.select::<F, _>(&<F::Entity as ListQuery>::LIST, &arguments)
+            .await
+    }
+
+    /// Get the number of records in Thoth matching `arguments`. Any `order` argument is
+    /// ignored, so the same arguments can be used to list and count records.
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the root query does not accept one of the `arguments` or if there
+    /// was an error while sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use thoth_api::model::institution::Institution;
+    /// # use thoth_errors::ThothResult;
+    /// # use thoth_client::{QueryArguments, ThothClient};
+    ///
+    /// # async fn run() -> ThothResult<i64> {
+    /// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string());
+    /// let arguments = QueryArguments::new().filter("University");
+    /// let count = thoth_client.get_entity_count::<Institution>(&arguments).await?;
+    /// # Ok(count)
+    /// # }
+    /// ```
+    pub async fn get_entity_count<E: CountQuery>(
+        &self,
+        arguments: &QueryArguments,
+    ) -> ThothResult<i64> {
+        let mut arguments = arguments.clone();
+        arguments.values.remove("order");
+        self.count(&E::COUNT, &arguments).await
+    }
+
+    /// Stream every record matching `arguments`, one page of `page_size` records at a time
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error, and then ends, if a page could not be retrieved
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use futures::TryStreamExt;
+    /// # use thoth_api::model::series::Series;
+    /// # use thoth_errors::ThothResult;
+    /// # use thoth_client::{QueryArguments, ThothClient};
+    /// # use uuid::Uuid;
+    ///
+    /// # async fn run() -> ThothResult<()> {
+    /// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string());
+    /// let publisher_id = Uuid::parse_str("00000000-0000-0000-AAAA-000000000001")?;
+    /// let arguments = QueryArguments::new().publishers(vec![publisher_id]);
+    /// let mut pages = Box::pin(thoth_client.stream_entity_pages::<Series>(arguments, 100));
+    /// while let Some(page) = pages.try_next().await? {
+    ///     println!("Retrieved {} series", page.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_entity_pages<'a, F>(
+        &'a self,
+        arguments: QueryArguments,
+        page_size: i64,
+    ) -> impl Stream<Item = ThothResult<Vec<F>>> + 'a
+    where
+        F: Fragment + 'a,
+        F::Entity: ListQuery,
+    {
+        self.paginate(<F::Entity as ListQuery>::LIST, arguments, page_size)
+    }
+
+    /// Stream every record matching `arguments`, requesting `page_size` records at a time
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error, and then ends, if a page could not be retrieved
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use futures::TryStreamExt;
+    /// # use thoth_api::model::funding::Funding;
+    /// # use thoth_errors::ThothResult;
+    /// # use thoth_client::{QueryArguments, ThothClient};
+    ///
+    /// # async fn run() -> ThothResult<Vec<Funding>> {
+    /// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string());
+    /// let fundings: Vec<Funding> = thoth_client
+    ///     .stream_entities(QueryArguments::new(), 500)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(fundings)
+    /// # }
+    /// ```
+    pub fn stream_entities<'a, F>(
+        &'a self,
+        arguments: QueryArguments,
+        page_size: i64,
+    ) -> impl Stream<Item = ThothResult<F>> + 'a
+    where
+        F: Fragment + 'a,
+        F::Entity: ListQuery,
+    {
+        Self::flatten_pages(self.stream_entity_pages(arguments, page_size))
+    }
+
+    /// Get a `Work` from Thoth given its DOI
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the DOI was not found or if there was an error while sending the
+    /// request
+    pub async fn get_work_by_doi<F: Fragment<Entity = Work>>(&self, doi: &Doi) -> ThothResult<F> {
+        let arguments = QueryArguments::new().argument("doi", doi);
+        self.select::<F, _>(&WORK_BY_DOI, &arguments).await
+    }
+
+    /// Get a book (a work of any type other than book chapter) from Thoth given its DOI
+    ///
+    /// # Errors
+    ///
+    /// This method fails if no book has this DOI or if there was an error while sending the
+    /// request
+    pub async fn get_book_by_doi<F: Fragment<Entity = Work>>(&self, doi: &Doi) -> ThothResult<F> {
+        let arguments = QueryArguments::new().argument("doi", doi);
+        self.select::<F, _>(&BOOK_BY_DOI, &arguments).await
+    }
+
+    /// Get a book chapter from Thoth given its DOI
+    ///
+    /// # Errors
+    ///
+    /// This method fails if no chapter has this DOI or if there was an error while sending
+    /// the request
+    pub async fn get_chapter_by_doi<F: Fragment<Entity = Work>>(
+        &self,
+        doi: &Doi,
+    ) -> ThothResult<F> {
+        let arguments = QueryArguments::new().argument("doi", doi);
+        self.select::<F, _>(&CHAPTER_BY_DOI, &arguments).await
+    }
+
+    /// Get a page of books from Thoth
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the root query does not accept one of the `arguments` or if there
+    /// was an error while sending the request
+    pub async fn get_books<F: Fragment<Entity = Work>>(
+        &self,
+        arguments: &QueryArguments,
+        limit: i64,
+        offset: i64,
+    ) -> ThothResult<Vec<F>> {
+        let arguments = arguments
+            .clone()
+            .argument("limit", limit)
+            .argument("offset", offset);
+        self.select::<F, _>(&BOOKS, &arguments).await
+    }
+
+    /// Get a page of book chapters from Thoth
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the root query does not accept one of the `arguments` or if there
+    /// was an error while sending the request
+    pub async fn get_chapters<F: Fragment<Entity = Work>>(
+        &self,
+        arguments: &QueryArguments,
+        limit: i64,
+        offset: i64,
+    ) -> ThothResult<Vec<F>> {
+        let arguments = arguments
+            .clone()
+            .argument("limit", limit)
+            .argument("offset", offset);
+        self.select::<F, _>(&CHAPTERS, &arguments).await
+    }
+
+    /// Get the number of books in Thoth matching `arguments`, ignoring any `order` argument
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the root query does not accept one of the `arguments` or if there
+    /// was an error while sending the request
+    pub async fn get_book_count(&self, arguments: &QueryArguments) -> ThothResult<i64> {
+        let mut arguments = arguments.clone();
+        arguments.values.remove("order");
+        self.count(&BOOK_COUNT, &arguments).await
+    }
+
+    /// Get the number of book chapters in Thoth matching `arguments`, ignoring any `order`
+    /// argument
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the root query does not accept one of the `arguments` or if there
+    /// was an error while sending the request
+    pub async fn get_chapter_count(&self, arguments: &QueryArguments) -> ThothResult<i64> {
+        let mut arguments = arguments.clone();
+        arguments.values.remove("order");
+        self.count(&CHAPTER_COUNT, &arguments).await
+    }
+
+    /// Stream every book matching `arguments`, requesting `page_size` books at a time
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error, and then ends, if a page could not be retrieved
+    pub fn stream_books<'a, F: Fragment<Entity = Work> + 'a>(
+        &'a self,
+        arguments: QueryArguments,
+        page_size: i64,
+    ) -> impl Stream<Item = ThothResult<F>> + 'a {
+        Self::flatten_pages(self.paginate(BOOKS, arguments, page_size))
+    }
+
+    /// Stream every book chapter matching `arguments`, requesting `page_size` chapters at a
+    /// time
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error, and then ends, if a page could not be retrieved
+    pub fn stream_chapters<'a, F: Fragment<Entity = Work> + 'a>(
+        &'a self,
+        arguments: QueryArguments,
+        page_size: i64,
+    ) -> impl Stream<Item = ThothResult<F>> + 'a {
+        Self::flatten_pages(self.paginate(CHAPTERS, arguments, page_size))
+    }
+
+    /// Get the files stored for an imprint, optionally restricted to the given validation
+    /// statuses
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the client may not access the imprint or if there was an error
+    /// while sending the request
+    pub async fn get_files<F: Fragment<Entity = File>>(
+        &self,
+        imprint_id: Uuid,
+        validation_statuses: Vec<FileValidationStatus>,
+    ) -> ThothResult<Vec<F>> {
+        let arguments = QueryArguments::new()
+            .argument("imprintId", imprint_id)
+            .argument("validationStatuses", validation_statuses);
+        self.select::<F, _>(&FILES, &arguments).await
+    }
+
+    /// Check every file stored for an imprint against the object held in S3, and get the
+    /// recorded audits. Superuser only.
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the client is not authenticated as a superuser, if the imprint
+    /// was not found, or if there was an error while sending the request
+    pub async fn get_file_integrity_audit<F: Fragment<Entity = FileAudit>>(
+        &self,
+        imprint_id: Uuid,
+        failures_only: bool,
+        verify_checksums: bool,
+    ) -> ThothResult<Vec<F>> {
+        let arguments = QueryArguments::new()
+            .argument("imprintId", imprint_id)
+            .argument("failuresOnly", failures_only)
+            .argument("verifyChecksums", verify_checksums);
+        self.select::<F, _>(&FILE_INTEGRITY_AUDIT, &arguments).await
+    }
+
+    /// Get the Creative Commons licences (and public domain tools) accepted by Thoth
+    ///
+    /// # Errors
+    ///
+    /// This method fails if there was an error while sending the request
+    pub async fn get_creative_commons_licenses<F>(&self) -> ThothResult<Vec<F>>
+    where
+        F: Fragment<Entity = CreativeCommonsLicense>,
+    {
+        self.select::<F, _>(&CREATIVE_COMMONS_LICENSES, &QueryArguments::new())
+            .await
+    }
+
+    /// Search the controlled vocabulary of a subject type. `arguments` may set `filter`,
+    /// `parentCode` and `limit`.
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the subject type has no controlled vocabulary, if the root query
+    /// does not accept one of the `arguments`, or if there was an error while sending the
+    /// request
+    pub async fn get_subject_vocabulary<F>(
+        &self,
+        subject_type: SubjectType,
+        arguments: &QueryArguments,
+    ) -> ThothResult<Vec<F>>
+    where
+        F: Fragment<Entity = SubjectHeading>,
+    {
+        let arguments = arguments.clone().argument("subjectType", subject_type);
+        self.select::<F, _>(&SUBJECT_VOCABULARY, &arguments).await
+    }
+
+    /// Search the local snapshot of the Crossref Funder Registry by funder name or DOI
+    ///
+    /// # Errors
+    ///
+    /// This method fails if there was an error while sending the request
+    pub async fn get_registered_funders<F>(&self, filter: &str, limit: i64) -> ThothResult<Vec<F>>
+    where
+        F: Fragment<Entity = RegisteredFunder>,
+    {
+        let arguments = QueryArguments::new()
+            .filter(filter)
+            .argument("limit", limit);
+        self.select::<F, _>(&REGISTERED_FUNDERS, &arguments).await
+    }
+
+    /// Look up a funder DOI in the local snapshot of the Crossref Funder Registry, returning
+    /// `None` if it is not listed
+    ///
+    /// # Errors
+    ///
+    /// This method fails if there was an error while sending the request
+    pub async fn get_registered_funder<F>(&self, funder_doi: &Doi) -> ThothResult<Option<F>>
+    where
+        F: Fragment<Entity = RegisteredFunder>,
+    {
+        let arguments = QueryArguments::new().argument("funderDoi", funder_doi);
+        self.select::<F, _>(&REGISTERED_FUNDER, &arguments).await
+    }
+
+    /// Get the user the client is authenticated as
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the client is not authenticated or if there was an error while
+    /// sending the request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use serde::Deserialize;
+    /// # use thoth_errors::ThothResult;
+    /// # use thoth_client::{Fragment, Me, ThothClient};
+    ///
+    /// #[derive(Deserialize)]
+    /// #[serde(rename_all = "camelCase")]
+    /// struct User {
+    ///     user_id: String,
+    ///     is_superuser: bool,
+    /// }
+    ///
+    /// impl Fragment for User {
+    ///     type Entity = Me;
+    ///     const SELECTION: &'static str = "userId isSuperuser";
+    /// }
+    ///
+    /// # async fn run() -> ThothResult<User> {
+    /// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string())
+    ///     .with_token("my-personal-access-token".to_string());
+    /// let user: User = thoth_client.get_me().await?;
+    /// # Ok(user)
+    /// # }
+    /// ```
+    pub async fn get_me<F: Fragment<Entity = Me>>(&self) -> ThothResult<F> {
+        self.select::<F, _>(&ME, &QueryArguments::new()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct PublisherName {
+        #[allow(dead_code)]
+        publisher_name: String,
+    }
+
+    impl Fragment for PublisherName {
+        type Entity = Publisher;
+        const SELECTION: &'static str = "...PublisherName";
+        const FRAGMENTS: &'static str = "fragment PublisherName on Publisher { publisherName }";
+    }
+
+    #[test]
+    fn test_document_declares_arguments_as_variables() {
+        let arguments = QueryArguments::new()
+            .filter("Open")
+            .argument("limit", 10)
+            .publishers(vec![Uuid::nil()]);
+        let (query, variables) = Publisher::LIST
+            .document(
+                &arguments,
+                PublisherName::SELECTION,
+                PublisherName::FRAGMENTS,
+            )
+            .unwrap();
+        assert_eq!(
+            query,
+            "query($filter: String, $limit: Int, $publishers: [Uuid!]) { \
+             publishers(filter: $filter, limit: $limit, publishers: $publishers) \
+             {...PublisherName} }\n\
+             fragment PublisherName on Publisher { publisherName }"
+        );
+        assert_eq!(variables.get("filter"), Some(&json!("Open")));
+        assert_eq!(variables.get("limit"), Some(&json!(10)));
+        assert_eq!(
+            variables.get("publishers"),
+            Some(&json!(["00000000-0000-0000-0000-000000000000"]))
+        );
+    }
+
+    #[test]
+    fn test_document_without_arguments_or_selection() {
+        let (query, variables) = Issue::COUNT
+            .document(&QueryArguments::new(), "", "")
+            .unwrap();
+        assert_eq!(query, "query { issueCount }\n");
+        assert!(variables.is_empty());
+    }
+
+    #[test]
+    fn test_document_rejects_unknown_argument() {
+        let arguments = QueryArguments::new().publishers(vec![Uuid::nil()]);
+        assert_eq!(
+            Contributor::LIST.document(&arguments, Contributor::SELECTION, ""),
+            Err(ThothError::GraphqlError(
+                "Unknown argument \"publishers\" on field \"contributors\"".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_entities_use_graphql_names() {
+        assert_eq!(AdditionalResource::TYPE_NAME, "WorkResource");
+        assert_eq!(
+            AdditionalResource::SINGLE.arguments[0].0,
+            "additionalResourceId"
+        );
+        assert_eq!(SalesRights::SINGLE.name, "salesRight");
+        assert_eq!(Series::LIST.name, "serieses");
+        assert_eq!(Me::TYPE_NAME, "Me");
+    }
+}
@@ -1,8 +1,19 @@
+mod auth;
+mod entities;
+mod mutations;
 mod parameters;
 // GraphQLQuery derive macro breaks this linting rule - ignore while awaiting fix
 #[allow(clippy::derive_partial_eq_without_eq)]
 mod queries;
+mod request_id;
+mod selections;
+mod upload;
 
+pub use crate::auth::TokenProvider;
+pub use crate::entities::{
+    CountQuery, CreativeCommonsLicense, Entity, Fragment, ListQuery, Me, QueryArguments,
+    RegisteredFunder, RootQuery, SingleQuery, SubjectHeading,
+};
 pub use crate::parameters::QueryParameters;
 use crate::parameters::{WorkQueryVariables, WorksQueryVariables};
 pub use crate::queries::work_query::*;
@@ -10,15 +21,18 @@ use crate::queries::{
     work_count_query, work_last_updated_query, work_query, works_last_updated_query, works_query,
     WorkCountQuery, WorkLastUpdatedQuery, WorkQuery, WorksLastUpdatedQuery, WorksQuery,
 };
+use crate::request_id::current_request_id;
+pub use crate::request_id::{with_request_id, REQUEST_ID_HEADER};
+pub use crate::upload::FileUploadTarget;
 pub use chrono::NaiveDate;
 use graphql_client::GraphQLQuery;
 use graphql_client::Response;
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 use serde::de::DeserializeOwned;
-use serde::Serialize;
-use serde_json::Value;
-use std::future::Future;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::sync::Arc;
 use thoth_api::model::Timestamp;
 use thoth_errors::{ThothError, ThothResult};
 use uuid::Uuid;
@@ -26,12 +40,47 @@ use uuid::Uuid;
 /// Maximum number of allowed request retries attempts.
 const MAX_REQUEST_RETRIES: u32 = 5;
 
-type HttpFuture = Result<reqwest::Response, reqwest_middleware::Error>;
-
-/// A GraphQL `ThothClient` to query metadata
+/// A GraphQL `ThothClient` to query and edit metadata
 pub struct ThothClient {
     graphql_endpoint: String,
     http_client: ClientWithMiddleware,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+}
+
+/// Single entry of the `errors` array of a GraphQL response
+#[derive(Deserialize)]
+struct GraphqlErrorResponse {
+    message: String,
+    #[serde(default)]
+    extensions: GraphqlErrorExtensions,
+}
+
+/// Error details added to GraphQL `extensions` by the Thoth API
+#[derive(Default, Deserialize)]
+struct GraphqlErrorExtensions {
+    code: Option<String>,
+    #[serde(default)]
+    arguments: Vec<String>,
+}
+
+/// Rebuild the `ThothError` raised by the API from the first entry in a GraphQL `errors`
+/// array, falling back to a `GraphqlError` with the server's message when its code is not
+/// recognised (e.g. query validation errors, or a newer API version)
+fn graphql_error(errors: &Value) -> ThothError {
+    let error = errors
+        .as_array()
+        .and_then(|errors| errors.first())
+        .and_then(|error| GraphqlErrorResponse::deserialize(error).ok());
+    match error {
+        Some(GraphqlErrorResponse {
+            message,
+            extensions,
+        }) => extensions
+            .code
+            .and_then(|code| ThothError::from_code(&code, &extensions.arguments))
+            .unwrap_or(ThothError::GraphqlError(message)),
+        None => ThothError::GraphqlError(format!("GraphQL errors: {errors}")),
+    }
 }
 
 impl ThothClient {
@@ -45,17 +94,92 @@ impl ThothClient {
         ThothClient {
             graphql_endpoint,
             http_client,
+            token_provider: None,
         }
     }
 
+    /// Authenticate every request with the given bearer token, e.g. a service account
+    /// Personal Access Token (PAT)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use thoth_errors::ThothResult;
+    /// # use thoth_api::model::publisher::{NewPublisher, Publisher};
+    /// # use thoth_client::ThothClient;
+    ///
+    /// # async fn run() -> ThothResult<Publisher> {
+    /// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string())
+    ///     .with_token("my-personal-access-token".to_string());
+    /// let publisher = thoth_client
+    ///     .create_publisher(NewPublisher {
+    ///         publisher_name: "Test Publisher".to_string(),
+    ///         publisher_shortname: None,
+    ///         publisher_url: Some("https://example.org".to_string()),
+    ///         zitadel_id: None,
+    ///         accessibility_statement: None,
+    ///         accessibility_report_url: None,
+    ///     })
+    ///     .await?;
+    /// # Ok(publisher)
+    /// # }
+    /// ```
+    pub fn with_token(self, token: String) -> Self {
+        self.with_token_provider(move || -> ThothResult<String> { Ok(token.clone()) })
+    }
+
+    /// Authenticate every request with a bearer token obtained from `token_provider` just
+    /// before the request is sent
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use thoth_errors::ThothError;
+    /// # use thoth_client::ThothClient;
+    ///
+    /// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string())
+    ///     .with_token_provider(|| {
+    ///         std::env::var("THOTH_PAT")
+    ///             .map_err(|_| ThothError::InternalError("THOTH_PAT is not set".to_string()))
+    ///     });
+    /// ```
+    pub fn with_token_provider(mut self, token_provider: impl TokenProvider + 'static) -> Self {
+        self.token_provider = Some(Arc::new(token_provider));
+        self
+    }
+
     async fn post_request<T: Serialize + ?Sized>(
         &self,
         request_body: &T,
-    ) -> impl Future<Output = HttpFuture> {
-        self.http_client
-            .post(&self.graphql_endpoint)
-            .json(&request_body)
-            .send()
+    ) -> ThothResult<reqwest::Response> {
+        let mut request = self.http_client.post(&self.graphql_endpoint);
+        if let Some(token_provider) = &self.token_provider {
+            request = request.bearer_auth(token_provider.token()?);
+        }
+        if let Some(request_id) = current_request_id() {
+            request = request.header(REQUEST_ID_HEADER, request_id);
+        }
+        request.json(&request_body).send().await.map_err(Into::into)
+    }
+
+    /// Send a GraphQL operation and deserialise the result of its root field, found under
+    /// `data.<field>`
+    async fn execute<T: DeserializeOwned>(
+        &self,
+        field: &str,
+        query: String,
+        variables: Map<String, Value>,
+    ) -> ThothResult<T> {
+        let request_body = json!({ "query": query, "variables": variables });
+        let res = self.post_request(&request_body).await?;
+        let response_body: Response<Value> = self.parse_graphql_response(res).await?;
+        match response_body
+            .data
+            .and_then(|mut data| data.get_mut(field).map(Value::take))
+        {
+            Some(value) => serde_json::from_value(value).map_err(Into::into),
+            None => Err(ThothError::EntityNotFound),
+        }
     }
 
     async fn parse_graphql_response<T: DeserializeOwned>(
@@ -74,9 +198,7 @@ impl ThothClient {
         let value: Value = serde_json::from_slice(&bytes)?;
 
         if let Some(errors) = value.get("errors") {
-            return Err(ThothError::RequestError(format!(
-                "GraphQL errors: {errors}"
-            )));
+            return Err(graphql_error(errors));
         }
 
         match value.get("data") {
@@ -90,6 +212,17 @@ impl ThothClient {
         }
     }
 
+    /// Check that the GraphQL endpoint is reachable and able to answer queries
+    ///
+    /// # Errors
+    ///
+    /// This method fails if the request could not be sent or the API returned an error
+    pub async fn ping(&self) -> ThothResult<()> {
+        self.execute::<String>("__typename", "query { __typename }".to_string(), Map::new())
+            .await
+            .map(|_| ())
+    }
+
     /// Get a `Work` from Thoth given its `work_id`
     ///
     /// # Errors
@@ -114,7 +247,7 @@ impl ThothClient {
     pub async fn get_work(&self, work_id: Uuid, parameters: QueryParameters) -> ThothResult<Work> {
         let variables: work_query::Variables = WorkQueryVariables::new(work_id, parameters).into();
         let request_body = WorkQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
+        let res = self.post_request(&request_body).await?;
         let response_body: Response<work_query::ResponseData> =
             self.parse_graphql_response(res).await?;
         match response_body.data {
@@ -153,7 +286,7 @@ impl ThothClient {
         let variables: works_query::Variables =
             WorksQueryVariables::new(publishers, limit, offset, parameters).into();
         let request_body = WorksQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
+        let res = self.post_request(&request_body).await?;
         let response_body: Response<works_query::ResponseData> =
             self.parse_graphql_response(res).await?;
         match response_body.data {
@@ -185,7 +318,7 @@ impl ThothClient {
     pub async fn get_work_count(&self, publishers: Option<Vec<Uuid>>) -> ThothResult<i64> {
         let variables = work_count_query::Variables { publishers };
         let request_body = WorkCountQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
+        let res = self.post_request(&request_body).await?;
         let response_body: Response<work_count_query::ResponseData> =
             self.parse_graphql_response(res).await?;
         match response_body.data {
@@ -218,7 +351,7 @@ impl ThothClient {
     pub async fn get_work_last_updated(&self, work_id: Uuid) -> ThothResult<Timestamp> {
         let variables = work_last_updated_query::Variables { work_id };
         let request_body = WorkLastUpdatedQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
+        let res = self.post_request(&request_body).await?;
         let response_body: Response<work_last_updated_query::ResponseData> =
             self.parse_graphql_response(res).await?;
         match response_body.data {
@@ -254,7 +387,7 @@ impl ThothClient {
     ) -> ThothResult<Timestamp> {
         let variables = works_last_updated_query::Variables { publishers };
         let request_body = WorksLastUpdatedQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
+        let res = self.post_request(&request_body).await?;
         let response_body: Response<works_last_updated_query::ResponseData> =
             self.parse_graphql_response(res).await?;
         match response_body.data {
@@ -269,3 +402,65 @@ impl ThothClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graphql_error_is_rebuilt_from_extensions() {
+        let errors = json!([{
+            "message": "A work with this DOI already exists.",
+            "locations": [{ "line": 1, "column": 30 }],
+            "path": ["createWork"],
+            "extensions": {
+                "type": "INTERNAL_ERROR",
+                "code": "DATABASE_CONSTRAINT_VIOLATION",
+                "category": "CONFLICT",
+                "status": 409,
+                "field": "doi",
+                "arguments": ["doi_uniq_idx"]
+            }
+        }]);
+        let error = graphql_error(&errors);
+        assert_eq!(error.code(), "DATABASE_CONSTRAINT_VIOLATION");
+        assert_eq!(error.field(), Some("doi"));
+        assert_eq!(error.to_string(), "A work with this DOI already exists.");
+
+        let errors = json!([{
+            "message": "Publication Date is required for Active, Withdrawn, and Superseded Works.",
+            "extensions": { "code": "PUBLICATION_DATE" }
+        }]);
+        assert_eq!(graphql_error(&errors), ThothError::PublicationDateError);
+
+        let errors = json!([{
+            "message": "10.1234 is not a validly formatted DOI and will not be saved",
+            "extensions": { "code": "DOI_PARSE", "arguments": ["10.1234"] }
+        }]);
+        assert_eq!(
+            graphql_error(&errors),
+            ThothError::DoiParseError("10.1234".to_string())
+        );
+    }
+
+    #[test]
+    fn test_graphql_error_without_known_code() {
+        let errors = json!([{
+            "message": "Unknown field \"foo\" on type \"Work\"",
+            "locations": [{ "line": 1, "column": 10 }]
+        }]);
+        assert_eq!(
+            graphql_error(&errors),
+            ThothError::GraphqlError("Unknown field \"foo\" on type \"Work\"".to_string())
+        );
+
+        let errors = json!([{
+            "message": "Something new",
+            "extensions": { "code": "SOMETHING_NEW" }
+        }]);
+        assert_eq!(
+            graphql_error(&errors),
+            ThothError::GraphqlError("Something new".to_string())
+        );
+    }
+}
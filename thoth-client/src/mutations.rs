@@ -0,0 +1,613 @@
+//! Typed methods for every mutation exposed by the Thoth GraphQL API.
+//!
+//! Inputs and outputs are the `thoth_api` model structs, so records can be passed between
+//! the API and the client without conversion. Mutations require a client authenticated with
+//! [`ThothClient::with_token`] or [`ThothClient::with_token_provider`].
+
+use thoth_api::markup::MarkupFormat;
+use thoth_api::model::additional_resource::{
+    AdditionalResource, NewAdditionalResource, PatchAdditionalResource,
+};
+use thoth_api::model::affiliation::{Affiliation, NewAffiliation, PatchAffiliation};
+use thoth_api::model::audience::{Audience, NewAudience, PatchAudience};
+use thoth_api::model::award::{Award, NewAward, PatchAward};
+use thoth_api::model::biography::{Biography, NewBiography, PatchBiography};
+use thoth_api::model::book_review::{BookReview, NewBookReview, PatchBookReview};
+use thoth_api::model::contact::{Contact, NewContact, PatchContact};
+use thoth_api::model::contribution::{Contribution, NewContribution, PatchContribution};
+use thoth_api::model::contributor::{Contributor, NewContributor, PatchContributor};
+use thoth_api::model::credit_role::{CreditRole, NewCreditRole, PatchCreditRole};
+use thoth_api::model::endorsement::{Endorsement, NewEndorsement, PatchEndorsement};
+use thoth_api::model::file::{
+    CompleteFileUpload, File, FileUploadResponse, NewAdditionalResourceFileUpload,
+    NewFrontcoverFileUpload, NewPublicationFileUpload, NewWorkFeaturedVideoFileUpload,
+};
+use thoth_api::model::file_version::FileVersion;
+use thoth_api::model::funding::{Funding, NewFunding, PatchFunding};
+use thoth_api::model::imprint::{Imprint, NewImprint, PatchImprint};
+use thoth_api::model::institution::{Institution, NewInstitution, PatchInstitution};
+use thoth_api::model::issue::{Issue, NewIssue, PatchIssue};
+use thoth_api::model::language::{Language, NewLanguage, PatchLanguage};
+use thoth_api::model::location::{Location, NewLocation, PatchLocation};
+use thoth_api::model::name_variant::{NameVariant, NewNameVariant, PatchNameVariant};
+use thoth_api::model::price::{NewPrice, PatchPrice, Price};
+use thoth_api::model::product_form_detail::{
+    NewProductFormDetail, PatchProductFormDetail, ProductFormDetail,
+};
+use thoth_api::model::publication::{NewPublication, PatchPublication, Publication};
+use thoth_api::model::publisher::{NewPublisher, PatchPublisher, Publisher};
+use thoth_api::model::r#abstract::{Abstract, NewAbstract, PatchAbstract};
+use thoth_api::model::reference::{NewReference, PatchReference, Reference};
+use thoth_api::model::sales_rights::{NewSalesRights, PatchSalesRights, SalesRights};
+use thoth_api::model::series::{NewSeries, PatchSeries, Series};
+use thoth_api::model::subject::{NewSubject, PatchSubject, Subject};
+use thoth_api::model::title::{NewTitle, PatchTitle, Title};
+use thoth_api::model::work::{NewWork, PatchWork, Work};
+use thoth_api::model::work_date::{NewWorkDate, PatchWorkDate, WorkDate};
+use thoth_api::model::work_featured_video::{
+    NewWorkFeaturedVideo, PatchWorkFeaturedVideo, WorkFeaturedVideo,
+};
+use thoth_api::model::work_relation::{NewWorkRelation, PatchWorkRelation, WorkRelation};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+use crate::selections;
+use crate::ThothClient;
+
+/// Generate a method per mutation, each sending the mutation with its arguments as variables
+/// and deserialising the selected fields of the result.
+macro_rules! mutations {
+    ($(
+        $(#[$meta:meta])*
+        $method:ident => $field:literal(
+            $($arg:ident: $ty:ty = $name:literal: $graphql_type:literal),+
+        ) -> $output:ty { $selection:path }
+    )*) => {
+        /// GraphQL name and arguments of each generated mutation
+        #[cfg(test)]
+        const MUTATIONS: &[(&str, &[(&str, &str)])] = &[$(($field, &[$(($name, $graphql_type)),+])),*];
+
+        impl ThothClient {
+            $(
+                $(#[$meta])*
+                ///
+                /// # Errors
+                ///
+                /// This method fails if the request could not be sent, or if the API rejected
+                /// the mutation, in which case the [`ThothError`](thoth_errors::ThothError)
+                /// raised by the API is returned
+                pub async fn $method(&self, $($arg: $ty),+) -> ThothResult<$output> {
+                    let query = format!(
+                        "mutation({}) {{ {}({}) {{ {} }} }}",
+                        [$(concat!("$", $name, ": ", $graphql_type)),+].join(", "),
+                        $field,
+                        [$(concat!($name, ": $", $name)),+].join(", "),
+                        $selection,
+                    );
+                    let mut variables = serde_json::Map::new();
+                    $(variables.insert($name.to_string(), serde_json::to_value($arg)?);)+
+                    self.execute($field, query, variables).await
+                }
+            )*
+        }
+    };
+}
+
+mutations! {
+    /// Create a new work with the specified values
+    create_work => "createWork"(data: NewWork = "data": "NewWork!") -> Work { selections::WORK }
+    /// Create a new publisher with the specified values
+    create_publisher => "createPublisher"(
+        data: NewPublisher = "data": "NewPublisher!"
+    ) -> Publisher { selections::PUBLISHER }
+    /// Create a new imprint with the specified values
+    create_imprint => "createImprint"(
+        data: NewImprint = "data": "NewImprint!"
+    ) -> Imprint { selections::IMPRINT }
+    /// Create a new contributor with the specified values
+    create_contributor => "createContributor"(
+        data: NewContributor = "data": "NewContributor!"
+    ) -> Contributor { selections::CONTRIBUTOR }
+    /// Create a new contribution with the specified values
+    create_contribution => "createContribution"(
+        data: NewContribution = "data": "NewContribution!"
+    ) -> Contribution { selections::CONTRIBUTION }
+    /// Create a new publication with the specified values
+    create_publication => "createPublication"(
+        data: NewPublication = "data": "NewPublication!"
+    ) -> Publication { selections::PUBLICATION }
+    /// Create a new series with the specified values
+    create_series => "createSeries"(
+        data: NewSeries = "data": "NewSeries!"
+    ) -> Series { selections::SERIES }
+    /// Create a new issue with the specified values
+    create_issue => "createIssue"(
+        data: NewIssue = "data": "NewIssue!"
+    ) -> Issue { selections::ISSUE }
+    /// Create a new language with the specified values
+    create_language => "createLanguage"(
+        data: NewLanguage = "data": "NewLanguage!"
+    ) -> Language { selections::LANGUAGE }
+    /// Create a new title with the specified values
+    create_title => "createTitle"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: NewTitle = "data": "NewTitle!"
+    ) -> Title { selections::TITLE }
+    /// Create a new abstract with the specified values
+    create_abstract => "createAbstract"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: NewAbstract = "data": "NewAbstract!"
+    ) -> Abstract { selections::ABSTRACT }
+    /// Create a new biography with the specified values
+    create_biography => "createBiography"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: NewBiography = "data": "NewBiography!"
+    ) -> Biography { selections::BIOGRAPHY }
+    /// Create a new institution with the specified values
+    create_institution => "createInstitution"(
+        data: NewInstitution = "data": "NewInstitution!"
+    ) -> Institution { selections::INSTITUTION }
+    /// Create a new funding with the specified values
+    create_funding => "createFunding"(
+        data: NewFunding = "data": "NewFunding!"
+    ) -> Funding { selections::FUNDING }
+    /// Create a new location with the specified values
+    create_location => "createLocation"(
+        data: NewLocation = "data": "NewLocation!"
+    ) -> Location { selections::LOCATION }
+    /// Create a new price with the specified values
+    create_price => "createPrice"(
+        data: NewPrice = "data": "NewPrice!"
+    ) -> Price { selections::PRICE }
+    /// Create a new product form detail with the specified values
+    create_product_form_detail => "createProductFormDetail"(
+        data: NewProductFormDetail = "data": "NewProductFormDetail!"
+    ) -> ProductFormDetail { selections::PRODUCT_FORM_DETAIL }
+    /// Create new sales rights with the specified values
+    create_sales_rights => "createSalesRights"(
+        data: NewSalesRights = "data": "NewSalesRights!"
+    ) -> SalesRights { selections::SALES_RIGHTS }
+    /// Create a new subject with the specified values
+    create_subject => "createSubject"(
+        data: NewSubject = "data": "NewSubject!"
+    ) -> Subject { selections::SUBJECT }
+    /// Create a new affiliation with the specified values
+    create_affiliation => "createAffiliation"(
+        data: NewAffiliation = "data": "NewAffiliation!"
+    ) -> Affiliation { selections::AFFILIATION }
+    /// Create a new audience with the specified values
+    create_audience => "createAudience"(
+        data: NewAudience = "data": "NewAudience!"
+    ) -> Audience { selections::AUDIENCE }
+    /// Create a new work date with the specified values
+    create_work_date => "createWorkDate"(
+        data: NewWorkDate = "data": "NewWorkDate!"
+    ) -> WorkDate { selections::WORK_DATE }
+    /// Create a new name variant with the specified values
+    create_name_variant => "createNameVariant"(
+        data: NewNameVariant = "data": "NewNameVariant!"
+    ) -> NameVariant { selections::NAME_VARIANT }
+    /// Create a new CRediT role with the specified values
+    create_credit_role => "createCreditRole"(
+        data: NewCreditRole = "data": "NewCreditRole!"
+    ) -> CreditRole { selections::CREDIT_ROLE }
+    /// Create a new work relation with the specified values
+    create_work_relation => "createWorkRelation"(
+        data: NewWorkRelation = "data": "NewWorkRelation!"
+    ) -> WorkRelation { selections::WORK_RELATION }
+    /// Create a new reference with the specified values
+    create_reference => "createReference"(
+        data: NewReference = "data": "NewReference!"
+    ) -> Reference { selections::REFERENCE }
+    /// Create a new additional resource with the specified values
+    create_additional_resource => "createAdditionalResource"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: NewAdditionalResource = "data": "NewAdditionalResource!"
+    ) -> AdditionalResource { selections::ADDITIONAL_RESOURCE }
+    /// Create a new award with the specified values
+    create_award => "createAward"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: NewAward = "data": "NewAward!"
+    ) -> Award { selections::AWARD }
+    /// Create a new endorsement with the specified values
+    create_endorsement => "createEndorsement"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: NewEndorsement = "data": "NewEndorsement!"
+    ) -> Endorsement { selections::ENDORSEMENT }
+    /// Create a new book review with the specified values
+    create_book_review => "createBookReview"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: NewBookReview = "data": "NewBookReview!"
+    ) -> BookReview { selections::BOOK_REVIEW }
+    /// Create a new featured video with the specified values
+    create_work_featured_video => "createWorkFeaturedVideo"(
+        data: NewWorkFeaturedVideo = "data": "NewWorkFeaturedVideo!"
+    ) -> WorkFeaturedVideo { selections::WORK_FEATURED_VIDEO }
+    /// Create a new contact with the specified values
+    create_contact => "createContact"(
+        data: NewContact = "data": "NewContact!"
+    ) -> Contact { selections::CONTACT }
+    /// Update an existing work with the specified values
+    update_work => "updateWork"(data: PatchWork = "data": "PatchWork!") -> Work { selections::WORK }
+    /// Update an existing publisher with the specified values
+    update_publisher => "updatePublisher"(
+        data: PatchPublisher = "data": "PatchPublisher!"
+    ) -> Publisher { selections::PUBLISHER }
+    /// Update an existing imprint with the specified values
+    update_imprint => "updateImprint"(
+        data: PatchImprint = "data": "PatchImprint!"
+    ) -> Imprint { selections::IMPRINT }
+    /// Update an existing contributor with the specified values
+    update_contributor => "updateContributor"(
+        data: PatchContributor = "data": "PatchContributor!"
+    ) -> Contributor { selections::CONTRIBUTOR }
+    /// Update an existing contribution with the specified values
+    update_contribution => "updateContribution"(
+        data: PatchContribution = "data": "PatchContribution!"
+    ) -> Contribution { selections::CONTRIBUTION }
+    /// Update an existing publication with the specified values
+    update_publication => "updatePublication"(
+        data: PatchPublication = "data": "PatchPublication!"
+    ) -> Publication { selections::PUBLICATION }
+    /// Update an existing series with the specified values
+    update_series => "updateSeries"(
+        data: PatchSeries = "data": "PatchSeries!"
+    ) -> Series { selections::SERIES }
+    /// Update an existing issue with the specified values
+    update_issue => "updateIssue"(
+        data: PatchIssue = "data": "PatchIssue!"
+    ) -> Issue { selections::ISSUE }
+    /// Update an existing language with the specified values
+    update_language => "updateLanguage"(
+        data: PatchLanguage = "data": "PatchLanguage!"
+    ) -> Language { selections::LANGUAGE }
+    /// Update an existing institution with the specified values
+    update_institution => "updateInstitution"(
+        data: PatchInstitution = "data": "PatchInstitution!"
+    ) -> Institution { selections::INSTITUTION }
+    /// Update an existing funding with the specified values
+    update_funding => "updateFunding"(
+        data: PatchFunding = "data": "PatchFunding!"
+    ) -> Funding { selections::FUNDING }
+    /// Update an existing location with the specified values
+    update_location => "updateLocation"(
+        data: PatchLocation = "data": "PatchLocation!"
+    ) -> Location { selections::LOCATION }
+    /// Update an existing price with the specified values
+    update_price => "updatePrice"(
+        data: PatchPrice = "data": "PatchPrice!"
+    ) -> Price { selections::PRICE }
+    /// Update an existing product form detail with the specified values
+    update_product_form_detail => "updateProductFormDetail"(
+        data: PatchProductFormDetail = "data": "PatchProductFormDetail!"
+    ) -> ProductFormDetail { selections::PRODUCT_FORM_DETAIL }
+    /// Update existing sales rights with the specified values
+    update_sales_rights => "updateSalesRights"(
+        data: PatchSalesRights = "data": "PatchSalesRights!"
+    ) -> SalesRights { selections::SALES_RIGHTS }
+    /// Update an existing subject with the specified values
+    update_subject => "updateSubject"(
+        data: PatchSubject = "data": "PatchSubject!"
+    ) -> Subject { selections::SUBJECT }
+    /// Update an existing affiliation with the specified values
+    update_affiliation => "updateAffiliation"(
+        data: PatchAffiliation = "data": "PatchAffiliation!"
+    ) -> Affiliation { selections::AFFILIATION }
+    /// Update an existing audience with the specified values
+    update_audience => "updateAudience"(
+        data: PatchAudience = "data": "PatchAudience!"
+    ) -> Audience { selections::AUDIENCE }
+    /// Update an existing work date with the specified values
+    update_work_date => "updateWorkDate"(
+        data: PatchWorkDate = "data": "PatchWorkDate!"
+    ) -> WorkDate { selections::WORK_DATE }
+    /// Update an existing name variant with the specified values
+    update_name_variant => "updateNameVariant"(
+        data: PatchNameVariant = "data": "PatchNameVariant!"
+    ) -> NameVariant { selections::NAME_VARIANT }
+    /// Update an existing CRediT role with the specified values
+    update_credit_role => "updateCreditRole"(
+        data: PatchCreditRole = "data": "PatchCreditRole!"
+    ) -> CreditRole { selections::CREDIT_ROLE }
+    /// Update an existing work relation with the specified values
+    update_work_relation => "updateWorkRelation"(
+        data: PatchWorkRelation = "data": "PatchWorkRelation!"
+    ) -> WorkRelation { selections::WORK_RELATION }
+    /// Update an existing reference with the specified values
+    update_reference => "updateReference"(
+        data: PatchReference = "data": "PatchReference!"
+    ) -> Reference { selections::REFERENCE }
+    /// Update an existing additional resource with the specified values
+    update_additional_resource => "updateAdditionalResource"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: PatchAdditionalResource = "data": "PatchAdditionalResource!"
+    ) -> AdditionalResource { selections::ADDITIONAL_RESOURCE }
+    /// Update an existing award with the specified values
+    update_award => "updateAward"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: PatchAward = "data": "PatchAward!"
+    ) -> Award { selections::AWARD }
+    /// Update an existing endorsement with the specified values
+    update_endorsement => "updateEndorsement"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: PatchEndorsement = "data": "PatchEndorsement!"
+    ) -> Endorsement { selections::ENDORSEMENT }
+    /// Update an existing book review with the specified values
+    update_book_review => "updateBookReview"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: PatchBookReview = "data": "PatchBookReview!"
+    ) -> BookReview { selections::BOOK_REVIEW }
+    /// Update an existing featured video with the specified values
+    update_work_featured_video => "updateWorkFeaturedVideo"(
+        data: PatchWorkFeaturedVideo = "data": "PatchWorkFeaturedVideo!"
+    ) -> WorkFeaturedVideo { selections::WORK_FEATURED_VIDEO }
+    /// Update an existing contact with the specified values
+    update_contact => "updateContact"(
+        data: PatchContact = "data": "PatchContact!"
+    ) -> Contact { selections::CONTACT }
+    /// Update an existing title with the specified values
+    update_title => "updateTitle"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: PatchTitle = "data": "PatchTitle!"
+    ) -> Title { selections::TITLE }
+    /// Update an existing abstract with the specified values
+    update_abstract => "updateAbstract"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: PatchAbstract = "data": "PatchAbstract!"
+    ) -> Abstract { selections::ABSTRACT }
+    /// Update an existing biography with the specified values
+    update_biography => "updateBiography"(
+        markup_format: Option<MarkupFormat> = "markupFormat": "MarkupFormat",
+        data: PatchBiography = "data": "PatchBiography!"
+    ) -> Biography { selections::BIOGRAPHY }
+    /// Delete a single work using its ID
+    delete_work => "deleteWork"(work_id: Uuid = "workId": "Uuid!") -> Work { selections::WORK }
+    /// Delete a single publisher using its ID
+    delete_publisher => "deletePublisher"(
+        publisher_id: Uuid = "publisherId": "Uuid!"
+    ) -> Publisher { selections::PUBLISHER }
+    /// Delete a single imprint using its ID
+    delete_imprint => "deleteImprint"(
+        imprint_id: Uuid = "imprintId": "Uuid!"
+    ) -> Imprint { selections::IMPRINT }
+    /// Delete a single contributor using its ID
+    delete_contributor => "deleteContributor"(
+        contributor_id: Uuid = "contributorId": "Uuid!"
+    ) -> Contributor { selections::CONTRIBUTOR }
+    /// Delete a single contribution using its ID
+    delete_contribution => "deleteContribution"(
+        contribution_id: Uuid = "contributionId": "Uuid!"
+    ) -> Contribution { selections::CONTRIBUTION }
+    /// Delete a single publication using its ID
+    delete_publication => "deletePublication"(
+        publication_id: Uuid = "publicationId": "Uuid!"
+    ) -> Publication { selections::PUBLICATION }
+    /// Delete a single series using its ID
+    delete_series => "deleteSeries"(
+        series_id: Uuid = "seriesId": "Uuid!"
+    ) -> Series { selections::SERIES }
+    /// Delete a single issue using its ID
+    delete_issue => "deleteIssue"(
+        issue_id: Uuid = "issueId": "Uuid!"
+    ) -> Issue { selections::ISSUE }
+    /// Delete a single language using its ID
+    delete_language => "deleteLanguage"(
+        language_id: Uuid = "languageId": "Uuid!"
+    ) -> Language { selections::LANGUAGE }
+    /// Delete a single title using its ID
+    delete_title => "deleteTitle"(
+        title_id: Uuid = "titleId": "Uuid!"
+    ) -> Title { selections::TITLE }
+    /// Delete a single institution using its ID
+    delete_institution => "deleteInstitution"(
+        institution_id: Uuid = "institutionId": "Uuid!"
+    ) -> Institution { selections::INSTITUTION }
+    /// Delete a single funding using its ID
+    delete_funding => "deleteFunding"(
+        funding_id: Uuid = "fundingId": "Uuid!"
+    ) -> Funding { selections::FUNDING }
+    /// Delete a single location using its ID
+    delete_location => "deleteLocation"(
+        location_id: Uuid = "locationId": "Uuid!"
+    ) -> Location { selections::LOCATION }
+    /// Delete a single price using its ID
+    delete_price => "deletePrice"(
+        price_id: Uuid = "priceId": "Uuid!"
+    ) -> Price { selections::PRICE }
+    /// Delete a single product form detail using its ID
+    delete_product_form_detail => "deleteProductFormDetail"(
+        product_form_detail_id: Uuid = "productFormDetailId": "Uuid!"
+    ) -> ProductFormDetail { selections::PRODUCT_FORM_DETAIL }
+    /// Delete a single sales rights record using its ID
+    delete_sales_rights => "deleteSalesRights"(
+        sales_rights_id: Uuid = "salesRightsId": "Uuid!"
+    ) -> SalesRights { selections::SALES_RIGHTS }
+    /// Delete a single subject using its ID
+    delete_subject => "deleteSubject"(
+        subject_id: Uuid = "subjectId": "Uuid!"
+    ) -> Subject { selections::SUBJECT }
+    /// Delete a single affiliation using its ID
+    delete_affiliation => "deleteAffiliation"(
+        affiliation_id: Uuid = "affiliationId": "Uuid!"
+    ) -> Affiliation { selections::AFFILIATION }
+    /// Delete a single audience using its ID
+    delete_audience => "deleteAudience"(
+        audience_id: Uuid = "audienceId": "Uuid!"
+    ) -> Audience { selections::AUDIENCE }
+    /// Delete a single work date using its ID
+    delete_work_date => "deleteWorkDate"(
+        work_date_id: Uuid = "workDateId": "Uuid!"
+    ) -> WorkDate { selections::WORK_DATE }
+    /// Delete a single name variant using its ID
+    delete_name_variant => "deleteNameVariant"(
+        name_variant_id: Uuid = "nameVariantId": "Uuid!"
+    ) -> NameVariant { selections::NAME_VARIANT }
+    /// Delete a single CRediT role using its ID
+    delete_credit_role => "deleteCreditRole"(
+        credit_role_id: Uuid = "creditRoleId": "Uuid!"
+    ) -> CreditRole { selections::CREDIT_ROLE }
+    /// Delete a single work relation using its ID
+    delete_work_relation => "deleteWorkRelation"(
+        work_relation_id: Uuid = "workRelationId": "Uuid!"
+    ) -> WorkRelation { selections::WORK_RELATION }
+    /// Delete a single reference using its ID
+    delete_reference => "deleteReference"(
+        reference_id: Uuid = "referenceId": "Uuid!"
+    ) -> Reference { selections::REFERENCE }
+    /// Delete a single additional resource using its ID
+    delete_additional_resource => "deleteAdditionalResource"(
+        additional_resource_id: Uuid = "additionalResourceId": "Uuid!"
+    ) -> AdditionalResource { selections::ADDITIONAL_RESOURCE }
+    /// Delete a single award using its ID
+    delete_award => "deleteAward"(
+        award_id: Uuid = "awardId": "Uuid!"
+    ) -> Award { selections::AWARD }
+    /// Delete a single endorsement using its ID
+    delete_endorsement => "deleteEndorsement"(
+        endorsement_id: Uuid = "endorsementId": "Uuid!"
+    ) -> Endorsement { selections::ENDORSEMENT }
+    /// Delete a single book review using its ID
+    delete_book_review => "deleteBookReview"(
+        book_review_id: Uuid = "bookReviewId": "Uuid!"
+    ) -> BookReview { selections::BOOK_REVIEW }
+    /// Delete a single featured video using its ID
+    delete_work_featured_video => "deleteWorkFeaturedVideo"(
+        work_featured_video_id: Uuid = "workFeaturedVideoId": "Uuid!"
+    ) -> WorkFeaturedVideo { selections::WORK_FEATURED_VIDEO }
+    /// Delete a single abstract using its ID
+    delete_abstract => "deleteAbstract"(
+        abstract_id: Uuid = "abstractId": "Uuid!"
+    ) -> Abstract { selections::ABSTRACT }
+    /// Delete a single biography using its ID
+    delete_biography => "deleteBiography"(
+        biography_id: Uuid = "biographyId": "Uuid!"
+    ) -> Biography { selections::BIOGRAPHY }
+    /// Change the ordering of an affiliation within a contribution
+    move_affiliation => "moveAffiliation"(
+        affiliation_id: Uuid = "affiliationId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> Affiliation { selections::AFFILIATION }
+    /// Change the ordering of a contribution within a work
+    move_contribution => "moveContribution"(
+        contribution_id: Uuid = "contributionId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> Contribution { selections::CONTRIBUTION }
+    /// Change the ordering of an issue within a series
+    move_issue => "moveIssue"(
+        issue_id: Uuid = "issueId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> Issue { selections::ISSUE }
+    /// Change the ordering of a reference within a work
+    move_reference => "moveReference"(
+        reference_id: Uuid = "referenceId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> Reference { selections::REFERENCE }
+    /// Change the ordering of an additional resource within a work
+    move_additional_resource => "moveAdditionalResource"(
+        additional_resource_id: Uuid = "additionalResourceId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> AdditionalResource { selections::ADDITIONAL_RESOURCE }
+    /// Change the ordering of an award within a work
+    move_award => "moveAward"(
+        award_id: Uuid = "awardId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> Award { selections::AWARD }
+    /// Change the ordering of an endorsement within a work
+    move_endorsement => "moveEndorsement"(
+        endorsement_id: Uuid = "endorsementId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> Endorsement { selections::ENDORSEMENT }
+    /// Change the ordering of a book review within a work
+    move_book_review => "moveBookReview"(
+        book_review_id: Uuid = "bookReviewId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> BookReview { selections::BOOK_REVIEW }
+    /// Change the ordering of a subject within a work
+    move_subject => "moveSubject"(
+        subject_id: Uuid = "subjectId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> Subject { selections::SUBJECT }
+    /// Change the ordering of a work relation within a work
+    move_work_relation => "moveWorkRelation"(
+        work_relation_id: Uuid = "workRelationId": "Uuid!",
+        new_ordinal: i32 = "newOrdinal": "Int!"
+    ) -> WorkRelation { selections::WORK_RELATION }
+    /// Start uploading a publication file (e.g. PDF, EPUB, XML) for a given publication. Returns an
+    /// upload session ID, a presigned S3 PUT URL, and required PUT headers.
+    init_publication_file_upload => "initPublicationFileUpload"(
+        data: NewPublicationFileUpload = "data": "NewPublicationFileUpload!"
+    ) -> FileUploadResponse { selections::FILE_UPLOAD_RESPONSE }
+    /// Start uploading a front cover image for a given work. Returns an upload session ID, a
+    /// presigned S3 PUT URL, and required PUT headers.
+    init_frontcover_file_upload => "initFrontcoverFileUpload"(
+        data: NewFrontcoverFileUpload = "data": "NewFrontcoverFileUpload!"
+    ) -> FileUploadResponse { selections::FILE_UPLOAD_RESPONSE }
+    /// Start uploading a file for an additional resource. Supported resource types include AUDIO,
+    /// VIDEO, IMAGE, DOCUMENT, DATASET, and SPREADSHEET.
+    init_additional_resource_file_upload => "initAdditionalResourceFileUpload"(
+        data: NewAdditionalResourceFileUpload = "data": "NewAdditionalResourceFileUpload!"
+    ) -> FileUploadResponse { selections::FILE_UPLOAD_RESPONSE }
+    /// Start uploading a hosted featured video for a work. The uploaded file is promoted to a DOI-
+    /// scoped resource path.
+    init_work_featured_video_file_upload => "initWorkFeaturedVideoFileUpload"(
+        data: NewWorkFeaturedVideoFileUpload = "data": "NewWorkFeaturedVideoFileUpload!"
+    ) -> FileUploadResponse { selections::FILE_UPLOAD_RESPONSE }
+    /// Complete a file upload, validate it, and promote it to its final DOI-based location.
+    complete_file_upload => "completeFileUpload"(
+        data: CompleteFileUpload = "data": "CompleteFileUpload!"
+    ) -> File { selections::FILE }
+    /// Make an earlier version of a publication file the current one. The restored content is
+    /// recorded as a new version, so no history is lost.
+    restore_file_version => "restoreFileVersion"(
+        file_version_id: Uuid = "fileVersionId": "Uuid!"
+    ) -> FileVersion { selections::FILE_VERSION }
+    /// Delete a single contact using its ID
+    delete_contact => "deleteContact"(
+        contact_id: Uuid = "contactId": "Uuid!"
+    ) -> Contact { selections::CONTACT }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use juniper::meta::{MetaType, ObjectMeta};
+    use thoth_api::graphql::create_schema;
+
+    type Signature = (String, Vec<(String, String)>);
+
+    #[test]
+    fn test_mutations_match_schema() {
+        let schema = create_schema();
+        let Some(MetaType::Object(ObjectMeta { fields, .. })) =
+            schema.schema.concrete_mutation_type()
+        else {
+            panic!("Schema does not define a mutation type");
+        };
+        let mut expected: Vec<Signature> = fields
+            .iter()
+            .filter(|field| !field.is_builtin())
+            .map(|field| {
+                let arguments = field
+                    .arguments
+                    .iter()
+                    .flatten()
+                    .map(|argument| (argument.name.clone(), argument.arg_type.to_string()))
+                    .collect();
+                (field.name.to_string(), arguments)
+            })
+            .collect();
+        let mut generated: Vec<Signature> = MUTATIONS
+            .iter()
+            .map(|(field, arguments)| {
+                let arguments = arguments
+                    .iter()
+                    .map(|(name, graphql_type)| (name.to_string(), graphql_type.to_string()))
+                    .collect();
+                (field.to_string(), arguments)
+            })
+            .collect();
+        expected.sort();
+        generated.sort();
+        assert_eq!(generated, expected);
+    }
+}
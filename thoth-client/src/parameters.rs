@@ -14,6 +14,10 @@ pub struct QueryParameters {
     with_references: bool,
     with_abstracts: bool,
     with_titles: bool,
+    with_awards: bool,
+    with_endorsements: bool,
+    with_book_reviews: bool,
+    with_resources: bool,
 }
 
 /// An intermediate struct to parse QueryParameters into work_query::Variables
@@ -82,6 +86,10 @@ impl QueryParameters {
             .with_fundings()
             .with_relations()
             .with_references()
+            .with_awards()
+            .with_endorsements()
+            .with_book_reviews()
+            .with_resources()
     }
 
     pub fn with_issues(mut self) -> Self {
@@ -99,6 +107,7 @@ impl QueryParameters {
         self
     }
 
+    /// Include subjects and audiences, which together classify the work's content
     pub fn with_subjects(mut self) -> Self {
         self.with_subjects = true;
         self
@@ -119,6 +128,27 @@ impl QueryParameters {
         self
     }
 
+    pub fn with_awards(mut self) -> Self {
+        self.with_awards = true;
+        self
+    }
+
+    pub fn with_endorsements(mut self) -> Self {
+        self.with_endorsements = true;
+        self
+    }
+
+    pub fn with_book_reviews(mut self) -> Self {
+        self.with_book_reviews = true;
+        self
+    }
+
+    /// Include additional resources and the featured video, with their hosted files
+    pub fn with_resources(mut self) -> Self {
+        self.with_resources = true;
+        self
+    }
+
     pub fn with_all_abstracts(mut self) -> Self {
         self.with_abstracts = true;
         self
@@ -164,6 +194,26 @@ impl QueryParameters {
         self
     }
 
+    pub fn without_awards(mut self) -> Self {
+        self.with_awards = false;
+        self
+    }
+
+    pub fn without_endorsements(mut self) -> Self {
+        self.with_endorsements = false;
+        self
+    }
+
+    pub fn without_book_reviews(mut self) -> Self {
+        self.with_book_reviews = false;
+        self
+    }
+
+    pub fn without_resources(mut self) -> Self {
+        self.with_resources = false;
+        self
+    }
+
     pub fn with_canonical_abstracts_only(mut self) -> Self {
         self.with_abstracts = false;
         self
@@ -229,6 +279,27 @@ impl From<WorkQueryVariables> for work_query::Variables {
             } else {
                 FILTER_INCLUDE_CANONICAL
             },
+            awards_limit: if v.parameters.with_awards {
+                FILTER_INCLUDE_ALL
+            } else {
+                FILTER_INCLUDE_NONE
+            },
+            endorsements_limit: if v.parameters.with_endorsements {
+                FILTER_INCLUDE_ALL
+            } else {
+                FILTER_INCLUDE_NONE
+            },
+            book_reviews_limit: if v.parameters.with_book_reviews {
+                FILTER_INCLUDE_ALL
+            } else {
+                FILTER_INCLUDE_NONE
+            },
+            resources_limit: if v.parameters.with_resources {
+                FILTER_INCLUDE_ALL
+            } else {
+                FILTER_INCLUDE_NONE
+            },
+            with_resources: v.parameters.with_resources,
         }
     }
 }
@@ -284,6 +355,27 @@ impl From<WorksQueryVariables> for works_query::Variables {
             } else {
                 FILTER_INCLUDE_CANONICAL
             },
+            awards_limit: if v.parameters.with_awards {
+                FILTER_INCLUDE_ALL
+            } else {
+                FILTER_INCLUDE_NONE
+            },
+            endorsements_limit: if v.parameters.with_endorsements {
+                FILTER_INCLUDE_ALL
+            } else {
+                FILTER_INCLUDE_NONE
+            },
+            book_reviews_limit: if v.parameters.with_book_reviews {
+                FILTER_INCLUDE_ALL
+            } else {
+                FILTER_INCLUDE_NONE
+            },
+            resources_limit: if v.parameters.with_resources {
+                FILTER_INCLUDE_ALL
+            } else {
+                FILTER_INCLUDE_NONE
+            },
+            with_resources: v.parameters.with_resources,
         }
     }
 }
@@ -305,6 +397,10 @@ mod tests {
             with_references: false,
             with_abstracts: false,
             with_titles: false,
+            with_awards: false,
+            with_endorsements: false,
+            with_book_reviews: false,
+            with_resources: false,
         };
         assert_eq!(to_test, QueryParameters::default());
         assert_eq!(to_test, QueryParameters::new())
@@ -324,6 +420,10 @@ mod tests {
                 with_references: true,
                 with_abstracts: true,
                 with_titles: true,
+                with_awards: true,
+                with_endorsements: true,
+                with_book_reviews: true,
+                with_resources: true,
             },
         );
         assert_eq!(
@@ -336,6 +436,10 @@ mod tests {
                 .without_fundings()
                 .without_relations()
                 .without_references()
+                .without_awards()
+                .without_endorsements()
+                .without_book_reviews()
+                .without_resources()
                 .with_canonical_abstracts_only()
                 .with_canonical_title_only(),
             QueryParameters {
@@ -348,6 +452,10 @@ mod tests {
                 with_references: false,
                 with_abstracts: false,
                 with_titles: false,
+                with_awards: false,
+                with_endorsements: false,
+                with_book_reviews: false,
+                with_resources: false,
             },
         );
         assert_eq!(
@@ -369,6 +477,10 @@ mod tests {
                 with_references: true,
                 with_abstracts: false,
                 with_titles: false,
+                with_awards: false,
+                with_endorsements: false,
+                with_book_reviews: false,
+                with_resources: false,
             },
         );
     }
@@ -392,6 +504,11 @@ mod tests {
                 relations_limit: FILTER_INCLUDE_ALL,
                 references_limit: FILTER_INCLUDE_ALL,
                 titles_limit: FILTER_INCLUDE_ALL,
+                awards_limit: FILTER_INCLUDE_ALL,
+                endorsements_limit: FILTER_INCLUDE_ALL,
+                book_reviews_limit: FILTER_INCLUDE_ALL,
+                resources_limit: FILTER_INCLUDE_ALL,
+                with_resources: true,
             }
         );
         parameters = QueryParameters::new();
@@ -409,6 +526,11 @@ mod tests {
                 relations_limit: FILTER_INCLUDE_NONE,
                 references_limit: FILTER_INCLUDE_NONE,
                 titles_limit: FILTER_INCLUDE_CANONICAL,
+                awards_limit: FILTER_INCLUDE_NONE,
+                endorsements_limit: FILTER_INCLUDE_NONE,
+                book_reviews_limit: FILTER_INCLUDE_NONE,
+                resources_limit: FILTER_INCLUDE_NONE,
+                with_resources: false,
             }
         );
         parameters = QueryParameters::new().with_all().without_relations();
@@ -426,6 +548,11 @@ mod tests {
                 relations_limit: FILTER_INCLUDE_NONE,
                 references_limit: FILTER_INCLUDE_ALL,
                 titles_limit: FILTER_INCLUDE_ALL,
+                awards_limit: FILTER_INCLUDE_ALL,
+                endorsements_limit: FILTER_INCLUDE_ALL,
+                book_reviews_limit: FILTER_INCLUDE_ALL,
+                resources_limit: FILTER_INCLUDE_ALL,
+                with_resources: true,
             }
         );
     }
@@ -452,6 +579,11 @@ mod tests {
                 relations_limit: FILTER_INCLUDE_ALL,
                 references_limit: FILTER_INCLUDE_ALL,
                 titles_limit: FILTER_INCLUDE_ALL,
+                awards_limit: FILTER_INCLUDE_ALL,
+                endorsements_limit: FILTER_INCLUDE_ALL,
+                book_reviews_limit: FILTER_INCLUDE_ALL,
+                resources_limit: FILTER_INCLUDE_ALL,
+                with_resources: true,
             }
         );
         parameters = QueryParameters::new();
@@ -471,6 +603,11 @@ mod tests {
                 relations_limit: FILTER_INCLUDE_NONE,
                 references_limit: FILTER_INCLUDE_NONE,
                 titles_limit: FILTER_INCLUDE_CANONICAL,
+                awards_limit: FILTER_INCLUDE_NONE,
+                endorsements_limit: FILTER_INCLUDE_NONE,
+                book_reviews_limit: FILTER_INCLUDE_NONE,
+                resources_limit: FILTER_INCLUDE_NONE,
+                with_resources: false,
             }
         );
         parameters = QueryParameters::new()
@@ -493,6 +630,11 @@ mod tests {
                 relations_limit: FILTER_INCLUDE_NONE,
                 references_limit: FILTER_INCLUDE_NONE,
                 titles_limit: FILTER_INCLUDE_ALL,
+                awards_limit: FILTER_INCLUDE_ALL,
+                endorsements_limit: FILTER_INCLUDE_ALL,
+                book_reviews_limit: FILTER_INCLUDE_ALL,
+                resources_limit: FILTER_INCLUDE_ALL,
+                with_resources: true,
             }
         );
     }
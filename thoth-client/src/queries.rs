@@ -2,10 +2,12 @@ use std::fmt;
 
 use chrono::naive::NaiveDate;
 use graphql_client::GraphQLQuery;
-use thoth_api::model::contribution::ContributionType;
+use thoth_api::model::contribution::{ContributionRoleMapping, ContributionType};
+use thoth_api::model::country::CountryCode;
 use thoth_api::model::language::LanguageRelation;
 use thoth_api::model::locale::LocaleCode;
-use thoth_api::model::publication::PublicationType;
+use thoth_api::model::product_form_detail::ProductFormDetailType;
+use thoth_api::model::publication::{AccessStatus, PublicationType};
 use thoth_api::model::Doi;
 use thoth_api::model::Isbn;
 use thoth_api::model::Orcid;
@@ -138,6 +140,13 @@ impl From<work_query::ContributionType> for ContributionType {
     }
 }
 
+impl work_query::ContributionType {
+    /// The terms used for this contribution type by ONIX, MARC 21 and Crossref
+    pub fn role_mapping(&self) -> &'static ContributionRoleMapping {
+        ContributionType::from(self.clone()).role_mapping()
+    }
+}
+
 impl From<work_query::PublicationType> for PublicationType {
     fn from(value: crate::PublicationType) -> Self {
         match value {
@@ -158,6 +167,17 @@ impl From<work_query::PublicationType> for PublicationType {
     }
 }
 
+impl From<work_query::ProductFormDetailType> for ProductFormDetailType {
+    fn from(value: work_query::ProductFormDetailType) -> Self {
+        match value {
+            work_query::ProductFormDetailType::FORM_DETAIL => ProductFormDetailType::FormDetail,
+            work_query::ProductFormDetailType::FORM_FEATURE => ProductFormDetailType::FormFeature,
+            work_query::ProductFormDetailType::PACKAGING => ProductFormDetailType::Packaging,
+            work_query::ProductFormDetailType::Other(_) => unreachable!(),
+        }
+    }
+}
+
 impl From<work_query::LanguageRelation> for LanguageRelation {
     fn from(value: crate::LanguageRelation) -> Self {
         match value {
@@ -781,3 +801,273 @@ impl From<work_query::LocaleCode> for LocaleCode {
         }
     }
 }
+
+// Convert generated GraphQL AccessStatus into API AccessStatus
+impl From<work_query::AccessStatus> for AccessStatus {
+    fn from(value: work_query::AccessStatus) -> Self {
+        match value {
+            work_query::AccessStatus::OPEN => AccessStatus::Open,
+            work_query::AccessStatus::EMBARGOED => AccessStatus::Embargoed,
+            work_query::AccessStatus::RESTRICTED => AccessStatus::Restricted,
+            work_query::AccessStatus::Other(_) => unreachable!(),
+        }
+    }
+}
+
+// Convert generated GraphQL CountryCode into API CountryCode
+impl From<work_query::CountryCode> for CountryCode {
+    fn from(value: work_query::CountryCode) -> Self {
+        match value {
+            work_query::CountryCode::AFG => CountryCode::Afg,
+            work_query::CountryCode::ALA => CountryCode::Ala,
+            work_query::CountryCode::ALB => CountryCode::Alb,
+            work_query::CountryCode::DZA => CountryCode::Dza,
+            work_query::CountryCode::ASM => CountryCode::Asm,
+            work_query::CountryCode::AND => CountryCode::And,
+            work_query::CountryCode::AGO => CountryCode::Ago,
+            work_query::CountryCode::AIA => CountryCode::Aia,
+            work_query::CountryCode::ATA => CountryCode::Ata,
+            work_query::CountryCode::ATG => CountryCode::Atg,
+            work_query::CountryCode::ARG => CountryCode::Arg,
+            work_query::CountryCode::ARM => CountryCode::Arm,
+            work_query::CountryCode::ABW => CountryCode::Abw,
+            work_query::CountryCode::AUS => CountryCode::Aus,
+            work_query::CountryCode::AUT => CountryCode::Aut,
+            work_query::CountryCode::AZE => CountryCode::Aze,
+            work_query::CountryCode::BHS => CountryCode::Bhs,
+            work_query::CountryCode::BHR => CountryCode::Bhr,
+            work_query::CountryCode::BGD => CountryCode::Bgd,
+            work_query::CountryCode::BRB => CountryCode::Brb,
+            work_query::CountryCode::BLR => CountryCode::Blr,
+            work_query::CountryCode::BEL => CountryCode::Bel,
+            work_query::CountryCode::BLZ => CountryCode::Blz,
+            work_query::CountryCode::BEN => CountryCode::Ben,
+            work_query::CountryCode::BMU => CountryCode::Bmu,
+            work_query::CountryCode::BTN => CountryCode::Btn,
+            work_query::CountryCode::BOL => CountryCode::Bol,
+            work_query::CountryCode::BES => CountryCode::Bes,
+            work_query::CountryCode::BIH => CountryCode::Bih,
+            work_query::CountryCode::BWA => CountryCode::Bwa,
+            work_query::CountryCode::BVT => CountryCode::Bvt,
+            work_query::CountryCode::BRA => CountryCode::Bra,
+            work_query::CountryCode::IOT => CountryCode::Iot,
+            work_query::CountryCode::BRN => CountryCode::Brn,
+            work_query::CountryCode::BGR => CountryCode::Bgr,
+            work_query::CountryCode::BFA => CountryCode::Bfa,
+            work_query::CountryCode::BDI => CountryCode::Bdi,
+            work_query::CountryCode::CPV => CountryCode::Cpv,
+            work_query::CountryCode::KHM => CountryCode::Khm,
+            work_query::CountryCode::CMR => CountryCode::Cmr,
+            work_query::CountryCode::CAN => CountryCode::Can,
+            work_query::CountryCode::CYM => CountryCode::Cym,
+            work_query::CountryCode::CAF => CountryCode::Caf,
+            work_query::CountryCode::TCD => CountryCode::Tcd,
+            work_query::CountryCode::CHL => CountryCode::Chl,
+            work_query::CountryCode::CHN => CountryCode::Chn,
+            work_query::CountryCode::CXR => CountryCode::Cxr,
+            work_query::CountryCode::CCK => CountryCode::Cck,
+            work_query::CountryCode::COL => CountryCode::Col,
+            work_query::CountryCode::COM => CountryCode::Com,
+            work_query::CountryCode::COK => CountryCode::Cok,
+            work_query::CountryCode::CRI => CountryCode::Cri,
+            work_query::CountryCode::CIV => CountryCode::Civ,
+            work_query::CountryCode::HRV => CountryCode::Hrv,
+            work_query::CountryCode::CUB => CountryCode::Cub,
+            work_query::CountryCode::CUW => CountryCode::Cuw,
+            work_query::CountryCode::CYP => CountryCode::Cyp,
+            work_query::CountryCode::CZE => CountryCode::Cze,
+            work_query::CountryCode::COD => CountryCode::Cod,
+            work_query::CountryCode::DNK => CountryCode::Dnk,
+            work_query::CountryCode::DJI => CountryCode::Dji,
+            work_query::CountryCode::DMA => CountryCode::Dma,
+            work_query::CountryCode::DOM => CountryCode::Dom,
+            work_query::CountryCode::ECU => CountryCode::Ecu,
+            work_query::CountryCode::EGY => CountryCode::Egy,
+            work_query::CountryCode::SLV => CountryCode::Slv,
+            work_query::CountryCode::GNQ => CountryCode::Gnq,
+            work_query::CountryCode::ERI => CountryCode::Eri,
+            work_query::CountryCode::EST => CountryCode::Est,
+            work_query::CountryCode::SWZ => CountryCode::Swz,
+            work_query::CountryCode::ETH => CountryCode::Eth,
+            work_query::CountryCode::FLK => CountryCode::Flk,
+            work_query::CountryCode::FRO => CountryCode::Fro,
+            work_query::CountryCode::FJI => CountryCode::Fji,
+            work_query::CountryCode::FIN => CountryCode::Fin,
+            work_query::CountryCode::FRA => CountryCode::Fra,
+            work_query::CountryCode::GUF => CountryCode::Guf,
+            work_query::CountryCode::PYF => CountryCode::Pyf,
+            work_query::CountryCode::ATF => CountryCode::Atf,
+            work_query::CountryCode::GAB => CountryCode::Gab,
+            work_query::CountryCode::GMB => CountryCode::Gmb,
+            work_query::CountryCode::GEO => CountryCode::Geo,
+            work_query::CountryCode::DEU => CountryCode::Deu,
+            work_query::CountryCode::GHA => CountryCode::Gha,
+            work_query::CountryCode::GIB => CountryCode::Gib,
+            work_query::CountryCode::GRC => CountryCode::Grc,
+            work_query::CountryCode::GRL => CountryCode::Grl,
+            work_query::CountryCode::GRD => CountryCode::Grd,
+            work_query::CountryCode::GLP => CountryCode::Glp,
+            work_query::CountryCode::GUM => CountryCode::Gum,
+            work_query::CountryCode::GTM => CountryCode::Gtm,
+            work_query::CountryCode::GGY => CountryCode::Ggy,
+            work_query::CountryCode::GIN => CountryCode::Gin,
+            work_query::CountryCode::GNB => CountryCode::Gnb,
+            work_query::CountryCode::GUY => CountryCode::Guy,
+            work_query::CountryCode::HTI => CountryCode::Hti,
+            work_query::CountryCode::HMD => CountryCode::Hmd,
+            work_query::CountryCode::HND => CountryCode::Hnd,
+            work_query::CountryCode::HKG => CountryCode::Hkg,
+            work_query::CountryCode::HUN => CountryCode::Hun,
+            work_query::CountryCode::ISL => CountryCode::Isl,
+            work_query::CountryCode::IND => CountryCode::Ind,
+            work_query::CountryCode::IDN => CountryCode::Idn,
+            work_query::CountryCode::IRN => CountryCode::Irn,
+            work_query::CountryCode::IRQ => CountryCode::Irq,
+            work_query::CountryCode::IRL => CountryCode::Irl,
+            work_query::CountryCode::IMN => CountryCode::Imn,
+            work_query::CountryCode::ISR => CountryCode::Isr,
+            work_query::CountryCode::ITA => CountryCode::Ita,
+            work_query::CountryCode::JAM => CountryCode::Jam,
+            work_query::CountryCode::JPN => CountryCode::Jpn,
+            work_query::CountryCode::JEY => CountryCode::Jey,
+            work_query::CountryCode::JOR => CountryCode::Jor,
+            work_query::CountryCode::KAZ => CountryCode::Kaz,
+            work_query::CountryCode::KEN => CountryCode::Ken,
+            work_query::CountryCode::KIR => CountryCode::Kir,
+            work_query::CountryCode::KWT => CountryCode::Kwt,
+            work_query::CountryCode::KGZ => CountryCode::Kgz,
+            work_query::CountryCode::LAO => CountryCode::Lao,
+            work_query::CountryCode::LVA => CountryCode::Lva,
+            work_query::CountryCode::LBN => CountryCode::Lbn,
+            work_query::CountryCode::LSO => CountryCode::Lso,
+            work_query::CountryCode::LBR => CountryCode::Lbr,
+            work_query::CountryCode::LBY => CountryCode::Lby,
+            work_query::CountryCode::LIE => CountryCode::Lie,
+            work_query::CountryCode::LTU => CountryCode::Ltu,
+            work_query::CountryCode::LUX => CountryCode::Lux,
+            work_query::CountryCode::MAC => CountryCode::Mac,
+            work_query::CountryCode::MDG => CountryCode::Mdg,
+            work_query::CountryCode::MWI => CountryCode::Mwi,
+            work_query::CountryCode::MYS => CountryCode::Mys,
+            work_query::CountryCode::MDV => CountryCode::Mdv,
+            work_query::CountryCode::MLI => CountryCode::Mli,
+            work_query::CountryCode::MLT => CountryCode::Mlt,
+            work_query::CountryCode::MHL => CountryCode::Mhl,
+            work_query::CountryCode::MTQ => CountryCode::Mtq,
+            work_query::CountryCode::MRT => CountryCode::Mrt,
+            work_query::CountryCode::MUS => CountryCode::Mus,
+            work_query::CountryCode::MYT => CountryCode::Myt,
+            work_query::CountryCode::MEX => CountryCode::Mex,
+            work_query::CountryCode::FSM => CountryCode::Fsm,
+            work_query::CountryCode::MDA => CountryCode::Mda,
+            work_query::CountryCode::MCO => CountryCode::Mco,
+            work_query::CountryCode::MNG => CountryCode::Mng,
+            work_query::CountryCode::MNE => CountryCode::Mne,
+            work_query::CountryCode::MSR => CountryCode::Msr,
+            work_query::CountryCode::MAR => CountryCode::Mar,
+            work_query::CountryCode::MOZ => CountryCode::Moz,
+            work_query::CountryCode::MMR => CountryCode::Mmr,
+            work_query::CountryCode::NAM => CountryCode::Nam,
+            work_query::CountryCode::NRU => CountryCode::Nru,
+            work_query::CountryCode::NPL => CountryCode::Npl,
+            work_query::CountryCode::NLD => CountryCode::Nld,
+            work_query::CountryCode::NCL => CountryCode::Ncl,
+            work_query::CountryCode::NZL => CountryCode::Nzl,
+            work_query::CountryCode::NIC => CountryCode::Nic,
+            work_query::CountryCode::NER => CountryCode::Ner,
+            work_query::CountryCode::NGA => CountryCode::Nga,
+            work_query::CountryCode::NIU => CountryCode::Niu,
+            work_query::CountryCode::NFK => CountryCode::Nfk,
+            work_query::CountryCode::PRK => CountryCode::Prk,
+            work_query::CountryCode::MKD => CountryCode::Mkd,
+            work_query::CountryCode::MNP => CountryCode::Mnp,
+            work_query::CountryCode::NOR => CountryCode::Nor,
+            work_query::CountryCode::OMN => CountryCode::Omn,
+            work_query::CountryCode::PAK => CountryCode::Pak,
+            work_query::CountryCode::PLW => CountryCode::Plw,
+            work_query::CountryCode::PSE => CountryCode::Pse,
+            work_query::CountryCode::PAN => CountryCode::Pan,
+            work_query::CountryCode::PNG => CountryCode::Png,
+            work_query::CountryCode::PRY => CountryCode::Pry,
+            work_query::CountryCode::PER => CountryCode::Per,
+            work_query::CountryCode::PHL => CountryCode::Phl,
+            work_query::CountryCode::PCN => CountryCode::Pcn,
+            work_query::CountryCode::POL => CountryCode::Pol,
+            work_query::CountryCode::PRT => CountryCode::Prt,
+            work_query::CountryCode::PRI => CountryCode::Pri,
+            work_query::CountryCode::QAT => CountryCode::Qat,
+            work_query::CountryCode::COG => CountryCode::Cog,
+            work_query::CountryCode::REU => CountryCode::Reu,
+            work_query::CountryCode::ROU => CountryCode::Rou,
+            work_query::CountryCode::RUS => CountryCode::Rus,
+            work_query::CountryCode::RWA => CountryCode::Rwa,
+            work_query::CountryCode::BLM => CountryCode::Blm,
+            work_query::CountryCode::SHN => CountryCode::Shn,
+            work_query::CountryCode::KNA => CountryCode::Kna,
+            work_query::CountryCode::LCA => CountryCode::Lca,
+            work_query::CountryCode::MAF => CountryCode::Maf,
+            work_query::CountryCode::SPM => CountryCode::Spm,
+            work_query::CountryCode::VCT => CountryCode::Vct,
+            work_query::CountryCode::WSM => CountryCode::Wsm,
+            work_query::CountryCode::SMR => CountryCode::Smr,
+            work_query::CountryCode::STP => CountryCode::Stp,
+            work_query::CountryCode::SAU => CountryCode::Sau,
+            work_query::CountryCode::SEN => CountryCode::Sen,
+            work_query::CountryCode::SRB => CountryCode::Srb,
+            work_query::CountryCode::SYC => CountryCode::Syc,
+            work_query::CountryCode::SLE => CountryCode::Sle,
+            work_query::CountryCode::SGP => CountryCode::Sgp,
+            work_query::CountryCode::SXM => CountryCode::Sxm,
+            work_query::CountryCode::SVK => CountryCode::Svk,
+            work_query::CountryCode::SVN => CountryCode::Svn,
+            work_query::CountryCode::SLB => CountryCode::Slb,
+            work_query::CountryCode::SOM => CountryCode::Som,
+            work_query::CountryCode::ZAF => CountryCode::Zaf,
+            work_query::CountryCode::SGS => CountryCode::Sgs,
+            work_query::CountryCode::KOR => CountryCode::Kor,
+            work_query::CountryCode::SSD => CountryCode::Ssd,
+            work_query::CountryCode::ESP => CountryCode::Esp,
+            work_query::CountryCode::LKA => CountryCode::Lka,
+            work_query::CountryCode::SDN => CountryCode::Sdn,
+            work_query::CountryCode::SUR => CountryCode::Sur,
+            work_query::CountryCode::SJM => CountryCode::Sjm,
+            work_query::CountryCode::SWE => CountryCode::Swe,
+            work_query::CountryCode::CHE => CountryCode::Che,
+            work_query::CountryCode::SYR => CountryCode::Syr,
+            work_query::CountryCode::TWN => CountryCode::Twn,
+            work_query::CountryCode::TJK => CountryCode::Tjk,
+            work_query::CountryCode::TZA => CountryCode::Tza,
+            work_query::CountryCode::THA => CountryCode::Tha,
+            work_query::CountryCode::TLS => CountryCode::Tls,
+            work_query::CountryCode::TGO => CountryCode::Tgo,
+            work_query::CountryCode::TKL => CountryCode::Tkl,
+            work_query::CountryCode::TON => CountryCode::Ton,
+            work_query::CountryCode::TTO => CountryCode::Tto,
+            work_query::CountryCode::TUN => CountryCode::Tun,
+            work_query::CountryCode::TUR => CountryCode::Tur,
+            work_query::CountryCode::TKM => CountryCode::Tkm,
+            work_query::CountryCode::TCA => CountryCode::Tca,
+            work_query::CountryCode::TUV => CountryCode::Tuv,
+            work_query::CountryCode::UGA => CountryCode::Uga,
+            work_query::CountryCode::UKR => CountryCode::Ukr,
+            work_query::CountryCode::ARE => CountryCode::Are,
+            work_query::CountryCode::GBR => CountryCode::Gbr,
+            work_query::CountryCode::UMI => CountryCode::Umi,
+            work_query::CountryCode::USA => CountryCode::Usa,
+            work_query::CountryCode::URY => CountryCode::Ury,
+            work_query::CountryCode::UZB => CountryCode::Uzb,
+            work_query::CountryCode::VUT => CountryCode::Vut,
+            work_query::CountryCode::VAT => CountryCode::Vat,
+            work_query::CountryCode::VEN => CountryCode::Ven,
+            work_query::CountryCode::VNM => CountryCode::Vnm,
+            work_query::CountryCode::VGB => CountryCode::Vgb,
+            work_query::CountryCode::VIR => CountryCode::Vir,
+            work_query::CountryCode::WLF => CountryCode::Wlf,
+            work_query::CountryCode::ESH => CountryCode::Esh,
+            work_query::CountryCode::YEM => CountryCode::Yem,
+            work_query::CountryCode::ZMB => CountryCode::Zmb,
+            work_query::CountryCode::ZWE => CountryCode::Zwe,
+            work_query::CountryCode::Other(_) => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::future::Future;
+
+/// Header carrying the ID of the request that caused a call to the API, matching the one
+/// adopted by the Thoth servers
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Run `future`, tagging every request it makes through a `ThothClient` with `request_id`, so
+/// that the API's logs of those requests can be correlated with the caller's.
+///
+/// # Example
+///
+/// ```no_run
+/// # use thoth_errors::ThothResult;
+/// # use thoth_client::{with_request_id, ThothClient};
+///
+/// # async fn run() -> ThothResult<()> {
+/// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string());
+/// with_request_id("5f0c8e1e-6f3a-4a57-9a8e-0a4c1d3e2b7f".to_string(), async {
+///     thoth_client.ping().await
+/// })
+/// .await
+/// # }
+/// ```
+pub async fn with_request_id<F: Future>(request_id: String, future: F) -> F::Output {
+    REQUEST_ID.scope(request_id, future).await
+}
+
+/// ID set by the closest enclosing [`with_request_id`], if any
+pub(crate) fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn test_with_request_id() {
+        assert_eq!(current_request_id(), None);
+        let request_id = block_on(with_request_id("abc-123".to_string(), async {
+            current_request_id()
+        }));
+        assert_eq!(request_id, Some("abc-123".to_string()));
+        assert_eq!(current_request_id(), None);
+    }
+}
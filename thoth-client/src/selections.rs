@@ -0,0 +1,499 @@
+//! GraphQL selection sets requesting every field of the `thoth_api` model structs, so that
+//! responses can be deserialised straight into them.
+
+pub(crate) const ABSTRACT: &str = "
+    abstractId
+    workId
+    content
+    localeCode
+    abstractType
+    canonical
+";
+
+pub(crate) const ADDITIONAL_RESOURCE: &str = "
+    additionalResourceId: workResourceId
+    workId
+    title
+    description
+    attribution
+    resourceType
+    doi
+    handle
+    url
+    date
+    resourceOrdinal
+    createdAt
+    updatedAt
+";
+
+pub(crate) const AFFILIATION: &str = "
+    affiliationId
+    contributionId
+    institutionId
+    affiliationOrdinal
+    position
+    createdAt
+    updatedAt
+";
+
+pub(crate) const AUDIENCE: &str = "
+    audienceId
+    workId
+    audienceCode
+    audienceRangeQualifier
+    audienceRangePrecision
+    audienceRangeFrom
+    audienceRangeTo
+    createdAt
+    updatedAt
+";
+
+pub(crate) const AWARD: &str = "
+    awardId
+    workId
+    title
+    url
+    category
+    year
+    jury
+    country
+    prizeStatement
+    role
+    awardOrdinal
+    createdAt
+    updatedAt
+";
+
+pub(crate) const BIOGRAPHY: &str = "
+    biographyId
+    contributionId
+    content
+    canonical
+    localeCode
+";
+
+pub(crate) const BOOK_REVIEW: &str = "
+    bookReviewId
+    workId
+    title
+    authorName
+    reviewerOrcid
+    reviewerInstitutionId
+    url
+    doi
+    reviewDate
+    journalName
+    journalVolume
+    journalNumber
+    journalIssn
+    pageRange
+    text
+    reviewOrdinal
+    createdAt
+    updatedAt
+";
+
+pub(crate) const CONTACT: &str = "
+    contactId
+    publisherId
+    contactType
+    email
+    createdAt
+    updatedAt
+";
+
+pub(crate) const CONTRIBUTION: &str = "
+    contributionId
+    workId
+    contributorId
+    contributionType
+    mainContribution
+    createdAt
+    updatedAt
+    firstName
+    lastName
+    fullName
+    contributionOrdinal
+";
+
+pub(crate) const CONTRIBUTOR: &str = "
+    contributorId
+    firstName
+    lastName
+    fullName
+    orcid
+    website
+    createdAt
+    updatedAt
+";
+
+pub(crate) const CREDIT_ROLE: &str = "
+    creditRoleId
+    contributionId
+    roleType
+    degree
+    createdAt
+    updatedAt
+";
+
+pub(crate) const ENDORSEMENT: &str = "
+    endorsementId
+    workId
+    authorName
+    authorRole
+    authorOrcid
+    authorInstitutionId
+    url
+    text
+    endorsementOrdinal
+    createdAt
+    updatedAt
+";
+
+pub(crate) const FILE: &str = "
+    fileId
+    fileType
+    workId
+    publicationId
+    additionalResourceId
+    workFeaturedVideoId
+    objectKey
+    cdnUrl
+    mimeType
+    bytes
+    sha256
+    createdAt
+    updatedAt
+    validationStatus
+    validationIssues {
+        validator
+        severity
+        message
+    }
+    validatedAt
+";
+
+pub(crate) const FILE_AUDIT: &str = "
+    fileAuditId
+    auditRunId
+    fileId
+    imprintId
+    objectKey
+    expectedBytes
+    actualBytes
+    expectedSha256
+    actualSha256
+    expectedMimeType
+    actualMimeType
+    objectMissing
+    sizeMismatch
+    checksumMismatch
+    mimeTypeMismatch
+    errorMessage
+    checkedAt
+";
+
+pub(crate) const FILE_VERSION: &str = "
+    fileVersionId
+    fileId
+    publicationId
+    versionNumber
+    objectKey
+    cdnUrl
+    mimeType
+    bytes
+    sha256
+    uploadedBy
+    restoredFromFileVersionId
+    createdAt
+";
+
+pub(crate) const FUNDING: &str = "
+    fundingId
+    workId
+    institutionId
+    program
+    projectName
+    projectShortname
+    grantNumber
+    createdAt
+    updatedAt
+";
+
+pub(crate) const IMPRINT: &str = "
+    imprintId
+    publisherId
+    imprintName
+    imprintUrl
+    crossmarkDoi
+    defaultCurrency
+    defaultPlace
+    defaultLocale
+    createdAt
+    updatedAt
+    rejectInvalidFiles
+";
+
+pub(crate) const INSTITUTION: &str = "
+    institutionId
+    institutionName
+    institutionDoi
+    createdAt
+    updatedAt
+    ror
+    countryCode
+";
+
+pub(crate) const ISSUE: &str = "
+    issueId
+    seriesId
+    workId
+    issueOrdinal
+    issueNumber
+    createdAt
+    updatedAt
+";
+
+pub(crate) const LANGUAGE: &str = "
+    languageId
+    workId
+    languageCode
+    languageRelation
+    createdAt
+    updatedAt
+";
+
+pub(crate) const LOCATION: &str = "
+    locationId
+    publicationId
+    landingPage
+    fullTextUrl
+    locationPlatform
+    canonical
+    createdAt
+    updatedAt
+";
+
+pub(crate) const NAME_VARIANT: &str = "
+    nameVariantId
+    contributorId
+    contributionId
+    variantType
+    scriptCode
+    localeCode
+    firstName
+    lastName
+    fullName
+    createdAt
+    updatedAt
+";
+
+pub(crate) const PRICE: &str = "
+    priceId
+    publicationId
+    currencyCode
+    unitPrice
+    createdAt
+    updatedAt
+    priceType
+    taxInclusive
+    countriesIncluded
+    effectiveFrom
+    effectiveUntil
+";
+
+pub(crate) const PRODUCT_FORM_DETAIL: &str = "
+    productFormDetailId
+    publicationId
+    detailType
+    detailCode
+    featureValue
+    featureDescription
+    createdAt
+    updatedAt
+";
+
+pub(crate) const PUBLICATION: &str = "
+    publicationId
+    publicationType
+    workId
+    isbn
+    createdAt
+    updatedAt
+    widthMm: width(units: MM)
+    widthIn: width(units: IN)
+    heightMm: height(units: MM)
+    heightIn: height(units: IN)
+    depthMm: depth(units: MM)
+    depthIn: depth(units: IN)
+    weightG: weight(units: G)
+    weightOz: weight(units: OZ)
+    accessibilityStandard
+    accessibilityAdditionalStandard
+    accessibilityException
+    accessibilityReportUrl
+    license
+    accessStatus
+    embargoEndDate
+";
+
+pub(crate) const PUBLISHER: &str = "
+    publisherId
+    publisherName
+    publisherShortname
+    publisherUrl
+    zitadelId
+    accessibilityStatement
+    accessibilityReportUrl
+    createdAt
+    updatedAt
+";
+
+pub(crate) const REFERENCE: &str = "
+    referenceId
+    workId
+    referenceOrdinal
+    doi
+    unstructuredCitation
+    issn
+    isbn
+    journalTitle
+    articleTitle
+    seriesTitle
+    volumeTitle
+    edition
+    author
+    volume
+    issue
+    firstPage
+    componentNumber
+    standardDesignator
+    standardsBodyName
+    standardsBodyAcronym
+    url
+    publicationDate
+    retrievalDate
+    createdAt
+    updatedAt
+";
+
+pub(crate) const SALES_RIGHTS: &str = "
+    salesRightsId
+    publicationId
+    salesRightsType
+    countriesIncluded
+    countriesExcluded
+    createdAt
+    updatedAt
+";
+
+pub(crate) const SERIES: &str = "
+    seriesId
+    seriesType
+    seriesName
+    issnPrint
+    issnDigital
+    seriesUrl
+    imprintId
+    createdAt
+    updatedAt
+    seriesDescription
+    seriesCfpUrl
+";
+
+pub(crate) const SUBJECT: &str = "
+    subjectId
+    workId
+    subjectType
+    subjectCode
+    subjectOrdinal
+    createdAt
+    updatedAt
+";
+
+pub(crate) const TITLE: &str = "
+    titleId
+    workId
+    fullTitle
+    title
+    subtitle
+    canonical
+    localeCode
+";
+
+pub(crate) const WORK: &str = "
+    workId
+    workType
+    workStatus
+    reference
+    edition
+    imprintId
+    doi
+    publicationDate
+    withdrawnDate
+    place
+    pageCount
+    pageBreakdown
+    imageCount
+    tableCount
+    audioCount
+    videoCount
+    license
+    copyrightHolder
+    landingPage
+    lccn
+    oclc
+    generalNote
+    bibliographyNote
+    toc
+    resourcesDescription
+    coverUrl
+    coverCaption
+    createdAt
+    updatedAt
+    firstPage
+    lastPage
+    pageInterval
+    updatedAtWithRelations
+    exposeLatestFileUrl
+";
+
+pub(crate) const WORK_DATE: &str = "
+    workDateId
+    workId
+    dateType
+    date
+    createdAt
+    updatedAt
+";
+
+pub(crate) const WORK_FEATURED_VIDEO: &str = "
+    workFeaturedVideoId
+    workId
+    title
+    url
+    width
+    height
+    createdAt
+    updatedAt
+";
+
+pub(crate) const WORK_RELATION: &str = "
+    workRelationId
+    relatorWorkId
+    relatedWorkId
+    relationType
+    relationOrdinal
+    createdAt
+    updatedAt
+";
+
+pub(crate) const FILE_UPLOAD_RESPONSE: &str = "
+    fileUploadId
+    uploadUrl
+    uploadHeaders {
+        name
+        value
+    }
+    expiresAt
+";
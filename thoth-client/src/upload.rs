@@ -0,0 +1,127 @@
+use sha2::{Digest, Sha256};
+use thoth_api::model::file::{
+    CompleteFileUpload, File, FileUploadResponse, NewAdditionalResourceFileUpload,
+    NewFrontcoverFileUpload, NewPublicationFileUpload, NewWorkFeaturedVideoFileUpload,
+};
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::ThothClient;
+
+/// Record a file is uploaded for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileUploadTarget {
+    /// Publication file (PDF, EPUB, XML, etc.), given the publication's Thoth ID
+    Publication(Uuid),
+    /// Front cover image, given the work's Thoth ID
+    Frontcover(Uuid),
+    /// Asset of an additional resource, given the additional resource's Thoth ID
+    AdditionalResource(Uuid),
+    /// Hosted featured video, given the work featured video's Thoth ID
+    WorkFeaturedVideo(Uuid),
+}
+
+impl ThothClient {
+    /// Upload a file to Thoth and attach it to the given record.
+    ///
+    /// This runs the whole upload flow: it starts an upload session declaring the file's
+    /// SHA-256 checksum, sends the file to the presigned URL returned by the API, and then
+    /// completes the upload, which validates the file and promotes it to its final location.
+    ///
+    /// # Errors
+    ///
+    /// This method fails if any of the requests could not be sent, if the storage backend
+    /// rejected the file, or if the API refused the upload (e.g. the file failed validation)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use thoth_api::model::file::File;
+    /// # use thoth_errors::ThothResult;
+    /// # use thoth_client::{FileUploadTarget, ThothClient};
+    /// # use uuid::Uuid;
+    ///
+    /// # async fn run() -> ThothResult<File> {
+    /// let thoth_client = ThothClient::new("https://api.thoth.pub/graphql".to_string())
+    ///     .with_token("my-personal-access-token".to_string());
+    /// let publication_id = Uuid::parse_str("00000000-0000-0000-AAAA-000000000001")?;
+    /// let bytes = std::fs::read("book.pdf")?;
+    /// let file = thoth_client
+    ///     .upload_file(FileUploadTarget::Publication(publication_id), bytes, "application/pdf", "pdf")
+    ///     .await?;
+    /// # Ok(file)
+    /// # }
+    /// ```
+    pub async fn upload_file(
+        &self,
+        target: FileUploadTarget,
+        bytes: Vec<u8>,
+        mime_type: &str,
+        extension: &str,
+    ) -> ThothResult<File> {
+        let declared_mime_type = mime_type.to_string();
+        let declared_extension = extension.to_string();
+        let declared_sha256 = hex::encode(Sha256::digest(&bytes));
+        let upload = match target {
+            FileUploadTarget::Publication(publication_id) => {
+                self.init_publication_file_upload(NewPublicationFileUpload {
+                    publication_id,
+                    declared_mime_type,
+                    declared_extension,
+                    declared_sha256,
+                })
+                .await?
+            }
+            FileUploadTarget::Frontcover(work_id) => {
+                self.init_frontcover_file_upload(NewFrontcoverFileUpload {
+                    work_id,
+                    declared_mime_type,
+                    declared_extension,
+                    declared_sha256,
+                })
+                .await?
+            }
+            FileUploadTarget::AdditionalResource(additional_resource_id) => {
+                self.init_additional_resource_file_upload(NewAdditionalResourceFileUpload {
+                    additional_resource_id,
+                    declared_mime_type,
+                    declared_extension,
+                    declared_sha256,
+                })
+                .await?
+            }
+            FileUploadTarget::WorkFeaturedVideo(work_featured_video_id) => {
+                self.init_work_featured_video_file_upload(NewWorkFeaturedVideoFileUpload {
+                    work_featured_video_id,
+                    declared_mime_type,
+                    declared_extension,
+                    declared_sha256,
+                })
+                .await?
+            }
+        };
+        self.put_file(&upload, bytes).await?;
+        self.complete_file_upload(CompleteFileUpload {
+            file_upload_id: upload.file_upload_id,
+        })
+        .await
+    }
+
+    /// Send the file to the presigned URL of an upload session, with the headers it requires
+    async fn put_file(&self, upload: &FileUploadResponse, bytes: Vec<u8>) -> ThothResult<()> {
+        let request = upload.upload_headers.iter().fold(
+            self.http_client.put(&upload.upload_url),
+            |request, header| request.header(&header.name, &header.value),
+        );
+        let res = request.body(bytes).send().await?;
+        let status = res.status();
+        if !status.is_success() {
+            return Err(ThothError::RequestError(format!(
+                "File upload {}: {}",
+                status.as_u16(),
+                res.text().await.unwrap_or_default()
+            )));
+        }
+        Ok(())
+    }
+}
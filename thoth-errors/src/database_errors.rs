@@ -33,6 +33,11 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "biography_content_check" => "Biography content must not be an empty string.",
     "biography_uniq_locale_idx" => "Biography with this locale code of current contribution already exists.",
     "biography_unique_canonical_true_idx" => "Canonical biography of current contribution already exists.",
+    "audience_range_check" => "Audience range qualifier and precision must be supplied together, and range values require both.",
+    "audience_range_from_check" => "Audience range From value must not be an empty string.",
+    "audience_range_precision_check" => "Audience range values do not match the precision: Exact requires only a From value, From requires a From value, and To requires only a To value.",
+    "audience_range_to_check" => "Audience range To value must not be an empty string.",
+    "audience_uniq_idx" => "An audience with this code and range qualifier already exists for this work.",
     "award_award_ordinal_work_id_uniq" => "An award with this ordinal number already exists.",
     "book_review_review_ordinal_work_id_uniq" => "A book review with this ordinal number already exists.",
     "contribution_contribution_ordinal_check" => "A contribution ordinal number must be greater than 0.",
@@ -46,6 +51,7 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "contributor_last_name_check" => "Last name must not be an empty string.",
     "contributor_orcid_check" => "Invalid ORCID ID.",
     "contributor_website_check" => "Website must not be an empty string.",
+    "credit_role_uniq_idx" => "This CRediT role has already been assigned to this contribution.",
     "doi_uniq_idx" => "A work with this DOI already exists.",
     "email_uniq_idx" => "An account with this email already exists.",
     "endorsement_endorsement_ordinal_work_id_uniq" => "An endorsement with this ordinal number already exists.",
@@ -70,6 +76,13 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "location_uniq_canonical_true_idx" => "A canonical location for this publication already exists.",
     "location_uniq_platform_idx" => "A location on the selected platform already exists.",
     "location_url_check" => "A location must have a landing page and/or a full text URL.",
+    "name_variant_contribution_uniq_idx" => "A name variant of this type with this name already exists for this contribution.",
+    "name_variant_contributor_uniq_idx" => "A name variant of this type with this name already exists for this contributor.",
+    "name_variant_first_name_check" => "First name must not be an empty string.",
+    "name_variant_full_name_check" => "Full name must not be an empty string.",
+    "name_variant_last_name_check" => "Last name must not be an empty string.",
+    "name_variant_parent_check" => "A name variant must belong to either a contributor or a contribution, but not both.",
+    "name_variant_script_code_check" => "A script code must be a four-letter ISO 15924 code, e.g. Cyrl.",
     "file_frontcover_work_unique_idx" => "A frontcover file for this work already exists.",
     "file_object_key_unique_idx" => "A file with this object key already exists.",
     "file_publication_unique_idx" => "A publication file for this publication already exists.",
@@ -78,18 +91,28 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "file_type_check" => "File type is invalid: frontcover must have work_id, publication must have publication_id, additional_resource must have additional_resource_id, work_featured_video must have work_featured_video_id.",
     "file_upload_type_check" => "File upload type is invalid: frontcover must have work_id, publication must have publication_id, additional_resource must have additional_resource_id, work_featured_video must have work_featured_video_id.",
     "orcid_uniq_idx" => "A contributor with this ORCID ID already exists.",
-    "price_publication_id_currency_code_uniq" => "A price in this currency already exists for this publication.",
+    "price_countries_included_check" => "A territory must list at least one country, or omit included countries to indicate worldwide.",
+    "price_effective_dates_check" => "Price Effective Until date must not be earlier than its Effective From date.",
+    "price_publication_id_currency_code_uniq" => "A price in this currency, territory and type already exists for this publication from this date.",
     "price_unit_price_check" => "Price values must be greater than zero. To indicate an unpriced Publication, omit all Prices.",
+    "product_form_detail_detail_code_check" => "Product form detail code must not be an empty string.",
+    "product_form_detail_feature_check" => "Feature values and descriptions are only applicable to product form features.",
+    "product_form_detail_feature_description_check" => "Product form feature description must not be an empty string.",
+    "product_form_detail_feature_value_check" => "Product form feature value must not be an empty string.",
+    "product_form_detail_packaging_uniq_idx" => "This publication already has a packaging type.",
+    "product_form_detail_uniq_idx" => "This product form detail has already been added to the publication.",
     "publication_depth_in_check" => "Publication depth must be greater than 0.0.",
     "publication_depth_in_not_missing" => "When specifying Depth, both values (mm and in) must be supplied.",
     "publication_depth_mm_check" => "Publication depth must be greater than 0.0.",
     "publication_depth_mm_not_missing" => "When specifying Depth, both values (mm and in) must be supplied.",
+    "publication_embargo_end_date_check" => "An embargo end date must be given for embargoed publications, and only for them.",
     "publication_height_in_check" => "Publication height must be greater than 0.0.",
     "publication_height_in_not_missing" => "When specifying Height, both values (mm and in) must be supplied.",
     "publication_height_mm_check" => "Publication height must be greater than 0.0.",
     "publication_height_mm_not_missing" => "When specifying Height, both values (mm and in) must be supplied.",
     "publication_isbn_check" => "A valid ISBN must be exactly 17 characters.",
     "publication_isbn_idx" => "A publication with this ISBN already exists.",
+    "publication_license_check" => "Invalid license URL.",
     "publication_non_physical_no_dimensions" => "Width/Height/Depth/Weight are only applicable to physical (Paperback/Hardback) Publications.",
     "publication_publication_type_work_id_uniq" => "A publication with the selected type already exists.",
     "publication_weight_g_check" => "Publication weight must be greater than 0.0.",
@@ -126,6 +149,8 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "reference_url_check" => "Invalid URL.",
     "reference_volume_check" => "Volume must not be an empty string.",
     "reference_volume_title_check" => "Volume title must not be an empty string.",
+    "sales_rights_countries_excluded_check" => "Excluded countries can only be specified for worldwide territories.",
+    "sales_rights_countries_included_check" => "A territory must list at least one country, or omit included countries to indicate worldwide.",
     "series_issn_digital_check" => "Invalid digital ISSN.",
     "series_issn_digital_idx" => "A series with this digital ISSN already exists.",
     "series_issn_print_check" => "Invalid print ISSN.",
@@ -153,6 +178,7 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "work_copyright_holder_check" => "Copyright holder must not be an empty string.",
     "work_cover_caption_check" => "Cover caption must not be an empty string.",
     "work_cover_url_check" => "Invalid cover URL.",
+    "work_date_uniq_idx" => "A date of this type already exists for this work.",
     "work_doi_check" => "Invalid DOI.",
     "work_edition_check" => "Edition number must be greater than 0.",
     "work_first_page_check" => "First page must not be an empty string.",
@@ -186,15 +212,216 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "work_featured_video_work_id_key" => "A featured video for this work already exists."
 };
 
+/// A map of database constraint name and the GraphQL input field whose value violates it,
+/// where the constraint concerns a single field.
+static CONSTRAINT_FIELDS: Map<&'static str, &'static str> = phf_map! {
+    "abstract_content_check" => "content",
+    "abstract_uniq_locale_idx" => "localeCode",
+    "abstract_unique_canonical_true_idx" => "canonical",
+    "additional_resource_resource_ordinal_work_id_uniq" => "resourceOrdinal",
+    "affiliation_affiliation_ordinal_check" => "affiliationOrdinal",
+    "affiliation_affiliation_ordinal_contribution_id_uniq" => "affiliationOrdinal",
+    "affiliation_position_check" => "position",
+    "audience_range_check" => "audienceRangeQualifier",
+    "audience_range_from_check" => "audienceRangeFrom",
+    "audience_range_precision_check" => "audienceRangePrecision",
+    "audience_range_to_check" => "audienceRangeTo",
+    "audience_uniq_idx" => "audienceCode",
+    "award_award_ordinal_work_id_uniq" => "awardOrdinal",
+    "biography_content_check" => "content",
+    "biography_uniq_locale_idx" => "localeCode",
+    "biography_unique_canonical_true_idx" => "canonical",
+    "book_review_review_ordinal_work_id_uniq" => "reviewOrdinal",
+    "contribution_contribution_ordinal_check" => "contributionOrdinal",
+    "contribution_contribution_ordinal_work_id_uniq" => "contributionOrdinal",
+    "contribution_first_name_check" => "firstName",
+    "contribution_full_name_check" => "fullName",
+    "contribution_last_name_check" => "lastName",
+    "contribution_work_id_contributor_id_contribution_type_uniq" => "contributionType",
+    "contributor_first_name_check" => "firstName",
+    "contributor_full_name_check" => "fullName",
+    "contributor_last_name_check" => "lastName",
+    "contributor_orcid_check" => "orcid",
+    "contributor_website_check" => "website",
+    "credit_role_uniq_idx" => "roleType",
+    "doi_uniq_idx" => "doi",
+    "endorsement_endorsement_ordinal_work_id_uniq" => "endorsementOrdinal",
+    "funding_grant_number_check" => "grantNumber",
+    "funding_program_check" => "program",
+    "funding_project_name_check" => "projectName",
+    "funding_project_shortname_check" => "projectShortname",
+    "imprint_imprint_name_check" => "imprintName",
+    "imprint_imprint_url_check" => "imprintUrl",
+    "imprint_uniq_idx" => "imprintName",
+    "institution_doi_uniq_idx" => "institutionDoi",
+    "institution_institution_doi_check" => "institutionDoi",
+    "institution_institution_name_check" => "institutionName",
+    "institution_ror_check" => "ror",
+    "issue_issue_ordinal_check" => "issueOrdinal",
+    "issue_issue_ordinal_series_id_uniq" => "issueOrdinal",
+    "issue_series_id_work_id_uniq" => "seriesId",
+    "language_uniq_work_idx" => "languageCode",
+    "location_full_text_url_check" => "fullTextUrl",
+    "location_landing_page_check" => "landingPage",
+    "location_uniq_canonical_true_idx" => "canonical",
+    "location_uniq_platform_idx" => "locationPlatform",
+    "name_variant_contribution_uniq_idx" => "fullName",
+    "name_variant_contributor_uniq_idx" => "fullName",
+    "name_variant_first_name_check" => "firstName",
+    "name_variant_full_name_check" => "fullName",
+    "name_variant_last_name_check" => "lastName",
+    "name_variant_script_code_check" => "scriptCode",
+    "orcid_uniq_idx" => "orcid",
+    "price_countries_included_check" => "countriesIncluded",
+    "price_effective_dates_check" => "effectiveUntil",
+    "price_publication_id_currency_code_uniq" => "currencyCode",
+    "price_unit_price_check" => "unitPrice",
+    "product_form_detail_detail_code_check" => "detailCode",
+    "product_form_detail_feature_description_check" => "featureDescription",
+    "product_form_detail_feature_value_check" => "featureValue",
+    "product_form_detail_packaging_uniq_idx" => "detailCode",
+    "product_form_detail_uniq_idx" => "detailCode",
+    "publication_depth_in_check" => "depthIn",
+    "publication_depth_in_not_missing" => "depthIn",
+    "publication_depth_mm_check" => "depthMm",
+    "publication_depth_mm_not_missing" => "depthMm",
+    "publication_embargo_end_date_check" => "embargoEndDate",
+    "publication_height_in_check" => "heightIn",
+    "publication_height_in_not_missing" => "heightIn",
+    "publication_height_mm_check" => "heightMm",
+    "publication_height_mm_not_missing" => "heightMm",
+    "publication_isbn_check" => "isbn",
+    "publication_isbn_idx" => "isbn",
+    "publication_license_check" => "license",
+    "publication_publication_type_work_id_uniq" => "publicationType",
+    "publication_weight_g_check" => "weightG",
+    "publication_weight_g_not_missing" => "weightG",
+    "publication_weight_oz_check" => "weightOz",
+    "publication_weight_oz_not_missing" => "weightOz",
+    "publication_width_in_check" => "widthIn",
+    "publication_width_in_not_missing" => "widthIn",
+    "publication_width_mm_check" => "widthMm",
+    "publication_width_mm_not_missing" => "widthMm",
+    "publisher_publisher_name_check" => "publisherName",
+    "publisher_publisher_shortname_check" => "publisherShortname",
+    "publisher_publisher_url_check" => "publisherUrl",
+    "publisher_uniq_idx" => "publisherName",
+    "reference_article_title_check" => "articleTitle",
+    "reference_author_check" => "author",
+    "reference_component_number_check" => "componentNumber",
+    "reference_doi_check" => "doi",
+    "reference_edition_check" => "edition",
+    "reference_first_page_check" => "firstPage",
+    "reference_isbn_check" => "isbn",
+    "reference_issn_check" => "issn",
+    "reference_issue_check" => "issue",
+    "reference_journal_title_check" => "journalTitle",
+    "reference_reference_ordinal_check" => "referenceOrdinal",
+    "reference_reference_ordinal_work_id_uniq" => "referenceOrdinal",
+    "reference_series_title_check" => "seriesTitle",
+    "reference_standard_designator_check" => "standardDesignator",
+    "reference_standards_body_acronym_check" => "standardsBodyAcronym",
+    "reference_standards_body_name_check" => "standardsBodyName",
+    "reference_unstructured_citation_check" => "unstructuredCitation",
+    "reference_url_check" => "url",
+    "reference_volume_check" => "volume",
+    "reference_volume_title_check" => "volumeTitle",
+    "sales_rights_countries_excluded_check" => "countriesExcluded",
+    "sales_rights_countries_included_check" => "countriesIncluded",
+    "series_issn_digital_check" => "issnDigital",
+    "series_issn_digital_idx" => "issnDigital",
+    "series_issn_print_check" => "issnPrint",
+    "series_issn_print_idx" => "issnPrint",
+    "series_series_cfp_url_check" => "seriesCfpUrl",
+    "series_series_description_check" => "seriesDescription",
+    "series_series_name_check" => "seriesName",
+    "series_series_url_check" => "seriesUrl",
+    "subject_ordinal_type_uniq" => "subjectOrdinal",
+    "subject_subject_code_check" => "subjectCode",
+    "subject_subject_ordinal_check" => "subjectOrdinal",
+    "title_full_title_check" => "fullTitle",
+    "title_subtitle_check" => "subtitle",
+    "title_title_check" => "title",
+    "title_uniq_locale_idx" => "localeCode",
+    "title_unique_canonical_true_idx" => "canonical",
+    "work_active_publication_date_check" => "publicationDate",
+    "work_active_withdrawn_date_check" => "withdrawnDate",
+    "work_audio_count_check" => "audioCount",
+    "work_bibliography_note_check" => "bibliographyNote",
+    "work_chapter_no_edition" => "edition",
+    "work_chapter_no_lccn" => "lccn",
+    "work_chapter_no_oclc" => "oclc",
+    "work_chapter_no_toc" => "toc",
+    "work_copyright_holder_check" => "copyrightHolder",
+    "work_cover_caption_check" => "coverCaption",
+    "work_cover_url_check" => "coverUrl",
+    "work_date_uniq_idx" => "dateType",
+    "work_doi_check" => "doi",
+    "work_edition_check" => "edition",
+    "work_first_page_check" => "firstPage",
+    "work_general_note_check" => "generalNote",
+    "work_image_count_check" => "imageCount",
+    "work_inactive_no_withdrawn_date_check" => "withdrawnDate",
+    "work_landing_page_check" => "landingPage",
+    "work_last_page_check" => "lastPage",
+    "work_lccn_check" => "lccn",
+    "work_license_check" => "license",
+    "work_non_chapter_has_edition" => "edition",
+    "work_non_chapter_no_first_page" => "firstPage",
+    "work_non_chapter_no_last_page" => "lastPage",
+    "work_non_chapter_no_page_interval" => "pageInterval",
+    "work_oclc_check" => "oclc",
+    "work_page_breakdown_check" => "pageBreakdown",
+    "work_page_count_check" => "pageCount",
+    "work_page_interval_check" => "pageInterval",
+    "work_place_check" => "place",
+    "work_reference_check" => "reference",
+    "work_relation_ids_check" => "relatedWorkId",
+    "work_relation_ordinal_type_uniq" => "relationOrdinal",
+    "work_relation_relation_ordinal_check" => "relationOrdinal",
+    "work_relation_relator_related_uniq" => "relatedWorkId",
+    "work_table_count_check" => "tableCount",
+    "work_toc_check" => "toc",
+    "work_video_count_check" => "videoCount",
+    "work_withdrawn_date_after_publication_date_check" => "withdrawnDate",
+};
+
+/// Input field at fault when `constraint` is violated, where known
+pub(crate) fn constraint_field(constraint: &str) -> Option<&'static str> {
+    CONSTRAINT_FIELDS.get(constraint).copied()
+}
+
+/// Error raised when `constraint` is violated, if it is a known constraint
+pub(crate) fn constraint_error(constraint: &str) -> Option<ThothError> {
+    DATABASE_CONSTRAINT_ERRORS
+        .get_entry(constraint)
+        .map(
+            |(constraint, message)| ThothError::DatabaseConstraintError {
+                constraint: Cow::Borrowed(constraint),
+                message: Cow::Borrowed(message),
+            },
+        )
+}
+
+#[cfg(test)]
+pub(crate) fn constraint_names() -> impl Iterator<Item = &'static str> {
+    DATABASE_CONSTRAINT_ERRORS.keys().copied()
+}
+
+/// Whether `constraint` is a uniqueness constraint (as opposed to a check constraint)
+pub(crate) fn is_unique_constraint(constraint: &str) -> bool {
+    ["_uniq", "_uniq_idx", "_unique_idx", "_key", "_idx"]
+        .iter()
+        .any(|suffix| constraint.ends_with(suffix))
+}
+
 impl From<diesel::result::Error> for ThothError {
     fn from(error: diesel::result::Error) -> ThothError {
         use diesel::result::Error;
         match error {
             Error::DatabaseError(_kind, info) => {
-                if let Some(constraint_name) = info.constraint_name() {
-                    if let Some(error) = DATABASE_CONSTRAINT_ERRORS.get(constraint_name) {
-                        return ThothError::DatabaseConstraintError(Cow::Borrowed(error));
-                    }
+                if let Some(error) = info.constraint_name().and_then(constraint_error) {
+                    return error;
                 }
                 ThothError::DatabaseError(info.message().to_string())
             }
@@ -271,9 +498,10 @@ mod tests {
                 DatabaseErrorKind::UniqueViolation,
                 error_information
             )),
-            ThothError::DatabaseConstraintError(Cow::Borrowed(
-                "A contribution with this ordinal number already exists."
-            ))
+            ThothError::DatabaseConstraintError {
+                constraint: Cow::Borrowed("contribution_contribution_ordinal_work_id_uniq"),
+                message: Cow::Borrowed("A contribution with this ordinal number already exists.")
+            }
         )
     }
     #[test]
@@ -0,0 +1,727 @@
+use serde::{Deserialize, Serialize};
+
+use crate::database_errors::{constraint_error, constraint_field, is_unique_constraint};
+use crate::ThothError;
+
+/// HTTP-like classification of a [`ThothError`], telling clients whether the request can be
+/// corrected (and how) or whether the failure lies with the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCategory {
+    /// The input could not be parsed or is not a recognised value
+    BadRequest,
+    /// No valid credentials were supplied
+    Unauthenticated,
+    /// The credentials supplied do not allow the operation
+    Forbidden,
+    /// The requested record does not exist or has not been generated
+    NotFound,
+    /// The input clashes with an existing record
+    Conflict,
+    /// The input is well formed but breaks a validation rule
+    Unprocessable,
+    /// The failure lies with the server or one of its dependencies
+    Internal,
+}
+
+impl ErrorCategory {
+    /// Name of the category as used in GraphQL error extensions and JSON error bodies
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::BadRequest => "BAD_REQUEST",
+            ErrorCategory::Unauthenticated => "UNAUTHENTICATED",
+            ErrorCategory::Forbidden => "FORBIDDEN",
+            ErrorCategory::NotFound => "NOT_FOUND",
+            ErrorCategory::Conflict => "CONFLICT",
+            ErrorCategory::Unprocessable => "UNPROCESSABLE",
+            ErrorCategory::Internal => "INTERNAL",
+        }
+    }
+
+    /// HTTP status code corresponding to the category
+    pub fn status_code(&self) -> u16 {
+        match self {
+            ErrorCategory::BadRequest => 400,
+            ErrorCategory::Unauthenticated => 401,
+            ErrorCategory::Forbidden => 403,
+            ErrorCategory::NotFound => 404,
+            ErrorCategory::Conflict => 409,
+            ErrorCategory::Unprocessable => 422,
+            ErrorCategory::Internal => 500,
+        }
+    }
+}
+
+/// Machine-readable description of an error, as returned in export server error bodies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorBody {
+    pub code: String,
+    pub category: ErrorCategory,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+}
+
+impl ThothError {
+    /// Stable, machine-readable identifier of the error.
+    ///
+    /// Codes are part of the public API: they must not change when a variant is renamed
+    /// or its message reworded.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ThothError::InvalidSubjectCode { .. } => "INVALID_SUBJECT_CODE",
+            ThothError::DatabaseError(..) => "DATABASE_ERROR",
+            ThothError::RedisError(..) => "REDIS_ERROR",
+            ThothError::DatabaseConstraintError { .. } => "DATABASE_CONSTRAINT_VIOLATION",
+            ThothError::InternalError(..) => "INTERNAL_ERROR",
+            ThothError::Unauthorised => "UNAUTHORISED",
+            ThothError::InvalidToken => "INVALID_TOKEN",
+            ThothError::EntityNotFound => "ENTITY_NOT_FOUND",
+            ThothError::IssueImprintsError => "ISSUE_IMPRINTS",
+            ThothError::InvalidMetadataSpecification(..) => "INVALID_METADATA_SPECIFICATION",
+            ThothError::InvalidUuid => "INVALID_UUID",
+            ThothError::InvalidTimestamp => "INVALID_TIMESTAMP",
+            ThothError::CsvError(..) => "CSV_ERROR",
+            ThothError::MarcError(..) => "MARC_ERROR",
+            ThothError::IncompleteMetadataRecord(..) => "INCOMPLETE_METADATA_RECORD",
+            ThothError::MetadataRecordNotGenerated => "METADATA_RECORD_NOT_GENERATED",
+            ThothError::OrcidParseError(..) => "ORCID_PARSE",
+            ThothError::DoiParseError(..) => "DOI_PARSE",
+            ThothError::IsbnParseError(..) => "ISBN_PARSE",
+            ThothError::RorParseError(..) => "ROR_PARSE",
+            ThothError::OrcidEmptyError => "ORCID_EMPTY",
+            ThothError::DoiEmptyError => "DOI_EMPTY",
+            ThothError::IsbnEmptyError => "ISBN_EMPTY",
+            ThothError::RorEmptyError => "ROR_EMPTY",
+            ThothError::ChapterIsbnError => "CHAPTER_ISBN",
+            ThothError::ChapterBookMetadataError => "CHAPTER_BOOK_METADATA",
+            ThothError::ChapterDimensionError => "CHAPTER_DIMENSION",
+            ThothError::CanonicalLocationError => "CANONICAL_LOCATION",
+            ThothError::LocationUrlError => "LOCATION_URL",
+            ThothError::WeightEmptyError => "WEIGHT_EMPTY",
+            ThothError::WidthEmptyError => "WIDTH_EMPTY",
+            ThothError::HeightEmptyError => "HEIGHT_EMPTY",
+            ThothError::DepthEmptyError => "DEPTH_EMPTY",
+            ThothError::DimensionDigitalError => "DIMENSION_DIGITAL",
+            ThothError::PriceZeroError => "PRICE_ZERO",
+            ThothError::PublicationDateError => "PUBLICATION_DATE",
+            ThothError::RequestError(..) => "REQUEST_ERROR",
+            ThothError::GraphqlError(..) => "GRAPHQL_ERROR",
+            ThothError::WithdrawnDateBeforePublicationDateError => {
+                "WITHDRAWN_DATE_BEFORE_PUBLICATION_DATE"
+            }
+            ThothError::WithdrawnDateError => "WITHDRAWN_DATE",
+            ThothError::NoWithdrawnDateError => "NO_WITHDRAWN_DATE",
+            ThothError::ThothLocationError => "THOTH_LOCATION",
+            ThothError::ThothUpdateCanonicalError => "THOTH_UPDATE_CANONICAL",
+            ThothError::ThothSetWorkStatusError => "THOTH_SET_WORK_STATUS",
+            ThothError::ThothDeleteWorkError => "THOTH_DELETE_WORK",
+            ThothError::ThothDeletePublicationError => "THOTH_DELETE_PUBLICATION",
+            ThothError::CanonicalAbstractExistsError => "CANONICAL_ABSTRACT_EXISTS",
+            ThothError::ShortAbstractLimitExceedError => "SHORT_ABSTRACT_LIMIT_EXCEEDED",
+            ThothError::CanonicalBiographyExistsError => "CANONICAL_BIOGRAPHY_EXISTS",
+            ThothError::CanonicalTitleExistsError => "CANONICAL_TITLE_EXISTS",
+            ThothError::NoFileExtensionFound => "NO_FILE_EXTENSION_FOUND",
+            ThothError::UnsupportedFileFormatError => "UNSUPPORTED_FILE_FORMAT",
+            ThothError::TagNotFoundError => "TAG_NOT_FOUND",
+            ThothError::TitleMultipleTopLevelElementsError => "TITLE_MULTIPLE_TOP_LEVEL_ELEMENTS",
+            ThothError::TitleListItemError => "TITLE_LIST_ITEM",
+            ThothError::MissingMarkupFormat => "MISSING_MARKUP_FORMAT",
+            ThothError::InvalidFileExtension => "INVALID_FILE_EXTENSION",
+            ThothError::InvalidFileMimeType => "INVALID_FILE_MIME_TYPE",
+            ThothError::FileTooSmall => "FILE_TOO_SMALL",
+            ThothError::FileTooLarge => "FILE_TOO_LARGE",
+            ThothError::UnsupportedPublicationTypeForFileUpload => {
+                "UNSUPPORTED_PUBLICATION_TYPE_FOR_FILE_UPLOAD"
+            }
+            ThothError::UnsupportedResourceTypeForFileUpload => {
+                "UNSUPPORTED_RESOURCE_TYPE_FOR_FILE_UPLOAD"
+            }
+            ThothError::PublicationTypeRequiredForFileValidation => {
+                "PUBLICATION_TYPE_REQUIRED_FOR_FILE_VALIDATION"
+            }
+            ThothError::FileMissingWorkOrPublicationId => "FILE_MISSING_WORK_OR_PUBLICATION_ID",
+            ThothError::FileUploadMissingWorkOrPublicationId => {
+                "FILE_UPLOAD_MISSING_WORK_OR_PUBLICATION_ID"
+            }
+            ThothError::WorkMissingDoiForFileUpload => "WORK_MISSING_DOI_FOR_FILE_UPLOAD",
+            ThothError::PublicationFileUploadMissingPublicationId => {
+                "PUBLICATION_FILE_UPLOAD_MISSING_PUBLICATION_ID"
+            }
+            ThothError::FrontcoverFileUploadMissingWorkId => {
+                "FRONTCOVER_FILE_UPLOAD_MISSING_WORK_ID"
+            }
+            ThothError::AdditionalResourceFileUploadMissingAdditionalResourceId => {
+                "ADDITIONAL_RESOURCE_FILE_UPLOAD_MISSING_ADDITIONAL_RESOURCE_ID"
+            }
+            ThothError::WorkFeaturedVideoFileUploadMissingWorkFeaturedVideoId => {
+                "WORK_FEATURED_VIDEO_FILE_UPLOAD_MISSING_WORK_FEATURED_VIDEO_ID"
+            }
+            ThothError::FileValidationFailed(..) => "FILE_VALIDATION_FAILED",
+            ThothError::TerritoryEmptyCountriesError => "TERRITORY_EMPTY_COUNTRIES",
+            ThothError::TerritoryExcludedCountriesError => "TERRITORY_EXCLUDED_COUNTRIES",
+            ThothError::TerritoryDuplicateCountryError => "TERRITORY_DUPLICATE_COUNTRY",
+            ThothError::PriceEffectiveDatesError => "PRICE_EFFECTIVE_DATES",
+            ThothError::PublicationEmbargoEndDateError => "PUBLICATION_EMBARGO_END_DATE",
+            ThothError::UnrecognisedCreativeCommonsLicense(..) => {
+                "UNRECOGNISED_CREATIVE_COMMONS_LICENSE"
+            }
+            ThothError::AudienceRangeIncompleteError => "AUDIENCE_RANGE_INCOMPLETE",
+            ThothError::AudienceRangePrecisionError => "AUDIENCE_RANGE_PRECISION",
+            ThothError::AudienceRangeValueError(..) => "AUDIENCE_RANGE_VALUE",
+            ThothError::ProductFormDetailCodeError(..) => "PRODUCT_FORM_DETAIL_CODE",
+            ThothError::ProductFormFeatureError(..) => "PRODUCT_FORM_FEATURE",
+            ThothError::WorkDateError(..) => "WORK_DATE",
+            ThothError::WorkStatusMissingDateError(..) => "WORK_STATUS_MISSING_DATE",
+            ThothError::NameVariantParentError => "NAME_VARIANT_PARENT",
+            ThothError::NameVariantScriptError(..) => "NAME_VARIANT_SCRIPT",
+            ThothError::NameVariantMissingScriptError => "NAME_VARIANT_MISSING_SCRIPT",
+            ThothError::FunderRegistrySnapshotError(..) => "FUNDER_REGISTRY_SNAPSHOT",
+            ThothError::FunderRegistryUnknownDoiError(..) => "FUNDER_REGISTRY_UNKNOWN_DOI",
+            ThothError::FunderRegistryReplacedDoiError(..) => "FUNDER_REGISTRY_REPLACED_DOI",
+            ThothError::InvalidConfiguration(..) => "INVALID_CONFIGURATION",
+        }
+    }
+
+    /// Rebuild an error from its stable [`code`](ThothError::code) and
+    /// [`arguments`](ThothError::arguments), e.g. as read back from GraphQL error extensions.
+    ///
+    /// Returns `None` if the code is unknown or an argument the variant requires is missing.
+    pub fn from_code(code: &str, arguments: &[String]) -> Option<ThothError> {
+        let arg = |index: usize| arguments.get(index).cloned();
+        let error = match code {
+            "INVALID_SUBJECT_CODE" => ThothError::InvalidSubjectCode {
+                input: arg(0)?,
+                subject_type: arg(1)?,
+            },
+            "DATABASE_ERROR" => ThothError::DatabaseError(arg(0)?),
+            "REDIS_ERROR" => ThothError::RedisError(arg(0)?),
+            "DATABASE_CONSTRAINT_VIOLATION" => constraint_error(&arg(0)?)?,
+            "INTERNAL_ERROR" => ThothError::InternalError(arg(0)?),
+            "UNAUTHORISED" => ThothError::Unauthorised,
+            "INVALID_TOKEN" => ThothError::InvalidToken,
+            "ENTITY_NOT_FOUND" => ThothError::EntityNotFound,
+            "ISSUE_IMPRINTS" => ThothError::IssueImprintsError,
+            "INVALID_METADATA_SPECIFICATION" => ThothError::InvalidMetadataSpecification(arg(0)?),
+            "INVALID_UUID" => ThothError::InvalidUuid,
+            "INVALID_TIMESTAMP" => ThothError::InvalidTimestamp,
+            "CSV_ERROR" => ThothError::CsvError(arg(0)?),
+            "MARC_ERROR" => ThothError::MarcError(arg(0)?),
+            "INCOMPLETE_METADATA_RECORD" => ThothError::IncompleteMetadataRecord(arg(0)?, arg(1)?),
+            "METADATA_RECORD_NOT_GENERATED" => ThothError::MetadataRecordNotGenerated,
+            "ORCID_PARSE" => ThothError::OrcidParseError(arg(0)?),
+            "DOI_PARSE" => ThothError::DoiParseError(arg(0)?),
+            "ISBN_PARSE" => ThothError::IsbnParseError(arg(0)?),
+            "ROR_PARSE" => ThothError::RorParseError(arg(0)?),
+            "ORCID_EMPTY" => ThothError::OrcidEmptyError,
+            "DOI_EMPTY" => ThothError::DoiEmptyError,
+            "ISBN_EMPTY" => ThothError::IsbnEmptyError,
+            "ROR_EMPTY" => ThothError::RorEmptyError,
+            "CHAPTER_ISBN" => ThothError::ChapterIsbnError,
+            "CHAPTER_BOOK_METADATA" => ThothError::ChapterBookMetadataError,
+            "CHAPTER_DIMENSION" => ThothError::ChapterDimensionError,
+            "CANONICAL_LOCATION" => ThothError::CanonicalLocationError,
+            "LOCATION_URL" => ThothError::LocationUrlError,
+            "WEIGHT_EMPTY" => ThothError::WeightEmptyError,
+            "WIDTH_EMPTY" => ThothError::WidthEmptyError,
+            "HEIGHT_EMPTY" => ThothError::HeightEmptyError,
+            "DEPTH_EMPTY" => ThothError::DepthEmptyError,
+            "DIMENSION_DIGITAL" => ThothError::DimensionDigitalError,
+            "PRICE_ZERO" => ThothError::PriceZeroError,
+            "PUBLICATION_DATE" => ThothError::PublicationDateError,
+            "REQUEST_ERROR" => ThothError::RequestError(arg(0)?),
+            "GRAPHQL_ERROR" => ThothError::GraphqlError(arg(0)?),
+            "WITHDRAWN_DATE_BEFORE_PUBLICATION_DATE" => {
+                ThothError::WithdrawnDateBeforePublicationDateError
+            }
+            "WITHDRAWN_DATE" => ThothError::WithdrawnDateError,
+            "NO_WITHDRAWN_DATE" => ThothError::NoWithdrawnDateError,
+            "THOTH_LOCATION" => ThothError::ThothLocationError,
+            "THOTH_UPDATE_CANONICAL" => ThothError::ThothUpdateCanonicalError,
+            "THOTH_SET_WORK_STATUS" => ThothError::ThothSetWorkStatusError,
+            "THOTH_DELETE_WORK" => ThothError::ThothDeleteWorkError,
+            "THOTH_DELETE_PUBLICATION" => ThothError::ThothDeletePublicationError,
+            "CANONICAL_ABSTRACT_EXISTS" => ThothError::CanonicalAbstractExistsError,
+            "SHORT_ABSTRACT_LIMIT_EXCEEDED" => ThothError::ShortAbstractLimitExceedError,
+            "CANONICAL_BIOGRAPHY_EXISTS" => ThothError::CanonicalBiographyExistsError,
+            "CANONICAL_TITLE_EXISTS" => ThothError::CanonicalTitleExistsError,
+            "NO_FILE_EXTENSION_FOUND" => ThothError::NoFileExtensionFound,
+            "UNSUPPORTED_FILE_FORMAT" => ThothError::UnsupportedFileFormatError,
+            "TAG_NOT_FOUND" => ThothError::TagNotFoundError,
+            "TITLE_MULTIPLE_TOP_LEVEL_ELEMENTS" => ThothError::TitleMultipleTopLevelElementsError,
+            "TITLE_LIST_ITEM" => ThothError::TitleListItemError,
+            "MISSING_MARKUP_FORMAT" => ThothError::MissingMarkupFormat,
+            "INVALID_FILE_EXTENSION" => ThothError::InvalidFileExtension,
+            "INVALID_FILE_MIME_TYPE" => ThothError::InvalidFileMimeType,
+            "FILE_TOO_SMALL" => ThothError::FileTooSmall,
+            "FILE_TOO_LARGE" => ThothError::FileTooLarge,
+            "UNSUPPORTED_PUBLICATION_TYPE_FOR_FILE_UPLOAD" => {
+                ThothError::UnsupportedPublicationTypeForFileUpload
+            }
+            "UNSUPPORTED_RESOURCE_TYPE_FOR_FILE_UPLOAD" => {
+                ThothError::UnsupportedResourceTypeForFileUpload
+            }
+            "PUBLICATION_TYPE_REQUIRED_FOR_FILE_VALIDATION" => {
+                ThothError::PublicationTypeRequiredForFileValidation
+            }
+            "FILE_MISSING_WORK_OR_PUBLICATION_ID" => ThothError::FileMissingWorkOrPublicationId,
+            "FILE_UPLOAD_MISSING_WORK_OR_PUBLICATION_ID" => {
+                ThothError::FileUploadMissingWorkOrPublicationId
+            }
+            "WORK_MISSING_DOI_FOR_FILE_UPLOAD" => ThothError::WorkMissingDoiForFileUpload,
+            "PUBLICATION_FILE_UPLOAD_MISSING_PUBLICATION_ID" => {
+                ThothError::PublicationFileUploadMissingPublicationId
+            }
+            "FRONTCOVER_FILE_UPLOAD_MISSING_WORK_ID" => {
+                ThothError::FrontcoverFileUploadMissingWorkId
+            }
+            "ADDITIONAL_RESOURCE_FILE_UPLOAD_MISSING_ADDITIONAL_RESOURCE_ID" => {
+                ThothError::AdditionalResourceFileUploadMissingAdditionalResourceId
+            }
+            "WORK_FEATURED_VIDEO_FILE_UPLOAD_MISSING_WORK_FEATURED_VIDEO_ID" => {
+                ThothError::WorkFeaturedVideoFileUploadMissingWorkFeaturedVideoId
+            }
+            "FILE_VALIDATION_FAILED" => ThothError::FileValidationFailed(arg(0)?),
+            "TERRITORY_EMPTY_COUNTRIES" => ThothError::TerritoryEmptyCountriesError,
+            "TERRITORY_EXCLUDED_COUNTRIES" => ThothError::TerritoryExcludedCountriesError,
+            "TERRITORY_DUPLICATE_COUNTRY" => ThothError::TerritoryDuplicateCountryError,
+            "PRICE_EFFECTIVE_DATES" => ThothError::PriceEffectiveDatesError,
+            "PUBLICATION_EMBARGO_END_DATE" => ThothError::PublicationEmbargoEndDateError,
+            "UNRECOGNISED_CREATIVE_COMMONS_LICENSE" => {
+                ThothError::UnrecognisedCreativeCommonsLicense(arg(0)?)
+            }
+            "AUDIENCE_RANGE_INCOMPLETE" => ThothError::AudienceRangeIncompleteError,
+            "AUDIENCE_RANGE_PRECISION" => ThothError::AudienceRangePrecisionError,
+            "AUDIENCE_RANGE_VALUE" => ThothError::AudienceRangeValueError(arg(0)?),
+            "PRODUCT_FORM_DETAIL_CODE" => ThothError::ProductFormDetailCodeError(arg(0)?),
+            "PRODUCT_FORM_FEATURE" => ThothError::ProductFormFeatureError(arg(0)?),
+            "WORK_DATE" => ThothError::WorkDateError(arg(0)?),
+            "WORK_STATUS_MISSING_DATE" => ThothError::WorkStatusMissingDateError(arg(0)?, arg(1)?),
+            "NAME_VARIANT_PARENT" => ThothError::NameVariantParentError,
+            "NAME_VARIANT_SCRIPT" => ThothError::NameVariantScriptError(arg(0)?),
+            "NAME_VARIANT_MISSING_SCRIPT" => ThothError::NameVariantMissingScriptError,
+            "FUNDER_REGISTRY_SNAPSHOT" => ThothError::FunderRegistrySnapshotError(arg(0)?),
+            "FUNDER_REGISTRY_UNKNOWN_DOI" => ThothError::FunderRegistryUnknownDoiError(arg(0)?),
+            "FUNDER_REGISTRY_REPLACED_DOI" => {
+                ThothError::FunderRegistryReplacedDoiError(arg(0)?, arg(1)?)
+            }
+            "INVALID_CONFIGURATION" => ThothError::InvalidConfiguration(arg(0)?),
+            _ => return None,
+        };
+        Some(error)
+    }
+
+    /// Values interpolated into the error message, in the order they appear; for a database
+    /// constraint violation, the name of the constraint
+    pub fn arguments(&self) -> Vec<String> {
+        match self {
+            ThothError::InvalidSubjectCode {
+                input,
+                subject_type,
+            } => vec![input.clone(), subject_type.clone()],
+            ThothError::DatabaseConstraintError { constraint, .. } => vec![constraint.to_string()],
+            ThothError::DatabaseError(arg)
+            | ThothError::RedisError(arg)
+            | ThothError::InternalError(arg)
+            | ThothError::InvalidMetadataSpecification(arg)
+            | ThothError::CsvError(arg)
+            | ThothError::MarcError(arg)
+            | ThothError::OrcidParseError(arg)
+            | ThothError::DoiParseError(arg)
+            | ThothError::IsbnParseError(arg)
+            | ThothError::RorParseError(arg)
+            | ThothError::RequestError(arg)
+            | ThothError::GraphqlError(arg)
+            | ThothError::FileValidationFailed(arg)
+            | ThothError::UnrecognisedCreativeCommonsLicense(arg)
+            | ThothError::AudienceRangeValueError(arg)
+            | ThothError::ProductFormDetailCodeError(arg)
+            | ThothError::ProductFormFeatureError(arg)
+            | ThothError::WorkDateError(arg)
+            | ThothError::NameVariantScriptError(arg)
+            | ThothError::FunderRegistrySnapshotError(arg)
+            | ThothError::FunderRegistryUnknownDoiError(arg)
+            | ThothError::InvalidConfiguration(arg) => vec![arg.clone()],
+            ThothError::IncompleteMetadataRecord(first, second)
+            | ThothError::WorkStatusMissingDateError(first, second)
+            | ThothError::FunderRegistryReplacedDoiError(first, second) => {
+                vec![first.clone(), second.clone()]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// HTTP-like category of the error
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            ThothError::DatabaseConstraintError { constraint, .. } => {
+                if is_unique_constraint(constraint) {
+                    ErrorCategory::Conflict
+                } else {
+                    ErrorCategory::Unprocessable
+                }
+            }
+            ThothError::InvalidSubjectCode { .. }
+            | ThothError::InvalidMetadataSpecification(..)
+            | ThothError::InvalidUuid
+            | ThothError::InvalidTimestamp
+            | ThothError::OrcidParseError(..)
+            | ThothError::DoiParseError(..)
+            | ThothError::IsbnParseError(..)
+            | ThothError::RorParseError(..)
+            | ThothError::OrcidEmptyError
+            | ThothError::DoiEmptyError
+            | ThothError::IsbnEmptyError
+            | ThothError::RorEmptyError
+            | ThothError::RequestError(..)
+            | ThothError::NoFileExtensionFound
+            | ThothError::UnsupportedFileFormatError
+            | ThothError::TagNotFoundError
+            | ThothError::TitleMultipleTopLevelElementsError
+            | ThothError::TitleListItemError
+            | ThothError::MissingMarkupFormat
+            | ThothError::InvalidFileExtension
+            | ThothError::InvalidFileMimeType
+            | ThothError::FunderRegistrySnapshotError(..) => ErrorCategory::BadRequest,
+            ThothError::Unauthorised | ThothError::InvalidToken => ErrorCategory::Unauthenticated,
+            ThothError::ThothLocationError
+            | ThothError::ThothUpdateCanonicalError
+            | ThothError::ThothSetWorkStatusError
+            | ThothError::ThothDeleteWorkError
+            | ThothError::ThothDeletePublicationError => ErrorCategory::Forbidden,
+            ThothError::EntityNotFound
+            | ThothError::IncompleteMetadataRecord(..)
+            | ThothError::MetadataRecordNotGenerated => ErrorCategory::NotFound,
+            ThothError::CanonicalAbstractExistsError
+            | ThothError::CanonicalBiographyExistsError
+            | ThothError::CanonicalTitleExistsError => ErrorCategory::Conflict,
+            ThothError::DatabaseError(..)
+            | ThothError::RedisError(..)
+            | ThothError::InternalError(..)
+            | ThothError::CsvError(..)
+            | ThothError::MarcError(..)
+            | ThothError::GraphqlError(..)
+            | ThothError::InvalidConfiguration(..) => ErrorCategory::Internal,
+            ThothError::IssueImprintsError
+            | ThothError::ChapterIsbnError
+            | ThothError::ChapterBookMetadataError
+            | ThothError::ChapterDimensionError
+            | ThothError::CanonicalLocationError
+            | ThothError::LocationUrlError
+            | ThothError::WeightEmptyError
+            | ThothError::WidthEmptyError
+            | ThothError::HeightEmptyError
+            | ThothError::DepthEmptyError
+            | ThothError::DimensionDigitalError
+            | ThothError::PriceZeroError
+            | ThothError::PublicationDateError
+            | ThothError::WithdrawnDateBeforePublicationDateError
+            | ThothError::WithdrawnDateError
+            | ThothError::NoWithdrawnDateError
+            | ThothError::ShortAbstractLimitExceedError
+            | ThothError::FileTooSmall
+            | ThothError::FileTooLarge
+            | ThothError::UnsupportedPublicationTypeForFileUpload
+            | ThothError::UnsupportedResourceTypeForFileUpload
+            | ThothError::PublicationTypeRequiredForFileValidation
+            | ThothError::FileMissingWorkOrPublicationId
+            | ThothError::FileUploadMissingWorkOrPublicationId
+            | ThothError::WorkMissingDoiForFileUpload
+            | ThothError::PublicationFileUploadMissingPublicationId
+            | ThothError::FrontcoverFileUploadMissingWorkId
+            | ThothError::AdditionalResourceFileUploadMissingAdditionalResourceId
+            | ThothError::WorkFeaturedVideoFileUploadMissingWorkFeaturedVideoId
+            | ThothError::FileValidationFailed(..)
+            | ThothError::TerritoryEmptyCountriesError
+            | ThothError::TerritoryExcludedCountriesError
+            | ThothError::TerritoryDuplicateCountryError
+            | ThothError::PriceEffectiveDatesError
+            | ThothError::PublicationEmbargoEndDateError
+            | ThothError::UnrecognisedCreativeCommonsLicense(..)
+            | ThothError::AudienceRangeIncompleteError
+            | ThothError::AudienceRangePrecisionError
+            | ThothError::AudienceRangeValueError(..)
+            | ThothError::ProductFormDetailCodeError(..)
+            | ThothError::ProductFormFeatureError(..)
+            | ThothError::WorkDateError(..)
+            | ThothError::WorkStatusMissingDateError(..)
+            | ThothError::NameVariantParentError
+            | ThothError::NameVariantScriptError(..)
+            | ThothError::NameVariantMissingScriptError
+            | ThothError::FunderRegistryUnknownDoiError(..)
+            | ThothError::FunderRegistryReplacedDoiError(..) => ErrorCategory::Unprocessable,
+        }
+    }
+
+    /// Name of the input field at fault, where known, e.g. `grantNumber`
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            ThothError::DatabaseConstraintError { constraint, .. } => constraint_field(constraint),
+            ThothError::InvalidSubjectCode { .. } => Some("subjectCode"),
+            ThothError::IssueImprintsError => Some("seriesId"),
+            ThothError::ChapterIsbnError => Some("isbn"),
+            ThothError::LocationUrlError => Some("fullTextUrl"),
+            ThothError::PriceZeroError => Some("unitPrice"),
+            ThothError::PublicationDateError => Some("publicationDate"),
+            ThothError::WithdrawnDateBeforePublicationDateError
+            | ThothError::WithdrawnDateError
+            | ThothError::NoWithdrawnDateError => Some("withdrawnDate"),
+            ThothError::ThothLocationError => Some("locationPlatform"),
+            ThothError::ThothSetWorkStatusError | ThothError::WorkStatusMissingDateError(..) => {
+                Some("workStatus")
+            }
+            ThothError::CanonicalAbstractExistsError
+            | ThothError::CanonicalBiographyExistsError
+            | ThothError::CanonicalTitleExistsError => Some("canonical"),
+            ThothError::ShortAbstractLimitExceedError => Some("content"),
+            ThothError::MissingMarkupFormat => Some("markupFormat"),
+            ThothError::TerritoryEmptyCountriesError => Some("countriesIncluded"),
+            ThothError::TerritoryExcludedCountriesError => Some("countriesExcluded"),
+            ThothError::PriceEffectiveDatesError => Some("effectiveUntil"),
+            ThothError::PublicationEmbargoEndDateError => Some("embargoEndDate"),
+            ThothError::UnrecognisedCreativeCommonsLicense(..) => Some("license"),
+            ThothError::AudienceRangeIncompleteError => Some("audienceRangeQualifier"),
+            ThothError::AudienceRangePrecisionError => Some("audienceRangePrecision"),
+            ThothError::ProductFormDetailCodeError(..) => Some("detailCode"),
+            ThothError::NameVariantScriptError(..) | ThothError::NameVariantMissingScriptError => {
+                Some("scriptCode")
+            }
+            ThothError::FunderRegistryUnknownDoiError(..)
+            | ThothError::FunderRegistryReplacedDoiError(..) => Some("institutionDoi"),
+            _ => None,
+        }
+    }
+
+    /// Message safe to show to API clients: details of database and Redis failures are
+    /// withheld
+    pub fn public_message(&self) -> String {
+        match self {
+            ThothError::DatabaseError(_) => "DB error".to_string(),
+            ThothError::RedisError(_) => "Redis error".to_string(),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Machine-readable description of the error, as returned in export server error bodies
+    pub fn to_error_body(&self) -> ErrorBody {
+        ErrorBody {
+            code: self.code().to_string(),
+            category: self.category(),
+            message: self.public_message(),
+            field: self.field().map(str::to_string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::body::MessageBody;
+    use actix_web::error::ResponseError;
+    use juniper::{DefaultScalarValue, IntoFieldError, Value};
+    use std::borrow::Cow;
+
+    fn constraint_error(constraint: &'static str, message: &'static str) -> ThothError {
+        ThothError::DatabaseConstraintError {
+            constraint: Cow::Borrowed(constraint),
+            message: Cow::Borrowed(message),
+        }
+    }
+
+    #[test]
+    fn test_unique_constraint_is_a_conflict() {
+        let error = constraint_error(
+            "contribution_contribution_ordinal_work_id_uniq",
+            "A contribution with this ordinal number already exists.",
+        );
+        assert_eq!(error.code(), "DATABASE_CONSTRAINT_VIOLATION");
+        assert_eq!(error.category(), ErrorCategory::Conflict);
+        assert_eq!(error.field(), Some("contributionOrdinal"));
+    }
+
+    #[test]
+    fn test_check_constraint_is_unprocessable() {
+        let error = constraint_error("work_place_check", "Place must not be an empty string.");
+        assert_eq!(error.category(), ErrorCategory::Unprocessable);
+        assert_eq!(error.field(), Some("place"));
+    }
+
+    #[test]
+    fn test_codes_are_stable() {
+        assert_eq!(ThothError::Unauthorised.code(), "UNAUTHORISED");
+        assert_eq!(ThothError::EntityNotFound.code(), "ENTITY_NOT_FOUND");
+        assert_eq!(
+            ThothError::DoiParseError("10.1234".to_string()).code(),
+            "DOI_PARSE"
+        );
+        assert_eq!(
+            ThothError::ShortAbstractLimitExceedError.code(),
+            "SHORT_ABSTRACT_LIMIT_EXCEEDED"
+        );
+        assert_eq!(
+            ThothError::DatabaseError("boom".to_string()).code(),
+            "DATABASE_ERROR"
+        );
+    }
+
+    #[test]
+    fn test_categories() {
+        assert_eq!(
+            ThothError::InvalidUuid.category(),
+            ErrorCategory::BadRequest
+        );
+        assert_eq!(
+            ThothError::InvalidToken.category(),
+            ErrorCategory::Unauthenticated
+        );
+        assert_eq!(
+            ThothError::ThothDeleteWorkError.category(),
+            ErrorCategory::Forbidden
+        );
+        assert_eq!(
+            ThothError::MetadataRecordNotGenerated.category(),
+            ErrorCategory::NotFound
+        );
+        assert_eq!(
+            ThothError::CanonicalTitleExistsError.category(),
+            ErrorCategory::Conflict
+        );
+        assert_eq!(
+            ThothError::PriceZeroError.category(),
+            ErrorCategory::Unprocessable
+        );
+        assert_eq!(
+            ThothError::RedisError("down".to_string()).category(),
+            ErrorCategory::Internal
+        );
+    }
+
+    #[test]
+    fn test_field_paths() {
+        assert_eq!(
+            ThothError::PublicationDateError.field(),
+            Some("publicationDate")
+        );
+        assert_eq!(
+            ThothError::FunderRegistryUnknownDoiError("10.13039/000".to_string()).field(),
+            Some("institutionDoi")
+        );
+        assert_eq!(ThothError::InvalidUuid.field(), None);
+    }
+
+    #[test]
+    fn test_graphql_extensions() {
+        let error: juniper::FieldError<DefaultScalarValue> =
+            ThothError::PublicationDateError.into_field_error();
+        assert_eq!(
+            error.message(),
+            "Publication Date is required for Active, Withdrawn, and Superseded Works."
+        );
+        let extensions = error.extensions().as_object_value().unwrap();
+        assert_eq!(
+            extensions.get_field_value("code"),
+            Some(&Value::scalar("PUBLICATION_DATE"))
+        );
+        assert_eq!(
+            extensions.get_field_value("category"),
+            Some(&Value::scalar("UNPROCESSABLE"))
+        );
+        assert_eq!(
+            extensions.get_field_value("status"),
+            Some(&Value::scalar(422))
+        );
+        assert_eq!(
+            extensions.get_field_value("field"),
+            Some(&Value::scalar("publicationDate"))
+        );
+        assert_eq!(
+            extensions.get_field_value("type"),
+            Some(&Value::scalar("INTERNAL_ERROR"))
+        );
+    }
+
+    #[test]
+    fn test_graphql_extensions_omit_unknown_field() {
+        let error: juniper::FieldError<DefaultScalarValue> =
+            ThothError::Unauthorised.into_field_error();
+        assert_eq!(error.message(), "Unauthorized");
+        let extensions = error.extensions().as_object_value().unwrap();
+        assert!(extensions.get_field_value("field").is_none());
+        assert_eq!(
+            extensions.get_field_value("type"),
+            Some(&Value::scalar("NO_ACCESS"))
+        );
+    }
+
+    #[test]
+    fn test_from_code_round_trip() {
+        let errors = [
+            ThothError::Unauthorised,
+            ThothError::PublicationDateError,
+            ThothError::DoiParseError("10.1234".to_string()),
+            ThothError::WorkStatusMissingDateError(
+                "Withdrawn".to_string(),
+                "Withdrawn".to_string(),
+            ),
+            ThothError::InvalidSubjectCode {
+                input: "002".to_string(),
+                subject_type: "BIC".to_string(),
+            },
+            constraint_error("doi_uniq_idx", "A work with this DOI already exists."),
+        ];
+        for error in errors {
+            assert_eq!(
+                ThothError::from_code(error.code(), &error.arguments()),
+                Some(error)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_code_rejects_unknown_code_or_missing_arguments() {
+        assert_eq!(ThothError::from_code("NOT_A_CODE", &[]), None);
+        assert_eq!(ThothError::from_code("DOI_PARSE", &[]), None);
+        assert_eq!(
+            ThothError::from_code(
+                "DATABASE_CONSTRAINT_VIOLATION",
+                &["not_a_constraint".to_string()]
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_graphql_extensions_include_arguments() {
+        let error: juniper::FieldError<DefaultScalarValue> =
+            ThothError::DoiParseError("10.1234".to_string()).into_field_error();
+        let extensions = error.extensions().as_object_value().unwrap();
+        assert_eq!(
+            extensions.get_field_value("arguments"),
+            Some(&Value::list(vec![Value::scalar("10.1234")]))
+        );
+    }
+
+    #[test]
+    fn test_response_error_body() {
+        let error = ThothError::DatabaseError("relation does not exist".to_string());
+        assert_eq!(error.status_code().as_u16(), 500);
+        let body = error.error_response().into_body().try_into_bytes().unwrap();
+        let body: ErrorBody = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            body,
+            ErrorBody {
+                code: "DATABASE_ERROR".to_string(),
+                category: ErrorCategory::Internal,
+                message: "DB error".to_string(),
+                field: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_response_error_status_follows_category() {
+        assert_eq!(ThothError::InvalidUuid.status_code().as_u16(), 400);
+        assert_eq!(ThothError::EntityNotFound.status_code().as_u16(), 404);
+        assert_eq!(ThothError::Unauthorised.status_code().as_u16(), 401);
+    }
+}
@@ -1,10 +1,15 @@
 mod database_errors;
+mod error_codes;
+mod locales;
 
 use core::convert::From;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use thiserror::Error;
 
+pub use error_codes::{ErrorBody, ErrorCategory};
+pub use locales::Locale;
+
 /// A specialised result type for returning Thoth data
 pub type ThothResult<T> = Result<T, ThothError>;
 
@@ -20,8 +25,11 @@ pub enum ThothError {
     DatabaseError(String),
     #[error("Redis error: {0}")]
     RedisError(String),
-    #[error("{0}")]
-    DatabaseConstraintError(Cow<'static, str>),
+    #[error("{message}")]
+    DatabaseConstraintError {
+        constraint: Cow<'static, str>,
+        message: Cow<'static, str>,
+    },
     #[error("Internal error: {0}")]
     InternalError(String),
     #[error("Invalid credentials.")]
@@ -162,6 +170,48 @@ pub enum ThothError {
     AdditionalResourceFileUploadMissingAdditionalResourceId,
     #[error("Work featured video file upload missing work_featured_video_id")]
     WorkFeaturedVideoFileUploadMissingWorkFeaturedVideoId,
+    #[error("File failed validation: {0}")]
+    FileValidationFailed(String),
+    #[error("A territory must list at least one country, or omit included countries to indicate worldwide.")]
+    TerritoryEmptyCountriesError,
+    #[error("Excluded countries can only be specified for worldwide territories.")]
+    TerritoryExcludedCountriesError,
+    #[error("A country cannot appear more than once in a territory.")]
+    TerritoryDuplicateCountryError,
+    #[error("Price Effective Until date must not be earlier than its Effective From date.")]
+    PriceEffectiveDatesError,
+    #[error("An embargo end date must be given for embargoed publications, and only for them.")]
+    PublicationEmbargoEndDateError,
+    #[error("Unrecognised Creative Commons license URL: {0}")]
+    UnrecognisedCreativeCommonsLicense(String),
+    #[error("Audience range qualifier and precision must be supplied together, and range values require both.")]
+    AudienceRangeIncompleteError,
+    #[error("Audience range values do not match the precision: Exact requires only a From value, From requires a From value, and To requires only a To value.")]
+    AudienceRangePrecisionError,
+    #[error("Invalid audience range: {0}")]
+    AudienceRangeValueError(String),
+    #[error("Invalid product form detail: {0}")]
+    ProductFormDetailCodeError(String),
+    #[error("Invalid product form feature: {0}")]
+    ProductFormFeatureError(String),
+    #[error("Invalid work date: {0}")]
+    WorkDateError(String),
+    #[error("{0} Works must have a date of type {1}.")]
+    WorkStatusMissingDateError(String, String),
+    #[error("A name variant must belong to either a contributor or a contribution, but not both.")]
+    NameVariantParentError,
+    #[error("Unsupported ISO 15924 script code: {0}")]
+    NameVariantScriptError(String),
+    #[error("A transliterated name variant must specify the script in which it is written.")]
+    NameVariantMissingScriptError,
+    #[error("Invalid Funder Registry snapshot: {0}")]
+    FunderRegistrySnapshotError(String),
+    #[error("{0} is not a funder DOI listed in the Crossref Funder Registry.")]
+    FunderRegistryUnknownDoiError(String),
+    #[error("Funder DOI {0} has been replaced by {1} in the Crossref Funder Registry.")]
+    FunderRegistryReplacedDoiError(String, String),
+    #[error("Invalid configuration: {0}")]
+    InvalidConfiguration(String),
 }
 
 impl ThothError {
@@ -176,54 +226,52 @@ impl ThothError {
     }
 }
 
-impl juniper::IntoFieldError for ThothError {
-    fn into_field_error(self) -> juniper::FieldError {
-        use juniper::graphql_value;
-        match self {
-            ThothError::InvalidSubjectCode { .. } => juniper::FieldError::new(
-                self.to_string(),
-                graphql_value!({
-                    "type": "INVALID_SUBJECT_CODE"
-                }),
-            ),
-            ThothError::Unauthorised => juniper::FieldError::new(
-                "Unauthorized",
-                graphql_value!({
-                    "type": "NO_ACCESS"
-                }),
-            ),
-            _ => juniper::FieldError::new(
-                self.to_string(),
-                graphql_value!({
-                    "type": "INTERNAL_ERROR"
-                }),
-            ),
+impl<S: juniper::ScalarValue> juniper::IntoFieldError<S> for ThothError {
+    /// Surface the error's stable code, category, HTTP-like status and, where known, the
+    /// input field at fault in the GraphQL `extensions`, together with the message
+    /// `arguments` needed to rebuild the error with [`ThothError::from_code`]. The legacy
+    /// `type` is kept for clients written against earlier versions. The message is given in
+    /// the current [`Locale`].
+    fn into_field_error(self) -> juniper::FieldError<S> {
+        use juniper::{Object, Value};
+        let legacy_type = match self {
+            ThothError::InvalidSubjectCode { .. } => "INVALID_SUBJECT_CODE",
+            ThothError::Unauthorised => "NO_ACCESS",
+            _ => "INTERNAL_ERROR",
+        };
+        let message = match (&self, Locale::current()) {
+            (ThothError::Unauthorised, Locale::En) => "Unauthorized".to_string(),
+            (_, locale) => self.localised_message(locale),
+        };
+        let category = self.category();
+
+        let mut extensions = Object::with_capacity(6);
+        extensions.add_field("type", Value::scalar(legacy_type.to_string()));
+        extensions.add_field("code", Value::scalar(self.code().to_string()));
+        extensions.add_field("category", Value::scalar(category.as_str().to_string()));
+        extensions.add_field("status", Value::scalar(i32::from(category.status_code())));
+        if let Some(field) = self.field() {
+            extensions.add_field("field", Value::scalar(field.to_string()));
         }
+        let arguments = self.arguments();
+        if !arguments.is_empty() {
+            extensions.add_field(
+                "arguments",
+                Value::list(arguments.into_iter().map(Value::scalar).collect()),
+            );
+        }
+        juniper::FieldError::new(message, Value::Object(extensions))
     }
 }
 
 impl actix_web::error::ResponseError for ThothError {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::from_u16(self.category().status_code())
+            .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
     fn error_response(&self) -> actix_web::HttpResponse {
-        use actix_web::HttpResponse;
-        match self {
-            ThothError::Unauthorised | ThothError::InvalidToken => {
-                HttpResponse::Unauthorized().json(self.to_string())
-            }
-            ThothError::EntityNotFound => HttpResponse::NotFound().json(self.to_string()),
-            ThothError::InvalidMetadataSpecification(_) | ThothError::InvalidUuid => {
-                HttpResponse::BadRequest().json(self.to_string())
-            }
-            ThothError::DatabaseError { .. } => {
-                HttpResponse::InternalServerError().json("DB error")
-            }
-            ThothError::RedisError { .. } => {
-                HttpResponse::InternalServerError().json("Redis error")
-            }
-            ThothError::IncompleteMetadataRecord(_, _) => {
-                HttpResponse::NotFound().json(self.to_string())
-            }
-            _ => HttpResponse::InternalServerError().json(self.to_string()),
-        }
+        actix_web::HttpResponse::build(self.status_code()).json(self.to_error_body())
     }
 }
 
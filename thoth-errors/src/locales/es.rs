@@ -0,0 +1,285 @@
+use phf::{phf_map, Map};
+
+/// Spanish translations of error messages, keyed by error code
+pub(super) static MESSAGES: Map<&'static str, &'static str> = phf_map! {
+    "INVALID_SUBJECT_CODE" => "{0} no es un código {1} válido",
+    "DATABASE_ERROR" => "Error de base de datos: {0}",
+    "REDIS_ERROR" => "Error de Redis: {0}",
+    "INTERNAL_ERROR" => "Error interno: {0}",
+    "UNAUTHORISED" => "Credenciales no válidas.",
+    "INVALID_TOKEN" => "No se pudo validar el token.",
+    "ENTITY_NOT_FOUND" => "No se encontró ningún registro con el ID indicado.",
+    "ISSUE_IMPRINTS" => "La obra y la serie de un número no pueden tener sellos editoriales distintos.",
+    "INVALID_METADATA_SPECIFICATION" => "{0} no es una especificación de metadatos válida",
+    "INVALID_UUID" => "El UUID indicado no es válido.",
+    "INVALID_TIMESTAMP" => "La marca de tiempo indicada no es válida.",
+    "CSV_ERROR" => "Error de CSV: {0}",
+    "MARC_ERROR" => "Error de MARC: {0}",
+    "INCOMPLETE_METADATA_RECORD" => "No se pudo generar {0}: {1}",
+    "METADATA_RECORD_NOT_GENERATED" => "El registro de metadatos aún no se ha generado.",
+    "ORCID_PARSE" => "{0} no es un ORCID con un formato válido y no se guardará",
+    "DOI_PARSE" => "{0} no es un DOI con un formato válido y no se guardará",
+    "ISBN_PARSE" => "{0} no es un ISBN con un formato válido y no se guardará",
+    "ROR_PARSE" => "{0} no es un ID de ROR con un formato válido y no se guardará",
+    "ORCID_EMPTY" => "No se puede analizar el ORCID: no se indicó ningún valor",
+    "DOI_EMPTY" => "No se puede analizar el DOI: no se indicó ningún valor",
+    "ISBN_EMPTY" => "No se puede analizar el ISBN: no se indicó ningún valor",
+    "ROR_EMPTY" => "No se puede analizar el ID de ROR: no se indicó ningún valor",
+    "CHAPTER_ISBN" => "Las obras de tipo capítulo de libro no pueden tener ISBN en sus publicaciones.",
+    "CHAPTER_BOOK_METADATA" => "Las obras de tipo capítulo de libro no pueden tener registros de metadatos de nivel de libro.",
+    "CHAPTER_DIMENSION" => "Las obras de tipo capítulo de libro no pueden tener anchura, altura, profundidad ni peso en sus publicaciones.",
+    "CANONICAL_LOCATION" => "Cada publicación debe tener exactamente una ubicación canónica.",
+    "LOCATION_URL" => "Las ubicaciones canónicas de las publicaciones digitales deben tener tanto una página de destino como una URL de texto completo.",
+    "WEIGHT_EMPTY" => "Al indicar el peso, deben indicarse ambos valores (g y oz).",
+    "WIDTH_EMPTY" => "Al indicar la anchura, deben indicarse ambos valores (mm y in).",
+    "HEIGHT_EMPTY" => "Al indicar la altura, deben indicarse ambos valores (mm y in).",
+    "DEPTH_EMPTY" => "Al indicar la profundidad, deben indicarse ambos valores (mm y in).",
+    "DIMENSION_DIGITAL" => "La anchura, altura, profundidad y peso solo se aplican a publicaciones físicas (rústica o tapa dura).",
+    "PRICE_ZERO" => "Los precios deben ser mayores que cero. Para indicar una publicación sin precio, omita todos los precios.",
+    "PUBLICATION_DATE" => "La fecha de publicación es obligatoria para las obras activas, retiradas y reemplazadas.",
+    "REQUEST_ERROR" => "{0}",
+    "GRAPHQL_ERROR" => "{0}",
+    "WITHDRAWN_DATE_BEFORE_PUBLICATION_DATE" => "La fecha de retirada debe ser posterior a la fecha de publicación.",
+    "WITHDRAWN_DATE" => "La fecha de retirada solo puede añadirse a una obra reemplazada o retirada.",
+    "NO_WITHDRAWN_DATE" => "Una obra reemplazada o retirada debe tener fecha de retirada.",
+    "THOTH_LOCATION" => "Solo los superusuarios pueden crear, editar o eliminar ubicaciones cuya plataforma sea Thoth.",
+    "THOTH_UPDATE_CANONICAL" => "Solo los superusuarios pueden actualizar la ubicación canónica cuando la plataforma Thoth ya es la canónica.",
+    "THOTH_SET_WORK_STATUS" => "Una vez publicada, una obra no puede despublicarse. Utilice en su lugar el estado retirada o reemplazada.",
+    "THOTH_DELETE_WORK" => "Una vez publicada, una obra no puede eliminarse.",
+    "THOTH_DELETE_PUBLICATION" => "No pueden eliminarse las publicaciones de una obra publicada.",
+    "CANONICAL_ABSTRACT_EXISTS" => "Si ya existe un resumen canónico, no puede marcarse otro resumen como canónico.",
+    "SHORT_ABSTRACT_LIMIT_EXCEEDED" => "El resumen breve debe tener menos de 350 caracteres.",
+    "CANONICAL_BIOGRAPHY_EXISTS" => "Si ya existe una biografía canónica, no puede marcarse otra biografía como canónica.",
+    "CANONICAL_TITLE_EXISTS" => "Si ya existe un título canónico, no puede marcarse otro título como canónico.",
+    "NO_FILE_EXTENSION_FOUND" => "Si no se encuentra la extensión del archivo, el formato del archivo no es compatible.",
+    "UNSUPPORTED_FILE_FORMAT" => "Formato de archivo no compatible",
+    "TAG_NOT_FOUND" => "No se encontró la etiqueta de contenido",
+    "TITLE_MULTIPLE_TOP_LEVEL_ELEMENTS" => "El contenido del título no puede contener varios elementos de nivel superior.",
+    "TITLE_LIST_ITEM" => "El contenido del título no puede contener elementos de lista.",
+    "MISSING_MARKUP_FORMAT" => "No se indicó el formato de marcado.",
+    "INVALID_FILE_EXTENSION" => "Extensión de archivo no válida",
+    "INVALID_FILE_MIME_TYPE" => "Tipo MIME de archivo no válido",
+    "FILE_TOO_SMALL" => "El tamaño del archivo es inferior al mínimo permitido",
+    "FILE_TOO_LARGE" => "El tamaño del archivo supera el máximo permitido",
+    "UNSUPPORTED_PUBLICATION_TYPE_FOR_FILE_UPLOAD" => "No se admite la subida de archivos para este tipo de publicación",
+    "UNSUPPORTED_RESOURCE_TYPE_FOR_FILE_UPLOAD" => "No se admite la subida de archivos para este tipo de recurso adicional",
+    "PUBLICATION_TYPE_REQUIRED_FOR_FILE_VALIDATION" => "Se requiere el tipo de publicación para validar el archivo de la publicación",
+    "FILE_MISSING_WORK_OR_PUBLICATION_ID" => "El archivo debe hacer referencia a exactamente un ámbito: work_id, publication_id, additional_resource_id o work_featured_video_id",
+    "FILE_UPLOAD_MISSING_WORK_OR_PUBLICATION_ID" => "La subida de archivo debe hacer referencia a exactamente un ámbito: work_id, publication_id, additional_resource_id o work_featured_video_id",
+    "WORK_MISSING_DOI_FOR_FILE_UPLOAD" => "La obra debe tener un DOI para subir archivos",
+    "PUBLICATION_FILE_UPLOAD_MISSING_PUBLICATION_ID" => "Falta publication_id en la subida del archivo de la publicación",
+    "FRONTCOVER_FILE_UPLOAD_MISSING_WORK_ID" => "Falta work_id en la subida de la cubierta",
+    "ADDITIONAL_RESOURCE_FILE_UPLOAD_MISSING_ADDITIONAL_RESOURCE_ID" => "Falta additional_resource_id en la subida del archivo del recurso adicional",
+    "WORK_FEATURED_VIDEO_FILE_UPLOAD_MISSING_WORK_FEATURED_VIDEO_ID" => "Falta work_featured_video_id en la subida del vídeo destacado de la obra",
+    "FILE_VALIDATION_FAILED" => "El archivo no superó la validación: {0}",
+    "TERRITORY_EMPTY_COUNTRIES" => "Un territorio debe incluir al menos un país, u omitir los países incluidos para indicar todo el mundo.",
+    "TERRITORY_EXCLUDED_COUNTRIES" => "Los países excluidos solo pueden indicarse en territorios mundiales.",
+    "TERRITORY_DUPLICATE_COUNTRY" => "Un país no puede aparecer más de una vez en un territorio.",
+    "PRICE_EFFECTIVE_DATES" => "La fecha de fin de vigencia del precio no puede ser anterior a su fecha de inicio.",
+    "PUBLICATION_EMBARGO_END_DATE" => "Debe indicarse una fecha de fin de embargo para las publicaciones embargadas, y solo para ellas.",
+    "UNRECOGNISED_CREATIVE_COMMONS_LICENSE" => "URL de licencia Creative Commons no reconocida: {0}",
+    "AUDIENCE_RANGE_INCOMPLETE" => "El calificador y la precisión del rango de público deben indicarse juntos, y los valores del rango requieren ambos.",
+    "AUDIENCE_RANGE_PRECISION" => "Los valores del rango de público no coinciden con la precisión: Exacto requiere solo un valor Desde, Desde requiere un valor Desde y Hasta requiere solo un valor Hasta.",
+    "AUDIENCE_RANGE_VALUE" => "Rango de público no válido: {0}",
+    "PRODUCT_FORM_DETAIL_CODE" => "Detalle de forma de producto no válido: {0}",
+    "PRODUCT_FORM_FEATURE" => "Característica de forma de producto no válida: {0}",
+    "WORK_DATE" => "Fecha de obra no válida: {0}",
+    "WORK_STATUS_MISSING_DATE" => "Las obras con estado {0} deben tener una fecha de tipo {1}.",
+    "NAME_VARIANT_PARENT" => "Una variante de nombre debe pertenecer a un colaborador o a una contribución, pero no a ambos.",
+    "NAME_VARIANT_SCRIPT" => "Código de escritura ISO 15924 no admitido: {0}",
+    "NAME_VARIANT_MISSING_SCRIPT" => "Una variante de nombre transliterada debe indicar la escritura en la que está escrita.",
+    "FUNDER_REGISTRY_SNAPSHOT" => "Instantánea del Funder Registry no válida: {0}",
+    "FUNDER_REGISTRY_UNKNOWN_DOI" => "{0} no es un DOI de financiador registrado en el Crossref Funder Registry.",
+    "FUNDER_REGISTRY_REPLACED_DOI" => "El DOI de financiador {0} ha sido sustituido por {1} en el Crossref Funder Registry.",
+};
+
+/// Spanish translations of `DATABASE_CONSTRAINT_ERRORS`, keyed by constraint name
+pub(super) static CONSTRAINT_MESSAGES: Map<&'static str, &'static str> = phf_map! {
+    "affiliation_affiliation_ordinal_check" => "El número ordinal de una afiliación debe ser mayor que 0.",
+    "affiliation_position_check" => "El cargo no puede estar vacío.",
+    "affiliation_affiliation_ordinal_contribution_id_uniq" => "Ya existe una afiliación con este número ordinal.",
+    "abstract_content_check" => "El contenido del resumen no puede estar vacío.",
+    "abstract_uniq_locale_idx" => "Ya existe un resumen de la obra con este código de idioma y tipo.",
+    "abstract_unique_canonical_true_idx" => "Ya existe un resumen canónico para esta obra.",
+    "additional_resource_resource_ordinal_work_id_uniq" => "Ya existe un recurso adicional con este número ordinal.",
+    "biography_content_check" => "El contenido de la biografía no puede estar vacío.",
+    "biography_uniq_locale_idx" => "Ya existe una biografía de la contribución con este código de idioma.",
+    "biography_unique_canonical_true_idx" => "Ya existe una biografía canónica para esta contribución.",
+    "audience_range_check" => "El calificador y la precisión del rango de público deben indicarse juntos, y los valores del rango requieren ambos.",
+    "audience_range_from_check" => "El valor Desde del rango de público no puede estar vacío.",
+    "audience_range_precision_check" => "Los valores del rango de público no coinciden con la precisión: Exacto requiere solo un valor Desde, Desde requiere un valor Desde y Hasta requiere solo un valor Hasta.",
+    "audience_range_to_check" => "El valor Hasta del rango de público no puede estar vacío.",
+    "audience_uniq_idx" => "Ya existe un público con este código y calificador de rango para esta obra.",
+    "award_award_ordinal_work_id_uniq" => "Ya existe un premio con este número ordinal.",
+    "book_review_review_ordinal_work_id_uniq" => "Ya existe una reseña con este número ordinal.",
+    "contribution_contribution_ordinal_check" => "El número ordinal de una contribución debe ser mayor que 0.",
+    "contribution_contribution_ordinal_work_id_uniq" => "Ya existe una contribución con este número ordinal.",
+    "contribution_first_name_check" => "El nombre no puede estar vacío.",
+    "contribution_full_name_check" => "El nombre completo no puede estar vacío.",
+    "contribution_last_name_check" => "Los apellidos no pueden estar vacíos.",
+    "contribution_work_id_contributor_id_contribution_type_uniq" => "Ya existe una contribución de este tipo para este colaborador.",
+    "contributor_first_name_check" => "El nombre no puede estar vacío.",
+    "contributor_full_name_check" => "El nombre completo no puede estar vacío.",
+    "contributor_last_name_check" => "Los apellidos no pueden estar vacíos.",
+    "contributor_orcid_check" => "ORCID no válido.",
+    "contributor_website_check" => "El sitio web no puede estar vacío.",
+    "credit_role_uniq_idx" => "Este rol CRediT ya se ha asignado a esta contribución.",
+    "doi_uniq_idx" => "Ya existe una obra con este DOI.",
+    "email_uniq_idx" => "Ya existe una cuenta con este correo electrónico.",
+    "endorsement_endorsement_ordinal_work_id_uniq" => "Ya existe un aval con este número ordinal.",
+    "funding_grant_number_check" => "El número de subvención no puede estar vacío.",
+    "funding_program_check" => "El programa no puede estar vacío.",
+    "funding_project_name_check" => "El nombre del proyecto no puede estar vacío.",
+    "funding_project_shortname_check" => "El nombre corto del proyecto no puede estar vacío.",
+    "imprint_imprint_name_check" => "El nombre del sello editorial no puede estar vacío.",
+    "imprint_imprint_url_check" => "URL no válida.",
+    "imprint_storage_cfg_all_or_none" => "La configuración de almacenamiento debe estar completa: todos los campos (bucket de S3, región, dominio de CDN, ID de distribución de CloudFront) deben indicarse juntos o dejarse todos vacíos.",
+    "imprint_uniq_idx" => "Ya existe un sello editorial con este nombre.",
+    "institution_doi_uniq_idx" => "Ya existe una institución con este DOI.",
+    "institution_institution_doi_check" => "DOI no válido.",
+    "institution_institution_name_check" => "El nombre no puede estar vacío.",
+    "institution_ror_check" => "ROR no válido.",
+    "issue_issue_ordinal_check" => "El número ordinal de un número de serie debe ser mayor que 0.",
+    "issue_series_id_work_id_uniq" => "Ya existe un número de la serie seleccionada para esta obra.",
+    "issue_issue_ordinal_series_id_uniq" => "Ya existe un número de serie con este número ordinal.",
+    "language_uniq_work_idx" => "Código de idioma duplicado.",
+    "location_full_text_url_check" => "URL no válida.",
+    "location_landing_page_check" => "URL no válida.",
+    "location_uniq_canonical_true_idx" => "Ya existe una ubicación canónica para esta publicación.",
+    "location_uniq_platform_idx" => "Ya existe una ubicación en la plataforma seleccionada.",
+    "location_url_check" => "Una ubicación debe tener una página de destino y/o una URL de texto completo.",
+    "name_variant_contribution_uniq_idx" => "Ya existe una variante de nombre de este tipo con este nombre para esta contribución.",
+    "name_variant_contributor_uniq_idx" => "Ya existe una variante de nombre de este tipo con este nombre para este colaborador.",
+    "name_variant_first_name_check" => "El nombre no puede estar vacío.",
+    "name_variant_full_name_check" => "El nombre completo no puede estar vacío.",
+    "name_variant_last_name_check" => "Los apellidos no pueden estar vacíos.",
+    "name_variant_parent_check" => "Una variante de nombre debe pertenecer a un colaborador o a una contribución, pero no a ambos.",
+    "name_variant_script_code_check" => "Un código de escritura debe ser un código ISO 15924 de cuatro letras, p. ej. Cyrl.",
+    "file_frontcover_work_unique_idx" => "Ya existe un archivo de cubierta para esta obra.",
+    "file_object_key_unique_idx" => "Ya existe un archivo con esta clave de objeto.",
+    "file_publication_unique_idx" => "Ya existe un archivo para esta publicación.",
+    "file_additional_resource_unique_idx" => "Ya existe un archivo para este recurso adicional.",
+    "file_work_featured_video_unique_idx" => "Ya existe un archivo para este vídeo destacado.",
+    "file_type_check" => "El tipo de archivo no es válido: frontcover debe tener work_id, publication debe tener publication_id, additional_resource debe tener additional_resource_id y work_featured_video debe tener work_featured_video_id.",
+    "file_upload_type_check" => "El tipo de subida de archivo no es válido: frontcover debe tener work_id, publication debe tener publication_id, additional_resource debe tener additional_resource_id y work_featured_video debe tener work_featured_video_id.",
+    "orcid_uniq_idx" => "Ya existe un colaborador con este ORCID.",
+    "price_countries_included_check" => "Un territorio debe incluir al menos un país, u omitir los países incluidos para indicar todo el mundo.",
+    "price_effective_dates_check" => "La fecha de fin de vigencia del precio no puede ser anterior a su fecha de inicio.",
+    "price_publication_id_currency_code_uniq" => "Ya existe un precio en esta moneda, territorio y tipo para esta publicación a partir de esta fecha.",
+    "price_unit_price_check" => "Los precios deben ser mayores que cero. Para indicar una publicación sin precio, omita todos los precios.",
+    "product_form_detail_detail_code_check" => "El código de detalle de forma de producto no puede estar vacío.",
+    "product_form_detail_feature_check" => "Los valores y descripciones de características solo se aplican a las características de forma de producto.",
+    "product_form_detail_feature_description_check" => "La descripción de la característica de forma de producto no puede estar vacía.",
+    "product_form_detail_feature_value_check" => "El valor de la característica de forma de producto no puede estar vacío.",
+    "product_form_detail_packaging_uniq_idx" => "Esta publicación ya tiene un tipo de embalaje.",
+    "product_form_detail_uniq_idx" => "Este detalle de forma de producto ya se ha añadido a la publicación.",
+    "publication_depth_in_check" => "La profundidad de la publicación debe ser mayor que 0.0.",
+    "publication_depth_in_not_missing" => "Al indicar la profundidad, deben indicarse ambos valores (mm y in).",
+    "publication_depth_mm_check" => "La profundidad de la publicación debe ser mayor que 0.0.",
+    "publication_depth_mm_not_missing" => "Al indicar la profundidad, deben indicarse ambos valores (mm y in).",
+    "publication_embargo_end_date_check" => "Debe indicarse una fecha de fin de embargo para las publicaciones embargadas, y solo para ellas.",
+    "publication_height_in_check" => "La altura de la publicación debe ser mayor que 0.0.",
+    "publication_height_in_not_missing" => "Al indicar la altura, deben indicarse ambos valores (mm y in).",
+    "publication_height_mm_check" => "La altura de la publicación debe ser mayor que 0.0.",
+    "publication_height_mm_not_missing" => "Al indicar la altura, deben indicarse ambos valores (mm y in).",
+    "publication_isbn_check" => "Un ISBN válido debe tener exactamente 17 caracteres.",
+    "publication_isbn_idx" => "Ya existe una publicación con este ISBN.",
+    "publication_license_check" => "URL de licencia no válida.",
+    "publication_non_physical_no_dimensions" => "La anchura, altura, profundidad y peso solo se aplican a publicaciones físicas (rústica o tapa dura).",
+    "publication_publication_type_work_id_uniq" => "Ya existe una publicación del tipo seleccionado.",
+    "publication_weight_g_check" => "El peso de la publicación debe ser mayor que 0.0.",
+    "publication_weight_g_not_missing" => "Al indicar el peso, deben indicarse ambos valores (g y oz).",
+    "publication_weight_oz_check" => "El peso de la publicación debe ser mayor que 0.0.",
+    "publication_weight_oz_not_missing" => "Al indicar el peso, deben indicarse ambos valores (g y oz).",
+    "publication_width_in_check" => "La anchura de la publicación debe ser mayor que 0.0.",
+    "publication_width_in_not_missing" => "Al indicar la anchura, deben indicarse ambos valores (mm y in).",
+    "publication_width_mm_check" => "La anchura de la publicación debe ser mayor que 0.0.",
+    "publication_width_mm_not_missing" => "Al indicar la anchura, deben indicarse ambos valores (mm y in).",
+    "publisher_publisher_name_check" => "El nombre de la editorial no puede estar vacío.",
+    "publisher_publisher_shortname_check" => "El nombre corto de la editorial no puede estar vacío.",
+    "publisher_publisher_url_check" => "URL no válida.",
+    "publisher_uniq_idx" => "Ya existe una editorial con este nombre.",
+    "reference_article_title_check" => "El título del artículo no puede estar vacío.",
+    "reference_author_check" => "El autor no puede estar vacío.",
+    "reference_component_number_check" => "El número de componente no puede estar vacío.",
+    "reference_doi_andor_unstructured_citation" => "Una referencia debe tener un DOI y/o la cita no estructurada.",
+    "reference_doi_check" => "DOI no válido.",
+    "reference_edition_check" => "El número de edición debe ser mayor que 0.",
+    "reference_first_page_check" => "La primera página no puede estar vacía.",
+    "reference_isbn_check" => "Un ISBN válido debe tener exactamente 17 caracteres.",
+    "reference_issn_check" => "ISSN no válido.",
+    "reference_issue_check" => "El número no puede estar vacío.",
+    "reference_journal_title_check" => "El título de la revista no puede estar vacío.",
+    "reference_reference_ordinal_check" => "El número ordinal de una referencia debe ser mayor que 0.",
+    "reference_reference_ordinal_work_id_uniq" => "Ya existe una referencia con este número ordinal.",
+    "reference_series_title_check" => "El título de la serie no puede estar vacío.",
+    "reference_standard_citation_required_fields" => "La cita de una norma debe incluir el identificador de la norma, así como el nombre y las siglas del organismo de normalización.",
+    "reference_standard_designator_check" => "El identificador de la norma no puede estar vacío.",
+    "reference_standards_body_acronym_check" => "Las siglas del organismo de normalización no pueden estar vacías.",
+    "reference_standards_body_name_check" => "El nombre del organismo de normalización no puede estar vacío.",
+    "reference_unstructured_citation_check" => "La cita no estructurada no puede estar vacía.",
+    "reference_url_check" => "URL no válida.",
+    "reference_volume_check" => "El volumen no puede estar vacío.",
+    "reference_volume_title_check" => "El título del volumen no puede estar vacío.",
+    "sales_rights_countries_excluded_check" => "Los países excluidos solo pueden indicarse en territorios mundiales.",
+    "sales_rights_countries_included_check" => "Un territorio debe incluir al menos un país, u omitir los países incluidos para indicar todo el mundo.",
+    "series_issn_digital_check" => "ISSN digital no válido.",
+    "series_issn_digital_idx" => "Ya existe una serie con este ISSN digital.",
+    "series_issn_print_check" => "ISSN impreso no válido.",
+    "series_issn_print_idx" => "Ya existe una serie con este ISSN impreso.",
+    "series_series_cfp_url_check" => "URL de convocatoria no válida.",
+    "series_series_description_check" => "La descripción de la serie no puede estar vacía.",
+    "series_series_name_check" => "El nombre de la serie no puede estar vacío.",
+    "series_series_url_check" => "URL de la serie no válida.",
+    "subject_ordinal_type_uniq" => "Ya existe una materia con este número ordinal y tipo.",
+    "subject_subject_code_check" => "Los códigos de materia no pueden estar vacíos.",
+    "subject_subject_ordinal_check" => "El número ordinal de una materia debe ser mayor que 0.",
+    "title_unique_canonical_true_idx" => "Ya existe un título canónico para esta obra.",
+    "title_full_title_check" => "El título completo no puede estar vacío.",
+    "title_subtitle_check" => "El subtítulo no puede estar vacío.",
+    "title_title_check" => "El título no puede estar vacío.",
+    "title_uniq_locale_idx" => "Ya existe un título de la obra con este código de idioma.",
+    "work_active_withdrawn_date_check" => "La fecha de retirada solo puede indicarse para una obra retirada o reemplazada.",
+    "work_active_publication_date_check" => "Las obras activas, reemplazadas y retiradas deben tener fecha de publicación.",
+    "work_audio_count_check" => "El número de audios debe ser mayor que 0.",
+    "work_bibliography_note_check" => "La nota bibliográfica no puede estar vacía.",
+    "work_chapter_no_edition" => "Los capítulos no pueden tener número de edición.",
+    "work_chapter_no_lccn" => "Los capítulos no pueden tener LCCN.",
+    "work_chapter_no_oclc" => "Los capítulos no pueden tener número OCLC.",
+    "work_chapter_no_toc" => "Los capítulos no pueden tener índice.",
+    "work_copyright_holder_check" => "El titular de los derechos de autor no puede estar vacío.",
+    "work_cover_caption_check" => "El pie de la cubierta no puede estar vacío.",
+    "work_cover_url_check" => "URL de la cubierta no válida.",
+    "work_date_uniq_idx" => "Ya existe una fecha de este tipo para esta obra.",
+    "work_doi_check" => "DOI no válido.",
+    "work_edition_check" => "El número de edición debe ser mayor que 0.",
+    "work_first_page_check" => "La primera página no puede estar vacía.",
+    "work_general_note_check" => "La nota general no puede estar vacía.",
+    "work_image_count_check" => "El número de imágenes debe ser mayor que 0.",
+    "work_inactive_no_withdrawn_date_check" => "Una obra retirada o reemplazada debe incluir la fecha de retirada.",
+    "work_landing_page_check" => "URL de la página de destino no válida.",
+    "work_last_page_check" => "La última página no puede estar vacía.",
+    "work_lccn_check" => "El LCCN no puede estar vacío.",
+    "work_license_check" => "URL de licencia no válida.",
+    "work_long_abstract_check" => "El resumen extenso no puede estar vacío.",
+    "work_non_chapter_has_edition" => "El número de edición es obligatorio (salvo para los capítulos).",
+    "work_non_chapter_no_first_page" => "La primera página solo puede indicarse para capítulos de libro.",
+    "work_non_chapter_no_last_page" => "La última página solo puede indicarse para capítulos de libro.",
+    "work_non_chapter_no_page_interval" => "El intervalo de páginas solo puede indicarse para capítulos de libro.",
+    "work_oclc_check" => "El número OCLC no puede estar vacío.",
+    "work_page_breakdown_check" => "El desglose de páginas no puede estar vacío.",
+    "work_page_count_check" => "El número de páginas debe ser mayor que 0.",
+    "work_page_interval_check" => "El intervalo de páginas no puede estar vacío.",
+    "work_place_check" => "El lugar no puede estar vacío.",
+    "work_reference_check" => "La referencia no puede estar vacía.",
+    "work_relation_ids_check" => "Una obra no puede estar relacionada consigo misma.",
+    "work_relation_ordinal_type_uniq" => "Ya existe una relación con este número ordinal y tipo.",
+    "work_relation_relation_ordinal_check" => "El número ordinal de una relación entre obras debe ser mayor que 0.",
+    "work_relation_relator_related_uniq" => "Ya existe una relación entre estas dos obras.",
+    "work_short_abstract_check" => "El resumen breve no puede estar vacío.",
+    "work_table_count_check" => "El número de tablas debe ser mayor que 0.",
+    "work_toc_check" => "El índice no puede estar vacío.",
+    "work_video_count_check" => "El número de vídeos debe ser mayor que 0.",
+    "work_withdrawn_date_after_publication_date_check" => "La fecha de publicación debe ser anterior a la fecha de retirada.",
+    "work_featured_video_work_id_key" => "Ya existe un vídeo destacado para esta obra.",
+};
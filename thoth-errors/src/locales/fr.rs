@@ -0,0 +1,285 @@
+use phf::{phf_map, Map};
+
+/// French translations of error messages, keyed by error code
+pub(super) static MESSAGES: Map<&'static str, &'static str> = phf_map! {
+    "INVALID_SUBJECT_CODE" => "{0} n'est pas un code {1} valide",
+    "DATABASE_ERROR" => "Erreur de base de données : {0}",
+    "REDIS_ERROR" => "Erreur Redis : {0}",
+    "INTERNAL_ERROR" => "Erreur interne : {0}",
+    "UNAUTHORISED" => "Identifiants non valides.",
+    "INVALID_TOKEN" => "Impossible de valider le jeton.",
+    "ENTITY_NOT_FOUND" => "Aucun enregistrement ne correspond à l'identifiant indiqué.",
+    "ISSUE_IMPRINTS" => "L'ouvrage et la collection d'un numéro ne peuvent pas avoir des marques éditoriales différentes.",
+    "INVALID_METADATA_SPECIFICATION" => "{0} n'est pas une spécification de métadonnées valide",
+    "INVALID_UUID" => "L'UUID fourni n'est pas valide.",
+    "INVALID_TIMESTAMP" => "L'horodatage fourni n'est pas valide.",
+    "CSV_ERROR" => "Erreur CSV : {0}",
+    "MARC_ERROR" => "Erreur MARC : {0}",
+    "INCOMPLETE_METADATA_RECORD" => "Impossible de générer {0} : {1}",
+    "METADATA_RECORD_NOT_GENERATED" => "La notice de métadonnées n'a pas encore été générée.",
+    "ORCID_PARSE" => "{0} n'est pas un ORCID au format valide et ne sera pas enregistré",
+    "DOI_PARSE" => "{0} n'est pas un DOI au format valide et ne sera pas enregistré",
+    "ISBN_PARSE" => "{0} n'est pas un ISBN au format valide et ne sera pas enregistré",
+    "ROR_PARSE" => "{0} n'est pas un identifiant ROR au format valide et ne sera pas enregistré",
+    "ORCID_EMPTY" => "Impossible d'analyser l'ORCID : aucune valeur fournie",
+    "DOI_EMPTY" => "Impossible d'analyser le DOI : aucune valeur fournie",
+    "ISBN_EMPTY" => "Impossible d'analyser l'ISBN : aucune valeur fournie",
+    "ROR_EMPTY" => "Impossible d'analyser l'identifiant ROR : aucune valeur fournie",
+    "CHAPTER_ISBN" => "Les ouvrages de type chapitre de livre ne peuvent pas avoir d'ISBN dans leurs publications.",
+    "CHAPTER_BOOK_METADATA" => "Les ouvrages de type chapitre de livre ne peuvent pas avoir de notices de métadonnées au niveau du livre.",
+    "CHAPTER_DIMENSION" => "Les ouvrages de type chapitre de livre ne peuvent pas avoir de largeur, hauteur, épaisseur ni poids dans leurs publications.",
+    "CANONICAL_LOCATION" => "Chaque publication doit avoir exactement un emplacement canonique.",
+    "LOCATION_URL" => "Les emplacements canoniques des publications numériques doivent avoir à la fois une page d'accueil et une URL de texte intégral.",
+    "WEIGHT_EMPTY" => "Lorsque le poids est indiqué, les deux valeurs (g et oz) doivent être fournies.",
+    "WIDTH_EMPTY" => "Lorsque la largeur est indiquée, les deux valeurs (mm et in) doivent être fournies.",
+    "HEIGHT_EMPTY" => "Lorsque la hauteur est indiquée, les deux valeurs (mm et in) doivent être fournies.",
+    "DEPTH_EMPTY" => "Lorsque l'épaisseur est indiquée, les deux valeurs (mm et in) doivent être fournies.",
+    "DIMENSION_DIGITAL" => "La largeur, la hauteur, l'épaisseur et le poids ne s'appliquent qu'aux publications physiques (broché ou relié).",
+    "PRICE_ZERO" => "Les prix doivent être supérieurs à zéro. Pour indiquer une publication sans prix, omettez tous les prix.",
+    "PUBLICATION_DATE" => "La date de publication est obligatoire pour les ouvrages actifs, retirés et remplacés.",
+    "REQUEST_ERROR" => "{0}",
+    "GRAPHQL_ERROR" => "{0}",
+    "WITHDRAWN_DATE_BEFORE_PUBLICATION_DATE" => "La date de retrait doit être postérieure à la date de publication.",
+    "WITHDRAWN_DATE" => "La date de retrait ne peut être ajoutée qu'à un ouvrage remplacé ou retiré.",
+    "NO_WITHDRAWN_DATE" => "Un ouvrage remplacé ou retiré doit avoir une date de retrait.",
+    "THOTH_LOCATION" => "Seuls les super-utilisateurs peuvent créer, modifier ou supprimer des emplacements dont la plateforme est Thoth.",
+    "THOTH_UPDATE_CANONICAL" => "Seuls les super-utilisateurs peuvent modifier l'emplacement canonique lorsque la plateforme Thoth est déjà canonique.",
+    "THOTH_SET_WORK_STATUS" => "Une fois publié, un ouvrage ne peut plus être dépublié. Utilisez plutôt le statut retiré ou remplacé.",
+    "THOTH_DELETE_WORK" => "Une fois publié, un ouvrage ne peut plus être supprimé.",
+    "THOTH_DELETE_PUBLICATION" => "Les publications d'un ouvrage publié ne peuvent pas être supprimées.",
+    "CANONICAL_ABSTRACT_EXISTS" => "Si un résumé canonique existe déjà, aucun autre résumé ne peut être défini comme canonique.",
+    "SHORT_ABSTRACT_LIMIT_EXCEEDED" => "Le résumé court doit comporter moins de 350 caractères.",
+    "CANONICAL_BIOGRAPHY_EXISTS" => "Si une biographie canonique existe déjà, aucune autre biographie ne peut être définie comme canonique.",
+    "CANONICAL_TITLE_EXISTS" => "Si un titre canonique existe déjà, aucun autre titre ne peut être défini comme canonique.",
+    "NO_FILE_EXTENSION_FOUND" => "Si l'extension du fichier est introuvable, le format du fichier n'est pas pris en charge.",
+    "UNSUPPORTED_FILE_FORMAT" => "Format de fichier non pris en charge",
+    "TAG_NOT_FOUND" => "Balise de contenu introuvable",
+    "TITLE_MULTIPLE_TOP_LEVEL_ELEMENTS" => "Le contenu du titre ne peut pas contenir plusieurs éléments de premier niveau.",
+    "TITLE_LIST_ITEM" => "Le contenu du titre ne peut pas contenir d'éléments de liste.",
+    "MISSING_MARKUP_FORMAT" => "Le format de balisage n'a pas été fourni.",
+    "INVALID_FILE_EXTENSION" => "Extension de fichier non valide",
+    "INVALID_FILE_MIME_TYPE" => "Type MIME de fichier non valide",
+    "FILE_TOO_SMALL" => "La taille du fichier est inférieure au minimum autorisé",
+    "FILE_TOO_LARGE" => "La taille du fichier dépasse le maximum autorisé",
+    "UNSUPPORTED_PUBLICATION_TYPE_FOR_FILE_UPLOAD" => "Le téléversement de fichiers n'est pas pris en charge pour ce type de publication",
+    "UNSUPPORTED_RESOURCE_TYPE_FOR_FILE_UPLOAD" => "Le téléversement de fichiers n'est pas pris en charge pour ce type de ressource complémentaire",
+    "PUBLICATION_TYPE_REQUIRED_FOR_FILE_VALIDATION" => "Le type de publication est requis pour valider le fichier de la publication",
+    "FILE_MISSING_WORK_OR_PUBLICATION_ID" => "Le fichier doit référencer exactement une portée : work_id, publication_id, additional_resource_id ou work_featured_video_id",
+    "FILE_UPLOAD_MISSING_WORK_OR_PUBLICATION_ID" => "Le téléversement doit référencer exactement une portée : work_id, publication_id, additional_resource_id ou work_featured_video_id",
+    "WORK_MISSING_DOI_FOR_FILE_UPLOAD" => "L'ouvrage doit avoir un DOI pour téléverser des fichiers",
+    "PUBLICATION_FILE_UPLOAD_MISSING_PUBLICATION_ID" => "publication_id manquant pour le téléversement du fichier de la publication",
+    "FRONTCOVER_FILE_UPLOAD_MISSING_WORK_ID" => "work_id manquant pour le téléversement de la couverture",
+    "ADDITIONAL_RESOURCE_FILE_UPLOAD_MISSING_ADDITIONAL_RESOURCE_ID" => "additional_resource_id manquant pour le téléversement du fichier de la ressource complémentaire",
+    "WORK_FEATURED_VIDEO_FILE_UPLOAD_MISSING_WORK_FEATURED_VIDEO_ID" => "work_featured_video_id manquant pour le téléversement de la vidéo mise en avant de l'ouvrage",
+    "FILE_VALIDATION_FAILED" => "Le fichier n'a pas passé la validation : {0}",
+    "TERRITORY_EMPTY_COUNTRIES" => "Un territoire doit comporter au moins un pays, ou omettre les pays inclus pour indiquer le monde entier.",
+    "TERRITORY_EXCLUDED_COUNTRIES" => "Les pays exclus ne peuvent être indiqués que pour les territoires mondiaux.",
+    "TERRITORY_DUPLICATE_COUNTRY" => "Un pays ne peut pas figurer plus d'une fois dans un territoire.",
+    "PRICE_EFFECTIVE_DATES" => "La date de fin de validité du prix ne peut pas être antérieure à sa date de début.",
+    "PUBLICATION_EMBARGO_END_DATE" => "Une date de fin d'embargo doit être indiquée pour les publications sous embargo, et uniquement pour elles.",
+    "UNRECOGNISED_CREATIVE_COMMONS_LICENSE" => "URL de licence Creative Commons non reconnue : {0}",
+    "AUDIENCE_RANGE_INCOMPLETE" => "Le qualificatif et la précision de la tranche de public doivent être fournis ensemble, et les valeurs de la tranche exigent les deux.",
+    "AUDIENCE_RANGE_PRECISION" => "Les valeurs de la tranche de public ne correspondent pas à la précision : Exact n'exige qu'une valeur De, De exige une valeur De et À n'exige qu'une valeur À.",
+    "AUDIENCE_RANGE_VALUE" => "Tranche de public non valide : {0}",
+    "PRODUCT_FORM_DETAIL_CODE" => "Détail de forme de produit non valide : {0}",
+    "PRODUCT_FORM_FEATURE" => "Caractéristique de forme de produit non valide : {0}",
+    "WORK_DATE" => "Date d'ouvrage non valide : {0}",
+    "WORK_STATUS_MISSING_DATE" => "Les ouvrages au statut {0} doivent avoir une date de type {1}.",
+    "NAME_VARIANT_PARENT" => "Une variante de nom doit appartenir soit à un contributeur, soit à une contribution, mais pas aux deux.",
+    "NAME_VARIANT_SCRIPT" => "Code d'écriture ISO 15924 non pris en charge : {0}",
+    "NAME_VARIANT_MISSING_SCRIPT" => "Une variante de nom translittérée doit indiquer l'écriture dans laquelle elle est rédigée.",
+    "FUNDER_REGISTRY_SNAPSHOT" => "Instantané du Funder Registry non valide : {0}",
+    "FUNDER_REGISTRY_UNKNOWN_DOI" => "{0} n'est pas un DOI de financeur répertorié dans le Crossref Funder Registry.",
+    "FUNDER_REGISTRY_REPLACED_DOI" => "Le DOI de financeur {0} a été remplacé par {1} dans le Crossref Funder Registry.",
+};
+
+/// French translations of `DATABASE_CONSTRAINT_ERRORS`, keyed by constraint name
+pub(super) static CONSTRAINT_MESSAGES: Map<&'static str, &'static str> = phf_map! {
+    "affiliation_affiliation_ordinal_check" => "Le numéro d'ordre d'une affiliation doit être supérieur à 0.",
+    "affiliation_position_check" => "La fonction ne doit pas être une chaîne vide.",
+    "affiliation_affiliation_ordinal_contribution_id_uniq" => "Une affiliation avec ce numéro d'ordre existe déjà.",
+    "abstract_content_check" => "Le contenu du résumé ne doit pas être une chaîne vide.",
+    "abstract_uniq_locale_idx" => "Un résumé de l'ouvrage avec ce code de langue et ce type existe déjà.",
+    "abstract_unique_canonical_true_idx" => "Un résumé canonique existe déjà pour cet ouvrage.",
+    "additional_resource_resource_ordinal_work_id_uniq" => "Une ressource complémentaire avec ce numéro d'ordre existe déjà.",
+    "biography_content_check" => "Le contenu de la biographie ne doit pas être une chaîne vide.",
+    "biography_uniq_locale_idx" => "Une biographie de la contribution avec ce code de langue existe déjà.",
+    "biography_unique_canonical_true_idx" => "Une biographie canonique existe déjà pour cette contribution.",
+    "audience_range_check" => "Le qualificatif et la précision de la tranche de public doivent être fournis ensemble, et les valeurs de la tranche exigent les deux.",
+    "audience_range_from_check" => "La valeur De de la tranche de public ne doit pas être une chaîne vide.",
+    "audience_range_precision_check" => "Les valeurs de la tranche de public ne correspondent pas à la précision : Exact n'exige qu'une valeur De, De exige une valeur De et À n'exige qu'une valeur À.",
+    "audience_range_to_check" => "La valeur À de la tranche de public ne doit pas être une chaîne vide.",
+    "audience_uniq_idx" => "Un public avec ce code et ce qualificatif de tranche existe déjà pour cet ouvrage.",
+    "award_award_ordinal_work_id_uniq" => "Un prix avec ce numéro d'ordre existe déjà.",
+    "book_review_review_ordinal_work_id_uniq" => "Une critique avec ce numéro d'ordre existe déjà.",
+    "contribution_contribution_ordinal_check" => "Le numéro d'ordre d'une contribution doit être supérieur à 0.",
+    "contribution_contribution_ordinal_work_id_uniq" => "Une contribution avec ce numéro d'ordre existe déjà.",
+    "contribution_first_name_check" => "Le prénom ne doit pas être une chaîne vide.",
+    "contribution_full_name_check" => "Le nom complet ne doit pas être une chaîne vide.",
+    "contribution_last_name_check" => "Le nom de famille ne doit pas être une chaîne vide.",
+    "contribution_work_id_contributor_id_contribution_type_uniq" => "Une contribution de ce type existe déjà pour ce contributeur.",
+    "contributor_first_name_check" => "Le prénom ne doit pas être une chaîne vide.",
+    "contributor_full_name_check" => "Le nom complet ne doit pas être une chaîne vide.",
+    "contributor_last_name_check" => "Le nom de famille ne doit pas être une chaîne vide.",
+    "contributor_orcid_check" => "Identifiant ORCID non valide.",
+    "contributor_website_check" => "Le site web ne doit pas être une chaîne vide.",
+    "credit_role_uniq_idx" => "Ce rôle CRediT a déjà été attribué à cette contribution.",
+    "doi_uniq_idx" => "Un ouvrage avec ce DOI existe déjà.",
+    "email_uniq_idx" => "Un compte avec cette adresse e-mail existe déjà.",
+    "endorsement_endorsement_ordinal_work_id_uniq" => "Une recommandation avec ce numéro d'ordre existe déjà.",
+    "funding_grant_number_check" => "Le numéro de subvention ne doit pas être une chaîne vide.",
+    "funding_program_check" => "Le programme ne doit pas être une chaîne vide.",
+    "funding_project_name_check" => "Le nom du projet ne doit pas être une chaîne vide.",
+    "funding_project_shortname_check" => "Le nom court du projet ne doit pas être une chaîne vide.",
+    "imprint_imprint_name_check" => "Le nom de la marque éditoriale ne doit pas être une chaîne vide.",
+    "imprint_imprint_url_check" => "URL non valide.",
+    "imprint_storage_cfg_all_or_none" => "La configuration du stockage doit être complète : tous les champs (bucket S3, région, domaine CDN, identifiant de distribution CloudFront) doivent être fournis ensemble, ou tous laissés vides.",
+    "imprint_uniq_idx" => "Une marque éditoriale portant ce nom existe déjà.",
+    "institution_doi_uniq_idx" => "Une institution avec ce DOI existe déjà.",
+    "institution_institution_doi_check" => "DOI non valide.",
+    "institution_institution_name_check" => "Le nom ne doit pas être une chaîne vide.",
+    "institution_ror_check" => "ROR non valide.",
+    "issue_issue_ordinal_check" => "Le numéro d'ordre d'un numéro de collection doit être supérieur à 0.",
+    "issue_series_id_work_id_uniq" => "Un numéro de la collection sélectionnée existe déjà pour cet ouvrage.",
+    "issue_issue_ordinal_series_id_uniq" => "Un numéro de collection avec ce numéro d'ordre existe déjà.",
+    "language_uniq_work_idx" => "Code de langue en double.",
+    "location_full_text_url_check" => "URL non valide.",
+    "location_landing_page_check" => "URL non valide.",
+    "location_uniq_canonical_true_idx" => "Un emplacement canonique existe déjà pour cette publication.",
+    "location_uniq_platform_idx" => "Un emplacement sur la plateforme sélectionnée existe déjà.",
+    "location_url_check" => "Un emplacement doit avoir une page d'accueil et/ou une URL de texte intégral.",
+    "name_variant_contribution_uniq_idx" => "Une variante de nom de ce type portant ce nom existe déjà pour cette contribution.",
+    "name_variant_contributor_uniq_idx" => "Une variante de nom de ce type portant ce nom existe déjà pour ce contributeur.",
+    "name_variant_first_name_check" => "Le prénom ne doit pas être une chaîne vide.",
+    "name_variant_full_name_check" => "Le nom complet ne doit pas être une chaîne vide.",
+    "name_variant_last_name_check" => "Le nom de famille ne doit pas être une chaîne vide.",
+    "name_variant_parent_check" => "Une variante de nom doit appartenir soit à un contributeur, soit à une contribution, mais pas aux deux.",
+    "name_variant_script_code_check" => "Un code d'écriture doit être un code ISO 15924 de quatre lettres, par ex. Cyrl.",
+    "file_frontcover_work_unique_idx" => "Un fichier de couverture existe déjà pour cet ouvrage.",
+    "file_object_key_unique_idx" => "Un fichier avec cette clé d'objet existe déjà.",
+    "file_publication_unique_idx" => "Un fichier existe déjà pour cette publication.",
+    "file_additional_resource_unique_idx" => "Un fichier existe déjà pour cette ressource complémentaire.",
+    "file_work_featured_video_unique_idx" => "Un fichier existe déjà pour cette vidéo mise en avant.",
+    "file_type_check" => "Le type de fichier n'est pas valide : frontcover doit avoir work_id, publication doit avoir publication_id, additional_resource doit avoir additional_resource_id et work_featured_video doit avoir work_featured_video_id.",
+    "file_upload_type_check" => "Le type de téléversement n'est pas valide : frontcover doit avoir work_id, publication doit avoir publication_id, additional_resource doit avoir additional_resource_id et work_featured_video doit avoir work_featured_video_id.",
+    "orcid_uniq_idx" => "Un contributeur avec cet identifiant ORCID existe déjà.",
+    "price_countries_included_check" => "Un territoire doit comporter au moins un pays, ou omettre les pays inclus pour indiquer le monde entier.",
+    "price_effective_dates_check" => "La date de fin de validité du prix ne peut pas être antérieure à sa date de début.",
+    "price_publication_id_currency_code_uniq" => "Un prix dans cette devise, ce territoire et ce type existe déjà pour cette publication à partir de cette date.",
+    "price_unit_price_check" => "Les prix doivent être supérieurs à zéro. Pour indiquer une publication sans prix, omettez tous les prix.",
+    "product_form_detail_detail_code_check" => "Le code de détail de forme de produit ne doit pas être une chaîne vide.",
+    "product_form_detail_feature_check" => "Les valeurs et descriptions de caractéristiques ne s'appliquent qu'aux caractéristiques de forme de produit.",
+    "product_form_detail_feature_description_check" => "La description de la caractéristique de forme de produit ne doit pas être une chaîne vide.",
+    "product_form_detail_feature_value_check" => "La valeur de la caractéristique de forme de produit ne doit pas être une chaîne vide.",
+    "product_form_detail_packaging_uniq_idx" => "Cette publication a déjà un type de conditionnement.",
+    "product_form_detail_uniq_idx" => "Ce détail de forme de produit a déjà été ajouté à la publication.",
+    "publication_depth_in_check" => "L'épaisseur de la publication doit être supérieure à 0.0.",
+    "publication_depth_in_not_missing" => "Lorsque l'épaisseur est indiquée, les deux valeurs (mm et in) doivent être fournies.",
+    "publication_depth_mm_check" => "L'épaisseur de la publication doit être supérieure à 0.0.",
+    "publication_depth_mm_not_missing" => "Lorsque l'épaisseur est indiquée, les deux valeurs (mm et in) doivent être fournies.",
+    "publication_embargo_end_date_check" => "Une date de fin d'embargo doit être indiquée pour les publications sous embargo, et uniquement pour elles.",
+    "publication_height_in_check" => "La hauteur de la publication doit être supérieure à 0.0.",
+    "publication_height_in_not_missing" => "Lorsque la hauteur est indiquée, les deux valeurs (mm et in) doivent être fournies.",
+    "publication_height_mm_check" => "La hauteur de la publication doit être supérieure à 0.0.",
+    "publication_height_mm_not_missing" => "Lorsque la hauteur est indiquée, les deux valeurs (mm et in) doivent être fournies.",
+    "publication_isbn_check" => "Un ISBN valide doit comporter exactement 17 caractères.",
+    "publication_isbn_idx" => "Une publication avec cet ISBN existe déjà.",
+    "publication_license_check" => "URL de licence non valide.",
+    "publication_non_physical_no_dimensions" => "La largeur, la hauteur, l'épaisseur et le poids ne s'appliquent qu'aux publications physiques (broché ou relié).",
+    "publication_publication_type_work_id_uniq" => "Une publication du type sélectionné existe déjà.",
+    "publication_weight_g_check" => "Le poids de la publication doit être supérieur à 0.0.",
+    "publication_weight_g_not_missing" => "Lorsque le poids est indiqué, les deux valeurs (g et oz) doivent être fournies.",
+    "publication_weight_oz_check" => "Le poids de la publication doit être supérieur à 0.0.",
+    "publication_weight_oz_not_missing" => "Lorsque le poids est indiqué, les deux valeurs (g et oz) doivent être fournies.",
+    "publication_width_in_check" => "La largeur de la publication doit être supérieure à 0.0.",
+    "publication_width_in_not_missing" => "Lorsque la largeur est indiquée, les deux valeurs (mm et in) doivent être fournies.",
+    "publication_width_mm_check" => "La largeur de la publication doit être supérieure à 0.0.",
+    "publication_width_mm_not_missing" => "Lorsque la largeur est indiquée, les deux valeurs (mm et in) doivent être fournies.",
+    "publisher_publisher_name_check" => "Le nom de l'éditeur ne doit pas être une chaîne vide.",
+    "publisher_publisher_shortname_check" => "Le nom court de l'éditeur ne doit pas être une chaîne vide.",
+    "publisher_publisher_url_check" => "URL non valide.",
+    "publisher_uniq_idx" => "Un éditeur portant ce nom existe déjà.",
+    "reference_article_title_check" => "Le titre de l'article ne doit pas être une chaîne vide.",
+    "reference_author_check" => "L'auteur ne doit pas être une chaîne vide.",
+    "reference_component_number_check" => "Le numéro de composant ne doit pas être une chaîne vide.",
+    "reference_doi_andor_unstructured_citation" => "Une référence doit avoir un DOI et/ou la citation non structurée.",
+    "reference_doi_check" => "DOI non valide.",
+    "reference_edition_check" => "Le numéro d'édition doit être supérieur à 0.",
+    "reference_first_page_check" => "La première page ne doit pas être une chaîne vide.",
+    "reference_isbn_check" => "Un ISBN valide doit comporter exactement 17 caractères.",
+    "reference_issn_check" => "ISSN non valide.",
+    "reference_issue_check" => "Le numéro ne doit pas être une chaîne vide.",
+    "reference_journal_title_check" => "Le titre de la revue ne doit pas être une chaîne vide.",
+    "reference_reference_ordinal_check" => "Le numéro d'ordre d'une référence doit être supérieur à 0.",
+    "reference_reference_ordinal_work_id_uniq" => "Une référence avec ce numéro d'ordre existe déjà.",
+    "reference_series_title_check" => "Le titre de la collection ne doit pas être une chaîne vide.",
+    "reference_standard_citation_required_fields" => "La citation d'une norme doit comprendre l'identifiant de la norme ainsi que le nom et le sigle d'un organisme de normalisation.",
+    "reference_standard_designator_check" => "L'identifiant de la norme ne doit pas être une chaîne vide.",
+    "reference_standards_body_acronym_check" => "Le sigle de l'organisme de normalisation ne doit pas être une chaîne vide.",
+    "reference_standards_body_name_check" => "Le nom de l'organisme de normalisation ne doit pas être une chaîne vide.",
+    "reference_unstructured_citation_check" => "La citation non structurée ne doit pas être une chaîne vide.",
+    "reference_url_check" => "URL non valide.",
+    "reference_volume_check" => "Le volume ne doit pas être une chaîne vide.",
+    "reference_volume_title_check" => "Le titre du volume ne doit pas être une chaîne vide.",
+    "sales_rights_countries_excluded_check" => "Les pays exclus ne peuvent être indiqués que pour les territoires mondiaux.",
+    "sales_rights_countries_included_check" => "Un territoire doit comporter au moins un pays, ou omettre les pays inclus pour indiquer le monde entier.",
+    "series_issn_digital_check" => "ISSN numérique non valide.",
+    "series_issn_digital_idx" => "Une collection avec cet ISSN numérique existe déjà.",
+    "series_issn_print_check" => "ISSN imprimé non valide.",
+    "series_issn_print_idx" => "Une collection avec cet ISSN imprimé existe déjà.",
+    "series_series_cfp_url_check" => "URL d'appel à contributions non valide.",
+    "series_series_description_check" => "La description de la collection ne doit pas être une chaîne vide.",
+    "series_series_name_check" => "Le nom de la collection ne doit pas être une chaîne vide.",
+    "series_series_url_check" => "URL de la collection non valide.",
+    "subject_ordinal_type_uniq" => "Un sujet avec ce numéro d'ordre et ce type existe déjà.",
+    "subject_subject_code_check" => "Les codes de sujet ne doivent pas être une chaîne vide.",
+    "subject_subject_ordinal_check" => "Le numéro d'ordre d'un sujet doit être supérieur à 0.",
+    "title_unique_canonical_true_idx" => "Un titre canonique existe déjà pour cet ouvrage.",
+    "title_full_title_check" => "Le titre complet ne doit pas être une chaîne vide.",
+    "title_subtitle_check" => "Le sous-titre ne doit pas être une chaîne vide.",
+    "title_title_check" => "Le titre ne doit pas être une chaîne vide.",
+    "title_uniq_locale_idx" => "Un titre de l'ouvrage avec ce code de langue existe déjà.",
+    "work_active_withdrawn_date_check" => "La date de retrait ne peut être indiquée que pour un ouvrage retiré ou remplacé.",
+    "work_active_publication_date_check" => "Les ouvrages actifs, remplacés et retirés doivent avoir une date de publication.",
+    "work_audio_count_check" => "Le nombre d'enregistrements audio doit être supérieur à 0.",
+    "work_bibliography_note_check" => "La note bibliographique ne doit pas être une chaîne vide.",
+    "work_chapter_no_edition" => "Les chapitres ne doivent pas avoir de numéro d'édition.",
+    "work_chapter_no_lccn" => "Les chapitres ne doivent pas avoir de LCCN.",
+    "work_chapter_no_oclc" => "Les chapitres ne doivent pas avoir de numéro OCLC.",
+    "work_chapter_no_toc" => "Les chapitres ne doivent pas avoir de table des matières.",
+    "work_copyright_holder_check" => "Le titulaire des droits d'auteur ne doit pas être une chaîne vide.",
+    "work_cover_caption_check" => "La légende de la couverture ne doit pas être une chaîne vide.",
+    "work_cover_url_check" => "URL de la couverture non valide.",
+    "work_date_uniq_idx" => "Une date de ce type existe déjà pour cet ouvrage.",
+    "work_doi_check" => "DOI non valide.",
+    "work_edition_check" => "Le numéro d'édition doit être supérieur à 0.",
+    "work_first_page_check" => "La première page ne doit pas être une chaîne vide.",
+    "work_general_note_check" => "La note générale ne doit pas être une chaîne vide.",
+    "work_image_count_check" => "Le nombre d'images doit être supérieur à 0.",
+    "work_inactive_no_withdrawn_date_check" => "Un ouvrage retiré ou remplacé doit comporter une date de retrait.",
+    "work_landing_page_check" => "URL de la page d'accueil non valide.",
+    "work_last_page_check" => "La dernière page ne doit pas être une chaîne vide.",
+    "work_lccn_check" => "Le LCCN ne doit pas être une chaîne vide.",
+    "work_license_check" => "URL de licence non valide.",
+    "work_long_abstract_check" => "Le résumé long ne doit pas être une chaîne vide.",
+    "work_non_chapter_has_edition" => "Le numéro d'édition est obligatoire (sauf pour les chapitres).",
+    "work_non_chapter_no_first_page" => "La première page ne peut être indiquée que pour les chapitres de livre.",
+    "work_non_chapter_no_last_page" => "La dernière page ne peut être indiquée que pour les chapitres de livre.",
+    "work_non_chapter_no_page_interval" => "L'intervalle de pages ne peut être indiqué que pour les chapitres de livre.",
+    "work_oclc_check" => "Le numéro OCLC ne doit pas être une chaîne vide.",
+    "work_page_breakdown_check" => "La ventilation des pages ne doit pas être une chaîne vide.",
+    "work_page_count_check" => "Le nombre de pages doit être supérieur à 0.",
+    "work_page_interval_check" => "L'intervalle de pages ne doit pas être une chaîne vide.",
+    "work_place_check" => "Le lieu ne doit pas être une chaîne vide.",
+    "work_reference_check" => "La référence ne doit pas être une chaîne vide.",
+    "work_relation_ids_check" => "Un ouvrage ne peut pas être lié à lui-même.",
+    "work_relation_ordinal_type_uniq" => "Une relation avec ce numéro d'ordre et ce type existe déjà.",
+    "work_relation_relation_ordinal_check" => "Le numéro d'ordre d'une relation entre ouvrages doit être supérieur à 0.",
+    "work_relation_relator_related_uniq" => "Une relation entre ces deux ouvrages existe déjà.",
+    "work_short_abstract_check" => "Le résumé court ne doit pas être une chaîne vide.",
+    "work_table_count_check" => "Le nombre de tableaux doit être supérieur à 0.",
+    "work_toc_check" => "La table des matières ne doit pas être une chaîne vide.",
+    "work_video_count_check" => "Le nombre de vidéos doit être supérieur à 0.",
+    "work_withdrawn_date_after_publication_date_check" => "La date de publication doit être antérieure à la date de retrait.",
+    "work_featured_video_work_id_key" => "Une vidéo mise en avant existe déjà pour cet ouvrage.",
+};
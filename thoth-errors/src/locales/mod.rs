@@ -0,0 +1,272 @@
+mod es;
+mod fr;
+mod pt;
+
+use phf::Map;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+use crate::ThothError;
+
+tokio::task_local! {
+    static LOCALE: Locale;
+}
+
+/// Language in which error messages are returned to API clients.
+///
+/// English messages are the `#[error]` strings on [`ThothError`] and the database constraint
+/// messages; the other locales translate them by error code (or constraint name), with
+/// `{0}`, `{1}` standing for the error's arguments. Anything missing from a catalogue falls
+/// back to English.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+    Fr,
+    Pt,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 4] = [Locale::En, Locale::Es, Locale::Fr, Locale::Pt];
+
+    /// ISO 639-1 code of the locale
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::Fr => "fr",
+            Locale::Pt => "pt",
+        }
+    }
+
+    /// Match a language tag such as `pt-BR` or `es_ES` on its primary language subtag
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let language = tag.trim().split(['-', '_']).next()?;
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.as_str().eq_ignore_ascii_case(language))
+    }
+
+    /// Pick the supported locale with the highest weight in an `Accept-Language` header,
+    /// e.g. `es` for `de-DE, es;q=0.8, en;q=0.5`
+    pub fn from_accept_language(header: &str) -> Option<Locale> {
+        let mut ranges: Vec<(f32, Locale)> = header
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let locale = Locale::from_tag(parts.next()?)?;
+                let quality = match parts.find_map(|param| param.trim().strip_prefix("q=")) {
+                    Some(quality) => quality.trim().parse::<f32>().ok()?,
+                    None => 1.0,
+                };
+                (quality > 0.0).then_some((quality, locale))
+            })
+            .collect();
+        // stable sort: ranges of equal weight keep the client's order of preference
+        ranges.sort_by(|a, b| b.0.total_cmp(&a.0));
+        ranges.first().map(|(_, locale)| *locale)
+    }
+
+    /// Locale of the request being processed, or English outside of [`Locale::scope`]
+    pub fn current() -> Locale {
+        LOCALE.try_with(|locale| *locale).unwrap_or_default()
+    }
+
+    /// Run a future, e.g. the execution of a GraphQL request, with this as the current locale
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        LOCALE.scope(self, future).await
+    }
+
+    /// Synchronous counterpart of [`Locale::scope`]
+    pub fn sync_scope<F: FnOnce() -> R, R>(self, f: F) -> R {
+        LOCALE.sync_scope(self, f)
+    }
+
+    fn messages(&self) -> Option<&'static Map<&'static str, &'static str>> {
+        match self {
+            Locale::En => None,
+            Locale::Es => Some(&es::MESSAGES),
+            Locale::Fr => Some(&fr::MESSAGES),
+            Locale::Pt => Some(&pt::MESSAGES),
+        }
+    }
+
+    fn constraint_messages(&self) -> Option<&'static Map<&'static str, &'static str>> {
+        match self {
+            Locale::En => None,
+            Locale::Es => Some(&es::CONSTRAINT_MESSAGES),
+            Locale::Fr => Some(&fr::CONSTRAINT_MESSAGES),
+            Locale::Pt => Some(&pt::CONSTRAINT_MESSAGES),
+        }
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ThothError {
+    /// Message of the error in the given locale, falling back to English
+    pub fn localised_message(&self, locale: Locale) -> String {
+        let template = match self {
+            ThothError::DatabaseConstraintError { constraint, .. } => locale
+                .constraint_messages()
+                .and_then(|messages| messages.get(&**constraint)),
+            _ => locale
+                .messages()
+                .and_then(|messages| messages.get(self.code())),
+        };
+        match template {
+            Some(template) => self
+                .message_args()
+                .iter()
+                .enumerate()
+                .fold(template.to_string(), |message, (i, arg)| {
+                    message.replace(&format!("{{{i}}}"), arg)
+                }),
+            None => self.to_string(),
+        }
+    }
+
+    /// Arguments interpolated into the error message, in the order of its `#[error]` string
+    fn message_args(&self) -> Vec<String> {
+        match self {
+            ThothError::InvalidSubjectCode {
+                input,
+                subject_type,
+            } => vec![format!("{input:?}"), format!("{subject_type:?}")],
+            ThothError::DatabaseConstraintError { .. } => vec![],
+            _ => self.arguments(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use juniper::{DefaultScalarValue, IntoFieldError, Value};
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_from_tag() {
+        assert_eq!(Locale::from_tag("pt-BR"), Some(Locale::Pt));
+        assert_eq!(Locale::from_tag("es_ES"), Some(Locale::Es));
+        assert_eq!(Locale::from_tag("FR"), Some(Locale::Fr));
+        assert_eq!(Locale::from_tag("de"), None);
+        assert_eq!(Locale::from_tag("*"), None);
+    }
+
+    #[test]
+    fn test_from_accept_language() {
+        assert_eq!(
+            Locale::from_accept_language("de-DE, es;q=0.8, en;q=0.5"),
+            Some(Locale::Es)
+        );
+        assert_eq!(
+            Locale::from_accept_language("en;q=0.4, pt-BR;q=0.9"),
+            Some(Locale::Pt)
+        );
+        assert_eq!(
+            Locale::from_accept_language("fr-CA,fr;q=0.9,en;q=0.8"),
+            Some(Locale::Fr)
+        );
+        assert_eq!(
+            Locale::from_accept_language("es;q=0, en;q=0.1"),
+            Some(Locale::En)
+        );
+        assert_eq!(Locale::from_accept_language("de, *;q=0.5"), None);
+        assert_eq!(Locale::from_accept_language(""), None);
+    }
+
+    #[test]
+    fn test_catalogues_are_complete() {
+        let constraint_count = crate::database_errors::constraint_names().count();
+        for locale in [Locale::Es, Locale::Fr, Locale::Pt] {
+            assert_eq!(
+                locale.constraint_messages().unwrap().len(),
+                constraint_count,
+                "{locale} constraint catalogue is incomplete"
+            );
+            assert!(
+                crate::database_errors::constraint_names().all(|constraint| locale
+                    .constraint_messages()
+                    .unwrap()
+                    .contains_key(constraint))
+            );
+        }
+    }
+
+    #[test]
+    fn test_localised_message() {
+        assert_eq!(
+            ThothError::PublicationDateError.localised_message(Locale::Es),
+            "La fecha de publicación es obligatoria para las obras activas, retiradas y reemplazadas."
+        );
+        assert_eq!(
+            ThothError::PublicationDateError.localised_message(Locale::En),
+            ThothError::PublicationDateError.to_string()
+        );
+    }
+
+    #[test]
+    fn test_localised_message_with_arguments() {
+        let error = ThothError::FunderRegistryReplacedDoiError(
+            "https://doi.org/10.13039/100000001".to_string(),
+            "https://doi.org/10.13039/100000002".to_string(),
+        );
+        assert_eq!(
+            error.localised_message(Locale::Pt),
+            "O DOI de financiador https://doi.org/10.13039/100000001 foi substituído por https://doi.org/10.13039/100000002 no Crossref Funder Registry."
+        );
+        let error = ThothError::InvalidSubjectCode {
+            input: "002".to_string(),
+            subject_type: "BIC".to_string(),
+        };
+        assert_eq!(
+            error.localised_message(Locale::Fr),
+            "\"002\" n'est pas un code \"BIC\" valide"
+        );
+    }
+
+    #[test]
+    fn test_localised_constraint_message() {
+        let error = ThothError::DatabaseConstraintError {
+            constraint: Cow::Borrowed("doi_uniq_idx"),
+            message: Cow::Borrowed("A work with this DOI already exists."),
+        };
+        assert_eq!(
+            error.localised_message(Locale::Fr),
+            "Un ouvrage avec ce DOI existe déjà."
+        );
+        assert_eq!(
+            error.localised_message(Locale::En),
+            "A work with this DOI already exists."
+        );
+    }
+
+    #[test]
+    fn test_current_locale() {
+        assert_eq!(Locale::current(), Locale::En);
+        assert_eq!(Locale::Pt.sync_scope(Locale::current), Locale::Pt);
+        assert_eq!(Locale::current(), Locale::En);
+    }
+
+    #[test]
+    fn test_graphql_error_is_localised() {
+        let error: juniper::FieldError<DefaultScalarValue> =
+            Locale::Es.sync_scope(|| ThothError::Unauthorised.into_field_error());
+        assert_eq!(error.message(), "Credenciales no válidas.");
+        assert_eq!(
+            error
+                .extensions()
+                .as_object_value()
+                .unwrap()
+                .get_field_value("code"),
+            Some(&Value::scalar("UNAUTHORISED"))
+        );
+    }
+}
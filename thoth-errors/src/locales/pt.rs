@@ -0,0 +1,285 @@
+use phf::{phf_map, Map};
+
+/// Portuguese translations of error messages, keyed by error code
+pub(super) static MESSAGES: Map<&'static str, &'static str> = phf_map! {
+    "INVALID_SUBJECT_CODE" => "{0} não é um código {1} válido",
+    "DATABASE_ERROR" => "Erro de base de dados: {0}",
+    "REDIS_ERROR" => "Erro do Redis: {0}",
+    "INTERNAL_ERROR" => "Erro interno: {0}",
+    "UNAUTHORISED" => "Credenciais inválidas.",
+    "INVALID_TOKEN" => "Não foi possível validar o token.",
+    "ENTITY_NOT_FOUND" => "Não foi encontrado nenhum registo com o ID indicado.",
+    "ISSUE_IMPRINTS" => "A obra e a série de um número não podem ter chancelas editoriais diferentes.",
+    "INVALID_METADATA_SPECIFICATION" => "{0} não é uma especificação de metadados válida",
+    "INVALID_UUID" => "O UUID indicado não é válido.",
+    "INVALID_TIMESTAMP" => "A data e hora indicadas não são válidas.",
+    "CSV_ERROR" => "Erro de CSV: {0}",
+    "MARC_ERROR" => "Erro de MARC: {0}",
+    "INCOMPLETE_METADATA_RECORD" => "Não foi possível gerar {0}: {1}",
+    "METADATA_RECORD_NOT_GENERATED" => "O registo de metadados ainda não foi gerado.",
+    "ORCID_PARSE" => "{0} não é um ORCID com formato válido e não será guardado",
+    "DOI_PARSE" => "{0} não é um DOI com formato válido e não será guardado",
+    "ISBN_PARSE" => "{0} não é um ISBN com formato válido e não será guardado",
+    "ROR_PARSE" => "{0} não é um ID ROR com formato válido e não será guardado",
+    "ORCID_EMPTY" => "Não é possível analisar o ORCID: nenhum valor indicado",
+    "DOI_EMPTY" => "Não é possível analisar o DOI: nenhum valor indicado",
+    "ISBN_EMPTY" => "Não é possível analisar o ISBN: nenhum valor indicado",
+    "ROR_EMPTY" => "Não é possível analisar o ID ROR: nenhum valor indicado",
+    "CHAPTER_ISBN" => "As obras do tipo capítulo de livro não podem ter ISBN nas suas publicações.",
+    "CHAPTER_BOOK_METADATA" => "As obras do tipo capítulo de livro não podem ter registos de metadados ao nível do livro.",
+    "CHAPTER_DIMENSION" => "As obras do tipo capítulo de livro não podem ter largura, altura, profundidade nem peso nas suas publicações.",
+    "CANONICAL_LOCATION" => "Cada publicação deve ter exatamente uma localização canónica.",
+    "LOCATION_URL" => "As localizações canónicas das publicações digitais devem ter uma página de destino e um URL de texto integral.",
+    "WEIGHT_EMPTY" => "Ao indicar o peso, devem ser indicados ambos os valores (g e oz).",
+    "WIDTH_EMPTY" => "Ao indicar a largura, devem ser indicados ambos os valores (mm e in).",
+    "HEIGHT_EMPTY" => "Ao indicar a altura, devem ser indicados ambos os valores (mm e in).",
+    "DEPTH_EMPTY" => "Ao indicar a profundidade, devem ser indicados ambos os valores (mm e in).",
+    "DIMENSION_DIGITAL" => "A largura, altura, profundidade e peso só se aplicam a publicações físicas (brochura ou capa dura).",
+    "PRICE_ZERO" => "Os preços devem ser superiores a zero. Para indicar uma publicação sem preço, omita todos os preços.",
+    "PUBLICATION_DATE" => "A data de publicação é obrigatória para as obras ativas, retiradas e substituídas.",
+    "REQUEST_ERROR" => "{0}",
+    "GRAPHQL_ERROR" => "{0}",
+    "WITHDRAWN_DATE_BEFORE_PUBLICATION_DATE" => "A data de retirada deve ser posterior à data de publicação.",
+    "WITHDRAWN_DATE" => "A data de retirada só pode ser adicionada a uma obra substituída ou retirada.",
+    "NO_WITHDRAWN_DATE" => "Uma obra substituída ou retirada deve ter data de retirada.",
+    "THOTH_LOCATION" => "Apenas os superutilizadores podem criar, editar ou eliminar localizações cuja plataforma seja a Thoth.",
+    "THOTH_UPDATE_CANONICAL" => "Apenas os superutilizadores podem atualizar a localização canónica quando a plataforma Thoth já é a canónica.",
+    "THOTH_SET_WORK_STATUS" => "Depois de publicada, uma obra não pode ser despublicada. Utilize antes o estado retirada ou substituída.",
+    "THOTH_DELETE_WORK" => "Depois de publicada, uma obra não pode ser eliminada.",
+    "THOTH_DELETE_PUBLICATION" => "As publicações de uma obra publicada não podem ser eliminadas.",
+    "CANONICAL_ABSTRACT_EXISTS" => "Se já existir um resumo canónico, não é possível definir outro resumo como canónico.",
+    "SHORT_ABSTRACT_LIMIT_EXCEEDED" => "O resumo breve deve ter menos de 350 caracteres.",
+    "CANONICAL_BIOGRAPHY_EXISTS" => "Se já existir uma biografia canónica, não é possível definir outra biografia como canónica.",
+    "CANONICAL_TITLE_EXISTS" => "Se já existir um título canónico, não é possível definir outro título como canónico.",
+    "NO_FILE_EXTENSION_FOUND" => "Se a extensão do ficheiro não for encontrada, o formato do ficheiro não é suportado.",
+    "UNSUPPORTED_FILE_FORMAT" => "Formato de ficheiro não suportado",
+    "TAG_NOT_FOUND" => "Etiqueta de conteúdo não encontrada",
+    "TITLE_MULTIPLE_TOP_LEVEL_ELEMENTS" => "O conteúdo do título não pode conter vários elementos de nível superior.",
+    "TITLE_LIST_ITEM" => "O conteúdo do título não pode conter elementos de lista.",
+    "MISSING_MARKUP_FORMAT" => "O formato de marcação não foi indicado.",
+    "INVALID_FILE_EXTENSION" => "Extensão de ficheiro inválida",
+    "INVALID_FILE_MIME_TYPE" => "Tipo MIME de ficheiro inválido",
+    "FILE_TOO_SMALL" => "O tamanho do ficheiro é inferior ao mínimo permitido",
+    "FILE_TOO_LARGE" => "O tamanho do ficheiro excede o máximo permitido",
+    "UNSUPPORTED_PUBLICATION_TYPE_FOR_FILE_UPLOAD" => "O carregamento de ficheiros não é suportado para este tipo de publicação",
+    "UNSUPPORTED_RESOURCE_TYPE_FOR_FILE_UPLOAD" => "O carregamento de ficheiros não é suportado para este tipo de recurso adicional",
+    "PUBLICATION_TYPE_REQUIRED_FOR_FILE_VALIDATION" => "O tipo de publicação é necessário para validar o ficheiro da publicação",
+    "FILE_MISSING_WORK_OR_PUBLICATION_ID" => "O ficheiro deve referir exatamente um âmbito: work_id, publication_id, additional_resource_id ou work_featured_video_id",
+    "FILE_UPLOAD_MISSING_WORK_OR_PUBLICATION_ID" => "O carregamento deve referir exatamente um âmbito: work_id, publication_id, additional_resource_id ou work_featured_video_id",
+    "WORK_MISSING_DOI_FOR_FILE_UPLOAD" => "A obra deve ter um DOI para carregar ficheiros",
+    "PUBLICATION_FILE_UPLOAD_MISSING_PUBLICATION_ID" => "Falta o publication_id no carregamento do ficheiro da publicação",
+    "FRONTCOVER_FILE_UPLOAD_MISSING_WORK_ID" => "Falta o work_id no carregamento da capa",
+    "ADDITIONAL_RESOURCE_FILE_UPLOAD_MISSING_ADDITIONAL_RESOURCE_ID" => "Falta o additional_resource_id no carregamento do ficheiro do recurso adicional",
+    "WORK_FEATURED_VIDEO_FILE_UPLOAD_MISSING_WORK_FEATURED_VIDEO_ID" => "Falta o work_featured_video_id no carregamento do vídeo em destaque da obra",
+    "FILE_VALIDATION_FAILED" => "O ficheiro não passou na validação: {0}",
+    "TERRITORY_EMPTY_COUNTRIES" => "Um território deve incluir pelo menos um país, ou omitir os países incluídos para indicar todo o mundo.",
+    "TERRITORY_EXCLUDED_COUNTRIES" => "Os países excluídos só podem ser indicados em territórios mundiais.",
+    "TERRITORY_DUPLICATE_COUNTRY" => "Um país não pode aparecer mais de uma vez num território.",
+    "PRICE_EFFECTIVE_DATES" => "A data de fim de vigência do preço não pode ser anterior à sua data de início.",
+    "PUBLICATION_EMBARGO_END_DATE" => "Deve ser indicada uma data de fim de embargo para as publicações embargadas, e apenas para elas.",
+    "UNRECOGNISED_CREATIVE_COMMONS_LICENSE" => "URL de licença Creative Commons não reconhecido: {0}",
+    "AUDIENCE_RANGE_INCOMPLETE" => "O qualificador e a precisão do intervalo de público devem ser indicados em conjunto, e os valores do intervalo exigem ambos.",
+    "AUDIENCE_RANGE_PRECISION" => "Os valores do intervalo de público não correspondem à precisão: Exato requer apenas um valor De, De requer um valor De e Até requer apenas um valor Até.",
+    "AUDIENCE_RANGE_VALUE" => "Intervalo de público inválido: {0}",
+    "PRODUCT_FORM_DETAIL_CODE" => "Detalhe de forma de produto inválido: {0}",
+    "PRODUCT_FORM_FEATURE" => "Característica de forma de produto inválida: {0}",
+    "WORK_DATE" => "Data de obra inválida: {0}",
+    "WORK_STATUS_MISSING_DATE" => "As obras com o estado {0} devem ter uma data do tipo {1}.",
+    "NAME_VARIANT_PARENT" => "Uma variante de nome deve pertencer a um colaborador ou a uma contribuição, mas não a ambos.",
+    "NAME_VARIANT_SCRIPT" => "Código de escrita ISO 15924 não suportado: {0}",
+    "NAME_VARIANT_MISSING_SCRIPT" => "Uma variante de nome transliterada deve indicar a escrita em que está escrita.",
+    "FUNDER_REGISTRY_SNAPSHOT" => "Instantâneo do Funder Registry inválido: {0}",
+    "FUNDER_REGISTRY_UNKNOWN_DOI" => "{0} não é um DOI de financiador registado no Crossref Funder Registry.",
+    "FUNDER_REGISTRY_REPLACED_DOI" => "O DOI de financiador {0} foi substituído por {1} no Crossref Funder Registry.",
+};
+
+/// Portuguese translations of `DATABASE_CONSTRAINT_ERRORS`, keyed by constraint name
+pub(super) static CONSTRAINT_MESSAGES: Map<&'static str, &'static str> = phf_map! {
+    "affiliation_affiliation_ordinal_check" => "O número ordinal de uma afiliação deve ser superior a 0.",
+    "affiliation_position_check" => "O cargo não pode estar vazio.",
+    "affiliation_affiliation_ordinal_contribution_id_uniq" => "Já existe uma afiliação com este número ordinal.",
+    "abstract_content_check" => "O conteúdo do resumo não pode estar vazio.",
+    "abstract_uniq_locale_idx" => "Já existe um resumo da obra com este código de idioma e tipo.",
+    "abstract_unique_canonical_true_idx" => "Já existe um resumo canónico para esta obra.",
+    "additional_resource_resource_ordinal_work_id_uniq" => "Já existe um recurso adicional com este número ordinal.",
+    "biography_content_check" => "O conteúdo da biografia não pode estar vazio.",
+    "biography_uniq_locale_idx" => "Já existe uma biografia da contribuição com este código de idioma.",
+    "biography_unique_canonical_true_idx" => "Já existe uma biografia canónica para esta contribuição.",
+    "audience_range_check" => "O qualificador e a precisão do intervalo de público devem ser indicados em conjunto, e os valores do intervalo exigem ambos.",
+    "audience_range_from_check" => "O valor De do intervalo de público não pode estar vazio.",
+    "audience_range_precision_check" => "Os valores do intervalo de público não correspondem à precisão: Exato requer apenas um valor De, De requer um valor De e Até requer apenas um valor Até.",
+    "audience_range_to_check" => "O valor Até do intervalo de público não pode estar vazio.",
+    "audience_uniq_idx" => "Já existe um público com este código e qualificador de intervalo para esta obra.",
+    "award_award_ordinal_work_id_uniq" => "Já existe um prémio com este número ordinal.",
+    "book_review_review_ordinal_work_id_uniq" => "Já existe uma recensão com este número ordinal.",
+    "contribution_contribution_ordinal_check" => "O número ordinal de uma contribuição deve ser superior a 0.",
+    "contribution_contribution_ordinal_work_id_uniq" => "Já existe uma contribuição com este número ordinal.",
+    "contribution_first_name_check" => "O nome próprio não pode estar vazio.",
+    "contribution_full_name_check" => "O nome completo não pode estar vazio.",
+    "contribution_last_name_check" => "O apelido não pode estar vazio.",
+    "contribution_work_id_contributor_id_contribution_type_uniq" => "Já existe uma contribuição deste tipo para este colaborador.",
+    "contributor_first_name_check" => "O nome próprio não pode estar vazio.",
+    "contributor_full_name_check" => "O nome completo não pode estar vazio.",
+    "contributor_last_name_check" => "O apelido não pode estar vazio.",
+    "contributor_orcid_check" => "ORCID inválido.",
+    "contributor_website_check" => "O sítio web não pode estar vazio.",
+    "credit_role_uniq_idx" => "Esta função CRediT já foi atribuída a esta contribuição.",
+    "doi_uniq_idx" => "Já existe uma obra com este DOI.",
+    "email_uniq_idx" => "Já existe uma conta com este endereço de e-mail.",
+    "endorsement_endorsement_ordinal_work_id_uniq" => "Já existe uma recomendação com este número ordinal.",
+    "funding_grant_number_check" => "O número de financiamento não pode estar vazio.",
+    "funding_program_check" => "O programa não pode estar vazio.",
+    "funding_project_name_check" => "O nome do projeto não pode estar vazio.",
+    "funding_project_shortname_check" => "O nome abreviado do projeto não pode estar vazio.",
+    "imprint_imprint_name_check" => "O nome da chancela editorial não pode estar vazio.",
+    "imprint_imprint_url_check" => "URL inválido.",
+    "imprint_storage_cfg_all_or_none" => "A configuração de armazenamento deve estar completa: todos os campos (bucket S3, região, domínio CDN, ID de distribuição CloudFront) devem ser indicados em conjunto, ou ficar todos vazios.",
+    "imprint_uniq_idx" => "Já existe uma chancela editorial com este nome.",
+    "institution_doi_uniq_idx" => "Já existe uma instituição com este DOI.",
+    "institution_institution_doi_check" => "DOI inválido.",
+    "institution_institution_name_check" => "O nome não pode estar vazio.",
+    "institution_ror_check" => "ROR inválido.",
+    "issue_issue_ordinal_check" => "O número ordinal de um número de série deve ser superior a 0.",
+    "issue_series_id_work_id_uniq" => "Já existe um número da série selecionada para esta obra.",
+    "issue_issue_ordinal_series_id_uniq" => "Já existe um número de série com este número ordinal.",
+    "language_uniq_work_idx" => "Código de idioma duplicado.",
+    "location_full_text_url_check" => "URL inválido.",
+    "location_landing_page_check" => "URL inválido.",
+    "location_uniq_canonical_true_idx" => "Já existe uma localização canónica para esta publicação.",
+    "location_uniq_platform_idx" => "Já existe uma localização na plataforma selecionada.",
+    "location_url_check" => "Uma localização deve ter uma página de destino e/ou um URL de texto integral.",
+    "name_variant_contribution_uniq_idx" => "Já existe uma variante de nome deste tipo com este nome para esta contribuição.",
+    "name_variant_contributor_uniq_idx" => "Já existe uma variante de nome deste tipo com este nome para este colaborador.",
+    "name_variant_first_name_check" => "O nome próprio não pode estar vazio.",
+    "name_variant_full_name_check" => "O nome completo não pode estar vazio.",
+    "name_variant_last_name_check" => "O apelido não pode estar vazio.",
+    "name_variant_parent_check" => "Uma variante de nome deve pertencer a um colaborador ou a uma contribuição, mas não a ambos.",
+    "name_variant_script_code_check" => "Um código de escrita deve ser um código ISO 15924 de quatro letras, p. ex. Cyrl.",
+    "file_frontcover_work_unique_idx" => "Já existe um ficheiro de capa para esta obra.",
+    "file_object_key_unique_idx" => "Já existe um ficheiro com esta chave de objeto.",
+    "file_publication_unique_idx" => "Já existe um ficheiro para esta publicação.",
+    "file_additional_resource_unique_idx" => "Já existe um ficheiro para este recurso adicional.",
+    "file_work_featured_video_unique_idx" => "Já existe um ficheiro para este vídeo em destaque.",
+    "file_type_check" => "O tipo de ficheiro é inválido: frontcover deve ter work_id, publication deve ter publication_id, additional_resource deve ter additional_resource_id e work_featured_video deve ter work_featured_video_id.",
+    "file_upload_type_check" => "O tipo de carregamento é inválido: frontcover deve ter work_id, publication deve ter publication_id, additional_resource deve ter additional_resource_id e work_featured_video deve ter work_featured_video_id.",
+    "orcid_uniq_idx" => "Já existe um colaborador com este ORCID.",
+    "price_countries_included_check" => "Um território deve incluir pelo menos um país, ou omitir os países incluídos para indicar todo o mundo.",
+    "price_effective_dates_check" => "A data de fim de vigência do preço não pode ser anterior à sua data de início.",
+    "price_publication_id_currency_code_uniq" => "Já existe um preço nesta moeda, território e tipo para esta publicação a partir desta data.",
+    "price_unit_price_check" => "Os preços devem ser superiores a zero. Para indicar uma publicação sem preço, omita todos os preços.",
+    "product_form_detail_detail_code_check" => "O código de detalhe de forma de produto não pode estar vazio.",
+    "product_form_detail_feature_check" => "Os valores e descrições de características só se aplicam às características de forma de produto.",
+    "product_form_detail_feature_description_check" => "A descrição da característica de forma de produto não pode estar vazia.",
+    "product_form_detail_feature_value_check" => "O valor da característica de forma de produto não pode estar vazio.",
+    "product_form_detail_packaging_uniq_idx" => "Esta publicação já tem um tipo de embalagem.",
+    "product_form_detail_uniq_idx" => "Este detalhe de forma de produto já foi adicionado à publicação.",
+    "publication_depth_in_check" => "A profundidade da publicação deve ser superior a 0.0.",
+    "publication_depth_in_not_missing" => "Ao indicar a profundidade, devem ser indicados ambos os valores (mm e in).",
+    "publication_depth_mm_check" => "A profundidade da publicação deve ser superior a 0.0.",
+    "publication_depth_mm_not_missing" => "Ao indicar a profundidade, devem ser indicados ambos os valores (mm e in).",
+    "publication_embargo_end_date_check" => "Deve ser indicada uma data de fim de embargo para as publicações embargadas, e apenas para elas.",
+    "publication_height_in_check" => "A altura da publicação deve ser superior a 0.0.",
+    "publication_height_in_not_missing" => "Ao indicar a altura, devem ser indicados ambos os valores (mm e in).",
+    "publication_height_mm_check" => "A altura da publicação deve ser superior a 0.0.",
+    "publication_height_mm_not_missing" => "Ao indicar a altura, devem ser indicados ambos os valores (mm e in).",
+    "publication_isbn_check" => "Um ISBN válido deve ter exatamente 17 caracteres.",
+    "publication_isbn_idx" => "Já existe uma publicação com este ISBN.",
+    "publication_license_check" => "URL de licença inválido.",
+    "publication_non_physical_no_dimensions" => "A largura, altura, profundidade e peso só se aplicam a publicações físicas (brochura ou capa dura).",
+    "publication_publication_type_work_id_uniq" => "Já existe uma publicação do tipo selecionado.",
+    "publication_weight_g_check" => "O peso da publicação deve ser superior a 0.0.",
+    "publication_weight_g_not_missing" => "Ao indicar o peso, devem ser indicados ambos os valores (g e oz).",
+    "publication_weight_oz_check" => "O peso da publicação deve ser superior a 0.0.",
+    "publication_weight_oz_not_missing" => "Ao indicar o peso, devem ser indicados ambos os valores (g e oz).",
+    "publication_width_in_check" => "A largura da publicação deve ser superior a 0.0.",
+    "publication_width_in_not_missing" => "Ao indicar a largura, devem ser indicados ambos os valores (mm e in).",
+    "publication_width_mm_check" => "A largura da publicação deve ser superior a 0.0.",
+    "publication_width_mm_not_missing" => "Ao indicar a largura, devem ser indicados ambos os valores (mm e in).",
+    "publisher_publisher_name_check" => "O nome da editora não pode estar vazio.",
+    "publisher_publisher_shortname_check" => "O nome abreviado da editora não pode estar vazio.",
+    "publisher_publisher_url_check" => "URL inválido.",
+    "publisher_uniq_idx" => "Já existe uma editora com este nome.",
+    "reference_article_title_check" => "O título do artigo não pode estar vazio.",
+    "reference_author_check" => "O autor não pode estar vazio.",
+    "reference_component_number_check" => "O número de componente não pode estar vazio.",
+    "reference_doi_andor_unstructured_citation" => "Uma referência deve ter um DOI e/ou a citação não estruturada.",
+    "reference_doi_check" => "DOI inválido.",
+    "reference_edition_check" => "O número da edição deve ser superior a 0.",
+    "reference_first_page_check" => "A primeira página não pode estar vazia.",
+    "reference_isbn_check" => "Um ISBN válido deve ter exatamente 17 caracteres.",
+    "reference_issn_check" => "ISSN inválido.",
+    "reference_issue_check" => "O número não pode estar vazio.",
+    "reference_journal_title_check" => "O título da revista não pode estar vazio.",
+    "reference_reference_ordinal_check" => "O número ordinal de uma referência deve ser superior a 0.",
+    "reference_reference_ordinal_work_id_uniq" => "Já existe uma referência com este número ordinal.",
+    "reference_series_title_check" => "O título da série não pode estar vazio.",
+    "reference_standard_citation_required_fields" => "A citação de uma norma deve incluir o identificador da norma, bem como o nome e a sigla de um organismo de normalização.",
+    "reference_standard_designator_check" => "O identificador da norma não pode estar vazio.",
+    "reference_standards_body_acronym_check" => "A sigla do organismo de normalização não pode estar vazia.",
+    "reference_standards_body_name_check" => "O nome do organismo de normalização não pode estar vazio.",
+    "reference_unstructured_citation_check" => "A citação não estruturada não pode estar vazia.",
+    "reference_url_check" => "URL inválido.",
+    "reference_volume_check" => "O volume não pode estar vazio.",
+    "reference_volume_title_check" => "O título do volume não pode estar vazio.",
+    "sales_rights_countries_excluded_check" => "Os países excluídos só podem ser indicados em territórios mundiais.",
+    "sales_rights_countries_included_check" => "Um território deve incluir pelo menos um país, ou omitir os países incluídos para indicar todo o mundo.",
+    "series_issn_digital_check" => "ISSN digital inválido.",
+    "series_issn_digital_idx" => "Já existe uma série com este ISSN digital.",
+    "series_issn_print_check" => "ISSN impresso inválido.",
+    "series_issn_print_idx" => "Já existe uma série com este ISSN impresso.",
+    "series_series_cfp_url_check" => "URL de chamada de contribuições inválido.",
+    "series_series_description_check" => "A descrição da série não pode estar vazia.",
+    "series_series_name_check" => "O nome da série não pode estar vazio.",
+    "series_series_url_check" => "URL da série inválido.",
+    "subject_ordinal_type_uniq" => "Já existe um assunto com este número ordinal e tipo.",
+    "subject_subject_code_check" => "Os códigos de assunto não podem estar vazios.",
+    "subject_subject_ordinal_check" => "O número ordinal de um assunto deve ser superior a 0.",
+    "title_unique_canonical_true_idx" => "Já existe um título canónico para esta obra.",
+    "title_full_title_check" => "O título completo não pode estar vazio.",
+    "title_subtitle_check" => "O subtítulo não pode estar vazio.",
+    "title_title_check" => "O título não pode estar vazio.",
+    "title_uniq_locale_idx" => "Já existe um título da obra com este código de idioma.",
+    "work_active_withdrawn_date_check" => "A data de retirada só pode ser indicada para uma obra retirada ou substituída.",
+    "work_active_publication_date_check" => "As obras ativas, substituídas e retiradas devem ter data de publicação.",
+    "work_audio_count_check" => "O número de áudios deve ser superior a 0.",
+    "work_bibliography_note_check" => "A nota bibliográfica não pode estar vazia.",
+    "work_chapter_no_edition" => "Os capítulos não podem ter número de edição.",
+    "work_chapter_no_lccn" => "Os capítulos não podem ter LCCN.",
+    "work_chapter_no_oclc" => "Os capítulos não podem ter número OCLC.",
+    "work_chapter_no_toc" => "Os capítulos não podem ter índice.",
+    "work_copyright_holder_check" => "O titular dos direitos de autor não pode estar vazio.",
+    "work_cover_caption_check" => "A legenda da capa não pode estar vazia.",
+    "work_cover_url_check" => "URL da capa inválido.",
+    "work_date_uniq_idx" => "Já existe uma data deste tipo para esta obra.",
+    "work_doi_check" => "DOI inválido.",
+    "work_edition_check" => "O número da edição deve ser superior a 0.",
+    "work_first_page_check" => "A primeira página não pode estar vazia.",
+    "work_general_note_check" => "A nota geral não pode estar vazia.",
+    "work_image_count_check" => "O número de imagens deve ser superior a 0.",
+    "work_inactive_no_withdrawn_date_check" => "Uma obra retirada ou substituída deve incluir a data de retirada.",
+    "work_landing_page_check" => "URL da página de destino inválido.",
+    "work_last_page_check" => "A última página não pode estar vazia.",
+    "work_lccn_check" => "O LCCN não pode estar vazio.",
+    "work_license_check" => "URL de licença inválido.",
+    "work_long_abstract_check" => "O resumo longo não pode estar vazio.",
+    "work_non_chapter_has_edition" => "O número da edição é obrigatório (exceto para os capítulos).",
+    "work_non_chapter_no_first_page" => "A primeira página só pode ser indicada para capítulos de livro.",
+    "work_non_chapter_no_last_page" => "A última página só pode ser indicada para capítulos de livro.",
+    "work_non_chapter_no_page_interval" => "O intervalo de páginas só pode ser indicado para capítulos de livro.",
+    "work_oclc_check" => "O número OCLC não pode estar vazio.",
+    "work_page_breakdown_check" => "A discriminação de páginas não pode estar vazia.",
+    "work_page_count_check" => "O número de páginas deve ser superior a 0.",
+    "work_page_interval_check" => "O intervalo de páginas não pode estar vazio.",
+    "work_place_check" => "O local não pode estar vazio.",
+    "work_reference_check" => "A referência não pode estar vazia.",
+    "work_relation_ids_check" => "Uma obra não pode estar relacionada consigo própria.",
+    "work_relation_ordinal_type_uniq" => "Já existe uma relação com este número ordinal e tipo.",
+    "work_relation_relation_ordinal_check" => "O número ordinal de uma relação entre obras deve ser superior a 0.",
+    "work_relation_relator_related_uniq" => "Já existe uma relação entre estas duas obras.",
+    "work_short_abstract_check" => "O resumo breve não pode estar vazio.",
+    "work_table_count_check" => "O número de tabelas deve ser superior a 0.",
+    "work_toc_check" => "O índice não pode estar vazio.",
+    "work_video_count_check" => "O número de vídeos deve ser superior a 0.",
+    "work_withdrawn_date_after_publication_date_check" => "A data de publicação deve ser anterior à data de retirada.",
+    "work_featured_video_work_id_key" => "Já existe um vídeo em destaque para esta obra.",
+};
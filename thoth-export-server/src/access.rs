@@ -0,0 +1,81 @@
+use chrono::{NaiveDate, Utc};
+use thoth_api::model::publication::AccessStatus;
+use thoth_client::{PublicationType, WorkPublications, WorkRelationsRelatedWorkPublications};
+
+/// Licence and access status of a publication, resolved against its work's licence
+/// as of today's date.
+pub(crate) struct PublicationAccess<'a> {
+    pub license: Option<&'a str>,
+    pub status: AccessStatus,
+    pub embargo_end_date: Option<NaiveDate>,
+}
+
+impl<'a> PublicationAccess<'a> {
+    pub fn is_open(&self) -> bool {
+        self.status == AccessStatus::Open && self.license.is_some()
+    }
+
+    /// Licence to declare for this publication: present unless access is restricted.
+    /// Embargoed publications declare the licence which will apply once the embargo ends.
+    pub fn declared_license(&self) -> Option<&'a str> {
+        match self.status {
+            AccessStatus::Restricted => None,
+            AccessStatus::Open | AccessStatus::Embargoed => self.license,
+        }
+    }
+
+    /// Date from which the declared licence applies, if it does not already
+    pub fn license_start_date(&self) -> Option<NaiveDate> {
+        match self.status {
+            AccessStatus::Embargoed => self.embargo_end_date,
+            AccessStatus::Open | AccessStatus::Restricted => None,
+        }
+    }
+}
+
+pub(crate) trait PublicationAccessFields {
+    fn is_digital(&self) -> bool;
+    fn license(&self) -> Option<&String>;
+    fn access_status(&self) -> Option<AccessStatus>;
+    fn embargo_end_date(&self) -> Option<NaiveDate>;
+
+    /// Resolve this publication's access against its work's licence, which applies
+    /// wherever the publication does not record its own.
+    fn access<'a>(&'a self, work_license: Option<&'a String>) -> PublicationAccess<'a> {
+        let license = self.license().or(work_license).map(String::as_str);
+        PublicationAccess {
+            license,
+            status: AccessStatus::resolve(
+                self.access_status(),
+                self.embargo_end_date(),
+                license.is_some(),
+                Utc::now().date_naive(),
+            ),
+            embargo_end_date: self.embargo_end_date(),
+        }
+    }
+}
+
+macro_rules! publication_access_fields {
+    ($t:ty) => {
+        impl PublicationAccessFields for $t {
+            fn is_digital(&self) -> bool {
+                !matches!(
+                    self.publication_type,
+                    PublicationType::PAPERBACK | PublicationType::HARDBACK
+                )
+            }
+            fn license(&self) -> Option<&String> {
+                self.license.as_ref()
+            }
+            fn access_status(&self) -> Option<AccessStatus> {
+                self.access_status.clone().map(Into::into)
+            }
+            fn embargo_end_date(&self) -> Option<NaiveDate> {
+                self.embargo_end_date
+            }
+        }
+    };
+}
+publication_access_fields!(WorkPublications);
+publication_access_fields!(WorkRelationsRelatedWorkPublications);
@@ -123,7 +123,7 @@ impl TryFrom<Work> for BibtexThothEntry {
 
         let mut contributions = work.contributions;
         // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
-        contributions.sort_by(|a, b| a.contribution_ordinal.cmp(&b.contribution_ordinal));
+        contributions.sort_by_key(|c| c.contribution_ordinal);
         let (author, editor) = extract_authors_and_editors(contributions)?;
 
         let shorttitle = work.titles[0]
@@ -382,9 +382,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 1,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -396,9 +399,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 2,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -410,9 +416,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 3,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -424,9 +433,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 4,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -438,9 +450,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 5,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -452,9 +467,12 @@ mod tests {
                     main_contribution: false,
                     biographies: vec![],
                     contribution_ordinal: 6,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -466,9 +484,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 7,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -496,6 +517,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -518,9 +544,21 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
             ],
             subjects: vec![],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![],
             relations: vec![WorkRelations {
                 relation_type: RelationType::IS_CHILD_OF,
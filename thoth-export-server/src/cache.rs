@@ -0,0 +1,358 @@
+use futures::TryStreamExt;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use thoth_api::model::work::{Work, WorkStatus};
+use thoth_api::model::Timestamp;
+use thoth_api::redis::{del, get, memory_usage, scan_match, RedisPool};
+use thoth_client::{QueryArguments, ThothClient};
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::data::ALL_SPECIFICATIONS;
+use crate::record::{MetadataRecord, MetadataSpecification};
+use crate::specification_query::SpecificationQuery;
+
+const PAGE_SIZE: i64 = 100;
+
+/// Selection of cached records. Empty lists match everything.
+#[derive(Debug, Clone, Default)]
+pub struct CacheFilter {
+    /// Specification IDs, e.g. `onix_3.0::thoth`
+    pub specifications: Vec<String>,
+    /// Thoth IDs of the works or publishers the records were generated for
+    pub ids: Vec<Uuid>,
+    /// Only match records generated from data last updated before this time
+    pub older_than: Option<Timestamp>,
+}
+
+/// Record, or generation error, cached by the export API for a work or publisher
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedRecord {
+    pub specification: String,
+    pub id: Uuid,
+    /// Last update of the data the record was generated from
+    pub last_updated: Option<Timestamp>,
+    pub has_record: bool,
+    pub has_error: bool,
+    pub keys: Vec<String>,
+    pub memory_bytes: u64,
+}
+
+/// Totals of the records cached for a specification
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+    pub specification: String,
+    pub records: usize,
+    pub errors: usize,
+    pub keys: usize,
+    pub memory_bytes: u64,
+}
+
+/// Outcome of generating a record while warming the cache
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarmedRecord {
+    pub specification: String,
+    pub work_id: Uuid,
+    /// Whether the record was generated, as opposed to only listed in a dry run
+    pub generated: bool,
+    /// Error cached in place of the record, if it could not be generated
+    pub error: Option<String>,
+}
+
+impl CacheFilter {
+    /// Specifications to look into, checking that the requested ones exist
+    fn specifications(&self) -> ThothResult<Vec<String>> {
+        if self.specifications.is_empty() {
+            return Ok(ALL_SPECIFICATIONS
+                .iter()
+                .map(|specification| specification.id.to_string())
+                .collect());
+        }
+        for specification in &self.specifications {
+            if !ALL_SPECIFICATIONS
+                .iter()
+                .any(|s| s.id == specification.as_str())
+            {
+                return Err(ThothError::InvalidMetadataSpecification(
+                    specification.to_string(),
+                ));
+            }
+        }
+        Ok(self.specifications.clone())
+    }
+
+    fn matches(&self, record: &CachedRecord) -> bool {
+        (self.ids.is_empty() || self.ids.contains(&record.id))
+            && self.older_than.as_ref().is_none_or(|older_than| {
+                record
+                    .last_updated
+                    .as_ref()
+                    .is_some_and(|last_updated| last_updated < older_than)
+            })
+    }
+}
+
+/// Split a cache key (`{specification}:{id}`, optionally followed by `:timestamp` or `:error`)
+/// into the ID it belongs to and its suffix
+fn parse_key<'a>(specification: &str, key: &'a str) -> Option<(Uuid, Option<&'a str>)> {
+    let rest = key.strip_prefix(specification)?.strip_prefix(':')?;
+    let (id, suffix) = match rest.split_once(':') {
+        Some((id, suffix)) => (id, Some(suffix)),
+        None => (rest, None),
+    };
+    Uuid::parse_str(id).ok().map(|id| (id, suffix))
+}
+
+/// List the records cached by the export API that match `filter`
+pub async fn list_cached_records(
+    redis_pool: &RedisPool,
+    filter: &CacheFilter,
+) -> ThothResult<Vec<CachedRecord>> {
+    let mut records = vec![];
+    for specification in filter.specifications()? {
+        let mut by_id: BTreeMap<Uuid, CachedRecord> = BTreeMap::new();
+        for key in scan_match(redis_pool, &format!("{specification}:*")).await? {
+            let Some((id, suffix)) = parse_key(&specification, &key) else {
+                continue;
+            };
+            let record = by_id.entry(id).or_insert_with(|| CachedRecord {
+                specification: specification.clone(),
+                id,
+                last_updated: None,
+                has_record: false,
+                has_error: false,
+                keys: vec![],
+                memory_bytes: 0,
+            });
+            match suffix {
+                None => record.has_record = true,
+                Some("error") => record.has_error = true,
+                Some("timestamp") => {
+                    record.last_updated = get(redis_pool, &key)
+                        .await
+                        .ok()
+                        .and_then(|value| Timestamp::parse_from_rfc3339(&value).ok())
+                }
+                Some(_) => continue,
+            }
+            record.memory_bytes += memory_usage(redis_pool, &key).await?;
+            record.keys.push(key);
+        }
+        records.extend(by_id.into_values().filter(|record| filter.matches(record)));
+    }
+    Ok(records)
+}
+
+/// Sum up cached records per specification
+pub fn cache_stats(records: &[CachedRecord]) -> Vec<CacheStats> {
+    let mut stats: BTreeMap<&str, CacheStats> = BTreeMap::new();
+    for record in records {
+        let entry = stats
+            .entry(&record.specification)
+            .or_insert_with(|| CacheStats {
+                specification: record.specification.clone(),
+                ..Default::default()
+            });
+        entry.records += usize::from(record.has_record);
+        entry.errors += usize::from(record.has_error);
+        entry.keys += record.keys.len();
+        entry.memory_bytes += record.memory_bytes;
+    }
+    stats.into_values().collect()
+}
+
+/// Delete cached records, along with their timestamps and errors
+pub async fn delete_cached_records(
+    redis_pool: &RedisPool,
+    records: &[CachedRecord],
+) -> ThothResult<()> {
+    for key in records.iter().flat_map(|record| &record.keys) {
+        del(redis_pool, key).await?;
+    }
+    Ok(())
+}
+
+/// IDs of the records cached for a publisher: the publisher's own records, generated for all
+/// its works, followed by those of each of its works
+pub async fn publisher_record_ids(
+    thoth_client: &ThothClient,
+    publisher_id: Uuid,
+) -> ThothResult<Vec<Uuid>> {
+    let arguments = QueryArguments::new().publishers(vec![publisher_id]);
+    let mut ids = vec![publisher_id];
+    ids.extend(
+        thoth_client
+            .stream_entities::<Work>(arguments, PAGE_SIZE)
+            .map_ok(|work| work.work_id)
+            .try_collect::<Vec<Uuid>>()
+            .await?,
+    );
+    Ok(ids)
+}
+
+/// Generate and cache the records of every active work of a publisher, in each of the given
+/// specifications (all of them if empty).
+///
+/// Records that are already cached and up to date are left as they are. With `dry_run`, the
+/// records are only listed.
+pub async fn warm_cache(
+    thoth_client: Arc<ThothClient>,
+    redis_pool: Arc<RedisPool>,
+    specifications: &[String],
+    publisher_id: Uuid,
+    dry_run: bool,
+) -> ThothResult<Vec<WarmedRecord>> {
+    let filter = CacheFilter {
+        specifications: specifications.to_vec(),
+        ..Default::default()
+    };
+    let specifications = filter
+        .specifications()?
+        .iter()
+        .map(|id| id.parse::<MetadataSpecification>())
+        .collect::<ThothResult<Vec<_>>>()?;
+    let arguments = QueryArguments::new()
+        .publishers(vec![publisher_id])
+        .argument("workStatuses", vec![WorkStatus::Active]);
+    let works: Vec<Work> = thoth_client
+        .stream_entities(arguments, PAGE_SIZE)
+        .try_collect()
+        .await?;
+
+    let mut warmed = vec![];
+    for work in works {
+        for specification in &specifications {
+            let mut warmed_record = WarmedRecord {
+                specification: specification.to_string(),
+                work_id: work.work_id,
+                generated: false,
+                error: None,
+            };
+            if !dry_run {
+                let specification_query =
+                    SpecificationQuery::by_work(thoth_client.clone(), work.work_id, *specification);
+                let mut metadata_record = MetadataRecord::new(
+                    work.work_id.to_string(),
+                    *specification,
+                    work.updated_at_with_relations,
+                );
+                metadata_record
+                    .load_or_generate(specification_query, redis_pool.clone())
+                    .await?;
+                warmed_record.generated = true;
+                warmed_record.error = metadata_record.into_file().err().map(|e| e.to_string());
+            }
+            warmed.push(warmed_record);
+        }
+    }
+    Ok(warmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORK_ID: &str = "00000000-0000-0000-aaaa-000000000001";
+
+    fn cached_record(specification: &str, has_error: bool, bytes: u64) -> CachedRecord {
+        CachedRecord {
+            specification: specification.to_string(),
+            id: Uuid::parse_str(WORK_ID).unwrap(),
+            last_updated: Some(Timestamp::parse_from_rfc3339("2025-01-01T00:00:00Z").unwrap()),
+            has_record: !has_error,
+            has_error,
+            keys: vec![
+                format!("{specification}:{WORK_ID}"),
+                format!("{specification}:{WORK_ID}:timestamp"),
+            ],
+            memory_bytes: bytes,
+        }
+    }
+
+    #[test]
+    fn test_parse_key() {
+        let id = Uuid::parse_str(WORK_ID).unwrap();
+        assert_eq!(
+            parse_key("onix_3.0::thoth", &format!("onix_3.0::thoth:{WORK_ID}")),
+            Some((id, None))
+        );
+        assert_eq!(
+            parse_key(
+                "onix_3.0::thoth",
+                &format!("onix_3.0::thoth:{WORK_ID}:timestamp")
+            ),
+            Some((id, Some("timestamp")))
+        );
+        assert_eq!(
+            parse_key("onix_3.0::thoth", "onix_3.0::thoth:not-an-id"),
+            None
+        );
+        assert_eq!(
+            parse_key("onix_3.0::thoth", &format!("csv::thoth:{WORK_ID}")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let record = cached_record("csv::thoth", false, 10);
+        assert!(CacheFilter::default().matches(&record));
+        let other_id = CacheFilter {
+            ids: vec![Uuid::nil()],
+            ..Default::default()
+        };
+        assert!(!other_id.matches(&record));
+        let older_than = |timestamp: &str| CacheFilter {
+            older_than: Some(Timestamp::parse_from_rfc3339(timestamp).unwrap()),
+            ..Default::default()
+        };
+        assert!(older_than("2025-06-01T00:00:00Z").matches(&record));
+        assert!(!older_than("2024-06-01T00:00:00Z").matches(&record));
+    }
+
+    #[test]
+    fn test_filter_unknown_specification() {
+        let filter = CacheFilter {
+            specifications: vec!["onix_9::thoth".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.specifications(),
+            Err(ThothError::InvalidMetadataSpecification(
+                "onix_9::thoth".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_cache_stats() {
+        let stats = cache_stats(&[
+            cached_record("csv::thoth", false, 10),
+            cached_record("csv::thoth", true, 5),
+            cached_record("bibtex::thoth", false, 7),
+        ]);
+        assert_eq!(
+            stats,
+            vec![
+                CacheStats {
+                    specification: "bibtex::thoth".to_string(),
+                    records: 1,
+                    errors: 0,
+                    keys: 2,
+                    memory_bytes: 7,
+                },
+                CacheStats {
+                    specification: "csv::thoth".to_string(),
+                    records: 1,
+                    errors: 1,
+                    keys: 4,
+                    memory_bytes: 15,
+                },
+            ]
+        );
+    }
+}
@@ -1,21 +1,37 @@
-use csv::Writer;
-use serde::Serialize;
+use chrono::NaiveDate;
+use csv::{ReaderBuilder, Writer};
+use serde::{Deserialize, Serialize};
 use std::io::Write;
+use std::str::FromStr;
 use thoth_api::markup::ast::{ast_to_plain_text, jats_to_ast};
+use thoth_api::model::contribution::NewContribution;
+use thoth_api::model::contributor::NewContributor;
+use thoth_api::model::language::NewLanguage;
+use thoth_api::model::locale::LocaleCode;
+use thoth_api::model::location::NewLocation;
+use thoth_api::model::price::NewPrice;
+use thoth_api::model::publication::NewPublication;
+use thoth_api::model::r#abstract::{self, NewAbstract};
+use thoth_api::model::subject::{self, NewSubject};
+use thoth_api::model::title::NewTitle;
+use thoth_api::model::work::NewWork;
+use thoth_api::model::{Doi, Isbn, Orcid};
 use thoth_client::{
     AbstractType, SubjectType, Work, WorkContributions, WorkContributionsAffiliations,
     WorkFundings, WorkIssues, WorkLanguages, WorkPublications, WorkPublicationsLocations,
     WorkPublicationsPrices, WorkReferences, WorkRelations, WorkSubjects,
 };
-use thoth_errors::ThothResult;
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
 
 use super::{CsvCell, CsvRow, CsvSpecification};
+use crate::import::{parse_enum, ImportedContribution, ImportedPublication, ImportedWork};
 
 #[derive(Copy, Clone)]
 pub(crate) struct CsvThoth;
 
-#[derive(Debug, Serialize)]
-struct CsvThothRow {
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CsvThothRow {
     publisher: String,
     imprint: String,
     work_type: String,
@@ -110,7 +126,7 @@ impl From<Work> for CsvThothRow {
     fn from(work: Work) -> Self {
         let mut subjects = work.subjects;
         // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
-        subjects.sort_by(|a, b| a.subject_ordinal.cmp(&b.subject_ordinal));
+        subjects.sort_by_key(|s| s.subject_ordinal);
         CsvThothRow {
             publisher: work.imprint.publisher.publisher_name,
             imprint: work.imprint.imprint_name,
@@ -510,6 +526,396 @@ impl CsvCell<CsvThoth> for WorkReferences {
     }
 }
 
+/// Read back the works of a `csv::thoth` record
+pub(crate) fn read_csv_thoth(input: &str) -> ThothResult<Vec<ImportedWork>> {
+    ReaderBuilder::new()
+        .from_reader(input.as_bytes())
+        .deserialize::<CsvThothRow>()
+        .enumerate()
+        .map(|(index, row)| {
+            // rows are numbered from 2, after the header row, to match spreadsheet line numbers
+            row.map_err(ThothError::from)
+                .and_then(ImportedWork::try_from)
+                .map_err(|e| {
+                    let message = match e {
+                        ThothError::CsvError(message) => message,
+                        other => other.to_string(),
+                    };
+                    ThothError::CsvError(format!("Row {}: {}", index + 2, message))
+                })
+        })
+        .collect()
+}
+
+/// Value of a `csv::thoth` cell: a quoted string, or a list or tuple of values
+#[derive(Debug, PartialEq)]
+enum CellValue {
+    Text(String),
+    Items(Vec<CellValue>),
+}
+
+impl CellValue {
+    fn text(&self) -> ThothResult<&str> {
+        match self {
+            CellValue::Text(text) => Ok(text),
+            CellValue::Items(_) => Err(ThothError::CsvError(
+                "Expected a quoted value, found a list".to_string(),
+            )),
+        }
+    }
+
+    fn items(&self) -> ThothResult<&[CellValue]> {
+        match self {
+            CellValue::Items(items) => Ok(items),
+            CellValue::Text(text) => Err(ThothError::CsvError(format!(
+                "Expected a list, found \"{text}\""
+            ))),
+        }
+    }
+
+    /// Get the text at `index` of a tuple, treating empty strings as missing values
+    fn field(&self, index: usize) -> ThothResult<Option<&str>> {
+        let items = self.items()?;
+        let value = items.get(index).ok_or_else(|| {
+            ThothError::CsvError(format!(
+                "Expected at least {} values, found {}",
+                index + 1,
+                items.len()
+            ))
+        })?;
+        value
+            .text()
+            .map(|text| Some(text).filter(|text| !text.is_empty()))
+    }
+
+    /// Get the text at `index` of a tuple, failing if it is empty
+    fn required_field(&self, index: usize) -> ThothResult<&str> {
+        self.field(index)?.ok_or_else(|| {
+            ThothError::CsvError(format!("Missing required value at position {}", index + 1))
+        })
+    }
+
+    /// Get the list at `index` of a tuple
+    fn list(&self, index: usize) -> ThothResult<&[CellValue]> {
+        match self.items()?.get(index) {
+            Some(value) => value.items(),
+            None => Ok(&[]),
+        }
+    }
+}
+
+/// Parser for the list notation used in the multi-valued columns of `csv::thoth`, e.g.
+/// `[("AUTHOR", "Jane", "Doe"),("EDITOR", "John", "Doe")]`.
+///
+/// Strings are written without escaping, so a closing quote is only recognised when it is
+/// followed by a separator or the end of the cell.
+struct CellParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> CellParser<'a> {
+    /// Parse a whole cell, which is either empty or a list of values
+    fn parse(input: &'a str) -> ThothResult<Vec<CellValue>> {
+        let mut parser = CellParser { input, position: 0 };
+        let value = parser.value()?;
+        parser.skip_spaces();
+        if parser.position < input.len() {
+            return Err(parser.error("Unexpected trailing characters"));
+        }
+        match value {
+            CellValue::Items(items) => Ok(items),
+            CellValue::Text(text) => Err(ThothError::CsvError(format!(
+                "Expected a list, found \"{text}\""
+            ))),
+        }
+    }
+
+    fn error(&self, message: &str) -> ThothError {
+        ThothError::CsvError(format!("{} at position {}", message, self.position + 1))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn skip_spaces(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start_matches(' ').len();
+    }
+
+    fn value(&mut self) -> ThothResult<CellValue> {
+        self.skip_spaces();
+        match self.peek() {
+            Some('"') => self.text(),
+            Some('[') => self.sequence(']'),
+            Some('(') => self.sequence(')'),
+            // empty lists are written as nothing at all
+            _ => Ok(CellValue::Items(vec![])),
+        }
+    }
+
+    fn text(&mut self) -> ThothResult<CellValue> {
+        let start = self.position + 1;
+        let mut search = start;
+        loop {
+            let end = self.input[search..]
+                .find('"')
+                .map(|offset| search + offset)
+                .ok_or_else(|| self.error("Unterminated string"))?;
+            let after = self.input[end + 1..].trim_start_matches(' ');
+            if after.is_empty() || after.starts_with([',', ')', ']']) {
+                self.position = end + 1;
+                return Ok(CellValue::Text(self.input[start..end].to_string()));
+            }
+            search = end + 1;
+        }
+    }
+
+    fn sequence(&mut self, close: char) -> ThothResult<CellValue> {
+        self.position += 1;
+        self.skip_spaces();
+        let mut items = vec![];
+        if self.peek() == Some(close) {
+            self.position += 1;
+            return Ok(CellValue::Items(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_spaces();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(c) if c == close => {
+                    self.position += 1;
+                    return Ok(CellValue::Items(items));
+                }
+                _ => return Err(self.error(&format!("Expected ',' or '{close}'"))),
+            }
+        }
+    }
+}
+
+fn parse_number<T: FromStr>(value: Option<&str>) -> ThothResult<Option<T>> {
+    value
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| ThothError::CsvError(format!("{value} is not a valid number")))
+        })
+        .transpose()
+}
+
+fn parse_date(value: Option<&str>) -> ThothResult<Option<NaiveDate>> {
+    value
+        .map(|value| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map_err(|_| ThothError::CsvError(format!("{value} is not a valid date")))
+        })
+        .transpose()
+}
+
+fn to_i32(value: Option<i64>) -> ThothResult<Option<i32>> {
+    value
+        .map(|value| {
+            i32::try_from(value)
+                .map_err(|_| ThothError::CsvError(format!("{value} is out of range")))
+        })
+        .transpose()
+}
+
+fn ordinal(index: usize) -> ThothResult<i32> {
+    i32::try_from(index + 1).map_err(|_| ThothError::CsvError("Too many values".to_string()))
+}
+
+impl TryFrom<CsvThothRow> for ImportedWork {
+    type Error = ThothError;
+
+    fn try_from(row: CsvThothRow) -> ThothResult<Self> {
+        let work = NewWork {
+            work_type: parse_enum(&row.work_type)?,
+            work_status: parse_enum(&row.work_status)?,
+            reference: row.reference,
+            edition: to_i32(row.edition)?,
+            imprint_id: Uuid::nil(),
+            doi: row.doi.as_deref().map(Doi::from_str).transpose()?,
+            publication_date: parse_date(row.publication_date.as_deref())?,
+            withdrawn_date: parse_date(row.withdrawn_date.as_deref())?,
+            place: row.publication_place,
+            page_count: to_i32(row.page_count)?,
+            page_breakdown: row.page_breakdown,
+            image_count: to_i32(row.image_count)?,
+            table_count: to_i32(row.table_count)?,
+            audio_count: to_i32(row.audio_count)?,
+            video_count: to_i32(row.video_count)?,
+            license: row.license,
+            copyright_holder: row.copyright_holder,
+            landing_page: row.landing_page,
+            lccn: row.lccn,
+            oclc: row.oclc,
+            general_note: row.general_note,
+            bibliography_note: row.bibliography_note,
+            toc: row.toc,
+            resources_description: None,
+            cover_url: row.cover_url,
+            cover_caption: row.cover_caption,
+            first_page: row.first_page,
+            last_page: row.last_page,
+            page_interval: row.page_interval,
+            expose_latest_file_url: None,
+        };
+        let title = NewTitle {
+            work_id: Uuid::nil(),
+            locale_code: LocaleCode::default(),
+            full_title: match &row.subtitle {
+                Some(subtitle) => format!("{}: {}", row.title, subtitle),
+                None => row.title.clone(),
+            },
+            title: row.title,
+            subtitle: row.subtitle,
+            canonical: true,
+        };
+        let abstracts = [
+            (r#abstract::AbstractType::Short, row.short_abstract),
+            (r#abstract::AbstractType::Long, row.long_abstract),
+        ]
+        .into_iter()
+        .filter_map(|(abstract_type, content)| {
+            content.map(|content| NewAbstract {
+                work_id: Uuid::nil(),
+                content,
+                locale_code: LocaleCode::default(),
+                abstract_type,
+                canonical: true,
+            })
+        })
+        .collect();
+        let contributions = CellParser::parse(&row.contributions)?
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| {
+                let first_name = cell.field(1)?.map(ToString::to_string);
+                let last_name = cell.required_field(2)?.to_string();
+                let full_name = cell.required_field(3)?.to_string();
+                Ok(ImportedContribution {
+                    contributor: NewContributor {
+                        first_name: first_name.clone(),
+                        last_name: last_name.clone(),
+                        full_name: full_name.clone(),
+                        orcid: cell.field(6)?.map(Orcid::from_str).transpose()?,
+                        website: cell.field(7)?.map(ToString::to_string),
+                    },
+                    contribution: NewContribution {
+                        work_id: Uuid::nil(),
+                        contributor_id: Uuid::nil(),
+                        contribution_type: parse_enum(cell.required_field(0)?)?,
+                        main_contribution: cell.field(4)? == Some("true"),
+                        first_name,
+                        last_name,
+                        full_name,
+                        contribution_ordinal: ordinal(index)?,
+                    },
+                    biography: cell.field(5)?.map(ToString::to_string),
+                })
+            })
+            .collect::<ThothResult<_>>()?;
+        let publications = CellParser::parse(&row.publications)?
+            .iter()
+            .map(|cell| {
+                let prices = cell
+                    .list(13)?
+                    .iter()
+                    .map(|price| {
+                        Ok(NewPrice {
+                            publication_id: Uuid::nil(),
+                            currency_code: parse_enum(price.required_field(0)?)?,
+                            unit_price: parse_number(price.field(1)?)?.unwrap_or_default(),
+                            price_type: None,
+                            tax_inclusive: None,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
+                        })
+                    })
+                    .collect::<ThothResult<_>>()?;
+                let locations = cell
+                    .list(14)?
+                    .iter()
+                    .map(|location| {
+                        Ok(NewLocation {
+                            publication_id: Uuid::nil(),
+                            landing_page: location.field(0)?.map(ToString::to_string),
+                            full_text_url: location.field(1)?.map(ToString::to_string),
+                            location_platform: parse_enum(location.required_field(2)?)?,
+                            canonical: location.field(3)? == Some("true"),
+                        })
+                    })
+                    .collect::<ThothResult<_>>()?;
+                Ok(ImportedPublication {
+                    publication: NewPublication {
+                        publication_type: parse_enum(cell.required_field(0)?)?,
+                        work_id: Uuid::nil(),
+                        isbn: cell.field(1)?.map(Isbn::from_str).transpose()?,
+                        width_mm: parse_number(cell.field(2)?)?,
+                        width_in: parse_number(cell.field(4)?)?,
+                        height_mm: parse_number(cell.field(5)?)?,
+                        height_in: parse_number(cell.field(7)?)?,
+                        depth_mm: parse_number(cell.field(8)?)?,
+                        depth_in: parse_number(cell.field(10)?)?,
+                        weight_g: parse_number(cell.field(11)?)?,
+                        weight_oz: parse_number(cell.field(12)?)?,
+                        accessibility_standard: None,
+                        accessibility_additional_standard: None,
+                        accessibility_exception: None,
+                        accessibility_report_url: None,
+                        license: None,
+                        access_status: None,
+                        embargo_end_date: None,
+                    },
+                    prices,
+                    locations,
+                })
+            })
+            .collect::<ThothResult<_>>()?;
+        let languages = CellParser::parse(&row.languages)?
+            .iter()
+            .map(|cell| {
+                Ok(NewLanguage {
+                    work_id: Uuid::nil(),
+                    language_relation: parse_enum(cell.required_field(0)?)?,
+                    language_code: parse_enum(cell.required_field(1)?)?,
+                })
+            })
+            .collect::<ThothResult<_>>()?;
+        let mut subjects = vec![];
+        for (subject_type, cell) in [
+            (subject::SubjectType::Bic, &row.bic),
+            (subject::SubjectType::Thema, &row.thema),
+            (subject::SubjectType::Bisac, &row.bisac),
+            (subject::SubjectType::Lcc, &row.lcc),
+            (subject::SubjectType::Custom, &row.custom),
+            (subject::SubjectType::Keyword, &row.keywords),
+        ] {
+            for (index, code) in CellParser::parse(cell)?.iter().enumerate() {
+                subjects.push(NewSubject {
+                    work_id: Uuid::nil(),
+                    subject_type,
+                    subject_code: code.text()?.to_string(),
+                    subject_ordinal: ordinal(index)?,
+                });
+            }
+        }
+        Ok(ImportedWork {
+            work,
+            title,
+            abstracts,
+            contributions,
+            publications,
+            languages,
+            subjects,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -523,7 +929,7 @@ mod tests {
     use thoth_api::model::Ror;
     use thoth_client::{
         ContributionType, CountryCode, CurrencyCode, FundingInstitution, LanguageCode,
-        LanguageRelation, LocationPlatform, PublicationType, RelationType, SeriesType,
+        LanguageRelation, LocationPlatform, PriceType, PublicationType, RelationType, SeriesType,
         WorkContributionsAffiliations, WorkContributionsAffiliationsInstitution,
         WorkContributionsContributor, WorkImprint, WorkImprintPublisher, WorkIssuesSeries,
         WorkPublicationsLocations, WorkPublicationsPrices, WorkRelationsRelatedWork,
@@ -633,9 +1039,12 @@ mod tests {
                         }
                     ],
                     contribution_ordinal: 1,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![
                         WorkContributionsAffiliations {
@@ -658,9 +1067,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 2,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -695,14 +1107,29 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 25.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 22.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 31.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                     ],
                     locations: vec![
@@ -719,6 +1146,11 @@ mod tests {
                             canonical: false,
                         },
                     ],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -743,17 +1175,37 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 36.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 32.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 40.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                     ],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -781,6 +1233,11 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-EEEE-000000000005").unwrap(),
@@ -808,6 +1265,11 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -830,6 +1292,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
             ],
             subjects: vec![
@@ -879,6 +1346,13 @@ mod tests {
                     subject_ordinal: 1,
                 },
             ],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![WorkFundings {
                 program: Some("Name of program".to_string()),
                 project_name: Some("Name of project".to_string()),
@@ -1008,6 +1482,11 @@ mod tests {
             prices: vec![WorkPublicationsPrices {
                 currency_code: CurrencyCode::EUR,
                 unit_price: 25.95,
+                price_type: PriceType::RRP,
+                tax_inclusive: true,
+                countries_included: None,
+                effective_from: None,
+                effective_until: None,
             }],
             locations: vec![WorkPublicationsLocations {
                 landing_page: Some("https://www.book.com/paperback".to_string()),
@@ -1015,6 +1494,11 @@ mod tests {
                 location_platform: LocationPlatform::PROJECT_MUSE,
                 canonical: true,
             }],
+            sales_rights: vec![],
+            product_form_details: vec![],
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         };
         assert_eq!(CsvCell::<CsvThoth>::csv_cell(&publication),
             r#"("PAPERBACK", "978-3-16-148410-0", "156", "15.6", "6.14", "234", "23.4", "9.21", "25", "2.5", "1", "152", "5.3616", [("EUR", "25.95")], [("https://www.book.com/paperback", "", "PROJECT_MUSE", "true")])"#.to_string());
@@ -1055,9 +1539,12 @@ mod tests {
                 canonical: true,
             }],
             contribution_ordinal: 1,
+            name_variants: vec![],
+            credit_roles: vec![],
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: Some("https://www.author1.org".to_string()),
+                name_variants: vec![],
             },
             affiliations: vec![],
         };
@@ -1112,6 +1599,11 @@ mod tests {
         let mut price = WorkPublicationsPrices {
             currency_code: CurrencyCode::GBP,
             unit_price: 22.95,
+            price_type: PriceType::RRP,
+            tax_inclusive: true,
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
         };
         assert_eq!(
             CsvCell::<CsvThoth>::csv_cell(&price),
@@ -1352,4 +1844,97 @@ mod tests {
             r#"("", "", "", "978-92-95055-02-5", "", "", "Series", "Volume", "41", "", "5", "99", "", "13", "", "", "", "")"#.to_string()
         );
     }
+
+    #[test]
+    fn test_csv_thoth_cell_parser() {
+        assert_eq!(CellParser::parse("").unwrap(), vec![]);
+        assert_eq!(
+            CellParser::parse(r#"["AAA","AAB"]"#).unwrap(),
+            vec![
+                CellValue::Text("AAA".to_string()),
+                CellValue::Text("AAB".to_string())
+            ]
+        );
+        assert_eq!(
+            CellParser::parse(r#"[("PDF", "", , [("a, "quoted" b", "true")])]"#).unwrap(),
+            vec![CellValue::Items(vec![
+                CellValue::Text("PDF".to_string()),
+                CellValue::Text("".to_string()),
+                CellValue::Items(vec![]),
+                CellValue::Items(vec![CellValue::Items(vec![
+                    CellValue::Text(r#"a, "quoted" b"#.to_string()),
+                    CellValue::Text("true".to_string()),
+                ])]),
+            ])]
+        );
+        assert!(CellParser::parse(r#"[("AUTHOR", "Author""#).is_err());
+        assert!(CellParser::parse(r#""AUTHOR""#).is_err());
+    }
+
+    #[test]
+    fn test_read_csv_thoth() {
+        let works = read_csv_thoth(TEST_RESULT).unwrap();
+        assert_eq!(works.len(), 1);
+        let work = &works[0];
+        assert_eq!(
+            work.work.work_type,
+            thoth_api::model::work::WorkType::Monograph
+        );
+        assert_eq!(
+            work.work.doi,
+            Some(Doi::from_str("10.00001/BOOK.0001").unwrap())
+        );
+        assert_eq!(
+            work.work.publication_date,
+            NaiveDate::from_ymd_opt(1999, 12, 31)
+        );
+        assert_eq!(work.work.page_count, Some(334));
+        assert_eq!(work.title.full_title, "Book Title: Book Subtitle");
+        assert_eq!(work.abstracts.len(), 2);
+
+        assert_eq!(work.contributions.len(), 2);
+        let contribution = &work.contributions[0];
+        assert_eq!(
+            contribution.contributor.orcid,
+            Some(Orcid::from_str("0000-0002-0000-0001").unwrap())
+        );
+        assert!(contribution.contribution.main_contribution);
+        assert_eq!(
+            contribution.biography.as_deref(),
+            Some("Author 1 is an author")
+        );
+        assert_eq!(work.contributions[1].contribution.contribution_ordinal, 2);
+        assert_eq!(work.contributions[1].contributor.orcid, None);
+
+        assert_eq!(work.publications.len(), 5);
+        let paperback = &work.publications[0];
+        assert_eq!(
+            paperback.publication.isbn,
+            Some(Isbn::from_str("978-3-16-148410-0").unwrap())
+        );
+        assert_eq!(paperback.publication.width_mm, Some(156.0));
+        assert_eq!(paperback.publication.weight_oz, Some(5.3616));
+        assert_eq!(paperback.prices.len(), 3);
+        assert_eq!(paperback.prices[1].unit_price, 22.95);
+        assert_eq!(paperback.locations.len(), 2);
+        assert!(!paperback.locations[1].canonical);
+        assert!(work.publications[1].locations.is_empty());
+        assert!(work.publications[4].prices.is_empty());
+
+        assert_eq!(work.languages.len(), 1);
+        assert_eq!(work.subjects.len(), 9);
+        assert_eq!(work.subjects[1].subject_code, "AAB");
+        assert_eq!(work.subjects[1].subject_ordinal, 2);
+    }
+
+    #[test]
+    fn test_read_csv_thoth_invalid_value() {
+        let invalid = TEST_RESULT.replace(r#""MONOGRAPH""#, r#""NOVEL""#);
+        assert_eq!(
+            read_csv_thoth(&invalid).err(),
+            Some(ThothError::CsvError(
+                "Row 2: Unrecognised value: NOVEL".to_string()
+            ))
+        );
+    }
 }
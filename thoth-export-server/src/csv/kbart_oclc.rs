@@ -6,6 +6,7 @@ use thoth_client::{ContributionType, PublicationType, Work, WorkType};
 use thoth_errors::{ThothError, ThothResult};
 
 use super::{CsvRow, CsvSpecification};
+use crate::access::PublicationAccessFields;
 
 #[derive(Copy, Clone)]
 pub(crate) struct KbartOclc;
@@ -88,13 +89,30 @@ impl TryFrom<Work> for KbartOclcRow {
                 "Missing Publication Date".to_string(),
             )
         })?;
+        // Access is that of the online edition (preferably the PDF), which may carry its own
+        // licence and embargo; without digital publications, fall back to the work's licence
+        let online_access = work
+            .publications
+            .iter()
+            .filter(|p| p.is_digital())
+            .min_by_key(|p| p.publication_type != PublicationType::PDF)
+            .map(|p| p.access(work.license.as_ref()));
+        let (has_license, is_open) = match online_access {
+            Some(access) => (access.declared_license().is_some(), access.is_open()),
+            None => (work.license.is_some(), true),
+        };
         // Don't output works with no license
-        work.license.ok_or_else(|| {
-            ThothError::IncompleteMetadataRecord(
+        if !has_license {
+            return Err(ThothError::IncompleteMetadataRecord(
                 KBART_ERROR.to_string(),
                 "Missing License".to_string(),
-            )
-        })?;
+            ));
+        }
+        // F Free-to-read, P Paid (i.e. still under embargo)
+        let access_type = match is_open {
+            true => "F".to_string(),
+            false => "P".to_string(),
+        };
 
         let mut print_identifier = None;
         let mut online_identifier = None;
@@ -120,7 +138,7 @@ impl TryFrom<Work> for KbartOclcRow {
         // but this is not guaranteed, so we select the highest-ranked contributor of the
         // appropriate contribution type who is listed as a "main" contributor.
         // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
-        contributions.sort_by(|a, b| a.contribution_ordinal.cmp(&b.contribution_ordinal));
+        contributions.sort_by_key(|c| c.contribution_ordinal);
         for contribution in contributions {
             if contribution.main_contribution {
                 if work.work_type == WorkType::EDITED_BOOK {
@@ -180,7 +198,7 @@ impl TryFrom<Work> for KbartOclcRow {
                 .first()
                 .and_then(|i| i.series.issn_digital.as_ref().map(|s| s.to_string())),
             preceding_publication_title_id: None,
-            access_type: "F".to_string(),
+            access_type,
         })
     }
 }
@@ -196,9 +214,9 @@ mod tests {
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_client::{
-        ContributionType, PublicationType, WorkContributions, WorkContributionsContributor,
-        WorkImprint, WorkImprintPublisher, WorkIssues, WorkIssuesSeries, WorkPublications,
-        WorkStatus, WorkType,
+        AccessStatus, ContributionType, PublicationType, WorkContributions,
+        WorkContributionsContributor, WorkImprint, WorkImprintPublisher, WorkIssues,
+        WorkIssuesSeries, WorkPublications, WorkStatus, WorkType,
     };
     use uuid::Uuid;
 
@@ -218,12 +236,13 @@ mod tests {
         monograph_edition: String,
         first_editor: String,
         parent_publication_title_id: String,
+        access_type: String,
     }
 
     impl fmt::Display for TestResult {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             writeln!(f,
-                "{}{}\t{}\t{}\t\t\t\t\t\t\t{}\t{}\t{}\t\tfulltext\t\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t\tF",
+                "{}{}\t{}\t{}\t\t\t\t\t\t\t{}\t{}\t{}\t\tfulltext\t\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t\t{}",
                 self.headers,
                 self.title,
                 self.print_identifier,
@@ -239,6 +258,7 @@ mod tests {
                 self.monograph_edition,
                 self.first_editor,
                 self.parent_publication_title_id,
+                self.access_type,
             )
         }
     }
@@ -356,11 +376,14 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 1,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: Some(
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap(),
                         ),
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -372,9 +395,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 2,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -386,9 +412,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 3,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -416,6 +445,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -438,6 +472,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
@@ -460,6 +499,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-EEEE-000000000005").unwrap(),
@@ -482,6 +526,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -504,9 +553,21 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
             ],
             subjects: vec![],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![],
             relations: vec![],
             references: vec![],
@@ -527,6 +588,7 @@ mod tests {
             monograph_edition: "1".to_string(),
             first_editor: "".to_string(),
             parent_publication_title_id: "8765-4321".to_string(),
+            access_type: "F".to_string(),
         };
         let to_test =
             KbartOclc.generate(&[test_work.clone()], QuoteStyle::Necessary, DELIMITER_TAB);
@@ -567,6 +629,28 @@ mod tests {
             KbartOclc.generate(&[test_work.clone()], QuoteStyle::Necessary, DELIMITER_TAB);
         assert_eq!(to_test, Ok(test_result.to_string()));
 
+        // Embargo the PDF: it is not yet free to read, but is still listed
+        test_work.publications[0].access_status = Some(AccessStatus::EMBARGOED);
+        test_work.publications[0].embargo_end_date = chrono::NaiveDate::from_ymd_opt(2999, 1, 1);
+        test_result.access_type = "P".to_string();
+        let to_test =
+            KbartOclc.generate(&[test_work.clone()], QuoteStyle::Necessary, DELIMITER_TAB);
+        assert_eq!(to_test, Ok(test_result.to_string()));
+
+        // Restrict the PDF: KBART fails to generate, as the online edition is not licensed
+        test_work.publications[0].access_status = Some(AccessStatus::RESTRICTED);
+        test_work.publications[0].embargo_end_date = None;
+        let to_test =
+            KbartOclc.generate(&[test_work.clone()], QuoteStyle::Necessary, DELIMITER_TAB);
+        assert_eq!(
+            to_test,
+            Err(ThothError::IncompleteMetadataRecord(
+                KBART_ERROR.to_string(),
+                "Missing License".to_string(),
+            ))
+        );
+        test_work.publications[0].access_status = None;
+
         // Remove landing page: KBART fails to generate
         test_work.landing_page = None;
         let to_test =
@@ -35,6 +35,6 @@ pub(crate) trait CsvCell<T: CsvSpecification> {
 }
 
 mod csv_thoth;
-pub(crate) use csv_thoth::CsvThoth;
+pub(crate) use csv_thoth::{read_csv_thoth, CsvThoth, CsvThothRow};
 mod kbart_oclc;
 pub(crate) use kbart_oclc::KbartOclc;
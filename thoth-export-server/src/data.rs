@@ -10,125 +10,113 @@ lazy_static! {
         Specification {
             id: "onix_3.1::thoth",
             name: "Thoth ONIX 3.1",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/onix_3.1"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/thoth"),],
+            format: "/formats/onix_3.1".into(),
+            accepted_by: vec!["/platforms/thoth".into()],
         },
         Specification {
             id: "onix_3.0::thoth",
             name: "Thoth ONIX 3.0",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/onix_3.0"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/thoth"),],
+            format: "/formats/onix_3.0".into(),
+            accepted_by: vec!["/platforms/thoth".into()],
         },
         Specification {
             id: "onix_3.0::project_muse",
             name: "Project MUSE ONIX 3.0",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/onix_3.0"),
-            accepted_by: vec![
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/project_muse"),
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/jstor"),
-            ],
+            format: "/formats/onix_3.0".into(),
+            accepted_by: vec!["/platforms/project_muse".into(), "/platforms/jstor".into()],
         },
         Specification {
             id: "onix_3.0::oapen",
             name: "OAPEN ONIX 3.0",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/onix_3.0"),
-            accepted_by: vec![
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/oapen"),
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/doab"),
-            ],
+            format: "/formats/onix_3.0".into(),
+            accepted_by: vec!["/platforms/oapen".into(), "/platforms/doab".into()],
         },
         Specification {
             id: "onix_3.0::jstor",
             name: "JSTOR ONIX 3.0",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/onix_3.0"),
-            accepted_by: vec![
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/jstor"),
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/bds_live"),
-            ],
+            format: "/formats/onix_3.0".into(),
+            accepted_by: vec!["/platforms/jstor".into(), "/platforms/bds_live".into()],
         },
         Specification {
             id: "onix_3.0::google_books",
             name: "Google Books ONIX 3.0",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/onix_3.0"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/google_books"),],
+            format: "/formats/onix_3.0".into(),
+            accepted_by: vec!["/platforms/google_books".into()],
         },
         Specification {
             id: "onix_3.0::overdrive",
             name: "OverDrive ONIX 3.0",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/onix_3.0"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/overdrive"),],
+            format: "/formats/onix_3.0".into(),
+            accepted_by: vec!["/platforms/overdrive".into()],
         },
         Specification {
             id: "onix_2.1::ebsco_host",
             name: "EBSCO Host ONIX 2.1",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/onix_2.1"),
+            format: "/formats/onix_2.1".into(),
             accepted_by: vec![
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/ebsco_host"),
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/rnib_bookshare"),
+                "/platforms/ebsco_host".into(),
+                "/platforms/rnib_bookshare".into(),
             ],
         },
         Specification {
             id: "csv::thoth",
             name: "Thoth CSV",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/csv"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/thoth"),],
+            format: "/formats/csv".into(),
+            accepted_by: vec!["/platforms/thoth".into()],
         },
         Specification {
             id: "json::thoth",
             name: "Thoth JSON",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/json"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/thoth"),],
+            format: "/formats/json".into(),
+            accepted_by: vec!["/platforms/thoth".into()],
         },
         Specification {
             id: "kbart::oclc",
             name: "OCLC KBART",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/kbart"),
+            format: "/formats/kbart".into(),
             accepted_by: vec![
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/oclc_kb"),
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/proquest_kb"),
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/proquest_exlibris"),
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/ebsco_kb"),
-                concat!(env!("THOTH_EXPORT_API"), "/platforms/jisc_kb"),
+                "/platforms/oclc_kb".into(),
+                "/platforms/proquest_kb".into(),
+                "/platforms/proquest_exlibris".into(),
+                "/platforms/ebsco_kb".into(),
+                "/platforms/jisc_kb".into(),
             ],
         },
         Specification {
             id: "bibtex::thoth",
             name: "Thoth BibTeX",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/bibtex"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/zotero"),],
+            format: "/formats/bibtex".into(),
+            accepted_by: vec!["/platforms/zotero".into()],
         },
         Specification {
             id: "doideposit::crossref",
             name: "CrossRef DOI deposit",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/doideposit"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/crossref"),],
+            format: "/formats/doideposit".into(),
+            accepted_by: vec!["/platforms/crossref".into()],
         },
         Specification {
             id: "onix_2.1::proquest_ebrary",
             name: "ProQuest Ebrary ONIX 2.1",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/onix_2.1"),
-            accepted_by: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/platforms/proquest_ebrary"
-            )],
+            format: "/formats/onix_2.1".into(),
+            accepted_by: vec!["/platforms/proquest_ebrary".into()],
         },
         Specification {
             id: "marc21record::thoth",
             name: "Thoth MARC 21 Record",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/marc21record"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/thoth")],
+            format: "/formats/marc21record".into(),
+            accepted_by: vec!["/platforms/thoth".into()],
         },
         Specification {
             id: "marc21markup::thoth",
             name: "Thoth MARC 21 Markup",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/marc21markup"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/thoth")],
+            format: "/formats/marc21markup".into(),
+            accepted_by: vec!["/platforms/thoth".into()],
         },
         Specification {
             id: "marc21xml::thoth",
             name: "Thoth MARC 21 XML",
-            format: concat!(env!("THOTH_EXPORT_API"), "/formats/marc21xml"),
-            accepted_by: vec![concat!(env!("THOTH_EXPORT_API"), "/platforms/thoth")],
+            format: "/formats/marc21xml".into(),
+            accepted_by: vec!["/platforms/thoth".into()],
         },
     ];
     pub(crate) static ref ALL_PLATFORMS: Vec<Platform<'static>> = vec![
@@ -136,159 +124,102 @@ lazy_static! {
             id: "thoth",
             name: "Thoth",
             accepts: vec![
-                concat!(env!("THOTH_EXPORT_API"), "/specifications/csv::thoth"),
-                concat!(env!("THOTH_EXPORT_API"), "/specifications/json::thoth"),
-                concat!(
-                    env!("THOTH_EXPORT_API"),
-                    "/specifications/marc21record::thoth"
-                ),
-                concat!(
-                    env!("THOTH_EXPORT_API"),
-                    "/specifications/marc21markup::thoth"
-                ),
-                concat!(env!("THOTH_EXPORT_API"), "/specifications/marc21xml::thoth"),
-                concat!(env!("THOTH_EXPORT_API"), "/specifications/onix_3.0::thoth"),
-                concat!(env!("THOTH_EXPORT_API"), "/specifications/onix_3.1::thoth"),
+                "/specifications/csv::thoth".into(),
+                "/specifications/json::thoth".into(),
+                "/specifications/marc21record::thoth".into(),
+                "/specifications/marc21markup::thoth".into(),
+                "/specifications/marc21xml::thoth".into(),
+                "/specifications/onix_3.0::thoth".into(),
+                "/specifications/onix_3.1::thoth".into(),
             ],
         },
         Platform {
             id: "project_muse",
             name: "Project MUSE",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_3.0::project_muse"
-            ),],
+            accepts: vec!["/specifications/onix_3.0::project_muse".into()],
         },
         Platform {
             id: "oapen",
             name: "OAPEN",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_3.0::oapen"
-            ),],
+            accepts: vec!["/specifications/onix_3.0::oapen".into()],
         },
         Platform {
             id: "doab",
             name: "DOAB",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_3.0::oapen"
-            ),],
+            accepts: vec!["/specifications/onix_3.0::oapen".into()],
         },
         Platform {
             id: "jstor",
             name: "JSTOR",
             accepts: vec![
-                concat!(env!("THOTH_EXPORT_API"), "/specifications/onix_3.0::jstor"),
-                concat!(
-                    env!("THOTH_EXPORT_API"),
-                    "/specifications/onix_3.0::project_muse"
-                ),
+                "/specifications/onix_3.0::jstor".into(),
+                "/specifications/onix_3.0::project_muse".into(),
             ],
         },
         Platform {
             id: "google_books",
             name: "Google Books",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_3.0::google_books"
-            ),],
+            accepts: vec!["/specifications/onix_3.0::google_books".into()],
         },
         Platform {
             id: "overdrive",
             name: "OverDrive",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_3.0::overdrive"
-            ),],
+            accepts: vec!["/specifications/onix_3.0::overdrive".into()],
         },
         Platform {
             id: "bds_live",
             name: "BDS Live",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_3.0::jstor"
-            ),],
+            accepts: vec!["/specifications/onix_3.0::jstor".into()],
         },
         Platform {
             id: "ebsco_host",
             name: "EBSCO Host",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_2.1::ebsco_host"
-            ),],
+            accepts: vec!["/specifications/onix_2.1::ebsco_host".into()],
         },
         Platform {
             id: "oclc_kb",
             name: "OCLC KB",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/kbart::oclc"
-            ),],
+            accepts: vec!["/specifications/kbart::oclc".into()],
         },
         Platform {
             id: "proquest_kb",
             name: "ProQuest KB",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/kbart::oclc"
-            ),],
+            accepts: vec!["/specifications/kbart::oclc".into()],
         },
         Platform {
             id: "proquest_exlibris",
             name: "ProQuest ExLibris",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/kbart::oclc"
-            ),],
+            accepts: vec!["/specifications/kbart::oclc".into()],
         },
         Platform {
             id: "ebsco_kb",
             name: "EBSCO KB",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/kbart::oclc"
-            ),],
+            accepts: vec!["/specifications/kbart::oclc".into()],
         },
         Platform {
             id: "jisc_kb",
             name: "JISC KB",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/kbart::oclc"
-            ),],
+            accepts: vec!["/specifications/kbart::oclc".into()],
         },
         Platform {
             id: "zotero",
             name: "Zotero",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/bibtex::thoth"
-            ),],
+            accepts: vec!["/specifications/bibtex::thoth".into()],
         },
         Platform {
             id: "crossref",
             name: "CrossRef",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/doideposit::crossref"
-            ),],
+            accepts: vec!["/specifications/doideposit::crossref".into()],
         },
         Platform {
             id: "rnib_bookshare",
             name: "RNIB Bookshare",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_2.1::ebsco_host"
-            ),],
+            accepts: vec!["/specifications/onix_2.1::ebsco_host".into()],
         },
         Platform {
             id: "proquest_ebrary",
             name: "ProQuest Ebrary",
-            accepts: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_2.1::proquest_ebrary"
-            ),],
+            accepts: vec!["/specifications/onix_2.1::proquest_ebrary".into()],
         },
     ];
     pub(crate) static ref ALL_FORMATS: Vec<Format<'static>> = vec![
@@ -296,31 +227,19 @@ lazy_static! {
             id: "onix_3.1",
             name: "ONIX",
             version: Some("3.1"),
-            specifications: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/onix_3.1::thoth"
-            ),],
+            specifications: vec!["/specifications/onix_3.1::thoth".into()],
         },
         Format {
             id: "onix_3.0",
             name: "ONIX",
             version: Some("3.0"),
             specifications: vec![
-                concat!(env!("THOTH_EXPORT_API"), "/specifications/onix_3.0::thoth"),
-                concat!(
-                    env!("THOTH_EXPORT_API"),
-                    "/specifications/onix_3.0::project_muse"
-                ),
-                concat!(env!("THOTH_EXPORT_API"), "/specifications/onix_3.0::oapen"),
-                concat!(env!("THOTH_EXPORT_API"), "/specifications/onix_3.0::jstor"),
-                concat!(
-                    env!("THOTH_EXPORT_API"),
-                    "/specifications/onix_3.0::google_books"
-                ),
-                concat!(
-                    env!("THOTH_EXPORT_API"),
-                    "/specifications/onix_3.0::overdrive"
-                ),
+                "/specifications/onix_3.0::thoth".into(),
+                "/specifications/onix_3.0::project_muse".into(),
+                "/specifications/onix_3.0::oapen".into(),
+                "/specifications/onix_3.0::jstor".into(),
+                "/specifications/onix_3.0::google_books".into(),
+                "/specifications/onix_3.0::overdrive".into(),
             ],
         },
         Format {
@@ -328,87 +247,57 @@ lazy_static! {
             name: "ONIX",
             version: Some("2.1"),
             specifications: vec![
-                concat!(
-                    env!("THOTH_EXPORT_API"),
-                    "/specifications/onix_2.1::ebsco_host"
-                ),
-                concat!(
-                    env!("THOTH_EXPORT_API"),
-                    "/specifications/onix_2.1::proquest_ebrary"
-                ),
+                "/specifications/onix_2.1::ebsco_host".into(),
+                "/specifications/onix_2.1::proquest_ebrary".into(),
             ],
         },
         Format {
             id: "csv",
             name: "CSV",
             version: None,
-            specifications: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/csv::thoth"
-            ),],
+            specifications: vec!["/specifications/csv::thoth".into()],
         },
         Format {
             id: "json",
             name: "JSON",
             version: None,
-            specifications: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/json::thoth"
-            ),],
+            specifications: vec!["/specifications/json::thoth".into()],
         },
         Format {
             id: "kbart",
             name: "KBART",
             version: None,
-            specifications: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/kbart::oclc"
-            ),],
+            specifications: vec!["/specifications/kbart::oclc".into()],
         },
         Format {
             id: "bibtex",
             name: "BibTeX",
             version: None,
-            specifications: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/bibtex::thoth"
-            ),],
+            specifications: vec!["/specifications/bibtex::thoth".into()],
         },
         Format {
             id: "doideposit",
             name: "DOIdeposit",
             version: None,
-            specifications: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/doideposit::crossref"
-            ),],
+            specifications: vec!["/specifications/doideposit::crossref".into()],
         },
         Format {
             id: "marc21record",
             name: "MARC21Record",
             version: None,
-            specifications: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/marc21record::thoth"
-            ),],
+            specifications: vec!["/specifications/marc21record::thoth".into()],
         },
         Format {
             id: "marc21markup",
             name: "MARC21Markup",
             version: None,
-            specifications: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/marc21markup::thoth"
-            ),],
+            specifications: vec!["/specifications/marc21markup::thoth".into()],
         },
         Format {
             id: "marc21xml",
             name: "MARC21Xml",
             version: None,
-            specifications: vec![concat!(
-                env!("THOTH_EXPORT_API"),
-                "/specifications/marc21xml::thoth"
-            ),],
+            specifications: vec!["/specifications/marc21xml::thoth".into()],
         },
     ];
 }
@@ -441,9 +330,9 @@ pub(crate) fn find_specification(specification_id: String) -> ThothResult<Specif
 mod tests {
     use super::*;
 
-    const FORMATS_PREFIX: &str = concat!(env!("THOTH_EXPORT_API"), "/formats/");
-    const PLATFORMS_PREFIX: &str = concat!(env!("THOTH_EXPORT_API"), "/platforms/");
-    const SPECIFICATIONS_PREFIX: &str = concat!(env!("THOTH_EXPORT_API"), "/specifications/");
+    const FORMATS_PREFIX: &str = "/formats/";
+    const PLATFORMS_PREFIX: &str = "/platforms/";
+    const SPECIFICATIONS_PREFIX: &str = "/specifications/";
 
     fn format_id_from_url(url: &str) -> String {
         url.replace(FORMATS_PREFIX, "")
@@ -460,7 +349,7 @@ mod tests {
     #[test]
     fn test_specification_format_in_all_formats() {
         for s in ALL_SPECIFICATIONS.iter() {
-            let format_id = format_id_from_url(s.format);
+            let format_id = format_id_from_url(&s.format);
             assert!(find_format(format_id).is_ok())
         }
     }
@@ -478,7 +367,7 @@ mod tests {
     #[test]
     fn test_specification_id_begins_with_format_id() {
         for s in ALL_SPECIFICATIONS.iter() {
-            let format_id = format_id_from_url(s.format);
+            let format_id = format_id_from_url(&s.format);
             assert!(s.id.starts_with(&format_id));
         }
     }
@@ -487,7 +376,7 @@ mod tests {
     fn test_all_specifications_listed_in_formats() {
         for s in ALL_SPECIFICATIONS.iter() {
             let specification_id = s.id;
-            let format_id = format_id_from_url(s.format);
+            let format_id = format_id_from_url(&s.format);
             let format = find_format(format_id).unwrap();
             assert!(format
                 .specifications
@@ -519,6 +408,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_links_are_relative_until_given_public_url() {
+        let public_url = "https://export.thoth.pub";
+        let specification = find_specification("onix_3.0::oapen".to_string()).unwrap();
+        assert_eq!(specification.format, "/formats/onix_3.0");
+        let specification = specification.with_public_url(public_url);
+        assert_eq!(
+            specification.format,
+            "https://export.thoth.pub/formats/onix_3.0"
+        );
+        assert_eq!(
+            specification.accepted_by,
+            vec![
+                "https://export.thoth.pub/platforms/oapen",
+                "https://export.thoth.pub/platforms/doab"
+            ]
+        );
+        let platform = find_platform("doab".to_string())
+            .unwrap()
+            .with_public_url(public_url);
+        assert_eq!(
+            platform.accepts,
+            vec!["https://export.thoth.pub/specifications/onix_3.0::oapen"]
+        );
+        let format = find_format("csv".to_string())
+            .unwrap()
+            .with_public_url(public_url);
+        assert_eq!(
+            format.specifications,
+            vec!["https://export.thoth.pub/specifications/csv::thoth"]
+        );
+    }
+
     #[test]
     fn test_format_id_derives_from_name_and_version() {
         for f in ALL_FORMATS.iter() {
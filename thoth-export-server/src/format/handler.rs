@@ -6,14 +6,21 @@ use paperclip::actix::{
 
 use super::model::Format;
 use crate::data::{find_format, ALL_FORMATS};
+use crate::ApiConfig;
 
 #[api_v2_operation(
     summary = "List supported formats",
     description = "Full list of metadata formats that can be output by Thoth",
     tags(Formats)
 )]
-pub(crate) async fn get_all() -> Json<Vec<Format<'static>>> {
-    Json(ALL_FORMATS.clone())
+pub(crate) async fn get_all(config: web::Data<ApiConfig>) -> Json<Vec<Format<'static>>> {
+    Json(
+        ALL_FORMATS
+            .iter()
+            .cloned()
+            .map(|f| f.with_public_url(&config.public_url))
+            .collect(),
+    )
 }
 
 #[api_v2_operation(
@@ -21,8 +28,11 @@ pub(crate) async fn get_all() -> Json<Vec<Format<'static>>> {
     description = "Find the details of a format that can be output by Thoth",
     tags(Formats)
 )]
-pub(crate) async fn get_one(format_id: web::Path<String>) -> Result<Json<Format<'static>>, Error> {
+pub(crate) async fn get_one(
+    format_id: web::Path<String>,
+    config: web::Data<ApiConfig>,
+) -> Result<Json<Format<'static>>, Error> {
     find_format(format_id.into_inner())
-        .map(Json)
+        .map(|f| Json(f.with_public_url(&config.public_url)))
         .map_err(|e| e.into())
 }
@@ -7,5 +7,17 @@ pub(crate) struct Format<'a> {
     pub(crate) id: &'a str,
     pub(crate) name: &'a str,
     pub(crate) version: Option<&'a str>,
-    pub(crate) specifications: Vec<&'a str>,
+    /// Paths of the specifications, relative to the public URL of the export API until made
+    /// absolute with [`Format::with_public_url`]
+    pub(crate) specifications: Vec<String>,
+}
+
+impl Format<'_> {
+    /// Turn the links to the specifications into absolute URLs
+    pub(crate) fn with_public_url(mut self, public_url: &str) -> Self {
+        for specification in self.specifications.iter_mut() {
+            *specification = format!("{public_url}{specification}");
+        }
+        self
+    }
 }
@@ -0,0 +1,211 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use thoth_api::markup::MarkupFormat;
+use thoth_api::model::biography::NewBiography;
+use thoth_api::model::contribution::NewContribution;
+use thoth_api::model::contributor::{Contributor, NewContributor};
+use thoth_api::model::language::NewLanguage;
+use thoth_api::model::locale::LocaleCode;
+use thoth_api::model::location::NewLocation;
+use thoth_api::model::price::NewPrice;
+use thoth_api::model::publication::NewPublication;
+use thoth_api::model::r#abstract::NewAbstract;
+use thoth_api::model::subject::NewSubject;
+use thoth_api::model::title::NewTitle;
+use thoth_api::model::work::{NewWork, Work};
+use thoth_client::{QueryArguments, ThothClient};
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::csv::read_csv_thoth;
+use crate::json::read_json_thoth;
+
+/// A work read back from a `csv::thoth` or `json::thoth` record, with the records attached to
+/// it. Thoth IDs are left nil until the work is created.
+///
+/// Only the columns of the `csv::thoth` layout that describe the work itself are imported:
+/// series, affiliations, fundings, relations and references point to other records, which
+/// cannot be matched reliably, and are skipped.
+pub struct ImportedWork {
+    pub work: NewWork,
+    pub title: NewTitle,
+    pub abstracts: Vec<NewAbstract>,
+    pub contributions: Vec<ImportedContribution>,
+    pub publications: Vec<ImportedPublication>,
+    pub languages: Vec<NewLanguage>,
+    pub subjects: Vec<NewSubject>,
+}
+
+/// A contribution to an imported work, with the contributor it is attributed to
+pub struct ImportedContribution {
+    pub contributor: NewContributor,
+    pub contribution: NewContribution,
+    /// Biography of the contributor, in JATS XML
+    pub biography: Option<String>,
+}
+
+/// A publication of an imported work, with its prices and locations
+pub struct ImportedPublication {
+    pub publication: NewPublication,
+    pub prices: Vec<NewPrice>,
+    pub locations: Vec<NewLocation>,
+}
+
+/// Read the works contained in a record generated by one of the specifications that can be
+/// imported (`csv::thoth` and `json::thoth`)
+pub fn read_works(specification_id: &str, input: &str) -> ThothResult<Vec<ImportedWork>> {
+    match specification_id {
+        "csv::thoth" => read_csv_thoth(input),
+        "json::thoth" => read_json_thoth(input),
+        _ => Err(ThothError::InvalidMetadataSpecification(
+            specification_id.to_string(),
+        )),
+    }
+}
+
+/// Parse the name of a GraphQL enum value, as written in export records, into its `thoth_api`
+/// counterpart
+pub(crate) fn parse_enum<T: DeserializeOwned>(value: &str) -> ThothResult<T> {
+    serde_json::from_value(Value::String(value.to_string()))
+        .map_err(|_| ThothError::CsvError(format!("Unrecognised value: {value}")))
+}
+
+impl ImportedWork {
+    /// Create the work in the given imprint, followed by the records attached to it. If any of
+    /// them is rejected the work is deleted again, together with any contributors created for
+    /// it, so that no partial record is left behind.
+    pub async fn create(self, thoth_client: &ThothClient, imprint_id: Uuid) -> ThothResult<Work> {
+        let ImportedWork {
+            work,
+            title,
+            abstracts,
+            contributions,
+            publications,
+            languages,
+            subjects,
+        } = self;
+        let work = thoth_client
+            .create_work(NewWork { imprint_id, ..work })
+            .await?;
+        let work_id = work.work_id;
+        let mut created_contributors = Vec::new();
+        let attached = async {
+            thoth_client
+                .create_title(Some(MarkupFormat::PlainText), NewTitle { work_id, ..title })
+                .await?;
+            for r#abstract in abstracts {
+                thoth_client
+                    .create_abstract(
+                        Some(MarkupFormat::PlainText),
+                        NewAbstract {
+                            work_id,
+                            ..r#abstract
+                        },
+                    )
+                    .await?;
+            }
+            for imported in contributions {
+                let contributor_id =
+                    match find_contributor(thoth_client, &imported.contributor).await? {
+                        Some(contributor) => contributor.contributor_id,
+                        None => {
+                            let contributor_id = thoth_client
+                                .create_contributor(imported.contributor)
+                                .await?
+                                .contributor_id;
+                            created_contributors.push(contributor_id);
+                            contributor_id
+                        }
+                    };
+                let contribution = thoth_client
+                    .create_contribution(NewContribution {
+                        work_id,
+                        contributor_id,
+                        ..imported.contribution
+                    })
+                    .await?;
+                if let Some(content) = imported.biography {
+                    thoth_client
+                        .create_biography(
+                            Some(MarkupFormat::JatsXml),
+                            NewBiography {
+                                contribution_id: contribution.contribution_id,
+                                content,
+                                canonical: true,
+                                locale_code: LocaleCode::default(),
+                            },
+                        )
+                        .await?;
+                }
+            }
+            for imported in publications {
+                let publication_id = thoth_client
+                    .create_publication(NewPublication {
+                        work_id,
+                        ..imported.publication
+                    })
+                    .await?
+                    .publication_id;
+                for price in imported.prices {
+                    thoth_client
+                        .create_price(NewPrice {
+                            publication_id,
+                            ..price
+                        })
+                        .await?;
+                }
+                for location in imported.locations {
+                    thoth_client
+                        .create_location(NewLocation {
+                            publication_id,
+                            ..location
+                        })
+                        .await?;
+                }
+            }
+            for language in languages {
+                thoth_client
+                    .create_language(NewLanguage {
+                        work_id,
+                        ..language
+                    })
+                    .await?;
+            }
+            for subject in subjects {
+                thoth_client
+                    .create_subject(NewSubject { work_id, ..subject })
+                    .await?;
+            }
+            Ok::<(), ThothError>(())
+        }
+        .await;
+        match attached {
+            Ok(()) => Ok(work),
+            Err(error) => {
+                // deleting the work removes its contributions, which leaves the contributors
+                // created for them unreferenced
+                thoth_client.delete_work(work_id).await?;
+                for contributor_id in created_contributors {
+                    thoth_client.delete_contributor(contributor_id).await?;
+                }
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Find an existing contributor with the same ORCID, so that importing a work does not
+/// duplicate its contributors
+async fn find_contributor(
+    thoth_client: &ThothClient,
+    contributor: &NewContributor,
+) -> ThothResult<Option<Contributor>> {
+    let Some(orcid) = &contributor.orcid else {
+        return Ok(None);
+    };
+    let arguments = QueryArguments::new().filter(orcid.to_string());
+    let candidates: Vec<Contributor> = thoth_client.get_entities(&arguments, 10, 0).await?;
+    Ok(candidates
+        .into_iter()
+        .find(|candidate| candidate.orcid.as_ref() == Some(orcid)))
+}
@@ -4,6 +4,8 @@ use thoth_client::Work;
 use thoth_errors::{ThothError, ThothResult};
 
 use super::JsonSpecification;
+use crate::csv::CsvThothRow;
+use crate::import::ImportedWork;
 
 #[derive(Copy, Clone)]
 pub(crate) struct JsonThoth;
@@ -48,6 +50,16 @@ impl JsonSpecification for JsonThoth {
     }
 }
 
+/// Read back the work of a `json::thoth` record.
+///
+/// The work goes through the same conversion as `csv::thoth` rows, so both specifications
+/// import exactly the same set of fields.
+pub(crate) fn read_json_thoth(input: &str) -> ThothResult<Vec<ImportedWork>> {
+    let work: Work = serde_json::from_str(input)
+        .map_err(|e| ThothError::IncompleteMetadataRecord(JSON_ERROR.to_string(), e.to_string()))?;
+    ImportedWork::try_from(CsvThothRow::from(work)).map(|work| vec![work])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -60,8 +72,8 @@ mod tests {
     use thoth_api::model::Ror;
     use thoth_client::{
         ContributionType, CountryCode, CurrencyCode, FundingInstitution, LanguageCode,
-        LanguageRelation, LocationPlatform, PublicationType, RelationType, SeriesType, SubjectType,
-        Work, WorkContributions, WorkContributionsAffiliations,
+        LanguageRelation, LocationPlatform, PriceType, PublicationType, RelationType, SeriesType,
+        SubjectType, Work, WorkContributions, WorkContributionsAffiliations,
         WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkFundings,
         WorkImprint, WorkImprintPublisher, WorkIssues, WorkIssuesSeries, WorkLanguages,
         WorkPublications, WorkPublicationsLocations, WorkPublicationsPrices, WorkReferences,
@@ -183,9 +195,16 @@ mod tests {
                         },
                     ],
                     contribution_ordinal: 1,
+                    name_variants: vec![],
+                    credit_roles: vec![thoth_client::WorkContributionsCreditRoles {
+                        role_type: thoth_client::CreditRoleType::WRITING_ORIGINAL_DRAFT,
+                        degree: Some(thoth_client::CreditRoleDegree::LEAD),
+                        role_uri: "https://credit.niso.org/contributor-roles/writing-original-draft/".to_string(),
+                    }],
                     contributor: WorkContributionsContributor {
                         orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![
                         WorkContributionsAffiliations {
@@ -208,9 +227,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 2,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -245,14 +267,29 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 25.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 22.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 31.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                     ],
                     locations: vec![
@@ -269,6 +306,11 @@ mod tests {
                             canonical: false,
                         },
                     ],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -293,17 +335,37 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 36.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 32.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 40.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                     ],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -331,6 +393,11 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-EEEE-000000000005").unwrap(),
@@ -358,6 +425,11 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -380,6 +452,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
             ],
             subjects: vec![
@@ -429,6 +506,13 @@ mod tests {
                     subject_ordinal: 1,
                 },
             ],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![WorkFundings {
                 program: Some("Name of program".to_string()),
                 project_name: Some("Name of project".to_string()),
@@ -627,9 +711,18 @@ mod tests {
         }
       ],
       "contributionOrdinal": 1,
+      "nameVariants": [],
+      "creditRoles": [
+        {
+          "roleType": "WRITING_ORIGINAL_DRAFT",
+          "degree": "LEAD",
+          "roleUri": "https://credit.niso.org/contributor-roles/writing-original-draft/"
+        }
+      ],
       "contributor": {
         "orcid": "https://orcid.org/0000-0002-0000-0001",
-        "website": null
+        "website": null,
+        "nameVariants": []
       },
       "affiliations": [
         {
@@ -652,9 +745,12 @@ mod tests {
       "mainContribution": true,
       "biographies": [],
       "contributionOrdinal": 2,
+      "nameVariants": [],
+      "creditRoles": [],
       "contributor": {
         "orcid": null,
-        "website": null
+        "website": null,
+        "nameVariants": []
       },
       "affiliations": []
     }
@@ -685,20 +781,40 @@ mod tests {
       "accessibilityAdditionalStandard": null,
       "accessibilityException": null,
       "accessibilityReportUrl": null,
+      "license": null,
+      "accessStatus": null,
+      "embargoEndDate": null,
       "prices": [
         {
           "currencyCode": "EUR",
-          "unitPrice": 25.95
+          "unitPrice": 25.95,
+          "priceType": "RRP",
+          "taxInclusive": true,
+          "countriesIncluded": null,
+          "effectiveFrom": null,
+          "effectiveUntil": null
         },
         {
           "currencyCode": "GBP",
-          "unitPrice": 22.95
+          "unitPrice": 22.95,
+          "priceType": "RRP",
+          "taxInclusive": true,
+          "countriesIncluded": null,
+          "effectiveFrom": null,
+          "effectiveUntil": null
         },
         {
           "currencyCode": "USD",
-          "unitPrice": 31.95
+          "unitPrice": 31.95,
+          "priceType": "RRP",
+          "taxInclusive": true,
+          "countriesIncluded": null,
+          "effectiveFrom": null,
+          "effectiveUntil": null
         }
       ],
+      "salesRights": [],
+      "productFormDetails": [],
       "locations": [
         {
           "landingPage": "https://www.book.com/paperback",
@@ -733,20 +849,40 @@ mod tests {
       "accessibilityAdditionalStandard": null,
       "accessibilityException": null,
       "accessibilityReportUrl": null,
+      "license": null,
+      "accessStatus": null,
+      "embargoEndDate": null,
       "prices": [
         {
           "currencyCode": "EUR",
-          "unitPrice": 36.95
+          "unitPrice": 36.95,
+          "priceType": "RRP",
+          "taxInclusive": true,
+          "countriesIncluded": null,
+          "effectiveFrom": null,
+          "effectiveUntil": null
         },
         {
           "currencyCode": "GBP",
-          "unitPrice": 32.95
+          "unitPrice": 32.95,
+          "priceType": "RRP",
+          "taxInclusive": true,
+          "countriesIncluded": null,
+          "effectiveFrom": null,
+          "effectiveUntil": null
         },
         {
           "currencyCode": "USD",
-          "unitPrice": 40.95
+          "unitPrice": 40.95,
+          "priceType": "RRP",
+          "taxInclusive": true,
+          "countriesIncluded": null,
+          "effectiveFrom": null,
+          "effectiveUntil": null
         }
       ],
+      "salesRights": [],
+      "productFormDetails": [],
       "locations": []
     },
     {
@@ -768,7 +904,12 @@ mod tests {
       "accessibilityAdditionalStandard": null,
       "accessibilityException": null,
       "accessibilityReportUrl": null,
+      "license": null,
+      "accessStatus": null,
+      "embargoEndDate": null,
       "prices": [],
+      "salesRights": [],
+      "productFormDetails": [],
       "locations": [
         {
           "landingPage": "https://www.book.com/pdf_landing",
@@ -797,7 +938,12 @@ mod tests {
       "accessibilityAdditionalStandard": null,
       "accessibilityException": null,
       "accessibilityReportUrl": null,
+      "license": null,
+      "accessStatus": null,
+      "embargoEndDate": null,
       "prices": [],
+      "salesRights": [],
+      "productFormDetails": [],
       "locations": [
         {
           "landingPage": "https://www.book.com/html_landing",
@@ -826,7 +972,12 @@ mod tests {
       "accessibilityAdditionalStandard": null,
       "accessibilityException": null,
       "accessibilityReportUrl": null,
+      "license": null,
+      "accessStatus": null,
+      "embargoEndDate": null,
       "prices": [],
+      "salesRights": [],
+      "productFormDetails": [],
       "locations": []
     }
   ],
@@ -877,6 +1028,8 @@ mod tests {
       "subjectOrdinal": 1
     }
   ],
+  "audiences": [],
+  "workDates": [],
   "fundings": [
     {
       "program": "Name of program",
@@ -967,7 +1120,12 @@ mod tests {
       "publicationDate": "2022-01-01",
       "retrievalDate": null
     }
-  ]
+  ],
+  "awards": [],
+  "endorsements": [],
+  "bookReviews": [],
+  "additionalResources": [],
+  "featuredVideo": null
 }"#;
 
     #[test]
@@ -987,4 +1145,18 @@ mod tests {
         assert!(matches.is_some());
         assert_eq!(to_test_tuple.1, TEST_RESULT);
     }
+
+    #[test]
+    fn test_read_json_thoth() {
+        let json = JsonThoth
+            .generate(std::slice::from_ref(&TEST_WORK))
+            .unwrap();
+        let works = read_json_thoth(&json).unwrap();
+        assert_eq!(works.len(), 1);
+        assert_eq!(works[0].title.title, TEST_WORK.titles[0].title);
+        assert_eq!(works[0].contributions.len(), TEST_WORK.contributions.len());
+        assert_eq!(works[0].publications.len(), TEST_WORK.publications.len());
+        assert_eq!(works[0].subjects.len(), TEST_WORK.subjects.len());
+        assert!(read_json_thoth("{}").is_err());
+    }
 }
@@ -10,4 +10,4 @@ pub(crate) trait JsonSpecification {
 }
 
 mod json_thoth;
-pub(crate) use json_thoth::JsonThoth;
+pub(crate) use json_thoth::{read_json_thoth, JsonThoth};
@@ -2,19 +2,39 @@ use std::io;
 use std::time::Duration;
 
 use actix_cors::Cors;
-use actix_web::{middleware::Logger, web::Data, App, HttpServer};
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::{from_fn, Next};
+use actix_web::{web::Data, App, HttpMessage, HttpServer};
+pub use cache::{
+    cache_stats, delete_cached_records, list_cached_records, publisher_record_ids, warm_cache,
+    CacheFilter, CacheStats, CachedRecord, WarmedRecord,
+};
 pub use data::ALL_SPECIFICATIONS;
+pub use import::{read_works, ImportedContribution, ImportedPublication, ImportedWork};
+pub use local::{generate_record, GeneratedRecord, RecordTarget};
 use paperclip::actix::{web, web::HttpResponse, OpenApiExt};
 use paperclip::v2::models::{Contact, DefaultApiRaw, Info, License, OperationProtocol, Tag};
-use thoth_api::redis::init_pool;
-use thoth_client::ThothClient;
+use thoth_api::health::{live, Readiness};
+use thoth_api::metrics::{metrics, RequestMetrics};
+use thoth_api::redis::{init_pool, ping, RedisPool};
+use thoth_api::telemetry::{
+    init_telemetry, RequestId, RequestTracing, TelemetryConfig, REQUEST_ID_HEADER,
+};
+use thoth_client::{with_request_id, ThothClient};
+use thoth_errors::{ThothError, ThothResult};
 
+mod access;
 mod bibtex;
+mod cache;
 mod csv;
 mod data;
 mod format;
+mod import;
 mod json;
+mod local;
 mod marc21;
+mod metrics;
 mod platform;
 mod rapidoc;
 mod record;
@@ -24,16 +44,20 @@ mod xml;
 
 use crate::rapidoc::rapidoc_source;
 
-const LOG_FORMAT: &str = r#"%{r}a %a "%r" %s %b "%{Referer}i" "%{User-Agent}i" %T"#;
+/// Time allowed to each dependency to answer a readiness check
+const READINESS_TIMEOUT: Duration = Duration::from_secs(5);
 
-struct ApiConfig {
+pub(crate) struct ApiConfig {
     api_schema: String,
+    /// Root URL the API is served from, used to link formats, platforms and specifications
+    pub(crate) public_url: String,
 }
 
 impl ApiConfig {
     pub fn new(public_url: String) -> Self {
         Self {
             api_schema: format!("{public_url}/openapi.json"),
+            public_url,
         }
     }
 }
@@ -45,6 +69,44 @@ async fn index(config: web::Data<ApiConfig>) -> HttpResponse {
         .body(html)
 }
 
+/// Fail a readiness check whose dependency does not answer in time, e.g. while the client
+/// is retrying requests to an unreachable GraphQL API
+async fn with_timeout(
+    check: impl std::future::Future<Output = ThothResult<()>>,
+) -> ThothResult<()> {
+    actix_web::rt::time::timeout(READINESS_TIMEOUT, check)
+        .await
+        .unwrap_or_else(|_| Err(ThothError::InternalError("Timed out".to_string())))
+}
+
+async fn health_ready(
+    redis_pool: web::Data<RedisPool>,
+    thoth_client: web::Data<ThothClient>,
+) -> HttpResponse {
+    let (redis, graphql) = futures::join!(
+        with_timeout(ping(&redis_pool)),
+        with_timeout(thoth_client.ping()),
+    );
+    Readiness::default()
+        .check("redis", redis)
+        .check("graphql", graphql)
+        .into_response()
+}
+
+/// Pass the ID of the request being handled on to the GraphQL API, with every query that
+/// `ThothClient` makes for it
+async fn propagate_request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let request_id = req.extensions().get::<RequestId>().cloned();
+    match request_id {
+        Some(RequestId(request_id)) => with_request_id(request_id, next.call(req)).await,
+        None => next.call(req).await,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 #[actix_web::main]
 pub async fn start_server(
     redis_url: String,
@@ -54,9 +116,11 @@ pub async fn start_server(
     keep_alive: u64,
     public_url: String,
     gql_endpoint: String,
+    cors_origins: Vec<String>,
+    telemetry: TelemetryConfig,
 ) -> io::Result<()> {
-    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
-    log::info!("Setting Thoth GraphQL endpoint to {}", gql_endpoint);
+    let _telemetry = init_telemetry("thoth-export-api", &telemetry).map_err(io::Error::other)?;
+    tracing::info!("Setting Thoth GraphQL endpoint to {}", gql_endpoint);
     let redis_pool = Data::new(init_pool(&redis_url));
     let thoth_client = Data::new(ThothClient::new(gql_endpoint.clone()));
 
@@ -115,12 +179,28 @@ pub async fn start_server(
         };
 
         App::new()
-            .wrap(Logger::new(LOG_FORMAT))
-            .wrap(Cors::default().allowed_methods(vec!["GET", "OPTIONS"]))
+            .wrap(RequestMetrics)
+            .wrap(from_fn(propagate_request_id))
+            .wrap(RequestTracing)
+            .wrap(
+                cors_origins.iter().fold(
+                    Cors::default()
+                        .allowed_methods(vec!["GET", "OPTIONS"])
+                        .allowed_header(REQUEST_ID_HEADER)
+                        .expose_headers(vec![REQUEST_ID_HEADER]),
+                    |cors, origin| cors.allowed_origin(origin),
+                ),
+            )
             .app_data(thoth_client.clone())
             .app_data(Data::new(ApiConfig::new(public_url.clone())))
             .app_data(redis_pool.clone())
             .service(actix_web::web::resource("/").route(actix_web::web::get().to(index)))
+            .service(actix_web::web::resource("/health/live").route(actix_web::web::get().to(live)))
+            .service(
+                actix_web::web::resource("/health/ready")
+                    .route(actix_web::web::get().to(health_ready)),
+            )
+            .service(actix_web::web::resource("/metrics").route(actix_web::web::get().to(metrics)))
             .wrap_api_with_spec(spec)
             .configure(format::route)
             .configure(platform::route)
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use thoth_api::model::Timestamp;
+use thoth_client::ThothClient;
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+use crate::record::{MetadataRecord, MetadataSpecification};
+use crate::specification_query::SpecificationQuery;
+
+/// Records a metadata specification can be generated for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordTarget {
+    /// A single work, given its Thoth ID
+    Work(Uuid),
+    /// All works of a publisher, given the publisher's Thoth ID
+    Publisher(Uuid),
+}
+
+/// Metadata record generated outside of the export server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedRecord {
+    /// File name the export API would serve the record under
+    pub file_name: String,
+    pub content: String,
+}
+
+/// Generate a metadata record directly from the GraphQL API, without going through the export
+/// API or its cache.
+///
+/// `specification_id` is any of the IDs listed in [`ALL_SPECIFICATIONS`](crate::ALL_SPECIFICATIONS),
+/// e.g. `onix_3.0::thoth`.
+pub async fn generate_record(
+    thoth_client: Arc<ThothClient>,
+    specification_id: &str,
+    target: RecordTarget,
+) -> ThothResult<GeneratedRecord> {
+    let specification: MetadataSpecification = specification_id.parse()?;
+    let (id, specification_query) = match target {
+        RecordTarget::Work(work_id) => (
+            work_id,
+            SpecificationQuery::by_work(thoth_client, work_id, specification),
+        ),
+        RecordTarget::Publisher(publisher_id) => (
+            publisher_id,
+            SpecificationQuery::by_publisher(thoth_client, publisher_id, specification),
+        ),
+    };
+    // the timestamp is only used to validate cached records, which are not read here
+    let mut metadata_record =
+        MetadataRecord::new(id.to_string(), specification, Timestamp::default());
+    metadata_record
+        .generate_uncached(specification_query)
+        .await?;
+    let (file_name, content) = metadata_record.into_file()?;
+    Ok(GeneratedRecord { file_name, content })
+}
@@ -4,12 +4,14 @@ use chrono::{Datelike, Utc};
 use marc::{DescriptiveCatalogingForm, EncodingLevel, FieldRepr, Record, RecordBuilder};
 use thoth_api::markup::ast::{ast_to_plain_text, jats_to_ast};
 use thoth_api::model::contribution::ContributionType;
+use thoth_api::model::product_form_detail::{ProductFormDetail, ProductFormDetailProperties};
 use thoth_api::model::publication::PublicationType;
 use thoth_api::model::IdentifierWithDomain;
 use thoth_client::{
-    AbstractType, LanguageRelation, RelationType, SubjectType, Work, WorkContributions,
-    WorkFundings, WorkIssues, WorkLanguages, WorkPublications, WorkRelations, WorkSubjects,
-    WorkType,
+    AbstractType, AudienceCode, AudienceRangePrecision, AudienceRangeQualifier, LanguageRelation,
+    NameVariant, NameVariantType, RelationType, SubjectType, Work, WorkAudiences,
+    WorkContributions, WorkFundings, WorkIssues, WorkLanguages, WorkPublications, WorkRelations,
+    WorkSubjects, WorkType,
 };
 use thoth_errors::{ThothError, ThothResult};
 
@@ -260,6 +262,26 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
                 }
             })
             .and_then(|f| builder.add_field(f))?;
+        // repeated for print editions whose binding or other form details are recorded
+        for publication in &self.publications {
+            if let Some((extent_str, details_str, dimensions)) =
+                print_description_string(self, publication)
+            {
+                let publication_type: PublicationType = publication.publication_type.clone().into();
+                FieldRepr::from((b"300", "\\\\"))
+                    .add_subfield(b"3", format!("{publication_type}:"))
+                    .and_then(|f| f.add_subfield(b"a", extent_str))
+                    .and_then(|f| f.add_subfield(b"b", details_str))
+                    .and_then(|f| {
+                        if let Some(dimensions_str) = dimensions {
+                            f.add_subfield(b"c", dimensions_str)
+                        } else {
+                            Ok(f)
+                        }
+                    })
+                    .and_then(|f| builder.add_field(f))?;
+            }
+        }
 
         // 336 - content type
         FieldRepr::from((b"336", "\\\\"))
@@ -345,6 +367,11 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
                 .and_then(|f| builder.add_field(f))?;
         }
 
+        // 521 - target audience
+        for audience in &self.audiences {
+            Marc21Field::<Marc21RecordThoth>::to_field(audience, &mut builder)?;
+        }
+
         // 536 - funding
         for funding in &self.fundings {
             Marc21Field::<Marc21RecordThoth>::to_field(funding, &mut builder)?;
@@ -450,6 +477,8 @@ fn contributor_fields(contributions: &[WorkContributions]) -> ThothResult<Vec<Fi
     }
 
     let mut contributor_fields: Vec<FieldRepr> = vec![];
+    // 880 - alternate graphic representations of the names above, linked to them via $6
+    let mut alternate_fields: Vec<FieldRepr> = vec![];
     // only one 100 field is allowed, first-come first-served
     let mut is_main_author_defined = false;
     for (name, contributions, indicator) in contributions_by_name.iter() {
@@ -461,17 +490,42 @@ fn contributor_fields(contributions: &[WorkContributions]) -> ThothResult<Vec<Fi
             field_code = b"100";
             is_main_author_defined = true;
         }
-        let roles = contributions
-            .iter()
-            .map(|c| {
-                ContributionType::from(c.contribution_type.clone())
-                    .to_string()
-                    .to_lowercase()
-            })
-            .collect::<Vec<_>>()
-            .join(", ");
+        let mut relator_terms: Vec<&str> = vec![];
+        for c in contributions.iter() {
+            let term = c.contribution_type.role_mapping().marc_relator_term;
+            // different contribution types may share a relator term, e.g. editor
+            if !relator_terms.contains(&term) {
+                relator_terms.push(term);
+            }
+        }
+        let roles = relator_terms.join(", ");
 
         let mut contributor_field = FieldRepr::from((field_code, indicator.as_str()));
+        if let Some(variant) = alternate_script_name(contributions.first().unwrap()) {
+            let occurrence = format!("{:02}", alternate_fields.len() + 1);
+            let tag = String::from_utf8_lossy(field_code).to_string();
+            contributor_field =
+                contributor_field.add_subfield(b"6", format!("880-{occurrence}").as_bytes())?;
+
+            let script_identification = variant
+                .script_code
+                .as_deref()
+                .and_then(marc_script_identification_code)
+                .map(|code| format!("/{code}"))
+                .unwrap_or_default();
+            let (variant_name, variant_indicator) = match &variant.first_name {
+                Some(first_name) => (format!("{}, {}", variant.last_name, first_name), "1\\"),
+                None => (variant.full_name.clone(), "0\\"),
+            };
+            let alternate_field = FieldRepr::from((b"880", variant_indicator))
+                .add_subfield(
+                    b"6",
+                    format!("{tag}-{occurrence}{script_identification}").as_bytes(),
+                )
+                .and_then(|f| f.add_subfield(b"a", format!("{variant_name},").as_bytes()))
+                .and_then(|f| f.add_subfield(b"e", format!("{roles}.").as_bytes()))?;
+            alternate_fields.push(alternate_field);
+        }
         contributor_field = contributor_field.add_subfield(b"a", format!("{name},").as_bytes())?;
         contributor_field = contributor_field.add_subfield(b"e", format!("{roles}.").as_bytes())?;
         if let Some(affiliation) = &contributions.first().unwrap().affiliations.first() {
@@ -490,9 +544,38 @@ fn contributor_fields(contributions: &[WorkContributions]) -> ThothResult<Vec<Fi
         }
         contributor_fields.push(contributor_field);
     }
+    contributor_fields.append(&mut alternate_fields);
     Ok(contributor_fields)
 }
 
+/// The form of a contributor's name written in a non-Latin script, if any, preferring the
+/// form credited in the contribution over the one recorded for the contributor.
+fn alternate_script_name(contribution: &WorkContributions) -> Option<&NameVariant> {
+    contribution
+        .name_variants
+        .iter()
+        .chain(contribution.contributor.name_variants.iter())
+        .find(|variant| {
+            variant.variant_type == NameVariantType::TRANSLITERATION
+                && variant
+                    .script_code
+                    .as_deref()
+                    .is_some_and(|script_code| script_code != "Latn")
+        })
+}
+
+/// MARC 21 script identification code used in subfield $6 for an ISO 15924 script code
+fn marc_script_identification_code(script_code: &str) -> Option<&'static str> {
+    match script_code {
+        "Arab" => Some("(3"),
+        "Cyrl" => Some("(N"),
+        "Grek" => Some("(S"),
+        "Hebr" => Some("(2"),
+        "Hang" | "Hani" | "Hans" | "Hant" | "Hira" | "Jpan" | "Kana" | "Kore" => Some("$1"),
+        _ => None,
+    }
+}
+
 fn language_field(languages: &[WorkLanguages]) -> Option<FieldRepr> {
     if languages.len() == 1 {
         // 041 language fields are not needed when there is only one language
@@ -571,7 +654,7 @@ fn toc_field(relations: &[WorkRelations]) -> ThothResult<FieldRepr> {
         ));
     }
     // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
-    chapters.sort_by(|a, b| a.relation_ordinal.cmp(&b.relation_ordinal));
+    chapters.sort_by_key(|r| r.relation_ordinal);
     let mut chapter_list = chapters.iter().peekable();
     let mut toc_field: FieldRepr = FieldRepr::from((b"505", "00"));
     let mut separator = " --";
@@ -683,6 +766,60 @@ impl Marc21Field<Marc21RecordThoth> for WorkFundings {
     }
 }
 
+impl Marc21Field<Marc21RecordThoth> for WorkAudiences {
+    fn to_field(&self, builder: &mut RecordBuilder) -> ThothResult<()> {
+        let audience = match self.audience_code {
+            AudienceCode::GENERAL => "General/trade.",
+            AudienceCode::CHILDREN => "Children/juvenile.",
+            AudienceCode::YOUNG_ADULT => "Young adult.",
+            AudienceCode::PRIMARY_AND_SECONDARY => "Primary and secondary education.",
+            AudienceCode::HIGHER_EDUCATION => "College/higher education.",
+            AudienceCode::PROFESSIONAL_AND_SCHOLARLY => "Professional and scholarly.",
+            AudienceCode::ELT => "English language teaching.",
+            AudienceCode::ADULT_EDUCATION => "Adult education.",
+            AudienceCode::SECOND_LANGUAGE_TEACHING => "Second language teaching.",
+            AudienceCode::Other(_) => unreachable!(),
+        };
+        FieldRepr::from((b"521", "\\\\"))
+            .add_subfield(b"a", audience)
+            .and_then(|f| builder.add_field(f))?;
+
+        let (Some(qualifier), Some(precision)) = (
+            &self.audience_range_qualifier,
+            &self.audience_range_precision,
+        ) else {
+            return Ok(());
+        };
+        let from = self.audience_range_from.as_deref().unwrap_or_default();
+        let to = self.audience_range_to.as_deref().unwrap_or_default();
+        let range = match (precision, to.is_empty()) {
+            (AudienceRangePrecision::EXACT, _) => from.to_string(),
+            (AudienceRangePrecision::FROM, true) => format!("{from} and up"),
+            (AudienceRangePrecision::FROM, false) => format!("{from}-{to}"),
+            (AudienceRangePrecision::TO, _) => format!("up to {to}"),
+            (AudienceRangePrecision::Other(_), _) => unreachable!(),
+        };
+        // First indicator 1 (interest age level), 2 (interest grade level) or
+        // 8 (no display constant)
+        let (ind, text) = match qualifier {
+            AudienceRangeQualifier::US_SCHOOL_GRADE => ("2\\", format!("{range}.")),
+            AudienceRangeQualifier::UK_SCHOOL_GRADE => ("2\\", format!("UK school year {range}.")),
+            AudienceRangeQualifier::INTEREST_AGE_MONTHS => ("1\\", format!("{range} months.")),
+            AudienceRangeQualifier::INTEREST_AGE_YEARS => ("1\\", format!("{range}.")),
+            AudienceRangeQualifier::READING_AGE_YEARS => {
+                ("8\\", format!("Reading age {range} years."))
+            }
+            AudienceRangeQualifier::Other(_) => unreachable!(),
+        };
+        let mut text = text.into_bytes();
+        text[0] = text[0].to_ascii_uppercase();
+        FieldRepr::from((b"521", ind))
+            .add_subfield(b"a", text)
+            .and_then(|f| builder.add_field(f))?;
+        Ok(())
+    }
+}
+
 type SubjectField<'a, 'b> = (&'a [u8; 3], &'b str, Option<(&'a [u8; 1], &'b str)>);
 impl Marc21Field<Marc21RecordThoth> for WorkSubjects {
     fn to_field(&self, builder: &mut RecordBuilder) -> ThothResult<()> {
@@ -737,6 +874,55 @@ fn description_string(work: &Work) -> (String, Option<String>) {
     }
 }
 
+/// Extent, other physical details and height of a print publication with recorded product
+/// form details, punctuated for consecutive 300 subfields
+fn print_description_string(
+    work: &Work,
+    publication: &WorkPublications,
+) -> Option<(String, String, Option<String>)> {
+    let publication_type: PublicationType = publication.publication_type.clone().into();
+    if !matches!(
+        publication_type,
+        PublicationType::Paperback | PublicationType::Hardback
+    ) {
+        return None;
+    }
+    let details = publication
+        .product_form_details
+        .iter()
+        .filter_map(|detail| {
+            ProductFormDetail {
+                detail_type: detail.detail_type.clone().into(),
+                detail_code: detail.detail_code.clone(),
+                feature_value: detail.feature_value.clone(),
+                ..Default::default()
+            }
+            .label()
+        })
+        .map(|label| {
+            let mut chars = label.chars();
+            chars
+                .next()
+                .map(|first| first.to_lowercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    if details.is_empty() {
+        return None;
+    }
+    let extent = match (work.page_breakdown.as_ref(), work.page_count) {
+        (Some(breakdown), _) => format!("1 volume ({breakdown} pages) :"),
+        (_, Some(count)) => format!("1 volume ({count} pages) :"),
+        _ => "1 volume :".to_string(),
+    };
+    // RDA records height in centimetres, rounded up
+    let dimensions = publication
+        .height_cm
+        .map(|height| format!("{} cm", height.ceil() as i64));
+    let terminator = if dimensions.is_some() { " ;" } else { "." };
+    Some((extent, details.join(", ") + terminator, dimensions))
+}
+
 fn contributors_string(contributions: &[WorkContributions]) -> String {
     // group main contributions by contribution type
     let mut contributions_by_type: Vec<(ContributionType, Vec<&WorkContributions>)> = vec![];
@@ -796,9 +982,10 @@ pub(crate) mod tests {
     use std::str::FromStr;
     use thoth_api::model::{Doi, Isbn, Orcid};
     use thoth_client::{
-        FundingInstitution, LanguageCode, SeriesType, WorkContributionsAffiliations,
-        WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkImprint,
-        WorkImprintPublisher, WorkIssues, WorkIssuesSeries, WorkRelationsRelatedWork,
+        FundingInstitution, LanguageCode, ProductFormDetailType, SeriesType,
+        WorkContributionsAffiliations, WorkContributionsAffiliationsInstitution,
+        WorkContributionsContributor, WorkImprint, WorkImprintPublisher, WorkIssues,
+        WorkIssuesSeries, WorkPublicationsProductFormDetails, WorkRelationsRelatedWork,
         WorkRelationsRelatedWorkContributions, WorkRelationsRelatedWorkContributionsContributor,
         WorkRelationsRelatedWorkImprint, WorkRelationsRelatedWorkImprintPublisher, WorkStatus,
     };
@@ -891,11 +1078,14 @@ pub(crate) mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 1,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: Some(
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap(),
                         ),
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![WorkContributionsAffiliations {
                         position: None,
@@ -916,11 +1106,14 @@ pub(crate) mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 2,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: Some(
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0004").unwrap(),
                         ),
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -932,9 +1125,12 @@ pub(crate) mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 3,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![WorkContributionsAffiliations {
                         position: None,
@@ -980,6 +1176,11 @@ pub(crate) mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Default::default(),
@@ -1002,6 +1203,11 @@ pub(crate) mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Default::default(),
@@ -1024,6 +1230,11 @@ pub(crate) mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
             ],
             subjects: vec![
@@ -1063,6 +1274,13 @@ pub(crate) mod tests {
                     subject_ordinal: 7,
                 },
             ],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![WorkFundings {
                 program: Some("Funding Programme".to_string()),
                 project_name: Some("Funding Project".to_string()),
@@ -1089,9 +1307,12 @@ pub(crate) mod tests {
             main_contribution: true,
             biographies: vec![],
             contribution_ordinal: 1,
+            name_variants: vec![],
+            credit_roles: vec![],
             contributor: WorkContributionsContributor {
                 orcid: None,
                 website: None,
+                name_variants: vec![],
             },
             affiliations: vec![],
         }
@@ -1175,6 +1396,29 @@ pub(crate) mod tests {
         assert_eq!(contributor_fields(&contributions), expected);
     }
 
+    #[test]
+    fn test_contributor_fields_shared_relator_term() {
+        let mut contribution = test_contribution();
+        contribution.first_name = Some("John".to_string());
+        contribution.last_name = "Doe".to_string();
+        contribution.full_name = "John Doe".to_string();
+        let contributions = [
+            thoth_client::ContributionType::EDITOR,
+            thoth_client::ContributionType::MUSIC_EDITOR,
+            thoth_client::ContributionType::SOFTWARE_BY,
+        ]
+        .map(|contribution_type| WorkContributions {
+            contribution_type,
+            ..contribution.clone()
+        });
+
+        let expected = Ok(vec![FieldRepr::from((b"700", "1\\"))
+            .add_subfield(b"a", "Doe, John,".as_bytes())
+            .and_then(|f| f.add_subfield(b"e", "editor, programmer.".as_bytes()))
+            .unwrap()]);
+        assert_eq!(contributor_fields(&contributions), expected);
+    }
+
     #[test]
     fn test_contributor_fields_multiple_contributors_no_author() {
         let mut first_contribution = test_contribution();
@@ -1271,7 +1515,7 @@ pub(crate) mod tests {
                 .unwrap(),
             FieldRepr::from((b"700", "1\\"))
                 .add_subfield(b"a", "Johnson, Billy Bob,".as_bytes())
-                .and_then(|f| f.add_subfield(b"e", "introduction by.".as_bytes()))
+                .and_then(|f| f.add_subfield(b"e", "writer of introduction.".as_bytes()))
                 .unwrap(),
             FieldRepr::from((b"700", "1\\"))
                 .add_subfield(b"a", "García Sánchez, Juan,".as_bytes())
@@ -1354,6 +1598,7 @@ pub(crate) mod tests {
         contribution.contributor = WorkContributionsContributor {
             orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0011").unwrap()),
             website: None,
+            name_variants: vec![],
         };
         let contributions = [contribution];
 
@@ -1394,7 +1639,7 @@ pub(crate) mod tests {
         let expected = Ok(vec![
             FieldRepr::from((b"700", "0\\"))
                 .add_subfield(b"a", "Thoth Collective,".as_bytes())
-                .and_then(|f| f.add_subfield(b"e", "editor, introduction by.".as_bytes()))
+                .and_then(|f| f.add_subfield(b"e", "editor, writer of introduction.".as_bytes()))
                 .unwrap(),
             FieldRepr::from((b"100", "0\\"))
                 .add_subfield(b"a", "Anonymous,".as_bytes())
@@ -1402,7 +1647,79 @@ pub(crate) mod tests {
                 .unwrap(),
             FieldRepr::from((b"700", "1\\"))
                 .add_subfield(b"a", "Collective, Thoth,".as_bytes())
-                .and_then(|f| f.add_subfield(b"e", "research by.".as_bytes()))
+                .and_then(|f| f.add_subfield(b"e", "researcher.".as_bytes()))
+                .unwrap(),
+        ]);
+        assert_eq!(contributor_fields(&contributions), expected);
+    }
+
+    fn test_name_variant(
+        script_code: &str,
+        first_name: Option<&str>,
+        last_name: &str,
+    ) -> NameVariant {
+        NameVariant {
+            variant_type: NameVariantType::TRANSLITERATION,
+            script_code: Some(script_code.to_string()),
+            locale_code: None,
+            first_name: first_name.map(|n| n.to_string()),
+            last_name: last_name.to_string(),
+            full_name: match first_name {
+                Some(first_name) => format!("{first_name} {last_name}"),
+                None => last_name.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_contributor_fields_alternate_script_names() {
+        let mut first_contribution = test_contribution();
+        first_contribution.first_name = Some("Lev".to_string());
+        first_contribution.last_name = "Tolstoy".to_string();
+        first_contribution.full_name = "Lev Tolstoy".to_string();
+        first_contribution.contributor.name_variants =
+            vec![test_name_variant("Cyrl", Some("Лев"), "Толстой")];
+        let mut second_contribution = test_contribution();
+        second_contribution.first_name = Some("Jane".to_string());
+        second_contribution.last_name = "Doe".to_string();
+        second_contribution.full_name = "Jane Doe".to_string();
+        second_contribution.contribution_type = thoth_client::ContributionType::TRANSLATOR;
+        // Latin-script variants have no alternate graphic representation
+        second_contribution.name_variants = vec![test_name_variant("Latn", None, "J. Doe")];
+        let mut third_contribution = test_contribution();
+        third_contribution.last_name = "Lu Xun".to_string();
+        third_contribution.full_name = "Lu Xun".to_string();
+        third_contribution.contribution_type = thoth_client::ContributionType::INTRODUCTION_BY;
+        // a variant credited in the contribution takes precedence over the contributor's
+        third_contribution.contributor.name_variants =
+            vec![test_name_variant("Hant", None, "魯迅")];
+        third_contribution.name_variants = vec![test_name_variant("Hans", None, "鲁迅")];
+        let contributions = [first_contribution, second_contribution, third_contribution];
+
+        let expected = Ok(vec![
+            FieldRepr::from((b"100", "1\\"))
+                .add_subfield(b"6", "880-01".as_bytes())
+                .and_then(|f| f.add_subfield(b"a", "Tolstoy, Lev,".as_bytes()))
+                .and_then(|f| f.add_subfield(b"e", "author.".as_bytes()))
+                .unwrap(),
+            FieldRepr::from((b"700", "1\\"))
+                .add_subfield(b"a", "Doe, Jane,".as_bytes())
+                .and_then(|f| f.add_subfield(b"e", "translator.".as_bytes()))
+                .unwrap(),
+            FieldRepr::from((b"700", "0\\"))
+                .add_subfield(b"6", "880-02".as_bytes())
+                .and_then(|f| f.add_subfield(b"a", "Lu Xun,".as_bytes()))
+                .and_then(|f| f.add_subfield(b"e", "writer of introduction.".as_bytes()))
+                .unwrap(),
+            FieldRepr::from((b"880", "1\\"))
+                .add_subfield(b"6", "100-01/(N".as_bytes())
+                .and_then(|f| f.add_subfield(b"a", "Толстой, Лев,".as_bytes()))
+                .and_then(|f| f.add_subfield(b"e", "author.".as_bytes()))
+                .unwrap(),
+            FieldRepr::from((b"880", "0\\"))
+                .add_subfield(b"6", "700-02/$1".as_bytes())
+                .and_then(|f| f.add_subfield(b"a", "鲁迅,".as_bytes()))
+                .and_then(|f| f.add_subfield(b"e", "writer of introduction.".as_bytes()))
                 .unwrap(),
         ]);
         assert_eq!(contributor_fields(&contributions), expected);
@@ -1802,6 +2119,51 @@ pub(crate) mod tests {
         assert_eq!(description_string(&work), expected);
     }
 
+    #[test]
+    fn test_print_description_string() {
+        let mut work = test_work();
+        work.page_count = Some(248);
+        // PDF publications are described by the main 300 field
+        assert_eq!(print_description_string(&work, &work.publications[0]), None);
+        // Print publications are only described once their form has been recorded
+        assert_eq!(print_description_string(&work, &work.publications[2]), None);
+
+        work.publications[2].product_form_details = vec![
+            WorkPublicationsProductFormDetails {
+                detail_type: ProductFormDetailType::FORM_DETAIL,
+                detail_code: "B401".to_string(),
+                feature_value: None,
+                feature_description: None,
+            },
+            WorkPublicationsProductFormDetails {
+                detail_type: ProductFormDetailType::FORM_FEATURE,
+                detail_code: "01".to_string(),
+                feature_value: Some("NAV".to_string()),
+                feature_description: None,
+            },
+        ];
+        let expected = Some((
+            "1 volume (248 pages) :".to_string(),
+            "cloth over boards, colour of cover: Navy/Dark blue.".to_string(),
+            None,
+        ));
+        assert_eq!(
+            print_description_string(&work, &work.publications[2]),
+            expected
+        );
+
+        work.publications[2].height_cm = Some(23.4);
+        let expected = Some((
+            "1 volume (248 pages) :".to_string(),
+            "cloth over boards, colour of cover: Navy/Dark blue ;".to_string(),
+            Some("24 cm".to_string()),
+        ));
+        assert_eq!(
+            print_description_string(&work, &work.publications[2]),
+            expected
+        );
+    }
+
     #[test]
     fn test_contributors_string_single_author() {
         let mut contribution = test_contribution();
@@ -1934,6 +2296,46 @@ pub(crate) mod tests {
         assert_eq!(Marc21RecordThoth {}.generate(&[work]), Ok(expected))
     }
 
+    #[test]
+    fn test_generate_marc_audiences() {
+        let mut work = test_work();
+        work.audiences = vec![
+            WorkAudiences {
+                audience_code: AudienceCode::CHILDREN,
+                audience_range_qualifier: Some(AudienceRangeQualifier::INTEREST_AGE_YEARS),
+                audience_range_precision: Some(AudienceRangePrecision::FROM),
+                audience_range_from: Some("9".to_string()),
+                audience_range_to: Some("12".to_string()),
+            },
+            WorkAudiences {
+                audience_code: AudienceCode::PRIMARY_AND_SECONDARY,
+                audience_range_qualifier: Some(AudienceRangeQualifier::READING_AGE_YEARS),
+                audience_range_precision: Some(AudienceRangePrecision::TO),
+                audience_range_from: None,
+                audience_range_to: Some("10".to_string()),
+            },
+            WorkAudiences {
+                audience_code: AudienceCode::HIGHER_EDUCATION,
+                audience_range_qualifier: None,
+                audience_range_precision: None,
+                audience_range_from: None,
+                audience_range_to: None,
+            },
+        ];
+        let output = Marc21Entry::<Marc21RecordThoth>::marc21_markup(&work).unwrap();
+        assert!(output.contains(
+            "=520  \\\\$aLoremipsumdolor sit amet\n=521  \\\\$aChildren/juvenile.\n=521  1\\$a9-12.\n"
+        ));
+        assert!(output.contains(
+            "=521  \\\\$aPrimary and secondary education.\n=521  8\\$aReading age up to 10 years.\n"
+        ));
+        assert!(output.contains("=521  \\\\$aCollege/higher education.\n=536"));
+
+        work.audiences = vec![];
+        let output = Marc21Entry::<Marc21RecordThoth>::marc21_markup(&work).unwrap();
+        assert!(!output.contains("=521"));
+    }
+
     #[test]
     fn test_generate_no_work_error() {
         assert!(Marc21RecordThoth {}.generate(&[]).is_err())
@@ -0,0 +1,49 @@
+use lazy_static::lazy_static;
+use prometheus::{register_int_counter_vec, IntCounterVec};
+
+lazy_static! {
+    static ref CACHE_LOOKUPS: IntCounterVec = register_int_counter_vec!(
+        "thoth_export_cache_lookups_total",
+        "Lookups of records in the Redis cache, per specification and result (hit or miss)",
+        &["specification", "result"]
+    )
+    .unwrap();
+}
+
+/// Count a lookup of a cached record. The hit ratio is `hit / (hit + miss)`.
+pub(crate) fn record_cache_lookup(specification: &str, hit: bool) {
+    let result = if hit { "hit" } else { "miss" };
+    CACHE_LOOKUPS
+        .with_label_values(&[specification, result])
+        .inc();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_cache_lookup() {
+        let hits = CACHE_LOOKUPS
+            .with_label_values(&["csv::thoth", "hit"])
+            .get();
+        let misses = CACHE_LOOKUPS
+            .with_label_values(&["csv::thoth", "miss"])
+            .get();
+        record_cache_lookup("csv::thoth", true);
+        record_cache_lookup("csv::thoth", true);
+        record_cache_lookup("csv::thoth", false);
+        assert_eq!(
+            CACHE_LOOKUPS
+                .with_label_values(&["csv::thoth", "hit"])
+                .get(),
+            hits + 2
+        );
+        assert_eq!(
+            CACHE_LOOKUPS
+                .with_label_values(&["csv::thoth", "miss"])
+                .get(),
+            misses + 1
+        );
+    }
+}
@@ -6,14 +6,21 @@ use paperclip::actix::{
 
 use super::model::Platform;
 use crate::data::{find_platform, ALL_PLATFORMS};
+use crate::ApiConfig;
 
 #[api_v2_operation(
     summary = "List supported platforms",
     description = "Full list of platforms supported by Thoth's outputs",
     tags(Platforms)
 )]
-pub(crate) async fn get_all() -> Json<Vec<Platform<'static>>> {
-    Json(ALL_PLATFORMS.clone())
+pub(crate) async fn get_all(config: web::Data<ApiConfig>) -> Json<Vec<Platform<'static>>> {
+    Json(
+        ALL_PLATFORMS
+            .iter()
+            .cloned()
+            .map(|p| p.with_public_url(&config.public_url))
+            .collect(),
+    )
 }
 
 #[api_v2_operation(
@@ -23,8 +30,9 @@ pub(crate) async fn get_all() -> Json<Vec<Platform<'static>>> {
 )]
 pub(crate) async fn get_one(
     platform_id: web::Path<String>,
+    config: web::Data<ApiConfig>,
 ) -> Result<Json<Platform<'static>>, Error> {
     find_platform(platform_id.into_inner())
-        .map(Json)
+        .map(|p| Json(p.with_public_url(&config.public_url)))
         .map_err(|e| e.into())
 }
@@ -6,5 +6,17 @@ use serde::{Deserialize, Serialize};
 pub(crate) struct Platform<'a> {
     pub(crate) id: &'a str,
     pub(crate) name: &'a str,
-    pub(crate) accepts: Vec<&'a str>,
+    /// Paths of the specifications, relative to the public URL of the export API until made
+    /// absolute with [`Platform::with_public_url`]
+    pub(crate) accepts: Vec<String>,
+}
+
+impl Platform<'_> {
+    /// Turn the links to the specifications into absolute URLs
+    pub(crate) fn with_public_url(mut self, public_url: &str) -> Self {
+        for specification in self.accepts.iter_mut() {
+            *specification = format!("{public_url}{specification}");
+        }
+        self
+    }
 }
@@ -16,6 +16,7 @@ use crate::bibtex::{BibtexSpecification, BibtexThoth};
 use crate::csv::{CsvSpecification, CsvThoth, KbartOclc};
 use crate::json::{JsonSpecification, JsonThoth};
 use crate::marc21::{Marc21MarkupThoth, Marc21RecordThoth, Marc21Specification};
+use crate::metrics::record_cache_lookup;
 use crate::specification_query::SpecificationQuery;
 use crate::xml::{
     DoiDepositCrossref, Marc21XmlThoth, Onix21EbscoHost, Onix21ProquestEbrary, Onix31Thoth,
@@ -195,15 +196,18 @@ impl MetadataRecord {
             let cached_timestamp = Timestamp::parse_from_rfc3339(&cached_timestamp_value)?;
             if cached_timestamp >= self.last_updated {
                 if let Ok(cached_record) = get(&redis_pool, &cache_key).await {
+                    record_cache_lookup(&self.specification.to_string(), true);
                     self.record = Ok(cached_record);
                     return Ok(());
                 }
                 if let Ok(cached_error) = get(&redis_pool, &cache_error_key).await {
+                    record_cache_lookup(&self.specification.to_string(), true);
                     self.record = Err(ThothError::from_json(&cached_error)?);
                     return Ok(());
                 }
             }
         }
+        record_cache_lookup(&self.specification.to_string(), false);
 
         let data = specification_query.run().await?;
         self.record = self.generate(data);
@@ -211,6 +215,22 @@ impl MetadataRecord {
         Ok(())
     }
 
+    /// Generate the record straight from the API, bypassing the cache
+    pub(crate) async fn generate_uncached(
+        &mut self,
+        specification_query: SpecificationQuery,
+    ) -> ThothResult<()> {
+        let data = specification_query.run().await?;
+        self.record = self.generate(data);
+        Ok(())
+    }
+
+    /// Consume the record, returning its file name along with its contents
+    pub(crate) fn into_file(self) -> ThothResult<(String, String)> {
+        let file_name = self.file_name();
+        self.record.map(|record| (file_name, record))
+    }
+
     /// Cache the record, update the timestamp, and delete previous errors or records
     async fn update_cache(&self, redis_pool: &RedisPool) -> ThothResult<()> {
         set(
@@ -232,6 +252,10 @@ impl MetadataRecord {
         Ok(())
     }
 
+    #[tracing::instrument(
+        skip_all,
+        fields(specification = %self.specification, id = %self.id, works = data.len())
+    )]
     fn generate(&self, data: Vec<Work>) -> ThothResult<String> {
         match &self.specification {
             MetadataSpecification::Onix31Thoth(onix31_thoth) => onix31_thoth.generate(&data, None),
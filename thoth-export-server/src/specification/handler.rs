@@ -2,6 +2,7 @@ use super::model::Specification;
 use crate::data::{find_specification, ALL_SPECIFICATIONS};
 use crate::record::{MetadataRecord, MetadataSpecification};
 use crate::specification_query::SpecificationQuery;
+use crate::ApiConfig;
 use actix_web::Error;
 use paperclip::actix::{
     api_v2_operation,
@@ -16,8 +17,14 @@ use uuid::Uuid;
     description = "Full list of metadata specifications that can be output by Thoth",
     tags(Specifications)
 )]
-pub(crate) async fn get_all() -> Json<Vec<Specification<'static>>> {
-    Json(ALL_SPECIFICATIONS.clone())
+pub(crate) async fn get_all(config: web::Data<ApiConfig>) -> Json<Vec<Specification<'static>>> {
+    Json(
+        ALL_SPECIFICATIONS
+            .iter()
+            .cloned()
+            .map(|s| s.with_public_url(&config.public_url))
+            .collect(),
+    )
 }
 
 #[api_v2_operation(
@@ -27,9 +34,10 @@ pub(crate) async fn get_all() -> Json<Vec<Specification<'static>>> {
 )]
 pub(crate) async fn get_one(
     specification_id: web::Path<String>,
+    config: web::Data<ApiConfig>,
 ) -> Result<Json<Specification<'static>>, Error> {
     find_specification(specification_id.into_inner())
-        .map(Json)
+        .map(|s| Json(s.with_public_url(&config.public_url)))
         .map_err(|e| e.into())
 }
 
@@ -6,8 +6,21 @@ use serde::{Deserialize, Serialize};
 pub struct Specification<'a> {
     pub(crate) id: &'a str,
     pub(crate) name: &'a str,
-    pub(crate) format: &'a str,
-    pub(crate) accepted_by: Vec<&'a str>,
+    /// Path of the format, relative to the public URL of the export API until made absolute
+    /// with [`Specification::with_public_url`]
+    pub(crate) format: String,
+    pub(crate) accepted_by: Vec<String>,
+}
+
+impl Specification<'_> {
+    /// Turn the links to the format and platforms into absolute URLs
+    pub(crate) fn with_public_url(mut self, public_url: &str) -> Self {
+        self.format = format!("{public_url}{}", self.format);
+        for platform in self.accepted_by.iter_mut() {
+            *platform = format!("{public_url}{platform}");
+        }
+        self
+    }
 }
 
 impl std::fmt::Display for Specification<'_> {
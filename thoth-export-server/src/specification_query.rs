@@ -127,7 +127,13 @@ impl TryFrom<QueryConfiguration> for QueryParameters {
     fn try_from(q: QueryConfiguration) -> ThothResult<Self> {
         match q.specification {
             MetadataSpecification::Onix31Thoth(_) => Ok(QueryParameters::new().with_all()),
-            MetadataSpecification::Onix3Thoth(_) => Ok(QueryParameters::new().with_all()),
+            // awards, endorsements, reviews and resources are only output in ONIX 3.1
+            MetadataSpecification::Onix3Thoth(_) => Ok(QueryParameters::new()
+                .with_all()
+                .without_awards()
+                .without_endorsements()
+                .without_book_reviews()
+                .without_resources()),
             MetadataSpecification::Onix3ProjectMuse(_) => Ok(QueryParameters::new()
                 .with_canonical_abstracts_only()
                 .with_canonical_title_only()
@@ -1,16 +1,17 @@
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use regex::Regex;
 use std::io::Write;
 use thoth_api::model::IdentifierWithDomain;
 use thoth_client::{
-    AbstractType, ContributionType, Funding, PublicationType, Reference, RelationType, Work,
-    WorkContributions, WorkContributionsAffiliationsInstitution, WorkFundings, WorkIssuesSeries,
-    WorkPublications, WorkReferences, WorkRelations, WorkRelationsRelatedWorkContributions,
+    AbstractType, Funding, PublicationType, Reference, RelationType, Work, WorkContributions,
+    WorkContributionsAffiliationsInstitution, WorkFundings, WorkIssuesSeries, WorkPublications,
+    WorkReferences, WorkRelations, WorkRelationsRelatedWorkContributions,
     WorkRelationsRelatedWorkContributionsAffiliationsInstitution, WorkType,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
 use super::{write_element_block, XmlSpecification};
+use crate::access::PublicationAccessFields;
 use crate::xml::{write_full_element_block, XmlElementBlock};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -137,7 +138,7 @@ impl XmlElementBlock<DoiDepositCrossref> for Work {
 
                 let mut chapters = self.relations.clone();
                 // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
-                chapters.sort_by(|a, b| a.relation_ordinal.cmp(&b.relation_ordinal));
+                chapters.sort_by_key(|r| r.relation_ordinal);
                 for chapter in chapters
                     .iter()
                     .filter(|r| r.relation_type == RelationType::HAS_CHILD)
@@ -159,12 +160,8 @@ fn write_work_contributions<W: Write>(work: &Work, w: &mut EventWriter<W>) -> Th
         .contributions
         .clone()
         .into_iter()
-        // Only Author, Editor and Translator are supported by this format. Omit any other contributors.
-        .filter(|c| {
-            c.contribution_type == ContributionType::AUTHOR
-                || c.contribution_type == ContributionType::EDITOR
-                || c.contribution_type == ContributionType::TRANSLATOR
-        })
+        // Only roles with a Crossref equivalent are supported by this format. Omit any other contributors.
+        .filter(|c| c.contribution_type.role_mapping().crossref_role.is_some())
         .collect();
     if !contributions.is_empty() {
         write_element_block("contributors", w, |w| {
@@ -186,12 +183,8 @@ fn write_chapter_contributions<W: Write>(
         .contributions
         .clone()
         .into_iter()
-        // Only Author, Editor and Translator are supported by this format. Omit any other contributors.
-        .filter(|c| {
-            c.contribution_type == ContributionType::AUTHOR
-                || c.contribution_type == ContributionType::EDITOR
-                || c.contribution_type == ContributionType::TRANSLATOR
-        })
+        // Only roles with a Crossref equivalent are supported by this format. Omit any other contributors.
+        .filter(|c| c.contribution_type.role_mapping().crossref_role.is_some())
         .collect();
     if !contributions.is_empty() {
         write_element_block("contributors", w, |w| {
@@ -613,7 +606,8 @@ fn write_crossmark_funding_access<W: Write>(
 
             // If crossmark metadata is included, funding and access data must be inside the <crossmark> element
             // within <custom_metadata> tag. If no funding or access data exist, don't include <custom_metadata> tag.
-            if work.license.is_some() || !work.fundings.is_empty() {
+            let access = access_indicators(work.license.as_ref(), &work.publications);
+            if !access.is_empty() || !work.fundings.is_empty() {
                 write_element_block("custom_metadata", w, |w| write_work_funding_access(work, w))
             } else {
                 Ok(())
@@ -628,7 +622,10 @@ fn write_crossmark_funding_access<W: Write>(
 
 fn write_work_funding_access<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
     write_funding_content(&work.fundings, w)?;
-    write_access_content(&work.license, w)?;
+    write_access_content(
+        &access_indicators(work.license.as_ref(), &work.publications),
+        w,
+    )?;
     Ok(())
 }
 
@@ -637,7 +634,13 @@ fn write_chapter_funding_access<W: Write>(
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
     write_funding_content(&chapter.related_work.fundings, w)?;
-    write_access_content(&chapter.related_work.license, w)?;
+    write_access_content(
+        &access_indicators(
+            chapter.related_work.license.as_ref(),
+            &chapter.related_work.publications,
+        ),
+        w,
+    )?;
     Ok(())
 }
 
@@ -656,21 +659,85 @@ fn write_funding_content<W: Write>(
     Ok(())
 }
 
+/// Crossref access indicators, derived from the licences of a work's digital publications
+struct AccessIndicators<'a> {
+    /// Whether the content is free to read, and if so from which date (`None` meaning now)
+    free_to_read: Option<Option<NaiveDate>>,
+    license_refs: Vec<(&'a str, Option<NaiveDate>)>,
+}
+
+impl AccessIndicators<'_> {
+    fn is_empty(&self) -> bool {
+        self.free_to_read.is_none() && self.license_refs.is_empty()
+    }
+}
+
+fn access_indicators<'a, P: PublicationAccessFields>(
+    work_license: Option<&'a String>,
+    publications: &'a [P],
+) -> AccessIndicators<'a> {
+    let accesses: Vec<_> = publications
+        .iter()
+        .filter(|p| p.is_digital())
+        .map(|p| p.access(work_license))
+        .collect();
+    if accesses.is_empty() {
+        // Assume works without licences are non-OA
+        return AccessIndicators {
+            free_to_read: work_license.map(|_| None),
+            license_refs: work_license
+                .map(|l| (l.as_str(), None))
+                .into_iter()
+                .collect(),
+        };
+    }
+    let mut license_refs = vec![];
+    for access in &accesses {
+        if let Some(license) = access.declared_license() {
+            let license_ref = (license, access.license_start_date());
+            if !license_refs.contains(&license_ref) {
+                license_refs.push(license_ref);
+            }
+        }
+    }
+    let free_to_read = if accesses.iter().any(|a| a.is_open()) {
+        Some(None)
+    } else {
+        accesses
+            .iter()
+            .filter_map(|a| a.license_start_date())
+            .min()
+            .map(Some)
+    };
+    AccessIndicators {
+        free_to_read,
+        license_refs,
+    }
+}
+
 fn write_access_content<W: Write>(
-    license: &Option<String>,
+    access: &AccessIndicators,
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
-    // Assume works without licences are non-OA
-    if let Some(license) = license {
+    if !access.is_empty() {
         write_full_element_block(
             "ai:program",
             Some(vec![("name", "AccessIndicators")]),
             w,
             |w| {
-                write_element_block("ai:free_to_read", w, |_w| Ok(()))?;
-                write_element_block("ai:license_ref", w, |w| {
-                    w.write(XmlEvent::Characters(license)).map_err(|e| e.into())
-                })
+                if let Some(start_date) = access.free_to_read {
+                    let start_date = start_date.map(|d| d.to_string());
+                    let attr = start_date.as_deref().map(|d| vec![("start_date", d)]);
+                    write_full_element_block("ai:free_to_read", attr, w, |_w| Ok(()))?;
+                }
+                for (license, start_date) in &access.license_refs {
+                    let start_date = start_date.map(|d| d.to_string());
+                    let attr = start_date.as_deref().map(|d| vec![("start_date", d)]);
+                    write_full_element_block("ai:license_ref", attr, w, |w| {
+                        w.write(XmlEvent::Characters(license)).map_err(|e| e.into())
+                    })?;
+                }
+                Ok(())
             },
         )?;
     }
@@ -1003,28 +1070,16 @@ impl XmlElementBlock<DoiDepositCrossref> for WorkPublications {
 
 impl XmlElementBlock<DoiDepositCrossref> for WorkContributions {
     fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        let role = match &self.contribution_type {
-            ContributionType::AUTHOR => "author",
-            ContributionType::EDITOR => "editor",
-            ContributionType::TRANSLATOR => "translator",
-            // Only the above roles are supported by this format.
-            ContributionType::PHOTOGRAPHER
-            | ContributionType::ILLUSTRATOR
-            | ContributionType::MUSIC_EDITOR
-            | ContributionType::FOREWORD_BY
-            | ContributionType::INTRODUCTION_BY
-            | ContributionType::AFTERWORD_BY
-            | ContributionType::PREFACE_BY
-            | ContributionType::SOFTWARE_BY
-            | ContributionType::RESEARCH_BY
-            | ContributionType::CONTRIBUTIONS_BY
-            | ContributionType::INDEXER
-            | ContributionType::Other(_) => unreachable!(),
+        let role = match self.contribution_type.role_mapping().crossref_role {
+            Some(role) => role,
+            // Only roles with a Crossref equivalent are supported by this format.
+            None => unreachable!(),
         };
         let ordinal = match &self.contribution_ordinal {
             1 => "first",
             _ => "additional",
         };
+        // CRediT roles are not output: schema 5.3.1 has no element for them.
         write_full_element_block(
             "person_name",
             Some(vec![("sequence", ordinal), ("contributor_role", role)]),
@@ -1066,23 +1121,10 @@ impl XmlElementBlock<DoiDepositCrossref> for WorkContributions {
 
 impl XmlElementBlock<DoiDepositCrossref> for WorkRelationsRelatedWorkContributions {
     fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        let role = match &self.contribution_type {
-            ContributionType::AUTHOR => "author",
-            ContributionType::EDITOR => "editor",
-            ContributionType::TRANSLATOR => "translator",
-            // Only the above roles are supported by this format.
-            ContributionType::PHOTOGRAPHER
-            | ContributionType::ILLUSTRATOR
-            | ContributionType::MUSIC_EDITOR
-            | ContributionType::FOREWORD_BY
-            | ContributionType::INTRODUCTION_BY
-            | ContributionType::AFTERWORD_BY
-            | ContributionType::PREFACE_BY
-            | ContributionType::SOFTWARE_BY
-            | ContributionType::RESEARCH_BY
-            | ContributionType::CONTRIBUTIONS_BY
-            | ContributionType::INDEXER
-            | ContributionType::Other(_) => unreachable!(),
+        let role = match self.contribution_type.role_mapping().crossref_role {
+            Some(role) => role,
+            // Only roles with a Crossref equivalent are supported by this format.
+            None => unreachable!(),
         };
         let ordinal = match &self.contribution_ordinal {
             1 => "first",
@@ -1333,12 +1375,12 @@ mod tests {
     use std::str::FromStr;
     use thoth_api::model::{Doi, Isbn, Orcid, Ror};
     use thoth_client::{
-        ContributionType, FundingInstitution, LocationPlatform, PublicationType, SeriesType,
-        WorkContributions, WorkContributionsAffiliations, WorkContributionsAffiliationsInstitution,
-        WorkContributionsContributor, WorkFundings, WorkImprint, WorkImprintPublisher, WorkIssues,
-        WorkIssuesSeries, WorkPublications, WorkPublicationsLocations, WorkReferences,
-        WorkRelations, WorkRelationsRelatedWork, WorkRelationsRelatedWorkContributions,
-        WorkRelationsRelatedWorkContributionsAffiliations,
+        AccessStatus, ContributionType, FundingInstitution, LocationPlatform, PublicationType,
+        SeriesType, WorkContributions, WorkContributionsAffiliations,
+        WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkFundings,
+        WorkImprint, WorkImprintPublisher, WorkIssues, WorkIssuesSeries, WorkPublications,
+        WorkPublicationsLocations, WorkReferences, WorkRelations, WorkRelationsRelatedWork,
+        WorkRelationsRelatedWorkContributions, WorkRelationsRelatedWorkContributionsAffiliations,
         WorkRelationsRelatedWorkContributionsAffiliationsInstitution,
         WorkRelationsRelatedWorkContributionsContributor, WorkRelationsRelatedWorkImprint,
         WorkRelationsRelatedWorkImprintPublisher, WorkRelationsRelatedWorkPublications,
@@ -1458,6 +1500,9 @@ mod tests {
                         full_text_url: Some("https://www.book.com/chapterone_fulltext".to_string()),
                         canonical: true,
                     }],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 }],
                 references: vec![],
                 fundings: vec![],
@@ -1718,11 +1763,14 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 4,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: Some(
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0004").unwrap(),
                         ),
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -1734,11 +1782,14 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 1,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: Some(
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap(),
                         ),
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![WorkContributionsAffiliations {
                         position: None,
@@ -1759,11 +1810,14 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 2,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: Some(
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0002").unwrap(),
                         ),
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -1775,9 +1829,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 3,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![WorkContributionsAffiliations {
                         position: None,
@@ -1819,6 +1876,11 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -1846,6 +1908,11 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -1868,9 +1935,21 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
             ],
             subjects: vec![],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![
                 WorkFundings {
                     program: None,
@@ -2191,6 +2270,32 @@ mod tests {
         assert!(output.contains(r#"        </custom_metadata>"#));
         assert!(output.contains(r#"      </crossmark>"#));
 
+        // Hybrid title: PDF openly licensed after an embargo, XML restricted
+        test_work.publications[0].access_status = Some(AccessStatus::EMBARGOED);
+        test_work.publications[0].embargo_end_date = chrono::NaiveDate::from_ymd_opt(2999, 1, 1);
+        test_work.publications[1].access_status = Some(AccessStatus::RESTRICTED);
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"        <ai:free_to_read start_date="2999-01-01" />"#));
+        assert!(output.contains(
+            r#"      <ai:license_ref start_date="2999-01-01">https://creativecommons.org/licenses/by/4.0/</ai:license_ref>"#
+        ));
+        assert!(!output.contains(r#"        <ai:free_to_read />"#));
+        // Publication-level licence overrides the work's licence
+        test_work.publications[0].access_status = None;
+        test_work.publications[0].embargo_end_date = None;
+        test_work.publications[0].license =
+            Some("https://creativecommons.org/licenses/by-nc/4.0/".to_string());
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"        <ai:free_to_read />"#));
+        assert!(output.contains(
+            r#"      <ai:license_ref>https://creativecommons.org/licenses/by-nc/4.0/</ai:license_ref>"#
+        ));
+        assert!(!output.contains(
+            r#"      <ai:license_ref>https://creativecommons.org/licenses/by/4.0/</ai:license_ref>"#
+        ));
+        test_work.publications[0].license = None;
+        test_work.publications[1].access_status = None;
+
         // Remove/change some values to test variations/non-output of optional blocks
         test_work.work_type = WorkType::EDITED_BOOK;
         test_work.issues.clear();
@@ -2543,6 +2648,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
@@ -2565,6 +2675,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -2587,6 +2702,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -2609,6 +2729,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-EEEE-000000000005").unwrap(),
@@ -2631,6 +2756,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -2653,6 +2783,11 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-AAAB-000000000007").unwrap(),
@@ -2675,9 +2810,21 @@ mod tests {
                     accessibility_report_url: None,
                     prices: vec![],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
             ],
             subjects: vec![],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![],
             relations: vec![],
             references: vec![],
@@ -1,6 +1,14 @@
 use crate::record::XML_DECLARATION;
+use chrono::Utc;
 use std::io::Write;
-use thoth_client::Work;
+use thoth_api::model::subject::{vocabulary::crosswalk, SubjectType as ApiSubjectType};
+use thoth_api::model::CountryCode as ApiCountryCode;
+use thoth_client::{
+    AudienceCode, AudienceRangePrecision, AudienceRangeQualifier, CountryCode, PriceType,
+    ProductFormDetailType, SalesRightsType, SubjectType, Work, WorkAudiences, WorkDateType,
+    WorkPublicationsPrices, WorkPublicationsProductFormDetails, WorkPublicationsSalesRights,
+    WorkSubjects,
+};
 use thoth_errors::{ThothError, ThothResult};
 use xml::writer::events::StartElementBuilder;
 use xml::writer::{EmitterConfig, EventWriter, XmlEvent};
@@ -44,6 +52,481 @@ fn write_full_element_block<W: Write, F: Fn(&mut EventWriter<W>) -> ThothResult<
     w.write(event).map_err(|e| e.into())
 }
 
+/// Write an ONIX 3 `<Territory>` composite. A missing list of included countries
+/// represents the whole world, optionally minus the excluded countries.
+fn write_onix3_territory<W: Write>(
+    countries_included: Option<&[CountryCode]>,
+    countries_excluded: &[CountryCode],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("Territory", w, |w| match countries_included {
+        Some(countries) => write_element_block("CountriesIncluded", w, |w| {
+            w.write(XmlEvent::Characters(&onix_country_list(countries)))
+                .map_err(Into::into)
+        }),
+        None => {
+            write_element_block("RegionsIncluded", w, |w| {
+                w.write(XmlEvent::Characters("WORLD")).map_err(Into::into)
+            })?;
+            if !countries_excluded.is_empty() {
+                write_element_block("CountriesExcluded", w, |w| {
+                    w.write(XmlEvent::Characters(&onix_country_list(countries_excluded)))
+                        .map_err(Into::into)
+                })?;
+            }
+            Ok(())
+        }
+    })
+}
+
+/// Space-separated list of ISO 3166-1 alpha-2 codes, as used in ONIX territories
+fn onix_country_list(countries: &[CountryCode]) -> String {
+    countries
+        .iter()
+        .map(|country| ApiCountryCode::from(country.clone()).alpha2())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Write one ONIX 3 `<SalesRights>` composite per sales rights record. Publications
+/// without any recorded sales rights are assumed to be for sale worldwide.
+fn write_onix3_sales_rights<W: Write>(
+    sales_rights: &[WorkPublicationsSalesRights],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if sales_rights.is_empty() {
+        return write_element_block("SalesRights", w, |w| {
+            // 02 For sale with non-exclusive rights in the specified countries or territories
+            write_element_block("SalesRightsType", w, |w| {
+                w.write(XmlEvent::Characters("02")).map_err(Into::into)
+            })?;
+            write_onix3_territory(None, &[], w)
+        });
+    }
+    for rights in sales_rights {
+        write_element_block("SalesRights", w, |w| {
+            write_element_block("SalesRightsType", w, |w| {
+                let code = match rights.sales_rights_type {
+                    // 01 For sale with exclusive rights in the specified countries or territories
+                    SalesRightsType::FOR_SALE_EXCLUSIVE => "01",
+                    // 02 For sale with non-exclusive rights in the specified countries or territories
+                    SalesRightsType::FOR_SALE_NON_EXCLUSIVE => "02",
+                    // 03 Not for sale in the specified countries or territories
+                    SalesRightsType::NOT_FOR_SALE => "03",
+                    SalesRightsType::Other(_) => unreachable!(),
+                };
+                w.write(XmlEvent::Characters(code)).map_err(Into::into)
+            })?;
+            write_onix3_territory(
+                rights.countries_included.as_deref(),
+                &rights.countries_excluded,
+                w,
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Write ONIX 2.1 `<SalesRights>` composites for a publication's sales rights. ONIX 2.1
+/// cannot exclude countries from a territory, so a worldwide right with exclusions is
+/// written for the rest of the world (ROW) alongside a not-for-sale block for the excluded
+/// countries. Publications without any recorded sales rights are assumed to be for sale
+/// worldwide.
+fn write_onix21_sales_rights<W: Write>(
+    sales_rights: &[WorkPublicationsSalesRights],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if sales_rights.is_empty() {
+        return write_element_block("SalesRights", w, |w| {
+            // 02 For sale with non-exclusive rights in the specified countries or territories
+            write_element_block("SalesRightsType", w, |w| {
+                w.write(XmlEvent::Characters("02")).map_err(Into::into)
+            })?;
+            write_element_block("RightsTerritory", w, |w| {
+                w.write(XmlEvent::Characters("WORLD")).map_err(Into::into)
+            })
+        });
+    }
+    for rights in sales_rights {
+        let code = match rights.sales_rights_type {
+            // 01 For sale with exclusive rights in the specified countries or territories
+            SalesRightsType::FOR_SALE_EXCLUSIVE => "01",
+            // 02 For sale with non-exclusive rights in the specified countries or territories
+            SalesRightsType::FOR_SALE_NON_EXCLUSIVE => "02",
+            // 03 Not for sale in the specified countries or territories
+            SalesRightsType::NOT_FOR_SALE => "03",
+            SalesRightsType::Other(_) => unreachable!(),
+        };
+        write_element_block("SalesRights", w, |w| {
+            write_element_block("SalesRightsType", w, |w| {
+                w.write(XmlEvent::Characters(code)).map_err(Into::into)
+            })?;
+            match rights.countries_included.as_deref() {
+                Some(countries) => write_element_block("RightsCountry", w, |w| {
+                    w.write(XmlEvent::Characters(&onix_country_list(countries)))
+                        .map_err(Into::into)
+                }),
+                None => {
+                    let territory = if rights.countries_excluded.is_empty() {
+                        "WORLD"
+                    } else {
+                        "ROW"
+                    };
+                    write_element_block("RightsTerritory", w, |w| {
+                        w.write(XmlEvent::Characters(territory)).map_err(Into::into)
+                    })
+                }
+            }
+        })?;
+        if rights.countries_included.is_none()
+            && !rights.countries_excluded.is_empty()
+            && code != "03"
+        {
+            write_element_block("SalesRights", w, |w| {
+                write_element_block("SalesRightsType", w, |w| {
+                    w.write(XmlEvent::Characters("03")).map_err(Into::into)
+                })?;
+                write_element_block("RightsCountry", w, |w| {
+                    w.write(XmlEvent::Characters(&onix_country_list(
+                        &rights.countries_excluded,
+                    )))
+                    .map_err(Into::into)
+                })
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Write one ONIX 3 `<Audience>` composite per audience of the work, followed by an
+/// `<AudienceRange>` for each audience limited to a range of ages or grades. Works without
+/// any recorded audience are assumed to be for a professional and scholarly readership.
+fn write_onix3_audiences<W: Write>(
+    audiences: &[WorkAudiences],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let audience_codes = if audiences.is_empty() {
+        // 06 Professional and scholarly
+        vec!["06"]
+    } else {
+        audiences
+            .iter()
+            .map(|audience| onix_audience_code(&audience.audience_code))
+            .collect()
+    };
+    for code in audience_codes {
+        write_element_block("Audience", w, |w| {
+            // 01 ONIX audience codes
+            write_element_block("AudienceCodeType", w, |w| {
+                w.write(XmlEvent::Characters("01")).map_err(Into::into)
+            })?;
+            write_element_block("AudienceCodeValue", w, |w| {
+                w.write(XmlEvent::Characters(code)).map_err(Into::into)
+            })
+        })?;
+    }
+    for audience in audiences {
+        let (Some(qualifier), Some(precision)) = (
+            &audience.audience_range_qualifier,
+            &audience.audience_range_precision,
+        ) else {
+            continue;
+        };
+        let values = match precision {
+            // 01 Exact
+            AudienceRangePrecision::EXACT => vec![("01", &audience.audience_range_from)],
+            // 03 From, optionally followed by 04 To
+            AudienceRangePrecision::FROM => vec![
+                ("03", &audience.audience_range_from),
+                ("04", &audience.audience_range_to),
+            ],
+            // 04 To
+            AudienceRangePrecision::TO => vec![("04", &audience.audience_range_to)],
+            AudienceRangePrecision::Other(_) => unreachable!(),
+        };
+        write_element_block("AudienceRange", w, |w| {
+            write_element_block("AudienceRangeQualifier", w, |w| {
+                let code = match qualifier {
+                    // 11 US school grade range
+                    AudienceRangeQualifier::US_SCHOOL_GRADE => "11",
+                    // 12 UK school grade
+                    AudienceRangeQualifier::UK_SCHOOL_GRADE => "12",
+                    // 16 Interest age, months
+                    AudienceRangeQualifier::INTEREST_AGE_MONTHS => "16",
+                    // 17 Interest age, years
+                    AudienceRangeQualifier::INTEREST_AGE_YEARS => "17",
+                    // 18 Reading age, years
+                    AudienceRangeQualifier::READING_AGE_YEARS => "18",
+                    AudienceRangeQualifier::Other(_) => unreachable!(),
+                };
+                w.write(XmlEvent::Characters(code)).map_err(Into::into)
+            })?;
+            for (precision_code, value) in &values {
+                if let Some(value) = value {
+                    write_element_block("AudienceRangePrecision", w, |w| {
+                        w.write(XmlEvent::Characters(precision_code))
+                            .map_err(Into::into)
+                    })?;
+                    write_element_block("AudienceRangeValue", w, |w| {
+                        w.write(XmlEvent::Characters(value)).map_err(Into::into)
+                    })?;
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/// ONIX audience code (code list 28) of an audience
+fn onix_audience_code(audience_code: &AudienceCode) -> &'static str {
+    match audience_code {
+        // 01 General/trade
+        AudienceCode::GENERAL => "01",
+        // 02 Children/juvenile
+        AudienceCode::CHILDREN => "02",
+        // 03 Young adult
+        AudienceCode::YOUNG_ADULT => "03",
+        // 04 Primary and secondary/elementary and high school
+        AudienceCode::PRIMARY_AND_SECONDARY => "04",
+        // 05 College/higher education
+        AudienceCode::HIGHER_EDUCATION => "05",
+        // 06 Professional and scholarly
+        AudienceCode::PROFESSIONAL_AND_SCHOLARLY => "06",
+        // 07 ELT/ESL
+        AudienceCode::ELT => "07",
+        // 08 Adult education
+        AudienceCode::ADULT_EDUCATION => "08",
+        // 09 Second language teaching
+        AudienceCode::SECOND_LANGUAGE_TEACHING => "09",
+        AudienceCode::Other(_) => unreachable!(),
+    }
+}
+
+/// Write a publication's product form details, skipping `implied_detail`, which the caller
+/// has already written based on the publication type
+fn write_onix3_product_form_details<W: Write>(
+    details: &[WorkPublicationsProductFormDetails],
+    implied_detail: Option<&str>,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    for detail in details.iter().filter(|detail| {
+        detail.detail_type == ProductFormDetailType::FORM_DETAIL
+            && Some(detail.detail_code.as_str()) != implied_detail
+    }) {
+        write_element_block("ProductFormDetail", w, |w| {
+            w.write(XmlEvent::Characters(&detail.detail_code))
+                .map_err(Into::into)
+        })?;
+    }
+    Ok(())
+}
+
+/// Write a publication's product form features followed by its packaging, which must come
+/// after all other ProductFormFeature composites
+fn write_onix3_product_form_features<W: Write>(
+    details: &[WorkPublicationsProductFormDetails],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    for feature in details
+        .iter()
+        .filter(|detail| detail.detail_type == ProductFormDetailType::FORM_FEATURE)
+    {
+        write_element_block("ProductFormFeature", w, |w| {
+            write_element_block("ProductFormFeatureType", w, |w| {
+                w.write(XmlEvent::Characters(&feature.detail_code))
+                    .map_err(Into::into)
+            })?;
+            if let Some(value) = &feature.feature_value {
+                write_element_block("ProductFormFeatureValue", w, |w| {
+                    w.write(XmlEvent::Characters(value)).map_err(Into::into)
+                })?;
+            }
+            if let Some(description) = &feature.feature_description {
+                write_element_block("ProductFormFeatureDescription", w, |w| {
+                    w.write(XmlEvent::Characters(description))
+                        .map_err(Into::into)
+                })?;
+            }
+            Ok(())
+        })?;
+    }
+    if let Some(packaging) = details
+        .iter()
+        .find(|detail| detail.detail_type == ProductFormDetailType::PACKAGING)
+    {
+        write_element_block("ProductPackaging", w, |w| {
+            w.write(XmlEvent::Characters(&packaging.detail_code))
+                .map_err(Into::into)
+        })?;
+    }
+    Ok(())
+}
+
+/// Write a work's lifecycle dates as PublishingDate composites. The out-of-print date is
+/// skipped when the work has a withdrawn date, which the caller writes with the same role.
+fn write_onix3_work_dates<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
+    for work_date in &work.work_dates {
+        if work_date.date_type == WorkDateType::OUT_OF_PRINT && work.withdrawn_date.is_some() {
+            continue;
+        }
+        write_element_block("PublishingDate", w, |w| {
+            write_element_block("PublishingDateRole", w, |w| {
+                w.write(XmlEvent::Characters(onix_publishing_date_role(
+                    &work_date.date_type,
+                )))
+                .map_err(Into::into)
+            })?;
+            // dateformat="00" YYYYMMDD
+            write_full_element_block("Date", Some(vec![("dateformat", "00")]), w, |w| {
+                w.write(XmlEvent::Characters(
+                    &work_date.date.format("%Y%m%d").to_string(),
+                ))
+                .map_err(Into::into)
+            })
+        })?;
+    }
+    Ok(())
+}
+
+fn onix_publishing_date_role(date_type: &WorkDateType) -> &'static str {
+    match date_type {
+        // 02 Sales embargo date
+        WorkDateType::ON_SALE => "02",
+        // 09 Public announcement date
+        WorkDateType::PUBLIC_ANNOUNCEMENT => "09",
+        // 10 Trade announcement date
+        WorkDateType::TRADE_ANNOUNCEMENT => "10",
+        // 11 Date of first publication
+        WorkDateType::FIRST_PUBLICATION => "11",
+        // 12 Last reprint date
+        WorkDateType::LAST_REPRINT => "12",
+        // 13 Out-of-print / permanently withdrawn date
+        WorkDateType::OUT_OF_PRINT => "13",
+        // 16 Last reissue date
+        WorkDateType::LAST_REISSUE => "16",
+        WorkDateType::Other(_) => unreachable!(),
+    }
+}
+
+/// Subjects to export to platforms which require BIC or BISAC codes. Where a work has no
+/// subject in one of these schemes, the nearest equivalents of its Thema subjects are
+/// appended, so that works need only be classified in Thema.
+fn subjects_with_crosswalk(subjects: &[WorkSubjects]) -> Vec<WorkSubjects> {
+    let mut all_subjects = subjects.to_vec();
+    let mut ordinal = subjects
+        .iter()
+        .map(|s| s.subject_ordinal)
+        .max()
+        .unwrap_or_default();
+    for (subject_type, api_subject_type) in [
+        (SubjectType::BISAC, ApiSubjectType::Bisac),
+        (SubjectType::BIC, ApiSubjectType::Bic),
+    ] {
+        if subjects.iter().any(|s| s.subject_type == subject_type) {
+            continue;
+        }
+        let mut codes: Vec<&str> = vec![];
+        for subject in subjects
+            .iter()
+            .filter(|s| s.subject_type == SubjectType::THEMA)
+        {
+            if let Some(code) = crosswalk(
+                &ApiSubjectType::Thema,
+                &subject.subject_code,
+                &api_subject_type,
+            ) {
+                if !codes.contains(&code) {
+                    codes.push(code);
+                }
+            }
+        }
+        for code in codes {
+            ordinal += 1;
+            all_subjects.push(WorkSubjects {
+                subject_code: code.to_string(),
+                subject_type: subject_type.clone(),
+                subject_ordinal: ordinal,
+            });
+        }
+    }
+    all_subjects
+}
+
+/// Prices which have not yet expired, i.e. those in effect today plus any scheduled to start later.
+/// Future prices carry their own PriceDate so that recipients can apply them on the right day.
+fn current_and_future_prices(prices: &[WorkPublicationsPrices]) -> Vec<WorkPublicationsPrices> {
+    let today = Utc::now().date_naive();
+    prices
+        .iter()
+        .filter(|p| p.effective_until.is_none_or(|until| until >= today))
+        .cloned()
+        .collect()
+}
+
+/// Prices in effect today, for formats which can only carry a single price point per currency.
+fn current_prices(prices: &[WorkPublicationsPrices]) -> Vec<WorkPublicationsPrices> {
+    let today = Utc::now().date_naive();
+    prices
+        .iter()
+        .filter(|p| {
+            p.effective_from.is_none_or(|from| from <= today)
+                && p.effective_until.is_none_or(|until| until >= today)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Write an ONIX 3 `<Price>` composite, including its territory and validity dates
+fn write_onix3_price<W: Write>(
+    price: &WorkPublicationsPrices,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let unit_price = price.unit_price;
+    let formatted_price = format!("{unit_price:.2}");
+    write_element_block("Price", w, |w| {
+        write_element_block("PriceType", w, |w| {
+            let code = match (&price.price_type, price.tax_inclusive) {
+                // 01 RRP excluding tax
+                (PriceType::RRP, false) => "01",
+                // 02 RRP including tax
+                (PriceType::RRP, true) => "02",
+                // 41 Publishers retail price excluding tax
+                (PriceType::AGENCY, false) => "41",
+                // 42 Publishers retail price including tax
+                (PriceType::AGENCY, true) => "42",
+                (PriceType::Other(_), _) => unreachable!(),
+            };
+            w.write(XmlEvent::Characters(code)).map_err(Into::into)
+        })?;
+        write_element_block("PriceAmount", w, |w| {
+            w.write(XmlEvent::Characters(&formatted_price))
+                .map_err(Into::into)
+        })?;
+        write_element_block("CurrencyCode", w, |w| {
+            w.write(XmlEvent::Characters(&price.currency_code.to_string()))
+                .map_err(Into::into)
+        })?;
+        write_onix3_territory(price.countries_included.as_deref(), &[], w)?;
+        // 14 From date, 15 Until date
+        for (role, date) in [("14", price.effective_from), ("15", price.effective_until)] {
+            if let Some(date) = date {
+                write_element_block("PriceDate", w, |w| {
+                    write_element_block("PriceDateRole", w, |w| {
+                        w.write(XmlEvent::Characters(role)).map_err(Into::into)
+                    })?;
+                    // dateformat="00" YYYYMMDD
+                    write_full_element_block("Date", Some(vec![("dateformat", "00")]), w, |w| {
+                        w.write(XmlEvent::Characters(&date.format("%Y%m%d").to_string()))
+                            .map_err(Into::into)
+                    })
+                })?;
+            }
+        }
+        Ok(())
+    })
+}
+
 pub(crate) trait XmlSpecification {
     fn generate(&self, works: &[Work], doctype: Option<&str>) -> ThothResult<String> {
         let mut buffer = format!("{}{}", XML_DECLARATION, doctype.unwrap_or_default())
@@ -122,6 +605,7 @@ mod onix31_thoth;
 pub(crate) use onix31_thoth::Onix31Thoth;
 mod onix3_thoth;
 pub(crate) use onix3_thoth::Onix3Thoth;
+mod onix3_profile;
 mod onix3_project_muse;
 pub(crate) use onix3_project_muse::Onix3ProjectMuse;
 mod onix3_oapen;
@@ -8,7 +8,9 @@ use thoth_client::{
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
+use super::{
+    current_prices, write_element_block, write_onix21_sales_rights, XmlElement, XmlSpecification,
+};
 use crate::xml::{write_full_element_block, XmlElementBlock};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -447,15 +449,13 @@ impl XmlElementBlock<Onix21EbscoHost> for Work {
                         .map_err(|e| e.into())
                 })?;
             }
-            write_element_block("SalesRights", w, |w| {
-                // 02 For sale with non-exclusive rights in the specified countries or territories
-                write_element_block("SalesRightsType", w, |w| {
-                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                })?;
-                write_element_block("RightsTerritory", w, |w| {
-                    w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                })
-            })?;
+            write_onix21_sales_rights(
+                pdf_publication
+                    .or(epub_publication)
+                    .map(|p| p.sales_rights.as_slice())
+                    .unwrap_or_default(),
+                w,
+            )?;
             if !isbns.is_empty() {
                 for (publication_type, isbn) in &isbns {
                     let relation_code = match publication_type {
@@ -513,7 +513,9 @@ impl XmlElementBlock<Onix21EbscoHost> for Work {
                     true => pdf_publication,
                     false => epub_publication,
                 };
-                let prices = publication.map(|p| p.prices.clone()).unwrap_or_default();
+                let prices = publication
+                    .map(|p| current_prices(&p.prices))
+                    .unwrap_or_default();
                 if is_open_access || prices.is_empty() {
                     // EBSCO Host require the price point for unpriced/Open Access titles to be listed as "0.01 USD".
                     write_element_block("Price", w, |w| {
@@ -632,22 +634,7 @@ impl XmlElement<Onix21EbscoHost> for ContributionType {
     const ELEMENT: &'static str = "ContributorRole";
 
     fn value(&self) -> &'static str {
-        match self {
-            ContributionType::AUTHOR => "A01",
-            ContributionType::EDITOR => "B01",
-            ContributionType::TRANSLATOR => "B06",
-            ContributionType::PHOTOGRAPHER => "A13",
-            ContributionType::ILLUSTRATOR => "A12",
-            ContributionType::MUSIC_EDITOR => "B25",
-            ContributionType::FOREWORD_BY => "A23",
-            ContributionType::INTRODUCTION_BY => "A24",
-            ContributionType::AFTERWORD_BY => "A19",
-            ContributionType::PREFACE_BY => "A15",
-            ContributionType::SOFTWARE_BY => "A30",
-            ContributionType::CONTRIBUTIONS_BY => "A32",
-            ContributionType::INDEXER => "A34",
-            ContributionType::RESEARCH_BY | ContributionType::Other(_) => unreachable!(),
-        }
+        self.role_mapping().onix_role_code
     }
 }
 
@@ -759,10 +746,11 @@ mod tests {
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_client::{
-        AccessibilityException, ContributionType, CurrencyCode, LanguageCode, LanguageRelation,
-        LocationPlatform, PublicationType, WorkContributionsContributor, WorkImprint,
-        WorkImprintPublisher, WorkImprintPublisherContacts, WorkIssuesSeries,
-        WorkPublicationsLocations, WorkPublicationsPrices, WorkStatus, WorkType,
+        AccessibilityException, ContributionType, CountryCode, CurrencyCode, LanguageCode,
+        LanguageRelation, LocationPlatform, PriceType, PublicationType, SalesRightsType,
+        WorkContributionsContributor, WorkImprint, WorkImprintPublisher,
+        WorkImprintPublisherContacts, WorkIssuesSeries, WorkPublicationsLocations,
+        WorkPublicationsPrices, WorkPublicationsSalesRights, WorkStatus, WorkType,
     };
     use uuid::Uuid;
 
@@ -800,9 +788,12 @@ mod tests {
             main_contribution: true,
             biographies: vec![],
             contribution_ordinal: 1,
+            name_variants: vec![],
+            credit_roles: vec![],
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: None,
+                name_variants: vec![],
             },
             affiliations: vec![],
         };
@@ -1085,6 +1076,11 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -1109,10 +1105,20 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 5.99,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 7.99,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                     ],
                     locations: vec![WorkPublicationsLocations {
@@ -1121,6 +1127,11 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-AAAA-000000000001").unwrap(),
@@ -1145,20 +1156,47 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 5.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 4.95,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 7.99,
+                            price_type: PriceType::RRP,
+                            tax_inclusive: true,
+                            countries_included: None,
+                            effective_from: None,
+                            effective_until: None,
                         },
                     ],
                     locations: vec![],
+                    sales_rights: vec![],
+                    product_form_details: vec![],
+                    license: None,
+                    access_status: None,
+                    embargo_end_date: None,
                 },
             ],
             subjects: vec![],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![],
             relations: vec![],
             references: vec![],
@@ -1334,6 +1372,53 @@ mod tests {
         assert!(!output.contains(r#"  <ProductFormFeature>"#));
         assert!(!output.contains(r#"    <ProductFormFeatureType>09</ProductFormFeatureType>"#));
 
+        // Sales rights are taken from the PDF publication
+        test_work.publications[1].sales_rights = vec![
+            WorkPublicationsSalesRights {
+                sales_rights_type: SalesRightsType::FOR_SALE_EXCLUSIVE,
+                countries_included: Some(vec![CountryCode::GBR, CountryCode::IRL]),
+                countries_excluded: vec![],
+            },
+            WorkPublicationsSalesRights {
+                sales_rights_type: SalesRightsType::FOR_SALE_NON_EXCLUSIVE,
+                countries_included: None,
+                countries_excluded: vec![CountryCode::USA],
+            },
+        ];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+  <SalesRights>
+    <SalesRightsType>01</SalesRightsType>
+    <RightsCountry>GB IE</RightsCountry>
+  </SalesRights>
+  <SalesRights>
+    <SalesRightsType>02</SalesRightsType>
+    <RightsTerritory>ROW</RightsTerritory>
+  </SalesRights>
+  <SalesRights>
+    <SalesRightsType>03</SalesRightsType>
+    <RightsCountry>US</RightsCountry>
+  </SalesRights>"#
+        ));
+        assert!(!output.contains(r#"    <RightsTerritory>WORLD</RightsTerritory>"#));
+        // Countries excluded from a not-for-sale right need no further block
+        test_work.publications[1].sales_rights = vec![WorkPublicationsSalesRights {
+            sales_rights_type: SalesRightsType::NOT_FOR_SALE,
+            countries_included: None,
+            countries_excluded: vec![CountryCode::USA],
+        }];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+  <SalesRights>
+    <SalesRightsType>03</SalesRightsType>
+    <RightsTerritory>ROW</RightsTerritory>
+  </SalesRights>"#
+        ));
+        assert!(!output.contains(r#"    <RightsCountry>US</RightsCountry>"#));
+        test_work.publications[1].sales_rights.clear();
+
         // Add withdrawn date
         test_work.withdrawn_date = chrono::NaiveDate::from_ymd_opt(2020, 12, 31);
         let output = generate_test_output(true, &test_work);
@@ -1431,6 +1516,11 @@ mod tests {
         test_work.publications[0].prices = vec![WorkPublicationsPrices {
             currency_code: CurrencyCode::AUD,
             unit_price: 10.00,
+            price_type: PriceType::RRP,
+            tax_inclusive: true,
+            countries_included: None,
+            effective_from: None,
+            effective_until: None,
         }];
         let output = generate_test_output(true, &test_work);
         assert!(!output.contains(r#"      <PriceAmount>5.99</PriceAmount>"#));
@@ -1,1944 +1,96 @@
-use cc_license::License;
-use chrono::Utc;
-use std::io::Write;
-use thoth_api::model::language::LanguageCode as ApiLanguageCode;
-use thoth_api::model::locale::LocaleCode as ApiLocaleCode;
-use thoth_client::{
-    AbstractType, AccessibilityException, AccessibilityStandard, ContactType, ContributionType,
-    LanguageRelation, LocationPlatform, PublicationType, RelationType, SubjectType, Work,
-    WorkContributions, WorkFundings, WorkIssues, WorkLanguages, WorkPublicationsLocations,
-    WorkReferences, WorkRelations, WorkRelationsRelatedWork, WorkRelationsRelatedWorkContributions,
-    WorkRelationsRelatedWorkLanguages, WorkStatus, WorkType,
-};
-use xml::writer::{EventWriter, XmlEvent};
+use thoth_client::SubjectType;
 
-use super::{write_element_block, TitleData, XmlElement, XmlSpecification};
-use crate::xml::{write_full_element_block, XmlElementBlock, ONIX31_NS};
-use thoth_errors::{ThothError, ThothResult};
+use super::onix3_profile::{
+    onix3_profile, onix_contributor_role, onix_subject_scheme, Availability, Chapters, Collateral,
+    Collections, ContributorDetail, Contributors, Descriptions, Descriptive, Edition, EpubLicense,
+    Funders, Header, Identifiers, Onix3Profile, OpenAccessStatement, PriceRule, Products,
+    Publishing, RelatedMaterial, Subjects, Suppliers, Supply, Titles,
+};
+use super::ONIX31_NS;
 
 #[derive(Copy, Clone)]
 pub struct Onix31Thoth {}
 
-struct Measure {
-    measure_type: &'static str,
-    measurement: f64,
-    measure_unit_code: &'static str,
-}
-
-const ONIX_ERROR: &str = "onix_3.1::thoth";
-
 // Based on ONIX for Books Release 3.1.2 Specification accessed January 2025
 // Download link: https://www.editeur.org/files/ONIX%203/ONIX_for_Books_Release_3-1_pdf_docs+codes_Issue_67.zip
 // Retrieved from: https://www.editeur.org/93/Release-3.0-Downloads/#Specifications
-impl XmlSpecification for Onix31Thoth {
-    fn handle_event<W: Write>(w: &mut EventWriter<W>, works: &[Work]) -> ThothResult<()> {
-        write_full_element_block("ONIXMessage", Some(ONIX31_NS.to_vec()), w, |w| {
-            write_element_block("Header", w, |w| {
-                write_element_block("Sender", w, |w| {
-                    write_element_block("SenderName", w, |w| {
-                        w.write(XmlEvent::Characters("Thoth")).map_err(Into::into)
-                    })?;
-                    write_element_block("EmailAddress", w, |w| {
-                        w.write(XmlEvent::Characters("distribution@thoth.pub"))
-                            .map_err(Into::into)
-                    })
-                })?;
-                write_element_block("SentDateTime", w, |w| {
-                    w.write(XmlEvent::Characters(
-                        &Utc::now().format("%Y%m%dT%H%M%S").to_string(),
-                    ))
-                    .map_err(Into::into)
-                })
-            })?;
-
-            match works {
-                [] => Err(ThothError::IncompleteMetadataRecord(
-                    ONIX_ERROR.to_string(),
-                    "Not enough data".to_string(),
-                )),
-                [work] => XmlElementBlock::<Onix31Thoth>::xml_element(work, w),
-                _ => {
-                    for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
-                        // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
-                            XmlElementBlock::<Onix31Thoth>::xml_element(work, w).ok();
-                        }
-                    }
-                    Ok(())
-                }
-            }
-        })
-    }
-}
-
-impl XmlElementBlock<Onix31Thoth> for Work {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        // Format is one record per Publication
-        if self.publications.is_empty() {
-            return Err(ThothError::IncompleteMetadataRecord(
-                ONIX_ERROR.to_string(),
-                "No publications supplied".to_string(),
-            ));
-        }
-        let work_id = format!("urn:uuid:{}", self.work_id);
-        let isbns: Vec<String> = self
-            .publications
-            .iter()
-            .filter_map(|p| p.isbn.clone())
-            .map(|i| i.to_hyphenless_string())
-            .collect();
-        for publication in &self.publications {
-            let publication_id = format!("urn:uuid:{}", publication.publication_id);
-            let current_isbn = &publication.isbn.as_ref().map(|p| p.to_hyphenless_string());
-            write_element_block("Product", w, |w| {
-                write_element_block("RecordReference", w, |w| {
-                    // Note that most existing Thoth ONIX outputs use the Work ID, not Publication ID,
-                    // as they output one record per Work rather than one record per Publication
-                    w.write(XmlEvent::Characters(&publication_id))
-                        .map_err(Into::into)
-                })?;
-                // 03 Notification confirmed on publication
-                write_element_block("NotificationType", w, |w| {
-                    w.write(XmlEvent::Characters("03")).map_err(Into::into)
-                })?;
-                // 01 Publisher
-                write_element_block("RecordSourceType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                })?;
-                write_element_block("ProductIdentifier", w, |w| {
-                    // 01 Proprietary
-                    write_element_block("ProductIDType", w, |w| {
-                        w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("thoth-work-id"))
-                            .map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&work_id)).map_err(Into::into)
-                    })
-                })?;
-                write_element_block("ProductIdentifier", w, |w| {
-                    // 01 Proprietary
-                    write_element_block("ProductIDType", w, |w| {
-                        w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("thoth-publication-id"))
-                            .map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&publication_id))
-                            .map_err(Into::into)
-                    })
-                })?;
-                if let Some(isbn) = current_isbn {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        // 15 ISBN-13
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("15")).map_err(Into::into)
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(isbn)).map_err(Into::into)
-                        })
-                    })?;
-                    write_element_block("ProductIdentifier", w, |w| {
-                        // 03 GTIN-13
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("03")).map_err(Into::into)
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(isbn)).map_err(Into::into)
-                        })
-                    })?;
-                }
-                if let Some(doi) = &self.doi {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("06")).map_err(Into::into)
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(&doi.to_string()))
-                                .map_err(Into::into)
-                        })
-                    })?;
-                }
-                if let Some(lccn) = &self.lccn {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("13")).map_err(Into::into)
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(&lccn.to_string()))
-                                .map_err(Into::into)
-                        })
-                    })?;
-                }
-                if let Some(oclc) = &self.oclc {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("23")).map_err(Into::into)
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(&oclc.to_string()))
-                                .map_err(Into::into)
-                        })
-                    })?;
-                }
-                if let Some(reference) = &self.reference {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                        })?;
-                        write_element_block("IDTypeName", w, |w| {
-                            w.write(XmlEvent::Characters("internal-reference"))
-                                .map_err(Into::into)
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(&reference.to_string()))
-                                .map_err(Into::into)
-                        })
-                    })?;
-                }
-                write_element_block("DescriptiveDetail", w, |w| {
-                    // 00 Single-component retail product
-                    write_element_block("ProductComposition", w, |w| {
-                        w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                    })?;
-                    let (form, form_detail) = get_product_form_codes(&publication.publication_type);
-                    write_element_block("ProductForm", w, |w| {
-                        w.write(XmlEvent::Characters(form)).map_err(Into::into)
-                    })?;
-                    if let Some(code) = form_detail {
-                        write_element_block("ProductFormDetail", w, |w| {
-                            w.write(XmlEvent::Characters(code)).map_err(Into::into)
-                        })?;
-                    }
-                    if let Some(accessibility_statement) =
-                        &self.imprint.publisher.accessibility_statement
-                    {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            // 00 Accessibility summary
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureDescription", w, |w| {
-                                w.write(XmlEvent::Characters(&accessibility_statement.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    let mut accessibility_codes = vec![];
-                    if let Some(standard) = &publication.accessibility_standard {
-                        let standard_codes = match standard {
-                            AccessibilityStandard::WCAG21AA => vec!["81", "85"],
-                            AccessibilityStandard::WCAG21AAA => vec!["81", "86"],
-                            AccessibilityStandard::WCAG22AA => vec!["82", "85"],
-                            AccessibilityStandard::WCAG22AAA => vec!["82", "86"],
-                            _ => unreachable!(),
-                        };
-                        accessibility_codes.extend(standard_codes);
-                    }
-                    if let Some(additional_standard) =
-                        &publication.accessibility_additional_standard
-                    {
-                        let additional_standard_codes = match additional_standard {
-                            AccessibilityStandard::EPUB_A11Y10AA => vec!["03"],
-                            AccessibilityStandard::EPUB_A11Y10AAA => vec!["03", "86"],
-                            AccessibilityStandard::EPUB_A11Y11AA => vec!["04", "85"],
-                            AccessibilityStandard::EPUB_A11Y11AAA => vec!["04", "86"],
-                            AccessibilityStandard::PDF_UA1 => vec!["05"],
-                            AccessibilityStandard::PDF_UA2 => vec!["06"],
-                            _ => unreachable!(),
-                        };
-                        accessibility_codes.extend(additional_standard_codes);
-                    }
-                    if let Some(exception) = &publication.accessibility_exception {
-                        let exception_code = match exception {
-                            AccessibilityException::MICRO_ENTERPRISES => "75",
-                            AccessibilityException::DISPROPORTIONATE_BURDEN => "76",
-                            AccessibilityException::FUNDAMENTAL_ALTERATION => "77",
-                            AccessibilityException::Other(_) => unreachable!(),
-                        };
-                        accessibility_codes.push(exception_code);
-                    }
-                    for code in accessibility_codes {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters(code)).map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    if let Some(report_url) = &publication.accessibility_report_url {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            // 96 Publisher’s web page for detailed accessibility information
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters("96")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureDescription", w, |w| {
-                                w.write(XmlEvent::Characters(&report_url.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    for contact in &self.imprint.publisher.contacts {
-                        if contact.contact_type == ContactType::ACCESSIBILITY {
-                            write_element_block("ProductFormFeature", w, |w| {
-                                // 09 E-publication accessibility detail
-                                write_element_block("ProductFormFeatureType", w, |w| {
-                                    w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                                })?;
-                                // 99 Publisher contact for further accessibility information
-                                write_element_block("ProductFormFeatureValue", w, |w| {
-                                    w.write(XmlEvent::Characters("99")).map_err(Into::into)
-                                })?;
-                                write_element_block("ProductFormFeatureDescription", w, |w| {
-                                    w.write(XmlEvent::Characters(&contact.email))
-                                        .map_err(Into::into)
-                                })
-                            })?;
-                        }
-                    }
-                    // 10 Text (eye-readable)
-                    write_element_block("PrimaryContentType", w, |w| {
-                        w.write(XmlEvent::Characters("10")).map_err(Into::into)
-                    })?;
-                    for &(measurement_opt, measure_type, measure_unit_code) in &[
-                        // 01 height
-                        (publication.height_mm, "01", "mm"),
-                        (publication.height_cm, "01", "cm"),
-                        (publication.height_in, "01", "in"),
-                        // 02 width
-                        (publication.width_mm, "02", "mm"),
-                        (publication.width_cm, "02", "cm"),
-                        (publication.width_in, "02", "in"),
-                        // 03 thickness
-                        (publication.depth_mm, "03", "mm"),
-                        (publication.depth_cm, "03", "cm"),
-                        (publication.depth_in, "03", "in"),
-                        // 08 unit weight
-                        (publication.weight_g, "08", "gr"),
-                        (publication.weight_oz, "08", "oz"),
-                    ] {
-                        if let Some(measurement) = measurement_opt {
-                            XmlElementBlock::<Onix31Thoth>::xml_element(
-                                &Measure {
-                                    measure_type,
-                                    measurement,
-                                    measure_unit_code,
-                                },
-                                w,
-                            )
-                            .ok();
-                        }
-                    }
-                    if let Some(license_url) = &self.license {
-                        write_license(license_url.to_string(), w)?;
-                    }
-                    for issue in &self.issues {
-                        XmlElementBlock::<Onix31Thoth>::xml_element(issue, w).ok();
-                    }
-                    write_title(&self.titles, w)?;
-                    for contribution in &self.contributions {
-                        XmlElementBlock::<Onix31Thoth>::xml_element(contribution, w).ok();
-                    }
-                    if let Some(edition) = &self.edition {
-                        // "Normally sent only for the second and subsequent editions"
-                        if edition > &1 {
-                            write_element_block("EditionNumber", w, |w| {
-                                w.write(XmlEvent::Characters(&edition.to_string()))
-                                    .map_err(Into::into)
-                            })?;
-                        }
-                    }
-                    for language in &self.languages {
-                        XmlElementBlock::<Onix31Thoth>::xml_element(language, w).ok();
-                    }
-                    if let Some(page_count) = self.page_count {
-                        write_element_block("Extent", w, |w| {
-                            // 00 Main content
-                            write_element_block("ExtentType", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                            })?;
-                            write_element_block("ExtentValue", w, |w| {
-                                w.write(XmlEvent::Characters(&page_count.to_string()))
-                                    .map_err(Into::into)
-                            })?;
-                            // 03 Pages
-                            write_element_block("ExtentUnit", w, |w| {
-                                w.write(XmlEvent::Characters("03")).map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    if let Some(bibliography_note) = &self.bibliography_note {
-                        // "This data element carries text stating the number and type of
-                        // illustrations. The text may also include other content items,
-                        // eg maps, bibliography, tables, index etc."
-                        write_element_block("IllustrationsNote", w, |w| {
-                            w.write(XmlEvent::Characters(&bibliography_note.to_string()))
-                                .map_err(Into::into)
-                        })?;
-                    }
-                    if let Some(image_count) = self.image_count {
-                        write_element_block("AncillaryContent", w, |w| {
-                            // 09 Illustrations, unspecified
-                            // (note that there are separate codes for e.g. "halftones" - we don't distinguish)
-                            write_element_block("AncillaryContentType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            write_element_block("Number", w, |w| {
-                                w.write(XmlEvent::Characters(&image_count.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    if let Some(table_count) = self.table_count {
-                        write_element_block("AncillaryContent", w, |w| {
-                            // 11 Tables, unspecified
-                            write_element_block("AncillaryContentType", w, |w| {
-                                w.write(XmlEvent::Characters("11")).map_err(Into::into)
-                            })?;
-                            write_element_block("Number", w, |w| {
-                                w.write(XmlEvent::Characters(&table_count.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    if let Some(audio_count) = self.audio_count {
-                        write_element_block("AncillaryContent", w, |w| {
-                            // 19 Recorded music items
-                            // (closest equivalent - audio might not always be music)
-                            write_element_block("AncillaryContentType", w, |w| {
-                                w.write(XmlEvent::Characters("19")).map_err(Into::into)
-                            })?;
-                            write_element_block("Number", w, |w| {
-                                w.write(XmlEvent::Characters(&audio_count.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    if let Some(video_count) = self.video_count {
-                        write_element_block("AncillaryContent", w, |w| {
-                            // 00 Unspecified, see description
-                            // (there is no code for "videos")
-                            write_element_block("AncillaryContentType", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                            })?;
-                            write_element_block("AncillaryContentDescription", w, |w| {
-                                w.write(XmlEvent::Characters("Videos")).map_err(Into::into)
-                            })?;
-                            write_element_block("Number", w, |w| {
-                                w.write(XmlEvent::Characters(&video_count.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    let mut main_subject_found = vec![];
-                    let mut keywords = vec![];
-                    for subject in &self.subjects {
-                        // Best practice is to include only a single subject tag
-                        // for keywords, listing all of them separated by semicolons
-                        if subject.subject_type == SubjectType::KEYWORD {
-                            keywords.push(subject.subject_code.clone());
-                            continue;
-                        }
-                        // One subject within every subject type can/should be marked as Main
-                        // Use first one found with ordinal 1 (there may be multiple)
-                        let is_main_subject = subject.subject_ordinal == 1
-                            && !main_subject_found.contains(&subject.subject_type);
-                        write_element_block("Subject", w, |w| {
-                            if is_main_subject {
-                                // MainSubject is an empty element
-                                write_element_block("MainSubject", w, |_w| Ok(()))?;
-                            }
-                            XmlElement::<Onix31Thoth>::xml_element(&subject.subject_type, w)?;
-                            match subject.subject_type {
-                                SubjectType::CUSTOM => {
-                                    write_element_block("SubjectHeadingText", w, |w| {
-                                        w.write(XmlEvent::Characters(&subject.subject_code))
-                                            .map_err(Into::into)
-                                    })
-                                }
-                                SubjectType::KEYWORD => unreachable!(),
-                                _ => write_element_block("SubjectCode", w, |w| {
-                                    w.write(XmlEvent::Characters(&subject.subject_code))
-                                        .map_err(Into::into)
-                                }),
-                            }
-                        })?;
-                        if is_main_subject {
-                            main_subject_found.push(subject.subject_type.clone());
-                        }
-                    }
-                    if !keywords.is_empty() {
-                        write_element_block("Subject", w, |w| {
-                            XmlElement::<Onix31Thoth>::xml_element(&SubjectType::KEYWORD, w)?;
-                            write_element_block("SubjectHeadingText", w, |w| {
-                                w.write(XmlEvent::Characters(&keywords.join("; ")))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    write_element_block("Audience", w, |w| {
-                        // 01 ONIX audience codes
-                        write_element_block("AudienceCodeType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                        })?;
-                        // 06 Professional and scholarly
-                        write_element_block("AudienceCodeValue", w, |w| {
-                            w.write(XmlEvent::Characters("06")).map_err(Into::into)
-                        })
-                    })
-                })?;
-                if self
-                    .abstracts
-                    .iter()
-                    .any(|a| a.abstract_type == AbstractType::SHORT)
-                    || self
-                        .abstracts
-                        .iter()
-                        .any(|a| a.abstract_type == AbstractType::LONG)
-                    || self.toc.is_some()
-                    || self.general_note.is_some()
-                    || self.cover_url.is_some()
-                    || self.license.is_some()
-                {
-                    write_element_block("CollateralDetail", w, |w| {
-                        write_work_short_abstract(self, w)?;
-                        write_work_long_abstract(self, w)?;
-                        if let Some(toc) = &self.toc {
-                            write_element_block("TextContent", w, |w| {
-                                // 04 Table of contents
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("04")).map_err(Into::into)
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                                })?;
-                                write_element_block("Text", w, |w| {
-                                    w.write(XmlEvent::Characters(toc)).map_err(Into::into)
-                                })
-                            })?;
-                        }
-                        write_work_open_access_statement(self, w)?;
-                        write_work_general_note(self, w)?;
-                        if let Some(cover_url) = &self.cover_url {
-                            write_element_block("SupportingResource", w, |w| {
-                                // 01 Front cover
-                                write_element_block("ResourceContentType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                                })?;
-                                // 03 Image
-                                write_element_block("ResourceMode", w, |w| {
-                                    w.write(XmlEvent::Characters("03")).map_err(Into::into)
-                                })?;
-                                if let Some(cover_caption) = &self.cover_caption {
-                                    write_element_block("ResourceFeature", w, |w| {
-                                        // 02 Caption
-                                        write_element_block("ResourceFeatureType", w, |w| {
-                                            w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                                        })?;
-                                        write_element_block("FeatureNote", w, |w| {
-                                            w.write(XmlEvent::Characters(cover_caption))
-                                                .map_err(Into::into)
-                                        })
-                                    })?;
-                                }
-                                write_element_block("ResourceVersion", w, |w| {
-                                    // 02 Downloadable file
-                                    write_element_block("ResourceForm", w, |w| {
-                                        w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                                    })?;
-                                    write_element_block("ResourceLink", w, |w| {
-                                        w.write(XmlEvent::Characters(cover_url)).map_err(Into::into)
-                                    })
-                                })
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                }
-                let chapter_relations: Vec<WorkRelations> = self
-                    .relations
-                    .clone()
-                    .into_iter()
-                    .filter(|r| {
-                        r.relation_type == RelationType::HAS_CHILD && r.related_work.doi.is_some()
-                    })
-                    .collect();
-                if !chapter_relations.is_empty() {
-                    write_element_block("ContentDetail", w, |w| {
-                        for relation in &chapter_relations {
-                            let chapter = &relation.related_work;
-                            write_element_block("ContentItem", w, |w| {
-                                write_element_block("LevelSequenceNumber", w, |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &relation.relation_ordinal.to_string(),
-                                    ))
-                                    .map_err(Into::into)
-                                })?;
-                                write_element_block("TextItem", w, |w| {
-                                    // 03 Body matter
-                                    write_element_block("TextItemType", w, |w| {
-                                        w.write(XmlEvent::Characters("03")).map_err(Into::into)
-                                    })?;
-                                    write_element_block("TextItemIdentifier", w, |w| {
-                                        // 06 DOI
-                                        write_element_block("TextItemIDType", w, |w| {
-                                            w.write(XmlEvent::Characters("06")).map_err(Into::into)
-                                        })?;
-                                        write_element_block("IDValue", w, |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &chapter.doi.as_ref().unwrap().to_string(),
-                                            ))
-                                            .map_err(Into::into)
-                                        })
-                                    })
-                                })?;
-                                if let Some(first_page) = &chapter.first_page {
-                                    write_element_block("PageRun", w, |w| {
-                                        write_element_block("FirstPageNumber", w, |w| {
-                                            w.write(XmlEvent::Characters(first_page))
-                                                .map_err(Into::into)
-                                        })?;
-                                        if let Some(last_page) = &chapter.last_page {
-                                            write_element_block("LastPageNumber", w, |w| {
-                                                w.write(XmlEvent::Characters(last_page))
-                                                    .map_err(Into::into)
-                                            })?;
-                                        }
-                                        Ok(())
-                                    })?;
-                                }
-                                if let Some(page_count) = &chapter.page_count {
-                                    write_element_block("NumberOfPages", w, |w| {
-                                        w.write(XmlEvent::Characters(&page_count.to_string()))
-                                            .map_err(Into::into)
-                                    })?;
-                                }
-                                if let Some(license_url) = &chapter.license {
-                                    write_license(license_url.to_string(), w)?;
-                                }
-                                write_element_block("ComponentTypeName", w, |w| {
-                                    w.write(XmlEvent::Characters("Chapter")).map_err(Into::into)
-                                })?;
-                                write_title(&chapter.titles, w)?;
-                                for contribution in &chapter.contributions {
-                                    XmlElementBlock::<Onix31Thoth>::xml_element(contribution, w)
-                                        .ok();
-                                }
-                                for language in &chapter.languages {
-                                    XmlElementBlock::<Onix31Thoth>::xml_element(language, w).ok();
-                                }
-                                if chapter
-                                    .abstracts
-                                    .iter()
-                                    .any(|a| a.abstract_type == AbstractType::SHORT)
-                                {
-                                    write_chapter_short_abstract(chapter, w)?;
-                                }
-                                if chapter
-                                    .abstracts
-                                    .iter()
-                                    .any(|a| a.abstract_type == AbstractType::LONG)
-                                {
-                                    write_chapter_long_abstract(chapter, w)?;
-                                }
-                                if chapter.license.is_some() {
-                                    write_chapter_open_access_statement(chapter, w)?;
-                                }
-                                if chapter.general_note.is_some() {
-                                    write_chapter_general_note(chapter, w)?;
-                                }
-                                write_chapter_copyright(chapter, w)?;
-                                for reference in &chapter.references {
-                                    XmlElementBlock::<Onix31Thoth>::xml_element(reference, w).ok();
-                                }
-                                Ok(())
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                }
-                write_element_block("PublishingDetail", w, |w| {
-                    write_element_block("Imprint", w, |w| {
-                        if let Some(url) = &self.imprint.imprint_url {
-                            write_element_block("ImprintIdentifier", w, |w| {
-                                // 01 Proprietary
-                                write_element_block("ImprintIDType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                                })?;
-                                write_element_block("IDTypeName", w, |w| {
-                                    w.write(XmlEvent::Characters("URL")).map_err(Into::into)
-                                })?;
-                                write_element_block("IDValue", w, |w| {
-                                    w.write(XmlEvent::Characters(url)).map_err(Into::into)
-                                })
-                            })?;
-                        }
-                        write_element_block("ImprintName", w, |w| {
-                            w.write(XmlEvent::Characters(&self.imprint.imprint_name))
-                                .map_err(Into::into)
-                        })
-                    })?;
-                    write_element_block("Publisher", w, |w| {
-                        // 01 Publisher
-                        write_element_block("PublishingRole", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                        })?;
-                        write_element_block("PublisherName", w, |w| {
-                            w.write(XmlEvent::Characters(&self.imprint.publisher.publisher_name))
-                                .map_err(Into::into)
-                        })?;
-                        if let Some(url) = &self.imprint.publisher.publisher_url {
-                            write_element_block("Website", w, |w| {
-                                // 01 Publisher’s corporate website
-                                write_element_block("WebsiteRole", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                                })?;
-                                write_element_block("WebsiteDescription", w, |w| {
-                                    w.write(XmlEvent::Characters("Publisher's website: home page"))
-                                        .map_err(Into::into)
-                                })?;
-                                write_element_block("WebsiteLink", w, |w| {
-                                    w.write(XmlEvent::Characters(url)).map_err(Into::into)
-                                })
-                            })?;
-                        }
-                        if let Some(url) = &self.landing_page {
-                            write_element_block("Website", w, |w| {
-                                // 02 Publisher's website for a specified work
-                                write_element_block("WebsiteRole", w, |w| {
-                                    w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                                })?;
-                                write_element_block("WebsiteDescription", w, |w| {
-                                    w.write(XmlEvent::Characters(
-                                        "Publisher's website: webpage for this title",
-                                    ))
-                                    .map_err(Into::into)
-                                })?;
-                                write_element_block("WebsiteLink", w, |w| {
-                                    w.write(XmlEvent::Characters(url)).map_err(Into::into)
-                                })
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                    for funding in &self.fundings {
-                        XmlElementBlock::<Onix31Thoth>::xml_element(funding, w).ok();
-                    }
-                    if let Some(place) = &self.place {
-                        write_element_block("CityOfPublication", w, |w| {
-                            w.write(XmlEvent::Characters(place)).map_err(Into::into)
-                        })?;
-                    }
-                    XmlElement::<Onix31Thoth>::xml_element(&self.work_status, w)?;
-                    if let Some(date) = &self.publication_date {
-                        write_element_block("PublishingDate", w, |w| {
-                            write_element_block("PublishingDateRole", w, |w| {
-                                // 01 Publication date
-                                w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                            })?;
-                            // dateformat="00" YYYYMMDD
-                            write_full_element_block(
-                                "Date",
-                                Some(vec![("dateformat", "00")]),
-                                w,
-                                |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &date.format("%Y%m%d").to_string(),
-                                    ))
-                                    .map_err(Into::into)
-                                },
-                            )
-                        })?;
-                    }
-                    if let Some(date) = &self.withdrawn_date {
-                        write_element_block("PublishingDate", w, |w| {
-                            write_element_block("PublishingDateRole", w, |w| {
-                                // 13 Out-of-print / permanently withdrawn date
-                                w.write(XmlEvent::Characters("13")).map_err(Into::into)
-                            })?;
-                            // dateformat="00" YYYYMMDD
-                            write_full_element_block(
-                                "Date",
-                                Some(vec![("dateformat", "00")]),
-                                w,
-                                |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &date.format("%Y%m%d").to_string(),
-                                    ))
-                                    .map_err(Into::into)
-                                },
-                            )
-                        })?;
-                    }
-                    write_work_copyright(self, w)?;
-                    write_element_block("SalesRights", w, |w| {
-                        // 02 For sale with non-exclusive rights in the specified countries or territories
-                        write_element_block("SalesRightsType", w, |w| {
-                            w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                        })?;
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(Into::into)
-                            })
-                        })
-                    })
-                })?;
-                let non_child_relations: Vec<WorkRelations> = self
-                    .relations
-                    .iter()
-                    .filter(|r| {
-                        r.relation_type != RelationType::HAS_CHILD
-                            && r.relation_type != RelationType::IS_CHILD_OF
-                            && r.related_work.doi.is_some()
-                    })
-                    .cloned()
-                    .collect();
-                // Only output ISBNs in RelatedMaterial if at least one is present which
-                // doesn't relate to the current publication
-                if (!isbns.is_empty() && !isbns.eq(&vec![current_isbn.clone().unwrap_or_default()]))
-                    || !non_child_relations.is_empty()
-                    || !self.references.is_empty()
-                {
-                    write_element_block("RelatedMaterial", w, |w| {
-                        // RelatedWorks should be listed before RelatedProducts
-                        for relation in &non_child_relations {
-                            if relation.relation_type == RelationType::HAS_TRANSLATION
-                                || relation.relation_type == RelationType::IS_TRANSLATION_OF
-                            {
-                                XmlElementBlock::<Onix31Thoth>::xml_element(relation, w).ok();
-                            }
-                        }
-                        for relation in &non_child_relations {
-                            if relation.relation_type != RelationType::HAS_TRANSLATION
-                                && relation.relation_type != RelationType::IS_TRANSLATION_OF
-                            {
-                                XmlElementBlock::<Onix31Thoth>::xml_element(relation, w).ok();
-                            }
-                        }
-                        for isbn in &isbns {
-                            if !current_isbn.eq(&Some(isbn.clone())) {
-                                write_element_block("RelatedProduct", w, |w| {
-                                    // 06 Alternative format
-                                    write_element_block("ProductRelationCode", w, |w| {
-                                        w.write(XmlEvent::Characters("06")).map_err(Into::into)
-                                    })?;
-                                    write_element_block("ProductIdentifier", w, |w| {
-                                        // 15 ISBN-13
-                                        write_element_block("ProductIDType", w, |w| {
-                                            w.write(XmlEvent::Characters("15")).map_err(Into::into)
-                                        })?;
-                                        write_element_block("IDValue", w, |w| {
-                                            w.write(XmlEvent::Characters(isbn)).map_err(Into::into)
-                                        })
-                                    })?;
-                                    write_element_block("ProductIdentifier", w, |w| {
-                                        // 03 GTIN-13
-                                        write_element_block("ProductIDType", w, |w| {
-                                            w.write(XmlEvent::Characters("03")).map_err(Into::into)
-                                        })?;
-                                        write_element_block("IDValue", w, |w| {
-                                            w.write(XmlEvent::Characters(isbn)).map_err(Into::into)
-                                        })
-                                    })
-                                })?;
-                            }
-                        }
-                        for reference in &self.references {
-                            XmlElementBlock::<Onix31Thoth>::xml_element(reference, w).ok();
-                        }
-                        Ok(())
-                    })?;
-                }
-                write_element_block("ProductSupply", w, |w| {
-                    write_element_block("Market", w, |w| {
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(Into::into)
-                            })
-                        })
-                    })?;
-                    let mut locations: Vec<WorkPublicationsLocations> =
-                        publication.locations.clone();
-                    if locations.is_empty() {
-                        // Create single Supplier based on Work Landing Page
-                        // so that we can output a SupplyDetail block (inc. Price info)
-                        // (if Landing Page is None, Supplier will be complete apart from Website)
-                        locations = vec![WorkPublicationsLocations {
-                            landing_page: self.landing_page.clone(),
-                            full_text_url: None,
-                            location_platform: LocationPlatform::PUBLISHER_WEBSITE,
-                            canonical: true,
-                        }];
-                    }
-                    for location in locations {
-                        let (supplier_name, description_string, supplier_role, landing_page_role) =
-                            match location.location_platform {
-                                LocationPlatform::PUBLISHER_WEBSITE => (
-                                    self.imprint.publisher.publisher_name.clone(),
-                                    "Publisher's website".to_string(),
-                                    // 09 Publisher to end-customers
-                                    "09",
-                                    // 02 Publisher's website for a specified work
-                                    "02",
-                                ),
-                                LocationPlatform::OTHER => (
-                                    "Unknown".to_string(),
-                                    "Unspecified hosting platform".to_string(),
-                                    // 11 Non-exclusive distributor to end-customers
-                                    "11",
-                                    // 36 Supplier’s website for a specified work
-                                    "36",
-                                ),
-                                _ => (
-                                    location.location_platform.to_string(),
-                                    location.location_platform.to_string(),
-                                    // 11 Non-exclusive distributor to end-customers
-                                    "11",
-                                    // 36 Supplier’s website for a specified work
-                                    "36",
-                                ),
-                            };
-                        write_element_block("SupplyDetail", w, |w| {
-                            write_element_block("Supplier", w, |w| {
-                                write_element_block("SupplierRole", w, |w| {
-                                    w.write(XmlEvent::Characters(supplier_role))
-                                        .map_err(Into::into)
-                                })?;
-                                write_element_block("SupplierName", w, |w| {
-                                    w.write(XmlEvent::Characters(&supplier_name))
-                                        .map_err(Into::into)
-                                })?;
-                                if let Some(url) = &location.landing_page {
-                                    write_element_block("Website", w, |w| {
-                                        write_element_block("WebsiteRole", w, |w| {
-                                            w.write(XmlEvent::Characters(landing_page_role))
-                                                .map_err(Into::into)
-                                        })?;
-                                        write_element_block("WebsiteDescription", w, |w| {
-                                            w.write(XmlEvent::Characters(&format!(
-                                                "{description_string}: webpage for this product"
-                                            )))
-                                            .map_err(Into::into)
-                                        })?;
-                                        write_element_block("WebsiteLink", w, |w| {
-                                            w.write(XmlEvent::Characters(url)).map_err(Into::into)
-                                        })
-                                    })?;
-                                }
-                                if let Some(url) = &location.full_text_url {
-                                    write_element_block("Website", w, |w| {
-                                        write_element_block("WebsiteRole", w, |w| {
-                                            // 29 Web page for full content
-                                            w.write(XmlEvent::Characters("29")).map_err(Into::into)
-                                        })?;
-                                        write_element_block("WebsiteDescription", w, |w| {
-                                            w.write(XmlEvent::Characters(&format!(
-                                                "{description_string}: download the title"
-                                            )))
-                                            .map_err(Into::into)
-                                        })?;
-                                        write_element_block("WebsiteLink", w, |w| {
-                                            w.write(XmlEvent::Characters(url)).map_err(Into::into)
-                                        })
-                                    })?;
-                                }
-                                Ok(())
-                            })?;
-                            write_element_block("ProductAvailability", w, |w| {
-                                let availability = match self.work_status {
-                                    WorkStatus::CANCELLED => "01",              // 01 – Cancelled
-                                    WorkStatus::FORTHCOMING => "10", // 10 – Not yet available
-                                    WorkStatus::POSTPONED_INDEFINITELY => "09", // 09 – Not yet available, postponed indefinitely
-                                    WorkStatus::ACTIVE => "20",                 // 20 – Available
-                                    WorkStatus::SUPERSEDED => "41", // 41 – Not available, replaced by new product
-                                    WorkStatus::WITHDRAWN => "49",  // 49 – Recalled
-                                    WorkStatus::Other(_) => unreachable!(),
-                                };
-                                w.write(XmlEvent::Characters(availability))
-                                    .map_err(Into::into)
-                            })?;
-                            if let Some(date) = &self.publication_date {
-                                write_element_block("SupplyDate", w, |w| {
-                                    write_element_block("SupplyDateRole", w, |w| {
-                                        // 02 Expected availability date
-                                        w.write(XmlEvent::Characters("08")).map_err(Into::into)
-                                    })?;
-                                    // dateformat="00" YYYYMMDD
-                                    write_full_element_block(
-                                        "Date",
-                                        Some(vec![("dateformat", "00")]),
-                                        w,
-                                        |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &date.format("%Y%m%d").to_string(),
-                                            ))
-                                            .map_err(Into::into)
-                                        },
-                                    )
-                                })?;
-                            }
-                            if publication.prices.is_empty() {
-                                // 01 Free of charge
-                                write_element_block("UnpricedItemType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                                })
-                            } else {
-                                for price in &publication.prices {
-                                    let unit_price = price.unit_price;
-                                    let formatted_price = format!("{unit_price:.2}");
-                                    write_element_block("Price", w, |w| {
-                                        // 02 RRP including tax
-                                        write_element_block("PriceType", w, |w| {
-                                            w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                                        })?;
-                                        write_element_block("PriceAmount", w, |w| {
-                                            w.write(XmlEvent::Characters(&formatted_price))
-                                                .map_err(Into::into)
-                                        })?;
-                                        write_element_block("CurrencyCode", w, |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &price.currency_code.to_string(),
-                                            ))
-                                            .map_err(Into::into)
-                                        })?;
-                                        write_element_block("Territory", w, |w| {
-                                            write_element_block("RegionsIncluded", w, |w| {
-                                                w.write(XmlEvent::Characters("WORLD"))
-                                                    .map_err(Into::into)
-                                            })
-                                        })
-                                    })?;
-                                }
-                                Ok(())
-                            }
-                        })?;
-                    }
-                    Ok(())
-                })
-            })?;
-        }
-        Ok(())
-    }
-}
-
-fn write_license<W: Write>(license: String, w: &mut EventWriter<W>) -> ThothResult<()> {
-    let license_text = match License::from_url(&license) {
-        Ok(license) => license.to_string(),
-        Err(_) => "Unspecified".to_string(),
-    };
-    write_element_block("EpubLicense", w, |w| {
-        write_element_block("EpubLicenseName", w, |w| {
-            w.write(XmlEvent::Characters(&license_text))
-                .map_err(Into::into)
-        })?;
-        write_element_block("EpubLicenseExpression", w, |w| {
-            write_element_block("EpubLicenseExpressionType", w, |w| {
-                w.write(XmlEvent::Characters("02")).map_err(Into::into)
-            })?;
-            write_element_block("EpubLicenseExpressionLink", w, |w| {
-                w.write(XmlEvent::Characters(&license)).map_err(Into::into)
-            })
-        })
-    })?;
-    Ok(())
-}
-
-fn write_title<W: Write, T: TitleData>(titles: &[T], w: &mut EventWriter<W>) -> ThothResult<()> {
-    // Output canonical title with TitleType 01 (Distinctive title)
-    if let Some(canonical_title) = titles.iter().find(|t| t.canonical()) {
-        write_element_block("TitleDetail", w, |w| {
-            // 01 Distinctive title (book)
-            write_element_block("TitleType", w, |w| {
-                w.write(XmlEvent::Characters("01")).map_err(Into::into)
-            })?;
-            write_element_block("TitleElement", w, |w| {
-                // 01 Product
-                write_element_block("TitleElementLevel", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                })?;
-                let api_locale: ApiLocaleCode = canonical_title.locale_code().clone().into();
-                let lang_code: ApiLanguageCode = api_locale.into();
-                let iso_code = lang_code.to_string().to_lowercase();
-                write_full_element_block(
-                    "TitleText",
-                    Some(vec![("language", &iso_code)]),
-                    w,
-                    |w| {
-                        w.write(XmlEvent::Characters(canonical_title.title()))
-                            .map_err(Into::into)
-                    },
-                )?;
-                if let Some(subtitle) = canonical_title.subtitle() {
-                    write_full_element_block(
-                        "Subtitle",
-                        Some(vec![("language", &iso_code)]),
-                        w,
-                        |w| w.write(XmlEvent::Characters(subtitle)).map_err(Into::into),
-                    )?;
-                }
-                Ok(())
-            })
-        })?;
-    }
-    // Output non-canonical titles with TitleType 06 (Title in another language)
-    for title in titles.iter().filter(|t| !t.canonical()) {
-        write_element_block("TitleDetail", w, |w| {
-            // 06 Title in another language
-            write_element_block("TitleType", w, |w| {
-                w.write(XmlEvent::Characters("06")).map_err(Into::into)
-            })?;
-            let api_locale: ApiLocaleCode = title.locale_code().clone().into();
-            let lang_code: ApiLanguageCode = api_locale.into();
-            let iso_code = lang_code.to_string().to_lowercase();
-            write_element_block("TitleElement", w, |w| {
-                // 01 Product
-                write_element_block("TitleElementLevel", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                })?;
-                write_full_element_block(
-                    "TitleText",
-                    Some(vec![("language", &iso_code)]),
-                    w,
-                    |w| {
-                        w.write(XmlEvent::Characters(title.title()))
-                            .map_err(Into::into)
-                    },
-                )?;
-                if let Some(subtitle) = title.subtitle() {
-                    write_full_element_block(
-                        "Subtitle",
-                        Some(vec![("language", &iso_code)]),
-                        w,
-                        |w| w.write(XmlEvent::Characters(subtitle)).map_err(Into::into),
-                    )?;
-                }
-                Ok(())
-            })
-        })?;
-    }
-    Ok(())
-}
-
-fn write_work_copyright<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
-    write_copyright_content(work.copyright_holder.clone(), w)
-}
-
-fn write_chapter_copyright<W: Write>(
-    chapter: &WorkRelationsRelatedWork,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    write_copyright_content(chapter.copyright_holder.clone(), w)
-}
-
-fn write_copyright_content<W: Write>(
-    copyright_holder: Option<String>,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    if let Some(copyright_string) = &copyright_holder {
-        // Format of field isn't controlled, but many records
-        // use semicolon-separated lists
-        for copyright_holder in copyright_string.split("; ") {
-            write_element_block("CopyrightStatement", w, |w| {
-                write_element_block("CopyrightOwner", w, |w| {
-                    // This might be a CorporateName rather than PersonName, but we can't tell
-                    write_element_block("PersonName", w, |w| {
-                        w.write(XmlEvent::Characters(copyright_holder))
-                            .map_err(Into::into)
-                    })
-                })
-            })?;
-        }
-    }
-    Ok(())
-}
-
-fn write_work_short_abstract<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
-    let short_abstract = work
-        .abstracts
-        .iter()
-        .find(|a| a.abstract_type == AbstractType::SHORT)
-        .map(|a| a.content.clone())
-        .unwrap_or_default();
-    write_short_abstract_content(short_abstract, w)?;
-    Ok(())
-}
-
-fn write_chapter_short_abstract<W: Write>(
-    chapter: &WorkRelationsRelatedWork,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    let short_abstract = chapter
-        .abstracts
-        .iter()
-        .find(|a| a.abstract_type == AbstractType::LONG)
-        .map(|a| a.content.clone())
-        .unwrap_or_default();
-    write_short_abstract_content(short_abstract, w)?;
-    Ok(())
-}
-
-fn write_short_abstract_content<W: Write>(
-    mut short_abstract: String,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    // Short description field may not exceed 350 characters.
-    // Ensure that the string is truncated at a valid UTF-8 boundary
-    // by finding the byte index of the 350th character and then truncating
-    // the string at that index, to avoid creating invalid UTF-8 sequences.
-    if let Some((byte_index, _)) = short_abstract.char_indices().nth(350) {
-        short_abstract.truncate(byte_index);
-    }
-    write_element_block("TextContent", w, |w| {
-        // 02 Short description
-        write_element_block("TextType", w, |w| {
-            w.write(XmlEvent::Characters("02")).map_err(Into::into)
-        })?;
-        // 00 Unrestricted
-        write_element_block("ContentAudience", w, |w| {
-            w.write(XmlEvent::Characters("00")).map_err(Into::into)
-        })?;
-        write_full_element_block("Text", Some(vec![("textformat", "03")]), w, |w| {
-            w.write(XmlEvent::Characters(&short_abstract))
-                .map_err(Into::into)
-        })
-    })?;
-    Ok(())
-}
-
-fn write_work_long_abstract<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
-    if let Some(long_abstract) = work
-        .abstracts
-        .iter()
-        .find(|a| a.abstract_type == AbstractType::LONG)
-        .map(|a| a.content.clone())
-    {
-        write_long_abstract_content(long_abstract, w)?;
-    }
-    Ok(())
-}
-
-fn write_chapter_long_abstract<W: Write>(
-    chapter: &WorkRelationsRelatedWork,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    if let Some(long_abstract) = chapter
-        .abstracts
-        .iter()
-        .find(|a| a.abstract_type == AbstractType::LONG)
-        .map(|a| a.content.clone())
-    {
-        write_long_abstract_content(long_abstract, w)?;
-    }
-    Ok(())
-}
-
-fn write_long_abstract_content<W: Write>(
-    long_abstract: String,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    // 03 Description, 30 Abstract
-    for text_type in ["03", "30"] {
-        write_element_block("TextContent", w, |w| {
-            write_element_block("TextType", w, |w| {
-                w.write(XmlEvent::Characters(text_type)).map_err(Into::into)
-            })?;
-            // 00 Unrestricted
-            write_element_block("ContentAudience", w, |w| {
-                w.write(XmlEvent::Characters("00")).map_err(Into::into)
-            })?;
-            write_full_element_block("Text", Some(vec![("textformat", "03")]), w, |w| {
-                w.write(XmlEvent::Characters(&long_abstract))
-                    .map_err(Into::into)
-            })
-        })?;
-    }
-    Ok(())
-}
-
-fn write_work_general_note<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
-    if let Some(general_note) = work.general_note.clone() {
-        write_general_note_content(general_note, w)?;
-    }
-    Ok(())
-}
-
-fn write_chapter_general_note<W: Write>(
-    chapter: &WorkRelationsRelatedWork,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    if let Some(general_note) = chapter.general_note.clone() {
-        write_general_note_content(general_note, w)?;
-    }
-    Ok(())
-}
-
-fn write_general_note_content<W: Write>(
-    general_note: String,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    write_element_block("TextContent", w, |w| {
-        // 13 Publisher's notice
-        // "A statement included by a publisher in fulfillment of contractual obligations"
-        // Used in many different ways - closest approximation
-        write_element_block("TextType", w, |w| {
-            w.write(XmlEvent::Characters("13")).map_err(Into::into)
-        })?;
-        // 00 Unrestricted
-        write_element_block("ContentAudience", w, |w| {
-            w.write(XmlEvent::Characters("00")).map_err(Into::into)
-        })?;
-        write_element_block("Text", w, |w| {
-            w.write(XmlEvent::Characters(&general_note))
-                .map_err(Into::into)
-        })
-    })?;
-    Ok(())
-}
-
-fn write_work_open_access_statement<W: Write>(
-    work: &Work,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    if work.license.is_some() {
-        write_open_access_statement_content(w)?;
-    }
-    Ok(())
-}
-
-fn write_chapter_open_access_statement<W: Write>(
-    chapter: &WorkRelationsRelatedWork,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    if chapter.license.is_some() {
-        write_open_access_statement_content(w)?;
-    }
-    Ok(())
-}
-
-fn write_open_access_statement_content<W: Write>(w: &mut EventWriter<W>) -> ThothResult<()> {
-    write_element_block("TextContent", w, |w| {
-        // 20 Open access statement
-        write_element_block("TextType", w, |w| {
-            w.write(XmlEvent::Characters("20")).map_err(Into::into)
-        })?;
-        // 00 Unrestricted
-        write_element_block("ContentAudience", w, |w| {
-            w.write(XmlEvent::Characters("00")).map_err(Into::into)
-        })?;
-        write_full_element_block("Text", Some(vec![("language", "eng")]), w, |w| {
-            w.write(XmlEvent::Characters("Open Access"))
-                .map_err(Into::into)
-        })
-    })?;
-    Ok(())
-}
-
-fn get_product_form_codes(publication_type: &PublicationType) -> (&str, Option<&str>) {
-    match publication_type {
-        PublicationType::PAPERBACK => ("BC", None),
-        PublicationType::HARDBACK => ("BB", None),
-        // EB Digital download and online
-        PublicationType::PDF => ("EB", Some("E107")),
-        PublicationType::HTML => ("EB", Some("E105")),
-        PublicationType::XML => ("EB", Some("E113")),
-        PublicationType::EPUB => ("EB", Some("E101")),
-        PublicationType::MOBI => ("EB", Some("E127")),
-        PublicationType::AZW3 => ("EB", Some("E116")),
-        PublicationType::DOCX => ("EB", Some("E104")),
-        // E100 "not yet allocated" - no codelist entry for .fb2, .fb3, .fbz
-        PublicationType::FICTION_BOOK => ("EB", Some("E100")),
-        // AN Downloadable and online audio file
-        PublicationType::MP3 => ("AN", Some("A103")),
-        PublicationType::WAV => ("AN", Some("A104")),
-        PublicationType::Other(_) => unreachable!(),
-    }
-}
-
-impl XmlElement<Onix31Thoth> for WorkStatus {
-    const ELEMENT: &'static str = "PublishingStatus";
-
-    fn value(&self) -> &'static str {
-        match self {
-            WorkStatus::CANCELLED => "01",
-            WorkStatus::FORTHCOMING => "02",
-            WorkStatus::POSTPONED_INDEFINITELY => "03",
-            WorkStatus::ACTIVE => "04",
-            WorkStatus::SUPERSEDED => "08",
-            WorkStatus::WITHDRAWN => "11",
-            WorkStatus::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix31Thoth> for SubjectType {
-    const ELEMENT: &'static str = "SubjectSchemeIdentifier";
-
-    fn value(&self) -> &'static str {
-        match self {
-            SubjectType::BIC => "12",
-            SubjectType::BISAC => "10",
-            SubjectType::KEYWORD => "20",
-            SubjectType::LCC => "04",
-            SubjectType::THEMA => "93",
-            SubjectType::CUSTOM => "B2",
-            SubjectType::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix31Thoth> for LanguageRelation {
-    const ELEMENT: &'static str = "LanguageRole";
-
-    fn value(&self) -> &'static str {
-        match self {
-            LanguageRelation::ORIGINAL => "01",
-            LanguageRelation::TRANSLATED_FROM => "02",
-            LanguageRelation::TRANSLATED_INTO => "01",
-            LanguageRelation::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix31Thoth> for ContributionType {
-    const ELEMENT: &'static str = "ContributorRole";
-
-    fn value(&self) -> &'static str {
-        match self {
-            ContributionType::AUTHOR => "A01",
-            ContributionType::EDITOR => "B01",
-            ContributionType::TRANSLATOR => "B06",
-            ContributionType::PHOTOGRAPHER => "A13",
-            ContributionType::ILLUSTRATOR => "A12",
-            ContributionType::MUSIC_EDITOR => "B25",
-            ContributionType::FOREWORD_BY => "A23",
-            ContributionType::INTRODUCTION_BY => "A24",
-            ContributionType::AFTERWORD_BY => "A19",
-            ContributionType::PREFACE_BY => "A15",
-            ContributionType::SOFTWARE_BY => "A30",
-            ContributionType::RESEARCH_BY => "A51",
-            ContributionType::CONTRIBUTIONS_BY => "A32",
-            ContributionType::INDEXER => "A34",
-            ContributionType::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElementBlock<Onix31Thoth> for WorkContributions {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Contributor", w, |w| {
-            write_element_block("SequenceNumber", w, |w| {
-                w.write(XmlEvent::Characters(&self.contribution_ordinal.to_string()))
-                    .map_err(Into::into)
-            })?;
-            XmlElement::<Onix31Thoth>::xml_element(&self.contribution_type, w)?;
-
-            if let Some(orcid) = &self.contributor.orcid {
-                write_element_block("NameIdentifier", w, |w| {
-                    write_element_block("NameIDType", w, |w| {
-                        w.write(XmlEvent::Characters("21")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&orcid.to_hyphenless_string()))
-                            .map_err(Into::into)
-                    })
-                })?;
-            }
-            write_element_block("PersonName", w, |w| {
-                w.write(XmlEvent::Characters(&self.full_name))
-                    .map_err(Into::into)
-            })?;
-            if let Some(first_name) = &self.first_name {
-                write_element_block("NamesBeforeKey", w, |w| {
-                    w.write(XmlEvent::Characters(first_name))
-                        .map_err(Into::into)
-                })?;
-            }
-            write_element_block("KeyNames", w, |w| {
-                w.write(XmlEvent::Characters(&self.last_name))
-                    .map_err(Into::into)
-            })?;
-            for affiliation in &self.affiliations {
-                write_element_block("ProfessionalAffiliation", w, |w| {
-                    if let Some(position) = &affiliation.position {
-                        write_element_block("ProfessionalPosition", w, |w| {
-                            w.write(XmlEvent::Characters(position)).map_err(Into::into)
-                        })?;
-                    }
-                    if let Some(ror) = &affiliation.institution.ror {
-                        write_element_block("AffiliationIdentifier", w, |w| {
-                            // 40: ROR ID
-                            write_element_block("AffiliationIDType", w, |w| {
-                                w.write(XmlEvent::Characters("40")).map_err(Into::into)
-                            })?;
-                            write_element_block("IDValue", w, |w| {
-                                w.write(XmlEvent::Characters(&ror.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    write_element_block("Affiliation", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            &affiliation.institution.institution_name,
-                        ))
-                        .map_err(Into::into)
-                    })
-                })?;
-            }
-            if !&self.biographies.is_empty() {
-                let biography = &self.biographies[0].content.clone();
-                write_element_block("BiographicalNote", w, |w| {
-                    w.write(XmlEvent::Characters(biography)).map_err(Into::into)
-                })?;
-            }
-            if let Some(website) = &self.contributor.website {
-                write_element_block("Website", w, |w| {
-                    write_element_block("WebsiteRole", w, |w| {
-                        w.write(XmlEvent::Characters("06")).map_err(Into::into)
-                    })?;
-                    write_element_block("WebsiteDescription", w, |w| {
-                        w.write(XmlEvent::Characters("Own website"))
-                            .map_err(Into::into)
-                    })?;
-                    write_element_block("WebsiteLink", w, |w| {
-                        w.write(XmlEvent::Characters(website)).map_err(Into::into)
-                    })
-                })?;
-            }
-            Ok(())
-        })
-    }
-}
-
-impl XmlElementBlock<Onix31Thoth> for WorkRelationsRelatedWorkContributions {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Contributor", w, |w| {
-            write_element_block("SequenceNumber", w, |w| {
-                w.write(XmlEvent::Characters(&self.contribution_ordinal.to_string()))
-                    .map_err(Into::into)
-            })?;
-            XmlElement::<Onix31Thoth>::xml_element(&self.contribution_type, w)?;
-
-            if let Some(orcid) = &self.contributor.orcid {
-                write_element_block("NameIdentifier", w, |w| {
-                    write_element_block("NameIDType", w, |w| {
-                        w.write(XmlEvent::Characters("21")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&orcid.to_hyphenless_string()))
-                            .map_err(Into::into)
-                    })
-                })?;
-            }
-            write_element_block("PersonName", w, |w| {
-                w.write(XmlEvent::Characters(&self.full_name))
-                    .map_err(Into::into)
-            })?;
-            if let Some(first_name) = &self.first_name {
-                write_element_block("NamesBeforeKey", w, |w| {
-                    w.write(XmlEvent::Characters(first_name))
-                        .map_err(Into::into)
-                })?;
-            }
-            write_element_block("KeyNames", w, |w| {
-                w.write(XmlEvent::Characters(&self.last_name))
-                    .map_err(Into::into)
-            })?;
-            for affiliation in &self.affiliations {
-                write_element_block("ProfessionalAffiliation", w, |w| {
-                    if let Some(position) = &affiliation.position {
-                        write_element_block("ProfessionalPosition", w, |w| {
-                            w.write(XmlEvent::Characters(position)).map_err(Into::into)
-                        })?;
-                    }
-                    if let Some(ror) = &affiliation.institution.ror {
-                        write_element_block("AffiliationIdentifier", w, |w| {
-                            write_element_block("AffiliationIDType", w, |w| {
-                                w.write(XmlEvent::Characters("40")).map_err(Into::into)
-                            })?;
-                            write_element_block("IDValue", w, |w| {
-                                w.write(XmlEvent::Characters(&ror.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    write_element_block("Affiliation", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            &affiliation.institution.institution_name,
-                        ))
-                        .map_err(Into::into)
-                    })
-                })?;
-            }
-            if !&self.biographies.is_empty() {
-                let biography = &self.biographies[0].content.clone();
-                write_element_block("BiographicalNote", w, |w| {
-                    w.write(XmlEvent::Characters(biography)).map_err(Into::into)
-                })?;
-            }
-            if let Some(website) = &self.contributor.website {
-                write_element_block("Website", w, |w| {
-                    write_element_block("WebsiteRole", w, |w| {
-                        w.write(XmlEvent::Characters("06")).map_err(Into::into)
-                    })?;
-                    write_element_block("WebsiteDescription", w, |w| {
-                        w.write(XmlEvent::Characters("Own website"))
-                            .map_err(Into::into)
-                    })?;
-                    write_element_block("WebsiteLink", w, |w| {
-                        w.write(XmlEvent::Characters(website)).map_err(Into::into)
-                    })
-                })?;
-            }
-            Ok(())
-        })
-    }
-}
-
-impl XmlElementBlock<Onix31Thoth> for WorkLanguages {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Language", w, |w| {
-            XmlElement::<Onix31Thoth>::xml_element(&self.language_relation, w).ok();
-            // not worth implementing XmlElement for LanguageCode as all cases would
-            // need to be exhaustively matched and the codes are equivalent anyway
-            write_element_block("LanguageCode", w, |w| {
-                w.write(XmlEvent::Characters(
-                    &self.language_code.to_string().to_lowercase(),
-                ))
-                .map_err(Into::into)
-            })
-        })
-    }
-}
-
-impl XmlElementBlock<Onix31Thoth> for WorkRelationsRelatedWorkLanguages {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Language", w, |w| {
-            XmlElement::<Onix31Thoth>::xml_element(&self.language_relation, w).ok();
-            // not worth implementing XmlElement for LanguageCode as all cases would
-            // need to be exhaustively matched and the codes are equivalent anyway
-            write_element_block("LanguageCode", w, |w| {
-                w.write(XmlEvent::Characters(
-                    &self.language_code.to_string().to_lowercase(),
-                ))
-                .map_err(Into::into)
-            })
-        })
-    }
-}
-
-impl XmlElementBlock<Onix31Thoth> for WorkIssues {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Collection", w, |w| {
-            // 10 Publisher collection (e.g. series)
-            write_element_block("CollectionType", w, |w| {
-                w.write(XmlEvent::Characters("10")).map_err(Into::into)
-            })?;
-            write_element_block("CollectionIdentifier", w, |w| {
-                // 01 Proprietary
-                write_element_block("CollectionIDType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                })?;
-                write_element_block("IDTypeName", w, |w| {
-                    w.write(XmlEvent::Characters("Series ID"))
-                        .map_err(Into::into)
-                })?;
-                write_element_block("IDValue", w, |w| {
-                    w.write(XmlEvent::Characters(&self.series.series_id.to_string()))
-                        .map_err(Into::into)
-                })
-            })?;
-            if let Some(issn_digital) = &self.series.issn_digital {
-                write_element_block("CollectionIdentifier", w, |w| {
-                    // 02 ISSN
-                    write_element_block("CollectionIDType", w, |w| {
-                        w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            &issn_digital.as_str().replace('-', ""),
-                        ))
-                        .map_err(Into::into)
-                    })
-                })?;
-            }
-            if let Some(url) = &self.series.series_url {
-                write_element_block("CollectionIdentifier", w, |w| {
-                    // 01 Proprietary
-                    write_element_block("CollectionIDType", w, |w| {
-                        w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("Series URL"))
-                            .map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(url)).map_err(Into::into)
-                    })
-                })?;
-            }
-            if let Some(url) = &self.series.series_cfp_url {
-                write_element_block("CollectionIdentifier", w, |w| {
-                    // 01 Proprietary
-                    write_element_block("CollectionIDType", w, |w| {
-                        w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("Series Call for Proposals URL"))
-                            .map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(url)).map_err(Into::into)
-                    })
-                })?;
-            }
-            write_element_block("CollectionSequence", w, |w| {
-                // 03 Publication order
-                write_element_block("CollectionSequenceType", w, |w| {
-                    w.write(XmlEvent::Characters("03")).map_err(Into::into)
-                })?;
-                write_element_block("CollectionSequenceNumber", w, |w| {
-                    w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
-                        .map_err(Into::into)
-                })
-            })?;
-            write_element_block("TitleDetail", w, |w| {
-                // 01 Cover title (serial)
-                write_element_block("TitleType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                })?;
-                write_element_block("TitleElement", w, |w| {
-                    // 02 Collection level
-                    write_element_block("TitleElementLevel", w, |w| {
-                        w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                    })?;
-                    if let Some(issue_number) = &self.issue_number {
-                        write_element_block("PartNumber", w, |w| {
-                            w.write(XmlEvent::Characters(&issue_number.to_string()))
-                                .map_err(|e| e.into())
-                        })?;
-                    }
-                    write_element_block("TitleText", w, |w| {
-                        w.write(XmlEvent::Characters(&self.series.series_name))
-                            .map_err(Into::into)
-                    })
-                })
-            })
-        })
-    }
-}
-
-impl XmlElementBlock<Onix31Thoth> for WorkFundings {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Publisher", w, |w| {
-            // 16 Funding body
-            write_element_block("PublishingRole", w, |w| {
-                w.write(XmlEvent::Characters("16")).map_err(Into::into)
-            })?;
-            if let Some(ror) = &self.institution.ror {
-                write_element_block("PublisherIdentifier", w, |w| {
-                    // 40 ROR
-                    write_element_block("PublisherIDType", w, |w| {
-                        w.write(XmlEvent::Characters("40")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&ror.to_string()))
-                            .map_err(Into::into)
-                    })
-                })?;
-            }
-            if let Some(doi) = &self.institution.institution_doi {
-                write_element_block("PublisherIdentifier", w, |w| {
-                    // 32 FundRef DOI
-                    write_element_block("PublisherIDType", w, |w| {
-                        w.write(XmlEvent::Characters("32")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&doi.to_string()))
-                            .map_err(Into::into)
-                    })
-                })?;
-            }
-            write_element_block("PublisherName", w, |w| {
-                w.write(XmlEvent::Characters(&self.institution.institution_name))
-                    .map_err(Into::into)
-            })?;
-            let identifiers: Vec<(&str, Option<&str>)> = vec![
-                ("programname", self.program.as_deref()),
-                ("projectname", self.project_name.as_deref()),
-                ("projectshortname", self.project_shortname.as_deref()),
-                ("grantnumber", self.grant_number.as_deref()),
-            ];
-            if identifiers.iter().any(|(_, i)| i.is_some()) {
-                write_element_block("Funding", w, |w| {
-                    for (typename, value_opt) in &identifiers {
-                        if let Some(value) = *value_opt {
-                            write_element_block("FundingIdentifier", w, |w| {
-                                // 01 Proprietary
-                                write_element_block("FundingIDType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                                })?;
-                                write_element_block("IDTypeName", w, |w| {
-                                    w.write(XmlEvent::Characters(typename)).map_err(Into::into)
-                                })?;
-                                write_element_block("IDValue", w, |w| {
-                                    w.write(XmlEvent::Characters(value)).map_err(Into::into)
-                                })
-                            })?;
-                        }
-                    }
-                    Ok(())
-                })?;
-            }
-            Ok(())
-        })
-    }
-}
-
-impl XmlElementBlock<Onix31Thoth> for WorkReferences {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("RelatedProduct", w, |w| {
-            // 34 Cites
-            write_element_block("ProductRelationCode", w, |w| {
-                w.write(XmlEvent::Characters("34")).map_err(Into::into)
-            })?;
-            let (product_id_type, id_value) = self.doi.as_ref().map_or_else(
-                || {
-                    (
-                        // 01 Proprietary
-                        "01",
-                        // Unstructured citation is mandatory in Thoth if DOI is missing
-                        self.unstructured_citation.as_ref().unwrap().to_owned(),
-                    )
-                },
-                // 06 DOI
-                |doi| ("06", doi.to_string()),
-            );
-            write_element_block("ProductIdentifier", w, |w| {
-                write_element_block("ProductIDType", w, |w| {
-                    w.write(XmlEvent::Characters(product_id_type))
-                        .map_err(Into::into)
-                })?;
-                if product_id_type == "01" {
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("Unstructured citation"))
-                            .map_err(Into::into)
-                    })?;
-                }
-                write_element_block("IDValue", w, |w| {
-                    w.write(XmlEvent::Characters(&id_value)).map_err(Into::into)
-                })
-            })
-        })
-    }
-}
-
-impl XmlElementBlock<Onix31Thoth> for WorkRelations {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        if self.relation_type == RelationType::HAS_TRANSLATION
-            || self.relation_type == RelationType::IS_TRANSLATION_OF
-        {
-            write_element_block("RelatedWork", w, |w| {
-                let work_relation_code = match &self.relation_type {
-                    // 49 Related work is derived from this via translation
-                    RelationType::HAS_TRANSLATION => "49",
-                    // 29 Derived from via translation
-                    RelationType::IS_TRANSLATION_OF => "29",
-                    _ => unreachable!(),
-                };
-                write_element_block("WorkRelationCode", w, |w| {
-                    w.write(XmlEvent::Characters(work_relation_code))
-                        .map_err(Into::into)
-                })?;
-                write_element_block("WorkIdentifier", w, |w| {
-                    // 06 DOI
-                    write_element_block("WorkIDType", w, |w| {
-                        w.write(XmlEvent::Characters("06")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            // Caller must guarantee that DOI is present
-                            &self.related_work.doi.as_ref().unwrap().to_string(),
-                        ))
-                        .map_err(Into::into)
-                    })
-                })
-            })
-        } else {
-            write_element_block("RelatedProduct", w, |w| {
-                let product_relation_code = match &self.relation_type {
-                    // 01 Includes
-                    RelationType::HAS_PART => "01",
-                    // 02 Is part of
-                    RelationType::IS_PART_OF => "02",
-                    // 03 Replaces
-                    RelationType::REPLACES => "03",
-                    // 05 Replaced by
-                    RelationType::IS_REPLACED_BY => "05",
-                    // This implementation is only valid for translation/part/replacement
-                    // relationships, not parent/child relationships
-                    _ => unreachable!(),
-                };
-                write_element_block("ProductRelationCode", w, |w| {
-                    w.write(XmlEvent::Characters(product_relation_code))
-                        .map_err(Into::into)
-                })?;
-                write_element_block("ProductIdentifier", w, |w| {
-                    // 06 DOI
-                    write_element_block("ProductIDType", w, |w| {
-                        w.write(XmlEvent::Characters("06")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            // Caller must guarantee that DOI is present
-                            &self.related_work.doi.as_ref().unwrap().to_string(),
-                        ))
-                        .map_err(Into::into)
-                    })
-                })
-            })
-        }
-    }
-}
+const PROFILE: Onix3Profile = Onix3Profile {
+    name: "onix_3.1::thoth",
+    namespace: ONIX31_NS,
+    header: Header {
+        addressee: None,
+        sent_date_format: "%Y%m%dT%H%M%S",
+    },
+    requirements: &[],
+    // Format is one record per Publication
+    products: Products::EveryPublication,
+    identifiers: Identifiers::Complete,
+    descriptive: Descriptive {
+        measures: true,
+        epub_license: Some(EpubLicense::Declared),
+        collections: Some(Collections {
+            issn: true,
+            proprietary_identifiers: true,
+            sequence_number: false,
+            collection_sequence: true,
+        }),
+        titles: Titles::Translated {
+            canonical_locale: false,
+        },
+        contributors: Contributors {
+            role: onix_contributor_role,
+            excluded: &[],
+            require_author: false,
+            detail: ContributorDetail::Complete {
+                alternative_names: true,
+                affiliation_ror: true,
+            },
+        },
+        edition: Some(Edition::Number),
+        ancillary_content: true,
+        subjects: Subjects {
+            crosswalk: false,
+            scheme: onix_subject_scheme,
+            headings: &[SubjectType::CUSTOM],
+            combine_keywords: true,
+            main_subject: true,
+        },
+        default_audience: true,
+    },
+    collateral: Collateral {
+        descriptions: Descriptions::Complete,
+        table_of_contents: true,
+        toc_language: None,
+        open_access_statement: Some(OpenAccessStatement::Open),
+        general_note: true,
+        cover: true,
+        cover_caption: true,
+        supplementary: true,
+    },
+    chapters: Some(Chapters::Described),
+    publishing: Publishing {
+        websites: true,
+        funders: Some(Funders::Complete),
+        city_of_publication: true,
+        // 01 Publication date
+        publication_date_role: "01",
+        publication_year_only: false,
+        copyright_year: false,
+        copyright_holders: true,
+        sales_rights: true,
+    },
+    related_material: RelatedMaterial::Complete,
+    supply: Supply {
+        world_market: true,
+        suppliers: Suppliers::Locations,
+        availability: Availability::WorkStatus,
+        // 08 Expected availability date
+        supply_date_role: Some("08"),
+        prices: PriceRule::Listed,
+    },
+};
 
-impl XmlElementBlock<Onix31Thoth> for Measure {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Measure", w, |w| {
-            write_element_block("MeasureType", w, |w| {
-                w.write(XmlEvent::Characters(self.measure_type))
-                    .map_err(Into::into)
-            })?;
-            write_element_block("Measurement", w, |w| {
-                w.write(XmlEvent::Characters(&self.measurement.to_string()))
-                    .map_err(Into::into)
-            })?;
-            write_element_block("MeasureUnitCode", w, |w| {
-                w.write(XmlEvent::Characters(self.measure_unit_code))
-                    .map_err(Into::into)
-            })
-        })
-    }
-}
+onix3_profile!(Onix31Thoth, PROFILE);
 
 #[cfg(test)]
 mod tests {
@@ -1946,23 +98,31 @@ mod tests {
     // We therefore rely on `assert!(contains)` rather than `assert_eq!` in these cases
     // println!s throughout will only be printed if test fails - this assists debugging
     use super::*;
+    use crate::xml::XmlElementBlock;
+    use chrono::NaiveDate;
     use std::str::FromStr;
     use thoth_api::model::Doi;
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_api::model::Ror;
     use thoth_client::{
-        ContributionType, CurrencyCode, FundingInstitution, LanguageCode, LanguageRelation,
-        LocationPlatform, PublicationType, WorkContributionsAffiliations,
-        WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkImprint,
-        WorkImprintPublisher, WorkImprintPublisherContacts, WorkIssuesSeries, WorkPublications,
-        WorkPublicationsLocations, WorkPublicationsPrices, WorkRelationsRelatedWork,
+        AccessStatus, AccessibilityException, AccessibilityStandard, AwardRole, ContactType,
+        ContributionType, CountryCode, CurrencyCode, FundingInstitution, LanguageCode,
+        LanguageRelation, LocationPlatform, NameVariant, NameVariantType, PriceType,
+        PublicationType, RelationType, Work, WorkAdditionalResources, WorkAdditionalResourcesFile,
+        WorkAwards, WorkBookReviews, WorkContributions, WorkContributionsAffiliations,
+        WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkEndorsements,
+        WorkFeaturedVideo, WorkFeaturedVideoFile, WorkFundings, WorkImprint, WorkImprintPublisher,
+        WorkImprintPublisherContacts, WorkIssues, WorkIssuesSeries, WorkLanguages,
+        WorkPublications, WorkPublicationsLocations, WorkPublicationsPrices, WorkReferences,
+        WorkRelations, WorkRelationsRelatedWork, WorkRelationsRelatedWorkContributions,
         WorkRelationsRelatedWorkContributionsAffiliations,
         WorkRelationsRelatedWorkContributionsAffiliationsInstitution,
         WorkRelationsRelatedWorkContributionsContributor, WorkRelationsRelatedWorkImprint,
-        WorkRelationsRelatedWorkImprintPublisher, WorkRelationsRelatedWorkReferences, WorkStatus,
-        WorkSubjects, WorkType,
+        WorkRelationsRelatedWorkImprintPublisher, WorkRelationsRelatedWorkLanguages,
+        WorkRelationsRelatedWorkReferences, WorkStatus, WorkSubjects, WorkType,
     };
+    use thoth_errors::ThothError;
     use uuid::Uuid;
 
     fn generate_test_output(expect_ok: bool, input: &impl XmlElementBlock<Onix31Thoth>) -> String {
@@ -2002,9 +162,12 @@ mod tests {
                 canonical: true,
             }],
             contribution_ordinal: 1,
+            name_variants: vec![],
+            credit_roles: vec![],
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: Some("https://contributor.site".to_string()),
+                name_variants: vec![],
             },
             affiliations: vec![WorkContributionsAffiliations {
                 position: Some("Manager".to_string()),
@@ -2109,6 +272,44 @@ mod tests {
   </ProfessionalAffiliation>"#
         ));
 
+        // Test alternative names, listing the contributor's before the contribution's
+        test_contribution.contributor.name_variants = vec![NameVariant {
+            variant_type: NameVariantType::TRANSLITERATION,
+            script_code: Some("Cyrl".to_string()),
+            locale_code: Some(thoth_client::LocaleCode::RU),
+            first_name: Some("Автор".to_string()),
+            last_name: "1".to_string(),
+            full_name: "Автор 1".to_string(),
+        }];
+        test_contribution.name_variants = vec![NameVariant {
+            variant_type: NameVariantType::PSEUDONYM,
+            script_code: None,
+            locale_code: None,
+            first_name: None,
+            last_name: "Anonymous".to_string(),
+            full_name: "Anonymous".to_string(),
+        }];
+        let output = generate_test_output(true, &test_contribution);
+        println!("{output}");
+        assert!(output.contains(
+            r#"
+  <KeyNames>1</KeyNames>
+  <AlternativeName>
+    <NameType>05</NameType>
+    <PersonName textscript="Cyrl">Автор 1</PersonName>
+    <NamesBeforeKey textscript="Cyrl">Автор</NamesBeforeKey>
+    <KeyNames textscript="Cyrl">1</KeyNames>
+  </AlternativeName>
+  <AlternativeName>
+    <NameType>01</NameType>
+    <PersonName>Anonymous</PersonName>
+    <KeyNames>Anonymous</KeyNames>
+  </AlternativeName>
+  <ProfessionalAffiliation>"#
+        ));
+        test_contribution.contributor.name_variants = vec![];
+        test_contribution.name_variants = vec![];
+
         // Test all remaining contributor roles
         test_contribution.contribution_type = ContributionType::TRANSLATOR;
         let output = generate_test_output(true, &test_contribution);
@@ -2488,6 +689,228 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_onix31_thoth_awards() {
+        let mut test_award = WorkAwards {
+            title: "Book Prize".to_string(),
+            url: None,
+            category: Some("Monograph".to_string()),
+            year: Some("2024".to_string()),
+            jury: Some("The Prize Committee".to_string()),
+            country: Some(CountryCode::GBR),
+            role: Some(AwardRole::SHORT_LISTED),
+            prize_statement: Some("A remarkable book".to_string()),
+            award_ordinal: 1,
+        };
+
+        // Test standard output
+        let output = generate_test_output(true, &test_award);
+        println!("{output}");
+        assert_eq!(
+            output,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Prize>
+  <PrizeName>Book Prize: Monograph</PrizeName>
+  <PrizeYear>2024</PrizeYear>
+  <PrizeCountry>GB</PrizeCountry>
+  <PrizeCode>04</PrizeCode>
+  <PrizeStatement>A remarkable book</PrizeStatement>
+  <PrizeJury>The Prize Committee</PrizeJury>
+</Prize>"#
+        );
+
+        // Remove optional fields: a span of years cannot be output
+        test_award.category = None;
+        test_award.year = Some("2023-2024".to_string());
+        test_award.jury = None;
+        test_award.country = None;
+        test_award.role = None;
+        test_award.prize_statement = None;
+        let output = generate_test_output(true, &test_award);
+        println!("{output}");
+        assert_eq!(
+            output,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Prize>
+  <PrizeName>Book Prize</PrizeName>
+</Prize>"#
+        );
+    }
+
+    #[test]
+    fn test_onix31_thoth_reviews_and_endorsements() {
+        let mut test_review = WorkBookReviews {
+            title: Some("Review of a book".to_string()),
+            author_name: Some("Review Author".to_string()),
+            url: None,
+            doi: None,
+            review_date: Some(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()),
+            journal_name: Some("Journal of Reviews".to_string()),
+            text: Some("An essential read".to_string()),
+            review_ordinal: 1,
+        };
+        let output = generate_test_output(true, &test_review);
+        println!("{output}");
+        assert_eq!(
+            output,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<TextContent>
+  <TextType>06</TextType>
+  <ContentAudience>00</ContentAudience>
+  <Text>An essential read</Text>
+  <TextAuthor>Review Author</TextAuthor>
+  <SourceTitle>Journal of Reviews</SourceTitle>
+  <ContentDate>
+    <ContentDateRole>01</ContentDateRole>
+    <Date>20240301</Date>
+  </ContentDate>
+</TextContent>"#
+        );
+
+        // Reviews without text are not output
+        test_review.text = None;
+        let output = generate_test_output(true, &test_review);
+        assert!(!output.contains("TextContent"));
+
+        let mut test_endorsement = WorkEndorsements {
+            author_name: Some("Endorsement Author".to_string()),
+            author_role: Some("Professor of History".to_string()),
+            url: None,
+            text: Some("A landmark study".to_string()),
+            endorsement_ordinal: 1,
+        };
+        let output = generate_test_output(true, &test_endorsement);
+        println!("{output}");
+        assert_eq!(
+            output,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<TextContent>
+  <TextType>09</TextType>
+  <ContentAudience>00</ContentAudience>
+  <Text>A landmark study</Text>
+  <TextAuthor>Endorsement Author, Professor of History</TextAuthor>
+</TextContent>"#
+        );
+
+        test_endorsement.author_role = None;
+        let output = generate_test_output(true, &test_endorsement);
+        println!("{output}");
+        assert!(output.contains(r#"<TextAuthor>Endorsement Author</TextAuthor>"#));
+    }
+
+    #[test]
+    fn test_onix31_thoth_supporting_resources() {
+        let mut test_resource = WorkAdditionalResources {
+            title: "Interview with the author".to_string(),
+            description: None,
+            attribution: Some("Recorded by Thoth".to_string()),
+            resource_type: "AUDIO".to_string(),
+            doi: Some(Doi::from_str("https://doi.org/10.00001/resource").unwrap()),
+            url: Some("https://www.book.com/interview".to_string()),
+            resource_ordinal: 1,
+            file: Some(WorkAdditionalResourcesFile {
+                cdn_url: "https://cdn.thoth.pub/interview.mp3".to_string(),
+                mime_type: "audio/mpeg".to_string(),
+            }),
+        };
+
+        // Test standard output: hosted files take precedence
+        let output = generate_test_output(true, &test_resource);
+        println!("{output}");
+        assert_eq!(
+            output,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<SupportingResource>
+  <ResourceContentType>15</ResourceContentType>
+  <ContentAudience>00</ContentAudience>
+  <ResourceMode>02</ResourceMode>
+  <ResourceFeature>
+    <ResourceFeatureType>02</ResourceFeatureType>
+    <FeatureNote>Interview with the author</FeatureNote>
+  </ResourceFeature>
+  <ResourceFeature>
+    <ResourceFeatureType>01</ResourceFeatureType>
+    <FeatureNote>Recorded by Thoth</FeatureNote>
+  </ResourceFeature>
+  <ResourceVersion>
+    <ResourceForm>02</ResourceForm>
+    <ResourceLink>https://cdn.thoth.pub/interview.mp3</ResourceLink>
+  </ResourceVersion>
+</SupportingResource>"#
+        );
+
+        // Fall back to the URL, then to the DOI
+        test_resource.file = None;
+        test_resource.attribution = None;
+        test_resource.resource_type = "BLOG".to_string();
+        let output = generate_test_output(true, &test_resource);
+        println!("{output}");
+        assert!(output.contains(r#"<ResourceContentType>18</ResourceContentType>"#));
+        assert!(output.contains(r#"<ResourceMode>04</ResourceMode>"#));
+        assert!(!output.contains(r#"<ResourceFeatureType>01</ResourceFeatureType>"#));
+        assert!(output.contains(r#"<ResourceForm>01</ResourceForm>"#));
+        assert!(output.contains(r#"<ResourceLink>https://www.book.com/interview</ResourceLink>"#));
+        test_resource.url = None;
+        let output = generate_test_output(true, &test_resource);
+        println!("{output}");
+        assert!(
+            output.contains(r#"<ResourceLink>https://doi.org/10.00001/resource</ResourceLink>"#)
+        );
+
+        // Resources without any link are not output
+        test_resource.doi = None;
+        let output = generate_test_output(true, &test_resource);
+        assert!(!output.contains("SupportingResource"));
+
+        let mut test_video = WorkFeaturedVideo {
+            title: Some("Book trailer".to_string()),
+            url: Some("https://www.book.com/trailer".to_string()),
+            width: 1280,
+            height: 720,
+            file: Some(WorkFeaturedVideoFile {
+                cdn_url: "https://cdn.thoth.pub/trailer.mp4".to_string(),
+                mime_type: "video/mp4".to_string(),
+            }),
+        };
+        let output = generate_test_output(true, &test_video);
+        println!("{output}");
+        assert_eq!(
+            output,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<SupportingResource>
+  <ResourceContentType>26</ResourceContentType>
+  <ContentAudience>00</ContentAudience>
+  <ResourceMode>05</ResourceMode>
+  <ResourceFeature>
+    <ResourceFeatureType>02</ResourceFeatureType>
+    <FeatureNote>Book trailer</FeatureNote>
+  </ResourceFeature>
+  <ResourceVersion>
+    <ResourceForm>02</ResourceForm>
+    <ResourceVersionFeature>
+      <ResourceVersionFeatureType>02</ResourceVersionFeatureType>
+      <FeatureValue>720</FeatureValue>
+    </ResourceVersionFeature>
+    <ResourceVersionFeature>
+      <ResourceVersionFeatureType>03</ResourceVersionFeatureType>
+      <FeatureValue>1280</FeatureValue>
+    </ResourceVersionFeature>
+    <ResourceLink>https://cdn.thoth.pub/trailer.mp4</ResourceLink>
+  </ResourceVersion>
+</SupportingResource>"#
+        );
+
+        // Videos without known dimensions link to the original URL
+        test_video.file = None;
+        test_video.width = 0;
+        test_video.height = 0;
+        let output = generate_test_output(true, &test_video);
+        println!("{output}");
+        assert!(!output.contains("ResourceVersionFeature"));
+        assert!(output.contains(r#"<ResourceForm>01</ResourceForm>"#));
+        assert!(output.contains(r#"<ResourceLink>https://www.book.com/trailer</ResourceLink>"#));
+    }
+
     #[test]
     fn test_onix31_thoth_relations() {
         let mut test_relation = WorkRelations {
@@ -2689,14 +1112,29 @@ mod tests {
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::EUR,
                         unit_price: 5.95,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::GBP,
                         unit_price: 4.95,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::USD,
                         unit_price: 8.0,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                 ],
                 locations: vec![
@@ -2714,6 +1152,11 @@ mod tests {
                         canonical: false,
                     },
                 ],
+                sales_rights: vec![],
+                product_form_details: vec![],
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
             }],
             subjects: vec![
                 WorkSubjects {
@@ -2752,6 +1195,13 @@ mod tests {
                     subject_ordinal: 1,
                 },
             ],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![],
             relations: vec![
                 WorkRelations {
@@ -3659,6 +2109,38 @@ mod tests {
     </PublishingDate>"#
         ));
 
+        // Embargoed publication: licence declared with its start date, no open access statement
+        test_work.publications[0].access_status = Some(AccessStatus::EMBARGOED);
+        test_work.publications[0].embargo_end_date = chrono::NaiveDate::from_ymd_opt(2999, 1, 1);
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <EpubLicense>
+      <EpubLicenseName>Creative Commons Attribution 4.0 International license (CC BY 4.0).</EpubLicenseName>
+      <EpubLicenseExpression>
+        <EpubLicenseExpressionType>02</EpubLicenseExpressionType>
+        <EpubLicenseExpressionLink>https://creativecommons.org/licenses/by/4.0/</EpubLicenseExpressionLink>
+      </EpubLicenseExpression>
+      <EpubLicenseDate>
+        <EpubLicenseDateRole>14</EpubLicenseDateRole>
+        <Date dateformat="00">29990101</Date>
+      </EpubLicenseDate>
+    </EpubLicense>"#
+        ));
+        assert!(!output.contains(
+            r#"
+      <TextType>20</TextType>"#
+        ));
+        // Restricted publication: no licence declared
+        test_work.publications[0].access_status = Some(AccessStatus::RESTRICTED);
+        test_work.publications[0].embargo_end_date = None;
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(
+            r#"
+    <EpubLicense>"#
+        ));
+        test_work.publications[0].access_status = None;
+
         // Test ProductForm[Detail] with different publication types
         test_work.publications[0].publication_type = PublicationType::HARDBACK;
         let output = generate_test_output(true, &test_work);
@@ -4346,6 +2828,11 @@ mod tests {
             accessibility_report_url: None,
             prices: vec![],
             locations: vec![],
+            sales_rights: vec![],
+            product_form_details: vec![],
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         });
         let output = generate_test_output(true, &test_work);
         println!("{output}");
@@ -1,772 +1,122 @@
-use super::{write_element_block, XmlElement, XmlSpecification};
-use crate::xml::{write_full_element_block, XmlElementBlock, ONIX3_NS};
-use chrono::Utc;
-use std::io::Write;
-use thoth_api::model::language::LanguageCode as ApiLanguageCode;
-use thoth_api::model::locale::LocaleCode as ApiLocaleCode;
-use thoth_client::{
-    AbstractType, AccessibilityException, AccessibilityStandard, ContactType, ContributionType,
-    CurrencyCode, LanguageRelation, PublicationType, SubjectType, Work, WorkContributions,
-    WorkIssues, WorkLanguages, WorkPublications, WorkStatus, WorkType,
+use thoth_client::{ContributionType, CurrencyCode, PublicationType, SubjectType};
+
+use super::onix3_profile::{
+    onix3_profile, onix_contributor_role, onix_subject_scheme, Availability, Collateral,
+    Collections, ContributorDetail, Contributors, Descriptions, Descriptive, Header, Identifiers,
+    Onix3Profile, PriceRule, Products, Publishing, RelatedMaterial, Requirement, Subjects,
+    Suppliers, Supply, Titles,
 };
-use thoth_errors::{ThothError, ThothResult};
-use xml::writer::{EventWriter, XmlEvent};
+use super::ONIX3_NS;
 
 #[derive(Copy, Clone)]
 pub struct Onix3GoogleBooks {}
 
-const ONIX_ERROR: &str = "onix_3.0::google_books";
-
-// Output format based on documentation at https://support.google.com/books/partner/answer/6374180.
-impl XmlSpecification for Onix3GoogleBooks {
-    fn handle_event<W: Write>(w: &mut EventWriter<W>, works: &[Work]) -> ThothResult<()> {
-        write_full_element_block("ONIXMessage", Some(ONIX3_NS.to_vec()), w, |w| {
-            write_element_block("Header", w, |w| {
-                write_element_block("Sender", w, |w| {
-                    write_element_block("SenderName", w, |w| {
-                        w.write(XmlEvent::Characters("Thoth")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("EmailAddress", w, |w| {
-                        w.write(XmlEvent::Characters("distribution@thoth.pub"))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-                write_element_block("Addressee", w, |w| {
-                    write_element_block("AddresseeName", w, |w| {
-                        w.write(XmlEvent::Characters("Google"))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-                write_element_block("SentDateTime", w, |w| {
-                    w.write(XmlEvent::Characters(
-                        &Utc::now().format("%Y%m%dT%H%M%S").to_string(),
-                    ))
-                    .map_err(|e| e.into())
-                })
-            })?;
-
-            match works {
-                [] => Err(ThothError::IncompleteMetadataRecord(
-                    ONIX_ERROR.to_string(),
-                    "Not enough data".to_string(),
-                )),
-                [work] => XmlElementBlock::<Onix3GoogleBooks>::xml_element(work, w),
-                _ => {
-                    for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
-                        // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
-                            XmlElementBlock::<Onix3GoogleBooks>::xml_element(work, w).ok();
-                        }
-                    }
-                    Ok(())
-                }
-            }
-        })
-    }
-}
-
-impl XmlElementBlock<Onix3GoogleBooks> for Work {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        // Don't output works with no BIC, BISAC or LCC subject code
-        // Google Books can only ingest works which have at least one
-        if !self.subjects.iter().any(|s| {
-            matches!(
-                s.subject_type,
-                SubjectType::BISAC | SubjectType::BIC | SubjectType::LCC
-            )
-        }) {
-            return Err(ThothError::IncompleteMetadataRecord(
-                ONIX_ERROR.to_string(),
-                "No BIC, BISAC or LCC subject code".to_string(),
-            ));
-        }
-        // Don't output works with no publication date (mandatory in Google Books)
-        if self.publication_date.is_none() {
-            return Err(ThothError::IncompleteMetadataRecord(
-                ONIX_ERROR.to_string(),
-                "Missing Publication Date".to_string(),
-            ));
-        }
-        // Don't output works with no contributors (at least one required for Google Books)
-        if self.contributions.is_empty() {
-            return Err(ThothError::IncompleteMetadataRecord(
-                ONIX_ERROR.to_string(),
-                "No contributors supplied".to_string(),
-            ));
-        }
-        // We can only generate the document if there's an EPUB or PDF
-        if let Some(main_publication) = self
-            .publications
-            .iter()
-            // For preference, distribute the EPUB only
-            .find(|p| {
-                p.publication_type.eq(&PublicationType::EPUB)
-                    && p.locations
-                        .iter()
-                        .any(|l| l.canonical && l.full_text_url.is_some())
-            })
-            // If no EPUB is found, distribute the PDF only
-            .or_else(|| {
-                self.publications.iter().find(|p| {
-                    p.publication_type.eq(&PublicationType::PDF)
-                        && p.locations
-                            .iter()
-                            .any(|l| l.canonical && l.full_text_url.is_some())
-                })
-            })
-        {
-            let (main_isbn, isbns) = get_publications_data(&self.publications, main_publication);
-            if main_isbn.is_empty() {
-                // Google Books requires at least one ProductIdentifier block with an ISBN type
-                return Err(ThothError::IncompleteMetadataRecord(
-                    ONIX_ERROR.to_string(),
-                    "This work does not have a PDF, EPUB or paperback ISBN".to_string(),
-                ));
-            }
-            write_element_block("Product", w, |w| {
-                write_element_block("RecordReference", w, |w| {
-                    w.write(XmlEvent::Characters(&format!("urn:uuid:{}", self.work_id)))
-                        .map_err(|e| e.into())
-                })?;
-                // 03 Notification confirmed on publication
-                write_element_block("NotificationType", w, |w| {
-                    w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                })?;
-                write_element_block("ProductIdentifier", w, |w| {
-                    // 15 ISBN-13
-                    write_element_block("ProductIDType", w, |w| {
-                        w.write(XmlEvent::Characters("15")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&main_isbn))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-                write_element_block("DescriptiveDetail", w, |w| {
-                    // 00 Single-component retail product
-                    write_element_block("ProductComposition", w, |w| {
-                        w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                    })?;
-                    // EB Digital download and online
-                    write_element_block("ProductForm", w, |w| {
-                        w.write(XmlEvent::Characters("EB")).map_err(|e| e.into())
-                    })?;
-                    let digital_type = match main_publication.publication_type {
-                        PublicationType::EPUB => "E101",
-                        PublicationType::PDF => "E107",
-                        _ => unreachable!(),
-                    };
-                    write_element_block("ProductFormDetail", w, |w| {
-                        w.write(XmlEvent::Characters(digital_type))
-                            .map_err(|e| e.into())
-                    })?;
-                    if let Some(accessibility_statement) =
-                        &self.imprint.publisher.accessibility_statement
-                    {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            // 00 Accessibility summary
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureDescription", w, |w| {
-                                w.write(XmlEvent::Characters(&accessibility_statement.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    let mut accessibility_codes = vec![];
-                    if let Some(standard) = &main_publication.accessibility_standard {
-                        let standard_codes = match standard {
-                            AccessibilityStandard::WCAG21AA => vec!["81", "85"],
-                            AccessibilityStandard::WCAG21AAA => vec!["81", "86"],
-                            AccessibilityStandard::WCAG22AA => vec!["82", "85"],
-                            AccessibilityStandard::WCAG22AAA => vec!["82", "86"],
-                            _ => unreachable!(),
-                        };
-                        accessibility_codes.extend(standard_codes);
-                    }
-                    if let Some(additional_standard) =
-                        &main_publication.accessibility_additional_standard
-                    {
-                        let additional_standard_codes = match additional_standard {
-                            AccessibilityStandard::EPUB_A11Y10AA => vec!["03"],
-                            AccessibilityStandard::EPUB_A11Y10AAA => vec!["03", "86"],
-                            AccessibilityStandard::EPUB_A11Y11AA => vec!["04", "85"],
-                            AccessibilityStandard::EPUB_A11Y11AAA => vec!["04", "86"],
-                            AccessibilityStandard::PDF_UA1 => vec!["05"],
-                            AccessibilityStandard::PDF_UA2 => vec!["06"],
-                            _ => unreachable!(),
-                        };
-                        accessibility_codes.extend(additional_standard_codes);
-                    }
-                    if let Some(exception) = &main_publication.accessibility_exception {
-                        let exception_code = match exception {
-                            AccessibilityException::MICRO_ENTERPRISES => "75",
-                            AccessibilityException::DISPROPORTIONATE_BURDEN => "76",
-                            AccessibilityException::FUNDAMENTAL_ALTERATION => "77",
-                            AccessibilityException::Other(_) => unreachable!(),
-                        };
-                        accessibility_codes.push(exception_code);
-                    }
-                    for code in accessibility_codes {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters(code)).map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    if let Some(report_url) = &main_publication.accessibility_report_url {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            // 96 Publisher’s web page for detailed accessibility information
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters("96")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureDescription", w, |w| {
-                                w.write(XmlEvent::Characters(&report_url.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    for contact in &self.imprint.publisher.contacts {
-                        if contact.contact_type == ContactType::ACCESSIBILITY {
-                            write_element_block("ProductFormFeature", w, |w| {
-                                // 09 E-publication accessibility detail
-                                write_element_block("ProductFormFeatureType", w, |w| {
-                                    w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                                })?;
-                                // 99 Publisher contact for further accessibility information
-                                write_element_block("ProductFormFeatureValue", w, |w| {
-                                    w.write(XmlEvent::Characters("99")).map_err(Into::into)
-                                })?;
-                                write_element_block("ProductFormFeatureDescription", w, |w| {
-                                    w.write(XmlEvent::Characters(&contact.email))
-                                        .map_err(Into::into)
-                                })
-                            })?;
-                        }
-                    }
-                    // 10 Text (eye-readable)
-                    write_element_block("PrimaryContentType", w, |w| {
-                        w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
-                    })?;
-                    for issue in &self.issues {
-                        XmlElementBlock::<Onix3GoogleBooks>::xml_element(issue, w).ok();
-                    }
-                    write_element_block("TitleDetail", w, |w| {
-                        // 01 Distinctive title (book)
-                        write_element_block("TitleType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("TitleElement", w, |w| {
-                            // 01 Product
-                            write_element_block("TitleElementLevel", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("TitleText", w, |w| {
-                                w.write(XmlEvent::Characters(&self.titles[0].title))
-                                    .map_err(|e| e.into())
-                            })?;
-                            if let Some(subtitle) = &self.titles[0].subtitle {
-                                write_element_block("Subtitle", w, |w| {
-                                    w.write(XmlEvent::Characters(subtitle))
-                                        .map_err(|e| e.into())
-                                })?;
-                            }
-                            Ok(())
-                        })
-                    })?;
-                    // Google Books requires at least one contributor coded as A01 (Author) -
-                    // if this is e.g. a wholly edited book, code the first main contributor as an author.
-                    let mut contributions = self.contributions.clone();
-                    if !contributions
-                        .iter()
-                        .any(|c| c.contribution_type.eq(&ContributionType::AUTHOR))
-                    {
-                        // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
-                        contributions
-                            .sort_by(|a, b| a.contribution_ordinal.cmp(&b.contribution_ordinal));
-                        contributions.sort_by(|a, b| b.main_contribution.cmp(&a.main_contribution));
-                        contributions[0].contribution_type = ContributionType::AUTHOR;
-                    }
-                    for contribution in &contributions {
-                        // Google Books doesn't support B25, A30, A34 or A51 codes
-                        // (or any appropriate "Other" code)
-                        match contribution.contribution_type {
-                            ContributionType::SOFTWARE_BY
-                            | ContributionType::RESEARCH_BY
-                            | ContributionType::INDEXER
-                            | ContributionType::MUSIC_EDITOR => (),
-                            _ => {
-                                XmlElementBlock::<Onix3GoogleBooks>::xml_element(contribution, w)
-                                    .ok();
-                            }
-                        }
-                    }
-                    for language in &self.languages {
-                        XmlElementBlock::<Onix3GoogleBooks>::xml_element(language, w).ok();
-                    }
-                    if let Some(page_count) = self.page_count {
-                        write_element_block("Extent", w, |w| {
-                            // 00 Main content
-                            write_element_block("ExtentType", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("ExtentValue", w, |w| {
-                                w.write(XmlEvent::Characters(&page_count.to_string()))
-                                    .map_err(|e| e.into())
-                            })?;
-                            // 03 Pages
-                            write_element_block("ExtentUnit", w, |w| {
-                                w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                            })
-                        })?;
-                    }
-                    for subject in &self.subjects {
-                        // Google Books doesn't support Thema codes
-                        if subject.subject_type != SubjectType::THEMA {
-                            write_element_block("Subject", w, |w| {
-                                XmlElement::<Onix3GoogleBooks>::xml_element(
-                                    &subject.subject_type,
-                                    w,
-                                )?;
-                                write_element_block("SubjectCode", w, |w| {
-                                    w.write(XmlEvent::Characters(&subject.subject_code))
-                                        .map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                    }
-                    write_element_block("Audience", w, |w| {
-                        // 01 ONIX audience codes
-                        write_element_block("AudienceCodeType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        // 06 Professional and scholarly
-                        write_element_block("AudienceCodeValue", w, |w| {
-                            w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                        })
-                    })
-                })?;
-                if self
-                    .abstracts
-                    .iter()
-                    .any(|a| a.abstract_type == AbstractType::LONG && a.canonical)
-                    || self.toc.is_some()
-                {
-                    write_element_block("CollateralDetail", w, |w| {
-                        if let Some(r#abstract) = &self
-                            .abstracts
-                            .iter()
-                            .find(|a| a.abstract_type == AbstractType::LONG && a.canonical)
-                        {
-                            write_element_block("TextContent", w, |w| {
-                                // 03 Description ("30 Abstract" not implemented in Google Books)
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                {
-                                    let api_locale: ApiLocaleCode =
-                                        r#abstract.locale_code.clone().into();
-                                    let lang_code: ApiLanguageCode = api_locale.into();
-                                    let iso_code = lang_code.to_string().to_lowercase();
-
-                                    write_full_element_block(
-                                        "Text",
-                                        Some(vec![("language", &iso_code), ("textformat", "03")]),
-                                        w,
-                                        |w| {
-                                            w.write(XmlEvent::Characters(&r#abstract.content))
-                                                .map_err(|e| e.into())
-                                        },
-                                    )
-                                }
-                            })?;
-                        }
-                        if let Some(toc) = &self.toc {
-                            write_element_block("TextContent", w, |w| {
-                                // 04 Table of contents
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("04")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                write_full_element_block(
-                                    "Text",
-                                    Some(vec![("language", "eng")]),
-                                    w,
-                                    |w| w.write(XmlEvent::Characters(toc)).map_err(|e| e.into()),
-                                )
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                }
-                // Google Books also supports <ContentDetail> blocks for chapter information.
-                // Omitted at present but could be considered as a future enhancement.
-                write_element_block("PublishingDetail", w, |w| {
-                    write_element_block("Imprint", w, |w| {
-                        write_element_block("ImprintName", w, |w| {
-                            w.write(XmlEvent::Characters(&self.imprint.imprint_name))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                    write_element_block("Publisher", w, |w| {
-                        // 01 Publisher
-                        write_element_block("PublishingRole", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("PublisherName", w, |w| {
-                            w.write(XmlEvent::Characters(&self.imprint.publisher.publisher_name))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                    XmlElement::<Onix3GoogleBooks>::xml_element(&self.work_status, w)?;
-                    write_element_block("PublishingDate", w, |w| {
-                        write_element_block("PublishingDateRole", w, |w| {
-                            // 01 Publishing Date (19 not supported by Google Books)
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        // dateformat="00" YYYYMMDD
-                        write_full_element_block("Date", Some(vec![("dateformat", "00")]), w, |w| {
-                            w.write(XmlEvent::Characters(
-                                &self.publication_date.unwrap().format("%Y%m%d").to_string(),
-                            ))
-                            .map_err(|e| e.into())
-                        })
-                    })?;
-                    if let Some(date) = &self.withdrawn_date {
-                        write_element_block("PublishingDate", w, |w| {
-                            write_element_block("PublishingDateRole", w, |w| {
-                                // 13 Out-of-print / permanently withdrawn date
-                                w.write(XmlEvent::Characters("13")).map_err(|e| e.into())
-                            })?;
-                            // dateformat="00" YYYYMMDD
-                            write_full_element_block(
-                                "Date",
-                                Some(vec![("dateformat", "00")]),
-                                w,
-                                |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &date.format("%Y%m%d").to_string(),
-                                    ))
-                                    .map_err(|e| e.into())
-                                },
-                            )
-                        })?;
-                    }
-                    write_element_block("SalesRights", w, |w| {
-                        // 02 For sale with non-exclusive rights in the specified countries or territories
-                        write_element_block("SalesRightsType", w, |w| {
-                            w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                            })
-                        })
-                    })
-                })?;
-                if !isbns.is_empty() {
-                    write_element_block("RelatedMaterial", w, |w| {
-                        for isbn in &isbns {
-                            write_element_block("RelatedProduct", w, |w| {
-                                // 06 Alternative format
-                                write_element_block("ProductRelationCode", w, |w| {
-                                    w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("ProductIdentifier", w, |w| {
-                                    // 15 ISBN-13
-                                    write_element_block("ProductIDType", w, |w| {
-                                        w.write(XmlEvent::Characters("15")).map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("IDValue", w, |w| {
-                                        w.write(XmlEvent::Characters(isbn)).map_err(|e| e.into())
-                                    })
-                                })
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                }
-                write_element_block("ProductSupply", w, |w| {
-                    write_element_block("Market", w, |w| {
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                            })
-                        })
-                    })?;
-                    write_element_block("SupplyDetail", w, |w| {
-                        write_element_block("Supplier", w, |w| {
-                            // 09 Publisher to end-customers
-                            write_element_block("SupplierRole", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("SupplierName", w, |w| {
-                                w.write(XmlEvent::Characters(
-                                    &self.imprint.publisher.publisher_name,
-                                ))
-                                .map_err(|e| e.into())
-                            })
-                        })?;
-                        // 20 Available from us (form of availability unspecified)
-                        // (99 Contact supplier is not supported by Google Books)
-                        write_element_block("ProductAvailability", w, |w| {
-                            w.write(XmlEvent::Characters("20")).map_err(|e| e.into())
-                        })?;
-                        // Assume that the GBP price is the canonical one, currency conversion is
-                        // turned on (a Google Books account setting which cannot be specified in the ONIX),
-                        // and all other prices will be automatically derived from the GBP price.
-                        if let Some(price) = main_publication
-                            .prices
-                            .iter()
-                            .find(|pr| {
-                                // Thoth database only accepts non-zero prices
-                                pr.currency_code.eq(&CurrencyCode::GBP)
-                            })
-                            .map(|pr| pr.unit_price)
-                        {
-                            let formatted_price = format!("{price:.2}");
-                            write_element_block("Price", w, |w| {
-                                // 02 RRP including tax
-                                write_element_block("PriceType", w, |w| {
-                                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("PriceAmount", w, |w| {
-                                    w.write(XmlEvent::Characters(&formatted_price))
-                                        .map_err(|e| e.into())
-                                })?;
-                                write_element_block("CurrencyCode", w, |w| {
-                                    w.write(XmlEvent::Characters("GBP")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("Territory", w, |w| {
-                                    write_element_block("RegionsIncluded", w, |w| {
-                                        w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                                    })
-                                })
-                            })
-                        } else {
-                            // 01 Free of charge (this is the only UnpricedItemType code supported by Google Books)
-                            write_element_block("UnpricedItemType", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })
-                        }
-                    })
-                })
-            })
-        } else {
-            Err(ThothError::IncompleteMetadataRecord(
-                ONIX_ERROR.to_string(),
-                "Missing EPUB or PDF URL".to_string(),
-            ))
-        }
-    }
-}
-
-fn get_publications_data(
-    publications: &[WorkPublications],
-    main_publication: &WorkPublications,
-) -> (String, Vec<String>) {
-    let mut main_isbn = "".to_string();
-    let mut isbns: Vec<String> = Vec::new();
-
-    for publication in publications {
-        if let Some(isbn) = &publication.isbn.as_ref() {
-            isbns.push(isbn.to_hyphenless_string());
-            // The default product ISBN is the main publication's (EPUB or PDF)
-            if publication
-                .publication_id
-                .eq(&main_publication.publication_id)
-            {
-                main_isbn = isbn.to_hyphenless_string();
-            }
-            // If the main publication has no ISBN, use either the PDF's or the paperback's
-            // (no guarantee as to which will be chosen)
-            if (publication.publication_type.eq(&PublicationType::PDF)
-                || publication.publication_type.eq(&PublicationType::PAPERBACK))
-                && main_isbn.is_empty()
-            {
-                main_isbn = isbn.to_hyphenless_string();
-            }
-        }
-    }
-
-    (main_isbn, isbns)
-}
-
-impl XmlElement<Onix3GoogleBooks> for WorkStatus {
-    const ELEMENT: &'static str = "PublishingStatus";
-
-    fn value(&self) -> &'static str {
-        match self {
-            WorkStatus::CANCELLED => "01",
-            WorkStatus::FORTHCOMING => "02",
-            WorkStatus::POSTPONED_INDEFINITELY => "03",
-            WorkStatus::ACTIVE => "04",
-            WorkStatus::SUPERSEDED => "08",
-            WorkStatus::WITHDRAWN => "11",
-            WorkStatus::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix3GoogleBooks> for SubjectType {
-    const ELEMENT: &'static str = "SubjectSchemeIdentifier";
-
-    fn value(&self) -> &'static str {
-        match self {
-            SubjectType::BIC => "12",
-            SubjectType::BISAC => "10",
-            SubjectType::LCC => "04",
-            // 23 Publisher's own category code
-            SubjectType::KEYWORD | SubjectType::CUSTOM => "23",
-            // Thema codes are not output for Google Books
-            SubjectType::THEMA | SubjectType::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix3GoogleBooks> for LanguageRelation {
-    const ELEMENT: &'static str = "LanguageRole";
-
-    fn value(&self) -> &'static str {
-        match self {
-            LanguageRelation::ORIGINAL => "01",
-            LanguageRelation::TRANSLATED_FROM => "02",
-            LanguageRelation::TRANSLATED_INTO => "01",
-            LanguageRelation::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix3GoogleBooks> for ContributionType {
-    const ELEMENT: &'static str = "ContributorRole";
-
-    fn value(&self) -> &'static str {
-        match self {
-            ContributionType::AUTHOR => "A01",
-            ContributionType::EDITOR => "B01",
-            ContributionType::TRANSLATOR => "B06",
-            ContributionType::PHOTOGRAPHER => "A13",
-            ContributionType::ILLUSTRATOR => "A12",
-            ContributionType::FOREWORD_BY => "A23",
-            ContributionType::INTRODUCTION_BY => "A24",
-            ContributionType::AFTERWORD_BY => "A19",
-            ContributionType::PREFACE_BY => "A15",
-            ContributionType::CONTRIBUTIONS_BY => "A32",
-            // B25, A30, A34, and A51 are not output for Google Books
-            ContributionType::SOFTWARE_BY
-            | ContributionType::RESEARCH_BY
-            | ContributionType::INDEXER
-            | ContributionType::MUSIC_EDITOR
-            | ContributionType::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElementBlock<Onix3GoogleBooks> for WorkContributions {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Contributor", w, |w| {
-            write_element_block("SequenceNumber", w, |w| {
-                w.write(XmlEvent::Characters(&self.contribution_ordinal.to_string()))
-                    .map_err(|e| e.into())
-            })?;
-            XmlElement::<Onix3GoogleBooks>::xml_element(&self.contribution_type, w)?;
-            write_element_block("PersonName", w, |w| {
-                w.write(XmlEvent::Characters(&self.full_name))
-                    .map_err(|e| e.into())
-            })?;
-            if !&self.biographies.is_empty() {
-                let biography = &self.biographies[0].content.clone();
-                write_element_block("BiographicalNote", w, |w| {
-                    w.write(XmlEvent::Characters(biography))
-                        .map_err(|e| e.into())
-                })?;
-            }
-            Ok(())
-        })
-    }
-}
+const PROFILE: Onix3Profile = Onix3Profile {
+    name: "onix_3.0::google_books",
+    namespace: ONIX3_NS,
+    header: Header {
+        addressee: Some("Google"),
+        sent_date_format: "%Y%m%dT%H%M%S",
+    },
+    requirements: &[
+        // Google Books can only ingest works which have at least one BIC, BISAC or LCC
+        // subject code (given or derived from Thema)
+        Requirement::SubjectScheme(&[SubjectType::BIC, SubjectType::BISAC, SubjectType::LCC]),
+        // Mandatory in Google Books
+        Requirement::PublicationDate,
+        // At least one required for Google Books
+        Requirement::Contributors,
+    ],
+    // For preference, distribute the EPUB only
+    products: Products::Distributed(&[PublicationType::EPUB, PublicationType::PDF]),
+    identifiers: Identifiers::Isbn {
+        // Google Books requires at least one ProductIdentifier block with an ISBN type
+        required: true,
+    },
+    descriptive: Descriptive {
+        measures: false,
+        epub_license: None,
+        collections: Some(Collections {
+            issn: true,
+            proprietary_identifiers: false,
+            sequence_number: false,
+            collection_sequence: false,
+        }),
+        titles: Titles::First,
+        contributors: Contributors {
+            role: onix_contributor_role,
+            // Google Books doesn't support B25, A30, A34 or A51 codes
+            // (or any appropriate "Other" code)
+            excluded: &[
+                ContributionType::SOFTWARE_BY,
+                ContributionType::RESEARCH_BY,
+                ContributionType::INDEXER,
+                ContributionType::MUSIC_EDITOR,
+            ],
+            // Google Books requires at least one contributor coded as A01 (Author) -
+            // if this is e.g. a wholly edited book, code the first main contributor as an author.
+            require_author: true,
+            detail: ContributorDetail::Biography,
+        },
+        edition: None,
+        ancillary_content: false,
+        subjects: Subjects {
+            crosswalk: true,
+            scheme: subject_scheme,
+            headings: &[],
+            combine_keywords: false,
+            main_subject: false,
+        },
+        default_audience: true,
+    },
+    collateral: Collateral {
+        descriptions: Descriptions::Description,
+        table_of_contents: true,
+        toc_language: Some("eng"),
+        open_access_statement: None,
+        general_note: false,
+        cover: false,
+        cover_caption: false,
+        supplementary: false,
+    },
+    // Google Books also supports <ContentDetail> blocks for chapter information.
+    // Omitted at present but could be considered as a future enhancement.
+    chapters: None,
+    publishing: Publishing {
+        websites: false,
+        funders: None,
+        city_of_publication: false,
+        // 01 Publishing Date (19 not supported by Google Books)
+        publication_date_role: "01",
+        publication_year_only: false,
+        copyright_year: false,
+        copyright_holders: false,
+        sales_rights: true,
+    },
+    related_material: RelatedMaterial::AllIsbns,
+    supply: Supply {
+        world_market: true,
+        suppliers: Suppliers::Publisher,
+        // 20 Available from us (form of availability unspecified)
+        // (99 Contact supplier is not supported by Google Books)
+        availability: Availability::Fixed("20"),
+        supply_date_role: None,
+        // Assume that the GBP price is the canonical one, currency conversion is
+        // turned on (a Google Books account setting which cannot be specified in the ONIX),
+        // and all other prices will be automatically derived from the GBP price.
+        prices: PriceRule::Currency(CurrencyCode::GBP),
+    },
+};
 
-impl XmlElementBlock<Onix3GoogleBooks> for WorkLanguages {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Language", w, |w| {
-            XmlElement::<Onix3GoogleBooks>::xml_element(&self.language_relation, w).ok();
-            // not worth implementing XmlElement for LanguageCode as all cases would
-            // need to be exhaustively matched and the codes are equivalent anyway
-            write_element_block("LanguageCode", w, |w| {
-                w.write(XmlEvent::Characters(
-                    &self.language_code.to_string().to_lowercase(),
-                ))
-                .map_err(|e| e.into())
-            })
-        })
-    }
-}
+onix3_profile!(Onix3GoogleBooks, PROFILE);
 
-impl XmlElementBlock<Onix3GoogleBooks> for WorkIssues {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Collection", w, |w| {
-            // 10 Publisher collection (e.g. series)
-            write_element_block("CollectionType", w, |w| {
-                w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
-            })?;
-            if let Some(issn_digital) = &self.series.issn_digital {
-                write_element_block("CollectionIdentifier", w, |w| {
-                    // 02 ISSN
-                    write_element_block("CollectionIDType", w, |w| {
-                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            &issn_digital.as_str().replace('-', ""),
-                        ))
-                        .map_err(|e| e.into())
-                    })
-                })?;
-            }
-            write_element_block("TitleDetail", w, |w| {
-                // 01 Cover title (serial)
-                write_element_block("TitleType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                })?;
-                write_element_block("TitleElement", w, |w| {
-                    // 02 Collection level
-                    write_element_block("TitleElementLevel", w, |w| {
-                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                    })?;
-                    if let Some(issue_number) = &self.issue_number {
-                        write_element_block("PartNumber", w, |w| {
-                            w.write(XmlEvent::Characters(&issue_number.to_string()))
-                                .map_err(|e| e.into())
-                        })?;
-                    }
-                    write_element_block("TitleText", w, |w| {
-                        w.write(XmlEvent::Characters(&self.series.series_name))
-                            .map_err(|e| e.into())
-                    })
-                })
-            })
-        })
+fn subject_scheme(subject_type: &SubjectType) -> Option<&'static str> {
+    match subject_type {
+        // 23 Publisher's own category code
+        SubjectType::KEYWORD | SubjectType::CUSTOM => Some("23"),
+        // Google Books doesn't support Thema codes
+        SubjectType::THEMA => None,
+        _ => onix_subject_scheme(subject_type),
     }
 }
 
@@ -775,17 +125,21 @@ mod tests {
     // Testing note: XML nodes cannot be guaranteed to be output in the same order every time
     // We therefore rely on `assert!(contains)` rather than `assert_eq!`
     use super::*;
+    use crate::xml::XmlElementBlock;
     use std::str::FromStr;
     use thoth_api::model::Doi;
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_client::WorkContributionsBiographies;
     use thoth_client::{
-        ContributionType, LanguageCode, LanguageRelation, LocationPlatform, PublicationType,
-        WorkContributionsContributor, WorkImprint, WorkImprintPublisher,
-        WorkImprintPublisherContacts, WorkIssuesSeries, WorkPublicationsLocations,
-        WorkPublicationsPrices, WorkStatus, WorkSubjects, WorkType,
+        AccessibilityException, AccessibilityStandard, ContactType, ContributionType, CurrencyCode,
+        LanguageCode, LanguageRelation, LocationPlatform, PriceType, PublicationType, SubjectType,
+        Work, WorkContributions, WorkContributionsContributor, WorkImprint, WorkImprintPublisher,
+        WorkImprintPublisherContacts, WorkIssues, WorkIssuesSeries, WorkLanguages,
+        WorkPublications, WorkPublicationsLocations, WorkPublicationsPrices, WorkStatus,
+        WorkSubjects, WorkType,
     };
+    use thoth_errors::ThothError;
     use uuid::Uuid;
 
     fn generate_test_output(
@@ -828,9 +182,12 @@ mod tests {
                 locale_code: thoth_client::LocaleCode::EN,
             }],
             contribution_ordinal: 1,
+            name_variants: vec![],
+            credit_roles: vec![],
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: None,
+                name_variants: vec![],
             },
             affiliations: vec![],
         };
@@ -1034,9 +391,12 @@ mod tests {
                     main_contribution: false,
                     biographies: vec![],
                     contribution_ordinal: 1,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -1048,9 +408,12 @@ mod tests {
                     main_contribution: true,
                     biographies: vec![],
                     contribution_ordinal: 2,
+                    name_variants: vec![],
+                    credit_roles: vec![],
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        name_variants: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -1079,14 +442,29 @@ mod tests {
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::EUR,
                         unit_price: 5.95,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::USD,
                         unit_price: 7.99,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::GBP,
                         unit_price: 5.0,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                 ],
                 locations: vec![WorkPublicationsLocations {
@@ -1095,6 +473,11 @@ mod tests {
                     location_platform: LocationPlatform::OTHER,
                     canonical: true,
                 }],
+                sales_rights: vec![],
+                product_form_details: vec![],
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
             }],
             subjects: vec![
                 WorkSubjects {
@@ -1128,6 +511,13 @@ mod tests {
                     subject_ordinal: 6,
                 },
             ],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![],
             relations: vec![],
             references: vec![],
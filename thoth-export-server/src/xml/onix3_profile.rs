@@ -0,0 +1,3010 @@
+//! ONIX 3 records generated from declarative [`Onix3Profile`]s.
+//!
+//! The Thoth exports and the distribution platforms differ in which works they accept, which
+//! publications they output and which parts of the record they carry, but otherwise share the
+//! same record structure. Each specification therefore only declares its profile, and
+//! [`onix3_profile!`] wires it up to the writers in this module.
+
+use cc_license::License;
+use chrono::{NaiveDate, Utc};
+use std::collections::HashMap;
+use std::io::Write;
+use thoth_api::model::additional_resource::ResourceType;
+use thoth_api::model::language::LanguageCode as ApiLanguageCode;
+use thoth_api::model::locale::LocaleCode as ApiLocaleCode;
+use thoth_api::model::{CountryCode as ApiCountryCode, IdentifierWithDomain, Orcid, Ror};
+use thoth_client::{
+    AbstractType, AccessibilityException, AccessibilityStandard, AwardRole, ContactType,
+    ContributionType, CurrencyCode, LanguageCode, LanguageRelation, LocaleCode, LocationPlatform,
+    NameVariant, NameVariantType, PublicationType, RelationType, SubjectType, Work,
+    WorkAdditionalResources, WorkAwards, WorkBookReviews, WorkContributions, WorkEndorsements,
+    WorkFeaturedVideo, WorkFundings, WorkIssues, WorkLanguages, WorkPublications,
+    WorkPublicationsLocations, WorkReferences, WorkRelations, WorkRelationsRelatedWork,
+    WorkRelationsRelatedWorkContributions, WorkStatus, WorkSubjects, WorkType,
+};
+use thoth_errors::{ThothError, ThothResult};
+use xml::writer::{EventWriter, XmlEvent};
+
+use super::{
+    current_and_future_prices, current_prices, subjects_with_crosswalk, write_element_block,
+    write_full_element_block, write_onix3_audiences, write_onix3_price,
+    write_onix3_product_form_details, write_onix3_product_form_features, write_onix3_sales_rights,
+    write_onix3_work_dates, TitleData,
+};
+use crate::access::PublicationAccessFields;
+
+/// Implement `XmlSpecification` for a specification, and `XmlElementBlock` for the work and
+/// its parts, by delegating to the writers of its [`Onix3Profile`]
+macro_rules! onix3_profile {
+    ($specification:ty, $profile:expr) => {
+        impl $crate::xml::XmlSpecification for $specification {
+            fn handle_event<W: std::io::Write>(
+                w: &mut xml::writer::EventWriter<W>,
+                works: &[thoth_client::Work],
+            ) -> thoth_errors::ThothResult<()> {
+                $profile.write_message(w, works)
+            }
+        }
+
+        $crate::xml::onix3_profile::onix3_profile!(
+            @blocks $specification, $profile,
+            thoth_client::Work => write_product,
+            thoth_client::WorkContributions => write_contributor,
+            thoth_client::WorkLanguages => write_language,
+            thoth_client::WorkIssues => write_collection,
+            thoth_client::WorkFundings => write_funder,
+            thoth_client::WorkSubjects => write_subject,
+            thoth_client::WorkReferences => write_reference,
+            thoth_client::WorkRelations => write_relation,
+            thoth_client::WorkBookReviews => write_book_review,
+            thoth_client::WorkEndorsements => write_endorsement,
+            thoth_client::WorkAdditionalResources => write_additional_resource,
+            thoth_client::WorkFeaturedVideo => write_featured_video,
+            thoth_client::WorkAwards => write_prize,
+        );
+    };
+    (@blocks $specification:ty, $profile:expr, $($block:ty => $writer:ident,)*) => {
+        $(
+            impl $crate::xml::XmlElementBlock<$specification> for $block {
+                fn xml_element<W: std::io::Write>(
+                    &self,
+                    w: &mut xml::writer::EventWriter<W>,
+                ) -> thoth_errors::ThothResult<()> {
+                    $profile.$writer(self, w)
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use onix3_profile;
+
+/// What a specification expects of an ONIX 3 record
+pub(crate) struct Onix3Profile {
+    /// Specification ID, reported in errors
+    pub name: &'static str,
+    /// Attributes of `<ONIXMessage>`, selecting the ONIX release
+    pub namespace: &'static [(&'static str, &'static str)],
+    pub header: Header,
+    /// Conditions every work must meet to be output, checked in order
+    pub requirements: &'static [Requirement],
+    pub products: Products,
+    pub identifiers: Identifiers,
+    pub descriptive: Descriptive,
+    pub collateral: Collateral,
+    /// Chapters with a DOI, if output as content items
+    pub chapters: Option<Chapters>,
+    pub publishing: Publishing,
+    pub related_material: RelatedMaterial,
+    pub supply: Supply,
+}
+
+pub(crate) struct Header {
+    /// Name of the recipient, if the platform expects an `<Addressee>`
+    pub addressee: Option<&'static str>,
+    /// Format of `<SentDateTime>`
+    pub sent_date_format: &'static str,
+}
+
+/// Metadata without which a platform rejects a record
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Requirement {
+    /// A subject in one of these schemes, given or derived from Thema
+    SubjectScheme(&'static [SubjectType]),
+    License,
+    PublicationDate,
+    Contributors,
+    LongAbstract,
+    Languages,
+}
+
+/// Which publications of a work are output as products
+pub(crate) enum Products {
+    /// A single product, identified by the work ID: the first publication of one of these
+    /// types, in order of preference, with a canonical full text URL
+    Distributed(&'static [PublicationType]),
+    /// One product per publication, identified by the publication ID
+    EveryPublication,
+}
+
+pub(crate) enum Identifiers {
+    /// Only the product's ISBN, optionally rejecting works whose product has none
+    Isbn { required: bool },
+    /// Output `<RecordSourceType>` and identify the product by its work ID and DOI as well
+    /// as its ISBN
+    Proprietary,
+    /// Output `<RecordSourceType>` and every identifier Thoth records: work and publication
+    /// IDs, ISBN and GTIN-13, DOI, LCCN, OCLC number and internal reference
+    Complete,
+}
+
+pub(crate) struct Descriptive {
+    /// Give the height, width, thickness and weight of the publication
+    pub measures: bool,
+    /// Licence of the product, if output
+    pub epub_license: Option<EpubLicense>,
+    /// Series the work is part of, if output
+    pub collections: Option<Collections>,
+    pub titles: Titles,
+    pub contributors: Contributors,
+    pub edition: Option<Edition>,
+    /// Give the bibliography note and the number of images, tables, audio and video items
+    pub ancillary_content: bool,
+    pub subjects: Subjects,
+    /// Assume a professional and scholarly audience for works without any
+    pub default_audience: bool,
+}
+
+pub(crate) enum EpubLicense {
+    /// The work's licence, as a Creative Commons licence
+    CreativeCommons,
+    /// The licence of publications which are open access, named after the licence it links to
+    Open,
+    /// The licence the publication declares, along with the date it applies from if embargoed
+    Declared,
+}
+
+pub(crate) struct Collections {
+    /// Identify the series by its digital ISSN
+    pub issn: bool,
+    /// Also identify the series by its Thoth ID, URL and call for proposals URL
+    pub proprietary_identifiers: bool,
+    /// Give the position of the work within the series in its title
+    pub sequence_number: bool,
+    /// Give the position of the work within the series as a collection sequence
+    pub collection_sequence: bool,
+}
+
+pub(crate) enum Titles {
+    /// The work's first title
+    First,
+    /// The canonical title, then the title in every other language, each tagged with its
+    /// language
+    Translated {
+        /// Tag the canonical title with its locale code rather than its ISO 639 language code
+        canonical_locale: bool,
+    },
+}
+
+pub(crate) struct Contributors {
+    /// Contributor role (code list 17) of each contribution type
+    pub role: fn(&ContributionType) -> &'static str,
+    /// Contribution types which are not output
+    pub excluded: &'static [ContributionType],
+    /// Code the first main contributor as an author if there is no author
+    pub require_author: bool,
+    pub detail: ContributorDetail,
+}
+
+pub(crate) enum ContributorDetail {
+    /// The full name and biography
+    Biography,
+    /// The ORCID and structured name, or the full name if there is no first name
+    Name,
+    /// The ORCID (without hyphens), full and structured names, affiliations, biography and
+    /// website
+    Complete {
+        /// Also give the contributor's alternative names
+        alternative_names: bool,
+        /// Identify affiliated institutions by their ROR ID
+        affiliation_ror: bool,
+    },
+}
+
+pub(crate) enum Edition {
+    /// Spell out the edition, e.g. "Second edition."
+    Statement,
+    /// The edition number, for second and subsequent editions only
+    Number,
+}
+
+pub(crate) struct Subjects {
+    /// Add the BIC and BISAC codes derived from Thema codes
+    pub crosswalk: bool,
+    /// Subject scheme identifier (code list 26) of each subject type, if output
+    pub scheme: fn(&SubjectType) -> Option<&'static str>,
+    /// Subject types output as `<SubjectHeadingText>` rather than `<SubjectCode>`
+    pub headings: &'static [SubjectType],
+    /// Output all keywords as a single subject heading, separated by semicolons
+    pub combine_keywords: bool,
+    /// Mark the first subject of each type with ordinal 1 as the main subject
+    pub main_subject: bool,
+}
+
+pub(crate) struct Collateral {
+    pub descriptions: Descriptions,
+    pub table_of_contents: bool,
+    /// Language of the table of contents, if given
+    pub toc_language: Option<&'static str>,
+    pub open_access_statement: Option<OpenAccessStatement>,
+    /// Output the general note as a publisher's notice
+    pub general_note: bool,
+    pub cover: bool,
+    pub cover_caption: bool,
+    /// Output book reviews, endorsements, additional resources, the featured video and prizes
+    pub supplementary: bool,
+}
+
+pub(crate) enum Descriptions {
+    /// The canonical long abstract as the description, tagged with its language
+    Description,
+    /// The short abstract as the short description, and the long abstract as both the
+    /// description and the abstract
+    Complete,
+}
+
+pub(crate) enum OpenAccessStatement {
+    /// For works with a licence
+    Licensed,
+    /// For publications which are open access, in English
+    Open,
+}
+
+pub(crate) enum Chapters {
+    /// Chapters identified by their DOI, along with their position and pages
+    Listed,
+    /// Also each chapter's licence, titles, contributors, languages, abstracts, notes,
+    /// copyright holders and references
+    Described,
+}
+
+pub(crate) struct Publishing {
+    /// Identify the imprint by its URL, and link to the publisher's home page and the work's
+    /// landing page
+    pub websites: bool,
+    /// Output funders as publishers with the funding body role, if at all
+    pub funders: Option<Funders>,
+    pub city_of_publication: bool,
+    /// Publishing date role (code list 163) of the publication date
+    pub publication_date_role: &'static str,
+    /// Only give the year of publication
+    pub publication_year_only: bool,
+    pub copyright_year: bool,
+    pub copyright_holders: bool,
+    pub sales_rights: bool,
+}
+
+pub(crate) enum Funders {
+    /// The funder's name
+    Named,
+    /// Also the funding programme, project name and grant number
+    Funded,
+    /// Also the funder's ROR ID and FundRef DOI, and the project's short name
+    Complete,
+}
+
+pub(crate) enum RelatedMaterial {
+    /// Every ISBN of the work, as alternative formats
+    AllIsbns,
+    /// The print ISBN (hardback, else paperback) the product is based on
+    PrintIsbn,
+    /// Translations and other related works with a DOI, every other ISBN of the work, and
+    /// the works it cites
+    Complete,
+}
+
+pub(crate) struct Supply {
+    /// Declare a worldwide market
+    pub world_market: bool,
+    pub suppliers: Suppliers,
+    pub availability: Availability,
+    /// Supply date role (code list 166) of the publication date, if given
+    pub supply_date_role: Option<&'static str>,
+    pub prices: PriceRule,
+}
+
+pub(crate) enum Suppliers {
+    /// A single supply detail for the publisher
+    Publisher,
+    /// One supply detail per publisher website: the product's full text URL and the work's
+    /// landing page
+    PublisherWebsites,
+    /// One supply detail per location of the publication, or the publisher's website if it
+    /// has none
+    Locations,
+}
+
+pub(crate) enum Availability {
+    /// Product availability (code list 65)
+    Fixed(&'static str),
+    /// Derived from the work status
+    WorkStatus,
+}
+
+pub(crate) enum PriceRule {
+    /// Current and future prices, or free of charge for open access works and works without
+    /// prices
+    All,
+    /// Current and future prices, or free of charge for publications without prices
+    Listed,
+    /// The current price in the given currency, or free of charge if there is none
+    Currency(CurrencyCode),
+    /// The current price in the given currency, without which publications are not distributed
+    RequiredCurrency(CurrencyCode),
+    /// Never priced, with the given unpriced item type (code list 57)
+    Unpriced(&'static str),
+}
+
+/// Subject scheme identifier (code list 26) of each subject type
+pub(crate) fn onix_subject_scheme(subject_type: &SubjectType) -> Option<&'static str> {
+    match subject_type {
+        SubjectType::BIC => Some("12"),
+        SubjectType::BISAC => Some("10"),
+        SubjectType::KEYWORD => Some("20"),
+        SubjectType::LCC => Some("04"),
+        SubjectType::THEMA => Some("93"),
+        SubjectType::CUSTOM => Some("B2"),
+        SubjectType::Other(_) => unreachable!(),
+    }
+}
+
+/// Contributor role (code list 17) of each contribution type
+pub(crate) fn onix_contributor_role(contribution_type: &ContributionType) -> &'static str {
+    contribution_type.role_mapping().onix_role_code
+}
+
+impl Requirement {
+    fn check(&self, work: &Work) -> Result<(), String> {
+        let (met, error) = match self {
+            Requirement::SubjectScheme(schemes) => (
+                subjects_with_crosswalk(&work.subjects)
+                    .iter()
+                    .any(|s| schemes.contains(&s.subject_type)),
+                format!("No {} subject code", alternatives(schemes)),
+            ),
+            Requirement::License => (work.license.is_some(), "Missing License".to_string()),
+            Requirement::PublicationDate => (
+                work.publication_date.is_some(),
+                "Missing Publication Date".to_string(),
+            ),
+            Requirement::Contributors => (
+                !work.contributions.is_empty(),
+                "No contributors supplied".to_string(),
+            ),
+            Requirement::LongAbstract => (
+                work.abstracts
+                    .iter()
+                    .any(|a| a.abstract_type == AbstractType::LONG && a.canonical),
+                "Missing Long Abstract".to_string(),
+            ),
+            Requirement::Languages => (
+                !work.languages.is_empty(),
+                "Missing Language Code(s)".to_string(),
+            ),
+        };
+        if met {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+}
+
+/// List items as alternatives, e.g. "BIC, BISAC or LCC"
+fn alternatives(items: &[impl ToString]) -> String {
+    let items: Vec<String> = items.iter().map(ToString::to_string).collect();
+    match items.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+        None => String::new(),
+    }
+}
+
+/// A publication output as a product, along with its work
+struct Product<'a> {
+    work: &'a Work,
+    publication: &'a WorkPublications,
+    record_reference: String,
+    /// The product's ISBN, empty if there is none
+    isbn: String,
+    /// Every ISBN of the work
+    isbns: &'a [String],
+    /// Canonical full text URL of the distributed publication
+    full_text_url: Option<&'a str>,
+}
+
+/// A contributor to the work or to one of its chapters, which the API returns as different
+/// types
+struct Contributor<'a> {
+    ordinal: i64,
+    contribution_type: &'a ContributionType,
+    orcid: Option<&'a Orcid>,
+    full_name: &'a str,
+    first_name: Option<&'a str>,
+    last_name: &'a str,
+    name_variants: Vec<&'a NameVariant>,
+    /// Position, ROR ID and name of each affiliation
+    affiliations: Vec<(Option<&'a str>, Option<&'a Ror>, &'a str)>,
+    biography: Option<&'a str>,
+    website: Option<&'a str>,
+}
+
+impl<'a> From<&'a WorkContributions> for Contributor<'a> {
+    fn from(contribution: &'a WorkContributions) -> Self {
+        Contributor {
+            ordinal: contribution.contribution_ordinal,
+            contribution_type: &contribution.contribution_type,
+            orcid: contribution.contributor.orcid.as_ref(),
+            full_name: &contribution.full_name,
+            first_name: contribution.first_name.as_deref(),
+            last_name: &contribution.last_name,
+            name_variants: contribution
+                .contributor
+                .name_variants
+                .iter()
+                .chain(contribution.name_variants.iter())
+                .collect(),
+            affiliations: contribution
+                .affiliations
+                .iter()
+                .map(|a| {
+                    (
+                        a.position.as_deref(),
+                        a.institution.ror.as_ref(),
+                        a.institution.institution_name.as_str(),
+                    )
+                })
+                .collect(),
+            biography: contribution.biographies.first().map(|b| b.content.as_str()),
+            website: contribution.contributor.website.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a WorkRelationsRelatedWorkContributions> for Contributor<'a> {
+    fn from(contribution: &'a WorkRelationsRelatedWorkContributions) -> Self {
+        Contributor {
+            ordinal: contribution.contribution_ordinal,
+            contribution_type: &contribution.contribution_type,
+            orcid: contribution.contributor.orcid.as_ref(),
+            full_name: &contribution.full_name,
+            first_name: contribution.first_name.as_deref(),
+            last_name: &contribution.last_name,
+            name_variants: vec![],
+            affiliations: contribution
+                .affiliations
+                .iter()
+                .map(|a| {
+                    (
+                        a.position.as_deref(),
+                        a.institution.ror.as_ref(),
+                        a.institution.institution_name.as_str(),
+                    )
+                })
+                .collect(),
+            biography: contribution.biographies.first().map(|b| b.content.as_str()),
+            website: contribution.contributor.website.as_deref(),
+        }
+    }
+}
+
+/// Who supplies the product, and where
+struct Supplier<'a> {
+    /// Supplier role (code list 93)
+    role: &'a str,
+    name: &'a str,
+    /// Website role (code list 73), description and link of each website
+    websites: Vec<(&'a str, String, &'a str)>,
+}
+
+impl Onix3Profile {
+    fn error(&self, message: impl Into<String>) -> ThothError {
+        ThothError::IncompleteMetadataRecord(self.name.to_string(), message.into())
+    }
+
+    pub(crate) fn write_message<W: Write>(
+        &self,
+        w: &mut EventWriter<W>,
+        works: &[Work],
+    ) -> ThothResult<()> {
+        write_full_element_block("ONIXMessage", Some(self.namespace.to_vec()), w, |w| {
+            write_element_block("Header", w, |w| {
+                write_element_block("Sender", w, |w| {
+                    write_element_block("SenderName", w, |w| {
+                        w.write(XmlEvent::Characters("Thoth")).map_err(Into::into)
+                    })?;
+                    write_element_block("EmailAddress", w, |w| {
+                        w.write(XmlEvent::Characters("distribution@thoth.pub"))
+                            .map_err(Into::into)
+                    })
+                })?;
+                if let Some(addressee) = self.header.addressee {
+                    write_element_block("Addressee", w, |w| {
+                        write_element_block("AddresseeName", w, |w| {
+                            w.write(XmlEvent::Characters(addressee)).map_err(Into::into)
+                        })
+                    })?;
+                }
+                write_element_block("SentDateTime", w, |w| {
+                    w.write(XmlEvent::Characters(
+                        &Utc::now().format(self.header.sent_date_format).to_string(),
+                    ))
+                    .map_err(Into::into)
+                })
+            })?;
+
+            match works {
+                [] => Err(self.error("Not enough data")),
+                [work] => self.write_product(work, w),
+                _ => {
+                    for work in works.iter() {
+                        // Do not include Chapters in full publisher metadata record
+                        // (assumes that a publisher will always have more than one work)
+                        if work.work_type != WorkType::BOOK_CHAPTER {
+                            self.write_product(work, w).ok();
+                        }
+                    }
+                    Ok(())
+                }
+            }
+        })
+    }
+
+    /// The publication distributed as the product, along with its full text URL
+    fn main_publication<'a>(
+        &self,
+        work: &'a Work,
+        publication_types: &[PublicationType],
+    ) -> Option<(&'a WorkPublications, &'a String)> {
+        publication_types.iter().find_map(|publication_type| {
+            work.publications
+                .iter()
+                .filter(|p| p.publication_type.eq(publication_type))
+                .filter(|p| match self.supply.prices {
+                    // Thoth database only accepts non-zero prices
+                    PriceRule::RequiredCurrency(_) => !p.prices.is_empty(),
+                    _ => true,
+                })
+                .find_map(|p| {
+                    p.locations
+                        .iter()
+                        .find(|l| l.canonical)
+                        .and_then(|l| l.full_text_url.as_ref())
+                        .map(|url| (p, url))
+                })
+        })
+    }
+
+    fn missing_publication_error(&self, publication_types: &[PublicationType]) -> ThothError {
+        let publication_types = alternatives(publication_types);
+        match self.supply.prices {
+            PriceRule::RequiredCurrency(_) => {
+                self.error(format!("No priced {publication_types} URL"))
+            }
+            _ => self.error(format!("Missing {publication_types} URL")),
+        }
+    }
+
+    pub(crate) fn write_product<W: Write>(
+        &self,
+        work: &Work,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        for requirement in self.requirements {
+            requirement.check(work).map_err(|e| self.error(e))?;
+        }
+        match self.products {
+            Products::Distributed(publication_types) => {
+                let (publication, full_text_url) =
+                    self.main_publication(work, publication_types)
+                        .ok_or_else(|| self.missing_publication_error(publication_types))?;
+                let (isbn, isbns) = get_publications_data(&work.publications, publication);
+                if matches!(self.identifiers, Identifiers::Isbn { required: true })
+                    && isbn.is_empty()
+                {
+                    return Err(self.error("This work does not have a PDF, EPUB or paperback ISBN"));
+                }
+                let product = Product {
+                    work,
+                    publication,
+                    record_reference: format!("urn:uuid:{}", work.work_id),
+                    isbn,
+                    isbns: &isbns,
+                    full_text_url: Some(full_text_url),
+                };
+                self.write_publication(&product, w)
+            }
+            Products::EveryPublication => {
+                if work.publications.is_empty() {
+                    return Err(self.error("No publications supplied"));
+                }
+                let isbns: Vec<String> = work
+                    .publications
+                    .iter()
+                    .filter_map(|p| p.isbn.as_ref())
+                    .map(|i| i.to_hyphenless_string())
+                    .collect();
+                for publication in &work.publications {
+                    let product = Product {
+                        work,
+                        publication,
+                        // Note that most platform records use the Work ID, not Publication ID,
+                        // as they output one record per Work rather than one per Publication
+                        record_reference: format!("urn:uuid:{}", publication.publication_id),
+                        isbn: publication
+                            .isbn
+                            .as_ref()
+                            .map(|i| i.to_hyphenless_string())
+                            .unwrap_or_default(),
+                        isbns: &isbns,
+                        full_text_url: None,
+                    };
+                    self.write_publication(&product, w)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn write_publication<W: Write>(
+        &self,
+        product: &Product,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let subjects = match self.descriptive.subjects.crosswalk {
+            true => subjects_with_crosswalk(&product.work.subjects),
+            false => product.work.subjects.clone(),
+        };
+        write_element_block("Product", w, |w| {
+            write_element_block("RecordReference", w, |w| {
+                w.write(XmlEvent::Characters(&product.record_reference))
+                    .map_err(Into::into)
+            })?;
+            // 03 Notification confirmed on publication
+            write_element_block("NotificationType", w, |w| {
+                w.write(XmlEvent::Characters("03")).map_err(Into::into)
+            })?;
+            self.write_product_identifiers(product, w)?;
+            self.write_descriptive_detail(product, &subjects, w)?;
+            self.write_collateral_detail(product, w)?;
+            if let Some(chapters) = &self.chapters {
+                self.write_content_detail(chapters, product.work, w)?;
+            }
+            self.write_publishing_detail(product, w)?;
+            self.write_related_material(product, w)?;
+            self.write_product_supply(product, w)
+        })
+    }
+
+    fn write_product_identifiers<W: Write>(
+        &self,
+        product: &Product,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let work = product.work;
+        let work_id = format!("urn:uuid:{}", work.work_id);
+        if !matches!(self.identifiers, Identifiers::Isbn { .. }) {
+            // 01 Publisher
+            write_element_block("RecordSourceType", w, |w| {
+                w.write(XmlEvent::Characters("01")).map_err(Into::into)
+            })?;
+        }
+        match self.identifiers {
+            Identifiers::Isbn { .. } => {
+                // 15 ISBN-13
+                write_product_identifier("15", None, &product.isbn, w)
+            }
+            Identifiers::Proprietary => {
+                // 01 Proprietary
+                write_product_identifier("01", None, &work_id, w)?;
+                // 15 ISBN-13
+                write_product_identifier("15", None, &product.isbn, w)?;
+                if let Some(doi) = &work.doi {
+                    // 06 DOI
+                    write_product_identifier("06", None, &doi.to_string(), w)?;
+                }
+                Ok(())
+            }
+            Identifiers::Complete => {
+                // 01 Proprietary
+                write_product_identifier("01", Some("thoth-work-id"), &work_id, w)?;
+                write_product_identifier(
+                    "01",
+                    Some("thoth-publication-id"),
+                    &format!("urn:uuid:{}", product.publication.publication_id),
+                    w,
+                )?;
+                if !product.isbn.is_empty() {
+                    // 15 ISBN-13
+                    write_product_identifier("15", None, &product.isbn, w)?;
+                    // 03 GTIN-13
+                    write_product_identifier("03", None, &product.isbn, w)?;
+                }
+                if let Some(doi) = &work.doi {
+                    // 06 DOI
+                    write_product_identifier("06", None, &doi.to_string(), w)?;
+                }
+                if let Some(lccn) = &work.lccn {
+                    // 13 LCCN
+                    write_product_identifier("13", None, lccn, w)?;
+                }
+                if let Some(oclc) = &work.oclc {
+                    // 23 OCLC number
+                    write_product_identifier("23", None, oclc, w)?;
+                }
+                if let Some(reference) = &work.reference {
+                    // 01 Proprietary
+                    write_product_identifier("01", Some("internal-reference"), reference, w)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn write_descriptive_detail<W: Write>(
+        &self,
+        product: &Product,
+        subjects: &[WorkSubjects],
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let descriptive = &self.descriptive;
+        let (work, publication) = (product.work, product.publication);
+        write_element_block("DescriptiveDetail", w, |w| {
+            // 00 Single-component retail product
+            write_element_block("ProductComposition", w, |w| {
+                w.write(XmlEvent::Characters("00")).map_err(Into::into)
+            })?;
+            let (form, form_detail) = product_form_codes(&publication.publication_type);
+            write_element_block("ProductForm", w, |w| {
+                w.write(XmlEvent::Characters(form)).map_err(Into::into)
+            })?;
+            if let Some(code) = form_detail {
+                write_element_block("ProductFormDetail", w, |w| {
+                    w.write(XmlEvent::Characters(code)).map_err(Into::into)
+                })?;
+            }
+            write_onix3_product_form_details(&publication.product_form_details, form_detail, w)?;
+            write_accessibility_features(work, publication, w)?;
+            write_onix3_product_form_features(&publication.product_form_details, w)?;
+            // 10 Text (eye-readable)
+            write_element_block("PrimaryContentType", w, |w| {
+                w.write(XmlEvent::Characters("10")).map_err(Into::into)
+            })?;
+            if descriptive.measures {
+                write_measures(publication, w)?;
+            }
+            if let Some(epub_license) = &descriptive.epub_license {
+                write_product_license(epub_license, product, w)?;
+            }
+            if descriptive.collections.is_some() {
+                for issue in &work.issues {
+                    self.write_collection(issue, w).ok();
+                }
+            }
+            self.write_titles(&work.titles, w)?;
+            for contribution in &self.contributions(work) {
+                self.write_contributor(contribution, w).ok();
+            }
+            if let Some(edition) = work.edition {
+                match descriptive.edition {
+                    Some(Edition::Statement) => {
+                        write_element_block("EditionStatement", w, |w| {
+                            w.write(XmlEvent::Characters(&edition_statement(edition)))
+                                .map_err(Into::into)
+                        })?;
+                    }
+                    // "Normally sent only for the second and subsequent editions"
+                    Some(Edition::Number) if edition > 1 => {
+                        write_element_block("EditionNumber", w, |w| {
+                            w.write(XmlEvent::Characters(&edition.to_string()))
+                                .map_err(Into::into)
+                        })?;
+                    }
+                    _ => {}
+                }
+            }
+            for language in &work.languages {
+                self.write_language(language, w).ok();
+            }
+            if let Some(page_count) = work.page_count {
+                write_element_block("Extent", w, |w| {
+                    // 00 Main content
+                    write_element_block("ExtentType", w, |w| {
+                        w.write(XmlEvent::Characters("00")).map_err(Into::into)
+                    })?;
+                    write_element_block("ExtentValue", w, |w| {
+                        w.write(XmlEvent::Characters(&page_count.to_string()))
+                            .map_err(Into::into)
+                    })?;
+                    // 03 Pages
+                    write_element_block("ExtentUnit", w, |w| {
+                        w.write(XmlEvent::Characters("03")).map_err(Into::into)
+                    })
+                })?;
+            }
+            if descriptive.ancillary_content {
+                write_ancillary_content(work, w)?;
+            }
+            self.write_subjects(subjects, w)?;
+            if descriptive.default_audience || !work.audiences.is_empty() {
+                write_onix3_audiences(&work.audiences, w)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Contributions to output, in order
+    fn contributions(&self, work: &Work) -> Vec<WorkContributions> {
+        let contributors = &self.descriptive.contributors;
+        let mut contributions = work.contributions.clone();
+        if contributors.require_author
+            && !contributions
+                .iter()
+                .any(|c| c.contribution_type.eq(&ContributionType::AUTHOR))
+        {
+            // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
+            contributions.sort_by_key(|c| c.contribution_ordinal);
+            contributions.sort_by_key(|c| std::cmp::Reverse(c.main_contribution));
+            if let Some(first) = contributions.first_mut() {
+                first.contribution_type = ContributionType::AUTHOR;
+            }
+        }
+        contributions.retain(|c| !contributors.excluded.contains(&c.contribution_type));
+        contributions
+    }
+
+    fn write_titles<W: Write, T: TitleData>(
+        &self,
+        titles: &[T],
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        match self.descriptive.titles {
+            Titles::First => {
+                if let Some(title) = titles.first() {
+                    // 01 Distinctive title (book)
+                    write_title_detail("01", title, None, w)?;
+                }
+            }
+            Titles::Translated { canonical_locale } => {
+                if let Some(title) = titles.iter().find(|t| t.canonical()) {
+                    let language = match canonical_locale {
+                        true => title.locale_code().to_string(),
+                        false => iso_language_code(title.locale_code()),
+                    };
+                    // 01 Distinctive title (book)
+                    write_title_detail("01", title, Some(&language), w)?;
+                }
+                for title in titles.iter().filter(|t| !t.canonical()) {
+                    let language = iso_language_code(title.locale_code());
+                    // 06 Title in another language
+                    write_title_detail("06", title, Some(&language), w)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_subjects<W: Write>(
+        &self,
+        subjects: &[WorkSubjects],
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let options = &self.descriptive.subjects;
+        let mut main_subject_found = vec![];
+        let mut keywords = vec![];
+        for subject in subjects {
+            // Best practice is to include only a single subject tag
+            // for keywords, listing all of them separated by semicolons
+            if options.combine_keywords && subject.subject_type == SubjectType::KEYWORD {
+                keywords.push(subject.subject_code.as_str());
+                continue;
+            }
+            if (options.scheme)(&subject.subject_type).is_none() {
+                continue;
+            }
+            // One subject within every subject type can/should be marked as Main
+            // Use first one found with ordinal 1 (there may be multiple)
+            let is_main_subject = options.main_subject
+                && subject.subject_ordinal == 1
+                && !main_subject_found.contains(&subject.subject_type);
+            self.write_subject_heading(
+                &subject.subject_type,
+                &subject.subject_code,
+                is_main_subject,
+                w,
+            )?;
+            if is_main_subject {
+                main_subject_found.push(subject.subject_type.clone());
+            }
+        }
+        if !keywords.is_empty() {
+            self.write_subject_heading(&SubjectType::KEYWORD, &keywords.join("; "), false, w)?;
+        }
+        Ok(())
+    }
+
+    fn write_collateral_detail<W: Write>(
+        &self,
+        product: &Product,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let collateral = &self.collateral;
+        let work = product.work;
+        let (short_abstract, long_abstract) = match collateral.descriptions {
+            Descriptions::Description => (
+                None,
+                work.abstracts
+                    .iter()
+                    .find(|a| a.abstract_type == AbstractType::LONG && a.canonical),
+            ),
+            Descriptions::Complete => (
+                work.abstracts
+                    .iter()
+                    .find(|a| a.abstract_type == AbstractType::SHORT),
+                work.abstracts
+                    .iter()
+                    .find(|a| a.abstract_type == AbstractType::LONG),
+            ),
+        };
+        let toc = work.toc.as_ref().filter(|_| collateral.table_of_contents);
+        let open_access = match collateral.open_access_statement {
+            Some(OpenAccessStatement::Licensed) => work.license.is_some(),
+            Some(OpenAccessStatement::Open) => {
+                product.publication.access(work.license.as_ref()).is_open()
+            }
+            None => false,
+        };
+        let general_note = work
+            .general_note
+            .as_ref()
+            .filter(|_| collateral.general_note);
+        let cover_url = work.cover_url.as_ref().filter(|_| collateral.cover);
+        let supplementary = collateral.supplementary
+            && (work.book_reviews.iter().any(|r| r.text.is_some())
+                || work.endorsements.iter().any(|e| e.text.is_some())
+                || work
+                    .additional_resources
+                    .iter()
+                    .any(|r| r.file.is_some() || r.url.is_some() || r.doi.is_some())
+                || work
+                    .featured_video
+                    .as_ref()
+                    .is_some_and(|v| v.file.is_some() || v.url.is_some())
+                || !work.awards.is_empty());
+        if short_abstract.is_none()
+            && long_abstract.is_none()
+            && toc.is_none()
+            && !open_access
+            && general_note.is_none()
+            && cover_url.is_none()
+            && !supplementary
+        {
+            return Ok(());
+        }
+        write_element_block("CollateralDetail", w, |w| {
+            if let Some(r#abstract) = short_abstract {
+                write_short_description(&r#abstract.content, w)?;
+            }
+            if let Some(r#abstract) = long_abstract {
+                match collateral.descriptions {
+                    Descriptions::Description => {
+                        let language = iso_language_code(&r#abstract.locale_code);
+                        // 03 Description ("30 Abstract" is not implemented by most platforms)
+                        write_text_content(
+                            "03",
+                            Some(vec![("language", &language), ("textformat", "03")]),
+                            &r#abstract.content,
+                            w,
+                        )?;
+                    }
+                    Descriptions::Complete => write_long_description(&r#abstract.content, w)?,
+                }
+            }
+            if let Some(toc) = toc {
+                // 04 Table of contents
+                write_text_content(
+                    "04",
+                    collateral
+                        .toc_language
+                        .map(|language| vec![("language", language)]),
+                    toc,
+                    w,
+                )?;
+            }
+            if open_access {
+                let language = match collateral.open_access_statement {
+                    Some(OpenAccessStatement::Open) => Some("eng"),
+                    _ => None,
+                };
+                write_open_access_statement(language, w)?;
+            }
+            if let Some(general_note) = general_note {
+                write_general_note(general_note, w)?;
+            }
+            if collateral.supplementary {
+                for book_review in &work.book_reviews {
+                    self.write_book_review(book_review, w)?;
+                }
+                for endorsement in &work.endorsements {
+                    self.write_endorsement(endorsement, w)?;
+                }
+            }
+            if let Some(cover_url) = cover_url {
+                write_element_block("SupportingResource", w, |w| {
+                    // 01 Front cover
+                    write_element_block("ResourceContentType", w, |w| {
+                        w.write(XmlEvent::Characters("01")).map_err(Into::into)
+                    })?;
+                    // 00 Unrestricted
+                    write_element_block("ContentAudience", w, |w| {
+                        w.write(XmlEvent::Characters("00")).map_err(Into::into)
+                    })?;
+                    // 03 Image
+                    write_element_block("ResourceMode", w, |w| {
+                        w.write(XmlEvent::Characters("03")).map_err(Into::into)
+                    })?;
+                    if let Some(cover_caption) = work
+                        .cover_caption
+                        .as_ref()
+                        .filter(|_| collateral.cover_caption)
+                    {
+                        // 02 Caption
+                        write_resource_feature("02", cover_caption, w)?;
+                    }
+                    write_element_block("ResourceVersion", w, |w| {
+                        // 02 Downloadable file
+                        write_element_block("ResourceForm", w, |w| {
+                            w.write(XmlEvent::Characters("02")).map_err(Into::into)
+                        })?;
+                        write_element_block("ResourceLink", w, |w| {
+                            w.write(XmlEvent::Characters(cover_url)).map_err(Into::into)
+                        })
+                    })
+                })?;
+            }
+            if collateral.supplementary {
+                for additional_resource in &work.additional_resources {
+                    self.write_additional_resource(additional_resource, w)?;
+                }
+                if let Some(featured_video) = &work.featured_video {
+                    self.write_featured_video(featured_video, w)?;
+                }
+                for award in &work.awards {
+                    self.write_prize(award, w)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    fn write_content_detail<W: Write>(
+        &self,
+        chapters: &Chapters,
+        work: &Work,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let chapter_relations: Vec<&WorkRelations> = work
+            .relations
+            .iter()
+            .filter(|r| r.relation_type == RelationType::HAS_CHILD && r.related_work.doi.is_some())
+            .collect();
+        if chapter_relations.is_empty() {
+            return Ok(());
+        }
+        write_element_block("ContentDetail", w, |w| {
+            for relation in &chapter_relations {
+                let chapter = &relation.related_work;
+                write_element_block("ContentItem", w, |w| {
+                    write_element_block("LevelSequenceNumber", w, |w| {
+                        w.write(XmlEvent::Characters(&relation.relation_ordinal.to_string()))
+                            .map_err(Into::into)
+                    })?;
+                    write_element_block("TextItem", w, |w| {
+                        // 03 Body matter
+                        write_element_block("TextItemType", w, |w| {
+                            w.write(XmlEvent::Characters("03")).map_err(Into::into)
+                        })?;
+                        write_element_block("TextItemIdentifier", w, |w| {
+                            // 06 DOI
+                            write_element_block("TextItemIDType", w, |w| {
+                                w.write(XmlEvent::Characters("06")).map_err(Into::into)
+                            })?;
+                            write_element_block("IDValue", w, |w| {
+                                w.write(XmlEvent::Characters(
+                                    &chapter.doi.as_ref().unwrap().to_string(),
+                                ))
+                                .map_err(Into::into)
+                            })
+                        })
+                    })?;
+                    if let Some(first_page) = &chapter.first_page {
+                        write_element_block("PageRun", w, |w| {
+                            write_element_block("FirstPageNumber", w, |w| {
+                                w.write(XmlEvent::Characters(first_page))
+                                    .map_err(Into::into)
+                            })?;
+                            if let Some(last_page) = &chapter.last_page {
+                                write_element_block("LastPageNumber", w, |w| {
+                                    w.write(XmlEvent::Characters(last_page)).map_err(Into::into)
+                                })?;
+                            }
+                            Ok(())
+                        })?;
+                    }
+                    if let Some(page_count) = &chapter.page_count {
+                        write_element_block("NumberOfPages", w, |w| {
+                            w.write(XmlEvent::Characters(&page_count.to_string()))
+                                .map_err(Into::into)
+                        })?;
+                    }
+                    if let Chapters::Described = chapters {
+                        self.write_chapter_description(chapter, w)?;
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Write the metadata of a chapter which `<ContentItem>` shares with `<Product>`
+    fn write_chapter_description<W: Write>(
+        &self,
+        chapter: &WorkRelationsRelatedWork,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        if let Some(license) = &chapter.license {
+            write_epub_license(&license_name(license), license, None, w)?;
+        }
+        write_element_block("ComponentTypeName", w, |w| {
+            w.write(XmlEvent::Characters("Chapter")).map_err(Into::into)
+        })?;
+        self.write_titles(&chapter.titles, w)?;
+        for contribution in &chapter.contributions {
+            self.write_contributor_detail(&Contributor::from(contribution), w)
+                .ok();
+        }
+        for language in &chapter.languages {
+            write_language_code(&language.language_code, &language.language_relation, w).ok();
+        }
+        if let Some(r#abstract) = chapter
+            .abstracts
+            .iter()
+            .find(|a| a.abstract_type == AbstractType::SHORT)
+        {
+            write_short_description(&r#abstract.content, w)?;
+        }
+        if let Some(r#abstract) = chapter
+            .abstracts
+            .iter()
+            .find(|a| a.abstract_type == AbstractType::LONG)
+        {
+            write_long_description(&r#abstract.content, w)?;
+        }
+        if chapter.license.is_some() {
+            write_open_access_statement(Some("eng"), w)?;
+        }
+        if let Some(general_note) = &chapter.general_note {
+            write_general_note(general_note, w)?;
+        }
+        write_copyright_holders(chapter.copyright_holder.as_deref(), w)?;
+        for reference in &chapter.references {
+            self.write_reference(reference, w).ok();
+        }
+        Ok(())
+    }
+
+    fn write_publishing_detail<W: Write>(
+        &self,
+        product: &Product,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let publishing = &self.publishing;
+        let work = product.work;
+        write_element_block("PublishingDetail", w, |w| {
+            write_element_block("Imprint", w, |w| {
+                if let Some(url) = work
+                    .imprint
+                    .imprint_url
+                    .as_ref()
+                    .filter(|_| publishing.websites)
+                {
+                    write_element_block("ImprintIdentifier", w, |w| {
+                        // 01 Proprietary
+                        write_element_block("ImprintIDType", w, |w| {
+                            w.write(XmlEvent::Characters("01")).map_err(Into::into)
+                        })?;
+                        write_element_block("IDTypeName", w, |w| {
+                            w.write(XmlEvent::Characters("URL")).map_err(Into::into)
+                        })?;
+                        write_element_block("IDValue", w, |w| {
+                            w.write(XmlEvent::Characters(url)).map_err(Into::into)
+                        })
+                    })?;
+                }
+                write_element_block("ImprintName", w, |w| {
+                    w.write(XmlEvent::Characters(&work.imprint.imprint_name))
+                        .map_err(Into::into)
+                })
+            })?;
+            write_element_block("Publisher", w, |w| {
+                // 01 Publisher
+                write_element_block("PublishingRole", w, |w| {
+                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
+                })?;
+                write_element_block("PublisherName", w, |w| {
+                    w.write(XmlEvent::Characters(&work.imprint.publisher.publisher_name))
+                        .map_err(Into::into)
+                })?;
+                if publishing.websites {
+                    if let Some(url) = &work.imprint.publisher.publisher_url {
+                        // 01 Publisher's corporate website
+                        write_website("01", "Publisher's website: home page", url, w)?;
+                    }
+                    if let Some(url) = &work.landing_page {
+                        // 02 Publisher's website for a specified work
+                        write_website("02", "Publisher's website: webpage for this title", url, w)?;
+                    }
+                }
+                Ok(())
+            })?;
+            if publishing.funders.is_some() {
+                for funding in &work.fundings {
+                    self.write_funder(funding, w).ok();
+                }
+            }
+            if publishing.city_of_publication {
+                if let Some(place) = &work.place {
+                    write_element_block("CityOfPublication", w, |w| {
+                        w.write(XmlEvent::Characters(place)).map_err(Into::into)
+                    })?;
+                }
+            }
+            write_element_block("PublishingStatus", w, |w| {
+                w.write(XmlEvent::Characters(onix_publishing_status(
+                    &work.work_status,
+                )))
+                .map_err(Into::into)
+            })?;
+            if let Some(date) = work.publication_date {
+                let (dateformat, format) = match publishing.publication_year_only {
+                    // dateformat="05" YYYY
+                    true => ("05", "%Y"),
+                    // dateformat="00" YYYYMMDD
+                    false => ("00", "%Y%m%d"),
+                };
+                write_element_block("PublishingDate", w, |w| {
+                    write_element_block("PublishingDateRole", w, |w| {
+                        w.write(XmlEvent::Characters(publishing.publication_date_role))
+                            .map_err(Into::into)
+                    })?;
+                    write_full_element_block(
+                        "Date",
+                        Some(vec![("dateformat", dateformat)]),
+                        w,
+                        |w| {
+                            w.write(XmlEvent::Characters(&date.format(format).to_string()))
+                                .map_err(Into::into)
+                        },
+                    )
+                })?;
+                if publishing.copyright_year {
+                    write_element_block("CopyrightStatement", w, |w| {
+                        write_element_block("CopyrightYear", w, |w| {
+                            w.write(XmlEvent::Characters(&date.format("%Y").to_string()))
+                                .map_err(Into::into)
+                        })
+                    })?;
+                }
+            }
+            if let Some(date) = &work.withdrawn_date {
+                write_element_block("PublishingDate", w, |w| {
+                    write_element_block("PublishingDateRole", w, |w| {
+                        // 13 Out-of-print / permanently withdrawn date
+                        w.write(XmlEvent::Characters("13")).map_err(Into::into)
+                    })?;
+                    // dateformat="00" YYYYMMDD
+                    write_full_element_block("Date", Some(vec![("dateformat", "00")]), w, |w| {
+                        w.write(XmlEvent::Characters(&date.format("%Y%m%d").to_string()))
+                            .map_err(Into::into)
+                    })
+                })?;
+            }
+            write_onix3_work_dates(work, w)?;
+            if publishing.copyright_holders {
+                write_copyright_holders(work.copyright_holder.as_deref(), w)?;
+            }
+            if publishing.sales_rights {
+                write_onix3_sales_rights(&product.publication.sales_rights, w)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn write_related_material<W: Write>(
+        &self,
+        product: &Product,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let work = product.work;
+        let (isbns, relations, references): (Vec<(&str, String)>, Vec<&WorkRelations>, &[_]) =
+            match self.related_material {
+                // 06 Alternative format
+                RelatedMaterial::AllIsbns => (
+                    product
+                        .isbns
+                        .iter()
+                        .map(|isbn| ("06", isbn.clone()))
+                        .collect(),
+                    vec![],
+                    &[],
+                ),
+                // 13 Epublication based on (print product)
+                RelatedMaterial::PrintIsbn => (
+                    print_isbn(&work.publications)
+                        .map(|isbn| ("13", isbn))
+                        .into_iter()
+                        .collect(),
+                    vec![],
+                    &[],
+                ),
+                RelatedMaterial::Complete => (
+                    // Only output ISBNs which don't relate to the current publication
+                    product
+                        .isbns
+                        .iter()
+                        .filter(|isbn| !isbn.eq(&&product.isbn))
+                        // 06 Alternative format
+                        .map(|isbn| ("06", isbn.clone()))
+                        .collect(),
+                    work.relations
+                        .iter()
+                        .filter(|r| {
+                            r.relation_type != RelationType::HAS_CHILD
+                                && r.relation_type != RelationType::IS_CHILD_OF
+                                && r.related_work.doi.is_some()
+                        })
+                        .collect(),
+                    &work.references,
+                ),
+            };
+        if isbns.is_empty() && relations.is_empty() && references.is_empty() {
+            return Ok(());
+        }
+        let gtin = matches!(self.related_material, RelatedMaterial::Complete);
+        write_element_block("RelatedMaterial", w, |w| {
+            // RelatedWorks should be listed before RelatedProducts
+            for relation in relations.iter().filter(|r| is_translation(r)) {
+                self.write_relation(relation, w).ok();
+            }
+            for relation in relations.iter().filter(|r| !is_translation(r)) {
+                self.write_relation(relation, w).ok();
+            }
+            for (relation_code, isbn) in &isbns {
+                write_element_block("RelatedProduct", w, |w| {
+                    write_element_block("ProductRelationCode", w, |w| {
+                        w.write(XmlEvent::Characters(relation_code))
+                            .map_err(Into::into)
+                    })?;
+                    // 15 ISBN-13
+                    write_product_identifier("15", None, isbn, w)?;
+                    if gtin {
+                        // 03 GTIN-13
+                        write_product_identifier("03", None, isbn, w)?;
+                    }
+                    Ok(())
+                })?;
+            }
+            for reference in references {
+                self.write_reference(reference, w).ok();
+            }
+            Ok(())
+        })
+    }
+
+    fn write_product_supply<W: Write>(
+        &self,
+        product: &Product,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let supply = &self.supply;
+        let work = product.work;
+        let publisher_name = work.imprint.publisher.publisher_name.as_str();
+        write_element_block("ProductSupply", w, |w| {
+            if supply.world_market {
+                write_element_block("Market", w, |w| {
+                    write_element_block("Territory", w, |w| {
+                        write_element_block("RegionsIncluded", w, |w| {
+                            w.write(XmlEvent::Characters("WORLD")).map_err(Into::into)
+                        })
+                    })
+                })?;
+            }
+            match supply.suppliers {
+                Suppliers::Publisher => {
+                    let supplier = Supplier {
+                        // 09 Publisher to end-customers
+                        role: "09",
+                        name: publisher_name,
+                        websites: vec![],
+                    };
+                    self.write_supply_detail(product, &supplier, w)
+                }
+                Suppliers::PublisherWebsites => {
+                    let mut websites: HashMap<&str, (&str, &str)> = HashMap::new();
+                    if let Some(full_text_url) = product.full_text_url {
+                        // 29 Web page for full content
+                        websites.insert(
+                            full_text_url,
+                            ("29", "Publisher's website: download the title"),
+                        );
+                    }
+                    if let Some(landing_page) = &work.landing_page {
+                        // 02 Publisher's website for a specified work
+                        websites.insert(landing_page, ("02", "Publisher's website: web shop"));
+                    }
+                    for (url, (role, description)) in websites {
+                        let supplier = Supplier {
+                            // 09 Publisher to end-customers
+                            role: "09",
+                            name: publisher_name,
+                            websites: vec![(role, description.to_string(), url)],
+                        };
+                        self.write_supply_detail(product, &supplier, w)?;
+                    }
+                    Ok(())
+                }
+                Suppliers::Locations => {
+                    let mut locations = product.publication.locations.clone();
+                    if locations.is_empty() {
+                        // Create single Supplier based on Work Landing Page
+                        // so that we can output a SupplyDetail block (inc. Price info)
+                        // (if Landing Page is None, Supplier will be complete apart from Website)
+                        locations = vec![WorkPublicationsLocations {
+                            landing_page: work.landing_page.clone(),
+                            full_text_url: None,
+                            location_platform: LocationPlatform::PUBLISHER_WEBSITE,
+                            canonical: true,
+                        }];
+                    }
+                    for location in &locations {
+                        let platform = location.location_platform.to_string();
+                        let (name, description, role, landing_page_role) =
+                            match location.location_platform {
+                                LocationPlatform::PUBLISHER_WEBSITE => (
+                                    publisher_name,
+                                    "Publisher's website",
+                                    // 09 Publisher to end-customers
+                                    "09",
+                                    // 02 Publisher's website for a specified work
+                                    "02",
+                                ),
+                                LocationPlatform::OTHER => (
+                                    "Unknown",
+                                    "Unspecified hosting platform",
+                                    // 11 Non-exclusive distributor to end-customers
+                                    "11",
+                                    // 36 Supplier's website for a specified work
+                                    "36",
+                                ),
+                                _ => (
+                                    platform.as_str(),
+                                    platform.as_str(),
+                                    // 11 Non-exclusive distributor to end-customers
+                                    "11",
+                                    // 36 Supplier's website for a specified work
+                                    "36",
+                                ),
+                            };
+                        let mut websites = vec![];
+                        if let Some(url) = &location.landing_page {
+                            websites.push((
+                                landing_page_role,
+                                format!("{description}: webpage for this product"),
+                                url.as_str(),
+                            ));
+                        }
+                        if let Some(url) = &location.full_text_url {
+                            // 29 Web page for full content
+                            websites.push((
+                                "29",
+                                format!("{description}: download the title"),
+                                url.as_str(),
+                            ));
+                        }
+                        let supplier = Supplier {
+                            role,
+                            name,
+                            websites,
+                        };
+                        self.write_supply_detail(product, &supplier, w)?;
+                    }
+                    Ok(())
+                }
+            }
+        })
+    }
+
+    fn write_supply_detail<W: Write>(
+        &self,
+        product: &Product,
+        supplier: &Supplier,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let work = product.work;
+        write_element_block("SupplyDetail", w, |w| {
+            write_element_block("Supplier", w, |w| {
+                write_element_block("SupplierRole", w, |w| {
+                    w.write(XmlEvent::Characters(supplier.role))
+                        .map_err(Into::into)
+                })?;
+                write_element_block("SupplierName", w, |w| {
+                    w.write(XmlEvent::Characters(supplier.name))
+                        .map_err(Into::into)
+                })?;
+                for (role, description, url) in &supplier.websites {
+                    write_website(role, description, url, w)?;
+                }
+                Ok(())
+            })?;
+            write_element_block("ProductAvailability", w, |w| {
+                let availability = match self.supply.availability {
+                    Availability::Fixed(code) => code,
+                    Availability::WorkStatus => onix_product_availability(&work.work_status),
+                };
+                w.write(XmlEvent::Characters(availability))
+                    .map_err(Into::into)
+            })?;
+            if let Some(role) = self.supply.supply_date_role {
+                if let Some(date) = work.publication_date {
+                    write_element_block("SupplyDate", w, |w| {
+                        write_element_block("SupplyDateRole", w, |w| {
+                            w.write(XmlEvent::Characters(role)).map_err(Into::into)
+                        })?;
+                        // dateformat="00" YYYYMMDD
+                        write_full_element_block("Date", Some(vec![("dateformat", "00")]), w, |w| {
+                            w.write(XmlEvent::Characters(&date.format("%Y%m%d").to_string()))
+                                .map_err(Into::into)
+                        })
+                    })?;
+                }
+            }
+            self.write_prices(product, w)
+        })
+    }
+
+    fn write_prices<W: Write>(&self, product: &Product, w: &mut EventWriter<W>) -> ThothResult<()> {
+        let publication = product.publication;
+        match &self.supply.prices {
+            PriceRule::All | PriceRule::Listed => {
+                let prices = current_and_future_prices(&publication.prices);
+                let open_access =
+                    matches!(self.supply.prices, PriceRule::All) && product.work.license.is_some();
+                if open_access || prices.is_empty() {
+                    // 01 Free of charge
+                    write_unpriced_item_type("01", w)
+                } else {
+                    for price in prices {
+                        write_onix3_price(&price, w)?;
+                    }
+                    Ok(())
+                }
+            }
+            PriceRule::Currency(currency_code) => {
+                match current_prices(&publication.prices)
+                    .iter()
+                    .find(|pr| pr.currency_code.eq(currency_code))
+                {
+                    Some(price) => write_onix3_price(price, w),
+                    // 01 Free of charge
+                    None => write_unpriced_item_type("01", w),
+                }
+            }
+            PriceRule::RequiredCurrency(currency_code) => {
+                match current_prices(&publication.prices)
+                    .iter()
+                    .find(|pr| pr.currency_code.eq(currency_code))
+                {
+                    Some(price) => write_onix3_price(price, w),
+                    None => Err(self.error(format!("No {currency_code} price found"))),
+                }
+            }
+            PriceRule::Unpriced(code) => write_unpriced_item_type(code, w),
+        }
+    }
+
+    pub(crate) fn write_contributor<W: Write>(
+        &self,
+        contribution: &WorkContributions,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        self.write_contributor_detail(&Contributor::from(contribution), w)
+    }
+
+    fn write_contributor_detail<W: Write>(
+        &self,
+        contributor: &Contributor,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let contributors = &self.descriptive.contributors;
+        write_element_block("Contributor", w, |w| {
+            write_element_block("SequenceNumber", w, |w| {
+                w.write(XmlEvent::Characters(&contributor.ordinal.to_string()))
+                    .map_err(Into::into)
+            })?;
+            write_element_block("ContributorRole", w, |w| {
+                w.write(XmlEvent::Characters((contributors.role)(
+                    contributor.contribution_type,
+                )))
+                .map_err(Into::into)
+            })?;
+            let (alternative_names, affiliation_ror) = match contributors.detail {
+                ContributorDetail::Biography => {
+                    write_element_block("PersonName", w, |w| {
+                        w.write(XmlEvent::Characters(contributor.full_name))
+                            .map_err(Into::into)
+                    })?;
+                    if let Some(biography) = contributor.biography {
+                        write_element_block("BiographicalNote", w, |w| {
+                            w.write(XmlEvent::Characters(biography)).map_err(Into::into)
+                        })?;
+                    }
+                    return Ok(());
+                }
+                ContributorDetail::Name => {
+                    if let Some(orcid) = contributor.orcid {
+                        write_name_identifier(&orcid.to_string(), w)?;
+                    }
+                    if let Some(first_name) = contributor.first_name {
+                        write_element_block("NamesBeforeKey", w, |w| {
+                            w.write(XmlEvent::Characters(first_name))
+                                .map_err(Into::into)
+                        })?;
+                        write_element_block("KeyNames", w, |w| {
+                            w.write(XmlEvent::Characters(contributor.last_name))
+                                .map_err(Into::into)
+                        })?;
+                    } else {
+                        write_element_block("PersonName", w, |w| {
+                            w.write(XmlEvent::Characters(contributor.full_name))
+                                .map_err(Into::into)
+                        })?;
+                    }
+                    return Ok(());
+                }
+                ContributorDetail::Complete {
+                    alternative_names,
+                    affiliation_ror,
+                } => (alternative_names, affiliation_ror),
+            };
+            if let Some(orcid) = contributor.orcid {
+                write_name_identifier(&orcid.to_hyphenless_string(), w)?;
+            }
+            write_element_block("PersonName", w, |w| {
+                w.write(XmlEvent::Characters(contributor.full_name))
+                    .map_err(Into::into)
+            })?;
+            if let Some(first_name) = contributor.first_name {
+                write_element_block("NamesBeforeKey", w, |w| {
+                    w.write(XmlEvent::Characters(first_name))
+                        .map_err(Into::into)
+                })?;
+            }
+            write_element_block("KeyNames", w, |w| {
+                w.write(XmlEvent::Characters(contributor.last_name))
+                    .map_err(Into::into)
+            })?;
+            if alternative_names {
+                for name_variant in &contributor.name_variants {
+                    write_alternative_name(name_variant, w)?;
+                }
+            }
+            for (position, ror, institution_name) in &contributor.affiliations {
+                write_element_block("ProfessionalAffiliation", w, |w| {
+                    if let Some(position) = position {
+                        write_element_block("ProfessionalPosition", w, |w| {
+                            w.write(XmlEvent::Characters(position)).map_err(Into::into)
+                        })?;
+                    }
+                    if let Some(ror) = ror.filter(|_| affiliation_ror) {
+                        write_element_block("AffiliationIdentifier", w, |w| {
+                            // 40: ROR ID
+                            write_element_block("AffiliationIDType", w, |w| {
+                                w.write(XmlEvent::Characters("40")).map_err(Into::into)
+                            })?;
+                            write_element_block("IDValue", w, |w| {
+                                w.write(XmlEvent::Characters(&ror.to_string()))
+                                    .map_err(Into::into)
+                            })
+                        })?;
+                    }
+                    write_element_block("Affiliation", w, |w| {
+                        w.write(XmlEvent::Characters(institution_name))
+                            .map_err(Into::into)
+                    })
+                })?;
+            }
+            if let Some(biography) = contributor.biography {
+                write_element_block("BiographicalNote", w, |w| {
+                    w.write(XmlEvent::Characters(biography)).map_err(Into::into)
+                })?;
+            }
+            if let Some(website) = contributor.website {
+                // 06 Contributor's own website
+                write_website("06", "Own website", website, w)?;
+            }
+            Ok(())
+        })
+    }
+
+    pub(crate) fn write_language<W: Write>(
+        &self,
+        language: &WorkLanguages,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        write_language_code(&language.language_code, &language.language_relation, w)
+    }
+
+    pub(crate) fn write_collection<W: Write>(
+        &self,
+        issue: &WorkIssues,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let Some(collections) = &self.descriptive.collections else {
+            return Ok(());
+        };
+        write_element_block("Collection", w, |w| {
+            // 10 Publisher collection (e.g. series)
+            write_element_block("CollectionType", w, |w| {
+                w.write(XmlEvent::Characters("10")).map_err(Into::into)
+            })?;
+            if collections.proprietary_identifiers {
+                write_collection_identifier(
+                    "01",
+                    Some("Series ID"),
+                    &issue.series.series_id.to_string(),
+                    w,
+                )?;
+            }
+            if collections.issn {
+                if let Some(issn_digital) = &issue.series.issn_digital {
+                    // 02 ISSN
+                    write_collection_identifier(
+                        "02",
+                        None,
+                        &issn_digital.as_str().replace('-', ""),
+                        w,
+                    )?;
+                }
+            }
+            if collections.proprietary_identifiers {
+                if let Some(url) = &issue.series.series_url {
+                    write_collection_identifier("01", Some("Series URL"), url, w)?;
+                }
+                if let Some(url) = &issue.series.series_cfp_url {
+                    write_collection_identifier(
+                        "01",
+                        Some("Series Call for Proposals URL"),
+                        url,
+                        w,
+                    )?;
+                }
+            }
+            if collections.collection_sequence {
+                write_element_block("CollectionSequence", w, |w| {
+                    // 03 Publication order
+                    write_element_block("CollectionSequenceType", w, |w| {
+                        w.write(XmlEvent::Characters("03")).map_err(Into::into)
+                    })?;
+                    write_element_block("CollectionSequenceNumber", w, |w| {
+                        w.write(XmlEvent::Characters(&issue.issue_ordinal.to_string()))
+                            .map_err(Into::into)
+                    })
+                })?;
+            }
+            write_element_block("TitleDetail", w, |w| {
+                // 01 Cover title (serial)
+                write_element_block("TitleType", w, |w| {
+                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
+                })?;
+                write_element_block("TitleElement", w, |w| {
+                    // 02 Collection level
+                    write_element_block("TitleElementLevel", w, |w| {
+                        w.write(XmlEvent::Characters("02")).map_err(Into::into)
+                    })?;
+                    if collections.sequence_number {
+                        write_element_block("SequenceNumber", w, |w| {
+                            w.write(XmlEvent::Characters(&issue.issue_ordinal.to_string()))
+                                .map_err(Into::into)
+                        })?;
+                    }
+                    if let Some(issue_number) = &issue.issue_number {
+                        write_element_block("PartNumber", w, |w| {
+                            w.write(XmlEvent::Characters(&issue_number.to_string()))
+                                .map_err(Into::into)
+                        })?;
+                    }
+                    write_element_block("TitleText", w, |w| {
+                        w.write(XmlEvent::Characters(&issue.series.series_name))
+                            .map_err(Into::into)
+                    })
+                })
+            })
+        })
+    }
+
+    pub(crate) fn write_funder<W: Write>(
+        &self,
+        funding: &WorkFundings,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let complete = matches!(self.publishing.funders, Some(Funders::Complete));
+        write_element_block("Publisher", w, |w| {
+            // 16 Funding body
+            write_element_block("PublishingRole", w, |w| {
+                w.write(XmlEvent::Characters("16")).map_err(Into::into)
+            })?;
+            if complete {
+                if let Some(ror) = &funding.institution.ror {
+                    // 40 ROR
+                    write_publisher_identifier("40", &ror.to_string(), w)?;
+                }
+                if let Some(doi) = &funding.institution.institution_doi {
+                    // 32 FundRef DOI
+                    write_publisher_identifier("32", &doi.to_string(), w)?;
+                }
+            }
+            write_element_block("PublisherName", w, |w| {
+                w.write(XmlEvent::Characters(&funding.institution.institution_name))
+                    .map_err(Into::into)
+            })?;
+            let identifiers: Vec<(&str, Option<&str>)> = match self.publishing.funders {
+                Some(Funders::Funded) => vec![
+                    ("programname", funding.program.as_deref()),
+                    ("projectname", funding.project_name.as_deref()),
+                    ("grantnumber", funding.grant_number.as_deref()),
+                ],
+                Some(Funders::Complete) => vec![
+                    ("programname", funding.program.as_deref()),
+                    ("projectname", funding.project_name.as_deref()),
+                    ("projectshortname", funding.project_shortname.as_deref()),
+                    ("grantnumber", funding.grant_number.as_deref()),
+                ],
+                _ => vec![],
+            };
+            if identifiers.iter().any(|(_, i)| i.is_some()) {
+                write_element_block("Funding", w, |w| {
+                    for (typename, value) in &identifiers {
+                        if let Some(value) = value {
+                            write_element_block("FundingIdentifier", w, |w| {
+                                // 01 Proprietary
+                                write_element_block("FundingIDType", w, |w| {
+                                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
+                                })?;
+                                write_element_block("IDTypeName", w, |w| {
+                                    w.write(XmlEvent::Characters(typename)).map_err(Into::into)
+                                })?;
+                                write_element_block("IDValue", w, |w| {
+                                    w.write(XmlEvent::Characters(value)).map_err(Into::into)
+                                })
+                            })?;
+                        }
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })
+    }
+
+    pub(crate) fn write_subject<W: Write>(
+        &self,
+        subject: &WorkSubjects,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        self.write_subject_heading(&subject.subject_type, &subject.subject_code, false, w)
+    }
+
+    fn write_subject_heading<W: Write>(
+        &self,
+        subject_type: &SubjectType,
+        subject_code: &str,
+        is_main_subject: bool,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let subjects = &self.descriptive.subjects;
+        let Some(scheme) = (subjects.scheme)(subject_type) else {
+            return Ok(());
+        };
+        write_element_block("Subject", w, |w| {
+            if is_main_subject {
+                // MainSubject is an empty element
+                write_element_block("MainSubject", w, |_w| Ok(()))?;
+            }
+            write_element_block("SubjectSchemeIdentifier", w, |w| {
+                w.write(XmlEvent::Characters(scheme)).map_err(Into::into)
+            })?;
+            let element = match subjects.headings.contains(subject_type)
+                || (subjects.combine_keywords && subject_type == &SubjectType::KEYWORD)
+            {
+                true => "SubjectHeadingText",
+                false => "SubjectCode",
+            };
+            write_element_block(element, w, |w| {
+                w.write(XmlEvent::Characters(subject_code))
+                    .map_err(Into::into)
+            })
+        })
+    }
+
+    pub(crate) fn write_reference<W: Write>(
+        &self,
+        reference: &WorkReferences,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        write_element_block("RelatedProduct", w, |w| {
+            // 34 Cites
+            write_element_block("ProductRelationCode", w, |w| {
+                w.write(XmlEvent::Characters("34")).map_err(Into::into)
+            })?;
+            match &reference.doi {
+                // 06 DOI
+                Some(doi) => write_product_identifier("06", None, &doi.to_string(), w),
+                // 01 Proprietary
+                None => write_product_identifier(
+                    "01",
+                    Some("Unstructured citation"),
+                    // Unstructured citation is mandatory in Thoth if DOI is missing
+                    reference.unstructured_citation.as_ref().unwrap(),
+                    w,
+                ),
+            }
+        })
+    }
+
+    /// Write a translation as a related work, and any other relation as a related product.
+    /// Only valid for relations with a DOI other than parent/child relations.
+    pub(crate) fn write_relation<W: Write>(
+        &self,
+        relation: &WorkRelations,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        // Caller must guarantee that DOI is present
+        let doi = relation.related_work.doi.as_ref().unwrap().to_string();
+        if is_translation(relation) {
+            write_element_block("RelatedWork", w, |w| {
+                let work_relation_code = match &relation.relation_type {
+                    // 49 Related work is derived from this via translation
+                    RelationType::HAS_TRANSLATION => "49",
+                    // 29 Derived from via translation
+                    RelationType::IS_TRANSLATION_OF => "29",
+                    _ => unreachable!(),
+                };
+                write_element_block("WorkRelationCode", w, |w| {
+                    w.write(XmlEvent::Characters(work_relation_code))
+                        .map_err(Into::into)
+                })?;
+                write_element_block("WorkIdentifier", w, |w| {
+                    // 06 DOI
+                    write_element_block("WorkIDType", w, |w| {
+                        w.write(XmlEvent::Characters("06")).map_err(Into::into)
+                    })?;
+                    write_element_block("IDValue", w, |w| {
+                        w.write(XmlEvent::Characters(&doi)).map_err(Into::into)
+                    })
+                })
+            })
+        } else {
+            write_element_block("RelatedProduct", w, |w| {
+                let product_relation_code = match &relation.relation_type {
+                    // 01 Includes
+                    RelationType::HAS_PART => "01",
+                    // 02 Is part of
+                    RelationType::IS_PART_OF => "02",
+                    // 03 Replaces
+                    RelationType::REPLACES => "03",
+                    // 05 Replaced by
+                    RelationType::IS_REPLACED_BY => "05",
+                    _ => unreachable!(),
+                };
+                write_element_block("ProductRelationCode", w, |w| {
+                    w.write(XmlEvent::Characters(product_relation_code))
+                        .map_err(Into::into)
+                })?;
+                // 06 DOI
+                write_product_identifier("06", None, &doi, w)
+            })
+        }
+    }
+
+    pub(crate) fn write_book_review<W: Write>(
+        &self,
+        book_review: &WorkBookReviews,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        // reviews without a quotable text are not output
+        let Some(text) = &book_review.text else {
+            return Ok(());
+        };
+        write_element_block("TextContent", w, |w| {
+            // 06 Review quote
+            write_element_block("TextType", w, |w| {
+                w.write(XmlEvent::Characters("06")).map_err(Into::into)
+            })?;
+            // 00 Unrestricted
+            write_element_block("ContentAudience", w, |w| {
+                w.write(XmlEvent::Characters("00")).map_err(Into::into)
+            })?;
+            write_element_block("Text", w, |w| {
+                w.write(XmlEvent::Characters(text)).map_err(Into::into)
+            })?;
+            if let Some(author_name) = &book_review.author_name {
+                write_element_block("TextAuthor", w, |w| {
+                    w.write(XmlEvent::Characters(author_name))
+                        .map_err(Into::into)
+                })?;
+            }
+            if let Some(journal_name) = &book_review.journal_name {
+                write_element_block("SourceTitle", w, |w| {
+                    w.write(XmlEvent::Characters(journal_name))
+                        .map_err(Into::into)
+                })?;
+            }
+            if let Some(review_date) = &book_review.review_date {
+                write_element_block("ContentDate", w, |w| {
+                    // 01 Publication date
+                    write_element_block("ContentDateRole", w, |w| {
+                        w.write(XmlEvent::Characters("01")).map_err(Into::into)
+                    })?;
+                    write_element_block("Date", w, |w| {
+                        w.write(XmlEvent::Characters(
+                            &review_date.format("%Y%m%d").to_string(),
+                        ))
+                        .map_err(Into::into)
+                    })
+                })?;
+            }
+            Ok(())
+        })
+    }
+
+    pub(crate) fn write_endorsement<W: Write>(
+        &self,
+        endorsement: &WorkEndorsements,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let Some(text) = &endorsement.text else {
+            return Ok(());
+        };
+        write_element_block("TextContent", w, |w| {
+            // 09 Endorsement
+            write_element_block("TextType", w, |w| {
+                w.write(XmlEvent::Characters("09")).map_err(Into::into)
+            })?;
+            // 00 Unrestricted
+            write_element_block("ContentAudience", w, |w| {
+                w.write(XmlEvent::Characters("00")).map_err(Into::into)
+            })?;
+            write_element_block("Text", w, |w| {
+                w.write(XmlEvent::Characters(text)).map_err(Into::into)
+            })?;
+            let text_author = match (&endorsement.author_name, &endorsement.author_role) {
+                (Some(name), Some(role)) => Some(format!("{name}, {role}")),
+                (Some(name), None) => Some(name.clone()),
+                (None, _) => None,
+            };
+            if let Some(text_author) = text_author {
+                write_element_block("TextAuthor", w, |w| {
+                    w.write(XmlEvent::Characters(&text_author))
+                        .map_err(Into::into)
+                })?;
+            }
+            Ok(())
+        })
+    }
+
+    pub(crate) fn write_additional_resource<W: Write>(
+        &self,
+        additional_resource: &WorkAdditionalResources,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        // prefer the copy hosted by Thoth over the original location
+        let (link, hosted) = match (
+            &additional_resource.file,
+            &additional_resource.url,
+            &additional_resource.doi,
+        ) {
+            (Some(file), _, _) => (file.cdn_url.clone(), true),
+            (None, Some(url), _) => (url.clone(), false),
+            (None, None, Some(doi)) => (doi.with_domain(), false),
+            (None, None, None) => return Ok(()),
+        };
+        // the API exposes the resource type as its string representation
+        let resource_type = additional_resource
+            .resource_type
+            .parse::<ResourceType>()
+            .unwrap_or_default();
+        write_element_block("SupportingResource", w, |w| {
+            write_element_block("ResourceContentType", w, |w| {
+                let code = match resource_type {
+                    // 18 Other commentary / discussion
+                    ResourceType::Blog | ResourceType::Article | ResourceType::Website => "18",
+                    // 15 Sample content
+                    // Closest approximation for material supplementing the work
+                    _ => "15",
+                };
+                w.write(XmlEvent::Characters(code)).map_err(Into::into)
+            })?;
+            // 00 Unrestricted
+            write_element_block("ContentAudience", w, |w| {
+                w.write(XmlEvent::Characters("00")).map_err(Into::into)
+            })?;
+            write_element_block("ResourceMode", w, |w| {
+                let code = match resource_type {
+                    // 02 Audio
+                    ResourceType::Audio => "02",
+                    // 05 Video
+                    ResourceType::Video => "05",
+                    // 03 Image
+                    ResourceType::Image | ResourceType::Map => "03",
+                    // 04 Text
+                    ResourceType::Blog
+                    | ResourceType::Article
+                    | ResourceType::Book
+                    | ResourceType::Document
+                    | ResourceType::Source => "04",
+                    // 06 Multi-mode
+                    _ => "06",
+                };
+                w.write(XmlEvent::Characters(code)).map_err(Into::into)
+            })?;
+            // 02 Caption
+            write_resource_feature("02", &additional_resource.title, w)?;
+            if let Some(attribution) = &additional_resource.attribution {
+                // 01 Required credit
+                write_resource_feature("01", attribution, w)?;
+            }
+            write_resource_version(&link, hosted, None, w)
+        })
+    }
+
+    pub(crate) fn write_featured_video<W: Write>(
+        &self,
+        featured_video: &WorkFeaturedVideo,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        let (link, hosted) = match (&featured_video.file, &featured_video.url) {
+            (Some(file), _) => (file.cdn_url.clone(), true),
+            (None, Some(url)) => (url.clone(), false),
+            (None, None) => return Ok(()),
+        };
+        write_element_block("SupportingResource", w, |w| {
+            // 26 Trailer
+            // Promotional video, closest approximation for a work's featured video
+            write_element_block("ResourceContentType", w, |w| {
+                w.write(XmlEvent::Characters("26")).map_err(Into::into)
+            })?;
+            // 00 Unrestricted
+            write_element_block("ContentAudience", w, |w| {
+                w.write(XmlEvent::Characters("00")).map_err(Into::into)
+            })?;
+            // 05 Video
+            write_element_block("ResourceMode", w, |w| {
+                w.write(XmlEvent::Characters("05")).map_err(Into::into)
+            })?;
+            if let Some(title) = &featured_video.title {
+                // 02 Caption
+                write_resource_feature("02", title, w)?;
+            }
+            write_resource_version(
+                &link,
+                hosted,
+                Some((featured_video.width, featured_video.height)),
+                w,
+            )
+        })
+    }
+
+    pub(crate) fn write_prize<W: Write>(
+        &self,
+        award: &WorkAwards,
+        w: &mut EventWriter<W>,
+    ) -> ThothResult<()> {
+        write_element_block("Prize", w, |w| {
+            write_element_block("PrizeName", w, |w| {
+                let prize_name = match &award.category {
+                    Some(category) => format!("{}: {category}", award.title),
+                    None => award.title.clone(),
+                };
+                w.write(XmlEvent::Characters(&prize_name))
+                    .map_err(Into::into)
+            })?;
+            // PrizeYear only accepts a single year, not a span
+            if let Some(year) = award
+                .year
+                .as_ref()
+                .filter(|year| year.len() == 4 && year.chars().all(|c| c.is_ascii_digit()))
+            {
+                write_element_block("PrizeYear", w, |w| {
+                    w.write(XmlEvent::Characters(year)).map_err(Into::into)
+                })?;
+            }
+            if let Some(country) = &award.country {
+                write_element_block("PrizeCountry", w, |w| {
+                    w.write(XmlEvent::Characters(
+                        ApiCountryCode::from(country.clone()).alpha2(),
+                    ))
+                    .map_err(Into::into)
+                })?;
+            }
+            if let Some(role) = &award.role {
+                write_element_block("PrizeCode", w, |w| {
+                    w.write(XmlEvent::Characters(onix_prize_code(role)))
+                        .map_err(Into::into)
+                })?;
+            }
+            if let Some(prize_statement) = &award.prize_statement {
+                write_element_block("PrizeStatement", w, |w| {
+                    w.write(XmlEvent::Characters(prize_statement))
+                        .map_err(Into::into)
+                })?;
+            }
+            if let Some(jury) = &award.jury {
+                write_element_block("PrizeJury", w, |w| {
+                    w.write(XmlEvent::Characters(jury)).map_err(Into::into)
+                })?;
+            }
+            Ok(())
+        })
+    }
+}
+
+fn write_product_identifier<W: Write>(
+    id_type: &str,
+    id_type_name: Option<&str>,
+    id_value: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("ProductIdentifier", w, |w| {
+        write_element_block("ProductIDType", w, |w| {
+            w.write(XmlEvent::Characters(id_type)).map_err(Into::into)
+        })?;
+        if let Some(id_type_name) = id_type_name {
+            write_element_block("IDTypeName", w, |w| {
+                w.write(XmlEvent::Characters(id_type_name))
+                    .map_err(Into::into)
+            })?;
+        }
+        write_element_block("IDValue", w, |w| {
+            w.write(XmlEvent::Characters(id_value)).map_err(Into::into)
+        })
+    })
+}
+
+fn write_collection_identifier<W: Write>(
+    id_type: &str,
+    id_type_name: Option<&str>,
+    id_value: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("CollectionIdentifier", w, |w| {
+        write_element_block("CollectionIDType", w, |w| {
+            w.write(XmlEvent::Characters(id_type)).map_err(Into::into)
+        })?;
+        if let Some(id_type_name) = id_type_name {
+            write_element_block("IDTypeName", w, |w| {
+                w.write(XmlEvent::Characters(id_type_name))
+                    .map_err(Into::into)
+            })?;
+        }
+        write_element_block("IDValue", w, |w| {
+            w.write(XmlEvent::Characters(id_value)).map_err(Into::into)
+        })
+    })
+}
+
+fn write_publisher_identifier<W: Write>(
+    id_type: &str,
+    id_value: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("PublisherIdentifier", w, |w| {
+        write_element_block("PublisherIDType", w, |w| {
+            w.write(XmlEvent::Characters(id_type)).map_err(Into::into)
+        })?;
+        write_element_block("IDValue", w, |w| {
+            w.write(XmlEvent::Characters(id_value)).map_err(Into::into)
+        })
+    })
+}
+
+fn write_name_identifier<W: Write>(orcid: &str, w: &mut EventWriter<W>) -> ThothResult<()> {
+    write_element_block("NameIdentifier", w, |w| {
+        // 21 ORCID
+        write_element_block("NameIDType", w, |w| {
+            w.write(XmlEvent::Characters("21")).map_err(Into::into)
+        })?;
+        write_element_block("IDValue", w, |w| {
+            w.write(XmlEvent::Characters(orcid)).map_err(Into::into)
+        })
+    })
+}
+
+fn write_alternative_name<W: Write>(
+    name_variant: &NameVariant,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("AlternativeName", w, |w| {
+        write_element_block("NameType", w, |w| {
+            let code = match name_variant.variant_type {
+                NameVariantType::PSEUDONYM => "01",
+                NameVariantType::FORMER_NAME => "03",
+                NameVariantType::TRANSLITERATION => "05",
+                NameVariantType::Other(_) => unreachable!(),
+            };
+            w.write(XmlEvent::Characters(code)).map_err(Into::into)
+        })?;
+        let textscript = name_variant
+            .script_code
+            .as_deref()
+            .map(|script_code| vec![("textscript", script_code)]);
+        write_full_element_block("PersonName", textscript.clone(), w, |w| {
+            w.write(XmlEvent::Characters(&name_variant.full_name))
+                .map_err(Into::into)
+        })?;
+        if let Some(first_name) = &name_variant.first_name {
+            write_full_element_block("NamesBeforeKey", textscript.clone(), w, |w| {
+                w.write(XmlEvent::Characters(first_name))
+                    .map_err(Into::into)
+            })?;
+        }
+        write_full_element_block("KeyNames", textscript, w, |w| {
+            w.write(XmlEvent::Characters(&name_variant.last_name))
+                .map_err(Into::into)
+        })
+    })
+}
+
+fn write_website<W: Write>(
+    role: &str,
+    description: &str,
+    link: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("Website", w, |w| {
+        write_element_block("WebsiteRole", w, |w| {
+            w.write(XmlEvent::Characters(role)).map_err(Into::into)
+        })?;
+        write_element_block("WebsiteDescription", w, |w| {
+            w.write(XmlEvent::Characters(description))
+                .map_err(Into::into)
+        })?;
+        write_element_block("WebsiteLink", w, |w| {
+            w.write(XmlEvent::Characters(link)).map_err(Into::into)
+        })
+    })
+}
+
+fn write_language_code<W: Write>(
+    language_code: &LanguageCode,
+    language_relation: &LanguageRelation,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("Language", w, |w| {
+        write_element_block("LanguageRole", w, |w| {
+            w.write(XmlEvent::Characters(onix_language_role(language_relation)))
+                .map_err(Into::into)
+        })?;
+        // not worth implementing XmlElement for LanguageCode as all cases would
+        // need to be exhaustively matched and the codes are equivalent anyway
+        write_element_block("LanguageCode", w, |w| {
+            w.write(XmlEvent::Characters(
+                &language_code.to_string().to_lowercase(),
+            ))
+            .map_err(Into::into)
+        })
+    })
+}
+
+fn write_title_detail<W: Write, T: TitleData>(
+    title_type: &str,
+    title: &T,
+    language: Option<&str>,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let language = language.map(|language| vec![("language", language)]);
+    write_element_block("TitleDetail", w, |w| {
+        write_element_block("TitleType", w, |w| {
+            w.write(XmlEvent::Characters(title_type))
+                .map_err(Into::into)
+        })?;
+        write_element_block("TitleElement", w, |w| {
+            // 01 Product
+            write_element_block("TitleElementLevel", w, |w| {
+                w.write(XmlEvent::Characters("01")).map_err(Into::into)
+            })?;
+            write_full_element_block("TitleText", language.clone(), w, |w| {
+                w.write(XmlEvent::Characters(title.title()))
+                    .map_err(Into::into)
+            })?;
+            if let Some(subtitle) = title.subtitle() {
+                write_full_element_block("Subtitle", language.clone(), w, |w| {
+                    w.write(XmlEvent::Characters(subtitle)).map_err(Into::into)
+                })?;
+            }
+            Ok(())
+        })
+    })
+}
+
+/// Write the product's licence, if it declares one
+fn write_product_license<W: Write>(
+    epub_license: &EpubLicense,
+    product: &Product,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let access = product.publication.access(product.work.license.as_ref());
+    match epub_license {
+        EpubLicense::CreativeCommons => match &product.work.license {
+            Some(license) => write_epub_license("Creative Commons License", license, None, w),
+            None => Ok(()),
+        },
+        EpubLicense::Open => match access.license.filter(|_| access.is_open()) {
+            Some(license) => write_epub_license(&license_name(license), license, None, w),
+            None => Ok(()),
+        },
+        EpubLicense::Declared => match access.declared_license() {
+            Some(license) => write_epub_license(
+                &license_name(license),
+                license,
+                access.license_start_date(),
+                w,
+            ),
+            None => Ok(()),
+        },
+    }
+}
+
+fn write_epub_license<W: Write>(
+    name: &str,
+    license: &str,
+    start_date: Option<NaiveDate>,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("EpubLicense", w, |w| {
+        write_element_block("EpubLicenseName", w, |w| {
+            w.write(XmlEvent::Characters(name)).map_err(Into::into)
+        })?;
+        write_element_block("EpubLicenseExpression", w, |w| {
+            // 02 Human readable
+            write_element_block("EpubLicenseExpressionType", w, |w| {
+                w.write(XmlEvent::Characters("02")).map_err(Into::into)
+            })?;
+            write_element_block("EpubLicenseExpressionLink", w, |w| {
+                w.write(XmlEvent::Characters(license)).map_err(Into::into)
+            })
+        })?;
+        if let Some(date) = start_date {
+            write_element_block("EpubLicenseDate", w, |w| {
+                // 14 Valid from
+                write_element_block("EpubLicenseDateRole", w, |w| {
+                    w.write(XmlEvent::Characters("14")).map_err(Into::into)
+                })?;
+                // dateformat="00" YYYYMMDD
+                write_full_element_block("Date", Some(vec![("dateformat", "00")]), w, |w| {
+                    w.write(XmlEvent::Characters(&date.format("%Y%m%d").to_string()))
+                        .map_err(Into::into)
+                })
+            })?;
+        }
+        Ok(())
+    })
+}
+
+/// Name of the licence a URL links to, e.g. "Creative Commons Attribution 4.0 International"
+fn license_name(license: &str) -> String {
+    match License::from_url(license) {
+        Ok(license) => license.to_string(),
+        Err(_) => "Unspecified".to_string(),
+    }
+}
+
+fn write_measures<W: Write>(
+    publication: &WorkPublications,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    for (measurement, measure_type, measure_unit_code) in [
+        // 01 height
+        (publication.height_mm, "01", "mm"),
+        (publication.height_cm, "01", "cm"),
+        (publication.height_in, "01", "in"),
+        // 02 width
+        (publication.width_mm, "02", "mm"),
+        (publication.width_cm, "02", "cm"),
+        (publication.width_in, "02", "in"),
+        // 03 thickness
+        (publication.depth_mm, "03", "mm"),
+        (publication.depth_cm, "03", "cm"),
+        (publication.depth_in, "03", "in"),
+        // 08 unit weight
+        (publication.weight_g, "08", "gr"),
+        (publication.weight_oz, "08", "oz"),
+    ] {
+        let Some(measurement) = measurement else {
+            continue;
+        };
+        write_element_block("Measure", w, |w| {
+            write_element_block("MeasureType", w, |w| {
+                w.write(XmlEvent::Characters(measure_type))
+                    .map_err(Into::into)
+            })?;
+            write_element_block("Measurement", w, |w| {
+                w.write(XmlEvent::Characters(&measurement.to_string()))
+                    .map_err(Into::into)
+            })?;
+            write_element_block("MeasureUnitCode", w, |w| {
+                w.write(XmlEvent::Characters(measure_unit_code))
+                    .map_err(Into::into)
+            })
+        })?;
+    }
+    Ok(())
+}
+
+fn write_ancillary_content<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
+    if let Some(bibliography_note) = &work.bibliography_note {
+        // "This data element carries text stating the number and type of
+        // illustrations. The text may also include other content items,
+        // eg maps, bibliography, tables, index etc."
+        write_element_block("IllustrationsNote", w, |w| {
+            w.write(XmlEvent::Characters(bibliography_note))
+                .map_err(Into::into)
+        })?;
+    }
+    for (count, content_type, description) in [
+        // 09 Illustrations, unspecified
+        // (note that there are separate codes for e.g. "halftones" - we don't distinguish)
+        (work.image_count, "09", None),
+        // 11 Tables, unspecified
+        (work.table_count, "11", None),
+        // 19 Recorded music items
+        // (closest equivalent - audio might not always be music)
+        (work.audio_count, "19", None),
+        // 00 Unspecified, see description
+        // (there is no code for "videos")
+        (work.video_count, "00", Some("Videos")),
+    ] {
+        let Some(count) = count else {
+            continue;
+        };
+        write_element_block("AncillaryContent", w, |w| {
+            write_element_block("AncillaryContentType", w, |w| {
+                w.write(XmlEvent::Characters(content_type))
+                    .map_err(Into::into)
+            })?;
+            if let Some(description) = description {
+                write_element_block("AncillaryContentDescription", w, |w| {
+                    w.write(XmlEvent::Characters(description))
+                        .map_err(Into::into)
+                })?;
+            }
+            write_element_block("Number", w, |w| {
+                w.write(XmlEvent::Characters(&count.to_string()))
+                    .map_err(Into::into)
+            })
+        })?;
+    }
+    Ok(())
+}
+
+fn write_text_content<W: Write>(
+    text_type: &str,
+    attributes: Option<Vec<(&str, &str)>>,
+    text: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("TextContent", w, |w| {
+        write_element_block("TextType", w, |w| {
+            w.write(XmlEvent::Characters(text_type)).map_err(Into::into)
+        })?;
+        // 00 Unrestricted
+        write_element_block("ContentAudience", w, |w| {
+            w.write(XmlEvent::Characters("00")).map_err(Into::into)
+        })?;
+        write_full_element_block("Text", attributes.clone(), w, |w| {
+            w.write(XmlEvent::Characters(text)).map_err(Into::into)
+        })
+    })
+}
+
+fn write_short_description<W: Write>(
+    short_abstract: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    // Short description field may not exceed 350 characters.
+    // Ensure that the string is truncated at a valid UTF-8 boundary
+    // by finding the byte index of the 350th character and then truncating
+    // the string at that index, to avoid creating invalid UTF-8 sequences.
+    let short_abstract = match short_abstract.char_indices().nth(350) {
+        Some((byte_index, _)) => &short_abstract[..byte_index],
+        None => short_abstract,
+    };
+    // 02 Short description
+    write_text_content("02", Some(vec![("textformat", "03")]), short_abstract, w)
+}
+
+fn write_long_description<W: Write>(
+    long_abstract: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    // 03 Description, 30 Abstract
+    for text_type in ["03", "30"] {
+        write_text_content(
+            text_type,
+            Some(vec![("textformat", "03")]),
+            long_abstract,
+            w,
+        )?;
+    }
+    Ok(())
+}
+
+fn write_open_access_statement<W: Write>(
+    language: Option<&str>,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    // 20 Open access statement
+    write_text_content(
+        "20",
+        language.map(|language| vec![("language", language)]),
+        "Open Access",
+        w,
+    )
+}
+
+fn write_general_note<W: Write>(general_note: &str, w: &mut EventWriter<W>) -> ThothResult<()> {
+    // 13 Publisher's notice
+    // "A statement included by a publisher in fulfillment of contractual obligations"
+    // Used in many different ways - closest approximation
+    write_text_content("13", None, general_note, w)
+}
+
+fn write_copyright_holders<W: Write>(
+    copyright_holder: Option<&str>,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let Some(copyright_string) = copyright_holder else {
+        return Ok(());
+    };
+    // Format of field isn't controlled, but many records
+    // use semicolon-separated lists
+    for copyright_holder in copyright_string.split("; ") {
+        write_element_block("CopyrightStatement", w, |w| {
+            write_element_block("CopyrightOwner", w, |w| {
+                // This might be a CorporateName rather than PersonName, but we can't tell
+                write_element_block("PersonName", w, |w| {
+                    w.write(XmlEvent::Characters(copyright_holder))
+                        .map_err(Into::into)
+                })
+            })
+        })?;
+    }
+    Ok(())
+}
+
+/// Write a `<ResourceVersion>` linking to a file hosted by Thoth (downloadable) or to an
+/// external URL (linkable)
+fn write_resource_version<W: Write>(
+    link: &str,
+    hosted: bool,
+    dimensions: Option<(i64, i64)>,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("ResourceVersion", w, |w| {
+        // 02 Downloadable file, 01 Linkable resource
+        write_element_block("ResourceForm", w, |w| {
+            w.write(XmlEvent::Characters(if hosted { "02" } else { "01" }))
+                .map_err(Into::into)
+        })?;
+        if let Some((width, height)) =
+            dimensions.filter(|(width, height)| *width > 0 && *height > 0)
+        {
+            // 02 Image height in pixels, 03 Image width in pixels
+            for (feature_type, value) in [("02", height), ("03", width)] {
+                write_element_block("ResourceVersionFeature", w, |w| {
+                    write_element_block("ResourceVersionFeatureType", w, |w| {
+                        w.write(XmlEvent::Characters(feature_type))
+                            .map_err(Into::into)
+                    })?;
+                    write_element_block("FeatureValue", w, |w| {
+                        w.write(XmlEvent::Characters(&value.to_string()))
+                            .map_err(Into::into)
+                    })
+                })?;
+            }
+        }
+        write_element_block("ResourceLink", w, |w| {
+            w.write(XmlEvent::Characters(link)).map_err(Into::into)
+        })
+    })
+}
+
+fn write_resource_feature<W: Write>(
+    feature_type: &str,
+    note: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("ResourceFeature", w, |w| {
+        write_element_block("ResourceFeatureType", w, |w| {
+            w.write(XmlEvent::Characters(feature_type))
+                .map_err(Into::into)
+        })?;
+        write_element_block("FeatureNote", w, |w| {
+            w.write(XmlEvent::Characters(note)).map_err(Into::into)
+        })
+    })
+}
+
+fn write_unpriced_item_type<W: Write>(code: &str, w: &mut EventWriter<W>) -> ThothResult<()> {
+    write_element_block("UnpricedItemType", w, |w| {
+        w.write(XmlEvent::Characters(code)).map_err(Into::into)
+    })
+}
+
+/// Write the accessibility of the publication, and where to find out more about it, as
+/// e-publication accessibility details
+fn write_accessibility_features<W: Write>(
+    work: &Work,
+    publication: &WorkPublications,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    // 09 E-publication accessibility detail
+    let write_feature = |value: &str, description: Option<&str>, w: &mut EventWriter<W>| {
+        write_element_block("ProductFormFeature", w, |w| {
+            write_element_block("ProductFormFeatureType", w, |w| {
+                w.write(XmlEvent::Characters("09")).map_err(Into::into)
+            })?;
+            write_element_block("ProductFormFeatureValue", w, |w| {
+                w.write(XmlEvent::Characters(value)).map_err(Into::into)
+            })?;
+            if let Some(description) = description {
+                write_element_block("ProductFormFeatureDescription", w, |w| {
+                    w.write(XmlEvent::Characters(description))
+                        .map_err(Into::into)
+                })?;
+            }
+            Ok(())
+        })
+    };
+    if let Some(accessibility_statement) = &work.imprint.publisher.accessibility_statement {
+        // 00 Accessibility summary
+        write_feature("00", Some(accessibility_statement.to_string().as_str()), w)?;
+    }
+    let mut accessibility_codes = vec![];
+    if let Some(standard) = &publication.accessibility_standard {
+        let standard_codes = match standard {
+            AccessibilityStandard::WCAG21AA => vec!["81", "85"],
+            AccessibilityStandard::WCAG21AAA => vec!["81", "86"],
+            AccessibilityStandard::WCAG22AA => vec!["82", "85"],
+            AccessibilityStandard::WCAG22AAA => vec!["82", "86"],
+            _ => unreachable!(),
+        };
+        accessibility_codes.extend(standard_codes);
+    }
+    if let Some(additional_standard) = &publication.accessibility_additional_standard {
+        let additional_standard_codes = match additional_standard {
+            AccessibilityStandard::EPUB_A11Y10AA => vec!["03"],
+            AccessibilityStandard::EPUB_A11Y10AAA => vec!["03", "86"],
+            AccessibilityStandard::EPUB_A11Y11AA => vec!["04", "85"],
+            AccessibilityStandard::EPUB_A11Y11AAA => vec!["04", "86"],
+            AccessibilityStandard::PDF_UA1 => vec!["05"],
+            AccessibilityStandard::PDF_UA2 => vec!["06"],
+            _ => unreachable!(),
+        };
+        accessibility_codes.extend(additional_standard_codes);
+    }
+    if let Some(exception) = &publication.accessibility_exception {
+        let exception_code = match exception {
+            AccessibilityException::MICRO_ENTERPRISES => "75",
+            AccessibilityException::DISPROPORTIONATE_BURDEN => "76",
+            AccessibilityException::FUNDAMENTAL_ALTERATION => "77",
+            AccessibilityException::Other(_) => unreachable!(),
+        };
+        accessibility_codes.push(exception_code);
+    }
+    for code in accessibility_codes {
+        write_feature(code, None, w)?;
+    }
+    if let Some(report_url) = &publication.accessibility_report_url {
+        // 96 Publisher’s web page for detailed accessibility information
+        write_feature("96", Some(report_url.to_string().as_str()), w)?;
+    }
+    for contact in &work.imprint.publisher.contacts {
+        if contact.contact_type == ContactType::ACCESSIBILITY {
+            // 99 Publisher contact for further accessibility information
+            write_feature("99", Some(contact.email.as_str()), w)?;
+        }
+    }
+    Ok(())
+}
+
+/// Spell out the edition, e.g. "Second edition."
+fn edition_statement(edition: i64) -> String {
+    match edition {
+        // Spell out commonest ordinals
+        1 => "First edition.".to_string(),
+        2 => "Second edition.".to_string(),
+        3 => "Third edition.".to_string(),
+        _ => {
+            let suffix = match edition % 10 {
+                1 if edition % 100 != 11 => "st",
+                2 if edition % 100 != 12 => "nd",
+                3 if edition % 100 != 13 => "rd",
+                _ => "th",
+            };
+            format!("{edition}{suffix} edition.")
+        }
+    }
+}
+
+/// ISO 639 code of the language of a locale, e.g. "eng"
+fn iso_language_code(locale_code: &LocaleCode) -> String {
+    let api_locale: ApiLocaleCode = locale_code.clone().into();
+    let lang_code: ApiLanguageCode = api_locale.into();
+    lang_code.to_string().to_lowercase()
+}
+
+fn is_translation(relation: &WorkRelations) -> bool {
+    relation.relation_type == RelationType::HAS_TRANSLATION
+        || relation.relation_type == RelationType::IS_TRANSLATION_OF
+}
+
+/// Product form (code list 150) and product form detail (code list 175) of each
+/// publication type
+fn product_form_codes(publication_type: &PublicationType) -> (&'static str, Option<&'static str>) {
+    match publication_type {
+        PublicationType::PAPERBACK => ("BC", None),
+        PublicationType::HARDBACK => ("BB", None),
+        // EB Digital download and online
+        PublicationType::PDF => ("EB", Some("E107")),
+        PublicationType::HTML => ("EB", Some("E105")),
+        PublicationType::XML => ("EB", Some("E113")),
+        PublicationType::EPUB => ("EB", Some("E101")),
+        PublicationType::MOBI => ("EB", Some("E127")),
+        PublicationType::AZW3 => ("EB", Some("E116")),
+        PublicationType::DOCX => ("EB", Some("E104")),
+        // E100 "not yet allocated" - no codelist entry for .fb2, .fb3, .fbz
+        PublicationType::FICTION_BOOK => ("EB", Some("E100")),
+        // AN Downloadable and online audio file
+        PublicationType::MP3 => ("AN", Some("A103")),
+        PublicationType::WAV => ("AN", Some("A104")),
+        PublicationType::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_publishing_status(work_status: &WorkStatus) -> &'static str {
+    match work_status {
+        WorkStatus::CANCELLED => "01",
+        WorkStatus::FORTHCOMING => "02",
+        WorkStatus::POSTPONED_INDEFINITELY => "03",
+        WorkStatus::ACTIVE => "04",
+        WorkStatus::SUPERSEDED => "08",
+        WorkStatus::WITHDRAWN => "11",
+        WorkStatus::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_product_availability(work_status: &WorkStatus) -> &'static str {
+    match work_status {
+        WorkStatus::CANCELLED => "01",              // 01 – Cancelled
+        WorkStatus::FORTHCOMING => "10",            // 10 – Not yet available
+        WorkStatus::POSTPONED_INDEFINITELY => "09", // 09 – Not yet available, postponed indefinitely
+        WorkStatus::ACTIVE => "20",                 // 20 – Available
+        WorkStatus::SUPERSEDED => "41",             // 41 – Not available, replaced by new product
+        WorkStatus::WITHDRAWN => "49",              // 49 – Recalled
+        WorkStatus::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_language_role(language_relation: &LanguageRelation) -> &'static str {
+    match language_relation {
+        LanguageRelation::ORIGINAL => "01",
+        LanguageRelation::TRANSLATED_FROM => "02",
+        LanguageRelation::TRANSLATED_INTO => "01",
+        LanguageRelation::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_prize_code(award_role: &AwardRole) -> &'static str {
+    match award_role {
+        AwardRole::WINNER => "01",
+        AwardRole::RUNNER_UP => "02",
+        AwardRole::COMMENDED => "03",
+        AwardRole::SHORT_LISTED => "04",
+        AwardRole::LONG_LISTED => "05",
+        AwardRole::JOINT_WINNER => "06",
+        AwardRole::NOMINATED => "07",
+        AwardRole::Other(_) => unreachable!(),
+    }
+}
+
+/// The product's ISBN, along with every ISBN of the work
+fn get_publications_data(
+    publications: &[WorkPublications],
+    main_publication: &WorkPublications,
+) -> (String, Vec<String>) {
+    let mut main_isbn = "".to_string();
+    let mut isbns: Vec<String> = Vec::new();
+
+    for publication in publications {
+        if let Some(isbn) = &publication.isbn.as_ref() {
+            isbns.push(isbn.to_hyphenless_string());
+            // The default product ISBN is the main publication's (EPUB or PDF)
+            if publication
+                .publication_id
+                .eq(&main_publication.publication_id)
+            {
+                main_isbn = isbn.to_hyphenless_string();
+            }
+            // If the main publication has no ISBN, use either the PDF's or the paperback's
+            // (no guarantee as to which will be chosen)
+            if (publication.publication_type.eq(&PublicationType::PDF)
+                || publication.publication_type.eq(&PublicationType::PAPERBACK))
+                && main_isbn.is_empty()
+            {
+                main_isbn = isbn.to_hyphenless_string();
+            }
+        }
+    }
+
+    (main_isbn, isbns)
+}
+
+/// ISBN of the hardback, or failing that of the paperback
+fn print_isbn(publications: &[WorkPublications]) -> Option<String> {
+    let isbn = |publication_type: PublicationType| {
+        publications
+            .iter()
+            .find(|p| p.publication_type.eq(&publication_type))
+            .and_then(|p| p.isbn.as_ref())
+    };
+    isbn(PublicationType::HARDBACK)
+        .or_else(|| isbn(PublicationType::PAPERBACK))
+        .map(|isbn| isbn.to_hyphenless_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alternatives() {
+        assert_eq!(alternatives(&["PDF"]), "PDF");
+        assert_eq!(alternatives(&["EPUB", "PDF"]), "EPUB or PDF");
+        assert_eq!(alternatives(&["BIC", "BISAC", "LCC"]), "BIC, BISAC or LCC");
+        assert_eq!(
+            alternatives(&[SubjectType::BIC, SubjectType::BISAC]),
+            "BIC or BISAC"
+        );
+    }
+
+    #[test]
+    fn test_edition_statement() {
+        assert_eq!(edition_statement(1), "First edition.");
+        assert_eq!(edition_statement(3), "Third edition.");
+        assert_eq!(edition_statement(4), "4th edition.");
+        assert_eq!(edition_statement(11), "11th edition.");
+        assert_eq!(edition_statement(21), "21st edition.");
+        assert_eq!(edition_statement(112), "112th edition.");
+    }
+}
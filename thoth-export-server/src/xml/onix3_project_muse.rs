@@ -1,833 +1,98 @@
-use chrono::Utc;
-use std::collections::HashMap;
-use std::io::Write;
-use thoth_api::model::language::LanguageCode as ApiLanguageCode;
-use thoth_api::model::locale::LocaleCode as ApiLocaleCode;
-use thoth_client::{
-    AbstractType, AccessibilityException, AccessibilityStandard, ContactType, ContributionType,
-    LanguageRelation, PublicationType, SubjectType, Work, WorkContributions, WorkFundings,
-    WorkIssues, WorkLanguages, WorkPublications, WorkStatus, WorkType,
-};
-use xml::writer::{EventWriter, XmlEvent};
+use thoth_client::{PublicationType, SubjectType};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
-use crate::xml::{write_full_element_block, XmlElementBlock, ONIX3_NS};
-use thoth_errors::{ThothError, ThothResult};
+use super::onix3_profile::{
+    onix3_profile, onix_contributor_role, onix_subject_scheme, Availability, Collateral,
+    Collections, ContributorDetail, Contributors, Descriptions, Descriptive, Edition, EpubLicense,
+    Funders, Header, Identifiers, Onix3Profile, OpenAccessStatement, PriceRule, Products,
+    Publishing, RelatedMaterial, Requirement, Subjects, Suppliers, Supply, Titles,
+};
+use super::ONIX3_NS;
 
 #[derive(Copy, Clone)]
 pub struct Onix3ProjectMuse {}
 
-const ONIX_ERROR: &str = "onix_3.0::project_muse";
-
-impl XmlSpecification for Onix3ProjectMuse {
-    fn handle_event<W: Write>(w: &mut EventWriter<W>, works: &[Work]) -> ThothResult<()> {
-        write_full_element_block("ONIXMessage", Some(ONIX3_NS.to_vec()), w, |w| {
-            write_element_block("Header", w, |w| {
-                write_element_block("Sender", w, |w| {
-                    write_element_block("SenderName", w, |w| {
-                        w.write(XmlEvent::Characters("Thoth")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("EmailAddress", w, |w| {
-                        w.write(XmlEvent::Characters("distribution@thoth.pub"))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-                write_element_block("SentDateTime", w, |w| {
-                    w.write(XmlEvent::Characters(
-                        &Utc::now().format("%Y%m%dT%H%M%S").to_string(),
-                    ))
-                    .map_err(|e| e.into())
-                })
-            })?;
-
-            match works {
-                [] => Err(ThothError::IncompleteMetadataRecord(
-                    ONIX_ERROR.to_string(),
-                    "Not enough data".to_string(),
-                )),
-                [work] => XmlElementBlock::<Onix3ProjectMuse>::xml_element(work, w),
-                _ => {
-                    for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
-                        // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
-                            XmlElementBlock::<Onix3ProjectMuse>::xml_element(work, w).ok();
-                        }
-                    }
-                    Ok(())
-                }
-            }
-        })
-    }
-}
-
-impl XmlElementBlock<Onix3ProjectMuse> for Work {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        // Project MUSE can only ingest works which have at least one BIC or BISAC subject code
-        if !self
-            .subjects
-            .iter()
-            .any(|s| s.subject_type.eq(&SubjectType::BISAC) || s.subject_type.eq(&SubjectType::BIC))
-        {
-            return Err(ThothError::IncompleteMetadataRecord(
-                ONIX_ERROR.to_string(),
-                "No BIC or BISAC subject code".to_string(),
-            ));
-        }
-        // We can only generate the document if there's a PDF
-        let pdf_publication = self
-            .publications
-            .iter()
-            .find(|p| p.publication_type.eq(&PublicationType::PDF) && !p.locations.is_empty());
-        if let Some(pdf_url) = pdf_publication
-            .and_then(|p| p.locations.iter().find(|l| l.canonical))
-            .and_then(|l| l.full_text_url.as_ref())
-        {
-            let work_id = format!("urn:uuid:{}", self.work_id);
-            let is_open_access = self.license.is_some();
-            let (main_isbn, isbns) = get_publications_data(&self.publications);
-            write_element_block("Product", w, |w| {
-                write_element_block("RecordReference", w, |w| {
-                    w.write(XmlEvent::Characters(&work_id))
-                        .map_err(|e| e.into())
-                })?;
-                // 03 Notification confirmed on publication
-                write_element_block("NotificationType", w, |w| {
-                    w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                })?;
-                // 01 Publisher
-                write_element_block("RecordSourceType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                })?;
-                write_element_block("ProductIdentifier", w, |w| {
-                    // 01 Proprietary
-                    write_element_block("ProductIDType", w, |w| {
-                        w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&work_id))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-                write_element_block("ProductIdentifier", w, |w| {
-                    // 15 ISBN-13
-                    write_element_block("ProductIDType", w, |w| {
-                        w.write(XmlEvent::Characters("15")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&main_isbn))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-                if let Some(doi) = &self.doi {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(&doi.to_string()))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                }
-                write_element_block("DescriptiveDetail", w, |w| {
-                    // 00 Single-component retail product
-                    write_element_block("ProductComposition", w, |w| {
-                        w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                    })?;
-                    // EB Digital download and online
-                    write_element_block("ProductForm", w, |w| {
-                        w.write(XmlEvent::Characters("EB")).map_err(|e| e.into())
-                    })?;
-                    // E107 PDF
-                    write_element_block("ProductFormDetail", w, |w| {
-                        w.write(XmlEvent::Characters("E107")).map_err(|e| e.into())
-                    })?;
-                    if let Some(accessibility_statement) =
-                        &self.imprint.publisher.accessibility_statement
-                    {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            // 00 Accessibility summary
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureDescription", w, |w| {
-                                w.write(XmlEvent::Characters(&accessibility_statement.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    if let Some(publication) = pdf_publication {
-                        let mut accessibility_codes = vec![];
-                        if let Some(standard) = &publication.accessibility_standard {
-                            let standard_codes = match standard {
-                                AccessibilityStandard::WCAG21AA => vec!["81", "85"],
-                                AccessibilityStandard::WCAG21AAA => vec!["81", "86"],
-                                AccessibilityStandard::WCAG22AA => vec!["82", "85"],
-                                AccessibilityStandard::WCAG22AAA => vec!["82", "86"],
-                                _ => unreachable!(),
-                            };
-                            accessibility_codes.extend(standard_codes);
-                        }
-                        if let Some(additional_standard) =
-                            &publication.accessibility_additional_standard
-                        {
-                            let additional_standard_codes = match additional_standard {
-                                AccessibilityStandard::EPUB_A11Y10AA => vec!["03"],
-                                AccessibilityStandard::EPUB_A11Y10AAA => vec!["03", "86"],
-                                AccessibilityStandard::EPUB_A11Y11AA => vec!["04", "85"],
-                                AccessibilityStandard::EPUB_A11Y11AAA => vec!["04", "86"],
-                                AccessibilityStandard::PDF_UA1 => vec!["05"],
-                                AccessibilityStandard::PDF_UA2 => vec!["06"],
-                                _ => unreachable!(),
-                            };
-                            accessibility_codes.extend(additional_standard_codes);
-                        }
-                        if let Some(exception) = &publication.accessibility_exception {
-                            let exception_code = match exception {
-                                AccessibilityException::MICRO_ENTERPRISES => "75",
-                                AccessibilityException::DISPROPORTIONATE_BURDEN => "76",
-                                AccessibilityException::FUNDAMENTAL_ALTERATION => "77",
-                                AccessibilityException::Other(_) => unreachable!(),
-                            };
-                            accessibility_codes.push(exception_code);
-                        }
-                        for code in accessibility_codes {
-                            write_element_block("ProductFormFeature", w, |w| {
-                                // 09 E-publication accessibility detail
-                                write_element_block("ProductFormFeatureType", w, |w| {
-                                    w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                                })?;
-                                write_element_block("ProductFormFeatureValue", w, |w| {
-                                    w.write(XmlEvent::Characters(code)).map_err(Into::into)
-                                })
-                            })?;
-                        }
-                        if let Some(report_url) = &publication.accessibility_report_url {
-                            write_element_block("ProductFormFeature", w, |w| {
-                                // 09 E-publication accessibility detail
-                                write_element_block("ProductFormFeatureType", w, |w| {
-                                    w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                                })?;
-                                // 96 Publisher’s web page for detailed accessibility information
-                                write_element_block("ProductFormFeatureValue", w, |w| {
-                                    w.write(XmlEvent::Characters("96")).map_err(Into::into)
-                                })?;
-                                write_element_block("ProductFormFeatureDescription", w, |w| {
-                                    w.write(XmlEvent::Characters(&report_url.to_string()))
-                                        .map_err(Into::into)
-                                })
-                            })?;
-                        }
-                    }
-                    for contact in &self.imprint.publisher.contacts {
-                        if contact.contact_type == ContactType::ACCESSIBILITY {
-                            write_element_block("ProductFormFeature", w, |w| {
-                                // 09 E-publication accessibility detail
-                                write_element_block("ProductFormFeatureType", w, |w| {
-                                    w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                                })?;
-                                // 99 Publisher contact for further accessibility information
-                                write_element_block("ProductFormFeatureValue", w, |w| {
-                                    w.write(XmlEvent::Characters("99")).map_err(Into::into)
-                                })?;
-                                write_element_block("ProductFormFeatureDescription", w, |w| {
-                                    w.write(XmlEvent::Characters(&contact.email))
-                                        .map_err(Into::into)
-                                })
-                            })?;
-                        }
-                    }
-                    // 10 Text (eye-readable)
-                    write_element_block("PrimaryContentType", w, |w| {
-                        w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
-                    })?;
-                    if let Some(license) = &self.license {
-                        write_element_block("EpubLicense", w, |w| {
-                            write_element_block("EpubLicenseName", w, |w| {
-                                w.write(XmlEvent::Characters("Creative Commons License"))
-                                    .map_err(|e| e.into())
-                            })?;
-                            write_element_block("EpubLicenseExpression", w, |w| {
-                                write_element_block("EpubLicenseExpressionType", w, |w| {
-                                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("EpubLicenseExpressionLink", w, |w| {
-                                    w.write(XmlEvent::Characters(license)).map_err(|e| e.into())
-                                })
-                            })
-                        })?;
-                    }
-                    for issue in &self.issues {
-                        XmlElementBlock::<Onix3ProjectMuse>::xml_element(issue, w).ok();
-                    }
-                    write_element_block("TitleDetail", w, |w| {
-                        // 01 Distinctive title (book)
-                        write_element_block("TitleType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("TitleElement", w, |w| {
-                            // 01 Product
-                            write_element_block("TitleElementLevel", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("TitleText", w, |w| {
-                                w.write(XmlEvent::Characters(&self.titles[0].title))
-                                    .map_err(|e| e.into())
-                            })?;
-                            if let Some(subtitle) = &self.titles[0].subtitle {
-                                write_element_block("Subtitle", w, |w| {
-                                    w.write(XmlEvent::Characters(subtitle))
-                                        .map_err(|e| e.into())
-                                })?;
-                            }
-                            Ok(())
-                        })
-                    })?;
-                    for contribution in &self.contributions {
-                        XmlElementBlock::<Onix3ProjectMuse>::xml_element(contribution, w).ok();
-                    }
-                    if let Some(edition) = &self.edition {
-                        if let Some(edition_statement) = match edition {
-                            // Spell out commonest ordinals
-                            1 => Some("First edition.".to_string()),
-                            2 => Some("Second edition.".to_string()),
-                            3 => Some("Third edition.".to_string()),
-                            _ => {
-                                let suffix = match edition % 10 {
-                                    1 if edition % 100 != 11 => "st",
-                                    2 if edition % 100 != 12 => "nd",
-                                    3 if edition % 100 != 13 => "rd",
-                                    _ => "th",
-                                };
-                                Some(format!("{edition}{suffix} edition."))
-                            }
-                        } {
-                            write_element_block("EditionStatement", w, |w| {
-                                w.write(XmlEvent::Characters(&edition_statement))
-                                    .map_err(|e| e.into())
-                            })?;
-                        }
-                    }
-
-                    for language in &self.languages {
-                        XmlElementBlock::<Onix3ProjectMuse>::xml_element(language, w).ok();
-                    }
-                    if let Some(page_count) = self.page_count {
-                        write_element_block("Extent", w, |w| {
-                            // 00 Main content
-                            write_element_block("ExtentType", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("ExtentValue", w, |w| {
-                                w.write(XmlEvent::Characters(&page_count.to_string()))
-                                    .map_err(|e| e.into())
-                            })?;
-                            // 03 Pages
-                            write_element_block("ExtentUnit", w, |w| {
-                                w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                            })
-                        })?;
-                    }
-                    for subject in &self.subjects {
-                        // Project MUSE can't process records containing keywords
-                        if subject.subject_type != SubjectType::KEYWORD {
-                            write_element_block("Subject", w, |w| {
-                                XmlElement::<Onix3ProjectMuse>::xml_element(
-                                    &subject.subject_type,
-                                    w,
-                                )?;
-                                write_element_block("SubjectCode", w, |w| {
-                                    w.write(XmlEvent::Characters(&subject.subject_code))
-                                        .map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                    }
-                    write_element_block("Audience", w, |w| {
-                        // 01 ONIX audience codes
-                        write_element_block("AudienceCodeType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        // 06 Professional and scholarly
-                        write_element_block("AudienceCodeValue", w, |w| {
-                            w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                        })
-                    })?;
-                    Ok(())
-                })?;
-                if self
-                    .abstracts
-                    .iter()
-                    .any(|a| a.abstract_type == AbstractType::LONG && a.canonical)
-                    || self.toc.is_some()
-                    || is_open_access
-                {
-                    write_element_block("CollateralDetail", w, |w| {
-                        if let Some(r#abstract) = &self
-                            .abstracts
-                            .iter()
-                            .find(|a| a.abstract_type == AbstractType::LONG && a.canonical)
-                        {
-                            write_element_block("TextContent", w, |w| {
-                                // 03 Description (using LONG abstract if available)
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                let api_locale: ApiLocaleCode =
-                                    r#abstract.locale_code.clone().into();
-                                let lang_code: ApiLanguageCode = api_locale.into();
-                                let iso_code = lang_code.to_string().to_lowercase();
-                                write_full_element_block(
-                                    "Text",
-                                    Some(vec![("language", &iso_code), ("textformat", "03")]),
-                                    w,
-                                    |w| {
-                                        w.write(XmlEvent::Characters(&r#abstract.content))
-                                            .map_err(|e| e.into())
-                                    },
-                                )
-                            })?;
-                        }
-                        if let Some(toc) = &self.toc {
-                            write_element_block("TextContent", w, |w| {
-                                // 04 Table of contents
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("04")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("Text", w, |w| {
-                                    w.write(XmlEvent::Characters(toc)).map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                        if is_open_access {
-                            write_element_block("TextContent", w, |w| {
-                                // 20 Open access statement
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("20")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("Text", w, |w| {
-                                    w.write(XmlEvent::Characters("Open Access"))
-                                        .map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                }
-                write_element_block("PublishingDetail", w, |w| {
-                    write_element_block("Imprint", w, |w| {
-                        write_element_block("ImprintName", w, |w| {
-                            w.write(XmlEvent::Characters(&self.imprint.imprint_name))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                    write_element_block("Publisher", w, |w| {
-                        // 01 Publisher
-                        write_element_block("PublishingRole", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("PublisherName", w, |w| {
-                            w.write(XmlEvent::Characters(&self.imprint.publisher.publisher_name))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                    for funding in &self.fundings {
-                        XmlElementBlock::<Onix3ProjectMuse>::xml_element(funding, w).ok();
-                    }
-                    if let Some(place) = &self.place {
-                        write_element_block("CityOfPublication", w, |w| {
-                            w.write(XmlEvent::Characters(place)).map_err(|e| e.into())
-                        })?;
-                    }
-                    XmlElement::<Onix3ProjectMuse>::xml_element(&self.work_status, w)?;
-                    if let Some(date) = self.publication_date {
-                        write_element_block("PublishingDate", w, |w| {
-                            write_element_block("PublishingDateRole", w, |w| {
-                                // 01 Publication date
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            // dateformat="00" YYYYMMDD
-                            write_full_element_block(
-                                "Date",
-                                Some(vec![("dateformat", "00")]),
-                                w,
-                                |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &date.format("%Y%m%d").to_string(),
-                                    ))
-                                    .map_err(|e| e.into())
-                                },
-                            )
-                        })?;
-                        write_element_block("CopyrightStatement", w, |w| {
-                            write_element_block("CopyrightYear", w, |w| {
-                                w.write(XmlEvent::Characters(&date.format("%Y").to_string()))
-                                    .map_err(|e| e.into())
-                            })
-                        })?;
-                    }
-                    if let Some(date) = &self.withdrawn_date {
-                        write_element_block("PublishingDate", w, |w| {
-                            write_element_block("PublishingDateRole", w, |w| {
-                                // 13 Out-of-print / permanently withdrawn date
-                                w.write(XmlEvent::Characters("13")).map_err(|e| e.into())
-                            })?;
-                            // dateformat="00" YYYYMMDD
-                            write_full_element_block(
-                                "Date",
-                                Some(vec![("dateformat", "00")]),
-                                w,
-                                |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &date.format("%Y%m%d").to_string(),
-                                    ))
-                                    .map_err(|e| e.into())
-                                },
-                            )
-                        })?;
-                    }
-                    Ok(())
-                })?;
-                if !isbns.is_empty() {
-                    write_element_block("RelatedMaterial", w, |w| {
-                        for isbn in &isbns {
-                            write_element_block("RelatedProduct", w, |w| {
-                                // 06 Alternative format
-                                write_element_block("ProductRelationCode", w, |w| {
-                                    w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("ProductIdentifier", w, |w| {
-                                    // 15 ISBN-13
-                                    write_element_block("ProductIDType", w, |w| {
-                                        w.write(XmlEvent::Characters("15")).map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("IDValue", w, |w| {
-                                        w.write(XmlEvent::Characters(isbn)).map_err(|e| e.into())
-                                    })
-                                })
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                }
-                write_element_block("ProductSupply", w, |w| {
-                    let mut supplies: HashMap<String, (String, String)> = HashMap::new();
-                    supplies.insert(
-                        pdf_url.to_string(),
-                        (
-                            "29".to_string(),
-                            "Publisher's website: download the title".to_string(),
-                        ),
-                    );
-                    if let Some(landing_page) = &self.landing_page {
-                        supplies.insert(
-                            landing_page.to_string(),
-                            (
-                                "02".to_string(),
-                                "Publisher's website: web shop".to_string(),
-                            ),
-                        );
-                    }
-                    for (url, description) in supplies.iter() {
-                        write_element_block("SupplyDetail", w, |w| {
-                            write_element_block("Supplier", w, |w| {
-                                // 09 Publisher to end-customers
-                                write_element_block("SupplierRole", w, |w| {
-                                    w.write(XmlEvent::Characters("09")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("SupplierName", w, |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &self.imprint.publisher.publisher_name,
-                                    ))
-                                    .map_err(|e| e.into())
-                                })?;
-                                write_element_block("Website", w, |w| {
-                                    write_element_block("WebsiteRole", w, |w| {
-                                        w.write(XmlEvent::Characters(&description.0))
-                                            .map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("WebsiteDescription", w, |w| {
-                                        w.write(XmlEvent::Characters(&description.1))
-                                            .map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("WebsiteLink", w, |w| {
-                                        w.write(XmlEvent::Characters(url)).map_err(|e| e.into())
-                                    })
-                                })
-                            })?;
-                            // 99 Contact supplier
-                            write_element_block("ProductAvailability", w, |w| {
-                                w.write(XmlEvent::Characters("99")).map_err(|e| e.into())
-                            })?;
-                            let prices = self
-                                .publications
-                                .iter()
-                                .find(|p| p.publication_type.eq(&PublicationType::PDF))
-                                .map(|p| p.prices.clone())
-                                .unwrap_or_default();
-                            if is_open_access || prices.is_empty() {
-                                // 01 Free of charge
-                                write_element_block("UnpricedItemType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })
-                            } else {
-                                for price in prices {
-                                    let unit_price = price.unit_price;
-                                    let formatted_price = format!("{unit_price:.2}");
-                                    write_element_block("Price", w, |w| {
-                                        // 02 RRP including tax
-                                        write_element_block("PriceType", w, |w| {
-                                            w.write(XmlEvent::Characters("02"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("PriceAmount", w, |w| {
-                                            w.write(XmlEvent::Characters(&formatted_price))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("CurrencyCode", w, |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &price.currency_code.to_string(),
-                                            ))
-                                            .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("Territory", w, |w| {
-                                            write_element_block("RegionsIncluded", w, |w| {
-                                                w.write(XmlEvent::Characters("WORLD"))
-                                                    .map_err(|e| e.into())
-                                            })
-                                        })
-                                    })?;
-                                }
-                                Ok(())
-                            }
-                        })?;
-                    }
-                    Ok(())
-                })
-            })
-        } else {
-            Err(ThothError::IncompleteMetadataRecord(
-                ONIX_ERROR.to_string(),
-                "Missing PDF URL".to_string(),
-            ))
-        }
-    }
-}
-
-fn get_publications_data(publications: &[WorkPublications]) -> (String, Vec<String>) {
-    let mut main_isbn = "".to_string();
-    let mut isbns: Vec<String> = Vec::new();
-
-    for publication in publications {
-        if let Some(isbn) = &publication.isbn.as_ref() {
-            isbns.push(isbn.to_hyphenless_string());
-            // The default product ISBN is the PDF's
-            if publication.publication_type.eq(&PublicationType::PDF) {
-                main_isbn = isbn.to_hyphenless_string();
-            }
-            // Books that don't have a PDF ISBN will use the paperback's
-            if publication.publication_type.eq(&PublicationType::PAPERBACK) && main_isbn.is_empty()
-            {
-                main_isbn = isbn.to_hyphenless_string();
-            }
-        }
-    }
-
-    (main_isbn, isbns)
-}
-
-impl XmlElement<Onix3ProjectMuse> for WorkStatus {
-    const ELEMENT: &'static str = "PublishingStatus";
-
-    fn value(&self) -> &'static str {
-        match self {
-            WorkStatus::CANCELLED => "01",
-            WorkStatus::FORTHCOMING => "02",
-            WorkStatus::POSTPONED_INDEFINITELY => "03",
-            WorkStatus::ACTIVE => "04",
-            WorkStatus::SUPERSEDED => "08",
-            WorkStatus::WITHDRAWN => "11",
-            WorkStatus::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix3ProjectMuse> for SubjectType {
-    const ELEMENT: &'static str = "SubjectSchemeIdentifier";
-
-    fn value(&self) -> &'static str {
-        match self {
-            SubjectType::BIC => "12",
-            SubjectType::BISAC => "10",
-            SubjectType::LCC => "04",
-            SubjectType::THEMA => "93",
-            SubjectType::CUSTOM => "B2",
-            // Keywords are not output for Project MUSE
-            SubjectType::KEYWORD | SubjectType::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix3ProjectMuse> for LanguageRelation {
-    const ELEMENT: &'static str = "LanguageRole";
-
-    fn value(&self) -> &'static str {
-        match self {
-            LanguageRelation::ORIGINAL => "01",
-            LanguageRelation::TRANSLATED_FROM => "02",
-            LanguageRelation::TRANSLATED_INTO => "01",
-            LanguageRelation::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElementBlock<Onix3ProjectMuse> for WorkFundings {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Publisher", w, |w| {
-            // 16 Funding body
-            write_element_block("PublishingRole", w, |w| {
-                w.write(XmlEvent::Characters("16")).map_err(|e| e.into())
-            })?;
-            write_element_block("PublisherName", w, |w| {
-                w.write(XmlEvent::Characters(&self.institution.institution_name))
-                    .map_err(|e| e.into())
-            })?;
-            Ok(())
-        })
-    }
-}
-
-impl XmlElementBlock<Onix3ProjectMuse> for WorkIssues {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Collection", w, |w| {
-            // 10 Publisher collection (e.g. series)
-            write_element_block("CollectionType", w, |w| {
-                w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
-            })?;
-            write_element_block("TitleDetail", w, |w| {
-                // 01 Cover title (serial)
-                write_element_block("TitleType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                })?;
-                write_element_block("TitleElement", w, |w| {
-                    // 02 Collection level
-                    write_element_block("TitleElementLevel", w, |w| {
-                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("SequenceNumber", w, |w| {
-                        w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
-                            .map_err(|e| e.into())
-                    })?;
-                    if let Some(issue_number) = &self.issue_number {
-                        write_element_block("PartNumber", w, |w| {
-                            w.write(XmlEvent::Characters(&issue_number.to_string()))
-                                .map_err(|e| e.into())
-                        })?;
-                    }
-                    write_element_block("TitleText", w, |w| {
-                        w.write(XmlEvent::Characters(&self.series.series_name))
-                            .map_err(|e| e.into())
-                    })
-                })
-            })
-        })
-    }
-}
-
-impl XmlElement<Onix3ProjectMuse> for ContributionType {
-    const ELEMENT: &'static str = "ContributorRole";
-
-    fn value(&self) -> &'static str {
-        match self {
-            ContributionType::AUTHOR => "A01",
-            ContributionType::EDITOR => "B01",
-            ContributionType::TRANSLATOR => "B06",
-            ContributionType::PHOTOGRAPHER => "A13",
-            ContributionType::ILLUSTRATOR => "A12",
-            ContributionType::MUSIC_EDITOR => "B25",
-            ContributionType::FOREWORD_BY => "A23",
-            ContributionType::INTRODUCTION_BY => "A24",
-            ContributionType::AFTERWORD_BY => "A19",
-            ContributionType::PREFACE_BY => "A15",
-            ContributionType::SOFTWARE_BY => "A30",
-            ContributionType::RESEARCH_BY => "A51",
-            ContributionType::CONTRIBUTIONS_BY => "A32",
-            ContributionType::INDEXER => "A34",
-            ContributionType::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElementBlock<Onix3ProjectMuse> for WorkContributions {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Contributor", w, |w| {
-            write_element_block("SequenceNumber", w, |w| {
-                w.write(XmlEvent::Characters(&self.contribution_ordinal.to_string()))
-                    .map_err(|e| e.into())
-            })?;
-            XmlElement::<Onix3ProjectMuse>::xml_element(&self.contribution_type, w)?;
+const PROFILE: Onix3Profile = Onix3Profile {
+    name: "onix_3.0::project_muse",
+    namespace: ONIX3_NS,
+    header: Header {
+        addressee: None,
+        sent_date_format: "%Y%m%dT%H%M%S",
+    },
+    // Project MUSE can only ingest works which have at least one BIC or BISAC subject code
+    // (given or derived from Thema)
+    requirements: &[Requirement::SubjectScheme(&[
+        SubjectType::BIC,
+        SubjectType::BISAC,
+    ])],
+    products: Products::Distributed(&[PublicationType::PDF]),
+    identifiers: Identifiers::Proprietary,
+    descriptive: Descriptive {
+        measures: false,
+        epub_license: Some(EpubLicense::CreativeCommons),
+        collections: Some(Collections {
+            issn: false,
+            proprietary_identifiers: false,
+            sequence_number: true,
+            collection_sequence: false,
+        }),
+        titles: Titles::First,
+        contributors: Contributors {
+            role: onix_contributor_role,
+            excluded: &[],
+            require_author: false,
+            detail: ContributorDetail::Name,
+        },
+        edition: Some(Edition::Statement),
+        ancillary_content: false,
+        subjects: Subjects {
+            crosswalk: true,
+            scheme: subject_scheme,
+            headings: &[],
+            combine_keywords: false,
+            main_subject: false,
+        },
+        default_audience: true,
+    },
+    collateral: Collateral {
+        descriptions: Descriptions::Description,
+        table_of_contents: true,
+        toc_language: None,
+        open_access_statement: Some(OpenAccessStatement::Licensed),
+        general_note: false,
+        cover: false,
+        cover_caption: false,
+        supplementary: false,
+    },
+    chapters: None,
+    publishing: Publishing {
+        websites: false,
+        funders: Some(Funders::Named),
+        city_of_publication: true,
+        // 01 Publication date
+        publication_date_role: "01",
+        publication_year_only: false,
+        copyright_year: true,
+        copyright_holders: false,
+        sales_rights: false,
+    },
+    related_material: RelatedMaterial::AllIsbns,
+    supply: Supply {
+        world_market: false,
+        suppliers: Suppliers::PublisherWebsites,
+        // 99 Contact supplier
+        availability: Availability::Fixed("99"),
+        supply_date_role: None,
+        prices: PriceRule::All,
+    },
+};
 
-            if let Some(orcid) = &self.contributor.orcid {
-                write_element_block("NameIdentifier", w, |w| {
-                    write_element_block("NameIDType", w, |w| {
-                        w.write(XmlEvent::Characters("21")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&orcid.to_string()))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-            }
-            if let Some(first_name) = &self.first_name {
-                write_element_block("NamesBeforeKey", w, |w| {
-                    w.write(XmlEvent::Characters(first_name))
-                        .map_err(|e| e.into())
-                })?;
-                write_element_block("KeyNames", w, |w| {
-                    w.write(XmlEvent::Characters(&self.last_name))
-                        .map_err(|e| e.into())
-                })?;
-            } else {
-                write_element_block("PersonName", w, |w| {
-                    w.write(XmlEvent::Characters(&self.full_name))
-                        .map_err(|e| e.into())
-                })?;
-            }
-            Ok(())
-        })
-    }
-}
+onix3_profile!(Onix3ProjectMuse, PROFILE);
 
-impl XmlElementBlock<Onix3ProjectMuse> for WorkLanguages {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Language", w, |w| {
-            XmlElement::<Onix3ProjectMuse>::xml_element(&self.language_relation, w).ok();
-            // not worth implementing XmlElement for LanguageCode as all cases would
-            // need to be exhaustively matched and the codes are equivalent anyway
-            write_element_block("LanguageCode", w, |w| {
-                w.write(XmlEvent::Characters(
-                    &self.language_code.to_string().to_lowercase(),
-                ))
-                .map_err(|e| e.into())
-            })
-        })
+fn subject_scheme(subject_type: &SubjectType) -> Option<&'static str> {
+    match subject_type {
+        // Project MUSE can't process records containing keywords
+        SubjectType::KEYWORD => None,
+        _ => onix_subject_scheme(subject_type),
     }
 }
 
@@ -836,16 +101,20 @@ mod tests {
     // Testing note: XML nodes cannot be guaranteed to be output in the same order every time
     // We therefore rely on `assert!(contains)` rather than `assert_eq!`
     use super::*;
+    use crate::xml::XmlElementBlock;
     use std::str::FromStr;
     use thoth_api::model::Doi;
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_client::{
-        ContributionType, CurrencyCode, LanguageCode, LanguageRelation, LocationPlatform,
-        PublicationType, WorkContributionsContributor, WorkFundings, WorkImprint,
+        AccessibilityException, AccessibilityStandard, ContactType, ContributionType, CurrencyCode,
+        LanguageCode, LanguageRelation, LocationPlatform, PriceType, PublicationType, SubjectType,
+        Work, WorkContributions, WorkContributionsContributor, WorkFundings, WorkImprint,
         WorkImprintPublisher, WorkImprintPublisherContacts, WorkIssues, WorkIssuesSeries,
-        WorkPublicationsLocations, WorkPublicationsPrices, WorkStatus, WorkSubjects, WorkType,
+        WorkLanguages, WorkPublications, WorkPublicationsLocations, WorkPublicationsPrices,
+        WorkStatus, WorkSubjects, WorkType,
     };
+    use thoth_errors::ThothError;
     use uuid::Uuid;
 
     fn generate_test_output(
@@ -882,9 +151,12 @@ mod tests {
             main_contribution: true,
             biographies: vec![],
             contribution_ordinal: 1,
+            name_variants: vec![],
+            credit_roles: vec![],
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: None,
+                name_variants: vec![],
             },
             affiliations: vec![],
         };
@@ -1091,10 +363,20 @@ mod tests {
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::EUR,
                         unit_price: 5.95,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::GBP,
                         unit_price: 4.95,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                 ],
                 locations: vec![WorkPublicationsLocations {
@@ -1103,6 +385,11 @@ mod tests {
                     location_platform: LocationPlatform::OTHER,
                     canonical: true,
                 }],
+                sales_rights: vec![],
+                product_form_details: vec![],
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
             }],
             subjects: vec![
                 WorkSubjects {
@@ -1136,6 +423,13 @@ mod tests {
                     subject_ordinal: 6,
                 },
             ],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![WorkFundings {
                 program: Some("Name of program".to_string()),
                 project_name: Some("Name of project".to_string()),
@@ -1,1708 +1,98 @@
-use cc_license::License;
-use chrono::Utc;
-use std::io::Write;
-use thoth_api::model::language::LanguageCode as ApiLanguageCode;
-use thoth_client::{
-    AbstractType, AccessibilityException, AccessibilityStandard, ContactType, ContributionType,
-    LanguageRelation, LocationPlatform, PublicationType, RelationType, SubjectType, Work,
-    WorkContributions, WorkFundings, WorkIssues, WorkLanguages, WorkPublicationsLocations,
-    WorkReferences, WorkRelations, WorkStatus, WorkType,
-};
-use xml::writer::{EventWriter, XmlEvent};
+use thoth_client::SubjectType;
 
-use super::{write_element_block, XmlElement, XmlSpecification};
-use crate::xml::{write_full_element_block, XmlElementBlock, ONIX3_NS};
-use thoth_api::model::locale::LocaleCode as ApiLocaleCode;
-use thoth_errors::{ThothError, ThothResult};
+use super::onix3_profile::{
+    onix3_profile, onix_contributor_role, onix_subject_scheme, Availability, Chapters, Collateral,
+    Collections, ContributorDetail, Contributors, Descriptions, Descriptive, Edition, EpubLicense,
+    Funders, Header, Identifiers, Onix3Profile, OpenAccessStatement, PriceRule, Products,
+    Publishing, RelatedMaterial, Subjects, Suppliers, Supply, Titles,
+};
+use super::ONIX3_NS;
 
 #[derive(Copy, Clone)]
 pub struct Onix3Thoth {}
 
-struct Measure {
-    measure_type: &'static str,
-    measurement: f64,
-    measure_unit_code: &'static str,
-}
-
-const ONIX_ERROR: &str = "onix_3.0::thoth";
-
 // Based on ONIX for Books Release 3.0.8 Specification
 // Download link: https://www.editeur.org/files/ONIX%203/ONIX_for_Books_Release_3-0_pdf_docs+codes_Issue_64.zip
 // Retrieved from: https://www.editeur.org/93/Release-3.0-Downloads/#Specifications
-impl XmlSpecification for Onix3Thoth {
-    fn handle_event<W: Write>(w: &mut EventWriter<W>, works: &[Work]) -> ThothResult<()> {
-        write_full_element_block("ONIXMessage", Some(ONIX3_NS.to_vec()), w, |w| {
-            write_element_block("Header", w, |w| {
-                write_element_block("Sender", w, |w| {
-                    write_element_block("SenderName", w, |w| {
-                        w.write(XmlEvent::Characters("Thoth")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("EmailAddress", w, |w| {
-                        w.write(XmlEvent::Characters("distribution@thoth.pub"))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-                write_element_block("SentDateTime", w, |w| {
-                    w.write(XmlEvent::Characters(
-                        &Utc::now().format("%Y%m%dT%H%M%S").to_string(),
-                    ))
-                    .map_err(|e| e.into())
-                })
-            })?;
-
-            match works {
-                [] => Err(ThothError::IncompleteMetadataRecord(
-                    ONIX_ERROR.to_string(),
-                    "Not enough data".to_string(),
-                )),
-                [work] => XmlElementBlock::<Onix3Thoth>::xml_element(work, w),
-                _ => {
-                    for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
-                        // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
-                            XmlElementBlock::<Onix3Thoth>::xml_element(work, w).ok();
-                        }
-                    }
-                    Ok(())
-                }
-            }
-        })
-    }
-}
-
-impl XmlElementBlock<Onix3Thoth> for Work {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        // Format is one record per Publication
-        if self.publications.is_empty() {
-            return Err(ThothError::IncompleteMetadataRecord(
-                ONIX_ERROR.to_string(),
-                "No publications supplied".to_string(),
-            ));
-        }
-        let work_id = format!("urn:uuid:{}", self.work_id);
-        let is_open_access = self.license.is_some();
-        let isbns: Vec<String> = self
-            .publications
-            .iter()
-            .filter_map(|p| p.isbn.as_ref())
-            .map(|i| i.to_hyphenless_string())
-            .collect();
-        for publication in &self.publications {
-            let publication_id = format!("urn:uuid:{}", publication.publication_id);
-            let current_isbn = &publication.isbn.as_ref().map(|p| p.to_hyphenless_string());
-            write_element_block("Product", w, |w| {
-                write_element_block("RecordReference", w, |w| {
-                    // Note that most existing Thoth ONIX outputs use the Work ID, not Publication ID,
-                    // as they output one record per Work rather than one record per Publication
-                    w.write(XmlEvent::Characters(&publication_id))
-                        .map_err(|e| e.into())
-                })?;
-                // 03 Notification confirmed on publication
-                write_element_block("NotificationType", w, |w| {
-                    w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                })?;
-                // 01 Publisher
-                write_element_block("RecordSourceType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                })?;
-                write_element_block("ProductIdentifier", w, |w| {
-                    // 01 Proprietary
-                    write_element_block("ProductIDType", w, |w| {
-                        w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("thoth-work-id"))
-                            .map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&work_id))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-                write_element_block("ProductIdentifier", w, |w| {
-                    // 01 Proprietary
-                    write_element_block("ProductIDType", w, |w| {
-                        w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("thoth-publication-id"))
-                            .map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&publication_id))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-                if let Some(isbn) = current_isbn {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        // 15 ISBN-13
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("15")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(isbn)).map_err(|e| e.into())
-                        })
-                    })?;
-                    write_element_block("ProductIdentifier", w, |w| {
-                        // 03 GTIN-13
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("03")).map_err(Into::into)
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(isbn)).map_err(Into::into)
-                        })
-                    })?;
-                }
-                if let Some(doi) = &self.doi {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(&doi.to_string()))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                }
-                if let Some(lccn) = &self.lccn {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("13")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(&lccn.to_string()))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                }
-                if let Some(oclc) = &self.oclc {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("23")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(&oclc.to_string()))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                }
-                if let Some(reference) = &self.reference {
-                    write_element_block("ProductIdentifier", w, |w| {
-                        write_element_block("ProductIDType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("IDTypeName", w, |w| {
-                            w.write(XmlEvent::Characters("internal-reference"))
-                                .map_err(|e| e.into())
-                        })?;
-                        write_element_block("IDValue", w, |w| {
-                            w.write(XmlEvent::Characters(&reference.to_string()))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                }
-                write_element_block("DescriptiveDetail", w, |w| {
-                    // 00 Single-component retail product
-                    write_element_block("ProductComposition", w, |w| {
-                        w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                    })?;
-                    let (form, form_detail) = get_product_form_codes(&publication.publication_type);
-                    write_element_block("ProductForm", w, |w| {
-                        w.write(XmlEvent::Characters(form)).map_err(|e| e.into())
-                    })?;
-                    if let Some(code) = form_detail {
-                        write_element_block("ProductFormDetail", w, |w| {
-                            w.write(XmlEvent::Characters(code)).map_err(|e| e.into())
-                        })?;
-                    }
-                    if let Some(accessibility_statement) =
-                        &self.imprint.publisher.accessibility_statement
-                    {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            // 00 Accessibility summary
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureDescription", w, |w| {
-                                w.write(XmlEvent::Characters(&accessibility_statement.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    let mut accessibility_codes = vec![];
-                    if let Some(standard) = &publication.accessibility_standard {
-                        let standard_codes = match standard {
-                            AccessibilityStandard::WCAG21AA => vec!["81", "85"],
-                            AccessibilityStandard::WCAG21AAA => vec!["81", "86"],
-                            AccessibilityStandard::WCAG22AA => vec!["82", "85"],
-                            AccessibilityStandard::WCAG22AAA => vec!["82", "86"],
-                            _ => unreachable!(),
-                        };
-                        accessibility_codes.extend(standard_codes);
-                    }
-                    if let Some(additional_standard) =
-                        &publication.accessibility_additional_standard
-                    {
-                        let additional_standard_codes = match additional_standard {
-                            AccessibilityStandard::EPUB_A11Y10AA => vec!["03"],
-                            AccessibilityStandard::EPUB_A11Y10AAA => vec!["03", "86"],
-                            AccessibilityStandard::EPUB_A11Y11AA => vec!["04", "85"],
-                            AccessibilityStandard::EPUB_A11Y11AAA => vec!["04", "86"],
-                            AccessibilityStandard::PDF_UA1 => vec!["05"],
-                            AccessibilityStandard::PDF_UA2 => vec!["06"],
-                            _ => unreachable!(),
-                        };
-                        accessibility_codes.extend(additional_standard_codes);
-                    }
-                    if let Some(exception) = &publication.accessibility_exception {
-                        let exception_code = match exception {
-                            AccessibilityException::MICRO_ENTERPRISES => "75",
-                            AccessibilityException::DISPROPORTIONATE_BURDEN => "76",
-                            AccessibilityException::FUNDAMENTAL_ALTERATION => "77",
-                            AccessibilityException::Other(_) => unreachable!(),
-                        };
-                        accessibility_codes.push(exception_code);
-                    }
-                    for code in accessibility_codes {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters(code)).map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    if let Some(report_url) = &publication.accessibility_report_url {
-                        write_element_block("ProductFormFeature", w, |w| {
-                            // 09 E-publication accessibility detail
-                            write_element_block("ProductFormFeatureType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                            })?;
-                            // 96 Publisher’s web page for detailed accessibility information
-                            write_element_block("ProductFormFeatureValue", w, |w| {
-                                w.write(XmlEvent::Characters("96")).map_err(Into::into)
-                            })?;
-                            write_element_block("ProductFormFeatureDescription", w, |w| {
-                                w.write(XmlEvent::Characters(&report_url.to_string()))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    for contact in &self.imprint.publisher.contacts {
-                        if contact.contact_type == ContactType::ACCESSIBILITY {
-                            write_element_block("ProductFormFeature", w, |w| {
-                                // 09 E-publication accessibility detail
-                                write_element_block("ProductFormFeatureType", w, |w| {
-                                    w.write(XmlEvent::Characters("09")).map_err(Into::into)
-                                })?;
-                                // 99 Publisher contact for further accessibility information
-                                write_element_block("ProductFormFeatureValue", w, |w| {
-                                    w.write(XmlEvent::Characters("99")).map_err(Into::into)
-                                })?;
-                                write_element_block("ProductFormFeatureDescription", w, |w| {
-                                    w.write(XmlEvent::Characters(&contact.email))
-                                        .map_err(Into::into)
-                                })
-                            })?;
-                        }
-                    }
-                    // 10 Text (eye-readable)
-                    write_element_block("PrimaryContentType", w, |w| {
-                        w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
-                    })?;
-                    for &(measurement_opt, measure_type, measure_unit_code) in &[
-                        // 01 height
-                        (publication.height_mm, "01", "mm"),
-                        (publication.height_cm, "01", "cm"),
-                        (publication.height_in, "01", "in"),
-                        // 02 width
-                        (publication.width_mm, "02", "mm"),
-                        (publication.width_cm, "02", "cm"),
-                        (publication.width_in, "02", "in"),
-                        // 03 thickness
-                        (publication.depth_mm, "03", "mm"),
-                        (publication.depth_cm, "03", "cm"),
-                        (publication.depth_in, "03", "in"),
-                        // 08 unit weight
-                        (publication.weight_g, "08", "gr"),
-                        (publication.weight_oz, "08", "oz"),
-                    ] {
-                        if let Some(measurement) = measurement_opt {
-                            XmlElementBlock::<Onix3Thoth>::xml_element(
-                                &Measure {
-                                    measure_type,
-                                    measurement,
-                                    measure_unit_code,
-                                },
-                                w,
-                            )
-                            .ok();
-                        }
-                    }
-                    if let Some(license_url) = &self.license {
-                        let license_text = match License::from_url(license_url) {
-                            Ok(license) => license.to_string(),
-                            Err(_) => "Unspecified".to_string(),
-                        };
-                        write_element_block("EpubLicense", w, |w| {
-                            write_element_block("EpubLicenseName", w, |w| {
-                                w.write(XmlEvent::Characters(&license_text))
-                                    .map_err(|e| e.into())
-                            })?;
-                            write_element_block("EpubLicenseExpression", w, |w| {
-                                write_element_block("EpubLicenseExpressionType", w, |w| {
-                                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("EpubLicenseExpressionLink", w, |w| {
-                                    w.write(XmlEvent::Characters(license_url))
-                                        .map_err(|e| e.into())
-                                })
-                            })
-                        })?;
-                    }
-                    for issue in &self.issues {
-                        XmlElementBlock::<Onix3Thoth>::xml_element(issue, w).ok();
-                    }
-                    // Output canonical title with TitleType 01 (Distinctive title)
-                    if let Some(canonical_title) = self.titles.iter().find(|t| t.canonical) {
-                        write_element_block("TitleDetail", w, |w| {
-                            // 01 Distinctive title (book)
-                            write_element_block("TitleType", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("TitleElement", w, |w| {
-                                // 01 Product
-                                write_element_block("TitleElementLevel", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })?;
-                                write_full_element_block(
-                                    "TitleText",
-                                    Some(vec![(
-                                        "language",
-                                        &canonical_title.locale_code.to_string(),
-                                    )]),
-                                    w,
-                                    |w| {
-                                        w.write(XmlEvent::Characters(&canonical_title.title))
-                                            .map_err(|e| e.into())
-                                    },
-                                )?;
-                                if let Some(subtitle) = &canonical_title.subtitle {
-                                    write_full_element_block(
-                                        "Subtitle",
-                                        Some(vec![(
-                                            "language",
-                                            &canonical_title.locale_code.to_string(),
-                                        )]),
-                                        w,
-                                        |w| {
-                                            w.write(XmlEvent::Characters(subtitle))
-                                                .map_err(|e| e.into())
-                                        },
-                                    )?;
-                                }
-                                Ok(())
-                            })
-                        })?;
-                    }
-                    // Output non-canonical titles with TitleType 06 (Title in another language)
-                    for title in self.titles.iter().filter(|t| !t.canonical) {
-                        write_element_block("TitleDetail", w, |w| {
-                            // 06 Title in another language
-                            write_element_block("TitleType", w, |w| {
-                                w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("TitleElement", w, |w| {
-                                // 01 Product
-                                write_element_block("TitleElementLevel", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })?;
-                                let api_locale: ApiLocaleCode = title.locale_code.clone().into();
-                                let lang_code: ApiLanguageCode = api_locale.into();
-                                let iso_code = lang_code.to_string().to_lowercase();
-                                write_full_element_block(
-                                    "TitleText",
-                                    Some(vec![("language", &iso_code)]),
-                                    w,
-                                    |w| {
-                                        w.write(XmlEvent::Characters(&title.title))
-                                            .map_err(|e| e.into())
-                                    },
-                                )?;
-                                if let Some(subtitle) = &title.subtitle {
-                                    write_full_element_block(
-                                        "Subtitle",
-                                        Some(vec![("language", &iso_code)]),
-                                        w,
-                                        |w| {
-                                            w.write(XmlEvent::Characters(subtitle))
-                                                .map_err(|e| e.into())
-                                        },
-                                    )?;
-                                }
-                                Ok(())
-                            })
-                        })?;
-                    }
-                    for contribution in &self.contributions {
-                        XmlElementBlock::<Onix3Thoth>::xml_element(contribution, w).ok();
-                    }
-                    if let Some(edition) = &self.edition {
-                        // "Normally sent only for the second and subsequent editions"
-                        if edition > &1 {
-                            write_element_block("EditionNumber", w, |w| {
-                                w.write(XmlEvent::Characters(&edition.to_string()))
-                                    .map_err(|e| e.into())
-                            })?;
-                        }
-                    }
-                    for language in &self.languages {
-                        XmlElementBlock::<Onix3Thoth>::xml_element(language, w).ok();
-                    }
-                    if let Some(page_count) = self.page_count {
-                        write_element_block("Extent", w, |w| {
-                            // 00 Main content
-                            write_element_block("ExtentType", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("ExtentValue", w, |w| {
-                                w.write(XmlEvent::Characters(&page_count.to_string()))
-                                    .map_err(|e| e.into())
-                            })?;
-                            // 03 Pages
-                            write_element_block("ExtentUnit", w, |w| {
-                                w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                            })
-                        })?;
-                    }
-                    if let Some(bibliography_note) = &self.bibliography_note {
-                        // "This data element carries text stating the number and type of
-                        // illustrations. The text may also include other content items,
-                        // eg maps, bibliography, tables, index etc."
-                        write_element_block("IllustrationsNote", w, |w| {
-                            w.write(XmlEvent::Characters(&bibliography_note.to_string()))
-                                .map_err(|e| e.into())
-                        })?;
-                    }
-                    if let Some(image_count) = self.image_count {
-                        write_element_block("AncillaryContent", w, |w| {
-                            // 09 Illustrations, unspecified
-                            // (note that there are separate codes for e.g. "halftones" - we don't distinguish)
-                            write_element_block("AncillaryContentType", w, |w| {
-                                w.write(XmlEvent::Characters("09")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("Number", w, |w| {
-                                w.write(XmlEvent::Characters(&image_count.to_string()))
-                                    .map_err(|e| e.into())
-                            })
-                        })?;
-                    }
-                    if let Some(table_count) = self.table_count {
-                        write_element_block("AncillaryContent", w, |w| {
-                            // 11 Tables, unspecified
-                            write_element_block("AncillaryContentType", w, |w| {
-                                w.write(XmlEvent::Characters("11")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("Number", w, |w| {
-                                w.write(XmlEvent::Characters(&table_count.to_string()))
-                                    .map_err(|e| e.into())
-                            })
-                        })?;
-                    }
-                    if let Some(audio_count) = self.audio_count {
-                        write_element_block("AncillaryContent", w, |w| {
-                            // 19 Recorded music items
-                            // (closest equivalent - audio might not always be music)
-                            write_element_block("AncillaryContentType", w, |w| {
-                                w.write(XmlEvent::Characters("19")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("Number", w, |w| {
-                                w.write(XmlEvent::Characters(&audio_count.to_string()))
-                                    .map_err(|e| e.into())
-                            })
-                        })?;
-                    }
-                    if let Some(video_count) = self.video_count {
-                        write_element_block("AncillaryContent", w, |w| {
-                            // 00 Unspecified, see description
-                            // (there is no code for "videos")
-                            write_element_block("AncillaryContentType", w, |w| {
-                                w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("AncillaryContentDescription", w, |w| {
-                                w.write(XmlEvent::Characters("Videos"))
-                                    .map_err(|e| e.into())
-                            })?;
-                            write_element_block("Number", w, |w| {
-                                w.write(XmlEvent::Characters(&video_count.to_string()))
-                                    .map_err(|e| e.into())
-                            })
-                        })?;
-                    }
-                    let mut main_subject_found = vec![];
-                    let mut keywords = vec![];
-                    for subject in &self.subjects {
-                        // Best practice is to include only a single subject tag
-                        // for keywords, listing all of them separated by semicolons
-                        if subject.subject_type == SubjectType::KEYWORD {
-                            keywords.push(subject.subject_code.clone());
-                            continue;
-                        }
-                        // One subject within every subject type can/should be marked as Main
-                        // Use first one found with ordinal 1 (there may be multiple)
-                        let is_main_subject = subject.subject_ordinal == 1
-                            && !main_subject_found.contains(&subject.subject_type);
-                        write_element_block("Subject", w, |w| {
-                            if is_main_subject {
-                                // MainSubject is an empty element
-                                write_element_block("MainSubject", w, |_w| Ok(()))?;
-                            }
-                            XmlElement::<Onix3Thoth>::xml_element(&subject.subject_type, w)?;
-                            match subject.subject_type {
-                                SubjectType::CUSTOM => {
-                                    write_element_block("SubjectHeadingText", w, |w| {
-                                        w.write(XmlEvent::Characters(&subject.subject_code))
-                                            .map_err(|e| e.into())
-                                    })
-                                }
-                                SubjectType::KEYWORD => unreachable!(),
-                                _ => write_element_block("SubjectCode", w, |w| {
-                                    w.write(XmlEvent::Characters(&subject.subject_code))
-                                        .map_err(|e| e.into())
-                                }),
-                            }
-                        })?;
-                        if is_main_subject {
-                            main_subject_found.push(subject.subject_type.clone());
-                        }
-                    }
-                    if !keywords.is_empty() {
-                        write_element_block("Subject", w, |w| {
-                            XmlElement::<Onix3Thoth>::xml_element(&SubjectType::KEYWORD, w)?;
-                            write_element_block("SubjectHeadingText", w, |w| {
-                                w.write(XmlEvent::Characters(&keywords.join("; ")))
-                                    .map_err(Into::into)
-                            })
-                        })?;
-                    }
-                    write_element_block("Audience", w, |w| {
-                        // 01 ONIX audience codes
-                        write_element_block("AudienceCodeType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        // 06 Professional and scholarly
-                        write_element_block("AudienceCodeValue", w, |w| {
-                            w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                        })
-                    })
-                })?;
-                if self
-                    .abstracts
-                    .iter()
-                    .any(|a| a.abstract_type == AbstractType::SHORT)
-                    || self
-                        .abstracts
-                        .iter()
-                        .any(|a| a.abstract_type == AbstractType::LONG)
-                    || self.toc.is_some()
-                    || self.general_note.is_some()
-                    || self.cover_url.is_some()
-                    || is_open_access
-                {
-                    write_element_block("CollateralDetail", w, |w| {
-                        if let Some(mut short_abstract) = self
-                            .abstracts
-                            .iter()
-                            .find(|a| a.abstract_type == AbstractType::SHORT)
-                            .map(|a| a.content.clone())
-                        {
-                            // Short description field may not exceed 350 characters.
-                            // Ensure that the string is truncated at a valid UTF-8 boundary
-                            // by finding the byte index of the 350th character and then truncating
-                            // the string at that index, to avoid creating invalid UTF-8 sequences.
-                            if let Some((byte_index, _)) = short_abstract.char_indices().nth(350) {
-                                short_abstract.truncate(byte_index);
-                            }
-                            write_element_block("TextContent", w, |w| {
-                                // 02 Short description
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                write_full_element_block(
-                                    "Text",
-                                    Some(vec![("textformat", "03")]),
-                                    w,
-                                    |w| {
-                                        w.write(XmlEvent::Characters(&short_abstract))
-                                            .map_err(|e| e.into())
-                                    },
-                                )
-                            })?;
-                        }
-                        if let Some(long_abstract) = &self
-                            .abstracts
-                            .iter()
-                            .find(|a| a.abstract_type == AbstractType::LONG)
-                            .map(|a| a.content.clone())
-                        {
-                            // 03 Description, 30 Abstract
-                            for text_type in ["03", "30"] {
-                                write_element_block("TextContent", w, |w| {
-                                    write_element_block("TextType", w, |w| {
-                                        w.write(XmlEvent::Characters(text_type))
-                                            .map_err(|e| e.into())
-                                    })?;
-                                    // 00 Unrestricted
-                                    write_element_block("ContentAudience", w, |w| {
-                                        w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                    })?;
-                                    write_full_element_block(
-                                        "Text",
-                                        Some(vec![("textformat", "03")]),
-                                        w,
-                                        |w| {
-                                            w.write(XmlEvent::Characters(long_abstract))
-                                                .map_err(|e| e.into())
-                                        },
-                                    )
-                                })?;
-                            }
-                        }
-                        if let Some(toc) = &self.toc {
-                            write_element_block("TextContent", w, |w| {
-                                // 04 Table of contents
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("04")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("Text", w, |w| {
-                                    w.write(XmlEvent::Characters(toc)).map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                        if is_open_access {
-                            write_element_block("TextContent", w, |w| {
-                                // 20 Open access statement
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("20")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                write_full_element_block(
-                                    "Text",
-                                    Some(vec![("language", "eng")]),
-                                    w,
-                                    |w| {
-                                        w.write(XmlEvent::Characters("Open Access"))
-                                            .map_err(|e| e.into())
-                                    },
-                                )
-                            })?;
-                        }
-                        if let Some(general_note) = &self.general_note {
-                            write_element_block("TextContent", w, |w| {
-                                // 13 Publisher's notice
-                                // "A statement included by a publisher in fulfillment of contractual obligations"
-                                // Used in many different ways - closest approximation
-                                write_element_block("TextType", w, |w| {
-                                    w.write(XmlEvent::Characters("13")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("Text", w, |w| {
-                                    w.write(XmlEvent::Characters(general_note))
-                                        .map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                        if let Some(cover_url) = &self.cover_url {
-                            write_element_block("SupportingResource", w, |w| {
-                                // 01 Front cover
-                                write_element_block("ResourceContentType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                // 03 Image
-                                write_element_block("ResourceMode", w, |w| {
-                                    w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                                })?;
-                                if let Some(cover_caption) = &self.cover_caption {
-                                    write_element_block("ResourceFeature", w, |w| {
-                                        // 02 Caption
-                                        write_element_block("ResourceFeatureType", w, |w| {
-                                            w.write(XmlEvent::Characters("02"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("FeatureNote", w, |w| {
-                                            w.write(XmlEvent::Characters(cover_caption))
-                                                .map_err(|e| e.into())
-                                        })
-                                    })?;
-                                }
-                                write_element_block("ResourceVersion", w, |w| {
-                                    // 02 Downloadable file
-                                    write_element_block("ResourceForm", w, |w| {
-                                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("ResourceLink", w, |w| {
-                                        w.write(XmlEvent::Characters(cover_url))
-                                            .map_err(|e| e.into())
-                                    })
-                                })
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                }
-                let chapter_relations: Vec<WorkRelations> = self
-                    .relations
-                    .clone()
-                    .into_iter()
-                    .filter(|r| {
-                        r.relation_type == RelationType::HAS_CHILD && r.related_work.doi.is_some()
-                    })
-                    .collect();
-                if !chapter_relations.is_empty() {
-                    write_element_block("ContentDetail", w, |w| {
-                        for relation in &chapter_relations {
-                            let chapter = &relation.related_work;
-                            write_element_block("ContentItem", w, |w| {
-                                write_element_block("LevelSequenceNumber", w, |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &relation.relation_ordinal.to_string(),
-                                    ))
-                                    .map_err(|e| e.into())
-                                })?;
-                                write_element_block("TextItem", w, |w| {
-                                    // 03 Body matter
-                                    write_element_block("TextItemType", w, |w| {
-                                        w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("TextItemIdentifier", w, |w| {
-                                        // 06 DOI
-                                        write_element_block("TextItemIDType", w, |w| {
-                                            w.write(XmlEvent::Characters("06"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("IDValue", w, |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &chapter.doi.as_ref().unwrap().to_string(),
-                                            ))
-                                            .map_err(|e| e.into())
-                                        })
-                                    })
-                                })?;
-                                if let Some(first_page) = &chapter.first_page {
-                                    write_element_block("PageRun", w, |w| {
-                                        write_element_block("FirstPageNumber", w, |w| {
-                                            w.write(XmlEvent::Characters(first_page))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        if let Some(last_page) = &chapter.last_page {
-                                            write_element_block("LastPageNumber", w, |w| {
-                                                w.write(XmlEvent::Characters(last_page))
-                                                    .map_err(|e| e.into())
-                                            })?;
-                                        }
-                                        Ok(())
-                                    })?;
-                                }
-                                if let Some(page_count) = &chapter.page_count {
-                                    write_element_block("NumberOfPages", w, |w| {
-                                        w.write(XmlEvent::Characters(&page_count.to_string()))
-                                            .map_err(|e| e.into())
-                                    })?;
-                                }
-                                Ok(())
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                }
-                write_element_block("PublishingDetail", w, |w| {
-                    write_element_block("Imprint", w, |w| {
-                        if let Some(url) = &self.imprint.imprint_url {
-                            write_element_block("ImprintIdentifier", w, |w| {
-                                // 01 Proprietary
-                                write_element_block("ImprintIDType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("IDTypeName", w, |w| {
-                                    w.write(XmlEvent::Characters("URL")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("IDValue", w, |w| {
-                                    w.write(XmlEvent::Characters(url)).map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                        write_element_block("ImprintName", w, |w| {
-                            w.write(XmlEvent::Characters(&self.imprint.imprint_name))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                    write_element_block("Publisher", w, |w| {
-                        // 01 Publisher
-                        write_element_block("PublishingRole", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("PublisherName", w, |w| {
-                            w.write(XmlEvent::Characters(&self.imprint.publisher.publisher_name))
-                                .map_err(|e| e.into())
-                        })?;
-                        if let Some(url) = &self.imprint.publisher.publisher_url {
-                            write_element_block("Website", w, |w| {
-                                // 01 Publisher's corporate website
-                                write_element_block("WebsiteRole", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("WebsiteDescription", w, |w| {
-                                    w.write(XmlEvent::Characters("Publisher's website: home page"))
-                                        .map_err(|e| e.into())
-                                })?;
-                                write_element_block("WebsiteLink", w, |w| {
-                                    w.write(XmlEvent::Characters(url)).map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                        if let Some(url) = &self.landing_page {
-                            write_element_block("Website", w, |w| {
-                                // 02 Publisher's website for a specified work
-                                write_element_block("WebsiteRole", w, |w| {
-                                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("WebsiteDescription", w, |w| {
-                                    w.write(XmlEvent::Characters(
-                                        "Publisher's website: webpage for this title",
-                                    ))
-                                    .map_err(|e| e.into())
-                                })?;
-                                write_element_block("WebsiteLink", w, |w| {
-                                    w.write(XmlEvent::Characters(url)).map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                        Ok(())
-                    })?;
-                    for funding in &self.fundings {
-                        XmlElementBlock::<Onix3Thoth>::xml_element(funding, w).ok();
-                    }
-                    if let Some(place) = &self.place {
-                        write_element_block("CityOfPublication", w, |w| {
-                            w.write(XmlEvent::Characters(place)).map_err(|e| e.into())
-                        })?;
-                    }
-                    XmlElement::<Onix3Thoth>::xml_element(&self.work_status, w)?;
-                    if let Some(date) = &self.publication_date {
-                        write_element_block("PublishingDate", w, |w| {
-                            write_element_block("PublishingDateRole", w, |w| {
-                                // 01 Publication date
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            // dateformat="00" YYYYMMDD
-                            write_full_element_block(
-                                "Date",
-                                Some(vec![("dateformat", "00")]),
-                                w,
-                                |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &date.format("%Y%m%d").to_string(),
-                                    ))
-                                    .map_err(|e| e.into())
-                                },
-                            )
-                        })?;
-                    }
-                    if let Some(date) = &self.withdrawn_date {
-                        write_element_block("PublishingDate", w, |w| {
-                            write_element_block("PublishingDateRole", w, |w| {
-                                // 13 Out-of-print / permanently withdrawn date
-                                w.write(XmlEvent::Characters("13")).map_err(|e| e.into())
-                            })?;
-                            // dateformat="00" YYYYMMDD
-                            write_full_element_block(
-                                "Date",
-                                Some(vec![("dateformat", "00")]),
-                                w,
-                                |w| {
-                                    w.write(XmlEvent::Characters(
-                                        &date.format("%Y%m%d").to_string(),
-                                    ))
-                                    .map_err(|e| e.into())
-                                },
-                            )
-                        })?;
-                    }
-                    if let Some(copyright_string) = &self.copyright_holder {
-                        // Format of field isn't controlled, but many records
-                        // use semicolon-separated lists
-                        for copyright_holder in copyright_string.split("; ") {
-                            write_element_block("CopyrightStatement", w, |w| {
-                                write_element_block("CopyrightOwner", w, |w| {
-                                    // This might be a CorporateName rather than PersonName, but we can't tell
-                                    write_element_block("PersonName", w, |w| {
-                                        w.write(XmlEvent::Characters(copyright_holder))
-                                            .map_err(Into::into)
-                                    })
-                                })
-                            })?;
-                        }
-                    }
-                    write_element_block("SalesRights", w, |w| {
-                        // 02 For sale with non-exclusive rights in the specified countries or territories
-                        write_element_block("SalesRightsType", w, |w| {
-                            w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                            })
-                        })
-                    })
-                })?;
-                let non_child_relations: Vec<WorkRelations> = self
-                    .relations
-                    .iter()
-                    .filter(|r| {
-                        r.relation_type != RelationType::HAS_CHILD
-                            && r.relation_type != RelationType::IS_CHILD_OF
-                            && r.related_work.doi.is_some()
-                    })
-                    .cloned()
-                    .collect();
-                // Only output ISBNs in RelatedMaterial if at least one is present which
-                // doesn't relate to the current publication
-                if (!isbns.is_empty() && !isbns.eq(&vec![current_isbn.clone().unwrap_or_default()]))
-                    || !non_child_relations.is_empty()
-                    || !self.references.is_empty()
-                {
-                    write_element_block("RelatedMaterial", w, |w| {
-                        // RelatedWorks should be listed before RelatedProducts
-                        for relation in &non_child_relations {
-                            if relation.relation_type == RelationType::HAS_TRANSLATION
-                                || relation.relation_type == RelationType::IS_TRANSLATION_OF
-                            {
-                                XmlElementBlock::<Onix3Thoth>::xml_element(relation, w).ok();
-                            }
-                        }
-                        for relation in &non_child_relations {
-                            if relation.relation_type != RelationType::HAS_TRANSLATION
-                                && relation.relation_type != RelationType::IS_TRANSLATION_OF
-                            {
-                                XmlElementBlock::<Onix3Thoth>::xml_element(relation, w).ok();
-                            }
-                        }
-                        for isbn in &isbns {
-                            if !current_isbn.eq(&Some(isbn.clone())) {
-                                write_element_block("RelatedProduct", w, |w| {
-                                    // 06 Alternative format
-                                    write_element_block("ProductRelationCode", w, |w| {
-                                        w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("ProductIdentifier", w, |w| {
-                                        // 15 ISBN-13
-                                        write_element_block("ProductIDType", w, |w| {
-                                            w.write(XmlEvent::Characters("15"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("IDValue", w, |w| {
-                                            w.write(XmlEvent::Characters(isbn))
-                                                .map_err(|e| e.into())
-                                        })
-                                    })?;
-                                    write_element_block("ProductIdentifier", w, |w| {
-                                        // 03 GTIN-13
-                                        write_element_block("ProductIDType", w, |w| {
-                                            w.write(XmlEvent::Characters("03"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("IDValue", w, |w| {
-                                            w.write(XmlEvent::Characters(isbn))
-                                                .map_err(|e| e.into())
-                                        })
-                                    })
-                                })?;
-                            }
-                        }
-                        for reference in &self.references {
-                            XmlElementBlock::<Onix3Thoth>::xml_element(reference, w).ok();
-                        }
-                        Ok(())
-                    })?;
-                }
-                write_element_block("ProductSupply", w, |w| {
-                    write_element_block("Market", w, |w| {
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                            })
-                        })
-                    })?;
-                    let mut locations: Vec<WorkPublicationsLocations> =
-                        publication.locations.clone();
-                    if locations.is_empty() {
-                        // Create single Supplier based on Work Landing Page
-                        // so that we can output a SupplyDetail block (inc. Price info)
-                        // (if Landing Page is None, Supplier will be complete apart from Website)
-                        locations = vec![WorkPublicationsLocations {
-                            landing_page: self.landing_page.clone(),
-                            full_text_url: None,
-                            location_platform: LocationPlatform::PUBLISHER_WEBSITE,
-                            canonical: true,
-                        }];
-                    }
-                    for location in locations {
-                        let (supplier_name, description_string, supplier_role, landing_page_role) =
-                            match location.location_platform {
-                                LocationPlatform::PUBLISHER_WEBSITE => (
-                                    self.imprint.publisher.publisher_name.clone(),
-                                    "Publisher's website".to_string(),
-                                    // 09 Publisher to end-customers
-                                    "09",
-                                    // 02 Publisher's website for a specified work
-                                    "02",
-                                ),
-                                LocationPlatform::OTHER => (
-                                    "Unknown".to_string(),
-                                    "Unspecified hosting platform".to_string(),
-                                    // 11 Non-exclusive distributor to end-customers
-                                    "11",
-                                    // 36 Supplier's website for a specified work
-                                    "36",
-                                ),
-                                _ => (
-                                    location.location_platform.to_string(),
-                                    location.location_platform.to_string(),
-                                    // 11 Non-exclusive distributor to end-customers
-                                    "11",
-                                    // 36 Supplier's website for a specified work
-                                    "36",
-                                ),
-                            };
-                        write_element_block("SupplyDetail", w, |w| {
-                            write_element_block("Supplier", w, |w| {
-                                write_element_block("SupplierRole", w, |w| {
-                                    w.write(XmlEvent::Characters(supplier_role))
-                                        .map_err(|e| e.into())
-                                })?;
-                                write_element_block("SupplierName", w, |w| {
-                                    w.write(XmlEvent::Characters(&supplier_name))
-                                        .map_err(|e| e.into())
-                                })?;
-                                if let Some(url) = &location.landing_page {
-                                    write_element_block("Website", w, |w| {
-                                        write_element_block("WebsiteRole", w, |w| {
-                                            w.write(XmlEvent::Characters(landing_page_role))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("WebsiteDescription", w, |w| {
-                                            w.write(XmlEvent::Characters(&format!(
-                                                "{description_string}: webpage for this product"
-                                            )))
-                                            .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("WebsiteLink", w, |w| {
-                                            w.write(XmlEvent::Characters(url)).map_err(|e| e.into())
-                                        })
-                                    })?;
-                                }
-                                if let Some(url) = &location.full_text_url {
-                                    write_element_block("Website", w, |w| {
-                                        write_element_block("WebsiteRole", w, |w| {
-                                            // 29 Web page for full content
-                                            w.write(XmlEvent::Characters("29"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("WebsiteDescription", w, |w| {
-                                            w.write(XmlEvent::Characters(&format!(
-                                                "{description_string}: download the title"
-                                            )))
-                                            .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("WebsiteLink", w, |w| {
-                                            w.write(XmlEvent::Characters(url)).map_err(|e| e.into())
-                                        })
-                                    })?;
-                                }
-                                Ok(())
-                            })?;
-                            write_element_block("ProductAvailability", w, |w| {
-                                let availability = match self.work_status {
-                                    WorkStatus::CANCELLED => "01",              // 01 – Cancelled
-                                    WorkStatus::FORTHCOMING => "10", // 10 – Not yet available
-                                    WorkStatus::POSTPONED_INDEFINITELY => "09", // 09 – Not yet available, postponed indefinitely
-                                    WorkStatus::ACTIVE => "20",                 // 20 – Available
-                                    WorkStatus::SUPERSEDED => "41", // 41 – Not available, replaced by new product
-                                    WorkStatus::WITHDRAWN => "49",  // 49 – Recalled
-                                    WorkStatus::Other(_) => unreachable!(),
-                                };
-                                w.write(XmlEvent::Characters(availability))
-                                    .map_err(|e| e.into())
-                            })?;
-                            if let Some(date) = &self.publication_date {
-                                write_element_block("SupplyDate", w, |w| {
-                                    write_element_block("SupplyDateRole", w, |w| {
-                                        // 02 Expected availability date
-                                        w.write(XmlEvent::Characters("08")).map_err(|e| e.into())
-                                    })?;
-                                    // dateformat="00" YYYYMMDD
-                                    write_full_element_block(
-                                        "Date",
-                                        Some(vec![("dateformat", "00")]),
-                                        w,
-                                        |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &date.format("%Y%m%d").to_string(),
-                                            ))
-                                            .map_err(|e| e.into())
-                                        },
-                                    )
-                                })?;
-                            }
-                            if publication.prices.is_empty() {
-                                // 01 Free of charge
-                                write_element_block("UnpricedItemType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })
-                            } else {
-                                for price in &publication.prices {
-                                    let unit_price = price.unit_price;
-                                    let formatted_price = format!("{unit_price:.2}");
-                                    write_element_block("Price", w, |w| {
-                                        // 02 RRP including tax
-                                        write_element_block("PriceType", w, |w| {
-                                            w.write(XmlEvent::Characters("02"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("PriceAmount", w, |w| {
-                                            w.write(XmlEvent::Characters(&formatted_price))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("CurrencyCode", w, |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &price.currency_code.to_string(),
-                                            ))
-                                            .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("Territory", w, |w| {
-                                            write_element_block("RegionsIncluded", w, |w| {
-                                                w.write(XmlEvent::Characters("WORLD"))
-                                                    .map_err(|e| e.into())
-                                            })
-                                        })
-                                    })?;
-                                }
-                                Ok(())
-                            }
-                        })?;
-                    }
-                    Ok(())
-                })
-            })?;
-        }
-        Ok(())
-    }
-}
-
-fn get_product_form_codes(publication_type: &PublicationType) -> (&str, Option<&str>) {
-    match publication_type {
-        PublicationType::PAPERBACK => ("BC", None),
-        PublicationType::HARDBACK => ("BB", None),
-        // EB Digital download and online
-        PublicationType::PDF => ("EB", Some("E107")),
-        PublicationType::HTML => ("EB", Some("E105")),
-        PublicationType::XML => ("EB", Some("E113")),
-        PublicationType::EPUB => ("EB", Some("E101")),
-        PublicationType::MOBI => ("EB", Some("E127")),
-        PublicationType::AZW3 => ("EB", Some("E116")),
-        PublicationType::DOCX => ("EB", Some("E104")),
-        // E100 "not yet allocated" - no codelist entry for .fb2, .fb3, .fbz
-        PublicationType::FICTION_BOOK => ("EB", Some("E100")),
-        // AN Downloadable and online audio file
-        PublicationType::MP3 => ("AN", Some("A103")),
-        PublicationType::WAV => ("AN", Some("A104")),
-        PublicationType::Other(_) => unreachable!(),
-    }
-}
-
-impl XmlElement<Onix3Thoth> for WorkStatus {
-    const ELEMENT: &'static str = "PublishingStatus";
-
-    fn value(&self) -> &'static str {
-        match self {
-            WorkStatus::CANCELLED => "01",
-            WorkStatus::FORTHCOMING => "02",
-            WorkStatus::POSTPONED_INDEFINITELY => "03",
-            WorkStatus::ACTIVE => "04",
-            WorkStatus::SUPERSEDED => "08",
-            WorkStatus::WITHDRAWN => "11",
-            WorkStatus::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix3Thoth> for SubjectType {
-    const ELEMENT: &'static str = "SubjectSchemeIdentifier";
-
-    fn value(&self) -> &'static str {
-        match self {
-            SubjectType::BIC => "12",
-            SubjectType::BISAC => "10",
-            SubjectType::KEYWORD => "20",
-            SubjectType::LCC => "04",
-            SubjectType::THEMA => "93",
-            SubjectType::CUSTOM => "B2",
-            SubjectType::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix3Thoth> for LanguageRelation {
-    const ELEMENT: &'static str = "LanguageRole";
-
-    fn value(&self) -> &'static str {
-        match self {
-            LanguageRelation::ORIGINAL => "01",
-            LanguageRelation::TRANSLATED_FROM => "02",
-            LanguageRelation::TRANSLATED_INTO => "01",
-            LanguageRelation::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElement<Onix3Thoth> for ContributionType {
-    const ELEMENT: &'static str = "ContributorRole";
-
-    fn value(&self) -> &'static str {
-        match self {
-            ContributionType::AUTHOR => "A01",
-            ContributionType::EDITOR => "B01",
-            ContributionType::TRANSLATOR => "B06",
-            ContributionType::PHOTOGRAPHER => "A13",
-            ContributionType::ILLUSTRATOR => "A12",
-            ContributionType::MUSIC_EDITOR => "B25",
-            ContributionType::FOREWORD_BY => "A23",
-            ContributionType::INTRODUCTION_BY => "A24",
-            ContributionType::AFTERWORD_BY => "A19",
-            ContributionType::PREFACE_BY => "A15",
-            ContributionType::SOFTWARE_BY => "A30",
-            ContributionType::RESEARCH_BY => "A51",
-            ContributionType::CONTRIBUTIONS_BY => "A32",
-            ContributionType::INDEXER => "A34",
-            ContributionType::Other(_) => unreachable!(),
-        }
-    }
-}
-
-impl XmlElementBlock<Onix3Thoth> for WorkContributions {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Contributor", w, |w| {
-            write_element_block("SequenceNumber", w, |w| {
-                w.write(XmlEvent::Characters(&self.contribution_ordinal.to_string()))
-                    .map_err(|e| e.into())
-            })?;
-            XmlElement::<Onix3Thoth>::xml_element(&self.contribution_type, w)?;
-
-            if let Some(orcid) = &self.contributor.orcid {
-                write_element_block("NameIdentifier", w, |w| {
-                    write_element_block("NameIDType", w, |w| {
-                        w.write(XmlEvent::Characters("21")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&orcid.to_hyphenless_string()))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-            }
-            write_element_block("PersonName", w, |w| {
-                w.write(XmlEvent::Characters(&self.full_name))
-                    .map_err(|e| e.into())
-            })?;
-            if let Some(first_name) = &self.first_name {
-                write_element_block("NamesBeforeKey", w, |w| {
-                    w.write(XmlEvent::Characters(first_name))
-                        .map_err(|e| e.into())
-                })?;
-            }
-            write_element_block("KeyNames", w, |w| {
-                w.write(XmlEvent::Characters(&self.last_name))
-                    .map_err(|e| e.into())
-            })?;
-            for affiliation in &self.affiliations {
-                write_element_block("ProfessionalAffiliation", w, |w| {
-                    if let Some(position) = &affiliation.position {
-                        write_element_block("ProfessionalPosition", w, |w| {
-                            w.write(XmlEvent::Characters(position))
-                                .map_err(|e| e.into())
-                        })?;
-                    }
-                    write_element_block("Affiliation", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            &affiliation.institution.institution_name,
-                        ))
-                        .map_err(|e| e.into())
-                    })
-                })?;
-            }
-            if !&self.biographies.is_empty() {
-                let biography = &self.biographies[0].content.clone();
-                write_element_block("BiographicalNote", w, |w| {
-                    w.write(XmlEvent::Characters(biography))
-                        .map_err(|e| e.into())
-                })?;
-            }
-            if let Some(website) = &self.contributor.website {
-                write_element_block("Website", w, |w| {
-                    write_element_block("WebsiteRole", w, |w| {
-                        w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("WebsiteDescription", w, |w| {
-                        w.write(XmlEvent::Characters("Own website"))
-                            .map_err(|e| e.into())
-                    })?;
-                    write_element_block("WebsiteLink", w, |w| {
-                        w.write(XmlEvent::Characters(website)).map_err(|e| e.into())
-                    })
-                })?;
-            }
-            Ok(())
-        })
-    }
-}
-
-impl XmlElementBlock<Onix3Thoth> for WorkLanguages {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Language", w, |w| {
-            XmlElement::<Onix3Thoth>::xml_element(&self.language_relation, w).ok();
-            // not worth implementing XmlElement for LanguageCode as all cases would
-            // need to be exhaustively matched and the codes are equivalent anyway
-            write_element_block("LanguageCode", w, |w| {
-                w.write(XmlEvent::Characters(
-                    &self.language_code.to_string().to_lowercase(),
-                ))
-                .map_err(|e| e.into())
-            })
-        })
-    }
-}
-
-impl XmlElementBlock<Onix3Thoth> for WorkIssues {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Collection", w, |w| {
-            // 10 Publisher collection (e.g. series)
-            write_element_block("CollectionType", w, |w| {
-                w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
-            })?;
-            write_element_block("CollectionIdentifier", w, |w| {
-                // 01 Proprietary
-                write_element_block("CollectionIDType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                })?;
-                write_element_block("IDTypeName", w, |w| {
-                    w.write(XmlEvent::Characters("Series ID"))
-                        .map_err(|e| e.into())
-                })?;
-                write_element_block("IDValue", w, |w| {
-                    w.write(XmlEvent::Characters(&self.series.series_id.to_string()))
-                        .map_err(|e| e.into())
-                })
-            })?;
-            if let Some(issn_digital) = &self.series.issn_digital {
-                write_element_block("CollectionIdentifier", w, |w| {
-                    // 02 ISSN
-                    write_element_block("CollectionIDType", w, |w| {
-                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            &issn_digital.as_str().replace('-', ""),
-                        ))
-                        .map_err(|e| e.into())
-                    })
-                })?;
-            }
-            if let Some(url) = &self.series.series_url {
-                write_element_block("CollectionIdentifier", w, |w| {
-                    // 01 Proprietary
-                    write_element_block("CollectionIDType", w, |w| {
-                        w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("Series URL"))
-                            .map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(url)).map_err(|e| e.into())
-                    })
-                })?;
-            }
-            if let Some(url) = &self.series.series_cfp_url {
-                write_element_block("CollectionIdentifier", w, |w| {
-                    // 01 Proprietary
-                    write_element_block("CollectionIDType", w, |w| {
-                        w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("Series Call for Proposals URL"))
-                            .map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(url)).map_err(|e| e.into())
-                    })
-                })?;
-            }
-            write_element_block("CollectionSequence", w, |w| {
-                // 03 Publication order
-                write_element_block("CollectionSequenceType", w, |w| {
-                    w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                })?;
-                write_element_block("CollectionSequenceNumber", w, |w| {
-                    w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
-                        .map_err(|e| e.into())
-                })
-            })?;
-            write_element_block("TitleDetail", w, |w| {
-                // 01 Cover title (serial)
-                write_element_block("TitleType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                })?;
-                write_element_block("TitleElement", w, |w| {
-                    // 02 Collection level
-                    write_element_block("TitleElementLevel", w, |w| {
-                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                    })?;
-                    if let Some(issue_number) = &self.issue_number {
-                        write_element_block("PartNumber", w, |w| {
-                            w.write(XmlEvent::Characters(&issue_number.to_string()))
-                                .map_err(|e| e.into())
-                        })?;
-                    }
-                    write_element_block("TitleText", w, |w| {
-                        w.write(XmlEvent::Characters(&self.series.series_name))
-                            .map_err(|e| e.into())
-                    })
-                })
-            })
-        })
-    }
-}
-
-impl XmlElementBlock<Onix3Thoth> for WorkFundings {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Publisher", w, |w| {
-            // 16 Funding body
-            write_element_block("PublishingRole", w, |w| {
-                w.write(XmlEvent::Characters("16")).map_err(|e| e.into())
-            })?;
-            if let Some(ror) = &self.institution.ror {
-                write_element_block("PublisherIdentifier", w, |w| {
-                    // 40 ROR
-                    write_element_block("PublisherIDType", w, |w| {
-                        w.write(XmlEvent::Characters("40")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&ror.to_string()))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-            }
-            if let Some(doi) = &self.institution.institution_doi {
-                write_element_block("PublisherIdentifier", w, |w| {
-                    // 32 FundRef DOI
-                    write_element_block("PublisherIDType", w, |w| {
-                        w.write(XmlEvent::Characters("32")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&doi.to_string()))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-            }
-            write_element_block("PublisherName", w, |w| {
-                w.write(XmlEvent::Characters(&self.institution.institution_name))
-                    .map_err(|e| e.into())
-            })?;
-            let identifiers: Vec<(&str, Option<&str>)> = vec![
-                ("programname", self.program.as_deref().to_owned()),
-                ("projectname", self.project_name.as_deref().to_owned()),
-                (
-                    "projectshortname",
-                    self.project_shortname.as_deref().to_owned(),
-                ),
-                ("grantnumber", self.grant_number.as_deref().to_owned()),
-            ];
-            if identifiers.iter().any(|(_, i)| i.is_some()) {
-                write_element_block("Funding", w, |w| {
-                    for (typename, value_opt) in &identifiers {
-                        if let Some(value) = *value_opt {
-                            write_element_block("FundingIdentifier", w, |w| {
-                                // 01 Proprietary
-                                write_element_block("FundingIDType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("IDTypeName", w, |w| {
-                                    w.write(XmlEvent::Characters(typename))
-                                        .map_err(|e| e.into())
-                                })?;
-                                write_element_block("IDValue", w, |w| {
-                                    w.write(XmlEvent::Characters(value)).map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                    }
-                    Ok(())
-                })?;
-            }
-            Ok(())
-        })
-    }
-}
-
-impl XmlElementBlock<Onix3Thoth> for WorkReferences {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("RelatedProduct", w, |w| {
-            // 34 Cites
-            write_element_block("ProductRelationCode", w, |w| {
-                w.write(XmlEvent::Characters("34")).map_err(|e| e.into())
-            })?;
-            let (product_id_type, id_value) = self.doi.as_ref().map_or_else(
-                || {
-                    (
-                        // 01 Proprietary
-                        "01",
-                        // Unstructured citation is mandatory in Thoth if DOI is missing
-                        self.unstructured_citation.as_ref().unwrap().to_owned(),
-                    )
-                },
-                // 06 DOI
-                |doi| ("06", doi.to_string()),
-            );
-            write_element_block("ProductIdentifier", w, |w| {
-                write_element_block("ProductIDType", w, |w| {
-                    w.write(XmlEvent::Characters(product_id_type))
-                        .map_err(|e| e.into())
-                })?;
-                if product_id_type == "01" {
-                    write_element_block("IDTypeName", w, |w| {
-                        w.write(XmlEvent::Characters("Unstructured citation"))
-                            .map_err(|e| e.into())
-                    })?;
-                }
-                write_element_block("IDValue", w, |w| {
-                    w.write(XmlEvent::Characters(&id_value))
-                        .map_err(|e| e.into())
-                })
-            })
-        })
-    }
-}
-
-impl XmlElementBlock<Onix3Thoth> for WorkRelations {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        if self.relation_type == RelationType::HAS_TRANSLATION
-            || self.relation_type == RelationType::IS_TRANSLATION_OF
-        {
-            write_element_block("RelatedWork", w, |w| {
-                let work_relation_code = match &self.relation_type {
-                    // 49 Related work is derived from this via translation
-                    RelationType::HAS_TRANSLATION => "49",
-                    // 29 Derived from via translation
-                    RelationType::IS_TRANSLATION_OF => "29",
-                    _ => unreachable!(),
-                };
-                write_element_block("WorkRelationCode", w, |w| {
-                    w.write(XmlEvent::Characters(work_relation_code))
-                        .map_err(|e| e.into())
-                })?;
-                write_element_block("WorkIdentifier", w, |w| {
-                    // 06 DOI
-                    write_element_block("WorkIDType", w, |w| {
-                        w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            // Caller must guarantee that DOI is present
-                            &self.related_work.doi.as_ref().unwrap().to_string(),
-                        ))
-                        .map_err(|e| e.into())
-                    })
-                })
-            })
-        } else {
-            write_element_block("RelatedProduct", w, |w| {
-                let product_relation_code = match &self.relation_type {
-                    // 01 Includes
-                    RelationType::HAS_PART => "01",
-                    // 02 Is part of
-                    RelationType::IS_PART_OF => "02",
-                    // 03 Replaces
-                    RelationType::REPLACES => "03",
-                    // 05 Replaced by
-                    RelationType::IS_REPLACED_BY => "05",
-                    // This implementation is only valid for translation/part/replacement
-                    // relationships, not parent/child relationships
-                    _ => unreachable!(),
-                };
-                write_element_block("ProductRelationCode", w, |w| {
-                    w.write(XmlEvent::Characters(product_relation_code))
-                        .map_err(|e| e.into())
-                })?;
-                write_element_block("ProductIdentifier", w, |w| {
-                    // 06 DOI
-                    write_element_block("ProductIDType", w, |w| {
-                        w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(
-                            // Caller must guarantee that DOI is present
-                            &self.related_work.doi.as_ref().unwrap().to_string(),
-                        ))
-                        .map_err(|e| e.into())
-                    })
-                })
-            })
-        }
-    }
-}
+const PROFILE: Onix3Profile = Onix3Profile {
+    name: "onix_3.0::thoth",
+    namespace: ONIX3_NS,
+    header: Header {
+        addressee: None,
+        sent_date_format: "%Y%m%dT%H%M%S",
+    },
+    requirements: &[],
+    // Format is one record per Publication
+    products: Products::EveryPublication,
+    identifiers: Identifiers::Complete,
+    descriptive: Descriptive {
+        measures: true,
+        // ONIX 3.0 cannot express a licence start date, so embargoed
+        // publications only declare their licence once it applies
+        epub_license: Some(EpubLicense::Open),
+        collections: Some(Collections {
+            issn: true,
+            proprietary_identifiers: true,
+            sequence_number: false,
+            collection_sequence: true,
+        }),
+        titles: Titles::Translated {
+            canonical_locale: true,
+        },
+        contributors: Contributors {
+            role: onix_contributor_role,
+            excluded: &[],
+            require_author: false,
+            detail: ContributorDetail::Complete {
+                alternative_names: false,
+                affiliation_ror: false,
+            },
+        },
+        edition: Some(Edition::Number),
+        ancillary_content: true,
+        subjects: Subjects {
+            crosswalk: false,
+            scheme: onix_subject_scheme,
+            headings: &[SubjectType::CUSTOM],
+            combine_keywords: true,
+            main_subject: true,
+        },
+        default_audience: true,
+    },
+    collateral: Collateral {
+        descriptions: Descriptions::Complete,
+        table_of_contents: true,
+        toc_language: None,
+        open_access_statement: Some(OpenAccessStatement::Open),
+        general_note: true,
+        cover: true,
+        cover_caption: true,
+        supplementary: false,
+    },
+    chapters: Some(Chapters::Listed),
+    publishing: Publishing {
+        websites: true,
+        funders: Some(Funders::Complete),
+        city_of_publication: true,
+        // 01 Publication date
+        publication_date_role: "01",
+        publication_year_only: false,
+        copyright_year: false,
+        copyright_holders: true,
+        sales_rights: true,
+    },
+    related_material: RelatedMaterial::Complete,
+    supply: Supply {
+        world_market: true,
+        suppliers: Suppliers::Locations,
+        availability: Availability::WorkStatus,
+        // 08 Expected availability date
+        supply_date_role: Some("08"),
+        prices: PriceRule::Listed,
+    },
+};
 
-impl XmlElementBlock<Onix3Thoth> for Measure {
-    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
-        write_element_block("Measure", w, |w| {
-            write_element_block("MeasureType", w, |w| {
-                w.write(XmlEvent::Characters(self.measure_type))
-                    .map_err(|e| e.into())
-            })?;
-            write_element_block("Measurement", w, |w| {
-                w.write(XmlEvent::Characters(&self.measurement.to_string()))
-                    .map_err(|e| e.into())
-            })?;
-            write_element_block("MeasureUnitCode", w, |w| {
-                w.write(XmlEvent::Characters(self.measure_unit_code))
-                    .map_err(|e| e.into())
-            })
-        })
-    }
-}
+onix3_profile!(Onix3Thoth, PROFILE);
 
 #[cfg(test)]
 mod tests {
@@ -1710,20 +100,27 @@ mod tests {
     // We therefore rely on `assert!(contains)` rather than `assert_eq!` in these cases
     // println!s throughout will only be printed if test fails - this assists debugging
     use super::*;
+    use crate::xml::XmlElementBlock;
     use std::str::FromStr;
     use thoth_api::model::Doi;
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_api::model::Ror;
     use thoth_client::{
-        ContributionType, CurrencyCode, FundingInstitution, LanguageCode, LanguageRelation,
-        LocationPlatform, PublicationType, WorkContributionsAffiliations,
-        WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkImprint,
-        WorkImprintPublisher, WorkImprintPublisherContacts, WorkIssuesSeries, WorkPublications,
-        WorkPublicationsLocations, WorkPublicationsPrices, WorkRelationsRelatedWork,
-        WorkRelationsRelatedWorkImprint, WorkRelationsRelatedWorkImprintPublisher, WorkStatus,
-        WorkSubjects, WorkType,
+        AccessibilityException, AccessibilityStandard, AudienceCode, AudienceRangePrecision,
+        AudienceRangeQualifier, ContactType, ContributionType, CountryCode, CurrencyCode,
+        FundingInstitution, LanguageCode, LanguageRelation, LocationPlatform, PriceType,
+        ProductFormDetailType, PublicationType, RelationType, SalesRightsType, Work, WorkAudiences,
+        WorkContributions, WorkContributionsAffiliations, WorkContributionsAffiliationsInstitution,
+        WorkContributionsContributor, WorkDateType, WorkFundings, WorkImprint,
+        WorkImprintPublisher, WorkImprintPublisherContacts, WorkIssues, WorkIssuesSeries,
+        WorkLanguages, WorkPublications, WorkPublicationsLocations, WorkPublicationsPrices,
+        WorkPublicationsProductFormDetails, WorkPublicationsSalesRights, WorkReferences,
+        WorkRelations, WorkRelationsRelatedWork, WorkRelationsRelatedWorkImprint,
+        WorkRelationsRelatedWorkImprintPublisher, WorkStatus, WorkSubjects, WorkType,
+        WorkWorkDates,
     };
+    use thoth_errors::ThothError;
     use uuid::Uuid;
 
     fn generate_test_output(expect_ok: bool, input: &impl XmlElementBlock<Onix3Thoth>) -> String {
@@ -1763,9 +160,12 @@ mod tests {
                 canonical: true,
             }],
             contribution_ordinal: 1,
+            name_variants: vec![],
+            credit_roles: vec![],
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: Some("https://contributor.site".to_string()),
+                name_variants: vec![],
             },
             affiliations: vec![WorkContributionsAffiliations {
                 position: Some("Manager".to_string()),
@@ -2441,14 +841,29 @@ mod tests {
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::EUR,
                         unit_price: 5.95,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::GBP,
                         unit_price: 4.95,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::USD,
                         unit_price: 8.0,
+                        price_type: PriceType::RRP,
+                        tax_inclusive: true,
+                        countries_included: None,
+                        effective_from: None,
+                        effective_until: None,
                     },
                 ],
                 locations: vec![
@@ -2466,6 +881,11 @@ mod tests {
                         canonical: false,
                     },
                 ],
+                sales_rights: vec![],
+                product_form_details: vec![],
+                license: None,
+                access_status: None,
+                embargo_end_date: None,
             }],
             subjects: vec![
                 WorkSubjects {
@@ -2504,6 +924,13 @@ mod tests {
                     subject_ordinal: 1,
                 },
             ],
+            audiences: vec![],
+            work_dates: vec![],
+            awards: vec![],
+            endorsements: vec![],
+            book_reviews: vec![],
+            additional_resources: vec![],
+            featured_video: None,
             fundings: vec![],
             relations: vec![
                 WorkRelations {
@@ -3225,6 +1652,145 @@ mod tests {
       </Price>"#
         ));
 
+        // Add territorial sales rights and a territory-specific agency price
+        test_work.publications[0].sales_rights = vec![
+            WorkPublicationsSalesRights {
+                sales_rights_type: SalesRightsType::FOR_SALE_EXCLUSIVE,
+                countries_included: Some(vec![CountryCode::GBR, CountryCode::IRL]),
+                countries_excluded: vec![],
+            },
+            WorkPublicationsSalesRights {
+                sales_rights_type: SalesRightsType::FOR_SALE_NON_EXCLUSIVE,
+                countries_included: None,
+                countries_excluded: vec![CountryCode::GBR, CountryCode::IRL],
+            },
+        ];
+        test_work.publications[0].prices[0].price_type = PriceType::AGENCY;
+        test_work.publications[0].prices[0].tax_inclusive = false;
+        test_work.publications[0].prices[0].countries_included =
+            Some(vec![CountryCode::DEU, CountryCode::AUT]);
+        test_work.publications[0].prices[0].effective_from =
+            chrono::NaiveDate::from_ymd_opt(2026, 1, 1);
+        test_work.publications[0].prices[0].effective_until =
+            chrono::NaiveDate::from_ymd_opt(2999, 12, 31);
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <SalesRights>
+      <SalesRightsType>01</SalesRightsType>
+      <Territory>
+        <CountriesIncluded>GB IE</CountriesIncluded>
+      </Territory>
+    </SalesRights>
+    <SalesRights>
+      <SalesRightsType>02</SalesRightsType>
+      <Territory>
+        <RegionsIncluded>WORLD</RegionsIncluded>
+        <CountriesExcluded>GB IE</CountriesExcluded>
+      </Territory>
+    </SalesRights>
+  </PublishingDetail>"#
+        ));
+        assert!(output.contains(
+            r#"
+      <Price>
+        <PriceType>41</PriceType>
+        <PriceAmount>5.95</PriceAmount>
+        <CurrencyCode>EUR</CurrencyCode>
+        <Territory>
+          <CountriesIncluded>DE AT</CountriesIncluded>
+        </Territory>
+        <PriceDate>
+          <PriceDateRole>14</PriceDateRole>
+          <Date dateformat="00">20260101</Date>
+        </PriceDate>
+        <PriceDate>
+          <PriceDateRole>15</PriceDateRole>
+          <Date dateformat="00">29991231</Date>
+        </PriceDate>
+      </Price>"#
+        ));
+        test_work.publications[0].sales_rights = vec![];
+        test_work.publications[0].prices[0].price_type = PriceType::RRP;
+        test_work.publications[0].prices[0].tax_inclusive = true;
+        test_work.publications[0].prices[0].countries_included = None;
+        test_work.publications[0].prices[0].effective_from = None;
+        test_work.publications[0].prices[0].effective_until = None;
+
+        // Expired prices are dropped, scheduled prices are announced ahead of time
+        let mut expired_price = test_work.publications[0].prices[0].clone();
+        expired_price.unit_price = 4.50;
+        expired_price.effective_until = chrono::NaiveDate::from_ymd_opt(2000, 1, 1);
+        let mut future_price = test_work.publications[0].prices[0].clone();
+        future_price.unit_price = 6.50;
+        future_price.effective_from = chrono::NaiveDate::from_ymd_opt(2999, 1, 1);
+        test_work.publications[0]
+            .prices
+            .extend([expired_price, future_price]);
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(r#"<PriceAmount>4.50</PriceAmount>"#));
+        assert!(output.contains(
+            r#"
+      <Price>
+        <PriceType>02</PriceType>
+        <PriceAmount>6.50</PriceAmount>
+        <CurrencyCode>EUR</CurrencyCode>
+        <Territory>
+          <RegionsIncluded>WORLD</RegionsIncluded>
+        </Territory>
+        <PriceDate>
+          <PriceDateRole>14</PriceDateRole>
+          <Date dateformat="00">29990101</Date>
+        </PriceDate>
+      </Price>"#
+        ));
+        test_work.publications[0].prices.truncate(1);
+
+        // Recorded audiences replace the professional and scholarly default
+        test_work.audiences = vec![
+            WorkAudiences {
+                audience_code: AudienceCode::CHILDREN,
+                audience_range_qualifier: Some(AudienceRangeQualifier::INTEREST_AGE_YEARS),
+                audience_range_precision: Some(AudienceRangePrecision::FROM),
+                audience_range_from: Some("9".to_string()),
+                audience_range_to: Some("12".to_string()),
+            },
+            WorkAudiences {
+                audience_code: AudienceCode::PRIMARY_AND_SECONDARY,
+                audience_range_qualifier: Some(AudienceRangeQualifier::US_SCHOOL_GRADE),
+                audience_range_precision: Some(AudienceRangePrecision::EXACT),
+                audience_range_from: Some("K".to_string()),
+                audience_range_to: None,
+            },
+        ];
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(r#"<AudienceCodeValue>06</AudienceCodeValue>"#));
+        assert!(output.contains(
+            r#"
+    <Audience>
+      <AudienceCodeType>01</AudienceCodeType>
+      <AudienceCodeValue>02</AudienceCodeValue>
+    </Audience>
+    <Audience>
+      <AudienceCodeType>01</AudienceCodeType>
+      <AudienceCodeValue>04</AudienceCodeValue>
+    </Audience>
+    <AudienceRange>
+      <AudienceRangeQualifier>17</AudienceRangeQualifier>
+      <AudienceRangePrecision>03</AudienceRangePrecision>
+      <AudienceRangeValue>9</AudienceRangeValue>
+      <AudienceRangePrecision>04</AudienceRangePrecision>
+      <AudienceRangeValue>12</AudienceRangeValue>
+    </AudienceRange>
+    <AudienceRange>
+      <AudienceRangeQualifier>11</AudienceRangeQualifier>
+      <AudienceRangePrecision>01</AudienceRangePrecision>
+      <AudienceRangeValue>K</AudienceRangeValue>
+    </AudienceRange>
+  </DescriptiveDetail>"#
+        ));
+        test_work.audiences = vec![];
+
         // Add withdrawn_date
         test_work.withdrawn_date = chrono::NaiveDate::from_ymd_opt(2020, 12, 31);
         let output = generate_test_output(true, &test_work);
@@ -3236,6 +1802,42 @@ mod tests {
     </PublishingDate>"#
         ));
 
+        // Lifecycle dates follow the publication and withdrawn dates; the withdrawn date
+        // takes precedence over a recorded out-of-print date
+        test_work.work_dates = vec![
+            WorkWorkDates {
+                date_type: WorkDateType::PUBLIC_ANNOUNCEMENT,
+                date: chrono::NaiveDate::from_ymd_opt(1999, 6, 1).unwrap(),
+            },
+            WorkWorkDates {
+                date_type: WorkDateType::OUT_OF_PRINT,
+                date: chrono::NaiveDate::from_ymd_opt(2021, 1, 31).unwrap(),
+            },
+        ];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <PublishingDate>
+      <PublishingDateRole>13</PublishingDateRole>
+      <Date dateformat="00">20201231</Date>
+    </PublishingDate>
+    <PublishingDate>
+      <PublishingDateRole>09</PublishingDateRole>
+      <Date dateformat="00">19990601</Date>
+    </PublishingDate>"#
+        ));
+        assert!(!output.contains(r#"<Date dateformat="00">20210131</Date>"#));
+        test_work.withdrawn_date = None;
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <PublishingDate>
+      <PublishingDateRole>13</PublishingDateRole>
+      <Date dateformat="00">20210131</Date>
+    </PublishingDate>"#
+        ));
+        test_work.work_dates = vec![];
+
         // Test ProductForm[Detail] with different publication types
         test_work.publications[0].publication_type = PublicationType::HARDBACK;
         let output = generate_test_output(true, &test_work);
@@ -3314,6 +1916,54 @@ mod tests {
         ));
         test_work.publications[0].publication_type = PublicationType::PAPERBACK;
 
+        // Test recorded product form details, features and packaging
+        test_work.publications[0].publication_type = PublicationType::HARDBACK;
+        test_work.publications[0].product_form_details = vec![
+            WorkPublicationsProductFormDetails {
+                detail_type: ProductFormDetailType::FORM_FEATURE,
+                detail_code: "01".to_string(),
+                feature_value: Some("NAV".to_string()),
+                feature_description: None,
+            },
+            WorkPublicationsProductFormDetails {
+                detail_type: ProductFormDetailType::PACKAGING,
+                detail_code: "10".to_string(),
+                feature_value: None,
+                feature_description: None,
+            },
+            WorkPublicationsProductFormDetails {
+                detail_type: ProductFormDetailType::FORM_DETAIL,
+                detail_code: "B401".to_string(),
+                feature_value: None,
+                feature_description: None,
+            },
+            WorkPublicationsProductFormDetails {
+                detail_type: ProductFormDetailType::FORM_DETAIL,
+                detail_code: "B501".to_string(),
+                feature_value: None,
+                feature_description: None,
+            },
+        ];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <ProductForm>BB</ProductForm>
+    <ProductFormDetail>B401</ProductFormDetail>
+    <ProductFormDetail>B501</ProductFormDetail>
+    <ProductFormFeature>"#
+        ));
+        assert!(output.contains(
+            r#"
+    <ProductFormFeature>
+      <ProductFormFeatureType>01</ProductFormFeatureType>
+      <ProductFormFeatureValue>NAV</ProductFormFeatureValue>
+    </ProductFormFeature>
+    <ProductPackaging>10</ProductPackaging>
+    <PrimaryContentType>10</PrimaryContentType>"#
+        ));
+        test_work.publications[0].product_form_details = vec![];
+        test_work.publications[0].publication_type = PublicationType::PAPERBACK;
+
         // Test e-publication accessibility details output
         test_work.publications[0].publication_type = PublicationType::PDF;
         test_work.publications[0].accessibility_standard = Some(AccessibilityStandard::WCAG21AA);
@@ -3912,6 +2562,11 @@ mod tests {
             accessibility_report_url: None,
             prices: vec![],
             locations: vec![],
+            sales_rights: vec![],
+            product_form_details: vec![],
+            license: None,
+            access_status: None,
+            embargo_end_date: None,
         });
         let output = generate_test_output(true, &test_work);
         println!("{output}");